@@ -0,0 +1,136 @@
+//! Benchmarks for repository hot paths: ticket list, search, workboard queue,
+//! ticket detail, and customer search.
+//!
+//! Usage: DATABASE_URL=<scratch database> cargo bench --features bench
+//!
+//! Synthetic data is generated first when the database has none (volumes
+//! from BENCH_CUSTOMERS, BENCH_TICKETS, and BENCH_PHOTOS_PER_TICKET).
+
+use api::bench_data::{count_bench_customers, generate, BenchDataConfig};
+use api::models::customer::CustomerSearchParams;
+use api::models::{TicketFilters, TicketOrder, TicketSearchParams};
+use api::repositories::{
+    CustomerRepository, StatusHistoryRepository, TicketNoteRepository, TicketPhotoRepository,
+    TicketRepository,
+};
+use api::{create_pool, DbConfig};
+use criterion::{criterion_group, criterion_main, Criterion};
+use sqlx::PgPool;
+use tokio::runtime::Runtime;
+use uuid::Uuid;
+
+/// Connect and make sure synthetic data is loaded.
+fn setup(runtime: &Runtime) -> (PgPool, Uuid) {
+    runtime.block_on(async {
+        let database_url =
+            std::env::var("DATABASE_URL").expect("DATABASE_URL must point at a scratch database");
+        let pool = create_pool(&DbConfig::new(database_url))
+            .await
+            .expect("failed to connect to database");
+
+        if count_bench_customers(&pool).await.unwrap() == 0 {
+            let config = BenchDataConfig::from_env().expect("invalid BENCH_* setting");
+            let summary = generate(&pool, &config)
+                .await
+                .expect("failed to generate synthetic data");
+            println!(
+                "Generated {} customers, {} tickets, and {} photos in {:.1?}",
+                summary.customers, summary.tickets, summary.photos, summary.elapsed
+            );
+        }
+
+        let ticket_id = sqlx::query_scalar::<_, Uuid>(
+            "SELECT ticket_id FROM tickets WHERE deleted_at IS NULL ORDER BY created_at DESC LIMIT 1",
+        )
+        .fetch_one(&pool)
+        .await
+        .expect("no tickets to benchmark");
+
+        (pool, ticket_id)
+    })
+}
+
+fn repository_benchmarks(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("failed to start runtime");
+    let (pool, ticket_id) = setup(&runtime);
+
+    c.bench_function("ticket_list", |b| {
+        b.to_async(&runtime).iter(|| async {
+            TicketRepository::list(
+                &pool,
+                TicketFilters {
+                    limit: Some(50),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap()
+        })
+    });
+
+    c.bench_function("ticket_search", |b| {
+        b.to_async(&runtime).iter(|| async {
+            TicketRepository::search(
+                &pool,
+                TicketSearchParams {
+                    query: "clasp".to_string(),
+                    statuses: None,
+                    limit: Some(50),
+                    offset: None,
+                    order: TicketOrder::Priority,
+                    after: None,
+                    visible_to: None,
+                    include_deleted: false,
+                    training: false,
+                },
+            )
+            .await
+            .unwrap()
+        })
+    });
+
+    c.bench_function("workboard_queue", |b| {
+        b.to_async(&runtime).iter(|| async {
+            TicketRepository::get_queue(&pool, Some(50), None, false, false)
+                .await
+                .unwrap()
+        })
+    });
+
+    c.bench_function("ticket_detail", |b| {
+        b.to_async(&runtime).iter(|| async {
+            let ticket = TicketRepository::find_by_id(&pool, ticket_id)
+                .await
+                .unwrap();
+            let photos = TicketPhotoRepository::find_by_ticket_id(&pool, ticket_id)
+                .await
+                .unwrap();
+            let notes = TicketNoteRepository::find_by_ticket_id(&pool, ticket_id)
+                .await
+                .unwrap();
+            let history = StatusHistoryRepository::find_by_ticket_id(&pool, ticket_id)
+                .await
+                .unwrap();
+            (ticket, photos, notes, history)
+        })
+    });
+
+    c.bench_function("customer_search", |b| {
+        b.to_async(&runtime).iter(|| async {
+            CustomerRepository::search(
+                &pool,
+                CustomerSearchParams {
+                    query: "Customer 42".to_string(),
+                    limit: Some(50),
+                    offset: None,
+                    include_training: false,
+                },
+            )
+            .await
+            .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, repository_benchmarks);
+criterion_main!(benches);
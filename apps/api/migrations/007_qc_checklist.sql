@@ -0,0 +1,25 @@
+-- Quality control checklist gate before ReadyForPickup
+-- Stores configure a list of QC items; a passing check must be recorded
+-- (with the checker's employee ID) before a ticket may move to ready_for_pickup.
+
+-- Configurable checklist items (empty = QC gate disabled)
+ALTER TABLE store_settings
+ADD COLUMN qc_checklist TEXT[] NOT NULL DEFAULT '{}';
+
+-- ticket_qc_checks
+-- One row per QC attempt. Append-only so failed attempts remain visible.
+CREATE TABLE ticket_qc_checks (
+    check_id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE RESTRICT,
+    passed              BOOLEAN NOT NULL,
+    passed_items        TEXT[] NOT NULL DEFAULT '{}',
+    failed_items        TEXT[] NOT NULL DEFAULT '{}',
+    notes               TEXT,
+    checked_by          UUID NOT NULL REFERENCES employees(employee_id),
+    checked_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ticket_qc_checks_ticket ON ticket_qc_checks (ticket_id, checked_at DESC);
+
+COMMENT ON TABLE ticket_qc_checks IS 'Quality control checklist results recorded before a ticket is marked ready for pickup';
+COMMENT ON COLUMN ticket_qc_checks.passed IS 'True only if every configured checklist item passed';
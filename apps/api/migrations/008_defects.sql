@@ -0,0 +1,34 @@
+-- Rework/defect tracking
+-- Records a defect reason whenever QC fails or a customer returns an item,
+-- feeding the quality report (rework rates by employee, item type, and reason).
+
+CREATE TYPE defect_reason AS ENUM (
+    'loose_stone',
+    'finish',
+    'sizing',
+    'solder_joint',
+    'clasp',
+    'wrong_work',
+    'damage',
+    'other'
+);
+
+CREATE TYPE defect_source AS ENUM ('qc_failure', 'customer_return');
+
+-- ticket_defects
+CREATE TABLE ticket_defects (
+    defect_id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE RESTRICT,
+    reason              defect_reason NOT NULL,
+    source              defect_source NOT NULL,
+    qc_check_id         UUID REFERENCES ticket_qc_checks(check_id),
+    notes               TEXT,
+    recorded_by         UUID NOT NULL REFERENCES employees(employee_id),
+    recorded_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ticket_defects_ticket ON ticket_defects (ticket_id);
+CREATE INDEX idx_ticket_defects_recorded_at ON ticket_defects (recorded_at);
+
+COMMENT ON TABLE ticket_defects IS 'Defects found at QC or reported on customer return; drives rework reporting';
+COMMENT ON COLUMN ticket_defects.qc_check_id IS 'The failed QC check that produced this defect (NULL for customer returns)';
@@ -0,0 +1,31 @@
+-- Debug request capture
+-- Opt-in, auto-expiring capture of request/response bodies for selected
+-- routes. Bodies are redacted before they are written.
+
+-- Capture toggle (NULL or past = disabled)
+ALTER TABLE store_settings
+ADD COLUMN debug_capture_until TIMESTAMPTZ,
+ADD COLUMN debug_capture_routes TEXT[] NOT NULL DEFAULT '{}';
+
+COMMENT ON COLUMN store_settings.debug_capture_until IS 'Debug capture is active until this time; NULL when disabled';
+COMMENT ON COLUMN store_settings.debug_capture_routes IS 'Path prefixes to capture; empty captures every /api/v1 route';
+
+-- request_logs
+CREATE TABLE request_logs (
+    log_id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    method              VARCHAR(10) NOT NULL,
+    path                TEXT NOT NULL,
+    query               TEXT,
+    status              INTEGER NOT NULL,
+    duration_ms         INTEGER NOT NULL,
+    client_ip           TEXT,
+    request_headers     TEXT NOT NULL,
+    request_body        TEXT,
+    response_body       TEXT,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_request_logs_created_at ON request_logs (created_at DESC);
+CREATE INDEX idx_request_logs_path ON request_logs (path);
+
+COMMENT ON TABLE request_logs IS 'Redacted request/response captures recorded while debug capture is enabled';
@@ -0,0 +1,11 @@
+-- Customer soft delete
+-- Customers maintained from the front desk can be removed without losing
+-- the ticket history that references them.
+
+ALTER TABLE customers
+ADD COLUMN deleted_at TIMESTAMPTZ,
+ADD COLUMN deleted_by UUID REFERENCES employees(employee_id);
+
+COMMENT ON COLUMN customers.deleted_at IS 'When the customer was soft-deleted; NULL for active customers';
+
+CREATE INDEX idx_customers_active ON customers (customer_id) WHERE deleted_at IS NULL;
@@ -0,0 +1,21 @@
+-- Customer merges
+-- Audit trail for duplicate customers folded into a surviving record.
+
+-- customer_merges
+-- One row per merge. The source customer is soft-deleted; a snapshot of its
+-- contact details is kept here so the merge can be reviewed later.
+CREATE TABLE customer_merges (
+    merge_id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    target_customer_id  UUID NOT NULL REFERENCES customers(customer_id),
+    source_customer_id  UUID NOT NULL REFERENCES customers(customer_id),
+    source_name         VARCHAR(255) NOT NULL,
+    source_phone        VARCHAR(50),
+    source_email        VARCHAR(255),
+    tickets_moved       INTEGER NOT NULL,
+    merged_by           UUID NOT NULL REFERENCES employees(employee_id),
+    merged_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT customer_merges_distinct CHECK (target_customer_id <> source_customer_id)
+);
+
+CREATE INDEX idx_customer_merges_target ON customer_merges (target_customer_id);
+CREATE INDEX idx_customer_merges_source ON customer_merges (source_customer_id);
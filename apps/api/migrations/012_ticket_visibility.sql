@@ -0,0 +1,8 @@
+-- Employee-scoped ticket visibility
+-- When enabled, staff only see tickets they took in or are assigned to in
+-- ticket lists, search, and the workboard queue. Admins always see everything.
+
+ALTER TABLE store_settings
+ADD COLUMN scope_ticket_visibility BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN store_settings.scope_ticket_visibility IS 'Limit staff ticket lists/search/queue to tickets they took in or are assigned';
@@ -0,0 +1,20 @@
+-- Storage location rules
+-- Admin-configured rules used to suggest where a new item should be stored
+-- during intake (e.g., watches go to drawer W, high-value items to the safe).
+
+-- storage_location_rules
+-- A rule matches when its item_type equals the ticket's item type
+-- (case-insensitive) and the quote meets min_quote_amount. Unset conditions
+-- always match, but at least one must be set. Higher priority wins.
+CREATE TABLE storage_location_rules (
+    rule_id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    item_type           VARCHAR(100),
+    min_quote_amount    DECIMAL(10,2),
+    location_id         UUID NOT NULL REFERENCES storage_locations(location_id),
+    priority            INTEGER NOT NULL DEFAULT 0,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT storage_location_rules_has_condition
+        CHECK (item_type IS NOT NULL OR min_quote_amount IS NOT NULL)
+);
+
+CREATE INDEX idx_storage_location_rules_location ON storage_location_rules (location_id);
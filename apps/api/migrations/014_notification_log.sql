@@ -0,0 +1,26 @@
+-- Customer notification log
+-- Records every outbound customer notification (e.g., the ready-for-pickup
+-- text) with its delivery status so staff can see it on the ticket.
+
+CREATE TYPE notification_channel AS ENUM ('sms');
+
+CREATE TYPE notification_status AS ENUM ('pending', 'sent', 'failed', 'skipped');
+
+-- notification_log
+CREATE TABLE notification_log (
+    notification_id     UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE RESTRICT,
+    channel             notification_channel NOT NULL,
+    recipient           VARCHAR(255),
+    message             TEXT NOT NULL,
+    status              notification_status NOT NULL DEFAULT 'pending',
+    provider            VARCHAR(50),
+    provider_message_id VARCHAR(255),
+    error               TEXT,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    sent_at             TIMESTAMPTZ
+);
+
+CREATE INDEX idx_notification_log_ticket ON notification_log (ticket_id, created_at);
+
+COMMENT ON COLUMN notification_log.error IS 'Provider error for failed sends, or why a notification was skipped';
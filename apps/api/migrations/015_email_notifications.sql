@@ -0,0 +1,36 @@
+-- Email notifications
+-- Adds email as a notification channel, store-editable message templates,
+-- and a per-customer opt-out.
+
+ALTER TYPE notification_channel ADD VALUE 'email';
+
+CREATE TYPE notification_event AS ENUM ('intake_confirmation', 'ready_for_pickup');
+
+-- notification_templates
+-- One email template per event. Placeholders such as {friendly_code} and
+-- {promise_date} are filled in when the message is sent.
+CREATE TABLE notification_templates (
+    event               notification_event PRIMARY KEY,
+    subject             VARCHAR(255) NOT NULL,
+    body                TEXT NOT NULL,
+    is_enabled          BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO notification_templates (event, subject, body) VALUES
+(
+    'intake_confirmation',
+    'We received your item ({friendly_code})',
+    E'Hi {customer_name},\n\nThank you for bringing your {item_description} to {store_name}. Your ticket number is {friendly_code}.\n\nPromised by: {promise_date}\n\nQuestions? Call us at {store_phone}.'
+),
+(
+    'ready_for_pickup',
+    'Your item is ready for pickup ({friendly_code})',
+    E'Hi {customer_name},\n\nGood news: your {item_description} (ticket {friendly_code}) is ready for pickup at {store_name}.\n\nQuestions? Call us at {store_phone}.'
+);
+
+-- Subject line for email notifications (NULL for SMS)
+ALTER TABLE notification_log ADD COLUMN subject VARCHAR(255);
+
+-- Customers who asked not to be contacted are skipped for every channel
+ALTER TABLE customers ADD COLUMN notifications_opt_out BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,18 @@
+-- Weight and metal capture
+-- Optional weight and metal on tickets, plus an admin-maintained price table
+-- used to estimate melt value for scrap and insurance decisions. The estimate
+-- is internal only and never printed on customer receipts.
+
+ALTER TABLE tickets
+    ADD COLUMN weight_grams DECIMAL(10,3) CHECK (weight_grams IS NULL OR weight_grams >= 0),
+    ADD COLUMN metal_type   VARCHAR(50);
+
+-- metal_prices
+-- One row per metal/alloy (e.g., "14k_gold"). purity is the fine metal
+-- fraction (0.585 for 14k); price_per_gram is for the pure metal.
+CREATE TABLE metal_prices (
+    metal_type      VARCHAR(50) PRIMARY KEY,
+    purity          DECIMAL(5,4) NOT NULL CHECK (purity > 0 AND purity <= 1),
+    price_per_gram  DECIMAL(12,4) NOT NULL CHECK (price_per_gram >= 0),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,32 @@
+-- Chain of custody for high-value items
+-- Tickets quoted at or above the store's custody threshold must record a
+-- witnessed custody event every time the item moves. Events are numbered per
+-- ticket so gaps or reordering are detectable in the custody report.
+
+ALTER TABLE store_settings
+    ADD COLUMN custody_value_threshold DECIMAL(10,2)
+        CHECK (custody_value_threshold IS NULL OR custody_value_threshold >= 0);
+
+CREATE TYPE custody_event_type AS ENUM (
+    'intake',           -- Item received from the customer
+    'location_change',  -- Item moved between storage locations
+    'handoff',          -- Item handed between employees
+    'release'           -- Item returned to the customer
+);
+
+-- custody_events
+CREATE TABLE custody_events (
+    event_id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id),
+    sequence            INTEGER NOT NULL,
+    event_type          custody_event_type NOT NULL,
+    from_location_id    UUID REFERENCES storage_locations(location_id),
+    to_location_id      UUID REFERENCES storage_locations(location_id),
+    handled_by          UUID NOT NULL REFERENCES employees(employee_id),
+    witnessed_by        UUID REFERENCES employees(employee_id),
+    notes               TEXT,
+    occurred_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT custody_events_sequence_unique UNIQUE (ticket_id, sequence),
+    CONSTRAINT custody_events_distinct_witness
+        CHECK (witnessed_by IS NULL OR witnessed_by <> handled_by)
+);
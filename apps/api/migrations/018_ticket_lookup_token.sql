@@ -0,0 +1,12 @@
+-- Public status lookup
+-- Each ticket gets a random token at intake, printed on the customer's
+-- receipt. The token plus the friendly code unlocks a redacted status view
+-- without authentication.
+
+ALTER TABLE tickets ADD COLUMN lookup_token VARCHAR(32);
+
+UPDATE tickets
+SET lookup_token = substr(md5(random()::text || ticket_id::text), 1, 16)
+WHERE lookup_token IS NULL;
+
+ALTER TABLE tickets ALTER COLUMN lookup_token SET NOT NULL;
@@ -0,0 +1,11 @@
+-- Store settings sections
+-- version is bumped on every settings update and served as the ETag so two
+-- admins editing at once can't silently overwrite each other.
+-- notifications_enabled is a store-wide switch for customer notifications.
+
+ALTER TABLE store_settings
+    ADD COLUMN version INTEGER NOT NULL DEFAULT 1,
+    ADD COLUMN notifications_enabled BOOLEAN NOT NULL DEFAULT TRUE;
+
+COMMENT ON COLUMN store_settings.version IS 'Incremented on every update; used for ETag / If-Match checks';
+COMMENT ON COLUMN store_settings.notifications_enabled IS 'Send customer SMS/email notifications (false skips all sends)';
@@ -0,0 +1,9 @@
+-- Auto-archive closed tickets
+-- A background task archives tickets closed more than auto_archive_after_days
+-- days ago. NULL disables auto-archiving.
+
+ALTER TABLE store_settings
+    ADD COLUMN auto_archive_after_days INTEGER
+        CHECK (auto_archive_after_days IS NULL OR auto_archive_after_days > 0);
+
+COMMENT ON COLUMN store_settings.auto_archive_after_days IS 'Archive tickets closed more than this many days ago (NULL = disabled)';
@@ -0,0 +1,20 @@
+-- Settings change history
+-- Every settings update records the fields it changed (old and new values)
+-- so a bad edit can be reviewed and rolled back. changed_by is the employee
+-- signed in when the change was made, if any (admin auth alone has no
+-- employee).
+
+CREATE TABLE settings_changes (
+    change_id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    version                 INTEGER NOT NULL,
+    old_values              JSONB NOT NULL,
+    new_values              JSONB NOT NULL,
+    changed_by              UUID REFERENCES employees(employee_id),
+    rolled_back_change_id   UUID REFERENCES settings_changes(change_id),
+    changed_at              TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_settings_changes_changed_at ON settings_changes (changed_at DESC);
+
+COMMENT ON COLUMN settings_changes.version IS 'Settings version produced by this change';
+COMMENT ON COLUMN settings_changes.rolled_back_change_id IS 'Set when this change rolled back an earlier one';
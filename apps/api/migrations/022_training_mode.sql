@@ -0,0 +1,19 @@
+-- Training mode
+-- A terminal can switch its employee session into training mode so new hires
+-- can practice intake and close. Tickets and customers created in training
+-- are tagged and kept out of real lists, the workboard, and reports.
+
+ALTER TABLE employee_sessions
+    ADD COLUMN training_mode BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE tickets
+    ADD COLUMN is_training BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE customers
+    ADD COLUMN is_training BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE INDEX idx_tickets_training ON tickets (is_training) WHERE is_training;
+
+COMMENT ON COLUMN employee_sessions.training_mode IS 'Session operates on training data only';
+COMMENT ON COLUMN tickets.is_training IS 'Created in training mode; excluded from real views and reports';
+COMMENT ON COLUMN customers.is_training IS 'Created in training mode; hidden from real customer search';
@@ -0,0 +1,25 @@
+-- Payments and deposits
+-- Deposits are taken at intake and the final payment at close. Closing a
+-- ticket requires payments to cover the actual amount unless a balance due
+-- is explicitly allowed.
+
+CREATE TYPE payment_kind AS ENUM ('deposit', 'final');
+
+CREATE TYPE payment_method AS ENUM ('cash', 'card', 'check', 'other');
+
+-- ticket_payments
+CREATE TABLE ticket_payments (
+    payment_id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE RESTRICT,
+    kind                payment_kind NOT NULL,
+    method              payment_method NOT NULL,
+    amount              NUMERIC(10, 2) NOT NULL CHECK (amount > 0),
+    notes               TEXT,
+    received_by         UUID NOT NULL REFERENCES employees(employee_id),
+    received_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ticket_payments_ticket ON ticket_payments (ticket_id, received_at);
+
+COMMENT ON TABLE ticket_payments IS 'Money received against a ticket: deposits at intake, final payment at close';
+COMMENT ON COLUMN ticket_payments.kind IS 'deposit (before close) or final (at close)';
@@ -0,0 +1,46 @@
+-- Partner API for trade work
+-- Jewelers who send trade work get a partner account with an API key. Each
+-- partner is linked to a customer record (the partner shop), so their
+-- tickets are the ones they can see. Requests are rate limited per partner
+-- and counted per day for reporting.
+
+-- partners
+CREATE TABLE partners (
+    partner_id              UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name                    VARCHAR(255) NOT NULL,
+    customer_id             UUID NOT NULL UNIQUE REFERENCES customers(customer_id),
+    api_key_prefix          VARCHAR(16) NOT NULL,
+    api_key_hash            VARCHAR(64) NOT NULL UNIQUE,
+    rate_limit_per_minute   INTEGER NOT NULL DEFAULT 30 CHECK (rate_limit_per_minute > 0),
+    taken_in_by             UUID NOT NULL REFERENCES employees(employee_id),
+    is_active               BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at            TIMESTAMPTZ
+);
+
+-- partner_tickets: tickets submitted through the partner API
+CREATE TABLE partner_tickets (
+    ticket_id               UUID PRIMARY KEY REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    partner_id              UUID NOT NULL REFERENCES partners(partner_id),
+    partner_reference       VARCHAR(100),
+    submitted_at            TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_partner_tickets_partner ON partner_tickets (partner_id, submitted_at);
+
+-- partner_api_usage: daily request counts per partner
+CREATE TABLE partner_api_usage (
+    partner_id              UUID NOT NULL REFERENCES partners(partner_id) ON DELETE CASCADE,
+    day                     DATE NOT NULL,
+    request_count           INTEGER NOT NULL DEFAULT 0,
+    rate_limited_count      INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (partner_id, day)
+);
+
+COMMENT ON TABLE partners IS 'Partner jewelers that submit trade work through the partner API';
+COMMENT ON COLUMN partners.customer_id IS 'Customer record for the partner shop; its tickets are visible to the partner';
+COMMENT ON COLUMN partners.api_key_prefix IS 'First characters of the API key, for identifying keys in lists';
+COMMENT ON COLUMN partners.api_key_hash IS 'SHA-256 of the API key, hex encoded';
+COMMENT ON COLUMN partners.taken_in_by IS 'Employee recorded as taking in tickets the partner submits';
+COMMENT ON COLUMN partner_tickets.partner_reference IS 'The partner''s own job number';
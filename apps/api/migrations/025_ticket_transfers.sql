@@ -0,0 +1,34 @@
+-- Ticket transfers between storage locations
+-- A transfer sends an item to another location (another shop's safe, an
+-- off-site workshop) and holds it in transit until an employee at the
+-- receiving location accepts it. The ticket's storage location only changes
+-- on acceptance; while in transit, occupancy counts the item at the
+-- destination that is expecting it.
+
+ALTER TYPE custody_event_type ADD VALUE 'transfer_out';  -- Item sent to another location
+ALTER TYPE custody_event_type ADD VALUE 'transfer_in';   -- Item received at a location
+
+CREATE TYPE transfer_status AS ENUM ('pending', 'accepted', 'cancelled');
+
+-- ticket_transfers
+CREATE TABLE ticket_transfers (
+    transfer_id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    from_location_id    UUID NOT NULL REFERENCES storage_locations(location_id),
+    to_location_id      UUID NOT NULL REFERENCES storage_locations(location_id),
+    status              transfer_status NOT NULL DEFAULT 'pending',
+    requested_by        UUID NOT NULL REFERENCES employees(employee_id),
+    notes               TEXT,
+    requested_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_by         UUID REFERENCES employees(employee_id),
+    resolved_at         TIMESTAMPTZ,
+    CONSTRAINT ticket_transfers_distinct_locations CHECK (from_location_id <> to_location_id)
+);
+
+-- A ticket can only be in transit once at a time
+CREATE UNIQUE INDEX idx_ticket_transfers_one_pending
+    ON ticket_transfers (ticket_id) WHERE status = 'pending';
+
+CREATE INDEX idx_ticket_transfers_ticket ON ticket_transfers (ticket_id, requested_at);
+CREATE INDEX idx_ticket_transfers_incoming
+    ON ticket_transfers (to_location_id) WHERE status = 'pending';
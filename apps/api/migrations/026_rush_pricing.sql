@@ -0,0 +1,24 @@
+-- Rush pricing
+-- Admin-maintained surcharge tiers for rush jobs, chosen by lead time (days
+-- from today to the promise date). A tier with no max_lead_days catches rush
+-- jobs beyond every other tier or without a promise date. The surcharge is
+-- recorded on the ticket as the part of quote_amount it accounts for, so
+-- receipts can show it as its own line.
+
+CREATE TYPE rush_surcharge_kind AS ENUM ('flat', 'percent');
+
+-- rush_surcharge_tiers
+CREATE TABLE rush_surcharge_tiers (
+    tier_id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    max_lead_days   INTEGER CHECK (max_lead_days IS NULL OR max_lead_days >= 0),
+    kind            rush_surcharge_kind NOT NULL,
+    amount          DECIMAL(10,2) NOT NULL CHECK (amount >= 0),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_rush_surcharge_tiers_lead_days
+    ON rush_surcharge_tiers (COALESCE(max_lead_days, -1));
+
+ALTER TABLE tickets
+    ADD COLUMN rush_surcharge DECIMAL(10,2)
+        CHECK (rush_surcharge IS NULL OR rush_surcharge >= 0);
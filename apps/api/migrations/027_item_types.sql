@@ -0,0 +1,19 @@
+-- Item types
+-- Admin-configured item types with intake defaults: a turnaround that sets
+-- the promise date, photos required before work starts, a condition
+-- checklist, and suggested services. Tickets keep item_type as free text,
+-- so one-off types still work; a ticket whose item_type matches a
+-- configured type (case-insensitive) picks up its defaults.
+
+CREATE TABLE item_types (
+    item_type_id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name                    VARCHAR(100) NOT NULL,
+    default_turnaround_days INTEGER CHECK (default_turnaround_days IS NULL OR default_turnaround_days >= 0),
+    required_photos         INTEGER NOT NULL DEFAULT 0 CHECK (required_photos >= 0),
+    condition_checklist     TEXT[] NOT NULL DEFAULT '{}',
+    suggested_services      TEXT[] NOT NULL DEFAULT '{}',
+    sort_order              INTEGER NOT NULL DEFAULT 0,
+    updated_at              TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_item_types_name ON item_types (LOWER(name));
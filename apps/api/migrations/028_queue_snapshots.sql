@@ -0,0 +1,18 @@
+-- Queue snapshots
+-- Open ticket counts per workboard lane, recorded periodically so the
+-- queue-trends report can show how the backlog changes over time. Counts
+-- cover real (non-training), non-deleted tickets.
+
+CREATE TABLE queue_snapshots (
+    snapshot_id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    taken_at            TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    intake              INTEGER NOT NULL,
+    in_progress         INTEGER NOT NULL,
+    waiting_on_parts    INTEGER NOT NULL,
+    ready_for_pickup    INTEGER NOT NULL,
+    -- Open tickets past their promise date
+    overdue             INTEGER NOT NULL,
+    rush                INTEGER NOT NULL
+);
+
+CREATE INDEX idx_queue_snapshots_taken ON queue_snapshots (taken_at);
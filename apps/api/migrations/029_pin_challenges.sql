@@ -0,0 +1,16 @@
+-- PIN challenge-response
+-- Lets clients prove they know an employee PIN without sending it: the
+-- server issues a single-use nonce and the client answers with
+-- HMAC-SHA256(PIN-derived key, nonce). The plain PIN flow stays available.
+
+-- PBKDF2-HMAC-SHA256 key derived from the PIN (hex). Set whenever a PIN is
+-- created or changed, and on the next plain PIN login for existing PINs.
+ALTER TABLE employees ADD COLUMN pin_challenge_key TEXT;
+
+CREATE TABLE pin_challenges (
+    nonce       VARCHAR(64) PRIMARY KEY,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at  TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_pin_challenges_expires ON pin_challenges (expires_at);
@@ -0,0 +1,32 @@
+-- Break-glass admin recovery
+-- A one-time recovery code is issued when setup completes (and on request
+-- by the admin). Only its hash is stored. POST /admin/recover accepts the
+-- code to reset a lost admin PIN; every attempt is recorded.
+
+CREATE TABLE admin_recovery_codes (
+    code_id     UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    -- argon2 hash of the normalized code
+    code_hash   TEXT NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Set when the code was used to recover
+    used_at     TIMESTAMPTZ,
+    -- Set when a newer code replaced it
+    revoked_at  TIMESTAMPTZ
+);
+
+-- At most one code can be live at a time
+CREATE UNIQUE INDEX idx_admin_recovery_codes_live
+    ON admin_recovery_codes ((TRUE))
+    WHERE used_at IS NULL AND revoked_at IS NULL;
+
+CREATE TABLE admin_recovery_attempts (
+    attempt_id      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    attempted_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    client_ip       TEXT NOT NULL,
+    user_agent      TEXT,
+    succeeded       BOOLEAN NOT NULL,
+    -- Why a failed attempt was rejected
+    failure_reason  TEXT
+);
+
+CREATE INDEX idx_admin_recovery_attempts_at ON admin_recovery_attempts (attempted_at);
@@ -0,0 +1,6 @@
+-- Promise date change notifications
+-- Adds the notification event on its own: a new enum value cannot be used
+-- in the same transaction that adds it, and the next migration seeds a
+-- template for it.
+
+ALTER TYPE notification_event ADD VALUE 'promise_date_changed';
@@ -0,0 +1,29 @@
+-- Promise date reasons
+-- Admin-configured reasons for moving a promise date later (waiting on
+-- parts, stone on order, ...). When a promise date slips, staff pick one and
+-- the customer can be told the new date and why.
+
+CREATE TABLE promise_date_reasons (
+    reason_id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    code                VARCHAR(50) NOT NULL,
+    label               VARCHAR(255) NOT NULL,
+    sort_order          INTEGER NOT NULL DEFAULT 0,
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_promise_date_reasons_code ON promise_date_reasons (LOWER(code));
+
+INSERT INTO promise_date_reasons (code, label, sort_order) VALUES
+    ('parts_on_order', 'We are waiting on parts', 0),
+    ('workload', 'Our workshop is busier than expected', 1),
+    ('additional_work', 'The item needs additional work', 2);
+
+-- The email is off until the store turns it on; when enabled, a text is
+-- also sent to customers with a mobile number.
+INSERT INTO notification_templates (event, subject, body, is_enabled) VALUES
+(
+    'promise_date_changed',
+    'New promise date for your item ({friendly_code})',
+    E'Hi {customer_name},\n\nYour {item_description} (ticket {friendly_code}) will now be ready by {promise_date}.\n\nReason: {promise_date_reason}\n\nWe are sorry for the delay. Questions? Call us at {store_phone}.',
+    FALSE
+);
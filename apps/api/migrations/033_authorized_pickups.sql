@@ -0,0 +1,41 @@
+-- Authorized pickups
+-- Customers can name other people (a spouse, a courier) who may collect
+-- their item. Each release records who collected it, the ID they showed,
+-- and their signature.
+
+-- ticket_authorized_pickups
+-- People the customer has authorized to collect the item. Revoked rather
+-- than deleted so past authorizations stay on record.
+CREATE TABLE ticket_authorized_pickups (
+    authorized_pickup_id    UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id               UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    name                    VARCHAR(255) NOT NULL,
+    relationship            VARCHAR(100),
+    phone                   VARCHAR(50),
+    notes                   TEXT,
+    added_by                UUID NOT NULL REFERENCES employees(employee_id),
+    created_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at              TIMESTAMPTZ,
+    revoked_by              UUID REFERENCES employees(employee_id)
+);
+
+CREATE INDEX idx_ticket_authorized_pickups_ticket ON ticket_authorized_pickups (ticket_id);
+
+-- ticket_pickups
+-- One row per release. A reopened and re-closed ticket has several.
+-- Only the last four characters of the ID number are kept.
+CREATE TABLE ticket_pickups (
+    pickup_id               UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id               UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    -- NULL when the customer collected the item themselves
+    authorized_pickup_id    UUID REFERENCES ticket_authorized_pickups(authorized_pickup_id),
+    picked_up_by_name       VARCHAR(255) NOT NULL,
+    id_type                 VARCHAR(50),
+    id_last_four            VARCHAR(4),
+    -- PNG image of the signature
+    signature               BYTEA,
+    released_by             UUID NOT NULL REFERENCES employees(employee_id),
+    picked_up_at            TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ticket_pickups_ticket ON ticket_pickups (ticket_id, picked_up_at);
@@ -0,0 +1,38 @@
+-- Intake drafts
+-- Requests that arrive outside the counter (e.g., a transcribed phone call)
+-- become drafts. Staff confirm a draft into a ticket or dismiss it.
+
+CREATE TYPE intake_draft_source AS ENUM (
+    'phone'             -- Phone call or voicemail transcription service
+);
+
+CREATE TYPE intake_draft_status AS ENUM (
+    'pending',          -- Waiting for staff to review
+    'converted',        -- Confirmed into a ticket
+    'dismissed'         -- Reviewed and not needed (spam, wrong number)
+);
+
+CREATE TABLE intake_drafts (
+    draft_id                UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    source                  intake_draft_source NOT NULL,
+    -- The sending service's ID for the call, so redelivered payloads
+    -- don't create a second draft
+    external_id             VARCHAR(255),
+    customer_name           VARCHAR(255),
+    customer_phone          VARCHAR(50),
+    requested_work          TEXT,
+    transcript              TEXT,
+    recording_url           TEXT,
+    received_at             TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    status                  intake_draft_status NOT NULL DEFAULT 'pending',
+    ticket_id               UUID REFERENCES tickets(ticket_id) ON DELETE SET NULL,
+    resolved_by             UUID REFERENCES employees(employee_id),
+    resolved_at             TIMESTAMPTZ,
+    created_at              TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_intake_drafts_external
+    ON intake_drafts (source, external_id)
+    WHERE external_id IS NOT NULL;
+
+CREATE INDEX idx_intake_drafts_status ON intake_drafts (status, received_at);
@@ -0,0 +1,64 @@
+-- Customer campaigns
+-- Admins send one templated message to a segment of customers. Recipients
+-- are captured when the campaign is created and work as the send queue:
+-- a background worker claims pending rows at the campaign's send rate.
+
+CREATE TYPE campaign_status AS ENUM (
+    'queued',           -- Created, waiting for the worker
+    'running',          -- Worker is sending
+    'completed',        -- Every recipient was attempted
+    'aborted'           -- Stopped by an admin; unsent recipients cancelled
+);
+
+CREATE TYPE campaign_recipient_status AS ENUM (
+    'pending',          -- Waiting to be sent
+    'sending',          -- Claimed by the worker
+    'sent',             -- Accepted by the provider
+    'failed',           -- The provider rejected the message or could not be reached
+    'skipped',          -- Not sent (opted out since, no usable contact, no provider)
+    'cancelled'         -- Campaign aborted before it was sent
+);
+
+CREATE TABLE campaigns (
+    campaign_id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name                    VARCHAR(255) NOT NULL,
+    channel                 notification_channel NOT NULL,
+    -- Email subject (NULL for SMS)
+    subject                 VARCHAR(255),
+    body                    TEXT NOT NULL,
+    -- Segment: customers with a ticket closed in [closed_from, closed_to]
+    closed_from             DATE,
+    closed_to               DATE,
+    -- Segment: customers with a ticket of this item type
+    item_type               VARCHAR(100),
+    -- Segment: customers with (true) or without (false) an open ticket
+    has_open_tickets        BOOLEAN,
+    send_rate_per_minute    INTEGER NOT NULL CHECK (send_rate_per_minute > 0),
+    status                  campaign_status NOT NULL DEFAULT 'queued',
+    created_by              UUID NOT NULL REFERENCES employees(employee_id),
+    created_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    started_at              TIMESTAMPTZ,
+    finished_at             TIMESTAMPTZ,
+    aborted_by              UUID REFERENCES employees(employee_id)
+);
+
+CREATE INDEX idx_campaigns_active ON campaigns (created_at)
+    WHERE status IN ('queued', 'running');
+
+CREATE TABLE campaign_recipients (
+    recipient_id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    campaign_id             UUID NOT NULL REFERENCES campaigns(campaign_id) ON DELETE CASCADE,
+    customer_id             UUID NOT NULL REFERENCES customers(customer_id) ON DELETE CASCADE,
+    -- Phone or email when the campaign was created; the current one is used to send
+    recipient               VARCHAR(255) NOT NULL,
+    status                  campaign_recipient_status NOT NULL DEFAULT 'pending',
+    provider_message_id     VARCHAR(255),
+    error                   TEXT,
+    attempted_at            TIMESTAMPTZ,
+    sent_at                 TIMESTAMPTZ,
+    UNIQUE (campaign_id, customer_id)
+);
+
+CREATE INDEX idx_campaign_recipients_status ON campaign_recipients (campaign_id, status);
+CREATE INDEX idx_campaign_recipients_attempted ON campaign_recipients (campaign_id, attempted_at)
+    WHERE attempted_at IS NOT NULL;
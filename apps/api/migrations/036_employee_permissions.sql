@@ -0,0 +1,30 @@
+-- Employee permissions
+-- Each staff employee holds their own set of permissions, starting from the
+-- staff defaults. Admins hold every permission regardless of this column.
+
+CREATE TYPE employee_permission AS ENUM (
+    'create_ticket',        -- Take in new tickets
+    'modify_own_ticket',    -- Edit tickets they took in or are assigned to
+    'modify_any_ticket',    -- Edit and see every ticket, regardless of owner
+    'add_notes',            -- Add notes to tickets
+    'upload_photos',        -- Upload ticket photos
+    'delete_photos',        -- Delete ticket photos
+    'close_any_ticket',     -- Close tickets at pickup
+    'edit_prices',          -- Set quote and actual amounts on tickets
+    'manage_employees',     -- Add, edit, and deactivate employees and their permissions
+    'manage_settings',      -- Change store settings
+    'manage_locations',     -- Add and edit storage locations
+    'manage_customers',     -- Create and edit customer records
+    'delete_customers'      -- Delete and merge customer records
+);
+
+ALTER TABLE employees
+    ADD COLUMN permissions employee_permission[] NOT NULL DEFAULT ARRAY[
+        'create_ticket', 'modify_own_ticket', 'add_notes', 'upload_photos',
+        'edit_prices', 'manage_customers'
+    ]::employee_permission[];
+
+-- Admins hold every permission
+UPDATE employees
+SET permissions = enum_range(NULL::employee_permission)
+WHERE role = 'admin';
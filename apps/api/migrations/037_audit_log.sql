@@ -0,0 +1,54 @@
+-- Audit log of admin actions
+-- One row per admin or destructive action: who did it, how they
+-- authenticated, from where, and a summary of what changed. Secrets such as
+-- PINs and recovery codes are never recorded.
+
+CREATE TYPE audit_action AS ENUM (
+    'admin_setup',                  -- First-time admin PIN change
+    'admin_pin_changed',            -- Admin PIN changed
+    'admin_recovered',              -- Admin PIN reset with the recovery code
+    'recovery_code_regenerated',    -- New recovery code issued
+    'employee_created',
+    'employee_updated',             -- Name, PIN, role, or active flag changed
+    'employee_deleted',
+    'employee_permissions_changed',
+    'photo_deleted',
+    'settings_updated',             -- Store settings or a settings list replaced
+    'settings_rolled_back',
+    'config_imported',
+    'partner_created',
+    'partner_updated',
+    'partner_key_rotated',
+    'campaign_created',
+    'campaign_aborted'
+);
+
+CREATE TYPE audit_auth_method AS ENUM (
+    'admin_session',     -- X-Admin-Session
+    'admin_pin',         -- X-Admin-PIN, or the PIN in the setup request
+    'employee_session',  -- X-Employee-Session holding the needed permission
+    'recovery_code'      -- POST /admin/recover
+);
+
+CREATE TABLE audit_log (
+    audit_id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    action              audit_action NOT NULL,
+    auth_method         audit_auth_method NOT NULL,
+    -- Employee signed in when the action was taken, if any. Not a foreign
+    -- key: entries outlive the employees they name.
+    actor_employee_id   UUID,
+    -- Employee name at the time of the action
+    actor_name          VARCHAR(255),
+    client_ip           TEXT NOT NULL,
+    -- What the action was taken on, e.g. ('employee', employee_id)
+    target_type         VARCHAR(50),
+    target_id           UUID,
+    -- What changed (field names and non-secret values)
+    summary             JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_audit_log_created_at ON audit_log (created_at DESC);
+CREATE INDEX idx_audit_log_action ON audit_log (action, created_at DESC);
+CREATE INDEX idx_audit_log_actor ON audit_log (actor_employee_id, created_at DESC);
+CREATE INDEX idx_audit_log_target ON audit_log (target_id) WHERE target_id IS NOT NULL;
@@ -0,0 +1,29 @@
+-- Saved custom report definitions
+-- A definition groups tickets by chosen dimensions and computes chosen
+-- measures. Both come from fixed lists, so a report is always compiled from
+-- known SQL fragments.
+
+CREATE TYPE report_dimension AS ENUM (
+    'status',       -- Current ticket status
+    'employee',     -- Employee who took the ticket in
+    'item_type',    -- Ticket item type
+    'month'         -- Month the ticket was taken in
+);
+
+CREATE TYPE report_measure AS ENUM (
+    'count',                -- Tickets taken in
+    'revenue',              -- Sum of actual amounts on closed tickets
+    'avg_turnaround_days'   -- Average days from intake to close
+);
+
+CREATE TABLE report_definitions (
+    report_id   UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name        VARCHAR(255) NOT NULL,
+    dimensions  report_dimension[] NOT NULL,
+    measures    report_measure[] NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT report_definitions_has_measure CHECK (cardinality(measures) > 0)
+);
+
+CREATE UNIQUE INDEX idx_report_definitions_name ON report_definitions (LOWER(name));
@@ -0,0 +1,9 @@
+-- Retrying notifications queued while a provider was down
+-- A message whose provider's circuit breaker was open stays 'pending'. The
+-- retry worker claims it by stamping last_attempt_at, so two API instances
+-- never resend the same message.
+
+ALTER TABLE notification_log ADD COLUMN last_attempt_at TIMESTAMPTZ;
+
+CREATE INDEX idx_notification_log_pending ON notification_log (created_at)
+    WHERE status = 'pending';
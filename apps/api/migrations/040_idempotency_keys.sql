@@ -0,0 +1,18 @@
+-- Idempotency keys for retried mutations
+-- A client that sends an Idempotency-Key header on create ticket, close
+-- ticket, photo upload, or record payment gets the stored response back when
+-- it retries with the same key, instead of a duplicate. The response columns
+-- are NULL while the first request is still running.
+
+CREATE TABLE idempotency_keys (
+    idempotency_key VARCHAR(255) NOT NULL,
+    endpoint TEXT NOT NULL,                 -- Method and path, e.g. 'POST /api/v1/tickets'
+    request_hash CHAR(64) NOT NULL,         -- SHA-256 of the request body
+    response_status SMALLINT,
+    response_content_type TEXT,
+    response_body BYTEA,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (idempotency_key, endpoint)
+);
+
+CREATE INDEX idx_idempotency_keys_created_at ON idempotency_keys (created_at);
@@ -0,0 +1,28 @@
+-- Ticket line items
+-- A ticket can hold several pieces (a ring and a matching pendant) under one
+-- friendly code and receipt. Each item has its own description, condition,
+-- requested work, and quote. The ticket's own item columns keep mirroring
+-- item 1, so the workboard, search, and labels keep working unchanged.
+
+CREATE TABLE ticket_items (
+    item_id             UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    position            INTEGER NOT NULL CHECK (position > 0),
+    item_type           VARCHAR(100),
+    item_description    TEXT NOT NULL,
+    condition_notes     TEXT NOT NULL,
+    requested_work      TEXT NOT NULL,
+    quote_amount        DECIMAL(10,2),
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (ticket_id, position)
+);
+
+-- Existing tickets become single-item tickets
+INSERT INTO ticket_items (
+    ticket_id, position, item_type, item_description, condition_notes,
+    requested_work, quote_amount, created_at, updated_at
+)
+SELECT ticket_id, 1, item_type, item_description, condition_notes,
+       requested_work, quote_amount, created_at, updated_at
+FROM tickets;
@@ -0,0 +1,32 @@
+-- Quote approval
+-- A ticket's quote moves from draft to sent to approved or declined. Each
+-- step is logged with the channel it went through, who decided, and the
+-- employee who recorded it. Changing the quote amount puts it back to draft.
+-- Tickets quoted above quote_approval_threshold can't start work until the
+-- quote is approved (NULL disables the check).
+
+CREATE TYPE quote_status AS ENUM ('draft', 'sent', 'approved', 'declined');
+CREATE TYPE quote_channel AS ENUM ('in_person', 'phone', 'sms', 'email');
+
+ALTER TABLE tickets
+    ADD COLUMN quote_status quote_status NOT NULL DEFAULT 'draft';
+
+CREATE TABLE ticket_quote_events (
+    event_id        UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id       UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    status          quote_status NOT NULL CHECK (status <> 'draft'),
+    quote_amount    DECIMAL(10,2) NOT NULL,
+    channel         quote_channel NOT NULL,
+    decided_by      VARCHAR(255),
+    notes           TEXT,
+    recorded_by     UUID NOT NULL REFERENCES employees(employee_id),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ticket_quote_events_ticket ON ticket_quote_events (ticket_id, created_at);
+
+ALTER TABLE store_settings
+    ADD COLUMN quote_approval_threshold DECIMAL(10,2)
+        CHECK (quote_approval_threshold IS NULL OR quote_approval_threshold >= 0);
+
+COMMENT ON COLUMN store_settings.quote_approval_threshold IS 'Quotes above this amount need customer approval before work starts (NULL = disabled)';
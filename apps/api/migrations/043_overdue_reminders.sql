@@ -0,0 +1,44 @@
+-- Promise-date reminders
+-- Each morning a background job finds open tickets due today or past their
+-- promise date and writes one reminder per ticket per day. The day's
+-- reminders go out as a digest to overdue_digest_email and, when
+-- overdue_digest_sms is on, as a text to each assigned employee with a
+-- phone number. overdue_digests records each day's run so a digest is only
+-- sent once.
+
+CREATE TYPE reminder_kind AS ENUM ('due_today', 'overdue');
+
+CREATE TABLE ticket_reminders (
+    reminder_id     UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id       UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    reminder_date   DATE NOT NULL,
+    kind            reminder_kind NOT NULL,
+    promise_date    DATE NOT NULL,
+    worked_by       UUID REFERENCES employees(employee_id),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (ticket_id, reminder_date)
+);
+
+CREATE INDEX idx_ticket_reminders_date ON ticket_reminders (reminder_date);
+
+CREATE TABLE overdue_digests (
+    digest_date     DATE PRIMARY KEY,
+    due_today       INTEGER NOT NULL DEFAULT 0,
+    overdue         INTEGER NOT NULL DEFAULT 0,
+    email_sent      BOOLEAN NOT NULL DEFAULT FALSE,
+    sms_sent        INTEGER NOT NULL DEFAULT 0,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE employees
+    ADD COLUMN phone VARCHAR(50);
+
+ALTER TABLE store_settings
+    ADD COLUMN overdue_digest_email VARCHAR(255),
+    ADD COLUMN overdue_digest_sms BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN overdue_digest_hour INTEGER NOT NULL DEFAULT 8
+        CHECK (overdue_digest_hour BETWEEN 0 AND 23);
+
+COMMENT ON COLUMN store_settings.overdue_digest_email IS 'Where the daily overdue digest is emailed (NULL = no email)';
+COMMENT ON COLUMN store_settings.overdue_digest_sms IS 'Text assigned employees their due and overdue tickets each morning';
+COMMENT ON COLUMN store_settings.overdue_digest_hour IS 'Hour of the day (UTC) the reminder job runs';
@@ -0,0 +1,49 @@
+-- Webhooks
+-- Admins register URLs that receive ticket events. Each event is written to
+-- the webhook_deliveries outbox, one row per subscribed webhook, and a
+-- background worker POSTs it with an HMAC-SHA256 signature made with the
+-- webhook's secret. Failed attempts are retried with exponential backoff
+-- until they succeed or run out of attempts.
+
+CREATE TYPE webhook_event AS ENUM (
+    'ticket.created',
+    'ticket.status_changed',
+    'ticket.closed',
+    'photo.uploaded'
+);
+
+CREATE TYPE webhook_delivery_status AS ENUM ('pending', 'delivered', 'failed');
+
+CREATE TABLE webhooks (
+    webhook_id      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    url             VARCHAR(2048) NOT NULL,
+    description     VARCHAR(255),
+    events          webhook_event[] NOT NULL CHECK (cardinality(events) > 0),
+    secret          VARCHAR(255) NOT NULL,
+    is_active       BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE webhook_deliveries (
+    delivery_id     UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    webhook_id      UUID NOT NULL REFERENCES webhooks(webhook_id) ON DELETE CASCADE,
+    event           webhook_event NOT NULL,
+    payload         JSONB NOT NULL,
+    status          webhook_delivery_status NOT NULL DEFAULT 'pending',
+    attempts        INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    response_status INTEGER,
+    last_error      TEXT,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at    TIMESTAMPTZ
+);
+
+CREATE INDEX idx_webhook_deliveries_pending ON webhook_deliveries (next_attempt_at)
+    WHERE status = 'pending';
+CREATE INDEX idx_webhook_deliveries_webhook ON webhook_deliveries (webhook_id, created_at DESC);
+
+ALTER TYPE audit_action ADD VALUE 'webhook_created';
+ALTER TYPE audit_action ADD VALUE 'webhook_updated';
+ALTER TYPE audit_action ADD VALUE 'webhook_deleted';
+ALTER TYPE audit_action ADD VALUE 'webhook_secret_rotated';
@@ -0,0 +1,8 @@
+-- Webhook delivery capture
+-- Keep what the receiver answered on the last attempt, and when that attempt
+-- was made, so admins can debug a failing integration and redeliver events.
+
+ALTER TABLE webhook_deliveries ADD COLUMN response_body TEXT;
+ALTER TABLE webhook_deliveries ADD COLUMN last_attempt_at TIMESTAMPTZ;
+
+ALTER TYPE audit_action ADD VALUE 'webhook_redelivered';
@@ -0,0 +1,14 @@
+-- Receipt and label templates
+-- Stores can brand receipts with a logo, add their own disclaimer under the
+-- footer, leave prices off the receipt, and print labels on stock other than
+-- the default 2" x 1" tags. The logo itself lives in object storage under
+-- branding/; only its key is kept here.
+
+ALTER TABLE store_settings
+    ADD COLUMN receipt_logo_key VARCHAR(500),
+    ADD COLUMN receipt_footer_text TEXT,
+    ADD COLUMN receipt_show_prices BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN label_width_mm NUMERIC(5,1) NOT NULL DEFAULT 50.8
+        CHECK (label_width_mm BETWEEN 25 AND 150),
+    ADD COLUMN label_height_mm NUMERIC(5,1) NOT NULL DEFAULT 25.4
+        CHECK (label_height_mm BETWEEN 12 AND 100);
@@ -0,0 +1,10 @@
+-- Default receipt format
+-- Stores printing on 80mm thermal printers get ESC/POS bytes or plain text
+-- instead of a letter-size PDF. The format picks what GET
+-- /tickets/:id/receipt serves and which receipt URL a new ticket returns;
+-- every format stays available at its own extension.
+
+CREATE TYPE receipt_format AS ENUM ('pdf', 'escpos', 'text');
+
+ALTER TABLE store_settings
+    ADD COLUMN receipt_format receipt_format NOT NULL DEFAULT 'pdf';
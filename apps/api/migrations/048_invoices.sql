@@ -0,0 +1,19 @@
+-- Invoices
+-- Closed tickets get a numbered invoice for customers who need one rather
+-- than the intake receipt. Invoice numbers come from their own sequence in
+-- store settings, separate from ticket numbers. A ticket keeps its invoice
+-- number, and the tax rate it was issued at, if it is reopened and closed
+-- again.
+
+ALTER TABLE store_settings
+    ADD COLUMN tax_rate NUMERIC(6,3) NOT NULL DEFAULT 0
+        CHECK (tax_rate BETWEEN 0 AND 100),
+    ADD COLUMN next_invoice_number INTEGER NOT NULL DEFAULT 1;
+
+CREATE TABLE ticket_invoices (
+    ticket_id           UUID PRIMARY KEY REFERENCES tickets(ticket_id) ON DELETE RESTRICT,
+    invoice_number      INTEGER NOT NULL UNIQUE,
+    -- Percentage included in the ticket's amount when the invoice was issued
+    tax_rate            NUMERIC(6,3) NOT NULL,
+    issued_at           TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,24 @@
+-- Tax-exclusive pricing and the tax snapshot taken at close
+-- Stores that quote before tax add it on top of the ticket amount instead
+-- of breaking it out. Closing a ticket records the tax on the amount
+-- charged alongside its invoice, so a later rate change doesn't rewrite
+-- what the customer was billed.
+
+ALTER TABLE store_settings
+    ADD COLUMN tax_inclusive BOOLEAN NOT NULL DEFAULT TRUE;
+
+ALTER TABLE ticket_invoices
+    ADD COLUMN tax_inclusive BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN tax_amount NUMERIC(10,2),
+    ADD COLUMN total_amount NUMERIC(10,2);
+
+-- Invoices so far broke tax out of the amount charged
+UPDATE ticket_invoices i
+SET tax_amount = ROUND(COALESCE(t.actual_amount, 0) * i.tax_rate / (100 + i.tax_rate), 2),
+    total_amount = COALESCE(t.actual_amount, 0)
+FROM tickets t
+WHERE t.ticket_id = i.ticket_id;
+
+ALTER TABLE ticket_invoices
+    ALTER COLUMN tax_amount SET NOT NULL,
+    ALTER COLUMN total_amount SET NOT NULL;
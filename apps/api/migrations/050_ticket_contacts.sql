@@ -0,0 +1,21 @@
+-- Customer contact log
+-- Staff record each call, text, email, or counter conversation with a
+-- customer about a ticket: which way it went, how it turned out, and who
+-- made it. Contact notes are matched by ticket search.
+
+CREATE TYPE contact_channel AS ENUM ('phone', 'sms', 'email', 'in_person');
+CREATE TYPE contact_direction AS ENUM ('outbound', 'inbound');
+CREATE TYPE contact_outcome AS ENUM ('reached', 'left_message', 'no_answer', 'wrong_number');
+
+CREATE TABLE ticket_contacts (
+    contact_id      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id       UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    channel         contact_channel NOT NULL,
+    direction       contact_direction NOT NULL,
+    outcome         contact_outcome NOT NULL,
+    notes           TEXT,
+    contacted_by    UUID NOT NULL REFERENCES employees(employee_id),
+    contacted_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ticket_contacts_ticket ON ticket_contacts (ticket_id, contacted_at);
@@ -0,0 +1,86 @@
+-- Ticket full-text search
+-- Ticket search matched an ILIKE pattern against nine columns across
+-- tickets, customers, and notes, which scans every row and can't rank.
+-- Each ticket now keeps a search_vector, maintained by triggers, built from
+-- its code and customer (weight A), item (B), work (C), and notes and
+-- contact notes (D). Trigram indexes keep partial matches on ticket codes
+-- and phone numbers fast.
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE tickets
+    ADD COLUMN search_vector TSVECTOR NOT NULL DEFAULT ''::tsvector;
+
+-- Codes, names, and emails use the simple configuration so they aren't
+-- stemmed or dropped as stop words; descriptive text uses english.
+CREATE FUNCTION ticket_search_vector(t tickets)
+RETURNS TSVECTOR AS $$
+    SELECT
+        setweight(to_tsvector('simple', t.friendly_code), 'A') ||
+        setweight(to_tsvector('simple', c.name || ' ' || COALESCE(c.email, '')), 'A') ||
+        setweight(to_tsvector('english', COALESCE(t.item_type, '') || ' ' || t.item_description), 'B') ||
+        setweight(to_tsvector('english', t.requested_work || ' ' || t.condition_notes), 'C') ||
+        setweight(to_tsvector('english', COALESCE(
+            (SELECT string_agg(n.content, ' ') FROM ticket_notes n WHERE n.ticket_id = t.ticket_id),
+            ''
+        )), 'D') ||
+        setweight(to_tsvector('english', COALESCE(
+            (SELECT string_agg(ct.notes, ' ') FROM ticket_contacts ct WHERE ct.ticket_id = t.ticket_id),
+            ''
+        )), 'D')
+    FROM customers c
+    WHERE c.customer_id = t.customer_id
+$$ LANGUAGE sql STABLE;
+
+CREATE FUNCTION set_ticket_search_vector()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.search_vector := COALESCE(ticket_search_vector(NEW), ''::tsvector);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tickets_search_vector
+    BEFORE INSERT OR UPDATE OF friendly_code, customer_id, item_type, item_description,
+        condition_notes, requested_work
+    ON tickets
+    FOR EACH ROW EXECUTE FUNCTION set_ticket_search_vector();
+
+-- Notes and contacts are searched through their ticket
+CREATE FUNCTION refresh_ticket_search_vector()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE tickets t
+    SET search_vector = COALESCE(ticket_search_vector(t), ''::tsvector)
+    WHERE t.ticket_id = NEW.ticket_id;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER ticket_notes_search_vector
+    AFTER INSERT OR UPDATE OF content ON ticket_notes
+    FOR EACH ROW EXECUTE FUNCTION refresh_ticket_search_vector();
+
+CREATE TRIGGER ticket_contacts_search_vector
+    AFTER INSERT OR UPDATE OF notes ON ticket_contacts
+    FOR EACH ROW EXECUTE FUNCTION refresh_ticket_search_vector();
+
+CREATE FUNCTION refresh_customer_ticket_search_vectors()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE tickets t
+    SET search_vector = COALESCE(ticket_search_vector(t), ''::tsvector)
+    WHERE t.customer_id = NEW.customer_id;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER customers_search_vector
+    AFTER UPDATE OF name, email ON customers
+    FOR EACH ROW EXECUTE FUNCTION refresh_customer_ticket_search_vectors();
+
+UPDATE tickets t SET search_vector = COALESCE(ticket_search_vector(t), ''::tsvector);
+
+CREATE INDEX idx_tickets_search_vector ON tickets USING GIN (search_vector);
+CREATE INDEX idx_tickets_friendly_code_trgm ON tickets USING GIN (friendly_code gin_trgm_ops);
+CREATE INDEX idx_customers_phone_trgm ON customers USING GIN (phone gin_trgm_ops);
@@ -0,0 +1,7 @@
+-- Storage location capacity
+-- A location may have a capacity: the number of open tickets it holds
+-- before it counts as full. Tickets can still be stored at a full location;
+-- staff get a warning instead. Unset means no limit.
+
+ALTER TABLE storage_locations
+    ADD COLUMN capacity INTEGER CHECK (capacity > 0);
@@ -0,0 +1,25 @@
+-- Ticket movements
+-- Tracks an item leaving its storage location for an employee's bench and
+-- coming back, for every ticket rather than only the high-value ones in the
+-- chain of custody. Each end of a movement is either a storage location or
+-- an employee; a ticket's latest movement says where the item is now.
+
+CREATE TABLE ticket_movements (
+    movement_id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    from_location_id    UUID REFERENCES storage_locations(location_id),
+    from_employee_id    UUID REFERENCES employees(employee_id),
+    to_location_id      UUID REFERENCES storage_locations(location_id),
+    -- Employee holding the item at their bench
+    to_employee_id      UUID REFERENCES employees(employee_id),
+    moved_by            UUID NOT NULL REFERENCES employees(employee_id),
+    notes               TEXT,
+    moved_at            TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT ticket_movements_one_origin
+        CHECK ((from_location_id IS NULL) <> (from_employee_id IS NULL)),
+    CONSTRAINT ticket_movements_one_destination
+        CHECK ((to_location_id IS NULL) <> (to_employee_id IS NULL))
+);
+
+CREATE INDEX idx_ticket_movements_ticket ON ticket_movements (ticket_id, moved_at);
+CREATE INDEX idx_ticket_movements_moved_at ON ticket_movements (moved_at);
@@ -0,0 +1,21 @@
+-- Bench time entries
+-- Employees clock in on a ticket when they start working on it at the bench
+-- and clock out when they stop, so owners can see the labor behind each
+-- repair. An entry is running until it is stopped.
+
+CREATE TABLE time_entries (
+    entry_id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    employee_id         UUID NOT NULL REFERENCES employees(employee_id),
+    started_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    stopped_at          TIMESTAMPTZ,
+    CONSTRAINT time_entries_stopped_after_start
+        CHECK (stopped_at IS NULL OR stopped_at >= started_at)
+);
+
+-- An employee is clocked in on one ticket at a time
+CREATE UNIQUE INDEX idx_time_entries_one_running
+    ON time_entries (employee_id) WHERE stopped_at IS NULL;
+
+CREATE INDEX idx_time_entries_ticket ON time_entries (ticket_id, started_at);
+CREATE INDEX idx_time_entries_started_at ON time_entries (started_at);
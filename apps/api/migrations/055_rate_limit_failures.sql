@@ -0,0 +1,15 @@
+-- Failed authentication attempts per client IP
+-- PIN verification and admin recovery back off exponentially after repeated
+-- failures. With RATE_LIMIT_BACKEND=postgres the counters live here, so they
+-- survive restarts and are shared by every API replica. A success deletes the
+-- row; rows idle for a day are purged.
+
+CREATE TABLE rate_limit_failures (
+    scope TEXT NOT NULL,                    -- Limiter, e.g. 'pin' or 'recovery'
+    client_ip TEXT NOT NULL,
+    failure_count INTEGER NOT NULL,
+    last_failure_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (scope, client_ip)
+);
+
+CREATE INDEX idx_rate_limit_failures_last_failure_at ON rate_limit_failures (last_failure_at);
@@ -0,0 +1,18 @@
+-- Per-employee PIN lockout
+-- When a login names the employee, wrong PINs are counted against them. Too
+-- many inside the window locks the employee out until an admin unlocks them.
+-- The lockout and the unlock are recorded in the audit log.
+
+ALTER TABLE employees
+    ADD COLUMN failed_pin_attempts INTEGER NOT NULL DEFAULT 0,
+    ADD COLUMN failed_pin_window_started_at TIMESTAMPTZ,
+    ADD COLUMN pin_locked_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN employees.failed_pin_attempts IS 'Wrong PINs since failed_pin_window_started_at';
+COMMENT ON COLUMN employees.pin_locked_at IS 'When the employee was locked out (NULL = not locked)';
+
+ALTER TYPE audit_action ADD VALUE 'employee_locked_out';
+ALTER TYPE audit_action ADD VALUE 'employee_unlocked';
+
+-- Failed PIN logins aren't authenticated; they're attributed to the PIN entry
+ALTER TYPE audit_auth_method ADD VALUE 'employee_pin';
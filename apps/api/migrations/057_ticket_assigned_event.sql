@@ -0,0 +1,5 @@
+-- Ticket assignment webhook event
+-- Raised when POST /tickets/:ticket_id/assign changes who is working on a
+-- ticket.
+
+ALTER TYPE webhook_event ADD VALUE 'ticket.assigned';
@@ -0,0 +1,12 @@
+-- Item type catalog
+-- Item types get an optional base price, shown at intake as the starting
+-- quote. Stores whose item types drift into typos can require tickets to
+-- use a configured type; "Other" is always accepted for one-off items.
+
+ALTER TABLE item_types
+    ADD COLUMN base_price NUMERIC(10, 2) CHECK (base_price IS NULL OR base_price >= 0);
+
+ALTER TABLE store_settings
+    ADD COLUMN restrict_item_types BOOLEAN NOT NULL DEFAULT FALSE;
+
+COMMENT ON COLUMN store_settings.restrict_item_types IS 'Tickets must use a configured item type or "Other"';
@@ -0,0 +1,28 @@
+-- Service catalog
+-- Common bench work (ring sizing, prong re-tip, chain solder) with a default
+-- price and turnaround. A new ticket can name services to pre-fill its
+-- requested work, quote, and promise date; the services picked and their
+-- prices at intake are kept per ticket for the service report. Services are
+-- deactivated rather than deleted so ticket history keeps its names.
+
+CREATE TABLE services (
+    service_id      UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name            VARCHAR(100) NOT NULL,
+    default_price   DECIMAL(10,2) CHECK (default_price IS NULL OR default_price >= 0),
+    turnaround_days INTEGER CHECK (turnaround_days IS NULL OR turnaround_days >= 0),
+    is_active       BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_services_name ON services (LOWER(name));
+
+CREATE TABLE ticket_services (
+    ticket_id   UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    service_id  UUID NOT NULL REFERENCES services(service_id),
+    -- The service's default price when the ticket was taken in
+    price       DECIMAL(10,2),
+    PRIMARY KEY (ticket_id, service_id)
+);
+
+CREATE INDEX idx_ticket_services_service_id ON ticket_services (service_id);
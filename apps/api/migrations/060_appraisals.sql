@@ -0,0 +1,21 @@
+-- Appraisals
+-- Stores that appraise items record the appraisal against the ticket: the
+-- appraised value, metal, stones, grading report or certificate numbers, and
+-- the employee who appraised it. A ticket has at most one appraisal, which
+-- is revised in place and printed as the appraisal PDF.
+
+CREATE TABLE appraisals (
+    appraisal_id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id             UUID NOT NULL UNIQUE REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    appraised_value       DECIMAL(12,2) NOT NULL CHECK (appraised_value >= 0),
+    metal                 VARCHAR(100),
+    stones                TEXT,
+    certification_numbers TEXT[] NOT NULL DEFAULT '{}',
+    notes                 TEXT,
+    appraiser_id          UUID NOT NULL REFERENCES employees(employee_id),
+    appraised_on          DATE NOT NULL DEFAULT CURRENT_DATE,
+    created_at            TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at            TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_appraisals_appraiser_id ON appraisals (appraiser_id);
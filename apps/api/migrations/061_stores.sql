@@ -0,0 +1,102 @@
+-- Stores
+-- A small chain runs one deployment for all its branches. Each store has its
+-- own settings row (name, contact details, ticket prefix and numbering, tax,
+-- receipts, ...), employees, storage locations, and tickets. The primary
+-- store is the one every existing row belongs to; its settings row also
+-- holds the deployment-wide admin PIN and setup state. Customers and the
+-- catalogs (item types, services, metal prices) are shared by every store.
+--
+-- A ticket belongs to the store holding it: the store of its storage
+-- location, so moving it to another branch's location moves the ticket.
+
+CREATE TABLE stores (
+    store_id    UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    is_primary  BOOLEAN NOT NULL DEFAULT FALSE,
+    is_active   BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (is_active OR NOT is_primary)
+);
+
+CREATE UNIQUE INDEX idx_stores_primary ON stores ((TRUE)) WHERE is_primary;
+
+INSERT INTO stores (is_primary) VALUES (TRUE);
+
+CREATE FUNCTION primary_store_id()
+RETURNS UUID AS $$
+    SELECT store_id FROM stores WHERE is_primary
+$$ LANGUAGE sql STABLE;
+
+-- Settings: one row per store
+ALTER TABLE store_settings ADD COLUMN store_id UUID REFERENCES stores(store_id);
+UPDATE store_settings SET store_id = primary_store_id();
+ALTER TABLE store_settings ALTER COLUMN store_id SET NOT NULL;
+DROP INDEX idx_store_settings_singleton;
+CREATE UNIQUE INDEX idx_store_settings_store_id ON store_settings (store_id);
+-- Friendly codes stay unique across the chain
+CREATE UNIQUE INDEX idx_store_settings_ticket_prefix ON store_settings (UPPER(ticket_prefix));
+
+-- Rows created without a store belong to the primary store
+ALTER TABLE employees
+    ADD COLUMN store_id UUID NOT NULL DEFAULT primary_store_id() REFERENCES stores(store_id);
+CREATE INDEX idx_employees_store_id ON employees (store_id);
+
+ALTER TABLE storage_locations
+    ADD COLUMN store_id UUID NOT NULL DEFAULT primary_store_id() REFERENCES stores(store_id);
+CREATE INDEX idx_storage_locations_store_id ON storage_locations (store_id);
+-- Location names are unique within a store
+ALTER TABLE storage_locations DROP CONSTRAINT storage_locations_name_key;
+CREATE UNIQUE INDEX idx_storage_locations_store_name ON storage_locations (store_id, name);
+
+ALTER TABLE tickets
+    ADD COLUMN store_id UUID NOT NULL DEFAULT primary_store_id() REFERENCES stores(store_id);
+CREATE INDEX idx_tickets_store_id ON tickets (store_id);
+
+-- A ticket follows its storage location between stores
+CREATE FUNCTION set_ticket_store()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.store_id := COALESCE(
+        (SELECT store_id FROM storage_locations WHERE location_id = NEW.storage_location_id),
+        NEW.store_id
+    );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tickets_store
+    BEFORE INSERT OR UPDATE OF storage_location_id
+    ON tickets
+    FOR EACH ROW EXECUTE FUNCTION set_ticket_store();
+
+-- Settings history is kept per store
+ALTER TABLE settings_changes
+    ADD COLUMN store_id UUID NOT NULL DEFAULT primary_store_id() REFERENCES stores(store_id);
+CREATE INDEX idx_settings_changes_store_id ON settings_changes (store_id, changed_at DESC);
+
+-- The store an employee signed in at
+ALTER TABLE employee_sessions ADD COLUMN store_id UUID REFERENCES stores(store_id);
+
+-- Ticket numbers come from the store's own sequence and prefix
+CREATE OR REPLACE FUNCTION generate_friendly_code(p_store_id UUID)
+RETURNS VARCHAR(20) AS $$
+DECLARE
+    prefix VARCHAR(10);
+    next_num INTEGER;
+    code VARCHAR(20);
+BEGIN
+    UPDATE store_settings
+    SET next_ticket_number = next_ticket_number + 1
+    WHERE store_id = p_store_id
+    RETURNING ticket_prefix, next_ticket_number - 1
+    INTO prefix, next_num;
+
+    code := prefix || '-' || LPAD(next_num::TEXT, 4, '0');
+    RETURN code;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION generate_friendly_code()
+RETURNS VARCHAR(20) AS $$
+    SELECT generate_friendly_code(primary_store_id())
+$$ LANGUAGE sql;
@@ -0,0 +1,28 @@
+-- Ticket numbering format
+-- Each store chooses how its ticket numbers look: its prefix, how many digits
+-- the number is padded to, and whether numbering starts over each year with
+-- the year in the code (JR-2025-0042). Codes are now generated by the API, so
+-- the fixed-format generate_friendly_code() functions are dropped.
+
+ALTER TABLE store_settings
+    ADD COLUMN ticket_number_padding INTEGER NOT NULL DEFAULT 4
+        CHECK (ticket_number_padding BETWEEN 1 AND 8),
+    ADD COLUMN ticket_number_yearly_reset BOOLEAN NOT NULL DEFAULT FALSE,
+    -- Year the store's sequence last numbered a ticket in
+    ADD COLUMN ticket_number_year INTEGER;
+
+-- Room for a 10-character prefix, the year, and a 10-digit number. The
+-- search trigger names the column, so it's recreated around the change.
+DROP TRIGGER tickets_search_vector ON tickets;
+ALTER TABLE tickets ALTER COLUMN friendly_code TYPE VARCHAR(32);
+CREATE TRIGGER tickets_search_vector
+    BEFORE INSERT OR UPDATE OF friendly_code, customer_id, item_type, item_description,
+        condition_notes, requested_work
+    ON tickets
+    FOR EACH ROW EXECUTE FUNCTION set_ticket_search_vector();
+
+-- New codes are checked against existing ones case-insensitively
+CREATE INDEX idx_tickets_friendly_code_upper ON tickets (UPPER(friendly_code));
+
+DROP FUNCTION generate_friendly_code();
+DROP FUNCTION generate_friendly_code(UUID);
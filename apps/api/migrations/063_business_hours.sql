@@ -0,0 +1,12 @@
+-- Business hours and holidays
+-- Each store records the days it's open and the dates it's closed, so
+-- promise dates are counted in open days and never land on a closed one.
+-- Hours are a list of {weekday, opens_at, closes_at} with ISO weekdays
+-- (1 = Monday); an empty list means every day is open.
+
+ALTER TABLE store_settings
+    ADD COLUMN business_hours JSONB NOT NULL DEFAULT '[]',
+    ADD COLUMN holidays DATE[] NOT NULL DEFAULT '{}',
+    -- Open days a rush job takes, when sooner than the item type's turnaround
+    ADD COLUMN rush_turnaround_days INTEGER
+        CHECK (rush_turnaround_days BETWEEN 0 AND 365);
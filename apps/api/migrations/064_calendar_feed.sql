@@ -0,0 +1,14 @@
+-- Promise date calendar feed
+-- A store can publish its open tickets' promise dates as an iCalendar feed
+-- for calendar apps to subscribe to. The feed URL carries a random token;
+-- only its SHA-256 hash is kept, and clearing it revokes the feed.
+
+ALTER TABLE store_settings
+    ADD COLUMN calendar_feed_token_hash VARCHAR(64);
+
+CREATE UNIQUE INDEX idx_store_settings_calendar_feed_token
+    ON store_settings (calendar_feed_token_hash)
+    WHERE calendar_feed_token_hash IS NOT NULL;
+
+ALTER TYPE audit_action ADD VALUE 'calendar_feed_token_rotated';
+ALTER TYPE audit_action ADD VALUE 'calendar_feed_token_revoked';
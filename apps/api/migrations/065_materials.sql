@@ -0,0 +1,34 @@
+-- Materials inventory
+-- Stock the bench uses up (gold solder, sizing stock, findings) with the
+-- quantity on hand and unit cost. Recording a material against a ticket
+-- takes it out of stock and keeps its cost at the time, which the revenue
+-- report subtracts as cost of goods. Materials are deactivated rather than
+-- deleted so ticket history keeps their names.
+
+CREATE TABLE materials (
+    material_id         UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name                VARCHAR(100) NOT NULL,
+    -- Unit quantities are counted in (g, dwt, in, each, ...)
+    unit                VARCHAR(20) NOT NULL,
+    quantity_on_hand    NUMERIC(12, 3) NOT NULL DEFAULT 0 CHECK (quantity_on_hand >= 0),
+    unit_cost           NUMERIC(10, 2) NOT NULL CHECK (unit_cost >= 0),
+    is_active           BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_materials_name ON materials (LOWER(name));
+
+CREATE TABLE ticket_materials (
+    ticket_material_id  UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    ticket_id           UUID NOT NULL REFERENCES tickets(ticket_id) ON DELETE CASCADE,
+    material_id         UUID NOT NULL REFERENCES materials(material_id),
+    quantity            NUMERIC(12, 3) NOT NULL CHECK (quantity > 0),
+    -- The material's unit cost when it was used
+    unit_cost           NUMERIC(10, 2) NOT NULL,
+    recorded_by         UUID NOT NULL REFERENCES employees(employee_id),
+    recorded_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ticket_materials_ticket ON ticket_materials (ticket_id, recorded_at);
+CREATE INDEX idx_ticket_materials_material ON ticket_materials (material_id);
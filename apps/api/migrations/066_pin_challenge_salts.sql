@@ -0,0 +1,16 @@
+-- Per-employee salts for PIN challenge keys
+-- Keys were derived with one salt shared by every employee, so a single
+-- pass over the possible PINs would have recovered all of them. Each key now
+-- gets its own random salt, sent to the client with the challenge, and
+-- challenges are issued for a named employee.
+
+ALTER TABLE employees ADD COLUMN pin_challenge_salt TEXT;
+
+-- Drop keys derived with the shared salt. Employees get a new key the next
+-- time their PIN is set; until then clients use the plain PIN flow.
+UPDATE employees SET pin_challenge_key = NULL;
+
+-- The employee whose salt the challenge was issued with
+DELETE FROM pin_challenges;
+ALTER TABLE pin_challenges
+    ADD COLUMN employee_id UUID NOT NULL REFERENCES employees(employee_id) ON DELETE CASCADE;
@@ -0,0 +1,27 @@
+-- Ticket search vectors on note and contact deletes
+-- The search vector was refreshed when notes and contact notes were added
+-- or edited, but not when they were deleted, so tickets kept matching on
+-- text that was gone.
+
+CREATE OR REPLACE FUNCTION refresh_ticket_search_vector()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE tickets t
+    SET search_vector = COALESCE(ticket_search_vector(t), ''::tsvector)
+    WHERE t.ticket_id = CASE TG_OP WHEN 'DELETE' THEN OLD.ticket_id ELSE NEW.ticket_id END;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER ticket_notes_search_vector ON ticket_notes;
+CREATE TRIGGER ticket_notes_search_vector
+    AFTER INSERT OR UPDATE OF content OR DELETE ON ticket_notes
+    FOR EACH ROW EXECUTE FUNCTION refresh_ticket_search_vector();
+
+DROP TRIGGER ticket_contacts_search_vector ON ticket_contacts;
+CREATE TRIGGER ticket_contacts_search_vector
+    AFTER INSERT OR UPDATE OF notes OR DELETE ON ticket_contacts
+    FOR EACH ROW EXECUTE FUNCTION refresh_ticket_search_vector();
+
+-- Drop text from notes and contacts deleted before now
+UPDATE tickets t SET search_vector = COALESCE(ticket_search_vector(t), ''::tsvector);
@@ -0,0 +1,7 @@
+-- Store time zone
+-- Daily reports ran from UTC midnight to midnight, which splits a US
+-- store's business day and drops evening payments from its closeout. Each
+-- store now has an IANA time zone that its days are counted in.
+
+ALTER TABLE store_settings
+    ADD COLUMN timezone VARCHAR(64) NOT NULL DEFAULT 'UTC';
@@ -0,0 +1,13 @@
+-- Scope idempotency keys to the caller
+-- Keys were unique per method and path only, so another caller sending the
+-- same key got the first caller's stored response. Each key is now also
+-- scoped to a hash of the request's credentials. Stored keys can't be
+-- attributed to a caller, so they're dropped; they expire within a day anyway.
+
+DELETE FROM idempotency_keys;
+
+ALTER TABLE idempotency_keys
+    ADD COLUMN principal CHAR(64) NOT NULL;   -- SHA-256 of the credential headers
+
+ALTER TABLE idempotency_keys DROP CONSTRAINT idempotency_keys_pkey;
+ALTER TABLE idempotency_keys ADD PRIMARY KEY (principal, idempotency_key, endpoint);
@@ -0,0 +1,19 @@
+-- Materials stock per store
+-- The materials catalog (name, unit, cost) is shared by every store like the
+-- other catalogs, but each branch has its own shelf. Stock moves from the
+-- materials row to one row per material and store; a store without a row
+-- has none on hand. Existing stock belongs to the primary store.
+
+CREATE TABLE material_stock (
+    material_id         UUID NOT NULL REFERENCES materials(material_id) ON DELETE CASCADE,
+    store_id            UUID NOT NULL REFERENCES stores(store_id),
+    quantity_on_hand    NUMERIC(12, 3) NOT NULL DEFAULT 0 CHECK (quantity_on_hand >= 0),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (material_id, store_id)
+);
+
+INSERT INTO material_stock (material_id, store_id, quantity_on_hand, updated_at)
+SELECT material_id, primary_store_id(), quantity_on_hand, updated_at
+FROM materials;
+
+ALTER TABLE materials DROP COLUMN quantity_on_hand;
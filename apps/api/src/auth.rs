@@ -1,11 +1,20 @@
 //! Password hashing utilities for employee PINs.
 //!
-//! This module provides argon2-based password hashing for secure PIN storage.
+//! This module provides argon2-based password hashing for secure PIN storage,
+//! and the PIN-derived keys used by challenge-response PIN entry.
 
 use argon2::{
-    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    password_hash::{
+        rand_core::{OsRng, RngCore},
+        PasswordHash, PasswordHasher, PasswordVerifier, SaltString,
+    },
     Argon2,
 };
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// PBKDF2-HMAC-SHA256 iterations for PIN challenge keys.
+pub const PIN_CHALLENGE_ITERATIONS: u32 = 100_000;
 
 /// Error type for password hashing operations.
 #[derive(Debug)]
@@ -148,6 +157,50 @@ pub fn verify_pin(pin: &str, hash: &str) -> Result<bool, HashError> {
     }
 }
 
+/// Generate a random salt for an employee's PIN challenge key (128-bit, hex).
+pub fn generate_pin_challenge_salt() -> String {
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    hex::encode(salt)
+}
+
+/// Derive the challenge key for a PIN.
+///
+/// PBKDF2-HMAC-SHA256 over the PIN with the employee's salt and
+/// [`PIN_CHALLENGE_ITERATIONS`], hex encoded. Clients get the salt with the
+/// challenge and derive the same key to answer it without sending the PIN.
+///
+/// The key answers any challenge for the employee, so it's as sensitive as
+/// the PIN: with so few possible PINs, anyone holding the key and salt (or a
+/// captured nonce and response) can find the PIN by trying them all. The
+/// per-employee salt means that search has to be repeated for each employee.
+pub fn derive_pin_challenge_key(pin: &str, salt: &str) -> String {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(
+        pin.as_bytes(),
+        salt.as_bytes(),
+        PIN_CHALLENGE_ITERATIONS,
+        &mut key,
+    );
+    hex::encode(key)
+}
+
+/// Check a challenge response: hex HMAC-SHA256 of the nonce, keyed with
+/// the PIN challenge key.
+///
+/// The comparison runs in constant time. Malformed keys or responses never
+/// match.
+pub fn verify_pin_challenge(key: &str, nonce: &str, response: &str) -> bool {
+    let (Ok(key), Ok(response)) = (hex::decode(key), hex::decode(response.trim())) else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(&key) else {
+        return false;
+    };
+    mac.update(nonce.as_bytes());
+    mac.verify_slice(&response).is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,4 +341,44 @@ mod tests {
         let result = validate_pin_complexity("abcd", 4);
         assert!(result.valid);
     }
+
+    /// Answer a challenge the way a client does.
+    fn answer_challenge(pin: &str, nonce: &str) -> String {
+        let key = hex::decode(derive_pin_challenge_key(pin, "salt")).unwrap();
+        let mut mac = Hmac::<Sha256>::new_from_slice(&key).unwrap();
+        mac.update(nonce.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    #[test]
+    fn test_derive_pin_challenge_key_is_stable() {
+        let key = derive_pin_challenge_key("4821", "salt");
+        assert_eq!(key.len(), 64);
+        assert_eq!(key, derive_pin_challenge_key("4821", "salt"));
+        assert_ne!(key, derive_pin_challenge_key("4822", "salt"));
+        assert_ne!(key, derive_pin_challenge_key("4821", "other-salt"));
+    }
+
+    #[test]
+    fn test_generate_pin_challenge_salt_is_random() {
+        let salt = generate_pin_challenge_salt();
+        assert_eq!(salt.len(), 32);
+        assert_ne!(salt, generate_pin_challenge_salt());
+    }
+
+    #[test]
+    fn test_verify_pin_challenge() {
+        let key = derive_pin_challenge_key("4821", "salt");
+        let response = answer_challenge("4821", "nonce-1");
+
+        assert!(verify_pin_challenge(&key, "nonce-1", &response));
+        assert!(!verify_pin_challenge(&key, "nonce-2", &response));
+        assert!(!verify_pin_challenge(
+            &key,
+            "nonce-1",
+            &answer_challenge("9999", "nonce-1")
+        ));
+        assert!(!verify_pin_challenge(&key, "nonce-1", "not-hex"));
+        assert!(!verify_pin_challenge("not-hex", "nonce-1", &response));
+    }
 }
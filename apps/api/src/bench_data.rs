@@ -0,0 +1,266 @@
+//! Synthetic data for load testing and benchmarks.
+//!
+//! Generates customers, tickets, and photo metadata in bulk so query changes
+//! can be measured against realistic volumes. Only built with the `bench`
+//! feature; point it at a scratch database, never a store's real data.
+
+use std::time::Duration;
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::repositories::TicketRepository;
+
+/// Name prefix marking generated customers.
+pub const BENCH_CUSTOMER_PREFIX: &str = "Bench Customer";
+
+/// How much synthetic data to generate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchDataConfig {
+    pub customers: u32,
+    pub tickets: u32,
+    pub photos_per_ticket: u32,
+}
+
+impl Default for BenchDataConfig {
+    fn default() -> Self {
+        Self {
+            customers: 1_000,
+            tickets: 10_000,
+            photos_per_ticket: 3,
+        }
+    }
+}
+
+impl BenchDataConfig {
+    /// Read volumes from `BENCH_CUSTOMERS`, `BENCH_TICKETS`, and
+    /// `BENCH_PHOTOS_PER_TICKET`, falling back to the defaults.
+    pub fn from_env() -> Result<Self, String> {
+        let defaults = Self::default();
+        let read = |name: &str, default: u32| match std::env::var(name) {
+            Ok(value) => value
+                .parse()
+                .map_err(|_| format!("{} must be a whole number", name)),
+            Err(_) => Ok(default),
+        };
+
+        Ok(Self {
+            customers: read("BENCH_CUSTOMERS", defaults.customers)?,
+            tickets: read("BENCH_TICKETS", defaults.tickets)?,
+            photos_per_ticket: read("BENCH_PHOTOS_PER_TICKET", defaults.photos_per_ticket)?,
+        })
+    }
+
+    /// Parse `--customers N --tickets N --photos-per-ticket N`, starting from
+    /// `base` for anything not given.
+    pub fn from_args<I>(base: Self, args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut config = base;
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let target = match flag.as_str() {
+                "--customers" => &mut config.customers,
+                "--tickets" => &mut config.tickets,
+                "--photos-per-ticket" => &mut config.photos_per_ticket,
+                _ => return Err(format!("Unknown argument: {}", flag)),
+            };
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} needs a value", flag))?;
+            *target = value
+                .parse()
+                .map_err(|_| format!("{} must be a whole number", flag))?;
+        }
+
+        if config.tickets > 0 && config.customers == 0 {
+            return Err("Tickets need at least one customer".to_string());
+        }
+        Ok(config)
+    }
+}
+
+/// What [`generate`] created.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchDataSummary {
+    pub customers: u64,
+    pub tickets: u64,
+    pub photos: u64,
+    pub elapsed: Duration,
+}
+
+/// Number of generated customers already in the database.
+pub async fn count_bench_customers(pool: &PgPool) -> Result<i64, AppError> {
+    let count =
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM customers WHERE name LIKE $1 || ' %'")
+            .bind(BENCH_CUSTOMER_PREFIX)
+            .fetch_one(pool)
+            .await?;
+
+    Ok(count)
+}
+
+/// Generate synthetic customers, tickets, and photo metadata.
+///
+/// Tickets are spread across the workboard statuses (about one in five is
+/// closed) and the last 180 days, one in ten is rush, and each is taken in
+/// by the first active employee at the first active location. Photos are
+/// metadata only; no files are written.
+pub async fn generate(
+    pool: &PgPool,
+    config: &BenchDataConfig,
+) -> Result<BenchDataSummary, AppError> {
+    let started = std::time::Instant::now();
+
+    let employee_id = sqlx::query_scalar::<_, Uuid>(
+        "SELECT employee_id FROM employees WHERE is_active ORDER BY created_at LIMIT 1",
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::validation("Synthetic data needs an active employee"))?;
+    let location_id = sqlx::query_scalar::<_, Uuid>(
+        "SELECT location_id FROM storage_locations WHERE is_active ORDER BY created_at LIMIT 1",
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| AppError::validation("Synthetic data needs an active storage location"))?;
+
+    let mut tx = pool.begin().await?;
+
+    let customer_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO customers (name, phone, email)
+        SELECT
+            $1 || ' ' || n,
+            '555-' || LPAD(n::TEXT, 7, '0'),
+            'bench' || n || '@example.com'
+        FROM generate_series(1, $2) AS n
+        RETURNING customer_id
+        "#,
+    )
+    .bind(BENCH_CUSTOMER_PREFIX)
+    .bind(config.customers as i32)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let codes =
+        TicketRepository::reserve_friendly_codes(&mut tx, location_id, config.tickets as usize)
+            .await?;
+    let ticket_ids = sqlx::query_scalar::<_, Uuid>(
+        r#"
+        INSERT INTO tickets (
+            friendly_code, customer_id, item_type, item_description, condition_notes,
+            requested_work, status, is_rush, promise_date, storage_location_id,
+            quote_amount, taken_in_by, lookup_token, created_at, closed_at, closed_by
+        )
+        SELECT
+            ($5::TEXT[])[n],
+            ($1::UUID[])[1 + n % CARDINALITY($1::UUID[])],
+            (ARRAY['ring', 'necklace', 'bracelet', 'watch', 'earrings'])[1 + n % 5],
+            'Bench item ' || n,
+            'Light wear',
+            (ARRAY['Resize', 'Polish', 'Replace clasp', 'Battery', 'Re-tip prongs'])[1 + n % 5],
+            s.status,
+            n % 10 = 0,
+            (NOW() - (n % 180) * INTERVAL '1 day' + INTERVAL '7 days')::DATE,
+            $3,
+            (20 + n % 480)::NUMERIC(10, 2),
+            $4,
+            SUBSTRING(MD5(RANDOM()::TEXT) FOR 32),
+            NOW() - (n % 180) * INTERVAL '1 day',
+            CASE WHEN s.status = 'closed' THEN NOW() - (n % 180) * INTERVAL '1 day' + INTERVAL '5 days' END,
+            CASE WHEN s.status = 'closed' THEN $4::UUID END
+        FROM generate_series(1, $2) AS n
+        CROSS JOIN LATERAL (
+            SELECT (ARRAY['intake', 'in_progress', 'waiting_on_parts', 'ready_for_pickup', 'closed'])[1 + n % 5]::ticket_status AS status
+        ) AS s
+        RETURNING ticket_id
+        "#,
+    )
+    .bind(&customer_ids)
+    .bind(config.tickets as i32)
+    .bind(location_id)
+    .bind(employee_id)
+    .bind(&codes)
+    .fetch_all(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO ticket_items (
+            ticket_id, position, item_type, item_description, condition_notes,
+            requested_work, quote_amount, created_at
+        )
+        SELECT ticket_id, 1, item_type, item_description, condition_notes,
+               requested_work, quote_amount, created_at
+        FROM tickets
+        WHERE ticket_id = ANY($1)
+        "#,
+    )
+    .bind(&ticket_ids)
+    .execute(&mut *tx)
+    .await?;
+
+    let photos = sqlx::query(
+        r#"
+        INSERT INTO ticket_photos (ticket_id, storage_key, content_type, size_bytes, uploaded_by)
+        SELECT t.ticket_id, 'tickets/' || t.ticket_id || '/' || gen_random_uuid() || '.jpg',
+               'image/jpeg', 250000, $3
+        FROM UNNEST($1::UUID[]) AS t(ticket_id)
+        CROSS JOIN generate_series(1, $2)
+        "#,
+    )
+    .bind(&ticket_ids)
+    .bind(config.photos_per_ticket as i32)
+    .bind(employee_id)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+
+    Ok(BenchDataSummary {
+        customers: customer_ids.len() as u64,
+        tickets: ticket_ids.len() as u64,
+        photos,
+        elapsed: started.elapsed(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args_overrides_base() {
+        let config = BenchDataConfig::from_args(
+            BenchDataConfig::default(),
+            args(&["--tickets", "500", "--photos-per-ticket", "0"]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            BenchDataConfig {
+                customers: 1_000,
+                tickets: 500,
+                photos_per_ticket: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_args_rejects_bad_input() {
+        let base = BenchDataConfig::default();
+        assert!(BenchDataConfig::from_args(base.clone(), args(&["--tickets"])).is_err());
+        assert!(BenchDataConfig::from_args(base.clone(), args(&["--tickets", "many"])).is_err());
+        assert!(BenchDataConfig::from_args(base.clone(), args(&["--verbose"])).is_err());
+        assert!(BenchDataConfig::from_args(base, args(&["--customers", "0"])).is_err());
+    }
+}
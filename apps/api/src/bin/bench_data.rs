@@ -0,0 +1,50 @@
+//! Generate synthetic data for load testing.
+//!
+//! Usage: cargo run --features bench --bin bench_data -- \
+//!            [--customers N] [--tickets N] [--photos-per-ticket N]
+//!
+//! Writes to DATABASE_URL; use a scratch database. Defaults come from
+//! BENCH_CUSTOMERS, BENCH_TICKETS, and BENCH_PHOTOS_PER_TICKET.
+
+use api::bench_data::{generate, BenchDataConfig};
+use api::{create_pool, DbConfig};
+use std::env;
+
+#[tokio::main]
+async fn main() {
+    dotenvy::dotenv().ok();
+
+    let config = BenchDataConfig::from_env()
+        .and_then(|base| BenchDataConfig::from_args(base, env::args().skip(1)));
+    let config = match config {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            eprintln!("Usage: bench_data [--customers N] [--tickets N] [--photos-per-ticket N]");
+            std::process::exit(1);
+        }
+    };
+
+    let Ok(database_url) = env::var("DATABASE_URL") else {
+        eprintln!("Error: DATABASE_URL must be set");
+        std::process::exit(1);
+    };
+    let pool = match create_pool(&DbConfig::new(database_url)).await {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("Error: failed to connect to database: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    match generate(&pool, &config).await {
+        Ok(summary) => println!(
+            "Created {} customers, {} tickets, and {} photos in {:.1?}",
+            summary.customers, summary.tickets, summary.photos, summary.elapsed
+        ),
+        Err(e) => {
+            eprintln!("Error: {}", e.message());
+            std::process::exit(1);
+        }
+    }
+}
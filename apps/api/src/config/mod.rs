@@ -1,6 +1,16 @@
 //! Application configuration from environment variables.
 
-use crate::storage::StorageConfig;
+use crate::middleware::{
+    ProbePolicy, RateLimitBackendKind, RateLimitPolicies, RequestTimeouts, TrustedProxies,
+};
+use crate::services::notifications::{SmtpConfig, SmtpTls, TwilioConfig};
+use crate::services::pin_lockout::{
+    PinLockoutPolicy, DEFAULT_PIN_LOCKOUT_ATTEMPTS, DEFAULT_PIN_LOCKOUT_WINDOW_MINUTES,
+};
+use crate::storage::{
+    BackendConfig, GcsConfig, LocalStorageConfig, StorageBackendKind, StorageConfig,
+};
+use std::collections::HashMap;
 use std::env;
 use std::net::SocketAddr;
 
@@ -10,6 +20,27 @@ pub const DEFAULT_MAX_BODY_SIZE: usize = 1024 * 1024;
 /// Default maximum body size for photo uploads (10MB).
 pub const DEFAULT_MAX_PHOTO_SIZE: usize = 10 * 1024 * 1024;
 
+/// Default SMTP submission port (STARTTLS).
+pub const DEFAULT_SMTP_PORT: u16 = 587;
+
+/// Default directory for the local storage backend.
+pub const DEFAULT_LOCAL_STORAGE_DIR: &str = "uploads";
+
+/// Default minutes between queue snapshots.
+pub const DEFAULT_QUEUE_SNAPSHOT_MINUTES: u64 = 30;
+
+/// Default lifetime of signed photo URLs (seconds).
+pub const DEFAULT_PHOTO_URL_TTL_SECS: u64 = 5 * 60;
+
+/// Default time budget for ordinary requests (seconds).
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Default time budget for reports, exports, imports, and PDFs (seconds).
+pub const DEFAULT_SLOW_REQUEST_TIMEOUT_SECS: u64 = 120;
+
+/// Default time in-flight work gets to finish on shutdown (seconds).
+pub const DEFAULT_SHUTDOWN_DRAIN_SECS: u64 = 25;
+
 /// Application configuration loaded from environment variables.
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -19,12 +50,24 @@ pub struct Config {
     /// Database connection URL
     pub database_url: String,
 
+    /// Where photos are stored
+    pub storage_backend: StorageBackendKind,
+
     /// S3-compatible storage configuration
     pub s3_endpoint: Option<String>,
     pub s3_bucket: String,
     pub s3_access_key: Option<String>,
     pub s3_secret_key: Option<String>,
 
+    /// Google Cloud Storage configuration (HMAC keys)
+    pub gcs_bucket: String,
+    pub gcs_access_key: Option<String>,
+    pub gcs_secret_key: Option<String>,
+
+    /// Local storage directory and URL signing key
+    pub local_storage_dir: String,
+    pub storage_signing_key: Option<String>,
+
     /// CORS allowed origins (comma-separated)
     pub cors_origins: Vec<String>,
 
@@ -36,6 +79,70 @@ pub struct Config {
 
     /// Maximum body size for photo uploads (bytes)
     pub max_photo_size: usize,
+
+    /// How requests for missing resources are answered
+    pub probe_policy: ProbePolicy,
+
+    /// Twilio credentials for customer text messages
+    pub twilio_account_sid: Option<String>,
+    pub twilio_auth_token: Option<String>,
+    pub twilio_from_number: Option<String>,
+
+    /// SMTP relay for customer email
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    pub smtp_username: Option<String>,
+    pub smtp_password: Option<String>,
+    pub smtp_from: Option<String>,
+    pub smtp_tls: SmtpTls,
+
+    /// Minutes between queue snapshots for the trend report (0 = off)
+    pub queue_snapshot_minutes: u64,
+
+    /// Strip EXIF metadata and apply orientation to uploaded photos
+    pub process_photos: bool,
+
+    /// How long signed photo URLs stay valid (seconds)
+    pub photo_url_ttl_secs: u64,
+
+    /// Shared secret for signed phone intake webhooks (unset = endpoint off)
+    pub phone_intake_secret: Option<String>,
+
+    /// Time budget for ordinary requests (seconds)
+    pub request_timeout_secs: u64,
+
+    /// Time budget for reports, exports, imports, and PDFs (seconds)
+    pub slow_request_timeout_secs: u64,
+
+    /// Record request metrics and serve them at `/metrics`
+    pub metrics_enabled: bool,
+
+    /// Seconds between runs for background jobs, by job name (0 = off)
+    pub job_intervals: HashMap<String, u64>,
+
+    /// Apply pending migrations on startup
+    pub run_migrations: bool,
+
+    /// Time in-flight requests and background work get to finish on shutdown (seconds)
+    pub shutdown_drain_secs: u64,
+
+    /// Proxies whose forwarding headers identify the client IP
+    pub trusted_proxies: TrustedProxies,
+
+    /// Per-IP and per-employee limits for route groups
+    pub rate_limits: RateLimitPolicies,
+
+    /// Where PIN and recovery failure counters are kept
+    pub rate_limit_backend: RateLimitBackendKind,
+
+    /// Wrong PINs within the window that lock an employee out (0 = never)
+    pub pin_lockout_attempts: u32,
+
+    /// Window wrong PINs are counted over (minutes)
+    pub pin_lockout_window_minutes: u64,
+
+    /// Where security alerts such as lockouts are emailed
+    pub security_alert_email: Option<String>,
 }
 
 impl Config {
@@ -43,18 +150,45 @@ impl Config {
     ///
     /// Required variables:
     /// - `DATABASE_URL`: PostgreSQL connection string
-    /// - `S3_BUCKET`: S3 bucket name for photo storage
+    /// - `S3_BUCKET`: S3 bucket name for photo storage (when `STORAGE_BACKEND=s3`)
+    /// - `GCS_BUCKET`: Cloud Storage bucket name (when `STORAGE_BACKEND=gcs`)
     ///
     /// Optional variables:
     /// - `HOST`: Server host (default: 0.0.0.0)
     /// - `PORT`: Server port (default: 3001)
+    /// - `STORAGE_BACKEND`: `s3`, `gcs`, or `local` (default: local)
     /// - `S3_ENDPOINT`: S3 endpoint URL (default: AWS S3)
     /// - `S3_ACCESS_KEY`: S3 access key
     /// - `S3_SECRET_KEY`: S3 secret key
+    /// - `GCS_ACCESS_KEY`, `GCS_SECRET_KEY`: Cloud Storage HMAC key
+    /// - `LOCAL_STORAGE_DIR`: Directory for local storage (default: uploads)
+    /// - `STORAGE_SIGNING_KEY`: Key for signing local storage URLs (default: random per start)
     /// - `CORS_ORIGINS`: Comma-separated allowed origins (default: *)
     /// - `RUST_LOG`: Log level filter (default: api=debug,tower_http=debug)
     /// - `MAX_BODY_SIZE`: Maximum body size for JSON endpoints in bytes (default: 1MB)
     /// - `MAX_PHOTO_SIZE`: Maximum body size for photo uploads in bytes (default: 10MB)
+    /// - `RESOURCE_PROBE_POLICY`: `not_found` or `forbidden` for missing resources (default: not_found)
+    /// - `TWILIO_ACCOUNT_SID`, `TWILIO_AUTH_TOKEN`, `TWILIO_FROM_NUMBER`: Enable SMS notifications
+    /// - `SMTP_HOST`, `SMTP_FROM`: Enable email notifications
+    /// - `SMTP_PORT`: SMTP port (default: 587)
+    /// - `SMTP_USERNAME`, `SMTP_PASSWORD`: SMTP credentials
+    /// - `SMTP_TLS`: `starttls`, `tls`, or `none` (default: starttls)
+    /// - `QUEUE_SNAPSHOT_MINUTES`: Minutes between queue snapshots, 0 to disable (default: 30)
+    /// - `PROCESS_PHOTOS`: Strip EXIF metadata and apply orientation on upload (default: true)
+    /// - `SIGNED_URL_TTL_SECONDS`: How long signed photo URLs stay valid (default: 300)
+    /// - `PHONE_INTAKE_SECRET`: Secret for signing phone intake webhooks (default: endpoint disabled)
+    /// - `REQUEST_TIMEOUT_SECS`: Time budget for ordinary requests (default: 30)
+    /// - `SLOW_REQUEST_TIMEOUT_SECS`: Time budget for reports, exports, imports, and PDFs (default: 120)
+    /// - `METRICS_ENABLED`: Record request metrics and serve them at `/metrics` (default: false)
+    /// - `JOB_INTERVALS`: Comma-separated `job=seconds` overrides for background jobs, 0 to disable
+    /// - `RUN_MIGRATIONS`: Apply pending migrations on startup (default: false)
+    /// - `SHUTDOWN_DRAIN_SECS`: Time in-flight work gets to finish on shutdown (default: 25)
+    /// - `TRUSTED_PROXIES`: Comma-separated CIDR ranges of proxies allowed to set `X-Real-IP` and `X-Forwarded-For` (default: loopback only)
+    /// - `RATE_LIMITS`: Comma-separated `group.ip=N` / `group.employee=N` per-minute limits for route groups, 0 to disable
+    /// - `RATE_LIMIT_BACKEND`: Where PIN failure counters are kept: `memory` or `postgres` (default: memory)
+    /// - `PIN_LOCKOUT_ATTEMPTS`: Wrong PINs within the window that lock an employee out, 0 to disable (default: 5)
+    /// - `PIN_LOCKOUT_WINDOW_MINUTES`: Window wrong PINs are counted over (default: 15)
+    /// - `SECURITY_ALERT_EMAIL`: Address emailed when an employee is locked out (optional)
     pub fn from_env() -> Result<Self, ConfigError> {
         let host = env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
         let port = env::var("PORT")
@@ -69,8 +203,13 @@ impl Config {
         let database_url = env::var("DATABASE_URL")
             .map_err(|_| ConfigError::Missing("DATABASE_URL".to_string()))?;
 
-        let s3_bucket =
-            env::var("S3_BUCKET").map_err(|_| ConfigError::Missing("S3_BUCKET".to_string()))?;
+        let storage_backend = env::var("STORAGE_BACKEND")
+            .ok()
+            .and_then(|s| StorageBackendKind::parse(&s))
+            .unwrap_or_default();
+
+        let s3_bucket = required_for(storage_backend, StorageBackendKind::S3, "S3_BUCKET")?;
+        let gcs_bucket = required_for(storage_backend, StorageBackendKind::Gcs, "GCS_BUCKET")?;
 
         let cors_origins = env::var("CORS_ORIGINS")
             .unwrap_or_else(|_| "*".to_string())
@@ -91,17 +230,113 @@ impl Config {
             .and_then(|s| s.parse().ok())
             .unwrap_or(DEFAULT_MAX_PHOTO_SIZE);
 
+        let probe_policy = env::var("RESOURCE_PROBE_POLICY")
+            .ok()
+            .and_then(|s| ProbePolicy::parse(&s))
+            .unwrap_or_default();
+
+        let smtp_port = env::var("SMTP_PORT")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_SMTP_PORT);
+
+        let smtp_tls = env::var("SMTP_TLS")
+            .ok()
+            .and_then(|s| SmtpTls::parse(&s))
+            .unwrap_or_default();
+
+        let queue_snapshot_minutes = env::var("QUEUE_SNAPSHOT_MINUTES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_QUEUE_SNAPSHOT_MINUTES);
+
+        let process_photos = env_flag("PROCESS_PHOTOS", true);
+
+        let photo_url_ttl_secs = env::var("SIGNED_URL_TTL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_PHOTO_URL_TTL_SECS);
+
+        let request_timeout_secs = env::var("REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS);
+
+        let slow_request_timeout_secs = env::var("SLOW_REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_SLOW_REQUEST_TIMEOUT_SECS);
+
+        let shutdown_drain_secs = env::var("SHUTDOWN_DRAIN_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_SHUTDOWN_DRAIN_SECS);
+
+        let pin_lockout_attempts = env::var("PIN_LOCKOUT_ATTEMPTS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_PIN_LOCKOUT_ATTEMPTS);
+
+        let pin_lockout_window_minutes = env::var("PIN_LOCKOUT_WINDOW_MINUTES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|&minutes| minutes > 0)
+            .unwrap_or(DEFAULT_PIN_LOCKOUT_WINDOW_MINUTES);
+
+        let trusted_proxies = env::var("TRUSTED_PROXIES")
+            .ok()
+            .map(|s| TrustedProxies::parse(&s))
+            .unwrap_or_default();
+
         Ok(Config {
             server_addr,
             database_url,
+            storage_backend,
             s3_endpoint: env::var("S3_ENDPOINT").ok(),
             s3_bucket,
             s3_access_key: env::var("S3_ACCESS_KEY").ok(),
             s3_secret_key: env::var("S3_SECRET_KEY").ok(),
+            gcs_bucket,
+            gcs_access_key: env::var("GCS_ACCESS_KEY").ok(),
+            gcs_secret_key: env::var("GCS_SECRET_KEY").ok(),
+            local_storage_dir: env::var("LOCAL_STORAGE_DIR")
+                .unwrap_or_else(|_| DEFAULT_LOCAL_STORAGE_DIR.to_string()),
+            storage_signing_key: env::var("STORAGE_SIGNING_KEY").ok(),
             cors_origins,
             log_filter,
             max_body_size,
             max_photo_size,
+            probe_policy,
+            twilio_account_sid: env::var("TWILIO_ACCOUNT_SID").ok(),
+            twilio_auth_token: env::var("TWILIO_AUTH_TOKEN").ok(),
+            twilio_from_number: env::var("TWILIO_FROM_NUMBER").ok(),
+            smtp_host: env::var("SMTP_HOST").ok(),
+            smtp_port,
+            smtp_username: env::var("SMTP_USERNAME").ok(),
+            smtp_password: env::var("SMTP_PASSWORD").ok(),
+            smtp_from: env::var("SMTP_FROM").ok(),
+            smtp_tls,
+            queue_snapshot_minutes,
+            process_photos,
+            photo_url_ttl_secs,
+            phone_intake_secret: env::var("PHONE_INTAKE_SECRET").ok(),
+            request_timeout_secs,
+            slow_request_timeout_secs,
+            metrics_enabled: env_flag("METRICS_ENABLED", false),
+            job_intervals: parse_job_intervals(&env::var("JOB_INTERVALS").unwrap_or_default()),
+            run_migrations: env_flag("RUN_MIGRATIONS", false),
+            shutdown_drain_secs,
+            trusted_proxies,
+            rate_limits: RateLimitPolicies::parse(&env::var("RATE_LIMITS").unwrap_or_default()),
+            rate_limit_backend: env::var("RATE_LIMIT_BACKEND")
+                .ok()
+                .and_then(|s| RateLimitBackendKind::parse(&s))
+                .unwrap_or_default(),
+            pin_lockout_attempts,
+            pin_lockout_window_minutes,
+            security_alert_email: env::var("SECURITY_ALERT_EMAIL")
+                .ok()
+                .filter(|s| !s.trim().is_empty()),
         })
     }
 
@@ -137,18 +372,117 @@ impl Config {
             .and_then(|s| s.parse().ok())
             .unwrap_or(DEFAULT_MAX_PHOTO_SIZE);
 
+        let probe_policy = env::var("RESOURCE_PROBE_POLICY")
+            .ok()
+            .and_then(|s| ProbePolicy::parse(&s))
+            .unwrap_or_default();
+
+        let smtp_port = env::var("SMTP_PORT")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_SMTP_PORT);
+
+        let smtp_tls = env::var("SMTP_TLS")
+            .ok()
+            .and_then(|s| SmtpTls::parse(&s))
+            .unwrap_or_default();
+
+        let queue_snapshot_minutes = env::var("QUEUE_SNAPSHOT_MINUTES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_QUEUE_SNAPSHOT_MINUTES);
+
+        let process_photos = env_flag("PROCESS_PHOTOS", true);
+
+        let photo_url_ttl_secs = env::var("SIGNED_URL_TTL_SECONDS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_PHOTO_URL_TTL_SECS);
+
+        let request_timeout_secs = env::var("REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS);
+
+        let slow_request_timeout_secs = env::var("SLOW_REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_SLOW_REQUEST_TIMEOUT_SECS);
+
+        let shutdown_drain_secs = env::var("SHUTDOWN_DRAIN_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_SHUTDOWN_DRAIN_SECS);
+
+        let pin_lockout_attempts = env::var("PIN_LOCKOUT_ATTEMPTS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_PIN_LOCKOUT_ATTEMPTS);
+
+        let pin_lockout_window_minutes = env::var("PIN_LOCKOUT_WINDOW_MINUTES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|&minutes| minutes > 0)
+            .unwrap_or(DEFAULT_PIN_LOCKOUT_WINDOW_MINUTES);
+
+        let trusted_proxies = env::var("TRUSTED_PROXIES")
+            .ok()
+            .map(|s| TrustedProxies::parse(&s))
+            .unwrap_or_default();
+
         Config {
             server_addr,
             database_url: env::var("DATABASE_URL")
                 .unwrap_or_else(|_| "postgres://localhost/facet_dev".to_string()),
+            storage_backend: env::var("STORAGE_BACKEND")
+                .ok()
+                .and_then(|s| StorageBackendKind::parse(&s))
+                .unwrap_or_default(),
             s3_endpoint: env::var("S3_ENDPOINT").ok(),
             s3_bucket: env::var("S3_BUCKET").unwrap_or_else(|_| "facet-dev".to_string()),
             s3_access_key: env::var("S3_ACCESS_KEY").ok(),
             s3_secret_key: env::var("S3_SECRET_KEY").ok(),
+            gcs_bucket: env::var("GCS_BUCKET").unwrap_or_else(|_| "facet-dev".to_string()),
+            gcs_access_key: env::var("GCS_ACCESS_KEY").ok(),
+            gcs_secret_key: env::var("GCS_SECRET_KEY").ok(),
+            local_storage_dir: env::var("LOCAL_STORAGE_DIR")
+                .unwrap_or_else(|_| DEFAULT_LOCAL_STORAGE_DIR.to_string()),
+            storage_signing_key: env::var("STORAGE_SIGNING_KEY").ok(),
             cors_origins,
             log_filter,
             max_body_size,
             max_photo_size,
+            probe_policy,
+            twilio_account_sid: env::var("TWILIO_ACCOUNT_SID").ok(),
+            twilio_auth_token: env::var("TWILIO_AUTH_TOKEN").ok(),
+            twilio_from_number: env::var("TWILIO_FROM_NUMBER").ok(),
+            smtp_host: env::var("SMTP_HOST").ok(),
+            smtp_port,
+            smtp_username: env::var("SMTP_USERNAME").ok(),
+            smtp_password: env::var("SMTP_PASSWORD").ok(),
+            smtp_from: env::var("SMTP_FROM").ok(),
+            smtp_tls,
+            queue_snapshot_minutes,
+            process_photos,
+            photo_url_ttl_secs,
+            phone_intake_secret: env::var("PHONE_INTAKE_SECRET").ok(),
+            request_timeout_secs,
+            slow_request_timeout_secs,
+            metrics_enabled: env_flag("METRICS_ENABLED", false),
+            job_intervals: parse_job_intervals(&env::var("JOB_INTERVALS").unwrap_or_default()),
+            run_migrations: env_flag("RUN_MIGRATIONS", false),
+            shutdown_drain_secs,
+            trusted_proxies,
+            rate_limits: RateLimitPolicies::parse(&env::var("RATE_LIMITS").unwrap_or_default()),
+            rate_limit_backend: env::var("RATE_LIMIT_BACKEND")
+                .ok()
+                .and_then(|s| RateLimitBackendKind::parse(&s))
+                .unwrap_or_default(),
+            pin_lockout_attempts,
+            pin_lockout_window_minutes,
+            security_alert_email: env::var("SECURITY_ALERT_EMAIL")
+                .ok()
+                .filter(|s| !s.trim().is_empty()),
         }
     }
 
@@ -174,6 +508,97 @@ impl Config {
 
         config
     }
+
+    /// Settings for the storage backend selected by `STORAGE_BACKEND`.
+    pub fn backend_config(&self) -> BackendConfig {
+        match self.storage_backend {
+            StorageBackendKind::S3 => BackendConfig::S3(self.storage_config()),
+            StorageBackendKind::Gcs => BackendConfig::Gcs(GcsConfig {
+                bucket: self.gcs_bucket.clone(),
+                access_key: self.gcs_access_key.clone(),
+                secret_key: self.gcs_secret_key.clone(),
+            }),
+            StorageBackendKind::Local => BackendConfig::Local(LocalStorageConfig {
+                root: self.local_storage_dir.clone().into(),
+                signing_key: self.storage_signing_key.clone(),
+            }),
+        }
+    }
+
+    /// How often to snapshot the queue, or None when snapshots are off.
+    pub fn queue_snapshot_interval(&self) -> Option<std::time::Duration> {
+        (self.queue_snapshot_minutes > 0)
+            .then(|| std::time::Duration::from_secs(self.queue_snapshot_minutes * 60))
+    }
+
+    /// How long signed photo URLs stay valid (at least a minute).
+    pub fn photo_url_ttl(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.photo_url_ttl_secs.max(60))
+    }
+
+    /// Time budgets for requests.
+    ///
+    /// The slow budget is never shorter than the default one.
+    pub fn request_timeouts(&self) -> RequestTimeouts {
+        let default = std::time::Duration::from_secs(self.request_timeout_secs.max(1));
+        RequestTimeouts {
+            default,
+            slow: default.max(std::time::Duration::from_secs(
+                self.slow_request_timeout_secs,
+            )),
+        }
+    }
+
+    /// How long in-flight work gets to finish once shutdown starts.
+    pub fn shutdown_drain_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.shutdown_drain_secs)
+    }
+
+    /// When wrong PINs lock an employee out, and where alerts go.
+    pub fn pin_lockout_policy(&self) -> PinLockoutPolicy {
+        PinLockoutPolicy {
+            max_attempts: self.pin_lockout_attempts,
+            window: std::time::Duration::from_secs(self.pin_lockout_window_minutes * 60),
+            alert_email: self.security_alert_email.clone(),
+        }
+    }
+
+    /// Create a TwilioConfig if all Twilio variables are set.
+    ///
+    /// Returns None when SMS is not configured; notifications are then
+    /// logged as skipped instead of sent.
+    pub fn twilio_config(&self) -> Option<TwilioConfig> {
+        match (
+            &self.twilio_account_sid,
+            &self.twilio_auth_token,
+            &self.twilio_from_number,
+        ) {
+            (Some(account_sid), Some(auth_token), Some(from_number)) => Some(TwilioConfig {
+                account_sid: account_sid.clone(),
+                auth_token: auth_token.clone(),
+                from_number: from_number.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Create an SmtpConfig if a host and from address are set.
+    ///
+    /// Returns None when email is not configured; email notifications are
+    /// then logged as skipped instead of sent.
+    pub fn smtp_config(&self) -> Option<SmtpConfig> {
+        match (&self.smtp_host, &self.smtp_from) {
+            (Some(host), Some(from_address)) => Some(SmtpConfig {
+                host: host.clone(),
+                port: self.smtp_port,
+                username: self.smtp_username.clone(),
+                password: self.smtp_password.clone(),
+                from_address: from_address.clone(),
+                tls: self.smtp_tls,
+            }),
+            _ => None,
+        }
+    }
 }
 
 /// Extract region from S3-compatible endpoint URL.
@@ -193,6 +618,50 @@ fn extract_region_from_endpoint(endpoint: &str) -> Option<String> {
         .filter(|s| !s.is_empty())
 }
 
+/// Read a bucket variable that is required only when `backend` is selected.
+fn required_for(
+    selected: StorageBackendKind,
+    backend: StorageBackendKind,
+    name: &str,
+) -> Result<String, ConfigError> {
+    match env::var(name) {
+        Ok(value) => Ok(value),
+        Err(_) if selected == backend => Err(ConfigError::Missing(name.to_string())),
+        Err(_) => Ok(String::new()),
+    }
+}
+
+/// Parse `job=seconds` pairs separated by commas, skipping malformed ones.
+fn parse_job_intervals(value: &str) -> HashMap<String, u64> {
+    value
+        .split(',')
+        .filter_map(|pair| {
+            let (name, secs) = pair.split_once('=')?;
+            let name = name.trim();
+            let secs = secs.trim().parse().ok()?;
+            (!name.is_empty()).then(|| (name.to_string(), secs))
+        })
+        .collect()
+}
+
+/// Read a boolean environment variable, falling back to `default` when it is
+/// unset or not recognized.
+fn env_flag(name: &str, default: bool) -> bool {
+    env::var(name)
+        .ok()
+        .and_then(|s| parse_flag(&s))
+        .unwrap_or(default)
+}
+
+/// Parse a boolean setting: true/false, 1/0, yes/no, or on/off.
+fn parse_flag(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Some(true),
+        "false" | "0" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
 /// Configuration loading errors.
 #[derive(Debug)]
 pub enum ConfigError {
@@ -227,6 +696,94 @@ mod tests {
         assert!(!config.cors_origins.is_empty());
     }
 
+    #[test]
+    fn test_twilio_config_requires_all_values() {
+        let mut config = Config::from_env_or_defaults();
+        config.twilio_account_sid = Some("AC123".to_string());
+        config.twilio_auth_token = Some("token".to_string());
+        config.twilio_from_number = None;
+        assert!(config.twilio_config().is_none());
+
+        config.twilio_from_number = Some("+15550001111".to_string());
+        let twilio = config.twilio_config().unwrap();
+        assert_eq!(twilio.account_sid, "AC123");
+        assert_eq!(twilio.from_number, "+15550001111");
+    }
+
+    #[test]
+    fn test_smtp_config_requires_host_and_from() {
+        let mut config = Config::from_env_or_defaults();
+        config.smtp_host = Some("smtp.example.com".to_string());
+        config.smtp_from = None;
+        assert!(config.smtp_config().is_none());
+
+        config.smtp_from = Some("Shop <shop@example.com>".to_string());
+        config.smtp_port = 465;
+        config.smtp_tls = SmtpTls::Tls;
+        let smtp = config.smtp_config().unwrap();
+        assert_eq!(smtp.host, "smtp.example.com");
+        assert_eq!(smtp.port, 465);
+        assert_eq!(smtp.tls, SmtpTls::Tls);
+        assert!(smtp.username.is_none());
+    }
+
+    #[test]
+    fn test_queue_snapshot_interval() {
+        let mut config = Config::from_env_or_defaults();
+        config.queue_snapshot_minutes = 15;
+        assert_eq!(
+            config.queue_snapshot_interval(),
+            Some(std::time::Duration::from_secs(15 * 60))
+        );
+
+        config.queue_snapshot_minutes = 0;
+        assert!(config.queue_snapshot_interval().is_none());
+    }
+
+    #[test]
+    fn test_request_timeouts() {
+        let mut config = Config::from_env_or_defaults();
+        config.request_timeout_secs = 10;
+        config.slow_request_timeout_secs = 60;
+        let timeouts = config.request_timeouts();
+        assert_eq!(timeouts.default, std::time::Duration::from_secs(10));
+        assert_eq!(timeouts.slow, std::time::Duration::from_secs(60));
+
+        config.slow_request_timeout_secs = 5;
+        assert_eq!(
+            config.request_timeouts().slow,
+            std::time::Duration::from_secs(10)
+        );
+    }
+
+    #[test]
+    fn test_photo_url_ttl() {
+        let mut config = Config::from_env_or_defaults();
+        config.photo_url_ttl_secs = 900;
+        assert_eq!(config.photo_url_ttl(), std::time::Duration::from_secs(900));
+
+        config.photo_url_ttl_secs = 0;
+        assert_eq!(config.photo_url_ttl(), std::time::Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_parse_flag() {
+        assert_eq!(parse_flag("true"), Some(true));
+        assert_eq!(parse_flag(" ON "), Some(true));
+        assert_eq!(parse_flag("0"), Some(false));
+        assert_eq!(parse_flag("No"), Some(false));
+        assert_eq!(parse_flag("maybe"), None);
+    }
+
+    #[test]
+    fn test_parse_job_intervals() {
+        let intervals = parse_job_intervals(" webhook_delivery = 30,auto_archive=0,bad,=5,x=y");
+        assert_eq!(intervals.len(), 2);
+        assert_eq!(intervals["webhook_delivery"], 30);
+        assert_eq!(intervals["auto_archive"], 0);
+        assert!(parse_job_intervals("").is_empty());
+    }
+
     #[test]
     fn test_extract_region_from_endpoint() {
         // DigitalOcean Spaces
@@ -264,6 +821,29 @@ mod tests {
         assert_eq!(storage_config.bucket, config.s3_bucket);
     }
 
+    #[test]
+    fn test_backend_config_follows_storage_backend() {
+        let mut config = Config::from_env_or_defaults();
+        config.storage_backend = StorageBackendKind::Local;
+        config.local_storage_dir = "/var/facet/photos".to_string();
+        match config.backend_config() {
+            BackendConfig::Local(local) => {
+                assert_eq!(local.root, std::path::PathBuf::from("/var/facet/photos"))
+            }
+            other => panic!("expected local backend, got {:?}", other),
+        }
+
+        config.storage_backend = StorageBackendKind::Gcs;
+        config.gcs_bucket = "facet-photos".to_string();
+        match config.backend_config() {
+            BackendConfig::Gcs(gcs) => assert_eq!(gcs.bucket, "facet-photos"),
+            other => panic!("expected gcs backend, got {:?}", other),
+        }
+
+        config.storage_backend = StorageBackendKind::S3;
+        assert!(matches!(config.backend_config(), BackendConfig::S3(_)));
+    }
+
     #[test]
     fn test_default_body_size_limits() {
         let config = Config::from_env_or_defaults();
@@ -277,6 +857,12 @@ mod tests {
         assert_eq!(config.max_photo_size, 10 * 1024 * 1024);
     }
 
+    #[test]
+    fn test_default_probe_policy() {
+        let config = Config::from_env_or_defaults();
+        assert_eq!(config.probe_policy, ProbePolicy::NotFound);
+    }
+
     #[test]
     fn test_body_size_constants() {
         // Verify constants are reasonable values
@@ -1,7 +1,9 @@
 //! CORS configuration for the API.
 
+use crate::middleware::idempotency::REPLAYED_HEADER;
+use crate::middleware::REQUEST_ID_HEADER;
 use crate::Config;
-use axum::http::HeaderValue;
+use axum::http::{header, HeaderName, HeaderValue};
 use std::time::Duration;
 use tower_http::cors::{AllowOrigin, Any, CorsLayer};
 
@@ -16,6 +18,14 @@ pub fn build_cors_layer(config: &Config) -> CorsLayer {
     let cors = CorsLayer::new()
         .allow_methods(Any)
         .allow_headers(Any)
+        // Settings responses carry an ETag the client sends back as If-Match;
+        // every response carries the request ID for bug reports; replayed
+        // idempotent responses are flagged
+        .expose_headers([
+            header::ETAG,
+            REQUEST_ID_HEADER,
+            HeaderName::from_static(REPLAYED_HEADER),
+        ])
         .max_age(Duration::from_secs(3600));
 
     // If origins is "*", allow any origin; otherwise, parse specific origins
@@ -55,14 +65,46 @@ mod tests {
         Config {
             server_addr: "127.0.0.1:3001".parse().unwrap(),
             database_url: "postgres://test".to_string(),
+            storage_backend: Default::default(),
             s3_endpoint: None,
             s3_bucket: "test".to_string(),
             s3_access_key: None,
             s3_secret_key: None,
+            gcs_bucket: "test".to_string(),
+            gcs_access_key: None,
+            gcs_secret_key: None,
+            local_storage_dir: "uploads".to_string(),
+            storage_signing_key: None,
             cors_origins: origins.into_iter().map(String::from).collect(),
             log_filter: "".to_string(),
             max_body_size: DEFAULT_MAX_BODY_SIZE,
             max_photo_size: DEFAULT_MAX_PHOTO_SIZE,
+            probe_policy: Default::default(),
+            twilio_account_sid: None,
+            twilio_auth_token: None,
+            twilio_from_number: None,
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            smtp_password: None,
+            smtp_from: None,
+            smtp_tls: Default::default(),
+            queue_snapshot_minutes: 0,
+            process_photos: true,
+            photo_url_ttl_secs: 300,
+            phone_intake_secret: None,
+            request_timeout_secs: 30,
+            slow_request_timeout_secs: 120,
+            metrics_enabled: false,
+            job_intervals: Default::default(),
+            run_migrations: false,
+            shutdown_drain_secs: 25,
+            trusted_proxies: Default::default(),
+            rate_limits: Default::default(),
+            rate_limit_backend: Default::default(),
+            pin_lockout_attempts: 5,
+            pin_lockout_window_minutes: 15,
+            security_alert_email: None,
         }
     }
 
@@ -1,10 +1,16 @@
 //! Database connection pool and utilities.
 //!
-//! This module provides the PostgreSQL connection pool setup using sqlx.
+//! This module provides the PostgreSQL connection pool setup using sqlx,
+//! and the migrations embedded in the binary.
 
-use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::migrate::{MigrateError, Migrator};
+use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+use std::str::FromStr;
 use std::time::Duration;
 
+/// Migrations under `migrations/`, embedded at build time.
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
 /// Database pool configuration options.
 #[derive(Debug, Clone)]
 pub struct DbConfig {
@@ -18,6 +24,8 @@ pub struct DbConfig {
     pub acquire_timeout: Duration,
     /// Maximum idle time for a connection
     pub idle_timeout: Duration,
+    /// Longest a single statement may run before Postgres cancels it
+    pub statement_timeout: Option<Duration>,
 }
 
 impl Default for DbConfig {
@@ -28,6 +36,7 @@ impl Default for DbConfig {
             min_connections: 2,
             acquire_timeout: Duration::from_secs(30),
             idle_timeout: Duration::from_secs(600),
+            statement_timeout: None,
         }
     }
 }
@@ -64,6 +73,15 @@ impl DbConfig {
         self.idle_timeout = timeout;
         self
     }
+
+    /// Set the statement timeout for every connection.
+    ///
+    /// Stops queries on the server once the request that issued them has
+    /// been cancelled by its own time budget.
+    pub fn statement_timeout(mut self, timeout: Duration) -> Self {
+        self.statement_timeout = Some(timeout);
+        self
+    }
 }
 
 /// Create a PostgreSQL connection pool with the given configuration.
@@ -72,12 +90,17 @@ impl DbConfig {
 ///
 /// Returns an error if the pool cannot be created or initial connections fail.
 pub async fn create_pool(config: &DbConfig) -> Result<PgPool, sqlx::Error> {
+    let mut options = PgConnectOptions::from_str(&config.url)?;
+    if let Some(timeout) = config.statement_timeout {
+        options = options.options([("statement_timeout", format!("{}ms", timeout.as_millis()))]);
+    }
+
     let pool = PgPoolOptions::new()
         .max_connections(config.max_connections)
         .min_connections(config.min_connections)
         .acquire_timeout(config.acquire_timeout)
         .idle_timeout(config.idle_timeout)
-        .connect(&config.url)
+        .connect_with(options)
         .await?;
 
     tracing::info!(
@@ -100,6 +123,57 @@ pub async fn test_connection(pool: &PgPool) -> Result<(), sqlx::Error> {
     Ok(())
 }
 
+/// Apply embedded migrations the database hasn't run yet.
+///
+/// Migrations already applied are skipped; their checksums must still match
+/// the embedded files.
+///
+/// # Errors
+///
+/// Returns an error if a migration fails or an applied one has changed.
+pub async fn run_migrations(pool: &PgPool) -> Result<(), MigrateError> {
+    let before = applied_migration_version(pool).await?;
+    MIGRATOR.run(pool).await?;
+    let after = applied_migration_version(pool).await?;
+    if after != before {
+        tracing::info!(
+            "Applied migrations {} through {}",
+            before.map_or(1, |version| version + 1),
+            after.unwrap_or_default()
+        );
+    }
+    Ok(())
+}
+
+/// Version of the newest migration embedded in this binary.
+pub fn latest_migration_version() -> i64 {
+    MIGRATOR
+        .iter()
+        .map(|migration| migration.version)
+        .max()
+        .unwrap_or_default()
+}
+
+/// Version of the newest migration the database has applied.
+///
+/// Returns None when migrations have never been run through sqlx.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub async fn applied_migration_version(pool: &PgPool) -> Result<Option<i64>, sqlx::Error> {
+    let tracked: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+        .fetch_one(pool)
+        .await?;
+    if !tracked {
+        return Ok(None);
+    }
+
+    sqlx::query_scalar("SELECT MAX(version) FROM _sqlx_migrations WHERE success")
+        .fetch_one(pool)
+        .await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -111,6 +185,15 @@ mod tests {
         assert_eq!(config.min_connections, 2);
         assert_eq!(config.acquire_timeout, Duration::from_secs(30));
         assert_eq!(config.idle_timeout, Duration::from_secs(600));
+        assert_eq!(config.statement_timeout, None);
+    }
+
+    #[test]
+    fn test_migrations_are_numbered_without_gaps() {
+        let versions: Vec<i64> = MIGRATOR.iter().map(|migration| migration.version).collect();
+        let expected: Vec<i64> = (1..=versions.len() as i64).collect();
+        assert_eq!(versions, expected);
+        assert_eq!(latest_migration_version(), versions.len() as i64);
     }
 
     #[test]
@@ -125,11 +208,13 @@ mod tests {
             .max_connections(20)
             .min_connections(5)
             .acquire_timeout(Duration::from_secs(60))
-            .idle_timeout(Duration::from_secs(300));
+            .idle_timeout(Duration::from_secs(300))
+            .statement_timeout(Duration::from_secs(120));
 
         assert_eq!(config.max_connections, 20);
         assert_eq!(config.min_connections, 5);
         assert_eq!(config.acquire_timeout, Duration::from_secs(60));
         assert_eq!(config.idle_timeout, Duration::from_secs(300));
+        assert_eq!(config.statement_timeout, Some(Duration::from_secs(120)));
     }
 }
@@ -2,6 +2,8 @@
 //!
 //! This module defines the error types used throughout the API
 //! and implements conversion to HTTP responses with consistent JSON format.
+//! Constructor messages are translated into the request's locale (see
+//! [`crate::i18n`]).
 
 use axum::{
     http::StatusCode,
@@ -10,6 +12,9 @@ use axum::{
 };
 use serde::Serialize;
 
+use crate::i18n::localize;
+use crate::middleware::RequestId;
+
 /// Error codes matching the API specification.
 pub mod codes {
     pub const VALIDATION_ERROR: &str = "VALIDATION_ERROR";
@@ -18,19 +23,139 @@ pub mod codes {
     pub const FORBIDDEN: &str = "FORBIDDEN";
     pub const NOT_FOUND: &str = "NOT_FOUND";
     pub const CONFLICT: &str = "CONFLICT";
+    pub const PRECONDITION_FAILED: &str = "PRECONDITION_FAILED";
     pub const PHOTO_LIMIT: &str = "PHOTO_LIMIT";
     pub const PRINT_REQUIRED: &str = "PRINT_REQUIRED";
+    pub const QC_REQUIRED: &str = "QC_REQUIRED";
+    pub const PAYMENT_REQUIRED: &str = "PAYMENT_REQUIRED";
+    pub const APPROVAL_REQUIRED: &str = "APPROVAL_REQUIRED";
+    pub const ACCOUNT_LOCKED: &str = "ACCOUNT_LOCKED";
     pub const RATE_LIMITED: &str = "RATE_LIMITED";
     pub const SETUP_EXPIRED: &str = "SETUP_EXPIRED";
     pub const PAYLOAD_TOO_LARGE: &str = "PAYLOAD_TOO_LARGE";
+    pub const SERVICE_UNAVAILABLE: &str = "SERVICE_UNAVAILABLE";
+    pub const TIMEOUT: &str = "TIMEOUT";
     pub const SERVER_ERROR: &str = "SERVER_ERROR";
 }
 
+/// An entry in the error catalog served at `GET /api/v1/errors`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorCatalogEntry {
+    pub code: &'static str,
+    pub status: u16,
+    pub description: &'static str,
+}
+
+/// Every error code the API can return.
+///
+/// Keep in sync with [`AppError`]; the tests check that each variant is listed
+/// with its status code.
+pub const ERROR_CATALOG: &[ErrorCatalogEntry] = &[
+    ErrorCatalogEntry {
+        code: codes::VALIDATION_ERROR,
+        status: 400,
+        description: "Invalid request data",
+    },
+    ErrorCatalogEntry {
+        code: codes::INVALID_PIN,
+        status: 401,
+        description: "Employee or admin PIN incorrect",
+    },
+    ErrorCatalogEntry {
+        code: codes::UNAUTHORIZED,
+        status: 401,
+        description: "Missing or invalid authentication",
+    },
+    ErrorCatalogEntry {
+        code: codes::FORBIDDEN,
+        status: 403,
+        description: "Action not allowed; also returned for missing resources under the forbidden probe policy",
+    },
+    ErrorCatalogEntry {
+        code: codes::NOT_FOUND,
+        status: 404,
+        description: "Resource not found (under the not_found probe policy)",
+    },
+    ErrorCatalogEntry {
+        code: codes::CONFLICT,
+        status: 409,
+        description: "Conflict (e.g., duplicate identifier)",
+    },
+    ErrorCatalogEntry {
+        code: codes::PRECONDITION_FAILED,
+        status: 412,
+        description: "If-Match ETag is stale; reload and retry",
+    },
+    ErrorCatalogEntry {
+        code: codes::PAYLOAD_TOO_LARGE,
+        status: 413,
+        description: "Request body exceeds maximum allowed size",
+    },
+    ErrorCatalogEntry {
+        code: codes::PHOTO_LIMIT,
+        status: 422,
+        description: "Max photos per ticket reached",
+    },
+    ErrorCatalogEntry {
+        code: codes::PRINT_REQUIRED,
+        status: 422,
+        description: "Cannot complete action until print succeeds",
+    },
+    ErrorCatalogEntry {
+        code: codes::QC_REQUIRED,
+        status: 422,
+        description: "Passing QC check required before ready for pickup",
+    },
+    ErrorCatalogEntry {
+        code: codes::PAYMENT_REQUIRED,
+        status: 422,
+        description: "Payments must cover the actual amount before closing, unless a balance due is allowed",
+    },
+    ErrorCatalogEntry {
+        code: codes::APPROVAL_REQUIRED,
+        status: 422,
+        description: "Customer approval of the quote required before work starts",
+    },
+    ErrorCatalogEntry {
+        code: codes::ACCOUNT_LOCKED,
+        status: 423,
+        description: "Employee locked out after too many wrong PINs; an admin must unlock them",
+    },
+    ErrorCatalogEntry {
+        code: codes::RATE_LIMITED,
+        status: 429,
+        description: "Too many requests; see the Retry-After header",
+    },
+    ErrorCatalogEntry {
+        code: codes::SETUP_EXPIRED,
+        status: 403,
+        description: "Initial setup deadline has passed",
+    },
+    ErrorCatalogEntry {
+        code: codes::SERVER_ERROR,
+        status: 500,
+        description: "Internal server error",
+    },
+    ErrorCatalogEntry {
+        code: codes::SERVICE_UNAVAILABLE,
+        status: 503,
+        description: "An external provider is down; retry after the Retry-After delay",
+    },
+    ErrorCatalogEntry {
+        code: codes::TIMEOUT,
+        status: 504,
+        description: "Request ran past its time limit and was cancelled; safe to retry",
+    },
+];
+
 /// Error detail in API response.
 #[derive(Debug, Clone, Serialize)]
 pub struct ErrorDetail {
     pub code: &'static str,
     pub message: String,
+    /// ID of the request that failed, also sent as `X-Request-ID`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 
 /// Application errors that can be returned from handlers.
@@ -48,18 +173,32 @@ pub enum AppError {
     NotFound(String),
     /// Conflict (409).
     Conflict(String),
+    /// If-Match precondition not met (412).
+    PreconditionFailed(String),
     /// Request body too large (413).
     PayloadTooLarge(String),
     /// Max photos per ticket reached (422).
     PhotoLimit(String),
     /// Cannot complete action until print succeeds (422).
     PrintRequired(String),
+    /// Passing QC check required before the status change (422).
+    QcRequired(String),
+    /// Payments don't cover the amount due at close (422).
+    PaymentRequired(String),
+    /// Customer approval of the quote required before the status change (422).
+    ApprovalRequired(String),
+    /// Employee locked out after too many wrong PINs (423).
+    AccountLocked(String),
     /// Too many requests (429).
     RateLimited { message: String, retry_after: u64 },
     /// Initial setup deadline has passed (403).
     SetupExpired(String),
     /// Internal server error (500).
     ServerError(String),
+    /// External provider unavailable, e.g. an open circuit breaker (503).
+    ServiceUnavailable { message: String, retry_after: u64 },
+    /// Request ran past its time limit (504).
+    Timeout(String),
 }
 
 impl AppError {
@@ -72,12 +211,19 @@ impl AppError {
             AppError::Forbidden(_) => codes::FORBIDDEN,
             AppError::NotFound(_) => codes::NOT_FOUND,
             AppError::Conflict(_) => codes::CONFLICT,
+            AppError::PreconditionFailed(_) => codes::PRECONDITION_FAILED,
             AppError::PayloadTooLarge(_) => codes::PAYLOAD_TOO_LARGE,
             AppError::PhotoLimit(_) => codes::PHOTO_LIMIT,
             AppError::PrintRequired(_) => codes::PRINT_REQUIRED,
+            AppError::QcRequired(_) => codes::QC_REQUIRED,
+            AppError::PaymentRequired(_) => codes::PAYMENT_REQUIRED,
+            AppError::ApprovalRequired(_) => codes::APPROVAL_REQUIRED,
+            AppError::AccountLocked(_) => codes::ACCOUNT_LOCKED,
             AppError::RateLimited { .. } => codes::RATE_LIMITED,
             AppError::SetupExpired(_) => codes::SETUP_EXPIRED,
             AppError::ServerError(_) => codes::SERVER_ERROR,
+            AppError::ServiceUnavailable { .. } => codes::SERVICE_UNAVAILABLE,
+            AppError::Timeout(_) => codes::TIMEOUT,
         }
     }
 
@@ -90,12 +236,19 @@ impl AppError {
             AppError::Forbidden(_) => StatusCode::FORBIDDEN,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
             AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
             AppError::PhotoLimit(_) => StatusCode::UNPROCESSABLE_ENTITY,
             AppError::PrintRequired(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::QcRequired(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::PaymentRequired(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::ApprovalRequired(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            AppError::AccountLocked(_) => StatusCode::LOCKED,
             AppError::RateLimited { .. } => StatusCode::TOO_MANY_REQUESTS,
             AppError::SetupExpired(_) => StatusCode::FORBIDDEN,
             AppError::ServerError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            AppError::ServiceUnavailable { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
         }
     }
 
@@ -108,84 +261,130 @@ impl AppError {
             | AppError::Forbidden(msg)
             | AppError::NotFound(msg)
             | AppError::Conflict(msg)
+            | AppError::PreconditionFailed(msg)
             | AppError::PayloadTooLarge(msg)
             | AppError::PhotoLimit(msg)
             | AppError::PrintRequired(msg)
+            | AppError::QcRequired(msg)
+            | AppError::PaymentRequired(msg)
+            | AppError::ApprovalRequired(msg)
+            | AppError::AccountLocked(msg)
             | AppError::SetupExpired(msg)
-            | AppError::ServerError(msg) => msg,
-            AppError::RateLimited { message, .. } => message,
+            | AppError::ServerError(msg)
+            | AppError::Timeout(msg) => msg,
+            AppError::RateLimited { message, .. }
+            | AppError::ServiceUnavailable { message, .. } => message,
         }
     }
 
-    /// Get the Retry-After value if this is a rate limited error.
+    /// Get the Retry-After value for rate limited and unavailable errors.
     pub fn retry_after(&self) -> Option<u64> {
         match self {
-            AppError::RateLimited { retry_after, .. } => Some(*retry_after),
+            AppError::RateLimited { retry_after, .. }
+            | AppError::ServiceUnavailable { retry_after, .. } => Some(*retry_after),
             _ => None,
         }
     }
 
     /// Create a validation error.
     pub fn validation(message: impl Into<String>) -> Self {
-        AppError::ValidationError(message.into())
+        AppError::ValidationError(localize(message.into()))
     }
 
     /// Create an invalid PIN error.
     pub fn invalid_pin(message: impl Into<String>) -> Self {
-        AppError::InvalidPin(message.into())
+        AppError::InvalidPin(localize(message.into()))
     }
 
     /// Create an unauthorized error (missing or invalid authentication).
     pub fn unauthorized(message: impl Into<String>) -> Self {
-        AppError::Unauthorized(message.into())
+        AppError::Unauthorized(localize(message.into()))
     }
 
     /// Create a forbidden error.
     pub fn forbidden(message: impl Into<String>) -> Self {
-        AppError::Forbidden(message.into())
+        AppError::Forbidden(localize(message.into()))
     }
 
     /// Create a not found error.
     pub fn not_found(message: impl Into<String>) -> Self {
-        AppError::NotFound(message.into())
+        AppError::NotFound(localize(message.into()))
     }
 
     /// Create a conflict error.
     pub fn conflict(message: impl Into<String>) -> Self {
-        AppError::Conflict(message.into())
+        AppError::Conflict(localize(message.into()))
+    }
+
+    /// Create a precondition failed error (stale If-Match).
+    pub fn precondition_failed(message: impl Into<String>) -> Self {
+        AppError::PreconditionFailed(localize(message.into()))
     }
 
     /// Create a photo limit error.
     pub fn photo_limit(message: impl Into<String>) -> Self {
-        AppError::PhotoLimit(message.into())
+        AppError::PhotoLimit(localize(message.into()))
     }
 
     /// Create a print required error.
     pub fn print_required(message: impl Into<String>) -> Self {
-        AppError::PrintRequired(message.into())
+        AppError::PrintRequired(localize(message.into()))
+    }
+
+    /// Create a QC required error.
+    pub fn qc_required(message: impl Into<String>) -> Self {
+        AppError::QcRequired(localize(message.into()))
+    }
+
+    /// Create a payment required error.
+    pub fn payment_required(message: impl Into<String>) -> Self {
+        AppError::PaymentRequired(localize(message.into()))
+    }
+
+    /// Create an approval required error.
+    pub fn approval_required(message: impl Into<String>) -> Self {
+        AppError::ApprovalRequired(localize(message.into()))
     }
 
     /// Create a payload too large error.
     pub fn payload_too_large(message: impl Into<String>) -> Self {
-        AppError::PayloadTooLarge(message.into())
+        AppError::PayloadTooLarge(localize(message.into()))
     }
 
     /// Create a server error.
     pub fn server_error(message: impl Into<String>) -> Self {
-        AppError::ServerError(message.into())
+        AppError::ServerError(localize(message.into()))
     }
 
     /// Create a rate limited error with Retry-After duration.
     pub fn rate_limited(message: impl Into<String>, retry_after: u64) -> Self {
         AppError::RateLimited {
-            message: message.into(),
+            message: localize(message.into()),
             retry_after,
         }
     }
 
+    /// Create an account locked error.
+    pub fn account_locked(message: impl Into<String>) -> Self {
+        AppError::AccountLocked(localize(message.into()))
+    }
+
     /// Create a setup expired error.
     pub fn setup_expired(message: impl Into<String>) -> Self {
-        AppError::SetupExpired(message.into())
+        AppError::SetupExpired(localize(message.into()))
+    }
+
+    /// Create a service unavailable error with Retry-After duration.
+    pub fn service_unavailable(message: impl Into<String>, retry_after: u64) -> Self {
+        AppError::ServiceUnavailable {
+            message: localize(message.into()),
+            retry_after,
+        }
+    }
+
+    /// Create a timeout error.
+    pub fn timeout(message: impl Into<String>) -> Self {
+        AppError::Timeout(localize(message.into()))
     }
 }
 
@@ -211,6 +410,7 @@ impl IntoResponse for AppError {
             error: ErrorDetail {
                 code: self.code(),
                 message: self.message().to_string(),
+                request_id: RequestId::current().map(|id| id.0),
             },
         };
 
@@ -263,6 +463,10 @@ impl From<sqlx::Error> for AppError {
                 if db_err.code() == Some(std::borrow::Cow::Borrowed("23505")) {
                     return AppError::conflict("A resource with that identifier already exists");
                 }
+                // PostgreSQL query_canceled code: 57014 (statement_timeout)
+                if db_err.code() == Some(std::borrow::Cow::Borrowed("57014")) {
+                    return AppError::timeout("The request took too long and was cancelled");
+                }
                 AppError::server_error("Database error")
             }
             _ => AppError::server_error("Database error"),
@@ -284,6 +488,40 @@ mod tests {
     use http_body_util::BodyExt;
     use serde::Deserialize;
 
+    #[test]
+    fn test_error_catalog_covers_every_variant() {
+        let variants = [
+            AppError::validation(""),
+            AppError::invalid_pin(""),
+            AppError::unauthorized(""),
+            AppError::forbidden(""),
+            AppError::not_found(""),
+            AppError::conflict(""),
+            AppError::precondition_failed(""),
+            AppError::payload_too_large(""),
+            AppError::photo_limit(""),
+            AppError::print_required(""),
+            AppError::qc_required(""),
+            AppError::payment_required(""),
+            AppError::approval_required(""),
+            AppError::account_locked(""),
+            AppError::rate_limited("", 1),
+            AppError::setup_expired(""),
+            AppError::server_error(""),
+            AppError::service_unavailable("", 1),
+            AppError::timeout(""),
+        ];
+        assert_eq!(variants.len(), ERROR_CATALOG.len());
+
+        for err in variants {
+            let entry = ERROR_CATALOG
+                .iter()
+                .find(|e| e.code == err.code())
+                .unwrap_or_else(|| panic!("{} missing from catalog", err.code()));
+            assert_eq!(entry.status, err.status_code().as_u16(), "{}", entry.code);
+        }
+    }
+
     #[derive(Deserialize)]
     struct TestErrorDetail {
         code: String,
@@ -387,6 +625,21 @@ mod tests {
         assert_eq!(body.error.message, "Print receipt before completing intake");
     }
 
+    #[tokio::test]
+    async fn test_qc_required_error_response() {
+        let err = AppError::qc_required("QC checklist must pass before ready for pickup");
+        let response = err.into_response();
+        let (status, body) = extract_error_response(response).await;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.data.is_none());
+        assert_eq!(body.error.code, codes::QC_REQUIRED);
+        assert_eq!(
+            body.error.message,
+            "QC checklist must pass before ready for pickup"
+        );
+    }
+
     #[tokio::test]
     async fn test_server_error_response() {
         let err = AppError::server_error("Internal server error");
@@ -412,15 +665,25 @@ mod tests {
         assert_eq!(AppError::forbidden("").code(), codes::FORBIDDEN);
         assert_eq!(AppError::not_found("").code(), codes::NOT_FOUND);
         assert_eq!(AppError::conflict("").code(), codes::CONFLICT);
+        assert_eq!(
+            AppError::precondition_failed("").code(),
+            codes::PRECONDITION_FAILED
+        );
         assert_eq!(
             AppError::payload_too_large("").code(),
             codes::PAYLOAD_TOO_LARGE
         );
         assert_eq!(AppError::photo_limit("").code(), codes::PHOTO_LIMIT);
         assert_eq!(AppError::print_required("").code(), codes::PRINT_REQUIRED);
+        assert_eq!(AppError::account_locked("").code(), codes::ACCOUNT_LOCKED);
         assert_eq!(AppError::rate_limited("", 60).code(), codes::RATE_LIMITED);
         assert_eq!(AppError::setup_expired("").code(), codes::SETUP_EXPIRED);
         assert_eq!(AppError::server_error("").code(), codes::SERVER_ERROR);
+        assert_eq!(
+            AppError::service_unavailable("", 30).code(),
+            codes::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(AppError::timeout("").code(), codes::TIMEOUT);
     }
 
     #[test]
@@ -436,6 +699,10 @@ mod tests {
         assert_eq!(AppError::forbidden("").status_code(), StatusCode::FORBIDDEN);
         assert_eq!(AppError::not_found("").status_code(), StatusCode::NOT_FOUND);
         assert_eq!(AppError::conflict("").status_code(), StatusCode::CONFLICT);
+        assert_eq!(
+            AppError::precondition_failed("").status_code(),
+            StatusCode::PRECONDITION_FAILED
+        );
         assert_eq!(
             AppError::payload_too_large("").status_code(),
             StatusCode::PAYLOAD_TOO_LARGE
@@ -448,6 +715,10 @@ mod tests {
             AppError::print_required("").status_code(),
             StatusCode::UNPROCESSABLE_ENTITY
         );
+        assert_eq!(
+            AppError::account_locked("").status_code(),
+            StatusCode::LOCKED
+        );
         assert_eq!(
             AppError::rate_limited("", 60).status_code(),
             StatusCode::TOO_MANY_REQUESTS
@@ -460,6 +731,14 @@ mod tests {
             AppError::server_error("").status_code(),
             StatusCode::INTERNAL_SERVER_ERROR
         );
+        assert_eq!(
+            AppError::service_unavailable("", 30).status_code(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
+        assert_eq!(
+            AppError::timeout("").status_code(),
+            StatusCode::GATEWAY_TIMEOUT
+        );
     }
 
     #[tokio::test]
@@ -492,6 +771,9 @@ mod tests {
 
         let err2 = AppError::validation("Test");
         assert_eq!(err2.retry_after(), None);
+
+        let err3 = AppError::service_unavailable("Test", 15);
+        assert_eq!(err3.retry_after(), Some(15));
     }
 
     #[tokio::test]
@@ -1,20 +1,27 @@
 //! Admin request handlers.
 
 use axum::{
-    extract::{ConnectInfo, State},
-    http::HeaderMap,
+    extract::{ConnectInfo, Query, State},
+    http::{header, HeaderMap},
     response::IntoResponse,
     Json,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use std::net::SocketAddr;
 
 use crate::auth::validate_pin_complexity;
 use crate::error::AppError;
-use crate::middleware::extract_client_ip;
+use crate::handlers::audit_log::{record_audit, AuditEvent};
+use crate::handlers::tickets::extract_employee_from_session;
+use crate::middleware::{extract_client_ip, require_permission, ClientIp};
+use crate::models::admin_recovery::{AdminRecoveryAttempt, CreateAdminRecoveryAttempt};
+use crate::models::audit_log::{AuditAction, AuditAuthMethod};
 use crate::models::store_settings::StoreSettingsPublic;
-use crate::repositories::{AdminSessionRepository, StoreSettingsRepository};
+use crate::models::Permission;
+use crate::repositories::{
+    AdminRecoveryRepository, AdminSessionRepository, StoreSettingsRepository,
+};
 use crate::response::ApiResponse;
 use crate::routes::AppState;
 
@@ -87,6 +94,27 @@ pub async fn verify_admin_auth(state: &AppState, headers: &HeaderMap) -> Result<
     ))
 }
 
+/// Verify admin authentication, or an employee session holding `permission`.
+///
+/// Admin credentials are checked whenever they are sent. Without them, the
+/// employee in `X-Employee-Session` must hold the permission, which lets
+/// admins delegate settings and employee management to trusted staff.
+pub async fn verify_permission(
+    state: &AppState,
+    headers: &HeaderMap,
+    permission: Permission,
+) -> Result<(), AppError> {
+    let has_admin_auth =
+        headers.contains_key("X-Admin-Session") || headers.contains_key("X-Admin-PIN");
+    let has_employee_session = headers.contains_key("X-Employee-Session");
+    if has_admin_auth || !has_employee_session {
+        return verify_admin_auth(state, headers).await;
+    }
+
+    let employee = extract_employee_from_session(state, headers).await?;
+    require_permission(&employee, permission)
+}
+
 // =============================================================================
 // POST /admin/setup - Initial Admin Setup
 // =============================================================================
@@ -105,12 +133,16 @@ pub struct AdminSetupRequest {
 pub struct AdminSetupResponse {
     /// The updated store settings
     pub settings: StoreSettingsPublic,
+    /// One-time code for `POST /admin/recover` (shown only once)
+    pub recovery_code: String,
 }
 
 /// POST /api/v1/admin/setup - Initial admin setup (force password change).
 ///
 /// This endpoint is used for first-time setup to change the default admin PIN.
 /// It can only be called once (when setup_complete is false) and within the setup deadline.
+/// The response includes the recovery code for a lost admin PIN; it is not
+/// stored in plain text and can't be shown again.
 ///
 /// # Request Body
 /// - `current_pin`: The current PIN (default: "changeme")
@@ -129,7 +161,7 @@ pub async fn admin_setup(
     Json(body): Json<AdminSetupRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     // Extract client IP for rate limiting
-    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0));
+    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0), &state.trusted_proxies);
 
     // Check rate limit
     if let Err(retry_after) = state.rate_limit.check_rate_limit(client_ip).await {
@@ -186,10 +218,25 @@ pub async fn admin_setup(
     // Mark setup as complete
     let settings = StoreSettingsRepository::mark_setup_complete(&state.db).await?;
 
+    // Issue the break-glass recovery code
+    let mut tx = state.db.begin().await?;
+    let recovery_code = AdminRecoveryRepository::issue_code(&mut tx).await?;
+    tx.commit().await?;
+
     // Record success to reset backoff
     state.rate_limit.record_success(client_ip).await;
-
-    let response = AdminSetupResponse { settings };
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::AdminSetup).authenticated_by(AuditAuthMethod::AdminPin),
+    )
+    .await;
+
+    let response = AdminSetupResponse {
+        settings,
+        recovery_code,
+    };
     Ok(Json(ApiResponse::success(response)))
 }
 
@@ -263,7 +310,7 @@ pub async fn verify_admin(
     Json(body): Json<AdminVerifyRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     // Extract client IP for rate limiting
-    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0));
+    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0), &state.trusted_proxies);
 
     // Check rate limit
     if let Err(retry_after) = state.rate_limit.check_rate_limit(client_ip).await {
@@ -334,6 +381,7 @@ pub async fn verify_admin(
 pub async fn change_pin(
     State(state): State<AppState>,
     headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
     Json(body): Json<ChangePinRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     // Verify admin authentication (session or PIN)
@@ -357,11 +405,258 @@ pub async fn change_pin(
 
     // Change the admin PIN
     let settings = StoreSettingsRepository::change_admin_pin(&state.db, &body.new_pin).await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::AdminPinChanged),
+    )
+    .await;
 
     let response = ChangePinResponse { settings };
     Ok(Json(ApiResponse::success(response)))
 }
 
+// =============================================================================
+// POST /admin/recover - Break-Glass Admin Recovery
+// =============================================================================
+
+/// Wrong recovery codes allowed across all clients in
+/// [`RECOVERY_FAILURE_WINDOW_HOURS`], on top of the per-IP limit.
+const MAX_RECOVERY_FAILURES: i64 = 10;
+
+/// Window for counting failed recovery attempts.
+const RECOVERY_FAILURE_WINDOW_HOURS: i64 = 24;
+
+/// Request body for admin recovery.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecoverAdminRequest {
+    /// The recovery code shown at setup (dashes and case don't matter)
+    pub recovery_code: String,
+    /// The new admin PIN
+    pub new_pin: String,
+}
+
+/// Response for a successful recovery.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoverAdminResponse {
+    /// The updated store settings
+    pub settings: StoreSettingsPublic,
+    /// Replacement recovery code (the used one no longer works)
+    pub recovery_code: String,
+    /// Admin sessions that were signed out
+    pub sessions_revoked: u64,
+}
+
+/// POST /api/v1/admin/recover - Reset a lost admin PIN with the recovery code.
+///
+/// The code is single-use: on success the admin PIN is replaced, every admin
+/// session is signed out, and a new recovery code is returned in its place.
+///
+/// Limited to 3 attempts per hour with exponential backoff on failures, and
+/// to 10 wrong codes per day across all clients (attempts turned away by
+/// these limits don't count toward it). The code is used up together with
+/// the PIN change and the new code, so a failure part way keeps it. Every attempt is
+/// recorded with the client IP and user agent (see
+/// `GET /admin/recovery/attempts`).
+///
+/// # Request Body
+/// - `recovery_code`: The recovery code
+/// - `new_pin`: The new PIN to set
+///
+/// # Errors
+/// - INVALID_PIN: If the recovery code is wrong or already used
+/// - VALIDATION_ERROR: If the new PIN is empty or too weak
+/// - RATE_LIMITED: If too many attempts were made
+pub async fn recover_admin(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    Json(body): Json<RecoverAdminRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0), &state.trusted_proxies);
+    let attempt = |succeeded: bool, failure_reason: Option<&str>| CreateAdminRecoveryAttempt {
+        client_ip: client_ip.to_string(),
+        user_agent: headers
+            .get(header::USER_AGENT)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        succeeded,
+        failure_reason: failure_reason.map(str::to_string),
+    };
+
+    if let Err(retry_after) = state.recovery_rate_limit.check_rate_limit(client_ip).await {
+        AdminRecoveryRepository::record_attempt(&state.db, attempt(false, Some("rate_limited")))
+            .await?;
+        return Err(AppError::rate_limited(
+            "Too many recovery attempts. Please wait before trying again.",
+            retry_after,
+        ));
+    }
+
+    // Cap wrong codes across all clients, so rotating IPs doesn't help
+    let window_start = Utc::now() - Duration::hours(RECOVERY_FAILURE_WINDOW_HOURS);
+    let (failures, oldest) =
+        AdminRecoveryRepository::failures_since(&state.db, window_start).await?;
+    if failures >= MAX_RECOVERY_FAILURES {
+        AdminRecoveryRepository::record_attempt(&state.db, attempt(false, Some("locked_out")))
+            .await?;
+        let retry_after = oldest
+            .map(|at| (at - window_start).num_seconds().max(1) as u64)
+            .unwrap_or(1);
+        return Err(AppError::rate_limited(
+            "Too many recovery attempts. Please wait before trying again.",
+            retry_after,
+        ));
+    }
+
+    // Validate the new PIN before using up the code
+    if body.new_pin.is_empty() {
+        return Err(AppError::validation("New PIN is required"));
+    }
+    let min_pin_length = StoreSettingsRepository::get_min_pin_length(&state.db).await?;
+    let validation_result = validate_pin_complexity(&body.new_pin, min_pin_length);
+    if !validation_result.valid {
+        return Err(AppError::validation(
+            validation_result
+                .error
+                .unwrap_or_else(|| "Invalid PIN".to_string()),
+        ));
+    }
+
+    // Use up the code, change the PIN, and issue the next code together
+    let mut tx = state.db.begin().await?;
+    if !AdminRecoveryRepository::redeem(&mut tx, &body.recovery_code).await? {
+        tx.rollback().await?;
+        state.recovery_rate_limit.record_failure(client_ip).await;
+        AdminRecoveryRepository::record_attempt(
+            &state.db,
+            attempt(false, Some("invalid_recovery_code")),
+        )
+        .await?;
+        tracing::warn!(ip = %client_ip, "Admin recovery attempted with an invalid code");
+        return Err(AppError::invalid_pin("Invalid recovery code"));
+    }
+
+    let settings = StoreSettingsRepository::change_admin_pin(&mut *tx, &body.new_pin).await?;
+    let sessions_revoked = AdminSessionRepository::delete_all(&mut *tx).await?;
+    let recovery_code = AdminRecoveryRepository::issue_code(&mut tx).await?;
+    tx.commit().await?;
+
+    state.recovery_rate_limit.record_success(client_ip).await;
+    AdminRecoveryRepository::record_attempt(&state.db, attempt(true, None)).await?;
+    tracing::warn!(ip = %client_ip, "Admin PIN reset with the recovery code");
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::AdminRecovered)
+            .authenticated_by(AuditAuthMethod::RecoveryCode)
+            .summary(serde_json::json!({ "sessions_revoked": sessions_revoked })),
+    )
+    .await;
+
+    let response = RecoverAdminResponse {
+        settings,
+        recovery_code,
+        sessions_revoked,
+    };
+    Ok(Json(ApiResponse::success(response)))
+}
+
+// =============================================================================
+// POST /admin/recovery/code - Regenerate Recovery Code (Admin Only)
+// =============================================================================
+
+/// Response with a newly issued recovery code.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoveryCodeResponse {
+    /// One-time code for `POST /admin/recover` (shown only once)
+    pub recovery_code: String,
+}
+
+/// POST /api/v1/admin/recovery/code - Issue a new recovery code.
+///
+/// Replaces the current code, for when it was lost or exposed, or for
+/// stores that finished setup before recovery codes existed.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+pub async fn regenerate_recovery_code(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let mut tx = state.db.begin().await?;
+    let recovery_code = AdminRecoveryRepository::issue_code(&mut tx).await?;
+    tx.commit().await?;
+    tracing::info!("Admin recovery code regenerated");
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::RecoveryCodeRegenerated),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(RecoveryCodeResponse {
+        recovery_code,
+    })))
+}
+
+// =============================================================================
+// GET /admin/recovery/attempts - Recovery Audit Log (Admin Only)
+// =============================================================================
+
+/// Default and maximum number of attempts returned.
+const DEFAULT_RECOVERY_ATTEMPTS_LIMIT: i64 = 100;
+const MAX_RECOVERY_ATTEMPTS_LIMIT: i64 = 500;
+
+/// Query parameters for the recovery audit log.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecoveryAttemptsQuery {
+    pub limit: Option<i64>,
+}
+
+/// Response for the recovery audit log.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoveryAttemptsResponse {
+    /// Whether a recovery code is currently set
+    pub recovery_code_set: bool,
+    pub attempts: Vec<AdminRecoveryAttempt>,
+}
+
+/// GET /api/v1/admin/recovery/attempts - List recovery attempts, newest first.
+///
+/// # Query Parameters
+/// - `limit`: Maximum attempts to return (default 100, max 500)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+pub async fn list_recovery_attempts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<RecoveryAttemptsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_RECOVERY_ATTEMPTS_LIMIT)
+        .clamp(1, MAX_RECOVERY_ATTEMPTS_LIMIT);
+    let recovery_code_set = AdminRecoveryRepository::find_live(&state.db)
+        .await?
+        .is_some();
+    let attempts = AdminRecoveryRepository::list_attempts(&state.db, limit).await?;
+
+    Ok(Json(ApiResponse::success(RecoveryAttemptsResponse {
+        recovery_code_set,
+        attempts,
+    })))
+}
+
 // =============================================================================
 // POST /admin/logout - End Admin Session
 // =============================================================================
@@ -442,18 +737,59 @@ mod tests {
                 setup_complete: true,
                 setup_required: false,
                 min_pin_length: 6,
+                qc_checklist: vec![],
+                scope_ticket_visibility: false,
+                custody_value_threshold: None,
+                quote_approval_threshold: None,
+                notifications_enabled: true,
+                auto_archive_after_days: None,
+                restrict_item_types: false,
+                overdue_digest_email: None,
+                overdue_digest_sms: false,
+                overdue_digest_hour: 8,
+                receipt_logo_key: None,
+                receipt_footer_text: None,
+                receipt_show_prices: true,
+                label_width_mm: rust_decimal::Decimal::new(508, 1),
+                label_height_mm: rust_decimal::Decimal::new(254, 1),
+                receipt_format: Default::default(),
+                tax_rate: rust_decimal::Decimal::ZERO,
+                next_invoice_number: 1,
+                tax_inclusive: true,
+                ticket_number_padding: 4,
+                ticket_number_yearly_reset: false,
+                business_hours: vec![],
+                holidays: vec![],
+                rush_turnaround_days: None,
+                timezone: "UTC".to_string(),
+                calendar_feed_enabled: false,
+                version: 1,
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
             },
+            recovery_code: "7K2QX-M9D4R-TB8NC-0WZ5H".to_string(),
         };
 
         let json = serde_json::to_string(&response).unwrap();
         assert!(json.contains("\"setup_complete\":true"));
+        assert!(json.contains("\"recovery_code\":\"7K2QX-M9D4R-TB8NC-0WZ5H\""));
         assert!(json.contains("\"store_name\":\"Test Store\""));
         // Should NOT contain admin_pin_hash
         assert!(!json.contains("admin_pin_hash"));
     }
 
+    #[test]
+    fn test_recover_admin_request_deserialize() {
+        let json = r#"{"recovery_code": "7k2qx-m9d4r-tb8nc-0wz5h", "new_pin": "secure1234"}"#;
+        let request: RecoverAdminRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.recovery_code, "7k2qx-m9d4r-tb8nc-0wz5h");
+        assert_eq!(request.new_pin, "secure1234");
+
+        let result: Result<RecoverAdminRequest, _> =
+            serde_json::from_str(r#"{"new_pin": "secure1234"}"#);
+        assert!(result.is_err());
+    }
+
     // Tests for ChangePinRequest
 
     #[test]
@@ -554,6 +890,33 @@ mod tests {
                 setup_complete: true,
                 setup_required: false,
                 min_pin_length: 6,
+                qc_checklist: vec![],
+                scope_ticket_visibility: false,
+                custody_value_threshold: None,
+                quote_approval_threshold: None,
+                notifications_enabled: true,
+                auto_archive_after_days: None,
+                restrict_item_types: false,
+                overdue_digest_email: None,
+                overdue_digest_sms: false,
+                overdue_digest_hour: 8,
+                receipt_logo_key: None,
+                receipt_footer_text: None,
+                receipt_show_prices: true,
+                label_width_mm: rust_decimal::Decimal::new(508, 1),
+                label_height_mm: rust_decimal::Decimal::new(254, 1),
+                receipt_format: Default::default(),
+                tax_rate: rust_decimal::Decimal::ZERO,
+                next_invoice_number: 1,
+                tax_inclusive: true,
+                ticket_number_padding: 4,
+                ticket_number_yearly_reset: false,
+                business_hours: vec![],
+                holidays: vec![],
+                rush_turnaround_days: None,
+                timezone: "UTC".to_string(),
+                calendar_feed_enabled: false,
+                version: 1,
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
             },
@@ -0,0 +1,342 @@
+//! Appraisal handlers.
+//!
+//! Stores that appraise items record one appraisal per ticket: the appraised
+//! value, metal, stones, certification numbers, and the appraiser. Saving
+//! again revises it. The appraisal PDF is the copy handed to the customer.
+
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::tickets::extract_employee_from_session;
+use crate::middleware::require_ticket_access;
+use crate::models::{
+    CreateFieldHistory, Permission, SaveAppraisal, TicketStatus, MAX_CERTIFICATION_NUMBERS,
+};
+use crate::repositories::{
+    AppraisalRepository, CustomerRepository, FieldHistoryRepository, StoreSettingsRepository,
+    TicketRepository,
+};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::services::pdf::{generate_appraisal_pdf, AppraisalData};
+use crate::validation::{
+    validate_employee, validate_optional, validate_required, MAX_APPRAISAL_METAL_LENGTH,
+    MAX_CERTIFICATION_NUMBER_LENGTH, MAX_DESCRIPTION_LENGTH, MAX_NOTE_LENGTH,
+};
+
+// =============================================================================
+// GET /tickets/:ticket_id/appraisal - Get Appraisal
+// =============================================================================
+
+/// GET /api/v1/tickets/:ticket_id/appraisal - Get a ticket's appraisal.
+///
+/// # Errors
+/// - NOT_FOUND: If the ticket doesn't exist or hasn't been appraised
+pub async fn get_appraisal(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    let appraisal = AppraisalRepository::find_entry_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("appraisal"))?;
+
+    Ok(Json(ApiResponse::success(appraisal)))
+}
+
+// =============================================================================
+// PUT /tickets/:ticket_id/appraisal - Save Appraisal
+// =============================================================================
+
+/// Request body for recording or revising an appraisal.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SaveAppraisalRequest {
+    pub appraised_value: Decimal,
+    pub metal: Option<String>,
+    pub stones: Option<String>,
+    /// Grading report or certificate numbers
+    #[serde(default)]
+    pub certification_numbers: Vec<String>,
+    pub notes: Option<String>,
+    /// Employee who appraised the item (default: the authenticated employee)
+    pub appraiser_id: Option<Uuid>,
+    /// Date of the appraisal (default: today)
+    pub appraised_on: Option<NaiveDate>,
+}
+
+/// PUT /api/v1/tickets/:ticket_id/appraisal - Record or revise a ticket's appraisal.
+///
+/// Replaces the whole appraisal; fields left out are cleared. The value is
+/// recorded in field history for the ticket timeline.
+///
+/// # Errors
+/// - UNAUTHORIZED: If no valid employee session
+/// - FORBIDDEN: If the employee can't modify the ticket, or it is archived
+/// - NOT_FOUND: If the ticket doesn't exist or the appraiser isn't an
+///   active employee
+/// - VALIDATION_ERROR: If a field is out of range
+pub async fn save_appraisal(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<SaveAppraisalRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket and check the employee may modify it
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    require_ticket_access(&employee, &ticket, Permission::ModifyOwnTicket)?;
+    if ticket.status == TicketStatus::Archived {
+        return Err(AppError::forbidden("Cannot appraise an archived ticket"));
+    }
+
+    // 3. Validate the appraisal
+    let input = validate_appraisal(body, ticket_id, employee.employee_id)?;
+    if input.appraiser_id != employee.employee_id {
+        validate_employee(&state.db, input.appraiser_id).await?;
+    }
+
+    // 4. Save it and note the value on the timeline
+    let previous = AppraisalRepository::find_entry_by_ticket_id(&state.db, ticket_id).await?;
+    let appraisal = AppraisalRepository::save(&state.db, input).await?;
+    FieldHistoryRepository::create(
+        &state.db,
+        CreateFieldHistory {
+            ticket_id,
+            field_name: "appraised_value".to_string(),
+            old_value: previous.map(|previous| format!("{:.2}", previous.appraised_value)),
+            new_value: Some(format!("{:.2}", appraisal.appraised_value)),
+            changed_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    let entry = AppraisalRepository::find_entry_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| AppError::server_error("Appraisal disappeared after being saved"))?;
+
+    Ok(Json(ApiResponse::success(entry)))
+}
+
+/// Check an appraisal request, defaulting the appraiser and date.
+fn validate_appraisal(
+    body: SaveAppraisalRequest,
+    ticket_id: Uuid,
+    employee_id: Uuid,
+) -> Result<SaveAppraisal, AppError> {
+    if body.appraised_value < Decimal::ZERO {
+        return Err(AppError::validation("appraised_value cannot be negative"));
+    }
+    let appraised_on = body.appraised_on.unwrap_or_else(|| Utc::now().date_naive());
+    if appraised_on > Utc::now().date_naive() {
+        return Err(AppError::validation("appraised_on cannot be in the future"));
+    }
+    if body.certification_numbers.len() > MAX_CERTIFICATION_NUMBERS {
+        return Err(AppError::validation(format!(
+            "No more than {} certification numbers are allowed",
+            MAX_CERTIFICATION_NUMBERS
+        )));
+    }
+    let certification_numbers = body
+        .certification_numbers
+        .iter()
+        .map(|number| {
+            validate_required(
+                number,
+                "certification_numbers",
+                MAX_CERTIFICATION_NUMBER_LENGTH,
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(SaveAppraisal {
+        ticket_id,
+        appraised_value: body.appraised_value,
+        metal: validate_optional(body.metal.as_deref(), "metal", MAX_APPRAISAL_METAL_LENGTH)?,
+        stones: validate_optional(body.stones.as_deref(), "stones", MAX_DESCRIPTION_LENGTH)?,
+        certification_numbers,
+        notes: validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?,
+        appraiser_id: body.appraiser_id.unwrap_or(employee_id),
+        appraised_on,
+    })
+}
+
+// =============================================================================
+// DELETE /tickets/:ticket_id/appraisal - Delete Appraisal
+// =============================================================================
+
+/// Response for a deleted appraisal.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteAppraisalResponse {
+    /// The ID of the deleted appraisal
+    pub appraisal_id: Uuid,
+    /// The ticket the appraisal belonged to
+    pub ticket_id: Uuid,
+}
+
+/// DELETE /api/v1/tickets/:ticket_id/appraisal - Remove a ticket's appraisal.
+///
+/// # Errors
+/// - UNAUTHORIZED: If no valid employee session
+/// - FORBIDDEN: If the employee can't modify the ticket
+/// - NOT_FOUND: If the ticket doesn't exist or hasn't been appraised
+pub async fn delete_appraisal(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket and check the employee may modify it
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    require_ticket_access(&employee, &ticket, Permission::ModifyOwnTicket)?;
+
+    // 3. Delete the appraisal and note it on the timeline
+    let appraisal = AppraisalRepository::find_entry_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("appraisal"))?;
+    if AppraisalRepository::delete(&state.db, ticket_id).await? {
+        FieldHistoryRepository::create(
+            &state.db,
+            CreateFieldHistory {
+                ticket_id,
+                field_name: "appraised_value".to_string(),
+                old_value: Some(format!("{:.2}", appraisal.appraised_value)),
+                new_value: None,
+                changed_by: employee.employee_id,
+            },
+        )
+        .await?;
+    }
+
+    Ok(Json(ApiResponse::success(DeleteAppraisalResponse {
+        appraisal_id: appraisal.appraisal_id,
+        ticket_id,
+    })))
+}
+
+// =============================================================================
+// GET /tickets/:ticket_id/appraisal.pdf - Appraisal PDF
+// =============================================================================
+
+/// GET /api/v1/tickets/:ticket_id/appraisal.pdf - Generate the appraisal PDF.
+///
+/// # Errors
+/// - NOT_FOUND: If the ticket doesn't exist or hasn't been appraised
+pub async fn get_appraisal_pdf(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    // 1. Find the ticket and its appraisal
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    let appraisal = AppraisalRepository::find_entry_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("appraisal"))?;
+
+    // 2. Find the customer and the info of the store holding the ticket
+    let customer = CustomerRepository::find_by_id(&state.db, ticket.customer_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Customer not found"))?;
+    let settings = StoreSettingsRepository::get_store_settings(&state.db, ticket.store_id).await?;
+
+    // 3. Generate PDF
+    let appraisal_data = AppraisalData {
+        ticket,
+        customer_name: customer.name,
+        appraisal,
+        store_name: settings.store_name,
+        store_phone: settings.store_phone,
+        store_address: settings.store_address,
+    };
+    let pdf_bytes = generate_appraisal_pdf(&appraisal_data)?;
+
+    // 4. Return PDF response
+    let filename = format!("appraisal-{}.pdf", appraisal_data.ticket.friendly_code);
+    let response = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/pdf")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("inline; filename=\"{}\"", filename),
+        )
+        .body(Body::from(pdf_bytes))
+        .map_err(|e| AppError::server_error(format!("Failed to build response: {}", e)))?;
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> SaveAppraisalRequest {
+        SaveAppraisalRequest {
+            appraised_value: Decimal::new(150000, 2),
+            metal: Some("  18k white gold ".to_string()),
+            stones: Some(String::new()),
+            certification_numbers: vec!["GIA 2141438171".to_string()],
+            notes: None,
+            appraiser_id: None,
+            appraised_on: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_appraisal_defaults() {
+        let employee_id = Uuid::new_v4();
+        let input = validate_appraisal(request(), Uuid::nil(), employee_id).unwrap();
+        assert_eq!(input.appraiser_id, employee_id);
+        assert_eq!(input.appraised_on, Utc::now().date_naive());
+        assert_eq!(input.metal.as_deref(), Some("18k white gold"));
+        assert_eq!(input.stones, None);
+    }
+
+    #[test]
+    fn test_validate_appraisal_rejects_bad_input() {
+        let employee_id = Uuid::new_v4();
+        let negative = SaveAppraisalRequest {
+            appraised_value: Decimal::NEGATIVE_ONE,
+            ..request()
+        };
+        assert!(validate_appraisal(negative, Uuid::nil(), employee_id).is_err());
+
+        let future = SaveAppraisalRequest {
+            appraised_on: Some(Utc::now().date_naive() + chrono::Duration::days(2)),
+            ..request()
+        };
+        assert!(validate_appraisal(future, Uuid::nil(), employee_id).is_err());
+
+        let blank_number = SaveAppraisalRequest {
+            certification_numbers: vec!["  ".to_string()],
+            ..request()
+        };
+        assert!(validate_appraisal(blank_number, Uuid::nil(), employee_id).is_err());
+
+        let too_many = SaveAppraisalRequest {
+            certification_numbers: vec!["1".to_string(); MAX_CERTIFICATION_NUMBERS + 1],
+            ..request()
+        };
+        assert!(validate_appraisal(too_many, Uuid::nil(), employee_id).is_err());
+    }
+}
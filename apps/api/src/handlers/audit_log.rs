@@ -0,0 +1,282 @@
+//! Audit log request handlers and recording helper.
+//!
+//! Admin handlers call [`record_audit`] after an action succeeds. The actor
+//! is worked out from the request headers the same way the action was
+//! authorized, so handlers only describe what they did.
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{Duration, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::tickets::{extract_employee_from_session, PaginationInfo};
+use crate::handlers::verify_admin_auth;
+use crate::models::audit_log::{
+    AuditAction, AuditAuthMethod, AuditLogEntry, AuditLogFilter, CreateAuditLogEntry,
+};
+use crate::models::Employee;
+use crate::repositories::AuditLogRepository;
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+
+// =============================================================================
+// Recording
+// =============================================================================
+
+/// An admin action about to be recorded.
+#[derive(Debug, Clone)]
+pub(crate) struct AuditEvent {
+    action: AuditAction,
+    auth_method: Option<AuditAuthMethod>,
+    actor: Option<(Uuid, String)>,
+    target: Option<(&'static str, Uuid)>,
+    summary: serde_json::Value,
+}
+
+impl AuditEvent {
+    pub(crate) fn new(action: AuditAction) -> Self {
+        Self {
+            action,
+            auth_method: None,
+            actor: None,
+            target: None,
+            summary: serde_json::json!({}),
+        }
+    }
+
+    /// The record acted on, e.g. `("employee", employee_id)`.
+    pub(crate) fn target(mut self, target_type: &'static str, target_id: Uuid) -> Self {
+        self.target = Some((target_type, target_id));
+        self
+    }
+
+    /// What changed. Never include PINs, codes, or keys.
+    pub(crate) fn summary(mut self, summary: serde_json::Value) -> Self {
+        self.summary = summary;
+        self
+    }
+
+    /// Override how the actor authenticated, for endpoints that don't use
+    /// the admin or employee headers (setup, recovery).
+    pub(crate) fn authenticated_by(mut self, auth_method: AuditAuthMethod) -> Self {
+        self.auth_method = Some(auth_method);
+        self
+    }
+
+    /// The employee who took the action, when the handler already knows.
+    pub(crate) fn actor(mut self, employee: &Employee) -> Self {
+        self.actor = Some((employee.employee_id, employee.name.clone()));
+        self
+    }
+}
+
+/// How a request authenticated, following the precedence of
+/// `verify_permission`: admin credentials first, then the employee session.
+fn auth_method_from_headers(headers: &HeaderMap) -> AuditAuthMethod {
+    if headers.contains_key("X-Admin-Session") {
+        AuditAuthMethod::AdminSession
+    } else if headers.contains_key("X-Admin-PIN") {
+        AuditAuthMethod::AdminPin
+    } else {
+        AuditAuthMethod::EmployeeSession
+    }
+}
+
+/// Record an admin action that already succeeded.
+///
+/// The action has happened by the time this runs, so a failure to record it
+/// is logged rather than returned to the client.
+pub(crate) async fn record_audit(
+    state: &AppState,
+    headers: &HeaderMap,
+    client_ip: IpAddr,
+    event: AuditEvent,
+) {
+    let actor = match event.actor {
+        Some(actor) => Some(actor),
+        None if headers.contains_key("X-Employee-Session")
+            || headers.contains_key("X-Employee-ID") =>
+        {
+            extract_employee_from_session(state, headers)
+                .await
+                .ok()
+                .map(|employee| (employee.employee_id, employee.name))
+        }
+        None => None,
+    };
+    let (target_type, target_id) = event.target.unzip();
+
+    let input = CreateAuditLogEntry {
+        action: event.action,
+        auth_method: event
+            .auth_method
+            .unwrap_or_else(|| auth_method_from_headers(headers)),
+        actor_employee_id: actor.as_ref().map(|(id, _)| *id),
+        actor_name: actor.map(|(_, name)| name),
+        client_ip: client_ip.to_string(),
+        target_type: target_type.map(String::from),
+        target_id,
+        summary: event.summary,
+    };
+
+    if let Err(err) = AuditLogRepository::record(&state.db, input).await {
+        tracing::error!(action = ?event.action, "Failed to record audit log entry: {:?}", err);
+    }
+}
+
+// =============================================================================
+// GET /admin/audit-log - List Audit Log (Admin Only)
+// =============================================================================
+
+/// Query parameters for the audit log.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuditLogQuery {
+    /// Filter by action
+    pub action: Option<AuditAction>,
+    /// Filter by the employee who took the action
+    pub actor_id: Option<Uuid>,
+    /// Filter by the record acted on
+    pub target_id: Option<Uuid>,
+    /// First day to include (YYYY-MM-DD, UTC)
+    pub from_date: Option<NaiveDate>,
+    /// Last day to include (YYYY-MM-DD, UTC)
+    pub to_date: Option<NaiveDate>,
+    /// Limit results (default: 50, max 200)
+    pub limit: Option<i64>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+}
+
+impl AuditLogQuery {
+    /// Turn the query into repository filters.
+    fn filter(&self) -> Result<AuditLogFilter, AppError> {
+        if let (Some(from), Some(to)) = (self.from_date, self.to_date) {
+            if from > to {
+                return Err(AppError::validation(
+                    "from_date must be on or before to_date",
+                ));
+            }
+        }
+
+        Ok(AuditLogFilter {
+            action: self.action,
+            actor_employee_id: self.actor_id,
+            target_id: self.target_id,
+            from: self
+                .from_date
+                .map(|date| date.and_time(NaiveTime::MIN).and_utc()),
+            to: self.to_date.map(|date| {
+                (date + Duration::days(1))
+                    .and_time(NaiveTime::MIN)
+                    .and_utc()
+            }),
+        })
+    }
+}
+
+/// Response for the audit log.
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditLogResponse {
+    pub entries: Vec<AuditLogEntry>,
+    pub pagination: PaginationInfo,
+}
+
+/// GET /api/v1/admin/audit-log - List admin actions, newest first.
+///
+/// # Query Parameters
+/// - `action`: Only this action
+/// - `actor_id`: Only actions by this employee
+/// - `target_id`: Only actions on this record
+/// - `from_date`, `to_date`: Inclusive date range (UTC)
+/// - `limit`, `offset`: Pagination (default 50, max 200)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from_date is after to_date
+pub async fn list_audit_log(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<AuditLogQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let filter = query.filter()?;
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    // Load one extra entry to determine has_more
+    let entries = AuditLogRepository::list(&state.db, &filter, limit + 1, offset).await?;
+
+    let has_more = entries.len() as i64 > limit;
+    let entries: Vec<AuditLogEntry> = entries.into_iter().take(limit as usize).collect();
+
+    Ok(Json(ApiResponse::success(AuditLogResponse {
+        pagination: PaginationInfo {
+            count: entries.len(),
+            limit,
+            offset,
+            has_more,
+            next_cursor: None,
+        },
+        entries,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query() -> AuditLogQuery {
+        AuditLogQuery {
+            action: None,
+            actor_id: None,
+            target_id: None,
+            from_date: None,
+            to_date: None,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    #[test]
+    fn test_audit_log_filter_includes_whole_to_date() {
+        let mut query = query();
+        query.from_date = NaiveDate::from_ymd_opt(2026, 3, 1);
+        query.to_date = NaiveDate::from_ymd_opt(2026, 3, 1);
+        let filter = query.filter().unwrap();
+        assert_eq!(
+            filter.from.unwrap().to_rfc3339(),
+            "2026-03-01T00:00:00+00:00"
+        );
+        assert_eq!(filter.to.unwrap().to_rfc3339(), "2026-03-02T00:00:00+00:00");
+
+        query.from_date = NaiveDate::from_ymd_opt(2026, 3, 2);
+        assert!(query.filter().is_err());
+    }
+
+    #[test]
+    fn test_auth_method_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(
+            auth_method_from_headers(&headers),
+            AuditAuthMethod::EmployeeSession
+        );
+        headers.insert("X-Admin-PIN", "1234".parse().unwrap());
+        assert_eq!(
+            auth_method_from_headers(&headers),
+            AuditAuthMethod::AdminPin
+        );
+        headers.insert("X-Admin-Session", "token".parse().unwrap());
+        assert_eq!(
+            auth_method_from_headers(&headers),
+            AuditAuthMethod::AdminSession
+        );
+    }
+}
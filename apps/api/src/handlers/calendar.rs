@@ -0,0 +1,73 @@
+//! Calendar feed request handlers.
+
+use axum::{
+    extract::{Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::error::AppError;
+use crate::repositories::{StoreSettingsRepository, TicketRepository};
+use crate::routes::AppState;
+use crate::services::ical::promise_date_feed;
+
+// =============================================================================
+// GET /calendar/promise-dates.ics - Promise Date Feed
+// =============================================================================
+
+/// Query parameters for the promise date feed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CalendarFeedQuery {
+    /// Feed token from `POST /settings/calendar-feed`
+    pub token: Option<String>,
+}
+
+/// GET /api/v1/calendar/promise-dates.ics - iCalendar feed of promise dates.
+///
+/// Authorized by the store's feed token rather than a session, so calendar
+/// apps can subscribe to the URL. Each open ticket with a promise date is an
+/// all-day event summarized by its code and customer name. An unknown or
+/// revoked token and a closed store return the same error.
+///
+/// # Query Parameters
+/// - `token`: Feed token (required)
+///
+/// # Errors
+/// - VALIDATION_ERROR: If token is missing
+/// - NOT_FOUND: If the token doesn't unlock a feed
+pub async fn get_promise_date_feed(
+    State(state): State<AppState>,
+    Query(query): Query<CalendarFeedQuery>,
+) -> Result<Response, AppError> {
+    // 1. Find the store the token belongs to
+    let token = query
+        .token
+        .as_deref()
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| AppError::validation("token is required"))?;
+    let (store_id, store_name) =
+        StoreSettingsRepository::find_store_by_calendar_feed_token(&state.db, token)
+            .await?
+            .ok_or_else(|| state.probe_policy.missing("calendar feed"))?;
+
+    // 2. Render its open tickets' promise dates
+    let entries = TicketRepository::list_promise_dates(&state.db, store_id).await?;
+    let calendar = promise_date_feed(&store_name, &entries, Utc::now());
+
+    Ok((
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "inline; filename=\"promise-dates.ics\"",
+            ),
+            (header::CACHE_CONTROL, "private, max-age=300"),
+        ],
+        calendar,
+    )
+        .into_response())
+}
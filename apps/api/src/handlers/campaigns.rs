@@ -0,0 +1,374 @@
+//! Customer campaign request handlers (admin only).
+//!
+//! Admins preview a message against a customer segment, then create a
+//! campaign, which captures the recipients and hands them to the background
+//! sender. Progress is read per campaign and per recipient, and a running
+//! campaign can be aborted at any time.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::audit_log::{record_audit, AuditEvent};
+use crate::handlers::tickets::admin_actor;
+use crate::handlers::verify_admin_auth;
+use crate::middleware::ClientIp;
+use crate::models::audit_log::AuditAction;
+use crate::models::{
+    CampaignRecipient, CampaignRecipientStatus, CampaignSegment, CampaignSummary, CreateCampaign,
+    NotificationChannel, DEFAULT_CAMPAIGN_SEND_RATE,
+};
+use crate::repositories::{CampaignRepository, StoreSettingsRepository};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::services::campaigns::unsupported_placeholders;
+use crate::services::notifications::TemplateContext;
+use crate::validation::{
+    validate_optional, validate_required, CAMPAIGN_SEND_RATE_RANGE, MAX_ITEM_TYPE_LENGTH,
+    MAX_NAME_LENGTH, MAX_TEMPLATE_BODY_LENGTH,
+};
+
+/// Customer name used when previewing a campaign message.
+const PREVIEW_CUSTOMER_NAME: &str = "Jane Doe";
+
+/// Request body for previewing or creating a campaign.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CampaignRequest {
+    /// Campaign name, for staff (required)
+    pub name: String,
+    /// Channel to send on
+    pub channel: NotificationChannel,
+    /// Email subject template (required for email, ignored for SMS)
+    pub subject: Option<String>,
+    /// Message template; may use {customer_name}, {store_name}, {store_phone}
+    pub body: String,
+    /// Which customers to send to (default: every reachable customer)
+    #[serde(default)]
+    pub segment: CampaignSegment,
+    /// Messages per minute (default: 30)
+    pub send_rate_per_minute: Option<i32>,
+}
+
+/// A rendered campaign message and how many customers it would reach.
+#[derive(Debug, Clone, Serialize)]
+pub struct CampaignPreviewResponse {
+    /// Customers the campaign would be sent to
+    pub recipients: i64,
+    /// Rendered email subject (None for SMS)
+    pub subject: Option<String>,
+    /// Message rendered for a sample customer
+    pub message: String,
+}
+
+/// Response for listing campaigns.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListCampaignsResponse {
+    pub campaigns: Vec<CampaignSummary>,
+}
+
+/// Query parameters for listing a campaign's recipients.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListRecipientsQuery {
+    pub status: Option<CampaignRecipientStatus>,
+}
+
+/// Response for listing a campaign's recipients.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListRecipientsResponse {
+    pub recipients: Vec<CampaignRecipient>,
+}
+
+/// Validate a template against the placeholders campaigns can fill in.
+fn validate_campaign_template(template: &str) -> Result<(), AppError> {
+    match unsupported_placeholders(template).first() {
+        Some(placeholder) => Err(AppError::validation(format!(
+            "Placeholder {{{}}} is not available in campaigns",
+            placeholder
+        ))),
+        None => Ok(()),
+    }
+}
+
+impl CampaignRequest {
+    /// Validate the request into a campaign attributed to `created_by`.
+    fn into_campaign(self, created_by: Uuid) -> Result<CreateCampaign, AppError> {
+        let name = validate_required(&self.name, "name", MAX_NAME_LENGTH)?;
+        let body = validate_required(&self.body, "body", MAX_TEMPLATE_BODY_LENGTH)?;
+        validate_campaign_template(&body)?;
+
+        let subject = match self.channel {
+            NotificationChannel::Email => {
+                let subject = validate_required(
+                    self.subject.as_deref().unwrap_or(""),
+                    "subject",
+                    MAX_NAME_LENGTH,
+                )?;
+                validate_campaign_template(&subject)?;
+                Some(subject)
+            }
+            NotificationChannel::Sms => None,
+        };
+
+        let mut segment = self.segment;
+        segment.item_type = validate_optional(
+            segment.item_type.as_deref(),
+            "item_type",
+            MAX_ITEM_TYPE_LENGTH,
+        )?;
+        if let (Some(from), Some(to)) = (segment.closed_from, segment.closed_to) {
+            if from > to {
+                return Err(AppError::validation(
+                    "closed_from must be on or before closed_to",
+                ));
+            }
+        }
+
+        let send_rate_per_minute = self
+            .send_rate_per_minute
+            .unwrap_or(DEFAULT_CAMPAIGN_SEND_RATE);
+        if !CAMPAIGN_SEND_RATE_RANGE.contains(&send_rate_per_minute) {
+            return Err(AppError::validation(format!(
+                "send_rate_per_minute must be between {} and {}",
+                CAMPAIGN_SEND_RATE_RANGE.start(),
+                CAMPAIGN_SEND_RATE_RANGE.end()
+            )));
+        }
+
+        Ok(CreateCampaign {
+            name,
+            channel: self.channel,
+            subject,
+            body,
+            segment,
+            send_rate_per_minute,
+            created_by,
+        })
+    }
+}
+
+/// Find a campaign with its progress, or fail with not found.
+async fn find_campaign(state: &AppState, campaign_id: Uuid) -> Result<CampaignSummary, AppError> {
+    CampaignRepository::find_summary(&state.db, campaign_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("campaign"))
+}
+
+/// POST /api/v1/admin/campaigns/preview - Count recipients and render the message.
+///
+/// Validates the request exactly as creating the campaign would.
+pub async fn preview_campaign(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<CampaignRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+    let admin = admin_actor(&state, &headers).await?;
+    let campaign = body.into_campaign(admin.employee_id)?;
+
+    let recipients =
+        CampaignRepository::count_segment(&state.db, campaign.channel, &campaign.segment).await?;
+    let settings = StoreSettingsRepository::get_settings(&state.db).await?;
+    let context = TemplateContext::for_customer(
+        PREVIEW_CUSTOMER_NAME.to_string(),
+        settings.store_name,
+        settings.store_phone,
+    );
+
+    Ok(Json(ApiResponse::success(CampaignPreviewResponse {
+        recipients,
+        subject: campaign.subject.as_deref().map(|s| context.render(s)),
+        message: context.render(&campaign.body),
+    })))
+}
+
+/// POST /api/v1/admin/campaigns - Create a campaign and queue it for sending.
+///
+/// Recipients are the customers in the segment right now; customers who
+/// opt out before their message is sent are skipped.
+pub async fn create_campaign(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<CampaignRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication
+    verify_admin_auth(&state, &headers).await?;
+    let admin = admin_actor(&state, &headers).await?;
+
+    // 2. Validate input
+    let input = body.into_campaign(admin.employee_id)?;
+
+    // 3. Refuse a campaign nobody would receive
+    let recipients =
+        CampaignRepository::count_segment(&state.db, input.channel, &input.segment).await?;
+    if recipients == 0 {
+        return Err(AppError::validation("No customers match this segment"));
+    }
+
+    // 4. Create the campaign and its recipients
+    let campaign = CampaignRepository::create(&state.db, input).await?;
+    let summary = find_campaign(&state, campaign.campaign_id).await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::CampaignCreated)
+            .actor(&admin)
+            .target("campaign", campaign.campaign_id)
+            .summary(serde_json::json!({
+                "name": campaign.name,
+                "channel": campaign.channel,
+                "recipients": summary.progress.total,
+            })),
+    )
+    .await;
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(summary))))
+}
+
+/// GET /api/v1/admin/campaigns - List campaigns with progress, newest first.
+pub async fn list_campaigns(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let campaigns = CampaignRepository::list(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(ListCampaignsResponse {
+        campaigns,
+    })))
+}
+
+/// GET /api/v1/admin/campaigns/:campaign_id - Get a campaign with progress.
+pub async fn get_campaign(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(campaign_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let campaign = find_campaign(&state, campaign_id).await?;
+
+    Ok(Json(ApiResponse::success(campaign)))
+}
+
+/// GET /api/v1/admin/campaigns/:campaign_id/recipients - List delivery status per customer.
+pub async fn list_campaign_recipients(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(campaign_id): Path<Uuid>,
+    Query(query): Query<ListRecipientsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    find_campaign(&state, campaign_id).await?;
+    let recipients =
+        CampaignRepository::list_recipients(&state.db, campaign_id, query.status).await?;
+
+    Ok(Json(ApiResponse::success(ListRecipientsResponse {
+        recipients,
+    })))
+}
+
+/// POST /api/v1/admin/campaigns/:campaign_id/abort - Stop sending a campaign.
+///
+/// Unsent recipients are cancelled. A message already handed to the
+/// provider can't be recalled.
+pub async fn abort_campaign(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(campaign_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+    let admin = admin_actor(&state, &headers).await?;
+
+    find_campaign(&state, campaign_id).await?;
+    if CampaignRepository::abort(&state.db, campaign_id, admin.employee_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::conflict("Campaign has already finished"));
+    }
+
+    let campaign = find_campaign(&state, campaign_id).await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::CampaignAborted)
+            .actor(&admin)
+            .target("campaign", campaign_id)
+            .summary(serde_json::json!({
+                "name": campaign.campaign.name,
+                "sent": campaign.progress.sent,
+                "cancelled": campaign.progress.cancelled,
+            })),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(campaign)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(channel: NotificationChannel) -> CampaignRequest {
+        CampaignRequest {
+            name: "Holiday cleaning".to_string(),
+            channel,
+            subject: None,
+            body: "Hi {customer_name}, {store_name} is offering free cleaning.".to_string(),
+            segment: CampaignSegment::default(),
+            send_rate_per_minute: None,
+        }
+    }
+
+    #[test]
+    fn test_sms_campaign_defaults() {
+        let mut body = request(NotificationChannel::Sms);
+        body.subject = Some("Ignored".to_string());
+        let campaign = body.into_campaign(Uuid::new_v4()).unwrap();
+        assert_eq!(campaign.subject, None);
+        assert_eq!(campaign.send_rate_per_minute, DEFAULT_CAMPAIGN_SEND_RATE);
+    }
+
+    #[test]
+    fn test_email_campaign_requires_subject() {
+        assert!(request(NotificationChannel::Email)
+            .into_campaign(Uuid::new_v4())
+            .is_err());
+
+        let mut body = request(NotificationChannel::Email);
+        body.subject = Some("News from {store_name}".to_string());
+        assert!(body.into_campaign(Uuid::new_v4()).is_ok());
+    }
+
+    #[test]
+    fn test_campaign_rejects_ticket_placeholders() {
+        let mut body = request(NotificationChannel::Sms);
+        body.body = "Ticket {friendly_code} is ready".to_string();
+        let err = body.into_campaign(Uuid::new_v4()).unwrap_err();
+        assert!(err.to_string().contains("{friendly_code}"));
+    }
+
+    #[test]
+    fn test_campaign_validates_segment_and_rate() {
+        let mut body = request(NotificationChannel::Sms);
+        body.segment.closed_from = chrono::NaiveDate::from_ymd_opt(2026, 2, 1);
+        body.segment.closed_to = chrono::NaiveDate::from_ymd_opt(2026, 1, 1);
+        assert!(body.into_campaign(Uuid::new_v4()).is_err());
+
+        let mut body = request(NotificationChannel::Sms);
+        body.send_rate_per_minute = Some(0);
+        assert!(body.into_campaign(Uuid::new_v4()).is_err());
+    }
+}
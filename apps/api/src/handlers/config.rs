@@ -0,0 +1,566 @@
+//! Store configuration export/import handlers.
+//!
+//! The configuration bundle is a single JSON document holding everything an
+//! admin sets up for a store, so a new store can be provisioned from an
+//! existing one or a configuration moved between environments.
+
+use axum::{extract::State, http::HeaderMap, response::IntoResponse, Json};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::audit_log::{record_audit, AuditEvent};
+use crate::handlers::locations::validate_capacity;
+use crate::handlers::settings::{
+    apply_settings_update, validate_item_types, validate_location_rules, validate_metal_prices,
+    validate_notification_template, validate_promise_date_reasons, validate_rush_tiers,
+    validate_settings_update,
+};
+use crate::handlers::verify_admin_auth;
+use crate::middleware::{ClientIp, CurrentStore};
+use crate::models::audit_log::AuditAction;
+use crate::models::item_type::CreateItemType;
+use crate::models::metal_price::CreateMetalPrice;
+use crate::models::notification::{NotificationEvent, UpdateNotificationTemplate};
+use crate::models::promise_date_reason::CreatePromiseDateReason;
+use crate::models::rush_pricing::CreateRushSurchargeTier;
+use crate::models::storage_location::{
+    CreateStorageLocation, CreateStorageLocationRule, UpdateStorageLocation,
+};
+use crate::models::store_settings::{SettingsSection, StoreSettingsPublic, UpdateStoreSettings};
+use crate::repositories::{
+    ItemTypeRepository, MetalPriceRepository, NotificationTemplateRepository,
+    PromiseDateReasonRepository, RushPricingRepository, StorageLocationRepository,
+    StoreSettingsRepository,
+};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::validation::{validate_required, MAX_NAME_LENGTH};
+
+/// Current configuration bundle format.
+pub const CONFIG_FORMAT_VERSION: u32 = 1;
+
+/// Full store configuration.
+///
+/// Every part is optional on import; parts that are left out are not touched.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigBundle {
+    pub format_version: u32,
+    #[serde(default, skip_deserializing)]
+    pub exported_at: Option<DateTime<Utc>>,
+    /// Editable store settings, keyed by field name
+    #[serde(default)]
+    pub settings: Option<Map<String, Value>>,
+    #[serde(default)]
+    pub storage_locations: Option<Vec<ConfigLocation>>,
+    #[serde(default)]
+    pub location_rules: Option<Vec<ConfigLocationRule>>,
+    #[serde(default)]
+    pub metal_prices: Option<Vec<CreateMetalPrice>>,
+    #[serde(default)]
+    pub rush_surcharge_tiers: Option<Vec<CreateRushSurchargeTier>>,
+    #[serde(default)]
+    pub item_types: Option<Vec<CreateItemType>>,
+    #[serde(default)]
+    pub promise_date_reasons: Option<Vec<CreatePromiseDateReason>>,
+    #[serde(default)]
+    pub notification_templates: Option<Vec<ConfigNotificationTemplate>>,
+}
+
+/// A storage location, matched by name on import.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigLocation {
+    pub name: String,
+    #[serde(default = "default_true")]
+    pub is_active: bool,
+    /// Open tickets the location holds before intake warns; absent means unlimited
+    #[serde(default)]
+    pub capacity: Option<i32>,
+}
+
+/// A storage suggestion rule that refers to its location by name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigLocationRule {
+    pub item_type: Option<String>,
+    pub min_quote_amount: Option<Decimal>,
+    pub location: String,
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// A customer notification template.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigNotificationTemplate {
+    pub event: NotificationEvent,
+    pub subject: String,
+    pub body: String,
+    pub is_enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+// =============================================================================
+// GET /admin/config/export - Export Store Configuration (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/admin/config/export - Export the store configuration (admin only).
+///
+/// Returns settings, storage locations, location rules, metal prices, rush
+/// surcharge tiers, item types, promise date reasons, and notification
+/// templates as one bundle
+/// that `POST /admin/config/import` accepts. Employees, PINs, and tickets are
+/// never included.
+pub async fn export_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let settings = StoreSettingsRepository::get_settings_public(&state.db, store_id).await?;
+    let locations = StorageLocationRepository::list(&state.db, store_id, true).await?;
+    let rules = StorageLocationRepository::list_rules(&state.db).await?;
+    let metal_prices = MetalPriceRepository::list(&state.db).await?;
+    let rush_tiers = RushPricingRepository::list(&state.db).await?;
+    let item_types = ItemTypeRepository::list(&state.db).await?;
+    let reasons = PromiseDateReasonRepository::list(&state.db).await?;
+    let templates = NotificationTemplateRepository::list(&state.db).await?;
+
+    let location_names: HashMap<_, _> = locations
+        .iter()
+        .map(|location| (location.location_id, location.name.clone()))
+        .collect();
+
+    let bundle = ConfigBundle {
+        format_version: CONFIG_FORMAT_VERSION,
+        exported_at: Some(Utc::now()),
+        settings: Some(settings_fields(&settings)),
+        storage_locations: Some(
+            locations
+                .into_iter()
+                .map(|location| ConfigLocation {
+                    name: location.name,
+                    is_active: location.is_active,
+                    capacity: location.capacity,
+                })
+                .collect(),
+        ),
+        location_rules: Some(
+            rules
+                .into_iter()
+                .filter_map(|rule| {
+                    Some(ConfigLocationRule {
+                        location: location_names.get(&rule.location_id)?.clone(),
+                        item_type: rule.item_type,
+                        min_quote_amount: rule.min_quote_amount,
+                        priority: rule.priority,
+                    })
+                })
+                .collect(),
+        ),
+        metal_prices: Some(
+            metal_prices
+                .into_iter()
+                .map(|price| CreateMetalPrice {
+                    metal_type: price.metal_type,
+                    purity: price.purity,
+                    price_per_gram: price.price_per_gram,
+                })
+                .collect(),
+        ),
+        rush_surcharge_tiers: Some(
+            rush_tiers
+                .into_iter()
+                .map(|tier| CreateRushSurchargeTier {
+                    max_lead_days: tier.max_lead_days,
+                    kind: tier.kind,
+                    amount: tier.amount,
+                })
+                .collect(),
+        ),
+        item_types: Some(
+            item_types
+                .into_iter()
+                .map(|item_type| CreateItemType {
+                    name: item_type.name,
+                    default_turnaround_days: item_type.default_turnaround_days,
+                    required_photos: item_type.required_photos,
+                    condition_checklist: item_type.condition_checklist,
+                    suggested_services: item_type.suggested_services,
+                    base_price: item_type.base_price,
+                })
+                .collect(),
+        ),
+        promise_date_reasons: Some(
+            reasons
+                .into_iter()
+                .map(|reason| CreatePromiseDateReason {
+                    code: reason.code,
+                    label: reason.label,
+                })
+                .collect(),
+        ),
+        notification_templates: Some(
+            templates
+                .into_iter()
+                .map(|template| ConfigNotificationTemplate {
+                    event: template.event,
+                    subject: template.subject,
+                    body: template.body,
+                    is_enabled: template.is_enabled,
+                })
+                .collect(),
+        ),
+    };
+
+    Ok(Json(ApiResponse::success(bundle)))
+}
+
+/// Every editable settings field, merged across sections.
+fn settings_fields(settings: &StoreSettingsPublic) -> Map<String, Value> {
+    SettingsSection::ALL
+        .iter()
+        .filter_map(|section| match section.view(settings) {
+            Value::Object(fields) => Some(fields),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+// =============================================================================
+// POST /admin/config/import - Import Store Configuration (Admin Only)
+// =============================================================================
+
+/// What an import changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConfigImportResponse {
+    /// Whether any store setting changed
+    pub settings_changed: bool,
+    pub locations_created: usize,
+    pub locations_updated: usize,
+    /// Number of location rules now in place (None if not imported)
+    pub location_rules: Option<usize>,
+    /// Number of metal prices now in place (None if not imported)
+    pub metal_prices: Option<usize>,
+    /// Number of rush surcharge tiers now in place (None if not imported)
+    pub rush_surcharge_tiers: Option<usize>,
+    /// Number of item types now in place (None if not imported)
+    pub item_types: Option<usize>,
+    /// Number of promise date reasons now in place (None if not imported)
+    pub promise_date_reasons: Option<usize>,
+    pub notification_templates_updated: usize,
+}
+
+/// POST /api/v1/admin/config/import - Import a store configuration (admin only).
+///
+/// Accepts a bundle from `GET /admin/config/export`. Each part present in the
+/// bundle is applied; missing parts are left alone. Storage locations are
+/// matched by name (created if new, never deleted); location rules, metal
+/// prices, rush surcharge tiers, item types, and promise date reasons replace
+/// the current sets; templates are updated per event. The whole bundle is
+/// validated before anything is written.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If the format version is unsupported or any part is invalid
+pub async fn import_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    CurrentStore(store_id): CurrentStore,
+    Json(bundle): Json<ConfigBundle>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication (session or PIN)
+    verify_admin_auth(&state, &headers).await?;
+
+    if bundle.format_version != CONFIG_FORMAT_VERSION {
+        return Err(AppError::validation(format!(
+            "Unsupported config format_version {}; expected {}",
+            bundle.format_version, CONFIG_FORMAT_VERSION
+        )));
+    }
+
+    // 2. Validate every part before writing anything
+    let settings = bundle
+        .settings
+        .map(|fields| {
+            serde_json::from_value::<UpdateStoreSettings>(Value::Object(fields))
+                .map_err(|err| AppError::validation(format!("Invalid settings: {}", err)))
+                .and_then(validate_settings_update)
+        })
+        .transpose()?;
+    let locations = bundle
+        .storage_locations
+        .map(validate_config_locations)
+        .transpose()?;
+    let rules = match bundle.location_rules {
+        Some(rules) => {
+            Some(resolve_rule_targets(&state, store_id, rules, locations.as_deref()).await?)
+        }
+        None => None,
+    };
+    let metal_prices = bundle.metal_prices.map(validate_metal_prices).transpose()?;
+    let rush_tiers = bundle
+        .rush_surcharge_tiers
+        .map(validate_rush_tiers)
+        .transpose()?;
+    let item_types = bundle.item_types.map(validate_item_types).transpose()?;
+    let reasons = bundle
+        .promise_date_reasons
+        .map(validate_promise_date_reasons)
+        .transpose()?;
+    let templates = bundle
+        .notification_templates
+        .map(|templates| {
+            templates
+                .into_iter()
+                .map(|template| {
+                    validate_notification_template(UpdateNotificationTemplate {
+                        subject: Some(template.subject),
+                        body: Some(template.body),
+                        is_enabled: Some(template.is_enabled),
+                    })
+                    .map(|input| (template.event, input))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()?;
+
+    let mut response = ConfigImportResponse::default();
+
+    // 3. Apply settings (recorded in the settings history)
+    if let Some(settings) = settings {
+        let (_, change) =
+            apply_settings_update(&state, &headers, store_id, settings, None, None).await?;
+        response.settings_changed = change.is_some();
+    }
+
+    // 4. Create or update storage locations by name
+    for location in locations.unwrap_or_default() {
+        match StorageLocationRepository::find_by_name(&state.db, store_id, &location.name).await? {
+            Some(existing) => {
+                if existing.is_active != location.is_active
+                    || existing.capacity != location.capacity
+                {
+                    StorageLocationRepository::update(
+                        &state.db,
+                        existing.location_id,
+                        UpdateStorageLocation {
+                            name: None,
+                            is_active: Some(location.is_active),
+                            capacity: Some(location.capacity),
+                        },
+                    )
+                    .await?;
+                    response.locations_updated += 1;
+                }
+            }
+            None => {
+                let created = StorageLocationRepository::create(
+                    &state.db,
+                    store_id,
+                    CreateStorageLocation {
+                        name: location.name,
+                        capacity: location.capacity,
+                    },
+                )
+                .await?;
+                response.locations_created += 1;
+                if !location.is_active {
+                    StorageLocationRepository::update(
+                        &state.db,
+                        created.location_id,
+                        UpdateStorageLocation {
+                            name: None,
+                            is_active: Some(false),
+                            capacity: None,
+                        },
+                    )
+                    .await?;
+                }
+            }
+        }
+    }
+
+    // 5. Replace location rules, now that every target location exists
+    if let Some(rules) = rules {
+        let mut resolved = Vec::with_capacity(rules.len());
+        for (location_name, rule) in rules {
+            let location =
+                StorageLocationRepository::find_by_name(&state.db, store_id, &location_name)
+                    .await?
+                    .ok_or_else(|| unknown_rule_location(&location_name))?;
+            resolved.push(CreateStorageLocationRule {
+                location_id: location.location_id,
+                ..rule
+            });
+        }
+        let rules = StorageLocationRepository::replace_rules(&state.db, resolved).await?;
+        response.location_rules = Some(rules.len());
+    }
+
+    // 6. Replace metal prices
+    if let Some(prices) = metal_prices {
+        let prices = MetalPriceRepository::replace_all(&state.db, prices).await?;
+        response.metal_prices = Some(prices.len());
+    }
+
+    // 7. Replace rush surcharge tiers
+    if let Some(tiers) = rush_tiers {
+        let tiers = RushPricingRepository::replace_all(&state.db, tiers).await?;
+        response.rush_surcharge_tiers = Some(tiers.len());
+    }
+
+    // 8. Replace item types
+    if let Some(item_types) = item_types {
+        let item_types = ItemTypeRepository::replace_all(&state.db, item_types).await?;
+        response.item_types = Some(item_types.len());
+    }
+
+    // 9. Replace promise date reasons
+    if let Some(reasons) = reasons {
+        let reasons = PromiseDateReasonRepository::replace_all(&state.db, reasons).await?;
+        response.promise_date_reasons = Some(reasons.len());
+    }
+
+    // 10. Update notification templates
+    for (event, input) in templates.unwrap_or_default() {
+        if NotificationTemplateRepository::update(&state.db, event, input)
+            .await?
+            .is_some()
+        {
+            response.notification_templates_updated += 1;
+        }
+    }
+
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::ConfigImported)
+            .summary(serde_json::to_value(&response).unwrap_or_default()),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// Validate bundle locations; names must be unique (case-insensitive).
+fn validate_config_locations(
+    locations: Vec<ConfigLocation>,
+) -> Result<Vec<ConfigLocation>, AppError> {
+    let mut validated: Vec<ConfigLocation> = Vec::with_capacity(locations.len());
+    for location in locations {
+        let name = validate_required(&location.name, "name", MAX_NAME_LENGTH)?;
+        validate_capacity(location.capacity)?;
+        if validated
+            .iter()
+            .any(|other| other.name.eq_ignore_ascii_case(&name))
+        {
+            return Err(AppError::validation(format!(
+                "Duplicate storage location: {}",
+                name
+            )));
+        }
+        validated.push(ConfigLocation { name, ..location });
+    }
+    Ok(validated)
+}
+
+/// Validate bundle rules and check each target is an active location, either
+/// in the bundle or already in the store.
+///
+/// Returns each rule paired with its location name; IDs are resolved once the
+/// bundle's locations have been created.
+async fn resolve_rule_targets(
+    state: &AppState,
+    store_id: Uuid,
+    rules: Vec<ConfigLocationRule>,
+    locations: Option<&[ConfigLocation]>,
+) -> Result<Vec<(String, CreateStorageLocationRule)>, AppError> {
+    let names: Vec<String> = rules.iter().map(|rule| rule.location.clone()).collect();
+    let validated = validate_location_rules(
+        rules
+            .into_iter()
+            .map(|rule| CreateStorageLocationRule {
+                item_type: rule.item_type,
+                min_quote_amount: rule.min_quote_amount,
+                location_id: uuid::Uuid::nil(),
+                priority: rule.priority,
+            })
+            .collect(),
+    )?;
+
+    let mut resolved = Vec::with_capacity(validated.len());
+    for (name, rule) in names.into_iter().zip(validated) {
+        let in_bundle = locations.and_then(|locations| {
+            locations
+                .iter()
+                .find(|location| location.name.eq_ignore_ascii_case(name.trim()))
+        });
+        let is_active = match in_bundle {
+            Some(location) => location.is_active,
+            None => StorageLocationRepository::find_by_name(&state.db, store_id, name.trim())
+                .await?
+                .is_some_and(|location| location.is_active),
+        };
+        if !is_active {
+            return Err(unknown_rule_location(&name));
+        }
+        resolved.push((name.trim().to_string(), rule));
+    }
+
+    Ok(resolved)
+}
+
+fn unknown_rule_location(name: &str) -> AppError {
+    AppError::validation(format!(
+        "Storage location {} does not exist or is inactive",
+        name
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_bundle_parts_are_optional() {
+        let bundle: ConfigBundle = serde_json::from_str(r#"{"format_version": 1}"#).unwrap();
+        assert!(bundle.settings.is_none());
+        assert!(bundle.storage_locations.is_none());
+        assert!(bundle.location_rules.is_none());
+        assert!(bundle.metal_prices.is_none());
+        assert!(bundle.rush_surcharge_tiers.is_none());
+        assert!(bundle.item_types.is_none());
+        assert!(bundle.promise_date_reasons.is_none());
+        assert!(bundle.notification_templates.is_none());
+    }
+
+    #[test]
+    fn test_validate_config_locations_rejects_duplicates() {
+        let locations = vec![
+            ConfigLocation {
+                name: " Safe A ".to_string(),
+                is_active: true,
+                capacity: Some(20),
+            },
+            ConfigLocation {
+                name: "safe a".to_string(),
+                is_active: false,
+                capacity: None,
+            },
+        ];
+        assert!(validate_config_locations(locations.clone()).is_err());
+
+        let validated = validate_config_locations(locations[..1].to_vec()).unwrap();
+        assert_eq!(validated[0].name, "Safe A");
+    }
+}
@@ -2,17 +2,29 @@
 
 use axum::{
     extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::error::AppError;
-use crate::models::customer::CustomerSearchParams;
-use crate::repositories::CustomerRepository;
+use crate::handlers::tickets::{deserialize_optional_nullable, extract_employee_from_session};
+use crate::middleware::{require_permission, TrainingMode};
+use crate::models::customer::{
+    CreateCustomer, Customer, CustomerMerge, CustomerSearchParams, UpdateCustomer,
+};
+use crate::models::partner::Partner;
+use crate::models::Permission;
+use crate::repositories::{CustomerRepository, PartnerRepository};
 use crate::response::ApiResponse;
 use crate::routes::AppState;
+use crate::validation::{
+    validate_email, validate_phone, validate_required, MAX_EMAIL_LENGTH, MAX_NAME_LENGTH,
+    MAX_PHONE_LENGTH,
+};
 
 // =============================================================================
 // GET /customers - Search Customers
@@ -40,9 +52,11 @@ pub struct CustomerSearchQuery {
 /// - `offset`: Offset for pagination (default: 0)
 ///
 /// # Returns
-/// List of matching customers with ticket_count included.
+/// List of matching customers with ticket_count included. Customers created
+/// in training mode are only returned to training sessions.
 pub async fn search_customers(
     State(state): State<AppState>,
+    training: TrainingMode,
     Query(query): Query<CustomerSearchQuery>,
 ) -> Result<impl IntoResponse, AppError> {
     let search_query = query.search.unwrap_or_default();
@@ -51,6 +65,7 @@ pub async fn search_customers(
         query: search_query,
         limit: query.limit,
         offset: query.offset,
+        include_training: training.0,
     };
 
     let customers = CustomerRepository::search_with_ticket_count(&state.db, params).await?;
@@ -65,7 +80,8 @@ pub async fn search_customers(
 /// GET /api/v1/customers/:customer_id - Get customer with ticket history.
 ///
 /// Returns the customer details along with a summary list of their tickets,
-/// sorted by created_at descending (most recent first).
+/// sorted by created_at descending (most recent first). Training sessions see
+/// only training tickets.
 ///
 /// # Path Parameters
 /// - `customer_id`: UUID of the customer to retrieve
@@ -74,18 +90,329 @@ pub async fn search_customers(
 /// Customer data with their ticket history included.
 pub async fn get_customer(
     State(state): State<AppState>,
+    training: TrainingMode,
     Path(customer_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, AppError> {
-    let customer_with_tickets = CustomerRepository::get_with_tickets(&state.db, customer_id)
-        .await?
-        .ok_or_else(|| AppError::not_found("Customer not found"))?;
+    let customer_with_tickets =
+        CustomerRepository::get_with_tickets(&state.db, customer_id, training.0)
+            .await?
+            .ok_or_else(|| state.probe_policy.missing("customer"))?;
 
     Ok(Json(ApiResponse::success(customer_with_tickets)))
 }
 
+// =============================================================================
+// POST /customers - Create Customer
+// =============================================================================
+
+/// Request body for creating a customer.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateCustomerRequest {
+    /// Customer name (required)
+    pub name: String,
+    /// Phone number
+    pub phone: Option<String>,
+    /// Email address
+    pub email: Option<String>,
+}
+
+/// POST /api/v1/customers - Create a customer outside of ticket intake.
+///
+/// Requires employee authentication via X-Employee-Session header.
+///
+/// # Errors
+/// - UNAUTHORIZED: If no valid employee session is provided
+/// - VALIDATION_ERROR: If the name is blank or phone/email are malformed
+pub async fn create_customer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    training: TrainingMode,
+    Json(body): Json<CreateCustomerRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Authenticate and check permission
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    require_permission(&employee, Permission::ManageCustomers)?;
+
+    // 2. Validate and sanitize fields
+    let input = CreateCustomer {
+        name: validate_required(&body.name, "name", MAX_NAME_LENGTH)?,
+        phone: validate_phone(body.phone.as_deref(), MAX_PHONE_LENGTH)?,
+        email: validate_email(body.email.as_deref(), MAX_EMAIL_LENGTH)?,
+        is_training: training.0,
+    };
+
+    // 3. Create the customer
+    let customer = CustomerRepository::create(&state.db, input).await?;
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(customer))))
+}
+
+// =============================================================================
+// PUT /customers/:customer_id - Update Customer
+// =============================================================================
+
+/// Request body for updating a customer.
+///
+/// All fields are optional - only provided fields are updated.
+/// Phone and email may be set to null (or an empty string) to clear them.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateCustomerRequest {
+    /// Customer name
+    pub name: Option<String>,
+    /// Phone number (null to clear)
+    #[serde(default, deserialize_with = "deserialize_optional_nullable")]
+    pub phone: Option<Option<String>>,
+    /// Email address (null to clear)
+    #[serde(default, deserialize_with = "deserialize_optional_nullable")]
+    pub email: Option<Option<String>>,
+    /// Stop sending this customer texts and emails
+    pub notifications_opt_out: Option<bool>,
+}
+
+impl UpdateCustomerRequest {
+    /// Validate and sanitize the provided fields.
+    fn validate(&self) -> Result<UpdateCustomer, AppError> {
+        Ok(UpdateCustomer {
+            name: self
+                .name
+                .as_deref()
+                .map(|name| validate_required(name, "name", MAX_NAME_LENGTH))
+                .transpose()?,
+            phone: self
+                .phone
+                .as_ref()
+                .map(|phone| validate_phone(phone.as_deref(), MAX_PHONE_LENGTH))
+                .transpose()?,
+            email: self
+                .email
+                .as_ref()
+                .map(|email| validate_email(email.as_deref(), MAX_EMAIL_LENGTH))
+                .transpose()?,
+            notifications_opt_out: self.notifications_opt_out,
+        })
+    }
+}
+
+/// PUT /api/v1/customers/:customer_id - Update a customer.
+///
+/// Requires employee authentication via X-Employee-Session header.
+///
+/// # Errors
+/// - UNAUTHORIZED: If no valid employee session is provided
+/// - NOT_FOUND: If the customer does not exist or was deleted
+/// - VALIDATION_ERROR: If a provided field is invalid
+pub async fn update_customer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(customer_id): Path<Uuid>,
+    Json(body): Json<UpdateCustomerRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Authenticate and check permission
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    require_permission(&employee, Permission::ManageCustomers)?;
+
+    // 2. Validate fields before touching the database
+    let update = body.validate()?;
+
+    // 3. Verify the customer exists
+    CustomerRepository::find_active_by_id(&state.db, customer_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("customer"))?;
+
+    // 4. Update the customer
+    let customer = CustomerRepository::update(&state.db, customer_id, update).await?;
+
+    Ok(Json(ApiResponse::success(customer)))
+}
+
+// =============================================================================
+// DELETE /customers/:customer_id - Soft-Delete Customer
+// =============================================================================
+
+/// Response for a deleted customer.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteCustomerResponse {
+    /// The ID of the deleted customer
+    pub customer_id: Uuid,
+    /// Timestamp when the customer was deleted
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// DELETE /api/v1/customers/:customer_id - Soft-delete a customer (admin only).
+///
+/// The customer is hidden from search and can't be used for new tickets,
+/// but existing tickets keep their customer. Customers with open tickets,
+/// and the customer a partner is linked to, can't be deleted.
+///
+/// # Errors
+/// - UNAUTHORIZED: If no valid employee session is provided
+/// - FORBIDDEN: If the employee is not an admin
+/// - NOT_FOUND: If the customer does not exist or was already deleted
+/// - CONFLICT: If the customer still has open tickets or is a partner's
+pub async fn delete_customer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(customer_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Authenticate and check permission
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    require_permission(&employee, Permission::DeleteCustomers)?;
+
+    // 2. Verify the customer exists
+    CustomerRepository::find_active_by_id(&state.db, customer_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("customer"))?;
+
+    // 3. Refuse while work is still in the shop
+    let open_tickets = CustomerRepository::count_open_tickets(&state.db, customer_id).await?;
+    if open_tickets > 0 {
+        return Err(AppError::conflict(format!(
+            "Customer has {} open ticket(s); close them before deleting",
+            open_tickets
+        )));
+    }
+
+    // 4. A partner submits and reads its work through its customer
+    let partner = PartnerRepository::find_by_customer_id(&state.db, customer_id).await?;
+    refuse_partner_customer(partner.as_ref(), "deleted")?;
+
+    // 5. Soft-delete the customer
+    let deleted_at =
+        CustomerRepository::soft_delete(&state.db, customer_id, employee.employee_id).await?;
+
+    Ok(Json(ApiResponse::success(DeleteCustomerResponse {
+        customer_id,
+        deleted_at,
+    })))
+}
+
+// =============================================================================
+// POST /customers/:customer_id/merge - Merge Duplicate Customer
+// =============================================================================
+
+/// Request body for merging a duplicate customer.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MergeCustomerRequest {
+    /// The duplicate customer to fold into the path customer
+    pub source_customer_id: Uuid,
+}
+
+/// Response for a customer merge.
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeCustomerResponse {
+    /// The surviving customer after the merge
+    pub customer: Customer,
+    /// The merge audit record
+    pub merge: CustomerMerge,
+}
+
+/// POST /api/v1/customers/:customer_id/merge - Merge a duplicate into this customer (admin only).
+///
+/// Moves every ticket from `source_customer_id` to the path customer (notes,
+/// photos, and history follow their tickets), fills in missing phone/email,
+/// soft-deletes the source, and records the merge in `customer_merges`.
+/// All changes happen in one transaction. A partner's customer can't be
+/// merged either way: the partner would lose its tickets, or see another
+/// customer's.
+///
+/// # Errors
+/// - UNAUTHORIZED: If no valid employee session is provided
+/// - FORBIDDEN: If the employee is not an admin
+/// - VALIDATION_ERROR: If the source and target are the same customer
+/// - NOT_FOUND: If either customer does not exist or was deleted
+/// - CONFLICT: If either customer is a partner's
+pub async fn merge_customer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(customer_id): Path<Uuid>,
+    Json(body): Json<MergeCustomerRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Authenticate and check permission (merging deletes the source record)
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    require_permission(&employee, Permission::DeleteCustomers)?;
+
+    // 2. A customer can't be merged into itself
+    if body.source_customer_id == customer_id {
+        return Err(AppError::validation(
+            "source_customer_id must be a different customer",
+        ));
+    }
+
+    // 3. Partners see exactly their own customer's tickets
+    for id in [customer_id, body.source_customer_id] {
+        let partner = PartnerRepository::find_by_customer_id(&state.db, id).await?;
+        refuse_partner_customer(partner.as_ref(), "merged")?;
+    }
+
+    // 4. Merge inside a transaction
+    let (customer, merge) = CustomerRepository::merge(
+        &state.db,
+        customer_id,
+        body.source_customer_id,
+        employee.employee_id,
+    )
+    .await?
+    .ok_or_else(|| state.probe_policy.missing("customer"))?;
+
+    Ok(Json(ApiResponse::success(MergeCustomerResponse {
+        customer,
+        merge,
+    })))
+}
+
+/// Refuse to merge or delete (`action`) the customer a partner is linked to.
+fn refuse_partner_customer(partner: Option<&Partner>, action: &str) -> Result<(), AppError> {
+    match partner {
+        Some(partner) => Err(AppError::conflict(format!(
+            "Customer is linked to partner {}; it can't be {}",
+            partner.name, action
+        ))),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::codes;
+
+    fn partner() -> Partner {
+        Partner {
+            partner_id: Uuid::new_v4(),
+            name: "Trade Jewelers".to_string(),
+            customer_id: Uuid::new_v4(),
+            api_key_prefix: "fpk_abcd".to_string(),
+            api_key_hash: String::new(),
+            rate_limit_per_minute: 30,
+            taken_in_by: Uuid::new_v4(),
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_used_at: None,
+        }
+    }
+
+    #[test]
+    fn test_refuse_partner_customer_delete() {
+        assert!(refuse_partner_customer(None, "deleted").is_ok());
+
+        let err = refuse_partner_customer(Some(&partner()), "deleted").unwrap_err();
+        assert_eq!(err.code(), codes::CONFLICT);
+        assert_eq!(
+            err.message(),
+            "Customer is linked to partner Trade Jewelers; it can't be deleted"
+        );
+    }
+
+    #[test]
+    fn test_refuse_partner_customer_merge() {
+        // Checked for both the source and the target of a merge
+        assert!(refuse_partner_customer(None, "merged").is_ok());
+
+        let err = refuse_partner_customer(Some(&partner()), "merged").unwrap_err();
+        assert_eq!(err.code(), codes::CONFLICT);
+        assert!(err.message().ends_with("it can't be merged"));
+    }
 
     #[test]
     fn test_customer_search_query_deserialize_empty() {
@@ -112,6 +439,58 @@ mod tests {
         assert_eq!(query.offset, Some(20));
     }
 
+    #[test]
+    fn test_create_customer_request_validation() {
+        let json = r#"{"name": "  Jane Doe ", "phone": "555-1234", "email": "Jane@Example.com"}"#;
+        let body: CreateCustomerRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            validate_required(&body.name, "name", MAX_NAME_LENGTH).unwrap(),
+            "Jane Doe"
+        );
+        assert_eq!(
+            validate_email(body.email.as_deref(), MAX_EMAIL_LENGTH).unwrap(),
+            Some("jane@example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_update_customer_request_absent_vs_null() {
+        let json = r#"{"phone": null}"#;
+        let body: UpdateCustomerRequest = serde_json::from_str(json).unwrap();
+        let update = body.validate().unwrap();
+        assert!(update.name.is_none());
+        assert_eq!(update.phone, Some(None));
+        assert!(update.email.is_none());
+    }
+
+    #[test]
+    fn test_update_customer_request_empty_string_clears() {
+        let json = r#"{"email": "  "}"#;
+        let body: UpdateCustomerRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(body.validate().unwrap().email, Some(None));
+    }
+
+    #[test]
+    fn test_update_customer_request_rejects_invalid() {
+        let body: UpdateCustomerRequest = serde_json::from_str(r#"{"name": "   "}"#).unwrap();
+        assert!(body.validate().is_err());
+
+        let body: UpdateCustomerRequest = serde_json::from_str(r#"{"phone": "call me"}"#).unwrap();
+        assert!(body.validate().is_err());
+    }
+
+    #[test]
+    fn test_merge_customer_request_deserialize() {
+        let json = r#"{"source_customer_id": "550e8400-e29b-41d4-a716-446655440000"}"#;
+        let body: MergeCustomerRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            body.source_customer_id,
+            Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap()
+        );
+
+        assert!(serde_json::from_str::<MergeCustomerRequest>("{}").is_err());
+    }
+
     #[test]
     fn test_customer_search_query_deserialize_limit_only() {
         let json = r#"{"limit": 25}"#;
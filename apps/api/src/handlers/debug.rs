@@ -0,0 +1,217 @@
+//! Debug capture request handlers (admin only).
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::handlers::verify_admin_auth;
+use crate::models::request_log::{DebugCaptureConfig, RequestLog};
+use crate::repositories::RequestLogRepository;
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+
+/// Default capture window when enabling without a duration.
+const DEFAULT_CAPTURE_MINUTES: i64 = 60;
+
+/// Longest capture window an admin can request (24 hours).
+const MAX_CAPTURE_MINUTES: i64 = 24 * 60;
+
+/// Captured logs older than this are purged whenever capture is enabled.
+const REQUEST_LOG_RETENTION_DAYS: i64 = 7;
+
+/// Response describing the current debug capture state.
+#[derive(Debug, Clone, Serialize)]
+pub struct DebugCaptureResponse {
+    /// Whether capture is currently active
+    pub enabled: bool,
+    /// When capture turns itself off
+    pub capture_until: Option<DateTime<Utc>>,
+    /// Path prefixes being captured (empty = every API route)
+    pub routes: Vec<String>,
+}
+
+impl From<DebugCaptureConfig> for DebugCaptureResponse {
+    fn from(config: DebugCaptureConfig) -> Self {
+        Self {
+            enabled: config.is_active(Utc::now()),
+            capture_until: config
+                .capture_until
+                .filter(|_| config.is_active(Utc::now())),
+            routes: config.routes,
+        }
+    }
+}
+
+// =============================================================================
+// GET /admin/debug-capture - Get Debug Capture State
+// =============================================================================
+
+/// GET /api/v1/admin/debug-capture - Get the debug capture toggle.
+pub async fn get_debug_capture(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let config = RequestLogRepository::get_capture_config(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(DebugCaptureResponse::from(
+        config,
+    ))))
+}
+
+// =============================================================================
+// PUT /admin/debug-capture - Enable/Disable Debug Capture
+// =============================================================================
+
+/// Request body for toggling debug capture.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateDebugCaptureRequest {
+    /// Turn capture on or off
+    pub enabled: bool,
+    /// How long capture stays on (default 60, max 1440 minutes)
+    pub duration_minutes: Option<i64>,
+    /// Path prefixes to capture, e.g. "/api/v1/tickets" (empty = every API route)
+    #[serde(default)]
+    pub routes: Vec<String>,
+}
+
+/// Validate route prefixes for debug capture.
+fn validate_capture_routes(routes: &[String]) -> Result<Vec<String>, AppError> {
+    routes
+        .iter()
+        .map(|route| {
+            let route = route.trim();
+            if !route.starts_with("/api/v1/") {
+                return Err(AppError::validation(format!(
+                    "Capture route must start with /api/v1/: {}",
+                    route
+                )));
+            }
+            Ok(route.to_string())
+        })
+        .collect()
+}
+
+/// PUT /api/v1/admin/debug-capture - Enable or disable debug capture.
+///
+/// Capture switches itself off after `duration_minutes`. Enabling capture
+/// also purges logs older than 7 days.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If the duration or a route prefix is invalid
+pub async fn update_debug_capture(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<UpdateDebugCaptureRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let config = if body.enabled {
+        let minutes = body.duration_minutes.unwrap_or(DEFAULT_CAPTURE_MINUTES);
+        if !(1..=MAX_CAPTURE_MINUTES).contains(&minutes) {
+            return Err(AppError::validation(format!(
+                "duration_minutes must be between 1 and {}",
+                MAX_CAPTURE_MINUTES
+            )));
+        }
+
+        let cutoff = Utc::now() - Duration::days(REQUEST_LOG_RETENTION_DAYS);
+        RequestLogRepository::delete_older_than(&state.db, cutoff).await?;
+
+        DebugCaptureConfig {
+            capture_until: Some(Utc::now() + Duration::minutes(minutes)),
+            routes: validate_capture_routes(&body.routes)?,
+        }
+    } else {
+        DebugCaptureConfig::default()
+    };
+
+    let config = RequestLogRepository::set_capture_config(&state.db, &config).await?;
+    state.debug_capture.set(config.clone()).await;
+
+    Ok(Json(ApiResponse::success(DebugCaptureResponse::from(
+        config,
+    ))))
+}
+
+// =============================================================================
+// GET /admin/request-logs - List Captured Requests
+// =============================================================================
+
+/// Query parameters for listing captured requests.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListRequestLogsQuery {
+    /// Only show paths starting with this prefix
+    pub path: Option<String>,
+    /// Maximum number of results (default 50, max 500)
+    pub limit: Option<i64>,
+    /// Pagination offset
+    pub offset: Option<i64>,
+}
+
+/// Response for listing captured requests.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListRequestLogsResponse {
+    pub logs: Vec<RequestLog>,
+}
+
+/// GET /api/v1/admin/request-logs - List captured requests, most recent first.
+pub async fn list_request_logs(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ListRequestLogsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let limit = query.limit.unwrap_or(50).clamp(1, 500);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let logs = RequestLogRepository::list(&state.db, query.path.as_deref(), limit, offset).await?;
+
+    Ok(Json(ApiResponse::success(ListRequestLogsResponse { logs })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_debug_capture_request_defaults() {
+        let json = r#"{"enabled": true}"#;
+        let request: UpdateDebugCaptureRequest = serde_json::from_str(json).unwrap();
+        assert!(request.enabled);
+        assert!(request.duration_minutes.is_none());
+        assert!(request.routes.is_empty());
+    }
+
+    #[test]
+    fn test_validate_capture_routes() {
+        let routes = vec![" /api/v1/tickets ".to_string()];
+        assert_eq!(
+            validate_capture_routes(&routes).unwrap(),
+            vec!["/api/v1/tickets"]
+        );
+
+        let routes = vec!["/tickets".to_string()];
+        assert!(validate_capture_routes(&routes).is_err());
+    }
+
+    #[test]
+    fn test_debug_capture_response_hides_expired_until() {
+        let config = DebugCaptureConfig {
+            capture_until: Some(Utc::now() - Duration::minutes(5)),
+            routes: vec![],
+        };
+        let response = DebugCaptureResponse::from(config);
+        assert!(!response.enabled);
+        assert!(response.capture_until.is_none());
+    }
+}
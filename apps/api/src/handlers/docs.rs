@@ -0,0 +1,43 @@
+//! API documentation handlers.
+
+use axum::{
+    response::{Html, IntoResponse},
+    Json,
+};
+
+use crate::openapi::openapi_document;
+
+/// Swagger UI page. The UI assets load from a CDN and read the spec from
+/// this server.
+const SWAGGER_UI_HTML: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <meta name="viewport" content="width=device-width, initial-scale=1">
+  <title>Facet API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js" crossorigin></script>
+  <script>
+    window.onload = () => {
+      window.ui = SwaggerUIBundle({ url: "/api/v1/openapi.json", dom_id: "#swagger-ui" });
+    };
+  </script>
+</body>
+</html>
+"##;
+
+/// GET /api/v1/openapi.json - The OpenAPI 3 description of this API.
+///
+/// Returned as a bare document rather than in the response envelope, so
+/// OpenAPI tooling can read it directly.
+pub async fn get_openapi_spec() -> impl IntoResponse {
+    Json(openapi_document())
+}
+
+/// GET /docs - Swagger UI for browsing and trying the API.
+pub async fn get_api_docs() -> impl IntoResponse {
+    Html(SWAGGER_UI_HTML)
+}
@@ -4,23 +4,34 @@ use axum::{
     extract::{ConnectInfo, Path, Query, State},
     http::HeaderMap,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use serde::{Deserialize, Serialize};
-use std::net::SocketAddr;
+use std::net::{IpAddr, SocketAddr};
 use uuid::Uuid;
 
 use chrono::{DateTime, Utc};
 
-use crate::auth::verify_pin;
+use crate::auth::{verify_pin, verify_pin_challenge};
 use crate::error::AppError;
-use crate::handlers::verify_admin_auth;
-use crate::middleware::extract_client_ip;
-use crate::models::employee::{CreateEmployee, EmployeeRole, EmployeeSummary, UpdateEmployee};
-use crate::repositories::{EmployeeRepository, EmployeeSessionRepository};
+use crate::handlers::audit_log::{record_audit, AuditEvent};
+use crate::handlers::tickets::extract_employee_from_session;
+use crate::handlers::verify_permission;
+use crate::middleware::{extract_client_ip, ClientIp, CurrentStore, TrainingMode};
+use crate::models::audit_log::{AuditAction, AuditAuthMethod};
+use crate::models::employee::{
+    CreateEmployee, Employee, EmployeeName, EmployeeProfile, EmployeeRole, EmployeeSummary,
+    Permission, UpdateEmployee,
+};
+use crate::models::pin_challenge::PinChallengeResponse;
+use crate::models::STORE_HEADER;
+use crate::repositories::{
+    EmployeeRepository, EmployeeSessionRepository, PinChallengeRepository, StoreRepository,
+};
 use crate::response::{created, ApiResponse};
 use crate::routes::AppState;
-use crate::validation::{validate_required, MAX_NAME_LENGTH};
+use crate::services::pin_lockout::{lockout_alert, send_lockout_alert};
+use crate::validation::{validate_phone, validate_required, MAX_NAME_LENGTH, MAX_PHONE_LENGTH};
 
 // =============================================================================
 // GET /employees (admin) - List Employees
@@ -44,23 +55,24 @@ pub struct ListEmployeesResponse {
     pub count: usize,
 }
 
-/// GET /api/v1/employees - List all employees (admin only).
+/// GET /api/v1/employees - List all employees (admin or manage_employees).
 ///
 /// Requires admin authentication via X-Admin-Session header (preferred)
 /// or X-Admin-PIN header (deprecated).
-/// Returns a list of employees (without pin_hash).
-/// By default only active employees are returned.
+/// Returns the employees whose home store is the request's store (without
+/// pin_hash). By default only active employees are returned.
 /// Use `?include_inactive=true` to include inactive employees.
 pub async fn list_employees(
     State(state): State<AppState>,
     headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
     Query(query): Query<ListEmployeesQuery>,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify admin authentication (session or PIN)
-    verify_admin_auth(&state, &headers).await?;
+    // Verify admin authentication or the manage_employees permission
+    verify_permission(&state, &headers, Permission::ManageEmployees).await?;
 
     // Fetch employees from repository
-    let employees = EmployeeRepository::list(&state.db, query.include_inactive).await?;
+    let employees = EmployeeRepository::list(&state.db, store_id, query.include_inactive).await?;
 
     let response = ListEmployeesResponse {
         count: employees.len(),
@@ -70,6 +82,61 @@ pub async fn list_employees(
     Ok(Json(ApiResponse::success(response)))
 }
 
+// =============================================================================
+// GET /employees/active - List Active Employee Names
+// =============================================================================
+
+/// Response for listing active employee names.
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveEmployeesResponse {
+    pub employees: Vec<EmployeeName>,
+}
+
+/// GET /api/v1/employees/active - List active employees' IDs and names.
+///
+/// No authentication: returns only what a "worked by" picker needs, so
+/// terminals can fill it without admin credentials. Lists the request's
+/// store's employees.
+pub async fn list_active_employees(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+) -> Result<impl IntoResponse, AppError> {
+    let employees = EmployeeRepository::list_active_names(&state.db, store_id).await?;
+
+    Ok(Json(ApiResponse::success(ActiveEmployeesResponse {
+        employees,
+    })))
+}
+
+// =============================================================================
+// GET /employees/me - Current Employee Profile
+// =============================================================================
+
+/// GET /api/v1/employees/me - Get the logged-in employee's profile.
+///
+/// Resolves the employee from X-Employee-Session (or the deprecated
+/// X-Employee-ID) and returns their name, role, permissions, and counts of
+/// open tickets they took in or are working on. In training mode only
+/// training tickets are counted.
+pub async fn get_my_profile(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    training: TrainingMode,
+) -> Result<impl IntoResponse, AppError> {
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    let open_tickets =
+        EmployeeRepository::count_open_tickets(&state.db, employee.employee_id, training.0).await?;
+
+    let profile = EmployeeProfile {
+        permissions: employee.effective_permissions(),
+        employee_id: employee.employee_id,
+        name: employee.name,
+        role: employee.role,
+        open_tickets,
+    };
+    Ok(Json(ApiResponse::success(profile)))
+}
+
 // =============================================================================
 // POST /employees/verify - Verify Employee PIN
 // =============================================================================
@@ -79,6 +146,9 @@ pub async fn list_employees(
 pub struct VerifyPinRequest {
     /// The PIN to verify
     pub pin: String,
+    /// The employee logging in, when the client knows (enables lockout)
+    #[serde(default)]
+    pub employee_id: Option<Uuid>,
 }
 
 /// Response for a successful PIN verification.
@@ -93,6 +163,8 @@ pub struct VerifyPinResponse {
     pub name: String,
     /// The employee's role
     pub role: EmployeeRole,
+    /// Store the session works for
+    pub store_id: Uuid,
     /// Session token for subsequent requests (use in X-Employee-Session header)
     pub session_token: String,
     /// When the session expires (ISO 8601 format)
@@ -108,16 +180,24 @@ pub struct VerifyPinResponse {
 /// X-Employee-Session header. This replaces the X-Employee-ID header
 /// which is deprecated due to security concerns (spoofing risk).
 ///
+/// When `employee_id` is sent, only that employee's PIN is checked and wrong
+/// PINs count toward their lockout.
+///
+/// Employees can sign in at any store. The session works for the store named
+/// by `X-Store-ID`, or the employee's home store without it.
+///
 /// Returns INVALID_PIN error if no active employee matches the PIN.
+/// Returns ACCOUNT_LOCKED error (423) if the named employee is locked out.
 /// Returns RATE_LIMITED error (429) if too many attempts from the same IP.
 pub async fn verify_employee_pin(
     State(state): State<AppState>,
     headers: HeaderMap,
+    store: CurrentStore,
     connect_info: Option<ConnectInfo<SocketAddr>>,
     Json(body): Json<VerifyPinRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     // Extract client IP for rate limiting
-    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0));
+    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0), &state.trusted_proxies);
 
     // Check rate limit
     if let Err(retry_after) = state.rate_limit.check_rate_limit(client_ip).await {
@@ -127,56 +207,307 @@ pub async fn verify_employee_pin(
         ));
     }
 
-    // Get all active employees for PIN verification
-    let employees = EmployeeRepository::find_active_for_pin_verification(&state.db).await?;
+    // Get the employees this PIN could belong to
+    let employees = pin_candidates(&state, body.employee_id).await?;
 
     // Find an employee whose PIN matches
     for employee in employees {
         if verify_pin(&body.pin, &employee.pin_hash)? {
-            // Record success to reset backoff
+            // Record success to reset backoff and lockout counting
             state.rate_limit.record_success(client_ip).await;
+            EmployeeRepository::clear_pin_failures(&state.db, employee.employee_id).await?;
 
-            // Create a session for the employee
-            let session =
-                EmployeeSessionRepository::create(&state.db, employee.employee_id).await?;
-
-            let response = VerifyPinResponse {
-                employee_id: employee.employee_id,
-                name: employee.name,
-                role: employee.role,
-                session_token: session.session_token,
-                expires_at: session.expires_at,
-            };
+            let response = start_employee_session(&state, &headers, store, employee).await?;
             return Ok(Json(ApiResponse::success(response)));
         }
     }
 
-    // Record failure for exponential backoff
+    // Record failure for exponential backoff and the employee's lockout
     state.rate_limit.record_failure(client_ip).await;
+    if let Some(employee_id) = body.employee_id {
+        record_wrong_pin(&state, &headers, client_ip, employee_id).await?;
+    }
 
     // No matching PIN found
     Err(AppError::invalid_pin("Invalid PIN"))
 }
 
+/// Active employees a PIN is checked against.
+///
+/// With `employee_id`, just that employee; a locked-out employee fails
+/// before their PIN is checked, so guesses reveal nothing. Without it,
+/// every active employee who isn't locked out.
+async fn pin_candidates(
+    state: &AppState,
+    employee_id: Option<Uuid>,
+) -> Result<Vec<Employee>, AppError> {
+    match employee_id {
+        Some(employee_id) => {
+            match EmployeeRepository::find_active_by_id(&state.db, employee_id).await? {
+                Some(employee) if employee.pin_locked_at.is_some() => Err(
+                    AppError::account_locked("Too many wrong PINs; ask an admin to unlock you"),
+                ),
+                employee => Ok(employee.into_iter().collect()),
+            }
+        }
+        None => {
+            let employees = EmployeeRepository::find_active_for_pin_verification(&state.db).await?;
+            Ok(employees
+                .into_iter()
+                .filter(|employee| employee.pin_locked_at.is_none())
+                .collect())
+        }
+    }
+}
+
+/// Count a wrong PIN against the employee it named, locking them out and
+/// raising the alarm when it's one too many.
+async fn record_wrong_pin(
+    state: &AppState,
+    headers: &HeaderMap,
+    client_ip: IpAddr,
+    employee_id: Uuid,
+) -> Result<(), AppError> {
+    let policy = &state.pin_lockout;
+    if !policy.enabled() {
+        return Ok(());
+    }
+
+    let window_start = policy.window_start(Utc::now());
+    let failure = EmployeeRepository::record_pin_failure(
+        &state.db,
+        employee_id,
+        window_start,
+        policy.max_attempts,
+    )
+    .await?;
+    let Some((failed_attempts, Some(locked_at))) = failure else {
+        return Ok(());
+    };
+    if !policy.locks_out(failed_attempts) {
+        return Ok(());
+    }
+
+    let Some(employee) = EmployeeRepository::find_by_id(&state.db, employee_id).await? else {
+        return Ok(());
+    };
+    tracing::warn!(
+        "Employee {} locked out after {} wrong PINs (last from {})",
+        employee_id,
+        failed_attempts,
+        client_ip
+    );
+    record_audit(
+        state,
+        headers,
+        client_ip,
+        AuditEvent::new(AuditAction::EmployeeLockedOut)
+            .authenticated_by(AuditAuthMethod::EmployeePin)
+            .target("employee", employee_id)
+            .summary(serde_json::json!({
+                "name": employee.name,
+                "failed_attempts": failed_attempts,
+                "window_minutes": policy.window.as_secs() / 60,
+            })),
+    )
+    .await;
+
+    if let Some(to) = policy.alert_email.clone() {
+        let (subject, body) = lockout_alert(
+            &employee.name,
+            failed_attempts,
+            policy.window,
+            client_ip,
+            locked_at,
+        );
+        let notifications = state.notifications.clone();
+        state.shutdown.spawn(async move {
+            send_lockout_alert(&notifications, &to, &subject, &body).await;
+        });
+    }
+
+    Ok(())
+}
+
+/// Create a session for a verified employee, at the store named by
+/// `X-Store-ID` or else their home store.
+async fn start_employee_session(
+    state: &AppState,
+    headers: &HeaderMap,
+    store: CurrentStore,
+    employee: Employee,
+) -> Result<VerifyPinResponse, AppError> {
+    let store_id = if headers.contains_key(STORE_HEADER) {
+        store.0
+    } else {
+        employee.store_id
+    };
+    let session =
+        EmployeeSessionRepository::create(&state.db, employee.employee_id, store_id).await?;
+
+    Ok(VerifyPinResponse {
+        employee_id: employee.employee_id,
+        store_id,
+        name: employee.name,
+        role: employee.role,
+        session_token: session.session_token,
+        expires_at: session.expires_at,
+    })
+}
+
+// =============================================================================
+// POST /employees/verify/challenge - Issue PIN Challenge
+// =============================================================================
+
+/// Request body for a PIN challenge.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PinChallengeRequest {
+    /// The employee logging in
+    pub employee_id: Uuid,
+}
+
+/// POST /api/v1/employees/verify/challenge - Issue a nonce for challenge-response PIN entry.
+///
+/// The nonce is single-use, can only be answered for the named employee,
+/// and expires after two minutes. The response includes the key derivation
+/// parameters with the employee's salt: the client derives a key from the
+/// PIN with PBKDF2-HMAC-SHA256 and answers at `/employees/verify/response`
+/// with HMAC-SHA256(key, nonce), so the PIN is never sent.
+///
+/// Rate limited per IP like `/employees/verify`. Unknown, inactive, and
+/// unenrolled employees get the same error and count as failures, so the
+/// endpoint can't be used to find employee IDs.
+///
+/// # Errors
+/// - VALIDATION_ERROR: If the employee doesn't exist, is inactive, or their
+///   PIN predates challenge entry and hasn't been set since; clients fall
+///   back to `/employees/verify`
+/// - RATE_LIMITED: If too many attempts from the same IP
+pub async fn create_pin_challenge(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    Json(body): Json<PinChallengeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0), &state.trusted_proxies);
+
+    if let Err(retry_after) = state.rate_limit.check_rate_limit(client_ip).await {
+        return Err(AppError::rate_limited(
+            "Too many authentication attempts. Please wait before trying again.",
+            retry_after,
+        ));
+    }
+
+    let enrolled = EmployeeRepository::find_active_by_id(&state.db, body.employee_id)
+        .await?
+        .and_then(|employee| {
+            employee
+                .pin_challenge_salt
+                .filter(|_| employee.pin_challenge_key.is_some())
+                .map(|salt| (employee.employee_id, salt))
+        });
+    let Some((employee_id, salt)) = enrolled else {
+        state.rate_limit.record_failure(client_ip).await;
+        return Err(AppError::validation(
+            "Challenge entry isn't set up for this employee; log in with the PIN",
+        ));
+    };
+
+    let challenge = PinChallengeRepository::create(&state.db, employee_id).await?;
+
+    Ok(created(PinChallengeResponse::new(challenge, salt)))
+}
+
+// =============================================================================
+// POST /employees/verify/response - Verify PIN Challenge Response
+// =============================================================================
+
+/// Request body for answering a PIN challenge.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VerifyPinChallengeRequest {
+    /// Nonce from `/employees/verify/challenge`
+    pub nonce: String,
+    /// Hex HMAC-SHA256 of the nonce, keyed with the PIN-derived key
+    pub response: String,
+}
+
+/// POST /api/v1/employees/verify/response - Verify a PIN challenge response and create a session.
+///
+/// Works like `/employees/verify` for the employee the challenge was issued
+/// to, without the PIN in the request. Wrong answers count toward that
+/// employee's lockout.
+///
+/// Returns INVALID_PIN if the challenge is unknown, expired, or already
+/// used, or if the employee's key doesn't match the response.
+/// Returns ACCOUNT_LOCKED error (423) if the named employee is locked out.
+/// Returns RATE_LIMITED error (429) if too many attempts from the same IP.
+pub async fn verify_employee_pin_challenge(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    store: CurrentStore,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    Json(body): Json<VerifyPinChallengeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let client_ip = extract_client_ip(&headers, connect_info.map(|c| c.0), &state.trusted_proxies);
+
+    if let Err(retry_after) = state.rate_limit.check_rate_limit(client_ip).await {
+        return Err(AppError::rate_limited(
+            "Too many authentication attempts. Please wait before trying again.",
+            retry_after,
+        ));
+    }
+
+    // Each nonce can be answered once, right or wrong
+    let Some(employee_id) = PinChallengeRepository::consume(&state.db, &body.nonce).await? else {
+        state.rate_limit.record_failure(client_ip).await;
+        return Err(AppError::invalid_pin("Invalid PIN"));
+    };
+
+    let matched = pin_candidates(&state, Some(employee_id))
+        .await?
+        .into_iter()
+        .find(|employee| {
+            employee
+                .pin_challenge_key
+                .as_deref()
+                .is_some_and(|key| verify_pin_challenge(key, &body.nonce, &body.response))
+        });
+
+    if let Some(employee) = matched {
+        state.rate_limit.record_success(client_ip).await;
+        EmployeeRepository::clear_pin_failures(&state.db, employee.employee_id).await?;
+        let response = start_employee_session(&state, &headers, store, employee).await?;
+        return Ok(Json(ApiResponse::success(response)));
+    }
+
+    state.rate_limit.record_failure(client_ip).await;
+    record_wrong_pin(&state, &headers, client_ip, employee_id).await?;
+
+    Err(AppError::invalid_pin("Invalid PIN"))
+}
+
 // =============================================================================
 // POST /employees (admin) - Create Employee
 // =============================================================================
 
-/// POST /api/v1/employees - Create a new employee (admin only).
+/// POST /api/v1/employees - Create a new employee (admin or manage_employees).
 ///
 /// Requires admin authentication via X-Admin-Session header (preferred)
 /// or X-Admin-PIN header (deprecated).
-/// Creates an employee with the provided name, PIN, and role.
+/// Creates an employee with the provided name, PIN, and role, with the
+/// request's store as their home store.
 /// The PIN is hashed before storage using argon2.
 ///
 /// Returns the created employee (without pin_hash).
 pub async fn create_employee(
     State(state): State<AppState>,
     headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    CurrentStore(store_id): CurrentStore,
     Json(body): Json<CreateEmployee>,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify admin authentication (session or PIN)
-    verify_admin_auth(&state, &headers).await?;
+    // Verify admin authentication or the manage_employees permission
+    verify_permission(&state, &headers, Permission::ManageEmployees).await?;
 
     // Validate and sanitize input
     let name = validate_required(&body.name, "name", MAX_NAME_LENGTH)?;
@@ -184,21 +515,36 @@ pub async fn create_employee(
     if body.pin.is_empty() {
         return Err(AppError::validation("PIN is required"));
     }
+    let phone = validate_phone(body.phone.as_deref(), MAX_PHONE_LENGTH)?;
 
     // Create the employee with validated name (PIN is hashed in the repository)
     let create_input = CreateEmployee {
         name,
         pin: body.pin.clone(),
         role: body.role,
+        phone,
     };
-    let employee = EmployeeRepository::create(&state.db, create_input).await?;
+    let employee = EmployeeRepository::create(&state.db, store_id, create_input).await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::EmployeeCreated)
+            .target("employee", employee.employee_id)
+            .summary(serde_json::json!({ "name": employee.name, "role": employee.role })),
+    )
+    .await;
 
     // Return as EmployeeSummary (without pin_hash)
     let summary = EmployeeSummary {
+        permissions: employee.effective_permissions(),
         employee_id: employee.employee_id,
+        store_id: employee.store_id,
         name: employee.name,
         role: employee.role,
+        phone: employee.phone,
         is_active: employee.is_active,
+        pin_locked_at: employee.pin_locked_at,
     };
 
     Ok(created(summary))
@@ -208,22 +554,23 @@ pub async fn create_employee(
 // PUT /employees/:employee_id (admin) - Update Employee
 // =============================================================================
 
-/// PUT /api/v1/employees/:employee_id - Update an employee (admin only).
+/// PUT /api/v1/employees/:employee_id - Update an employee (admin or manage_employees).
 ///
 /// Requires admin authentication via X-Admin-Session header (preferred)
 /// or X-Admin-PIN header (deprecated).
-/// Updates employee fields: name, role, is_active.
-/// If PIN is provided, it's re-hashed before storage.
+/// Updates employee fields: name, role, is_active, phone, and store_id (their
+/// home store). If PIN is provided, it's re-hashed before storage.
 ///
 /// Returns the updated employee (without pin_hash).
 pub async fn update_employee(
     State(state): State<AppState>,
     headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
     Path(employee_id): Path<Uuid>,
     Json(body): Json<UpdateEmployee>,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify admin authentication (session or PIN)
-    verify_admin_auth(&state, &headers).await?;
+    // Verify admin authentication or the manage_employees permission
+    verify_permission(&state, &headers, Permission::ManageEmployees).await?;
 
     // Validate and sanitize input - if name is provided, validate it
     let name = body
@@ -232,6 +579,12 @@ pub async fn update_employee(
         .map(|n| validate_required(n, "name", MAX_NAME_LENGTH))
         .transpose()?;
 
+    // Validate the phone number; null or blank clears it
+    let phone = body
+        .phone
+        .map(|phone| validate_phone(phone.as_deref(), MAX_PHONE_LENGTH))
+        .transpose()?;
+
     // Validate input - if PIN is provided, it shouldn't be empty
     if let Some(ref pin) = body.pin {
         if pin.is_empty() {
@@ -239,12 +592,31 @@ pub async fn update_employee(
         }
     }
 
+    // A home store must be open
+    if let Some(store_id) = body.store_id {
+        if !StoreRepository::is_active(&state.db, store_id).await? {
+            return Err(AppError::validation("store_id must name an active store"));
+        }
+    }
+
+    // Summarize the change for the audit log, without the PIN itself
+    let audit_summary = serde_json::json!({
+        "name": name,
+        "role": body.role,
+        "is_active": body.is_active,
+        "store_id": body.store_id,
+        "phone_changed": phone.is_some(),
+        "pin_changed": body.pin.is_some(),
+    });
+
     // Build update input with validated name
     let update_input = UpdateEmployee {
         name,
         pin: body.pin.clone(),
         role: body.role,
         is_active: body.is_active,
+        store_id: body.store_id,
+        phone,
     };
 
     // Update the employee
@@ -252,19 +624,188 @@ pub async fn update_employee(
 
     match employee {
         Some(emp) => {
+            record_audit(
+                &state,
+                &headers,
+                client_ip,
+                AuditEvent::new(AuditAction::EmployeeUpdated)
+                    .target("employee", employee_id)
+                    .summary(audit_summary),
+            )
+            .await;
+
             // Return as EmployeeSummary (without pin_hash)
             let summary = EmployeeSummary {
+                permissions: emp.effective_permissions(),
                 employee_id: emp.employee_id,
+                store_id: emp.store_id,
                 name: emp.name,
                 role: emp.role,
+                phone: emp.phone,
                 is_active: emp.is_active,
+                pin_locked_at: emp.pin_locked_at,
             };
             Ok(Json(ApiResponse::success(summary)))
         }
-        None => Err(AppError::not_found("Employee not found")),
+        None => Err(state.probe_policy.missing("employee")),
     }
 }
 
+// =============================================================================
+// POST /employees/:employee_id/unlock (admin) - Unlock Employee
+// =============================================================================
+
+/// POST /api/v1/employees/:employee_id/unlock - Lift a PIN lockout (admin or manage_employees).
+///
+/// Clears the lockout and the employee's count of wrong PINs so they can log
+/// in again. Unlocking an employee who isn't locked out just resets the count.
+///
+/// Returns the updated employee (without pin_hash).
+pub async fn unlock_employee(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(employee_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageEmployees).await?;
+
+    let locked_at = EmployeeRepository::find_by_id(&state.db, employee_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("employee"))?
+        .pin_locked_at;
+    let employee = EmployeeRepository::unlock_pin(&state.db, employee_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("employee"))?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::EmployeeUnlocked)
+            .target("employee", employee_id)
+            .summary(serde_json::json!({ "name": employee.name, "locked_at": locked_at })),
+    )
+    .await;
+
+    let summary = EmployeeSummary {
+        permissions: employee.effective_permissions(),
+        employee_id: employee.employee_id,
+        store_id: employee.store_id,
+        name: employee.name,
+        role: employee.role,
+        phone: employee.phone,
+        is_active: employee.is_active,
+        pin_locked_at: employee.pin_locked_at,
+    };
+    Ok(Json(ApiResponse::success(summary)))
+}
+
+// =============================================================================
+// /employees/:employee_id/permissions (admin) - Employee Permissions
+// =============================================================================
+
+/// An employee's permissions.
+#[derive(Debug, Clone, Serialize)]
+pub struct EmployeePermissionsResponse {
+    pub employee_id: Uuid,
+    pub role: EmployeeRole,
+    /// Permissions the employee holds (every permission for admins)
+    pub permissions: Vec<Permission>,
+    /// Permissions that can be granted
+    pub assignable: Vec<Permission>,
+}
+
+impl From<&Employee> for EmployeePermissionsResponse {
+    fn from(employee: &Employee) -> Self {
+        Self {
+            employee_id: employee.employee_id,
+            role: employee.role,
+            permissions: employee.effective_permissions(),
+            assignable: Permission::ASSIGNABLE.to_vec(),
+        }
+    }
+}
+
+/// Request body for replacing an employee's permissions.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetPermissionsRequest {
+    pub permissions: Vec<Permission>,
+}
+
+/// Validate a permission list, returning it sorted without duplicates.
+fn validate_permissions(mut permissions: Vec<Permission>) -> Result<Vec<Permission>, AppError> {
+    if let Some(permission) = permissions.iter().find(|p| !p.is_assignable()) {
+        let name = serde_json::to_value(permission)
+            .ok()
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default();
+        return Err(AppError::validation(format!("{} cannot be assigned", name)));
+    }
+    permissions.sort();
+    permissions.dedup();
+    Ok(permissions)
+}
+
+/// GET /api/v1/employees/:employee_id/permissions - Get an employee's permissions (admin or manage_employees).
+pub async fn get_employee_permissions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(employee_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageEmployees).await?;
+
+    let employee = EmployeeRepository::find_by_id(&state.db, employee_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("employee"))?;
+
+    Ok(Json(ApiResponse::success(
+        EmployeePermissionsResponse::from(&employee),
+    )))
+}
+
+/// PUT /api/v1/employees/:employee_id/permissions - Replace an employee's permissions (admin or manage_employees).
+///
+/// Only staff permissions can be changed; admins always hold every
+/// permission. Takes effect on the employee's next request.
+pub async fn set_employee_permissions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(employee_id): Path<Uuid>,
+    Json(body): Json<SetPermissionsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_employees permission
+    verify_permission(&state, &headers, Permission::ManageEmployees).await?;
+
+    // 2. Validate input
+    let permissions = validate_permissions(body.permissions)?;
+
+    // 3. Admins can't be restricted
+    let employee = EmployeeRepository::find_by_id(&state.db, employee_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("employee"))?;
+    if employee.role == EmployeeRole::Admin {
+        return Err(AppError::validation("Administrators have every permission"));
+    }
+
+    // 4. Replace the permissions
+    let employee = EmployeeRepository::set_permissions(&state.db, employee_id, &permissions)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("employee"))?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::EmployeePermissionsChanged)
+            .target("employee", employee_id)
+            .summary(serde_json::json!({ "permissions": permissions })),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(
+        EmployeePermissionsResponse::from(&employee),
+    )))
+}
+
 // =============================================================================
 // DELETE /employees/:employee_id (admin) - Delete Employee
 // =============================================================================
@@ -279,7 +820,7 @@ pub struct DeleteEmployeeResponse {
     pub warning: Option<String>,
 }
 
-/// DELETE /api/v1/employees/:employee_id - Delete an employee (admin only).
+/// DELETE /api/v1/employees/:employee_id - Delete an employee (admin or manage_employees).
 ///
 /// Requires admin authentication via X-Admin-Session header (preferred)
 /// or X-Admin-PIN header (deprecated).
@@ -290,16 +831,16 @@ pub struct DeleteEmployeeResponse {
 pub async fn delete_employee(
     State(state): State<AppState>,
     headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
     Path(employee_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify admin authentication (session or PIN)
-    verify_admin_auth(&state, &headers).await?;
+    // Verify admin authentication or the manage_employees permission
+    verify_permission(&state, &headers, Permission::ManageEmployees).await?;
 
     // Check if employee exists
-    let employee = EmployeeRepository::find_by_id(&state.db, employee_id).await?;
-    if employee.is_none() {
-        return Err(AppError::not_found("Employee not found"));
-    }
+    let Some(employee) = EmployeeRepository::find_by_id(&state.db, employee_id).await? else {
+        return Err(state.probe_policy.missing("employee"));
+    };
 
     // Check for attribution history
     let attribution_count = EmployeeRepository::count_attributions(&state.db, employee_id).await?;
@@ -318,8 +859,21 @@ pub async fn delete_employee(
     let deleted = EmployeeRepository::hard_delete(&state.db, employee_id).await?;
 
     if !deleted {
-        return Err(AppError::not_found("Employee not found"));
+        return Err(state.probe_policy.missing("employee"));
     }
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::EmployeeDeleted)
+            .target("employee", employee_id)
+            .summary(serde_json::json!({
+                "name": employee.name,
+                "role": employee.role,
+                "attributions": attribution_count,
+            })),
+    )
+    .await;
 
     let response = DeleteEmployeeResponse { deleted, warning };
 
@@ -368,6 +922,57 @@ pub async fn employee_logout(
     Ok(Json(ApiResponse::success(response)))
 }
 
+// =============================================================================
+// PUT /employees/training - Toggle Training Mode
+// =============================================================================
+
+/// Request body for switching training mode.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SetTrainingModeRequest {
+    pub enabled: bool,
+}
+
+/// Response after switching training mode.
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainingModeResponse {
+    pub training_mode: bool,
+}
+
+/// PUT /api/v1/employees/training - Switch the session into or out of training mode.
+///
+/// Training mode applies to the current `X-Employee-Session` only. While it
+/// is on, tickets and customers the terminal creates are tagged as training
+/// data, lists and the workboard show only training tickets, no customer
+/// notifications are sent, and every response's `meta` carries
+/// `training_mode: true`. Reports never include training tickets.
+pub async fn set_training_mode(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<SetTrainingModeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let token = headers
+        .get("X-Employee-Session")
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| {
+            AppError::unauthorized("Missing authentication. Provide X-Employee-Session header.")
+        })?;
+    let session = EmployeeSessionRepository::verify_and_touch(&state.db, token)
+        .await?
+        .ok_or_else(|| AppError::unauthorized("Invalid or expired session"))?;
+
+    EmployeeSessionRepository::set_training_mode(&state.db, session.session_id, body.enabled)
+        .await?;
+
+    // Flag this response by the new mode rather than the one the request started in
+    let response = TrainingModeResponse {
+        training_mode: body.enabled,
+    };
+    Ok((
+        Extension(TrainingMode(body.enabled)),
+        Json(ApiResponse::success(response)),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,6 +982,17 @@ mod tests {
         let json = r#"{"pin": "1234"}"#;
         let request: VerifyPinRequest = serde_json::from_str(json).unwrap();
         assert_eq!(request.pin, "1234");
+        assert_eq!(request.employee_id, None);
+    }
+
+    #[test]
+    fn test_verify_pin_request_deserialize_with_employee_id() {
+        let json = r#"{"pin": "1234", "employee_id": "550e8400-e29b-41d4-a716-446655440000"}"#;
+        let request: VerifyPinRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            request.employee_id,
+            Some(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap())
+        );
     }
 
     #[test]
@@ -393,12 +1009,25 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_verify_pin_challenge_request_deserialize() {
+        let json = r#"{"nonce": "abc", "response": "00ff"}"#;
+        let request: VerifyPinChallengeRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.nonce, "abc");
+        assert_eq!(request.response, "00ff");
+
+        let result: Result<VerifyPinChallengeRequest, _> =
+            serde_json::from_str(r#"{"nonce": "abc"}"#);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_verify_pin_response_serialization() {
         let response = VerifyPinResponse {
             employee_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
             name: "Alice".to_string(),
             role: EmployeeRole::Staff,
+            store_id: Uuid::nil(),
             session_token: "test_token_abc123".to_string(),
             expires_at: chrono::Utc::now() + chrono::Duration::hours(8),
         };
@@ -417,6 +1046,7 @@ mod tests {
             employee_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
             name: "Admin User".to_string(),
             role: EmployeeRole::Admin,
+            store_id: Uuid::nil(),
             session_token: "admin_session_token".to_string(),
             expires_at: chrono::Utc::now() + chrono::Duration::hours(8),
         };
@@ -473,9 +1103,13 @@ mod tests {
     fn test_employee_summary_serialization() {
         let summary = EmployeeSummary {
             employee_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
+            store_id: Uuid::nil(),
             name: "Test User".to_string(),
             role: EmployeeRole::Staff,
+            phone: None,
+            permissions: EmployeeRole::Staff.default_permissions(),
             is_active: true,
+            pin_locked_at: None,
         };
 
         let json = serde_json::to_string(&summary).unwrap();
@@ -483,6 +1117,7 @@ mod tests {
         assert!(json.contains("\"name\":\"Test User\""));
         assert!(json.contains("\"role\":\"staff\""));
         assert!(json.contains("\"is_active\":true"));
+        assert!(json.contains("\"pin_locked_at\":null"));
         // Importantly, should NOT contain pin_hash
         assert!(!json.contains("pin_hash"));
     }
@@ -491,9 +1126,13 @@ mod tests {
     fn test_employee_summary_serialization_inactive() {
         let summary = EmployeeSummary {
             employee_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
+            store_id: Uuid::nil(),
             name: "Inactive User".to_string(),
             role: EmployeeRole::Admin,
+            phone: None,
+            permissions: EmployeeRole::Admin.default_permissions(),
             is_active: false,
+            pin_locked_at: None,
         };
 
         let json = serde_json::to_string(&summary).unwrap();
@@ -624,21 +1263,69 @@ mod tests {
         assert!(json.contains("\"count\":0"));
     }
 
+    #[test]
+    fn test_active_employees_response_has_only_ids_and_names() {
+        let response = ActiveEmployeesResponse {
+            employees: vec![EmployeeName {
+                employee_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
+                name: "Alice".to_string(),
+            }],
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "employees": [
+                    { "employee_id": "550e8400-e29b-41d4-a716-446655440000", "name": "Alice" }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_employee_profile_serialization() {
+        let profile = EmployeeProfile {
+            employee_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
+            name: "Alice".to_string(),
+            role: EmployeeRole::Staff,
+            permissions: vec![Permission::CreateTicket],
+            open_tickets: crate::models::OpenTicketCounts {
+                taken_in: 3,
+                working_on: 1,
+            },
+        };
+
+        let json = serde_json::to_string(&profile).unwrap();
+        assert!(json.contains("\"role\":\"staff\""));
+        assert!(json.contains("\"permissions\":[\"create_ticket\"]"));
+        assert!(json.contains("\"open_tickets\":{\"taken_in\":3,\"working_on\":1}"));
+        assert!(!json.contains("pin_hash"));
+    }
+
     #[test]
     fn test_list_employees_response_serialization_with_employees() {
         let response = ListEmployeesResponse {
             employees: vec![
                 EmployeeSummary {
                     employee_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
+                    store_id: Uuid::nil(),
                     name: "Alice".to_string(),
                     role: EmployeeRole::Staff,
+                    phone: None,
+                    permissions: EmployeeRole::Staff.default_permissions(),
                     is_active: true,
+                    pin_locked_at: None,
                 },
                 EmployeeSummary {
                     employee_id: Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap(),
+                    store_id: Uuid::nil(),
                     name: "Bob".to_string(),
                     role: EmployeeRole::Admin,
+                    phone: None,
+                    permissions: EmployeeRole::Admin.default_permissions(),
                     is_active: true,
+                    pin_locked_at: None,
                 },
             ],
             count: 2,
@@ -653,4 +1340,25 @@ mod tests {
         // Should NOT contain pin_hash
         assert!(!json.contains("pin_hash"));
     }
+
+    #[test]
+    fn test_validate_permissions_sorts_and_dedupes() {
+        let permissions = validate_permissions(vec![
+            Permission::DeleteCustomers,
+            Permission::CloseAnyTicket,
+            Permission::DeleteCustomers,
+        ])
+        .unwrap();
+        assert_eq!(
+            permissions,
+            vec![Permission::CloseAnyTicket, Permission::DeleteCustomers]
+        );
+        assert!(validate_permissions(vec![]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_validate_permissions_rejects_view_ticket() {
+        let err = validate_permissions(vec![Permission::ViewTicket]).unwrap_err();
+        assert_eq!(err.message(), "view_ticket cannot be assigned");
+    }
 }
@@ -0,0 +1,60 @@
+//! Error catalog handler.
+
+use axum::{extract::State, response::IntoResponse, Json};
+use serde::Serialize;
+
+use crate::error::{ErrorCatalogEntry, ERROR_CATALOG};
+use crate::middleware::ProbePolicy;
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+
+/// How missing resources are reported by this server.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProbePolicyInfo {
+    pub policy: ProbePolicy,
+    pub description: &'static str,
+}
+
+/// Response for the error catalog.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorCatalogResponse {
+    pub errors: &'static [ErrorCatalogEntry],
+    pub resource_probe_policy: ProbePolicyInfo,
+}
+
+/// GET /api/v1/errors - List every error code the API can return.
+///
+/// Also reports the configured probe policy so clients know whether a
+/// missing resource comes back as NOT_FOUND or FORBIDDEN.
+pub async fn get_error_catalog(State(state): State<AppState>) -> impl IntoResponse {
+    Json(ApiResponse::success(ErrorCatalogResponse {
+        errors: ERROR_CATALOG,
+        resource_probe_policy: ProbePolicyInfo {
+            policy: state.probe_policy,
+            description: state.probe_policy.description(),
+        },
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_catalog_response_serialization() {
+        let response = ErrorCatalogResponse {
+            errors: ERROR_CATALOG,
+            resource_probe_policy: ProbePolicyInfo {
+                policy: ProbePolicy::Forbidden,
+                description: ProbePolicy::Forbidden.description(),
+            },
+        };
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["resource_probe_policy"]["policy"], "forbidden");
+        assert!(json["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|e| e["code"] == "NOT_FOUND" && e["status"] == 404));
+    }
+}
@@ -0,0 +1,682 @@
+//! Legacy data import handlers (admin only).
+//!
+//! Customers and tickets can be loaded from a CSV export of a previous system
+//! or from transcribed paper records. Each row is validated on its own with
+//! the same rules as the regular endpoints: valid rows are imported, invalid
+//! rows are reported with the reason so they can be fixed and imported again.
+//! A dry run validates the whole file without writing anything.
+
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Multipart, Query, State},
+    http::HeaderMap,
+    response::IntoResponse,
+    Json,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::verify_admin_auth;
+use crate::middleware::CurrentStore;
+use crate::models::customer::CreateCustomer;
+use crate::models::status_history::CreateStatusHistory;
+use crate::models::ticket::{CreateTicket, TicketStatus};
+use crate::models::ticket_note::CreateTicketNote;
+use crate::repositories::{
+    CustomerRepository, StatusHistoryRepository, StorageLocationRepository, TicketNoteRepository,
+    TicketRepository,
+};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::validation::{
+    validate_email, validate_employee, validate_optional, validate_phone, validate_required,
+    MAX_DESCRIPTION_LENGTH, MAX_EMAIL_LENGTH, MAX_IMPORT_ROWS, MAX_ITEM_TYPE_LENGTH,
+    MAX_NAME_LENGTH, MAX_NOTE_LENGTH, MAX_PHONE_LENGTH,
+};
+
+/// Query parameters for the import endpoints.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ImportQuery {
+    /// Validate every row without writing anything
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Employee recorded as having taken in imported tickets (tickets only)
+    pub taken_in_by: Option<Uuid>,
+}
+
+/// A row that was (or, on a dry run, would be) imported.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportedRow {
+    /// Line number in the file (the header is row 1)
+    pub row: usize,
+    /// Created customer or ticket (None on a dry run)
+    pub record_id: Option<Uuid>,
+    /// Friendly code of the created ticket
+    pub friendly_code: Option<String>,
+}
+
+/// A row that was rejected, with the reason.
+#[derive(Debug, Clone, Serialize)]
+pub struct RejectedRow {
+    /// Line number in the file (the header is row 1)
+    pub row: usize,
+    pub reason: String,
+}
+
+/// Result of a CSV import.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportSummary {
+    pub dry_run: bool,
+    pub total_rows: usize,
+    pub accepted_count: usize,
+    pub rejected_count: usize,
+    /// Customers created (for ticket imports, customers not matched to an
+    /// existing record)
+    pub customers_created: usize,
+    pub accepted: Vec<ImportedRow>,
+    pub rejected: Vec<RejectedRow>,
+}
+
+impl ImportSummary {
+    fn new(dry_run: bool, total_rows: usize) -> Self {
+        Self {
+            dry_run,
+            total_rows,
+            accepted_count: 0,
+            rejected_count: 0,
+            customers_created: 0,
+            accepted: Vec::new(),
+            rejected: Vec::new(),
+        }
+    }
+
+    /// Record the outcome of one row.
+    ///
+    /// Server errors abort the import rather than being reported against a
+    /// row; rows already imported are kept.
+    fn record(
+        &mut self,
+        row: usize,
+        outcome: Result<(Option<Uuid>, Option<String>), AppError>,
+    ) -> Result<(), AppError> {
+        match outcome {
+            Ok((record_id, friendly_code)) => {
+                self.accepted_count += 1;
+                self.accepted.push(ImportedRow {
+                    row,
+                    record_id,
+                    friendly_code,
+                });
+            }
+            Err(err @ AppError::ServerError(_)) => return Err(err),
+            Err(err) => {
+                self.rejected_count += 1;
+                self.rejected.push(RejectedRow {
+                    row,
+                    reason: err.message().to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// CSV Parsing
+// =============================================================================
+
+/// Read the uploaded CSV from the multipart `file` field.
+async fn read_csv_upload(multipart: &mut Multipart) -> Result<Vec<u8>, AppError> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::validation(format!("Failed to read multipart field: {}", e)))?
+    {
+        if field.name() == Some("file") {
+            let data = field
+                .bytes()
+                .await
+                .map_err(|e| AppError::validation(format!("Failed to read file data: {}", e)))?;
+            if data.is_empty() {
+                return Err(AppError::validation("Empty file provided"));
+            }
+            return Ok(data.to_vec());
+        }
+    }
+
+    Err(AppError::validation("No 'file' field in request"))
+}
+
+/// Normalize a CSV header: "Customer Name" becomes "customer_name".
+fn normalize_header(header: &str) -> String {
+    header
+        .trim_start_matches('\u{feff}')
+        .trim()
+        .to_lowercase()
+        .replace([' ', '-'], "_")
+}
+
+/// Parsed CSV rows: each row's line number and its contents, or why it
+/// couldn't be read.
+pub(crate) type CsvRows<T> = Vec<(usize, Result<T, AppError>)>;
+
+/// Parse CSV rows into `T`, keyed by normalized header.
+///
+/// Fails as a whole when the header is unreadable, a required column is
+/// missing, or the file has too many rows; a malformed row is returned as
+/// that row's error.
+pub(crate) fn parse_csv<T: DeserializeOwned>(
+    data: &[u8],
+    required_columns: &[&str],
+) -> Result<CsvRows<T>, AppError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(data);
+
+    let headers: csv::StringRecord = reader
+        .headers()
+        .map_err(|e| AppError::validation(format!("Failed to read CSV header: {}", e)))?
+        .iter()
+        .map(normalize_header)
+        .collect();
+    for column in required_columns {
+        if !headers.iter().any(|header| header == *column) {
+            return Err(AppError::validation(format!(
+                "CSV is missing required column: {}",
+                column
+            )));
+        }
+    }
+
+    let mut rows = Vec::new();
+    for (index, record) in reader.records().enumerate() {
+        if index >= MAX_IMPORT_ROWS {
+            return Err(AppError::validation(format!(
+                "CSV cannot have more than {} rows",
+                MAX_IMPORT_ROWS
+            )));
+        }
+        let row = record
+            .and_then(|record| record.deserialize(Some(&headers)))
+            .map_err(|e| AppError::validation(format!("Malformed row: {}", e)));
+        rows.push((index + 2, row));
+    }
+
+    Ok(rows)
+}
+
+/// Parse a yes/no column; empty means no.
+fn parse_csv_bool(value: Option<&str>, field: &str) -> Result<bool, AppError> {
+    match value.unwrap_or("").to_lowercase().as_str() {
+        "" | "false" | "no" | "n" | "0" => Ok(false),
+        "true" | "yes" | "y" | "1" => Ok(true),
+        _ => Err(AppError::validation(format!(
+            "{} must be true or false",
+            field
+        ))),
+    }
+}
+
+/// Parse a YYYY-MM-DD date column.
+fn parse_csv_date(value: Option<&str>, field: &str) -> Result<Option<NaiveDate>, AppError> {
+    match value.filter(|value| !value.is_empty()) {
+        None => Ok(None),
+        Some(value) => NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map(Some)
+            .map_err(|_| AppError::validation(format!("{} must be a date (YYYY-MM-DD)", field))),
+    }
+}
+
+/// Parse a non-negative amount column; a leading "$" is allowed.
+fn parse_csv_amount(value: Option<&str>, field: &str) -> Result<Option<Decimal>, AppError> {
+    let Some(value) = value.filter(|value| !value.is_empty()) else {
+        return Ok(None);
+    };
+    let amount = value
+        .trim_start_matches('$')
+        .replace(',', "")
+        .parse::<Decimal>()
+        .map_err(|_| AppError::validation(format!("{} must be a number", field)))?;
+    if amount < Decimal::ZERO {
+        return Err(AppError::validation(format!(
+            "{} cannot be negative",
+            field
+        )));
+    }
+    Ok(Some(amount))
+}
+
+// =============================================================================
+// Customer Matching
+// =============================================================================
+
+/// Digits of a phone number, for matching differently formatted numbers.
+pub(crate) fn phone_digits(phone: Option<&str>) -> Option<String> {
+    let digits: String = phone?.chars().filter(char::is_ascii_digit).collect();
+    (!digits.is_empty()).then_some(digits)
+}
+
+/// Keys identifying a customer by phone and email within one import.
+fn contact_keys(customer: &CreateCustomer) -> Vec<String> {
+    let phone = phone_digits(customer.phone.as_deref()).map(|digits| format!("phone:{}", digits));
+    let email = customer
+        .email
+        .as_ref()
+        .map(|email| format!("email:{}", email.to_lowercase()));
+    phone.into_iter().chain(email).collect()
+}
+
+/// Find a real customer with the same phone number or email.
+async fn find_existing_customer(
+    state: &AppState,
+    customer: &CreateCustomer,
+) -> Result<Option<Uuid>, AppError> {
+    let phone = phone_digits(customer.phone.as_deref());
+    if phone.is_none() && customer.email.is_none() {
+        return Ok(None);
+    }
+
+    let existing = CustomerRepository::find_active_by_contact(
+        &state.db,
+        phone.as_deref(),
+        customer.email.as_deref(),
+    )
+    .await?;
+
+    Ok(existing.map(|customer| customer.customer_id))
+}
+
+// =============================================================================
+// POST /admin/import/customers - Import Customers
+// =============================================================================
+
+/// A customer CSV row.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct CustomerCsvRow {
+    pub name: String,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+}
+
+/// Validate a customer row with the rules of `POST /customers`.
+pub(crate) fn validate_customer_row(row: &CustomerCsvRow) -> Result<CreateCustomer, AppError> {
+    Ok(CreateCustomer {
+        name: validate_required(&row.name, "name", MAX_NAME_LENGTH)?,
+        phone: validate_phone(row.phone.as_deref(), MAX_PHONE_LENGTH)?,
+        email: validate_email(row.email.as_deref(), MAX_EMAIL_LENGTH)?,
+        is_training: false,
+    })
+}
+
+/// POST /api/v1/admin/import/customers - Import customers from CSV.
+///
+/// Columns: `name` (required), `phone`, `email`. Rows whose phone or email
+/// matches an existing customer or an earlier row are rejected as duplicates.
+pub async fn import_customers(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ImportQuery>,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let data = read_csv_upload(&mut multipart).await?;
+    let rows = parse_csv::<CustomerCsvRow>(&data, &["name"])?;
+
+    let mut summary = ImportSummary::new(query.dry_run, rows.len());
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    for (row, parsed) in rows {
+        let outcome = async {
+            let customer = validate_customer_row(&parsed?)?;
+
+            let keys = contact_keys(&customer);
+            if let Some(earlier) = keys.iter().find_map(|key| seen.get(key)) {
+                return Err(AppError::conflict(format!("Duplicate of row {}", earlier)));
+            }
+            if find_existing_customer(&state, &customer).await?.is_some() {
+                return Err(AppError::conflict(
+                    "A customer with this phone or email already exists",
+                ));
+            }
+            seen.extend(keys.into_iter().map(|key| (key, row)));
+
+            if query.dry_run {
+                return Ok((None, None));
+            }
+            let created = CustomerRepository::create(&state.db, customer).await?;
+            Ok((Some(created.customer_id), None))
+        }
+        .await;
+
+        summary.record(row, outcome)?;
+    }
+    summary.customers_created = summary.accepted_count;
+
+    Ok(Json(ApiResponse::success(summary)))
+}
+
+// =============================================================================
+// POST /admin/import/tickets - Import Tickets
+// =============================================================================
+
+/// A ticket CSV row.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct TicketCsvRow {
+    pub customer_name: String,
+    pub customer_phone: Option<String>,
+    pub customer_email: Option<String>,
+    pub item_type: Option<String>,
+    pub item_description: String,
+    pub condition_notes: String,
+    pub requested_work: String,
+    pub is_rush: Option<String>,
+    pub promise_date: Option<String>,
+    pub storage_location: String,
+    pub quote_amount: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Columns a ticket CSV must have.
+const TICKET_REQUIRED_COLUMNS: [&str; 5] = [
+    "customer_name",
+    "item_description",
+    "condition_notes",
+    "requested_work",
+    "storage_location",
+];
+
+/// A validated ticket row, before the customer and location are resolved.
+#[derive(Debug, Clone)]
+pub(crate) struct TicketImport {
+    pub customer: CreateCustomer,
+    pub item_type: Option<String>,
+    pub item_description: String,
+    pub condition_notes: String,
+    pub requested_work: String,
+    pub is_rush: bool,
+    pub promise_date: Option<NaiveDate>,
+    pub storage_location: String,
+    pub quote_amount: Option<Decimal>,
+    pub note: Option<String>,
+}
+
+/// Validate a ticket row with the rules of `POST /tickets`.
+pub(crate) fn validate_ticket_row(row: &TicketCsvRow) -> Result<TicketImport, AppError> {
+    Ok(TicketImport {
+        customer: CreateCustomer {
+            name: validate_required(&row.customer_name, "customer_name", MAX_NAME_LENGTH)?,
+            phone: validate_phone(row.customer_phone.as_deref(), MAX_PHONE_LENGTH)?,
+            email: validate_email(row.customer_email.as_deref(), MAX_EMAIL_LENGTH)?,
+            is_training: false,
+        },
+        item_type: validate_optional(row.item_type.as_deref(), "item_type", MAX_ITEM_TYPE_LENGTH)?,
+        item_description: validate_required(
+            &row.item_description,
+            "item_description",
+            MAX_DESCRIPTION_LENGTH,
+        )?,
+        condition_notes: validate_required(
+            &row.condition_notes,
+            "condition_notes",
+            MAX_DESCRIPTION_LENGTH,
+        )?,
+        requested_work: validate_required(
+            &row.requested_work,
+            "requested_work",
+            MAX_DESCRIPTION_LENGTH,
+        )?,
+        is_rush: parse_csv_bool(row.is_rush.as_deref(), "is_rush")?,
+        promise_date: parse_csv_date(row.promise_date.as_deref(), "promise_date")?,
+        storage_location: validate_required(
+            &row.storage_location,
+            "storage_location",
+            MAX_NAME_LENGTH,
+        )?,
+        quote_amount: parse_csv_amount(row.quote_amount.as_deref(), "quote_amount")?,
+        note: validate_optional(row.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?,
+    })
+}
+
+/// POST /api/v1/admin/import/tickets - Import tickets from CSV.
+///
+/// Requires `taken_in_by`. Each row's customer is matched to an existing
+/// customer (or one created earlier in the file) by phone or email, otherwise
+/// created. Tickets start in Intake; `notes` is added as a ticket note.
+/// `storage_location` names a location in the request's store.
+pub async fn import_tickets(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
+    Query(query): Query<ImportQuery>,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let taken_in_by = query
+        .taken_in_by
+        .ok_or_else(|| AppError::validation("taken_in_by is required"))?;
+    validate_employee(&state.db, taken_in_by).await?;
+
+    let data = read_csv_upload(&mut multipart).await?;
+    let rows = parse_csv::<TicketCsvRow>(&data, &TICKET_REQUIRED_COLUMNS)?;
+
+    let mut summary = ImportSummary::new(query.dry_run, rows.len());
+    // Customers created by this import, by contact key (no ID on a dry run)
+    let mut created_customers: HashMap<String, Option<Uuid>> = HashMap::new();
+
+    for (row, parsed) in rows {
+        let mut customer_created = false;
+        let outcome = async {
+            let ticket = validate_ticket_row(&parsed?)?;
+
+            let location = StorageLocationRepository::find_by_name(
+                &state.db,
+                store_id,
+                &ticket.storage_location,
+            )
+            .await?
+            .filter(|location| location.is_active)
+            .ok_or_else(|| AppError::not_found("Storage location not found or inactive"))?;
+
+            let keys = contact_keys(&ticket.customer);
+            let known = match keys.iter().find_map(|key| created_customers.get(key)) {
+                Some(customer_id) => Some(*customer_id),
+                None => find_existing_customer(&state, &ticket.customer)
+                    .await?
+                    .map(Some),
+            };
+            customer_created = known.is_none();
+
+            if query.dry_run {
+                if customer_created {
+                    created_customers.extend(keys.into_iter().map(|key| (key, None)));
+                }
+                return Ok((None, None));
+            }
+
+            // The row's writes land together, or not at all
+            let mut tx = state.db.begin().await?;
+            let customer_id = match known.flatten() {
+                Some(customer_id) => customer_id,
+                None => {
+                    CustomerRepository::create(&mut *tx, ticket.customer)
+                        .await?
+                        .customer_id
+                }
+            };
+
+            let created = TicketRepository::create(
+                &mut tx,
+                CreateTicket {
+                    customer_id,
+                    item_type: ticket.item_type,
+                    item_description: ticket.item_description,
+                    condition_notes: ticket.condition_notes,
+                    requested_work: ticket.requested_work,
+                    is_rush: ticket.is_rush,
+                    promise_date: ticket.promise_date,
+                    storage_location_id: location.location_id,
+                    quote_amount: ticket.quote_amount,
+                    rush_surcharge: None,
+                    weight_grams: None,
+                    metal_type: None,
+                    taken_in_by,
+                    is_training: false,
+                    items: Vec::new(),
+                },
+            )
+            .await?;
+
+            StatusHistoryRepository::create(
+                &mut *tx,
+                CreateStatusHistory {
+                    ticket_id: created.ticket_id,
+                    from_status: None,
+                    to_status: TicketStatus::Intake,
+                    changed_by: taken_in_by,
+                },
+            )
+            .await?;
+
+            if let Some(content) = ticket.note {
+                TicketNoteRepository::create(
+                    &mut *tx,
+                    CreateTicketNote {
+                        ticket_id: created.ticket_id,
+                        content,
+                        created_by: taken_in_by,
+                    },
+                )
+                .await?;
+            }
+            tx.commit().await?;
+
+            if customer_created {
+                created_customers.extend(keys.into_iter().map(|key| (key, Some(customer_id))));
+            }
+
+            Ok((Some(created.ticket_id), Some(created.friendly_code)))
+        }
+        .await;
+
+        if outcome.is_ok() && customer_created {
+            summary.customers_created += 1;
+        }
+        summary.record(row, outcome)?;
+    }
+
+    Ok(Json(ApiResponse::success(summary)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_normalizes_headers() {
+        let data = "\u{feff}Name, Phone ,EMAIL\nJane Doe,555-123-4567,jane@example.com\n";
+        let rows = parse_csv::<CustomerCsvRow>(data.as_bytes(), &["name"]).unwrap();
+
+        assert_eq!(rows.len(), 1);
+        let (row, parsed) = &rows[0];
+        assert_eq!(*row, 2);
+        let parsed = parsed.as_ref().unwrap();
+        assert_eq!(parsed.name, "Jane Doe");
+        assert_eq!(parsed.phone.as_deref(), Some("555-123-4567"));
+        assert_eq!(parsed.email.as_deref(), Some("jane@example.com"));
+    }
+
+    #[test]
+    fn test_parse_csv_missing_column() {
+        let err = parse_csv::<CustomerCsvRow>(b"phone,email\n", &["name"]).unwrap_err();
+        assert_eq!(err.message(), "CSV is missing required column: name");
+    }
+
+    #[test]
+    fn test_parse_csv_malformed_row_is_rejected_alone() {
+        let data = "name,phone\nJane,555\nToo,many,fields\nBob,\n";
+        let rows = parse_csv::<CustomerCsvRow>(data.as_bytes(), &["name"]).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert!(rows[0].1.is_ok());
+        assert!(rows[1].1.is_err());
+        assert_eq!(rows[2].0, 4);
+        assert!(rows[2].1.is_ok());
+    }
+
+    #[test]
+    fn test_validate_customer_row() {
+        let row = CustomerCsvRow {
+            name: "  Jane  ".to_string(),
+            phone: Some("(555) 123-4567".to_string()),
+            email: Some(String::new()),
+        };
+        let customer = validate_customer_row(&row).unwrap();
+        assert_eq!(customer.name, "Jane");
+        assert_eq!(customer.email, None);
+
+        let row = CustomerCsvRow {
+            name: String::new(),
+            ..Default::default()
+        };
+        assert!(validate_customer_row(&row).is_err());
+    }
+
+    #[test]
+    fn test_validate_ticket_row() {
+        let row = TicketCsvRow {
+            customer_name: "Jane".to_string(),
+            item_description: "Gold ring".to_string(),
+            condition_notes: "Scratched".to_string(),
+            requested_work: "Resize".to_string(),
+            is_rush: Some("Yes".to_string()),
+            promise_date: Some("2024-03-15".to_string()),
+            storage_location: "Safe A".to_string(),
+            quote_amount: Some("$1,250.00".to_string()),
+            ..Default::default()
+        };
+        let ticket = validate_ticket_row(&row).unwrap();
+        assert!(ticket.is_rush);
+        assert_eq!(ticket.promise_date, NaiveDate::from_ymd_opt(2024, 3, 15));
+        assert_eq!(ticket.quote_amount, Some(Decimal::new(1250, 0)));
+
+        let bad_date = TicketCsvRow {
+            promise_date: Some("03/15/2024".to_string()),
+            ..row.clone()
+        };
+        assert_eq!(
+            validate_ticket_row(&bad_date).unwrap_err().message(),
+            "promise_date must be a date (YYYY-MM-DD)"
+        );
+
+        let negative = TicketCsvRow {
+            quote_amount: Some("-5".to_string()),
+            ..row
+        };
+        assert_eq!(
+            validate_ticket_row(&negative).unwrap_err().message(),
+            "quote_amount cannot be negative"
+        );
+    }
+
+    #[test]
+    fn test_contact_keys() {
+        let customer = CreateCustomer {
+            name: "Jane".to_string(),
+            phone: Some("+1 (555) 123-4567".to_string()),
+            email: Some("Jane@Example.com".to_string()),
+            is_training: false,
+        };
+        assert_eq!(
+            contact_keys(&customer),
+            vec!["phone:15551234567", "email:jane@example.com"]
+        );
+    }
+}
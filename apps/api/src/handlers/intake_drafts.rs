@@ -0,0 +1,430 @@
+//! Intake draft request handlers.
+//!
+//! A phone or voicemail transcription service posts each call to
+//! `/integrations/phone-intake`, signed with `PHONE_INTAKE_SECRET`, and the
+//! call becomes an intake draft. Staff review drafts under `/intake-drafts`
+//! and either convert one into a ticket, through the same path as
+//! `POST /tickets`, or dismiss it.
+
+use axum::{
+    body::Bytes,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use sha2::Sha256;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::imports::phone_digits;
+use crate::handlers::tickets::{
+    create_ticket_for, extract_employee_from_session, CreateTicketRequest, CreateTicketResponse,
+};
+use crate::middleware::{CurrentStore, TrainingMode};
+use crate::models::{CreateIntakeDraft, IntakeDraft, IntakeDraftSource, IntakeDraftStatus};
+use crate::repositories::{CustomerRepository, IntakeDraftRepository};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::validation::{
+    validate_optional, validate_phone, MAX_DESCRIPTION_LENGTH, MAX_NAME_LENGTH,
+    MAX_PARTNER_REFERENCE_LENGTH, MAX_PHONE_LENGTH, MAX_TRANSCRIPT_LENGTH, MAX_URL_LENGTH,
+};
+
+/// Header carrying the webhook signature: `sha256=<hex HMAC of the body>`.
+pub const PHONE_INTAKE_SIGNATURE_HEADER: &str = "X-Facet-Signature";
+
+/// Check a webhook signature against the raw request body.
+///
+/// Accepts the hex digest with or without a `sha256=` prefix.
+fn verify_signature(secret: &str, body: &[u8], signature: &str) -> bool {
+    let hex_digest = signature.trim();
+    let hex_digest = hex_digest.strip_prefix("sha256=").unwrap_or(hex_digest);
+    let Ok(expected) = hex::decode(hex_digest) else {
+        return false;
+    };
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+// =============================================================================
+// POST /integrations/phone-intake - Phone Call Webhook
+// =============================================================================
+
+/// A transcribed call sent by the phone intake service.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PhoneIntakePayload {
+    /// The service's ID for the call; repeated deliveries return the same draft
+    pub call_id: Option<String>,
+    /// Caller's name, as spoken or from caller ID
+    pub caller_name: Option<String>,
+    /// Caller's phone number
+    pub caller_phone: Option<String>,
+    /// Summary of the work the caller asked for
+    pub requested_work: Option<String>,
+    /// Full transcription of the call
+    pub transcript: Option<String>,
+    /// Link to the call recording
+    pub recording_url: Option<String>,
+    /// When the call was received (default: now)
+    pub received_at: Option<DateTime<Utc>>,
+}
+
+impl PhoneIntakePayload {
+    /// Validate the payload into a draft.
+    fn into_draft(self) -> Result<CreateIntakeDraft, AppError> {
+        let draft = CreateIntakeDraft {
+            source: IntakeDraftSource::Phone,
+            external_id: validate_optional(
+                self.call_id.as_deref(),
+                "call_id",
+                MAX_PARTNER_REFERENCE_LENGTH,
+            )?,
+            customer_name: validate_optional(
+                self.caller_name.as_deref(),
+                "caller_name",
+                MAX_NAME_LENGTH,
+            )?,
+            customer_phone: validate_phone(self.caller_phone.as_deref(), MAX_PHONE_LENGTH)?,
+            requested_work: validate_optional(
+                self.requested_work.as_deref(),
+                "requested_work",
+                MAX_DESCRIPTION_LENGTH,
+            )?,
+            transcript: validate_optional(
+                self.transcript.as_deref(),
+                "transcript",
+                MAX_TRANSCRIPT_LENGTH,
+            )?,
+            recording_url: validate_optional(
+                self.recording_url.as_deref(),
+                "recording_url",
+                MAX_URL_LENGTH,
+            )?,
+            received_at: self.received_at,
+        };
+
+        if draft.customer_phone.is_none()
+            && draft.requested_work.is_none()
+            && draft.transcript.is_none()
+        {
+            return Err(AppError::validation(
+                "caller_phone, requested_work, or transcript is required",
+            ));
+        }
+
+        Ok(draft)
+    }
+}
+
+/// POST /api/v1/integrations/phone-intake - Record a transcribed call as a draft.
+///
+/// The body must be signed with `PHONE_INTAKE_SECRET` in the
+/// `X-Facet-Signature` header. Returns 201 with the new draft, or 200 with
+/// the existing draft when the same `call_id` was already received.
+pub async fn receive_phone_intake(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<impl IntoResponse, AppError> {
+    let Some(secret) = state.phone_intake_secret.as_deref() else {
+        return Err(AppError::forbidden("Phone intake is not enabled"));
+    };
+
+    let signature = headers
+        .get(PHONE_INTAKE_SIGNATURE_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| {
+            AppError::unauthorized("Missing authentication. Provide X-Facet-Signature header.")
+        })?;
+    if !verify_signature(secret, &body, signature) {
+        return Err(AppError::unauthorized("Invalid webhook signature"));
+    }
+
+    let payload: PhoneIntakePayload = serde_json::from_slice(&body)
+        .map_err(|e| AppError::validation(format!("Invalid request body: {}", e)))?;
+    let (draft, created) = IntakeDraftRepository::create(&state.db, payload.into_draft()?).await?;
+
+    if created {
+        tracing::info!(draft_id = %draft.draft_id, "Phone intake draft received");
+    }
+    let status = if created {
+        StatusCode::CREATED
+    } else {
+        StatusCode::OK
+    };
+
+    Ok((status, Json(ApiResponse::success(draft))))
+}
+
+// =============================================================================
+// /intake-drafts - Staff Review
+// =============================================================================
+
+/// Query parameters for listing drafts.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct IntakeDraftListQuery {
+    /// Only drafts in this state (default: all)
+    pub status: Option<IntakeDraftStatus>,
+}
+
+/// GET /api/v1/intake-drafts - List intake drafts, oldest first.
+pub async fn list_intake_drafts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<IntakeDraftListQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    extract_employee_from_session(&state, &headers).await?;
+
+    let drafts = IntakeDraftRepository::list(&state.db, query.status).await?;
+
+    Ok(Json(ApiResponse::success(drafts)))
+}
+
+/// Find a draft or answer with the probe policy.
+async fn find_draft(state: &AppState, draft_id: Uuid) -> Result<IntakeDraft, AppError> {
+    IntakeDraftRepository::find_by_id(&state.db, draft_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("intake draft"))
+}
+
+/// GET /api/v1/intake-drafts/:draft_id - Get one intake draft.
+pub async fn get_intake_draft(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(draft_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    extract_employee_from_session(&state, &headers).await?;
+
+    let draft = find_draft(&state, draft_id).await?;
+
+    Ok(Json(ApiResponse::success(draft)))
+}
+
+/// Fill ticket fields the staff member left out from the draft.
+///
+/// The customer comes from `customer_id`, then `customer`, then
+/// `existing_customer_id` (a customer matching the caller's phone), then
+/// the caller's name and phone. `requested_work` comes from the draft.
+fn apply_draft_defaults(
+    body: &mut Map<String, Value>,
+    draft: &IntakeDraft,
+    existing_customer_id: Option<Uuid>,
+) {
+    let is_missing =
+        |body: &Map<String, Value>, field: &str| body.get(field).is_none_or(Value::is_null);
+
+    if is_missing(body, "customer_id") && is_missing(body, "customer") {
+        if let Some(customer_id) = existing_customer_id {
+            body.insert("customer_id".to_string(), json!(customer_id));
+        } else if let Some(name) = &draft.customer_name {
+            body.insert(
+                "customer".to_string(),
+                json!({ "name": name, "phone": draft.customer_phone }),
+            );
+        }
+    }
+
+    if is_missing(body, "requested_work") {
+        if let Some(requested_work) = &draft.requested_work {
+            body.insert("requested_work".to_string(), json!(requested_work));
+        }
+    }
+}
+
+/// Response for a converted draft.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConvertIntakeDraftResponse {
+    /// The draft, now marked converted (unchanged in training mode)
+    pub draft: IntakeDraft,
+    /// The created ticket
+    pub ticket: CreateTicketResponse,
+}
+
+/// POST /api/v1/intake-drafts/:draft_id/convert - Create a ticket from a draft.
+///
+/// Takes the same body as `POST /tickets`. The customer and
+/// `requested_work` may be omitted to use the draft's; a caller whose phone
+/// matches an existing customer is linked to that customer. In training
+/// mode a training ticket is created and the draft stays pending.
+pub async fn convert_intake_draft(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    training: TrainingMode,
+    store: CurrentStore,
+    Path(draft_id): Path<Uuid>,
+    Json(mut body): Json<Map<String, Value>>,
+) -> Result<impl IntoResponse, AppError> {
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    let draft = find_draft(&state, draft_id).await?;
+    if !draft.is_pending() {
+        return Err(AppError::conflict(
+            "Intake draft has already been converted or dismissed",
+        ));
+    }
+
+    // Training tickets can't belong to real customers
+    let existing_customer_id = match phone_digits(draft.customer_phone.as_deref()) {
+        Some(digits) if !training.0 => {
+            CustomerRepository::find_active_by_contact(&state.db, Some(&digits), None)
+                .await?
+                .map(|customer| customer.customer_id)
+        }
+        _ => None,
+    };
+    apply_draft_defaults(&mut body, &draft, existing_customer_id);
+
+    let request: CreateTicketRequest = serde_json::from_value(Value::Object(body))
+        .map_err(|e| AppError::validation(format!("Invalid request body: {}", e)))?;
+    let (ticket, warnings) = create_ticket_for(&state, &employee, training, store, request).await?;
+
+    let draft = if training.0 {
+        draft
+    } else {
+        IntakeDraftRepository::mark_converted(
+            &state.db,
+            draft_id,
+            ticket.ticket.ticket_id,
+            employee.employee_id,
+        )
+        .await?
+        .ok_or_else(|| AppError::conflict("Intake draft has already been converted or dismissed"))?
+    };
+
+    Ok((
+        StatusCode::CREATED,
+        Json(
+            ApiResponse::success(ConvertIntakeDraftResponse { draft, ticket })
+                .with_warnings(warnings),
+        ),
+    ))
+}
+
+/// POST /api/v1/intake-drafts/:draft_id/dismiss - Dismiss a draft without a ticket.
+pub async fn dismiss_intake_draft(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(draft_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    find_draft(&state, draft_id).await?;
+    let draft = IntakeDraftRepository::dismiss(&state.db, draft_id, employee.employee_id)
+        .await?
+        .ok_or_else(|| {
+            AppError::conflict("Intake draft has already been converted or dismissed")
+        })?;
+
+    Ok(Json(ApiResponse::success(draft)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn draft() -> IntakeDraft {
+        IntakeDraft {
+            draft_id: Uuid::new_v4(),
+            source: IntakeDraftSource::Phone,
+            external_id: Some("CA123".to_string()),
+            customer_name: Some("Jane Doe".to_string()),
+            customer_phone: Some("555-0100".to_string()),
+            requested_work: Some("Resize ring to 7".to_string()),
+            transcript: None,
+            recording_url: None,
+            received_at: Utc::now(),
+            status: IntakeDraftStatus::Pending,
+            ticket_id: None,
+            resolved_by: None,
+            resolved_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let body = br#"{"call_id":"CA123"}"#;
+        let signature = sign("secret", body);
+
+        assert!(verify_signature("secret", body, &signature));
+        assert!(verify_signature(
+            "secret",
+            body,
+            &format!("sha256={}", signature)
+        ));
+        assert!(!verify_signature("other", body, &signature));
+        assert!(!verify_signature("secret", b"{}", &signature));
+        assert!(!verify_signature("secret", body, "not-hex"));
+    }
+
+    #[test]
+    fn test_payload_requires_something_to_act_on() {
+        let payload = PhoneIntakePayload {
+            call_id: Some("CA123".to_string()),
+            caller_name: Some("Jane Doe".to_string()),
+            caller_phone: None,
+            requested_work: Some("  ".to_string()),
+            transcript: None,
+            recording_url: None,
+            received_at: None,
+        };
+        assert!(payload.clone().into_draft().is_err());
+
+        let draft = PhoneIntakePayload {
+            caller_phone: Some("+1 555 0100".to_string()),
+            ..payload
+        }
+        .into_draft()
+        .unwrap();
+        assert_eq!(draft.source, IntakeDraftSource::Phone);
+        assert_eq!(draft.customer_phone.as_deref(), Some("+1 555 0100"));
+        assert_eq!(draft.requested_work, None);
+    }
+
+    #[test]
+    fn test_apply_draft_defaults_fills_customer_and_work() {
+        let mut body = Map::new();
+        body.insert("requested_work".to_string(), Value::Null);
+        apply_draft_defaults(&mut body, &draft(), None);
+
+        assert_eq!(body["customer"]["name"], "Jane Doe");
+        assert_eq!(body["customer"]["phone"], "555-0100");
+        assert_eq!(body["requested_work"], "Resize ring to 7");
+    }
+
+    #[test]
+    fn test_apply_draft_defaults_prefers_existing_customer() {
+        let customer_id = Uuid::new_v4();
+        let mut body = Map::new();
+        apply_draft_defaults(&mut body, &draft(), Some(customer_id));
+
+        assert_eq!(body["customer_id"], json!(customer_id));
+        assert!(!body.contains_key("customer"));
+    }
+
+    #[test]
+    fn test_apply_draft_defaults_keeps_staff_values() {
+        let customer_id = Uuid::new_v4();
+        let mut body = Map::new();
+        body.insert("customer_id".to_string(), json!(customer_id));
+        body.insert("requested_work".to_string(), json!("Clean and polish"));
+        apply_draft_defaults(&mut body, &draft(), None);
+
+        assert_eq!(body["customer_id"], json!(customer_id));
+        assert!(!body.contains_key("customer"));
+        assert_eq!(body["requested_work"], "Clean and polish");
+    }
+}
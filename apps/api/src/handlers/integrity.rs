@@ -0,0 +1,27 @@
+//! Integrity check request handlers (admin only).
+
+use axum::{extract::State, http::HeaderMap, response::IntoResponse, Json};
+
+use crate::error::AppError;
+use crate::handlers::verify_admin_auth;
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::services::integrity::run_integrity_checks;
+
+// =============================================================================
+// GET /admin/integrity - Run Integrity Checks
+// =============================================================================
+
+/// GET /api/v1/admin/integrity - Run the consistency checks and report problems.
+///
+/// Read-only: problems are reported with a suggested fix, never repaired.
+pub async fn get_integrity_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let report = run_integrity_checks(&state.db, state.storage.as_ref()).await?;
+
+    Ok(Json(ApiResponse::success(report)))
+}
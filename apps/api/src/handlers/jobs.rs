@@ -0,0 +1,25 @@
+//! Background job status handlers (admin only).
+
+use axum::{extract::State, http::HeaderMap, response::IntoResponse, Json};
+
+use crate::error::AppError;
+use crate::handlers::verify_admin_auth;
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+
+// =============================================================================
+// GET /admin/jobs - Background Job Status
+// =============================================================================
+
+/// GET /api/v1/admin/jobs - List scheduled jobs with their last and next runs.
+///
+/// Jobs are listed in the order they were registered. Counts and errors
+/// cover runs since the server started.
+pub async fn list_jobs(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    Ok(Json(ApiResponse::success(state.jobs.snapshot())))
+}
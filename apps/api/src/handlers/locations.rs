@@ -1,7 +1,7 @@
 //! Storage location request handlers.
 
 use axum::{
-    extract::{Query, State},
+    extract::{Path, Query, State},
     http::HeaderMap,
     response::IntoResponse,
     Json,
@@ -9,14 +9,19 @@ use axum::{
 use serde::{Deserialize, Serialize};
 
 use crate::error::AppError;
-use crate::handlers::verify_admin_auth;
+use crate::handlers::verify_permission;
+use crate::middleware::{CurrentStore, TrainingMode};
+use crate::models::employee::Permission;
 use crate::models::storage_location::{
-    CreateStorageLocation, StorageLocationSummary, UpdateStorageLocation,
+    rank_locations, CreateStorageLocation, LocationSuggestion, StorageLocationSummary,
+    UpdateStorageLocation,
 };
+use crate::models::ticket::TicketSummary;
 use crate::repositories::StorageLocationRepository;
 use crate::response::{created, ApiResponse};
 use crate::routes::AppState;
 use crate::validation::{validate_required, MAX_NAME_LENGTH};
+use rust_decimal::Decimal;
 use uuid::Uuid;
 
 // =============================================================================
@@ -44,15 +49,17 @@ pub struct ListLocationsResponse {
 /// GET /api/v1/locations - List all storage locations.
 ///
 /// This endpoint is public and does not require authentication.
-/// Returns a list of storage locations.
+/// Returns the storage locations of the request's store.
 /// By default only active locations are returned.
 /// Use `?include_inactive=true` to include inactive locations.
 pub async fn list_locations(
     State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
     Query(query): Query<ListLocationsQuery>,
 ) -> Result<impl IntoResponse, AppError> {
     // Fetch locations from repository
-    let locations = StorageLocationRepository::list(&state.db, query.include_inactive).await?;
+    let locations =
+        StorageLocationRepository::list(&state.db, store_id, query.include_inactive).await?;
 
     let response = ListLocationsResponse {
         count: locations.len(),
@@ -62,31 +69,86 @@ pub async fn list_locations(
     Ok(Json(ApiResponse::success(response)))
 }
 
+// =============================================================================
+// GET /locations/suggest - Suggest Storage Location
+// =============================================================================
+
+/// Query parameters for a storage location suggestion.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SuggestLocationQuery {
+    /// Item type from the intake form (e.g., "watch")
+    pub item_type: Option<String>,
+    /// Quote amount, used by high-value rules
+    pub quote_amount: Option<Decimal>,
+}
+
+/// Response for a storage location suggestion.
+#[derive(Debug, Clone, Serialize)]
+pub struct SuggestLocationResponse {
+    /// Best location for the item (None if there are no active locations)
+    pub suggested: Option<LocationSuggestion>,
+    /// Remaining active locations in ranked order
+    pub alternatives: Vec<LocationSuggestion>,
+}
+
+/// GET /api/v1/locations/suggest - Suggest where to store an item.
+///
+/// Public like the location list, so the intake form can preselect a
+/// location. Matching storage rules (configured under
+/// `/settings/location-rules`) win by priority; ties and unmatched items go
+/// to the location with the fewest open tickets. Only the request's store's
+/// locations are suggested.
+pub async fn suggest_location(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+    Query(query): Query<SuggestLocationQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let rules = StorageLocationRepository::list_rules(&state.db).await?;
+    let occupancy = StorageLocationRepository::occupancy(&state.db, store_id).await?;
+
+    let mut ranked = rank_locations(
+        query.item_type.as_deref(),
+        query.quote_amount,
+        &rules,
+        &occupancy,
+    )
+    .into_iter();
+
+    let response = SuggestLocationResponse {
+        suggested: ranked.next(),
+        alternatives: ranked.collect(),
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
 // =============================================================================
 // POST /locations (admin) - Create Storage Location
 // =============================================================================
 
-/// POST /api/v1/locations - Create a new storage location (admin only).
+/// POST /api/v1/locations - Create a new storage location (admin or manage_locations).
 ///
 /// Requires admin authentication via X-Admin-Session header (preferred)
 /// or X-Admin-PIN header (deprecated).
-/// Creates a storage location with the provided name.
-/// Name must be unique (case-insensitive).
+/// Creates a storage location with the provided name in the request's store.
+/// Name must be unique within the store (case-insensitive).
 ///
 /// Returns the created location.
 pub async fn create_location(
     State(state): State<AppState>,
     headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
     Json(body): Json<CreateStorageLocation>,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify admin authentication (session or PIN)
-    verify_admin_auth(&state, &headers).await?;
+    // Verify admin authentication or the manage_locations permission
+    verify_permission(&state, &headers, Permission::ManageLocations).await?;
 
     // Validate and sanitize input
     let name = validate_required(&body.name, "name", MAX_NAME_LENGTH)?;
+    validate_capacity(body.capacity)?;
 
     // Check for duplicate name
-    let existing = StorageLocationRepository::find_by_name(&state.db, &name).await?;
+    let existing = StorageLocationRepository::find_by_name(&state.db, store_id, &name).await?;
     if existing.is_some() {
         return Err(AppError::validation(
             "A location with this name already exists",
@@ -94,43 +156,58 @@ pub async fn create_location(
     }
 
     // Create the location
-    let location =
-        StorageLocationRepository::create(&state.db, CreateStorageLocation { name }).await?;
-
-    // Return as StorageLocationSummary
-    let summary = StorageLocationSummary {
-        location_id: location.location_id,
-        name: location.name,
-        is_active: location.is_active,
-    };
+    let location = StorageLocationRepository::create(
+        &state.db,
+        store_id,
+        CreateStorageLocation {
+            name,
+            capacity: body.capacity,
+        },
+    )
+    .await?;
+
+    // Return as StorageLocationSummary with occupancy
+    let summary = StorageLocationRepository::find_summary(&state.db, location.location_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("location"))?;
 
     Ok(created(summary))
 }
 
+/// Reject a capacity that isn't a positive number of tickets.
+pub(crate) fn validate_capacity(capacity: Option<i32>) -> Result<(), AppError> {
+    match capacity {
+        Some(c) if c <= 0 => Err(AppError::validation(
+            "capacity must be a positive number of tickets",
+        )),
+        _ => Ok(()),
+    }
+}
+
 // =============================================================================
 // PUT /locations/:location_id (admin) - Update Storage Location
 // =============================================================================
 
-/// PUT /api/v1/locations/:location_id - Update a storage location (admin only).
+/// PUT /api/v1/locations/:location_id - Update a storage location (admin or manage_locations).
 ///
 /// Requires admin authentication via X-Admin-Session header (preferred)
 /// or X-Admin-PIN header (deprecated).
 /// Updates the location with the provided fields.
-/// Name must be unique (case-insensitive) if changed.
+/// Name must be unique within its store (case-insensitive) if changed.
 ///
 /// Returns the updated location.
 pub async fn update_location(
     State(state): State<AppState>,
     headers: HeaderMap,
-    axum::extract::Path(location_id): axum::extract::Path<Uuid>,
+    Path(location_id): Path<Uuid>,
     Json(body): Json<UpdateStorageLocation>,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify admin authentication (session or PIN)
-    verify_admin_auth(&state, &headers).await?;
+    // Verify admin authentication or the manage_locations permission
+    verify_permission(&state, &headers, Permission::ManageLocations).await?;
 
     // Find the existing location
     let existing = StorageLocationRepository::find_by_id(&state.db, location_id).await?;
-    let existing = existing.ok_or_else(|| AppError::not_found("Location not found"))?;
+    let existing = existing.ok_or_else(|| state.probe_policy.missing("location"))?;
 
     // Validate and sanitize name if provided
     let name = body
@@ -141,8 +218,9 @@ pub async fn update_location(
 
     // If name is being changed, check for duplicates
     if let Some(ref new_name) = name {
-        // Check if another location has this name (case-insensitive)
-        let duplicate = StorageLocationRepository::find_by_name(&state.db, new_name).await?;
+        // Check if another location in the store has this name (case-insensitive)
+        let duplicate =
+            StorageLocationRepository::find_by_name(&state.db, existing.store_id, new_name).await?;
         if let Some(dup) = duplicate {
             if dup.location_id != existing.location_id {
                 return Err(AppError::validation(
@@ -152,27 +230,135 @@ pub async fn update_location(
         }
     }
 
+    // A cleared capacity (null) is fine; a set one must be positive
+    if let Some(capacity) = body.capacity {
+        validate_capacity(capacity)?;
+    }
+
+    // Only an empty location may be deactivated
+    if body.is_active == Some(false) && existing.is_active {
+        ensure_location_empty(&state, location_id).await?;
+    }
+
     // Build the update input with validated name
     let update_input = UpdateStorageLocation {
         name,
         is_active: body.is_active,
+        capacity: body.capacity,
     };
 
     // Update the location
     let location = StorageLocationRepository::update(&state.db, location_id, update_input)
         .await?
-        .ok_or_else(|| AppError::not_found("Location not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("location"))?;
 
-    // Return as StorageLocationSummary
-    let summary = StorageLocationSummary {
-        location_id: location.location_id,
-        name: location.name,
-        is_active: location.is_active,
-    };
+    // Return as StorageLocationSummary with occupancy
+    let summary = StorageLocationRepository::find_summary(&state.db, location.location_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("location"))?;
+
+    Ok(Json(ApiResponse::success(summary)))
+}
+
+// =============================================================================
+// DELETE /locations/:location_id (admin) - Deactivate Storage Location
+// =============================================================================
+
+/// Refuse to retire a location that open tickets are stored at or headed to.
+async fn ensure_location_empty(state: &AppState, location_id: Uuid) -> Result<(), AppError> {
+    let open_tickets =
+        StorageLocationRepository::count_open_tickets(&state.db, location_id).await?;
+    if open_tickets > 0 {
+        return Err(AppError::conflict(format!(
+            "{} open ticket(s) are stored at or in transit to this location; move them first",
+            open_tickets
+        )));
+    }
+    Ok(())
+}
+
+/// DELETE /api/v1/locations/:location_id - Deactivate a storage location (admin or manage_locations).
+///
+/// Requires admin authentication via X-Admin-Session header (preferred)
+/// or X-Admin-PIN header (deprecated).
+/// The location is kept for ticket history but no longer offered for new
+/// tickets or moves. Fails with CONFLICT while open tickets are stored at
+/// or in transit to it. Deactivating an inactive location is a no-op.
+///
+/// Returns the deactivated location.
+pub async fn delete_location(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(location_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // Verify admin authentication or the manage_locations permission
+    verify_permission(&state, &headers, Permission::ManageLocations).await?;
+
+    // Find the location and check it's empty
+    StorageLocationRepository::find_by_id(&state.db, location_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("location"))?;
+    ensure_location_empty(&state, location_id).await?;
+
+    // Deactivate it
+    let location = StorageLocationRepository::update(
+        &state.db,
+        location_id,
+        UpdateStorageLocation {
+            name: None,
+            is_active: Some(false),
+            capacity: None,
+        },
+    )
+    .await?
+    .ok_or_else(|| state.probe_policy.missing("location"))?;
+
+    let summary = StorageLocationRepository::find_summary(&state.db, location.location_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("location"))?;
 
     Ok(Json(ApiResponse::success(summary)))
 }
 
+// =============================================================================
+// GET /locations/:location_id/tickets - Tickets Stored at a Location
+// =============================================================================
+
+/// Response for the tickets stored at a location.
+#[derive(Debug, Clone, Serialize)]
+pub struct LocationTicketsResponse {
+    pub location: StorageLocationSummary,
+    /// Open tickets stored there, rush first, then oldest first
+    pub tickets: Vec<TicketSummary>,
+    /// Total count of tickets returned
+    pub count: usize,
+}
+
+/// GET /api/v1/locations/:location_id/tickets - List open tickets stored at a location.
+///
+/// Public like the location list. Tickets in transit are listed at the
+/// location they left until the transfer is accepted. Training sessions see
+/// only training tickets.
+pub async fn list_location_tickets(
+    State(state): State<AppState>,
+    training: TrainingMode,
+    Path(location_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let location = StorageLocationRepository::find_summary(&state.db, location_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("location"))?;
+    let tickets =
+        StorageLocationRepository::list_open_tickets(&state.db, location_id, training.0).await?;
+
+    let response = LocationTicketsResponse {
+        location,
+        count: tickets.len(),
+        tickets,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,12 +406,16 @@ mod tests {
                         .unwrap(),
                     name: "Safe Drawer 1".to_string(),
                     is_active: true,
+                    capacity: None,
+                    open_tickets: 0,
                 },
                 StorageLocationSummary {
                     location_id: uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001")
                         .unwrap(),
                     name: "Workbench A".to_string(),
                     is_active: true,
+                    capacity: None,
+                    open_tickets: 0,
                 },
             ],
             count: 2,
@@ -247,12 +437,16 @@ mod tests {
                         .unwrap(),
                     name: "Active Location".to_string(),
                     is_active: true,
+                    capacity: None,
+                    open_tickets: 0,
                 },
                 StorageLocationSummary {
                     location_id: uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001")
                         .unwrap(),
                     name: "Inactive Location".to_string(),
                     is_active: false,
+                    capacity: None,
+                    open_tickets: 0,
                 },
             ],
             count: 2,
@@ -279,6 +473,25 @@ mod tests {
         assert_eq!(input.name, "  Workbench A  ");
     }
 
+    #[test]
+    fn test_create_storage_location_deserialize_capacity() {
+        let json = r#"{"name": "Safe Drawer 1", "capacity": 25}"#;
+        let input: CreateStorageLocation = serde_json::from_str(json).unwrap();
+        assert_eq!(input.capacity, Some(25));
+
+        let json = r#"{"name": "Safe Drawer 1"}"#;
+        let input: CreateStorageLocation = serde_json::from_str(json).unwrap();
+        assert!(input.capacity.is_none());
+    }
+
+    #[test]
+    fn test_validate_capacity() {
+        assert!(validate_capacity(None).is_ok());
+        assert!(validate_capacity(Some(1)).is_ok());
+        assert!(validate_capacity(Some(0)).is_err());
+        assert!(validate_capacity(Some(-5)).is_err());
+    }
+
     #[test]
     fn test_create_storage_location_missing_name() {
         let json = r#"{}"#;
@@ -294,12 +507,16 @@ mod tests {
             location_id: uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
             name: "Display Case".to_string(),
             is_active: true,
+            capacity: Some(30),
+            open_tickets: 12,
         };
 
         let json = serde_json::to_string(&summary).unwrap();
         assert!(json.contains("\"location_id\":\"550e8400-e29b-41d4-a716-446655440000\""));
         assert!(json.contains("\"name\":\"Display Case\""));
         assert!(json.contains("\"is_active\":true"));
+        assert!(json.contains("\"capacity\":30"));
+        assert!(json.contains("\"open_tickets\":12"));
     }
 
     #[test]
@@ -308,6 +525,8 @@ mod tests {
             location_id: uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap(),
             name: "Old Storage".to_string(),
             is_active: false,
+            capacity: None,
+            open_tickets: 0,
         };
 
         let json = serde_json::to_string(&summary).unwrap();
@@ -340,11 +559,59 @@ mod tests {
         assert_eq!(input.is_active, Some(true));
     }
 
+    #[test]
+    fn test_update_storage_location_deserialize_capacity() {
+        let input: UpdateStorageLocation = serde_json::from_str(r#"{"capacity": 10}"#).unwrap();
+        assert_eq!(input.capacity, Some(Some(10)));
+
+        let input: UpdateStorageLocation = serde_json::from_str(r#"{"capacity": null}"#).unwrap();
+        assert_eq!(input.capacity, Some(None));
+    }
+
     #[test]
     fn test_update_storage_location_deserialize_empty() {
         let json = r#"{}"#;
         let input: UpdateStorageLocation = serde_json::from_str(json).unwrap();
         assert!(input.name.is_none());
         assert!(input.is_active.is_none());
+        assert!(input.capacity.is_none());
+    }
+
+    // Tests for LocationTicketsResponse serialization
+
+    #[test]
+    fn test_location_tickets_response_serialization() {
+        let location_id = uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        let response = LocationTicketsResponse {
+            location: StorageLocationSummary {
+                location_id,
+                name: "Safe Drawer 1".to_string(),
+                is_active: true,
+                capacity: None,
+                open_tickets: 0,
+            },
+            tickets: vec![TicketSummary {
+                ticket_id: uuid::Uuid::new_v4(),
+                friendly_code: "JR-0001".to_string(),
+                customer_id: uuid::Uuid::new_v4(),
+                customer_name: "Jane Doe".to_string(),
+                item_type: Some("ring".to_string()),
+                item_description: "Gold band".to_string(),
+                status: crate::models::TicketStatus::InProgress,
+                is_rush: false,
+                promise_date: None,
+                quote_amount: None,
+                created_at: chrono::Utc::now(),
+                deleted_at: None,
+            }],
+            count: 1,
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["location"]["name"], "Safe Drawer 1");
+        assert_eq!(json["count"], 1);
+        assert_eq!(json["tickets"][0]["friendly_code"], "JR-0001");
+        assert_eq!(json["tickets"][0]["status"], "in_progress");
+        assert!(json["tickets"][0].get("deleted_at").is_none());
     }
 }
@@ -0,0 +1,405 @@
+//! Materials inventory handlers.
+//!
+//! The inventory lists stock the bench uses up (gold solder, sizing stock,
+//! findings) with the quantity on hand at the current store and unit cost.
+//! Recording a material against a ticket takes it out of the holding store's
+//! stock and keeps its cost, which the revenue report counts as cost of
+//! goods.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::tickets::extract_employee_from_session;
+use crate::handlers::verify_permission;
+use crate::middleware::{require_ticket_access, CurrentStore};
+use crate::models::material::{
+    CreateMaterial, CreateTicketMaterial, Material, TicketMaterialEntry, UpdateMaterial,
+};
+use crate::models::{Permission, TicketStatus};
+use crate::repositories::{MaterialRepository, TicketRepository};
+use crate::response::{created, ApiResponse};
+use crate::routes::AppState;
+use crate::validation::{
+    validate_required, MAX_MATERIAL_NAME_LENGTH, MAX_MATERIAL_QUANTITY, MAX_MATERIAL_UNIT_LENGTH,
+};
+
+// =============================================================================
+// GET /materials - List Materials
+// =============================================================================
+
+/// Query parameters for listing materials.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListMaterialsQuery {
+    /// Include inactive materials (default: false)
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+/// Response for listing materials.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListMaterialsResponse {
+    pub materials: Vec<Material>,
+}
+
+/// GET /api/v1/materials - List the materials inventory by name, with the
+/// current store's stock.
+///
+/// This endpoint is public, like the service catalog, so the bench can
+/// offer the materials. Only active materials are returned unless
+/// `?include_inactive=true`.
+pub async fn list_materials(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+    Query(query): Query<ListMaterialsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let materials = MaterialRepository::list(&state.db, store_id, query.include_inactive).await?;
+
+    Ok(Json(ApiResponse::success(ListMaterialsResponse {
+        materials,
+    })))
+}
+
+// =============================================================================
+// POST /materials - Create Material
+// =============================================================================
+
+/// POST /api/v1/materials - Add a material to the inventory (admin or manage_settings).
+///
+/// `quantity_on_hand` is the current store's starting stock; other stores
+/// start with none.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If the name or unit is blank, the name is taken, or
+///   the quantity or cost is out of range
+pub async fn create_material(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+    headers: HeaderMap,
+    Json(body): Json<CreateMaterial>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate the material
+    let name = validate_required(&body.name, "name", MAX_MATERIAL_NAME_LENGTH)?;
+    let unit = validate_required(&body.unit, "unit", MAX_MATERIAL_UNIT_LENGTH)?;
+    validate_stock(Some(body.quantity_on_hand), Some(body.unit_cost))?;
+    ensure_name_free(&state, &name, None).await?;
+
+    // 3. Create it
+    let material = MaterialRepository::create(
+        &state.db,
+        store_id,
+        CreateMaterial {
+            name,
+            unit,
+            quantity_on_hand: body.quantity_on_hand,
+            unit_cost: body.unit_cost,
+        },
+    )
+    .await?;
+
+    Ok(created(material))
+}
+
+// =============================================================================
+// PUT /materials/:material_id - Update Material
+// =============================================================================
+
+/// PUT /api/v1/materials/:material_id - Update a material (admin or manage_settings).
+///
+/// Setting `quantity_on_hand` records a restock or a count at the current
+/// store. Tickets keep the
+/// cost the material had when it was used; a new cost applies from then on.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the material doesn't exist
+/// - VALIDATION_ERROR: If the name or unit is blank, the name is taken, or
+///   the quantity or cost is out of range
+pub async fn update_material(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+    headers: HeaderMap,
+    Path(material_id): Path<Uuid>,
+    Json(body): Json<UpdateMaterial>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate the changes
+    let name = body
+        .name
+        .as_ref()
+        .map(|name| validate_required(name, "name", MAX_MATERIAL_NAME_LENGTH))
+        .transpose()?;
+    if let Some(ref name) = name {
+        ensure_name_free(&state, name, Some(material_id)).await?;
+    }
+    let unit = body
+        .unit
+        .as_ref()
+        .map(|unit| validate_required(unit, "unit", MAX_MATERIAL_UNIT_LENGTH))
+        .transpose()?;
+    validate_stock(body.quantity_on_hand, body.unit_cost)?;
+
+    // 3. Update the material
+    let material = MaterialRepository::update(
+        &state.db,
+        material_id,
+        store_id,
+        UpdateMaterial { name, unit, ..body },
+    )
+    .await?
+    .ok_or_else(|| state.probe_policy.missing("material"))?;
+
+    Ok(Json(ApiResponse::success(material)))
+}
+
+// =============================================================================
+// DELETE /materials/:material_id - Deactivate Material
+// =============================================================================
+
+/// DELETE /api/v1/materials/:material_id - Deactivate a material (admin or manage_settings).
+///
+/// The material is kept for ticket history but can no longer be recorded
+/// against tickets. Deactivating an inactive material is a no-op.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the material doesn't exist
+pub async fn delete_material(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+    headers: HeaderMap,
+    Path(material_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let material = MaterialRepository::update(
+        &state.db,
+        material_id,
+        store_id,
+        UpdateMaterial {
+            is_active: Some(false),
+            ..Default::default()
+        },
+    )
+    .await?
+    .ok_or_else(|| state.probe_policy.missing("material"))?;
+
+    Ok(Json(ApiResponse::success(material)))
+}
+
+// =============================================================================
+// /tickets/:ticket_id/materials - Materials Used on a Ticket
+// =============================================================================
+
+/// Request body for recording a material used on a ticket.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordMaterialRequest {
+    pub material_id: Uuid,
+    /// Quantity used, in the material's unit (must be greater than 0)
+    pub quantity: Decimal,
+}
+
+/// Response for a ticket's materials.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketMaterialsResponse {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    /// Materials used, oldest first
+    pub materials: Vec<TicketMaterialEntry>,
+    /// Total cost of the materials used
+    pub material_cost: Decimal,
+}
+
+/// Response for recording a material used on a ticket.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordMaterialResponse {
+    pub material: TicketMaterialEntry,
+    /// Stock left of the material at the ticket's store
+    pub quantity_on_hand: Decimal,
+    /// Total cost of the materials used on the ticket
+    pub material_cost: Decimal,
+}
+
+/// GET /api/v1/tickets/:ticket_id/materials - List the materials used on a ticket.
+pub async fn list_ticket_materials(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    let materials = MaterialRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+
+    Ok(Json(ApiResponse::success(TicketMaterialsResponse {
+        ticket_id,
+        friendly_code: ticket.friendly_code,
+        material_cost: materials.iter().map(|material| material.cost).sum(),
+        materials,
+    })))
+}
+
+/// POST /api/v1/tickets/:ticket_id/materials - Record a material used on a ticket.
+///
+/// Takes the quantity out of the stock of the store holding the ticket, at
+/// the material's current cost. Training
+/// tickets are recorded without touching stock. Requires X-Employee-Session
+/// header for attribution.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - FORBIDDEN: If the employee can't modify the ticket, or it's archived
+/// - NOT_FOUND: If the ticket or material doesn't exist
+/// - VALIDATION_ERROR: If the quantity is out of range, the material is
+///   inactive, or not enough is in stock
+pub async fn record_ticket_material(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<RecordMaterialRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    require_ticket_access(&employee, &ticket, Permission::ModifyOwnTicket)?;
+    if ticket.status == TicketStatus::Archived {
+        return Err(AppError::forbidden(
+            "Cannot record materials on an archived ticket",
+        ));
+    }
+
+    // 3. Validate the material and quantity
+    validate_quantity(body.quantity)?;
+    let material = MaterialRepository::find_by_id(&state.db, body.material_id, ticket.store_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("material"))?;
+    if !material.is_active {
+        return Err(AppError::validation("Material is inactive"));
+    }
+
+    // 4. Take it out of stock and record it
+    let entry = MaterialRepository::consume(
+        &state.db,
+        ticket.store_id,
+        CreateTicketMaterial {
+            ticket_id,
+            material_id: material.material_id,
+            quantity: body.quantity,
+            recorded_by: employee.employee_id,
+        },
+        !ticket.is_training,
+    )
+    .await?
+    .ok_or_else(|| AppError::validation(format!("Not enough {} in stock", material.name)))?;
+
+    // 5. Return it with the stock left and the ticket's material cost
+    let quantity_on_hand =
+        MaterialRepository::find_by_id(&state.db, material.material_id, ticket.store_id)
+            .await?
+            .map_or(Decimal::ZERO, |material| material.quantity_on_hand);
+    let material_cost = MaterialRepository::find_entries_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .iter()
+        .map(|material| material.cost)
+        .sum();
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(RecordMaterialResponse {
+            material: entry,
+            quantity_on_hand,
+            material_cost,
+        })),
+    ))
+}
+
+/// Refuse a name another material already has (case-insensitive).
+async fn ensure_name_free(
+    state: &AppState,
+    name: &str,
+    material_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let existing = MaterialRepository::find_id_by_name(&state.db, name).await?;
+    if existing.is_some_and(|existing| Some(existing) != material_id) {
+        return Err(AppError::validation(
+            "A material with this name already exists",
+        ));
+    }
+    Ok(())
+}
+
+/// Check a material's quantity on hand and unit cost.
+fn validate_stock(
+    quantity_on_hand: Option<Decimal>,
+    unit_cost: Option<Decimal>,
+) -> Result<(), AppError> {
+    if quantity_on_hand
+        .is_some_and(|quantity| quantity < Decimal::ZERO || quantity > max_quantity())
+    {
+        return Err(AppError::validation(format!(
+            "quantity_on_hand must be between 0 and {}",
+            MAX_MATERIAL_QUANTITY
+        )));
+    }
+    if unit_cost.is_some_and(|cost| cost < Decimal::ZERO) {
+        return Err(AppError::validation("unit_cost cannot be negative"));
+    }
+    Ok(())
+}
+
+/// Check a quantity used on a ticket.
+fn validate_quantity(quantity: Decimal) -> Result<(), AppError> {
+    if quantity <= Decimal::ZERO || quantity > max_quantity() {
+        return Err(AppError::validation(format!(
+            "quantity must be greater than 0 and at most {}",
+            MAX_MATERIAL_QUANTITY
+        )));
+    }
+    Ok(())
+}
+
+/// [`MAX_MATERIAL_QUANTITY`] as a decimal.
+fn max_quantity() -> Decimal {
+    Decimal::from(MAX_MATERIAL_QUANTITY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_stock() {
+        assert!(validate_stock(None, None).is_ok());
+        assert!(validate_stock(Some(Decimal::new(12_500, 3)), Some(Decimal::new(4000, 2))).is_ok());
+        assert!(validate_stock(Some(Decimal::ZERO), Some(Decimal::ZERO)).is_ok());
+        assert!(validate_stock(Some(Decimal::new(-1, 0)), None).is_err());
+        assert!(validate_stock(Some(max_quantity() + Decimal::ONE), None).is_err());
+        assert!(validate_stock(None, Some(Decimal::new(-1, 2))).is_err());
+    }
+
+    #[test]
+    fn test_validate_quantity() {
+        assert!(validate_quantity(Decimal::new(5, 1)).is_ok());
+        assert!(validate_quantity(max_quantity()).is_ok());
+        assert!(validate_quantity(Decimal::ZERO).is_err());
+        assert!(validate_quantity(Decimal::new(-1, 0)).is_err());
+        assert!(validate_quantity(max_quantity() + Decimal::ONE).is_err());
+    }
+}
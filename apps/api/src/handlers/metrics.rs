@@ -0,0 +1,132 @@
+//! Prometheus metrics handler.
+
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+};
+
+use crate::error::AppError;
+use crate::middleware::Exposition;
+use crate::routes::AppState;
+use crate::utils::circuit_breaker::BreakerState;
+
+/// GET /metrics - Request, pool, rate limiter, and breaker metrics.
+///
+/// Served in the Prometheus text format rather than the JSON envelope. Only
+/// available when `METRICS_ENABLED` is set; the endpoint is unauthenticated,
+/// so keep it off the public network.
+pub async fn get_metrics(State(state): State<AppState>) -> Result<Response, AppError> {
+    let Some(metrics) = &state.metrics else {
+        return Err(AppError::not_found(
+            "Metrics are disabled; set METRICS_ENABLED to serve them",
+        ));
+    };
+
+    let mut out = Exposition::new();
+    metrics.render(&mut out);
+
+    // Database pool
+    let size = state.db.size();
+    let idle = state.db.num_idle() as u32;
+    out.family(
+        "db_pool_connections",
+        "gauge",
+        "Database pool connections by state",
+    );
+    out.sample("db_pool_connections", &[("state", "idle")], idle);
+    out.sample(
+        "db_pool_connections",
+        &[("state", "active")],
+        size.saturating_sub(idle),
+    );
+    out.family(
+        "db_pool_max_connections",
+        "gauge",
+        "Maximum database pool connections",
+    );
+    out.sample(
+        "db_pool_max_connections",
+        &[],
+        state.db.options().get_max_connections(),
+    );
+
+    // Rate limiters
+    let limiters = [
+        ("pin", &state.rate_limit),
+        ("recovery", &state.recovery_rate_limit),
+    ];
+    let mut tracked = Vec::new();
+    for (name, limiter) in limiters {
+        tracked.push((name, limiter.tracked_clients().await));
+    }
+    out.family(
+        "rate_limit_tracked_clients",
+        "gauge",
+        "Client IPs with recorded authentication failures",
+    );
+    for (name, (clients, _)) in &tracked {
+        out.sample("rate_limit_tracked_clients", &[("limiter", name)], clients);
+    }
+    out.family(
+        "rate_limit_clients_in_backoff",
+        "gauge",
+        "Client IPs currently blocked by failure backoff",
+    );
+    for (name, (_, in_backoff)) in &tracked {
+        out.sample(
+            "rate_limit_clients_in_backoff",
+            &[("limiter", name)],
+            in_backoff,
+        );
+    }
+    out.family(
+        "rate_limit_partner_limiters",
+        "gauge",
+        "Partners with an active API rate limiter",
+    );
+    out.sample(
+        "rate_limit_partner_limiters",
+        &[],
+        state.partner_rate_limits.partner_count().await,
+    );
+
+    // Circuit breakers
+    let mut breakers: Vec<_> = state.storage.breaker_status().into_iter().collect();
+    breakers.extend(state.notifications.breakers());
+    out.family(
+        "circuit_breaker_state",
+        "gauge",
+        "Provider circuit breaker state (0 closed, 1 half-open, 2 open)",
+    );
+    for breaker in &breakers {
+        let value = match breaker.state {
+            BreakerState::Closed => 0,
+            BreakerState::HalfOpen => 1,
+            BreakerState::Open => 2,
+        };
+        out.sample(
+            "circuit_breaker_state",
+            &[("provider", breaker.name)],
+            value,
+        );
+    }
+    out.family(
+        "circuit_breaker_consecutive_failures",
+        "gauge",
+        "Consecutive failed calls to the provider",
+    );
+    for breaker in &breakers {
+        out.sample(
+            "circuit_breaker_consecutive_failures",
+            &[("provider", breaker.name)],
+            breaker.consecutive_failures,
+        );
+    }
+
+    Ok((
+        [(header::CONTENT_TYPE, Exposition::CONTENT_TYPE)],
+        out.finish(),
+    )
+        .into_response())
+}
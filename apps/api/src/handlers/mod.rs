@@ -4,22 +4,117 @@
 //! Business logic is delegated to services.
 
 pub mod admin;
+pub mod appraisals;
+pub mod audit_log;
+pub mod calendar;
+pub mod campaigns;
+pub mod config;
 pub mod customers;
+pub mod debug;
+pub mod docs;
 pub mod employees;
+pub mod errors;
+pub mod imports;
+pub mod intake_drafts;
+pub mod integrity;
+pub mod jobs;
 pub mod locations;
+pub mod materials;
+pub mod metrics;
+pub mod movements;
+pub mod partners;
+pub mod public;
+pub mod reports;
+pub mod services;
 pub mod settings;
+pub mod storage;
+pub mod stores;
 pub mod tickets;
+pub mod time_entries;
+pub mod transfers;
+pub mod webhooks;
 
-pub use admin::{admin_logout, admin_setup, change_pin, verify_admin, verify_admin_auth};
-pub use customers::{get_customer, search_customers};
+pub use admin::{
+    admin_logout, admin_setup, change_pin, list_recovery_attempts, recover_admin,
+    regenerate_recovery_code, verify_admin, verify_admin_auth, verify_permission,
+};
+pub use appraisals::{delete_appraisal, get_appraisal, get_appraisal_pdf, save_appraisal};
+pub use audit_log::list_audit_log;
+pub use calendar::get_promise_date_feed;
+pub use campaigns::{
+    abort_campaign, create_campaign, get_campaign, list_campaign_recipients, list_campaigns,
+    preview_campaign,
+};
+pub use config::{export_config, import_config};
+pub use customers::{
+    create_customer, delete_customer, get_customer, merge_customer, search_customers,
+    update_customer,
+};
+pub use debug::{get_debug_capture, list_request_logs, update_debug_capture};
+pub use docs::{get_api_docs, get_openapi_spec};
 pub use employees::{
-    create_employee, delete_employee, employee_logout, list_employees, update_employee,
-    verify_employee_pin,
+    create_employee, create_pin_challenge, delete_employee, employee_logout,
+    get_employee_permissions, get_my_profile, list_active_employees, list_employees,
+    set_employee_permissions, set_training_mode, unlock_employee, update_employee,
+    verify_employee_pin, verify_employee_pin_challenge,
+};
+pub use errors::get_error_catalog;
+pub use imports::{import_customers, import_tickets};
+pub use intake_drafts::{
+    convert_intake_draft, dismiss_intake_draft, get_intake_draft, list_intake_drafts,
+    receive_phone_intake,
+};
+pub use integrity::get_integrity_report;
+pub use jobs::list_jobs;
+pub use locations::{
+    create_location, delete_location, list_location_tickets, list_locations, suggest_location,
+    update_location,
+};
+pub use materials::{
+    create_material, delete_material, list_materials, list_ticket_materials,
+    record_ticket_material, update_material,
 };
-pub use locations::{create_location, list_locations, update_location};
-pub use settings::{get_settings, update_settings};
+pub use metrics::get_metrics;
+pub use movements::{list_ticket_movements, record_movement};
+pub use partners::{
+    create_partner, list_partners, partner_create_ticket, partner_get_ticket, partner_list_tickets,
+    rotate_partner_key, update_partner,
+};
+pub use public::get_public_ticket_status;
+pub use reports::{
+    create_custom_report, daily_closeout_pdf, daily_closeout_report, delete_custom_report,
+    employee_report, list_custom_reports, location_audit_report, overdue_report, partner_report,
+    quality_report, queue_trends_report, revenue_report, run_custom_report, service_report,
+    throughput_report, update_custom_report,
+};
+pub use services::{create_service, delete_service, list_services, update_service};
+pub use settings::{
+    delete_receipt_logo, get_item_types, get_location_rules, get_metal_prices,
+    get_promise_date_reasons, get_rush_pricing, get_settings, get_settings_history,
+    get_settings_section, list_notification_templates, patch_settings_section,
+    revoke_calendar_feed_token, rollback_settings, rotate_calendar_feed_token, update_item_types,
+    update_location_rules, update_metal_prices, update_notification_template,
+    update_promise_date_reasons, update_rush_pricing, update_settings, upload_receipt_logo,
+    validate_template,
+};
+pub use storage::{get_stored_object, reconcile_storage};
+pub use stores::{create_store, delete_store, list_stores};
 pub use tickets::{
-    add_note, change_status, close_ticket, create_ticket, delete_photo, delete_ticket,
-    get_label_pdf, get_queue, get_receipt_pdf, get_ticket, list_tickets, restore_ticket,
-    toggle_rush, update_ticket, upload_photo,
+    add_note, approve_quote, archive_ticket, assign_ticket, bulk_change_status, change_status,
+    close_ticket, create_authorized_pickup, create_ticket, decline_quote, delete_photo,
+    delete_ticket, get_custody_chain, get_custody_report_pdf, get_invoice_pdf, get_label_pdf,
+    get_photo, get_pickup_signature, get_queue, get_receipt, get_receipt_escpos, get_receipt_pdf,
+    get_receipt_text, get_ticket, get_ticket_history, get_work_order_pdf, list_authorized_pickups,
+    list_contacts, list_payments, list_tickets, log_contact, quote_ticket, record_custody_handoff,
+    record_defect, record_payment, record_qc_check, reopen_ticket, reorder_queue, restore_ticket,
+    revoke_authorized_pickup, send_quote, suggest_promise_date, toggle_rush, update_ticket,
+    upload_photo,
+};
+pub use time_entries::{list_time_entries, start_time_entry, stop_time_entry};
+pub use transfers::{
+    accept_transfer, cancel_transfer, create_transfer, list_ticket_transfers, list_transfers,
+};
+pub use webhooks::{
+    create_webhook, delete_webhook, list_webhook_deliveries, list_webhooks,
+    redeliver_webhook_delivery, rotate_webhook_secret, update_webhook,
 };
@@ -0,0 +1,214 @@
+//! Ticket movement handlers.
+//!
+//! Staff record an item leaving its storage location for a bench, passing
+//! between benches, and going back, so the shop always knows who has it.
+//! Changing where an item is stored is a ticket update or a transfer; a
+//! movement only ever returns the item to its storage location. High-value
+//! items also record each movement as a witnessed custody handoff.
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::tickets::{
+    custody_witness, ensure_not_in_transit, extract_employee_from_session,
+};
+use crate::models::{
+    CreateCustodyEvent, CreateTicketMovement, CurrentLocation, CustodyEventType, CustodyWitness,
+    TicketMovementEntry, TicketStatus,
+};
+use crate::repositories::{
+    CustodyRepository, MovementRepository, StoreSettingsRepository, TicketRepository,
+};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::validation::{validate_employee, validate_optional, MAX_NOTE_LENGTH};
+
+// =============================================================================
+// POST /tickets/:ticket_id/movements - Record a Movement
+// =============================================================================
+
+/// Request body for recording a movement. Exactly one destination is given.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordMovementRequest {
+    /// Storage location the item is going back to (must be the ticket's)
+    pub to_location_id: Option<Uuid>,
+    /// Employee taking the item to their bench
+    pub to_employee_id: Option<Uuid>,
+    pub notes: Option<String>,
+    /// Witness for the movement (required for high-value items)
+    pub custody: Option<CustodyWitness>,
+}
+
+/// POST /api/v1/tickets/:ticket_id/movements - Record an item moving.
+///
+/// Checks the item out to an employee's bench, or back into the ticket's
+/// storage location. Where it comes from is taken from its last movement.
+/// Closed, archived, and in-transit tickets can't be moved. Any active
+/// employee can record a movement.
+pub async fn record_movement(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<RecordMovementRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket and where its item is now
+    let detail = TicketRepository::find_detail(&state.db, ticket_id, false)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    let ticket = detail.ticket;
+    let latest = MovementRepository::find_latest_entry_by_ticket_id(&state.db, ticket_id).await?;
+    let current = CurrentLocation::derive(
+        ticket.storage_location_id,
+        detail.storage_location_name,
+        latest.as_ref(),
+    );
+
+    // 3. Validate the movement
+    if matches!(ticket.status, TicketStatus::Closed | TicketStatus::Archived) {
+        return Err(AppError::validation(
+            "Closed or archived tickets cannot be moved",
+        ));
+    }
+    ensure_not_in_transit(&state, ticket_id).await?;
+    match (body.to_location_id, body.to_employee_id) {
+        (Some(location_id), None) => {
+            if location_id != ticket.storage_location_id {
+                return Err(AppError::validation(
+                    "Items can only be checked back into the ticket's storage location",
+                ));
+            }
+            if !current.is_checked_out() {
+                return Err(AppError::validation(
+                    "Item is already in its storage location",
+                ));
+            }
+        }
+        (None, Some(employee_id)) => {
+            if current.employee_id == Some(employee_id) {
+                return Err(AppError::validation("Item is already with this employee"));
+            }
+            validate_employee(&state.db, employee_id).await?;
+        }
+        _ => {
+            return Err(AppError::validation(
+                "Provide exactly one of to_location_id or to_employee_id",
+            ));
+        }
+    }
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
+
+    // 4. Validate the witness (required for high-value items)
+    let threshold =
+        StoreSettingsRepository::get_custody_value_threshold(&state.db, ticket.store_id).await?;
+    let witness = custody_witness(
+        &state,
+        employee.employee_id,
+        body.custody.as_ref(),
+        ticket.requires_custody(threshold),
+    )
+    .await?;
+
+    // 5. Record the movement, and the handoff in the chain of custody
+    let (from_location_id, from_employee_id) = match current.employee_id {
+        Some(employee_id) => (None, Some(employee_id)),
+        None => (Some(current.location_id), None),
+    };
+    let mut tx = state.db.begin().await?;
+    let movement = MovementRepository::create(
+        &mut *tx,
+        CreateTicketMovement {
+            ticket_id,
+            from_location_id,
+            from_employee_id,
+            to_location_id: body.to_location_id,
+            to_employee_id: body.to_employee_id,
+            moved_by: employee.employee_id,
+            notes: notes.clone(),
+        },
+    )
+    .await?;
+    if let Some(witness) = witness {
+        CustodyRepository::create(
+            &mut *tx,
+            CreateCustodyEvent {
+                ticket_id,
+                event_type: CustodyEventType::Handoff,
+                from_location_id,
+                to_location_id: body.to_location_id,
+                handled_by: employee.employee_id,
+                witnessed_by: Some(witness.witnessed_by),
+                notes: witness.notes.or(notes),
+            },
+        )
+        .await?;
+    }
+    tx.commit().await?;
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(movement))))
+}
+
+// =============================================================================
+// GET /tickets/:ticket_id/movements - Movement History
+// =============================================================================
+
+/// Response for a ticket's movement history.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketMovementsResponse {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    /// Where the item is now
+    pub current_location: CurrentLocation,
+    /// Movements, oldest first
+    pub movements: Vec<TicketMovementEntry>,
+}
+
+/// GET /api/v1/tickets/:ticket_id/movements - List a ticket's movements.
+pub async fn list_ticket_movements(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Find the ticket
+    let detail = TicketRepository::find_detail(&state.db, ticket_id, false)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 2. Load movements, oldest first; the last one says where the item is
+    let movements = MovementRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+
+    let response = TicketMovementsResponse {
+        ticket_id,
+        current_location: CurrentLocation::derive(
+            detail.ticket.storage_location_id,
+            detail.storage_location_name,
+            movements.last(),
+        ),
+        friendly_code: detail.ticket.friendly_code,
+        movements,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_movement_request_deserialize() {
+        let json = r#"{"to_employee_id": "00000000-0000-0000-0000-000000000000"}"#;
+        let request: RecordMovementRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.to_employee_id, Some(Uuid::nil()));
+        assert!(request.to_location_id.is_none());
+        assert!(request.custody.is_none());
+    }
+}
@@ -0,0 +1,610 @@
+//! Partner API request handlers.
+//!
+//! Admins manage partner accounts under `/admin/partners`. Partner jewelers
+//! authenticate with `X-Partner-Key` and use `/partner/tickets` to submit
+//! trade work and follow it. Partners only see tickets for their own
+//! customer record, through a view without internal details.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::audit_log::{record_audit, AuditEvent};
+use crate::handlers::tickets::{admin_actor, PaginationInfo};
+use crate::handlers::verify_admin_auth;
+use crate::middleware::{ClientIp, CurrentStore};
+use crate::models::audit_log::AuditAction;
+use crate::models::{
+    rank_locations, CreateCustomer, CreatePartner, CreateStatusHistory, CreateTicket, Partner,
+    PartnerTicket, TicketStatus, UpdatePartner, DEFAULT_PARTNER_RATE_LIMIT,
+};
+use crate::repositories::{
+    CustomerRepository, PartnerRepository, StatusHistoryRepository, StorageLocationRepository,
+    TicketRepository,
+};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::services::webhooks;
+use crate::validation::warnings::ticket_warnings;
+use crate::validation::{
+    validate_email, validate_employee, validate_optional, validate_phone, validate_required,
+    MAX_DESCRIPTION_LENGTH, MAX_EMAIL_LENGTH, MAX_ITEM_TYPE_LENGTH, MAX_NAME_LENGTH,
+    MAX_PARTNER_REFERENCE_LENGTH, MAX_PHONE_LENGTH, PARTNER_RATE_LIMIT_RANGE,
+};
+
+/// Validate a partner's requests-per-minute limit.
+fn validate_rate_limit(rate_limit_per_minute: i32) -> Result<i32, AppError> {
+    if !PARTNER_RATE_LIMIT_RANGE.contains(&rate_limit_per_minute) {
+        return Err(AppError::validation(format!(
+            "rate_limit_per_minute must be between {} and {}",
+            PARTNER_RATE_LIMIT_RANGE.start(),
+            PARTNER_RATE_LIMIT_RANGE.end()
+        )));
+    }
+    Ok(rate_limit_per_minute)
+}
+
+// =============================================================================
+// /admin/partners - Partner Accounts (Admin Only)
+// =============================================================================
+
+/// Request body for creating a partner.
+///
+/// Links an existing customer with `customer_id`, or creates a customer
+/// record for the partner shop from `name`, `phone`, and `email`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreatePartnerRequest {
+    /// Partner shop name (required)
+    pub name: String,
+    /// Existing customer record for the shop
+    pub customer_id: Option<Uuid>,
+    /// Phone for a new customer record
+    pub phone: Option<String>,
+    /// Email for a new customer record
+    pub email: Option<String>,
+    /// Requests per minute (default: 30)
+    pub rate_limit_per_minute: Option<i32>,
+    /// Employee recorded as taking in submitted tickets (default: the admin)
+    pub taken_in_by: Option<Uuid>,
+}
+
+/// A partner with its API key, returned only when the key is issued.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartnerKeyResponse {
+    #[serde(flatten)]
+    pub partner: Partner,
+    /// The API key; shown once and not stored
+    pub api_key: String,
+}
+
+/// Response for listing partners.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListPartnersResponse {
+    pub partners: Vec<Partner>,
+}
+
+/// GET /api/v1/admin/partners - List partner accounts (admin only).
+pub async fn list_partners(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let partners = PartnerRepository::list(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(ListPartnersResponse {
+        partners,
+    })))
+}
+
+/// POST /api/v1/admin/partners - Create a partner account (admin only).
+///
+/// Returns the API key once; only its hash is stored.
+pub async fn create_partner(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<CreatePartnerRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication
+    verify_admin_auth(&state, &headers).await?;
+
+    // 2. Validate input
+    let name = validate_required(&body.name, "name", MAX_NAME_LENGTH)?;
+    let rate_limit_per_minute = validate_rate_limit(
+        body.rate_limit_per_minute
+            .unwrap_or(DEFAULT_PARTNER_RATE_LIMIT),
+    )?;
+    let taken_in_by = match body.taken_in_by {
+        Some(employee_id) => {
+            validate_employee(&state.db, employee_id).await?;
+            employee_id
+        }
+        None => admin_actor(&state, &headers).await?.employee_id,
+    };
+
+    // 3. Link or create the partner's customer record
+    let customer_id = match body.customer_id {
+        Some(customer_id) => {
+            CustomerRepository::find_active_by_id(&state.db, customer_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("Customer not found"))?;
+            if PartnerRepository::find_by_customer_id(&state.db, customer_id)
+                .await?
+                .is_some()
+            {
+                return Err(AppError::conflict(
+                    "Customer is already linked to a partner",
+                ));
+            }
+            customer_id
+        }
+        None => {
+            let phone = validate_phone(body.phone.as_deref(), MAX_PHONE_LENGTH)?;
+            let email = validate_email(body.email.as_deref(), MAX_EMAIL_LENGTH)?;
+            let customer = CustomerRepository::create(
+                &state.db,
+                CreateCustomer {
+                    name: name.clone(),
+                    phone,
+                    email,
+                    is_training: false,
+                },
+            )
+            .await?;
+            customer.customer_id
+        }
+    };
+
+    // 4. Issue the key and create the partner
+    let api_key = PartnerRepository::generate_api_key();
+    let partner = PartnerRepository::create(
+        &state.db,
+        CreatePartner {
+            name,
+            customer_id,
+            api_key_prefix: PartnerRepository::display_prefix(&api_key),
+            api_key_hash: PartnerRepository::hash_api_key(&api_key),
+            rate_limit_per_minute,
+            taken_in_by,
+        },
+    )
+    .await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::PartnerCreated)
+            .target("partner", partner.partner_id)
+            .summary(serde_json::json!({
+                "name": partner.name,
+                "api_key_prefix": partner.api_key_prefix,
+            })),
+    )
+    .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(PartnerKeyResponse {
+            partner,
+            api_key,
+        })),
+    ))
+}
+
+/// PUT /api/v1/admin/partners/:partner_id - Update a partner account (admin only).
+///
+/// Setting `is_active` to false revokes API access immediately.
+pub async fn update_partner(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(partner_id): Path<Uuid>,
+    Json(body): Json<UpdatePartner>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication
+    verify_admin_auth(&state, &headers).await?;
+
+    // 2. Validate provided fields
+    let name = body
+        .name
+        .as_deref()
+        .map(|name| validate_required(name, "name", MAX_NAME_LENGTH))
+        .transpose()?;
+    let rate_limit_per_minute = body
+        .rate_limit_per_minute
+        .map(validate_rate_limit)
+        .transpose()?;
+    if let Some(employee_id) = body.taken_in_by {
+        validate_employee(&state.db, employee_id).await?;
+    }
+
+    let audit_summary = serde_json::json!({
+        "name": name,
+        "rate_limit_per_minute": rate_limit_per_minute,
+        "taken_in_by": body.taken_in_by,
+        "is_active": body.is_active,
+    });
+
+    // 3. Update
+    let partner = PartnerRepository::update(
+        &state.db,
+        partner_id,
+        UpdatePartner {
+            name,
+            rate_limit_per_minute,
+            taken_in_by: body.taken_in_by,
+            is_active: body.is_active,
+        },
+    )
+    .await?
+    .ok_or_else(|| state.probe_policy.missing("partner"))?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::PartnerUpdated)
+            .target("partner", partner_id)
+            .summary(audit_summary),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(partner)))
+}
+
+/// POST /api/v1/admin/partners/:partner_id/rotate-key - Issue a new API key (admin only).
+///
+/// The old key stops working immediately.
+pub async fn rotate_partner_key(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(partner_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    PartnerRepository::find_by_id(&state.db, partner_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("partner"))?;
+
+    let api_key = PartnerRepository::generate_api_key();
+    let partner = PartnerRepository::set_api_key(
+        &state.db,
+        partner_id,
+        &PartnerRepository::display_prefix(&api_key),
+        &PartnerRepository::hash_api_key(&api_key),
+    )
+    .await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::PartnerKeyRotated)
+            .target("partner", partner_id)
+            .summary(serde_json::json!({ "api_key_prefix": partner.api_key_prefix })),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(PartnerKeyResponse {
+        partner,
+        api_key,
+    })))
+}
+
+// =============================================================================
+// /partner/tickets - Partner Intake and Status
+// =============================================================================
+
+/// Authenticate a partner from the X-Partner-Key header.
+///
+/// Applies the partner's own rate limit and counts the request toward its
+/// daily usage, including requests rejected by the limit.
+async fn authenticate_partner(state: &AppState, headers: &HeaderMap) -> Result<Partner, AppError> {
+    let key = headers
+        .get("X-Partner-Key")
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .ok_or_else(|| {
+            AppError::unauthorized("Missing authentication. Provide X-Partner-Key header.")
+        })?;
+
+    let partner = PartnerRepository::find_active_by_api_key(&state.db, key)
+        .await?
+        .ok_or_else(|| AppError::unauthorized("Invalid partner API key"))?;
+
+    let limit = state
+        .partner_rate_limits
+        .check(partner.partner_id, partner.rate_limit_per_minute as u32)
+        .await;
+    PartnerRepository::record_usage(&state.db, partner.partner_id, limit.is_err()).await?;
+
+    if let Err(retry_after) = limit {
+        tracing::warn!(
+            partner_id = %partner.partner_id,
+            retry_after = retry_after,
+            "Partner request blocked by rate limit"
+        );
+        return Err(AppError::rate_limited(
+            "Partner rate limit exceeded. Please wait before trying again.",
+            retry_after,
+        ));
+    }
+
+    Ok(partner)
+}
+
+/// Request body for a partner ticket submission.
+///
+/// Pricing, storage, and customer details are set by the shop, not the
+/// partner.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PartnerIntakeRequest {
+    /// The partner's own job number
+    pub partner_reference: Option<String>,
+    /// Item type (e.g., "ring", "necklace")
+    pub item_type: Option<String>,
+    /// Description of the item (required)
+    pub item_description: String,
+    /// Notes about the item's condition (required)
+    pub condition_notes: String,
+    /// Description of requested work (required)
+    pub requested_work: String,
+    /// Requested completion date
+    pub promise_date: Option<NaiveDate>,
+    /// Whether this is a rush job
+    #[serde(default)]
+    pub is_rush: bool,
+}
+
+/// Query parameters for listing a partner's tickets.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PartnerTicketsQuery {
+    /// Filter by status
+    pub status: Option<TicketStatus>,
+    /// Limit results (default: 50, max 200)
+    pub limit: Option<i64>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+}
+
+/// Response for listing a partner's tickets.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartnerTicketsResponse {
+    pub tickets: Vec<PartnerTicket>,
+    pub pagination: PaginationInfo,
+}
+
+/// POST /api/v1/partner/tickets - Submit trade work.
+///
+/// Creates an intake ticket for the partner's customer record, attributed
+/// to the partner's intake employee. The storage location is picked the
+/// same way as `/locations/suggest`, in the store named by `X-Store-ID`
+/// (the primary store by default).
+pub async fn partner_create_ticket(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
+    Json(body): Json<PartnerIntakeRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Authenticate the partner
+    let partner = authenticate_partner(&state, &headers).await?;
+
+    // 2. Validate and sanitize fields
+    let partner_reference = validate_optional(
+        body.partner_reference.as_deref(),
+        "partner_reference",
+        MAX_PARTNER_REFERENCE_LENGTH,
+    )?;
+    let item_type =
+        validate_optional(body.item_type.as_deref(), "item_type", MAX_ITEM_TYPE_LENGTH)?;
+    let item_description = validate_required(
+        &body.item_description,
+        "item_description",
+        MAX_DESCRIPTION_LENGTH,
+    )?;
+    let condition_notes = validate_required(
+        &body.condition_notes,
+        "condition_notes",
+        MAX_DESCRIPTION_LENGTH,
+    )?;
+    let requested_work = validate_required(
+        &body.requested_work,
+        "requested_work",
+        MAX_DESCRIPTION_LENGTH,
+    )?;
+
+    // 3. Pick a storage location
+    let rules = StorageLocationRepository::list_rules(&state.db).await?;
+    let occupancy = StorageLocationRepository::occupancy(&state.db, store_id).await?;
+    let location = rank_locations(item_type.as_deref(), None, &rules, &occupancy)
+        .into_iter()
+        .next()
+        .ok_or_else(|| AppError::validation("No active storage location is available"))?;
+
+    // 4. Create the ticket
+    let warnings = ticket_warnings(body.promise_date, None, Utc::now().date_naive());
+    let mut tx = state.db.begin().await?;
+    let ticket = TicketRepository::create(
+        &mut tx,
+        CreateTicket {
+            customer_id: partner.customer_id,
+            item_type,
+            item_description,
+            condition_notes,
+            requested_work,
+            is_rush: body.is_rush,
+            promise_date: body.promise_date,
+            storage_location_id: location.location_id,
+            quote_amount: None,
+            rush_surcharge: None,
+            weight_grams: None,
+            metal_type: None,
+            taken_in_by: partner.taken_in_by,
+            is_training: false,
+            items: Vec::new(),
+        },
+    )
+    .await?;
+
+    // 5. Record the initial status and the partner submission
+    StatusHistoryRepository::create(
+        &mut *tx,
+        CreateStatusHistory {
+            ticket_id: ticket.ticket_id,
+            from_status: None,
+            to_status: TicketStatus::Intake,
+            changed_by: partner.taken_in_by,
+        },
+    )
+    .await?;
+    PartnerRepository::link_ticket(
+        &mut *tx,
+        ticket.ticket_id,
+        partner.partner_id,
+        partner_reference.as_deref(),
+    )
+    .await?;
+    tx.commit().await?;
+
+    // 6. Notify webhook subscribers
+    webhooks::ticket_created(&state.db, &ticket).await;
+
+    // 7. Return the partner view of the ticket
+    let response = PartnerTicket {
+        friendly_code: ticket.friendly_code,
+        partner_reference,
+        item_type: ticket.item_type,
+        item_description: ticket.item_description,
+        requested_work: ticket.requested_work,
+        status: ticket.status,
+        is_rush: ticket.is_rush,
+        promise_date: ticket.promise_date,
+        quote_amount: ticket.quote_amount,
+        actual_amount: ticket.actual_amount,
+        created_at: ticket.created_at,
+        closed_at: ticket.closed_at,
+    };
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(response).with_warnings(warnings)),
+    ))
+}
+
+/// GET /api/v1/partner/tickets - List the partner's tickets, newest first.
+pub async fn partner_list_tickets(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<PartnerTicketsQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let partner = authenticate_partner(&state, &headers).await?;
+
+    // Load one extra ticket to determine has_more
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    let offset = query.offset.unwrap_or(0).max(0);
+    let tickets = PartnerRepository::list_tickets(
+        &state.db,
+        partner.customer_id,
+        query.status,
+        limit + 1,
+        offset,
+    )
+    .await?;
+
+    let has_more = tickets.len() as i64 > limit;
+    let tickets: Vec<PartnerTicket> = tickets.into_iter().take(limit as usize).collect();
+
+    let response = PartnerTicketsResponse {
+        pagination: PaginationInfo {
+            count: tickets.len(),
+            limit,
+            offset,
+            has_more,
+            next_cursor: None,
+        },
+        tickets,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// GET /api/v1/partner/tickets/:friendly_code - Get one of the partner's tickets.
+///
+/// Tickets belonging to other customers answer like missing ones.
+pub async fn partner_get_ticket(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(friendly_code): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    let partner = authenticate_partner(&state, &headers).await?;
+
+    let ticket =
+        PartnerRepository::find_ticket(&state.db, partner.customer_id, friendly_code.trim())
+            .await?
+            .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    Ok(Json(ApiResponse::success(ticket)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rate_limit() {
+        assert_eq!(validate_rate_limit(1).unwrap(), 1);
+        assert_eq!(validate_rate_limit(600).unwrap(), 600);
+        assert!(validate_rate_limit(0).is_err());
+        assert!(validate_rate_limit(601).is_err());
+    }
+
+    #[test]
+    fn test_partner_intake_request_ignores_shop_fields() {
+        let req: PartnerIntakeRequest = serde_json::from_str(
+            r#"{
+                "partner_reference": "SJ-1042",
+                "item_description": "Platinum band",
+                "condition_notes": "Worn shank",
+                "requested_work": "Replace shank",
+                "quote_amount": "500.00",
+                "storage_location_id": "550e8400-e29b-41d4-a716-446655440000"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(req.partner_reference.as_deref(), Some("SJ-1042"));
+        assert!(!req.is_rush);
+    }
+
+    #[test]
+    fn test_partner_key_response_includes_key_not_hash() {
+        let response = PartnerKeyResponse {
+            partner: Partner {
+                partner_id: Uuid::new_v4(),
+                name: "Smith Jewelers".to_string(),
+                customer_id: Uuid::new_v4(),
+                api_key_prefix: "fpk_Ab3d".to_string(),
+                api_key_hash: "deadbeef".to_string(),
+                rate_limit_per_minute: DEFAULT_PARTNER_RATE_LIMIT,
+                taken_in_by: Uuid::new_v4(),
+                is_active: true,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                last_used_at: None,
+            },
+            api_key: "fpk_Ab3dsecret".to_string(),
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["api_key"], "fpk_Ab3dsecret");
+        assert_eq!(json["name"], "Smith Jewelers");
+        assert!(json.get("api_key_hash").is_none());
+    }
+}
@@ -0,0 +1,222 @@
+//! Public (unauthenticated) request handlers.
+//!
+//! These endpoints are reachable by customers without a PIN or session.
+//! Responses use dedicated DTOs so no internal ticket data (notes, pricing,
+//! employees, storage locations) can leak through.
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::AppError;
+use crate::models::ticket::{Ticket, TicketStatus};
+use crate::models::wait_estimate::THROUGHPUT_WINDOW_DAYS;
+use crate::models::{estimate_completion, CompletionEstimate, WaitEstimateInput};
+use crate::repositories::{
+    ItemTypeRepository, StatusHistoryRepository, StoreSettingsRepository, TicketRepository,
+};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+
+// =============================================================================
+// GET /public/tickets/:friendly_code/status - Customer Status Lookup
+// =============================================================================
+
+/// Query parameters for the public status lookup.
+#[derive(Debug, Deserialize)]
+pub struct PublicStatusQuery {
+    /// Lookup token printed on the customer's receipt.
+    pub token: Option<String>,
+}
+
+/// Redacted ticket status shown to customers.
+#[derive(Debug, Serialize)]
+pub struct PublicTicketStatusResponse {
+    pub friendly_code: String,
+    pub status: TicketStatus,
+    pub promise_date: Option<NaiveDate>,
+    /// When the work is expected to be finished; null once ready or
+    /// while waiting on parts
+    pub estimated_completion: Option<CompletionEstimate>,
+    pub store_name: String,
+    pub store_phone: Option<String>,
+}
+
+impl PublicTicketStatusResponse {
+    fn new(
+        ticket: Ticket,
+        estimated_completion: Option<CompletionEstimate>,
+        store_name: String,
+        store_phone: Option<String>,
+    ) -> Self {
+        Self {
+            friendly_code: ticket.friendly_code,
+            status: ticket.status,
+            promise_date: ticket.promise_date,
+            estimated_completion,
+            store_name,
+            store_phone,
+        }
+    }
+}
+
+/// Estimate when a ticket's work will be finished.
+///
+/// Uses the ticket's place in the queue, how many tickets the shop made
+/// ready over the last few weeks, and the item type's default turnaround.
+async fn estimate_for(
+    state: &AppState,
+    ticket: &Ticket,
+) -> Result<Option<CompletionEstimate>, AppError> {
+    if !matches!(
+        ticket.status,
+        TicketStatus::Intake | TicketStatus::InProgress
+    ) {
+        return Ok(None);
+    }
+
+    let turnaround_days = match ticket.item_type.as_deref() {
+        Some(name) => ItemTypeRepository::find_by_name(&state.db, name)
+            .await?
+            .and_then(|item_type| item_type.default_turnaround_days),
+        None => None,
+    };
+    let tickets_ahead = TicketRepository::count_ahead_in_queue(&state.db, ticket).await?;
+    let now = Utc::now();
+    let recently_completed = StatusHistoryRepository::count_entered_since(
+        &state.db,
+        TicketStatus::ReadyForPickup,
+        now - Duration::days(THROUGHPUT_WINDOW_DAYS),
+        ticket.is_training,
+    )
+    .await?;
+
+    Ok(estimate_completion(&WaitEstimateInput {
+        status: ticket.status,
+        today: now.date_naive(),
+        intake_date: ticket.created_at.date_naive(),
+        turnaround_days,
+        tickets_ahead,
+        recently_completed,
+    }))
+}
+
+/// GET /api/v1/public/tickets/:friendly_code/status - Look up a ticket's status.
+///
+/// Requires the lookup token from the receipt. An unknown code and a wrong
+/// token return the same error, so codes can't be probed.
+///
+/// # Query Parameters
+/// - `token`: Lookup token printed on the receipt (required)
+///
+/// # Errors
+/// - VALIDATION_ERROR: If token is missing
+/// - NOT_FOUND: If the code and token don't match a ticket
+pub async fn get_public_ticket_status(
+    State(state): State<AppState>,
+    Path(friendly_code): Path<String>,
+    Query(query): Query<PublicStatusQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Require the token
+    let token = query
+        .token
+        .as_deref()
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| AppError::validation("token is required"))?;
+
+    // 2. Match code and token together
+    let ticket = TicketRepository::find_by_lookup_token(&state.db, friendly_code.trim(), token)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Estimate when the work will be finished
+    let estimated_completion = estimate_for(&state, &ticket).await?;
+
+    // 4. Build the redacted view with the holding store's contact details
+    let settings = StoreSettingsRepository::get_store_settings(&state.db, ticket.store_id).await?;
+
+    Ok(Json(ApiResponse::success(PublicTicketStatusResponse::new(
+        ticket,
+        estimated_completion,
+        settings.store_name,
+        settings.store_phone,
+    ))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_public_status_exposes_only_customer_fields() {
+        let ticket = Ticket {
+            ticket_id: Uuid::new_v4(),
+            friendly_code: "JR-0042".to_string(),
+            customer_id: Uuid::new_v4(),
+            item_type: Some("ring".to_string()),
+            item_description: "Gold ring".to_string(),
+            condition_notes: "Scratched".to_string(),
+            requested_work: "Resize".to_string(),
+            status: TicketStatus::ReadyForPickup,
+            is_rush: false,
+            promise_date: NaiveDate::from_ymd_opt(2026, 10, 20),
+            storage_location_id: Uuid::new_v4(),
+            store_id: Uuid::nil(),
+            weight_grams: Some(Decimal::new(52, 1)),
+            metal_type: Some("14k_gold".to_string()),
+            quote_amount: Some(Decimal::new(15000, 2)),
+            actual_amount: None,
+            taken_in_by: Uuid::new_v4(),
+            worked_by: None,
+            closed_by: None,
+            last_modified_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            closed_at: None,
+            queue_position: None,
+            deleted_at: None,
+            deleted_by: None,
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "secret-token".to_string(),
+        };
+
+        let response = PublicTicketStatusResponse::new(
+            ticket,
+            None,
+            "Example Jewelers".to_string(),
+            Some("555-0100".to_string()),
+        );
+        let json = serde_json::to_value(&response).unwrap();
+        let mut keys: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        keys.sort_unstable();
+
+        assert_eq!(
+            keys,
+            vec![
+                "estimated_completion",
+                "friendly_code",
+                "promise_date",
+                "status",
+                "store_name",
+                "store_phone"
+            ]
+        );
+        assert_eq!(json["status"], "ready_for_pickup");
+        assert!(!json.to_string().contains("secret-token"));
+    }
+}
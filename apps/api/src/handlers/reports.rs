@@ -0,0 +1,949 @@
+//! Report request handlers.
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, Response, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{Duration, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::verify_admin_auth;
+use crate::middleware::CurrentStore;
+use crate::models::report::{
+    DailyCloseoutReport, EmployeeReport, LocationAuditReport, PartnerReport, QualityReport,
+    QueueTrendReport, ReportInterval, RevenueReport, ServiceReport, ThroughputReport,
+};
+use crate::models::report_definition::{
+    CreateReportDefinition, CustomReport, ReportDefinition, ReportDimension, ReportMeasure,
+    MAX_REPORT_DIMENSIONS,
+};
+use crate::repositories::{
+    MovementRepository, QueueSnapshotRepository, ReportDefinitionRepository, ReportRepository,
+    StoreSettingsRepository,
+};
+use crate::response::{created, ApiResponse};
+use crate::routes::AppState;
+use crate::services::pdf::generate_daily_closeout_pdf;
+use crate::services::reminders;
+use crate::validation::{validate_required, MAX_NAME_LENGTH};
+
+/// Default report window when no from_date is given.
+const DEFAULT_REPORT_DAYS: i64 = 90;
+
+/// Query parameters shared by date-ranged reports.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReportRangeQuery {
+    /// Start date (inclusive, YYYY-MM-DD). Defaults to 90 days before to_date.
+    #[serde(alias = "from")]
+    pub from_date: Option<NaiveDate>,
+    /// End date (inclusive, YYYY-MM-DD). Defaults to today.
+    #[serde(alias = "to")]
+    pub to_date: Option<NaiveDate>,
+    /// Time bucket for series data (day, week, month). Defaults to month.
+    #[serde(default)]
+    pub interval: ReportInterval,
+}
+
+impl ReportRangeQuery {
+    /// Resolve the inclusive date range, applying defaults.
+    pub fn resolve(&self) -> Result<(NaiveDate, NaiveDate), AppError> {
+        let to_date = self.to_date.unwrap_or_else(|| Utc::now().date_naive());
+        let from_date = self
+            .from_date
+            .unwrap_or(to_date - Duration::days(DEFAULT_REPORT_DAYS));
+
+        if from_date > to_date {
+            return Err(AppError::validation(
+                "from_date must be on or before to_date",
+            ));
+        }
+
+        Ok((from_date, to_date))
+    }
+}
+
+// =============================================================================
+// GET /reports/quality - Rework/Defect Report (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/reports/quality - Rework rates by employee, item type, and reason.
+///
+/// Covers tickets created within the date range. A ticket counts as reworked
+/// when it has at least one defect (failed QC or customer return).
+///
+/// # Query Parameters
+/// - `from_date`, `to_date`: Inclusive date range (defaults to the last 90 days)
+/// - `interval`: Bucket size for `over_time` (day, week, month)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from_date is after to_date
+pub async fn quality_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ReportRangeQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (from_date, to_date) = query.resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let overall = ReportRepository::quality_overall(&state.db, from, to).await?;
+    let by_employee = ReportRepository::quality_by_employee(&state.db, from, to).await?;
+    let by_item_type = ReportRepository::quality_by_item_type(&state.db, from, to).await?;
+    let by_reason = ReportRepository::quality_by_reason(&state.db, from, to).await?;
+    let over_time =
+        ReportRepository::quality_over_time(&state.db, from, to, query.interval).await?;
+
+    let report = QualityReport {
+        from_date,
+        to_date,
+        interval: query.interval,
+        overall,
+        by_employee,
+        by_item_type,
+        by_reason,
+        over_time,
+    };
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/partners - Partner Trade Work Report (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/reports/partners - Trade work and API usage by partner.
+///
+/// Counts tickets created, tickets closed with their revenue, and partner
+/// API requests (including rate-limited ones) within the date range.
+///
+/// # Query Parameters
+/// - `from_date`, `to_date`: Inclusive date range (defaults to the last 90 days)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from_date is after to_date
+pub async fn partner_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ReportRangeQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (from_date, to_date) = query.resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let partners = ReportRepository::partner_activity(&state.db, from, to).await?;
+
+    let report = PartnerReport {
+        from_date,
+        to_date,
+        partners,
+    };
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/services - Service Catalog Report (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/reports/services - Tickets, prices, and turnaround by service.
+///
+/// Covers tickets taken in within the date range with catalog services:
+/// how often each service was picked, its prices at intake, and what the
+/// closed tickets came to, for checking catalog prices against real work.
+///
+/// # Query Parameters
+/// - `from_date`, `to_date`: Inclusive date range (defaults to the last 90 days)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from_date is after to_date
+pub async fn service_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ReportRangeQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (from_date, to_date) = query.resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let services = ReportRepository::service_usage(&state.db, from, to).await?;
+
+    let report = ServiceReport {
+        from_date,
+        to_date,
+        services,
+    };
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/revenue - Revenue Report (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/reports/revenue - Revenue from closed tickets.
+///
+/// Covers tickets closed within the date range: their actual amounts, rush
+/// counts, rush surcharges, and the cost of the materials used on them,
+/// overall and per time bucket.
+///
+/// # Query Parameters
+/// - `from_date`, `to_date`: Inclusive date range (defaults to the last 90 days)
+/// - `interval`: Bucket size for `over_time` (day, week, month)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from_date is after to_date
+pub async fn revenue_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ReportRangeQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (from_date, to_date) = query.resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let overall = ReportRepository::revenue_overall(&state.db, from, to).await?;
+    let over_time =
+        ReportRepository::revenue_over_time(&state.db, from, to, query.interval).await?;
+
+    let report = RevenueReport {
+        from_date,
+        to_date,
+        interval: query.interval,
+        overall,
+        over_time,
+    };
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/throughput - Throughput Report (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/reports/throughput - Tickets received and closed, with turnaround.
+///
+/// Received tickets count by creation time and closed tickets by close time.
+/// Turnaround is the time from intake to close for tickets closed in the
+/// range.
+///
+/// # Query Parameters
+/// - `from_date`, `to_date`: Inclusive date range (defaults to the last 90 days)
+/// - `interval`: Bucket size for `over_time` (day, week, month)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from_date is after to_date
+pub async fn throughput_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ReportRangeQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (from_date, to_date) = query.resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let overall = ReportRepository::throughput_overall(&state.db, from, to).await?;
+    let over_time =
+        ReportRepository::throughput_over_time(&state.db, from, to, query.interval).await?;
+
+    let report = ThroughputReport {
+        from_date,
+        to_date,
+        interval: query.interval,
+        overall,
+        over_time,
+    };
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/employees - Employee Productivity Report (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/reports/employees - Tickets taken in, worked, and closed per employee.
+///
+/// Built from the ticket attribution fields and status history. Worked
+/// tickets count when they first became ready for pickup, with the average
+/// time from intake to that point.
+///
+/// # Query Parameters
+/// - `from`, `to` (or `from_date`, `to_date`): Inclusive date range
+///   (defaults to the last 90 days)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from is after to
+pub async fn employee_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ReportRangeQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (from_date, to_date) = query.resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let employees = ReportRepository::employee_productivity(&state.db, from, to).await?;
+
+    let report = EmployeeReport {
+        from_date,
+        to_date,
+        employees,
+    };
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/queue-trends - Queue Trends Report (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/reports/queue-trends - How the open ticket backlog changes over time.
+///
+/// Built from the queue snapshots recorded every `QUEUE_SNAPSHOT_MINUTES`.
+/// Lane, overdue, and rush counts are averaged over the snapshots in each
+/// time bucket and for each day of the week, alongside the latest snapshot.
+///
+/// # Query Parameters
+/// - `from`, `to` (or `from_date`, `to_date`): Inclusive date range
+///   (defaults to the last 90 days)
+/// - `interval`: Bucket size for `over_time` (day, week, month)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from is after to
+pub async fn queue_trends_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<ReportRangeQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (from_date, to_date) = query.resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let latest = QueueSnapshotRepository::latest(&state.db).await?;
+    let over_time =
+        ReportRepository::queue_trends_over_time(&state.db, from, to, query.interval).await?;
+    let by_weekday = ReportRepository::queue_trends_by_weekday(&state.db, from, to).await?;
+
+    let report = QueueTrendReport {
+        from_date,
+        to_date,
+        interval: query.interval,
+        latest,
+        over_time,
+        by_weekday,
+    };
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/overdue - Overdue Report (Admin Only)
+// =============================================================================
+
+/// GET /api/v1/reports/overdue - Open tickets due today and overdue.
+///
+/// The same tickets the morning reminder digest covers, as of now: open,
+/// non-training tickets with a promise date today or earlier, most overdue
+/// first.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+pub async fn overdue_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let report = reminders::overdue_report(&state.db, Utc::now().date_naive()).await?;
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/location-audit - Location Audit Report (Admin Only)
+// =============================================================================
+
+/// Query parameters for the location audit report.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LocationAuditQuery {
+    /// Limit to one storage location
+    pub location_id: Option<Uuid>,
+    /// Start date (inclusive, YYYY-MM-DD). Defaults to 90 days before to_date.
+    #[serde(alias = "from")]
+    pub from_date: Option<NaiveDate>,
+    /// End date (inclusive, YYYY-MM-DD). Defaults to today.
+    #[serde(alias = "to")]
+    pub to_date: Option<NaiveDate>,
+}
+
+/// GET /api/v1/reports/location-audit - Items out of storage and their movements.
+///
+/// Lists the open tickets checked out to a bench right now, and every
+/// movement into or out of storage within the date range, so a safe count
+/// can be reconciled against who has what.
+///
+/// # Query Parameters
+/// - `location_id`: Only tickets stored at, and movements in or out of, this location
+/// - `from_date`, `to_date`: Inclusive date range (defaults to the last 90 days)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If from_date is after to_date
+pub async fn location_audit_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<LocationAuditQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (from_date, to_date) = ReportRangeQuery {
+        from_date: query.from_date,
+        to_date: query.to_date,
+        interval: ReportInterval::default(),
+    }
+    .resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let checked_out = MovementRepository::list_checked_out(&state.db, query.location_id).await?;
+    let movements =
+        MovementRepository::list_entries(&state.db, query.location_id, from, to).await?;
+
+    let report = LocationAuditReport {
+        from_date,
+        to_date,
+        location_id: query.location_id,
+        checked_out,
+        movements,
+    };
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+// =============================================================================
+// GET /reports/daily-closeout - Daily Closeout Report (Admin Only)
+// =============================================================================
+
+/// Query parameters for the daily closeout.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DailyCloseoutQuery {
+    /// Day to close out (YYYY-MM-DD). Defaults to today in the store's time zone.
+    pub date: Option<NaiveDate>,
+}
+
+/// Build the current store's closeout for a day (today if None), counted
+/// midnight to midnight in the store's time zone.
+async fn build_daily_closeout(
+    state: &AppState,
+    store_id: Uuid,
+    date: Option<NaiveDate>,
+) -> Result<DailyCloseoutReport, AppError> {
+    let settings = StoreSettingsRepository::get_store_settings(&state.db, store_id).await?;
+    let (date, from, to) = ReportRepository::local_day(&state.db, &settings.timezone, date).await?;
+
+    let taken_in = ReportRepository::closeout_taken_in(&state.db, store_id, from, to).await?;
+    let closed = ReportRepository::closeout_closed(&state.db, store_id, from, to).await?;
+    let payments_by_method =
+        ReportRepository::closeout_payments_by_method(&state.db, store_id, from, to).await?;
+    let ready_for_pickup =
+        ReportRepository::closeout_ready_for_pickup(&state.db, store_id, to).await?;
+
+    Ok(DailyCloseoutReport {
+        date,
+        timezone: settings.timezone,
+        store_id,
+        store_name: settings.store_name,
+        closed_revenue: closed.iter().filter_map(|t| t.actual_amount).sum(),
+        payments_total: payments_by_method.iter().map(|p| p.total).sum(),
+        taken_in,
+        closed,
+        payments_by_method,
+        ready_for_pickup,
+    })
+}
+
+/// GET /api/v1/reports/daily-closeout - The current store's end-of-day summary.
+///
+/// Covers one day, midnight to midnight in the store's time zone: tickets
+/// taken in, tickets closed and their revenue, payments received by method,
+/// and the tickets still waiting for pickup at the end of the day. Training
+/// tickets are left out.
+///
+/// # Query Parameters
+/// - `date`: Day to close out (defaults to today in the store's time zone)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+pub async fn daily_closeout_report(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+    headers: HeaderMap,
+    Query(query): Query<DailyCloseoutQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let report = build_daily_closeout(&state, store_id, query.date).await?;
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+/// GET /api/v1/reports/daily-closeout.pdf - The daily closeout as a PDF.
+///
+/// The same report as `GET /reports/daily-closeout`, printed for the
+/// end-of-day paper trail with a counted-by signature line.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+pub async fn daily_closeout_pdf(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+    headers: HeaderMap,
+    Query(query): Query<DailyCloseoutQuery>,
+) -> Result<Response<Body>, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let report = build_daily_closeout(&state, store_id, query.date).await?;
+    let pdf_bytes = generate_daily_closeout_pdf(&report)?;
+
+    let filename = format!("closeout-{}.pdf", report.date);
+    let response = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/pdf")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("inline; filename=\"{}\"", filename),
+        )
+        .body(Body::from(pdf_bytes))
+        .map_err(|e| AppError::server_error(format!("Failed to build response: {}", e)))?;
+
+    Ok(response)
+}
+
+// =============================================================================
+// /reports/custom - Custom Report Builder (Admin Only)
+// =============================================================================
+
+/// Request body for creating or replacing a custom report.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReportDefinitionRequest {
+    pub name: String,
+    /// Grouping columns: status, employee, item_type, month
+    #[serde(default)]
+    pub dimensions: Vec<String>,
+    /// Computed columns: count, revenue, avg_turnaround_days
+    pub measures: Vec<String>,
+}
+
+/// Response for listing custom reports.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListReportDefinitionsResponse {
+    pub reports: Vec<ReportDefinition>,
+}
+
+/// Response for deleting a custom report.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteReportDefinitionResponse {
+    pub deleted: bool,
+}
+
+/// Parse names against an allowed list, rejecting unknown and repeated ones.
+fn parse_report_fields<T: PartialEq>(
+    names: &[String],
+    kind: &str,
+    allowed: &[&str],
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<T>, AppError> {
+    let mut fields = Vec::with_capacity(names.len());
+    for name in names {
+        let field = parse(name.trim()).ok_or_else(|| {
+            AppError::validation(format!(
+                "Unknown {} {}; use one of: {}",
+                kind,
+                name.trim(),
+                allowed.join(", ")
+            ))
+        })?;
+        if fields.contains(&field) {
+            return Err(AppError::validation(format!(
+                "Duplicate {} {}",
+                kind,
+                name.trim()
+            )));
+        }
+        fields.push(field);
+    }
+    Ok(fields)
+}
+
+impl ReportDefinitionRequest {
+    /// Validate the request against the allowed dimensions and measures.
+    fn into_definition(self) -> Result<CreateReportDefinition, AppError> {
+        let name = validate_required(&self.name, "name", MAX_NAME_LENGTH)?;
+
+        let dimension_names = ReportDimension::ALL.map(|d| d.as_str());
+        let dimensions = parse_report_fields(
+            &self.dimensions,
+            "dimension",
+            &dimension_names,
+            ReportDimension::parse,
+        )?;
+        if dimensions.len() > MAX_REPORT_DIMENSIONS {
+            return Err(AppError::validation(format!(
+                "A report can have at most {} dimensions",
+                MAX_REPORT_DIMENSIONS
+            )));
+        }
+
+        let measure_names = ReportMeasure::ALL.map(|m| m.as_str());
+        let measures = parse_report_fields(
+            &self.measures,
+            "measure",
+            &measure_names,
+            ReportMeasure::parse,
+        )?;
+        if measures.is_empty() {
+            return Err(AppError::validation("A report needs at least one measure"));
+        }
+
+        Ok(CreateReportDefinition {
+            name,
+            dimensions,
+            measures,
+        })
+    }
+}
+
+/// Reject a name another report already uses.
+async fn ensure_report_name_free(
+    state: &AppState,
+    name: &str,
+    report_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    match ReportDefinitionRepository::find_by_name(&state.db, name).await? {
+        Some(existing) if Some(existing.report_id) != report_id => {
+            Err(AppError::conflict("A report with this name already exists"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// GET /api/v1/reports/custom - List saved custom reports.
+pub async fn list_custom_reports(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let reports = ReportDefinitionRepository::list(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(ListReportDefinitionsResponse {
+        reports,
+    })))
+}
+
+/// POST /api/v1/reports/custom - Save a custom report.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - VALIDATION_ERROR: If a dimension or measure is unknown or repeated,
+///   there are too many dimensions, or no measures
+/// - CONFLICT: If another report has the same name
+pub async fn create_custom_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<ReportDefinitionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let input = body.into_definition()?;
+    ensure_report_name_free(&state, &input.name, None).await?;
+    let report = ReportDefinitionRepository::create(&state.db, input).await?;
+
+    Ok(created(report))
+}
+
+/// PUT /api/v1/reports/custom/:report_id - Replace a custom report.
+pub async fn update_custom_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(report_id): Path<Uuid>,
+    Json(body): Json<ReportDefinitionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let input = body.into_definition()?;
+    ensure_report_name_free(&state, &input.name, Some(report_id)).await?;
+    let report = ReportDefinitionRepository::update(&state.db, report_id, input)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("report"))?;
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+/// DELETE /api/v1/reports/custom/:report_id - Delete a custom report.
+pub async fn delete_custom_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(report_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    if !ReportDefinitionRepository::delete(&state.db, report_id).await? {
+        return Err(state.probe_policy.missing("report"));
+    }
+
+    Ok(Json(ApiResponse::success(DeleteReportDefinitionResponse {
+        deleted: true,
+    })))
+}
+
+/// Split a `:report_id` path segment into the ID and whether CSV was asked
+/// for with a `.csv` suffix.
+fn parse_report_path(segment: &str) -> Option<(Uuid, bool)> {
+    match segment.strip_suffix(".csv") {
+        Some(id) => Uuid::parse_str(id).ok().map(|id| (id, true)),
+        None => Uuid::parse_str(segment).ok().map(|id| (id, false)),
+    }
+}
+
+/// Render a custom report as CSV: a header row, then one line per group.
+fn custom_report_csv(report: &CustomReport) -> Result<Vec<u8>, AppError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let csv_error = |e: csv::Error| AppError::server_error(format!("Failed to write CSV: {}", e));
+
+    writer.write_record(&report.columns).map_err(csv_error)?;
+    for row in &report.rows {
+        writer
+            .write_record(row.iter().map(|cell| cell.to_csv_field()))
+            .map_err(csv_error)?;
+    }
+    writer
+        .into_inner()
+        .map_err(|e| AppError::server_error(format!("Failed to write CSV: {}", e)))
+}
+
+/// GET /api/v1/reports/custom/:report_id - Run a custom report.
+///
+/// Covers tickets taken in within the date range. Append `.csv` to the ID
+/// (`/reports/custom/:report_id.csv`) to download it as CSV.
+///
+/// # Query Parameters
+/// - `from_date`, `to_date`: Inclusive date range (defaults to the last 90 days)
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated as admin
+/// - NOT_FOUND: If the report does not exist
+/// - VALIDATION_ERROR: If from_date is after to_date
+pub async fn run_custom_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(segment): Path<String>,
+    Query(query): Query<ReportRangeQuery>,
+) -> Result<Response<Body>, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let (report_id, as_csv) =
+        parse_report_path(&segment).ok_or_else(|| state.probe_policy.missing("report"))?;
+    let report = ReportDefinitionRepository::find_by_id(&state.db, report_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("report"))?;
+
+    let (from_date, to_date) = query.resolve()?;
+    let from = from_date.and_time(NaiveTime::MIN).and_utc();
+    let to = (to_date + Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let rows = ReportDefinitionRepository::run(&state.db, &report, from, to).await?;
+    let result = CustomReport {
+        columns: report.columns(),
+        report,
+        from_date,
+        to_date,
+        rows,
+    };
+
+    if !as_csv {
+        return Ok(Json(ApiResponse::success(result)).into_response());
+    }
+
+    let filename = format!("report-{}-{}-{}.csv", report_id, from_date, to_date);
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "text/csv; charset=utf-8")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", filename),
+        )
+        .body(Body::from(custom_report_csv(&result)?))
+        .map_err(|e| AppError::server_error(format!("Failed to build response: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::report_definition::ReportCell;
+
+    #[test]
+    fn test_report_range_query_defaults() {
+        let query: ReportRangeQuery = serde_urlencoded::from_str("").unwrap();
+        assert_eq!(query.interval, ReportInterval::Month);
+
+        let (from_date, to_date) = query.resolve().unwrap();
+        assert_eq!(to_date, Utc::now().date_naive());
+        assert_eq!((to_date - from_date).num_days(), DEFAULT_REPORT_DAYS);
+    }
+
+    #[test]
+    fn test_report_range_query_explicit() {
+        let query: ReportRangeQuery =
+            serde_urlencoded::from_str("from_date=2024-01-01&to_date=2024-03-31&interval=week")
+                .unwrap();
+        assert_eq!(query.interval, ReportInterval::Week);
+
+        let (from_date, to_date) = query.resolve().unwrap();
+        assert_eq!(from_date, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!(to_date, NaiveDate::from_ymd_opt(2024, 3, 31).unwrap());
+    }
+
+    #[test]
+    fn test_report_range_query_short_names() {
+        let query: ReportRangeQuery =
+            serde_urlencoded::from_str("from=2024-01-01&to=2024-01-31").unwrap();
+
+        let (from_date, to_date) = query.resolve().unwrap();
+        assert_eq!(from_date, NaiveDate::from_ymd_opt(2024, 1, 1).unwrap());
+        assert_eq!(to_date, NaiveDate::from_ymd_opt(2024, 1, 31).unwrap());
+    }
+
+    #[test]
+    fn test_report_range_query_rejects_inverted_range() {
+        let query: ReportRangeQuery =
+            serde_urlencoded::from_str("from_date=2024-04-01&to_date=2024-03-31").unwrap();
+        assert!(query.resolve().is_err());
+    }
+
+    #[test]
+    fn test_report_range_query_rejects_unknown_interval() {
+        let result: Result<ReportRangeQuery, _> = serde_urlencoded::from_str("interval=year");
+        assert!(result.is_err());
+    }
+
+    fn definition(dimensions: &[&str], measures: &[&str]) -> ReportDefinitionRequest {
+        ReportDefinitionRequest {
+            name: "Monthly revenue".to_string(),
+            dimensions: dimensions.iter().map(|d| d.to_string()).collect(),
+            measures: measures.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_report_definition_validates_whitelist() {
+        let input = definition(&["month", "item_type"], &["count", "revenue"])
+            .into_definition()
+            .unwrap();
+        assert_eq!(
+            input.dimensions,
+            vec![ReportDimension::Month, ReportDimension::ItemType]
+        );
+
+        assert!(definition(&["customer_name"], &["count"])
+            .into_definition()
+            .is_err());
+        assert!(definition(&["month"], &["count(*); --"])
+            .into_definition()
+            .is_err());
+        assert!(definition(&["month", "month"], &["count"])
+            .into_definition()
+            .is_err());
+        assert!(definition(&["month"], &[]).into_definition().is_err());
+        assert!(
+            definition(&["month", "status", "employee", "item_type"], &["count"])
+                .into_definition()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_report_path() {
+        let id = Uuid::new_v4();
+        assert_eq!(parse_report_path(&id.to_string()), Some((id, false)));
+        assert_eq!(parse_report_path(&format!("{}.csv", id)), Some((id, true)));
+        assert_eq!(parse_report_path(&format!("{}.pdf", id)), None);
+    }
+
+    #[test]
+    fn test_custom_report_csv() {
+        let report = CustomReport {
+            report: ReportDefinition {
+                report_id: Uuid::new_v4(),
+                name: "By status".to_string(),
+                dimensions: vec![ReportDimension::Status],
+                measures: vec![ReportMeasure::Count, ReportMeasure::AvgTurnaroundDays],
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            },
+            from_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            to_date: NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            columns: vec!["status", "count", "avg_turnaround_days"],
+            rows: vec![
+                vec![
+                    ReportCell::Text(Some("closed".to_string())),
+                    ReportCell::Count(2),
+                    ReportCell::Days(Some(3.5)),
+                ],
+                vec![
+                    ReportCell::Text(Some("in_progress".to_string())),
+                    ReportCell::Count(1),
+                    ReportCell::Days(None),
+                ],
+            ],
+        };
+        let csv = String::from_utf8(custom_report_csv(&report).unwrap()).unwrap();
+        assert_eq!(
+            csv,
+            "status,count,avg_turnaround_days\nclosed,2,3.50\nin_progress,1,\n"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+//! Service catalog request handlers.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::IntoResponse,
+    Json,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::verify_permission;
+use crate::models::employee::Permission;
+use crate::models::service::{CreateService, Service, UpdateService};
+use crate::repositories::ServiceRepository;
+use crate::response::{created, ApiResponse};
+use crate::routes::AppState;
+use crate::validation::{validate_required, MAX_SERVICE_NAME_LENGTH, MAX_TURNAROUND_DAYS};
+
+// =============================================================================
+// GET /services - List Services
+// =============================================================================
+
+/// Query parameters for listing services.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListServicesQuery {
+    /// Include inactive services (default: false)
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+/// Response for listing services.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListServicesResponse {
+    pub services: Vec<Service>,
+}
+
+/// GET /api/v1/services - List catalog services by name.
+///
+/// This endpoint is public, like the location list, so intake can offer the
+/// services. Only active services are returned unless
+/// `?include_inactive=true`.
+pub async fn list_services(
+    State(state): State<AppState>,
+    Query(query): Query<ListServicesQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let services = ServiceRepository::list(&state.db, query.include_inactive).await?;
+
+    Ok(Json(ApiResponse::success(ListServicesResponse {
+        services,
+    })))
+}
+
+// =============================================================================
+// POST /services - Create Service
+// =============================================================================
+
+/// POST /api/v1/services - Add a service to the catalog (admin or manage_settings).
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If the name is blank or taken, or a default is out
+///   of range
+pub async fn create_service(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<CreateService>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate the service
+    let name = validate_required(&body.name, "name", MAX_SERVICE_NAME_LENGTH)?;
+    validate_service_defaults(body.default_price, body.turnaround_days)?;
+    ensure_name_free(&state, &name, None).await?;
+
+    // 3. Create it
+    let service = ServiceRepository::create(
+        &state.db,
+        CreateService {
+            name,
+            default_price: body.default_price,
+            turnaround_days: body.turnaround_days,
+        },
+    )
+    .await?;
+
+    Ok(created(service))
+}
+
+// =============================================================================
+// PUT /services/:service_id - Update Service
+// =============================================================================
+
+/// PUT /api/v1/services/:service_id - Update a service (admin or manage_settings).
+///
+/// Tickets keep the price the service had when they were taken in; new
+/// defaults apply to tickets taken in afterwards.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the service doesn't exist
+/// - VALIDATION_ERROR: If the name is blank or taken, or a default is out
+///   of range
+pub async fn update_service(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(service_id): Path<Uuid>,
+    Json(body): Json<UpdateService>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate the changes
+    let name = body
+        .name
+        .as_ref()
+        .map(|name| validate_required(name, "name", MAX_SERVICE_NAME_LENGTH))
+        .transpose()?;
+    if let Some(ref name) = name {
+        ensure_name_free(&state, name, Some(service_id)).await?;
+    }
+    validate_service_defaults(body.default_price.flatten(), body.turnaround_days.flatten())?;
+
+    // 3. Update the service
+    let service = ServiceRepository::update(&state.db, service_id, UpdateService { name, ..body })
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("service"))?;
+
+    Ok(Json(ApiResponse::success(service)))
+}
+
+// =============================================================================
+// DELETE /services/:service_id - Deactivate Service
+// =============================================================================
+
+/// DELETE /api/v1/services/:service_id - Deactivate a service (admin or manage_settings).
+///
+/// The service is kept for ticket history and the service report but no
+/// longer offered at intake. Deactivating an inactive service is a no-op.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the service doesn't exist
+pub async fn delete_service(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(service_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let service = ServiceRepository::update(
+        &state.db,
+        service_id,
+        UpdateService {
+            is_active: Some(false),
+            ..Default::default()
+        },
+    )
+    .await?
+    .ok_or_else(|| state.probe_policy.missing("service"))?;
+
+    Ok(Json(ApiResponse::success(service)))
+}
+
+/// Refuse a name another service already has (case-insensitive).
+async fn ensure_name_free(
+    state: &AppState,
+    name: &str,
+    service_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let existing = ServiceRepository::find_by_name(&state.db, name).await?;
+    if existing.is_some_and(|service| Some(service.service_id) != service_id) {
+        return Err(AppError::validation(
+            "A service with this name already exists",
+        ));
+    }
+    Ok(())
+}
+
+/// Check a service's default price and turnaround.
+fn validate_service_defaults(
+    default_price: Option<Decimal>,
+    turnaround_days: Option<i32>,
+) -> Result<(), AppError> {
+    if default_price.is_some_and(|price| price < Decimal::ZERO) {
+        return Err(AppError::validation("default_price cannot be negative"));
+    }
+    if turnaround_days.is_some_and(|days| !(0..=MAX_TURNAROUND_DAYS).contains(&days)) {
+        return Err(AppError::validation(format!(
+            "turnaround_days must be between 0 and {}",
+            MAX_TURNAROUND_DAYS
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_service_defaults() {
+        assert!(validate_service_defaults(None, None).is_ok());
+        assert!(validate_service_defaults(Some(Decimal::new(4000, 2)), Some(3)).is_ok());
+        assert!(validate_service_defaults(Some(Decimal::new(-1, 0)), None).is_err());
+        assert!(validate_service_defaults(None, Some(-1)).is_err());
+        assert!(validate_service_defaults(None, Some(MAX_TURNAROUND_DAYS + 1)).is_err());
+    }
+}
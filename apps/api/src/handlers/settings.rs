@@ -1,16 +1,59 @@
 //! Store settings request handlers.
 
-use axum::{extract::State, http::HeaderMap, response::IntoResponse, Json};
+use axum::{
+    extract::{Multipart, Path, Query, State},
+    http::{header, HeaderMap, HeaderValue},
+    response::IntoResponse,
+    Json,
+};
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 
 use crate::error::AppError;
-use crate::handlers::verify_admin_auth;
-use crate::models::store_settings::{StoreSettingsMinimalPublic, UpdateStoreSettings};
-use crate::repositories::StoreSettingsRepository;
+use crate::handlers::admin::verify_admin_session_header;
+use crate::handlers::audit_log::{record_audit, AuditEvent};
+use crate::handlers::storage::storage_error;
+use crate::handlers::tickets::extract_employee_from_session;
+use crate::handlers::verify_permission;
+use crate::middleware::{ClientIp, CurrentStore};
+use crate::models::audit_log::AuditAction;
+use crate::models::business_hours::OpeningHours;
+use crate::models::employee::Permission;
+use crate::models::item_type::{CreateItemType, ItemType};
+use crate::models::metal_price::{CreateMetalPrice, MetalPrice};
+use crate::models::notification::{
+    NotificationEvent, NotificationTemplate, UpdateNotificationTemplate,
+};
+use crate::models::promise_date_reason::{CreatePromiseDateReason, PromiseDateReason};
+use crate::models::rush_pricing::{CreateRushSurchargeTier, RushSurchargeKind, RushSurchargeTier};
+use crate::models::settings_change::{
+    settings_diff, CreateSettingsChange, SettingsChange, SettingsChangeEntry,
+};
+use crate::models::storage_location::{CreateStorageLocationRule, StorageLocationRule};
+use crate::models::store_settings::{
+    SettingsSection, StoreSettingsMinimalPublic, StoreSettingsPublic, UpdateStoreSettings,
+};
+use crate::repositories::{
+    ItemTypeRepository, MetalPriceRepository, NotificationTemplateRepository,
+    PromiseDateReasonRepository, RushPricingRepository, SettingsChangeRepository,
+    StorageLocationRepository, StoreRepository, StoreSettingsRepository,
+};
 use crate::response::ApiResponse;
 use crate::routes::AppState;
+use crate::services::notifications::{unknown_placeholders, TemplateContext, PLACEHOLDERS};
+use crate::services::photos::process_photo;
+use crate::utils::file_validation::{detect_image_format, ImageFormat};
 use crate::validation::{
-    validate_optional, validate_phone, MAX_ADDRESS_LENGTH, MAX_CURRENCY_LENGTH, MAX_NAME_LENGTH,
-    MAX_PHONE_LENGTH, MAX_TICKET_PREFIX_LENGTH,
+    validate_email, validate_optional, validate_phone, validate_required, AUTO_ARCHIVE_DAYS_RANGE,
+    LABEL_HEIGHT_MM_RANGE, LABEL_WIDTH_MM_RANGE, MAX_ADDRESS_LENGTH, MAX_CURRENCY_LENGTH,
+    MAX_EMAIL_LENGTH, MAX_HOLIDAYS, MAX_ITEM_TYPES, MAX_ITEM_TYPE_LENGTH, MAX_ITEM_TYPE_LIST_ITEMS,
+    MAX_LOCATION_RULES, MAX_METAL_PRICES, MAX_METAL_TYPE_LENGTH, MAX_NAME_LENGTH, MAX_PHONE_LENGTH,
+    MAX_PHOTOS_PER_TICKET_LIMIT, MAX_PROMISE_DATE_REASONS, MAX_QC_ITEMS, MAX_QC_ITEM_LENGTH,
+    MAX_REASON_CODE_LENGTH, MAX_RECEIPT_FOOTER_LENGTH, MAX_RECEIPT_LOGO_SIZE,
+    MAX_RUSH_SURCHARGE_PERCENT, MAX_RUSH_SURCHARGE_TIERS, MAX_STORAGE_KEY_LENGTH,
+    MAX_TAX_RATE_PERCENT, MAX_TEMPLATE_BODY_LENGTH, MAX_TICKET_PREFIX_LENGTH, MAX_TIMEZONE_LENGTH,
+    MAX_TURNAROUND_DAYS, MIN_PIN_LENGTH_RANGE, TICKET_NUMBER_PADDING_RANGE,
 };
 
 // =============================================================================
@@ -25,9 +68,14 @@ use crate::validation::{
 /// Excluded for security:
 /// - `setup_complete` / `setup_required` - could indicate target for attack
 /// - `next_ticket_number` - prevents ticket enumeration
-pub async fn get_settings(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
+///
+/// Returns the settings of the store the request works for (`X-Store-ID`).
+pub async fn get_settings(
+    State(state): State<AppState>,
+    CurrentStore(store_id): CurrentStore,
+) -> Result<impl IntoResponse, AppError> {
     let settings: StoreSettingsMinimalPublic =
-        StoreSettingsRepository::get_settings_minimal_public(&state.db).await?;
+        StoreSettingsRepository::get_settings_minimal_public(&state.db, store_id).await?;
 
     Ok(Json(ApiResponse::success(settings)))
 }
@@ -36,10 +84,13 @@ pub async fn get_settings(State(state): State<AppState>) -> Result<impl IntoResp
 // PUT /settings - Update Store Settings (Admin Only)
 // =============================================================================
 
-/// PUT /api/v1/settings - Update store settings (admin only).
+/// PUT /api/v1/settings - Update store settings (admin or manage_settings).
 ///
-/// Updates the store settings. Only the fields provided in the request body
-/// will be updated; other fields retain their current values.
+/// Updates the settings of the store the request works for. Only the fields
+/// provided in the request body will be updated; other fields retain their
+/// current values. `min_pin_length`, `scope_ticket_visibility`,
+/// `auto_archive_after_days`, `notifications_enabled`, and the overdue digest
+/// apply to every store and can only be changed on the primary store.
 ///
 /// # Request Headers
 /// - `X-Admin-Session`: Session token (preferred)
@@ -50,20 +101,717 @@ pub async fn get_settings(State(state): State<AppState>) -> Result<impl IntoResp
 /// - `store_phone`: Store phone number
 /// - `store_address`: Store address
 /// - `ticket_prefix`: Prefix for ticket IDs (e.g., "JR")
+/// - `ticket_number_padding`: Digits ticket numbers are zero-padded to (1-8)
+/// - `ticket_number_yearly_reset`: Start numbering over each year, with the
+///   year in the code (e.g., "JR-2025-0042")
 /// - `currency`: Currency code (e.g., "USD")
 /// - `max_photos_per_ticket`: Maximum photos allowed per ticket
+/// - `qc_checklist`: QC items required before ready for pickup (empty list disables)
+/// - `scope_ticket_visibility`: Limit staff lists/search/queue to their own tickets
+/// - `custody_value_threshold`: Quote amount requiring witnessed custody events (null disables)
+/// - `quote_approval_threshold`: Quotes above this need customer approval before work starts (null disables)
+/// - `notifications_enabled`: Send customer SMS/email notifications
+/// - `min_pin_length`: Minimum length for new PINs
+/// - `auto_archive_after_days`: Archive tickets closed more than this many days ago (null disables)
+/// - `restrict_item_types`: Tickets must use a configured item type or "Other"
+/// - `overdue_digest_email`: Where the daily due/overdue digest is emailed (null disables)
+/// - `overdue_digest_sms`: Text assigned employees their due and overdue tickets
+/// - `overdue_digest_hour`: Hour of the day (UTC, 0-23) the reminder job runs
+/// - `receipt_logo_key`: Logo printed on receipts (null removes; set by uploading a logo)
+/// - `receipt_footer_text`: Disclaimer printed at the bottom of receipts (null removes)
+/// - `receipt_show_prices`: Print quotes, payments, and the balance on receipts
+/// - `label_width_mm` / `label_height_mm`: Label stock size (25-150 x 12-100 mm)
+/// - `label_stock`: Named stock setting both label dimensions (`tag_2x1`,
+///   `barbell`, `tag_2.25x1.25`, or `dymo_30334`)
+/// - `receipt_format`: Default receipt format (`pdf`, `escpos`, or `text`)
+/// - `tax_rate`: Sales tax percentage (0-100)
+/// - `tax_inclusive`: Ticket amounts include tax; false adds tax on top
+/// - `business_hours`: Opening hours as `{weekday, opens_at, closes_at}`
+///   with ISO weekdays (1 = Monday); unlisted days are closed (empty list:
+///   open every day)
+/// - `holidays`: Dates the store is closed
+/// - `rush_turnaround_days`: Open days a rush job takes (null disables)
+/// - `timezone`: IANA time zone the store's days are counted in (e.g.
+///   `America/Chicago`)
+///
+/// Send `If-Match` with the ETag from a previous read to avoid overwriting
+/// another admin's changes. The response carries the new ETag.
 ///
 /// # Errors
 /// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If a field is invalid, the ticket prefix is another
+///   store's, or a deployment-wide setting is changed on another store
+/// - PRECONDITION_FAILED: If the settings changed since the If-Match ETag
 pub async fn update_settings(
     State(state): State<AppState>,
     headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    CurrentStore(store_id): CurrentStore,
     Json(body): Json<UpdateStoreSettings>,
 ) -> Result<impl IntoResponse, AppError> {
-    // Verify admin authentication (session or PIN)
-    verify_admin_auth(&state, &headers).await?;
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate and sanitize fields
+    let expected_version = if_match_version(&headers)?;
+    let validated_body = validate_settings_update(body)?;
+
+    // 3. Update the settings and record the change
+    let (settings, change) = apply_settings_update(
+        &state,
+        &headers,
+        store_id,
+        validated_body,
+        expected_version,
+        None,
+    )
+    .await?;
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        settings_audit_summary("all", change.as_ref()),
+    )
+    .await;
+
+    Ok((
+        [(header::ETAG, settings_etag(settings.version))],
+        Json(ApiResponse::success(settings)),
+    ))
+}
+
+// =============================================================================
+// GET/PATCH /settings/:section - Settings Sections (Admin Only)
+// =============================================================================
+
+/// One section of the store settings.
+#[derive(Debug, Serialize)]
+pub struct SettingsSectionResponse {
+    pub section: &'static str,
+    /// Settings version (also sent as the ETag header)
+    pub version: i32,
+    pub settings: serde_json::Value,
+}
+
+impl SettingsSectionResponse {
+    fn new(section: SettingsSection, settings: &StoreSettingsPublic) -> Self {
+        Self {
+            section: section.as_str(),
+            version: settings.version,
+            settings: section.view(settings),
+        }
+    }
+}
+
+/// GET /api/v1/settings/:section - Get one settings section (admin or manage_settings).
+///
+/// Sections: `store`, `printing`, `workflow`, `notifications`, `security`.
+/// The ETag header identifies the settings version for a later PATCH.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the section is unknown
+pub async fn get_settings_section(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
+    Path(section): Path<String>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let section = SettingsSection::parse(&section)
+        .ok_or_else(|| state.probe_policy.missing("settings section"))?;
+    let settings = StoreSettingsRepository::get_settings_public(&state.db, store_id).await?;
+
+    Ok((
+        [(header::ETAG, settings_etag(settings.version))],
+        Json(ApiResponse::success(SettingsSectionResponse::new(
+            section, &settings,
+        ))),
+    ))
+}
+
+/// PATCH /api/v1/settings/:section - Update one settings section (admin or manage_settings).
+///
+/// Only fields belonging to the section are accepted; omitted fields keep
+/// their values. Send `If-Match` with the ETag from GET so a concurrent
+/// change by another admin is rejected instead of overwritten.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the section is unknown
+/// - VALIDATION_ERROR: If a field is invalid or belongs to another section
+/// - PRECONDITION_FAILED: If the settings changed since the If-Match ETag
+pub async fn patch_settings_section(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    CurrentStore(store_id): CurrentStore,
+    Path(section): Path<String>,
+    Json(body): Json<serde_json::Value>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Resolve the section and parse its fields
+    let section = SettingsSection::parse(&section)
+        .ok_or_else(|| state.probe_policy.missing("settings section"))?;
+    let expected_version = if_match_version(&headers)?;
+    let input = section.parse_patch(body).map_err(AppError::validation)?;
+
+    // 3. Validate with the same rules as a full update
+    let input = validate_settings_update(input)?;
+
+    // 4. Apply the update and record the change
+    let (settings, change) =
+        apply_settings_update(&state, &headers, store_id, input, expected_version, None).await?;
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        settings_audit_summary(section.as_str(), change.as_ref()),
+    )
+    .await;
+
+    Ok((
+        [(header::ETAG, settings_etag(settings.version))],
+        Json(ApiResponse::success(SettingsSectionResponse::new(
+            section, &settings,
+        ))),
+    ))
+}
+
+// =============================================================================
+// PUT/DELETE /settings/receipt-logo - Receipt Logo (Admin Only)
+// =============================================================================
+
+/// Storage key prefix for receipt logos.
+pub(crate) const RECEIPT_LOGO_PREFIX: &str = "branding/";
+
+/// PUT /api/v1/settings/receipt-logo - Upload the receipt logo (admin or manage_settings).
+///
+/// Accepts multipart/form-data with a single PNG or JPEG file field named
+/// "logo" (max 512KB). Metadata is stripped before the logo is stored, and it
+/// is printed in greyscale above the store name on receipts. Replaced logos
+/// stay in storage so a settings rollback can bring them back.
+///
+/// Returns the `printing` settings section. Send `If-Match` like a PATCH.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If the file is missing, too large, or not a readable PNG or JPEG
+/// - PRECONDITION_FAILED: If the settings changed since the If-Match ETag
+pub async fn upload_receipt_logo(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    CurrentStore(store_id): CurrentStore,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+    let expected_version = if_match_version(&headers)?;
+
+    // 2. Read and check the image
+    let data = read_logo_upload(&mut multipart).await?;
+    let format = detect_image_format(&data)
+        .filter(|format| matches!(format, ImageFormat::Png | ImageFormat::Jpeg))
+        .ok_or_else(|| AppError::validation("The logo must be a PNG or JPEG image"))?;
+    let data = tokio::task::spawn_blocking(move || process_photo(&data, format))
+        .await
+        .map_err(|e| AppError::server_error(format!("Logo processing task failed: {}", e)))?
+        .map_err(|_| AppError::validation("The logo image could not be read"))?;
+
+    // 3. Store it under a new key
+    let extension = match format {
+        ImageFormat::Png => "png",
+        _ => "jpg",
+    };
+    let key = format!(
+        "{}receipt-logo-{}.{}",
+        RECEIPT_LOGO_PREFIX,
+        uuid::Uuid::new_v4(),
+        extension
+    );
+    state
+        .storage
+        .upload(&key, data, format.mime_type())
+        .await
+        .map_err(|e| storage_error("Failed to upload logo", e))?;
+
+    // 4. Point the settings at it
+    let input = UpdateStoreSettings {
+        receipt_logo_key: Some(Some(key)),
+        ..Default::default()
+    };
+    update_printing_settings(
+        &state,
+        &headers,
+        client_ip,
+        store_id,
+        input,
+        expected_version,
+    )
+    .await
+}
+
+/// DELETE /api/v1/settings/receipt-logo - Stop printing a logo on receipts (admin or manage_settings).
+///
+/// Returns the `printing` settings section.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - PRECONDITION_FAILED: If the settings changed since the If-Match ETag
+pub async fn delete_receipt_logo(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    CurrentStore(store_id): CurrentStore,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+    let expected_version = if_match_version(&headers)?;
+
+    let input = UpdateStoreSettings {
+        receipt_logo_key: Some(None),
+        ..Default::default()
+    };
+    update_printing_settings(
+        &state,
+        &headers,
+        client_ip,
+        store_id,
+        input,
+        expected_version,
+    )
+    .await
+}
+
+/// Read the uploaded logo from the multipart `logo` field.
+async fn read_logo_upload(multipart: &mut Multipart) -> Result<Vec<u8>, AppError> {
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::validation(format!("Failed to read multipart field: {}", e)))?
+    {
+        if field.name() == Some("logo") {
+            let data = field
+                .bytes()
+                .await
+                .map_err(|e| AppError::validation(format!("Failed to read file data: {}", e)))?;
+            if data.is_empty() {
+                return Err(AppError::validation("Empty file provided"));
+            }
+            if data.len() > MAX_RECEIPT_LOGO_SIZE {
+                return Err(AppError::validation(format!(
+                    "Logo too large. Maximum size is {}KB",
+                    MAX_RECEIPT_LOGO_SIZE / 1024
+                )));
+            }
+            return Ok(data.to_vec());
+        }
+    }
+
+    Err(AppError::validation("No 'logo' field in request"))
+}
+
+/// Apply a printing settings change and respond with the printing section.
+async fn update_printing_settings(
+    state: &AppState,
+    headers: &HeaderMap,
+    client_ip: std::net::IpAddr,
+    store_id: uuid::Uuid,
+    input: UpdateStoreSettings,
+    expected_version: Option<i32>,
+) -> Result<impl IntoResponse, AppError> {
+    let section = SettingsSection::Printing;
+    let (settings, change) =
+        apply_settings_update(state, headers, store_id, input, expected_version, None).await?;
+    audit_settings(
+        state,
+        headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        settings_audit_summary(section.as_str(), change.as_ref()),
+    )
+    .await;
+
+    Ok((
+        [(header::ETAG, settings_etag(settings.version))],
+        Json(ApiResponse::success(SettingsSectionResponse::new(
+            section, &settings,
+        ))),
+    ))
+}
+
+// =============================================================================
+// POST/DELETE /settings/calendar-feed - Promise Date Calendar Feed
+// =============================================================================
+
+/// Path of the promise date feed, without its token.
+pub const CALENDAR_FEED_PATH: &str = "/api/v1/calendar/promise-dates.ics";
+
+/// Response for publishing the promise date calendar feed.
+#[derive(Debug, Clone, Serialize)]
+pub struct CalendarFeedResponse {
+    /// Feed token; shown only once
+    pub token: String,
+    /// Feed path with the token, for calendar apps to subscribe to
+    pub feed_path: String,
+}
+
+/// POST /api/v1/settings/calendar-feed - Publish the promise date feed under a new token (admin or manage_settings).
+///
+/// Generates a token for the current store's [iCalendar feed](crate::handlers::get_promise_date_feed)
+/// and returns it once; only its hash is stored. Calling it again replaces
+/// the token, so subscriptions using the old one stop updating.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+pub async fn rotate_calendar_feed_token(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    CurrentStore(store_id): CurrentStore,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let token = StoreSettingsRepository::generate_calendar_feed_token();
+    StoreSettingsRepository::set_calendar_feed_token_hash(
+        &state.db,
+        store_id,
+        Some(&StoreSettingsRepository::hash_calendar_feed_token(&token)),
+    )
+    .await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::CalendarFeedTokenRotated).target("store", store_id),
+    )
+    .await;
+
+    let feed_path = format!("{}?token={}", CALENDAR_FEED_PATH, token);
+    Ok(Json(ApiResponse::success(CalendarFeedResponse {
+        token,
+        feed_path,
+    })))
+}
+
+/// DELETE /api/v1/settings/calendar-feed - Revoke the promise date feed (admin or manage_settings).
+///
+/// The feed's token stops working; revoking a feed that isn't published is
+/// a no-op. Returns the settings.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+pub async fn revoke_calendar_feed_token(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    CurrentStore(store_id): CurrentStore,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    StoreSettingsRepository::set_calendar_feed_token_hash(&state.db, store_id, None).await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::CalendarFeedTokenRevoked).target("store", store_id),
+    )
+    .await;
+
+    let settings = StoreSettingsRepository::get_settings_public(&state.db, store_id).await?;
+    Ok(Json(ApiResponse::success(settings)))
+}
+
+// =============================================================================
+// GET /settings/history, POST /settings/rollback/:change_id - Change History
+// =============================================================================
+
+/// Query parameters for the settings change history.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SettingsHistoryQuery {
+    /// Maximum number of results (default 50, max 500)
+    pub limit: Option<i64>,
+}
+
+/// Response for the settings change history.
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsHistoryResponse {
+    pub changes: Vec<SettingsChangeEntry>,
+}
+
+/// GET /api/v1/settings/history - List settings changes, newest first (admin or manage_settings).
+///
+/// Each entry lists only the fields that changed, with their old and new
+/// values. Only the changes to the request's store are listed.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+pub async fn get_settings_history(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
+    Query(query): Query<SettingsHistoryQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let limit = query.limit.unwrap_or(50).clamp(1, 500);
+    let changes = SettingsChangeRepository::list_recent(&state.db, store_id, limit).await?;
+
+    Ok(Json(ApiResponse::success(SettingsHistoryResponse {
+        changes,
+    })))
+}
+
+/// Response for a settings rollback.
+#[derive(Debug, Clone, Serialize)]
+pub struct SettingsRollbackResponse {
+    /// Settings after the rollback
+    pub settings: StoreSettingsPublic,
+    /// The change recording the rollback (None if nothing needed reverting)
+    pub change: Option<SettingsChange>,
+}
+
+/// POST /api/v1/settings/rollback/:change_id - Revert a settings change (admin session only).
+///
+/// Restores the fields the change modified to their previous values, on the
+/// store whose settings it changed. The rollback is itself recorded as a
+/// change. Send `If-Match` to make sure
+/// nothing else changed in the meantime. Unlike other settings endpoints,
+/// neither the deprecated PIN header nor the manage_settings permission is
+/// accepted.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the change does not exist
+/// - VALIDATION_ERROR: If the old values are no longer valid
+/// - PRECONDITION_FAILED: If the settings changed since the If-Match ETag
+pub async fn rollback_settings(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(change_id): Path<uuid::Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify an admin session
+    verify_admin_session_header(&state, &headers).await?;
+
+    // 2. Find the change to revert
+    let change = SettingsChangeRepository::find_by_id(&state.db, change_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("settings change"))?;
+
+    // 3. Turn its old values back into an update, validated like any other
+    let expected_version = if_match_version(&headers)?;
+    let input: UpdateStoreSettings = serde_json::from_value(change.old_values)
+        .map_err(|err| AppError::validation(format!("Invalid settings: {}", err)))?;
+    let input = validate_settings_update(input)?;
+
+    // 4. Apply it, linking the new change to the one reverted
+    let (settings, change) = apply_settings_update(
+        &state,
+        &headers,
+        change.store_id,
+        input,
+        expected_version,
+        Some(change_id),
+    )
+    .await?;
+    let mut summary = settings_audit_summary("all", change.as_ref());
+    summary["rolled_back_change_id"] = serde_json::json!(change_id);
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsRolledBack,
+        summary,
+    )
+    .await;
+
+    Ok((
+        [(header::ETAG, settings_etag(settings.version))],
+        Json(ApiResponse::success(SettingsRollbackResponse {
+            settings,
+            change,
+        })),
+    ))
+}
+
+/// Apply a validated settings update to a store and record what changed.
+///
+/// Returns the new settings and the recorded change (None if no editable
+/// value actually changed). Deployment-wide settings are refused on any
+/// store but the primary one, and a ticket prefix can't be another store's.
+/// The update is pinned to the version read here so the recorded old values
+/// are exactly the ones replaced. The change is attributed to the signed-in
+/// employee when an employee session is sent alongside admin auth.
+pub(crate) async fn apply_settings_update(
+    state: &AppState,
+    headers: &HeaderMap,
+    store_id: uuid::Uuid,
+    input: UpdateStoreSettings,
+    expected_version: Option<i32>,
+    rolled_back_change_id: Option<uuid::Uuid>,
+) -> Result<(StoreSettingsPublic, Option<SettingsChange>), AppError> {
+    let changed_by = acting_employee_id(state, headers).await?;
+    let store = StoreRepository::find_by_id(&state.db, store_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("store"))?;
+    let deployment_settings = input.deployment_settings();
+    if !store.is_primary && !deployment_settings.is_empty() {
+        return Err(AppError::validation(format!(
+            "Change {} on the primary store; they apply to every store",
+            deployment_settings.join(", ")
+        )));
+    }
+    if let Some(prefix) = &input.ticket_prefix {
+        ensure_ticket_prefix_free(state, prefix, Some(store_id)).await?;
+    }
+    if let Some(timezone) = &input.timezone {
+        if !StoreSettingsRepository::is_known_timezone(&state.db, timezone).await? {
+            return Err(AppError::validation(format!(
+                "Unknown time zone {}",
+                timezone
+            )));
+        }
+    }
+
+    let before = StoreSettingsRepository::get_settings_public(&state.db, store_id).await?;
+    let expected_version = expected_version.unwrap_or(before.version);
+
+    let after = StoreSettingsRepository::update_settings(
+        &state.db,
+        store_id,
+        input,
+        Some(expected_version),
+    )
+    .await?;
+
+    let change = match settings_diff(&before, &after) {
+        Some((old_values, new_values)) => Some(
+            SettingsChangeRepository::create(
+                &state.db,
+                CreateSettingsChange {
+                    store_id,
+                    version: after.version,
+                    old_values,
+                    new_values,
+                    changed_by,
+                    rolled_back_change_id,
+                },
+            )
+            .await?,
+        ),
+        None => None,
+    };
+
+    Ok((after, change))
+}
+
+/// Refuse a ticket prefix another store already uses.
+///
+/// Friendly codes must stay unique across the chain. Prefixes are compared
+/// case-insensitively.
+pub(crate) async fn ensure_ticket_prefix_free(
+    state: &AppState,
+    prefix: &str,
+    store_id: Option<uuid::Uuid>,
+) -> Result<(), AppError> {
+    let taken = StoreRepository::list(&state.db, true)
+        .await?
+        .into_iter()
+        .any(|store| {
+            Some(store.store_id) != store_id && store.ticket_prefix.eq_ignore_ascii_case(prefix)
+        });
+    if taken {
+        return Err(AppError::validation(format!(
+            "Ticket prefix {} is already used by another store",
+            prefix
+        )));
+    }
+    Ok(())
+}
 
-    // Validate and sanitize text fields
+/// Audit summary of a store settings update: the section and the fields
+/// that changed.
+fn settings_audit_summary(section: &str, change: Option<&SettingsChange>) -> serde_json::Value {
+    let fields: Vec<&String> = change
+        .and_then(|c| c.new_values.as_object())
+        .map(|values| values.keys().collect())
+        .unwrap_or_default();
+    serde_json::json!({
+        "section": section,
+        "fields": fields,
+        "version": change.map(|c| c.version),
+    })
+}
+
+/// Record a settings update in the audit log.
+async fn audit_settings(
+    state: &AppState,
+    headers: &HeaderMap,
+    client_ip: std::net::IpAddr,
+    action: AuditAction,
+    summary: serde_json::Value,
+) {
+    record_audit(
+        state,
+        headers,
+        client_ip,
+        AuditEvent::new(action).summary(summary),
+    )
+    .await;
+}
+
+/// The employee making an admin request, if an employee session was sent.
+async fn acting_employee_id(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Result<Option<uuid::Uuid>, AppError> {
+    if headers.contains_key("X-Employee-Session") || headers.contains_key("X-Employee-ID") {
+        let employee = extract_employee_from_session(state, headers).await?;
+        Ok(Some(employee.employee_id))
+    } else {
+        Ok(None)
+    }
+}
+
+/// ETag for a settings version.
+fn settings_etag(version: i32) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", version))
+        .unwrap_or_else(|_| HeaderValue::from_static("\"0\""))
+}
+
+/// Settings version from an If-Match header.
+///
+/// Returns None when the header is absent or `*`. Weak ETags are accepted.
+fn if_match_version(headers: &HeaderMap) -> Result<Option<i32>, AppError> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+    let value = value
+        .to_str()
+        .map_err(|_| AppError::validation("Invalid If-Match header value"))?
+        .trim();
+    if value == "*" {
+        return Ok(None);
+    }
+
+    value
+        .trim_start_matches("W/")
+        .trim_matches('"')
+        .parse::<i32>()
+        .map(Some)
+        .map_err(|_| AppError::validation("Invalid If-Match header value"))
+}
+
+/// Validate and sanitize a settings update.
+pub(crate) fn validate_settings_update(
+    body: UpdateStoreSettings,
+) -> Result<UpdateStoreSettings, AppError> {
     let store_name = body
         .store_name
         .as_ref()
@@ -95,69 +843,1510 @@ pub async fn update_settings(
         .transpose()?
         .flatten();
 
-    // Build validated update input
-    let validated_body = UpdateStoreSettings {
+    if let Some(rate) = body.tax_rate {
+        if rate < Decimal::ZERO || rate > Decimal::from(MAX_TAX_RATE_PERCENT) {
+            return Err(AppError::validation(format!(
+                "tax_rate must be between 0 and {}",
+                MAX_TAX_RATE_PERCENT
+            )));
+        }
+        if rate.round_dp(3) != rate {
+            return Err(AppError::validation(
+                "tax_rate cannot have more than three decimal places",
+            ));
+        }
+    }
+
+    let qc_checklist = body
+        .qc_checklist
+        .as_deref()
+        .map(validate_qc_checklist)
+        .transpose()?;
+
+    if let Some(Some(threshold)) = body.custody_value_threshold {
+        if threshold < Decimal::ZERO {
+            return Err(AppError::validation(
+                "custody_value_threshold cannot be negative",
+            ));
+        }
+    }
+
+    if let Some(Some(threshold)) = body.quote_approval_threshold {
+        if threshold < Decimal::ZERO {
+            return Err(AppError::validation(
+                "quote_approval_threshold cannot be negative",
+            ));
+        }
+    }
+
+    if let Some(max_photos) = body.max_photos_per_ticket {
+        if !(0..=MAX_PHOTOS_PER_TICKET_LIMIT).contains(&max_photos) {
+            return Err(AppError::validation(format!(
+                "max_photos_per_ticket must be between 0 and {}",
+                MAX_PHOTOS_PER_TICKET_LIMIT
+            )));
+        }
+    }
+
+    if let Some(min_pin_length) = body.min_pin_length {
+        if !MIN_PIN_LENGTH_RANGE.contains(&min_pin_length) {
+            return Err(AppError::validation(format!(
+                "min_pin_length must be between {} and {}",
+                MIN_PIN_LENGTH_RANGE.start(),
+                MIN_PIN_LENGTH_RANGE.end()
+            )));
+        }
+    }
+
+    if let Some(padding) = body.ticket_number_padding {
+        if !TICKET_NUMBER_PADDING_RANGE.contains(&padding) {
+            return Err(AppError::validation(format!(
+                "ticket_number_padding must be between {} and {}",
+                TICKET_NUMBER_PADDING_RANGE.start(),
+                TICKET_NUMBER_PADDING_RANGE.end()
+            )));
+        }
+    }
+
+    if let Some(Some(days)) = body.rush_turnaround_days {
+        if !(0..=MAX_TURNAROUND_DAYS).contains(&days) {
+            return Err(AppError::validation(format!(
+                "rush_turnaround_days must be between 0 and {}",
+                MAX_TURNAROUND_DAYS
+            )));
+        }
+    }
+    let business_hours = body
+        .business_hours
+        .map(validate_business_hours)
+        .transpose()?;
+    let holidays = body.holidays.map(validate_holidays).transpose()?;
+    let timezone = body
+        .timezone
+        .map(|v| validate_required(&v, "timezone", MAX_TIMEZONE_LENGTH))
+        .transpose()?;
+
+    if let Some(Some(days)) = body.auto_archive_after_days {
+        if !AUTO_ARCHIVE_DAYS_RANGE.contains(&days) {
+            return Err(AppError::validation(format!(
+                "auto_archive_after_days must be between {} and {}",
+                AUTO_ARCHIVE_DAYS_RANGE.start(),
+                AUTO_ARCHIVE_DAYS_RANGE.end()
+            )));
+        }
+    }
+
+    let overdue_digest_email = body
+        .overdue_digest_email
+        .map(|email| validate_email(email.as_deref(), MAX_EMAIL_LENGTH))
+        .transpose()?;
+
+    if let Some(hour) = body.overdue_digest_hour {
+        if !(0..=23).contains(&hour) {
+            return Err(AppError::validation(
+                "overdue_digest_hour must be between 0 and 23",
+            ));
+        }
+    }
+
+    let receipt_logo_key = body
+        .receipt_logo_key
+        .map(|key| key.map(validate_receipt_logo_key).transpose())
+        .transpose()?;
+    let receipt_footer_text = body
+        .receipt_footer_text
+        .map(|text| {
+            validate_optional(
+                text.as_deref(),
+                "receipt_footer_text",
+                MAX_RECEIPT_FOOTER_LENGTH,
+            )
+        })
+        .transpose()?;
+
+    // A named stock stands in for both dimensions
+    let (label_width_mm, label_height_mm) = match body.label_stock {
+        Some(_) if body.label_width_mm.is_some() || body.label_height_mm.is_some() => {
+            return Err(AppError::validation(
+                "label_stock cannot be combined with label_width_mm or label_height_mm",
+            ));
+        }
+        Some(stock) => {
+            let (width, height) = stock.size_mm();
+            (Some(width), Some(height))
+        }
+        None => (body.label_width_mm, body.label_height_mm),
+    };
+    if let Some(width) = label_width_mm {
+        validate_label_dimension(width, "label_width_mm", &LABEL_WIDTH_MM_RANGE)?;
+    }
+    if let Some(height) = label_height_mm {
+        validate_label_dimension(height, "label_height_mm", &LABEL_HEIGHT_MM_RANGE)?;
+    }
+
+    Ok(UpdateStoreSettings {
         store_name,
         store_phone,
         store_address,
         ticket_prefix,
         currency,
         max_photos_per_ticket: body.max_photos_per_ticket,
-    };
+        qc_checklist,
+        scope_ticket_visibility: body.scope_ticket_visibility,
+        custody_value_threshold: body.custody_value_threshold,
+        quote_approval_threshold: body.quote_approval_threshold,
+        notifications_enabled: body.notifications_enabled,
+        min_pin_length: body.min_pin_length,
+        auto_archive_after_days: body.auto_archive_after_days,
+        restrict_item_types: body.restrict_item_types,
+        overdue_digest_email,
+        overdue_digest_sms: body.overdue_digest_sms,
+        overdue_digest_hour: body.overdue_digest_hour,
+        receipt_logo_key,
+        receipt_footer_text,
+        receipt_show_prices: body.receipt_show_prices,
+        label_width_mm,
+        label_height_mm,
+        label_stock: None,
+        receipt_format: body.receipt_format,
+        tax_rate: body.tax_rate,
+        tax_inclusive: body.tax_inclusive,
+        ticket_number_padding: body.ticket_number_padding,
+        ticket_number_yearly_reset: body.ticket_number_yearly_reset,
+        business_hours,
+        holidays,
+        rush_turnaround_days: body.rush_turnaround_days,
+        timezone,
+    })
+}
 
-    // Update the settings
-    let settings = StoreSettingsRepository::update_settings(&state.db, validated_body).await?;
+/// Check a store's opening hours and sort them by weekday.
+///
+/// Each weekday may be listed once, and must close after it opens. An
+/// empty list is allowed (open every day).
+fn validate_business_hours(mut hours: Vec<OpeningHours>) -> Result<Vec<OpeningHours>, AppError> {
+    for (i, day) in hours.iter().enumerate() {
+        if !(1..=7).contains(&day.weekday) {
+            return Err(AppError::validation(format!(
+                "business_hours[{}].weekday must be between 1 and 7",
+                i
+            )));
+        }
+        if day.closes_at <= day.opens_at {
+            return Err(AppError::validation(format!(
+                "business_hours[{}] must close after it opens",
+                i
+            )));
+        }
+    }
+    hours.sort_by_key(|day| day.weekday);
+    if let Some(pair) = hours
+        .windows(2)
+        .find(|pair| pair[0].weekday == pair[1].weekday)
+    {
+        return Err(AppError::validation(format!(
+            "business_hours lists weekday {} more than once",
+            pair[0].weekday
+        )));
+    }
+    Ok(hours)
+}
 
-    Ok(Json(ApiResponse::success(settings)))
+/// Check a store's holidays, sorting them and dropping repeats.
+fn validate_holidays(mut holidays: Vec<NaiveDate>) -> Result<Vec<NaiveDate>, AppError> {
+    holidays.sort();
+    holidays.dedup();
+    if holidays.len() > MAX_HOLIDAYS {
+        return Err(AppError::validation(format!(
+            "holidays cannot have more than {} items",
+            MAX_HOLIDAYS
+        )));
+    }
+    Ok(holidays)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Check that a receipt logo key points at an uploaded logo.
+///
+/// Only keys under `RECEIPT_LOGO_PREFIX` are accepted, so a logo can't be
+/// pointed at a ticket photo or any other stored object.
+fn validate_receipt_logo_key(key: String) -> Result<String, AppError> {
+    let valid = key
+        .strip_prefix(RECEIPT_LOGO_PREFIX)
+        .is_some_and(|name| !name.is_empty() && !name.contains('/') && !name.contains(".."));
+    if !valid || key.len() > MAX_STORAGE_KEY_LENGTH {
+        return Err(AppError::validation(
+            "receipt_logo_key must be a logo uploaded through /settings/receipt-logo",
+        ));
+    }
+    Ok(key)
+}
 
-    #[test]
-    fn test_settings_handler_exists() {
-        // Basic sanity test
-        assert!(true);
+/// Check a label dimension against its allowed range in mm.
+fn validate_label_dimension(
+    value: Decimal,
+    field: &str,
+    range: &std::ops::RangeInclusive<i64>,
+) -> Result<(), AppError> {
+    if value < Decimal::from(*range.start()) || value > Decimal::from(*range.end()) {
+        return Err(AppError::validation(format!(
+            "{} must be between {} and {}",
+            field,
+            range.start(),
+            range.end()
+        )));
     }
+    if value.scale() > 1 && value.round_dp(1) != value {
+        return Err(AppError::validation(format!(
+            "{} cannot have more than one decimal place",
+            field
+        )));
+    }
+    Ok(())
+}
 
-    #[test]
-    fn test_update_store_settings_partial_deserialize() {
-        let json = r#"{"store_name": "Updated Store"}"#;
-        let input: UpdateStoreSettings = serde_json::from_str(json).unwrap();
-        assert_eq!(input.store_name, Some("Updated Store".to_string()));
-        assert!(input.store_phone.is_none());
-        assert!(input.ticket_prefix.is_none());
+/// Validate and normalize QC checklist items.
+///
+/// Items are trimmed; blank and duplicate items are rejected so each
+/// item can be answered unambiguously on a QC check.
+fn validate_qc_checklist(items: &[String]) -> Result<Vec<String>, AppError> {
+    validate_text_list(items, "qc_checklist", MAX_QC_ITEMS, MAX_QC_ITEM_LENGTH)
+}
+
+/// Validate and normalize a list of short text items.
+///
+/// Items are trimmed; blank and duplicate items are rejected.
+fn validate_text_list(
+    items: &[String],
+    field: &str,
+    max_items: usize,
+    max_length: usize,
+) -> Result<Vec<String>, AppError> {
+    if items.len() > max_items {
+        return Err(AppError::validation(format!(
+            "{} cannot have more than {} items",
+            field, max_items
+        )));
     }
 
-    #[test]
-    fn test_update_store_settings_empty_deserialize() {
-        let json = r#"{}"#;
-        let input: UpdateStoreSettings = serde_json::from_str(json).unwrap();
-        assert!(input.store_name.is_none());
-        assert!(input.store_phone.is_none());
-        assert!(input.store_address.is_none());
-        assert!(input.ticket_prefix.is_none());
-        assert!(input.currency.is_none());
-        assert!(input.max_photos_per_ticket.is_none());
+    let mut validated: Vec<String> = Vec::with_capacity(items.len());
+    for item in items {
+        let item = validate_required(item, &format!("{} item", field), max_length)?;
+        if validated.contains(&item) {
+            return Err(AppError::validation(format!(
+                "Duplicate {} item: {}",
+                field, item
+            )));
+        }
+        validated.push(item);
     }
 
-    #[test]
-    fn test_update_store_settings_full_deserialize() {
-        let json = r#"{
-            "store_name": "My Jewelry Store",
-            "store_phone": "555-0100",
-            "store_address": "456 Oak Ave",
-            "ticket_prefix": "MJ",
-            "currency": "EUR",
-            "max_photos_per_ticket": 8
-        }"#;
-        let input: UpdateStoreSettings = serde_json::from_str(json).unwrap();
-        assert_eq!(input.store_name, Some("My Jewelry Store".to_string()));
-        assert_eq!(input.store_phone, Some("555-0100".to_string()));
-        assert_eq!(input.store_address, Some("456 Oak Ave".to_string()));
-        assert_eq!(input.ticket_prefix, Some("MJ".to_string()));
+    Ok(validated)
+}
+
+// =============================================================================
+// GET/PUT /settings/location-rules - Storage Suggestion Rules (Admin Only)
+// =============================================================================
+
+/// Storage suggestion rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationRulesBody<T> {
+    pub rules: Vec<T>,
+}
+
+/// GET /api/v1/settings/location-rules - List storage suggestion rules (admin or manage_settings).
+pub async fn get_location_rules(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let rules = StorageLocationRepository::list_rules(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(LocationRulesBody { rules })))
+}
+
+/// PUT /api/v1/settings/location-rules - Replace storage suggestion rules (admin or manage_settings).
+///
+/// Rules drive `GET /locations/suggest` during intake. The full set is
+/// replaced; send an empty list to remove all rules.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If a rule has no condition, a negative amount, or an
+///   inactive/unknown location
+pub async fn update_location_rules(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<LocationRulesBody<CreateStorageLocationRule>>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate rule fields
+    let rules = validate_location_rules(body.rules)?;
+
+    // 3. Every rule must point at an active location
+    for rule in &rules {
+        if !StorageLocationRepository::exists_active(&state.db, rule.location_id).await? {
+            return Err(AppError::validation(format!(
+                "Storage location {} does not exist or is inactive",
+                rule.location_id
+            )));
+        }
+    }
+
+    // 4. Replace the rule set
+    let rules: Vec<StorageLocationRule> =
+        StorageLocationRepository::replace_rules(&state.db, rules).await?;
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        serde_json::json!({ "section": "location_rules", "count": rules.len() }),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(LocationRulesBody { rules })))
+}
+
+/// Validate and normalize storage suggestion rules.
+pub(crate) fn validate_location_rules(
+    rules: Vec<CreateStorageLocationRule>,
+) -> Result<Vec<CreateStorageLocationRule>, AppError> {
+    if rules.len() > MAX_LOCATION_RULES {
+        return Err(AppError::validation(format!(
+            "Cannot have more than {} location rules",
+            MAX_LOCATION_RULES
+        )));
+    }
+
+    rules
+        .into_iter()
+        .map(|rule| {
+            let item_type =
+                validate_optional(rule.item_type.as_deref(), "item_type", MAX_ITEM_TYPE_LENGTH)?;
+            if item_type.is_none() && rule.min_quote_amount.is_none() {
+                return Err(AppError::validation(
+                    "Each location rule needs an item_type or min_quote_amount",
+                ));
+            }
+            if rule
+                .min_quote_amount
+                .is_some_and(|amount| amount < Decimal::ZERO)
+            {
+                return Err(AppError::validation("min_quote_amount cannot be negative"));
+            }
+            Ok(CreateStorageLocationRule { item_type, ..rule })
+        })
+        .collect()
+}
+
+// =============================================================================
+// GET/PUT /settings/metal-prices - Melt Value Price Table (Admin Only)
+// =============================================================================
+
+/// Metal price table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetalPricesBody<T> {
+    pub prices: Vec<T>,
+}
+
+/// GET /api/v1/settings/metal-prices - List metal prices (admin or manage_settings).
+pub async fn get_metal_prices(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let prices = MetalPriceRepository::list(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(MetalPricesBody { prices })))
+}
+
+/// PUT /api/v1/settings/metal-prices - Replace metal prices (admin or manage_settings).
+///
+/// Prices drive the melt-value estimate on ticket details. The full table is
+/// replaced; tickets whose metal type is removed lose their estimate.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If a metal type is blank or repeated, purity is not
+///   in (0, 1], or a price is negative
+pub async fn update_metal_prices(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<MetalPricesBody<CreateMetalPrice>>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate price entries
+    let prices = validate_metal_prices(body.prices)?;
+
+    // 3. Replace the price table
+    let prices: Vec<MetalPrice> = MetalPriceRepository::replace_all(&state.db, prices).await?;
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        serde_json::json!({ "section": "metal_prices", "count": prices.len() }),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(MetalPricesBody { prices })))
+}
+
+/// Validate and normalize metal price entries.
+pub(crate) fn validate_metal_prices(
+    prices: Vec<CreateMetalPrice>,
+) -> Result<Vec<CreateMetalPrice>, AppError> {
+    if prices.len() > MAX_METAL_PRICES {
+        return Err(AppError::validation(format!(
+            "Cannot have more than {} metal prices",
+            MAX_METAL_PRICES
+        )));
+    }
+
+    let mut validated: Vec<CreateMetalPrice> = Vec::with_capacity(prices.len());
+    for price in prices {
+        let metal_type = validate_required(&price.metal_type, "metal_type", MAX_METAL_TYPE_LENGTH)?;
+        if validated.iter().any(|p| p.metal_type == metal_type) {
+            return Err(AppError::validation(format!(
+                "Duplicate metal_type: {}",
+                metal_type
+            )));
+        }
+        if price.purity <= Decimal::ZERO || price.purity > Decimal::ONE {
+            return Err(AppError::validation(
+                "purity must be greater than 0 and at most 1",
+            ));
+        }
+        if price.price_per_gram < Decimal::ZERO {
+            return Err(AppError::validation("price_per_gram cannot be negative"));
+        }
+        validated.push(CreateMetalPrice {
+            metal_type,
+            ..price
+        });
+    }
+
+    Ok(validated)
+}
+
+// =============================================================================
+// GET/PUT /settings/item-types - Item Type Defaults (Admin Only)
+// =============================================================================
+
+/// Configured item types.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemTypesBody<T> {
+    pub item_types: Vec<T>,
+}
+
+/// Query parameters for listing item types.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ItemTypesQuery {
+    /// Only item types whose name contains this text, prefix matches first
+    pub q: Option<String>,
+    /// Maximum number of matches for `q` (default 10, max 100)
+    pub limit: Option<i64>,
+}
+
+/// GET /api/v1/settings/item-types - List item types.
+///
+/// This endpoint is public, like the location list, so intake can offer the
+/// configured types and show their checklists. With `q`, returns up to
+/// `limit` matching types for typeahead.
+pub async fn get_item_types(
+    State(state): State<AppState>,
+    Query(query): Query<ItemTypesQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let item_types = match query.q.as_deref().map(str::trim) {
+        Some(q) if !q.is_empty() => {
+            let limit = query.limit.unwrap_or(10).clamp(1, 100);
+            ItemTypeRepository::search(&state.db, q, limit).await?
+        }
+        _ => ItemTypeRepository::list(&state.db).await?,
+    };
+
+    Ok(Json(ApiResponse::success(ItemTypesBody { item_types })))
+}
+
+/// PUT /api/v1/settings/item-types - Replace item types (admin or manage_settings).
+///
+/// The full list is replaced and its order kept. Tickets keep their item
+/// type text; defaults apply to tickets taken in afterwards.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If a name is blank or repeated, a default is out of
+///   range, or a checklist or service list is invalid
+pub async fn update_item_types(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<ItemTypesBody<CreateItemType>>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate item types
+    let item_types = validate_item_types(body.item_types)?;
+
+    // 3. Replace the item types
+    let item_types: Vec<ItemType> = ItemTypeRepository::replace_all(&state.db, item_types).await?;
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        serde_json::json!({ "section": "item_types", "count": item_types.len() }),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(ItemTypesBody { item_types })))
+}
+
+/// Validate and normalize item types.
+pub(crate) fn validate_item_types(
+    item_types: Vec<CreateItemType>,
+) -> Result<Vec<CreateItemType>, AppError> {
+    if item_types.len() > MAX_ITEM_TYPES {
+        return Err(AppError::validation(format!(
+            "Cannot have more than {} item types",
+            MAX_ITEM_TYPES
+        )));
+    }
+
+    let mut validated: Vec<CreateItemType> = Vec::with_capacity(item_types.len());
+    for item_type in item_types {
+        let name = validate_required(&item_type.name, "name", MAX_ITEM_TYPE_LENGTH)?;
+        if validated
+            .iter()
+            .any(|other| other.name.to_lowercase() == name.to_lowercase())
+        {
+            return Err(AppError::validation(format!(
+                "Duplicate item type: {}",
+                name
+            )));
+        }
+        if item_type
+            .default_turnaround_days
+            .is_some_and(|days| !(0..=MAX_TURNAROUND_DAYS).contains(&days))
+        {
+            return Err(AppError::validation(format!(
+                "default_turnaround_days must be between 0 and {}",
+                MAX_TURNAROUND_DAYS
+            )));
+        }
+        if item_type
+            .base_price
+            .is_some_and(|price| price < Decimal::ZERO)
+        {
+            return Err(AppError::validation("base_price cannot be negative"));
+        }
+        if !(0..=MAX_PHOTOS_PER_TICKET_LIMIT).contains(&item_type.required_photos) {
+            return Err(AppError::validation(format!(
+                "required_photos must be between 0 and {}",
+                MAX_PHOTOS_PER_TICKET_LIMIT
+            )));
+        }
+        let condition_checklist = validate_text_list(
+            &item_type.condition_checklist,
+            "condition_checklist",
+            MAX_ITEM_TYPE_LIST_ITEMS,
+            MAX_QC_ITEM_LENGTH,
+        )?;
+        let suggested_services = validate_text_list(
+            &item_type.suggested_services,
+            "suggested_services",
+            MAX_ITEM_TYPE_LIST_ITEMS,
+            MAX_QC_ITEM_LENGTH,
+        )?;
+        validated.push(CreateItemType {
+            name,
+            condition_checklist,
+            suggested_services,
+            ..item_type
+        });
+    }
+
+    Ok(validated)
+}
+
+// =============================================================================
+// GET/PUT /settings/promise-date-reasons - Promise Date Reasons (Admin Only)
+// =============================================================================
+
+/// Configured reasons for moving a promise date later.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromiseDateReasonsBody<T> {
+    pub reasons: Vec<T>,
+}
+
+/// GET /api/v1/settings/promise-date-reasons - List promise date reasons.
+///
+/// This endpoint is public, like the item type list, so staff can pick a
+/// reason when moving a promise date.
+pub async fn get_promise_date_reasons(
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, AppError> {
+    let reasons = PromiseDateReasonRepository::list(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(PromiseDateReasonsBody {
+        reasons,
+    })))
+}
+
+/// PUT /api/v1/settings/promise-date-reasons - Replace promise date reasons (admin or manage_settings).
+///
+/// The full list is replaced and its order kept. Notifications already sent
+/// keep the wording they were sent with.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If a code or label is blank, too long, or a code is repeated
+pub async fn update_promise_date_reasons(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<PromiseDateReasonsBody<CreatePromiseDateReason>>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate reasons
+    let reasons = validate_promise_date_reasons(body.reasons)?;
+
+    // 3. Replace the reasons
+    let reasons: Vec<PromiseDateReason> =
+        PromiseDateReasonRepository::replace_all(&state.db, reasons).await?;
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        serde_json::json!({ "section": "promise_date_reasons", "count": reasons.len() }),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(PromiseDateReasonsBody {
+        reasons,
+    })))
+}
+
+/// Validate and normalize promise date reasons.
+pub(crate) fn validate_promise_date_reasons(
+    reasons: Vec<CreatePromiseDateReason>,
+) -> Result<Vec<CreatePromiseDateReason>, AppError> {
+    if reasons.len() > MAX_PROMISE_DATE_REASONS {
+        return Err(AppError::validation(format!(
+            "Cannot have more than {} promise date reasons",
+            MAX_PROMISE_DATE_REASONS
+        )));
+    }
+
+    let mut validated: Vec<CreatePromiseDateReason> = Vec::with_capacity(reasons.len());
+    for reason in reasons {
+        let code = validate_required(&reason.code, "code", MAX_REASON_CODE_LENGTH)?;
+        if validated
+            .iter()
+            .any(|other| other.code.eq_ignore_ascii_case(&code))
+        {
+            return Err(AppError::validation(format!(
+                "Duplicate promise date reason: {}",
+                code
+            )));
+        }
+        let label = validate_required(&reason.label, "label", MAX_NAME_LENGTH)?;
+        validated.push(CreatePromiseDateReason { code, label });
+    }
+
+    Ok(validated)
+}
+
+// =============================================================================
+// GET/PUT /settings/rush-pricing - Rush Surcharge Tiers (Admin Only)
+// =============================================================================
+
+/// Rush surcharge tiers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RushPricingBody<T> {
+    pub tiers: Vec<T>,
+}
+
+/// GET /api/v1/settings/rush-pricing - List rush surcharge tiers (admin or manage_settings).
+pub async fn get_rush_pricing(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let tiers = RushPricingRepository::list(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(RushPricingBody { tiers })))
+}
+
+/// PUT /api/v1/settings/rush-pricing - Replace rush surcharge tiers (admin or manage_settings).
+///
+/// Tiers drive the quote helper (`POST /tickets/quote`). The full set is
+/// replaced; surcharges already recorded on tickets are unchanged.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If a lead time is negative or repeated, an amount is
+///   negative, or a percentage is too large
+pub async fn update_rush_pricing(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<RushPricingBody<CreateRushSurchargeTier>>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate tiers
+    let tiers = validate_rush_tiers(body.tiers)?;
+
+    // 3. Replace the tiers
+    let tiers: Vec<RushSurchargeTier> =
+        RushPricingRepository::replace_all(&state.db, tiers).await?;
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        serde_json::json!({ "section": "rush_pricing", "count": tiers.len() }),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(RushPricingBody { tiers })))
+}
+
+/// Validate rush surcharge tiers.
+pub(crate) fn validate_rush_tiers(
+    tiers: Vec<CreateRushSurchargeTier>,
+) -> Result<Vec<CreateRushSurchargeTier>, AppError> {
+    if tiers.len() > MAX_RUSH_SURCHARGE_TIERS {
+        return Err(AppError::validation(format!(
+            "Cannot have more than {} rush surcharge tiers",
+            MAX_RUSH_SURCHARGE_TIERS
+        )));
+    }
+
+    for (index, tier) in tiers.iter().enumerate() {
+        if tier.max_lead_days.is_some_and(|days| days < 0) {
+            return Err(AppError::validation("max_lead_days cannot be negative"));
+        }
+        if tiers[..index]
+            .iter()
+            .any(|other| other.max_lead_days == tier.max_lead_days)
+        {
+            return Err(AppError::validation(
+                "Each rush surcharge tier needs a different max_lead_days",
+            ));
+        }
+        if tier.amount < Decimal::ZERO {
+            return Err(AppError::validation("amount cannot be negative"));
+        }
+        if tier.kind == RushSurchargeKind::Percent
+            && tier.amount > Decimal::from(MAX_RUSH_SURCHARGE_PERCENT)
+        {
+            return Err(AppError::validation(format!(
+                "amount must be between 0 and {}",
+                MAX_RUSH_SURCHARGE_PERCENT
+            )));
+        }
+    }
+
+    Ok(tiers)
+}
+
+// =============================================================================
+// GET/PUT /settings/notification-templates - Email Templates (Admin Only)
+// =============================================================================
+
+/// Email templates and the placeholders they may use.
+#[derive(Debug, Serialize)]
+pub struct NotificationTemplatesResponse {
+    pub templates: Vec<NotificationTemplate>,
+    pub placeholders: &'static [&'static str],
+}
+
+/// GET /api/v1/settings/notification-templates - List email templates (admin or manage_settings).
+pub async fn list_notification_templates(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    let templates = NotificationTemplateRepository::list(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(NotificationTemplatesResponse {
+        templates,
+        placeholders: PLACEHOLDERS,
+    })))
+}
+
+/// PUT /api/v1/settings/notification-templates/:event - Update an email template (admin or manage_settings).
+///
+/// Subject and body may use `{placeholder}` tokens, which are filled in from
+/// the ticket when the email is sent. Set `is_enabled` to false to stop
+/// sending the email for that event.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the event is unknown
+/// - VALIDATION_ERROR: If subject or body is empty or too long
+pub async fn update_notification_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(event): Path<String>,
+    Json(body): Json<UpdateNotificationTemplate>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Resolve the event
+    let event = NotificationEvent::parse(&event)
+        .ok_or_else(|| state.probe_policy.missing("notification template"))?;
+
+    // 3. Validate provided fields
+    let input = validate_notification_template(body)?;
+
+    // 4. Apply the update
+    let template = NotificationTemplateRepository::update(&state.db, event, input)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("notification template"))?;
+    audit_settings(
+        &state,
+        &headers,
+        client_ip,
+        AuditAction::SettingsUpdated,
+        serde_json::json!({ "section": "notification_templates", "event": event }),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(template)))
+}
+
+// =============================================================================
+// POST /settings/templates/validate - Template Preview (Admin Only)
+// =============================================================================
+
+/// Request body for validating a template.
+#[derive(Debug, Deserialize)]
+pub struct ValidateTemplateRequest {
+    pub subject: Option<String>,
+    pub body: String,
+}
+
+/// Template rendered against a sample ticket.
+#[derive(Debug, Serialize)]
+pub struct TemplateValidationResponse {
+    /// True when every placeholder is known.
+    pub valid: bool,
+    pub unknown_variables: Vec<String>,
+    pub rendered_subject: Option<String>,
+    pub rendered_body: String,
+}
+
+/// POST /api/v1/settings/templates/validate - Preview a template (admin or manage_settings).
+///
+/// Renders the subject and body against a sample ticket using the store's
+/// name and phone, and lists any `{placeholder}` that would be sent to the
+/// customer unfilled. Nothing is saved.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If body is empty or too long
+pub async fn validate_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
+    Json(body): Json<ValidateTemplateRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication or the manage_settings permission
+    verify_permission(&state, &headers, Permission::ManageSettings).await?;
+
+    // 2. Validate lengths the same way a template update would
+    let input = validate_notification_template(UpdateNotificationTemplate {
+        subject: body.subject,
+        body: Some(body.body),
+        is_enabled: None,
+    })?;
+
+    // 3. Render against a sample ticket for this store
+    let settings = StoreSettingsRepository::get_store_settings(&state.db, store_id).await?;
+    let context = TemplateContext::sample(settings.store_name, settings.store_phone);
+
+    Ok(Json(ApiResponse::success(preview_template(
+        &context,
+        input.subject.as_deref(),
+        input.body.as_deref().unwrap_or_default(),
+    ))))
+}
+
+/// Render a template and collect its unknown placeholders.
+fn preview_template(
+    context: &TemplateContext,
+    subject: Option<&str>,
+    body: &str,
+) -> TemplateValidationResponse {
+    let mut unknown_variables = subject.map(unknown_placeholders).unwrap_or_default();
+    for name in unknown_placeholders(body) {
+        if !unknown_variables.contains(&name) {
+            unknown_variables.push(name);
+        }
+    }
+
+    TemplateValidationResponse {
+        valid: unknown_variables.is_empty(),
+        unknown_variables,
+        rendered_subject: subject.map(|s| context.render(s)),
+        rendered_body: context.render(body),
+    }
+}
+
+/// Validate and sanitize a template update.
+pub(crate) fn validate_notification_template(
+    input: UpdateNotificationTemplate,
+) -> Result<UpdateNotificationTemplate, AppError> {
+    let subject = input
+        .subject
+        .as_deref()
+        .map(|subject| validate_required(subject, "subject", MAX_NAME_LENGTH))
+        .transpose()?;
+    let body = input
+        .body
+        .as_deref()
+        .map(|body| validate_required(body, "body", MAX_TEMPLATE_BODY_LENGTH))
+        .transpose()?;
+
+    Ok(UpdateNotificationTemplate {
+        subject,
+        body,
+        is_enabled: input.is_enabled,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::store_settings::LabelStock;
+
+    fn metal_price(metal_type: &str, purity: &str, per_gram: &str) -> CreateMetalPrice {
+        CreateMetalPrice {
+            metal_type: metal_type.to_string(),
+            purity: purity.parse().unwrap(),
+            price_per_gram: per_gram.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_validate_metal_prices_trims_metal_type() {
+        let prices = validate_metal_prices(vec![metal_price(" 14k_gold ", "0.585", "80")]).unwrap();
+        assert_eq!(prices[0].metal_type, "14k_gold");
+    }
+
+    #[test]
+    fn test_validate_metal_prices_rejects_bad_purity() {
+        assert!(validate_metal_prices(vec![metal_price("gold", "0", "80")]).is_err());
+        assert!(validate_metal_prices(vec![metal_price("gold", "1.01", "80")]).is_err());
+        assert!(validate_metal_prices(vec![metal_price("gold", "1", "80")]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_metal_prices_rejects_negative_price() {
+        assert!(validate_metal_prices(vec![metal_price("gold", "1", "-1")]).is_err());
+    }
+
+    #[test]
+    fn test_validate_metal_prices_rejects_duplicates() {
+        let prices = vec![
+            metal_price("silver_925", "0.925", "1"),
+            metal_price(" silver_925", "0.925", "1.1"),
+        ];
+        assert!(validate_metal_prices(prices).is_err());
+    }
+
+    fn rush_tier(
+        max_lead_days: Option<i32>,
+        kind: RushSurchargeKind,
+        amount: i64,
+    ) -> CreateRushSurchargeTier {
+        CreateRushSurchargeTier {
+            max_lead_days,
+            kind,
+            amount: Decimal::new(amount, 0),
+        }
+    }
+
+    fn item_type(name: &str) -> CreateItemType {
+        CreateItemType {
+            name: name.to_string(),
+            default_turnaround_days: Some(7),
+            required_photos: 2,
+            condition_checklist: vec![" Stones secure ".to_string()],
+            suggested_services: vec!["Sizing".to_string()],
+            base_price: Some(Decimal::new(45, 0)),
+        }
+    }
+
+    #[test]
+    fn test_validate_item_types() {
+        let validated = validate_item_types(vec![item_type(" Ring "), item_type("Watch")]).unwrap();
+        assert_eq!(validated[0].name, "Ring");
+        assert_eq!(validated[0].condition_checklist, vec!["Stones secure"]);
+
+        assert!(validate_item_types(vec![item_type("Ring"), item_type("ring")]).is_err());
+        assert!(validate_item_types(vec![item_type(" ")]).is_err());
+
+        let mut bad_turnaround = item_type("Ring");
+        bad_turnaround.default_turnaround_days = Some(-1);
+        assert!(validate_item_types(vec![bad_turnaround]).is_err());
+
+        let mut bad_photos = item_type("Ring");
+        bad_photos.required_photos = MAX_PHOTOS_PER_TICKET_LIMIT + 1;
+        assert!(validate_item_types(vec![bad_photos]).is_err());
+
+        let mut bad_price = item_type("Ring");
+        bad_price.base_price = Some(Decimal::new(-1, 0));
+        assert!(validate_item_types(vec![bad_price]).is_err());
+
+        let mut duplicate_service = item_type("Ring");
+        duplicate_service.suggested_services = vec!["Sizing".to_string(), "Sizing".to_string()];
+        assert!(validate_item_types(vec![duplicate_service]).is_err());
+    }
+
+    #[test]
+    fn test_validate_promise_date_reasons() {
+        let reason = |code: &str, label: &str| CreatePromiseDateReason {
+            code: code.to_string(),
+            label: label.to_string(),
+        };
+
+        let validated =
+            validate_promise_date_reasons(vec![reason(" parts ", " Waiting on parts ")]).unwrap();
+        assert_eq!(validated[0].code, "parts");
+        assert_eq!(validated[0].label, "Waiting on parts");
+
+        assert!(validate_promise_date_reasons(vec![
+            reason("parts", "Parts"),
+            reason("PARTS", "More parts")
+        ])
+        .is_err());
+        assert!(validate_promise_date_reasons(vec![reason(" ", "Parts")]).is_err());
+        assert!(validate_promise_date_reasons(vec![reason("parts", "")]).is_err());
+    }
+
+    #[test]
+    fn test_validate_rush_tiers() {
+        let valid = vec![
+            rush_tier(Some(2), RushSurchargeKind::Percent, 50),
+            rush_tier(Some(7), RushSurchargeKind::Percent, 20),
+            rush_tier(None, RushSurchargeKind::Flat, 15),
+        ];
+        assert_eq!(validate_rush_tiers(valid).unwrap().len(), 3);
+
+        for tiers in [
+            vec![rush_tier(Some(-1), RushSurchargeKind::Flat, 10)],
+            vec![rush_tier(None, RushSurchargeKind::Flat, -10)],
+            vec![rush_tier(None, RushSurchargeKind::Percent, 501)],
+            vec![
+                rush_tier(None, RushSurchargeKind::Flat, 10),
+                rush_tier(None, RushSurchargeKind::Percent, 10),
+            ],
+        ] {
+            assert!(validate_rush_tiers(tiers).is_err());
+        }
+    }
+
+    #[test]
+    fn test_if_match_version() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(if_match_version(&headers).unwrap(), None);
+
+        headers.insert(header::IF_MATCH, HeaderValue::from_static("\"7\""));
+        assert_eq!(if_match_version(&headers).unwrap(), Some(7));
+        headers.insert(header::IF_MATCH, HeaderValue::from_static("W/\"7\""));
+        assert_eq!(if_match_version(&headers).unwrap(), Some(7));
+        headers.insert(header::IF_MATCH, HeaderValue::from_static("*"));
+        assert_eq!(if_match_version(&headers).unwrap(), None);
+        headers.insert(header::IF_MATCH, HeaderValue::from_static("\"abc\""));
+        assert!(if_match_version(&headers).is_err());
+
+        assert_eq!(settings_etag(7), "\"7\"");
+    }
+
+    #[test]
+    fn test_validate_settings_update_ranges() {
+        let valid = validate_settings_update(UpdateStoreSettings {
+            max_photos_per_ticket: Some(0),
+            min_pin_length: Some(4),
+            auto_archive_after_days: Some(Some(90)),
+            ..Default::default()
+        });
+        assert!(valid.is_ok());
+
+        for input in [
+            UpdateStoreSettings {
+                max_photos_per_ticket: Some(-1),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                max_photos_per_ticket: Some(MAX_PHOTOS_PER_TICKET_LIMIT + 1),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                min_pin_length: Some(3),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                custody_value_threshold: Some(Some(Decimal::NEGATIVE_ONE)),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                quote_approval_threshold: Some(Some(Decimal::NEGATIVE_ONE)),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                auto_archive_after_days: Some(Some(0)),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                overdue_digest_hour: Some(24),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                overdue_digest_email: Some(Some("not-an-email".to_string())),
+                ..Default::default()
+            },
+        ] {
+            assert!(validate_settings_update(input).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_settings_update_print_template() {
+        let valid = validate_settings_update(UpdateStoreSettings {
+            receipt_logo_key: Some(Some("branding/receipt-logo-1.png".to_string())),
+            receipt_footer_text: Some(Some("  All sales final.  ".to_string())),
+            label_width_mm: Some(Decimal::new(762, 1)),
+            label_height_mm: Some(Decimal::from(12)),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(
+            valid.receipt_footer_text,
+            Some(Some("All sales final.".to_string()))
+        );
+
+        let cleared = validate_settings_update(UpdateStoreSettings {
+            receipt_logo_key: Some(None),
+            receipt_footer_text: Some(Some(" ".to_string())),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(cleared.receipt_logo_key, Some(None));
+        assert_eq!(cleared.receipt_footer_text, Some(None));
+
+        for key in [
+            "tickets/abc/photo.png",
+            "branding/",
+            "branding/../tickets/x.png",
+            "branding/nested/logo.png",
+        ] {
+            let input = UpdateStoreSettings {
+                receipt_logo_key: Some(Some(key.to_string())),
+                ..Default::default()
+            };
+            assert!(validate_settings_update(input).is_err(), "{}", key);
+        }
+
+        for input in [
+            UpdateStoreSettings {
+                receipt_footer_text: Some(Some("x".repeat(MAX_RECEIPT_FOOTER_LENGTH + 1))),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                label_width_mm: Some(Decimal::from(24)),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                label_height_mm: Some(Decimal::from(101)),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                label_width_mm: Some(Decimal::new(5082, 2)),
+                ..Default::default()
+            },
+        ] {
+            assert!(validate_settings_update(input).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_settings_update_tax_rate() {
+        let valid = validate_settings_update(UpdateStoreSettings {
+            tax_rate: Some(Decimal::new(8875, 3)),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(valid.tax_rate, Some(Decimal::new(8875, 3)));
+
+        for rate in [
+            Decimal::new(-1, 0),
+            Decimal::from(101),
+            Decimal::new(82505, 4),
+        ] {
+            let input = UpdateStoreSettings {
+                tax_rate: Some(rate),
+                ..Default::default()
+            };
+            assert!(validate_settings_update(input).is_err(), "{}", rate);
+        }
+    }
+
+    #[test]
+    fn test_validate_settings_update_business_hours() {
+        let hours = |weekday, opens, closes| OpeningHours {
+            weekday,
+            opens_at: chrono::NaiveTime::from_hms_opt(opens, 0, 0).unwrap(),
+            closes_at: chrono::NaiveTime::from_hms_opt(closes, 0, 0).unwrap(),
+        };
+        let day = |d| NaiveDate::from_ymd_opt(2026, 12, d).unwrap();
+        let valid = validate_settings_update(UpdateStoreSettings {
+            business_hours: Some(vec![hours(6, 10, 14), hours(1, 9, 17)]),
+            holidays: Some(vec![day(26), day(25), day(25)]),
+            rush_turnaround_days: Some(Some(1)),
+            ..Default::default()
+        })
+        .unwrap();
+        let weekdays: Vec<u32> = valid
+            .business_hours
+            .unwrap()
+            .iter()
+            .map(|h| h.weekday)
+            .collect();
+        assert_eq!(weekdays, vec![1, 6]);
+        assert_eq!(valid.holidays, Some(vec![day(25), day(26)]));
+
+        for input in [
+            UpdateStoreSettings {
+                business_hours: Some(vec![hours(0, 9, 17)]),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                business_hours: Some(vec![hours(2, 17, 9)]),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                business_hours: Some(vec![hours(3, 9, 12), hours(3, 13, 17)]),
+                ..Default::default()
+            },
+            UpdateStoreSettings {
+                rush_turnaround_days: Some(Some(-1)),
+                ..Default::default()
+            },
+        ] {
+            assert!(validate_settings_update(input).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_settings_update_label_stock() {
+        let valid = validate_settings_update(UpdateStoreSettings {
+            label_stock: Some(LabelStock::Barbell),
+            ..Default::default()
+        })
+        .unwrap();
+        assert_eq!(valid.label_width_mm, Some(Decimal::new(559, 1)));
+        assert_eq!(valid.label_height_mm, Some(Decimal::new(127, 1)));
+        assert!(valid.label_stock.is_none());
+
+        let input: UpdateStoreSettings =
+            serde_json::from_value(serde_json::json!({ "label_stock": "dymo_30334" })).unwrap();
+        assert_eq!(input.label_stock, Some(LabelStock::Dymo30334));
+
+        let mixed = UpdateStoreSettings {
+            label_stock: Some(LabelStock::Tag2x1),
+            label_height_mm: Some(Decimal::from(20)),
+            ..Default::default()
+        };
+        assert!(validate_settings_update(mixed).is_err());
+    }
+
+    #[test]
+    fn test_rollback_values_parse_as_update() {
+        let old_values = serde_json::json!({
+            "ticket_prefix": "JR",
+            "custody_value_threshold": null,
+            "auto_archive_after_days": 30,
+        });
+        let input: UpdateStoreSettings = serde_json::from_value(old_values).unwrap();
+        assert_eq!(input.ticket_prefix.as_deref(), Some("JR"));
+        assert_eq!(input.custody_value_threshold, Some(None));
+        assert_eq!(input.auto_archive_after_days, Some(Some(30)));
+        assert!(input.store_name.is_none());
+        assert!(validate_settings_update(input).is_ok());
+    }
+
+    #[test]
+    fn test_preview_template_reports_unknown_variables() {
+        let context = TemplateContext::sample("Example Jewelers".to_string(), None);
+        let preview = preview_template(
+            &context,
+            Some("{friendly_code} from {shop}"),
+            "Hi {customer_name}, {shop} says {ticket_no} is ready.",
+        );
+        assert!(!preview.valid);
+        assert_eq!(preview.unknown_variables, vec!["shop", "ticket_no"]);
+        assert_eq!(
+            preview.rendered_subject.as_deref(),
+            Some("JR-0042 from {shop}")
+        );
+        assert!(preview.rendered_body.starts_with("Hi Jane Doe,"));
+
+        let preview = preview_template(&context, None, "Thanks from {store_name}");
+        assert!(preview.valid);
+        assert_eq!(preview.rendered_body, "Thanks from Example Jewelers");
+    }
+
+    #[test]
+    fn test_validate_notification_template_trims_fields() {
+        let input = validate_notification_template(UpdateNotificationTemplate {
+            subject: Some("  Ticket {friendly_code} ".to_string()),
+            body: None,
+            is_enabled: Some(true),
+        })
+        .unwrap();
+        assert_eq!(input.subject.as_deref(), Some("Ticket {friendly_code}"));
+        assert!(input.body.is_none());
+    }
+
+    #[test]
+    fn test_validate_notification_template_rejects_blank_body() {
+        let result = validate_notification_template(UpdateNotificationTemplate {
+            subject: None,
+            body: Some("   ".to_string()),
+            is_enabled: None,
+        });
+        assert!(result.is_err());
+    }
+
+    fn location_rule(item_type: Option<&str>, min: Option<i64>) -> CreateStorageLocationRule {
+        CreateStorageLocationRule {
+            item_type: item_type.map(String::from),
+            min_quote_amount: min.map(|m| Decimal::new(m, 0)),
+            location_id: uuid::Uuid::nil(),
+            priority: 0,
+        }
+    }
+
+    #[test]
+    fn test_validate_location_rules_trims_item_type() {
+        let rules = validate_location_rules(vec![location_rule(Some("  watch "), None)]).unwrap();
+        assert_eq!(rules[0].item_type.as_deref(), Some("watch"));
+    }
+
+    #[test]
+    fn test_validate_location_rules_requires_condition() {
+        assert!(validate_location_rules(vec![location_rule(None, None)]).is_err());
+        assert!(validate_location_rules(vec![location_rule(Some("  "), None)]).is_err());
+        assert!(validate_location_rules(vec![location_rule(None, Some(500))]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_location_rules_rejects_negative_amount() {
+        assert!(validate_location_rules(vec![location_rule(None, Some(-1))]).is_err());
+    }
+
+    #[test]
+    fn test_validate_location_rules_rejects_too_many() {
+        let rules = vec![location_rule(Some("ring"), None); MAX_LOCATION_RULES + 1];
+        assert!(validate_location_rules(rules).is_err());
+    }
+
+    #[test]
+    fn test_settings_handler_exists() {
+        // Basic sanity test
+        assert!(true);
+    }
+
+    #[test]
+    fn test_update_store_settings_partial_deserialize() {
+        let json = r#"{"store_name": "Updated Store"}"#;
+        let input: UpdateStoreSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(input.store_name, Some("Updated Store".to_string()));
+        assert!(input.store_phone.is_none());
+        assert!(input.ticket_prefix.is_none());
+    }
+
+    #[test]
+    fn test_update_store_settings_empty_deserialize() {
+        let json = r#"{}"#;
+        let input: UpdateStoreSettings = serde_json::from_str(json).unwrap();
+        assert!(input.store_name.is_none());
+        assert!(input.store_phone.is_none());
+        assert!(input.store_address.is_none());
+        assert!(input.ticket_prefix.is_none());
+        assert!(input.currency.is_none());
+        assert!(input.max_photos_per_ticket.is_none());
+    }
+
+    #[test]
+    fn test_update_store_settings_full_deserialize() {
+        let json = r#"{
+            "store_name": "My Jewelry Store",
+            "store_phone": "555-0100",
+            "store_address": "456 Oak Ave",
+            "ticket_prefix": "MJ",
+            "currency": "EUR",
+            "max_photos_per_ticket": 8
+        }"#;
+        let input: UpdateStoreSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(input.store_name, Some("My Jewelry Store".to_string()));
+        assert_eq!(input.store_phone, Some("555-0100".to_string()));
+        assert_eq!(input.store_address, Some("456 Oak Ave".to_string()));
+        assert_eq!(input.ticket_prefix, Some("MJ".to_string()));
         assert_eq!(input.currency, Some("EUR".to_string()));
         assert_eq!(input.max_photos_per_ticket, Some(8));
     }
+
+    #[test]
+    fn test_validate_qc_checklist_trims_items() {
+        let items = vec!["  Stones secure ".to_string(), "Polished".to_string()];
+        let result = validate_qc_checklist(&items).unwrap();
+        assert_eq!(result, vec!["Stones secure", "Polished"]);
+    }
+
+    #[test]
+    fn test_validate_qc_checklist_empty_disables() {
+        let result = validate_qc_checklist(&[]).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_validate_qc_checklist_rejects_blank_item() {
+        let items = vec!["Polished".to_string(), "   ".to_string()];
+        assert!(validate_qc_checklist(&items).is_err());
+    }
+
+    #[test]
+    fn test_validate_qc_checklist_rejects_duplicates() {
+        let items = vec!["Polished".to_string(), " Polished".to_string()];
+        assert!(validate_qc_checklist(&items).is_err());
+    }
+
+    #[test]
+    fn test_validate_qc_checklist_rejects_too_many() {
+        let items: Vec<String> = (0..=MAX_QC_ITEMS).map(|i| format!("Item {}", i)).collect();
+        assert!(validate_qc_checklist(&items).is_err());
+    }
 }
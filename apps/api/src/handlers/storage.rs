@@ -0,0 +1,128 @@
+//! Storage request handlers.
+//!
+//! Serves objects from backends whose signed URLs point back at the API
+//! (local disk; object stores such as S3 serve their own signed URLs), and
+//! lets admins clean up orphaned photo objects.
+
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Deserialize;
+
+use crate::error::AppError;
+use crate::handlers::verify_admin_auth;
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::services::storage_reconcile::reconcile_orphans;
+use crate::storage::StorageError;
+
+/// Query parameters on a signed storage URL.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SignedUrlQuery {
+    /// Expiry as Unix seconds
+    pub expires: i64,
+    /// Hex signature over the key and expiry
+    pub signature: String,
+}
+
+/// Map a storage failure to an API error.
+///
+/// An open circuit breaker becomes SERVICE_UNAVAILABLE with a Retry-After, so
+/// clients can back off instead of treating the outage as a server fault.
+pub(crate) fn storage_error(context: &str, err: StorageError) -> AppError {
+    match err {
+        StorageError::Unavailable { retry_after } => AppError::service_unavailable(
+            "Photo storage is temporarily unavailable; try again shortly",
+            retry_after,
+        ),
+        err => AppError::server_error(format!("{}: {}", context, err)),
+    }
+}
+
+// =============================================================================
+// GET /storage/*key - Signed Object Download
+// =============================================================================
+
+/// GET /api/v1/storage/*key - Download an object through a signed URL.
+///
+/// The URL comes from the storage backend (e.g., the `url` of an uploaded
+/// photo). No session is needed; the signature grants access until it
+/// expires.
+pub async fn get_stored_object(
+    State(state): State<AppState>,
+    Path(key): Path<String>,
+    Query(query): Query<SignedUrlQuery>,
+) -> Result<Response, AppError> {
+    if !state
+        .storage
+        .verify_signed_url(&key, query.expires, &query.signature)
+    {
+        return Err(AppError::forbidden(
+            "Storage link is invalid or has expired",
+        ));
+    }
+
+    let data = state.storage.download(&key).await.map_err(|e| match e {
+        StorageError::NotFound(_) | StorageError::InvalidKey(_) => {
+            state.probe_policy.missing("file")
+        }
+        e => storage_error("Failed to read file from storage", e),
+    })?;
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type_for(&key))
+        .header(header::CACHE_CONTROL, "private, max-age=3600")
+        .body(Body::from(data))
+        .map_err(|e| AppError::server_error(format!("Failed to build response: {}", e)))
+}
+
+// =============================================================================
+// POST /admin/storage/reconcile - Delete Orphaned Photo Objects
+// =============================================================================
+
+/// POST /api/v1/admin/storage/reconcile - Delete orphaned photo objects now.
+///
+/// Runs the same reconciliation as the daily background task: objects under
+/// `tickets/` with no photo record, last modified over 24 hours ago, are
+/// deleted.
+pub async fn reconcile_storage(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let report = reconcile_orphans(&state.db, state.storage.as_ref()).await?;
+
+    Ok(Json(ApiResponse::success(report)))
+}
+
+/// Content type for a stored object, from its extension.
+fn content_type_for(key: &str) -> &'static str {
+    match key
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+    {
+        Some(ext) if ext == "jpg" || ext == "jpeg" => "image/jpeg",
+        Some(ext) if ext == "png" => "image/png",
+        Some(ext) if ext == "webp" => "image/webp",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_type_for() {
+        assert_eq!(content_type_for("tickets/a/b.jpg"), "image/jpeg");
+        assert_eq!(content_type_for("tickets/a/b.PNG"), "image/png");
+        assert_eq!(content_type_for("tickets/a/b.webp"), "image/webp");
+        assert_eq!(content_type_for("tickets/a/b"), "application/octet-stream");
+    }
+}
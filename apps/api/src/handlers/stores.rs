@@ -0,0 +1,141 @@
+//! Store request handlers.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::settings::ensure_ticket_prefix_free;
+use crate::handlers::verify_admin_auth;
+use crate::models::store::{CreateStore, Store};
+use crate::repositories::StoreRepository;
+use crate::response::{created, ApiResponse};
+use crate::routes::AppState;
+use crate::validation::{
+    validate_optional, validate_phone, validate_required, MAX_ADDRESS_LENGTH, MAX_NAME_LENGTH,
+    MAX_PHONE_LENGTH, MAX_TICKET_PREFIX_LENGTH,
+};
+
+// =============================================================================
+// GET /stores - List Stores
+// =============================================================================
+
+/// Query parameters for listing stores.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListStoresQuery {
+    /// Include closed stores (default: false)
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+/// Response for listing stores.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListStoresResponse {
+    pub stores: Vec<Store>,
+}
+
+/// GET /api/v1/stores - List the chain's stores, primary first.
+///
+/// This endpoint is public so a terminal can offer the stores to pick from
+/// before anyone signs in. Only open stores are returned unless
+/// `?include_inactive=true`.
+pub async fn list_stores(
+    State(state): State<AppState>,
+    Query(query): Query<ListStoresQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let stores = StoreRepository::list(&state.db, query.include_inactive).await?;
+
+    Ok(Json(ApiResponse::success(ListStoresResponse { stores })))
+}
+
+// =============================================================================
+// POST /stores - Open Store
+// =============================================================================
+
+/// POST /api/v1/stores - Open a store (admin only).
+///
+/// The store's settings start as a copy of the primary store's, with the
+/// given name, contact details, and ticket prefix; its ticket numbers start
+/// at 1. Employees and storage locations are added to it afterwards.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - VALIDATION_ERROR: If the name or prefix is blank, or the prefix is
+///   already used by another store
+pub async fn create_store(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<CreateStore>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication
+    verify_admin_auth(&state, &headers).await?;
+
+    // 2. Validate the store
+    let name = validate_required(&body.name, "name", MAX_NAME_LENGTH)?;
+    let ticket_prefix = validate_required(
+        &body.ticket_prefix,
+        "ticket_prefix",
+        MAX_TICKET_PREFIX_LENGTH,
+    )?;
+    let store_phone = validate_phone(body.store_phone.as_deref(), MAX_PHONE_LENGTH)?;
+    let store_address = validate_optional(
+        body.store_address.as_deref(),
+        "store_address",
+        MAX_ADDRESS_LENGTH,
+    )?;
+    ensure_ticket_prefix_free(&state, &ticket_prefix, None).await?;
+
+    // 3. Open it
+    let store = StoreRepository::create(
+        &state.db,
+        &CreateStore {
+            name,
+            ticket_prefix,
+            store_phone,
+            store_address,
+        },
+    )
+    .await?;
+
+    Ok(created(store))
+}
+
+// =============================================================================
+// DELETE /stores/:store_id - Close Store
+// =============================================================================
+
+/// DELETE /api/v1/stores/:store_id - Close a store (admin only).
+///
+/// The store is kept for its tickets' history but can no longer be picked
+/// with `X-Store-ID`, and sessions started there fall back to the employee's
+/// home store. Closing a closed store is a no-op. Returns the closed store.
+///
+/// # Errors
+/// - UNAUTHORIZED: If not authenticated
+/// - NOT_FOUND: If the store doesn't exist
+/// - VALIDATION_ERROR: If it's the primary store
+pub async fn delete_store(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(store_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let store = StoreRepository::find_by_id(&state.db, store_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("store"))?;
+    if store.is_primary {
+        return Err(AppError::validation("The primary store can't be closed"));
+    }
+
+    let store = StoreRepository::set_active(&state.db, store_id, false)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("store"))?;
+
+    Ok(Json(ApiResponse::success(store)))
+}
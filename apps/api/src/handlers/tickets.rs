@@ -4,34 +4,84 @@ use axum::{
     body::Body,
     extract::{Multipart, Path, Query, State},
     http::{header, HeaderMap, StatusCode},
-    response::{IntoResponse, Response},
+    response::{IntoResponse, Redirect, Response},
     Json,
 };
+use base64::{engine::general_purpose::STANDARD, Engine};
 use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use sqlx::{PgConnection, PgExecutor};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
-use crate::error::AppError;
-use crate::middleware::{can_close_ticket, require_ticket_access};
+use crate::error::{AppError, ErrorDetail};
+use crate::handlers::audit_log::{record_audit, AuditEvent};
+use crate::handlers::storage::storage_error;
+use crate::middleware::{
+    can_close_ticket, can_delete_photo, require_permission, require_ticket_access, ClientIp,
+    CurrentStore, TrainingMode,
+};
+use crate::models::audit_log::AuditAction;
+use crate::models::business_hours::BusinessCalendar;
+use crate::models::item_type::{is_other_item_type, OTHER_ITEM_TYPE};
+use crate::models::notification::NotificationChannel;
+use crate::models::pickup::{
+    id_last_four, CreateAuthorizedPickup, CreateTicketPickup, TicketAuthorizedPickup,
+    TicketPickupEntry,
+};
+use crate::models::promise_date_reason::slipped_promise_date;
+use crate::models::service::{Service, ServiceDefaults, TicketService, MAX_TICKET_SERVICES};
+use crate::models::store_settings::ReceiptFormat;
+use crate::models::{
+    balance_due, ContactChannel, ContactDirection, ContactOutcome, CreateTicketContact,
+    CreateTicketInvoice, CreateTicketPayment, PaymentKind, PaymentMethod, TaxBreakdown,
+    TicketContact, TicketInvoice, TicketPayment, TicketPaymentEntry,
+};
 use crate::models::{
-    CreateCustomer, CreateFieldHistory, CreateStatusHistory, CreateTicket, CreateTicketNote,
-    CreateTicketPhoto, Customer, Employee, EmployeeRole, Permission, QueueTicket, Ticket,
-    TicketFilters, TicketNote as TicketNoteModel, TicketPhoto as TicketPhotoModel,
-    TicketSearchParams, TicketStatus, UpdateTicket,
+    custody_required, qc_gate_satisfied, quote_approval_required, quote_breakdown,
+    total_bench_seconds, CreateCustodyEvent, CreateCustomer, CreateFieldHistory, CreateQuoteEvent,
+    CreateStatusHistory, CreateTicket, CreateTicketDefect, CreateTicketItem, CreateTicketNote,
+    CreateTicketPhoto, CreateTicketQcCheck, CurrentLocation, CustodyEvent, CustodyEventEntry,
+    CustodyEventType, CustodyWitness, Customer, DefectReason, DefectSource, Employee, ItemType,
+    NotificationEvent, NotificationLog, Permission, PickupInput, QueueTicket, QuoteBreakdown,
+    QuoteChannel, QuoteEvent, QuoteStatus, Ticket, TicketCursor, TicketDefect, TicketDetail,
+    TicketFilters, TicketHistoryEvent, TicketItem, TicketNote as TicketNoteModel, TicketOrder,
+    TicketPhoto as TicketPhotoModel, TicketQcCheck, TicketSearchParams, TicketSort,
+    TicketSortField, TicketStatus, TicketTransferEntry, TimeEntryEntry, UpdateTicket,
+    MAX_TICKET_ITEMS, PHOTO_FIELD,
 };
 use crate::repositories::{
-    CustomerRepository, EmployeeRepository, EmployeeSessionRepository, FieldHistoryRepository,
-    StatusHistoryRepository, TicketNoteRepository, TicketPhotoRepository, TicketRepository,
+    ContactRepository, CustodyRepository, CustomerRepository, DefectRepository, EmployeeRepository,
+    EmployeeSessionRepository, FieldHistoryRepository, InvoiceRepository, ItemTypeRepository,
+    MetalPriceRepository, MovementRepository, NotificationRepository,
+    NotificationTemplateRepository, PaymentRepository, PickupRepository,
+    PromiseDateReasonRepository, QcCheckRepository, QuoteRepository, RushPricingRepository,
+    ServiceRepository, StatusHistoryRepository, StorageLocationRepository, StoreSettingsRepository,
+    TicketItemRepository, TicketNoteRepository, TicketPhotoRepository, TicketRepository,
+    TimeEntryRepository, TransferRepository,
 };
-use crate::response::ApiResponse;
+use crate::response::{ApiResponse, ApiWarning};
 use crate::routes::AppState;
-use crate::services::pdf::{generate_label_pdf, generate_receipt_pdf, LabelData, ReceiptData};
-use crate::utils::file_validation::validate_image_content_type;
+use crate::services::notifications::DeliveryOutcome;
+use crate::services::pdf::{
+    generate_custody_report_pdf, generate_invoice_pdf, generate_label_pdf, generate_receipt_pdf,
+    generate_work_order_pdf, CustodyReportData, InvoiceData, LabelData, PickupReportEntry,
+    ReceiptData, ReceiptTemplate, WorkOrderData, DEFAULT_LABEL_HEIGHT_MM, DEFAULT_LABEL_WIDTH_MM,
+};
+use crate::services::photos::process_photo;
+use crate::services::print::escpos::{receipt_escpos, receipt_text};
+use crate::services::webhooks;
+use crate::utils::file_validation::{
+    detect_image_format, validate_image_content_type, ImageFormat,
+};
+use crate::validation::warnings::{location_capacity_warnings, ticket_warnings};
 use crate::validation::{
-    validate_email, validate_employee, validate_optional, validate_phone, validate_required,
-    validate_storage_location, MAX_DESCRIPTION_LENGTH, MAX_EMAIL_LENGTH, MAX_ITEM_TYPE_LENGTH,
-    MAX_NAME_LENGTH, MAX_NOTE_LENGTH, MAX_PHONE_LENGTH,
+    validate_email, validate_employee, validate_metal_type, validate_optional, validate_phone,
+    validate_required, validate_storage_location, MAX_DESCRIPTION_LENGTH, MAX_EMAIL_LENGTH,
+    MAX_ID_TYPE_LENGTH, MAX_ITEM_TYPE_LENGTH, MAX_METAL_TYPE_LENGTH, MAX_NAME_LENGTH,
+    MAX_NOTE_LENGTH, MAX_PHONE_LENGTH, MAX_RELATIONSHIP_LENGTH,
 };
 
 /// Query parameters for listing tickets.
@@ -45,6 +95,10 @@ pub struct ListTicketsQuery {
     pub search: Option<String>,
     /// Filter by customer ID
     pub customer_id: Option<Uuid>,
+    /// Only tickets assigned to this employee
+    pub worked_by: Option<Uuid>,
+    /// Only tickets this employee took in
+    pub taken_in_by: Option<Uuid>,
     /// Filter by created date range (start)
     pub from_date: Option<DateTime<Utc>>,
     /// Filter by created date range (end)
@@ -52,10 +106,33 @@ pub struct ListTicketsQuery {
     /// Include archived tickets (default: false)
     #[serde(default)]
     pub include_archived: bool,
+    /// Include tickets held at every store (default: the current store only)
+    #[serde(default)]
+    pub all_stores: bool,
+    /// Include soft-deleted tickets (admin only, default: false)
+    #[serde(default)]
+    pub include_deleted: bool,
     /// Limit results (default: 100)
     pub limit: Option<i64>,
     /// Offset for pagination (default: 0)
     pub offset: Option<i64>,
+    /// Sort field, `-` prefixed for descending (e.g. "-promise_date")
+    pub sort: Option<String>,
+    /// Pagination mode (default: offset)
+    pub pagination: Option<PaginationMode>,
+    /// `next_cursor` from the previous page; implies cursor pagination
+    pub cursor: Option<String>,
+}
+
+/// How a ticket listing is paged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaginationMode {
+    /// Pages by `offset` in priority order
+    Offset,
+    /// Pages by `cursor`, oldest first, so tickets changing between requests
+    /// aren't skipped or repeated
+    Cursor,
 }
 
 impl ListTicketsQuery {
@@ -97,6 +174,9 @@ pub struct PaginationInfo {
     pub offset: i64,
     /// Whether there may be more results
     pub has_more: bool,
+    /// Cursor for the next page, with cursor pagination and more results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 // =============================================================================
@@ -139,7 +219,6 @@ pub struct TicketStorageLocation {
 
 /// Photo record from the database.
 #[derive(Debug, Clone, sqlx::FromRow)]
-#[allow(dead_code)] // storage_key reserved for future signed URL generation
 struct PhotoRecord {
     photo_id: Uuid,
     storage_key: String,
@@ -152,7 +231,7 @@ struct PhotoRecord {
 #[derive(Debug, Clone, Serialize)]
 pub struct TicketPhoto {
     pub photo_id: Uuid,
-    /// Signed URL for accessing the photo (placeholder until storage is integrated in AppState)
+    /// Signed URL for the photo, valid for `SIGNED_URL_TTL_SECONDS`
     pub url: String,
     pub uploaded_at: DateTime<Utc>,
     pub uploaded_by: EmployeeAttribution,
@@ -196,6 +275,31 @@ pub struct TicketStatusHistoryEntry {
     pub changed_by: EmployeeAttribution,
 }
 
+/// QC check record from the database.
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct QcCheckRecord {
+    check_id: Uuid,
+    passed: bool,
+    passed_items: Vec<String>,
+    failed_items: Vec<String>,
+    notes: Option<String>,
+    checked_at: DateTime<Utc>,
+    checked_by: Uuid,
+    employee_name: String,
+}
+
+/// QC check entry in ticket detail response.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketQcCheckEntry {
+    pub check_id: Uuid,
+    pub passed: bool,
+    pub passed_items: Vec<String>,
+    pub failed_items: Vec<String>,
+    pub notes: Option<String>,
+    pub checked_at: DateTime<Utc>,
+    pub checked_by: EmployeeAttribution,
+}
+
 /// Full ticket detail response.
 #[derive(Debug, Clone, Serialize)]
 pub struct TicketDetailResponse {
@@ -207,19 +311,54 @@ pub struct TicketDetailResponse {
     pub customer: TicketCustomer,
 
     pub item_type: Option<String>,
+    /// Configured defaults for the item type, when it matches one
+    pub item_type_config: Option<ItemType>,
     pub item_description: String,
     pub condition_notes: String,
     pub requested_work: String,
+    /// Items on the ticket, in order; item 1 matches the item fields above
+    pub items: Vec<TicketItem>,
+    /// Catalog services picked at intake, with their prices then
+    pub services: Vec<TicketService>,
 
     pub promise_date: Option<NaiveDate>,
     pub storage_location: TicketStorageLocation,
+    /// Where the item is now: its storage location or an employee's bench
+    pub current_location: CurrentLocation,
+    /// Transfer the item is in transit on, if any
+    pub pending_transfer: Option<TicketTransferEntry>,
 
     pub quote_amount: Option<Decimal>,
     pub actual_amount: Option<Decimal>,
+    /// Part of quote_amount charged for a rush job
+    pub rush_surcharge: Option<Decimal>,
+    /// Where the quote stands with the customer
+    pub quote_status: QuoteStatus,
+    /// Quote sends and customer decisions, oldest first
+    pub quote_events: Vec<QuoteEvent>,
+
+    pub weight_grams: Option<Decimal>,
+    pub metal_type: Option<String>,
+    /// Estimated melt value from the metal price table (internal only, never
+    /// printed on customer receipts)
+    pub melt_value_estimate: Option<Decimal>,
 
     pub photos: Vec<TicketPhoto>,
     pub notes: Vec<TicketNote>,
+    /// Calls and messages with the customer, oldest first
+    pub contacts: Vec<TicketContact>,
     pub status_history: Vec<TicketStatusHistoryEntry>,
+    pub qc_checks: Vec<TicketQcCheckEntry>,
+    /// Customer notifications sent (or attempted) for this ticket
+    pub notifications: Vec<NotificationLog>,
+    /// People the customer has authorized to collect the item
+    pub authorized_pickups: Vec<TicketAuthorizedPickup>,
+    /// Releases of the item, oldest first
+    pub pickups: Vec<TicketPickupEntry>,
+    /// Bench time across all entries, counting running ones up to now
+    pub bench_seconds: i64,
+    /// Employees clocking in and out on the ticket, oldest first
+    pub time_entries: Vec<TimeEntryEntry>,
 
     pub taken_in_by: EmployeeAttribution,
     pub worked_by: Option<EmployeeAttribution>,
@@ -228,69 +367,163 @@ pub struct TicketDetailResponse {
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub closed_at: Option<DateTime<Utc>>,
+    /// Set only when a soft-deleted ticket is viewed with `include_deleted`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Created in training mode
+    pub is_training: bool,
 }
 
-/// Storage location record from the database.
-#[derive(Debug, Clone, sqlx::FromRow)]
-struct StorageLocationRecord {
-    location_id: Uuid,
-    name: String,
+/// Query parameters for the ticket detail and queue views.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct IncludeDeletedQuery {
+    /// Include soft-deleted tickets (admin only, default: false)
+    #[serde(default)]
+    pub include_deleted: bool,
 }
 
 /// GET /api/v1/tickets/:ticket_id - Get full ticket details.
+///
+/// Soft-deleted tickets are not found unless `include_deleted=true` is sent
+/// with admin authentication.
 pub async fn get_ticket(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Path(ticket_id): Path<Uuid>,
+    Query(query): Query<IncludeDeletedQuery>,
 ) -> Result<impl IntoResponse, AppError> {
-    // 1. Find the ticket
-    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+    // 1. Find the ticket with its customer, location, and employees
+    require_admin_for_deleted(&state, &headers, query.include_deleted).await?;
+    let detail = TicketRepository::find_detail(&state.db, ticket_id, query.include_deleted)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
-
-    // 2. Find the customer
-    let customer = CustomerRepository::find_by_id(&state.db, ticket.customer_id)
-        .await?
-        .ok_or_else(|| AppError::not_found("Customer not found"))?;
-
-    // 3. Get storage location
-    let storage_location = sqlx::query_as::<_, StorageLocationRecord>(
-        "SELECT location_id, name FROM storage_locations WHERE location_id = $1",
-    )
-    .bind(ticket.storage_location_id)
-    .fetch_optional(&state.db)
-    .await?
-    .ok_or_else(|| AppError::not_found("Storage location not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    let ticket = &detail.ticket;
 
-    // 4. Get employee who took in the ticket
-    let taken_in_by = EmployeeRepository::find_by_id(&state.db, ticket.taken_in_by)
-        .await?
-        .ok_or_else(|| AppError::server_error("Taken in by employee not found"))?;
+    // 2. Load everything else about the ticket; the lookups are independent,
+    // so they run concurrently
+    let db = &state.db;
+    let (
+        photos,
+        notes,
+        status_history,
+        qc_checks,
+        notifications,
+        melt_value_estimate,
+        pending_transfer,
+        latest_movement,
+        item_type_config,
+        authorized_pickups,
+        pickups,
+        items,
+        services,
+        quote_events,
+        contacts,
+        time_entries,
+    ) = tokio::try_join!(
+        ticket_photos(&state, ticket_id),
+        ticket_notes(db, ticket_id),
+        ticket_status_history(db, ticket_id),
+        ticket_qc_checks(db, ticket_id),
+        NotificationRepository::find_by_ticket_id(db, ticket_id),
+        melt_value_estimate(db, ticket),
+        TransferRepository::find_pending_entry_by_ticket_id(db, ticket_id),
+        MovementRepository::find_latest_entry_by_ticket_id(db, ticket_id),
+        async {
+            match ticket.item_type.as_deref() {
+                Some(item_type) => ItemTypeRepository::find_by_name(db, item_type).await,
+                None => Ok(None),
+            }
+        },
+        PickupRepository::list_authorized(db, ticket_id),
+        PickupRepository::find_entries_by_ticket_id(db, ticket_id),
+        TicketItemRepository::find_by_ticket_id(db, ticket_id),
+        ServiceRepository::find_by_ticket_id(db, ticket_id),
+        QuoteRepository::find_by_ticket_id(db, ticket_id),
+        ContactRepository::find_by_ticket_id(db, ticket_id),
+        TimeEntryRepository::find_entries_by_ticket_id(db, ticket_id),
+    )?;
 
-    // 5. Get worked_by employee if set
-    let worked_by = if let Some(worked_by_id) = ticket.worked_by {
-        EmployeeRepository::find_by_id(&state.db, worked_by_id)
-            .await?
-            .map(|e| EmployeeAttribution {
-                employee_id: e.employee_id,
-                name: e.name,
-            })
-    } else {
-        None
+    // 3. Build the response
+    let TicketDetail {
+        ticket,
+        customer_name,
+        customer_phone,
+        customer_email,
+        storage_location_name,
+        taken_in_by_name,
+        worked_by_name,
+        closed_by_name,
+    } = detail;
+    let attribution = |employee_id: Option<Uuid>, name: Option<String>| {
+        employee_id
+            .zip(name)
+            .map(|(employee_id, name)| EmployeeAttribution { employee_id, name })
     };
-
-    // 6. Get closed_by employee if set
-    let closed_by = if let Some(closed_by_id) = ticket.closed_by {
-        EmployeeRepository::find_by_id(&state.db, closed_by_id)
-            .await?
-            .map(|e| EmployeeAttribution {
-                employee_id: e.employee_id,
-                name: e.name,
-            })
-    } else {
-        None
+    let response = TicketDetailResponse {
+        ticket_id: ticket.ticket_id,
+        friendly_code: ticket.friendly_code,
+        status: ticket.status,
+        is_rush: ticket.is_rush,
+        customer: TicketCustomer {
+            customer_id: ticket.customer_id,
+            name: customer_name,
+            phone: customer_phone,
+            email: customer_email,
+        },
+        item_type: ticket.item_type,
+        item_type_config,
+        item_description: ticket.item_description,
+        condition_notes: ticket.condition_notes,
+        requested_work: ticket.requested_work,
+        items,
+        services,
+        promise_date: ticket.promise_date,
+        current_location: CurrentLocation::derive(
+            ticket.storage_location_id,
+            storage_location_name.clone(),
+            latest_movement.as_ref(),
+        ),
+        storage_location: TicketStorageLocation {
+            location_id: ticket.storage_location_id,
+            name: storage_location_name,
+        },
+        pending_transfer,
+        quote_amount: ticket.quote_amount,
+        actual_amount: ticket.actual_amount,
+        rush_surcharge: ticket.rush_surcharge,
+        quote_status: ticket.quote_status,
+        quote_events,
+        weight_grams: ticket.weight_grams,
+        metal_type: ticket.metal_type,
+        melt_value_estimate,
+        photos,
+        notes,
+        contacts,
+        status_history,
+        qc_checks,
+        notifications,
+        authorized_pickups,
+        pickups,
+        bench_seconds: total_bench_seconds(&time_entries, Utc::now()),
+        time_entries,
+        taken_in_by: EmployeeAttribution {
+            employee_id: ticket.taken_in_by,
+            name: taken_in_by_name,
+        },
+        worked_by: attribution(ticket.worked_by, worked_by_name),
+        closed_by: attribution(ticket.closed_by, closed_by_name),
+        created_at: ticket.created_at,
+        updated_at: ticket.updated_at,
+        closed_at: ticket.closed_at,
+        deleted_at: ticket.deleted_at,
+        is_training: ticket.is_training,
     };
 
-    // 7. Get photos with employee names
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// A ticket's photos with signed URLs and the uploading employee, oldest first.
+async fn ticket_photos(state: &AppState, ticket_id: Uuid) -> Result<Vec<TicketPhoto>, AppError> {
     let photo_records = sqlx::query_as::<_, PhotoRecord>(
         r#"
         SELECT
@@ -309,25 +542,46 @@ pub async fn get_ticket(
     .fetch_all(&state.db)
     .await?;
 
-    // Convert to response format
-    // Note: For now, we use the storage_key as a placeholder URL.
-    // When StorageClient is integrated into AppState, this should generate signed URLs.
-    let photos: Vec<TicketPhoto> = photo_records
-        .into_iter()
-        .map(|p| TicketPhoto {
+    let mut photos = Vec::with_capacity(photo_records.len());
+    for p in photo_records {
+        photos.push(TicketPhoto {
             photo_id: p.photo_id,
-            // TODO: Generate signed URL when storage client is in AppState
-            // For now, return a placeholder API path
-            url: format!("/api/v1/tickets/{}/photos/{}", ticket_id, p.photo_id),
+            url: signed_photo_url(state, ticket_id, p.photo_id, &p.storage_key).await,
             uploaded_at: p.uploaded_at,
             uploaded_by: EmployeeAttribution {
                 employee_id: p.uploaded_by,
                 name: p.employee_name,
             },
-        })
-        .collect();
+        });
+    }
+    Ok(photos)
+}
 
-    // 8. Get notes with employee names
+/// A signed URL for a photo.
+///
+/// Falls back to the photo's API path, which redirects to a signed URL,
+/// when storage can't sign one right now.
+async fn signed_photo_url(
+    state: &AppState,
+    ticket_id: Uuid,
+    photo_id: Uuid,
+    storage_key: &str,
+) -> String {
+    match state
+        .storage
+        .get_signed_url(storage_key, Some(state.photo_url_ttl))
+        .await
+    {
+        Ok(url) => url,
+        Err(err) => {
+            tracing::warn!("Failed to sign URL for photo {}: {}", photo_id, err);
+            format!("/api/v1/tickets/{}/photos/{}", ticket_id, photo_id)
+        }
+    }
+}
+
+/// A ticket's notes with the author, oldest first.
+async fn ticket_notes(pool: &sqlx::PgPool, ticket_id: Uuid) -> Result<Vec<TicketNote>, AppError> {
     let note_records = sqlx::query_as::<_, NoteRecord>(
         r#"
         SELECT
@@ -343,10 +597,10 @@ pub async fn get_ticket(
         "#,
     )
     .bind(ticket_id)
-    .fetch_all(&state.db)
+    .fetch_all(pool)
     .await?;
 
-    let notes: Vec<TicketNote> = note_records
+    Ok(note_records
         .into_iter()
         .map(|n| TicketNote {
             note_id: n.note_id,
@@ -357,9 +611,14 @@ pub async fn get_ticket(
                 name: n.employee_name,
             },
         })
-        .collect();
+        .collect())
+}
 
-    // 9. Get status history with employee names
+/// A ticket's status changes with the employee who made them, oldest first.
+async fn ticket_status_history(
+    pool: &sqlx::PgPool,
+    ticket_id: Uuid,
+) -> Result<Vec<TicketStatusHistoryEntry>, AppError> {
     let status_history_records = sqlx::query_as::<_, StatusHistoryRecord>(
         r#"
         SELECT
@@ -375,10 +634,10 @@ pub async fn get_ticket(
         "#,
     )
     .bind(ticket_id)
-    .fetch_all(&state.db)
+    .fetch_all(pool)
     .await?;
 
-    let status_history: Vec<TicketStatusHistoryEntry> = status_history_records
+    Ok(status_history_records
         .into_iter()
         .map(|h| TicketStatusHistoryEntry {
             from_status: h.from_status,
@@ -389,51 +648,360 @@ pub async fn get_ticket(
                 name: h.employee_name,
             },
         })
-        .collect();
+        .collect())
+}
 
-    // 10. Build the response
-    let response = TicketDetailResponse {
-        ticket_id: ticket.ticket_id,
-        friendly_code: ticket.friendly_code,
-        status: ticket.status,
-        is_rush: ticket.is_rush,
-        customer: customer.into(),
-        item_type: ticket.item_type,
-        item_description: ticket.item_description,
-        condition_notes: ticket.condition_notes,
-        requested_work: ticket.requested_work,
-        promise_date: ticket.promise_date,
-        storage_location: TicketStorageLocation {
-            location_id: storage_location.location_id,
-            name: storage_location.name,
-        },
-        quote_amount: ticket.quote_amount,
-        actual_amount: ticket.actual_amount,
-        photos,
-        notes,
-        status_history,
-        taken_in_by: EmployeeAttribution {
-            employee_id: taken_in_by.employee_id,
-            name: taken_in_by.name,
-        },
-        worked_by,
-        closed_by,
-        created_at: ticket.created_at,
-        updated_at: ticket.updated_at,
-        closed_at: ticket.closed_at,
+/// A ticket's QC checks with the checking employee, oldest first.
+async fn ticket_qc_checks(
+    pool: &sqlx::PgPool,
+    ticket_id: Uuid,
+) -> Result<Vec<TicketQcCheckEntry>, AppError> {
+    let qc_check_records = sqlx::query_as::<_, QcCheckRecord>(
+        r#"
+        SELECT
+            q.check_id,
+            q.passed,
+            q.passed_items,
+            q.failed_items,
+            q.notes,
+            q.checked_at,
+            q.checked_by,
+            e.name as employee_name
+        FROM ticket_qc_checks q
+        JOIN employees e ON q.checked_by = e.employee_id
+        WHERE q.ticket_id = $1
+        ORDER BY q.checked_at ASC
+        "#,
+    )
+    .bind(ticket_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(qc_check_records
+        .into_iter()
+        .map(|q| TicketQcCheckEntry {
+            check_id: q.check_id,
+            passed: q.passed,
+            passed_items: q.passed_items,
+            failed_items: q.failed_items,
+            notes: q.notes,
+            checked_at: q.checked_at,
+            checked_by: EmployeeAttribution {
+                employee_id: q.checked_by,
+                name: q.employee_name,
+            },
+        })
+        .collect())
+}
+
+/// Estimate a ticket's melt value.
+///
+/// Returns None unless the ticket has a weight and a metal type with an entry
+/// in the metal price table.
+async fn melt_value_estimate(
+    pool: &sqlx::PgPool,
+    ticket: &Ticket,
+) -> Result<Option<Decimal>, AppError> {
+    let (Some(weight_grams), Some(metal_type)) = (ticket.weight_grams, &ticket.metal_type) else {
+        return Ok(None);
     };
 
-    Ok(Json(ApiResponse::success(response)))
+    let price = MetalPriceRepository::find_by_metal_type(pool, metal_type).await?;
+    Ok(price.map(|price| price.melt_value(weight_grams)))
+}
+
+/// Validate an item weight. Weights cannot be negative.
+fn validate_weight(weight_grams: Option<Decimal>) -> Result<(), AppError> {
+    if weight_grams.is_some_and(|weight| weight < Decimal::ZERO) {
+        return Err(AppError::validation("weight_grams cannot be negative"));
+    }
+    Ok(())
+}
+
+/// Validate a rush surcharge against the quote it is part of.
+fn validate_rush_surcharge(
+    rush_surcharge: Option<Decimal>,
+    quote_amount: Option<Decimal>,
+) -> Result<(), AppError> {
+    let Some(surcharge) = rush_surcharge else {
+        return Ok(());
+    };
+    if surcharge < Decimal::ZERO {
+        return Err(AppError::validation("rush_surcharge cannot be negative"));
+    }
+    match quote_amount {
+        None => Err(AppError::validation(
+            "rush_surcharge requires a quote_amount",
+        )),
+        Some(quote) if surcharge > quote => Err(AppError::validation(
+            "rush_surcharge cannot exceed quote_amount",
+        )),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Look up the catalog services picked for a new ticket and fill in what
+/// they imply: item 1's requested work and quote, and the promise date.
+/// Values sent in the request win.
+async fn intake_services(
+    state: &AppState,
+    calendar: &BusinessCalendar,
+    body: &mut CreateTicketRequest,
+) -> Result<Vec<Service>, AppError> {
+    if body.service_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    if body.service_ids.len() > MAX_TICKET_SERVICES {
+        return Err(AppError::validation(format!(
+            "service_ids must list at most {} services",
+            MAX_TICKET_SERVICES
+        )));
+    }
+    let mut unique = HashSet::new();
+    if !body.service_ids.iter().all(|id| unique.insert(*id)) {
+        return Err(AppError::validation(
+            "service_ids must not repeat a service",
+        ));
+    }
+
+    let services = ServiceRepository::find_active_by_ids(&state.db, &body.service_ids).await?;
+    if services.len() != body.service_ids.len() {
+        return Err(AppError::validation(
+            "service_ids must name active services",
+        ));
+    }
+
+    let defaults = ServiceDefaults::from_services(&services);
+    let (requested_work, quote_amount) = match body.items.first_mut() {
+        Some(item) => (&mut item.requested_work, &mut item.quote_amount),
+        None => (&mut body.requested_work, &mut body.quote_amount),
+    };
+    if requested_work.trim().is_empty() {
+        *requested_work = defaults.requested_work.clone();
+    }
+    if quote_amount.is_none() {
+        *quote_amount = defaults.quote_amount;
+    }
+    if body.promise_date.is_none() {
+        body.promise_date = defaults.promise_date(calendar, Utc::now().date_naive(), body.is_rush);
+    }
+
+    Ok(services)
+}
+
+/// Check an item type that matches no configured type, for stores that
+/// restrict tickets to the catalog. Only "Other" is accepted, returned with
+/// its standard spelling.
+fn unlisted_item_type(item_type: &str, field: &str) -> Result<String, AppError> {
+    if is_other_item_type(item_type) {
+        Ok(OTHER_ITEM_TYPE.to_string())
+    } else {
+        Err(AppError::validation(format!(
+            "{} must be a configured item type or \"{}\"",
+            field, OTHER_ITEM_TYPE
+        )))
+    }
+}
+
+/// Validate and sanitize a new ticket's items.
+///
+/// A single-item ticket sends its item's fields at the top level; a
+/// multi-item ticket sends `items` instead. Either way at least one item is
+/// returned, item 1 first.
+fn validate_ticket_items(body: &CreateTicketRequest) -> Result<Vec<CreateTicketItem>, AppError> {
+    if body.items.is_empty() {
+        let item = CreateTicketItem {
+            item_type: body.item_type.clone(),
+            item_description: body.item_description.clone(),
+            condition_notes: body.condition_notes.clone(),
+            requested_work: body.requested_work.clone(),
+            quote_amount: body.quote_amount,
+        };
+        return Ok(vec![validate_ticket_item(&item, "")?]);
+    }
+
+    let has_item_fields = body.item_type.is_some()
+        || !body.item_description.is_empty()
+        || !body.condition_notes.is_empty()
+        || !body.requested_work.is_empty();
+    if has_item_fields {
+        return Err(AppError::validation(
+            "Provide either item fields or items, not both",
+        ));
+    }
+    if body.items.len() > MAX_TICKET_ITEMS {
+        return Err(AppError::validation(format!(
+            "items must list at most {} items",
+            MAX_TICKET_ITEMS
+        )));
+    }
+
+    body.items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| validate_ticket_item(item, &format!("items[{}].", i)))
+        .collect()
+}
+
+/// Validate one item; `prefix` locates it in the request for error messages.
+fn validate_ticket_item(
+    item: &CreateTicketItem,
+    prefix: &str,
+) -> Result<CreateTicketItem, AppError> {
+    let field = |name: &str| format!("{}{}", prefix, name);
+    if item.quote_amount.is_some_and(|quote| quote < Decimal::ZERO) {
+        return Err(AppError::validation(format!(
+            "{} cannot be negative",
+            field("quote_amount")
+        )));
+    }
+    Ok(CreateTicketItem {
+        item_type: validate_optional(
+            item.item_type.as_deref(),
+            &field("item_type"),
+            MAX_ITEM_TYPE_LENGTH,
+        )?,
+        item_description: validate_required(
+            &item.item_description,
+            &field("item_description"),
+            MAX_DESCRIPTION_LENGTH,
+        )?,
+        condition_notes: validate_required(
+            &item.condition_notes,
+            &field("condition_notes"),
+            MAX_DESCRIPTION_LENGTH,
+        )?,
+        requested_work: validate_required(
+            &item.requested_work,
+            &field("requested_work"),
+            MAX_DESCRIPTION_LENGTH,
+        )?,
+        quote_amount: item.quote_amount,
+    })
+}
+
+/// The quote for a multi-item ticket: the sum of its item quotes, if any
+/// item is quoted. A `quote_amount` sent alongside must match that sum.
+fn ticket_quote(
+    quote_amount: Option<Decimal>,
+    items: &[CreateTicketItem],
+) -> Result<Option<Decimal>, AppError> {
+    let quotes: Vec<Decimal> = items.iter().filter_map(|item| item.quote_amount).collect();
+    if quotes.is_empty() {
+        return Ok(quote_amount);
+    }
+    let total: Decimal = quotes.into_iter().sum();
+    match quote_amount {
+        Some(quote) if quote != total => Err(AppError::validation(format!(
+            "quote_amount must equal the sum of the item quotes ({})",
+            total
+        ))),
+        _ => Ok(Some(total)),
+    }
+}
+
+/// A payment taken along with another action: a deposit at intake or the
+/// final payment at close.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaymentInput {
+    /// Amount received (must be greater than 0)
+    pub amount: Decimal,
+    /// How the customer paid
+    pub method: PaymentMethod,
+    /// Optional notes (e.g., check number)
+    pub notes: Option<String>,
+}
+
+/// Validate a payment's amount and sanitize its notes.
+fn validate_payment(payment: &PaymentInput) -> Result<PaymentInput, AppError> {
+    if payment.amount <= Decimal::ZERO {
+        return Err(AppError::validation("amount must be greater than 0"));
+    }
+    Ok(PaymentInput {
+        amount: payment.amount,
+        method: payment.method,
+        notes: validate_optional(payment.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?,
+    })
+}
+
+/// Record a validated payment against a ticket.
+async fn record_ticket_payment(
+    executor: impl PgExecutor<'_>,
+    ticket_id: Uuid,
+    kind: PaymentKind,
+    payment: PaymentInput,
+    received_by: Uuid,
+) -> Result<TicketPayment, AppError> {
+    PaymentRepository::create(
+        executor,
+        CreateTicketPayment {
+            ticket_id,
+            kind,
+            method: payment.method,
+            amount: payment.amount,
+            notes: payment.notes,
+            received_by,
+        },
+    )
+    .await
 }
 
 /// GET /api/v1/tickets - List tickets with filters.
+///
+/// Lists the current store's tickets unless `all_stores=true`. When scoped ticket visibility is enabled, staff only see their own tickets.
+/// Soft-deleted tickets are listed only with `include_deleted=true` and admin
+/// authentication. Training sessions see only training tickets.
 pub async fn list_tickets(
     State(state): State<AppState>,
+    headers: HeaderMap,
+    training: TrainingMode,
+    CurrentStore(current_store): CurrentStore,
     Query(query): Query<ListTicketsQuery>,
 ) -> Result<impl IntoResponse, AppError> {
+    require_admin_for_deleted(&state, &headers, query.include_deleted).await?;
+    let visible_to = visibility_scope(&state, &headers).await?;
+    let store_id = (!query.all_stores).then_some(current_store);
     let limit = query.limit.unwrap_or(100);
     let offset = query.offset.unwrap_or(0);
 
+    // Cursor pagination lists oldest first from just after the cursor
+    let after = query
+        .cursor
+        .as_deref()
+        .map(|cursor| {
+            TicketCursor::decode(cursor).ok_or_else(|| AppError::validation("Invalid cursor"))
+        })
+        .transpose()?;
+    let by_cursor = after.is_some() || query.pagination == Some(PaginationMode::Cursor);
+    if by_cursor && query.offset.is_some() {
+        return Err(AppError::validation(
+            "offset can't be combined with cursor pagination",
+        ));
+    }
+    let sort = query
+        .sort
+        .as_deref()
+        .map(|sort| {
+            TicketSort::parse(sort).ok_or_else(|| {
+                let fields: Vec<&str> = TicketSortField::ALL.iter().map(|f| f.as_str()).collect();
+                AppError::validation(format!(
+                    "sort must be one of {}, optionally prefixed with -",
+                    fields.join(", ")
+                ))
+            })
+        })
+        .transpose()?;
+    let order = match sort {
+        Some(_) if by_cursor => {
+            return Err(AppError::validation(
+                "sort can't be combined with cursor pagination",
+            ))
+        }
+        Some(sort) => TicketOrder::Sorted(sort),
+        None if by_cursor => TicketOrder::Created,
+        None => TicketOrder::Priority,
+    };
+
     // If search is provided, use the search method
     let tickets = if let Some(ref search_query) = query.search {
         // Determine which statuses to search
@@ -456,6 +1024,12 @@ pub async fn list_tickets(
             statuses,
             limit: Some(limit + 1), // Fetch one extra to determine has_more
             offset: Some(offset),
+            order,
+            after,
+            visible_to,
+            store_id,
+            include_deleted: query.include_deleted,
+            training: training.0,
         };
 
         TicketRepository::search(&state.db, params).await?
@@ -481,6 +1055,14 @@ pub async fn list_tickets(
             created_before: query.to_date,
             limit: Some(limit + 1), // Fetch one extra to determine has_more
             offset: Some(offset),
+            order,
+            after,
+            visible_to,
+            store_id,
+            worked_by: query.worked_by,
+            taken_in_by: query.taken_in_by,
+            include_deleted: query.include_deleted,
+            training: training.0,
         };
 
         // Convert TicketSummary to QueueTicket for consistent response format
@@ -502,10 +1084,12 @@ pub async fn list_tickets(
                 promise_date: s.promise_date,
                 quote_amount: s.quote_amount,
                 created_at: s.created_at,
+                queue_position: None,
                 is_overdue: s
                     .promise_date
                     .map(|d| d < today && s.status.is_open())
                     .unwrap_or(false),
+                deleted_at: s.deleted_at,
             })
             .collect()
     };
@@ -513,6 +1097,10 @@ pub async fn list_tickets(
     // Determine if there are more results
     let has_more = tickets.len() as i64 > limit;
     let tickets: Vec<QueueTicket> = tickets.into_iter().take(limit as usize).collect();
+    let next_cursor = tickets
+        .last()
+        .filter(|_| by_cursor && has_more)
+        .map(|last| TicketCursor::after(last).encode());
 
     let response = ListTicketsResponse {
         pagination: PaginationInfo {
@@ -520,6 +1108,7 @@ pub async fn list_tickets(
             limit,
             offset,
             has_more,
+            next_cursor,
         },
         tickets,
     };
@@ -550,15 +1139,22 @@ pub struct CreateTicketRequest {
     /// Item type (e.g., "ring", "necklace")
     pub item_type: Option<String>,
 
-    /// Description of the item (required)
+    /// Description of the item (required unless `items` is sent)
+    #[serde(default)]
     pub item_description: String,
 
-    /// Notes about the item's condition (required)
+    /// Notes about the item's condition (required unless `items` is sent)
+    #[serde(default)]
     pub condition_notes: String,
 
-    /// Description of requested work (required)
+    /// Description of requested work (required unless `items` is sent)
+    #[serde(default)]
     pub requested_work: String,
 
+    /// Items for a multi-item ticket, in place of the item fields above
+    #[serde(default)]
+    pub items: Vec<CreateTicketItem>,
+
     /// Whether this is a rush job
     #[serde(default)]
     pub is_rush: bool,
@@ -569,8 +1165,28 @@ pub struct CreateTicketRequest {
     /// Storage location ID (required)
     pub storage_location_id: Uuid,
 
-    /// Quoted amount for the work
+    /// Quoted amount for the work (defaults to the sum of the item quotes)
     pub quote_amount: Option<Decimal>,
+
+    /// Part of quote_amount charged for a rush job (see `POST /tickets/quote`)
+    pub rush_surcharge: Option<Decimal>,
+
+    /// Item weight in grams
+    pub weight_grams: Option<Decimal>,
+
+    /// Metal type from the metal price table (e.g., "14k_gold")
+    pub metal_type: Option<String>,
+
+    /// Custody witness (required when the quote meets the custody threshold)
+    pub custody: Option<CustodyWitness>,
+
+    /// Deposit taken at intake
+    pub deposit: Option<PaymentInput>,
+
+    /// Catalog services picked at intake; they fill in item 1's requested
+    /// work and quote, and the promise date, when those aren't sent
+    #[serde(default)]
+    pub service_ids: Vec<Uuid>,
 }
 
 /// Response for a created ticket.
@@ -580,11 +1196,15 @@ pub struct CreateTicketResponse {
     #[serde(flatten)]
     pub ticket: Ticket,
 
-    /// URL to download the receipt PDF
+    /// URL to download the receipt in the store's default format
     pub receipt_url: String,
 
     /// URL to download the label PDF
     pub label_url: String,
+
+    /// The deposit taken at intake, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deposit: Option<TicketPayment>,
 }
 
 /// Extract employee from session token (X-Employee-Session header).
@@ -592,7 +1212,7 @@ pub struct CreateTicketResponse {
 /// This is the secure method that prevents employee impersonation.
 /// Falls back to X-Employee-ID header for backwards compatibility,
 /// but that method is deprecated and should be removed in a future version.
-async fn extract_employee_from_session(
+pub(crate) async fn extract_employee_from_session(
     state: &AppState,
     headers: &HeaderMap,
 ) -> Result<Employee, AppError> {
@@ -635,82 +1255,151 @@ async fn extract_employee_from_session(
     ))
 }
 
+/// Soft-deleted tickets are only shown to admins.
+async fn require_admin_for_deleted(
+    state: &AppState,
+    headers: &HeaderMap,
+    include_deleted: bool,
+) -> Result<(), AppError> {
+    if include_deleted {
+        crate::handlers::admin::verify_admin_auth(state, headers).await?;
+    }
+    Ok(())
+}
+
+/// Determine which tickets the caller may see in lists, search, and the queue.
+///
+/// Returns None (all tickets) unless the store has scoped ticket visibility
+/// enabled. When scoped, the caller must identify themselves; admins still
+/// see everything and staff see only tickets they took in or are assigned to.
+async fn visibility_scope(state: &AppState, headers: &HeaderMap) -> Result<Option<Uuid>, AppError> {
+    if !StoreSettingsRepository::is_ticket_visibility_scoped(&state.db).await? {
+        return Ok(None);
+    }
+
+    let employee = extract_employee_from_session(state, headers).await?;
+    Ok(visible_to(&employee))
+}
+
+/// The employee a scoped ticket view is limited to (None for employees who
+/// may modify any ticket).
+fn visible_to(employee: &Employee) -> Option<Uuid> {
+    if employee.has_permission(Permission::ModifyAnyTicket) {
+        None
+    } else {
+        Some(employee.employee_id)
+    }
+}
+
 /// Check if an employee is authorized to modify a ticket.
 ///
 /// An employee can modify a ticket if:
-/// - They have the Admin role, OR
+/// - They may modify any ticket (admins, or a granted permission), OR
 /// - They took in the ticket (taken_in_by matches), OR
 /// - They are assigned to work on the ticket (worked_by matches)
 ///
 /// Returns an error if not authorized. The error message intentionally
 /// does not reveal whether the ticket exists to prevent enumeration.
 pub fn is_authorized_for_ticket(employee: &Employee, ticket: &Ticket) -> bool {
-    employee.role == EmployeeRole::Admin
+    employee.has_permission(Permission::ModifyAnyTicket)
         || ticket.taken_in_by == employee.employee_id
         || ticket.worked_by == Some(employee.employee_id)
 }
 
-/// Returns an authorization error for ticket operations.
-///
-/// Uses a generic message to avoid leaking information about ticket existence.
-fn forbidden_ticket_error() -> AppError {
-    AppError::forbidden("You do not have permission to modify this ticket")
+/// Check that an employee may set ticket prices.
+fn require_price_permission(employee: &Employee) -> Result<(), AppError> {
+    if employee.has_permission(Permission::EditPrices) {
+        Ok(())
+    } else {
+        Err(AppError::forbidden(
+            "You do not have permission to change prices",
+        ))
+    }
+}
+
+/// The customer a new ticket is taken in for.
+enum IntakeCustomer {
+    /// An existing customer
+    Existing(Uuid),
+    /// A customer given inline, created with the ticket
+    New(CreateCustomer),
 }
 
 /// POST /api/v1/tickets - Create a new ticket.
 pub async fn create_ticket(
     State(state): State<AppState>,
     headers: HeaderMap,
+    training: TrainingMode,
+    store: CurrentStore,
     Json(body): Json<CreateTicketRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    // 1. Extract and validate employee from session
     let employee = extract_employee_from_session(&state, &headers).await?;
+    let (response, warnings) = create_ticket_for(&state, &employee, training, store, body).await?;
 
-    // 2. Validate and sanitize ticket text fields
-    let item_description = validate_required(
-        &body.item_description,
-        "item_description",
-        MAX_DESCRIPTION_LENGTH,
-    )?;
-    let condition_notes = validate_required(
-        &body.condition_notes,
-        "condition_notes",
-        MAX_DESCRIPTION_LENGTH,
-    )?;
-    let requested_work = validate_required(
-        &body.requested_work,
-        "requested_work",
-        MAX_DESCRIPTION_LENGTH,
-    )?;
-    let item_type =
-        validate_optional(body.item_type.as_deref(), "item_type", MAX_ITEM_TYPE_LENGTH)?;
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(response).with_warnings(warnings)),
+    ))
+}
+
+/// Create a ticket taken in by `employee`.
+///
+/// Shared by ticket creation and intake draft conversion. The storage
+/// location must be in the request's store. Returns the created ticket with
+/// its non-blocking warnings.
+pub(crate) async fn create_ticket_for(
+    state: &AppState,
+    employee: &Employee,
+    training: TrainingMode,
+    store: CurrentStore,
+    mut body: CreateTicketRequest,
+) -> Result<(CreateTicketResponse, Vec<ApiWarning>), AppError> {
+    // 1. Check permissions; catalog prices filled in below don't need the
+    // price permission
+    require_permission(employee, Permission::CreateTicket)?;
+    let quotes_items = body.items.iter().any(|item| item.quote_amount.is_some());
+    if body.quote_amount.is_some() || body.rush_surcharge.is_some() || quotes_items {
+        require_price_permission(employee)?;
+    }
+
+    // 2. Apply the picked services' defaults, then validate and sanitize
+    // ticket text fields. Default promise dates fall on days the store is
+    // open.
+    let calendar = StoreSettingsRepository::get_business_calendar(&state.db, store.0).await?;
+    let services = intake_services(state, &calendar, &mut body).await?;
+    let mut items = validate_ticket_items(&body)?;
+    let quote_amount = ticket_quote(body.quote_amount, &body.items)?;
+    let metal_type = validate_optional(
+        body.metal_type.as_deref(),
+        "metal_type",
+        MAX_METAL_TYPE_LENGTH,
+    )?;
+    validate_weight(body.weight_grams)?;
+    validate_rush_surcharge(body.rush_surcharge, quote_amount)?;
+    let deposit = body.deposit.as_ref().map(validate_payment).transpose()?;
 
     // 3. Validate request - must have either customer_id OR customer, not both
-    let customer_id = match (&body.customer_id, &body.customer) {
+    let customer = match (&body.customer_id, &body.customer) {
         (Some(id), None) => {
             // Verify existing customer exists
-            CustomerRepository::find_by_id(&state.db, *id)
+            CustomerRepository::find_active_by_id(&state.db, *id)
                 .await?
                 .ok_or_else(|| AppError::not_found("Customer not found"))?;
-            *id
+            IntakeCustomer::Existing(*id)
         }
         (None, Some(inline)) => {
-            // Validate and sanitize inline customer fields
+            // Validate and sanitize inline customer fields; the customer is
+            // created with the ticket
             let customer_name = validate_required(&inline.name, "customer.name", MAX_NAME_LENGTH)?;
             let customer_phone = validate_phone(inline.phone.as_deref(), MAX_PHONE_LENGTH)?;
             let customer_email = validate_email(inline.email.as_deref(), MAX_EMAIL_LENGTH)?;
 
-            // Create new customer inline
-            let new_customer = CustomerRepository::create(
-                &state.db,
-                CreateCustomer {
-                    name: customer_name,
-                    phone: customer_phone,
-                    email: customer_email,
-                },
-            )
-            .await?;
-            new_customer.customer_id
+            IntakeCustomer::New(CreateCustomer {
+                name: customer_name,
+                phone: customer_phone,
+                email: customer_email,
+                is_training: training.0,
+            })
         }
         (Some(_), Some(_)) => {
             return Err(AppError::validation(
@@ -724,28 +1413,107 @@ pub async fn create_ticket(
         }
     };
 
-    // 4. Validate storage location exists and is active
-    validate_storage_location(&state.db, body.storage_location_id).await?;
+    // 4. Validate storage location exists, is active, and is in this store
+    // (and metal type is priced)
+    let location =
+        StorageLocationRepository::find_active_by_id(&state.db, body.storage_location_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Storage location not found or inactive"))?;
+    if location.store_id != store.0 {
+        return Err(AppError::validation(
+            "The storage location belongs to another store",
+        ));
+    }
+    let store_id = location.store_id;
+    if let Some(ref metal_type) = metal_type {
+        validate_metal_type(&state.db, metal_type).await?;
+    }
+
+    // Apply the configured item types' names, and item 1's defaults; other
+    // item types are kept as free text unless the store restricts them
+    let restrict_item_types =
+        StoreSettingsRepository::get_restrict_item_types(&state.db, store_id).await?;
+    let mut item_type_config = None;
+    for (i, item) in items.iter_mut().enumerate() {
+        let config = match item.item_type.as_deref() {
+            Some(item_type) => ItemTypeRepository::find_by_name(&state.db, item_type).await?,
+            None => None,
+        };
+        if let Some(ref config) = config {
+            item.item_type = Some(config.name.clone());
+        } else if let Some(item_type) = item.item_type.as_deref() {
+            if restrict_item_types {
+                let field = if body.items.is_empty() {
+                    "item_type".to_string()
+                } else {
+                    format!("items[{}].item_type", i)
+                };
+                item.item_type = Some(unlisted_item_type(item_type, &field)?);
+            }
+        }
+        if i == 0 {
+            item_type_config = config;
+        }
+    }
+    let promise_date = body.promise_date.or_else(|| {
+        item_type_config.as_ref().and_then(|config| {
+            config.default_promise_date(&calendar, Utc::now().date_naive(), body.is_rush)
+        })
+    });
+
+    // High-value items need a witnessed intake
+    let custody_threshold =
+        StoreSettingsRepository::get_custody_value_threshold(&state.db, store_id).await?;
+    let custody = custody_witness(
+        state,
+        employee.employee_id,
+        body.custody.as_ref(),
+        custody_required(quote_amount, custody_threshold),
+    )
+    .await?;
 
-    // 5. Create the ticket
+    // 5. Collect soft warnings (never block creation)
+    let mut warnings = ticket_warnings(promise_date, quote_amount, Utc::now().date_naive());
+    warnings.extend(location_warnings(state, body.storage_location_id).await?);
+
+    // 6. Create the customer (if new) and the ticket; its own item fields are
+    // item 1's. Steps 6-9 run in one transaction.
+    let mut tx = state.db.begin().await?;
+    let customer_id = match customer {
+        IntakeCustomer::Existing(customer_id) => customer_id,
+        IntakeCustomer::New(customer) => {
+            CustomerRepository::create(&mut *tx, customer)
+                .await?
+                .customer_id
+        }
+    };
+    let first = items[0].clone();
     let create_ticket = CreateTicket {
         customer_id,
-        item_type,
-        item_description,
-        condition_notes,
-        requested_work,
+        item_type: first.item_type,
+        item_description: first.item_description,
+        condition_notes: first.condition_notes,
+        requested_work: first.requested_work,
         is_rush: body.is_rush,
-        promise_date: body.promise_date,
+        promise_date,
         storage_location_id: body.storage_location_id,
-        quote_amount: body.quote_amount,
+        quote_amount,
+        rush_surcharge: body.rush_surcharge,
+        weight_grams: body.weight_grams,
+        metal_type,
         taken_in_by: employee.employee_id,
+        is_training: training.0,
+        items,
     };
 
-    let ticket = TicketRepository::create(&state.db, create_ticket).await?;
+    let ticket = TicketRepository::create(&mut tx, create_ticket).await?;
+    if !services.is_empty() {
+        ServiceRepository::add_to_ticket(&mut *tx, ticket.ticket_id, &services).await?;
+    }
 
-    // 6. Create initial status history entry (null -> intake)
+    // 7. Create initial status history entry (null -> intake)
     StatusHistoryRepository::create(
-        &state.db,
+        &mut *tx,
         CreateStatusHistory {
             ticket_id: ticket.ticket_id,
             from_status: None,
@@ -755,23 +1523,86 @@ pub async fn create_ticket(
     )
     .await?;
 
-    // 7. Build response with print URLs
+    // 8. Start the chain of custody
+    if let Some(custody) = custody {
+        CustodyRepository::create(
+            &mut *tx,
+            CreateCustodyEvent {
+                ticket_id: ticket.ticket_id,
+                event_type: CustodyEventType::Intake,
+                from_location_id: None,
+                to_location_id: Some(ticket.storage_location_id),
+                handled_by: employee.employee_id,
+                witnessed_by: Some(custody.witnessed_by),
+                notes: custody.notes,
+            },
+        )
+        .await?;
+    }
+
+    // 9. Record the deposit
+    let deposit = match deposit {
+        Some(deposit) => Some(
+            record_ticket_payment(
+                &mut *tx,
+                ticket.ticket_id,
+                PaymentKind::Deposit,
+                deposit,
+                employee.employee_id,
+            )
+            .await?,
+        ),
+        None => None,
+    };
+    tx.commit().await?;
+
+    // 10. Email the intake confirmation in the background
+    {
+        let notifications = state.notifications.clone();
+        let pool = state.db.clone();
+        let ticket = ticket.clone();
+        state.shutdown.spawn(async move {
+            if let Err(err) = notifications.notify_intake(&pool, &ticket).await {
+                tracing::warn!(
+                    "Failed to send intake confirmation for ticket {}: {:?}",
+                    ticket.friendly_code,
+                    err
+                );
+            }
+        });
+    }
+
+    // 11. Notify webhook subscribers
+    webhooks::ticket_created(&state.db, &ticket).await;
+
+    // 12. Build response with print URLs, the receipt in the store's default format
+    let receipt_format = StoreSettingsRepository::get_receipt_format(&state.db, ticket.store_id)
+        .await
+        .unwrap_or_default();
     let response = CreateTicketResponse {
-        receipt_url: format!("/api/v1/tickets/{}/receipt.pdf", ticket.ticket_id),
+        receipt_url: format!(
+            "/api/v1/tickets/{}/receipt.{}",
+            ticket.ticket_id,
+            receipt_format.extension()
+        ),
         label_url: format!("/api/v1/tickets/{}/label.pdf", ticket.ticket_id),
         ticket,
+        deposit,
     };
 
-    Ok((StatusCode::CREATED, Json(ApiResponse::success(response))))
+    Ok((response, warnings))
 }
 
-/// Store settings data for PDF generation.
-/// Fetched from database or uses defaults if not configured.
-#[derive(Debug, Clone, sqlx::FromRow)]
-struct StoreSettings {
-    store_name: String,
-    store_phone: Option<String>,
-    store_address: Option<String>,
+/// GET /api/v1/tickets/:ticket_id/receipt - Generate a receipt in the store's default format.
+///
+/// Serves the same output as `receipt.pdf`, `receipt.escpos`, or
+/// `receipt.txt`, as picked by the `receipt_format` setting.
+pub async fn get_receipt(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let (receipt_data, format) = load_receipt(&state, ticket_id, None).await?;
+    receipt_response(&receipt_data, format)
 }
 
 /// GET /api/v1/tickets/:ticket_id/receipt.pdf - Generate receipt PDF for a ticket.
@@ -779,45 +1610,184 @@ pub async fn get_receipt_pdf(
     State(state): State<AppState>,
     Path(ticket_id): Path<Uuid>,
 ) -> Result<Response, AppError> {
+    let (receipt_data, format) = load_receipt(&state, ticket_id, Some(ReceiptFormat::Pdf)).await?;
+    receipt_response(&receipt_data, format)
+}
+
+/// GET /api/v1/tickets/:ticket_id/receipt.escpos - Generate an ESC/POS receipt
+/// for an 80mm thermal printer.
+pub async fn get_receipt_escpos(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let (receipt_data, format) =
+        load_receipt(&state, ticket_id, Some(ReceiptFormat::Escpos)).await?;
+    receipt_response(&receipt_data, format)
+}
+
+/// GET /api/v1/tickets/:ticket_id/receipt.txt - Generate a plain-text receipt.
+pub async fn get_receipt_text(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    let (receipt_data, format) = load_receipt(&state, ticket_id, Some(ReceiptFormat::Text)).await?;
+    receipt_response(&receipt_data, format)
+}
+
+/// Gather everything printed on a ticket's receipt.
+///
+/// Returns the data with the format to render it in: `format`, or the
+/// store's default when None.
+async fn load_receipt(
+    state: &AppState,
+    ticket_id: Uuid,
+    format: Option<ReceiptFormat>,
+) -> Result<(ReceiptData, ReceiptFormat), AppError> {
     // 1. Find the ticket
     let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     // 2. Find the customer
     let customer = CustomerRepository::find_by_id(&state.db, ticket.customer_id)
         .await?
         .ok_or_else(|| AppError::not_found("Customer not found"))?;
 
-    // 3. Get store settings (or use defaults)
-    let store_settings = sqlx::query_as::<_, StoreSettings>(
-        r#"
-        SELECT store_name, store_phone, store_address
-        FROM store_settings
-        LIMIT 1
-        "#,
-    )
-    .fetch_optional(&state.db)
-    .await?
-    .unwrap_or(StoreSettings {
-        store_name: "Jewelry Store".to_string(),
-        store_phone: None,
-        store_address: None,
-    });
+    // 3. Get store settings and the receipt template (text receipts have no logo)
+    let store_settings =
+        StoreSettingsRepository::get_store_settings(&state.db, ticket.store_id).await?;
+    let format = format.unwrap_or(store_settings.receipt_format);
+    let logo = match format {
+        ReceiptFormat::Text => None,
+        _ => receipt_logo(state, store_settings.receipt_logo_key.as_deref()).await,
+    };
+    let template = ReceiptTemplate {
+        logo,
+        footer_text: store_settings.receipt_footer_text,
+        show_prices: store_settings.receipt_show_prices,
+    };
+
+    // 4. Total up payments for the deposit and balance lines
+    let (total_paid, deposit_total) = PaymentRepository::totals(&state.db, ticket_id).await?;
+
+    // 5. Get the items to list and the tax on the ticket's amount
+    let items = TicketItemRepository::find_by_ticket_id(&state.db, ticket_id).await?;
+    let tax = ticket_tax(state, &ticket).await?;
 
-    // 4. Generate PDF
     let receipt_data = ReceiptData {
         ticket,
         customer,
+        items,
         store_name: store_settings.store_name,
         store_phone: store_settings.store_phone,
         store_address: store_settings.store_address,
+        deposit_total,
+        total_paid,
+        tax,
+        template,
     };
+    Ok((receipt_data, format))
+}
 
-    let pdf_bytes = generate_receipt_pdf(&receipt_data)?;
+/// Render a receipt and wrap it in a download response.
+fn receipt_response(
+    receipt_data: &ReceiptData,
+    format: ReceiptFormat,
+) -> Result<Response, AppError> {
+    let (body, content_type, disposition) = match format {
+        ReceiptFormat::Pdf => (
+            generate_receipt_pdf(receipt_data)?,
+            "application/pdf",
+            "inline",
+        ),
+        // Raw printer bytes are saved or sent to the printer, never displayed
+        ReceiptFormat::Escpos => (
+            receipt_escpos(receipt_data),
+            "application/octet-stream",
+            "attachment",
+        ),
+        ReceiptFormat::Text => (
+            receipt_text(receipt_data).into_bytes(),
+            "text/plain; charset=utf-8",
+            "inline",
+        ),
+    };
 
-    // 5. Return PDF response
-    let filename = format!("receipt-{}.pdf", receipt_data.ticket.friendly_code);
+    let filename = format!(
+        "receipt-{}.{}",
+        receipt_data.ticket.friendly_code,
+        format.extension()
+    );
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, content_type)
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("{}; filename=\"{}\"", disposition, filename),
+        )
+        .body(Body::from(body))
+        .map_err(|e| AppError::server_error(format!("Failed to build response: {}", e)))
+}
+
+/// Load the receipt logo, if one is set.
+///
+/// A logo that can't be read is left off rather than failing the receipt.
+async fn receipt_logo(state: &AppState, key: Option<&str>) -> Option<Vec<u8>> {
+    let key = key?;
+    match state.storage.download(key).await {
+        Ok(data) => Some(data),
+        Err(err) => {
+            tracing::warn!(key, "Printing receipt without logo: {}", err);
+            None
+        }
+    }
+}
+
+/// GET /api/v1/tickets/:ticket_id/invoice.pdf - Generate an invoice PDF for a closed ticket.
+///
+/// Tickets are invoiced when closed; one closed before invoices existed is
+/// invoiced on first request. Open tickets have no invoice.
+pub async fn get_invoice_pdf(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    // 1. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 2. Only closed tickets are invoiced
+    if ticket.status.is_open() {
+        return Err(AppError::conflict("Only closed tickets can be invoiced"));
+    }
+
+    // 3. Find the customer
+    let customer = CustomerRepository::find_by_id(&state.db, ticket.customer_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Customer not found"))?;
+
+    // 4. Get the invoice, store info, items, and payments
+    let invoice = find_or_record_invoice(&state, &ticket).await?;
+    let store_settings =
+        StoreSettingsRepository::get_store_settings(&state.db, ticket.store_id).await?;
+    let items = TicketItemRepository::find_by_ticket_id(&state.db, ticket_id).await?;
+    let payments = PaymentRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+
+    // 5. Generate PDF
+    let invoice_data = InvoiceData {
+        ticket,
+        customer,
+        invoice,
+        items,
+        payments,
+        store_name: store_settings.store_name,
+        store_phone: store_settings.store_phone,
+        store_address: store_settings.store_address,
+    };
+    let pdf_bytes = generate_invoice_pdf(&invoice_data)?;
+
+    // 6. Return PDF response
+    let filename = format!("invoice-{}.pdf", invoice_data.invoice.code());
     let response = Response::builder()
         .status(StatusCode::OK)
         .header(header::CONTENT_TYPE, "application/pdf")
@@ -831,6 +1801,80 @@ pub async fn get_receipt_pdf(
     Ok(response)
 }
 
+/// Get a closed ticket's invoice.
+///
+/// A ticket closed before invoices were recorded is invoiced now, at the
+/// store's current tax rate.
+async fn find_or_record_invoice(
+    state: &AppState,
+    ticket: &Ticket,
+) -> Result<TicketInvoice, AppError> {
+    if let Some(invoice) = InvoiceRepository::find_by_ticket_id(&state.db, ticket.ticket_id).await?
+    {
+        return Ok(invoice);
+    }
+    let tax = StoreSettingsRepository::get_tax_rate(&state.db, ticket.store_id)
+        .await?
+        .apply(amount_due(ticket).unwrap_or_default());
+    let mut tx = state.db.begin().await?;
+    let invoice = record_invoice(&mut tx, ticket.ticket_id, tax).await?;
+    tx.commit().await?;
+    Ok(invoice)
+}
+
+/// Record the tax on a closed ticket's invoice.
+///
+/// The first close numbers the invoice from the store's invoice sequence;
+/// closing again after a reopen keeps the number and replaces the tax. If
+/// two requests number the same ticket at once, the loser's number goes
+/// unused.
+async fn record_invoice(
+    conn: &mut PgConnection,
+    ticket_id: Uuid,
+    tax: TaxBreakdown,
+) -> Result<TicketInvoice, AppError> {
+    if let Some(invoice) = InvoiceRepository::update_tax(&mut *conn, ticket_id, tax).await? {
+        return Ok(invoice);
+    }
+
+    let invoice_number =
+        StoreSettingsRepository::get_and_increment_invoice_number(&mut *conn).await?;
+    let created = InvoiceRepository::create(
+        &mut *conn,
+        CreateTicketInvoice {
+            ticket_id,
+            invoice_number,
+            tax,
+        },
+    )
+    .await?;
+    match created {
+        Some(invoice) => Ok(invoice),
+        None => InvoiceRepository::update_tax(&mut *conn, ticket_id, tax)
+            .await?
+            .ok_or_else(|| AppError::server_error("Invoice disappeared after being issued")),
+    }
+}
+
+/// What a ticket costs with tax.
+///
+/// Closed tickets use the tax recorded at close; open tickets apply the
+/// store's current rate to the quote. None when the ticket has no price.
+async fn ticket_tax(state: &AppState, ticket: &Ticket) -> Result<Option<TaxBreakdown>, AppError> {
+    if !ticket.status.is_open() {
+        if let Some(invoice) =
+            InvoiceRepository::find_by_ticket_id(&state.db, ticket.ticket_id).await?
+        {
+            return Ok(Some(invoice.tax()));
+        }
+    }
+    let Some(amount) = amount_due(ticket) else {
+        return Ok(None);
+    };
+    let rate = StoreSettingsRepository::get_tax_rate(&state.db, ticket.store_id).await?;
+    Ok(Some(rate.apply(amount)))
+}
+
 /// GET /api/v1/tickets/:ticket_id/label.pdf - Generate label PDF for a physical tag.
 pub async fn get_label_pdf(
     State(state): State<AppState>,
@@ -839,17 +1883,26 @@ pub async fn get_label_pdf(
     // 1. Find the ticket
     let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     // 2. Find the customer
     let customer = CustomerRepository::find_by_id(&state.db, ticket.customer_id)
         .await?
         .ok_or_else(|| AppError::not_found("Customer not found"))?;
 
-    // 3. Generate label PDF
+    // 3. Generate label PDF on the configured stock
+    let settings = StoreSettingsRepository::get_store_settings(&state.db, ticket.store_id).await?;
     let label_data = LabelData {
         ticket,
         customer_name: customer.name,
+        width_mm: settings
+            .label_width_mm
+            .to_f32()
+            .unwrap_or(DEFAULT_LABEL_WIDTH_MM),
+        height_mm: settings
+            .label_height_mm
+            .to_f32()
+            .unwrap_or(DEFAULT_LABEL_HEIGHT_MM),
     };
 
     let pdf_bytes = generate_label_pdf(&label_data)?;
@@ -869,13 +1922,66 @@ pub async fn get_label_pdf(
     Ok(response)
 }
 
+/// GET /api/v1/tickets/:ticket_id/work-order.pdf - Generate work order PDF for the bench.
+///
+/// Includes the configured QC checklist with the most recent result.
+pub async fn get_work_order_pdf(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    // 1. Find the ticket with its customer, location, and employees
+    let detail = TicketRepository::find_detail(&state.db, ticket_id, false)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 2. Get QC checklist and most recent check
+    let qc_checklist =
+        StoreSettingsRepository::get_qc_checklist(&state.db, detail.ticket.store_id).await?;
+    let qc_check = QcCheckRepository::find_latest(&state.db, ticket_id).await?;
+    let qc_checked_by_name = match qc_check {
+        Some(ref check) => EmployeeRepository::find_by_id(&state.db, check.checked_by)
+            .await?
+            .map(|e| e.name),
+        None => None,
+    };
+
+    // 3. Generate PDF
+    let work_order_data = WorkOrderData {
+        ticket: detail.ticket,
+        customer_name: detail.customer_name,
+        storage_location_name: detail.storage_location_name,
+        worked_by_name: detail.worked_by_name,
+        qc_checklist,
+        qc_check,
+        qc_checked_by_name,
+    };
+
+    let pdf_bytes = generate_work_order_pdf(&work_order_data)?;
+
+    // 4. Return PDF response
+    let filename = format!("work-order-{}.pdf", work_order_data.ticket.friendly_code);
+    let response = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/pdf")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("inline; filename=\"{}\"", filename),
+        )
+        .body(Body::from(pdf_bytes))
+        .map_err(|e| AppError::server_error(format!("Failed to build response: {}", e)))?;
+
+    Ok(response)
+}
+
 /// Helper to deserialize Option<Option<T>> where explicit null means Some(None).
 ///
 /// This is used for fields that can be:
 /// - Absent: The field is not in the JSON (outer Option is None)
 /// - Null: The field is explicitly set to null (outer Option is Some(None))
 /// - Present: The field has a value (outer Option is Some(Some(value)))
-fn deserialize_optional_nullable<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+pub(crate) fn deserialize_optional_nullable<'de, T, D>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
 where
     T: serde::Deserialize<'de>,
     D: serde::Deserializer<'de>,
@@ -912,6 +2018,10 @@ pub struct UpdateTicketRequest {
     #[serde(default, deserialize_with = "deserialize_optional_nullable")]
     pub promise_date: Option<Option<NaiveDate>>,
 
+    /// Reason code for moving the promise date later (see
+    /// `/settings/promise-date-reasons`)
+    pub promise_date_reason: Option<String>,
+
     /// Storage location ID
     pub storage_location_id: Option<Uuid>,
 
@@ -919,6 +2029,10 @@ pub struct UpdateTicketRequest {
     #[serde(default, deserialize_with = "deserialize_optional_nullable")]
     pub quote_amount: Option<Option<Decimal>>,
 
+    /// Part of quote_amount charged for a rush job (null to clear)
+    #[serde(default, deserialize_with = "deserialize_optional_nullable")]
+    pub rush_surcharge: Option<Option<Decimal>>,
+
     /// Actual amount charged (null to clear)
     #[serde(default, deserialize_with = "deserialize_optional_nullable")]
     pub actual_amount: Option<Option<Decimal>>,
@@ -926,6 +2040,17 @@ pub struct UpdateTicketRequest {
     /// Employee who worked on the ticket (null to clear)
     #[serde(default, deserialize_with = "deserialize_optional_nullable")]
     pub worked_by_employee_id: Option<Option<Uuid>>,
+
+    /// Item weight in grams (null to clear)
+    #[serde(default, deserialize_with = "deserialize_optional_nullable")]
+    pub weight_grams: Option<Option<Decimal>>,
+
+    /// Metal type from the metal price table (null to clear)
+    #[serde(default, deserialize_with = "deserialize_optional_nullable")]
+    pub metal_type: Option<Option<String>>,
+
+    /// Custody witness (required when moving a high-value item)
+    pub custody: Option<CustodyWitness>,
 }
 
 /// Check if admin PIN is valid.
@@ -938,10 +2063,11 @@ async fn verify_admin_pin(pool: &sqlx::PgPool, pin: &str) -> Result<bool, AppErr
     // This is a temporary implementation that won't work until argon2 hashing is added
 
     // Get the admin_pin_hash from store_settings
-    let result =
-        sqlx::query_scalar::<_, String>("SELECT admin_pin_hash FROM store_settings LIMIT 1")
-            .fetch_optional(pool)
-            .await?;
+    let result = sqlx::query_scalar::<_, String>(
+        "SELECT admin_pin_hash FROM store_settings WHERE store_id = primary_store_id()",
+    )
+    .fetch_optional(pool)
+    .await?;
 
     // If no store_settings row, admin PIN verification fails
     if result.is_none() {
@@ -955,10 +2081,21 @@ async fn verify_admin_pin(pool: &sqlx::PgPool, pin: &str) -> Result<bool, AppErr
     Ok(false)
 }
 
+/// Whether the store sends promise date change notifications.
+async fn promise_date_notifications_enabled(pool: &sqlx::PgPool) -> Result<bool, AppError> {
+    Ok(
+        NotificationTemplateRepository::find_by_event(pool, NotificationEvent::PromiseDateChanged)
+            .await?
+            .is_some_and(|template| template.is_enabled),
+    )
+}
+
 /// PUT /api/v1/tickets/:ticket_id - Update a ticket.
 ///
 /// Staff can only modify tickets they own (taken_in_by or worked_by).
-/// Admins can modify any ticket.
+/// Admins can modify any ticket. Moving the promise date later with a
+/// `promise_date_reason` notifies the customer when the store has enabled
+/// promise date notifications; the reason is then required.
 pub async fn update_ticket(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -971,10 +2108,22 @@ pub async fn update_ticket(
     // 2. Find the ticket
     let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(forbidden_ticket_error)?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     // 3. Authorization check: staff can only modify their own tickets, admin can modify any
     require_ticket_access(&employee, &existing_ticket, Permission::ModifyOwnTicket)?;
+    let changes_prices = body
+        .quote_amount
+        .is_some_and(|amount| amount != existing_ticket.quote_amount)
+        || body
+            .rush_surcharge
+            .is_some_and(|amount| amount != existing_ticket.rush_surcharge)
+        || body
+            .actual_amount
+            .is_some_and(|amount| amount != existing_ticket.actual_amount);
+    if changes_prices {
+        require_price_permission(&employee)?;
+    }
 
     // 4. Check if ticket is closed/archived
     if !existing_ticket.status.is_open() {
@@ -1000,11 +2149,21 @@ pub async fn update_ticket(
     // For update, if a text field is provided, it must be validated.
     // None in the request means "don't change this field".
     // Some(value) means "validate and set to this value".
-    let item_type = body
+    let mut item_type = body
         .item_type
         .as_ref()
         .map(|v| validate_optional(Some(v.as_str()), "item_type", MAX_ITEM_TYPE_LENGTH))
         .transpose()?;
+    if let Some(Some(ref mut name)) = item_type {
+        if StoreSettingsRepository::get_restrict_item_types(&state.db, existing_ticket.store_id)
+            .await?
+        {
+            *name = match ItemTypeRepository::find_by_name(&state.db, name).await? {
+                Some(config) => config.name,
+                None => unlisted_item_type(name, "item_type")?,
+            };
+        }
+    }
     let item_description = body
         .item_description
         .as_ref()
@@ -1020,6 +2179,19 @@ pub async fn update_ticket(
         .as_ref()
         .map(|v| validate_required(v, "requested_work", MAX_DESCRIPTION_LENGTH))
         .transpose()?;
+    let metal_type = body
+        .metal_type
+        .as_ref()
+        .map(|v| validate_optional(v.as_deref(), "metal_type", MAX_METAL_TYPE_LENGTH))
+        .transpose()?;
+    validate_weight(body.weight_grams.flatten())?;
+    if body.rush_surcharge.is_some() || body.quote_amount.is_some() {
+        validate_rush_surcharge(
+            body.rush_surcharge
+                .unwrap_or(existing_ticket.rush_surcharge),
+            body.quote_amount.unwrap_or(existing_ticket.quote_amount),
+        )?;
+    }
 
     // 6. Track field changes for audit trail
     let mut field_changes: Vec<CreateFieldHistory> = Vec::new();
@@ -1101,6 +2273,11 @@ pub async fn update_ticket(
         existing_ticket.quote_amount,
         body.quote_amount
     );
+    track_nullable_change!(
+        "rush_surcharge",
+        existing_ticket.rush_surcharge,
+        body.rush_surcharge
+    );
     track_nullable_change!(
         "actual_amount",
         existing_ticket.actual_amount,
@@ -1111,6 +2288,12 @@ pub async fn update_ticket(
         existing_ticket.worked_by,
         body.worked_by_employee_id
     );
+    track_nullable_change!(
+        "weight_grams",
+        existing_ticket.weight_grams,
+        body.weight_grams
+    );
+    track_nullable_change!("metal_type", existing_ticket.metal_type, metal_type);
 
     // 7. Validate referenced entities if they are being changed
     // Validate storage_location_id if provided
@@ -1123,6 +2306,71 @@ pub async fn update_ticket(
         validate_employee(&state.db, employee_id).await?;
     }
 
+    // Validate metal_type if provided and not None
+    if let Some(Some(ref metal_type)) = metal_type {
+        validate_metal_type(&state.db, metal_type).await?;
+    }
+
+    // Moving the promise date later needs a configured reason when the
+    // customer will be told about it; the reason is kept in the history
+    let slipped_date = slipped_promise_date(existing_ticket.promise_date, body.promise_date);
+    let promise_date_reason = match body.promise_date_reason.as_deref().map(str::trim) {
+        Some(code) => Some(
+            PromiseDateReasonRepository::find_by_code(&state.db, code)
+                .await?
+                .ok_or_else(|| {
+                    AppError::validation(format!("Unknown promise date reason: {}", code))
+                })?,
+        ),
+        None => None,
+    };
+    if slipped_date.is_some() {
+        match &promise_date_reason {
+            Some(reason) => field_changes.push(CreateFieldHistory {
+                ticket_id,
+                field_name: "promise_date_reason".to_string(),
+                old_value: None,
+                new_value: Some(reason.label.clone()),
+                changed_by: employee.employee_id,
+            }),
+            None if promise_date_notifications_enabled(&state.db).await? => {
+                return Err(AppError::validation(
+                    "promise_date_reason is required when moving the promise date later",
+                ));
+            }
+            None => {}
+        }
+    }
+
+    // Moving a high-value item needs a witnessed custody event
+    let location_changed = body
+        .storage_location_id
+        .is_some_and(|id| id != existing_ticket.storage_location_id);
+    if location_changed {
+        ensure_not_in_transit(&state, ticket_id).await?;
+    }
+    // Counted before the move, so the ticket doesn't count against its new home
+    let capacity_warnings = match body.storage_location_id {
+        Some(location_id) if location_changed => location_warnings(&state, location_id).await?,
+        _ => Vec::new(),
+    };
+    let custody = if location_changed {
+        let threshold = StoreSettingsRepository::get_custody_value_threshold(
+            &state.db,
+            existing_ticket.store_id,
+        )
+        .await?;
+        custody_witness(
+            &state,
+            employee.employee_id,
+            body.custody.as_ref(),
+            existing_ticket.requires_custody(threshold),
+        )
+        .await?
+    } else {
+        None
+    };
+
     // 8. Build update struct with validated values
     // For item_type: flatten Option<Option<String>> to Option<String>
     // - None (request didn't include field) -> None (don't change)
@@ -1139,64 +2387,460 @@ pub async fn update_ticket(
         promise_date: body.promise_date,
         storage_location_id: body.storage_location_id,
         quote_amount: body.quote_amount,
+        rush_surcharge: body.rush_surcharge,
         actual_amount: body.actual_amount,
         worked_by: body.worked_by_employee_id,
+        weight_grams: body.weight_grams,
+        metal_type,
         last_modified_by: Some(employee.employee_id),
     };
 
-    // 9. Update the ticket
-    let updated_ticket = TicketRepository::update(&state.db, ticket_id, update).await?;
+    // 9. Update the ticket; steps 9-11 run in one transaction
+    let mut tx = state.db.begin().await?;
+    let updated_ticket = TicketRepository::update(&mut *tx, ticket_id, update).await?;
 
     // 10. Record field changes in history
-    FieldHistoryRepository::create_batch(&state.db, field_changes).await?;
+    FieldHistoryRepository::create_batch(&mut tx, field_changes).await?;
+
+    // 11. Record the move in the chain of custody
+    if let Some(custody) = custody {
+        CustodyRepository::create(
+            &mut *tx,
+            CreateCustodyEvent {
+                ticket_id,
+                event_type: CustodyEventType::LocationChange,
+                from_location_id: Some(existing_ticket.storage_location_id),
+                to_location_id: Some(updated_ticket.storage_location_id),
+                handled_by: employee.employee_id,
+                witnessed_by: Some(custody.witnessed_by),
+                notes: custody.notes,
+            },
+        )
+        .await?;
+    }
+    tx.commit().await?;
+
+    // 12. Tell the customer about a later promise date.
+    // Sent in the background like the ready-for-pickup text; the outcome
+    // and the reason given are recorded in the notification log.
+    if let (Some(_), Some(reason)) = (slipped_date, promise_date_reason) {
+        let notifications = state.notifications.clone();
+        let pool = state.db.clone();
+        let ticket = updated_ticket.clone();
+        state.shutdown.spawn(async move {
+            if let Err(err) = notifications
+                .notify_promise_date_changed(&pool, &ticket, &reason.label)
+                .await
+            {
+                tracing::warn!(
+                    "Failed to notify customer for ticket {}: {:?}",
+                    ticket.friendly_code,
+                    err
+                );
+            }
+        });
+    }
 
-    // 11. Return updated ticket
-    Ok(Json(ApiResponse::success(updated_ticket)))
+    // 13. Return updated ticket with soft warnings for newly set values
+    let mut warnings = ticket_warnings(
+        body.promise_date.flatten(),
+        body.quote_amount.flatten(),
+        Utc::now().date_naive(),
+    );
+    warnings.extend(capacity_warnings);
+
+    Ok(Json(
+        ApiResponse::success(updated_ticket).with_warnings(warnings),
+    ))
 }
 
 // =============================================================================
-// GET /queue - Workboard Queue
+// POST /tickets/quote - Quote Calculation
 // =============================================================================
 
-/// A single lane in the workboard queue.
-#[derive(Debug, Clone, Serialize)]
-pub struct QueueLane {
-    /// Number of tickets in this lane.
-    pub count: usize,
-    /// Tickets in this lane, sorted by rush first then FIFO.
-    pub tickets: Vec<QueueTicket>,
-}
-
-/// All lanes in the workboard queue.
-#[derive(Debug, Clone, Serialize)]
-pub struct QueueLanes {
-    pub intake: QueueLane,
-    pub in_progress: QueueLane,
-    pub waiting_on_parts: QueueLane,
-    pub ready_for_pickup: QueueLane,
+/// Request body for calculating a quote.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuoteRequest {
+    /// Price of the work before any rush surcharge
+    pub base_amount: Decimal,
+    #[serde(default)]
+    pub is_rush: bool,
+    /// Promised completion date, which sets the lead time
+    pub promise_date: Option<NaiveDate>,
 }
 
-/// Response for the GET /queue endpoint.
+/// A quote breakdown with the tax on its total.
 #[derive(Debug, Clone, Serialize)]
-pub struct GetQueueResponse {
-    pub lanes: QueueLanes,
+pub struct QuoteResponse {
+    #[serde(flatten)]
+    pub quote: QuoteBreakdown,
+    /// `total` split into subtotal, tax, and what the customer pays
+    pub tax: TaxBreakdown,
 }
 
-/// GET /api/v1/queue - Get workboard queue with tickets grouped by status lane.
+/// POST /api/v1/tickets/quote - Calculate a quote with any rush surcharge.
 ///
-/// Returns tickets grouped by status for workboard display.
-/// Each lane is sorted by rush first, then FIFO (oldest first).
-/// Excludes closed and archived tickets.
-/// Includes `is_overdue` flag for visual indicator.
-///
-/// Public endpoint - no authentication required for viewing the workboard.
-/// Operations (status changes, ticket creation) still require PIN authentication.
-pub async fn get_queue(State(state): State<AppState>) -> Result<impl IntoResponse, AppError> {
-    // Use the repository method which handles grouping and sorting
-    let queue = TicketRepository::get_queue(&state.db, None).await?;
+/// Applies the store's rush surcharge tiers to a base amount and returns the
+/// breakdown with the tax at the request's store's rate. Nothing is saved:
+/// send `total` as the ticket's `quote_amount` and `rush_surcharge`
+/// alongside it so receipts show the surcharge line.
+pub async fn quote_ticket(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
+    Json(body): Json<QuoteRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    extract_employee_from_session(&state, &headers).await?;
 
-    // Build response with count for each lane
-    let response = GetQueueResponse {
+    // 2. Validate the base amount
+    if body.base_amount < Decimal::ZERO {
+        return Err(AppError::validation("base_amount cannot be negative"));
+    }
+
+    // 3. Apply the surcharge tiers
+    let tiers = RushPricingRepository::list(&state.db).await?;
+    let quote = quote_breakdown(
+        body.base_amount,
+        body.is_rush,
+        body.promise_date,
+        Utc::now().date_naive(),
+        &tiers,
+    );
+
+    // 4. Work out the tax on the total
+    let tax = StoreSettingsRepository::get_tax_rate(&state.db, store_id)
+        .await?
+        .apply(quote.total);
+
+    Ok(Json(ApiResponse::success(QuoteResponse { quote, tax })))
+}
+
+// =============================================================================
+// GET /tickets/promise-date-suggestion - Promise Date Suggestion
+// =============================================================================
+
+/// Query parameters for a promise date suggestion.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PromiseDateSuggestionQuery {
+    /// Item type whose default turnaround applies (case-insensitive)
+    pub item_type: Option<String>,
+    #[serde(default)]
+    pub is_rush: bool,
+}
+
+/// A suggested promise date and how it was worked out.
+#[derive(Debug, Clone, Serialize)]
+pub struct PromiseDateSuggestion {
+    /// Suggested promise date (null when no turnaround applies)
+    pub promise_date: Option<NaiveDate>,
+    /// The configured item type matched, with its standard spelling
+    pub item_type: Option<String>,
+    /// Open days of turnaround counted from today
+    pub turnaround_days: Option<i32>,
+    pub is_rush: bool,
+    /// Closed days and holidays passed over
+    pub closed_days_skipped: i64,
+}
+
+/// GET /api/v1/tickets/promise-date-suggestion - Suggest a promise date.
+///
+/// Counts the item type's default turnaround (or the store's rush
+/// turnaround, for rush jobs when that's sooner) in days the request's store
+/// is open, so the date never falls on a closed day or holiday. Unknown item
+/// types have no turnaround; `promise_date` is then null unless the job is
+/// a rush.
+pub async fn suggest_promise_date(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    CurrentStore(store_id): CurrentStore,
+    Query(query): Query<PromiseDateSuggestionQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the item type's default turnaround
+    let item_type = match query.item_type.as_deref().map(str::trim) {
+        Some(name) if !name.is_empty() => ItemTypeRepository::find_by_name(&state.db, name).await?,
+        _ => None,
+    };
+
+    // 3. Count it in the store's open days
+    let calendar = StoreSettingsRepository::get_business_calendar(&state.db, store_id).await?;
+    let turnaround_days = calendar.turnaround_days(
+        item_type.as_ref().and_then(|t| t.default_turnaround_days),
+        query.is_rush,
+    );
+    let promise = turnaround_days.map(|days| calendar.promise_date(Utc::now().date_naive(), days));
+
+    Ok(Json(ApiResponse::success(PromiseDateSuggestion {
+        promise_date: promise.map(|promise| promise.date),
+        item_type: item_type.map(|item_type| item_type.name),
+        turnaround_days,
+        is_rush: query.is_rush,
+        closed_days_skipped: promise.map_or(0, |promise| promise.closed_days_skipped),
+    })))
+}
+
+// =============================================================================
+// POST /tickets/:ticket_id/quote/{send,approve,decline} - Quote Approval
+// =============================================================================
+
+/// Request body for recording that a quote was sent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SendQuoteRequest {
+    /// How the quote was given to the customer
+    pub channel: QuoteChannel,
+    /// Optional details
+    pub notes: Option<String>,
+}
+
+/// Request body for recording the customer's decision on a quote.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuoteDecisionRequest {
+    /// How the decision came back
+    pub channel: QuoteChannel,
+    /// Person who decided (defaults to the customer's name)
+    pub decided_by: Option<String>,
+    /// Optional details
+    pub notes: Option<String>,
+}
+
+/// A ticket's quote and its approval steps.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketQuoteResponse {
+    pub ticket_id: Uuid,
+    pub quote_amount: Option<Decimal>,
+    pub quote_status: QuoteStatus,
+    /// Whether work can't start until the quote is approved
+    pub approval_required: bool,
+    /// Steps recorded so far, oldest first
+    pub events: Vec<QuoteEvent>,
+}
+
+/// POST /api/v1/tickets/:ticket_id/quote/send - Record that the quote was given to the customer.
+pub async fn send_quote(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<SendQuoteRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    let step = QuoteStep {
+        status: QuoteStatus::Sent,
+        channel: body.channel,
+        decided_by: None,
+        notes: body.notes,
+    };
+    let response = record_quote_step(&state, &employee, ticket_id, step).await?;
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// POST /api/v1/tickets/:ticket_id/quote/approve - Record the customer's approval.
+pub async fn approve_quote(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<QuoteDecisionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    let step = QuoteStep::decision(QuoteStatus::Approved, body);
+    let response = record_quote_step(&state, &employee, ticket_id, step).await?;
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// POST /api/v1/tickets/:ticket_id/quote/decline - Record that the customer declined.
+pub async fn decline_quote(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<QuoteDecisionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    let step = QuoteStep::decision(QuoteStatus::Declined, body);
+    let response = record_quote_step(&state, &employee, ticket_id, step).await?;
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// A quote step to record.
+struct QuoteStep {
+    status: QuoteStatus,
+    channel: QuoteChannel,
+    decided_by: Option<String>,
+    notes: Option<String>,
+}
+
+impl QuoteStep {
+    fn decision(status: QuoteStatus, body: QuoteDecisionRequest) -> Self {
+        Self {
+            status,
+            channel: body.channel,
+            decided_by: body.decided_by,
+            notes: body.notes,
+        }
+    }
+}
+
+/// Move a ticket's quote to the step's status and log the step.
+async fn record_quote_step(
+    state: &AppState,
+    employee: &Employee,
+    ticket_id: Uuid,
+    step: QuoteStep,
+) -> Result<TicketQuoteResponse, AppError> {
+    // 1. Find the ticket; staff can only handle quotes on their own tickets
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    require_ticket_access(employee, &ticket, Permission::ModifyOwnTicket)?;
+    if !ticket.status.is_open() {
+        return Err(AppError::forbidden(
+            "Cannot change the quote on a closed or archived ticket",
+        ));
+    }
+
+    // 2. Check there is a quote and it can take this step
+    let Some(quote_amount) = ticket.quote_amount else {
+        return Err(AppError::validation("Ticket has no quote_amount"));
+    };
+    if !ticket.quote_status.can_transition_to(step.status) {
+        return Err(AppError::conflict(format!(
+            "Quote cannot move from {} to {}",
+            ticket.quote_status.as_str(),
+            step.status.as_str()
+        )));
+    }
+    let notes = validate_optional(step.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
+    let decided_by = match step.status {
+        QuoteStatus::Sent => None,
+        _ => match validate_optional(step.decided_by.as_deref(), "decided_by", MAX_NAME_LENGTH)? {
+            Some(name) => Some(name),
+            None => CustomerRepository::find_by_id(&state.db, ticket.customer_id)
+                .await?
+                .map(|customer| customer.name),
+        },
+    };
+
+    // 3. Record the step, unless the quote changed meanwhile
+    QuoteRepository::record(
+        &state.db,
+        ticket.quote_status,
+        CreateQuoteEvent {
+            ticket_id,
+            status: step.status,
+            quote_amount,
+            channel: step.channel,
+            decided_by,
+            notes,
+            recorded_by: employee.employee_id,
+        },
+    )
+    .await?
+    .ok_or_else(|| AppError::conflict("Quote changed while recording; reload and retry"))?;
+
+    // 4. Record the change in field history for the timeline
+    FieldHistoryRepository::create(
+        &state.db,
+        CreateFieldHistory {
+            ticket_id,
+            field_name: "quote_status".to_string(),
+            old_value: Some(ticket.quote_status.as_str().to_string()),
+            new_value: Some(step.status.as_str().to_string()),
+            changed_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    // 5. Build the response
+    let threshold =
+        StoreSettingsRepository::get_quote_approval_threshold(&state.db, ticket.store_id).await?;
+    Ok(TicketQuoteResponse {
+        ticket_id,
+        quote_amount: Some(quote_amount),
+        quote_status: step.status,
+        approval_required: quote_approval_required(Some(quote_amount), threshold),
+        events: QuoteRepository::find_by_ticket_id(&state.db, ticket_id).await?,
+    })
+}
+
+// =============================================================================
+// GET /queue - Workboard Queue
+// =============================================================================
+
+/// A single lane in the workboard queue.
+#[derive(Debug, Clone, Serialize)]
+pub struct QueueLane {
+    /// Number of tickets in this lane.
+    pub count: usize,
+    /// Tickets in this lane, sorted by rush, then manual position, then FIFO.
+    pub tickets: Vec<QueueTicket>,
+}
+
+/// All lanes in the workboard queue.
+#[derive(Debug, Clone, Serialize)]
+pub struct QueueLanes {
+    pub intake: QueueLane,
+    pub in_progress: QueueLane,
+    pub waiting_on_parts: QueueLane,
+    pub ready_for_pickup: QueueLane,
+}
+
+/// Response for the GET /queue endpoint.
+#[derive(Debug, Clone, Serialize)]
+pub struct GetQueueResponse {
+    pub lanes: QueueLanes,
+}
+
+/// Query parameters for the workboard queue.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct QueueQuery {
+    /// Only tickets assigned to this employee
+    pub employee_id: Option<Uuid>,
+    /// Include soft-deleted tickets (admin only, default: false)
+    #[serde(default)]
+    pub include_deleted: bool,
+}
+
+/// GET /api/v1/queue - Get workboard queue with tickets grouped by status lane.
+///
+/// Returns tickets grouped by status for workboard display.
+/// Each lane is sorted by rush first, then manual queue position (set with
+/// POST /queue/:lane/reorder), then FIFO (oldest first).
+/// Excludes closed and archived tickets.
+/// Includes `is_overdue` flag for visual indicator. Only the current store's
+/// tickets are on its workboard.
+///
+/// Public endpoint - no authentication required for viewing the workboard.
+/// Operations (status changes, ticket creation) still require PIN authentication.
+/// When scoped ticket visibility is enabled, an employee session is required
+/// and staff only see their own tickets. `employee_id` narrows the lanes to
+/// tickets assigned to that employee, for a per-employee view. Soft-deleted
+/// tickets are included only with `include_deleted=true` and admin
+/// authentication. Training sessions see only training tickets.
+pub async fn get_queue(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    training: TrainingMode,
+    CurrentStore(store_id): CurrentStore,
+    Query(query): Query<QueueQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    require_admin_for_deleted(&state, &headers, query.include_deleted).await?;
+    let visible_to = visibility_scope(&state, &headers).await?;
+
+    // Use the repository method which handles grouping and sorting
+    let queue = TicketRepository::get_queue(
+        &state.db,
+        None,
+        Some(store_id),
+        visible_to,
+        query.employee_id,
+        query.include_deleted,
+        training.0,
+    )
+    .await?;
+
+    // Build response with count for each lane
+    let response = GetQueueResponse {
         lanes: QueueLanes {
             intake: QueueLane {
                 count: queue.intake.len(),
@@ -1220,6 +2864,153 @@ pub async fn get_queue(State(state): State<AppState>) -> Result<impl IntoRespons
     Ok(Json(ApiResponse::success(response)))
 }
 
+// =============================================================================
+// POST /queue/:lane/reorder - Reorder Workboard Lane
+// =============================================================================
+
+/// Request body for reordering a workboard lane.
+///
+/// Send either `ticket_ids` or `move`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReorderQueueRequest {
+    /// Tickets in their new order; lane tickets not listed follow in their
+    /// current order
+    pub ticket_ids: Option<Vec<Uuid>>,
+    /// Move a single ticket
+    #[serde(rename = "move")]
+    pub move_ticket: Option<MoveQueueTicket>,
+}
+
+/// Move one ticket within its lane.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MoveQueueTicket {
+    pub ticket_id: Uuid,
+    /// Place it right after this ticket (None = top of the lane)
+    pub after: Option<Uuid>,
+}
+
+/// Parse a workboard lane name.
+fn parse_lane(lane: &str) -> Result<TicketStatus, AppError> {
+    match lane {
+        "intake" => Ok(TicketStatus::Intake),
+        "in_progress" => Ok(TicketStatus::InProgress),
+        "waiting_on_parts" => Ok(TicketStatus::WaitingOnParts),
+        "ready_for_pickup" => Ok(TicketStatus::ReadyForPickup),
+        _ => Err(AppError::validation(format!(
+            "Unknown queue lane '{}'; expected intake, in_progress, waiting_on_parts, or ready_for_pickup",
+            lane
+        ))),
+    }
+}
+
+/// Apply a reorder request to a lane's current order.
+fn reorder_lane(current: &[Uuid], request: &ReorderQueueRequest) -> Result<Vec<Uuid>, AppError> {
+    let in_lane = |id: &Uuid| -> Result<(), AppError> {
+        if current.contains(id) {
+            Ok(())
+        } else {
+            Err(AppError::validation(format!(
+                "Ticket {} is not in this lane",
+                id
+            )))
+        }
+    };
+
+    match (&request.ticket_ids, &request.move_ticket) {
+        (Some(ticket_ids), None) => {
+            let mut order = Vec::with_capacity(current.len());
+            for id in ticket_ids {
+                in_lane(id)?;
+                if order.contains(id) {
+                    return Err(AppError::validation(format!(
+                        "Ticket {} is listed more than once",
+                        id
+                    )));
+                }
+                order.push(*id);
+            }
+            order.extend(current.iter().filter(|id| !ticket_ids.contains(id)));
+            Ok(order)
+        }
+        (None, Some(MoveQueueTicket { ticket_id, after })) => {
+            in_lane(ticket_id)?;
+            let mut order: Vec<Uuid> = current
+                .iter()
+                .filter(|id| *id != ticket_id)
+                .copied()
+                .collect();
+            let index = match after {
+                None => 0,
+                Some(after) if after == ticket_id => {
+                    return Err(AppError::validation(
+                        "A ticket cannot be moved after itself",
+                    ))
+                }
+                Some(after) => {
+                    in_lane(after)?;
+                    order.iter().position(|id| id == after).map_or(0, |i| i + 1)
+                }
+            };
+            order.insert(index, *ticket_id);
+            Ok(order)
+        }
+        _ => Err(AppError::validation("Send either ticket_ids or move")),
+    }
+}
+
+/// POST /api/v1/queue/:lane/reorder - Set the manual order of a workboard lane.
+///
+/// Accepts the lane's tickets in their new order (`ticket_ids`) or a single
+/// move (`move: {ticket_id, after}`). Every ticket in the lane is numbered
+/// so the order is stable; rush tickets still sort ahead of the rest, and a
+/// ticket that changes lanes loses its position. Returns the reordered lane.
+/// Requires the modify_any_ticket permission. Only the current store's lane
+/// is reordered; training sessions reorder the training lane.
+///
+/// # Errors
+/// - UNAUTHORIZED: If there is no valid employee session
+/// - FORBIDDEN: Without the modify_any_ticket permission
+/// - VALIDATION_ERROR: If the lane is unknown or a ticket isn't in it
+pub async fn reorder_queue(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    training: TrainingMode,
+    CurrentStore(store_id): CurrentStore,
+    Path(lane): Path<String>,
+    Json(body): Json<ReorderQueueRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let employee = extract_employee_from_session(&state, &headers).await?;
+    require_permission(&employee, Permission::ModifyAnyTicket)?;
+    let status = parse_lane(&lane)?;
+
+    let current = TicketRepository::lane_order(&state.db, store_id, status, training.0).await?;
+    let order = reorder_lane(&current, &body)?;
+    TicketRepository::set_queue_positions(&state.db, status, &order).await?;
+
+    let queue = TicketRepository::get_queue(
+        &state.db,
+        None,
+        Some(store_id),
+        None,
+        None,
+        false,
+        training.0,
+    )
+    .await?;
+    let tickets = match status {
+        TicketStatus::Intake => queue.intake,
+        TicketStatus::InProgress => queue.in_progress,
+        TicketStatus::WaitingOnParts => queue.waiting_on_parts,
+        // parse_lane only returns open lanes
+        _ => queue.ready_for_pickup,
+    };
+
+    Ok(Json(ApiResponse::success(QueueLane {
+        count: tickets.len(),
+        tickets,
+    })))
+}
+
 // =============================================================================
 // POST /tickets/:ticket_id/close - Close Ticket
 // =============================================================================
@@ -1229,6 +3020,19 @@ pub async fn get_queue(State(state): State<AppState>) -> Result<impl IntoRespons
 pub struct CloseTicketRequest {
     /// The actual amount charged for the repair work (required)
     pub actual_amount: Decimal,
+
+    /// Custody witness (required when releasing a high-value item)
+    pub custody: Option<CustodyWitness>,
+
+    /// Final payment taken at pickup
+    pub payment: Option<PaymentInput>,
+
+    /// Close even though payments don't cover the actual amount
+    #[serde(default)]
+    pub allow_balance_due: bool,
+
+    /// Who collected the item (defaults to the customer, without ID or signature)
+    pub pickup: Option<PickupInput>,
 }
 
 /// Response for a closed ticket.
@@ -1239,6 +3043,22 @@ pub struct CloseTicketResponse {
     pub ticket: Ticket,
     /// The previous status before closing
     pub previous_status: TicketStatus,
+    /// The final payment taken at close, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment: Option<TicketPayment>,
+    /// The actual amount split into subtotal, tax, and total
+    pub tax: TaxBreakdown,
+    /// Total of all payments on the ticket
+    pub total_paid: Decimal,
+    /// Amount still owed after close
+    pub balance_due: Decimal,
+    /// Who collected the item, if pickup details were sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pickup: Option<TicketPickupEntry>,
+    /// The ticket's invoice number, e.g. `INV-000042`
+    pub invoice_number: String,
+    /// URL to download the invoice PDF
+    pub invoice_url: String,
 }
 
 /// POST /api/v1/tickets/:ticket_id/close - Close a ticket.
@@ -1246,7 +3066,13 @@ pub struct CloseTicketResponse {
 /// Closes the ticket with the actual amount charged.
 /// Requires X-Employee-ID header for attribution.
 /// Only tickets with status ReadyForPickup can be closed.
-/// Only administrators can close tickets.
+/// Requires the close_any_ticket permission.
+/// Deposits plus the final payment must cover the actual amount with tax
+/// unless `allow_balance_due` is set; the balance is then recorded as a note.
+/// With `pickup`, the release is recorded with who collected the item; a
+/// third party must hold an active authorization and show ID and sign.
+/// The first close numbers the ticket's invoice; every close records the
+/// tax at the store's current rate on it.
 pub async fn close_ticket(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -1256,13 +3082,13 @@ pub async fn close_ticket(
     // 1. Extract and validate employee from session
     let employee = extract_employee_from_session(&state, &headers).await?;
 
-    // 2. Authorization check: only admins can close tickets
+    // 2. Authorization check: requires the close_any_ticket permission
     can_close_ticket(&employee)?;
 
     // 3. Find the ticket
     let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     let previous_status = existing_ticket.status;
 
@@ -1274,18 +3100,59 @@ pub async fn close_ticket(
         )));
     }
 
-    // 5. Close the ticket
+    // 5. An item in transit can't be released
+    ensure_not_in_transit(&state, ticket_id).await?;
+
+    // 6. Releasing a high-value item needs a witnessed custody event
+    let threshold =
+        StoreSettingsRepository::get_custody_value_threshold(&state.db, existing_ticket.store_id)
+            .await?;
+    let custody = custody_witness(
+        &state,
+        employee.employee_id,
+        body.custody.as_ref(),
+        existing_ticket.requires_custody(threshold),
+    )
+    .await?;
+
+    // 7. Payments must cover the amount with tax unless a balance due is allowed
+    let tax = StoreSettingsRepository::get_tax_rate(&state.db, existing_ticket.store_id)
+        .await?
+        .apply(body.actual_amount);
+    let payment = body.payment.as_ref().map(validate_payment).transpose()?;
+    let (paid_before, _) = PaymentRepository::totals(&state.db, ticket_id).await?;
+    let total_paid = paid_before + payment.as_ref().map_or(Decimal::ZERO, |p| p.amount);
+    let balance = balance_due(Some(tax.total), total_paid).unwrap_or_default();
+    if balance > Decimal::ZERO && !body.allow_balance_due {
+        return Err(AppError::payment_required(format!(
+            "Payments of {:.2} do not cover the total of {:.2}",
+            total_paid, tax.total
+        )));
+    }
+
+    // 8. Check who is collecting the item
+    let pickup = match &body.pickup {
+        Some(input) => {
+            Some(validate_pickup(&state, &existing_ticket, input, employee.employee_id).await?)
+        }
+        None => None,
+    };
+
+    // 9. Close the ticket; steps 9-14 run in one transaction
+    let mut tx = state.db.begin().await?;
     let closed_ticket = TicketRepository::close(
-        &state.db,
+        &mut *tx,
         ticket_id,
         body.actual_amount,
         employee.employee_id,
     )
     .await?;
+    // The item has left the bench, so anyone still clocked in on it stops
+    TimeEntryRepository::stop_all_for_ticket(&mut *tx, ticket_id).await?;
 
-    // 6. Create status history entry
+    // 10. Create status history entry
     StatusHistoryRepository::create(
-        &state.db,
+        &mut *tx,
         CreateStatusHistory {
             ticket_id,
             from_status: Some(previous_status),
@@ -1295,239 +3162,1775 @@ pub async fn close_ticket(
     )
     .await?;
 
-    // 7. Return closed ticket with previous status
+    // 11. Close the chain of custody
+    if let Some(custody) = custody {
+        CustodyRepository::create(
+            &mut *tx,
+            CreateCustodyEvent {
+                ticket_id,
+                event_type: CustodyEventType::Release,
+                from_location_id: Some(closed_ticket.storage_location_id),
+                to_location_id: None,
+                handled_by: employee.employee_id,
+                witnessed_by: Some(custody.witnessed_by),
+                notes: custody.notes,
+            },
+        )
+        .await?;
+    }
+
+    // 12. Record the final payment and any balance left owing
+    let payment = match payment {
+        Some(payment) => Some(
+            record_ticket_payment(
+                &mut *tx,
+                ticket_id,
+                PaymentKind::Final,
+                payment,
+                employee.employee_id,
+            )
+            .await?,
+        ),
+        None => None,
+    };
+    if balance > Decimal::ZERO {
+        TicketNoteRepository::create(
+            &mut *tx,
+            CreateTicketNote {
+                ticket_id,
+                content: format!("Closed with balance due of ${:.2}", balance),
+                created_by: employee.employee_id,
+            },
+        )
+        .await?;
+    }
+
+    // 13. Record who collected the item
+    let pickup = match pickup {
+        Some(pickup) => Some(PickupRepository::create_pickup(&mut tx, pickup).await?),
+        None => None,
+    };
+
+    // 14. Record the invoice with the tax as of now
+    let invoice = record_invoice(&mut tx, ticket_id, tax).await?;
+    tx.commit().await?;
+
+    // 15. Notify webhook subscribers
+    webhooks::ticket_status_changed(&state.db, &closed_ticket, previous_status).await;
+
+    // 16. Return closed ticket with previous status
     let response = CloseTicketResponse {
+        invoice_url: format!("/api/v1/tickets/{}/invoice.pdf", ticket_id),
+        invoice_number: invoice.code(),
+        tax,
         ticket: closed_ticket,
         previous_status,
+        payment,
+        total_paid,
+        balance_due: balance,
+        pickup,
     };
 
     Ok(Json(ApiResponse::success(response)))
 }
 
 // =============================================================================
-// POST /tickets/:ticket_id/status - Change Ticket Status
+// POST /tickets/:ticket_id/reopen - Reopen Closed Ticket (Admin Only)
 // =============================================================================
 
-/// Request body for changing ticket status.
+/// Request body for reopening a ticket.
 #[derive(Debug, Clone, Deserialize)]
-pub struct ChangeStatusRequest {
-    /// The new status to set
-    pub status: TicketStatus,
+pub struct ReopenTicketRequest {
+    /// Why the ticket is being reopened (required, saved as a note)
+    pub reason: String,
 }
 
-/// Response for a status change.
+/// Response for a reopened ticket.
 #[derive(Debug, Clone, Serialize)]
-pub struct ChangeStatusResponse {
-    /// The updated ticket
+pub struct ReopenTicketResponse {
+    /// The reopened ticket
     #[serde(flatten)]
     pub ticket: Ticket,
-    /// The previous status
+    /// The previous status before reopening
     pub previous_status: TicketStatus,
+    /// The note recording the reason
+    pub note: TicketNoteModel,
 }
 
-/// POST /api/v1/tickets/:ticket_id/status - Change ticket status.
+/// POST /api/v1/tickets/:ticket_id/reopen - Reopen a closed ticket (admin only).
 ///
-/// Validates the status transition and records it in the status history.
-/// Requires X-Employee-ID header for attribution.
-/// Staff can only change status on tickets they own. Admins can change any.
-pub async fn change_status(
+/// Used when a customer brings back a failed repair. Moves the ticket from
+/// Closed back to InProgress, clears closed_at/closed_by, and records the
+/// reason as a note.
+/// Requires admin authentication (X-Admin-Session or X-Admin-PIN header).
+/// The change is attributed to the X-Employee-Session employee, if sent.
+pub async fn reopen_ticket(
     State(state): State<AppState>,
     headers: HeaderMap,
     Path(ticket_id): Path<Uuid>,
-    Json(body): Json<ChangeStatusRequest>,
+    Json(body): Json<ReopenTicketRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    // 1. Extract and validate employee from session
-    let employee = extract_employee_from_session(&state, &headers).await?;
+    use crate::handlers::admin::verify_admin_auth;
 
-    // 2. Find the ticket
+    // 1. Verify admin authentication
+    verify_admin_auth(&state, &headers).await?;
+
+    // 2. Get the employee to attribute the change to
+    let employee = admin_actor(&state, &headers).await?;
+
+    // 3. Validate the reason
+    let reason = validate_required(&body.reason, "reason", MAX_NOTE_LENGTH)?;
+
+    // 4. Find the ticket
     let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(forbidden_ticket_error)?;
-
-    // 3. Authorization check: staff can only change status on their own tickets
-    require_ticket_access(&employee, &existing_ticket, Permission::ModifyOwnTicket)?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     let previous_status = existing_ticket.status;
 
-    // 4. Validate the status transition
-    if !previous_status.can_transition_to(body.status) {
+    // 5. Validate ticket is closed
+    if previous_status != TicketStatus::Closed {
+        return Err(AppError::validation(format!(
+            "Only tickets with status 'closed' can be reopened, current status is '{}'",
+            serde_json::to_string(&previous_status).unwrap_or_else(|_| "unknown".to_string())
+        )));
+    }
+
+    // 6. Reopen the ticket; steps 6-8 run in one transaction
+    let mut tx = state.db.begin().await?;
+    let reopened_ticket =
+        TicketRepository::reopen(&mut *tx, ticket_id, employee.employee_id).await?;
+
+    // 7. Create status history entry
+    StatusHistoryRepository::create(
+        &mut *tx,
+        CreateStatusHistory {
+            ticket_id,
+            from_status: Some(previous_status),
+            to_status: TicketStatus::InProgress,
+            changed_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    // 8. Record the reason as a note
+    let note = TicketNoteRepository::create(
+        &mut *tx,
+        CreateTicketNote {
+            ticket_id,
+            content: format!("Reopened: {}", reason),
+            created_by: employee.employee_id,
+        },
+    )
+    .await?;
+    tx.commit().await?;
+
+    // 9. Notify webhook subscribers
+    webhooks::ticket_status_changed(&state.db, &reopened_ticket, previous_status).await;
+
+    // 10. Return reopened ticket with previous status and the note
+    let response = ReopenTicketResponse {
+        ticket: reopened_ticket,
+        previous_status,
+        note,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+// =============================================================================
+// POST /tickets/:ticket_id/status - Change Ticket Status
+// =============================================================================
+
+/// Request body for changing ticket status.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeStatusRequest {
+    /// The new status to set
+    pub status: TicketStatus,
+}
+
+/// Response for a status change.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeStatusResponse {
+    /// The updated ticket
+    #[serde(flatten)]
+    pub ticket: Ticket,
+    /// The previous status
+    pub previous_status: TicketStatus,
+}
+
+/// Check that `employee` may move `ticket` to `status`: ticket access, a
+/// valid transition, a passing QC check where the store requires one, and
+/// the item type's intake photos before work starts.
+async fn check_status_change(
+    state: &AppState,
+    employee: &Employee,
+    ticket: &Ticket,
+    status: TicketStatus,
+) -> Result<(), AppError> {
+    // Staff can only change status on their own tickets
+    require_ticket_access(employee, ticket, Permission::ModifyOwnTicket)?;
+    let previous_status = ticket.status;
+
+    // Validate the status transition
+    if !previous_status.can_transition_to(status) {
         return Err(AppError::validation(format!(
             "Cannot transition from {} to {}",
             serde_json::to_string(&previous_status).unwrap_or_else(|_| "unknown".to_string()),
-            serde_json::to_string(&body.status).unwrap_or_else(|_| "unknown".to_string())
+            serde_json::to_string(&status).unwrap_or_else(|_| "unknown".to_string())
+        )));
+    }
+
+    // Enforce the QC gate when entering a QC-gated status
+    if status.requires_qc() {
+        let checklist =
+            StoreSettingsRepository::get_qc_checklist(&state.db, ticket.store_id).await?;
+        let latest = QcCheckRepository::find_latest(&state.db, ticket.ticket_id).await?;
+        if !qc_gate_satisfied(&checklist, latest.as_ref()) {
+            return Err(AppError::qc_required(
+                "A passing QC check is required before marking ready for pickup",
+            ));
+        }
+    }
+
+    // Quotes above the store's threshold need the customer's approval
+    // before work starts
+    if status == TicketStatus::InProgress && ticket.quote_status != QuoteStatus::Approved {
+        let threshold =
+            StoreSettingsRepository::get_quote_approval_threshold(&state.db, ticket.store_id)
+                .await?;
+        if quote_approval_required(ticket.quote_amount, threshold) {
+            return Err(AppError::approval_required(
+                "The customer must approve the quote before work starts",
+            ));
+        }
+    }
+
+    // Require the item type's intake photos before work starts
+    if previous_status == TicketStatus::Intake {
+        if let Some(item_type) = ticket.item_type.as_deref() {
+            if let Some(config) = ItemTypeRepository::find_by_name(&state.db, item_type).await? {
+                let photos =
+                    TicketPhotoRepository::count_by_ticket_id(&state.db, ticket.ticket_id).await?;
+                if photos < i64::from(config.required_photos) {
+                    return Err(AppError::validation(format!(
+                        "{} needs at least {} photos before work starts",
+                        config.name, config.required_photos
+                    )));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Text the customer that their item is ready for pickup.
+///
+/// Sent in the background so a slow or failing provider never blocks the
+/// status change; the outcome is recorded in the notification log.
+fn notify_ready_in_background(state: &AppState, ticket: &Ticket) {
+    let notifications = state.notifications.clone();
+    let pool = state.db.clone();
+    let ticket = ticket.clone();
+    state.shutdown.spawn(async move {
+        if let Err(err) = notifications.notify_ready_for_pickup(&pool, &ticket).await {
+            tracing::warn!(
+                "Failed to notify customer for ticket {}: {:?}",
+                ticket.friendly_code,
+                err
+            );
+        }
+    });
+}
+
+/// POST /api/v1/tickets/:ticket_id/status - Change ticket status.
+///
+/// Validates the status transition and records it in the status history.
+/// Moving to ready_for_pickup requires a passing QC check when the store
+/// has a QC checklist configured.
+/// Leaving intake requires the photos the ticket's configured item type
+/// asks for.
+/// Requires X-Employee-ID header for attribution.
+/// Staff can only change status on tickets they own. Admins can change any.
+pub async fn change_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<ChangeStatusRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Check access, the transition, and the QC and photo gates
+    check_status_change(&state, &employee, &existing_ticket, body.status).await?;
+    let previous_status = existing_ticket.status;
+
+    // 4. Update the ticket status; steps 4-5 run in one transaction
+    let mut tx = state.db.begin().await?;
+    let updated_ticket =
+        TicketRepository::update_status(&mut *tx, ticket_id, body.status, employee.employee_id)
+            .await?;
+
+    // 5. Create status history entry
+    StatusHistoryRepository::create(
+        &mut *tx,
+        CreateStatusHistory {
+            ticket_id,
+            from_status: Some(previous_status),
+            to_status: body.status,
+            changed_by: employee.employee_id,
+        },
+    )
+    .await?;
+    tx.commit().await?;
+
+    // 6. Text the customer when the item becomes ready for pickup
+    if body.status == TicketStatus::ReadyForPickup {
+        notify_ready_in_background(&state, &updated_ticket);
+    }
+
+    // 7. Notify webhook subscribers
+    webhooks::ticket_status_changed(&state.db, &updated_ticket, previous_status).await;
+
+    // 8. Return updated ticket with previous status
+    let response = ChangeStatusResponse {
+        ticket: updated_ticket,
+        previous_status,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+// =============================================================================
+// POST /tickets/bulk/status - Bulk Status Change
+// =============================================================================
+
+/// Most tickets one bulk status change may include.
+pub const MAX_BULK_STATUS_TICKETS: usize = 100;
+
+/// Request body for changing the status of several tickets.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulkStatusRequest {
+    /// Tickets to change (duplicates are ignored)
+    pub ticket_ids: Vec<Uuid>,
+    /// The new status to set
+    pub status: TicketStatus,
+}
+
+/// Outcome for one ticket in a bulk status change.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkStatusResult {
+    pub ticket_id: Uuid,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_status: Option<TicketStatus>,
+    /// Why the ticket wasn't changed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorDetail>,
+}
+
+impl BulkStatusResult {
+    fn failed(ticket_id: Uuid, err: AppError) -> Self {
+        BulkStatusResult {
+            ticket_id,
+            success: false,
+            friendly_code: None,
+            previous_status: None,
+            error: Some(ErrorDetail {
+                code: err.code(),
+                message: err.message().to_string(),
+                request_id: None,
+            }),
+        }
+    }
+}
+
+/// Response for a bulk status change.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkStatusResponse {
+    pub status: TicketStatus,
+    pub succeeded: usize,
+    pub failed: usize,
+    /// One result per ticket, in request order
+    pub results: Vec<BulkStatusResult>,
+}
+
+/// Drop repeated IDs, keeping the first occurrence.
+fn dedupe_ticket_ids(ticket_ids: Vec<Uuid>) -> Vec<Uuid> {
+    let mut seen = HashSet::new();
+    ticket_ids
+        .into_iter()
+        .filter(|id| seen.insert(*id))
+        .collect()
+}
+
+/// POST /api/v1/tickets/bulk/status - Change the status of several tickets.
+///
+/// Each ticket is checked as in the single-ticket status change (access,
+/// transition, QC gate, intake photos). The tickets that pass are updated,
+/// with their status history, in one transaction; the rest are reported as
+/// failed with the reason. Returns one result per ticket.
+///
+/// # Errors
+/// - UNAUTHORIZED: If there is no valid employee session
+/// - VALIDATION_ERROR: If no tickets or more than 100 are sent
+pub async fn bulk_change_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(body): Json<BulkStatusRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    let ticket_ids = dedupe_ticket_ids(body.ticket_ids);
+    if ticket_ids.is_empty() || ticket_ids.len() > MAX_BULK_STATUS_TICKETS {
+        return Err(AppError::validation(format!(
+            "ticket_ids must list between 1 and {} tickets",
+            MAX_BULK_STATUS_TICKETS
         )));
     }
 
-    // 5. Update the ticket status
+    // Check each ticket; only the ones that pass are changed
+    let mut failures = HashMap::new();
+    let mut changes = Vec::new();
+    for ticket_id in &ticket_ids {
+        let checked = match TicketRepository::find_by_id(&state.db, *ticket_id).await? {
+            Some(ticket) => check_status_change(&state, &employee, &ticket, body.status)
+                .await
+                .map(|()| ticket.status),
+            None => Err(state.probe_policy.missing("ticket")),
+        };
+        match checked {
+            Ok(from_status) => changes.push((*ticket_id, from_status)),
+            Err(err) => {
+                failures.insert(*ticket_id, err);
+            }
+        }
+    }
+
+    let updated = TicketRepository::update_status_bulk(
+        &state.db,
+        &changes,
+        body.status,
+        employee.employee_id,
+    )
+    .await?;
+    let previous: HashMap<Uuid, TicketStatus> = changes.into_iter().collect();
+    let mut updated: HashMap<Uuid, Ticket> =
+        updated.into_iter().map(|t| (t.ticket_id, t)).collect();
+
+    for ticket in updated.values() {
+        if body.status == TicketStatus::ReadyForPickup {
+            notify_ready_in_background(&state, ticket);
+        }
+        if let Some(from_status) = previous.get(&ticket.ticket_id) {
+            webhooks::ticket_status_changed(&state.db, ticket, *from_status).await;
+        }
+    }
+
+    let results: Vec<BulkStatusResult> = ticket_ids
+        .into_iter()
+        .map(|ticket_id| match updated.remove(&ticket_id) {
+            Some(ticket) => BulkStatusResult {
+                ticket_id,
+                success: true,
+                friendly_code: Some(ticket.friendly_code),
+                previous_status: previous.get(&ticket_id).copied(),
+                error: None,
+            },
+            None => {
+                let err = failures.remove(&ticket_id).unwrap_or_else(|| {
+                    AppError::conflict("Ticket status changed while updating; reload and retry")
+                });
+                BulkStatusResult::failed(ticket_id, err)
+            }
+        })
+        .collect();
+    let succeeded = results.iter().filter(|r| r.success).count();
+
+    Ok(Json(ApiResponse::success(BulkStatusResponse {
+        status: body.status,
+        succeeded,
+        failed: results.len() - succeeded,
+        results,
+    })))
+}
+
+// =============================================================================
+// POST /tickets/:ticket_id/rush - Toggle Rush Flag
+// =============================================================================
+
+/// Request body for toggling rush flag.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ToggleRushRequest {
+    /// The new rush status to set
+    pub is_rush: bool,
+}
+
+/// Response for a rush flag toggle.
+#[derive(Debug, Clone, Serialize)]
+pub struct ToggleRushResponse {
+    /// The updated ticket
+    #[serde(flatten)]
+    pub ticket: Ticket,
+    /// The previous rush status
+    pub previous_is_rush: bool,
+}
+
+/// POST /api/v1/tickets/:ticket_id/rush - Toggle rush flag on a ticket.
+///
+/// Sets the is_rush flag and records the change in field history.
+/// Requires X-Employee-ID header for attribution.
+/// Staff can only toggle rush on tickets they own. Admins can toggle any.
+pub async fn toggle_rush(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<ToggleRushRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Authorization check: staff can only toggle rush on their own tickets
+    require_ticket_access(&employee, &existing_ticket, Permission::ModifyOwnTicket)?;
+
+    let previous_is_rush = existing_ticket.is_rush;
+
+    // 4. Check if ticket is closed/archived
+    if !existing_ticket.status.is_open() {
+        return Err(AppError::forbidden(
+            "Cannot modify rush flag on closed or archived ticket",
+        ));
+    }
+
+    // 5. Skip update if value is the same
+    if previous_is_rush == body.is_rush {
+        let response = ToggleRushResponse {
+            ticket: existing_ticket,
+            previous_is_rush,
+        };
+        return Ok(Json(ApiResponse::success(response)));
+    }
+
+    // 6. Update the rush flag
     let updated_ticket =
-        TicketRepository::update_status(&state.db, ticket_id, body.status, employee.employee_id)
+        TicketRepository::set_rush(&state.db, ticket_id, body.is_rush, employee.employee_id)
             .await?;
 
-    // 6. Create status history entry
-    StatusHistoryRepository::create(
+    // 7. Record field change in history
+    FieldHistoryRepository::create(
+        &state.db,
+        CreateFieldHistory {
+            ticket_id,
+            field_name: "is_rush".to_string(),
+            old_value: Some(previous_is_rush.to_string()),
+            new_value: Some(body.is_rush.to_string()),
+            changed_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    // 8. Return updated ticket with previous status
+    let response = ToggleRushResponse {
+        ticket: updated_ticket,
+        previous_is_rush,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+// =============================================================================
+// POST /tickets/:ticket_id/assign - Assign Ticket
+// =============================================================================
+
+/// Request body for assigning a ticket.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AssignTicketRequest {
+    /// Employee to assign; null unassigns the ticket
+    #[serde(default, deserialize_with = "deserialize_optional_nullable")]
+    pub employee_id: Option<Option<Uuid>>,
+    /// Text the assignee about it (if they have a phone number)
+    #[serde(default)]
+    pub notify: bool,
+}
+
+/// Response for a ticket assignment.
+#[derive(Debug, Clone, Serialize)]
+pub struct AssignTicketResponse {
+    /// The updated ticket
+    #[serde(flatten)]
+    pub ticket: Ticket,
+    /// Who was working on the ticket before
+    pub previous_worked_by: Option<Uuid>,
+}
+
+/// POST /api/v1/tickets/:ticket_id/assign - Assign a ticket to an employee.
+///
+/// Sets who is working on the ticket (`worked_by`), or clears it when
+/// `employee_id` is null. The assignee must be an active employee. The
+/// change is recorded in field history and raises `ticket.assigned`. With
+/// `notify`, the assignee is texted unless they assigned themselves.
+/// Staff can only assign tickets they own. Admins can assign any.
+pub async fn assign_ticket(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<AssignTicketRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Validate input
+    let Some(assignee_id) = body.employee_id else {
+        return Err(AppError::validation(
+            "employee_id is required; send null to unassign",
+        ));
+    };
+
+    // 3. Find the ticket
+    let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 4. Authorization check: staff can only assign their own tickets
+    require_ticket_access(&employee, &existing_ticket, Permission::ModifyOwnTicket)?;
+
+    // 5. Check if ticket is closed/archived
+    if !existing_ticket.status.is_open() {
+        return Err(AppError::forbidden(
+            "Cannot assign a closed or archived ticket",
+        ));
+    }
+
+    // 6. The assignee must be an active employee
+    let assignee = match assignee_id {
+        Some(assignee_id) => Some(
+            EmployeeRepository::find_active_by_id(&state.db, assignee_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("Employee not found or inactive"))?,
+        ),
+        None => None,
+    };
+
+    // 7. Skip update if the assignee is the same
+    let previous_worked_by = existing_ticket.worked_by;
+    if previous_worked_by == assignee_id {
+        let response = AssignTicketResponse {
+            ticket: existing_ticket,
+            previous_worked_by,
+        };
+        return Ok(Json(ApiResponse::success(response)));
+    }
+
+    // 8. Update the assignee
+    let updated_ticket =
+        TicketRepository::set_worked_by(&state.db, ticket_id, assignee_id, employee.employee_id)
+            .await?;
+
+    // 9. Record field change in history
+    FieldHistoryRepository::create(
+        &state.db,
+        CreateFieldHistory {
+            ticket_id,
+            field_name: "worked_by".to_string(),
+            old_value: previous_worked_by.map(|id| id.to_string()),
+            new_value: assignee_id.map(|id| id.to_string()),
+            changed_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    webhooks::ticket_assigned(&state.db, &updated_ticket, previous_worked_by).await;
+
+    // 10. Text the assignee in the background; training tickets stay quiet
+    let phone = assignee
+        .filter(|assignee| body.notify && assignee.employee_id != employee.employee_id)
+        .and_then(|assignee| assignee.phone);
+    if let (Some(phone), false) = (phone, updated_ticket.is_training) {
+        let store_name =
+            StoreSettingsRepository::get_store_settings(&state.db, updated_ticket.store_id)
+                .await?
+                .store_name;
+        let message = assignment_text(&store_name, &employee.name, &updated_ticket);
+        let notifications = state.notifications.clone();
+        state.shutdown.spawn(async move {
+            match notifications
+                .send_to_staff(NotificationChannel::Sms, &phone, None, &message)
+                .await
+            {
+                DeliveryOutcome::Sent { .. } => {}
+                DeliveryOutcome::Skipped { reason } => {
+                    tracing::info!("Assignment text not sent: {}", reason)
+                }
+                outcome => tracing::warn!("Assignment text not sent: {:?}", outcome),
+            }
+        });
+    }
+
+    // 11. Return updated ticket with the previous assignee
+    let response = AssignTicketResponse {
+        ticket: updated_ticket,
+        previous_worked_by,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// Text telling an employee they were assigned a ticket.
+fn assignment_text(store_name: &str, assigned_by: &str, ticket: &Ticket) -> String {
+    let rush = if ticket.is_rush { " (rush)" } else { "" };
+    match ticket.promise_date {
+        Some(date) => format!(
+            "{}: {} assigned you {}{}: {}, due {}",
+            store_name,
+            assigned_by,
+            ticket.friendly_code,
+            rush,
+            ticket.item_description,
+            date.format("%b %-d")
+        ),
+        None => format!(
+            "{}: {} assigned you {}{}: {}",
+            store_name, assigned_by, ticket.friendly_code, rush, ticket.item_description
+        ),
+    }
+}
+
+// =============================================================================
+// POST /tickets/:ticket_id/notes - Add Note
+// =============================================================================
+
+/// Request body for adding a note to a ticket.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AddNoteRequest {
+    /// The note content (required)
+    pub content: String,
+}
+
+/// Response for a successfully created note.
+#[derive(Debug, Clone, Serialize)]
+pub struct AddNoteResponse {
+    /// The created note
+    #[serde(flatten)]
+    pub note: TicketNoteModel,
+}
+
+/// POST /api/v1/tickets/:ticket_id/notes - Add an internal note to a ticket.
+///
+/// Notes are append-only - no edit or delete available.
+/// Requires X-Employee-Session header for attribution.
+/// Any active employee (staff or admin) can add notes to any ticket.
+pub async fn add_note(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<AddNoteRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    require_permission(&employee, Permission::AddNotes)?;
+
+    // 2. Find the ticket (any employee who may add notes can add them to any ticket)
+    let _existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Validate and sanitize note content
+    let content = validate_required(&body.content, "content", MAX_NOTE_LENGTH)?;
+
+    // 4. Create the note
+    let note = TicketNoteRepository::create(
+        &state.db,
+        CreateTicketNote {
+            ticket_id,
+            content,
+            created_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    // 6. Return created note
+    let response = AddNoteResponse { note };
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(response))))
+}
+
+// =============================================================================
+// /tickets/:ticket_id/payments - Payments and Deposits
+// =============================================================================
+
+/// Request body for recording a payment.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordPaymentRequest {
+    /// Amount received (must be greater than 0)
+    pub amount: Decimal,
+    /// How the customer paid
+    pub method: PaymentMethod,
+    /// Deposit or final (defaults to final on closed tickets, deposit otherwise)
+    pub kind: Option<PaymentKind>,
+    /// Optional notes (e.g., check number)
+    pub notes: Option<String>,
+}
+
+/// Response for a recorded payment.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordPaymentResponse {
+    /// The recorded payment
+    #[serde(flatten)]
+    pub payment: TicketPayment,
+    /// Total of all payments on the ticket
+    pub total_paid: Decimal,
+    /// Amount still owed (null when the ticket has no price yet)
+    pub balance_due: Option<Decimal>,
+}
+
+/// Response for a ticket's payments.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketPaymentsResponse {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    /// Payments, oldest first
+    pub payments: Vec<TicketPaymentEntry>,
+    /// Total of all payments
+    pub total_paid: Decimal,
+    /// Total of deposits
+    pub deposit_total: Decimal,
+    /// What the ticket costs with tax: the actual amount once closed,
+    /// otherwise the quote
+    pub amount_due: Option<Decimal>,
+    /// Amount still owed (null when the ticket has no price yet)
+    pub balance_due: Option<Decimal>,
+}
+
+/// The amount a ticket costs: the actual amount once set, otherwise the quote.
+fn amount_due(ticket: &Ticket) -> Option<Decimal> {
+    ticket.actual_amount.or(ticket.quote_amount)
+}
+
+/// GET /api/v1/tickets/:ticket_id/payments - List a ticket's payments.
+pub async fn list_payments(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    let payments = PaymentRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+    let (total_paid, deposit_total) = PaymentRepository::totals(&state.db, ticket_id).await?;
+    let amount_due = ticket_tax(&state, &ticket).await?.map(|tax| tax.total);
+
+    let response = TicketPaymentsResponse {
+        ticket_id,
+        friendly_code: ticket.friendly_code,
+        payments,
+        total_paid,
+        deposit_total,
+        amount_due,
+        balance_due: balance_due(amount_due, total_paid),
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// POST /api/v1/tickets/:ticket_id/payments - Record a payment on a ticket.
+///
+/// Used for deposits taken after intake and for settling a balance left
+/// owing at close. Requires X-Employee-Session header for attribution.
+pub async fn record_payment(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<RecordPaymentRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Validate the payment
+    let payment = validate_payment(&PaymentInput {
+        amount: body.amount,
+        method: body.method,
+        notes: body.notes,
+    })?;
+    let kind = body.kind.unwrap_or(if ticket.status.is_open() {
+        PaymentKind::Deposit
+    } else {
+        PaymentKind::Final
+    });
+
+    // 4. Record it
+    let payment =
+        record_ticket_payment(&state.db, ticket_id, kind, payment, employee.employee_id).await?;
+
+    // 5. Return the payment with the updated balance
+    let (total_paid, _) = PaymentRepository::totals(&state.db, ticket_id).await?;
+    let amount_due = ticket_tax(&state, &ticket).await?.map(|tax| tax.total);
+    let response = RecordPaymentResponse {
+        payment,
+        total_paid,
+        balance_due: balance_due(amount_due, total_paid),
+    };
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(response))))
+}
+
+// =============================================================================
+// /tickets/:ticket_id/contacts - Customer Contact Log
+// =============================================================================
+
+/// Request body for logging a contact with the customer.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LogContactRequest {
+    pub channel: ContactChannel,
+    pub direction: ContactDirection,
+    pub outcome: ContactOutcome,
+    pub notes: Option<String>,
+}
+
+/// GET /api/v1/tickets/:ticket_id/contacts - List a ticket's customer contacts.
+pub async fn list_contacts(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    let contacts = ContactRepository::find_by_ticket_id(&state.db, ticket_id).await?;
+
+    Ok(Json(ApiResponse::success(contacts)))
+}
+
+/// POST /api/v1/tickets/:ticket_id/contacts - Log a contact with the customer.
+///
+/// Requires X-Employee-Session header for attribution.
+pub async fn log_contact(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<LogContactRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Validate and log the contact
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
+    let contact = ContactRepository::create(
+        &state.db,
+        CreateTicketContact {
+            ticket_id,
+            channel: body.channel,
+            direction: body.direction,
+            outcome: body.outcome,
+            notes,
+            contacted_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(contact))))
+}
+
+// =============================================================================
+// /tickets/:ticket_id/authorized-pickups - Authorized Pickups
+// =============================================================================
+
+/// Maximum size of a pickup signature image (256KB).
+const MAX_SIGNATURE_SIZE: usize = 256 * 1024;
+
+/// Request body for authorizing someone to collect an item.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateAuthorizedPickupRequest {
+    /// Name of the person (required)
+    pub name: String,
+    /// Relationship to the customer (e.g., "Spouse")
+    pub relationship: Option<String>,
+    pub phone: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Response for a ticket's authorized pickups and releases.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketPickupsResponse {
+    pub ticket_id: Uuid,
+    /// Authorizations, oldest first, including revoked ones
+    pub authorized_pickups: Vec<TicketAuthorizedPickup>,
+    /// Releases of the item, oldest first
+    pub pickups: Vec<TicketPickupEntry>,
+}
+
+/// Path parameters for a single authorization.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthorizedPickupPath {
+    pub ticket_id: Uuid,
+    pub authorized_pickup_id: Uuid,
+}
+
+/// Path parameters for a release's signature.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PickupSignaturePath {
+    pub ticket_id: Uuid,
+    pub pickup_id: Uuid,
+}
+
+/// GET /api/v1/tickets/:ticket_id/authorized-pickups - List authorizations and releases.
+pub async fn list_authorized_pickups(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    let authorized_pickups = PickupRepository::list_authorized(&state.db, ticket_id).await?;
+    let pickups = PickupRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+
+    Ok(Json(ApiResponse::success(TicketPickupsResponse {
+        ticket_id,
+        authorized_pickups,
+        pickups,
+    })))
+}
+
+/// POST /api/v1/tickets/:ticket_id/authorized-pickups - Authorize someone to collect the item.
+///
+/// Staff can only change tickets they own (taken_in_by or worked_by).
+/// Only open tickets accept new authorizations.
+pub async fn create_authorized_pickup(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<CreateAuthorizedPickupRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket and check access
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    require_ticket_access(&employee, &ticket, Permission::ModifyOwnTicket)?;
+    if !ticket.status.is_open() {
+        return Err(AppError::validation(
+            "Cannot authorize a pickup on a closed or archived ticket",
+        ));
+    }
+
+    // 3. Validate fields
+    let name = validate_required(&body.name, "name", MAX_NAME_LENGTH)?;
+    let relationship = validate_optional(
+        body.relationship.as_deref(),
+        "relationship",
+        MAX_RELATIONSHIP_LENGTH,
+    )?;
+    let phone = validate_phone(body.phone.as_deref(), MAX_PHONE_LENGTH)?;
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
+
+    // 4. Record the authorization
+    let authorized = PickupRepository::create_authorized(
+        &state.db,
+        CreateAuthorizedPickup {
+            ticket_id,
+            name,
+            relationship,
+            phone,
+            notes,
+            added_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(authorized))))
+}
+
+/// DELETE /api/v1/tickets/:ticket_id/authorized-pickups/:authorized_pickup_id - Revoke an authorization.
+///
+/// The authorization is kept on record with the time it was revoked.
+pub async fn revoke_authorized_pickup(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(path): Path<AuthorizedPickupPath>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket and check access
+    let ticket = TicketRepository::find_by_id(&state.db, path.ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    require_ticket_access(&employee, &ticket, Permission::ModifyOwnTicket)?;
+
+    // 3. Revoke it
+    let authorized = PickupRepository::revoke_authorized(
+        &state.db,
+        path.ticket_id,
+        path.authorized_pickup_id,
+        employee.employee_id,
+    )
+    .await?
+    .ok_or_else(|| state.probe_policy.missing("authorized pickup"))?;
+
+    Ok(Json(ApiResponse::success(authorized)))
+}
+
+/// GET /api/v1/tickets/:ticket_id/pickups/:pickup_id/signature - Signature captured at a release.
+///
+/// Returns the PNG image.
+pub async fn get_pickup_signature(
+    State(state): State<AppState>,
+    Path(path): Path<PickupSignaturePath>,
+) -> Result<Response, AppError> {
+    let signature = PickupRepository::find_signature(&state.db, path.ticket_id, path.pickup_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("signature"))?;
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "image/png")
+        .body(Body::from(signature))
+        .map_err(|e| AppError::server_error(format!("Failed to build response: {}", e)))
+}
+
+/// Check who is collecting a ticket's item and build the release record.
+///
+/// Without an authorization the customer is collecting the item and ID and
+/// signature are optional. Someone else must hold an active authorization
+/// on the ticket and show ID and sign.
+async fn validate_pickup(
+    state: &AppState,
+    ticket: &Ticket,
+    input: &PickupInput,
+    released_by: Uuid,
+) -> Result<CreateTicketPickup, AppError> {
+    let id_type = validate_optional(input.id_type.as_deref(), "id_type", MAX_ID_TYPE_LENGTH)?;
+    let id_last_four = input.id_number.as_deref().and_then(id_last_four);
+    let signature = input
+        .signature
+        .as_deref()
+        .map(decode_signature)
+        .transpose()?;
+
+    let (authorized_pickup_id, picked_up_by_name) = match input.authorized_pickup_id {
+        Some(authorized_pickup_id) => {
+            let authorized = PickupRepository::find_authorized(
+                &state.db,
+                ticket.ticket_id,
+                authorized_pickup_id,
+            )
+            .await?
+            .filter(TicketAuthorizedPickup::is_active)
+            .ok_or_else(|| {
+                AppError::validation("Authorized pickup is not active for this ticket")
+            })?;
+            if id_type.is_none() || id_last_four.is_none() || signature.is_none() {
+                return Err(AppError::validation(
+                    "ID type, ID number, and signature are required when someone other than the customer picks up",
+                ));
+            }
+            (Some(authorized.authorized_pickup_id), authorized.name)
+        }
+        None => {
+            let customer = CustomerRepository::find_by_id(&state.db, ticket.customer_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("Customer not found"))?;
+            (None, customer.name)
+        }
+    };
+
+    Ok(CreateTicketPickup {
+        ticket_id: ticket.ticket_id,
+        authorized_pickup_id,
+        picked_up_by_name,
+        id_type,
+        id_last_four,
+        signature,
+        released_by,
+    })
+}
+
+/// Decode a base64 PNG signature, with or without a `data:` URL prefix.
+fn decode_signature(encoded: &str) -> Result<Vec<u8>, AppError> {
+    let encoded = encoded.trim();
+    let encoded = encoded
+        .strip_prefix("data:image/png;base64,")
+        .unwrap_or(encoded);
+    let data = STANDARD
+        .decode(encoded)
+        .map_err(|_| AppError::validation("Signature must be a base64-encoded PNG image"))?;
+    if data.len() > MAX_SIGNATURE_SIZE {
+        return Err(AppError::validation(format!(
+            "Signature exceeds maximum size of {}KB",
+            MAX_SIGNATURE_SIZE / 1024
+        )));
+    }
+    if detect_image_format(&data) != Some(ImageFormat::Png) {
+        return Err(AppError::validation(
+            "Signature must be a base64-encoded PNG image",
+        ));
+    }
+    Ok(data)
+}
+
+// =============================================================================
+// POST /tickets/:ticket_id/qc - Record QC Check
+// =============================================================================
+
+/// Result for a single QC checklist item.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QcItemResult {
+    /// The checklist item text (must match a configured item)
+    pub item: String,
+    /// Whether the item passed
+    pub passed: bool,
+}
+
+/// Request body for recording a QC check.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordQcCheckRequest {
+    /// Results for every configured checklist item
+    pub items: Vec<QcItemResult>,
+    /// Optional notes from the checker
+    pub notes: Option<String>,
+    /// Defect reason code (required when any item fails)
+    pub defect_reason: Option<DefectReason>,
+}
+
+/// Response for a recorded QC check.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordQcCheckResponse {
+    /// The recorded check
+    #[serde(flatten)]
+    pub check: TicketQcCheck,
+    /// The defect recorded for a failed check
+    pub defect: Option<TicketDefect>,
+}
+
+/// Split QC item results into passed and failed items.
+///
+/// Every configured checklist item must be answered exactly once,
+/// and no unknown items are accepted. Results are returned in
+/// checklist order.
+fn partition_qc_results(
+    checklist: &[String],
+    results: &[QcItemResult],
+) -> Result<(Vec<String>, Vec<String>), AppError> {
+    let mut passed_items = Vec::new();
+    let mut failed_items = Vec::new();
+
+    for result in results {
+        let item = result.item.trim();
+        if !checklist.iter().any(|c| c == item) {
+            return Err(AppError::validation(format!(
+                "Unknown QC checklist item: {}",
+                item
+            )));
+        }
+        if results.iter().filter(|r| r.item.trim() == item).count() > 1 {
+            return Err(AppError::validation(format!(
+                "Duplicate QC checklist item: {}",
+                item
+            )));
+        }
+    }
+
+    for item in checklist {
+        let result = results
+            .iter()
+            .find(|r| r.item.trim() == item)
+            .ok_or_else(|| {
+                AppError::validation(format!("Missing result for QC checklist item: {}", item))
+            })?;
+        if result.passed {
+            passed_items.push(item.clone());
+        } else {
+            failed_items.push(item.clone());
+        }
+    }
+
+    Ok((passed_items, failed_items))
+}
+
+/// A failed QC check needs a defect reason; a passing one can't have one.
+fn check_defect_reason(failed: bool, reason: Option<DefectReason>) -> Result<(), AppError> {
+    match (failed, reason) {
+        (true, None) => Err(AppError::validation(
+            "defect_reason is required when a QC item fails",
+        )),
+        (false, Some(_)) => Err(AppError::validation(
+            "defect_reason is only allowed when a QC item fails",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// POST /api/v1/tickets/:ticket_id/qc - Record a QC checklist result.
+///
+/// Every configured checklist item must be answered. The check passes only
+/// if every item passed; a failed check requires a `defect_reason` and records
+/// a defect for rework reporting, and a passing check is refused one. The checker is taken from the employee
+/// session and the result is recorded in field history for the ticket timeline.
+/// Staff can only record QC on tickets they own. Admins can record any.
+pub async fn record_qc_check(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<RecordQcCheckRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Authorization check: staff can only record QC on their own tickets
+    require_ticket_access(&employee, &existing_ticket, Permission::ModifyOwnTicket)?;
+
+    // 4. Check if ticket is closed/archived
+    if !existing_ticket.status.is_open() {
+        return Err(AppError::forbidden(
+            "Cannot record QC on closed or archived ticket",
+        ));
+    }
+
+    // 5. Validate results against the configured checklist
+    let checklist =
+        StoreSettingsRepository::get_qc_checklist(&state.db, existing_ticket.store_id).await?;
+    if checklist.is_empty() {
+        return Err(AppError::validation("No QC checklist is configured"));
+    }
+    let (passed_items, failed_items) = partition_qc_results(&checklist, &body.items)?;
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
+    check_defect_reason(!failed_items.is_empty(), body.defect_reason)?;
+
+    // 6. Record the check
+    let check = QcCheckRepository::create(
+        &state.db,
+        CreateTicketQcCheck {
+            ticket_id,
+            passed_items,
+            failed_items,
+            notes,
+            checked_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    // 7. Record the result in field history for the timeline
+    let new_value = if check.passed {
+        "passed".to_string()
+    } else {
+        format!("failed: {}", check.failed_items.join(", "))
+    };
+    FieldHistoryRepository::create(
         &state.db,
-        CreateStatusHistory {
+        CreateFieldHistory {
             ticket_id,
-            from_status: Some(previous_status),
-            to_status: body.status,
+            field_name: "qc_check".to_string(),
+            old_value: None,
+            new_value: Some(new_value),
             changed_by: employee.employee_id,
         },
     )
     .await?;
 
-    // 7. Return updated ticket with previous status
-    let response = ChangeStatusResponse {
-        ticket: updated_ticket,
-        previous_status,
+    // 8. Record a defect for a failed check
+    let defect = match body.defect_reason {
+        Some(reason) => Some(
+            DefectRepository::create(
+                &state.db,
+                CreateTicketDefect {
+                    ticket_id,
+                    reason,
+                    source: DefectSource::QcFailure,
+                    qc_check_id: Some(check.check_id),
+                    notes: check.notes.clone(),
+                    recorded_by: employee.employee_id,
+                },
+            )
+            .await?,
+        ),
+        _ => None,
     };
 
-    Ok(Json(ApiResponse::success(response)))
+    let response = RecordQcCheckResponse { check, defect };
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(response))))
 }
 
 // =============================================================================
-// POST /tickets/:ticket_id/rush - Toggle Rush Flag
+// POST /tickets/:ticket_id/defects - Record Customer Return Defect
 // =============================================================================
 
-/// Request body for toggling rush flag.
+/// Request body for recording a customer return defect.
 #[derive(Debug, Clone, Deserialize)]
-pub struct ToggleRushRequest {
-    /// The new rush status to set
-    pub is_rush: bool,
+pub struct RecordDefectRequest {
+    /// Defect reason code
+    pub reason: DefectReason,
+    /// Optional details about the return
+    pub notes: Option<String>,
 }
 
-/// Response for a rush flag toggle.
+/// Response for a recorded defect.
 #[derive(Debug, Clone, Serialize)]
-pub struct ToggleRushResponse {
-    /// The updated ticket
+pub struct RecordDefectResponse {
+    /// The recorded defect
     #[serde(flatten)]
-    pub ticket: Ticket,
-    /// The previous rush status
-    pub previous_is_rush: bool,
+    pub defect: TicketDefect,
 }
 
-/// POST /api/v1/tickets/:ticket_id/rush - Toggle rush flag on a ticket.
+/// POST /api/v1/tickets/:ticket_id/defects - Record a defect for a customer return.
 ///
-/// Sets the is_rush flag and records the change in field history.
-/// Requires X-Employee-ID header for attribution.
-/// Staff can only toggle rush on tickets they own. Admins can toggle any.
-pub async fn toggle_rush(
+/// QC failures record their defects through the QC endpoint; this endpoint
+/// covers items the customer brings back. The defect is recorded in field
+/// history for the ticket timeline.
+/// Any active employee can record a return on any ticket.
+pub async fn record_defect(
     State(state): State<AppState>,
     headers: HeaderMap,
     Path(ticket_id): Path<Uuid>,
-    Json(body): Json<ToggleRushRequest>,
+    Json(body): Json<RecordDefectRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     // 1. Extract and validate employee from session
     let employee = extract_employee_from_session(&state, &headers).await?;
 
-    // 2. Find the ticket
-    let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+    // 2. Find the ticket (returns are handled at the counter by any employee)
+    let _existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(forbidden_ticket_error)?;
-
-    // 3. Authorization check: staff can only toggle rush on their own tickets
-    require_ticket_access(&employee, &existing_ticket, Permission::ModifyOwnTicket)?;
-
-    let previous_is_rush = existing_ticket.is_rush;
-
-    // 4. Check if ticket is closed/archived
-    if !existing_ticket.status.is_open() {
-        return Err(AppError::forbidden(
-            "Cannot modify rush flag on closed or archived ticket",
-        ));
-    }
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
-    // 5. Skip update if value is the same
-    if previous_is_rush == body.is_rush {
-        let response = ToggleRushResponse {
-            ticket: existing_ticket,
-            previous_is_rush,
-        };
-        return Ok(Json(ApiResponse::success(response)));
-    }
+    // 3. Validate notes
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
 
-    // 6. Update the rush flag
-    let updated_ticket =
-        TicketRepository::set_rush(&state.db, ticket_id, body.is_rush, employee.employee_id)
-            .await?;
+    // 4. Record the defect
+    let defect = DefectRepository::create(
+        &state.db,
+        CreateTicketDefect {
+            ticket_id,
+            reason: body.reason,
+            source: DefectSource::CustomerReturn,
+            qc_check_id: None,
+            notes,
+            recorded_by: employee.employee_id,
+        },
+    )
+    .await?;
 
-    // 7. Record field change in history
+    // 5. Record the defect in field history for the timeline
     FieldHistoryRepository::create(
         &state.db,
         CreateFieldHistory {
             ticket_id,
-            field_name: "is_rush".to_string(),
-            old_value: Some(previous_is_rush.to_string()),
-            new_value: Some(body.is_rush.to_string()),
+            field_name: "defect".to_string(),
+            old_value: None,
+            new_value: Some(format!("customer_return: {}", defect.reason.as_str())),
             changed_by: employee.employee_id,
         },
     )
     .await?;
 
-    // 8. Return updated ticket with previous status
-    let response = ToggleRushResponse {
-        ticket: updated_ticket,
-        previous_is_rush,
-    };
+    let response = RecordDefectResponse { defect };
 
-    Ok(Json(ApiResponse::success(response)))
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(response))))
 }
 
 // =============================================================================
-// POST /tickets/:ticket_id/notes - Add Note
+// /tickets/:ticket_id/custody - Chain of Custody
 // =============================================================================
 
-/// Request body for adding a note to a ticket.
-#[derive(Debug, Clone, Deserialize)]
-pub struct AddNoteRequest {
-    /// The note content (required)
-    pub content: String,
+/// Warn when a ticket is put in a location that is already at capacity.
+async fn location_warnings(
+    state: &AppState,
+    location_id: Uuid,
+) -> Result<Vec<ApiWarning>, AppError> {
+    Ok(
+        StorageLocationRepository::find_summary(&state.db, location_id)
+            .await?
+            .map(|location| location_capacity_warnings(&location))
+            .unwrap_or_default(),
+    )
 }
 
-/// Response for a successfully created note.
+/// Reject changes that need the item on hand while it is in transit.
+pub(crate) async fn ensure_not_in_transit(
+    state: &AppState,
+    ticket_id: Uuid,
+) -> Result<(), AppError> {
+    if TransferRepository::find_pending_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::conflict(
+            "Ticket is in transit; accept or cancel the transfer first",
+        ));
+    }
+    Ok(())
+}
+
+/// Validate custody witness details for a movement.
+///
+/// Returns the witness to record, or None when no custody event is needed.
+/// A witness is required for high-value items and must be an active
+/// employee other than the one handling the item.
+pub(crate) async fn custody_witness(
+    state: &AppState,
+    handled_by: Uuid,
+    custody: Option<&CustodyWitness>,
+    required: bool,
+) -> Result<Option<CustodyWitness>, AppError> {
+    let Some(custody) = custody else {
+        if required {
+            return Err(AppError::validation(
+                "custody.witnessed_by is required for high-value items",
+            ));
+        }
+        return Ok(None);
+    };
+
+    if custody.witnessed_by == handled_by {
+        return Err(AppError::validation(
+            "Custody witness must be a different employee",
+        ));
+    }
+    validate_employee(&state.db, custody.witnessed_by).await?;
+    let notes = validate_optional(custody.notes.as_deref(), "custody.notes", MAX_NOTE_LENGTH)?;
+
+    Ok(Some(CustodyWitness {
+        witnessed_by: custody.witnessed_by,
+        notes,
+    }))
+}
+
+/// Query parameters for a ticket's history feed.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TicketHistoryQuery {
+    /// Limit results (default: 100, max 500)
+    pub limit: Option<i64>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+}
+
+/// Response for a ticket's history feed.
 #[derive(Debug, Clone, Serialize)]
-pub struct AddNoteResponse {
-    /// The created note
-    #[serde(flatten)]
-    pub note: TicketNoteModel,
+pub struct TicketHistoryResponse {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    pub events: Vec<TicketHistoryEvent>,
+    pub pagination: PaginationInfo,
 }
 
-/// POST /api/v1/tickets/:ticket_id/notes - Add an internal note to a ticket.
+/// GET /api/v1/tickets/:ticket_id/history - Get the ticket's audit trail.
 ///
-/// Notes are append-only - no edit or delete available.
-/// Requires X-Employee-Session header for attribution.
-/// Any active employee (staff or admin) can add notes to any ticket.
-pub async fn add_note(
+/// Returns field changes, status changes, notes, and photo events merged in
+/// chronological order, each attributed to the employee responsible.
+pub async fn get_ticket_history(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+    Query(query): Query<TicketHistoryQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 2. Load one extra event to determine has_more
+    let limit = query.limit.unwrap_or(100).clamp(1, 500);
+    let offset = query.offset.unwrap_or(0).max(0);
+    let events = FieldHistoryRepository::list_feed(&state.db, ticket_id, limit + 1, offset).await?;
+
+    let has_more = events.len() as i64 > limit;
+    let events: Vec<TicketHistoryEvent> = events.into_iter().take(limit as usize).collect();
+
+    let response = TicketHistoryResponse {
+        ticket_id,
+        friendly_code: ticket.friendly_code,
+        pagination: PaginationInfo {
+            count: events.len(),
+            limit,
+            offset,
+            has_more,
+            next_cursor: None,
+        },
+        events,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// Response for a ticket's chain of custody.
+#[derive(Debug, Clone, Serialize)]
+pub struct CustodyChainResponse {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    /// Whether movements currently need a witnessed custody event
+    pub requires_custody: bool,
+    pub events: Vec<CustodyEventEntry>,
+}
+
+/// GET /api/v1/tickets/:ticket_id/custody - Get the chain of custody.
+pub async fn get_custody_chain(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 2. Load events with names
+    let threshold =
+        StoreSettingsRepository::get_custody_value_threshold(&state.db, ticket.store_id).await?;
+    let events = CustodyRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+
+    let response = CustodyChainResponse {
+        ticket_id,
+        requires_custody: ticket.requires_custody(threshold),
+        friendly_code: ticket.friendly_code,
+        events,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// Request body for recording a custody handoff.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordHandoffRequest {
+    /// Employee who witnessed the handoff (required for high-value items)
+    pub witnessed_by: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
+/// POST /api/v1/tickets/:ticket_id/custody - Record a custody handoff.
+///
+/// Records the item passing to the authenticated employee without a change
+/// of storage location (e.g., from the counter to a bench jeweler). Location
+/// changes, intake, and release are recorded automatically.
+/// Any active employee can record a handoff.
+pub async fn record_custody_handoff(
     State(state): State<AppState>,
     headers: HeaderMap,
     Path(ticket_id): Path<Uuid>,
-    Json(body): Json<AddNoteRequest>,
+    Json(body): Json<RecordHandoffRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     // 1. Extract and validate employee from session
     let employee = extract_employee_from_session(&state, &headers).await?;
 
-    // 2. Find the ticket (any active employee can add notes to any ticket)
-    let _existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+    // 2. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
-
-    // 3. Validate and sanitize note content
-    let content = validate_required(&body.content, "content", MAX_NOTE_LENGTH)?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Validate the witness (required for high-value items)
+    let threshold =
+        StoreSettingsRepository::get_custody_value_threshold(&state.db, ticket.store_id).await?;
+    let witness = body.witnessed_by.map(|witnessed_by| CustodyWitness {
+        witnessed_by,
+        notes: None,
+    });
+    let witness = custody_witness(
+        &state,
+        employee.employee_id,
+        witness.as_ref(),
+        ticket.requires_custody(threshold),
+    )
+    .await?;
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
 
-    // 4. Create the note
-    let note = TicketNoteRepository::create(
+    // 4. Record the handoff
+    let event: CustodyEvent = CustodyRepository::create(
         &state.db,
-        CreateTicketNote {
+        CreateCustodyEvent {
             ticket_id,
-            content,
-            created_by: employee.employee_id,
+            event_type: CustodyEventType::Handoff,
+            from_location_id: None,
+            to_location_id: None,
+            handled_by: employee.employee_id,
+            witnessed_by: witness.map(|w| w.witnessed_by),
+            notes,
         },
     )
     .await?;
 
-    // 6. Return created note
-    let response = AddNoteResponse { note };
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(event))))
+}
 
-    Ok((StatusCode::CREATED, Json(ApiResponse::success(response))))
+/// GET /api/v1/tickets/:ticket_id/custody.pdf - Generate chain-of-custody report PDF.
+pub async fn get_custody_report_pdf(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<Response, AppError> {
+    // 1. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 2. Find the customer
+    let customer = CustomerRepository::find_by_id(&state.db, ticket.customer_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("Customer not found"))?;
+
+    // 3. Load store name and events
+    let settings = StoreSettingsRepository::get_store_settings(&state.db, ticket.store_id).await?;
+    let events = CustodyRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+    let pickup_entries = PickupRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+    let mut pickups = Vec::with_capacity(pickup_entries.len());
+    for entry in pickup_entries {
+        let signature = if entry.has_signature {
+            PickupRepository::find_signature(&state.db, ticket_id, entry.pickup_id).await?
+        } else {
+            None
+        };
+        pickups.push(PickupReportEntry { entry, signature });
+    }
+
+    // 4. Generate PDF
+    let report_data = CustodyReportData {
+        ticket,
+        customer_name: customer.name,
+        store_name: settings.store_name,
+        events,
+        pickups,
+    };
+
+    let pdf_bytes = generate_custody_report_pdf(&report_data)?;
+
+    // 5. Return PDF response
+    let filename = format!("custody-{}.pdf", report_data.ticket.friendly_code);
+    let response = Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/pdf")
+        .header(
+            header::CONTENT_DISPOSITION,
+            format!("inline; filename=\"{}\"", filename),
+        )
+        .body(Body::from(pdf_bytes))
+        .map_err(|e| AppError::server_error(format!("Failed to build response: {}", e)))?;
+
+    Ok(response)
 }
 
 // =============================================================================
@@ -1556,7 +4959,9 @@ pub struct UploadPhotoResponse {
 /// POST /api/v1/tickets/:ticket_id/photos - Upload a photo to a ticket.
 ///
 /// Accepts multipart/form-data with a single file field named "photo".
-/// Validates file type (jpeg, png, webp) and size (max 10MB).
+/// Validates file type (jpeg, png, webp) and size (max 10MB). Unless
+/// `PROCESS_PHOTOS` is off, EXIF metadata is stripped and the orientation
+/// tag applied before the photo is stored.
 /// Requires X-Employee-Session header for attribution.
 /// Any active employee (staff or admin) can upload photos to any ticket.
 pub async fn upload_photo(
@@ -1568,10 +4973,12 @@ pub async fn upload_photo(
     // 1. Extract and validate employee from session
     let employee = extract_employee_from_session(&state, &headers).await?;
 
-    // 2. Find the ticket (any active employee can upload photos to any ticket)
+    require_permission(&employee, Permission::UploadPhotos)?;
+
+    // 2. Find the ticket (any employee who may upload photos can upload to any ticket)
     let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     // 3. Check photo limit
     let current_count = TicketPhotoRepository::count_by_ticket_id(&state.db, ticket_id).await?;
@@ -1582,10 +4989,7 @@ pub async fn upload_photo(
         )));
     }
 
-    // 5. Check if storage is available (S3 or local fallback)
-    let use_local_storage = state.storage.is_none();
-
-    // 6. Extract file from multipart form
+    // 4. Extract file from multipart form
     let mut file_data: Option<(String, Vec<u8>)> = None;
 
     while let Some(field) = multipart
@@ -1636,14 +5040,30 @@ pub async fn upload_photo(
     let (content_type, data) =
         file_data.ok_or_else(|| AppError::validation("No 'photo' field in request"))?;
 
-    // 7. Validate magic bytes match Content-Type
+    // 5. Validate magic bytes match Content-Type
     if !validate_image_content_type(&data, &content_type) {
         return Err(AppError::validation(
             "File content does not match declared Content-Type. Only JPEG, PNG, and WebP images are allowed.",
         ));
     }
 
-    // 8. Generate unique storage key
+    // 6. Strip EXIF metadata (GPS, camera details) and apply the orientation
+    let data = if state.process_photos {
+        let format = detect_image_format(&data).ok_or_else(|| {
+            AppError::server_error("Validated photo has no recognized image format")
+        })?;
+        tokio::task::spawn_blocking(move || process_photo(&data, format))
+            .await
+            .map_err(|e| AppError::server_error(format!("Photo processing task failed: {}", e)))?
+            .map_err(|e| {
+                tracing::debug!("Rejected unreadable photo upload: {}", e);
+                AppError::validation("The image could not be read. It may be corrupt or truncated.")
+            })?
+    } else {
+        data
+    };
+
+    // 7. Generate unique storage key
     let photo_id = Uuid::new_v4();
     let extension = match content_type.as_str() {
         "image/jpeg" => "jpg",
@@ -1653,43 +5073,21 @@ pub async fn upload_photo(
     };
     let storage_key = format!("tickets/{}/{}.{}", ticket.ticket_id, photo_id, extension);
 
-    // 9. Upload to storage (S3 or local fallback)
+    // 8. Upload to storage
     let file_size = data.len() as i32;
-    let url: String;
-
-    if use_local_storage {
-        // Local file storage fallback for development
-        let local_dir = std::path::Path::new("uploads")
-            .join("tickets")
-            .join(ticket.ticket_id.to_string());
-        std::fs::create_dir_all(&local_dir).map_err(|e| {
-            AppError::server_error(format!("Failed to create upload directory: {}", e))
-        })?;
-
-        let file_path = local_dir.join(format!("{}.{}", photo_id, extension));
-        std::fs::write(&file_path, &data)
-            .map_err(|e| AppError::server_error(format!("Failed to save photo: {}", e)))?;
-
-        // Return a local file path as the URL (for dev purposes)
-        url = format!(
-            "/uploads/tickets/{}/{}.{}",
-            ticket.ticket_id, photo_id, extension
-        );
-    } else {
-        // S3 storage
-        let storage = state.storage.as_ref().unwrap();
-        storage
-            .upload(&storage_key, data, &content_type)
-            .await
-            .map_err(|e| AppError::server_error(format!("Failed to upload photo: {}", e)))?;
+    state
+        .storage
+        .upload(&storage_key, data, &content_type)
+        .await
+        .map_err(|e| storage_error("Failed to upload photo", e))?;
 
-        url = storage
-            .get_signed_url(&storage_key, None)
-            .await
-            .map_err(|e| AppError::server_error(format!("Failed to generate signed URL: {}", e)))?;
-    }
+    let url = state
+        .storage
+        .get_signed_url(&storage_key, Some(state.photo_url_ttl))
+        .await
+        .map_err(|e| AppError::server_error(format!("Failed to generate signed URL: {}", e)))?;
 
-    // 10. Create database record
+    // 9. Create database record
     let photo = TicketPhotoRepository::create(
         &state.db,
         CreateTicketPhoto {
@@ -1702,17 +5100,51 @@ pub async fn upload_photo(
     )
     .await?;
 
-    // 11. Return response
-    let response = UploadPhotoResponse { photo, url };
+    // 10. Notify webhook subscribers
+    webhooks::photo_uploaded(&state.db, &ticket, &photo).await;
+
+    // 11. Return response
+    let response = UploadPhotoResponse { photo, url };
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(response))))
+}
+
+// =============================================================================
+// GET /tickets/:ticket_id/photos/:photo_id - Photo Redirect
+// =============================================================================
+
+/// GET /api/v1/tickets/:ticket_id/photos/:photo_id - Redirect to a photo.
+///
+/// Answers with a temporary redirect to a freshly signed URL for the photo,
+/// for clients holding a photo's API path rather than a signed URL.
+pub async fn get_photo(
+    State(state): State<AppState>,
+    Path(path): Path<DeletePhotoPath>,
+) -> Result<Response, AppError> {
+    // 1. Find the photo on a visible ticket
+    TicketRepository::find_by_id(&state.db, path.ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    let photo = TicketPhotoRepository::find_by_id(&state.db, path.photo_id)
+        .await?
+        .filter(|photo| photo.ticket_id == path.ticket_id)
+        .ok_or_else(|| state.probe_policy.missing("photo"))?;
+
+    // 2. Sign a URL and redirect to it
+    let url = state
+        .storage
+        .get_signed_url(&photo.storage_key, Some(state.photo_url_ttl))
+        .await
+        .map_err(|e| storage_error("Failed to generate signed URL", e))?;
 
-    Ok((StatusCode::CREATED, Json(ApiResponse::success(response))))
+    Ok(Redirect::temporary(&url).into_response())
 }
 
 // =============================================================================
 // DELETE /tickets/:ticket_id/photos/:photo_id - Delete Photo (Admin Only)
 // =============================================================================
 
-/// Path parameters for photo deletion.
+/// Path parameters for photo requests.
 #[derive(Debug, Clone, Deserialize)]
 pub struct DeletePhotoPath {
     pub ticket_id: Uuid,
@@ -1763,45 +5195,91 @@ async fn verify_admin_employee(
     Err(AppError::invalid_pin("Invalid admin PIN"))
 }
 
-/// DELETE /api/v1/tickets/:ticket_id/photos/:photo_id - Delete a photo (admin only).
+/// The employee to attribute an admin action to.
+///
+/// Uses the employee session sent alongside admin auth. Legacy clients that
+/// only send X-Admin-PIN are attributed to the admin employee with that PIN.
+pub(crate) async fn admin_actor(
+    state: &AppState,
+    headers: &HeaderMap,
+) -> Result<Employee, AppError> {
+    let has_employee =
+        headers.contains_key("X-Employee-Session") || headers.contains_key("X-Employee-ID");
+    if !has_employee && headers.contains_key("X-Admin-PIN") {
+        let admin_pin = extract_admin_pin(headers)?;
+        return verify_admin_employee(&state.db, &admin_pin).await;
+    }
+    extract_employee_from_session(state, headers).await
+}
+
+/// DELETE /api/v1/tickets/:ticket_id/photos/:photo_id - Delete a photo.
 ///
-/// Requires X-Admin-PIN header for authorization.
-/// Deletes the photo from S3 storage and the database.
+/// Requires an employee session with the delete_photos permission, or
+/// X-Admin-PIN.
+/// Deletes the photo from storage and the database, and records the
+/// removal in the ticket's field history.
 pub async fn delete_photo(
     State(state): State<AppState>,
     headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
     Path(path): Path<DeletePhotoPath>,
 ) -> Result<impl IntoResponse, AppError> {
-    // 1. Extract and verify admin PIN
-    let admin_pin = extract_admin_pin(&headers)?;
-    let _admin = verify_admin_employee(&state.db, &admin_pin).await?;
+    // 1. Identify the employee and check permission
+    let admin = admin_actor(&state, &headers).await?;
+    can_delete_photo(&admin)?;
 
     // 2. Verify ticket exists
     let _ticket = TicketRepository::find_by_id(&state.db, path.ticket_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     // 3. Find the photo
     let photo = TicketPhotoRepository::find_by_id(&state.db, path.photo_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Photo not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("photo"))?;
 
     // 4. Verify photo belongs to the ticket
     if photo.ticket_id != path.ticket_id {
-        return Err(AppError::not_found("Photo not found on this ticket"));
+        return Err(state.probe_policy.missing("photo"));
     }
 
-    // 5. Delete from S3 storage (if storage is configured)
-    if let Some(storage) = &state.storage {
-        storage.delete(&photo.storage_key).await.map_err(|e| {
-            AppError::server_error(format!("Failed to delete photo from storage: {}", e))
-        })?;
-    }
+    // 5. Delete from storage
+    state
+        .storage
+        .delete(&photo.storage_key)
+        .await
+        .map_err(|e| storage_error("Failed to delete photo from storage", e))?;
 
     // 6. Delete database record
     TicketPhotoRepository::delete(&state.db, path.photo_id).await?;
 
-    // 7. Return success response
+    // 7. Record the removal in field history
+    FieldHistoryRepository::create(
+        &state.db,
+        CreateFieldHistory {
+            ticket_id: path.ticket_id,
+            field_name: PHOTO_FIELD.to_string(),
+            old_value: Some(path.photo_id.to_string()),
+            new_value: None,
+            changed_by: admin.employee_id,
+        },
+    )
+    .await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::PhotoDeleted)
+            .actor(&admin)
+            .target("photo", path.photo_id)
+            .summary(serde_json::json!({
+                "ticket_id": path.ticket_id,
+                "storage_key": photo.storage_key,
+            })),
+    )
+    .await;
+
+    // 8. Return success response
     let response = DeletePhotoResponse {
         photo_id: path.photo_id,
         ticket_id: path.ticket_id,
@@ -1827,10 +5305,11 @@ pub struct DeleteTicketResponse {
 
 /// DELETE /api/v1/tickets/:ticket_id - Soft-delete a ticket (admin only).
 ///
-/// Requires admin authentication (X-Admin-Session or X-Admin-PIN header).
+/// Requires admin authentication (X-Admin-Session or X-Admin-PIN header); the
+/// deletion is attributed to the X-Employee-Session employee.
 /// The ticket is not permanently deleted; it is marked with a deleted_at timestamp
-/// and excluded from normal queries. Audit history (status_history, field_history)
-/// is preserved.
+/// and excluded from normal queries unless `include_deleted` is requested.
+/// Audit history (status_history, field_history) is preserved.
 pub async fn delete_ticket(
     State(state): State<AppState>,
     headers: HeaderMap,
@@ -1841,14 +5320,13 @@ pub async fn delete_ticket(
     // 1. Verify admin authentication
     verify_admin_auth(&state, &headers).await?;
 
-    // 2. Get an admin employee for attribution
-    let admin_pin = extract_admin_pin(&headers)?;
-    let admin = verify_admin_employee(&state.db, &admin_pin).await?;
+    // 2. Get the employee to attribute the change to
+    let admin = admin_actor(&state, &headers).await?;
 
     // 3. Verify ticket exists and is not already deleted
     let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     // 4. Soft-delete the ticket
     let deleted_ticket = TicketRepository::soft_delete(&state.db, ticket_id, admin.employee_id)
@@ -1893,7 +5371,8 @@ pub struct RestoreTicketResponse {
 
 /// POST /api/v1/tickets/:ticket_id/restore - Restore a soft-deleted ticket (admin only).
 ///
-/// Requires admin authentication (X-Admin-Session or X-Admin-PIN header).
+/// Requires admin authentication (X-Admin-Session or X-Admin-PIN header); the
+/// restore is attributed to the X-Employee-Session employee.
 /// Clears the deleted_at and deleted_by fields, making the ticket visible again.
 pub async fn restore_ticket(
     State(state): State<AppState>,
@@ -1905,14 +5384,13 @@ pub async fn restore_ticket(
     // 1. Verify admin authentication
     verify_admin_auth(&state, &headers).await?;
 
-    // 2. Get an admin employee for attribution
-    let admin_pin = extract_admin_pin(&headers)?;
-    let admin = verify_admin_employee(&state.db, &admin_pin).await?;
+    // 2. Get the employee to attribute the change to
+    let admin = admin_actor(&state, &headers).await?;
 
     // 3. Verify ticket exists and is deleted (using including_deleted)
     let ticket = TicketRepository::find_by_id_including_deleted(&state.db, ticket_id)
         .await?
-        .ok_or_else(|| AppError::not_found("Ticket not found"))?;
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
 
     if !ticket.is_deleted() {
         return Err(AppError::validation("Ticket is not deleted"));
@@ -1945,9 +5423,171 @@ pub async fn restore_ticket(
     Ok(Json(ApiResponse::success(response)))
 }
 
+// =============================================================================
+// POST /tickets/:ticket_id/archive - Archive Closed Ticket (Admin Only)
+// =============================================================================
+
+/// POST /api/v1/tickets/:ticket_id/archive - Archive a closed ticket (admin only).
+///
+/// Requires admin authentication (X-Admin-Session or X-Admin-PIN header).
+/// The change is attributed to the X-Employee-Session employee, if sent.
+/// Only closed tickets can be archived. Closed tickets are also archived
+/// automatically once they are older than the store's `auto_archive_after_days`.
+pub async fn archive_ticket(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    use crate::handlers::admin::verify_admin_auth;
+
+    // 1. Verify admin authentication
+    verify_admin_auth(&state, &headers).await?;
+
+    // 2. Get the employee to attribute the change to
+    let employee = admin_actor(&state, &headers).await?;
+
+    // 3. Find the ticket
+    let existing_ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    let previous_status = existing_ticket.status;
+
+    // 4. Validate ticket is closed
+    if previous_status != TicketStatus::Closed {
+        return Err(AppError::validation(format!(
+            "Only tickets with status 'closed' can be archived, current status is '{}'",
+            serde_json::to_string(&previous_status).unwrap_or_else(|_| "unknown".to_string())
+        )));
+    }
+
+    // 5. Archive the ticket
+    let archived_ticket = TicketRepository::update_status(
+        &state.db,
+        ticket_id,
+        TicketStatus::Archived,
+        employee.employee_id,
+    )
+    .await?;
+
+    // 6. Create status history entry
+    StatusHistoryRepository::create(
+        &state.db,
+        CreateStatusHistory {
+            ticket_id,
+            from_status: Some(previous_status),
+            to_status: TicketStatus::Archived,
+            changed_by: employee.employee_id,
+        },
+    )
+    .await?;
+
+    // 7. Notify webhook subscribers
+    webhooks::ticket_status_changed(&state.db, &archived_ticket, previous_status).await;
+
+    // 8. Return archived ticket with previous status
+    let response = ChangeStatusResponse {
+        ticket: archived_ticket,
+        previous_status,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::EmployeeRole;
+
+    #[test]
+    fn test_validate_rush_surcharge() {
+        let quote = Some(Decimal::new(150, 0));
+        assert!(validate_rush_surcharge(None, None).is_ok());
+        assert!(validate_rush_surcharge(Some(Decimal::new(50, 0)), quote).is_ok());
+        assert!(validate_rush_surcharge(Some(Decimal::new(150, 0)), quote).is_ok());
+        assert!(validate_rush_surcharge(Some(Decimal::new(151, 0)), quote).is_err());
+        assert!(validate_rush_surcharge(Some(Decimal::new(-1, 0)), quote).is_err());
+        assert!(validate_rush_surcharge(Some(Decimal::new(50, 0)), None).is_err());
+    }
+
+    #[test]
+    fn test_unlisted_item_type() {
+        assert_eq!(unlisted_item_type(" other", "item_type").unwrap(), "Other");
+        let err = unlisted_item_type("rnig", "items[1].item_type").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("items[1].item_type must be a configured item type"));
+    }
+
+    #[test]
+    fn test_validate_weight() {
+        assert!(validate_weight(None).is_ok());
+        assert!(validate_weight(Some(Decimal::ZERO)).is_ok());
+        assert!(validate_weight(Some("4.250".parse().unwrap())).is_ok());
+        assert!(validate_weight(Some(Decimal::new(-1, 3))).is_err());
+    }
+
+    #[test]
+    fn test_validate_payment() {
+        let payment = |amount: &str| PaymentInput {
+            amount: amount.parse().unwrap(),
+            method: PaymentMethod::Cash,
+            notes: Some("  ".to_string()),
+        };
+        let valid = validate_payment(&payment("25.00")).unwrap();
+        assert_eq!(valid.amount, Decimal::new(2500, 2));
+        assert_eq!(valid.notes, None);
+        assert!(validate_payment(&payment("0")).is_err());
+        assert!(validate_payment(&payment("-5")).is_err());
+    }
+
+    #[test]
+    fn test_close_ticket_request_payment_defaults() {
+        let req: CloseTicketRequest =
+            serde_json::from_str(r#"{"actual_amount": "150.00"}"#).unwrap();
+        assert!(req.payment.is_none());
+        assert!(!req.allow_balance_due);
+
+        let req: CloseTicketRequest = serde_json::from_str(
+            r#"{"actual_amount": "150.00", "payment": {"amount": "100.00", "method": "card"}, "allow_balance_due": true}"#,
+        )
+        .unwrap();
+        assert_eq!(req.payment.unwrap().method, PaymentMethod::Card);
+        assert!(req.allow_balance_due);
+    }
+
+    #[test]
+    fn test_quote_decision_request() {
+        let req: QuoteDecisionRequest =
+            serde_json::from_str(r#"{"channel": "phone", "decided_by": "Sam Doe"}"#).unwrap();
+        assert_eq!(req.channel, QuoteChannel::Phone);
+        assert_eq!(req.decided_by.as_deref(), Some("Sam Doe"));
+
+        let req: QuoteDecisionRequest =
+            serde_json::from_str(r#"{"channel": "in_person"}"#).unwrap();
+        assert!(req.decided_by.is_none());
+        assert!(serde_json::from_str::<QuoteDecisionRequest>(r#"{"channel": "fax"}"#).is_err());
+    }
+
+    #[test]
+    fn test_reopen_ticket_request_requires_reason() {
+        let req: ReopenTicketRequest =
+            serde_json::from_str(r#"{"reason": "Stone loose again"}"#).unwrap();
+        assert_eq!(req.reason, "Stone loose again");
+        assert!(serde_json::from_str::<ReopenTicketRequest>("{}").is_err());
+    }
+
+    #[test]
+    fn test_update_ticket_request_clears_metal_fields() {
+        let json = r#"{"weight_grams": null, "metal_type": null}"#;
+        let req: UpdateTicketRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(req.weight_grams, Some(None));
+        assert_eq!(req.metal_type, Some(None));
+
+        let req: UpdateTicketRequest = serde_json::from_str("{}").unwrap();
+        assert!(req.weight_grams.is_none());
+        assert!(req.metal_type.is_none());
+    }
 
     #[test]
     fn test_create_ticket_request_deserialize() {
@@ -1990,6 +5630,73 @@ mod tests {
         assert!(customer.email.is_none());
     }
 
+    fn multi_item_request(extra: &str) -> CreateTicketRequest {
+        let json = format!(
+            r#"{{
+                "customer_id": "550e8400-e29b-41d4-a716-446655440000",
+                "storage_location_id": "660e8400-e29b-41d4-a716-446655440000",
+                "items": [
+                    {{"item_type": "ring", "item_description": " Gold ring ", "condition_notes": "Scratched", "requested_work": "Resize", "quote_amount": "40.00"}},
+                    {{"item_description": "Pendant", "condition_notes": "Bent bail", "requested_work": "Repair bail", "quote_amount": "25.50"}}
+                ]{}
+            }}"#,
+            extra
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_validate_ticket_items_single_item_from_top_level() {
+        let json = r#"{
+            "customer_id": "550e8400-e29b-41d4-a716-446655440000",
+            "item_description": "Gold ring",
+            "condition_notes": "Minor scratches",
+            "requested_work": "Resize",
+            "quote_amount": "80.00",
+            "storage_location_id": "660e8400-e29b-41d4-a716-446655440000"
+        }"#;
+        let request: CreateTicketRequest = serde_json::from_str(json).unwrap();
+
+        let items = validate_ticket_items(&request).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].item_description, "Gold ring");
+        assert_eq!(items[0].quote_amount, Some(Decimal::new(8000, 2)));
+    }
+
+    #[test]
+    fn test_validate_ticket_items_multi_item() {
+        let items = validate_ticket_items(&multi_item_request("")).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].item_description, "Gold ring");
+        assert_eq!(items[1].item_type, None);
+
+        // Top-level item fields can't be mixed with items
+        let err = validate_ticket_items(&multi_item_request(r#", "item_description": "Ring""#))
+            .unwrap_err();
+        assert_eq!(err.code(), "VALIDATION_ERROR");
+
+        let mut request = multi_item_request("");
+        request.items[1].requested_work = " ".to_string();
+        let err = validate_ticket_items(&request).unwrap_err();
+        assert!(err.message().contains("items[1].requested_work"));
+
+        request.items = vec![request.items[0].clone(); MAX_TICKET_ITEMS + 1];
+        assert!(validate_ticket_items(&request).is_err());
+    }
+
+    #[test]
+    fn test_ticket_quote_sums_item_quotes() {
+        let request = multi_item_request("");
+        let total = Some(Decimal::new(6550, 2));
+        assert_eq!(ticket_quote(None, &request.items).unwrap(), total);
+        assert_eq!(ticket_quote(total, &request.items).unwrap(), total);
+        assert!(ticket_quote(Some(Decimal::new(60, 0)), &request.items).is_err());
+
+        // Unquoted items leave the ticket quote as sent
+        let quote = Some(Decimal::new(100, 0));
+        assert_eq!(ticket_quote(quote, &[]).unwrap(), quote);
+    }
+
     #[test]
     fn test_is_rush_defaults_to_false() {
         let json = r#"{
@@ -2021,6 +5728,20 @@ mod tests {
         assert!(request.is_rush.is_none());
     }
 
+    #[test]
+    fn test_update_ticket_request_promise_date_reason() {
+        let json = r#"{"promise_date": "2026-11-02", "promise_date_reason": "parts_on_order"}"#;
+        let request: UpdateTicketRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            request.promise_date,
+            Some(NaiveDate::from_ymd_opt(2026, 11, 2))
+        );
+        assert_eq!(
+            request.promise_date_reason.as_deref(),
+            Some("parts_on_order")
+        );
+    }
+
     #[test]
     fn test_update_ticket_request_nullable_fields() {
         // Test setting a nullable field to null explicitly
@@ -2078,10 +5799,20 @@ mod tests {
         assert!(query.from_date.is_none());
         assert!(query.to_date.is_none());
         assert!(!query.include_archived);
+        assert!(!query.include_deleted);
         assert!(query.limit.is_none());
         assert!(query.offset.is_none());
     }
 
+    #[test]
+    fn test_include_deleted_query() {
+        let query: ListTicketsQuery = serde_urlencoded::from_str("include_deleted=true").unwrap();
+        assert!(query.include_deleted);
+
+        let query: IncludeDeletedQuery = serde_urlencoded::from_str("").unwrap();
+        assert!(!query.include_deleted);
+    }
+
     #[test]
     fn test_list_tickets_query_with_status_filter() {
         let query: ListTicketsQuery =
@@ -2122,12 +5853,55 @@ mod tests {
         assert_eq!(query.is_rush, Some(false));
     }
 
+    #[test]
+    fn test_list_tickets_query_with_employee_filters() {
+        let employee_id = Uuid::new_v4();
+        let query: ListTicketsQuery =
+            serde_urlencoded::from_str(&format!("worked_by={}", employee_id)).unwrap();
+        assert_eq!(query.worked_by, Some(employee_id));
+        assert!(query.taken_in_by.is_none());
+
+        let query: ListTicketsQuery =
+            serde_urlencoded::from_str(&format!("taken_in_by={}", employee_id)).unwrap();
+        assert_eq!(query.taken_in_by, Some(employee_id));
+    }
+
+    #[test]
+    fn test_queue_query() {
+        let query: QueueQuery = serde_urlencoded::from_str("").unwrap();
+        assert!(query.employee_id.is_none());
+        assert!(!query.include_deleted);
+
+        let employee_id = Uuid::new_v4();
+        let query: QueueQuery =
+            serde_urlencoded::from_str(&format!("employee_id={}", employee_id)).unwrap();
+        assert_eq!(query.employee_id, Some(employee_id));
+    }
+
     #[test]
     fn test_list_tickets_query_with_search() {
         let query: ListTicketsQuery = serde_urlencoded::from_str("search=gold+ring").unwrap();
         assert_eq!(query.search, Some("gold ring".to_string()));
     }
 
+    #[test]
+    fn test_list_tickets_query_with_cursor() {
+        let query: ListTicketsQuery =
+            serde_urlencoded::from_str("pagination=cursor&limit=25").unwrap();
+        assert_eq!(query.pagination, Some(PaginationMode::Cursor));
+        assert!(query.cursor.is_none());
+
+        let query: ListTicketsQuery = serde_urlencoded::from_str("cursor=abc").unwrap();
+        assert_eq!(query.cursor.as_deref(), Some("abc"));
+        assert!(serde_urlencoded::from_str::<ListTicketsQuery>("pagination=pages").is_err());
+    }
+
+    #[test]
+    fn test_list_tickets_query_with_sort() {
+        let query: ListTicketsQuery = serde_urlencoded::from_str("sort=-promise_date").unwrap();
+        assert_eq!(query.sort.as_deref(), Some("-promise_date"));
+    }
+
     #[test]
     fn test_list_tickets_query_with_pagination() {
         let query: ListTicketsQuery = serde_urlencoded::from_str("limit=50&offset=100").unwrap();
@@ -2161,12 +5935,14 @@ mod tests {
             limit: 50,
             offset: 0,
             has_more: false,
+            next_cursor: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains("\"count\":10"));
         assert!(json.contains("\"limit\":50"));
         assert!(json.contains("\"offset\":0"));
         assert!(json.contains("\"has_more\":false"));
+        assert!(!json.contains("next_cursor"));
     }
 
     #[test]
@@ -2202,6 +5978,7 @@ mod tests {
             name: "Jane Doe".to_string(),
             phone: Some("555-1234".to_string()),
             email: Some("jane@example.com".to_string()),
+            notifications_opt_out: false,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -2389,6 +6166,99 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn lane_ids(n: u128) -> Vec<Uuid> {
+        (1..=n).map(Uuid::from_u128).collect()
+    }
+
+    #[test]
+    fn test_parse_lane() {
+        assert_eq!(parse_lane("in_progress").unwrap(), TicketStatus::InProgress);
+        assert!(parse_lane("closed").is_err());
+    }
+
+    #[test]
+    fn test_reorder_lane_with_ticket_ids_keeps_unlisted_after() {
+        let lane = lane_ids(4);
+        let request: ReorderQueueRequest =
+            serde_json::from_value(serde_json::json!({ "ticket_ids": [lane[2], lane[0]] }))
+                .unwrap();
+        let order = reorder_lane(&lane, &request).unwrap();
+        assert_eq!(order, vec![lane[2], lane[0], lane[1], lane[3]]);
+    }
+
+    #[test]
+    fn test_reorder_lane_rejects_foreign_and_duplicate_tickets() {
+        let lane = lane_ids(3);
+        let foreign = ReorderQueueRequest {
+            ticket_ids: Some(vec![Uuid::from_u128(99)]),
+            move_ticket: None,
+        };
+        assert!(reorder_lane(&lane, &foreign).is_err());
+
+        let duplicate = ReorderQueueRequest {
+            ticket_ids: Some(vec![lane[0], lane[0]]),
+            move_ticket: None,
+        };
+        assert!(reorder_lane(&lane, &duplicate).is_err());
+
+        let neither = ReorderQueueRequest {
+            ticket_ids: None,
+            move_ticket: None,
+        };
+        assert!(reorder_lane(&lane, &neither).is_err());
+    }
+
+    #[test]
+    fn test_reorder_lane_move_after() {
+        let lane = lane_ids(4);
+        let request: ReorderQueueRequest = serde_json::from_value(
+            serde_json::json!({ "move": { "ticket_id": lane[0], "after": lane[2] } }),
+        )
+        .unwrap();
+        let order = reorder_lane(&lane, &request).unwrap();
+        assert_eq!(order, vec![lane[1], lane[2], lane[0], lane[3]]);
+
+        let to_top = ReorderQueueRequest {
+            ticket_ids: None,
+            move_ticket: Some(MoveQueueTicket {
+                ticket_id: lane[3],
+                after: None,
+            }),
+        };
+        let order = reorder_lane(&lane, &to_top).unwrap();
+        assert_eq!(order, vec![lane[3], lane[0], lane[1], lane[2]]);
+    }
+
+    #[test]
+    fn test_dedupe_ticket_ids_keeps_first_occurrence() {
+        let ids = lane_ids(3);
+        let deduped = dedupe_ticket_ids(vec![ids[1], ids[0], ids[1], ids[2], ids[0]]);
+        assert_eq!(deduped, vec![ids[1], ids[0], ids[2]]);
+    }
+
+    #[test]
+    fn test_bulk_status_result_serialization() {
+        let ok = BulkStatusResult {
+            ticket_id: Uuid::from_u128(1),
+            success: true,
+            friendly_code: Some("JR-0001".to_string()),
+            previous_status: Some(TicketStatus::Intake),
+            error: None,
+        };
+        let json = serde_json::to_value(&ok).unwrap();
+        assert_eq!(json["previous_status"], "intake");
+        assert!(json.get("error").is_none());
+
+        let failed = BulkStatusResult::failed(
+            Uuid::from_u128(2),
+            AppError::validation("Cannot transition from \"closed\" to \"intake\""),
+        );
+        let json = serde_json::to_value(&failed).unwrap();
+        assert_eq!(json["success"], false);
+        assert_eq!(json["error"]["code"], "VALIDATION_ERROR");
+        assert!(json.get("friendly_code").is_none());
+    }
+
     #[test]
     fn test_close_ticket_request_deserialize() {
         let json = r#"{"actual_amount": 145.00}"#;
@@ -2433,6 +6303,7 @@ mod tests {
             is_rush: false,
             promise_date: None,
             storage_location_id: Uuid::parse_str("770e8400-e29b-41d4-a716-446655440000").unwrap(),
+            store_id: Uuid::nil(),
             quote_amount: Some(Decimal::new(10000, 2)),
             actual_amount: Some(Decimal::new(14500, 2)),
             taken_in_by: Uuid::parse_str("880e8400-e29b-41d4-a716-446655440000").unwrap(),
@@ -2447,11 +6318,31 @@ mod tests {
             queue_position: None,
             deleted_at: None,
             deleted_by: None,
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "tok".to_string(),
+            weight_grams: None,
+            metal_type: None,
         };
 
         let response = CloseTicketResponse {
             ticket,
             previous_status: TicketStatus::ReadyForPickup,
+            payment: None,
+            total_paid: Decimal::new(14500, 2),
+            tax: TaxBreakdown {
+                subtotal: Decimal::new(13395, 2),
+                tax_rate: Decimal::new(825, 2),
+                tax_inclusive: true,
+                tax_amount: Decimal::new(1105, 2),
+                total: Decimal::new(14500, 2),
+            },
+            balance_due: Decimal::ZERO,
+            pickup: None,
+            invoice_number: "INV-000007".to_string(),
+            invoice_url: "/api/v1/tickets/550e8400-e29b-41d4-a716-446655440000/invoice.pdf"
+                .to_string(),
         };
 
         let json = serde_json::to_string(&response).unwrap();
@@ -2459,6 +6350,27 @@ mod tests {
         assert!(json.contains("\"status\":\"closed\""));
         assert!(json.contains("\"actual_amount\":\"145.00\""));
         assert!(json.contains("\"previous_status\":\"ready_for_pickup\""));
+        assert!(json.contains("\"total_paid\":\"145.00\""));
+        assert!(!json.contains("\"payment\""));
+        assert!(!json.contains("\"pickup\""));
+        assert!(json.contains("\"invoice_number\":\"INV-000007\""));
+        assert!(json.contains("\"tax_amount\":\"11.05\""));
+    }
+
+    #[test]
+    fn test_decode_signature() {
+        let png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR";
+        let encoded = STANDARD.encode(png);
+        assert_eq!(decode_signature(&encoded).unwrap(), png.to_vec());
+        assert_eq!(
+            decode_signature(&format!("data:image/png;base64,{}", encoded)).unwrap(),
+            png.to_vec()
+        );
+
+        assert!(decode_signature("not base64!").is_err());
+        assert!(decode_signature(&STANDARD.encode(b"\xFF\xD8\xFF\xE0 jpeg")).is_err());
+        let oversized = [png.as_slice(), &vec![0; MAX_SIGNATURE_SIZE]].concat();
+        assert!(decode_signature(&STANDARD.encode(oversized)).is_err());
     }
 
     #[test]
@@ -2514,6 +6426,45 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_assign_ticket_request_distinguishes_null_from_missing() {
+        let request: AssignTicketRequest = serde_json::from_str(
+            r#"{"employee_id": "550e8400-e29b-41d4-a716-446655440000", "notify": true}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            request.employee_id,
+            Some(Some(
+                Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap()
+            ))
+        );
+        assert!(request.notify);
+
+        let request: AssignTicketRequest =
+            serde_json::from_str(r#"{"employee_id": null}"#).unwrap();
+        assert_eq!(request.employee_id, Some(None));
+        assert!(!request.notify);
+
+        let request: AssignTicketRequest = serde_json::from_str("{}").unwrap();
+        assert_eq!(request.employee_id, None);
+    }
+
+    #[test]
+    fn test_assignment_text() {
+        let mut ticket = create_test_ticket(Uuid::new_v4(), None);
+        assert_eq!(
+            assignment_text("Facet Jewelers", "Alice", &ticket),
+            "Facet Jewelers: Alice assigned you JR-TEST1: Gold ring"
+        );
+
+        ticket.is_rush = true;
+        ticket.promise_date = NaiveDate::from_ymd_opt(2026, 3, 5);
+        assert_eq!(
+            assignment_text("Facet Jewelers", "Alice", &ticket),
+            "Facet Jewelers: Alice assigned you JR-TEST1 (rush): Gold ring, due Mar 5"
+        );
+    }
+
     #[test]
     fn test_toggle_rush_response_serialization() {
         use chrono::TimeZone;
@@ -2530,6 +6481,7 @@ mod tests {
             is_rush: true,
             promise_date: None,
             storage_location_id: Uuid::parse_str("770e8400-e29b-41d4-a716-446655440000").unwrap(),
+            store_id: Uuid::nil(),
             quote_amount: Some(Decimal::new(10000, 2)),
             actual_amount: None,
             taken_in_by: Uuid::parse_str("880e8400-e29b-41d4-a716-446655440000").unwrap(),
@@ -2544,6 +6496,12 @@ mod tests {
             queue_position: None,
             deleted_at: None,
             deleted_by: None,
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "tok".to_string(),
+            weight_grams: None,
+            metal_type: None,
         };
 
         let response = ToggleRushResponse {
@@ -2614,10 +6572,16 @@ mod tests {
     fn create_test_employee(role: EmployeeRole, employee_id: Uuid) -> Employee {
         Employee {
             employee_id,
+            store_id: Uuid::nil(),
             name: "Test Employee".to_string(),
             pin_hash: "hash".to_string(),
+            pin_challenge_key: None,
+            pin_challenge_salt: None,
+            phone: None,
             role,
+            permissions: role.default_permissions(),
             is_active: true,
+            pin_locked_at: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -2636,6 +6600,7 @@ mod tests {
             is_rush: false,
             promise_date: None,
             storage_location_id: Uuid::parse_str("770e8400-e29b-41d4-a716-446655440000").unwrap(),
+            store_id: Uuid::nil(),
             quote_amount: None,
             actual_amount: None,
             taken_in_by,
@@ -2648,9 +6613,25 @@ mod tests {
             queue_position: None,
             deleted_at: None,
             deleted_by: None,
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "tok".to_string(),
+            weight_grams: None,
+            metal_type: None,
         }
     }
 
+    #[test]
+    fn test_visible_to_scopes_staff_only() {
+        let employee_id = Uuid::parse_str("880e8400-e29b-41d4-a716-446655440000").unwrap();
+        let staff = create_test_employee(EmployeeRole::Staff, employee_id);
+        let admin = create_test_employee(EmployeeRole::Admin, employee_id);
+
+        assert_eq!(visible_to(&staff), Some(employee_id));
+        assert_eq!(visible_to(&admin), None);
+    }
+
     #[test]
     fn test_is_authorized_for_ticket_owner() {
         let employee_id = Uuid::parse_str("880e8400-e29b-41d4-a716-446655440000").unwrap();
@@ -2713,4 +6694,106 @@ mod tests {
         // Admin who is also the owner should be authorized (tests short-circuit)
         assert!(is_authorized_for_ticket(&employee, &ticket));
     }
+
+    // =========================================================================
+    // QC Check Tests
+    // =========================================================================
+
+    fn qc_result(item: &str, passed: bool) -> QcItemResult {
+        QcItemResult {
+            item: item.to_string(),
+            passed,
+        }
+    }
+
+    #[test]
+    fn test_record_qc_check_request_deserialize() {
+        let json = r#"{
+            "items": [
+                {"item": "Stones secure", "passed": true},
+                {"item": "Polished", "passed": false}
+            ],
+            "notes": "Re-polish band"
+        }"#;
+        let request: RecordQcCheckRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.items.len(), 2);
+        assert!(request.items[0].passed);
+        assert!(!request.items[1].passed);
+        assert_eq!(request.notes, Some("Re-polish band".to_string()));
+    }
+
+    #[test]
+    fn test_record_qc_check_request_with_defect_reason() {
+        let json = r#"{
+            "items": [{"item": "Stones secure", "passed": false}],
+            "defect_reason": "loose_stone"
+        }"#;
+        let request: RecordQcCheckRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.defect_reason, Some(DefectReason::LooseStone));
+    }
+
+    #[test]
+    fn test_check_defect_reason() {
+        assert!(check_defect_reason(true, Some(DefectReason::Clasp)).is_ok());
+        assert!(check_defect_reason(false, None).is_ok());
+        assert!(check_defect_reason(true, None).is_err());
+        let err = check_defect_reason(false, Some(DefectReason::Clasp)).unwrap_err();
+        assert_eq!(
+            err.message(),
+            "defect_reason is only allowed when a QC item fails"
+        );
+    }
+
+    #[test]
+    fn test_record_defect_request_deserialize() {
+        let json = r#"{"reason": "clasp", "notes": "Clasp opened after two days"}"#;
+        let request: RecordDefectRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.reason, DefectReason::Clasp);
+        assert_eq!(
+            request.notes,
+            Some("Clasp opened after two days".to_string())
+        );
+    }
+
+    #[test]
+    fn test_partition_qc_results_splits_by_result() {
+        let checklist = vec!["Stones secure".to_string(), "Polished".to_string()];
+        let results = vec![
+            qc_result("Polished", false),
+            qc_result("Stones secure", true),
+        ];
+        let (passed, failed) = partition_qc_results(&checklist, &results).unwrap();
+        assert_eq!(passed, vec!["Stones secure"]);
+        assert_eq!(failed, vec!["Polished"]);
+    }
+
+    #[test]
+    fn test_partition_qc_results_trims_item_names() {
+        let checklist = vec!["Polished".to_string()];
+        let results = vec![qc_result("  Polished ", true)];
+        let (passed, failed) = partition_qc_results(&checklist, &results).unwrap();
+        assert_eq!(passed, vec!["Polished"]);
+        assert!(failed.is_empty());
+    }
+
+    #[test]
+    fn test_partition_qc_results_rejects_missing_item() {
+        let checklist = vec!["Stones secure".to_string(), "Polished".to_string()];
+        let results = vec![qc_result("Polished", true)];
+        assert!(partition_qc_results(&checklist, &results).is_err());
+    }
+
+    #[test]
+    fn test_partition_qc_results_rejects_unknown_item() {
+        let checklist = vec!["Polished".to_string()];
+        let results = vec![qc_result("Polished", true), qc_result("Engraved", true)];
+        assert!(partition_qc_results(&checklist, &results).is_err());
+    }
+
+    #[test]
+    fn test_partition_qc_results_rejects_duplicate_item() {
+        let checklist = vec!["Polished".to_string()];
+        let results = vec![qc_result("Polished", true), qc_result("Polished", false)];
+        assert!(partition_qc_results(&checklist, &results).is_err());
+    }
 }
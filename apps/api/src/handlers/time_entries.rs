@@ -0,0 +1,131 @@
+//! Bench time tracking handlers.
+//!
+//! Employees clock in on the ticket they're working on and clock out when
+//! they stop. An employee can only be clocked in on one ticket at a time;
+//! totals show on the ticket detail and in the employee report.
+
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::tickets::extract_employee_from_session;
+use crate::models::{total_bench_seconds, TicketStatus, TimeEntryEntry};
+use crate::repositories::{TicketRepository, TimeEntryRepository};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+
+// =============================================================================
+// POST /tickets/:ticket_id/time/start|stop - Clock In and Out
+// =============================================================================
+
+/// POST /api/v1/tickets/:ticket_id/time/start - Clock in on a ticket.
+///
+/// Starts a time entry for the authenticated employee. Fails with CONFLICT
+/// if they're already clocked in, on this ticket or another; they clock out
+/// of that one first. Closed and archived tickets can't be clocked in on.
+pub async fn start_time_entry(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+    if matches!(ticket.status, TicketStatus::Closed | TicketStatus::Archived) {
+        return Err(AppError::validation(
+            "Closed or archived tickets cannot be clocked in on",
+        ));
+    }
+
+    // 3. Entries can't overlap: one running entry per employee
+    if let Some(running) =
+        TimeEntryRepository::find_running_by_employee_id(&state.db, employee.employee_id).await?
+    {
+        return Err(if running.ticket_id == ticket_id {
+            AppError::conflict("Already clocked in on this ticket")
+        } else {
+            AppError::conflict(format!(
+                "Already clocked in on {}; clock out of it first",
+                running.friendly_code
+            ))
+        });
+    }
+
+    // 4. Start the entry
+    let entry = TimeEntryRepository::start(&state.db, ticket_id, employee.employee_id).await?;
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(entry))))
+}
+
+/// POST /api/v1/tickets/:ticket_id/time/stop - Clock out of a ticket.
+///
+/// Stops the authenticated employee's running entry on the ticket.
+pub async fn stop_time_entry(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Stop the entry
+    let entry = TimeEntryRepository::stop(&state.db, ticket_id, employee.employee_id)
+        .await?
+        .ok_or_else(|| AppError::validation("Not clocked in on this ticket"))?;
+
+    Ok(Json(ApiResponse::success(entry)))
+}
+
+// =============================================================================
+// GET /tickets/:ticket_id/time - Bench Time
+// =============================================================================
+
+/// Response for a ticket's bench time.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketTimeResponse {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    /// Total across entries, counting running ones up to now
+    pub bench_seconds: i64,
+    /// Entries, oldest first
+    pub entries: Vec<TimeEntryEntry>,
+}
+
+/// GET /api/v1/tickets/:ticket_id/time - List a ticket's time entries.
+pub async fn list_time_entries(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 2. Load entries and total them
+    let entries = TimeEntryRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+
+    let response = TicketTimeResponse {
+        ticket_id,
+        friendly_code: ticket.friendly_code,
+        bench_seconds: total_bench_seconds(&entries, Utc::now()),
+        entries,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
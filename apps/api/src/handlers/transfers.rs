@@ -0,0 +1,405 @@
+//! Ticket transfer request handlers.
+//!
+//! Sending a ticket to another storage location starts a transfer that
+//! holds the item in transit. The ticket's location only changes when an
+//! employee at the receiving location accepts it; until then the transfer
+//! can be cancelled and the item stays on record at its origin. Both ends
+//! of the trip are recorded in the chain of custody.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::tickets::{custody_witness, extract_employee_from_session, PaginationInfo};
+use crate::middleware::require_ticket_access;
+use crate::models::{
+    CreateCustodyEvent, CreateTicketTransfer, CustodyEventType, CustodyWitness, Permission, Ticket,
+    TicketStatus, TicketTransfer, TicketTransferEntry, TransferStatus,
+};
+use crate::repositories::{
+    CustodyRepository, MovementRepository, StoreSettingsRepository, TicketRepository,
+    TransferRepository,
+};
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::validation::{validate_optional, validate_storage_location, MAX_NOTE_LENGTH};
+
+// =============================================================================
+// POST /tickets/:ticket_id/transfer - Send a Ticket
+// =============================================================================
+
+/// Request body for sending a ticket to another location.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTransferRequest {
+    /// Storage location the item is going to (required)
+    pub to_location_id: Uuid,
+    pub notes: Option<String>,
+    /// Witness for the hand-off (required for high-value items)
+    pub custody: Option<CustodyWitness>,
+}
+
+/// POST /api/v1/tickets/:ticket_id/transfer - Send a ticket to another location.
+///
+/// Starts a pending transfer and records the item leaving its location in the
+/// chain of custody. The ticket keeps its storage location until the transfer
+/// is accepted. Closed and archived tickets can't be transferred, and a ticket
+/// can only be in transit once at a time.
+pub async fn create_transfer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<CreateTransferRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 3. Authorization check: staff can only transfer their own tickets
+    require_ticket_access(&employee, &ticket, Permission::ModifyOwnTicket)?;
+
+    // 4. Validate the transfer
+    if matches!(ticket.status, TicketStatus::Closed | TicketStatus::Archived) {
+        return Err(AppError::validation(
+            "Closed or archived tickets cannot be transferred",
+        ));
+    }
+    if body.to_location_id == ticket.storage_location_id {
+        return Err(AppError::validation(
+            "Ticket is already at this storage location",
+        ));
+    }
+    validate_storage_location(&state.db, body.to_location_id).await?;
+    if TransferRepository::find_pending_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::conflict("Ticket already has a pending transfer"));
+    }
+    if MovementRepository::find_latest_entry_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .is_some_and(|movement| movement.to_employee_id.is_some())
+    {
+        return Err(AppError::conflict(
+            "Item is checked out to a bench; check it back in first",
+        ));
+    }
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
+
+    // 5. Validate the witness (required for high-value items)
+    let threshold =
+        StoreSettingsRepository::get_custody_value_threshold(&state.db, ticket.store_id).await?;
+    let witness = custody_witness(
+        &state,
+        employee.employee_id,
+        body.custody.as_ref(),
+        ticket.requires_custody(threshold),
+    )
+    .await?;
+
+    // 6. Start the transfer
+    let transfer = TransferRepository::create(
+        &state.db,
+        CreateTicketTransfer {
+            ticket_id,
+            from_location_id: ticket.storage_location_id,
+            to_location_id: body.to_location_id,
+            requested_by: employee.employee_id,
+            notes: notes.clone(),
+        },
+    )
+    .await?;
+
+    // 7. Record the item leaving in the chain of custody
+    CustodyRepository::create(
+        &state.db,
+        CreateCustodyEvent {
+            ticket_id,
+            event_type: CustodyEventType::TransferOut,
+            from_location_id: Some(transfer.from_location_id),
+            to_location_id: Some(transfer.to_location_id),
+            handled_by: employee.employee_id,
+            witnessed_by: witness.as_ref().map(|w| w.witnessed_by),
+            notes: witness.and_then(|w| w.notes).or(notes),
+        },
+    )
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(ApiResponse::success(transfer))))
+}
+
+// =============================================================================
+// POST /tickets/:ticket_id/transfer/accept|cancel - Resolve a Transfer
+// =============================================================================
+
+/// Request body for accepting or cancelling a transfer.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ResolveTransferRequest {
+    pub notes: Option<String>,
+    /// Witness for receiving the item (required for high-value items)
+    pub custody: Option<CustodyWitness>,
+}
+
+/// Find a ticket and the transfer it is in transit on.
+async fn pending_transfer(
+    state: &AppState,
+    ticket_id: Uuid,
+) -> Result<(Ticket, TicketTransfer), AppError> {
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    let transfer = TransferRepository::find_pending_by_ticket_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| AppError::validation("Ticket has no pending transfer"))?;
+
+    Ok((ticket, transfer))
+}
+
+/// POST /api/v1/tickets/:ticket_id/transfer/accept - Confirm receipt of a ticket.
+///
+/// Moves the ticket to the transfer's destination and records the item
+/// arriving in the chain of custody. Any active employee can accept, since
+/// the receiving staff usually don't own the ticket.
+pub async fn accept_transfer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<ResolveTransferRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket and its transfer
+    let (ticket, transfer) = pending_transfer(&state, ticket_id).await?;
+
+    // 3. Validate the witness (required for high-value items)
+    let threshold =
+        StoreSettingsRepository::get_custody_value_threshold(&state.db, ticket.store_id).await?;
+    let witness = custody_witness(
+        &state,
+        employee.employee_id,
+        body.custody.as_ref(),
+        ticket.requires_custody(threshold),
+    )
+    .await?;
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
+
+    // 4. Move the ticket; another employee may have just resolved it
+    let transfer =
+        TransferRepository::accept(&state.db, transfer.transfer_id, employee.employee_id)
+            .await?
+            .ok_or_else(|| AppError::validation("Ticket has no pending transfer"))?;
+
+    // 5. Record the item arriving in the chain of custody
+    CustodyRepository::create(
+        &state.db,
+        CreateCustodyEvent {
+            ticket_id,
+            event_type: CustodyEventType::TransferIn,
+            from_location_id: Some(transfer.from_location_id),
+            to_location_id: Some(transfer.to_location_id),
+            handled_by: employee.employee_id,
+            witnessed_by: witness.as_ref().map(|w| w.witnessed_by),
+            notes: witness.and_then(|w| w.notes).or(notes),
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(transfer)))
+}
+
+/// POST /api/v1/tickets/:ticket_id/transfer/cancel - Call off a transfer.
+///
+/// The ticket stays at its original location, and the item coming back is
+/// recorded in the chain of custody. Staff can only cancel transfers of
+/// their own tickets.
+pub async fn cancel_transfer(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ticket_id): Path<Uuid>,
+    Json(body): Json<ResolveTransferRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Extract and validate employee from session
+    let employee = extract_employee_from_session(&state, &headers).await?;
+
+    // 2. Find the ticket and its transfer
+    let (ticket, transfer) = pending_transfer(&state, ticket_id).await?;
+
+    // 3. Authorization check: staff can only cancel for their own tickets
+    require_ticket_access(&employee, &ticket, Permission::ModifyOwnTicket)?;
+
+    // 4. Validate the witness (required for high-value items)
+    let threshold =
+        StoreSettingsRepository::get_custody_value_threshold(&state.db, ticket.store_id).await?;
+    let witness = custody_witness(
+        &state,
+        employee.employee_id,
+        body.custody.as_ref(),
+        ticket.requires_custody(threshold),
+    )
+    .await?;
+    let notes = validate_optional(body.notes.as_deref(), "notes", MAX_NOTE_LENGTH)?;
+
+    // 5. Cancel; another employee may have just resolved it
+    let transfer =
+        TransferRepository::cancel(&state.db, transfer.transfer_id, employee.employee_id)
+            .await?
+            .ok_or_else(|| AppError::validation("Ticket has no pending transfer"))?;
+
+    // 6. Record the item returning to its origin
+    CustodyRepository::create(
+        &state.db,
+        CreateCustodyEvent {
+            ticket_id,
+            event_type: CustodyEventType::TransferIn,
+            from_location_id: Some(transfer.to_location_id),
+            to_location_id: Some(transfer.from_location_id),
+            handled_by: employee.employee_id,
+            witnessed_by: witness.as_ref().map(|w| w.witnessed_by),
+            notes: witness
+                .and_then(|w| w.notes)
+                .or(notes)
+                .or_else(|| Some("Transfer cancelled".to_string())),
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(transfer)))
+}
+
+// =============================================================================
+// GET /tickets/:ticket_id/transfers and /transfers - Transfer History
+// =============================================================================
+
+/// Response for a ticket's transfer history.
+#[derive(Debug, Clone, Serialize)]
+pub struct TicketTransfersResponse {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    /// Whether the item is currently in transit
+    pub in_transit: bool,
+    pub transfers: Vec<TicketTransferEntry>,
+}
+
+/// GET /api/v1/tickets/:ticket_id/transfers - List a ticket's transfers.
+pub async fn list_ticket_transfers(
+    State(state): State<AppState>,
+    Path(ticket_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Find the ticket
+    let ticket = TicketRepository::find_by_id(&state.db, ticket_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("ticket"))?;
+
+    // 2. Load transfers, oldest first
+    let transfers = TransferRepository::find_entries_by_ticket_id(&state.db, ticket_id).await?;
+
+    let response = TicketTransfersResponse {
+        ticket_id,
+        friendly_code: ticket.friendly_code,
+        in_transit: transfers
+            .iter()
+            .any(|t| t.status == TransferStatus::Pending),
+        transfers,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+/// Query parameters for listing transfers.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListTransfersQuery {
+    /// Filter by status (default: pending)
+    pub status: Option<TransferStatus>,
+    /// Filter by destination location
+    pub to_location_id: Option<Uuid>,
+    /// Limit results (default: 50, max 200)
+    pub limit: Option<i64>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+}
+
+/// Response for listing transfers.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListTransfersResponse {
+    pub transfers: Vec<TicketTransferEntry>,
+    pub pagination: PaginationInfo,
+}
+
+/// GET /api/v1/transfers - List transfers across tickets.
+///
+/// Defaults to pending transfers, oldest first, so a receiving location can
+/// filter by `to_location_id` to see what it's expecting.
+pub async fn list_transfers(
+    State(state): State<AppState>,
+    Query(query): Query<ListTransfersQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    let status = query.status.unwrap_or(TransferStatus::Pending);
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    // Load one extra transfer to determine has_more
+    let transfers = TransferRepository::list_entries(
+        &state.db,
+        Some(status),
+        query.to_location_id,
+        limit + 1,
+        offset,
+    )
+    .await?;
+
+    let has_more = transfers.len() as i64 > limit;
+    let transfers: Vec<TicketTransferEntry> = transfers.into_iter().take(limit as usize).collect();
+
+    let response = ListTransfersResponse {
+        pagination: PaginationInfo {
+            count: transfers.len(),
+            limit,
+            offset,
+            has_more,
+            next_cursor: None,
+        },
+        transfers,
+    };
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_transfer_request_deserialize() {
+        let json = r#"{
+            "to_location_id": "550e8400-e29b-41d4-a716-446655440000",
+            "custody": {"witnessed_by": "660e8400-e29b-41d4-a716-446655440000"}
+        }"#;
+        let request: CreateTransferRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            request.to_location_id,
+            Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap()
+        );
+        assert!(request.notes.is_none());
+        assert!(request.custody.is_some());
+    }
+
+    #[test]
+    fn test_list_transfers_query_status() {
+        let query: ListTransfersQuery =
+            serde_urlencoded::from_str("status=accepted&limit=10").unwrap();
+        assert_eq!(query.status, Some(TransferStatus::Accepted));
+        assert_eq!(query.limit, Some(10));
+        assert!(query.to_location_id.is_none());
+    }
+}
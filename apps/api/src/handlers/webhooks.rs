@@ -0,0 +1,399 @@
+//! Webhook request handlers.
+//!
+//! Admins register URLs under `/admin/webhooks` to receive ticket events.
+//! The signing secret is shown once, when the webhook is created or its
+//! secret rotated; receivers use it to check `X-Facet-Signature`. Each
+//! webhook's deliveries can be inspected and redelivered for debugging.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::audit_log::{record_audit, AuditEvent};
+use crate::handlers::tickets::PaginationInfo;
+use crate::handlers::verify_admin_auth;
+use crate::middleware::ClientIp;
+use crate::models::audit_log::AuditAction;
+use crate::models::webhook::{
+    CreateWebhook, UpdateWebhook, Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEvent,
+};
+use crate::repositories::WebhookRepository;
+use crate::response::ApiResponse;
+use crate::routes::AppState;
+use crate::validation::{validate_optional, validate_required, MAX_NAME_LENGTH, MAX_URL_LENGTH};
+
+/// Validate a webhook URL: an absolute http or https URL.
+fn validate_webhook_url(url: &str) -> Result<String, AppError> {
+    let url = validate_required(url, "url", MAX_URL_LENGTH)?;
+    match reqwest::Url::parse(&url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => Ok(url),
+        _ => Err(AppError::validation("url must be an http or https URL")),
+    }
+}
+
+/// Validate an event filter: at least one event, duplicates dropped.
+fn validate_events(events: Vec<WebhookEvent>) -> Result<Vec<WebhookEvent>, AppError> {
+    let mut unique = Vec::with_capacity(events.len());
+    for event in events {
+        if !unique.contains(&event) {
+            unique.push(event);
+        }
+    }
+    if unique.is_empty() {
+        return Err(AppError::validation("events must list at least one event"));
+    }
+    Ok(unique)
+}
+
+/// Request body for registering a webhook.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateWebhookRequest {
+    /// Where events are POSTed (required)
+    pub url: String,
+    /// What the webhook is for
+    pub description: Option<String>,
+    /// Events to send (required, at least one)
+    pub events: Vec<WebhookEvent>,
+}
+
+/// A webhook with its signing secret, returned only when the secret is issued.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookSecretResponse {
+    #[serde(flatten)]
+    pub webhook: Webhook,
+    /// The signing secret; shown once
+    pub secret: String,
+}
+
+/// Response for listing webhooks.
+#[derive(Debug, Clone, Serialize)]
+pub struct ListWebhooksResponse {
+    pub webhooks: Vec<Webhook>,
+}
+
+/// Response for deleting a webhook.
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteWebhookResponse {
+    pub deleted: bool,
+}
+
+/// GET /api/v1/admin/webhooks - List webhooks (admin only).
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let webhooks = WebhookRepository::list(&state.db).await?;
+
+    Ok(Json(ApiResponse::success(ListWebhooksResponse {
+        webhooks,
+    })))
+}
+
+/// POST /api/v1/admin/webhooks - Register a webhook (admin only).
+///
+/// Returns the signing secret once.
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Json(body): Json<CreateWebhookRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication
+    verify_admin_auth(&state, &headers).await?;
+
+    // 2. Validate input
+    let url = validate_webhook_url(&body.url)?;
+    let description =
+        validate_optional(body.description.as_deref(), "description", MAX_NAME_LENGTH)?;
+    let events = validate_events(body.events)?;
+
+    // 3. Issue the secret and register the webhook
+    let secret = WebhookRepository::generate_secret();
+    let webhook = WebhookRepository::create(
+        &state.db,
+        CreateWebhook {
+            url,
+            description,
+            events,
+            secret: secret.clone(),
+        },
+    )
+    .await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::WebhookCreated)
+            .target("webhook", webhook.webhook_id)
+            .summary(serde_json::json!({
+                "url": webhook.url,
+                "events": webhook.events,
+            })),
+    )
+    .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success(WebhookSecretResponse {
+            webhook,
+            secret,
+        })),
+    ))
+}
+
+/// PUT /api/v1/admin/webhooks/:webhook_id - Update a webhook (admin only).
+///
+/// Setting `is_active` to false stops new events being queued for it and
+/// holds its pending deliveries until it is reactivated.
+pub async fn update_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(webhook_id): Path<Uuid>,
+    Json(body): Json<UpdateWebhook>,
+) -> Result<impl IntoResponse, AppError> {
+    // 1. Verify admin authentication
+    verify_admin_auth(&state, &headers).await?;
+
+    // 2. Validate provided fields
+    let url = body.url.as_deref().map(validate_webhook_url).transpose()?;
+    let description = body
+        .description
+        .map(|d| validate_optional(d.as_deref(), "description", MAX_NAME_LENGTH))
+        .transpose()?;
+    let events = body.events.map(validate_events).transpose()?;
+
+    let audit_summary = serde_json::json!({
+        "url": url,
+        "events": events,
+        "is_active": body.is_active,
+    });
+
+    // 3. Update
+    let webhook = WebhookRepository::update(
+        &state.db,
+        webhook_id,
+        UpdateWebhook {
+            url,
+            description,
+            events,
+            is_active: body.is_active,
+        },
+    )
+    .await?
+    .ok_or_else(|| state.probe_policy.missing("webhook"))?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::WebhookUpdated)
+            .target("webhook", webhook_id)
+            .summary(audit_summary),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(webhook)))
+}
+
+/// DELETE /api/v1/admin/webhooks/:webhook_id - Delete a webhook (admin only).
+///
+/// Its queued deliveries are dropped.
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let webhook = WebhookRepository::find_by_id(&state.db, webhook_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("webhook"))?;
+    if !WebhookRepository::delete(&state.db, webhook_id).await? {
+        return Err(state.probe_policy.missing("webhook"));
+    }
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::WebhookDeleted)
+            .target("webhook", webhook_id)
+            .summary(serde_json::json!({ "url": webhook.url })),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(DeleteWebhookResponse {
+        deleted: true,
+    })))
+}
+
+/// POST /api/v1/admin/webhooks/:webhook_id/rotate-secret - Issue a new signing secret (admin only).
+///
+/// Deliveries are signed with the new secret from the next attempt on.
+pub async fn rotate_webhook_secret(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(webhook_id): Path<Uuid>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    WebhookRepository::find_by_id(&state.db, webhook_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("webhook"))?;
+
+    let secret = WebhookRepository::generate_secret();
+    let webhook = WebhookRepository::set_secret(&state.db, webhook_id, &secret).await?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::WebhookSecretRotated).target("webhook", webhook_id),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(WebhookSecretResponse {
+        webhook,
+        secret,
+    })))
+}
+
+/// Query parameters for listing a webhook's deliveries.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookDeliveriesQuery {
+    /// Filter by status
+    pub status: Option<WebhookDeliveryStatus>,
+    /// Limit results (default: 50, max 200)
+    pub limit: Option<i64>,
+    /// Offset for pagination (default: 0)
+    pub offset: Option<i64>,
+}
+
+/// Response for listing a webhook's deliveries.
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookDeliveriesResponse {
+    pub deliveries: Vec<WebhookDelivery>,
+    pub pagination: PaginationInfo,
+}
+
+/// Path parameters for a single delivery.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookDeliveryPath {
+    pub webhook_id: Uuid,
+    pub delivery_id: Uuid,
+}
+
+/// GET /api/v1/admin/webhooks/:webhook_id/deliveries - List a webhook's deliveries (admin only).
+///
+/// Newest first, with each event's data and the last response captured.
+pub async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(webhook_id): Path<Uuid>,
+    Query(query): Query<WebhookDeliveriesQuery>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    WebhookRepository::find_by_id(&state.db, webhook_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("webhook"))?;
+
+    // Load one extra delivery to determine has_more
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+    let offset = query.offset.unwrap_or(0).max(0);
+    let deliveries =
+        WebhookRepository::list_deliveries(&state.db, webhook_id, query.status, limit + 1, offset)
+            .await?;
+
+    let has_more = deliveries.len() as i64 > limit;
+    let deliveries: Vec<WebhookDelivery> = deliveries.into_iter().take(limit as usize).collect();
+
+    Ok(Json(ApiResponse::success(WebhookDeliveriesResponse {
+        pagination: PaginationInfo {
+            count: deliveries.len(),
+            limit,
+            offset,
+            has_more,
+            next_cursor: None,
+        },
+        deliveries,
+    })))
+}
+
+/// POST /api/v1/admin/webhooks/:webhook_id/deliveries/:delivery_id/redeliver - Send a delivery again (admin only).
+///
+/// Queues a delivered or failed event for immediate sending with a fresh
+/// set of attempts. The event keeps its ID, so receivers can tell it's a
+/// replay.
+///
+/// # Errors
+/// - CONFLICT: If the delivery is still pending
+pub async fn redeliver_webhook_delivery(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    ClientIp(client_ip): ClientIp,
+    Path(path): Path<WebhookDeliveryPath>,
+) -> Result<impl IntoResponse, AppError> {
+    verify_admin_auth(&state, &headers).await?;
+
+    let existing = WebhookRepository::find_delivery(&state.db, path.webhook_id, path.delivery_id)
+        .await?
+        .ok_or_else(|| state.probe_policy.missing("delivery"))?;
+    let delivery = WebhookRepository::redeliver(&state.db, path.delivery_id)
+        .await?
+        .ok_or_else(|| AppError::conflict("Delivery is already queued"))?;
+    record_audit(
+        &state,
+        &headers,
+        client_ip,
+        AuditEvent::new(AuditAction::WebhookRedelivered)
+            .target("webhook", path.webhook_id)
+            .summary(serde_json::json!({
+                "delivery_id": delivery.delivery_id,
+                "event": delivery.event,
+                "previous_status": existing.status,
+            })),
+    )
+    .await;
+
+    Ok(Json(ApiResponse::success(delivery)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_webhook_url() {
+        assert_eq!(
+            validate_webhook_url(" https://hooks.example.com/facet ").unwrap(),
+            "https://hooks.example.com/facet"
+        );
+        assert!(validate_webhook_url("http://localhost:8080/in").is_ok());
+        assert!(validate_webhook_url("ftp://example.com/in").is_err());
+        assert!(validate_webhook_url("example.com/in").is_err());
+        assert!(validate_webhook_url("").is_err());
+    }
+
+    #[test]
+    fn test_validate_events_dedupes() {
+        assert_eq!(
+            validate_events(vec![
+                WebhookEvent::TicketClosed,
+                WebhookEvent::TicketCreated,
+                WebhookEvent::TicketClosed,
+            ])
+            .unwrap(),
+            vec![WebhookEvent::TicketClosed, WebhookEvent::TicketCreated]
+        );
+        assert!(validate_events(vec![]).is_err());
+    }
+}
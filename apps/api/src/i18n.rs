@@ -0,0 +1,945 @@
+//! Localized API messages.
+//!
+//! Error and warning messages are written in English at the call site. The
+//! `locale` middleware picks a [`Locale`] from the request's `Accept-Language`
+//! header and scopes it over the handler; [`AppError`](crate::AppError) and
+//! [`ApiWarning`](crate::response::ApiWarning) constructors pass their message
+//! through [`localize`], which looks it up in the catalog below.
+//!
+//! Catalog entries match the English message exactly, or as a pattern where
+//! `{}` stands for a value filled in by `format!` (a field name, a count, an
+//! ID). Captured values are carried into the translation in the same order.
+//! Messages with no entry are returned in English.
+
+use std::borrow::Cow;
+
+/// A language the API can answer in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+}
+
+impl Locale {
+    /// Every supported locale.
+    pub const ALL: &'static [Locale] = &[Locale::En, Locale::Es];
+
+    /// BCP 47 language tag, as sent in `Content-Language`.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+        }
+    }
+
+    /// Parse a language tag, ignoring region and case (`es-MX` -> `Es`).
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        Locale::ALL
+            .iter()
+            .copied()
+            .find(|locale| locale.code() == primary)
+    }
+
+    /// Choose the best supported locale for an `Accept-Language` header.
+    ///
+    /// Picks the supported language with the highest quality value, keeping
+    /// header order on ties. Falls back to English when nothing matches.
+    pub fn negotiate(accept_language: Option<&str>) -> Self {
+        let Some(header) = accept_language else {
+            return Locale::default();
+        };
+
+        let mut best: Option<(Locale, f32)> = None;
+        for entry in header.split(',') {
+            let mut parts = entry.split(';');
+            let Some(locale) = parts.next().and_then(Locale::from_tag) else {
+                continue;
+            };
+            let quality = parts
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if quality <= 0.0 {
+                continue;
+            }
+            if best.is_none_or(|(_, q)| quality > q) {
+                best = Some((locale, quality));
+            }
+        }
+
+        best.map(|(locale, _)| locale).unwrap_or_default()
+    }
+}
+
+tokio::task_local! {
+    static LOCALE: Locale;
+}
+
+/// Run a future with `locale` as the current locale.
+pub async fn scope<F: std::future::Future>(locale: Locale, f: F) -> F::Output {
+    LOCALE.scope(locale, f).await
+}
+
+/// Locale of the request being handled, or English outside a request.
+pub fn current() -> Locale {
+    LOCALE.try_with(|locale| *locale).unwrap_or_default()
+}
+
+/// Translate a message into the current locale.
+pub fn localize(message: String) -> String {
+    match translate(&message, current()) {
+        Cow::Borrowed(_) => message,
+        Cow::Owned(translated) => translated,
+    }
+}
+
+/// Translate an English message into `locale`.
+pub fn translate(message: &str, locale: Locale) -> Cow<'_, str> {
+    let catalog = match locale {
+        Locale::En => return Cow::Borrowed(message),
+        Locale::Es => ES,
+    };
+
+    catalog
+        .iter()
+        .find_map(|(pattern, translation)| {
+            match_pattern(pattern, message).map(|values| fill(translation, &values))
+        })
+        .map(Cow::Owned)
+        .unwrap_or(Cow::Borrowed(message))
+}
+
+/// Match a message against a pattern, returning the values for each `{}`.
+fn match_pattern<'a>(pattern: &str, message: &'a str) -> Option<Vec<&'a str>> {
+    let segments: Vec<&str> = pattern.split("{}").collect();
+    let (first, rest_segments) = segments.split_first()?;
+    let mut rest = message.strip_prefix(first)?;
+    let Some((last, middle)) = rest_segments.split_last() else {
+        return rest.is_empty().then(Vec::new);
+    };
+
+    let mut values = Vec::with_capacity(rest_segments.len());
+    for segment in middle {
+        let end = rest.find(segment).filter(|&end| end > 0)?;
+        values.push(&rest[..end]);
+        rest = &rest[end + segment.len()..];
+    }
+    let value = rest.strip_suffix(last).filter(|value| !value.is_empty())?;
+    values.push(value);
+
+    Some(values)
+}
+
+/// Substitute values into a translation's `{}` slots, in order.
+fn fill(translation: &str, values: &[&str]) -> String {
+    let mut output = String::with_capacity(translation.len());
+    let mut values = values.iter();
+    let mut pieces = translation.split("{}");
+    if let Some(first) = pieces.next() {
+        output.push_str(first);
+    }
+    for piece in pieces {
+        output.push_str(values.next().copied().unwrap_or_default());
+        output.push_str(piece);
+    }
+    output
+}
+
+/// Spanish catalog. Exact messages come before the patterns that would
+/// otherwise match them.
+const ES: &[(&str, &str)] = &[
+    // Authentication
+    ("Invalid PIN", "PIN no válido"),
+    (
+        "Challenge entry isn't set up for this employee; log in with the PIN",
+        "El acceso por desafío no está configurado para este empleado; inicie sesión con el PIN",
+    ),
+    ("Invalid admin PIN", "PIN de administrador no válido"),
+    ("Invalid current PIN", "El PIN actual no es válido"),
+    ("PIN is required", "El PIN es obligatorio"),
+    ("PIN cannot be empty", "El PIN no puede estar vacío"),
+    ("New PIN is required", "El nuevo PIN es obligatorio"),
+    ("Invalid or expired session", "Sesión no válida o vencida"),
+    (
+        "Missing authentication. Provide X-Employee-Session header.",
+        "Falta autenticación. Envíe el encabezado X-Employee-Session.",
+    ),
+    (
+        "Missing authentication. Provide X-Admin-Session header.",
+        "Falta autenticación. Envíe el encabezado X-Admin-Session.",
+    ),
+    (
+        "Missing authentication. Provide X-Partner-Key header.",
+        "Falta autenticación. Envíe el encabezado X-Partner-Key.",
+    ),
+    ("Invalid partner API key", "Clave de API de socio no válida"),
+    (
+        "Missing authentication. Provide X-Facet-Signature header.",
+        "Falta autenticación. Envíe el encabezado X-Facet-Signature.",
+    ),
+    ("Invalid webhook signature", "Firma del webhook no válida"),
+    (
+        "Phone intake is not enabled",
+        "La recepción telefónica no está habilitada",
+    ),
+    (
+        "Missing X-Admin-Session header",
+        "Falta el encabezado X-Admin-Session",
+    ),
+    ("Missing X-Admin-PIN header", "Falta el encabezado X-Admin-PIN"),
+    (
+        "X-Admin-PIN header is required",
+        "El encabezado X-Admin-PIN es obligatorio",
+    ),
+    (
+        "Invalid X-Admin-PIN header value",
+        "Valor no válido en el encabezado X-Admin-PIN",
+    ),
+    (
+        "Invalid X-Employee-ID header value",
+        "Valor no válido en el encabezado X-Employee-ID",
+    ),
+    (
+        "X-Employee-ID must be a valid UUID",
+        "X-Employee-ID debe ser un UUID válido",
+    ),
+    (
+        "Too many authentication attempts. Please wait before trying again.",
+        "Demasiados intentos de autenticación. Espere antes de volver a intentarlo.",
+    ),
+    ("Too many requests", "Demasiadas solicitudes"),
+    (
+        "Too many wrong PINs; ask an admin to unlock you",
+        "Demasiados PIN incorrectos; pida a un administrador que lo desbloquee",
+    ),
+    (
+        "Partner rate limit exceeded. Please wait before trying again.",
+        "Se superó el límite de solicitudes del socio. Espere antes de volver a intentarlo.",
+    ),
+    ("Invalid recovery code", "Código de recuperación no válido"),
+    (
+        "Too many recovery attempts. Please wait before trying again.",
+        "Demasiados intentos de recuperación. Espere antes de volver a intentarlo.",
+    ),
+    (
+        "Setup has already been completed",
+        "La configuración inicial ya se completó",
+    ),
+    (
+        "Storage link is invalid or has expired",
+        "El enlace de almacenamiento no es válido o ha caducado",
+    ),
+    (
+        "Initial setup deadline has passed. Please contact system administrator.",
+        "Venció el plazo de configuración inicial. Comuníquese con el administrador del sistema.",
+    ),
+    // Permissions
+    (
+        "You do not have permission to perform this action",
+        "No tiene permiso para realizar esta acción",
+    ),
+    (
+        "You do not have permission to access this ticket",
+        "No tiene permiso para acceder a este ticket",
+    ),
+    (
+        "You do not have permission to access this {}",
+        "No tiene permiso para acceder a este recurso",
+    ),
+    (
+        "You do not have permission to close tickets",
+        "No tiene permiso para cerrar tickets",
+    ),
+    (
+        "You do not have permission to delete photos",
+        "No tiene permiso para eliminar fotos",
+    ),
+    (
+        "You do not have permission to change prices",
+        "No tiene permiso para cambiar precios",
+    ),
+    (
+        "Administrators have every permission",
+        "Los administradores tienen todos los permisos",
+    ),
+    ("{} cannot be assigned", "{} no se puede asignar"),
+    // Missing resources
+    ("Ticket not found", "Ticket no encontrado"),
+    ("Customer not found", "Cliente no encontrado"),
+    ("Employee not found", "Empleado no encontrado"),
+    (
+        "Employee not found or inactive",
+        "Empleado no encontrado o inactivo",
+    ),
+    (
+        "Taken in by employee not found",
+        "No se encontró el empleado que recibió el artículo",
+    ),
+    ("Location not found", "Ubicación no encontrada"),
+    ("Storage location not found", "Ubicación de almacenamiento no encontrada"),
+    (
+        "Storage location not found or inactive",
+        "Ubicación de almacenamiento no encontrada o inactiva",
+    ),
+    (
+        "Storage location {} does not exist or is inactive",
+        "La ubicación de almacenamiento {} no existe o está inactiva",
+    ),
+    (
+        "The storage location belongs to another store",
+        "La ubicación de almacenamiento pertenece a otra tienda",
+    ),
+    (
+        "X-Store-ID must name an active store",
+        "X-Store-ID debe indicar una tienda activa",
+    ),
+    (
+        "store_id must name an active store",
+        "store_id debe indicar una tienda activa",
+    ),
+    (
+        "Ticket prefix {} is already used by another store",
+        "El prefijo de ticket {} ya lo usa otra tienda",
+    ),
+    (
+        "Change {} on the primary store; they apply to every store",
+        "Cambie {} en la tienda principal; se aplican a todas las tiendas",
+    ),
+    (
+        "The primary store can't be closed",
+        "La tienda principal no se puede cerrar",
+    ),
+    ("Store not found", "Tienda no encontrada"),
+    ("Photo not found", "Foto no encontrada"),
+    ("Calendar feed not found", "Calendario no encontrado"),
+    (
+        "Authorized pickup not found",
+        "Autorización de recogida no encontrada",
+    ),
+    ("Signature not found", "Firma no encontrada"),
+    ("File not found", "Archivo no encontrado"),
+    ("Partner not found", "Socio no encontrado"),
+    ("Intake draft not found", "Borrador de recepción no encontrado"),
+    ("Campaign not found", "Campaña no encontrada"),
+    ("Report not found", "Informe no encontrado"),
+    (
+        "Notification template not found",
+        "Plantilla de notificación no encontrada",
+    ),
+    ("Resource not found", "Recurso no encontrado"),
+    ("Referenced entity not found", "No se encontró la entidad referenciada"),
+    (
+        "Metal type not found in price table",
+        "El tipo de metal no está en la tabla de precios",
+    ),
+    ("{} not found", "{} no encontrado"),
+    (
+        "Metrics are disabled; set METRICS_ENABLED to serve them",
+        "Las métricas están desactivadas; configure METRICS_ENABLED para publicarlas",
+    ),
+    // Conflicts
+    (
+        "A resource with that identifier already exists",
+        "Ya existe un recurso con ese identificador",
+    ),
+    (
+        "A location with this name already exists",
+        "Ya existe una ubicación con ese nombre",
+    ),
+    (
+        "A service with this name already exists",
+        "Ya existe un servicio con ese nombre",
+    ),
+    (
+        "service_ids must list at most {} services",
+        "service_ids debe incluir como máximo {} servicios",
+    ),
+    (
+        "service_ids must not repeat a service",
+        "service_ids no debe repetir un servicio",
+    ),
+    (
+        "service_ids must name active services",
+        "service_ids debe indicar servicios activos",
+    ),
+    (
+        "Customer has {} open ticket(s); close them before deleting",
+        "El cliente tiene {} ticket(s) abierto(s); ciérrelos antes de eliminarlo",
+    ),
+    (
+        "Customer is linked to partner {}; it can't be deleted",
+        "El cliente está vinculado al socio {}; no se puede eliminar",
+    ),
+    (
+        "Customer is linked to partner {}; it can't be merged",
+        "El cliente está vinculado al socio {}; no se puede fusionar",
+    ),
+    (
+        "Ticket status changed while updating; reload and retry",
+        "El estado del ticket cambió durante la actualización; recargue y reintente",
+    ),
+    (
+        "A request with this Idempotency-Key is still in progress; retry shortly",
+        "Una solicitud con este Idempotency-Key todavía está en curso; reintente en un momento",
+    ),
+    // Tickets
+    (
+        "Cannot edit closed or archived ticket without admin override",
+        "No se puede editar un ticket cerrado o archivado sin autorización de administrador",
+    ),
+    (
+        "Cannot modify rush flag on closed or archived ticket",
+        "No se puede cambiar la urgencia de un ticket cerrado o archivado",
+    ),
+    (
+        "Cannot record QC on closed or archived ticket",
+        "No se puede registrar control de calidad en un ticket cerrado o archivado",
+    ),
+    (
+        "Cannot transition from {} to {}",
+        "No se puede cambiar el estado de {} a {}",
+    ),
+    (
+        "Unknown queue lane '{}'; expected intake, in_progress, waiting_on_parts, or ready_for_pickup",
+        "Carril de la cola '{}' desconocido; se esperaba intake, in_progress, waiting_on_parts o ready_for_pickup",
+    ),
+    ("Ticket {} is not in this lane", "El ticket {} no está en este carril"),
+    ("Ticket {} is listed more than once", "El ticket {} aparece más de una vez"),
+    (
+        "A ticket cannot be moved after itself",
+        "Un ticket no se puede mover después de sí mismo",
+    ),
+    ("Send either ticket_ids or move", "Envíe ticket_ids o move"),
+    (
+        "ticket_ids must list between 1 and {} tickets",
+        "ticket_ids debe incluir entre 1 y {} tickets",
+    ),
+    (
+        "Only tickets with status 'ready_for_pickup' can be closed, current status is '{}'",
+        "Solo se pueden cerrar tickets con estado 'ready_for_pickup'; el estado actual es '{}'",
+    ),
+    (
+        "Only tickets with status 'closed' can be reopened, current status is '{}'",
+        "Solo se pueden reabrir tickets con estado 'closed'; el estado actual es '{}'",
+    ),
+    (
+        "Only tickets with status 'closed' can be archived, current status is '{}'",
+        "Solo se pueden archivar tickets con estado 'closed'; el estado actual es '{}'",
+    ),
+    (
+        "Provide either customer_id or customer, not both",
+        "Envíe customer_id o customer, no ambos",
+    ),
+    (
+        "Cannot change the quote on a closed or archived ticket",
+        "No se puede cambiar la cotización de un ticket cerrado o archivado",
+    ),
+    ("Ticket has no quote_amount", "El ticket no tiene quote_amount"),
+    (
+        "Quote cannot move from {} to {}",
+        "La cotización no puede pasar de {} a {}",
+    ),
+    (
+        "Quote changed while recording; reload and retry",
+        "La cotización cambió mientras se registraba; recargue e intente de nuevo",
+    ),
+    (
+        "The customer must approve the quote before work starts",
+        "El cliente debe aprobar la cotización antes de empezar el trabajo",
+    ),
+    (
+        "Provide either item fields or items, not both",
+        "Envíe los campos del artículo o items, no ambos",
+    ),
+    (
+        "items must list at most {} items",
+        "items debe incluir como máximo {} artículos",
+    ),
+    (
+        "quote_amount must equal the sum of the item quotes ({})",
+        "quote_amount debe ser igual a la suma de las cotizaciones de los artículos ({})",
+    ),
+    (
+        "Either customer_id or customer is required",
+        "Se requiere customer_id o customer",
+    ),
+    ("Ticket is not deleted", "El ticket no está eliminado"),
+    (
+        "Print receipt before completing intake",
+        "Imprima el recibo antes de completar la recepción",
+    ),
+    (
+        "A passing QC check is required before marking ready for pickup",
+        "Se requiere un control de calidad aprobado antes de marcar como listo para recoger",
+    ),
+    (
+        "No QC checklist is configured",
+        "No hay una lista de control de calidad configurada",
+    ),
+    (
+        "Unknown QC checklist item: {}",
+        "Elemento desconocido de la lista de control de calidad: {}",
+    ),
+    (
+        "Duplicate QC checklist item: {}",
+        "Elemento repetido de la lista de control de calidad: {}",
+    ),
+    (
+        "Missing result for QC checklist item: {}",
+        "Falta el resultado del elemento de control de calidad: {}",
+    ),
+    (
+        "defect_reason is required when a QC item fails",
+        "defect_reason es obligatorio cuando un elemento de control de calidad falla",
+    ),
+    (
+        "defect_reason is only allowed when a QC item fails",
+        "defect_reason solo se permite cuando un elemento de control de calidad falla",
+    ),
+    (
+        "custody.witnessed_by is required for high-value items",
+        "custody.witnessed_by es obligatorio para artículos de alto valor",
+    ),
+    (
+        "Custody witness must be a different employee",
+        "El testigo de custodia debe ser otro empleado",
+    ),
+    // Materials
+    (
+        "A material with this name already exists",
+        "Ya existe un material con ese nombre",
+    ),
+    ("Material is inactive", "El material está inactivo"),
+    ("Not enough {} in stock", "No hay suficiente {} en existencia"),
+    (
+        "Cannot record materials on an archived ticket",
+        "No se pueden registrar materiales en un ticket archivado",
+    ),
+    (
+        "quantity must be greater than 0 and at most {}",
+        "quantity debe ser mayor que 0 y como máximo {}",
+    ),
+    // Appraisals
+    (
+        "Cannot appraise an archived ticket",
+        "No se puede tasar un ticket archivado",
+    ),
+    (
+        "appraised_value cannot be negative",
+        "appraised_value no puede ser negativo",
+    ),
+    (
+        "appraised_on cannot be in the future",
+        "appraised_on no puede estar en el futuro",
+    ),
+    (
+        "No more than {} certification numbers are allowed",
+        "No se permiten más de {} números de certificación",
+    ),
+    // Photos
+    ("Empty file provided", "El archivo está vacío"),
+    ("No 'photo' field in request", "La solicitud no tiene el campo 'photo'"),
+    (
+        "Maximum {} photos per ticket reached",
+        "Se alcanzó el máximo de {} fotos por ticket",
+    ),
+    (
+        "File too large. Maximum size is {}MB",
+        "Archivo demasiado grande. El tamaño máximo es {}MB",
+    ),
+    (
+        "Invalid file type '{}'. Allowed types: jpeg, png, webp",
+        "Tipo de archivo '{}' no válido. Tipos permitidos: jpeg, png, webp",
+    ),
+    (
+        "File content does not match declared Content-Type. Only JPEG, PNG, and WebP images are allowed.",
+        "El contenido del archivo no coincide con el Content-Type declarado. Solo se permiten imágenes JPEG, PNG y WebP.",
+    ),
+    (
+        "The image could not be read. It may be corrupt or truncated.",
+        "No se pudo leer la imagen. Puede estar dañada o incompleta.",
+    ),
+    // Pickups
+    (
+        "Cannot authorize a pickup on a closed or archived ticket",
+        "No se puede autorizar una recogida en un ticket cerrado o archivado",
+    ),
+    (
+        "Authorized pickup is not active for this ticket",
+        "La autorización de recogida no está activa para este ticket",
+    ),
+    (
+        "ID type, ID number, and signature are required when someone other than the customer picks up",
+        "Se requieren el tipo y número de identificación y la firma cuando recoge alguien distinto del cliente",
+    ),
+    (
+        "Signature must be a base64-encoded PNG image",
+        "La firma debe ser una imagen PNG codificada en base64",
+    ),
+    (
+        "Signature exceeds maximum size of {}KB",
+        "La firma supera el tamaño máximo de {}KB",
+    ),
+    // Customers
+    (
+        "source_customer_id must be a different customer",
+        "source_customer_id debe ser otro cliente",
+    ),
+    // Settings
+    (
+        "qc_checklist cannot have more than {} items",
+        "qc_checklist no puede tener más de {} elementos",
+    ),
+    (
+        "Duplicate qc_checklist item: {}",
+        "Elemento repetido en qc_checklist: {}",
+    ),
+    (
+        "business_hours[{}] must close after it opens",
+        "business_hours[{}] debe cerrar después de abrir",
+    ),
+    (
+        "business_hours lists weekday {} more than once",
+        "business_hours indica el día {} más de una vez",
+    ),
+    (
+        "Cannot have more than {} location rules",
+        "No puede haber más de {} reglas de ubicación",
+    ),
+    (
+        "Each location rule needs an item_type or min_quote_amount",
+        "Cada regla de ubicación necesita item_type o min_quote_amount",
+    ),
+    (
+        "Cannot have more than {} metal prices",
+        "No puede haber más de {} precios de metal",
+    ),
+    ("Duplicate metal_type: {}", "metal_type repetido: {}"),
+    (
+        "Duplicate storage location: {}",
+        "Ubicación de almacenamiento repetida: {}",
+    ),
+    (
+        "Settings were changed by someone else; reload and try again",
+        "Otra persona cambió la configuración; vuelva a cargarla e intente de nuevo",
+    ),
+    ("Settings section not found", "Sección de configuración no encontrada"),
+    ("Invalid settings: {}", "Configuración no válida: {}"),
+    (
+        "Unsupported config format_version {}; expected {}",
+        "format_version de configuración no admitido {}; se esperaba {}",
+    ),
+    ("Invalid If-Match header value", "Valor no válido en el encabezado If-Match"),
+    (
+        "purity must be greater than 0 and at most 1",
+        "purity debe ser mayor que 0 y como máximo 1",
+    ),
+    // Reports
+    (
+        "from_date must be on or before to_date",
+        "from_date debe ser igual o anterior a to_date",
+    ),
+    (
+        "Unknown dimension {}; use one of: {}",
+        "Dimensión desconocida {}; use una de: {}",
+    ),
+    (
+        "Unknown measure {}; use one of: {}",
+        "Medida desconocida {}; use una de: {}",
+    ),
+    ("Duplicate dimension {}", "Dimensión duplicada {}"),
+    ("Duplicate measure {}", "Medida duplicada {}"),
+    (
+        "A report can have at most {} dimensions",
+        "Un informe puede tener como máximo {} dimensiones",
+    ),
+    (
+        "A report needs at least one measure",
+        "Un informe necesita al menos una medida",
+    ),
+    (
+        "A report with this name already exists",
+        "Ya existe un informe con este nombre",
+    ),
+    // Partners
+    (
+        "Customer is already linked to a partner",
+        "El cliente ya está vinculado a un socio",
+    ),
+    (
+        "No active storage location is available",
+        "No hay ninguna ubicación de almacenamiento activa disponible",
+    ),
+    // Item types
+    (
+        "Cannot have more than {} item types",
+        "No puede haber más de {} tipos de artículo",
+    ),
+    ("Duplicate item type: {}", "Tipo de artículo repetido: {}"),
+    (
+        "{} must be a configured item type or \"{}\"",
+        "{} debe ser un tipo de artículo configurado o \"{}\"",
+    ),
+    (
+        "{} needs at least {} photos before work starts",
+        "{} necesita al menos {} fotos antes de empezar el trabajo",
+    ),
+    // Promise date reasons
+    (
+        "Cannot have more than {} promise date reasons",
+        "No puede haber más de {} motivos de cambio de fecha",
+    ),
+    (
+        "Duplicate promise date reason: {}",
+        "Motivo de cambio de fecha repetido: {}",
+    ),
+    (
+        "Unknown promise date reason: {}",
+        "Motivo de cambio de fecha desconocido: {}",
+    ),
+    (
+        "promise_date_reason is required when moving the promise date later",
+        "promise_date_reason es obligatorio al retrasar la fecha prometida",
+    ),
+    // Rush pricing
+    (
+        "Cannot have more than {} rush surcharge tiers",
+        "No puede haber más de {} tramos de recargo urgente",
+    ),
+    (
+        "Each rush surcharge tier needs a different max_lead_days",
+        "Cada tramo de recargo urgente necesita un max_lead_days distinto",
+    ),
+    (
+        "rush_surcharge requires a quote_amount",
+        "rush_surcharge requiere un quote_amount",
+    ),
+    (
+        "rush_surcharge cannot exceed quote_amount",
+        "rush_surcharge no puede superar quote_amount",
+    ),
+    // Transfers
+    (
+        "Closed or archived tickets cannot be transferred",
+        "Los tickets cerrados o archivados no se pueden transferir",
+    ),
+    (
+        "Ticket is already at this storage location",
+        "El ticket ya está en esta ubicación de almacenamiento",
+    ),
+    (
+        "Ticket already has a pending transfer",
+        "El ticket ya tiene una transferencia pendiente",
+    ),
+    (
+        "Ticket has no pending transfer",
+        "El ticket no tiene ninguna transferencia pendiente",
+    ),
+    (
+        "Ticket is in transit; accept or cancel the transfer first",
+        "El ticket está en tránsito; primero acepte o cancele la transferencia",
+    ),
+    // Payments
+    (
+        "Payments of {} do not cover the actual amount of {}",
+        "Los pagos de {} no cubren el importe real de {}",
+    ),
+    // Intake drafts
+    (
+        "Intake draft has already been converted or dismissed",
+        "El borrador de recepción ya se convirtió o se descartó",
+    ),
+    (
+        "caller_phone, requested_work, or transcript is required",
+        "Se requiere caller_phone, requested_work o transcript",
+    ),
+    // Campaigns
+    (
+        "Campaign has already finished",
+        "La campaña ya terminó",
+    ),
+    (
+        "No customers match this segment",
+        "Ningún cliente coincide con este segmento",
+    ),
+    (
+        "closed_from must be on or before closed_to",
+        "closed_from debe ser igual o anterior a closed_to",
+    ),
+    (
+        "Placeholder {} is not available in campaigns",
+        "El marcador {} no está disponible en campañas",
+    ),
+    // Imports
+    ("No 'file' field in request", "La solicitud no tiene el campo 'file'"),
+    (
+        "A customer with this phone or email already exists",
+        "Ya existe un cliente con este teléfono o email",
+    ),
+    (
+        "CSV is missing required column: {}",
+        "Al CSV le falta la columna obligatoria: {}",
+    ),
+    (
+        "CSV cannot have more than {} rows",
+        "El CSV no puede tener más de {} filas",
+    ),
+    ("Failed to read CSV header: {}", "No se pudo leer el encabezado del CSV: {}"),
+    ("Malformed row: {}", "Fila mal formada: {}"),
+    ("Duplicate of row {}", "Repite la fila {}"),
+    // Request body
+    (
+        "Request body exceeds maximum allowed size",
+        "El cuerpo de la solicitud supera el tamaño máximo permitido",
+    ),
+    ("Invalid request body: {}", "Cuerpo de solicitud no válido: {}"),
+    (
+        "Idempotency-Key must be 1 to {} characters",
+        "Idempotency-Key debe tener entre 1 y {} caracteres",
+    ),
+    (
+        "Idempotency-Key was already used for a different request",
+        "Idempotency-Key ya se usó para una solicitud diferente",
+    ),
+    // Field validation
+    (
+        "phone contains invalid characters (only digits, spaces, dashes, parentheses, and + are allowed)",
+        "phone contiene caracteres no válidos (solo se permiten dígitos, espacios, guiones, paréntesis y +)",
+    ),
+    ("email must contain @ symbol", "email debe contener el símbolo @"),
+    ("invalid email format", "formato de email no válido"),
+    ("Invalid email format", "Formato de email no válido"),
+    (
+        "{} exceeds maximum length of {} characters",
+        "{} supera la longitud máxima de {} caracteres",
+    ),
+    ("{} is required", "{} es obligatorio"),
+    (
+        "{} cannot have more than {} items",
+        "{} no puede tener más de {} elementos",
+    ),
+    ("Duplicate {} item: {}", "Elemento repetido en {}: {}"),
+    ("{} cannot be negative", "{} no puede ser negativo"),
+    ("{} must be greater than 0", "{} debe ser mayor que 0"),
+    ("{} must be between {} and {}", "{} debe estar entre {} y {}"),
+    ("{} must be true or false", "{} debe ser true o false"),
+    (
+        "{} must be a date (YYYY-MM-DD)",
+        "{} debe ser una fecha (AAAA-MM-DD)",
+    ),
+    ("{} must be a number", "{} debe ser un número"),
+    // Warnings
+    (
+        "Promise date {} is in the past",
+        "La fecha de entrega {} ya pasó",
+    ),
+    (
+        "Promise date {} falls on a Sunday",
+        "La fecha de entrega {} cae en domingo",
+    ),
+    (
+        "Quote of {} is below {}; confirm this is correct",
+        "La cotización de {} es menor que {}; confirme que es correcta",
+    ),
+    (
+        "Quote of {} is above {}; confirm this is correct",
+        "La cotización de {} es mayor que {}; confirme que es correcta",
+    ),
+    // Server errors
+    ("Internal server error", "Error interno del servidor"),
+    ("Database error", "Error de base de datos"),
+    (
+        "Store could not be determined",
+        "No se pudo determinar la tienda",
+    ),
+    (
+        "The request took too long and was cancelled",
+        "La solicitud tardó demasiado y se canceló",
+    ),
+    (
+        "Photo storage is temporarily unavailable; try again shortly",
+        "El almacenamiento de fotos no está disponible por ahora; inténtelo de nuevo en breve",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_picks_highest_quality_supported_language() {
+        assert_eq!(Locale::negotiate(None), Locale::En);
+        assert_eq!(Locale::negotiate(Some("es")), Locale::Es);
+        assert_eq!(Locale::negotiate(Some("es-MX,es;q=0.9")), Locale::Es);
+        assert_eq!(
+            Locale::negotiate(Some("fr-FR, es;q=0.8, en;q=0.5")),
+            Locale::Es
+        );
+        assert_eq!(Locale::negotiate(Some("en;q=0.4, es;q=0.6")), Locale::Es);
+        assert_eq!(Locale::negotiate(Some("en, es")), Locale::En);
+        assert_eq!(Locale::negotiate(Some("es;q=0, fr")), Locale::En);
+        assert_eq!(Locale::negotiate(Some("*")), Locale::En);
+    }
+
+    #[test]
+    fn test_translate_exact_and_pattern_messages() {
+        assert_eq!(
+            translate("PIN is required", Locale::Es),
+            "El PIN es obligatorio"
+        );
+        assert_eq!(
+            translate("name is required", Locale::Es),
+            "name es obligatorio"
+        );
+        assert_eq!(
+            translate(
+                "Cannot transition from \"intake\" to \"closed\"",
+                Locale::Es
+            ),
+            "No se puede cambiar el estado de \"intake\" a \"closed\""
+        );
+        assert_eq!(
+            translate("phone exceeds maximum length of 50 characters", Locale::Es),
+            "phone supera la longitud máxima de 50 caracteres"
+        );
+        assert_eq!(
+            translate("Ticket not found", Locale::Es),
+            "Ticket no encontrado"
+        );
+        assert_eq!(
+            translate("Widget not found", Locale::Es),
+            "Widget no encontrado"
+        );
+    }
+
+    #[test]
+    fn test_translate_falls_back_to_english() {
+        assert_eq!(translate("Something new", Locale::Es), "Something new");
+        assert_eq!(translate(" is required", Locale::Es), " is required");
+        assert_eq!(translate("PIN is required", Locale::En), "PIN is required");
+    }
+
+    #[test]
+    fn test_catalog_entries_keep_their_values() {
+        for (pattern, translation) in ES {
+            let slots = pattern.matches("{}").count();
+            let filled = translation.matches("{}").count();
+            assert!(filled == slots || filled == 0, "{}", pattern);
+            assert!(!pattern.contains("{}{}"), "{}", pattern);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_localize_uses_scoped_locale() {
+        assert_eq!(localize("PIN is required".to_string()), "PIN is required");
+        let message = scope(Locale::Es, async {
+            crate::AppError::validation("PIN is required")
+                .message()
+                .to_string()
+        })
+        .await;
+        assert_eq!(message, "El PIN es obligatorio");
+    }
+}
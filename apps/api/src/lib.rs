@@ -2,14 +2,21 @@
 //!
 //! This crate provides the REST API for the Facet application.
 
+// Placeholder and constraint sanity tests assert on constants by design.
+#![cfg_attr(test, allow(clippy::assertions_on_constants))]
+
 pub mod auth;
+#[cfg(feature = "bench")]
+pub mod bench_data;
 pub mod config;
 pub mod cors;
 pub mod db;
 pub mod error;
 pub mod handlers;
+pub mod i18n;
 pub mod middleware;
 pub mod models;
+pub mod openapi;
 pub mod repositories;
 pub mod response;
 pub mod routes;
@@ -20,10 +27,13 @@ pub mod validation;
 
 pub use config::Config;
 pub use cors::build_cors_layer;
-pub use db::{create_pool, test_connection, DbConfig};
+pub use db::{
+    applied_migration_version, create_pool, latest_migration_version, run_migrations,
+    test_connection, DbConfig,
+};
 pub use error::{codes as error_codes, AppError};
 pub use models::{CreateTicket, Ticket, TicketFilters, TicketStatus, TicketSummary, UpdateTicket};
 pub use repositories::TicketRepository;
 pub use response::{created, empty, no_content, ok, ApiResponse, ApiResult};
 pub use routes::{api_router, api_router_with_limits, AppState, BodyLimitConfig};
-pub use storage::{StorageClient, StorageConfig, StorageError, StorageResult};
+pub use storage::{StorageBackend, StorageClient, StorageConfig, StorageError, StorageResult};
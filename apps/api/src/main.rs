@@ -1,9 +1,25 @@
-use api::repositories::AdminSessionRepository;
+use api::middleware::{
+    idempotency, rate_limit, MemoryRateLimitBackend, PostgresRateLimitBackend, RateLimitBackend,
+    RateLimitBackendKind,
+};
+use api::repositories::{
+    AdminSessionRepository, EmployeeSessionRepository, QueueSnapshotRepository,
+};
+use api::services::notifications::{
+    NotificationService, SmtpEmailSender, TwilioSmsProvider, RETRY_INTERVAL,
+};
+use api::services::scheduler::{Scheduler, SESSION_CLEANUP_INTERVAL};
+use api::services::{
+    archive, campaigns, integrity, reminders, storage_reconcile, warmup, webhooks,
+};
+use api::storage;
 use api::{
-    api_router_with_limits, build_cors_layer, create_pool, test_connection, AppState,
-    BodyLimitConfig, Config, DbConfig,
+    api_router_with_limits, applied_migration_version, build_cors_layer, create_pool,
+    latest_migration_version, run_migrations, test_connection, AppState, BodyLimitConfig, Config,
+    DbConfig,
 };
 use std::net::SocketAddr;
+use std::sync::Arc;
 use tokio::signal;
 use tower_http::trace::TraceLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -26,7 +42,9 @@ async fn main() {
         .init();
 
     // Create database connection pool
-    let db_config = DbConfig::new(&config.database_url);
+    // Statements stop once the slowest request budget is spent
+    let request_timeouts = config.request_timeouts();
+    let db_config = DbConfig::new(&config.database_url).statement_timeout(request_timeouts.slow);
     let db_pool = create_pool(&db_config)
         .await
         .expect("Failed to create database pool");
@@ -36,20 +54,287 @@ async fn main() {
         .await
         .expect("Failed to connect to database");
 
-    // Clean up any expired admin sessions on startup
-    match AdminSessionRepository::delete_expired(&db_pool).await {
-        Ok(count) => {
+    // Apply pending migrations, or warn when the schema is behind this build
+    if config.run_migrations {
+        run_migrations(&db_pool)
+            .await
+            .expect("Failed to run database migrations");
+    }
+    let schema_version = applied_migration_version(&db_pool)
+        .await
+        .expect("Failed to read the database migration version");
+    let latest_schema_version = latest_migration_version();
+    match schema_version {
+        Some(version) if version >= latest_schema_version => {
+            tracing::info!("Database schema at migration {}", version)
+        }
+        Some(version) => tracing::warn!(
+            "Database schema at migration {} but this build expects {}; set RUN_MIGRATIONS=true to apply the rest",
+            version,
+            latest_schema_version
+        ),
+        None => tracing::warn!(
+            "Database migrations aren't tracked; set RUN_MIGRATIONS=true to apply them on startup"
+        ),
+    }
+
+    // Configure customer notifications
+    let mut notifications = NotificationService::new();
+    match config.twilio_config() {
+        Some(twilio) => {
+            tracing::info!("SMS notifications enabled (Twilio)");
+            notifications = notifications.with_sms(Arc::new(TwilioSmsProvider::new(twilio)));
+        }
+        None => tracing::info!("SMS notifications disabled; set TWILIO_* to enable"),
+    }
+    match config.smtp_config().map(SmtpEmailSender::new) {
+        Some(Ok(sender)) => {
+            tracing::info!("Email notifications enabled (SMTP)");
+            notifications = notifications.with_email(Arc::new(sender));
+        }
+        Some(Err(err)) => tracing::warn!("Email notifications disabled: {}", err),
+        None => tracing::info!("Email notifications disabled; set SMTP_* to enable"),
+    }
+
+    // Connect the photo storage backend
+    let storage = storage::connect(config.backend_config())
+        .await
+        .expect("Failed to configure photo storage");
+    tracing::info!("Photo storage: {}", storage.name());
+
+    // Forwarding headers are only honored from these peers
+    let trusted_proxies = &config.trusted_proxies;
+    if trusted_proxies.ranges().is_empty() {
+        tracing::info!("Trusted proxies: none; client IPs come from the socket");
+    } else {
+        let ranges: Vec<String> = trusted_proxies
+            .ranges()
+            .iter()
+            .map(|r| r.to_string())
+            .collect();
+        tracing::info!("Trusted proxies: {}", ranges.join(", "));
+    }
+
+    // PIN and recovery backoff counters; Postgres shares them across replicas
+    let rate_limit_backend: Arc<dyn RateLimitBackend> = match config.rate_limit_backend {
+        RateLimitBackendKind::Memory => Arc::new(MemoryRateLimitBackend::new()),
+        RateLimitBackendKind::Postgres => Arc::new(PostgresRateLimitBackend::new(db_pool.clone())),
+    };
+    tracing::info!("Rate limit backend: {}", rate_limit_backend.name());
+
+    // Create application state
+    let state = AppState::new(db_pool)
+        .with_storage(storage)
+        .with_probe_policy(config.probe_policy)
+        .with_notifications(notifications)
+        .with_photo_processing(config.process_photos)
+        .with_photo_url_ttl(config.photo_url_ttl())
+        .with_phone_intake_secret(config.phone_intake_secret.clone())
+        .with_request_timeouts(request_timeouts)
+        .with_metrics(config.metrics_enabled)
+        .with_schema_version(schema_version)
+        .with_trusted_proxies(config.trusted_proxies.clone())
+        .with_rate_limit_policies(&config.rate_limits)
+        .with_rate_limit_backend(rate_limit_backend)
+        .with_pin_lockout(config.pin_lockout_policy());
+
+    // Register periodic background work; JOB_INTERVALS overrides intervals
+    let mut scheduler = Scheduler::new(state.jobs.clone(), config.job_intervals.clone());
+
+    // Delete admin and employee sessions past their expiry
+    let session_pool = state.db.clone();
+    scheduler.register("session_cleanup", SESSION_CLEANUP_INTERVAL, move || {
+        let pool = session_pool.clone();
+        async move {
+            let count = AdminSessionRepository::delete_expired(&pool).await?
+                + EmployeeSessionRepository::delete_expired(&pool).await?;
+            if count > 0 {
+                tracing::info!("Cleaned up {} expired session(s)", count);
+            }
+            Ok(())
+        }
+    });
+
+    // Archive tickets closed longer than the configured age
+    let archive_pool = state.db.clone();
+    scheduler.register("auto_archive", archive::AUTO_ARCHIVE_INTERVAL, move || {
+        let pool = archive_pool.clone();
+        async move {
+            let count = archive::auto_archive(&pool).await?;
+            if count > 0 {
+                tracing::info!("Auto-archived {} closed ticket(s)", count);
+            }
+            Ok(())
+        }
+    });
+
+    // Run the integrity checks and log any problems found
+    let integrity_pool = state.db.clone();
+    let integrity_storage = state.storage.clone();
+    scheduler.register(
+        "integrity_checks",
+        integrity::INTEGRITY_CHECK_INTERVAL,
+        move || {
+            let pool = integrity_pool.clone();
+            let storage = integrity_storage.clone();
+            async move {
+                let report = integrity::run_integrity_checks(&pool, storage.as_ref()).await?;
+                if !report.issues.is_empty() {
+                    tracing::warn!(
+                        "Integrity checks found {} problem(s); see GET /api/v1/admin/integrity",
+                        report.issues.len()
+                    );
+                }
+                Ok(())
+            }
+        },
+    );
+
+    // Delete photo objects that no photo record points at
+    let reconcile_pool = state.db.clone();
+    let reconcile_storage = state.storage.clone();
+    scheduler.register(
+        "storage_reconcile",
+        storage_reconcile::RECONCILE_INTERVAL,
+        move || {
+            let pool = reconcile_pool.clone();
+            let storage = reconcile_storage.clone();
+            async move {
+                let report = storage_reconcile::reconcile_orphans(&pool, storage.as_ref()).await?;
+                if !report.deleted.is_empty() {
+                    tracing::info!("Deleted {} orphaned photo object(s)", report.deleted.len());
+                }
+                if !report.failed.is_empty() {
+                    tracing::warn!(
+                        "Failed to delete {} orphaned photo object(s)",
+                        report.failed.len()
+                    );
+                }
+                Ok(())
+            }
+        },
+    );
+
+    // Send queued campaign messages at each campaign's send rate
+    let campaign_pool = state.db.clone();
+    let campaign_notifications = state.notifications.clone();
+    scheduler.register("campaigns", campaigns::CAMPAIGN_POLL_INTERVAL, move || {
+        let pool = campaign_pool.clone();
+        let notifications = campaign_notifications.clone();
+        async move {
+            campaigns::run_campaigns(&pool, &notifications).await?;
+            Ok(())
+        }
+    });
+
+    // Resend ticket messages queued while a provider's breaker was open
+    let retry_pool = state.db.clone();
+    let retry_notifications = state.notifications.clone();
+    scheduler.register("notification_retry", RETRY_INTERVAL, move || {
+        let pool = retry_pool.clone();
+        let notifications = retry_notifications.clone();
+        async move {
+            let count = notifications.retry_pending(&pool).await?;
             if count > 0 {
-                tracing::info!("Cleaned up {} expired admin session(s)", count);
+                tracing::info!("Retried {} queued notifications", count);
             }
+            Ok(())
         }
-        Err(err) => {
-            tracing::warn!("Failed to clean up expired sessions: {:?}", err);
+    });
+
+    // Each morning, remind staff of tickets due today or overdue
+    let reminder_pool = state.db.clone();
+    let reminder_notifications = state.notifications.clone();
+    scheduler.register(
+        "promise_reminders",
+        reminders::REMINDER_CHECK_INTERVAL,
+        move || {
+            let pool = reminder_pool.clone();
+            let notifications = reminder_notifications.clone();
+            async move {
+                if let Some(digest) = reminders::run_daily_reminders(&pool, &notifications).await? {
+                    tracing::info!(
+                        "Recorded promise-date reminders: {} overdue, {} due today",
+                        digest.overdue,
+                        digest.due_today
+                    );
+                }
+                Ok(())
+            }
+        },
+    );
+
+    // Send queued webhook deliveries, retrying failures with backoff
+    let webhook_pool = state.db.clone();
+    let webhook_client = reqwest::Client::builder()
+        .timeout(webhooks::WEBHOOK_REQUEST_TIMEOUT)
+        .build()
+        .expect("Failed to build webhook HTTP client");
+    scheduler.register(
+        "webhook_delivery",
+        webhooks::WEBHOOK_POLL_INTERVAL,
+        move || {
+            let pool = webhook_pool.clone();
+            let client = webhook_client.clone();
+            async move {
+                let (delivered, failed) = webhooks::deliver_pending(&pool, &client).await?;
+                if delivered + failed > 0 {
+                    tracing::info!("Sent webhooks: {} delivered, {} failed", delivered, failed);
+                }
+                Ok(())
+            }
+        },
+    );
+
+    // Delete idempotency keys past their replay window
+    let idempotency_pool = state.db.clone();
+    scheduler.register(
+        "idempotency_purge",
+        idempotency::IDEMPOTENCY_PURGE_INTERVAL,
+        move || {
+            let pool = idempotency_pool.clone();
+            async move {
+                idempotency::purge_expired(&pool).await?;
+                Ok(())
+            }
+        },
+    );
+
+    // Forget clients whose route rate limits have replenished, and PIN
+    // failures that have gone quiet
+    let route_rate_limits = state.route_rate_limits.clone();
+    let pin_rate_limit = state.rate_limit.clone();
+    scheduler.register(
+        "rate_limit_cleanup",
+        rate_limit::RATE_LIMIT_CLEANUP_INTERVAL,
+        move || {
+            route_rate_limits.retain_recent();
+            let pin_rate_limit = pin_rate_limit.clone();
+            async move {
+                pin_rate_limit.purge_idle().await?;
+                Ok(())
+            }
+        },
+    );
+
+    // Record lane counts for the queue trends report
+    match config.queue_snapshot_interval() {
+        Some(period) => {
+            let snapshot_pool = state.db.clone();
+            scheduler.register("queue_snapshots", period, move || {
+                let pool = snapshot_pool.clone();
+                async move {
+                    QueueSnapshotRepository::capture(&pool).await?;
+                    Ok(())
+                }
+            });
         }
+        None => tracing::info!("Queue snapshots disabled; set QUEUE_SNAPSHOT_MINUTES to enable"),
     }
 
-    // Create application state
-    let state = AppState::new(db_pool);
+    // Jobs stop waiting for their next run once shutdown starts draining
+    let shutdown = state.shutdown.clone();
+    scheduler.start(&shutdown);
 
     // Build CORS layer
     let cors = build_cors_layer(&config);
@@ -65,6 +350,21 @@ async fn main() {
         config.max_body_size / 1024,
         config.max_photo_size / (1024 * 1024)
     );
+    tracing::info!(
+        "Request timeouts: {}s default, {}s for reports, exports, imports, and PDFs",
+        request_timeouts.default.as_secs(),
+        request_timeouts.slow.as_secs()
+    );
+    if config.metrics_enabled {
+        tracing::info!("Prometheus metrics enabled at /metrics");
+    }
+    let drain_timeout = config.shutdown_drain_timeout();
+    tracing::info!("Shutdown drain timeout: {}s", drain_timeout.as_secs());
+
+    // Kept for warm-up once the listener is bound
+    let warmup_pool = state.db.clone();
+    let warmup_capture = state.debug_capture.clone();
+    let readiness = state.readiness.clone();
 
     // Build router with middleware
     let app = api_router_with_limits(state, body_limits)
@@ -78,15 +378,44 @@ async fn main() {
         .await
         .expect("Failed to bind to address");
 
+    // Warm caches and connections before /health/ready reports ready
+    tokio::spawn(warmup::run(
+        warmup_pool,
+        warmup_capture,
+        readiness,
+        shutdown.token(),
+    ));
+
+    // On SIGTERM, start draining: new requests get 503 and the listener closes
+    let signal_shutdown = shutdown.clone();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        signal_shutdown.begin();
+    });
+
     // Use into_make_service_with_connect_info to enable ConnectInfo<SocketAddr>
     // extraction in handlers for rate limiting
-    axum::serve(
+    let server = axum::serve(
         listener,
         app.into_make_service_with_connect_info::<SocketAddr>(),
     )
-    .with_graceful_shutdown(shutdown_signal())
-    .await
-    .expect("Server error");
+    .with_graceful_shutdown(shutdown.token().cancelled_owned());
+
+    // Requests still running at the drain deadline are dropped
+    tokio::select! {
+        result = server => result.expect("Server error"),
+        _ = shutdown.deadline(drain_timeout) => {
+            tracing::warn!("Drain timeout passed with requests still running; dropping them")
+        }
+    }
+
+    // Give jobs mid-run and background notifications the rest of the drain time
+    if !shutdown.drain(drain_timeout).await {
+        tracing::warn!(
+            "Drain timeout passed with {} background task(s) still running; dropping them",
+            shutdown.running_tasks()
+        );
+    }
 
     tracing::info!("Server shutdown complete");
 }
@@ -10,6 +10,7 @@ use axum::{
 use serde::Serialize;
 
 use crate::error::codes;
+use crate::i18n::localize;
 
 /// Error response format for payload too large errors.
 #[derive(Serialize)]
@@ -38,7 +39,7 @@ pub async fn json_payload_error(request: Request<Body>, next: Next) -> Response<
             data: None,
             error: PayloadTooLargeDetail {
                 code: codes::PAYLOAD_TOO_LARGE,
-                message: "Request body exceeds maximum allowed size".to_string(),
+                message: localize("Request body exceeds maximum allowed size".to_string()),
             },
         };
 
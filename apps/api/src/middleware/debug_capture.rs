@@ -0,0 +1,433 @@
+//! Debug capture middleware.
+//!
+//! When an admin enables debug capture, requests to the selected routes are
+//! recorded to the `request_logs` table with their request and response
+//! bodies. Credentials and customer PII are redacted before anything is
+//! written. Capture expires automatically at the configured time.
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{ConnectInfo, State},
+    http::{header, HeaderMap, Request, Response},
+    middleware::Next,
+};
+use chrono::Utc;
+use serde_json::Value;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+use crate::middleware::extract_client_ip;
+use crate::models::request_log::{CreateRequestLog, DebugCaptureConfig};
+use crate::repositories::RequestLogRepository;
+use crate::routes::AppState;
+
+/// Largest request or response body that will be captured (64KB).
+const MAX_CAPTURE_BODY_BYTES: usize = 64 * 1024;
+
+/// How long the cached capture toggle is trusted before re-reading it.
+const CONFIG_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Replacement text for redacted values.
+const REDACTED: &str = "[REDACTED]";
+
+/// Headers whose values are never written to the log.
+const REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "cookie",
+    "x-admin-pin",
+    "x-admin-session",
+    "x-employee-session",
+    "x-partner-key",
+];
+
+/// JSON keys (and query parameters) whose values are never written to the log.
+const REDACTED_FIELDS: &[&str] = &[
+    "pin",
+    "current_pin",
+    "new_pin",
+    "token",
+    "session_token",
+    "lookup_token",
+    "feed_path",
+    "api_key",
+    "recovery_code",
+    "secret",
+    "response",
+    "name",
+    "customer_name",
+    "phone",
+    "email",
+    "address",
+    "search",
+    "picked_up_by_name",
+    "id_number",
+    "signature",
+    "caller_name",
+    "caller_phone",
+    "customer_phone",
+    "customer_email",
+    "transcript",
+    "recording_url",
+];
+
+#[derive(Debug, Default)]
+struct CachedConfig {
+    config: DebugCaptureConfig,
+    fetched_at: Option<Instant>,
+}
+
+/// Shared debug capture toggle with a short-lived cache.
+///
+/// The toggle lives on store settings so it survives restarts; the cache
+/// keeps the middleware from querying the database on every request.
+#[derive(Debug, Clone, Default)]
+pub struct DebugCaptureState {
+    inner: Arc<RwLock<CachedConfig>>,
+}
+
+impl DebugCaptureState {
+    /// Create an empty capture state (loaded lazily on first request).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the current capture toggle, refreshing it from the database if stale.
+    pub async fn current(&self, pool: &sqlx::PgPool) -> DebugCaptureConfig {
+        {
+            let cached = self.inner.read().await;
+            if cached
+                .fetched_at
+                .is_some_and(|at| at.elapsed() < CONFIG_REFRESH_INTERVAL)
+            {
+                return cached.config.clone();
+            }
+        }
+
+        match RequestLogRepository::get_capture_config(pool).await {
+            Ok(config) => {
+                self.set(config.clone()).await;
+                config
+            }
+            Err(err) => {
+                tracing::warn!("Failed to load debug capture config: {:?}", err);
+                DebugCaptureConfig::default()
+            }
+        }
+    }
+
+    /// Replace the cached capture toggle (called after an admin update).
+    pub async fn set(&self, config: DebugCaptureConfig) {
+        let mut cached = self.inner.write().await;
+        cached.config = config;
+        cached.fetched_at = Some(Instant::now());
+    }
+}
+
+/// Middleware that records redacted request/response pairs while debug capture is on.
+pub async fn debug_capture(
+    State(state): State<AppState>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let path = request.uri().path().to_string();
+    let config = state.debug_capture.current(&state.db).await;
+    if !config.should_capture(&path, Utc::now()) {
+        return next.run(request).await;
+    }
+
+    let started = Instant::now();
+    let method = request.method().to_string();
+    let query = request.uri().query().map(redact_query);
+    let socket_addr = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ci| ci.0);
+    let client_ip =
+        extract_client_ip(request.headers(), socket_addr, &state.trusted_proxies).to_string();
+    let request_headers = redact_headers(request.headers());
+
+    // Buffer small JSON request bodies; pass everything else through untouched
+    let (parts, body) = request.into_parts();
+    let (request_body, body) = if is_capturable(&parts.headers, true) {
+        match to_bytes(body, MAX_CAPTURE_BODY_BYTES).await {
+            Ok(bytes) => (Some(redact_body(&bytes)), Body::from(bytes)),
+            Err(_) => (Some("[unreadable body]".to_string()), Body::empty()),
+        }
+    } else {
+        (describe_uncaptured(&parts.headers), body)
+    };
+
+    let response = next.run(Request::from_parts(parts, body)).await;
+    let status = response.status().as_u16() as i32;
+
+    let (parts, body) = response.into_parts();
+    let (response_body, body) = if is_capturable(&parts.headers, false) {
+        match to_bytes(body, usize::MAX).await {
+            Ok(bytes) => {
+                let captured = if bytes.len() > MAX_CAPTURE_BODY_BYTES {
+                    format!("[body not captured, {} bytes]", bytes.len())
+                } else {
+                    redact_body(&bytes)
+                };
+                (Some(captured), Body::from(bytes))
+            }
+            Err(_) => (Some("[unreadable body]".to_string()), Body::empty()),
+        }
+    } else {
+        (describe_uncaptured(&parts.headers), body)
+    };
+
+    let log = CreateRequestLog {
+        method,
+        path,
+        query,
+        status,
+        duration_ms: started.elapsed().as_millis().min(i32::MAX as u128) as i32,
+        client_ip: Some(client_ip),
+        request_headers,
+        request_body,
+        response_body,
+    };
+
+    // Write the log in the background so capture never slows the response
+    let pool = state.db.clone();
+    state.shutdown.spawn(async move {
+        if let Err(err) = RequestLogRepository::create(&pool, log).await {
+            tracing::warn!("Failed to write request log: {:?}", err);
+        }
+    });
+
+    Response::from_parts(parts, body)
+}
+
+/// Returns true if the body is JSON and (for requests) small enough to buffer.
+fn is_capturable(headers: &HeaderMap, is_request: bool) -> bool {
+    let is_json = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"));
+    if !is_json {
+        return false;
+    }
+    if !is_request {
+        return true;
+    }
+
+    headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok())
+        .is_some_and(|len| len <= MAX_CAPTURE_BODY_BYTES)
+}
+
+/// Describe a body that was not captured (binary, multipart, or oversized).
+fn describe_uncaptured(headers: &HeaderMap) -> Option<String> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())?;
+    let length = headers
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("unknown");
+    Some(format!(
+        "[body not captured, {}, {} bytes]",
+        content_type, length
+    ))
+}
+
+/// Format headers one per line with credentials redacted.
+fn redact_headers(headers: &HeaderMap) -> String {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if REDACTED_HEADERS.contains(&name.as_str()) {
+                REDACTED
+            } else {
+                value.to_str().unwrap_or("[non-ascii]")
+            };
+            format!("{}: {}", name, value)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Redact sensitive query parameter values.
+fn redact_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if is_redacted_field(key) => format!("{}={}", key, REDACTED),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Redact sensitive fields in a JSON body.
+///
+/// Non-JSON bodies are replaced entirely since their contents can't be
+/// inspected for PII.
+fn redact_body(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return String::new();
+    }
+
+    match serde_json::from_slice::<Value>(bytes) {
+        Ok(mut value) => {
+            redact_value(&mut value);
+            value.to_string()
+        }
+        Err(_) => format!("[non-JSON body, {} bytes]", bytes.len()),
+    }
+}
+
+/// Recursively replace values of sensitive keys.
+fn redact_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, val) in map.iter_mut() {
+                if is_redacted_field(key) && !val.is_null() {
+                    *val = Value::String(REDACTED.to_string());
+                } else {
+                    redact_value(val);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+fn is_redacted_field(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    REDACTED_FIELDS.contains(&key.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_redact_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Admin-PIN", HeaderValue::from_static("1234"));
+        headers.insert("X-Employee-Session", HeaderValue::from_static("secret"));
+        headers.insert("X-Partner-Key", HeaderValue::from_static("fpk_live"));
+        headers.insert("User-Agent", HeaderValue::from_static("tablet"));
+
+        let result = redact_headers(&headers);
+        assert!(result.contains("x-admin-pin: [REDACTED]"));
+        assert!(result.contains("x-employee-session: [REDACTED]"));
+        assert!(result.contains("x-partner-key: [REDACTED]"));
+        assert!(!result.contains("fpk_live"));
+        assert!(result.contains("user-agent: tablet"));
+        assert!(!result.contains("1234"));
+        assert!(!result.contains("secret"));
+    }
+
+    #[test]
+    fn test_redact_query() {
+        assert_eq!(
+            redact_query("search=555-1234&status=intake"),
+            "search=[REDACTED]&status=intake"
+        );
+        assert_eq!(redact_query("limit=10"), "limit=10");
+    }
+
+    #[test]
+    fn test_redact_body_nested_pii() {
+        let body = br#"{"customer":{"name":"Jane","phone":"555-1234"},"item_description":"Ring","pin":"9999"}"#;
+        let result = redact_body(body);
+        assert!(!result.contains("Jane"));
+        assert!(!result.contains("555-1234"));
+        assert!(!result.contains("9999"));
+        assert!(result.contains("Ring"));
+    }
+
+    #[test]
+    fn test_redact_body_credentials() {
+        let body = br#"{"data":{"api_key":"fpk_1","recovery_code":"ABCD-EFGH","secret":"whsec","lookup_token":"lt","feed_path":"/feed?token=t","response":"00ff","nonce":"n1"}}"#;
+        let result = redact_body(body);
+        for value in ["fpk_1", "ABCD-EFGH", "whsec", "\"lt\"", "token=t", "00ff"] {
+            assert!(!result.contains(value), "{} leaked", value);
+        }
+        assert!(result.contains("n1"));
+    }
+
+    #[test]
+    fn test_redact_body_close_with_pickup() {
+        let body = br#"{"actual_amount":"45.00","picked_up_by_name":"Sam Lee","pickup":{"authorized_pickup_id":"a1","id_type":"drivers_license","id_number":"D1234-5678","signature":"data:image/png;base64,iVBOR"}}"#;
+        let result = redact_body(body);
+        for value in ["Sam Lee", "D1234-5678", "iVBOR"] {
+            assert!(!result.contains(value), "{} leaked", value);
+        }
+        assert!(result.contains("drivers_license"));
+        assert!(result.contains("45.00"));
+    }
+
+    #[test]
+    fn test_redact_body_phone_intake() {
+        let body = br#"{"call_id":"c-1","caller_name":"Ana Ruiz","caller_phone":"555-0100","transcript":"Hi, this is Ana","recording_url":"https://calls.example/r/1","requested_work":"Resize"}"#;
+        let result = redact_body(body);
+        for value in ["Ana Ruiz", "555-0100", "this is Ana", "calls.example"] {
+            assert!(!result.contains(value), "{} leaked", value);
+        }
+        assert!(result.contains("Resize"));
+
+        let draft = br#"{"data":{"drafts":[{"customer_phone":"555-0100","customer_email":"ana@example.com"}]}}"#;
+        let result = redact_body(draft);
+        assert!(!result.contains("555-0100"));
+        assert!(!result.contains("ana@example.com"));
+    }
+
+    #[test]
+    fn test_redact_body_arrays() {
+        let body = br#"{"data":[{"email":"a@b.com","customer_name":"Jane"},{"email":null}]}"#;
+        let result = redact_body(body);
+        assert!(!result.contains("a@b.com"));
+        assert!(!result.contains("Jane"));
+        assert!(result.contains("null"));
+    }
+
+    #[test]
+    fn test_redact_body_non_json() {
+        assert_eq!(redact_body(b"not json"), "[non-JSON body, 8 bytes]");
+        assert_eq!(redact_body(b""), "");
+    }
+
+    #[test]
+    fn test_is_capturable_request_requires_small_json() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        // No content length: can't bound the buffer
+        assert!(!is_capturable(&headers, true));
+
+        headers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("42"));
+        assert!(is_capturable(&headers, true));
+
+        headers.insert(header::CONTENT_LENGTH, HeaderValue::from_static("9999999"));
+        assert!(!is_capturable(&headers, true));
+        // Responses are ours, so any JSON response is capturable
+        assert!(is_capturable(&headers, false));
+    }
+
+    #[test]
+    fn test_is_capturable_rejects_pdf() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/pdf"),
+        );
+        assert!(!is_capturable(&headers, false));
+        assert_eq!(
+            describe_uncaptured(&headers),
+            Some("[body not captured, application/pdf, unknown bytes]".to_string())
+        );
+    }
+}
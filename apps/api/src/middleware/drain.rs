@@ -0,0 +1,65 @@
+//! Shutdown drain middleware.
+//!
+//! Once the server starts draining, new API requests are refused with
+//! SERVICE_UNAVAILABLE (503) and a `Retry-After`, so clients retry against
+//! another instance while requests already running here finish. Health
+//! endpoints sit outside this layer and keep answering.
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{Request, Response},
+    middleware::Next,
+    response::IntoResponse,
+};
+
+use crate::error::AppError;
+use crate::services::shutdown::{Shutdown, DRAIN_RETRY_AFTER_SECS};
+
+/// Middleware that refuses requests arriving while the server drains.
+pub async fn refuse_while_draining(
+    State(shutdown): State<Shutdown>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    if shutdown.is_draining() {
+        return AppError::service_unavailable(
+            "The server is shutting down; retry shortly",
+            DRAIN_RETRY_AFTER_SECS,
+        )
+        .into_response();
+    }
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_requests_refused_while_draining() {
+        let shutdown = Shutdown::new();
+        let app = Router::new()
+            .route("/tickets", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(
+                shutdown.clone(),
+                refuse_while_draining,
+            ));
+        let request = || {
+            Request::builder()
+                .uri("/tickets")
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        let response = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        shutdown.begin();
+        let response = app.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()["retry-after"], "5");
+    }
+}
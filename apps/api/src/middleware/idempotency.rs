@@ -0,0 +1,394 @@
+//! Idempotency key middleware.
+//!
+//! Intake kiosks on flaky Wi-Fi retry POSTs whose response they never saw.
+//! When such a request carries an `Idempotency-Key` header, the first
+//! attempt claims the key for its caller, method, and path and stores its
+//! response; retries with the same key and credentials get that response
+//! back (marked with `Idempotent-Replayed: true`) instead of creating a
+//! second ticket or payment. Applied to create ticket, close ticket, photo
+//! upload, and record payment. Requests without the header are unaffected.
+
+use axum::{
+    body::{to_bytes, Body, Bytes},
+    extract::{OriginalUri, State},
+    http::{header, HeaderMap, HeaderValue, Request, Response, StatusCode},
+    middleware::Next,
+    response::IntoResponse,
+};
+use chrono::{Duration, Utc};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::time::Duration as StdDuration;
+
+use crate::error::AppError;
+use crate::models::idempotency::{IdempotencyRecord, StoredResponse};
+use crate::repositories::IdempotencyRepository;
+use crate::routes::AppState;
+
+/// Request header carrying the client's key.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Response header set on replayed responses.
+pub const REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// Longest accepted key.
+const MAX_KEY_LEN: usize = 255;
+
+/// How long a key's response is kept for replay.
+pub const IDEMPOTENCY_KEY_TTL_HOURS: i64 = 24;
+
+/// How often expired keys are deleted.
+pub const IDEMPOTENCY_PURGE_INTERVAL: StdDuration = StdDuration::from_secs(60 * 60);
+
+/// How long a request may hold a key before a retry may take it over, for
+/// when the server died mid-request.
+const STALE_CLAIM_MINUTES: i64 = 10;
+
+/// Read the `Idempotency-Key` header, if sent.
+fn idempotency_key(headers: &HeaderMap) -> Result<Option<String>, AppError> {
+    let Some(value) = headers.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+    let key = value.to_str().map(str::trim).unwrap_or_default();
+    if key.is_empty() || key.len() > MAX_KEY_LEN {
+        return Err(AppError::validation(format!(
+            "Idempotency-Key must be 1 to {} characters",
+            MAX_KEY_LEN
+        )));
+    }
+    Ok(Some(key.to_string()))
+}
+
+/// Headers that identify the caller. A key is scoped to their values, so
+/// another caller reusing it can't read the first caller's response.
+const PRINCIPAL_HEADERS: &[&str] = &[
+    "x-employee-session",
+    "x-employee-id",
+    "x-admin-session",
+    "x-partner-key",
+];
+
+/// SHA-256 of the request's credential headers, so tokens aren't stored.
+///
+/// The credentials aren't verified here; a request the handler rejects gets
+/// 401 or 403, which isn't stored.
+fn principal(headers: &HeaderMap) -> String {
+    let mut hasher = Sha256::new();
+    for name in PRINCIPAL_HEADERS {
+        if let Some(value) = headers.get(*name) {
+            hasher.update(name.as_bytes());
+            hasher.update(b":");
+            hasher.update(value.as_bytes());
+            hasher.update(b"\n");
+        }
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Whether a response is stored for replay.
+///
+/// Server errors, rejected credentials, conflicts, and rate limits depend on
+/// the moment rather than the request, so a retry runs the request again.
+fn is_replayable(status: StatusCode) -> bool {
+    !status.is_server_error()
+        && !matches!(
+            status,
+            StatusCode::UNAUTHORIZED
+                | StatusCode::FORBIDDEN
+                | StatusCode::CONFLICT
+                | StatusCode::TOO_MANY_REQUESTS
+        )
+}
+
+/// SHA-256 of the request body, to catch a key reused for a different request.
+fn request_hash(body: &[u8]) -> String {
+    hex::encode(Sha256::digest(body))
+}
+
+/// Rebuild a stored response.
+fn replay(record: IdempotencyRecord) -> Response<Body> {
+    let status = record
+        .response_status
+        .and_then(|s| StatusCode::from_u16(s as u16).ok())
+        .unwrap_or(StatusCode::OK);
+    let mut response = Response::new(Body::from(record.response_body.unwrap_or_default()));
+    *response.status_mut() = status;
+
+    let headers = response.headers_mut();
+    if let Some(content_type) = record
+        .response_content_type
+        .and_then(|v| HeaderValue::from_str(&v).ok())
+    {
+        headers.insert(header::CONTENT_TYPE, content_type);
+    }
+    headers.insert(REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+/// Releases the key if the request is dropped before it stores a response,
+/// e.g. when it times out or the client disconnects.
+struct Claim {
+    pool: PgPool,
+    principal: String,
+    key: String,
+    endpoint: String,
+    settled: bool,
+}
+
+impl Claim {
+    async fn complete(mut self, response: &StoredResponse) {
+        self.settled = true;
+        if let Err(err) = IdempotencyRepository::complete(
+            &self.pool,
+            &self.principal,
+            &self.key,
+            &self.endpoint,
+            response,
+        )
+        .await
+        {
+            tracing::warn!("Failed to store idempotent response: {:?}", err);
+        }
+    }
+
+    async fn release(mut self) {
+        self.settled = true;
+        if let Err(err) =
+            IdempotencyRepository::release(&self.pool, &self.principal, &self.key, &self.endpoint)
+                .await
+        {
+            tracing::warn!("Failed to release idempotency key: {:?}", err);
+        }
+    }
+}
+
+impl Drop for Claim {
+    fn drop(&mut self) {
+        if self.settled {
+            return;
+        }
+        let (pool, principal, key, endpoint) = (
+            self.pool.clone(),
+            std::mem::take(&mut self.principal),
+            std::mem::take(&mut self.key),
+            std::mem::take(&mut self.endpoint),
+        );
+        tokio::spawn(async move {
+            if let Err(err) =
+                IdempotencyRepository::release(&pool, &principal, &key, &endpoint).await
+            {
+                tracing::warn!("Failed to release idempotency key: {:?}", err);
+            }
+        });
+    }
+}
+
+/// Middleware that replays the stored response for a repeated key.
+pub async fn idempotency(
+    State(state): State<AppState>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let key = match idempotency_key(request.headers()) {
+        Ok(Some(key)) => key,
+        Ok(None) => return next.run(request).await,
+        Err(err) => return err.into_response(),
+    };
+    let principal = principal(request.headers());
+    // The full path: nested routers see it with their prefix stripped
+    let path = request
+        .extensions()
+        .get::<OriginalUri>()
+        .map_or_else(|| request.uri().path(), |uri| uri.path());
+    let endpoint = format!("{} {}", request.method(), path);
+
+    // Buffer the body to fingerprint it; the body limit layer still applies
+    let (parts, body) = request.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(_) => return StatusCode::PAYLOAD_TOO_LARGE.into_response(),
+    };
+    let hash = request_hash(&bytes);
+
+    match claim_or_replay(&state.db, &principal, &key, &endpoint, &hash).await {
+        Ok(None) => {}
+        Ok(Some(replayed)) => return replayed,
+        Err(err) => return err.into_response(),
+    }
+    let claim = Claim {
+        pool: state.db.clone(),
+        principal,
+        key,
+        endpoint,
+        settled: false,
+    };
+
+    let response = next
+        .run(Request::from_parts(parts, Body::from(bytes)))
+        .await;
+
+    if !is_replayable(response.status()) {
+        claim.release().await;
+        return response;
+    }
+    let (parts, body) = response.into_parts();
+    let bytes: Bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            tracing::warn!("Failed to buffer idempotent response: {:?}", err);
+            claim.release().await;
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+    let stored = StoredResponse {
+        status: parts.status.as_u16() as i16,
+        content_type: parts
+            .headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        body: bytes.to_vec(),
+    };
+    claim.complete(&stored).await;
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Claim the key, or return the response to send instead of running the
+/// request: the stored one, or an error if the key is busy or was used for a
+/// different request.
+async fn claim_or_replay(
+    pool: &PgPool,
+    principal: &str,
+    key: &str,
+    endpoint: &str,
+    hash: &str,
+) -> Result<Option<Response<Body>>, AppError> {
+    let now = Utc::now();
+    let claimed = IdempotencyRepository::claim(
+        pool,
+        principal,
+        key,
+        endpoint,
+        hash,
+        now - Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS),
+        now - Duration::minutes(STALE_CLAIM_MINUTES),
+    )
+    .await?;
+    if claimed {
+        return Ok(None);
+    }
+
+    let record = IdempotencyRepository::find(pool, principal, key, endpoint)
+        .await?
+        .filter(|record| record.response_status.is_some());
+    match record {
+        Some(record) if record.request_hash != hash => Err(AppError::validation(
+            "Idempotency-Key was already used for a different request",
+        )),
+        Some(record) => Ok(Some(replay(record))),
+        // Still running, or released between the claim and the lookup
+        None => Err(AppError::conflict(
+            "A request with this Idempotency-Key is still in progress; retry shortly",
+        )),
+    }
+}
+
+/// Delete keys past their replay window.
+///
+/// Returns the number of keys deleted.
+pub async fn purge_expired(pool: &PgPool) -> Result<u64, AppError> {
+    let cutoff = Utc::now() - Duration::hours(IDEMPOTENCY_KEY_TTL_HOURS);
+    IdempotencyRepository::delete_older_than(pool, cutoff).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use http_body_util::BodyExt;
+
+    #[test]
+    fn test_idempotency_key_header() {
+        let mut headers = HeaderMap::new();
+        assert!(idempotency_key(&headers).unwrap().is_none());
+
+        headers.insert(
+            IDEMPOTENCY_KEY_HEADER,
+            HeaderValue::from_static(" kiosk-1-42 "),
+        );
+        assert_eq!(idempotency_key(&headers).unwrap().unwrap(), "kiosk-1-42");
+
+        headers.insert(IDEMPOTENCY_KEY_HEADER, HeaderValue::from_static(""));
+        assert!(idempotency_key(&headers).is_err());
+
+        let long = "k".repeat(MAX_KEY_LEN + 1);
+        headers.insert(
+            IDEMPOTENCY_KEY_HEADER,
+            HeaderValue::from_str(&long).unwrap(),
+        );
+        assert!(idempotency_key(&headers).is_err());
+    }
+
+    #[test]
+    fn test_request_hash_distinguishes_bodies() {
+        assert_eq!(
+            request_hash(b"{\"amount\":10}"),
+            request_hash(b"{\"amount\":10}")
+        );
+        assert_ne!(
+            request_hash(b"{\"amount\":10}"),
+            request_hash(b"{\"amount\":100}")
+        );
+        assert_eq!(request_hash(b"").len(), 64);
+    }
+
+    #[test]
+    fn test_principal_scopes_by_credentials() {
+        let mut first = HeaderMap::new();
+        first.insert("X-Employee-Session", HeaderValue::from_static("token-a"));
+        let mut second = HeaderMap::new();
+        second.insert("X-Employee-Session", HeaderValue::from_static("token-b"));
+
+        assert_eq!(principal(&first), principal(&first.clone()));
+        assert_ne!(principal(&first), principal(&second));
+        assert_ne!(principal(&first), principal(&HeaderMap::new()));
+        assert!(!principal(&first).contains("token-a"));
+
+        // The same value under another credential header is another caller
+        let mut admin = HeaderMap::new();
+        admin.insert("X-Admin-Session", HeaderValue::from_static("token-a"));
+        assert_ne!(principal(&first), principal(&admin));
+    }
+
+    #[test]
+    fn test_is_replayable() {
+        assert!(is_replayable(StatusCode::CREATED));
+        assert!(is_replayable(StatusCode::BAD_REQUEST));
+        assert!(is_replayable(StatusCode::NOT_FOUND));
+        assert!(!is_replayable(StatusCode::UNAUTHORIZED));
+        assert!(!is_replayable(StatusCode::FORBIDDEN));
+        assert!(!is_replayable(StatusCode::CONFLICT));
+        assert!(!is_replayable(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_replayable(StatusCode::SERVICE_UNAVAILABLE));
+    }
+
+    #[tokio::test]
+    async fn test_replay_restores_stored_response() {
+        let record = IdempotencyRecord {
+            principal: principal(&HeaderMap::new()),
+            idempotency_key: "kiosk-1-42".to_string(),
+            endpoint: "POST /api/v1/tickets".to_string(),
+            request_hash: request_hash(b"{}"),
+            response_status: Some(201),
+            response_content_type: Some("application/json".to_string()),
+            response_body: Some(br#"{"data":{"friendly_code":"JR-0001"},"error":null}"#.to_vec()),
+            created_at: Utc::now(),
+        };
+
+        let response = replay(record);
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        assert_eq!(response.headers()[REPLAYED_HEADER], "true");
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert!(body.starts_with(br#"{"data":{"friendly_code":"JR-0001"}"#));
+    }
+}
@@ -0,0 +1,80 @@
+//! Locale negotiation middleware.
+//!
+//! Picks the response language from `Accept-Language` and scopes it over the
+//! rest of the request so error and warning messages are translated where
+//! they are built. See [`crate::i18n`] for the message catalog.
+
+use axum::{
+    body::Body,
+    http::{header, HeaderValue, Request, Response},
+    middleware::Next,
+};
+
+use crate::i18n::{self, Locale};
+
+/// Middleware that negotiates the request locale.
+///
+/// Sets `Content-Language` on the response and adds `Accept-Language` to
+/// `Vary`, since the body depends on it.
+pub async fn negotiate_locale(request: Request<Body>, next: Next) -> Response<Body> {
+    let locale = Locale::negotiate(
+        request
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok()),
+    );
+
+    let mut response = i18n::scope(locale, next.run(request)).await;
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_LANGUAGE,
+        HeaderValue::from_static(locale.code()),
+    );
+    headers.append(header::VARY, HeaderValue::from_static("accept-language"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AppError;
+    use axum::{middleware, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn failing_handler() -> Result<(), AppError> {
+        Err(AppError::validation("name is required"))
+    }
+
+    async fn send(accept_language: Option<&str>) -> (Response<Body>, String) {
+        let app = Router::new()
+            .route("/", get(failing_handler))
+            .layer(middleware::from_fn(negotiate_locale));
+        let mut request = Request::builder().uri("/");
+        if let Some(value) = accept_language {
+            request = request.header(header::ACCEPT_LANGUAGE, value);
+        }
+        let response = app
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let (parts, body) = response.into_parts();
+        let bytes = body.collect().await.unwrap().to_bytes();
+        (
+            Response::from_parts(parts, Body::empty()),
+            String::from_utf8(bytes.to_vec()).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_errors_follow_accept_language() {
+        let (response, body) = send(Some("es-MX,es;q=0.9,en;q=0.5")).await;
+        assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "es");
+        assert_eq!(response.headers()[header::VARY], "accept-language");
+        assert!(body.contains("name es obligatorio"), "{}", body);
+
+        let (response, body) = send(None).await;
+        assert_eq!(response.headers()[header::CONTENT_LANGUAGE], "en");
+        assert!(body.contains("name is required"), "{}", body);
+    }
+}
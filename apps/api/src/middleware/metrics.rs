@@ -0,0 +1,314 @@
+//! Prometheus metrics middleware.
+//!
+//! Records a request count, latency histogram, and in-flight gauge for each
+//! route, labelled by method and route template (`/api/v1/tickets/:ticket_id`,
+//! not the raw path, so ticket IDs don't explode the series count). The
+//! `/metrics` handler renders these along with pool, rate limiter, and
+//! circuit breaker gauges in the Prometheus text format.
+
+use axum::{
+    body::Body,
+    extract::{MatchedPath, State},
+    http::{Request, Response},
+    middleware::Next,
+};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Upper bounds of the latency histogram buckets (seconds).
+pub const LATENCY_BUCKETS: [f64; 12] = [
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+/// Route label for requests that matched no route.
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Counters for one method and route.
+#[derive(Debug, Default)]
+struct RouteStats {
+    /// Completed requests by status code
+    by_status: BTreeMap<u16, u64>,
+    /// Completed requests per latency bucket (not cumulative)
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    /// Total latency of completed requests (seconds)
+    latency_sum: f64,
+    in_flight: i64,
+}
+
+/// Request metrics shared across the router.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    routes: Mutex<BTreeMap<(String, String), RouteStats>>,
+}
+
+impl Metrics {
+    /// Create an empty set of metrics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn with_route<T>(&self, method: &str, route: &str, f: impl FnOnce(&mut RouteStats) -> T) -> T {
+        let mut routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+        let stats = routes
+            .entry((method.to_string(), route.to_string()))
+            .or_default();
+        f(stats)
+    }
+
+    /// Record a request starting.
+    pub fn start(&self, method: &str, route: &str) {
+        self.with_route(method, route, |stats| stats.in_flight += 1);
+    }
+
+    /// Record a request leaving, whether it completed or was dropped.
+    pub fn end(&self, method: &str, route: &str) {
+        self.with_route(method, route, |stats| stats.in_flight -= 1);
+    }
+
+    /// Record a request completing with `status` after `seconds`.
+    pub fn finish(&self, method: &str, route: &str, status: u16, seconds: f64) {
+        self.with_route(method, route, |stats| {
+            *stats.by_status.entry(status).or_default() += 1;
+            stats.latency_sum += seconds;
+            if let Some(bucket) = LATENCY_BUCKETS.iter().position(|le| seconds <= *le) {
+                stats.buckets[bucket] += 1;
+            }
+        });
+    }
+
+    /// Write the request metrics to `out`.
+    pub fn render(&self, out: &mut Exposition) {
+        let routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+
+        out.family(
+            "http_requests_total",
+            "counter",
+            "Completed HTTP requests by method, route, and status",
+        );
+        for ((method, route), stats) in routes.iter() {
+            for (status, count) in &stats.by_status {
+                let status = status.to_string();
+                out.sample(
+                    "http_requests_total",
+                    &[("method", method), ("route", route), ("status", &status)],
+                    count,
+                );
+            }
+        }
+
+        out.family(
+            "http_request_duration_seconds",
+            "histogram",
+            "HTTP request latency by method and route",
+        );
+        for ((method, route), stats) in routes.iter() {
+            let labels = [("method", method.as_str()), ("route", route.as_str())];
+            let total: u64 = stats.by_status.values().sum();
+            let mut cumulative = 0;
+            for (le, count) in LATENCY_BUCKETS.iter().zip(stats.buckets) {
+                cumulative += count;
+                let le = le.to_string();
+                out.sample(
+                    "http_request_duration_seconds_bucket",
+                    &[labels[0], labels[1], ("le", &le)],
+                    cumulative,
+                );
+            }
+            out.sample(
+                "http_request_duration_seconds_bucket",
+                &[labels[0], labels[1], ("le", "+Inf")],
+                total,
+            );
+            out.sample(
+                "http_request_duration_seconds_sum",
+                &labels,
+                stats.latency_sum,
+            );
+            out.sample("http_request_duration_seconds_count", &labels, total);
+        }
+
+        out.family(
+            "http_requests_in_flight",
+            "gauge",
+            "HTTP requests currently being handled by method and route",
+        );
+        for ((method, route), stats) in routes.iter() {
+            out.sample(
+                "http_requests_in_flight",
+                &[("method", method), ("route", route)],
+                stats.in_flight,
+            );
+        }
+    }
+}
+
+/// Builder for a Prometheus text format (0.0.4) exposition.
+#[derive(Debug, Default)]
+pub struct Exposition {
+    out: String,
+}
+
+impl Exposition {
+    /// Content type of the rendered exposition.
+    pub const CONTENT_TYPE: &'static str = "text/plain; version=0.0.4; charset=utf-8";
+
+    /// Start an empty exposition.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a metric family with its type and help text.
+    pub fn family(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.out, "# HELP {} {}", name, help);
+        let _ = writeln!(self.out, "# TYPE {} {}", name, kind);
+    }
+
+    /// Add one sample.
+    pub fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: impl Display) {
+        self.out.push_str(name);
+        if !labels.is_empty() {
+            self.out.push('{');
+            for (i, (key, value)) in labels.iter().enumerate() {
+                if i > 0 {
+                    self.out.push(',');
+                }
+                let _ = write!(self.out, "{}=\"{}\"", key, escape_label(value));
+            }
+            self.out.push('}');
+        }
+        let _ = writeln!(self.out, " {}", value);
+    }
+
+    /// The rendered exposition.
+    pub fn finish(self) -> String {
+        self.out
+    }
+}
+
+/// Escape a label value: backslash, double quote, and newline.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Decrements the in-flight gauge when the request leaves, including when
+/// the client disconnects and the handler future is dropped.
+struct InFlight<'a> {
+    metrics: &'a Metrics,
+    method: &'a str,
+    route: &'a str,
+}
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.metrics.end(self.method, self.route);
+    }
+}
+
+/// Middleware that records request metrics by route.
+pub async fn track_metrics(
+    State(metrics): State<Arc<Metrics>>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+
+    let started = Instant::now();
+    metrics.start(&method, &route);
+    let _in_flight = InFlight {
+        metrics: &metrics,
+        method: &method,
+        route: &route,
+    };
+    let response = next.run(request).await;
+    metrics.finish(
+        &method,
+        &route,
+        response.status().as_u16(),
+        started.elapsed().as_secs_f64(),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::to_bytes, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_render_histogram_is_cumulative() {
+        let metrics = Metrics::new();
+        metrics.start("GET", "/api/v1/tickets");
+        metrics.finish("GET", "/api/v1/tickets", 200, 0.003);
+        metrics.end("GET", "/api/v1/tickets");
+        metrics.start("GET", "/api/v1/tickets");
+        metrics.finish("GET", "/api/v1/tickets", 404, 0.2);
+        metrics.end("GET", "/api/v1/tickets");
+        metrics.start("GET", "/api/v1/tickets");
+
+        let mut out = Exposition::new();
+        metrics.render(&mut out);
+        let text = out.finish();
+
+        assert!(text.contains("# TYPE http_requests_total counter"));
+        assert!(text.contains(
+            r#"http_requests_total{method="GET",route="/api/v1/tickets",status="200"} 1"#
+        ));
+        assert!(text.contains(
+            r#"http_request_duration_seconds_bucket{method="GET",route="/api/v1/tickets",le="0.005"} 1"#
+        ));
+        assert!(text.contains(
+            r#"http_request_duration_seconds_bucket{method="GET",route="/api/v1/tickets",le="0.25"} 2"#
+        ));
+        assert!(text.contains(
+            r#"http_request_duration_seconds_bucket{method="GET",route="/api/v1/tickets",le="+Inf"} 2"#
+        ));
+        assert!(text.contains(r#"http_requests_in_flight{method="GET",route="/api/v1/tickets"} 1"#));
+    }
+
+    #[test]
+    fn test_label_values_are_escaped() {
+        let mut out = Exposition::new();
+        out.sample("m", &[("route", "a\"b\\c\nd")], 1);
+        assert_eq!(out.finish(), "m{route=\"a\\\"b\\\\c\\nd\"} 1\n");
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_labelled_by_route_template() {
+        let metrics = Arc::new(Metrics::new());
+        let tickets = Router::new().route("/:ticket_id", get(|| async { "ok" }));
+        let app =
+            Router::new()
+                .nest("/api/v1/tickets", tickets)
+                .layer(middleware::from_fn_with_state(
+                    metrics.clone(),
+                    track_metrics,
+                ));
+
+        for uri in ["/api/v1/tickets/abc", "/api/v1/tickets/def", "/nowhere"] {
+            let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        }
+
+        let mut out = Exposition::new();
+        metrics.render(&mut out);
+        let text = out.finish();
+        assert!(text.contains(
+            r#"http_requests_total{method="GET",route="/api/v1/tickets/:ticket_id",status="200"} 2"#
+        ));
+        assert!(
+            text.contains(r#"http_requests_total{method="GET",route="unmatched",status="404"} 1"#)
+        );
+    }
+}
@@ -1,11 +1,40 @@
 //! Middleware modules for the API.
 
 pub mod body_limit;
+pub mod debug_capture;
+pub mod drain;
+pub mod idempotency;
+pub mod locale;
+pub mod metrics;
 pub mod rate_limit;
+pub mod rate_limit_backend;
 pub mod rbac;
+pub mod request_id;
+pub mod response_meta;
+pub mod store;
+pub mod timeout;
+pub mod training;
 
 pub use body_limit::json_payload_error;
-pub use rate_limit::{extract_client_ip, RateLimitState, RateLimiter};
+pub use debug_capture::{debug_capture, DebugCaptureState};
+pub use drain::refuse_while_draining;
+pub use idempotency::idempotency;
+pub use locale::negotiate_locale;
+pub use metrics::{track_metrics, Exposition, Metrics};
+pub use rate_limit::{
+    enforce_rate_limit, extract_client_ip, ClientIp, PartnerRateLimits, RateLimitPolicies,
+    RateLimitPolicy, RateLimitState, RateLimiter, RouteRateLimit, RouteRateLimits, TrustedProxies,
+    RATE_LIMIT_GROUPS,
+};
+pub use rate_limit_backend::{
+    MemoryRateLimitBackend, PostgresRateLimitBackend, RateLimitBackend, RateLimitBackendKind,
+};
 pub use rbac::{
-    can_close_ticket, can_delete_photo, is_ticket_owner, require_permission, require_ticket_access,
+    access_denied, can_close_ticket, can_delete_photo, is_ticket_owner, require_permission,
+    require_ticket_access, ProbePolicy,
 };
+pub use request_id::{request_id, RequestId, REQUEST_ID_HEADER};
+pub use response_meta::response_meta;
+pub use store::{current_store, CurrentStore};
+pub use timeout::{request_timeout, RequestTimeouts};
+pub use training::{training_mode, TrainingMode};
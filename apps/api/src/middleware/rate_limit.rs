@@ -1,93 +1,85 @@
-//! Rate limiting middleware for PIN verification endpoints.
+//! Rate limiting middleware for PIN verification endpoints, busy route
+//! groups, and the partner API.
 //!
 //! This module implements per-IP rate limiting with exponential backoff
-//! to prevent brute force attacks on PIN verification endpoints.
-
-use axum::http::HeaderMap;
+//! to prevent brute force attacks on PIN verification endpoints, and
+//! separate per-partner quotas for the partner API. Other route groups (photo
+//! uploads, ticket creation) get per-IP and per-employee limits declared in
+//! [`RATE_LIMIT_GROUPS`] and adjustable with `RATE_LIMITS`.
+//!
+//! Client IPs come from forwarding headers only when the connection is from
+//! a trusted proxy (`TRUSTED_PROXIES`); anyone else could set them to dodge
+//! the limits.
+
+use axum::{
+    async_trait,
+    body::Body,
+    extract::{ConnectInfo, FromRef, FromRequestParts, State},
+    http::{request::Parts, HeaderMap, Request, Response},
+    middleware::Next,
+    response::IntoResponse,
+};
+use chrono::Utc;
 use governor::{
     clock::{Clock, DefaultClock},
     state::{InMemoryState, NotKeyed},
-    Quota, RateLimiter as GovRateLimiter,
+    DefaultKeyedRateLimiter, NotUntil, Quota, RateLimiter as GovRateLimiter,
 };
+use ipnet::IpNet;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::num::NonZeroU32;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use tokio::sync::RwLock;
+use uuid::Uuid;
 
-/// Tracks failure attempts and calculates exponential backoff.
-#[derive(Debug, Clone)]
-struct FailureTracker {
-    /// Number of consecutive failures
-    failure_count: u32,
-    /// Time of last failure
-    last_failure: Instant,
-}
-
-impl FailureTracker {
-    fn new() -> Self {
-        Self {
-            failure_count: 0,
-            last_failure: Instant::now(),
-        }
-    }
-
-    /// Calculate backoff duration based on failure count.
-    /// - 1st failure: 0 seconds (immediate retry)
-    /// - 2nd failure: 5 seconds
-    /// - 3rd failure: 30 seconds
-    /// - 4th+ failure: 5 minutes
-    fn backoff_duration(&self) -> Duration {
-        match self.failure_count {
-            0 | 1 => Duration::from_secs(0),
-            2 => Duration::from_secs(5),
-            3 => Duration::from_secs(30),
-            _ => Duration::from_secs(300), // 5 minutes
-        }
-    }
-
-    /// Check if we're still within the backoff period.
-    fn is_in_backoff(&self) -> bool {
-        if self.failure_count < 2 {
-            return false;
-        }
-        self.last_failure.elapsed() < self.backoff_duration()
-    }
-
-    /// Get remaining backoff time in seconds.
-    fn remaining_backoff_seconds(&self) -> u64 {
-        if !self.is_in_backoff() {
-            return 0;
-        }
-        let backoff = self.backoff_duration();
-        let elapsed = self.last_failure.elapsed();
-        if elapsed >= backoff {
-            0
-        } else {
-            (backoff - elapsed).as_secs() + 1 // Round up
-        }
-    }
-
-    /// Record a new failure.
-    fn record_failure(&mut self) {
-        self.failure_count += 1;
-        self.last_failure = Instant::now();
-    }
+use crate::error::AppError;
+use crate::middleware::rate_limit_backend::{
+    MemoryRateLimitBackend, RateLimitBackend, FAILURE_RETENTION,
+};
 
-    /// Reset the tracker on successful authentication.
-    fn reset(&mut self) {
-        self.failure_count = 0;
-    }
-}
+/// Unkeyed limiter holding a single quota.
+type DirectRateLimiter = GovRateLimiter<NotKeyed, InMemoryState, DefaultClock>;
+
+/// A partner's limiter with the per-minute quota it was built with.
+type PartnerLimiter = (u32, Arc<DirectRateLimiter>);
+
+/// How often clients with replenished route limits are forgotten.
+pub const RATE_LIMIT_CLEANUP_INTERVAL: Duration = Duration::from_secs(10 * 60);
+
+/// Route groups with their own limits, and their default policies.
+pub const RATE_LIMIT_GROUPS: &[(&str, RateLimitPolicy)] = &[
+    (
+        "photo_uploads",
+        RateLimitPolicy {
+            per_ip: 60,
+            per_employee: 20,
+        },
+    ),
+    (
+        "ticket_creation",
+        RateLimitPolicy {
+            per_ip: 120,
+            per_employee: 60,
+        },
+    ),
+];
 
 /// Rate limiter state shared across handlers.
+///
+/// The per-minute quota is kept per instance; failure counters for backoff
+/// live in a [`RateLimitBackend`] under this limiter's scope.
 #[derive(Clone)]
 pub struct RateLimitState {
-    /// Per-IP basic rate limiter (5 requests per minute)
-    rate_limiter: Arc<GovRateLimiter<NotKeyed, InMemoryState, DefaultClock>>,
+    /// Basic rate limiter (5 requests per minute)
+    rate_limiter: Arc<DirectRateLimiter>,
+    /// Name failure counters are kept under, e.g. "pin"
+    scope: &'static str,
     /// Per-IP failure tracking for exponential backoff
-    failure_trackers: Arc<RwLock<HashMap<IpAddr, FailureTracker>>>,
+    backend: Arc<dyn RateLimitBackend>,
 }
 
 impl RateLimitState {
@@ -95,33 +87,50 @@ impl RateLimitState {
     /// Allows 5 requests per minute per IP for rate-limited endpoints.
     pub fn new() -> Self {
         // 5 requests per 60 seconds
-        let quota = Quota::per_minute(NonZeroU32::new(5).unwrap());
-        let rate_limiter = Arc::new(GovRateLimiter::direct(quota));
+        Self::with_quota("pin", Quota::per_minute(NonZeroU32::new(5).unwrap()))
+    }
 
+    /// Create the rate limit state for admin recovery.
+    /// Allows 3 requests per hour, on top of the same exponential backoff.
+    pub fn recovery() -> Self {
+        Self::with_quota("recovery", Quota::per_hour(NonZeroU32::new(3).unwrap()))
+    }
+
+    fn with_quota(scope: &'static str, quota: Quota) -> Self {
         Self {
-            rate_limiter,
-            failure_trackers: Arc::new(RwLock::new(HashMap::new())),
+            rate_limiter: Arc::new(GovRateLimiter::direct(quota)),
+            scope,
+            backend: Arc::new(MemoryRateLimitBackend::new()),
         }
     }
 
+    /// Keep failure counters in `backend`.
+    pub fn with_backend(mut self, backend: Arc<dyn RateLimitBackend>) -> Self {
+        self.backend = backend;
+        self
+    }
+
     /// Check if a request from the given IP is allowed.
     /// Returns Ok(()) if allowed, Err(retry_after_seconds) if rate limited.
+    ///
+    /// If the backend can't be read, backoff is skipped for this request and
+    /// only the per-minute quota applies.
     pub async fn check_rate_limit(&self, ip: IpAddr) -> Result<(), u64> {
         // First, check exponential backoff
-        {
-            let trackers = self.failure_trackers.read().await;
-            if let Some(tracker) = trackers.get(&ip) {
-                if tracker.is_in_backoff() {
-                    let retry_after = tracker.remaining_backoff_seconds();
+        match self.backend.get(self.scope, ip).await {
+            Ok(Some(record)) => {
+                if let Some(retry_after) = record.remaining_backoff_secs(Utc::now()) {
                     tracing::warn!(
                         ip = %ip,
-                        failures = tracker.failure_count,
+                        failures = record.failure_count,
                         retry_after = retry_after,
                         "Request blocked by exponential backoff"
                     );
                     return Err(retry_after);
                 }
             }
+            Ok(None) => {}
+            Err(err) => tracing::warn!(ip = %ip, "Failed to read rate limit backoff: {:?}", err),
         }
 
         // Then check basic rate limit
@@ -131,8 +140,7 @@ impl RateLimitState {
         match self.rate_limiter.check() {
             Ok(_) => Ok(()),
             Err(not_until) => {
-                let retry_after = not_until.wait_time_from(DefaultClock::default().now());
-                let retry_after_secs = retry_after.as_secs() + 1; // Round up
+                let retry_after_secs = retry_after_secs(not_until);
                 tracing::warn!(
                     ip = %ip,
                     retry_after = retry_after_secs,
@@ -145,24 +153,46 @@ impl RateLimitState {
 
     /// Record a failed authentication attempt for the given IP.
     pub async fn record_failure(&self, ip: IpAddr) {
-        let mut trackers = self.failure_trackers.write().await;
-        let tracker = trackers.entry(ip).or_insert_with(FailureTracker::new);
-        tracker.record_failure();
-        tracing::warn!(
-            ip = %ip,
-            failure_count = tracker.failure_count,
-            "Authentication failure recorded"
-        );
+        match self.backend.record_failure(self.scope, ip).await {
+            Ok(record) => tracing::warn!(
+                ip = %ip,
+                failure_count = record.failure_count,
+                "Authentication failure recorded"
+            ),
+            Err(err) => tracing::warn!(ip = %ip, "Failed to record rate limit failure: {:?}", err),
+        }
     }
 
     /// Reset failure tracking for the given IP on successful authentication.
     pub async fn record_success(&self, ip: IpAddr) {
-        let mut trackers = self.failure_trackers.write().await;
-        if let Some(tracker) = trackers.get_mut(&ip) {
-            tracker.reset();
-            tracing::info!(ip = %ip, "Authentication success, backoff reset");
+        match self.backend.reset(self.scope, ip).await {
+            Ok(()) => tracing::debug!(ip = %ip, "Authentication success, backoff reset"),
+            Err(err) => tracing::warn!(ip = %ip, "Failed to reset rate limit backoff: {:?}", err),
         }
     }
+
+    /// Number of IPs with failure tracking, and how many are in backoff now.
+    pub async fn tracked_clients(&self) -> (usize, usize) {
+        let records = match self.backend.list(self.scope).await {
+            Ok(records) => records,
+            Err(err) => {
+                tracing::warn!("Failed to list rate limit failures: {:?}", err);
+                return (0, 0);
+            }
+        };
+        let now = Utc::now();
+        let in_backoff = records
+            .iter()
+            .filter(|r| r.remaining_backoff_secs(now).is_some())
+            .count();
+        (records.len(), in_backoff)
+    }
+
+    /// Forget clients with no failure in [`FAILURE_RETENTION`], across every
+    /// limiter sharing this backend.
+    pub async fn purge_idle(&self) -> Result<u64, AppError> {
+        self.backend.purge(Utc::now() - FAILURE_RETENTION).await
+    }
 }
 
 impl Default for RateLimitState {
@@ -171,6 +201,56 @@ impl Default for RateLimitState {
     }
 }
 
+/// Per-partner rate limiters for the partner API.
+///
+/// Each partner has its own per-minute quota, kept apart from the PIN
+/// limiter so partner traffic can't lock staff out.
+#[derive(Clone, Default)]
+pub struct PartnerRateLimits {
+    limiters: Arc<RwLock<HashMap<Uuid, PartnerLimiter>>>,
+}
+
+impl PartnerRateLimits {
+    /// Create an empty set of partner limiters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check a partner request against its per-minute quota.
+    /// Returns Ok(()) if allowed, Err(retry_after_seconds) if rate limited.
+    ///
+    /// A changed quota takes effect immediately with a fresh limiter.
+    pub async fn check(&self, partner_id: Uuid, per_minute: u32) -> Result<(), u64> {
+        let existing = {
+            let limiters = self.limiters.read().await;
+            limiters
+                .get(&partner_id)
+                .filter(|(quota, _)| *quota == per_minute)
+                .map(|(_, limiter)| limiter.clone())
+        };
+
+        let limiter = match existing {
+            Some(limiter) => limiter,
+            None => {
+                let quota = Quota::per_minute(NonZeroU32::new(per_minute.max(1)).unwrap());
+                let limiter = Arc::new(GovRateLimiter::direct(quota));
+                self.limiters
+                    .write()
+                    .await
+                    .insert(partner_id, (per_minute, limiter.clone()));
+                limiter
+            }
+        };
+
+        limiter.check().map_err(retry_after_secs)
+    }
+
+    /// Number of partners with a limiter.
+    pub async fn partner_count(&self) -> usize {
+        self.limiters.read().await.len()
+    }
+}
+
 /// Rate limiter that can be added to AppState.
 pub struct RateLimiter {
     state: RateLimitState,
@@ -196,15 +276,294 @@ impl Default for RateLimiter {
     }
 }
 
+/// Per-minute request limits for a route group.
+///
+/// Tablets in one shop usually share an IP, so the per-IP limit is set above
+/// the per-employee one. 0 turns a limit off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitPolicy {
+    /// Requests per minute from one client IP
+    pub per_ip: u32,
+    /// Requests per minute from one employee
+    pub per_employee: u32,
+}
+
+/// Policies for every route group in [`RATE_LIMIT_GROUPS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitPolicies(HashMap<&'static str, RateLimitPolicy>);
+
+impl Default for RateLimitPolicies {
+    fn default() -> Self {
+        Self(RATE_LIMIT_GROUPS.iter().copied().collect())
+    }
+}
+
+impl RateLimitPolicies {
+    /// Defaults with overrides from `RATE_LIMITS`.
+    ///
+    /// Overrides are comma-separated `group.ip=N` or `group.employee=N`
+    /// pairs. Unknown groups and malformed pairs are skipped.
+    pub fn parse(value: &str) -> Self {
+        let mut policies = Self::default();
+        for pair in value.split(',') {
+            let Some((key, limit)) = pair.split_once('=') else {
+                continue;
+            };
+            let Some((group, scope)) = key.trim().split_once('.') else {
+                continue;
+            };
+            let Ok(limit) = limit.trim().parse() else {
+                continue;
+            };
+            let Some(policy) = policies.0.get_mut(group) else {
+                continue;
+            };
+            match scope {
+                "ip" => policy.per_ip = limit,
+                "employee" => policy.per_employee = limit,
+                _ => {}
+            }
+        }
+        policies
+    }
+
+    /// The policy for a route group.
+    pub fn get(&self, group: &str) -> Option<RateLimitPolicy> {
+        self.0.get(group).copied()
+    }
+}
+
+/// Limiters for one route group.
+struct GroupLimiters {
+    per_ip: Option<DefaultKeyedRateLimiter<IpAddr>>,
+    per_employee: Option<DefaultKeyedRateLimiter<String>>,
+}
+
+impl GroupLimiters {
+    fn new(policy: RateLimitPolicy) -> Self {
+        Self {
+            per_ip: keyed_limiter(policy.per_ip),
+            per_employee: keyed_limiter(policy.per_employee),
+        }
+    }
+}
+
+/// Keyed limiter allowing `per_minute` requests per key, or None for 0.
+fn keyed_limiter<K>(per_minute: u32) -> Option<DefaultKeyedRateLimiter<K>>
+where
+    K: std::hash::Hash + Eq + Clone,
+{
+    NonZeroU32::new(per_minute).map(|n| GovRateLimiter::keyed(Quota::per_minute(n)))
+}
+
+/// Per-IP and per-employee limiters for each route group, built from
+/// [`RateLimitPolicies`].
+#[derive(Clone)]
+pub struct RouteRateLimits {
+    groups: Arc<HashMap<&'static str, Arc<GroupLimiters>>>,
+}
+
+impl Default for RouteRateLimits {
+    fn default() -> Self {
+        Self::new(&RateLimitPolicies::default())
+    }
+}
+
+impl RouteRateLimits {
+    /// Create limiters for every group's policy.
+    pub fn new(policies: &RateLimitPolicies) -> Self {
+        let groups = policies
+            .0
+            .iter()
+            .map(|(&group, &policy)| (group, Arc::new(GroupLimiters::new(policy))))
+            .collect();
+        Self {
+            groups: Arc::new(groups),
+        }
+    }
+
+    /// Middleware state limiting requests to `group`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `group` isn't in [`RATE_LIMIT_GROUPS`].
+    pub fn group(&self, group: &'static str, trusted_proxies: &TrustedProxies) -> RouteRateLimit {
+        let limiters = self
+            .groups
+            .get(group)
+            .unwrap_or_else(|| panic!("unknown rate limit group {}", group))
+            .clone();
+        RouteRateLimit {
+            group,
+            limiters,
+            trusted_proxies: trusted_proxies.clone(),
+        }
+    }
+
+    /// Forget clients whose limits have fully replenished.
+    pub fn retain_recent(&self) {
+        for limiters in self.groups.values() {
+            if let Some(limiter) = &limiters.per_ip {
+                limiter.retain_recent();
+                limiter.shrink_to_fit();
+            }
+            if let Some(limiter) = &limiters.per_employee {
+                limiter.retain_recent();
+                limiter.shrink_to_fit();
+            }
+        }
+    }
+}
+
+/// State for [`enforce_rate_limit`] on one route group.
+#[derive(Clone)]
+pub struct RouteRateLimit {
+    group: &'static str,
+    limiters: Arc<GroupLimiters>,
+    trusted_proxies: TrustedProxies,
+}
+
+impl RouteRateLimit {
+    /// Check a request from `ip`, made by `employee` if known.
+    /// Returns Ok(()) if allowed, Err(retry_after_seconds) if rate limited.
+    fn check(&self, ip: IpAddr, employee: Option<&String>) -> Result<(), u64> {
+        if let Some(limiter) = &self.limiters.per_ip {
+            limiter.check_key(&ip).map_err(retry_after_secs)?;
+        }
+        if let (Some(limiter), Some(employee)) = (&self.limiters.per_employee, employee) {
+            limiter.check_key(employee).map_err(retry_after_secs)?;
+        }
+        Ok(())
+    }
+}
+
+/// Seconds until a limiter allows the next request, rounded up.
+fn retry_after_secs(not_until: NotUntil<<DefaultClock as Clock>::Instant>) -> u64 {
+    not_until
+        .wait_time_from(DefaultClock::default().now())
+        .as_secs()
+        + 1
+}
+
+/// Key identifying the employee making a request, from the session token
+/// (hashed, so tokens aren't kept in memory) or the legacy `X-Employee-ID`.
+///
+/// The session isn't verified here; an invalid one is rejected by the
+/// handler, after counting against its own key.
+fn employee_key(headers: &HeaderMap) -> Option<String> {
+    if let Some(token) = headers
+        .get("X-Employee-Session")
+        .and_then(|v| v.to_str().ok())
+    {
+        return Some(format!("session:{}", hex::encode(Sha256::digest(token))));
+    }
+    headers
+        .get("X-Employee-ID")
+        .and_then(|v| v.to_str().ok())
+        .map(|id| format!("id:{}", id.trim()))
+}
+
+/// Middleware that limits a route group per client IP and per employee.
+///
+/// Rejected requests get RATE_LIMITED (429) with Retry-After.
+pub async fn enforce_rate_limit(
+    State(limit): State<RouteRateLimit>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let socket_addr = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ci| ci.0);
+    let ip = extract_client_ip(request.headers(), socket_addr, &limit.trusted_proxies);
+    let employee = employee_key(request.headers());
+
+    if let Err(retry_after) = limit.check(ip, employee.as_ref()) {
+        tracing::warn!(
+            group = limit.group,
+            ip = %ip,
+            retry_after = retry_after,
+            "Request blocked by route rate limit"
+        );
+        return AppError::rate_limited("Too many requests", retry_after).into_response();
+    }
+
+    next.run(request).await
+}
+
+/// Proxies whose forwarding headers are believed (`TRUSTED_PROXIES`).
+///
+/// Defaults to loopback, for a reverse proxy on the same host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedProxies(Vec<IpNet>);
+
+impl Default for TrustedProxies {
+    fn default() -> Self {
+        Self::parse("127.0.0.0/8,::1/128")
+    }
+}
+
+impl TrustedProxies {
+    /// Trust no proxy: forwarding headers are always ignored.
+    pub fn none() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Parse comma-separated CIDR ranges or single addresses.
+    ///
+    /// Entries that don't parse are skipped, so a typo trusts less, not more.
+    pub fn parse(value: &str) -> Self {
+        Self(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .filter_map(|entry| {
+                    entry
+                        .parse::<IpNet>()
+                        .or_else(|_| entry.parse::<IpAddr>().map(IpNet::from))
+                        .ok()
+                })
+                .collect(),
+        )
+    }
+
+    /// Whether `ip` is a trusted proxy.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        self.0.iter().any(|net| net.contains(&ip))
+    }
+
+    /// The trusted ranges, for logging.
+    pub fn ranges(&self) -> &[IpNet] {
+        &self.0
+    }
+}
+
 /// Extract client IP address from request.
 ///
-/// Tries in order:
-/// 1. X-Real-IP header (set by reverse proxy)
-/// 2. X-Forwarded-For header (first IP in chain)
-/// 3. Socket address from connection
+/// Forwarding headers are only honored when the connection comes from a
+/// trusted proxy. Then, in order:
+/// 1. X-Real-IP header (set by the proxy)
+/// 2. X-Forwarded-For header: the nearest address that isn't a trusted
+///    proxy, walking the chain from the right, since anything further left
+///    may have been written by the client
 ///
-/// Falls back to 0.0.0.0 if no IP can be determined.
-pub fn extract_client_ip(headers: &HeaderMap, socket_addr: Option<SocketAddr>) -> IpAddr {
+/// Otherwise the socket address is used. Falls back to 0.0.0.0 if no IP can
+/// be determined.
+pub fn extract_client_ip(
+    headers: &HeaderMap,
+    socket_addr: Option<SocketAddr>,
+    trusted: &TrustedProxies,
+) -> IpAddr {
+    let Some(peer) = socket_addr.map(|addr| addr.ip()) else {
+        // Without a peer there's no way to tell who set the headers
+        return IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
+    };
+    if !trusted.contains(peer) {
+        return peer;
+    }
+
     // Try X-Real-IP first (most reliable when set by trusted proxy)
     if let Some(real_ip) = headers.get("X-Real-IP") {
         if let Ok(ip_str) = real_ip.to_str() {
@@ -214,25 +573,52 @@ pub fn extract_client_ip(headers: &HeaderMap, socket_addr: Option<SocketAddr>) -
         }
     }
 
-    // Try X-Forwarded-For (take first IP)
+    // Walk X-Forwarded-For from the right, past our own proxies
     if let Some(forwarded) = headers.get("X-Forwarded-For") {
         if let Ok(forwarded_str) = forwarded.to_str() {
-            // X-Forwarded-For can be a comma-separated list; take the first one
-            if let Some(first_ip) = forwarded_str.split(',').next() {
-                if let Ok(ip) = first_ip.trim().parse::<IpAddr>() {
-                    return ip;
+            let mut client = None;
+            for hop in forwarded_str.rsplit(',') {
+                let Ok(ip) = hop.trim().parse::<IpAddr>() else {
+                    break;
+                };
+                client = Some(ip);
+                if !trusted.contains(ip) {
+                    break;
                 }
             }
+            if let Some(ip) = client {
+                return ip;
+            }
         }
     }
 
-    // Fall back to socket address
-    if let Some(addr) = socket_addr {
-        return addr.ip();
-    }
+    peer
+}
 
-    // Last resort: use a default IP (this should rarely happen in practice)
-    IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0))
+/// The client IP of a request, as found by [`extract_client_ip`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+    TrustedProxies: FromRef<S>,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let socket_addr = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|c| c.0);
+        let trusted = TrustedProxies::from_ref(state);
+        Ok(ClientIp(extract_client_ip(
+            &parts.headers,
+            socket_addr,
+            &trusted,
+        )))
+    }
 }
 
 #[cfg(test)]
@@ -240,35 +626,6 @@ mod tests {
     use super::*;
     use std::net::Ipv4Addr;
 
-    #[test]
-    fn test_failure_tracker_backoff_durations() {
-        let mut tracker = FailureTracker::new();
-        assert_eq!(tracker.backoff_duration(), Duration::from_secs(0));
-
-        tracker.failure_count = 1;
-        assert_eq!(tracker.backoff_duration(), Duration::from_secs(0));
-
-        tracker.failure_count = 2;
-        assert_eq!(tracker.backoff_duration(), Duration::from_secs(5));
-
-        tracker.failure_count = 3;
-        assert_eq!(tracker.backoff_duration(), Duration::from_secs(30));
-
-        tracker.failure_count = 4;
-        assert_eq!(tracker.backoff_duration(), Duration::from_secs(300));
-
-        tracker.failure_count = 10;
-        assert_eq!(tracker.backoff_duration(), Duration::from_secs(300));
-    }
-
-    #[test]
-    fn test_failure_tracker_reset() {
-        let mut tracker = FailureTracker::new();
-        tracker.failure_count = 5;
-        tracker.reset();
-        assert_eq!(tracker.failure_count, 0);
-    }
-
     #[tokio::test]
     async fn test_rate_limit_state_allows_initial_requests() {
         let state = RateLimitState::new();
@@ -295,6 +652,17 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_recovery_rate_limit_is_stricter() {
+        let state = RateLimitState::recovery();
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 5));
+
+        for _ in 0..3 {
+            assert!(state.check_rate_limit(ip).await.is_ok());
+        }
+        assert!(state.check_rate_limit(ip).await.is_err());
+    }
+
     #[tokio::test]
     async fn test_exponential_backoff_after_failures() {
         let state = RateLimitState::new();
@@ -322,13 +690,132 @@ mod tests {
         state.record_failure(ip).await;
         state.record_failure(ip).await;
 
+        assert_eq!(state.tracked_clients().await, (1, 1));
+
         // Record success
         state.record_success(ip).await;
 
         // Backoff should be reset
-        let trackers = state.failure_trackers.read().await;
-        let tracker = trackers.get(&ip).unwrap();
-        assert_eq!(tracker.failure_count, 0);
+        assert!(state.check_rate_limit(ip).await.is_ok());
+        assert_eq!(state.tracked_clients().await, (0, 0));
+    }
+
+    #[tokio::test]
+    async fn test_limiters_sharing_a_backend_keep_separate_counters() {
+        let backend: Arc<dyn RateLimitBackend> = Arc::new(MemoryRateLimitBackend::new());
+        let pin = RateLimitState::new().with_backend(backend.clone());
+        let replica = RateLimitState::new().with_backend(backend.clone());
+        let recovery = RateLimitState::recovery().with_backend(backend);
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 6));
+
+        // Failures seen by one instance put the client in backoff on another
+        pin.record_failure(ip).await;
+        replica.record_failure(ip).await;
+        assert!(replica.check_rate_limit(ip).await.is_err());
+        assert!(pin.check_rate_limit(ip).await.is_err());
+        assert!(recovery.check_rate_limit(ip).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_partner_rate_limits_are_per_partner() {
+        let limits = PartnerRateLimits::new();
+        let busy = Uuid::new_v4();
+        let quiet = Uuid::new_v4();
+
+        for _ in 0..3 {
+            assert!(limits.check(busy, 3).await.is_ok());
+        }
+        let retry_after = limits.check(busy, 3).await.unwrap_err();
+        assert!(retry_after > 0);
+
+        // Another partner has its own quota
+        assert!(limits.check(quiet, 3).await.is_ok());
+
+        // Raising the quota starts a fresh limiter
+        assert!(limits.check(busy, 10).await.is_ok());
+    }
+
+    #[test]
+    fn test_rate_limit_policies_parse() {
+        let policies = RateLimitPolicies::parse(
+            "photo_uploads.employee=5, photo_uploads.ip=0,ticket_creation.bogus=1,unknown.ip=3,junk",
+        );
+        assert_eq!(
+            policies.get("photo_uploads"),
+            Some(RateLimitPolicy {
+                per_ip: 0,
+                per_employee: 5
+            })
+        );
+        assert_eq!(
+            policies.get("ticket_creation"),
+            RateLimitPolicies::default().get("ticket_creation")
+        );
+        assert_eq!(policies.get("unknown"), None);
+    }
+
+    #[test]
+    fn test_employee_key() {
+        use axum::http::HeaderValue;
+        let mut headers = HeaderMap::new();
+        assert_eq!(employee_key(&headers), None);
+
+        headers.insert("X-Employee-ID", HeaderValue::from_static("abc"));
+        assert_eq!(employee_key(&headers).as_deref(), Some("id:abc"));
+
+        // The session wins, and the raw token isn't kept
+        headers.insert(
+            "X-Employee-Session",
+            HeaderValue::from_static("secret-token"),
+        );
+        let key = employee_key(&headers).unwrap();
+        assert!(key.starts_with("session:"));
+        assert!(!key.contains("secret-token"));
+    }
+
+    #[tokio::test]
+    async fn test_enforce_rate_limit_per_employee_and_ip() {
+        use axum::{http::StatusCode, middleware, routing::post, Router};
+        use tower::ServiceExt;
+
+        let mut policies = RateLimitPolicies::default();
+        policies.0.insert(
+            "ticket_creation",
+            RateLimitPolicy {
+                per_ip: 4,
+                per_employee: 2,
+            },
+        );
+        let limit =
+            RouteRateLimits::new(&policies).group("ticket_creation", &TrustedProxies::default());
+        let app = Router::new()
+            .route("/", post(|| async { "created" }))
+            .layer(middleware::from_fn_with_state(limit, enforce_rate_limit));
+
+        let send = |employee: &'static str| {
+            let request = Request::post("/")
+                .header("X-Employee-ID", employee)
+                .body(Body::empty())
+                .unwrap();
+            app.clone().oneshot(request)
+        };
+
+        assert_eq!(send("alice").await.unwrap().status(), StatusCode::OK);
+        assert_eq!(send("alice").await.unwrap().status(), StatusCode::OK);
+        let response = send("alice").await.unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(response.headers().contains_key("Retry-After"));
+
+        // Another employee has their own quota, until the shared IP runs out
+        assert_eq!(send("bob").await.unwrap().status(), StatusCode::OK);
+        assert_eq!(
+            send("carol").await.unwrap().status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    fn proxy() -> Option<SocketAddr> {
+        Some(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 8080))
     }
 
     #[test]
@@ -337,7 +824,7 @@ mod tests {
         let mut headers = HeaderMap::new();
         headers.insert("X-Real-IP", HeaderValue::from_static("10.0.0.1"));
 
-        let ip = extract_client_ip(&headers, None);
+        let ip = extract_client_ip(&headers, proxy(), &TrustedProxies::default());
         assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
     }
 
@@ -350,8 +837,23 @@ mod tests {
             HeaderValue::from_static("203.0.113.50, 70.41.3.18, 150.172.238.178"),
         );
 
-        let ip = extract_client_ip(&headers, None);
-        // Should return the first IP in the chain
+        let ip = extract_client_ip(&headers, proxy(), &TrustedProxies::default());
+        // The rightmost hop was added by our proxy; anything left of it is
+        // client-supplied
+        assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(150, 172, 238, 178)));
+    }
+
+    #[test]
+    fn test_extract_client_ip_skips_trusted_hops() {
+        use axum::http::HeaderValue;
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Forwarded-For",
+            HeaderValue::from_static("198.51.100.7, 203.0.113.50, 10.0.0.2"),
+        );
+
+        let trusted = TrustedProxies::parse("127.0.0.1, 10.0.0.0/8");
+        let ip = extract_client_ip(&headers, proxy(), &trusted);
         assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(203, 0, 113, 50)));
     }
 
@@ -362,25 +864,44 @@ mod tests {
         headers.insert("X-Real-IP", HeaderValue::from_static("10.0.0.1"));
         headers.insert("X-Forwarded-For", HeaderValue::from_static("203.0.113.50"));
 
-        let ip = extract_client_ip(&headers, None);
+        let ip = extract_client_ip(&headers, proxy(), &TrustedProxies::default());
         // X-Real-IP should take precedence
         assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
     }
 
+    #[test]
+    fn test_extract_client_ip_ignores_headers_from_untrusted_peer() {
+        use axum::http::HeaderValue;
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Real-IP", HeaderValue::from_static("10.0.0.1"));
+        headers.insert("X-Forwarded-For", HeaderValue::from_static("203.0.113.50"));
+
+        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20)), 8080);
+        let ip = extract_client_ip(&headers, Some(socket_addr), &TrustedProxies::default());
+        assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20)));
+
+        // Trusting no proxy ignores headers even from loopback
+        let ip = extract_client_ip(&headers, proxy(), &TrustedProxies::none());
+        assert_eq!(ip, IpAddr::V4(Ipv4Addr::LOCALHOST));
+    }
+
     #[test]
     fn test_extract_client_ip_from_socket_addr() {
         let headers = HeaderMap::new();
         let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 8080);
 
-        let ip = extract_client_ip(&headers, Some(socket_addr));
+        let ip = extract_client_ip(&headers, Some(socket_addr), &TrustedProxies::default());
         assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)));
     }
 
     #[test]
     fn test_extract_client_ip_fallback() {
-        let headers = HeaderMap::new();
-        let ip = extract_client_ip(&headers, None);
-        // Should fallback to 0.0.0.0
+        use axum::http::HeaderValue;
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Real-IP", HeaderValue::from_static("10.0.0.1"));
+
+        // Headers can't be trusted without knowing the peer
+        let ip = extract_client_ip(&headers, None, &TrustedProxies::default());
         assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)));
     }
 
@@ -390,11 +911,10 @@ mod tests {
         let mut headers = HeaderMap::new();
         headers.insert("X-Real-IP", HeaderValue::from_static("not-an-ip"));
 
-        let socket_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)), 8080);
-        let ip = extract_client_ip(&headers, Some(socket_addr));
+        let ip = extract_client_ip(&headers, proxy(), &TrustedProxies::default());
 
         // Should fall back to socket addr since X-Real-IP is invalid
-        assert_eq!(ip, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)));
+        assert_eq!(ip, IpAddr::V4(Ipv4Addr::LOCALHOST));
     }
 
     #[test]
@@ -402,9 +922,26 @@ mod tests {
         use axum::http::HeaderValue;
         use std::net::Ipv6Addr;
         let mut headers = HeaderMap::new();
-        headers.insert("X-Real-IP", HeaderValue::from_static("::1"));
+        headers.insert("X-Real-IP", HeaderValue::from_static("2001:db8::1"));
+
+        let socket_addr = SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 8080);
+        let ip = extract_client_ip(&headers, Some(socket_addr), &TrustedProxies::default());
+        assert_eq!(
+            ip,
+            IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))
+        );
+    }
 
-        let ip = extract_client_ip(&headers, None);
-        assert_eq!(ip, IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)));
+    #[test]
+    fn test_trusted_proxies_parse() {
+        let trusted = TrustedProxies::parse(" 10.0.0.0/8, 192.168.1.5 ,bogus,, fd00::/8");
+        assert_eq!(trusted.ranges().len(), 3);
+        assert!(trusted.contains("10.20.30.40".parse().unwrap()));
+        assert!(trusted.contains("192.168.1.5".parse().unwrap()));
+        assert!(!trusted.contains("192.168.1.6".parse().unwrap()));
+        assert!(trusted.contains("fd12::1".parse().unwrap()));
+        // IPv4-mapped IPv6 peers match their IPv4 range
+        assert!(trusted.contains("::ffff:10.0.0.1".parse().unwrap()));
+        assert!(TrustedProxies::parse("").ranges().is_empty());
     }
 }
@@ -0,0 +1,216 @@
+//! Storage for PIN and recovery failure counters.
+//!
+//! Backoff after failed attempts is tracked per limiter and client IP in a
+//! [`RateLimitBackend`], chosen with `RATE_LIMIT_BACKEND`. The in-memory
+//! backend is per instance and forgets on restart. The Postgres backend keeps
+//! counters in `rate_limit_failures`, so backoff survives a deploy and every
+//! replica sees the same failures.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use tokio::sync::RwLock;
+
+use crate::error::AppError;
+use crate::models::rate_limit::FailureRecord;
+use crate::repositories::RateLimitRepository;
+
+/// How long a client's failures are kept after the last one.
+pub const FAILURE_RETENTION: chrono::Duration = chrono::Duration::days(1);
+
+/// Where failure counters are kept.
+#[async_trait]
+pub trait RateLimitBackend: Send + Sync {
+    /// Short backend name for logs (e.g., "postgres").
+    fn name(&self) -> &'static str;
+
+    /// A client's failures for a limiter, if any.
+    async fn get(&self, scope: &str, ip: IpAddr) -> Result<Option<FailureRecord>, AppError>;
+
+    /// Count a failure, returning the updated record.
+    async fn record_failure(&self, scope: &str, ip: IpAddr) -> Result<FailureRecord, AppError>;
+
+    /// Clear a client's failures for a limiter.
+    async fn reset(&self, scope: &str, ip: IpAddr) -> Result<(), AppError>;
+
+    /// Every failure record for a limiter.
+    async fn list(&self, scope: &str) -> Result<Vec<FailureRecord>, AppError>;
+
+    /// Forget clients whose last failure was before `idle_before`.
+    async fn purge(&self, idle_before: DateTime<Utc>) -> Result<u64, AppError>;
+}
+
+/// Which rate limit backend to use (`RATE_LIMIT_BACKEND`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitBackendKind {
+    /// Counters in this process only
+    #[default]
+    Memory,
+    /// Counters in the database, shared by every replica
+    Postgres,
+}
+
+impl RateLimitBackendKind {
+    /// Parse a backend name: `memory` or `postgres`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "memory" => Some(Self::Memory),
+            "postgres" => Some(Self::Postgres),
+            _ => None,
+        }
+    }
+}
+
+/// Failure counters kept in this process.
+#[derive(Default)]
+pub struct MemoryRateLimitBackend {
+    records: RwLock<HashMap<(String, IpAddr), FailureRecord>>,
+}
+
+impl MemoryRateLimitBackend {
+    /// Create an empty in-memory backend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RateLimitBackend for MemoryRateLimitBackend {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn get(&self, scope: &str, ip: IpAddr) -> Result<Option<FailureRecord>, AppError> {
+        let records = self.records.read().await;
+        Ok(records.get(&(scope.to_string(), ip)).copied())
+    }
+
+    async fn record_failure(&self, scope: &str, ip: IpAddr) -> Result<FailureRecord, AppError> {
+        let mut records = self.records.write().await;
+        let record = records
+            .entry((scope.to_string(), ip))
+            .or_insert(FailureRecord {
+                failure_count: 0,
+                last_failure_at: Utc::now(),
+            });
+        record.failure_count += 1;
+        record.last_failure_at = Utc::now();
+        Ok(*record)
+    }
+
+    async fn reset(&self, scope: &str, ip: IpAddr) -> Result<(), AppError> {
+        self.records.write().await.remove(&(scope.to_string(), ip));
+        Ok(())
+    }
+
+    async fn list(&self, scope: &str) -> Result<Vec<FailureRecord>, AppError> {
+        let records = self.records.read().await;
+        Ok(records
+            .iter()
+            .filter(|((record_scope, _), _)| record_scope == scope)
+            .map(|(_, record)| *record)
+            .collect())
+    }
+
+    async fn purge(&self, idle_before: DateTime<Utc>) -> Result<u64, AppError> {
+        let mut records = self.records.write().await;
+        let before = records.len();
+        records.retain(|_, record| record.last_failure_at >= idle_before);
+        Ok((before - records.len()) as u64)
+    }
+}
+
+/// Failure counters in Postgres, shared by every replica.
+pub struct PostgresRateLimitBackend {
+    pool: PgPool,
+}
+
+impl PostgresRateLimitBackend {
+    /// Create a backend storing counters through `pool`.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RateLimitBackend for PostgresRateLimitBackend {
+    fn name(&self) -> &'static str {
+        "postgres"
+    }
+
+    async fn get(&self, scope: &str, ip: IpAddr) -> Result<Option<FailureRecord>, AppError> {
+        RateLimitRepository::find(&self.pool, scope, &ip.to_string()).await
+    }
+
+    async fn record_failure(&self, scope: &str, ip: IpAddr) -> Result<FailureRecord, AppError> {
+        RateLimitRepository::record_failure(&self.pool, scope, &ip.to_string()).await
+    }
+
+    async fn reset(&self, scope: &str, ip: IpAddr) -> Result<(), AppError> {
+        RateLimitRepository::reset(&self.pool, scope, &ip.to_string()).await
+    }
+
+    async fn list(&self, scope: &str) -> Result<Vec<FailureRecord>, AppError> {
+        RateLimitRepository::list(&self.pool, scope).await
+    }
+
+    async fn purge(&self, idle_before: DateTime<Utc>) -> Result<u64, AppError> {
+        RateLimitRepository::purge(&self.pool, idle_before).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[test]
+    fn test_parse_backend_kind() {
+        assert_eq!(
+            RateLimitBackendKind::parse("memory"),
+            Some(RateLimitBackendKind::Memory)
+        );
+        assert_eq!(
+            RateLimitBackendKind::parse(" Postgres "),
+            Some(RateLimitBackendKind::Postgres)
+        );
+        assert_eq!(RateLimitBackendKind::parse("redis"), None);
+    }
+
+    #[tokio::test]
+    async fn test_memory_backend_counts_per_scope() {
+        let backend = MemoryRateLimitBackend::new();
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 9));
+
+        backend.record_failure("pin", ip).await.unwrap();
+        let record = backend.record_failure("pin", ip).await.unwrap();
+        assert_eq!(record.failure_count, 2);
+        assert_eq!(backend.get("recovery", ip).await.unwrap(), None);
+        assert_eq!(backend.list("pin").await.unwrap().len(), 1);
+
+        backend.reset("pin", ip).await.unwrap();
+        assert_eq!(backend.get("pin", ip).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_memory_backend_purges_idle_clients() {
+        let backend = MemoryRateLimitBackend::new();
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10));
+        backend.record_failure("pin", ip).await.unwrap();
+
+        assert_eq!(
+            backend.purge(Utc::now() - FAILURE_RETENTION).await.unwrap(),
+            0
+        );
+        assert_eq!(
+            backend
+                .purge(Utc::now() + chrono::Duration::seconds(1))
+                .await
+                .unwrap(),
+            1
+        );
+        assert!(backend.list("pin").await.unwrap().is_empty());
+    }
+}
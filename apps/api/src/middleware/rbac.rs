@@ -1,16 +1,86 @@
 //! Role-based access control (RBAC) helpers.
 //!
 //! Provides functions for checking employee permissions on tickets
-//! and other resources based on their role and relationship to the resource.
+//! and other resources based on the permissions each employee holds and
+//! their relationship to the resource.
+//!
+//! Also defines the [`ProbePolicy`] that decides how requests for
+//! resources that don't exist are answered.
+
+use serde::Serialize;
 
 use crate::error::AppError;
-use crate::models::{Employee, EmployeeRole, Permission, Ticket};
+use crate::models::{Employee, Permission, Ticket};
+
+/// How the API answers requests for resources that don't exist.
+///
+/// Every handler that looks up a resource by ID reports a miss through
+/// [`ProbePolicy::missing`], so clients see the same behavior everywhere.
+/// Configured with the `RESOURCE_PROBE_POLICY` environment variable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbePolicy {
+    /// Missing resources return 404 NOT_FOUND.
+    #[default]
+    NotFound,
+    /// Missing resources return the same 403 FORBIDDEN as an access denial,
+    /// so callers can't tell whether an ID exists.
+    Forbidden,
+}
+
+impl ProbePolicy {
+    /// Parse a policy from its config value ("not_found" or "forbidden").
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "not_found" => Some(ProbePolicy::NotFound),
+            "forbidden" => Some(ProbePolicy::Forbidden),
+            _ => None,
+        }
+    }
+
+    /// Error for a resource that doesn't exist.
+    ///
+    /// `resource` is a lowercase noun such as "ticket" or "customer".
+    pub fn missing(self, resource: &str) -> AppError {
+        match self {
+            ProbePolicy::NotFound => AppError::not_found(format!(
+                "{}{} not found",
+                resource[..1].to_ascii_uppercase(),
+                &resource[1..]
+            )),
+            ProbePolicy::Forbidden => access_denied(resource),
+        }
+    }
+
+    /// One-line description of the policy for the error catalog.
+    pub fn description(self) -> &'static str {
+        match self {
+            ProbePolicy::NotFound => {
+                "Requests for resources that do not exist return 404 NOT_FOUND"
+            }
+            ProbePolicy::Forbidden => {
+                "Requests for resources that do not exist return 403 FORBIDDEN, identical to an access denial"
+            }
+        }
+    }
+}
+
+/// Access denial for a specific resource.
+///
+/// Shares its message with [`ProbePolicy::Forbidden`] misses so the two
+/// can't be told apart.
+pub fn access_denied(resource: &str) -> AppError {
+    AppError::forbidden(format!(
+        "You do not have permission to access this {}",
+        resource
+    ))
+}
 
 /// Check if an employee has the required permission.
 ///
 /// Returns `Ok(())` if the employee has the permission, or an error if not.
 pub fn require_permission(employee: &Employee, permission: Permission) -> Result<(), AppError> {
-    if employee.role.has_permission(permission) {
+    if employee.has_permission(permission) {
         Ok(())
     } else {
         Err(AppError::forbidden(
@@ -21,8 +91,8 @@ pub fn require_permission(employee: &Employee, permission: Permission) -> Result
 
 /// Check if an employee has permission to access/modify a ticket.
 ///
-/// For staff employees, this checks ownership (taken_in_by or worked_by).
-/// Admin employees always have access.
+/// Employees without [`Permission::ModifyAnyTicket`] must own the ticket
+/// (taken_in_by or worked_by) to modify it. Admins always have access.
 ///
 /// # Arguments
 /// * `employee` - The employee requesting access
@@ -37,13 +107,13 @@ pub fn require_ticket_access(
     ticket: &Ticket,
     permission: Permission,
 ) -> Result<(), AppError> {
-    // Admin always has full access
-    if employee.role == EmployeeRole::Admin {
+    // Admins and employees who may modify any ticket have full access
+    if employee.has_permission(Permission::ModifyAnyTicket) {
         return Ok(());
     }
 
-    // For non-admins, check if they have the base permission first
-    if !employee.role.has_permission(permission) {
+    // Otherwise, check the base permission first
+    if !employee.has_permission(permission) {
         return Err(AppError::forbidden(
             "You do not have permission to perform this action",
         ));
@@ -54,10 +124,8 @@ pub fn require_ticket_access(
         if is_ticket_owner(employee, ticket) {
             return Ok(());
         }
-        // Staff can't modify tickets they don't own
-        return Err(AppError::forbidden(
-            "You do not have permission to modify this ticket",
-        ));
+        // Employees can't modify tickets they don't own
+        return Err(access_denied("ticket"));
     }
 
     // For other permissions (ViewTicket, AddNotes, UploadPhotos),
@@ -76,41 +144,51 @@ pub fn is_ticket_owner(employee: &Employee, ticket: &Ticket) -> bool {
 
 /// Check if an employee can close a specific ticket.
 ///
-/// Only admins can close tickets. Staff cannot close any ticket,
-/// even ones they own.
+/// Requires [`Permission::CloseAnyTicket`], even for tickets the employee
+/// owns.
 pub fn can_close_ticket(employee: &Employee) -> Result<(), AppError> {
-    if employee.role == EmployeeRole::Admin {
+    if employee.has_permission(Permission::CloseAnyTicket) {
         Ok(())
     } else {
-        Err(AppError::forbidden("Only administrators can close tickets"))
+        Err(AppError::forbidden(
+            "You do not have permission to close tickets",
+        ))
     }
 }
 
 /// Check if an employee can delete photos.
 ///
-/// Only admins can delete photos. Staff cannot delete any photos.
+/// Requires [`Permission::DeletePhotos`].
 pub fn can_delete_photo(employee: &Employee) -> Result<(), AppError> {
-    if employee.role.has_permission(Permission::DeletePhotos) {
+    if employee.has_permission(Permission::DeletePhotos) {
         Ok(())
     } else {
-        Err(AppError::forbidden("Only administrators can delete photos"))
+        Err(AppError::forbidden(
+            "You do not have permission to delete photos",
+        ))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::TicketStatus;
+    use crate::models::{EmployeeRole, TicketStatus};
     use chrono::Utc;
     use uuid::Uuid;
 
     fn create_test_employee(role: EmployeeRole) -> Employee {
         Employee {
             employee_id: Uuid::new_v4(),
+            store_id: Uuid::nil(),
             name: "Test Employee".to_string(),
             pin_hash: "hash".to_string(),
+            pin_challenge_key: None,
+            pin_challenge_salt: None,
+            phone: None,
             role,
+            permissions: role.default_permissions(),
             is_active: true,
+            pin_locked_at: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -129,6 +207,7 @@ mod tests {
             is_rush: false,
             promise_date: None,
             storage_location_id: Uuid::new_v4(),
+            store_id: Uuid::nil(),
             quote_amount: None,
             actual_amount: None,
             taken_in_by,
@@ -141,6 +220,12 @@ mod tests {
             queue_position: None,
             deleted_at: None,
             deleted_by: None,
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "tok".to_string(),
+            weight_grams: None,
+            metal_type: None,
         }
     }
 
@@ -238,6 +323,22 @@ mod tests {
         assert!(can_close_ticket(&staff).is_err());
     }
 
+    #[test]
+    fn test_granted_permissions_replace_role_checks() {
+        let mut staff = create_test_employee(EmployeeRole::Staff);
+        staff.permissions.push(Permission::CloseAnyTicket);
+        staff.permissions.push(Permission::ModifyAnyTicket);
+        assert!(can_close_ticket(&staff).is_ok());
+
+        let ticket = create_test_ticket(Uuid::new_v4(), None);
+        assert!(require_ticket_access(&staff, &ticket, Permission::ModifyOwnTicket).is_ok());
+
+        staff
+            .permissions
+            .retain(|p| *p != Permission::ManageCustomers);
+        assert!(require_permission(&staff, Permission::ManageCustomers).is_err());
+    }
+
     #[test]
     fn test_can_delete_photo_admin() {
         let admin = create_test_employee(EmployeeRole::Admin);
@@ -249,4 +350,39 @@ mod tests {
         let staff = create_test_employee(EmployeeRole::Staff);
         assert!(can_delete_photo(&staff).is_err());
     }
+
+    #[test]
+    fn test_probe_policy_parse() {
+        assert_eq!(ProbePolicy::parse("not_found"), Some(ProbePolicy::NotFound));
+        assert_eq!(
+            ProbePolicy::parse(" FORBIDDEN "),
+            Some(ProbePolicy::Forbidden)
+        );
+        assert_eq!(ProbePolicy::parse("hide"), None);
+        assert_eq!(ProbePolicy::default(), ProbePolicy::NotFound);
+    }
+
+    #[test]
+    fn test_probe_policy_not_found() {
+        let err = ProbePolicy::NotFound.missing("ticket");
+        assert_eq!(err.code(), "NOT_FOUND");
+        assert_eq!(err.message(), "Ticket not found");
+    }
+
+    #[test]
+    fn test_probe_policy_forbidden_matches_access_denial() {
+        let missing = ProbePolicy::Forbidden.missing("ticket");
+        let denied = access_denied("ticket");
+        assert_eq!(missing.code(), "FORBIDDEN");
+        assert_eq!(missing.status_code(), denied.status_code());
+        assert_eq!(missing.message(), denied.message());
+    }
+
+    #[test]
+    fn test_ticket_ownership_denial_uses_access_denied() {
+        let staff = create_test_employee(EmployeeRole::Staff);
+        let ticket = create_test_ticket(Uuid::new_v4(), None);
+        let err = require_ticket_access(&staff, &ticket, Permission::ModifyOwnTicket).unwrap_err();
+        assert_eq!(err.message(), access_denied("ticket").message());
+    }
 }
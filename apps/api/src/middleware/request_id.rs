@@ -0,0 +1,150 @@
+//! Request ID middleware.
+//!
+//! Every request gets an ID: the client's `X-Request-ID` when it sends a
+//! usable one (so a proxy or the web client can pick it), otherwise a new
+//! UUID. The ID is attached to a tracing span around the handler, so every
+//! log line for the request carries it; it is scoped over the request so
+//! [`AppError`](crate::AppError) bodies and the response `meta` include it;
+//! and it is echoed in the `X-Request-ID` response header. Support can then
+//! match a client bug report to the server logs.
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, HeaderName, HeaderValue, Request, Response},
+    middleware::Next,
+};
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// Header the request ID is read from and returned in.
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Longest client-supplied request ID that is accepted.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static CURRENT: RequestId;
+}
+
+/// Identifier assigned to each request, available to handlers as an extension.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    /// A new random request ID.
+    pub fn generate() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+
+    /// The client's `X-Request-ID` if it is usable, otherwise a new ID.
+    ///
+    /// Client IDs are limited to 128 letters, digits, and `-_.:` so they
+    /// can't inject anything into logs or headers.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(&REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .map(str::trim)
+            .filter(|id| is_valid(id))
+            .map(|id| Self(id.to_string()))
+            .unwrap_or_else(Self::generate)
+    }
+
+    /// ID of the request being handled, if any.
+    pub fn current() -> Option<Self> {
+        CURRENT.try_with(|id| id.clone()).ok()
+    }
+}
+
+fn is_valid(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'))
+}
+
+/// Middleware that assigns, traces, and returns the request ID.
+pub async fn request_id(mut request: Request<Body>, next: Next) -> Response<Body> {
+    let id = RequestId::from_headers(request.headers());
+    request.extensions_mut().insert(id.clone());
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %id.0,
+        method = %request.method(),
+        path = %request.uri().path(),
+    );
+    let mut response = CURRENT
+        .scope(id.clone(), next.run(request).instrument(span))
+        .await;
+
+    if let Ok(value) = HeaderValue::from_str(&id.0) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AppError;
+    use axum::{middleware, routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn failing_handler() -> Result<(), AppError> {
+        Err(AppError::not_found("Ticket not found"))
+    }
+
+    fn app() -> Router {
+        Router::new()
+            .route("/fail", get(failing_handler))
+            .layer(middleware::from_fn(request_id))
+    }
+
+    #[test]
+    fn test_client_ids_are_validated() {
+        let mut headers = HeaderMap::new();
+        headers.insert(&REQUEST_ID_HEADER, HeaderValue::from_static("web-1234.abc"));
+        assert_eq!(RequestId::from_headers(&headers).0, "web-1234.abc");
+
+        headers.insert(&REQUEST_ID_HEADER, HeaderValue::from_static("bad id\"{}"));
+        let generated = RequestId::from_headers(&headers);
+        assert!(Uuid::parse_str(&generated.0).is_ok());
+
+        let long = "a".repeat(MAX_REQUEST_ID_LEN + 1);
+        headers.insert(&REQUEST_ID_HEADER, HeaderValue::from_str(&long).unwrap());
+        assert_ne!(RequestId::from_headers(&headers).0, long);
+    }
+
+    #[tokio::test]
+    async fn test_client_id_is_echoed_in_header_and_error() {
+        let request = Request::builder()
+            .uri("/fail")
+            .header("x-request-id", "support-42")
+            .body(Body::empty())
+            .unwrap();
+        let response = app().oneshot(request).await.unwrap();
+
+        assert_eq!(response.headers()["x-request-id"], "support-42");
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"]["request_id"], "support-42");
+    }
+
+    #[tokio::test]
+    async fn test_id_is_generated_when_missing() {
+        let request = Request::builder().uri("/fail").body(Body::empty()).unwrap();
+        let response = app().oneshot(request).await.unwrap();
+
+        let header = response.headers()["x-request-id"]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(Uuid::parse_str(&header).is_ok());
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"]["request_id"], header.as_str());
+    }
+}
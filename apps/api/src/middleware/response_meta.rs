@@ -0,0 +1,234 @@
+//! Response metadata middleware.
+//!
+//! Adds a `meta` block to every JSON API envelope with the server time,
+//! processing duration, request ID, any deprecation notices that apply to
+//! the request, and a training-mode flag. Handlers never build `meta`
+//! themselves; this layer is the single place it is emitted.
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, HeaderMap, HeaderValue, Method, Request, Response},
+    middleware::Next,
+};
+use chrono::Utc;
+use serde_json::Value;
+use std::time::Instant;
+
+use crate::middleware::request_id::RequestId;
+use crate::middleware::training::TrainingMode;
+use crate::response::{DeprecationNotice, ResponseMeta};
+
+/// An endpoint scheduled for removal.
+struct DeprecatedEndpoint {
+    /// Method the notice applies to (None = every method)
+    method: Option<Method>,
+    /// Path prefix under /api/v1
+    path_prefix: &'static str,
+    message: &'static str,
+    /// Planned removal date (YYYY-MM-DD)
+    sunset: Option<&'static str>,
+}
+
+/// Endpoints that are deprecated. Add an entry here rather than in the handler.
+const DEPRECATED_ENDPOINTS: &[DeprecatedEndpoint] = &[];
+
+/// Request headers that are still accepted but deprecated.
+const DEPRECATED_HEADERS: &[(&str, &str)] = &[
+    (
+        "x-admin-pin",
+        "The X-Admin-PIN header is deprecated; use X-Admin-Session from POST /api/v1/admin/verify",
+    ),
+    (
+        "x-employee-id",
+        "The X-Employee-ID header is deprecated; use X-Employee-Session from POST /api/v1/employees/verify",
+    ),
+];
+
+/// Middleware that attaches `meta` to JSON API responses.
+pub async fn response_meta(mut request: Request<Body>, next: Next) -> Response<Body> {
+    let started = Instant::now();
+    // The request ID layer normally runs first; generate one if it didn't
+    let request_id = match request.extensions().get::<RequestId>() {
+        Some(id) => id.clone(),
+        None => {
+            let id = RequestId::generate();
+            request.extensions_mut().insert(id.clone());
+            id
+        }
+    };
+    let deprecations =
+        deprecation_notices(request.method(), request.uri().path(), request.headers());
+
+    let response = next.run(request).await;
+    if !is_json(response.headers()) {
+        return response;
+    }
+    let training_mode = response
+        .extensions()
+        .get::<TrainingMode>()
+        .is_some_and(|t| t.0);
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            tracing::warn!("Failed to buffer response for meta: {:?}", err);
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    let meta = ResponseMeta {
+        server_time: Utc::now(),
+        duration_ms: started.elapsed().as_millis().min(u64::MAX as u128) as u64,
+        request_id: request_id.0,
+        deprecations,
+        training_mode,
+    };
+    if !meta.deprecations.is_empty() {
+        parts
+            .headers
+            .insert("deprecation", HeaderValue::from_static("true"));
+    }
+
+    let bytes = match with_meta(&bytes, &meta) {
+        Some(rewritten) => {
+            parts.headers.remove(header::CONTENT_LENGTH);
+            rewritten
+        }
+        None => bytes.to_vec(),
+    };
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("application/json"))
+}
+
+/// Insert `meta` into a response envelope.
+///
+/// Returns None if the body isn't an API envelope (an object with `data`
+/// and `error`), in which case it is passed through unchanged.
+fn with_meta(bytes: &[u8], meta: &ResponseMeta) -> Option<Vec<u8>> {
+    let mut value: Value = serde_json::from_slice(bytes).ok()?;
+    let envelope = value.as_object_mut()?;
+    if !envelope.contains_key("data") || !envelope.contains_key("error") {
+        return None;
+    }
+
+    envelope.insert("meta".to_string(), serde_json::to_value(meta).ok()?);
+    serde_json::to_vec(&value).ok()
+}
+
+/// Collect deprecation notices for the endpoint and any deprecated headers used.
+fn deprecation_notices(method: &Method, path: &str, headers: &HeaderMap) -> Vec<DeprecationNotice> {
+    let endpoint_notices = DEPRECATED_ENDPOINTS
+        .iter()
+        .filter(|e| e.method.as_ref().is_none_or(|m| m == method))
+        .filter(|e| path.starts_with(e.path_prefix))
+        .map(|e| DeprecationNotice {
+            message: e.message.to_string(),
+            sunset: e.sunset.and_then(|s| s.parse().ok()),
+        });
+
+    let header_notices = DEPRECATED_HEADERS
+        .iter()
+        .filter(|(name, _)| headers.contains_key(*name))
+        .map(|(_, message)| DeprecationNotice {
+            message: message.to_string(),
+            sunset: None,
+        });
+
+    endpoint_notices.chain(header_notices).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{middleware, routing::get, Json, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::response::ApiResponse;
+
+    fn meta() -> ResponseMeta {
+        ResponseMeta {
+            server_time: Utc::now(),
+            duration_ms: 3,
+            request_id: "abc".to_string(),
+            deprecations: vec![],
+            training_mode: false,
+        }
+    }
+
+    #[test]
+    fn test_with_meta_inserts_into_envelope() {
+        let body = br#"{"data":{"id":1},"error":null}"#;
+        let result = with_meta(body, &meta()).unwrap();
+        let value: Value = serde_json::from_slice(&result).unwrap();
+        assert_eq!(value["data"]["id"], 1);
+        assert_eq!(value["meta"]["request_id"], "abc");
+        assert_eq!(value["meta"]["duration_ms"], 3);
+        assert!(value["meta"].get("deprecations").is_none());
+        assert!(value["meta"].get("training_mode").is_none());
+    }
+
+    #[test]
+    fn test_with_meta_flags_training_mode() {
+        let body = br#"{"data":null,"error":null}"#;
+        let meta = ResponseMeta {
+            training_mode: true,
+            ..meta()
+        };
+        let value: Value = serde_json::from_slice(&with_meta(body, &meta).unwrap()).unwrap();
+        assert_eq!(value["meta"]["training_mode"], true);
+    }
+
+    #[test]
+    fn test_with_meta_skips_non_envelopes() {
+        assert!(with_meta(br#"{"status":"ok"}"#, &meta()).is_none());
+        assert!(with_meta(br#"[1,2]"#, &meta()).is_none());
+        assert!(with_meta(b"not json", &meta()).is_none());
+    }
+
+    #[test]
+    fn test_deprecated_header_notices() {
+        let mut headers = HeaderMap::new();
+        assert!(deprecation_notices(&Method::GET, "/api/v1/employees", &headers).is_empty());
+
+        headers.insert("X-Admin-PIN", HeaderValue::from_static("1234"));
+        let notices = deprecation_notices(&Method::GET, "/api/v1/employees", &headers);
+        assert_eq!(notices.len(), 1);
+        assert!(notices[0].message.contains("X-Admin-Session"));
+    }
+
+    async fn ok_handler() -> Json<ApiResponse<&'static str>> {
+        Json(ApiResponse::success("ok"))
+    }
+
+    #[tokio::test]
+    async fn test_layer_adds_meta_and_deprecation_header() {
+        let app = Router::new()
+            .route("/test", get(ok_handler))
+            .layer(middleware::from_fn(response_meta));
+
+        let request = Request::builder()
+            .uri("/test")
+            .header("X-Employee-ID", "someone")
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.headers()["deprecation"], "true");
+
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let value: Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(value["data"], "ok");
+        assert!(value["meta"]["server_time"].is_string());
+        assert!(Uuid::parse_str(value["meta"]["request_id"].as_str().unwrap()).is_ok());
+        assert_eq!(value["meta"]["deprecations"].as_array().unwrap().len(), 1);
+    }
+}
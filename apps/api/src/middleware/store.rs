@@ -0,0 +1,127 @@
+//! Store resolution middleware.
+//!
+//! Every request works for one store: the one named by `X-Store-ID`, else
+//! the store the employee session was started at, else the employee's home
+//! store, else the primary store. This layer resolves it once per request
+//! and exposes it to handlers as [`CurrentStore`].
+
+use axum::{
+    async_trait,
+    body::Body,
+    extract::{FromRequestParts, State},
+    http::{request::Parts, Request, Response},
+    middleware::Next,
+    response::IntoResponse,
+};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::STORE_HEADER;
+use crate::repositories::StoreRepository;
+use crate::routes::AppState;
+
+/// The store the request works for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurrentStore(pub Uuid);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for CurrentStore {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        parts
+            .extensions
+            .get::<CurrentStore>()
+            .copied()
+            .ok_or_else(|| AppError::server_error("Store could not be determined"))
+    }
+}
+
+/// Middleware that resolves [`CurrentStore`].
+///
+/// A malformed or closed `X-Store-ID` is refused. If the lookup fails the
+/// store is left unresolved and only handlers that need it fail.
+pub async fn current_store(
+    State(state): State<AppState>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let headers = request.headers();
+    let requested = headers.get(STORE_HEADER).map(|value| {
+        value
+            .to_str()
+            .ok()
+            .and_then(|value| Uuid::parse_str(value.trim()).ok())
+    });
+    let session_token = headers
+        .get("X-Employee-Session")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let employee_id = headers
+        .get("X-Employee-ID")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| Uuid::parse_str(v).ok());
+
+    let resolved = match requested {
+        Some(store_id) => {
+            let active = match store_id {
+                Some(store_id) => StoreRepository::is_active(&state.db, store_id)
+                    .await
+                    .map(|active| active.then_some(store_id)),
+                None => Ok(None),
+            };
+            match active {
+                Ok(Some(store_id)) => Ok(store_id),
+                Ok(None) => {
+                    return AppError::validation(format!(
+                        "{} must name an active store",
+                        STORE_HEADER
+                    ))
+                    .into_response();
+                }
+                Err(err) => Err(err),
+            }
+        }
+        None => {
+            StoreRepository::resolve_default(&state.db, session_token.as_deref(), employee_id).await
+        }
+    };
+
+    match resolved {
+        Ok(store_id) => {
+            request.extensions_mut().insert(CurrentStore(store_id));
+        }
+        Err(err) => tracing::warn!("Failed to resolve the request's store: {:?}", err),
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::get, Router};
+    use tower::ServiceExt;
+
+    async fn echo(store: CurrentStore) -> String {
+        store.0.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_extractor_requires_resolved_store() {
+        let app = Router::new().route("/", get(echo));
+        let request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert!(response.status().is_server_error());
+    }
+
+    #[tokio::test]
+    async fn test_extractor_reads_extension() {
+        let store_id = Uuid::new_v4();
+        let app = Router::new().route("/", get(echo));
+        let mut request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        request.extensions_mut().insert(CurrentStore(store_id));
+        let response = app.oneshot(request).await.unwrap();
+        assert!(response.status().is_success());
+    }
+}
@@ -0,0 +1,147 @@
+//! Request timeout middleware.
+//!
+//! Every request gets a time budget so a stuck search or PDF can't hold a
+//! tablet's request open forever. Reports, exports, imports, and generated
+//! documents get a longer budget than ordinary requests. When the budget
+//! runs out the handler's future is dropped, which abandons any sqlx query it
+//! was awaiting, and the client gets TIMEOUT (504). The database pool's
+//! `statement_timeout` stops the query on the server side as well.
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{Request, Response},
+    middleware::Next,
+    response::IntoResponse,
+};
+use std::time::Duration;
+
+use crate::config::{DEFAULT_REQUEST_TIMEOUT_SECS, DEFAULT_SLOW_REQUEST_TIMEOUT_SECS};
+use crate::error::AppError;
+
+/// Path prefixes that get the slow budget.
+const SLOW_PATH_PREFIXES: &[&str] = &[
+    "/api/v1/reports/",
+    "/api/v1/admin/config/",
+    "/api/v1/admin/import/",
+    "/api/v1/admin/integrity",
+    "/api/v1/admin/storage/reconcile",
+];
+
+/// Path suffixes that get the slow budget (generated files, photo uploads).
+const SLOW_PATH_SUFFIXES: &[&str] = &[".pdf", ".csv", "/photos"];
+
+/// Time budgets for requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestTimeouts {
+    /// Budget for ordinary requests
+    pub default: Duration,
+    /// Budget for reports, exports, imports, and PDFs
+    pub slow: Duration,
+}
+
+impl Default for RequestTimeouts {
+    fn default() -> Self {
+        Self {
+            default: Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS),
+            slow: Duration::from_secs(DEFAULT_SLOW_REQUEST_TIMEOUT_SECS),
+        }
+    }
+}
+
+impl RequestTimeouts {
+    /// The budget for a request path.
+    pub fn for_path(&self, path: &str) -> Duration {
+        let slow = SLOW_PATH_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+            || SLOW_PATH_SUFFIXES
+                .iter()
+                .any(|suffix| path.ends_with(suffix));
+        if slow {
+            self.slow
+        } else {
+            self.default
+        }
+    }
+}
+
+/// Middleware that cancels requests running past their budget.
+pub async fn request_timeout(
+    State(timeouts): State<RequestTimeouts>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let path = request.uri().path().to_string();
+    let budget = timeouts.for_path(&path);
+
+    match tokio::time::timeout(budget, next.run(request)).await {
+        Ok(response) => response,
+        Err(_) => {
+            tracing::warn!(
+                path = %path,
+                budget_secs = budget.as_secs(),
+                "Request timed out and was cancelled"
+            );
+            AppError::timeout("The request took too long and was cancelled").into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::to_bytes, http::StatusCode, middleware, routing::get, Router};
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_slow_paths_get_the_slow_budget() {
+        let timeouts = RequestTimeouts::default();
+        for path in [
+            "/api/v1/reports/revenue",
+            "/api/v1/reports/custom/abc.csv",
+            "/api/v1/tickets/abc/receipt.pdf",
+            "/api/v1/tickets/abc/photos",
+            "/api/v1/admin/config/export",
+            "/api/v1/admin/import/tickets",
+        ] {
+            assert_eq!(timeouts.for_path(path), timeouts.slow, "{}", path);
+        }
+        for path in [
+            "/api/v1/tickets",
+            "/api/v1/customers",
+            "/api/v1/admin/verify",
+        ] {
+            assert_eq!(timeouts.for_path(path), timeouts.default, "{}", path);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_request_past_budget_returns_timeout() {
+        let timeouts = RequestTimeouts {
+            default: Duration::from_millis(20),
+            slow: Duration::from_secs(5),
+        };
+        let app = Router::new()
+            .route(
+                "/slow",
+                get(|| async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    "done"
+                }),
+            )
+            .route("/fast", get(|| async { "done" }))
+            .layer(middleware::from_fn_with_state(timeouts, request_timeout));
+
+        let request = Request::builder().uri("/slow").body(Body::empty()).unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"]["code"], "TIMEOUT");
+
+        let request = Request::builder().uri("/fast").body(Body::empty()).unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,105 @@
+//! Training mode middleware.
+//!
+//! A terminal can switch its employee session into training mode so new
+//! hires can practice intake and close. This layer looks up the session's
+//! flag once per request and exposes it to handlers as [`TrainingMode`].
+//! Responses in training mode carry the same extension, which
+//! `response_meta` uses to flag the `meta` block.
+
+use std::convert::Infallible;
+
+use axum::{
+    async_trait,
+    body::Body,
+    extract::{FromRequestParts, State},
+    http::{request::Parts, Request, Response},
+    middleware::Next,
+};
+
+use crate::repositories::EmployeeSessionRepository;
+use crate::routes::AppState;
+
+/// Whether the request's employee session is in training mode.
+///
+/// Extract it in a handler to keep training data apart from real data.
+/// Defaults to off when the middleware hasn't run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TrainingMode(pub bool);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for TrainingMode {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<TrainingMode>()
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
+/// Middleware that resolves training mode from `X-Employee-Session`.
+///
+/// A handler that changes the mode can set its own `TrainingMode` response
+/// extension, which takes precedence.
+pub async fn training_mode(
+    State(state): State<AppState>,
+    mut request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let token = request
+        .headers()
+        .get("X-Employee-Session")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    let enabled = match token {
+        Some(token) => EmployeeSessionRepository::is_training(&state.db, &token)
+            .await
+            .unwrap_or_else(|err| {
+                tracing::warn!("Failed to look up training mode: {:?}", err);
+                false
+            }),
+        None => false,
+    };
+    request.extensions_mut().insert(TrainingMode(enabled));
+
+    let mut response = next.run(request).await;
+    if enabled && response.extensions().get::<TrainingMode>().is_none() {
+        response.extensions_mut().insert(TrainingMode(true));
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::get, Router};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    async fn echo(training: TrainingMode) -> String {
+        training.0.to_string()
+    }
+
+    async fn call(request: Request<Body>) -> String {
+        let app = Router::new().route("/", get(echo));
+        let response = app.oneshot(request).await.unwrap();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_extractor_defaults_to_off() {
+        let request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        assert_eq!(call(request).await, "false");
+    }
+
+    #[tokio::test]
+    async fn test_extractor_reads_extension() {
+        let mut request = Request::builder().uri("/").body(Body::empty()).unwrap();
+        request.extensions_mut().insert(TrainingMode(true));
+        assert_eq!(call(request).await, "true");
+    }
+}
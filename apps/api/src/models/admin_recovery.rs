@@ -0,0 +1,104 @@
+//! Admin recovery model.
+//!
+//! Break-glass recovery for a lost admin PIN. A one-time recovery code is
+//! shown once when setup completes; only its hash is kept. Using the code
+//! resets the admin PIN and issues a replacement code. Every attempt is
+//! recorded, whether it succeeds or not.
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Characters used in recovery codes (Crockford base32: no I, L, O, or U).
+const RECOVERY_CODE_ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Groups of [`RECOVERY_CODE_GROUP_LEN`] characters in a code (100 bits).
+const RECOVERY_CODE_GROUPS: usize = 4;
+const RECOVERY_CODE_GROUP_LEN: usize = 5;
+
+/// A stored recovery code.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AdminRecoveryCode {
+    pub code_id: Uuid,
+    /// argon2 hash of the normalized code
+    pub code_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// A recorded recovery attempt.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AdminRecoveryAttempt {
+    pub attempt_id: Uuid,
+    pub attempted_at: DateTime<Utc>,
+    pub client_ip: String,
+    pub user_agent: Option<String>,
+    pub succeeded: bool,
+    /// Why a failed attempt was rejected
+    pub failure_reason: Option<String>,
+}
+
+/// Input for recording a recovery attempt.
+#[derive(Debug, Clone)]
+pub struct CreateAdminRecoveryAttempt {
+    pub client_ip: String,
+    pub user_agent: Option<String>,
+    pub succeeded: bool,
+    pub failure_reason: Option<String>,
+}
+
+/// Generate a new recovery code, e.g. `7K2QX-M9D4R-TB8NC-0WZ5H`.
+pub fn generate_recovery_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..RECOVERY_CODE_GROUPS)
+        .map(|_| {
+            (0..RECOVERY_CODE_GROUP_LEN)
+                .map(|_| {
+                    let index = rng.gen_range(0..RECOVERY_CODE_ALPHABET.len());
+                    RECOVERY_CODE_ALPHABET[index] as char
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Normalize a recovery code as typed: drop separators and whitespace, and
+/// upper-case it. The normalized form is what gets hashed.
+pub fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_recovery_code_format() {
+        let code = generate_recovery_code();
+        let groups: Vec<&str> = code.split('-').collect();
+        assert_eq!(groups.len(), RECOVERY_CODE_GROUPS);
+        for group in groups {
+            assert_eq!(group.len(), RECOVERY_CODE_GROUP_LEN);
+            assert!(group.bytes().all(|b| RECOVERY_CODE_ALPHABET.contains(&b)));
+        }
+        assert_ne!(code, generate_recovery_code());
+    }
+
+    #[test]
+    fn test_normalize_recovery_code() {
+        assert_eq!(
+            normalize_recovery_code(" 7k2qx-m9d4r tb8nc-0wz5h\n"),
+            "7K2QXM9D4RTB8NC0WZ5H"
+        );
+        assert_eq!(
+            normalize_recovery_code("7K2QX-M9D4R-TB8NC-0WZ5H"),
+            normalize_recovery_code("7k2qxm9d4rtb8nc0wz5h")
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Appraisal model.
+//!
+//! Stores that appraise items record the appraisal against the ticket: the
+//! appraised value, metal, stones, grading report or certificate numbers,
+//! and the employee who appraised it. A ticket has at most one appraisal,
+//! revised in place and printed as the appraisal PDF.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Maximum number of certification numbers on one appraisal.
+pub const MAX_CERTIFICATION_NUMBERS: usize = 20;
+
+/// A ticket's appraisal.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Appraisal {
+    pub appraisal_id: Uuid,
+    pub ticket_id: Uuid,
+    pub appraised_value: Decimal,
+    /// Metal and purity (e.g., "18k yellow gold")
+    pub metal: Option<String>,
+    /// Stones with their grading (e.g., "1.02ct round brilliant, G/VS1")
+    pub stones: Option<String>,
+    /// Grading report or certificate numbers (e.g., GIA report numbers)
+    pub certification_numbers: Vec<String>,
+    pub notes: Option<String>,
+    pub appraiser_id: Uuid,
+    pub appraised_on: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Appraisal with the appraiser's name, for display.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AppraisalEntry {
+    pub appraisal_id: Uuid,
+    pub ticket_id: Uuid,
+    pub appraised_value: Decimal,
+    pub metal: Option<String>,
+    pub stones: Option<String>,
+    pub certification_numbers: Vec<String>,
+    pub notes: Option<String>,
+    pub appraiser_id: Uuid,
+    pub appraiser_name: String,
+    pub appraised_on: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for recording or revising a ticket's appraisal.
+#[derive(Debug, Clone)]
+pub struct SaveAppraisal {
+    pub ticket_id: Uuid,
+    pub appraised_value: Decimal,
+    pub metal: Option<String>,
+    pub stones: Option<String>,
+    pub certification_numbers: Vec<String>,
+    pub notes: Option<String>,
+    pub appraiser_id: Uuid,
+    pub appraised_on: NaiveDate,
+}
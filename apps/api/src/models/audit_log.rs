@@ -0,0 +1,122 @@
+//! Audit log model.
+//!
+//! Every admin action (employee changes, PIN changes, photo deletes,
+//! settings updates, setup and recovery) is recorded with who took it, how
+//! they authenticated, their IP, and a summary of what changed. Secrets are
+//! never part of the summary.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// Recorded action, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "audit_action", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    AdminSetup,
+    AdminPinChanged,
+    AdminRecovered,
+    RecoveryCodeRegenerated,
+    EmployeeCreated,
+    EmployeeUpdated,
+    EmployeeDeleted,
+    EmployeePermissionsChanged,
+    PhotoDeleted,
+    SettingsUpdated,
+    SettingsRolledBack,
+    ConfigImported,
+    PartnerCreated,
+    PartnerUpdated,
+    PartnerKeyRotated,
+    CampaignCreated,
+    CampaignAborted,
+    WebhookCreated,
+    WebhookUpdated,
+    WebhookDeleted,
+    WebhookSecretRotated,
+    WebhookRedelivered,
+    EmployeeLockedOut,
+    EmployeeUnlocked,
+    CalendarFeedTokenRotated,
+    CalendarFeedTokenRevoked,
+}
+
+/// How the actor authenticated, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "audit_auth_method", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAuthMethod {
+    /// X-Admin-Session
+    AdminSession,
+    /// X-Admin-PIN, or the current PIN sent to setup
+    AdminPin,
+    /// An employee session holding the required permission
+    EmployeeSession,
+    /// The break-glass recovery code
+    RecoveryCode,
+    /// A PIN login attempt, not yet authenticated
+    EmployeePin,
+}
+
+/// A recorded admin action.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct AuditLogEntry {
+    pub audit_id: Uuid,
+    pub action: AuditAction,
+    pub auth_method: AuditAuthMethod,
+    /// Employee signed in when the action was taken (kept after deletion)
+    pub actor_employee_id: Option<Uuid>,
+    /// Employee name at the time of the action
+    pub actor_name: Option<String>,
+    pub client_ip: String,
+    /// Kind of record acted on, e.g. "employee" or "photo"
+    pub target_type: Option<String>,
+    pub target_id: Option<Uuid>,
+    /// What changed
+    pub summary: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Input for recording an admin action.
+#[derive(Debug, Clone)]
+pub struct CreateAuditLogEntry {
+    pub action: AuditAction,
+    pub auth_method: AuditAuthMethod,
+    pub actor_employee_id: Option<Uuid>,
+    pub actor_name: Option<String>,
+    pub client_ip: String,
+    pub target_type: Option<String>,
+    pub target_id: Option<Uuid>,
+    pub summary: serde_json::Value,
+}
+
+/// Filters for listing the audit log. Every filter is optional.
+#[derive(Debug, Clone, Default)]
+pub struct AuditLogFilter {
+    pub action: Option<AuditAction>,
+    pub actor_employee_id: Option<Uuid>,
+    pub target_id: Option<Uuid>,
+    /// Entries at or after this time
+    pub from: Option<DateTime<Utc>>,
+    /// Entries before this time
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_audit_action_serializes_snake_case() {
+        assert_eq!(
+            serde_json::to_value(AuditAction::EmployeePermissionsChanged).unwrap(),
+            "employee_permissions_changed"
+        );
+        assert_eq!(
+            serde_json::from_value::<AuditAction>("admin_pin_changed".into()).unwrap(),
+            AuditAction::AdminPinChanged
+        );
+    }
+}
@@ -0,0 +1,176 @@
+//! Business hours model.
+//!
+//! A store's weekly opening hours and the holidays it's closed. Turnaround
+//! is counted in days the store is open, so promise dates skip closed days
+//! and never land on one. A store that hasn't set its hours counts every
+//! day as open.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+/// Opening hours for one day of the week. Days without an entry are closed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpeningHours {
+    /// ISO 8601 day of the week (1 = Monday, 7 = Sunday)
+    pub weekday: u32,
+    pub opens_at: NaiveTime,
+    pub closes_at: NaiveTime,
+}
+
+/// Which days a store is open, and how quickly it turns rush jobs around.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BusinessCalendar {
+    /// Open days of the week, indexed from Monday
+    open_weekdays: [bool; 7],
+    holidays: Vec<NaiveDate>,
+    rush_turnaround_days: Option<i32>,
+}
+
+/// A promise date counted in open days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PromiseDate {
+    pub date: NaiveDate,
+    /// Closed days passed over on the way to it
+    pub closed_days_skipped: i64,
+}
+
+impl BusinessCalendar {
+    /// Calendar for a store's hours, holidays, and rush turnaround.
+    ///
+    /// With no hours set (or hours that would close every weekday), every
+    /// day but the holidays is open.
+    pub fn new(
+        hours: &[OpeningHours],
+        holidays: &[NaiveDate],
+        rush_turnaround_days: Option<i32>,
+    ) -> Self {
+        let mut open_weekdays = [hours.is_empty(); 7];
+        for day in hours {
+            if let Some(open) = day
+                .weekday
+                .checked_sub(1)
+                .and_then(|i| open_weekdays.get_mut(i as usize))
+            {
+                *open = true;
+            }
+        }
+        if !open_weekdays.contains(&true) {
+            open_weekdays = [true; 7];
+        }
+        Self {
+            open_weekdays,
+            holidays: holidays.to_vec(),
+            rush_turnaround_days,
+        }
+    }
+
+    /// Whether the store is open on `date`.
+    pub fn is_open(&self, date: NaiveDate) -> bool {
+        self.open_weekdays[date.weekday().num_days_from_monday() as usize]
+            && !self.holidays.contains(&date)
+    }
+
+    /// Turnaround for a job: the usual turnaround, or the rush turnaround
+    /// for rush jobs when that's sooner.
+    pub fn turnaround_days(&self, turnaround_days: Option<i32>, is_rush: bool) -> Option<i32> {
+        match (
+            turnaround_days,
+            self.rush_turnaround_days.filter(|_| is_rush),
+        ) {
+            (Some(days), Some(rush_days)) => Some(days.min(rush_days)),
+            (days, rush_days) => days.or(rush_days),
+        }
+    }
+
+    /// The date `turnaround_days` open days after `today`, or the first
+    /// open day from `today` for a same-day turnaround.
+    pub fn promise_date(&self, today: NaiveDate, turnaround_days: i32) -> PromiseDate {
+        let mut date = today;
+        let mut closed_days_skipped = 0;
+        let mut remaining = turnaround_days.max(0);
+        while remaining > 0 {
+            date += Duration::days(1);
+            if self.is_open(date) {
+                remaining -= 1;
+            } else {
+                closed_days_skipped += 1;
+            }
+        }
+        while !self.is_open(date) {
+            date += Duration::days(1);
+            closed_days_skipped += 1;
+        }
+        PromiseDate {
+            date,
+            closed_days_skipped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        // October 2026: the 5th is a Monday
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    fn weekdays_only() -> Vec<OpeningHours> {
+        (1..=6)
+            .map(|weekday| OpeningHours {
+                weekday,
+                opens_at: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                closes_at: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_no_hours_means_always_open() {
+        let calendar = BusinessCalendar::new(&[], &[], None);
+        assert!(calendar.is_open(date(11)));
+        let promise = calendar.promise_date(date(5), 7);
+        assert_eq!(promise.date, date(12));
+        assert_eq!(promise.closed_days_skipped, 0);
+    }
+
+    #[test]
+    fn test_promise_date_skips_sundays_and_holidays() {
+        let calendar = BusinessCalendar::new(&weekdays_only(), &[date(12)], None);
+        assert!(!calendar.is_open(date(11)));
+        assert!(!calendar.is_open(date(12)));
+
+        // Fri the 9th + 2 open days: Sat 10th, then Tue 13th
+        let promise = calendar.promise_date(date(9), 2);
+        assert_eq!(promise.date, date(13));
+        assert_eq!(promise.closed_days_skipped, 2);
+    }
+
+    #[test]
+    fn test_same_day_turnaround_moves_off_closed_day() {
+        let calendar = BusinessCalendar::new(&weekdays_only(), &[], None);
+        assert_eq!(calendar.promise_date(date(6), 0).date, date(6));
+        let promise = calendar.promise_date(date(11), 0);
+        assert_eq!(promise.date, date(12));
+        assert_eq!(promise.closed_days_skipped, 1);
+    }
+
+    #[test]
+    fn test_rush_turnaround() {
+        let calendar = BusinessCalendar::new(&[], &[], Some(2));
+        assert_eq!(calendar.turnaround_days(Some(7), true), Some(2));
+        assert_eq!(calendar.turnaround_days(Some(1), true), Some(1));
+        assert_eq!(calendar.turnaround_days(None, true), Some(2));
+        assert_eq!(calendar.turnaround_days(Some(7), false), Some(7));
+        assert_eq!(calendar.turnaround_days(None, false), None);
+    }
+
+    #[test]
+    fn test_opening_hours_parse_without_seconds() {
+        let hours: OpeningHours =
+            serde_json::from_str(r#"{"weekday": 1, "opens_at": "09:30", "closes_at": "17:00"}"#)
+                .unwrap();
+        assert_eq!(hours.opens_at, NaiveTime::from_hms_opt(9, 30, 0).unwrap());
+    }
+}
@@ -0,0 +1,24 @@
+//! Promise date calendar feed model.
+//!
+//! A store can publish its open tickets' promise dates as an iCalendar feed
+//! that calendar apps subscribe to by URL. The token in the URL names the
+//! store; only its hash is kept, and revoking the feed clears it.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::ticket::TicketStatus;
+
+/// An open ticket's promise date, as one all-day event in the feed.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PromiseDateEntry {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    pub customer_name: String,
+    pub item_description: String,
+    pub status: TicketStatus,
+    pub is_rush: bool,
+    pub promise_date: NaiveDate,
+    pub updated_at: DateTime<Utc>,
+}
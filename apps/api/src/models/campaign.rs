@@ -0,0 +1,192 @@
+//! Customer campaign model.
+//!
+//! A campaign sends one templated message to a segment of customers, such
+//! as everyone with a ticket closed in the last year. Recipients are
+//! captured when the campaign is created and sent by a background worker
+//! at the campaign's send rate, each with its own delivery status.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+use crate::models::notification::NotificationChannel;
+
+/// Default campaign send rate (messages per minute).
+pub const DEFAULT_CAMPAIGN_SEND_RATE: i32 = 30;
+
+/// Placeholders available in campaign messages. Ticket placeholders are not,
+/// since a campaign isn't about one ticket.
+pub const CAMPAIGN_PLACEHOLDERS: &[&str] = &["customer_name", "store_name", "store_phone"];
+
+/// Campaign state, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "campaign_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CampaignStatus {
+    /// Created, waiting for the worker
+    Queued,
+    /// Worker is sending
+    Running,
+    /// Every recipient was attempted
+    Completed,
+    /// Stopped by an admin; unsent recipients cancelled
+    Aborted,
+}
+
+impl CampaignStatus {
+    /// Returns true if the campaign may still send messages.
+    pub fn is_active(&self) -> bool {
+        matches!(self, CampaignStatus::Queued | CampaignStatus::Running)
+    }
+}
+
+/// Delivery status of one recipient, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "campaign_recipient_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CampaignRecipientStatus {
+    /// Waiting to be sent
+    Pending,
+    /// Claimed by the worker
+    Sending,
+    /// Accepted by the provider
+    Sent,
+    /// The provider rejected the message or could not be reached
+    Failed,
+    /// Not sent (opted out since, no usable contact, no provider)
+    Skipped,
+    /// Campaign aborted before it was sent
+    Cancelled,
+}
+
+/// Which customers a campaign goes to.
+///
+/// Every filter is optional; customers must match all that are set.
+/// Deleted, training, and opted-out customers, and customers without a
+/// phone (SMS) or email (email), are always left out.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CampaignSegment {
+    /// Customers with a ticket closed on or after this date
+    pub closed_from: Option<NaiveDate>,
+    /// Customers with a ticket closed on or before this date
+    pub closed_to: Option<NaiveDate>,
+    /// Customers with a ticket of this item type
+    pub item_type: Option<String>,
+    /// Customers with (true) or without (false) an open ticket
+    pub has_open_tickets: Option<bool>,
+}
+
+/// A campaign.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Campaign {
+    pub campaign_id: Uuid,
+    pub name: String,
+    pub channel: NotificationChannel,
+    /// Email subject template (None for SMS)
+    pub subject: Option<String>,
+    /// Message template
+    pub body: String,
+    #[sqlx(flatten)]
+    pub segment: CampaignSegment,
+    pub send_rate_per_minute: i32,
+    pub status: CampaignStatus,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// When the worker sent the first message
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the campaign completed or was aborted
+    pub finished_at: Option<DateTime<Utc>>,
+    pub aborted_by: Option<Uuid>,
+}
+
+/// Recipient counts by delivery status.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CampaignProgress {
+    pub total: i64,
+    pub pending: i64,
+    pub sending: i64,
+    pub sent: i64,
+    pub failed: i64,
+    pub skipped: i64,
+    pub cancelled: i64,
+}
+
+/// A campaign with its delivery progress.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CampaignSummary {
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub campaign: Campaign,
+    #[sqlx(flatten)]
+    pub progress: CampaignProgress,
+}
+
+/// Input for creating a campaign.
+#[derive(Debug, Clone)]
+pub struct CreateCampaign {
+    pub name: String,
+    pub channel: NotificationChannel,
+    pub subject: Option<String>,
+    pub body: String,
+    pub segment: CampaignSegment,
+    pub send_rate_per_minute: i32,
+    pub created_by: Uuid,
+}
+
+/// One customer a campaign is sent to.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CampaignRecipient {
+    pub recipient_id: Uuid,
+    pub campaign_id: Uuid,
+    pub customer_id: Uuid,
+    pub customer_name: String,
+    /// Phone or email the message was addressed to
+    pub recipient: String,
+    pub status: CampaignRecipientStatus,
+    pub provider_message_id: Option<String>,
+    /// Why the message failed or was skipped
+    pub error: Option<String>,
+    pub attempted_at: Option<DateTime<Utc>>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_campaign_status_is_active() {
+        assert!(CampaignStatus::Queued.is_active());
+        assert!(CampaignStatus::Running.is_active());
+        assert!(!CampaignStatus::Completed.is_active());
+        assert!(!CampaignStatus::Aborted.is_active());
+    }
+
+    #[test]
+    fn test_campaign_summary_flattens_campaign() {
+        let summary = CampaignSummary {
+            campaign: Campaign {
+                campaign_id: Uuid::new_v4(),
+                name: "Holiday cleaning".to_string(),
+                channel: NotificationChannel::Sms,
+                subject: None,
+                body: "Hi {customer_name}".to_string(),
+                segment: CampaignSegment::default(),
+                send_rate_per_minute: DEFAULT_CAMPAIGN_SEND_RATE,
+                status: CampaignStatus::Queued,
+                created_by: Uuid::new_v4(),
+                created_at: Utc::now(),
+                started_at: None,
+                finished_at: None,
+                aborted_by: None,
+            },
+            progress: CampaignProgress::default(),
+        };
+
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["name"], "Holiday cleaning");
+        assert_eq!(json["segment"]["closed_from"], serde_json::Value::Null);
+        assert_eq!(json["progress"]["pending"], 0);
+    }
+}
@@ -0,0 +1,85 @@
+//! Customer contact log model.
+//!
+//! Staff log each call, text, email, or counter conversation with a customer
+//! about a ticket, so anyone picking up the ticket can see who was told what.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// How the customer was contacted, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "contact_channel", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ContactChannel {
+    Phone,
+    Sms,
+    Email,
+    InPerson,
+}
+
+/// Who started the contact, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "contact_direction", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ContactDirection {
+    /// Staff reached out to the customer
+    Outbound,
+    /// The customer reached out to the store
+    Inbound,
+}
+
+/// How the contact turned out, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "contact_outcome", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ContactOutcome {
+    /// Spoke with or heard back from the customer
+    Reached,
+    /// Left a voicemail or message
+    LeftMessage,
+    NoAnswer,
+    WrongNumber,
+}
+
+/// A logged contact with the employee's name, for listings.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketContact {
+    pub contact_id: Uuid,
+    pub ticket_id: Uuid,
+    pub channel: ContactChannel,
+    pub direction: ContactDirection,
+    pub outcome: ContactOutcome,
+    pub notes: Option<String>,
+    pub contacted_by: Uuid,
+    pub contacted_by_name: String,
+    pub contacted_at: DateTime<Utc>,
+}
+
+/// Input for logging a contact.
+#[derive(Debug, Clone)]
+pub struct CreateTicketContact {
+    pub ticket_id: Uuid,
+    pub channel: ContactChannel,
+    pub direction: ContactDirection,
+    pub outcome: ContactOutcome,
+    pub notes: Option<String>,
+    pub contacted_by: Uuid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contact_enum_serialization() {
+        assert_eq!(
+            serde_json::to_string(&ContactChannel::InPerson).unwrap(),
+            "\"in_person\""
+        );
+        let parsed: ContactOutcome = serde_json::from_str("\"left_message\"").unwrap();
+        assert_eq!(parsed, ContactOutcome::LeftMessage);
+        assert!(serde_json::from_str::<ContactDirection>("\"sideways\"").is_err());
+    }
+}
@@ -0,0 +1,135 @@
+//! Chain-of-custody model.
+//!
+//! High-value tickets record a numbered custody event every time the item
+//! changes hands or location, with the employee who handled it and a witness.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// Kind of custody event, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "custody_event_type", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum CustodyEventType {
+    /// Item received from the customer
+    Intake,
+    /// Item moved between storage locations
+    LocationChange,
+    /// Item handed between employees
+    Handoff,
+    /// Item returned to the customer
+    Release,
+    /// Item sent to another location, in transit until accepted
+    TransferOut,
+    /// Item received at a location from a transfer
+    TransferIn,
+}
+
+impl CustodyEventType {
+    /// Human-readable label for reports.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Intake => "Intake",
+            Self::LocationChange => "Location change",
+            Self::Handoff => "Handoff",
+            Self::Release => "Release",
+            Self::TransferOut => "Transfer out",
+            Self::TransferIn => "Transfer in",
+        }
+    }
+}
+
+/// A custody event recorded against a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CustodyEvent {
+    pub event_id: Uuid,
+    pub ticket_id: Uuid,
+    /// Position in the ticket's chain, starting at 1
+    pub sequence: i32,
+    pub event_type: CustodyEventType,
+    pub from_location_id: Option<Uuid>,
+    pub to_location_id: Option<Uuid>,
+    pub handled_by: Uuid,
+    pub witnessed_by: Option<Uuid>,
+    pub notes: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Custody event with employee and location names, for display and reports.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CustodyEventEntry {
+    pub event_id: Uuid,
+    pub sequence: i32,
+    pub event_type: CustodyEventType,
+    pub from_location_name: Option<String>,
+    pub to_location_name: Option<String>,
+    pub handled_by: Uuid,
+    pub handled_by_name: String,
+    pub witnessed_by: Option<Uuid>,
+    pub witnessed_by_name: Option<String>,
+    pub notes: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// Input for recording a custody event. The sequence is assigned on insert.
+#[derive(Debug, Clone)]
+pub struct CreateCustodyEvent {
+    pub ticket_id: Uuid,
+    pub event_type: CustodyEventType,
+    pub from_location_id: Option<Uuid>,
+    pub to_location_id: Option<Uuid>,
+    pub handled_by: Uuid,
+    pub witnessed_by: Option<Uuid>,
+    pub notes: Option<String>,
+}
+
+/// Witness details sent with a movement of a high-value item.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CustodyWitness {
+    /// Employee who witnessed the movement (must differ from the handler)
+    pub witnessed_by: Uuid,
+    pub notes: Option<String>,
+}
+
+/// Returns true if an item quoted at `quote_amount` needs witnessed custody
+/// events under the store's threshold.
+pub fn custody_required(quote_amount: Option<Decimal>, threshold: Option<Decimal>) -> bool {
+    matches!(
+        (quote_amount, threshold),
+        (Some(quote), Some(threshold)) if quote >= threshold
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custody_required() {
+        let threshold = Some(Decimal::new(1000, 0));
+        assert!(custody_required(Some(Decimal::new(1000, 0)), threshold));
+        assert!(!custody_required(Some(Decimal::new(999, 0)), threshold));
+        assert!(!custody_required(None, threshold));
+        assert!(!custody_required(Some(Decimal::new(1000, 0)), None));
+    }
+
+    #[test]
+    fn test_custody_event_type_serialization() {
+        let json = serde_json::to_string(&CustodyEventType::LocationChange).unwrap();
+        assert_eq!(json, "\"location_change\"");
+
+        let parsed: CustodyEventType = serde_json::from_str("\"handoff\"").unwrap();
+        assert_eq!(parsed, CustodyEventType::Handoff);
+    }
+
+    #[test]
+    fn test_custody_witness_deserialize() {
+        let json = r#"{"witnessed_by": "00000000-0000-0000-0000-000000000000"}"#;
+        let witness: CustodyWitness = serde_json::from_str(json).unwrap();
+        assert_eq!(witness.witnessed_by, Uuid::nil());
+        assert!(witness.notes.is_none());
+    }
+}
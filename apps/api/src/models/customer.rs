@@ -13,6 +13,8 @@ pub struct Customer {
     pub name: String,
     pub phone: Option<String>,
     pub email: Option<String>,
+    /// Customer asked not to receive texts or emails
+    pub notifications_opt_out: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -23,6 +25,36 @@ pub struct CreateCustomer {
     pub name: String,
     pub phone: Option<String>,
     pub email: Option<String>,
+    /// Created from a training-mode session
+    #[serde(default)]
+    pub is_training: bool,
+}
+
+/// Input for updating a customer.
+///
+/// `None` leaves a field unchanged. For phone and email, `Some(None)` clears it.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateCustomer {
+    pub name: Option<String>,
+    pub phone: Option<Option<String>>,
+    pub email: Option<Option<String>>,
+    pub notifications_opt_out: Option<bool>,
+}
+
+/// Audit record of a duplicate customer merged into a surviving record.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CustomerMerge {
+    pub merge_id: Uuid,
+    /// The surviving customer
+    pub target_customer_id: Uuid,
+    /// The duplicate that was folded in and soft-deleted
+    pub source_customer_id: Uuid,
+    pub source_name: String,
+    pub source_phone: Option<String>,
+    pub source_email: Option<String>,
+    pub tickets_moved: i32,
+    pub merged_by: Uuid,
+    pub merged_at: DateTime<Utc>,
 }
 
 /// Search parameters for customer lookup.
@@ -34,6 +66,8 @@ pub struct CustomerSearchParams {
     pub limit: Option<i64>,
     /// Offset for pagination
     pub offset: Option<i64>,
+    /// Include customers created in training mode
+    pub include_training: bool,
 }
 
 /// Customer with their associated tickets.
@@ -72,12 +106,21 @@ mod tests {
         assert!(customer.email.is_none());
     }
 
+    #[test]
+    fn test_update_customer_default_changes_nothing() {
+        let update = UpdateCustomer::default();
+        assert!(update.name.is_none());
+        assert!(update.phone.is_none());
+        assert!(update.email.is_none());
+    }
+
     #[test]
     fn test_customer_search_params_default() {
         let params = CustomerSearchParams::default();
         assert!(params.query.is_empty());
         assert!(params.limit.is_none());
         assert!(params.offset.is_none());
+        assert!(!params.include_training);
     }
 
     #[test]
@@ -86,6 +129,7 @@ mod tests {
             query: "john".to_string(),
             limit: Some(10),
             offset: Some(20),
+            include_training: false,
         };
         assert_eq!(params.query, "john");
         assert_eq!(params.limit, Some(10));
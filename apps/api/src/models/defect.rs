@@ -0,0 +1,118 @@
+//! Ticket defect model.
+//!
+//! Defects are recorded when QC fails or a customer returns an item.
+//! They drive rework reporting by employee, item type, and reason.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// Defect reason code matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "defect_reason", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DefectReason {
+    LooseStone,
+    Finish,
+    Sizing,
+    SolderJoint,
+    Clasp,
+    WrongWork,
+    Damage,
+    Other,
+}
+
+impl DefectReason {
+    /// Reason code as used in the API (e.g., `loose_stone`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DefectReason::LooseStone => "loose_stone",
+            DefectReason::Finish => "finish",
+            DefectReason::Sizing => "sizing",
+            DefectReason::SolderJoint => "solder_joint",
+            DefectReason::Clasp => "clasp",
+            DefectReason::WrongWork => "wrong_work",
+            DefectReason::Damage => "damage",
+            DefectReason::Other => "other",
+        }
+    }
+}
+
+/// Where a defect was found, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "defect_source", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DefectSource {
+    QcFailure,
+    CustomerReturn,
+}
+
+/// A defect recorded against a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketDefect {
+    pub defect_id: Uuid,
+    pub ticket_id: Uuid,
+    pub reason: DefectReason,
+    pub source: DefectSource,
+    pub qc_check_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub recorded_by: Uuid,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Input for recording a defect.
+#[derive(Debug, Clone)]
+pub struct CreateTicketDefect {
+    pub ticket_id: Uuid,
+    pub reason: DefectReason,
+    pub source: DefectSource,
+    pub qc_check_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub recorded_by: Uuid,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defect_reason_serialization() {
+        let json = serde_json::to_string(&DefectReason::LooseStone).unwrap();
+        assert_eq!(json, "\"loose_stone\"");
+
+        let parsed: DefectReason = serde_json::from_str("\"solder_joint\"").unwrap();
+        assert_eq!(parsed, DefectReason::SolderJoint);
+    }
+
+    #[test]
+    fn test_defect_reason_as_str_matches_serde() {
+        for reason in [
+            DefectReason::LooseStone,
+            DefectReason::Finish,
+            DefectReason::Sizing,
+            DefectReason::SolderJoint,
+            DefectReason::Clasp,
+            DefectReason::WrongWork,
+            DefectReason::Damage,
+            DefectReason::Other,
+        ] {
+            assert_eq!(
+                serde_json::to_string(&reason).unwrap(),
+                format!("\"{}\"", reason.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn test_defect_source_serialization() {
+        let json = serde_json::to_string(&DefectSource::CustomerReturn).unwrap();
+        assert_eq!(json, "\"customer_return\"");
+    }
+
+    #[test]
+    fn test_defect_reason_rejects_unknown() {
+        let result: Result<DefectReason, _> = serde_json::from_str("\"bad_vibes\"");
+        assert!(result.is_err());
+    }
+}
@@ -10,31 +10,66 @@ use uuid::Uuid;
 /// Permission types for role-based access control.
 ///
 /// These define the specific actions that can be performed in the system.
-/// Admin has all permissions; Staff has a restricted subset.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Admins have every permission; each staff employee holds their own set,
+/// starting from the staff defaults.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Type,
+)]
+#[sqlx(type_name = "employee_permission", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
 pub enum Permission {
     /// Create new tickets
     CreateTicket,
-    /// View any ticket
+    /// View any ticket (every employee; not assignable)
     ViewTicket,
     /// Modify tickets the employee owns (taken_in_by or worked_by)
     ModifyOwnTicket,
-    /// Modify any ticket regardless of ownership
+    /// Modify and see any ticket regardless of ownership
     ModifyAnyTicket,
     /// Add notes to tickets the employee is authorized for
     AddNotes,
     /// Upload photos to tickets the employee is authorized for
     UploadPhotos,
-    /// Delete photos (admin only)
+    /// Delete photos
     DeletePhotos,
-    /// Close any ticket (admin only)
+    /// Close any ticket
     CloseAnyTicket,
-    /// Manage employees (admin only)
+    /// Set quote and actual amounts on tickets
+    EditPrices,
+    /// Manage employees and their permissions
     ManageEmployees,
-    /// Manage store settings (admin only)
+    /// Manage store settings
     ManageSettings,
-    /// Manage storage locations (admin only)
+    /// Manage storage locations
     ManageLocations,
+    /// Create and edit customer records
+    ManageCustomers,
+    /// Delete and merge customer records
+    DeleteCustomers,
+}
+
+impl Permission {
+    /// Permissions that can be granted to or taken from an employee.
+    pub const ASSIGNABLE: &'static [Permission] = &[
+        Permission::CreateTicket,
+        Permission::ModifyOwnTicket,
+        Permission::ModifyAnyTicket,
+        Permission::AddNotes,
+        Permission::UploadPhotos,
+        Permission::DeletePhotos,
+        Permission::CloseAnyTicket,
+        Permission::EditPrices,
+        Permission::ManageEmployees,
+        Permission::ManageSettings,
+        Permission::ManageLocations,
+        Permission::ManageCustomers,
+        Permission::DeleteCustomers,
+    ];
+
+    /// Returns true if the permission can be granted or taken away.
+    pub fn is_assignable(&self) -> bool {
+        Self::ASSIGNABLE.contains(self)
+    }
 }
 
 /// Employee role enum matching the database type.
@@ -47,10 +82,11 @@ pub enum EmployeeRole {
 }
 
 impl EmployeeRole {
-    /// Check if this role has the specified permission.
+    /// Check if this role grants the specified permission by default.
     ///
-    /// Admin has all permissions. Staff has a limited set of permissions
-    /// focused on day-to-day operations without destructive capabilities.
+    /// Admin has all permissions. Staff starts with a limited set of
+    /// permissions focused on day-to-day operations without destructive
+    /// capabilities; admins can change it per employee.
     pub fn has_permission(&self, permission: Permission) -> bool {
         match self {
             // Admin has all permissions
@@ -63,31 +99,105 @@ impl EmployeeRole {
                     | Permission::ModifyOwnTicket
                     | Permission::AddNotes
                     | Permission::UploadPhotos
+                    | Permission::EditPrices
+                    | Permission::ManageCustomers
             ),
         }
     }
+
+    /// Assignable permissions an employee with this role starts with.
+    pub fn default_permissions(&self) -> Vec<Permission> {
+        Permission::ASSIGNABLE
+            .iter()
+            .copied()
+            .filter(|permission| self.has_permission(*permission))
+            .collect()
+    }
 }
 
 /// Full employee entity with all fields.
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct Employee {
     pub employee_id: Uuid,
+    /// Home store; employees are listed there and can sign in anywhere
+    pub store_id: Uuid,
     pub name: String,
     #[serde(skip_serializing)]
     pub pin_hash: String,
+    /// Key for challenge-response PIN entry (None until the PIN is next set)
+    #[serde(skip_serializing)]
+    pub pin_challenge_key: Option<String>,
+    /// Salt the challenge key was derived with, sent with each challenge
+    #[serde(skip_serializing)]
+    pub pin_challenge_salt: Option<String>,
     pub role: EmployeeRole,
+    /// Mobile number for reminder texts
+    pub phone: Option<String>,
+    /// Permissions held as staff (admins hold every permission)
+    pub permissions: Vec<Permission>,
     pub is_active: bool,
+    /// When too many wrong PINs locked the employee out (None = not locked)
+    pub pin_locked_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+impl Employee {
+    /// Check if this employee holds the specified permission.
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.role == EmployeeRole::Admin
+            || permission == Permission::ViewTicket
+            || self.permissions.contains(&permission)
+    }
+
+    /// Every assignable permission this employee holds, in a stable order.
+    pub fn effective_permissions(&self) -> Vec<Permission> {
+        Permission::ASSIGNABLE
+            .iter()
+            .copied()
+            .filter(|permission| self.has_permission(*permission))
+            .collect()
+    }
+}
+
 /// Summary view of an employee (without PIN hash).
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct EmployeeSummary {
     pub employee_id: Uuid,
+    pub store_id: Uuid,
     pub name: String,
     pub role: EmployeeRole,
+    pub phone: Option<String>,
+    pub permissions: Vec<Permission>,
     pub is_active: bool,
+    /// When too many wrong PINs locked the employee out (None = not locked)
+    pub pin_locked_at: Option<DateTime<Utc>>,
+}
+
+/// An active employee's ID and name, for pickers such as "worked by".
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EmployeeName {
+    pub employee_id: Uuid,
+    pub name: String,
+}
+
+/// Open tickets an employee took in or is working on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, sqlx::FromRow)]
+pub struct OpenTicketCounts {
+    /// Open tickets the employee took in
+    pub taken_in: i64,
+    /// Open tickets the employee is working on
+    pub working_on: i64,
+}
+
+/// The logged-in employee's own profile.
+#[derive(Debug, Clone, Serialize)]
+pub struct EmployeeProfile {
+    pub employee_id: Uuid,
+    pub name: String,
+    pub role: EmployeeRole,
+    pub permissions: Vec<Permission>,
+    pub open_tickets: OpenTicketCounts,
 }
 
 /// Input for creating a new employee.
@@ -97,6 +207,8 @@ pub struct CreateEmployee {
     pub pin: String,
     #[serde(default)]
     pub role: Option<EmployeeRole>,
+    #[serde(default)]
+    pub phone: Option<String>,
 }
 
 /// Input for updating an employee.
@@ -106,6 +218,15 @@ pub struct UpdateEmployee {
     pub pin: Option<String>,
     pub role: Option<EmployeeRole>,
     pub is_active: Option<bool>,
+    /// Move the employee to another store
+    #[serde(default)]
+    pub store_id: Option<Uuid>,
+    /// Mobile number (null to clear)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub phone: Option<Option<String>>,
 }
 
 #[cfg(test)]
@@ -178,6 +299,8 @@ mod tests {
         assert!(admin.has_permission(Permission::ManageEmployees));
         assert!(admin.has_permission(Permission::ManageSettings));
         assert!(admin.has_permission(Permission::ManageLocations));
+        assert!(admin.has_permission(Permission::ManageCustomers));
+        assert!(admin.has_permission(Permission::DeleteCustomers));
     }
 
     #[test]
@@ -190,6 +313,7 @@ mod tests {
         assert!(staff.has_permission(Permission::ModifyOwnTicket));
         assert!(staff.has_permission(Permission::AddNotes));
         assert!(staff.has_permission(Permission::UploadPhotos));
+        assert!(staff.has_permission(Permission::ManageCustomers));
     }
 
     #[test]
@@ -203,5 +327,63 @@ mod tests {
         assert!(!staff.has_permission(Permission::ManageEmployees));
         assert!(!staff.has_permission(Permission::ManageSettings));
         assert!(!staff.has_permission(Permission::ManageLocations));
+        assert!(!staff.has_permission(Permission::DeleteCustomers));
+    }
+
+    fn employee(role: EmployeeRole, permissions: Vec<Permission>) -> Employee {
+        Employee {
+            employee_id: Uuid::new_v4(),
+            store_id: Uuid::nil(),
+            name: "Test Employee".to_string(),
+            pin_hash: "hash".to_string(),
+            pin_challenge_key: None,
+            pin_challenge_salt: None,
+            phone: None,
+            role,
+            permissions,
+            is_active: true,
+            pin_locked_at: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_employee_permissions_are_per_employee() {
+        let jeweler = employee(
+            EmployeeRole::Staff,
+            vec![Permission::CreateTicket, Permission::CloseAnyTicket],
+        );
+        assert!(jeweler.has_permission(Permission::CloseAnyTicket));
+        assert!(!jeweler.has_permission(Permission::AddNotes));
+        // Viewing tickets can't be taken away
+        assert!(jeweler.has_permission(Permission::ViewTicket));
+        assert_eq!(
+            jeweler.effective_permissions(),
+            vec![Permission::CreateTicket, Permission::CloseAnyTicket]
+        );
+
+        let admin = employee(EmployeeRole::Admin, vec![]);
+        assert!(admin.has_permission(Permission::ManageEmployees));
+        assert_eq!(admin.effective_permissions(), Permission::ASSIGNABLE);
+    }
+
+    #[test]
+    fn test_default_permissions() {
+        let staff = EmployeeRole::Staff.default_permissions();
+        assert!(staff.contains(&Permission::EditPrices));
+        assert!(!staff.contains(&Permission::CloseAnyTicket));
+        assert!(!staff.contains(&Permission::ViewTicket));
+        assert_eq!(
+            EmployeeRole::Admin.default_permissions(),
+            Permission::ASSIGNABLE
+        );
+        assert!(!Permission::ViewTicket.is_assignable());
+    }
+
+    #[test]
+    fn test_permission_serialization() {
+        let json = serde_json::to_string(&Permission::CloseAnyTicket).unwrap();
+        assert_eq!(json, "\"close_any_ticket\"");
     }
 }
@@ -22,6 +22,8 @@ pub struct EmployeeSession {
     pub expires_at: DateTime<Utc>,
     /// Last activity timestamp (for sliding expiration)
     pub last_activity_at: DateTime<Utc>,
+    /// Whether the terminal is practicing against training data
+    pub training_mode: bool,
 }
 
 impl EmployeeSession {
@@ -65,6 +67,7 @@ mod tests {
             created_at: Utc::now(),
             expires_at: Utc::now() + chrono::Duration::minutes(30),
             last_activity_at: Utc::now(),
+            training_mode: false,
         };
         assert!(!session.is_expired());
     }
@@ -78,6 +81,7 @@ mod tests {
             created_at: Utc::now() - chrono::Duration::hours(9),
             expires_at: Utc::now() - chrono::Duration::hours(1),
             last_activity_at: Utc::now() - chrono::Duration::hours(9),
+            training_mode: false,
         };
         assert!(session.is_expired());
     }
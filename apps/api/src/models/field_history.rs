@@ -1,11 +1,16 @@
 //! Ticket field history model.
 //!
-//! Records field changes for audit trail.
+//! Records field changes for audit trail, and defines the merged ticket
+//! history feed built from field, status, note, and photo records.
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sqlx::Type;
 use uuid::Uuid;
 
+/// Field name recorded in field history when a photo is removed.
+pub const PHOTO_FIELD: &str = "photo";
+
 /// A field change history entry.
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct FieldHistoryEntry {
@@ -27,3 +32,50 @@ pub struct CreateFieldHistory {
     pub new_value: Option<String>,
     pub changed_by: Uuid,
 }
+
+/// Kind of event in a ticket's history feed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TicketHistoryEventType {
+    /// A ticket field was edited
+    FieldChange,
+    /// The ticket moved to a new status
+    StatusChange,
+    /// A note was added
+    Note,
+    /// A photo was uploaded
+    PhotoAdded,
+    /// A photo was removed
+    PhotoRemoved,
+}
+
+/// One event in a ticket's merged history feed.
+///
+/// `field_name`, `old_value`, and `new_value` describe the change: the
+/// edited field, `status` for status changes, the note text in `new_value`
+/// for notes, and the photo ID for photo events.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketHistoryEvent {
+    pub event_id: Uuid,
+    pub event_type: TicketHistoryEventType,
+    pub field_name: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub employee_id: Uuid,
+    pub employee_name: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_event_type_serialization() {
+        let json = serde_json::to_string(&TicketHistoryEventType::PhotoRemoved).unwrap();
+        assert_eq!(json, r#""photo_removed""#);
+        let parsed: TicketHistoryEventType = serde_json::from_str(r#""status_change""#).unwrap();
+        assert_eq!(parsed, TicketHistoryEventType::StatusChange);
+    }
+}
@@ -0,0 +1,30 @@
+//! Idempotency key model.
+//!
+//! The stored outcome of a mutating request sent with an `Idempotency-Key`
+//! header, replayed when the client retries with the same key.
+
+use chrono::{DateTime, Utc};
+
+/// A claimed idempotency key and, once the request finished, its response.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct IdempotencyRecord {
+    /// SHA-256 of the request's credential headers (hex)
+    pub principal: String,
+    pub idempotency_key: String,
+    pub endpoint: String,
+    /// SHA-256 of the request body (hex)
+    pub request_hash: String,
+    /// None while the first request is still running
+    pub response_status: Option<i16>,
+    pub response_content_type: Option<String>,
+    pub response_body: Option<Vec<u8>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A finished response to store against a key.
+#[derive(Debug, Clone)]
+pub struct StoredResponse {
+    pub status: i16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
@@ -0,0 +1,93 @@
+//! Intake draft model.
+//!
+//! Repair requests that arrive outside the counter, such as a transcribed
+//! phone call, are held as drafts until staff confirm them into a ticket
+//! or dismiss them.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// Where a draft came from, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "intake_draft_source", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum IntakeDraftSource {
+    /// Phone call or voicemail transcription service
+    Phone,
+}
+
+/// Review state of a draft, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "intake_draft_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum IntakeDraftStatus {
+    /// Waiting for staff to review
+    Pending,
+    /// Confirmed into a ticket
+    Converted,
+    /// Reviewed and not needed
+    Dismissed,
+}
+
+/// A repair request waiting to be confirmed into a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct IntakeDraft {
+    pub draft_id: Uuid,
+    pub source: IntakeDraftSource,
+    /// The sending service's ID for the request (e.g., a call ID)
+    pub external_id: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_phone: Option<String>,
+    pub requested_work: Option<String>,
+    /// Full transcription, for staff to read before confirming
+    pub transcript: Option<String>,
+    pub recording_url: Option<String>,
+    pub received_at: DateTime<Utc>,
+    pub status: IntakeDraftStatus,
+    /// The ticket created from the draft, once converted
+    pub ticket_id: Option<Uuid>,
+    /// Employee who converted or dismissed the draft
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl IntakeDraft {
+    /// Returns true if the draft is still waiting for review.
+    pub fn is_pending(&self) -> bool {
+        self.status == IntakeDraftStatus::Pending
+    }
+}
+
+/// Input for recording a draft.
+#[derive(Debug, Clone)]
+pub struct CreateIntakeDraft {
+    pub source: IntakeDraftSource,
+    pub external_id: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_phone: Option<String>,
+    pub requested_work: Option<String>,
+    pub transcript: Option<String>,
+    pub recording_url: Option<String>,
+    /// When the request was received (defaults to now)
+    pub received_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_serializes_snake_case() {
+        assert_eq!(
+            serde_json::to_string(&IntakeDraftStatus::Converted).unwrap(),
+            "\"converted\""
+        );
+        assert_eq!(
+            serde_json::from_str::<IntakeDraftStatus>("\"pending\"").unwrap(),
+            IntakeDraftStatus::Pending
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Integrity check model.
+//!
+//! Consistency problems found by `GET /admin/integrity` and the scheduled
+//! integrity run, each with a suggested fix.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A consistency check run by the integrity audit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrityCheck {
+    /// Photo record whose stored file is gone
+    MissingPhotoObject,
+    /// Open ticket stored at a deactivated location
+    InactiveLocation,
+    /// Open ticket assigned to a deactivated employee
+    InactiveAssignee,
+    /// Field history naming an employee that doesn't exist
+    UnknownEmployeeReference,
+    /// Note left on a deleted ticket
+    OrphanedNote,
+}
+
+impl IntegrityCheck {
+    /// Every check, in the order they run.
+    pub const ALL: [IntegrityCheck; 5] = [
+        IntegrityCheck::MissingPhotoObject,
+        IntegrityCheck::InactiveLocation,
+        IntegrityCheck::InactiveAssignee,
+        IntegrityCheck::UnknownEmployeeReference,
+        IntegrityCheck::OrphanedNote,
+    ];
+
+    /// What an admin can do about a problem found by this check.
+    pub fn suggested_fix(&self) -> &'static str {
+        match self {
+            IntegrityCheck::MissingPhotoObject => {
+                "Delete the photo record and upload the photo again if it is still needed"
+            }
+            IntegrityCheck::InactiveLocation => {
+                "Move the ticket to an active location or reactivate the location"
+            }
+            IntegrityCheck::InactiveAssignee => {
+                "Reassign the ticket to an active employee or clear worked_by"
+            }
+            IntegrityCheck::UnknownEmployeeReference => {
+                "No action needed; the history keeps the raw employee ID"
+            }
+            IntegrityCheck::OrphanedNote => {
+                "Restore the ticket if it was deleted by mistake; otherwise no action is needed"
+            }
+        }
+    }
+}
+
+/// A record flagged by a check query, before it is described.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct IntegrityRecord {
+    /// The flagged row (photo, ticket, history entry, or note)
+    pub record_id: Uuid,
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    /// Check-specific detail (storage key, location or employee name, ...)
+    pub detail: String,
+}
+
+/// One problem found by the integrity audit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityIssue {
+    pub check: IntegrityCheck,
+    /// The flagged row (photo, ticket, history entry, or note)
+    pub record_id: Uuid,
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    pub message: String,
+    pub suggested_fix: String,
+}
+
+/// Number of problems found by one check.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityCheckSummary {
+    pub check: IntegrityCheck,
+    pub issues: usize,
+}
+
+/// Result of an integrity audit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    pub checked_at: DateTime<Utc>,
+    pub checks: Vec<IntegrityCheckSummary>,
+    pub issues: Vec<IntegrityIssue>,
+}
+
+impl IntegrityReport {
+    /// Build a report, summarizing issues per check.
+    pub fn new(checked_at: DateTime<Utc>, issues: Vec<IntegrityIssue>) -> Self {
+        let checks = IntegrityCheck::ALL
+            .iter()
+            .map(|&check| IntegrityCheckSummary {
+                check,
+                issues: issues.iter().filter(|issue| issue.check == check).count(),
+            })
+            .collect();
+
+        Self {
+            checked_at,
+            checks,
+            issues,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integrity_check_serialization() {
+        let json = serde_json::to_string(&IntegrityCheck::MissingPhotoObject).unwrap();
+        assert_eq!(json, "\"missing_photo_object\"");
+    }
+
+    #[test]
+    fn test_integrity_report_summarizes_every_check() {
+        let issue = IntegrityIssue {
+            check: IntegrityCheck::OrphanedNote,
+            record_id: Uuid::new_v4(),
+            ticket_id: Uuid::new_v4(),
+            friendly_code: "JR-0001".to_string(),
+            message: "Note on deleted ticket JR-0001".to_string(),
+            suggested_fix: IntegrityCheck::OrphanedNote.suggested_fix().to_string(),
+        };
+        let report = IntegrityReport::new(Utc::now(), vec![issue]);
+
+        assert_eq!(report.checks.len(), IntegrityCheck::ALL.len());
+        let orphaned = report
+            .checks
+            .iter()
+            .find(|summary| summary.check == IntegrityCheck::OrphanedNote)
+            .unwrap();
+        assert_eq!(orphaned.issues, 1);
+        assert!(report
+            .checks
+            .iter()
+            .filter(|summary| summary.check != IntegrityCheck::OrphanedNote)
+            .all(|summary| summary.issues == 0));
+    }
+}
@@ -0,0 +1,72 @@
+//! Ticket invoice model.
+//!
+//! A ticket is numbered once, when it is first closed. Each close records
+//! the tax on the amount charged, so the invoice keeps the rate in effect
+//! at close even if the store's rate changes later.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::tax::TaxBreakdown;
+
+/// A numbered invoice for a closed ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketInvoice {
+    pub ticket_id: Uuid,
+    pub invoice_number: i32,
+    /// Tax percentage at close
+    pub tax_rate: Decimal,
+    /// Whether the amount charged included the tax
+    pub tax_inclusive: bool,
+    pub tax_amount: Decimal,
+    /// What the customer pays, with tax
+    pub total_amount: Decimal,
+    pub issued_at: DateTime<Utc>,
+}
+
+impl TicketInvoice {
+    /// Invoice number as printed, e.g. `INV-000042`.
+    pub fn code(&self) -> String {
+        format!("INV-{:06}", self.invoice_number)
+    }
+
+    /// The tax recorded at close.
+    pub fn tax(&self) -> TaxBreakdown {
+        TaxBreakdown {
+            subtotal: self.total_amount - self.tax_amount,
+            tax_rate: self.tax_rate,
+            tax_inclusive: self.tax_inclusive,
+            tax_amount: self.tax_amount,
+            total: self.total_amount,
+        }
+    }
+}
+
+/// Input for issuing an invoice.
+#[derive(Debug, Clone)]
+pub struct CreateTicketInvoice {
+    pub ticket_id: Uuid,
+    pub invoice_number: i32,
+    pub tax: TaxBreakdown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invoice_code_is_zero_padded() {
+        let invoice = TicketInvoice {
+            ticket_id: Uuid::nil(),
+            invoice_number: 42,
+            tax_rate: Decimal::ZERO,
+            tax_inclusive: true,
+            tax_amount: Decimal::ZERO,
+            total_amount: Decimal::new(4500, 2),
+            issued_at: Utc::now(),
+        };
+        assert_eq!(invoice.code(), "INV-000042");
+    }
+}
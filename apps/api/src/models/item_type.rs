@@ -0,0 +1,138 @@
+//! Item type model.
+//!
+//! Admin-configured item types (ring, watch, chain, ...) with intake
+//! defaults. Tickets keep `item_type` as free text so one-off types still
+//! work; a ticket whose item type matches a configured one by name
+//! (case-insensitive) picks up its defaults. With `restrict_item_types` set,
+//! tickets must use a configured type or [`OTHER_ITEM_TYPE`].
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::business_hours::BusinessCalendar;
+
+/// Item type always accepted, even when tickets are restricted to the
+/// configured types.
+pub const OTHER_ITEM_TYPE: &str = "Other";
+
+/// A configured item type and its intake defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ItemType {
+    pub item_type_id: Uuid,
+    pub name: String,
+    /// Days from intake to the default promise date
+    pub default_turnaround_days: Option<i32>,
+    /// Photos required before work starts
+    pub required_photos: i32,
+    /// Condition points to check and note at intake
+    pub condition_checklist: Vec<String>,
+    /// Services commonly requested for this item type
+    pub suggested_services: Vec<String>,
+    /// Starting quote offered at intake (not applied automatically)
+    pub base_price: Option<Decimal>,
+    pub sort_order: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ItemType {
+    /// Promise date implied by the default turnaround (or the store's rush
+    /// turnaround), counted in days the store is open.
+    pub fn default_promise_date(
+        &self,
+        calendar: &BusinessCalendar,
+        today: NaiveDate,
+        is_rush: bool,
+    ) -> Option<NaiveDate> {
+        calendar
+            .turnaround_days(self.default_turnaround_days, is_rush)
+            .map(|days| calendar.promise_date(today, days).date)
+    }
+}
+
+/// Input for an item type (item types are replaced as a set, in order).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateItemType {
+    pub name: String,
+    #[serde(default)]
+    pub default_turnaround_days: Option<i32>,
+    #[serde(default)]
+    pub required_photos: i32,
+    #[serde(default)]
+    pub condition_checklist: Vec<String>,
+    #[serde(default)]
+    pub suggested_services: Vec<String>,
+    #[serde(default)]
+    pub base_price: Option<Decimal>,
+}
+
+/// Whether an item type is the "Other" escape hatch (case-insensitive).
+pub fn is_other_item_type(name: &str) -> bool {
+    name.trim().eq_ignore_ascii_case(OTHER_ITEM_TYPE)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item_type(default_turnaround_days: Option<i32>) -> ItemType {
+        ItemType {
+            item_type_id: Uuid::new_v4(),
+            name: "Ring".to_string(),
+            default_turnaround_days,
+            required_photos: 2,
+            condition_checklist: vec!["Stones secure".to_string()],
+            suggested_services: vec!["Sizing".to_string()],
+            base_price: None,
+            sort_order: 0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_default_promise_date() {
+        let calendar = BusinessCalendar::new(&[], &[], None);
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(
+            item_type(Some(7)).default_promise_date(&calendar, today, false),
+            NaiveDate::from_ymd_opt(2024, 3, 8)
+        );
+        assert_eq!(
+            item_type(None).default_promise_date(&calendar, today, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_default_promise_date_skips_holidays() {
+        let holiday = NaiveDate::from_ymd_opt(2024, 3, 8).unwrap();
+        let calendar = BusinessCalendar::new(&[], &[holiday], Some(1));
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(
+            item_type(Some(7)).default_promise_date(&calendar, today, false),
+            NaiveDate::from_ymd_opt(2024, 3, 9)
+        );
+        assert_eq!(
+            item_type(Some(7)).default_promise_date(&calendar, today, true),
+            NaiveDate::from_ymd_opt(2024, 3, 2)
+        );
+    }
+
+    #[test]
+    fn test_create_item_type_defaults() {
+        let input: CreateItemType = serde_json::from_str(r#"{"name": "Watch"}"#).unwrap();
+        assert_eq!(input.required_photos, 0);
+        assert!(input.default_turnaround_days.is_none());
+        assert!(input.condition_checklist.is_empty());
+        assert!(input.suggested_services.is_empty());
+        assert!(input.base_price.is_none());
+    }
+
+    #[test]
+    fn test_is_other_item_type() {
+        assert!(is_other_item_type("Other"));
+        assert!(is_other_item_type(" other "));
+        assert!(!is_other_item_type("Other ring"));
+    }
+}
@@ -0,0 +1,73 @@
+//! Materials inventory model.
+//!
+//! Stock the bench uses up (gold solder, sizing stock, findings) with the
+//! quantity on hand at each store and what a unit costs. Materials recorded
+//! against a ticket come out of the holding store's stock at their cost at
+//! the time, which the revenue report counts as cost of goods.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A material in the inventory.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Material {
+    pub material_id: Uuid,
+    pub name: String,
+    /// Unit quantities are counted in (g, dwt, in, each, ...)
+    pub unit: String,
+    /// Stock at the store it was looked up for
+    pub quantity_on_hand: Decimal,
+    /// Cost of one unit
+    pub unit_cost: Decimal,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for creating a material.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateMaterial {
+    pub name: String,
+    pub unit: String,
+    #[serde(default)]
+    pub quantity_on_hand: Decimal,
+    pub unit_cost: Decimal,
+}
+
+/// Input for updating a material. Setting `quantity_on_hand` records a
+/// restock or a count at one store.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateMaterial {
+    pub name: Option<String>,
+    pub unit: Option<String>,
+    pub quantity_on_hand: Option<Decimal>,
+    pub unit_cost: Option<Decimal>,
+    pub is_active: Option<bool>,
+}
+
+/// A material used on a ticket, with its cost when it was used.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketMaterialEntry {
+    pub ticket_material_id: Uuid,
+    pub material_id: Uuid,
+    pub material_name: String,
+    pub unit: String,
+    pub quantity: Decimal,
+    pub unit_cost: Decimal,
+    /// Quantity times unit cost
+    pub cost: Decimal,
+    pub recorded_by: Uuid,
+    pub recorded_by_name: String,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Input for recording a material used on a ticket.
+#[derive(Debug, Clone)]
+pub struct CreateTicketMaterial {
+    pub ticket_id: Uuid,
+    pub material_id: Uuid,
+    pub quantity: Decimal,
+    pub recorded_by: Uuid,
+}
@@ -0,0 +1,67 @@
+//! Metal price model.
+//!
+//! Admin-maintained prices used to estimate the melt value of an item from
+//! its weight and metal. Estimates are for internal scrap and insurance
+//! decisions only.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Price entry for a metal or alloy (e.g., "14k_gold").
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MetalPrice {
+    pub metal_type: String,
+    /// Fraction of fine metal in the alloy (0.585 for 14k gold)
+    pub purity: Decimal,
+    /// Price per gram of the pure metal
+    pub price_per_gram: Decimal,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MetalPrice {
+    /// Estimate the melt value of an item, rounded to cents.
+    pub fn melt_value(&self, weight_grams: Decimal) -> Decimal {
+        (weight_grams * self.purity * self.price_per_gram).round_dp(2)
+    }
+}
+
+/// Input for a metal price (prices are replaced as a set).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateMetalPrice {
+    pub metal_type: String,
+    pub purity: Decimal,
+    pub price_per_gram: Decimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(purity: &str, per_gram: &str) -> MetalPrice {
+        MetalPrice {
+            metal_type: "14k_gold".to_string(),
+            purity: purity.parse().unwrap(),
+            price_per_gram: per_gram.parse().unwrap(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_melt_value() {
+        // 5g of 14k gold at $80/g fine = 5 * 0.585 * 80
+        let value = price("0.585", "80").melt_value(Decimal::new(5, 0));
+        assert_eq!(value, Decimal::new(23400, 2));
+    }
+
+    #[test]
+    fn test_melt_value_rounds_to_cents() {
+        let value = price("0.925", "0.95").melt_value("3.333".parse().unwrap());
+        assert_eq!(value, "2.93".parse::<Decimal>().unwrap());
+    }
+
+    #[test]
+    fn test_melt_value_zero_weight() {
+        assert_eq!(price("1", "100").melt_value(Decimal::ZERO), Decimal::ZERO);
+    }
+}
@@ -2,35 +2,159 @@
 //!
 //! Models represent the core business entities used throughout the application.
 
+pub mod admin_recovery;
 pub mod admin_session;
+pub mod appraisal;
+pub mod audit_log;
+pub mod business_hours;
+pub mod calendar_feed;
+pub mod campaign;
+pub mod contact;
+pub mod custody;
 pub mod customer;
+pub mod defect;
 pub mod employee;
 pub mod employee_session;
 pub mod field_history;
+pub mod idempotency;
+pub mod intake_draft;
+pub mod integrity;
+pub mod invoice;
+pub mod item_type;
+pub mod material;
+pub mod metal_price;
+pub mod movement;
+pub mod notification;
+pub mod partner;
+pub mod payment;
+pub mod pickup;
+pub mod pin_challenge;
+pub mod promise_date_reason;
+pub mod qc_check;
+pub mod queue_snapshot;
+pub mod quote;
+pub mod rate_limit;
+pub mod reminder;
+pub mod report;
+pub mod report_definition;
+pub mod request_log;
+pub mod rush_pricing;
+pub mod service;
+pub mod settings_change;
 pub mod status_history;
 pub mod storage_location;
+pub mod storage_reconcile;
+pub mod store;
 pub mod store_settings;
+pub mod tax;
 pub mod ticket;
+pub mod ticket_item;
 pub mod ticket_note;
 pub mod ticket_photo;
+pub mod time_entry;
+pub mod transfer;
+pub mod wait_estimate;
+pub mod webhook;
 
+pub use admin_recovery::{AdminRecoveryAttempt, AdminRecoveryCode, CreateAdminRecoveryAttempt};
 pub use admin_session::{AdminSession, AdminSessionResponse, CreateAdminSession};
-pub use customer::{CreateCustomer, Customer};
+pub use appraisal::{Appraisal, AppraisalEntry, SaveAppraisal, MAX_CERTIFICATION_NUMBERS};
+pub use audit_log::{
+    AuditAction, AuditAuthMethod, AuditLogEntry, AuditLogFilter, CreateAuditLogEntry,
+};
+pub use business_hours::{BusinessCalendar, OpeningHours, PromiseDate};
+pub use calendar_feed::PromiseDateEntry;
+pub use campaign::{
+    Campaign, CampaignProgress, CampaignRecipient, CampaignRecipientStatus, CampaignSegment,
+    CampaignStatus, CampaignSummary, CreateCampaign, DEFAULT_CAMPAIGN_SEND_RATE,
+};
+pub use contact::{
+    ContactChannel, ContactDirection, ContactOutcome, CreateTicketContact, TicketContact,
+};
+pub use custody::{
+    custody_required, CreateCustodyEvent, CustodyEvent, CustodyEventEntry, CustodyEventType,
+    CustodyWitness,
+};
+pub use customer::{CreateCustomer, Customer, CustomerMerge, UpdateCustomer};
+pub use defect::{CreateTicketDefect, DefectReason, DefectSource, TicketDefect};
 pub use employee::{
-    CreateEmployee, Employee, EmployeeRole, EmployeeSummary, Permission, UpdateEmployee,
+    CreateEmployee, Employee, EmployeeName, EmployeeProfile, EmployeeRole, EmployeeSummary,
+    OpenTicketCounts, Permission, UpdateEmployee,
 };
 pub use employee_session::{CreateEmployeeSession, EmployeeSession, EmployeeSessionResponse};
-pub use field_history::{CreateFieldHistory, FieldHistoryEntry};
+pub use field_history::{
+    CreateFieldHistory, FieldHistoryEntry, TicketHistoryEvent, TicketHistoryEventType, PHOTO_FIELD,
+};
+pub use idempotency::{IdempotencyRecord, StoredResponse};
+pub use intake_draft::{CreateIntakeDraft, IntakeDraft, IntakeDraftSource, IntakeDraftStatus};
+pub use integrity::{
+    IntegrityCheck, IntegrityCheckSummary, IntegrityIssue, IntegrityRecord, IntegrityReport,
+};
+pub use invoice::{CreateTicketInvoice, TicketInvoice};
+pub use item_type::{CreateItemType, ItemType};
+pub use material::{
+    CreateMaterial, CreateTicketMaterial, Material, TicketMaterialEntry, UpdateMaterial,
+};
+pub use metal_price::{CreateMetalPrice, MetalPrice};
+pub use movement::{CreateTicketMovement, CurrentLocation, TicketMovement, TicketMovementEntry};
+pub use notification::{
+    CreateNotificationLog, NotificationChannel, NotificationEvent, NotificationLog,
+    NotificationStatus, NotificationTemplate, UpdateNotificationTemplate,
+};
+pub use partner::{
+    CreatePartner, Partner, PartnerTicket, UpdatePartner, DEFAULT_PARTNER_RATE_LIMIT,
+};
+pub use payment::{
+    balance_due, CreateTicketPayment, PaymentKind, PaymentMethod, TicketPayment, TicketPaymentEntry,
+};
+pub use pickup::{
+    id_last_four, CreateAuthorizedPickup, CreateTicketPickup, PickupInput, TicketAuthorizedPickup,
+    TicketPickupEntry,
+};
+pub use pin_challenge::{PinChallenge, PinChallengeKdf, PinChallengeResponse};
+pub use promise_date_reason::{CreatePromiseDateReason, PromiseDateReason};
+pub use qc_check::{qc_gate_satisfied, CreateTicketQcCheck, TicketQcCheck};
+pub use queue_snapshot::QueueSnapshot;
+pub use quote::{quote_approval_required, CreateQuoteEvent, QuoteChannel, QuoteEvent, QuoteStatus};
+pub use rate_limit::FailureRecord;
+pub use reminder::{DueTicket, OverdueDigest, OverdueReport, ReminderKind};
+pub use request_log::{CreateRequestLog, DebugCaptureConfig, RequestLog};
+pub use rush_pricing::{
+    quote_breakdown, CreateRushSurchargeTier, QuoteBreakdown, QuoteLine, RushSurchargeKind,
+    RushSurchargeTier,
+};
+pub use service::{
+    CreateService, Service, ServiceDefaults, TicketService, UpdateService, MAX_TICKET_SERVICES,
+};
+pub use settings_change::{
+    settings_diff, CreateSettingsChange, SettingsChange, SettingsChangeEntry,
+};
 pub use status_history::{CreateStatusHistory, StatusHistoryEntry};
 pub use storage_location::{
-    CreateStorageLocation, StorageLocation, StorageLocationSummary, UpdateStorageLocation,
+    rank_locations, CreateStorageLocation, CreateStorageLocationRule, LocationOccupancy,
+    LocationSuggestion, StorageLocation, StorageLocationRule, StorageLocationSummary,
+    SuggestionReason, UpdateStorageLocation,
 };
+pub use storage_reconcile::{ReconcileFailure, StorageReconcileReport};
+pub use store::{CreateStore, Store, STORE_HEADER};
 pub use store_settings::{
-    StoreSettings, StoreSettingsPublic, TicketNumberResult, UpdateStoreSettings,
+    LabelStock, ReceiptFormat, StoreSettings, StoreSettingsPublic, TicketNumbering,
+    UpdateStoreSettings,
 };
+pub use tax::{TaxBreakdown, TaxRate};
 pub use ticket::{
-    CreateTicket, QueueTicket, Ticket, TicketFilters, TicketSearchParams, TicketStatus,
-    TicketSummary, UpdateTicket, WorkboardQueue,
+    CreateTicket, QueueTicket, Ticket, TicketCursor, TicketDetail, TicketFilters, TicketOrder,
+    TicketSearchParams, TicketSort, TicketSortField, TicketStatus, TicketSummary, UpdateTicket,
+    WorkboardQueue,
 };
+pub use ticket_item::{CreateTicketItem, TicketItem, MAX_TICKET_ITEMS};
 pub use ticket_note::{CreateTicketNote, TicketNote};
 pub use ticket_photo::{CreateTicketPhoto, TicketPhoto, TicketPhotoSummary};
+pub use time_entry::{total_bench_seconds, TimeEntry, TimeEntryEntry};
+pub use transfer::{CreateTicketTransfer, TicketTransfer, TicketTransferEntry, TransferStatus};
+pub use wait_estimate::{estimate_completion, CompletionEstimate, WaitEstimateInput};
+pub use webhook::{
+    webhook_retry_delay, CreateWebhook, UpdateWebhook, Webhook, WebhookDelivery,
+    WebhookDeliveryStatus, WebhookDispatch, WebhookEvent, MAX_WEBHOOK_ATTEMPTS,
+    WEBHOOK_SCHEMA_VERSION,
+};
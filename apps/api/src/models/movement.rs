@@ -0,0 +1,152 @@
+//! Ticket movement model.
+//!
+//! A movement records an item leaving its storage location for an
+//! employee's bench, passing between benches, or going back. Each end is
+//! either a storage location or an employee. The latest movement tells where
+//! the item is now; the ticket's storage location stays where it belongs.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A movement of a ticket's item.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketMovement {
+    pub movement_id: Uuid,
+    pub ticket_id: Uuid,
+    pub from_location_id: Option<Uuid>,
+    pub from_employee_id: Option<Uuid>,
+    pub to_location_id: Option<Uuid>,
+    /// Employee holding the item at their bench
+    pub to_employee_id: Option<Uuid>,
+    pub moved_by: Uuid,
+    pub notes: Option<String>,
+    pub moved_at: DateTime<Utc>,
+}
+
+/// Movement with ticket, location, and employee names, for display.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketMovementEntry {
+    pub movement_id: Uuid,
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    pub from_location_id: Option<Uuid>,
+    pub from_location_name: Option<String>,
+    pub from_employee_id: Option<Uuid>,
+    pub from_employee_name: Option<String>,
+    pub to_location_id: Option<Uuid>,
+    pub to_location_name: Option<String>,
+    pub to_employee_id: Option<Uuid>,
+    pub to_employee_name: Option<String>,
+    pub moved_by: Uuid,
+    pub moved_by_name: String,
+    pub notes: Option<String>,
+    pub moved_at: DateTime<Utc>,
+}
+
+/// Input for recording a movement.
+#[derive(Debug, Clone)]
+pub struct CreateTicketMovement {
+    pub ticket_id: Uuid,
+    pub from_location_id: Option<Uuid>,
+    pub from_employee_id: Option<Uuid>,
+    pub to_location_id: Option<Uuid>,
+    pub to_employee_id: Option<Uuid>,
+    pub moved_by: Uuid,
+    pub notes: Option<String>,
+}
+
+/// Where a ticket's item is right now.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurrentLocation {
+    /// Storage location the item belongs in
+    pub location_id: Uuid,
+    pub location_name: String,
+    /// Employee who has the item at their bench; None while it's stored
+    pub employee_id: Option<Uuid>,
+    pub employee_name: Option<String>,
+    /// When the item last moved; None if it never has
+    pub since: Option<DateTime<Utc>>,
+}
+
+impl CurrentLocation {
+    /// Work out where an item is from its storage location and its latest
+    /// movement, if any.
+    ///
+    /// Only a movement to an employee takes the item out of its location, so
+    /// a change of storage location while it's stored needs no movement.
+    pub fn derive(
+        location_id: Uuid,
+        location_name: String,
+        latest: Option<&TicketMovementEntry>,
+    ) -> Self {
+        let (employee_id, employee_name) = latest
+            .and_then(|m| m.to_employee_id.map(|id| (id, m.to_employee_name.clone())))
+            .map_or((None, None), |(id, name)| (Some(id), name));
+
+        Self {
+            location_id,
+            location_name,
+            employee_id,
+            employee_name,
+            since: latest.map(|m| m.moved_at),
+        }
+    }
+
+    /// Returns true if the item is out at an employee's bench.
+    pub fn is_checked_out(&self) -> bool {
+        self.employee_id.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(to_location_id: Option<Uuid>, to_employee_id: Option<Uuid>) -> TicketMovementEntry {
+        TicketMovementEntry {
+            movement_id: Uuid::new_v4(),
+            ticket_id: Uuid::new_v4(),
+            friendly_code: "JR-0001".to_string(),
+            from_location_id: None,
+            from_location_name: None,
+            from_employee_id: None,
+            from_employee_name: None,
+            to_location_id,
+            to_location_name: to_location_id.map(|_| "Safe A".to_string()),
+            to_employee_id,
+            to_employee_name: to_employee_id.map(|_| "Sam".to_string()),
+            moved_by: Uuid::new_v4(),
+            moved_by_name: "Alex".to_string(),
+            notes: None,
+            moved_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_current_location_never_moved() {
+        let location_id = Uuid::new_v4();
+        let current = CurrentLocation::derive(location_id, "Safe A".to_string(), None);
+        assert_eq!(current.location_id, location_id);
+        assert!(!current.is_checked_out());
+        assert!(current.since.is_none());
+    }
+
+    #[test]
+    fn test_current_location_checked_out_and_back() {
+        let location_id = Uuid::new_v4();
+        let employee_id = Uuid::new_v4();
+
+        let out = entry(None, Some(employee_id));
+        let current = CurrentLocation::derive(location_id, "Safe A".to_string(), Some(&out));
+        assert!(current.is_checked_out());
+        assert_eq!(current.employee_id, Some(employee_id));
+        assert_eq!(current.employee_name.as_deref(), Some("Sam"));
+        assert_eq!(current.since, Some(out.moved_at));
+
+        let back = entry(Some(location_id), None);
+        let current = CurrentLocation::derive(location_id, "Safe A".to_string(), Some(&back));
+        assert!(!current.is_checked_out());
+        assert_eq!(current.since, Some(back.moved_at));
+    }
+}
@@ -0,0 +1,164 @@
+//! Customer notification models.
+//!
+//! Every outbound customer notification is logged with its delivery status
+//! so staff can see on the ticket whether the customer was told. Email
+//! wording comes from store-editable templates.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// Notification channel matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "notification_channel", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationChannel {
+    Sms,
+    Email,
+}
+
+/// Delivery status matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "notification_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationStatus {
+    /// Created, not yet handed to the provider
+    Pending,
+    /// Accepted by the provider
+    Sent,
+    /// The provider rejected the message or could not be reached
+    Failed,
+    /// Not sent (no contact details or no provider configured)
+    Skipped,
+}
+
+/// A logged customer notification.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct NotificationLog {
+    pub notification_id: Uuid,
+    pub ticket_id: Uuid,
+    pub channel: NotificationChannel,
+    pub recipient: Option<String>,
+    /// Email subject (None for SMS)
+    pub subject: Option<String>,
+    pub message: String,
+    pub status: NotificationStatus,
+    pub provider: Option<String>,
+    pub provider_message_id: Option<String>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+}
+
+/// Input for logging a notification.
+#[derive(Debug, Clone)]
+pub struct CreateNotificationLog {
+    pub ticket_id: Uuid,
+    pub channel: NotificationChannel,
+    pub recipient: Option<String>,
+    pub subject: Option<String>,
+    pub message: String,
+    pub status: NotificationStatus,
+    pub provider: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Ticket event that triggers a customer notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "notification_event", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// Sent when a ticket is created
+    IntakeConfirmation,
+    /// Sent when a ticket moves to ready_for_pickup
+    ReadyForPickup,
+    /// Sent when a ticket's promise date moves later
+    PromiseDateChanged,
+}
+
+impl NotificationEvent {
+    /// Parse an event name as used in URLs (e.g., `ready_for_pickup`).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "intake_confirmation" => Some(Self::IntakeConfirmation),
+            "ready_for_pickup" => Some(Self::ReadyForPickup),
+            "promise_date_changed" => Some(Self::PromiseDateChanged),
+            _ => None,
+        }
+    }
+}
+
+/// Store-editable email template for a notification event.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct NotificationTemplate {
+    pub event: NotificationEvent,
+    pub subject: String,
+    pub body: String,
+    /// Disabled templates are not sent
+    pub is_enabled: bool,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for updating a notification template.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateNotificationTemplate {
+    pub subject: Option<String>,
+    pub body: Option<String>,
+    pub is_enabled: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_status_serialization() {
+        let json = serde_json::to_string(&NotificationStatus::Skipped).unwrap();
+        assert_eq!(json, "\"skipped\"");
+
+        let parsed: NotificationStatus = serde_json::from_str("\"sent\"").unwrap();
+        assert_eq!(parsed, NotificationStatus::Sent);
+    }
+
+    #[test]
+    fn test_notification_channel_serialization() {
+        let json = serde_json::to_string(&NotificationChannel::Sms).unwrap();
+        assert_eq!(json, "\"sms\"");
+        let json = serde_json::to_string(&NotificationChannel::Email).unwrap();
+        assert_eq!(json, "\"email\"");
+    }
+
+    #[test]
+    fn test_notification_event_serialization() {
+        let parsed: NotificationEvent = serde_json::from_str("\"intake_confirmation\"").unwrap();
+        assert_eq!(parsed, NotificationEvent::IntakeConfirmation);
+        assert!(serde_json::from_str::<NotificationEvent>("\"closed\"").is_err());
+    }
+
+    #[test]
+    fn test_update_notification_template_partial() {
+        let json = r#"{"is_enabled": false}"#;
+        let input: UpdateNotificationTemplate = serde_json::from_str(json).unwrap();
+        assert_eq!(input.is_enabled, Some(false));
+        assert!(input.subject.is_none());
+        assert!(input.body.is_none());
+    }
+
+    #[test]
+    fn test_notification_event_parse() {
+        assert_eq!(
+            NotificationEvent::parse("ready_for_pickup"),
+            Some(NotificationEvent::ReadyForPickup)
+        );
+        assert_eq!(
+            NotificationEvent::parse("intake_confirmation"),
+            Some(NotificationEvent::IntakeConfirmation)
+        );
+        assert_eq!(
+            NotificationEvent::parse("promise_date_changed"),
+            Some(NotificationEvent::PromiseDateChanged)
+        );
+        assert_eq!(NotificationEvent::parse("Ready_For_Pickup"), None);
+    }
+}
@@ -0,0 +1,101 @@
+//! Partner model for trade work.
+//!
+//! Jewelers who send trade work get a partner account with an API key. A
+//! partner is tied to a customer record for their shop; the tickets of that
+//! customer are the ones the partner can see through the partner API.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::ticket::TicketStatus;
+
+/// Requests per minute for a new partner unless set otherwise.
+pub const DEFAULT_PARTNER_RATE_LIMIT: i32 = 30;
+
+/// Partner account.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Partner {
+    pub partner_id: Uuid,
+    pub name: String,
+    pub customer_id: Uuid,
+    /// First characters of the API key, for telling keys apart
+    pub api_key_prefix: String,
+    /// SHA-256 of the API key (never returned by the API)
+    #[serde(skip_serializing, default)]
+    pub api_key_hash: String,
+    pub rate_limit_per_minute: i32,
+    pub taken_in_by: Uuid,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// Input for creating a partner account.
+#[derive(Debug, Clone)]
+pub struct CreatePartner {
+    pub name: String,
+    pub customer_id: Uuid,
+    pub api_key_prefix: String,
+    pub api_key_hash: String,
+    pub rate_limit_per_minute: i32,
+    pub taken_in_by: Uuid,
+}
+
+/// Input for updating a partner account.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdatePartner {
+    pub name: Option<String>,
+    pub rate_limit_per_minute: Option<i32>,
+    pub taken_in_by: Option<Uuid>,
+    pub is_active: Option<bool>,
+}
+
+/// A ticket as a partner sees it.
+///
+/// Leaves out internal details (notes, storage location, employees).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PartnerTicket {
+    pub friendly_code: String,
+    /// The partner's own job number, when submitted through the API
+    pub partner_reference: Option<String>,
+    pub item_type: Option<String>,
+    pub item_description: String,
+    pub requested_work: String,
+    pub status: TicketStatus,
+    pub is_rush: bool,
+    pub promise_date: Option<NaiveDate>,
+    pub quote_amount: Option<Decimal>,
+    pub actual_amount: Option<Decimal>,
+    pub created_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partner_never_serializes_key_hash() {
+        let partner = Partner {
+            partner_id: Uuid::new_v4(),
+            name: "Smith Jewelers".to_string(),
+            customer_id: Uuid::new_v4(),
+            api_key_prefix: "fpk_Ab3d".to_string(),
+            api_key_hash: "deadbeef".to_string(),
+            rate_limit_per_minute: DEFAULT_PARTNER_RATE_LIMIT,
+            taken_in_by: Uuid::new_v4(),
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            last_used_at: None,
+        };
+
+        let json = serde_json::to_string(&partner).unwrap();
+        assert!(json.contains("\"api_key_prefix\":\"fpk_Ab3d\""));
+        assert!(!json.contains("api_key_hash"));
+        assert!(!json.contains("deadbeef"));
+    }
+}
@@ -0,0 +1,106 @@
+//! Ticket payment model.
+//!
+//! Deposits are taken at intake and the final payment at close. A ticket's
+//! balance is what it costs (the actual amount once closed, otherwise the
+//! quote) less everything paid so far.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// When a payment was taken, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "payment_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentKind {
+    /// Paid up front, before the ticket is closed
+    Deposit,
+    /// Paid when the item is picked up
+    Final,
+}
+
+/// How a payment was made, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "payment_method", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethod {
+    Cash,
+    Card,
+    Check,
+    Other,
+}
+
+/// A payment recorded against a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketPayment {
+    pub payment_id: Uuid,
+    pub ticket_id: Uuid,
+    pub kind: PaymentKind,
+    pub method: PaymentMethod,
+    pub amount: Decimal,
+    pub notes: Option<String>,
+    pub received_by: Uuid,
+    pub received_at: DateTime<Utc>,
+}
+
+/// A payment with the receiving employee's name, for listings.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketPaymentEntry {
+    pub payment_id: Uuid,
+    pub kind: PaymentKind,
+    pub method: PaymentMethod,
+    pub amount: Decimal,
+    pub notes: Option<String>,
+    pub received_by: Uuid,
+    pub received_by_name: String,
+    pub received_at: DateTime<Utc>,
+}
+
+/// Input for recording a payment.
+#[derive(Debug, Clone)]
+pub struct CreateTicketPayment {
+    pub ticket_id: Uuid,
+    pub kind: PaymentKind,
+    pub method: PaymentMethod,
+    pub amount: Decimal,
+    pub notes: Option<String>,
+    pub received_by: Uuid,
+}
+
+/// Amount still owed, or None if the ticket has no price yet.
+///
+/// Never negative; overpayment leaves a zero balance.
+pub fn balance_due(amount_owed: Option<Decimal>, total_paid: Decimal) -> Option<Decimal> {
+    amount_owed.map(|owed| (owed - total_paid).max(Decimal::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_enum_serialization() {
+        assert_eq!(
+            serde_json::to_string(&PaymentKind::Deposit).unwrap(),
+            "\"deposit\""
+        );
+        let parsed: PaymentMethod = serde_json::from_str("\"card\"").unwrap();
+        assert_eq!(parsed, PaymentMethod::Card);
+        assert!(serde_json::from_str::<PaymentMethod>("\"barter\"").is_err());
+    }
+
+    #[test]
+    fn test_balance_due() {
+        assert_eq!(balance_due(None, Decimal::new(2000, 2)), None);
+        assert_eq!(
+            balance_due(Some(Decimal::new(15000, 2)), Decimal::new(5000, 2)),
+            Some(Decimal::new(10000, 2))
+        );
+        assert_eq!(
+            balance_due(Some(Decimal::new(5000, 2)), Decimal::new(6000, 2)),
+            Some(Decimal::ZERO)
+        );
+    }
+}
@@ -0,0 +1,124 @@
+//! Authorized pickup model.
+//!
+//! Customers can authorize other people to collect their item. Every
+//! release records who collected it, the ID they showed, and their
+//! signature, whether that was the customer or an authorized person.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A person the customer has authorized to collect a ticket's item.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketAuthorizedPickup {
+    pub authorized_pickup_id: Uuid,
+    pub ticket_id: Uuid,
+    pub name: String,
+    /// Relationship to the customer (e.g., "Spouse")
+    pub relationship: Option<String>,
+    pub phone: Option<String>,
+    pub notes: Option<String>,
+    pub added_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// Set when the customer withdraws the authorization
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revoked_by: Option<Uuid>,
+}
+
+impl TicketAuthorizedPickup {
+    /// Returns true if the authorization has not been revoked.
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+}
+
+/// Input for authorizing someone to collect an item.
+#[derive(Debug, Clone)]
+pub struct CreateAuthorizedPickup {
+    pub ticket_id: Uuid,
+    pub name: String,
+    pub relationship: Option<String>,
+    pub phone: Option<String>,
+    pub notes: Option<String>,
+    pub added_by: Uuid,
+}
+
+/// A recorded release of a ticket's item, for display and reports.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketPickupEntry {
+    pub pickup_id: Uuid,
+    /// The authorization used, or None if the customer collected the item
+    pub authorized_pickup_id: Option<Uuid>,
+    pub picked_up_by_name: String,
+    /// Relationship to the customer, from the authorization
+    pub relationship: Option<String>,
+    pub id_type: Option<String>,
+    /// Last four characters of the ID number shown
+    pub id_last_four: Option<String>,
+    pub has_signature: bool,
+    pub released_by: Uuid,
+    pub released_by_name: String,
+    pub picked_up_at: DateTime<Utc>,
+}
+
+/// Input for recording a release.
+#[derive(Debug, Clone)]
+pub struct CreateTicketPickup {
+    pub ticket_id: Uuid,
+    pub authorized_pickup_id: Option<Uuid>,
+    pub picked_up_by_name: String,
+    pub id_type: Option<String>,
+    pub id_last_four: Option<String>,
+    /// PNG image of the signature
+    pub signature: Option<Vec<u8>>,
+    pub released_by: Uuid,
+}
+
+/// Pickup details sent when closing a ticket.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PickupInput {
+    /// Authorization being used; omit when the customer collects the item
+    pub authorized_pickup_id: Option<Uuid>,
+    /// Kind of ID shown (e.g., "drivers_license")
+    pub id_type: Option<String>,
+    /// ID number shown; only the last four characters are kept
+    pub id_number: Option<String>,
+    /// Base64-encoded PNG signature, optionally as a `data:` URL
+    pub signature: Option<String>,
+}
+
+/// The last four letters or digits of an ID number, uppercased.
+///
+/// Spaces, dashes, and other separators are ignored. Returns None if the
+/// number has no letters or digits.
+pub fn id_last_four(id_number: &str) -> Option<String> {
+    let chars: Vec<char> = id_number
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    if chars.is_empty() {
+        return None;
+    }
+    Some(chars[chars.len().saturating_sub(4)..].iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_last_four() {
+        assert_eq!(id_last_four("D123-4567-89").as_deref(), Some("6789"));
+        assert_eq!(id_last_four(" ab 12 ").as_deref(), Some("AB12"));
+        assert_eq!(id_last_four("x9").as_deref(), Some("X9"));
+        assert_eq!(id_last_four(" - "), None);
+    }
+
+    #[test]
+    fn test_pickup_input_defaults() {
+        let input: PickupInput = serde_json::from_str("{}").unwrap();
+        assert!(input.authorized_pickup_id.is_none());
+        assert!(input.signature.is_none());
+    }
+}
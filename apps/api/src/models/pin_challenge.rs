@@ -0,0 +1,83 @@
+//! PIN challenge model.
+//!
+//! A single-use nonce issued to an employee for challenge-response PIN
+//! entry. The client answers with an HMAC of the nonce keyed by its
+//! PIN-derived key, so the PIN itself never leaves the device.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::auth::PIN_CHALLENGE_ITERATIONS;
+
+/// An outstanding PIN challenge.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PinChallenge {
+    /// Random nonce (256-bit, base64url encoded)
+    pub nonce: String,
+    /// The employee it was issued for
+    pub employee_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// How clients derive the key used to answer a challenge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinChallengeKdf {
+    /// Always `pbkdf2-sha256`
+    pub algorithm: String,
+    /// The employee's salt
+    pub salt: String,
+    pub iterations: u32,
+}
+
+impl PinChallengeKdf {
+    /// Parameters for a key derived with the given salt.
+    pub fn new(salt: String) -> Self {
+        Self {
+            algorithm: "pbkdf2-sha256".to_string(),
+            salt,
+            iterations: PIN_CHALLENGE_ITERATIONS,
+        }
+    }
+}
+
+/// Response for an issued challenge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PinChallengeResponse {
+    pub nonce: String,
+    pub expires_at: DateTime<Utc>,
+    pub kdf: PinChallengeKdf,
+}
+
+impl PinChallengeResponse {
+    /// Response for a challenge to be answered with the key derived with `salt`.
+    pub fn new(challenge: PinChallenge, salt: String) -> Self {
+        Self {
+            nonce: challenge.nonce,
+            expires_at: challenge.expires_at,
+            kdf: PinChallengeKdf::new(salt),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_challenge_response_includes_kdf() {
+        let challenge = PinChallenge {
+            nonce: "abc".to_string(),
+            employee_id: Uuid::nil(),
+            created_at: Utc::now(),
+            expires_at: Utc::now(),
+        };
+        let json =
+            serde_json::to_value(PinChallengeResponse::new(challenge, "0f1e".to_string())).unwrap();
+        assert_eq!(json["nonce"], "abc");
+        assert_eq!(json["kdf"]["algorithm"], "pbkdf2-sha256");
+        assert_eq!(json["kdf"]["salt"], "0f1e");
+        assert_eq!(json["kdf"]["iterations"], PIN_CHALLENGE_ITERATIONS);
+    }
+}
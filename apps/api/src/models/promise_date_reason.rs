@@ -0,0 +1,71 @@
+//! Promise date reason model.
+//!
+//! Admin-configured reasons for moving a promise date later. When a
+//! ticket's promise date slips, staff pick a reason by code and the
+//! customer is told the new date along with the reason's label.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A configured reason for a later promise date.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PromiseDateReason {
+    pub reason_id: Uuid,
+    /// Short identifier sent by clients (e.g., "parts_on_order")
+    pub code: String,
+    /// Customer-facing wording
+    pub label: String,
+    pub sort_order: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for a promise date reason (reasons are replaced as a set, in order).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePromiseDateReason {
+    pub code: String,
+    pub label: String,
+}
+
+/// The new promise date if an update moves it later.
+///
+/// Only a date replacing an earlier date counts; setting a first date or
+/// clearing one is not a slip.
+pub fn slipped_promise_date(
+    current: Option<NaiveDate>,
+    update: Option<Option<NaiveDate>>,
+) -> Option<NaiveDate> {
+    match (current, update) {
+        (Some(current), Some(Some(new))) if new > current => Some(new),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    #[test]
+    fn test_slipped_promise_date() {
+        assert_eq!(
+            slipped_promise_date(Some(date(10)), Some(Some(date(14)))),
+            Some(date(14))
+        );
+        // Earlier, unchanged, cleared, first-time, and untouched dates don't slip
+        assert_eq!(
+            slipped_promise_date(Some(date(10)), Some(Some(date(8)))),
+            None
+        );
+        assert_eq!(
+            slipped_promise_date(Some(date(10)), Some(Some(date(10)))),
+            None
+        );
+        assert_eq!(slipped_promise_date(Some(date(10)), Some(None)), None);
+        assert_eq!(slipped_promise_date(None, Some(Some(date(14)))), None);
+        assert_eq!(slipped_promise_date(Some(date(10)), None), None);
+    }
+}
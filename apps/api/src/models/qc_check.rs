@@ -0,0 +1,111 @@
+//! Ticket QC check model.
+//!
+//! Records quality control checklist results. Append-only - each attempt
+//! is a new row so failed checks remain in the ticket timeline.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A QC checklist result recorded against a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketQcCheck {
+    pub check_id: Uuid,
+    pub ticket_id: Uuid,
+    pub passed: bool,
+    pub passed_items: Vec<String>,
+    pub failed_items: Vec<String>,
+    pub notes: Option<String>,
+    pub checked_by: Uuid,
+    pub checked_at: DateTime<Utc>,
+}
+
+impl TicketQcCheck {
+    /// Returns true if this check passed every item on the given checklist.
+    ///
+    /// A check recorded against an older checklist does not satisfy items
+    /// that were added afterwards.
+    pub fn covers(&self, checklist: &[String]) -> bool {
+        self.passed
+            && checklist
+                .iter()
+                .all(|item| self.passed_items.iter().any(|p| p == item))
+    }
+}
+
+/// Input for creating a QC check.
+#[derive(Debug, Clone)]
+pub struct CreateTicketQcCheck {
+    pub ticket_id: Uuid,
+    pub passed_items: Vec<String>,
+    pub failed_items: Vec<String>,
+    pub notes: Option<String>,
+    pub checked_by: Uuid,
+}
+
+/// Returns true if the QC gate is satisfied for a ticket.
+///
+/// The gate is disabled when the store has no checklist configured.
+/// Otherwise the most recent check must have passed every current item.
+pub fn qc_gate_satisfied(checklist: &[String], latest: Option<&TicketQcCheck>) -> bool {
+    if checklist.is_empty() {
+        return true;
+    }
+
+    latest.is_some_and(|check| check.covers(checklist))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(passed: bool, passed_items: &[&str]) -> TicketQcCheck {
+        TicketQcCheck {
+            check_id: Uuid::nil(),
+            ticket_id: Uuid::nil(),
+            passed,
+            passed_items: passed_items.iter().map(|s| s.to_string()).collect(),
+            failed_items: vec![],
+            notes: None,
+            checked_by: Uuid::nil(),
+            checked_at: Utc::now(),
+        }
+    }
+
+    fn checklist(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_gate_disabled_without_checklist() {
+        assert!(qc_gate_satisfied(&[], None));
+    }
+
+    #[test]
+    fn test_gate_requires_check_when_configured() {
+        let items = checklist(&["Stones secure", "Polished"]);
+        assert!(!qc_gate_satisfied(&items, None));
+    }
+
+    #[test]
+    fn test_gate_rejects_failed_check() {
+        let items = checklist(&["Stones secure", "Polished"]);
+        let latest = check(false, &["Stones secure"]);
+        assert!(!qc_gate_satisfied(&items, Some(&latest)));
+    }
+
+    #[test]
+    fn test_gate_accepts_passing_check() {
+        let items = checklist(&["Stones secure", "Polished"]);
+        let latest = check(true, &["Stones secure", "Polished"]);
+        assert!(qc_gate_satisfied(&items, Some(&latest)));
+    }
+
+    #[test]
+    fn test_gate_rejects_check_missing_new_item() {
+        // Checklist gained an item after the check was recorded
+        let items = checklist(&["Stones secure", "Polished", "Clasp tested"]);
+        let latest = check(true, &["Stones secure", "Polished"]);
+        assert!(!qc_gate_satisfied(&items, Some(&latest)));
+    }
+}
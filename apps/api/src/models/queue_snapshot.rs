@@ -0,0 +1,51 @@
+//! Queue snapshot model.
+//!
+//! A periodic count of open tickets per workboard lane, recorded by the
+//! snapshot task so the queue-trends report can show how the backlog moves
+//! over the week.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Open ticket counts at a point in time.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct QueueSnapshot {
+    pub snapshot_id: Uuid,
+    pub taken_at: DateTime<Utc>,
+    pub intake: i32,
+    pub in_progress: i32,
+    pub waiting_on_parts: i32,
+    pub ready_for_pickup: i32,
+    /// Open tickets past their promise date
+    pub overdue: i32,
+    /// Open rush tickets
+    pub rush: i32,
+}
+
+impl QueueSnapshot {
+    /// Open tickets across all lanes.
+    pub fn total(&self) -> i32 {
+        self.intake + self.in_progress + self.waiting_on_parts + self.ready_for_pickup
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_total_sums_lanes_only() {
+        let snapshot = QueueSnapshot {
+            snapshot_id: Uuid::new_v4(),
+            taken_at: Utc::now(),
+            intake: 3,
+            in_progress: 5,
+            waiting_on_parts: 1,
+            ready_for_pickup: 2,
+            overdue: 4,
+            rush: 2,
+        };
+        assert_eq!(snapshot.total(), 11);
+    }
+}
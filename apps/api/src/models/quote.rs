@@ -0,0 +1,141 @@
+//! Quote approval model.
+//!
+//! A ticket's quote moves from draft to sent to approved or declined. Each
+//! step is recorded as a quote event with the channel it went through and
+//! the employee who recorded it.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// Where a ticket's quote stands with the customer, matching the database type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "quote_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteStatus {
+    /// Not yet given to the customer, or changed since
+    #[default]
+    Draft,
+    /// Given to the customer, awaiting a decision
+    Sent,
+    /// Accepted by the customer
+    Approved,
+    /// Turned down by the customer
+    Declined,
+}
+
+impl QuoteStatus {
+    /// Status name as used in the API (e.g., `approved`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Sent => "sent",
+            Self::Approved => "approved",
+            Self::Declined => "declined",
+        }
+    }
+
+    /// Whether the quote can move to `next`.
+    ///
+    /// A quote can be (re)sent or approved until it is approved, and declined
+    /// while it is pending. A customer who declined can still approve later.
+    pub fn can_transition_to(self, next: QuoteStatus) -> bool {
+        match next {
+            Self::Sent | Self::Approved => self != Self::Approved,
+            Self::Declined => matches!(self, Self::Draft | Self::Sent),
+            Self::Draft => false,
+        }
+    }
+}
+
+/// How the quote reached the customer or their decision came back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "quote_channel", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum QuoteChannel {
+    InPerson,
+    Phone,
+    Sms,
+    Email,
+}
+
+/// A step in a ticket's quote approval, with the recording employee's name.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct QuoteEvent {
+    pub event_id: Uuid,
+    pub ticket_id: Uuid,
+    /// Status the quote moved to
+    pub status: QuoteStatus,
+    /// Quote amount at the time
+    pub quote_amount: Decimal,
+    pub channel: QuoteChannel,
+    /// Person who approved or declined (None for sends)
+    pub decided_by: Option<String>,
+    pub notes: Option<String>,
+    pub recorded_by: Uuid,
+    pub recorded_by_name: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Input for recording a quote step.
+#[derive(Debug, Clone)]
+pub struct CreateQuoteEvent {
+    pub ticket_id: Uuid,
+    pub status: QuoteStatus,
+    pub quote_amount: Decimal,
+    pub channel: QuoteChannel,
+    pub decided_by: Option<String>,
+    pub notes: Option<String>,
+    pub recorded_by: Uuid,
+}
+
+/// Returns true if a quote needs the customer's approval before work starts.
+///
+/// Applies when the store has an approval threshold and the quote is above it.
+pub fn quote_approval_required(quote_amount: Option<Decimal>, threshold: Option<Decimal>) -> bool {
+    matches!((quote_amount, threshold), (Some(quote), Some(threshold)) if quote > threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_status_transitions() {
+        use QuoteStatus::*;
+        assert!(Draft.can_transition_to(Sent));
+        assert!(Draft.can_transition_to(Approved));
+        assert!(Sent.can_transition_to(Sent));
+        assert!(Sent.can_transition_to(Declined));
+        assert!(Declined.can_transition_to(Approved));
+        assert!(!Declined.can_transition_to(Declined));
+        assert!(!Approved.can_transition_to(Sent));
+        assert!(!Approved.can_transition_to(Declined));
+        assert!(!Sent.can_transition_to(Draft));
+    }
+
+    #[test]
+    fn test_quote_approval_required() {
+        let threshold = Some(Decimal::new(500, 0));
+        assert!(!quote_approval_required(
+            Some(Decimal::new(500, 0)),
+            threshold
+        ));
+        assert!(quote_approval_required(
+            Some(Decimal::new(501, 0)),
+            threshold
+        ));
+        assert!(!quote_approval_required(None, threshold));
+        assert!(!quote_approval_required(Some(Decimal::new(9999, 0)), None));
+    }
+
+    #[test]
+    fn test_quote_channel_serialization() {
+        let json = serde_json::to_string(&QuoteChannel::InPerson).unwrap();
+        assert_eq!(json, "\"in_person\"");
+        let parsed: QuoteStatus = serde_json::from_str("\"declined\"").unwrap();
+        assert_eq!(parsed, QuoteStatus::Declined);
+    }
+}
@@ -0,0 +1,77 @@
+//! Failed authentication attempts per client, for rate limit backoff.
+
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Consecutive failed attempts from a client IP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::FromRow)]
+pub struct FailureRecord {
+    /// Number of consecutive failures
+    pub failure_count: i32,
+    /// Time of last failure
+    pub last_failure_at: DateTime<Utc>,
+}
+
+impl FailureRecord {
+    /// Calculate backoff duration based on failure count.
+    /// - 1st failure: 0 seconds (immediate retry)
+    /// - 2nd failure: 5 seconds
+    /// - 3rd failure: 30 seconds
+    /// - 4th+ failure: 5 minutes
+    pub fn backoff_duration(&self) -> Duration {
+        match self.failure_count {
+            i32::MIN..=1 => Duration::from_secs(0),
+            2 => Duration::from_secs(5),
+            3 => Duration::from_secs(30),
+            _ => Duration::from_secs(300), // 5 minutes
+        }
+    }
+
+    /// Seconds of backoff left at `now`, rounded up; None when not in backoff.
+    pub fn remaining_backoff_secs(&self, now: DateTime<Utc>) -> Option<u64> {
+        if self.failure_count < 2 {
+            return None;
+        }
+        let elapsed = (now - self.last_failure_at).to_std().unwrap_or_default();
+        let backoff = self.backoff_duration();
+        (elapsed < backoff).then(|| (backoff - elapsed).as_secs() + 1) // Round up
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(failure_count: i32, last_failure_at: DateTime<Utc>) -> FailureRecord {
+        FailureRecord {
+            failure_count,
+            last_failure_at,
+        }
+    }
+
+    #[test]
+    fn test_backoff_durations() {
+        let now = Utc::now();
+        assert_eq!(record(0, now).backoff_duration(), Duration::from_secs(0));
+        assert_eq!(record(1, now).backoff_duration(), Duration::from_secs(0));
+        assert_eq!(record(2, now).backoff_duration(), Duration::from_secs(5));
+        assert_eq!(record(3, now).backoff_duration(), Duration::from_secs(30));
+        assert_eq!(record(4, now).backoff_duration(), Duration::from_secs(300));
+        assert_eq!(record(10, now).backoff_duration(), Duration::from_secs(300));
+    }
+
+    #[test]
+    fn test_remaining_backoff() {
+        let now = Utc::now();
+        assert_eq!(record(1, now).remaining_backoff_secs(now), None);
+        assert_eq!(record(2, now).remaining_backoff_secs(now), Some(6));
+        assert_eq!(
+            record(3, now - chrono::Duration::seconds(10)).remaining_backoff_secs(now),
+            Some(21)
+        );
+        assert_eq!(
+            record(3, now - chrono::Duration::seconds(31)).remaining_backoff_secs(now),
+            None
+        );
+    }
+}
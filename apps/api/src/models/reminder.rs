@@ -0,0 +1,150 @@
+//! Promise-date reminder model.
+//!
+//! Each morning open tickets due today or past their promise date get a
+//! reminder, and the day's reminders go out as a digest. The same data is
+//! served on demand by the overdue report.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+use super::ticket::TicketStatus;
+
+/// Why a ticket was included in a day's reminders, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "reminder_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderKind {
+    /// Promise date is today
+    DueToday,
+    /// Promise date has passed
+    Overdue,
+}
+
+/// An open ticket due on or before the report date.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct DueTicket {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    pub customer_id: Uuid,
+    pub customer_name: String,
+    pub item_description: String,
+    pub status: TicketStatus,
+    pub is_rush: bool,
+    pub promise_date: NaiveDate,
+    /// Days past the promise date (0 when due today)
+    pub days_overdue: i32,
+    pub worked_by: Option<Uuid>,
+    pub worked_by_name: Option<String>,
+    /// Where reminder texts go; not shown in the report
+    #[serde(skip_serializing, default)]
+    pub worked_by_phone: Option<String>,
+}
+
+impl DueTicket {
+    /// Whether the ticket is due today or overdue.
+    pub fn kind(&self) -> ReminderKind {
+        if self.days_overdue > 0 {
+            ReminderKind::Overdue
+        } else {
+            ReminderKind::DueToday
+        }
+    }
+}
+
+/// Open tickets due today and overdue as of a date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverdueReport {
+    pub as_of: NaiveDate,
+    /// Most overdue first
+    pub overdue: Vec<DueTicket>,
+    pub due_today: Vec<DueTicket>,
+}
+
+impl OverdueReport {
+    /// Split due tickets into overdue and due today, keeping their order.
+    pub fn new(as_of: NaiveDate, tickets: Vec<DueTicket>) -> Self {
+        let (overdue, due_today) = tickets
+            .into_iter()
+            .partition(|ticket| ticket.kind() == ReminderKind::Overdue);
+        Self {
+            as_of,
+            overdue,
+            due_today,
+        }
+    }
+
+    /// Whether nothing is due.
+    pub fn is_empty(&self) -> bool {
+        self.overdue.is_empty() && self.due_today.is_empty()
+    }
+
+    /// Every due ticket, overdue first.
+    pub fn tickets(&self) -> impl Iterator<Item = &DueTicket> {
+        self.overdue.iter().chain(&self.due_today)
+    }
+}
+
+/// A day's reminder run.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct OverdueDigest {
+    pub digest_date: NaiveDate,
+    pub due_today: i32,
+    pub overdue: i32,
+    /// Whether the digest email was accepted by the provider
+    pub email_sent: bool,
+    /// Texts accepted by the provider
+    pub sms_sent: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn due(code: &str, days_overdue: i32) -> DueTicket {
+        DueTicket {
+            ticket_id: Uuid::new_v4(),
+            friendly_code: code.to_string(),
+            customer_id: Uuid::new_v4(),
+            customer_name: "Jane Doe".to_string(),
+            item_description: "Gold ring".to_string(),
+            status: TicketStatus::InProgress,
+            is_rush: false,
+            promise_date: NaiveDate::from_ymd_opt(2024, 3, 10).unwrap(),
+            days_overdue,
+            worked_by: None,
+            worked_by_name: None,
+            worked_by_phone: Some("555-0100".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_overdue_report_splits_by_kind() {
+        let as_of = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let report = OverdueReport::new(
+            as_of,
+            vec![due("JR-0001", 5), due("JR-0002", 1), due("JR-0003", 0)],
+        );
+        assert_eq!(report.overdue.len(), 2);
+        assert_eq!(report.due_today[0].friendly_code, "JR-0003");
+        assert_eq!(report.due_today[0].kind(), ReminderKind::DueToday);
+        assert_eq!(
+            report
+                .tickets()
+                .map(|t| t.friendly_code.as_str())
+                .collect::<Vec<_>>(),
+            ["JR-0001", "JR-0002", "JR-0003"]
+        );
+        assert!(!report.is_empty());
+        assert!(OverdueReport::new(as_of, Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_due_ticket_hides_phone() {
+        let json = serde_json::to_value(due("JR-0001", 2)).unwrap();
+        assert!(json.get("worked_by_phone").is_none());
+        assert_eq!(json["days_overdue"], 2);
+    }
+}
@@ -0,0 +1,452 @@
+//! Report models.
+//!
+//! Aggregated views over tickets used by the `/reports` endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::defect::DefectReason;
+use super::movement::TicketMovementEntry;
+use super::payment::PaymentMethod;
+use super::queue_snapshot::QueueSnapshot;
+
+/// Bucket size for time-series report data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportInterval {
+    Day,
+    Week,
+    #[default]
+    Month,
+}
+
+impl ReportInterval {
+    /// Returns the PostgreSQL `date_trunc` field name for this interval.
+    pub fn as_date_trunc(&self) -> &'static str {
+        match self {
+            ReportInterval::Day => "day",
+            ReportInterval::Week => "week",
+            ReportInterval::Month => "month",
+        }
+    }
+}
+
+/// Ticket, rework, and defect counts for a report grouping.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct QualityCounts {
+    /// Tickets created in the report window
+    pub tickets: i64,
+    /// Tickets with at least one recorded defect
+    pub reworked_tickets: i64,
+    /// Total defects recorded against those tickets
+    pub defects: i64,
+    /// reworked_tickets / tickets (0 when there are no tickets)
+    pub rework_rate: f64,
+}
+
+/// Quality breakdown for a single employee (the ticket's worked_by).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EmployeeQuality {
+    /// None for tickets with no assigned employee
+    pub employee_id: Option<Uuid>,
+    pub employee_name: Option<String>,
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub counts: QualityCounts,
+}
+
+/// Quality breakdown for a single item type.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ItemTypeQuality {
+    /// None for tickets with no item type
+    pub item_type: Option<String>,
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub counts: QualityCounts,
+}
+
+/// Defect count for a single reason code.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ReasonQuality {
+    pub reason: DefectReason,
+    pub defects: i64,
+}
+
+/// Quality counts for a single time bucket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PeriodQuality {
+    pub period_start: DateTime<Utc>,
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub counts: QualityCounts,
+}
+
+/// Rework/defect report over a date range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QualityReport {
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub interval: ReportInterval,
+    pub overall: QualityCounts,
+    pub by_employee: Vec<EmployeeQuality>,
+    pub by_item_type: Vec<ItemTypeQuality>,
+    pub by_reason: Vec<ReasonQuality>,
+    pub over_time: Vec<PeriodQuality>,
+}
+
+/// Trade work and API usage for a single partner.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PartnerActivity {
+    pub partner_id: Uuid,
+    pub partner_name: String,
+    pub is_active: bool,
+    /// Tickets created for the partner in the report window
+    pub tickets: i64,
+    /// Of those, tickets submitted through the partner API
+    pub submitted_via_api: i64,
+    /// Partner tickets closed in the report window
+    pub closed_tickets: i64,
+    /// Sum of actual amounts on tickets closed in the report window
+    pub revenue: Decimal,
+    /// Partner API requests in the report window
+    pub api_requests: i64,
+    /// Of those, requests rejected by the partner's rate limit
+    pub rate_limited_requests: i64,
+}
+
+/// Partner trade-work report over a date range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartnerReport {
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub partners: Vec<PartnerActivity>,
+}
+
+/// How often a catalog service was picked, and what it earned.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ServiceUsage {
+    pub service_id: Uuid,
+    pub service_name: String,
+    pub is_active: bool,
+    /// Tickets taken in with the service in the report window
+    pub tickets: i64,
+    /// Sum of the service's price on those tickets at intake
+    pub quoted_total: Decimal,
+    /// Average price at intake (None when the service had no price)
+    pub average_price: Option<Decimal>,
+    /// Of those tickets, how many are closed
+    pub closed_tickets: i64,
+    /// Average actual amount of the closed tickets, all work included
+    pub average_actual_amount: Option<Decimal>,
+    /// Average days from intake to close for the closed tickets
+    pub average_turnaround_days: Option<f64>,
+}
+
+/// Service catalog report over a date range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceReport {
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub services: Vec<ServiceUsage>,
+}
+
+/// Closed-ticket revenue totals for a report grouping.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RevenueCounts {
+    /// Tickets closed in the report window
+    pub closed_tickets: i64,
+    /// Sum of their actual amounts
+    pub revenue: Decimal,
+    /// Average actual amount (0 when nothing closed)
+    pub average_ticket: Decimal,
+    /// Closed tickets marked rush
+    pub rush_tickets: i64,
+    /// Sum of actual amounts on rush tickets
+    pub rush_revenue: Decimal,
+    /// Rush surcharges included in the quotes of closed tickets
+    pub rush_surcharges: Decimal,
+    /// Cost of the materials used on closed tickets
+    pub material_cost: Decimal,
+    /// Revenue less material cost
+    pub gross_profit: Decimal,
+}
+
+/// Revenue totals for a single time bucket (by close time).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PeriodRevenue {
+    pub period_start: DateTime<Utc>,
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub counts: RevenueCounts,
+}
+
+/// Revenue report over a date range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevenueReport {
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub interval: ReportInterval,
+    pub overall: RevenueCounts,
+    pub over_time: Vec<PeriodRevenue>,
+}
+
+/// Intake and completion totals for a report grouping.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ThroughputCounts {
+    /// Tickets created in the report window
+    pub tickets_received: i64,
+    /// Of those, tickets marked rush
+    pub rush_received: i64,
+    /// Tickets closed in the report window
+    pub tickets_closed: i64,
+    /// Of those, tickets marked rush
+    pub rush_closed: i64,
+    /// Average days from intake to close for closed tickets (None when
+    /// nothing closed)
+    pub average_turnaround_days: Option<f64>,
+    /// Average turnaround for closed rush tickets
+    pub rush_average_turnaround_days: Option<f64>,
+}
+
+/// Throughput totals for a single time bucket.
+///
+/// Received tickets count by creation time and closed tickets by close time.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PeriodThroughput {
+    pub period_start: DateTime<Utc>,
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub counts: ThroughputCounts,
+}
+
+/// Throughput report over a date range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThroughputReport {
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub interval: ReportInterval,
+    pub overall: ThroughputCounts,
+    pub over_time: Vec<PeriodThroughput>,
+}
+
+/// Ticket attribution and status activity for a single employee.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EmployeeProductivity {
+    pub employee_id: Uuid,
+    pub employee_name: String,
+    pub is_active: bool,
+    /// Tickets the employee took in during the report window
+    pub tickets_taken_in: i64,
+    /// Tickets the employee worked that first became ready for pickup in
+    /// the report window
+    pub tickets_worked: i64,
+    /// Tickets the employee closed in the report window
+    pub tickets_closed: i64,
+    /// Status changes the employee made in the report window
+    pub status_changes: i64,
+    /// Average days from intake to first ready_for_pickup for the tickets
+    /// counted in tickets_worked (None when there are none)
+    pub average_days_to_ready: Option<f64>,
+    /// Hours clocked in on tickets, for entries started in the report
+    /// window (running entries count up to now)
+    pub bench_hours: f64,
+    /// Tickets those hours were spent on
+    pub tickets_timed: i64,
+    /// bench_hours / tickets_timed (None when there are none)
+    pub average_bench_hours: Option<f64>,
+}
+
+/// Employee productivity report over a date range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmployeeReport {
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub employees: Vec<EmployeeProductivity>,
+}
+
+/// Average open ticket counts over the queue snapshots in a grouping.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct QueueTrendCounts {
+    /// Snapshots the averages are taken over
+    pub snapshots: i64,
+    pub intake: f64,
+    pub in_progress: f64,
+    pub waiting_on_parts: f64,
+    pub ready_for_pickup: f64,
+    pub overdue: f64,
+    pub rush: f64,
+    /// Average open tickets across all lanes
+    pub total: f64,
+    /// Largest open ticket count in a single snapshot
+    pub peak_total: i32,
+}
+
+/// Queue averages for a single time bucket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PeriodQueueTrend {
+    pub period_start: DateTime<Utc>,
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub counts: QueueTrendCounts,
+}
+
+/// Queue averages for a day of the week.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WeekdayQueueTrend {
+    /// ISO day of the week (1 = Monday, 7 = Sunday), in UTC
+    pub weekday: i32,
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub counts: QueueTrendCounts,
+}
+
+/// Queue trend report over a date range, built from queue snapshots.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueueTrendReport {
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    pub interval: ReportInterval,
+    /// Most recent snapshot, regardless of the range (None before the first)
+    pub latest: Option<QueueSnapshot>,
+    pub over_time: Vec<PeriodQueueTrend>,
+    /// Only days of the week with snapshots in the range are listed
+    pub by_weekday: Vec<WeekdayQueueTrend>,
+}
+
+/// Location audit over a date range: items out at benches now, and the
+/// movements in and out of storage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationAuditReport {
+    pub from_date: NaiveDate,
+    pub to_date: NaiveDate,
+    /// Location the report covers; None for every location
+    pub location_id: Option<Uuid>,
+    /// Open tickets out at a bench right now, longest out first, each with
+    /// the movement that took it there
+    pub checked_out: Vec<TicketMovementEntry>,
+    /// Movements in the range, oldest first
+    pub movements: Vec<TicketMovementEntry>,
+}
+
+/// A ticket listed on the daily closeout.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CloseoutTicketEntry {
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    pub customer_name: String,
+    pub item_description: String,
+    pub is_rush: bool,
+    pub quote_amount: Option<Decimal>,
+    pub actual_amount: Option<Decimal>,
+    /// Payments taken against the ticket so far
+    pub total_paid: Decimal,
+}
+
+/// Payments taken by one method on the closeout date.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PaymentMethodTotal {
+    pub method: PaymentMethod,
+    pub payments: i64,
+    pub total: Decimal,
+}
+
+/// A store's end-of-day summary for one date.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyCloseoutReport {
+    pub date: NaiveDate,
+    /// The store's time zone, which the day runs midnight to midnight in
+    pub timezone: String,
+    pub store_id: Uuid,
+    pub store_name: String,
+    /// Tickets received that day, in intake order
+    pub taken_in: Vec<CloseoutTicketEntry>,
+    /// Tickets closed that day, in close order
+    pub closed: Vec<CloseoutTicketEntry>,
+    /// Sum of the closed tickets' actual amounts
+    pub closed_revenue: Decimal,
+    /// Payments received that day, one row per method
+    pub payments_by_method: Vec<PaymentMethodTotal>,
+    pub payments_total: Decimal,
+    /// Tickets waiting for pickup at the end of the day, oldest promise first
+    pub ready_for_pickup: Vec<CloseoutTicketEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_interval_default_is_month() {
+        assert_eq!(ReportInterval::default(), ReportInterval::Month);
+    }
+
+    #[test]
+    fn test_report_interval_deserialize() {
+        let interval: ReportInterval = serde_json::from_str("\"week\"").unwrap();
+        assert_eq!(interval, ReportInterval::Week);
+        assert_eq!(interval.as_date_trunc(), "week");
+    }
+
+    #[test]
+    fn test_employee_quality_flattens_counts() {
+        let row = EmployeeQuality {
+            employee_id: None,
+            employee_name: None,
+            counts: QualityCounts {
+                tickets: 4,
+                reworked_tickets: 1,
+                defects: 2,
+                rework_rate: 0.25,
+            },
+        };
+        let json = serde_json::to_value(&row).unwrap();
+        assert_eq!(json["tickets"], 4);
+        assert_eq!(json["rework_rate"], 0.25);
+        assert!(json.get("counts").is_none());
+    }
+
+    #[test]
+    fn test_period_throughput_flattens_counts() {
+        let row = PeriodThroughput {
+            period_start: Utc::now(),
+            counts: ThroughputCounts {
+                tickets_received: 3,
+                rush_received: 1,
+                tickets_closed: 2,
+                rush_closed: 0,
+                average_turnaround_days: Some(4.5),
+                rush_average_turnaround_days: None,
+            },
+        };
+        let json = serde_json::to_value(&row).unwrap();
+        assert_eq!(json["tickets_closed"], 2);
+        assert_eq!(json["average_turnaround_days"], 4.5);
+        assert!(json["rush_average_turnaround_days"].is_null());
+        assert!(json.get("counts").is_none());
+    }
+
+    #[test]
+    fn test_weekday_queue_trend_flattens_counts() {
+        let row = WeekdayQueueTrend {
+            weekday: 1,
+            counts: QueueTrendCounts {
+                snapshots: 2,
+                intake: 1.5,
+                in_progress: 4.0,
+                waiting_on_parts: 0.0,
+                ready_for_pickup: 2.5,
+                overdue: 1.0,
+                rush: 0.5,
+                total: 8.0,
+                peak_total: 9,
+            },
+        };
+        let json = serde_json::to_value(&row).unwrap();
+        assert_eq!(json["weekday"], 1);
+        assert_eq!(json["intake"], 1.5);
+        assert_eq!(json["peak_total"], 9);
+        assert!(json.get("counts").is_none());
+    }
+}
@@ -0,0 +1,189 @@
+//! Custom report definition model.
+//!
+//! A saved report groups tickets by a few dimensions and computes measures
+//! for each group. Dimensions and measures come from fixed lists, so every
+//! report compiles from known SQL fragments.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// Maximum number of dimensions in one report.
+pub const MAX_REPORT_DIMENSIONS: usize = 3;
+
+/// What a custom report groups tickets by, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "report_dimension", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportDimension {
+    /// Current ticket status
+    Status,
+    /// Employee who took the ticket in
+    Employee,
+    /// Ticket item type
+    ItemType,
+    /// Month the ticket was taken in (YYYY-MM)
+    Month,
+}
+
+impl ReportDimension {
+    pub const ALL: [ReportDimension; 4] = [
+        ReportDimension::Status,
+        ReportDimension::Employee,
+        ReportDimension::ItemType,
+        ReportDimension::Month,
+    ];
+
+    /// Column name in report output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportDimension::Status => "status",
+            ReportDimension::Employee => "employee",
+            ReportDimension::ItemType => "item_type",
+            ReportDimension::Month => "month",
+        }
+    }
+
+    /// Parse a dimension name.
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.as_str() == name)
+    }
+}
+
+/// What a custom report computes per group, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "report_measure", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReportMeasure {
+    /// Tickets taken in
+    Count,
+    /// Sum of actual amounts on closed tickets
+    Revenue,
+    /// Average days from intake to close, for closed tickets
+    AvgTurnaroundDays,
+}
+
+impl ReportMeasure {
+    pub const ALL: [ReportMeasure; 3] = [
+        ReportMeasure::Count,
+        ReportMeasure::Revenue,
+        ReportMeasure::AvgTurnaroundDays,
+    ];
+
+    /// Column name in report output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportMeasure::Count => "count",
+            ReportMeasure::Revenue => "revenue",
+            ReportMeasure::AvgTurnaroundDays => "avg_turnaround_days",
+        }
+    }
+
+    /// Parse a measure name.
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.as_str() == name)
+    }
+}
+
+/// A saved custom report.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ReportDefinition {
+    pub report_id: Uuid,
+    pub name: String,
+    pub dimensions: Vec<ReportDimension>,
+    pub measures: Vec<ReportMeasure>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReportDefinition {
+    /// Output column names: dimensions, then measures.
+    pub fn columns(&self) -> Vec<&'static str> {
+        self.dimensions
+            .iter()
+            .map(ReportDimension::as_str)
+            .chain(self.measures.iter().map(ReportMeasure::as_str))
+            .collect()
+    }
+}
+
+/// Input for creating or replacing a report definition.
+#[derive(Debug, Clone)]
+pub struct CreateReportDefinition {
+    pub name: String,
+    pub dimensions: Vec<ReportDimension>,
+    pub measures: Vec<ReportMeasure>,
+}
+
+/// One value in a custom report row.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ReportCell {
+    /// A dimension value (None for tickets without one, e.g. no item type)
+    Text(Option<String>),
+    Count(i64),
+    Amount(Decimal),
+    /// None when no ticket in the group was closed
+    Days(Option<f64>),
+}
+
+impl ReportCell {
+    /// The value as written in CSV output (empty for None).
+    pub fn to_csv_field(&self) -> String {
+        match self {
+            ReportCell::Text(value) => value.clone().unwrap_or_default(),
+            ReportCell::Count(value) => value.to_string(),
+            ReportCell::Amount(value) => value.to_string(),
+            ReportCell::Days(value) => value.map(|d| format!("{:.2}", d)).unwrap_or_default(),
+        }
+    }
+}
+
+/// A custom report run over a date range.
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomReport {
+    pub report: ReportDefinition,
+    /// Tickets taken in on or after this date
+    pub from_date: NaiveDate,
+    /// Tickets taken in on or before this date
+    pub to_date: NaiveDate,
+    pub columns: Vec<&'static str>,
+    /// One row per group, with values in `columns` order
+    pub rows: Vec<Vec<ReportCell>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_names_round_trip() {
+        for dimension in ReportDimension::ALL {
+            assert_eq!(ReportDimension::parse(dimension.as_str()), Some(dimension));
+        }
+        for measure in ReportMeasure::ALL {
+            assert_eq!(ReportMeasure::parse(measure.as_str()), Some(measure));
+        }
+        assert_eq!(ReportDimension::parse("customer_id; DROP TABLE"), None);
+        assert_eq!(ReportMeasure::parse("sum"), None);
+    }
+
+    #[test]
+    fn test_report_cell_serializes_plain_values() {
+        let row = vec![
+            ReportCell::Text(Some("ring".to_string())),
+            ReportCell::Text(None),
+            ReportCell::Count(3),
+            ReportCell::Amount(Decimal::new(12550, 2)),
+            ReportCell::Days(None),
+        ];
+        assert_eq!(
+            serde_json::to_value(&row).unwrap(),
+            serde_json::json!(["ring", null, 3, "125.50", null])
+        );
+        assert_eq!(row[3].to_csv_field(), "125.50");
+        assert_eq!(ReportCell::Days(Some(2.0)).to_csv_field(), "2.00");
+    }
+}
@@ -0,0 +1,125 @@
+//! Request log model.
+//!
+//! Redacted request/response captures recorded while debug capture mode
+//! is enabled by an admin.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A captured request/response pair.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RequestLog {
+    pub log_id: Uuid,
+    pub method: String,
+    pub path: String,
+    pub query: Option<String>,
+    pub status: i32,
+    pub duration_ms: i32,
+    pub client_ip: Option<String>,
+    pub request_headers: String,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Input for recording a request log.
+#[derive(Debug, Clone)]
+pub struct CreateRequestLog {
+    pub method: String,
+    pub path: String,
+    pub query: Option<String>,
+    pub status: i32,
+    pub duration_ms: i32,
+    pub client_ip: Option<String>,
+    pub request_headers: String,
+    pub request_body: Option<String>,
+    pub response_body: Option<String>,
+}
+
+/// Debug capture toggle stored on store settings.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, sqlx::FromRow)]
+pub struct DebugCaptureConfig {
+    /// Capture is active until this time (None = disabled)
+    #[sqlx(rename = "debug_capture_until")]
+    pub capture_until: Option<DateTime<Utc>>,
+    /// Path prefixes to capture (empty = every API route)
+    #[sqlx(rename = "debug_capture_routes")]
+    pub routes: Vec<String>,
+}
+
+impl DebugCaptureConfig {
+    /// Returns true if capture has not yet expired.
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.capture_until.is_some_and(|until| until > now)
+    }
+
+    /// Returns true if a request to `path` should be captured at `now`.
+    ///
+    /// Only API routes are eligible. The admin debug endpoints themselves
+    /// are never captured so viewing logs doesn't generate more logs.
+    pub fn should_capture(&self, path: &str, now: DateTime<Utc>) -> bool {
+        if !self.is_active(now) || !path.starts_with("/api/v1/") {
+            return false;
+        }
+        if path.starts_with("/api/v1/admin/debug-capture")
+            || path.starts_with("/api/v1/admin/request-logs")
+        {
+            return false;
+        }
+
+        self.routes.is_empty() || self.routes.iter().any(|prefix| path.starts_with(prefix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn active(routes: &[&str]) -> DebugCaptureConfig {
+        DebugCaptureConfig {
+            capture_until: Some(Utc::now() + Duration::hours(1)),
+            routes: routes.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_capture_disabled_by_default() {
+        let config = DebugCaptureConfig::default();
+        assert!(!config.is_active(Utc::now()));
+        assert!(!config.should_capture("/api/v1/tickets", Utc::now()));
+    }
+
+    #[test]
+    fn test_capture_expires() {
+        let config = DebugCaptureConfig {
+            capture_until: Some(Utc::now() - Duration::minutes(1)),
+            routes: vec![],
+        };
+        assert!(!config.is_active(Utc::now()));
+    }
+
+    #[test]
+    fn test_capture_all_api_routes_when_no_prefixes() {
+        let config = active(&[]);
+        assert!(config.should_capture("/api/v1/tickets", Utc::now()));
+        assert!(config.should_capture("/api/v1/queue", Utc::now()));
+        assert!(!config.should_capture("/health", Utc::now()));
+    }
+
+    #[test]
+    fn test_capture_selected_routes_only() {
+        let config = active(&["/api/v1/tickets"]);
+        assert!(config.should_capture("/api/v1/tickets/abc/status", Utc::now()));
+        assert!(!config.should_capture("/api/v1/queue", Utc::now()));
+    }
+
+    #[test]
+    fn test_capture_skips_debug_endpoints() {
+        let config = active(&["/api/v1/admin"]);
+        assert!(config.should_capture("/api/v1/admin/verify", Utc::now()));
+        assert!(!config.should_capture("/api/v1/admin/request-logs", Utc::now()));
+        assert!(!config.should_capture("/api/v1/admin/debug-capture", Utc::now()));
+    }
+}
@@ -0,0 +1,238 @@
+//! Rush pricing model.
+//!
+//! Admin-maintained surcharge tiers for rush jobs, chosen by lead time: the
+//! number of days from today to the promise date. Each tier adds a flat
+//! amount or a percentage of the base quote. The surcharge is recorded on the
+//! ticket as the part of its quote it accounts for.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// How a tier's amount is applied, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "rush_surcharge_kind", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RushSurchargeKind {
+    /// A fixed amount added to the quote
+    Flat,
+    /// A percentage of the base quote
+    Percent,
+}
+
+/// Surcharge tier for rush jobs.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RushSurchargeTier {
+    pub tier_id: Uuid,
+    /// Applies to promise dates at most this many days out (null = any
+    /// lead time, including rush jobs without a promise date)
+    pub max_lead_days: Option<i32>,
+    pub kind: RushSurchargeKind,
+    /// Flat amount, or percentage for `percent` tiers (25 = 25%)
+    pub amount: Decimal,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RushSurchargeTier {
+    /// Surcharge on a base quote, rounded to cents.
+    pub fn surcharge(&self, base_amount: Decimal) -> Decimal {
+        match self.kind {
+            RushSurchargeKind::Flat => self.amount,
+            RushSurchargeKind::Percent => {
+                (base_amount * self.amount / Decimal::ONE_HUNDRED).round_dp(2)
+            }
+        }
+    }
+}
+
+/// Input for a surcharge tier (tiers are replaced as a set).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRushSurchargeTier {
+    pub max_lead_days: Option<i32>,
+    pub kind: RushSurchargeKind,
+    pub amount: Decimal,
+}
+
+/// Days from `today` to the promise date (0 for dates already past).
+pub fn lead_time_days(promise_date: Option<NaiveDate>, today: NaiveDate) -> Option<i64> {
+    promise_date.map(|date| (date - today).num_days().max(0))
+}
+
+/// Pick the tier for a lead time.
+///
+/// The tier with the smallest `max_lead_days` covering the lead time wins;
+/// the open-ended tier applies when none does or there is no promise date.
+pub fn select_rush_tier(
+    tiers: &[RushSurchargeTier],
+    lead_days: Option<i64>,
+) -> Option<&RushSurchargeTier> {
+    let bounded = lead_days.and_then(|days| {
+        tiers
+            .iter()
+            .filter(|tier| tier.max_lead_days.is_some_and(|max| days <= i64::from(max)))
+            .min_by_key(|tier| tier.max_lead_days)
+    });
+
+    bounded.or_else(|| tiers.iter().find(|tier| tier.max_lead_days.is_none()))
+}
+
+/// One line of a quote breakdown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuoteLine {
+    pub label: String,
+    pub amount: Decimal,
+}
+
+/// A quote split into the work and any rush surcharge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuoteBreakdown {
+    pub base_amount: Decimal,
+    pub is_rush: bool,
+    /// Days until the promise date, when one is given
+    pub lead_time_days: Option<i64>,
+    /// Tier applied (None when not rush or no tier matches)
+    pub tier: Option<RushSurchargeTier>,
+    pub rush_surcharge: Decimal,
+    pub lines: Vec<QuoteLine>,
+    /// Base amount plus surcharge; send as the ticket's `quote_amount`
+    pub total: Decimal,
+}
+
+/// Apply the rush surcharge tiers to a base quote.
+pub fn quote_breakdown(
+    base_amount: Decimal,
+    is_rush: bool,
+    promise_date: Option<NaiveDate>,
+    today: NaiveDate,
+    tiers: &[RushSurchargeTier],
+) -> QuoteBreakdown {
+    let lead_time_days = lead_time_days(promise_date, today);
+    let tier = is_rush
+        .then(|| select_rush_tier(tiers, lead_time_days))
+        .flatten()
+        .cloned();
+    let rush_surcharge = tier
+        .as_ref()
+        .map_or(Decimal::ZERO, |tier| tier.surcharge(base_amount));
+
+    let mut lines = vec![QuoteLine {
+        label: "Work".to_string(),
+        amount: base_amount,
+    }];
+    if rush_surcharge > Decimal::ZERO {
+        lines.push(QuoteLine {
+            label: "Rush surcharge".to_string(),
+            amount: rush_surcharge,
+        });
+    }
+
+    QuoteBreakdown {
+        base_amount,
+        is_rush,
+        lead_time_days,
+        tier,
+        rush_surcharge,
+        lines,
+        total: base_amount + rush_surcharge,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(max_lead_days: Option<i32>, kind: RushSurchargeKind, amount: i64) -> RushSurchargeTier {
+        RushSurchargeTier {
+            tier_id: Uuid::new_v4(),
+            max_lead_days,
+            kind,
+            amount: Decimal::new(amount, 0),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    #[test]
+    fn test_surcharge_flat_and_percent() {
+        let base = "123.45".parse().unwrap();
+        assert_eq!(
+            tier(None, RushSurchargeKind::Flat, 20).surcharge(base),
+            Decimal::new(20, 0)
+        );
+        // 25% of 123.45 = 30.8625
+        assert_eq!(
+            tier(None, RushSurchargeKind::Percent, 25).surcharge(base),
+            "30.86".parse::<Decimal>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_lead_time_days() {
+        assert_eq!(lead_time_days(Some(date(5)), date(1)), Some(4));
+        assert_eq!(lead_time_days(Some(date(1)), date(5)), Some(0));
+        assert_eq!(lead_time_days(None, date(1)), None);
+    }
+
+    #[test]
+    fn test_select_rush_tier_prefers_tightest_covering_tier() {
+        let tiers = vec![
+            tier(Some(7), RushSurchargeKind::Percent, 10),
+            tier(None, RushSurchargeKind::Flat, 15),
+            tier(Some(2), RushSurchargeKind::Percent, 50),
+        ];
+
+        assert_eq!(
+            select_rush_tier(&tiers, Some(0)).unwrap().max_lead_days,
+            Some(2)
+        );
+        assert_eq!(
+            select_rush_tier(&tiers, Some(2)).unwrap().max_lead_days,
+            Some(2)
+        );
+        assert_eq!(
+            select_rush_tier(&tiers, Some(3)).unwrap().max_lead_days,
+            Some(7)
+        );
+        assert_eq!(
+            select_rush_tier(&tiers, Some(30)).unwrap().max_lead_days,
+            None
+        );
+        assert_eq!(select_rush_tier(&tiers, None).unwrap().max_lead_days, None);
+    }
+
+    #[test]
+    fn test_select_rush_tier_without_open_ended_tier() {
+        let tiers = vec![tier(Some(2), RushSurchargeKind::Flat, 20)];
+        assert!(select_rush_tier(&tiers, Some(3)).is_none());
+        assert!(select_rush_tier(&tiers, None).is_none());
+        assert!(select_rush_tier(&[], Some(0)).is_none());
+    }
+
+    #[test]
+    fn test_quote_breakdown_adds_surcharge_line() {
+        let tiers = vec![tier(Some(3), RushSurchargeKind::Percent, 50)];
+        let quote = quote_breakdown(Decimal::new(100, 0), true, Some(date(3)), date(1), &tiers);
+
+        assert_eq!(quote.lead_time_days, Some(2));
+        assert_eq!(quote.rush_surcharge, Decimal::new(50, 0));
+        assert_eq!(quote.total, Decimal::new(150, 0));
+        assert_eq!(quote.lines.len(), 2);
+        assert_eq!(quote.lines[1].label, "Rush surcharge");
+    }
+
+    #[test]
+    fn test_quote_breakdown_not_rush() {
+        let tiers = vec![tier(None, RushSurchargeKind::Flat, 25)];
+        let quote = quote_breakdown(Decimal::new(100, 0), false, None, date(1), &tiers);
+
+        assert!(quote.tier.is_none());
+        assert_eq!(quote.rush_surcharge, Decimal::ZERO);
+        assert_eq!(quote.total, Decimal::new(100, 0));
+        assert_eq!(quote.lines.len(), 1);
+    }
+}
@@ -0,0 +1,174 @@
+//! Service catalog model.
+//!
+//! Common bench work (ring sizing, prong re-tip, chain solder, ...) with a
+//! default price and turnaround. Services named at intake pre-fill the
+//! ticket's requested work, quote, and promise date, and are kept on the
+//! ticket with the price they had at the time.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::business_hours::BusinessCalendar;
+
+/// Maximum number of services on one ticket.
+pub const MAX_TICKET_SERVICES: usize = 20;
+
+/// A catalog service.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Service {
+    pub service_id: Uuid,
+    pub name: String,
+    /// Price quoted when the service is picked at intake
+    pub default_price: Option<Decimal>,
+    /// Days the work usually takes
+    pub turnaround_days: Option<i32>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for creating a service.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateService {
+    pub name: String,
+    #[serde(default)]
+    pub default_price: Option<Decimal>,
+    #[serde(default)]
+    pub turnaround_days: Option<i32>,
+}
+
+/// Input for updating a service.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateService {
+    pub name: Option<String>,
+    /// Explicit null removes the default price
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub default_price: Option<Option<Decimal>>,
+    /// Explicit null removes the turnaround
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub turnaround_days: Option<Option<i32>>,
+    pub is_active: Option<bool>,
+}
+
+/// A service on a ticket, with its price at intake.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketService {
+    pub service_id: Uuid,
+    pub name: String,
+    pub price: Option<Decimal>,
+}
+
+/// Intake defaults implied by the services picked for a ticket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceDefaults {
+    /// Service names, in the order picked
+    pub requested_work: String,
+    /// Sum of the priced services' defaults (None when none are priced)
+    pub quote_amount: Option<Decimal>,
+    /// Longest turnaround among the services
+    pub turnaround_days: Option<i32>,
+}
+
+impl ServiceDefaults {
+    /// Defaults for `services`, which must not be empty.
+    pub fn from_services(services: &[Service]) -> Self {
+        let requested_work = services
+            .iter()
+            .map(|service| service.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+        let quote_amount = services
+            .iter()
+            .filter_map(|service| service.default_price)
+            .reduce(|total, price| total + price);
+        let turnaround_days = services
+            .iter()
+            .filter_map(|service| service.turnaround_days)
+            .max();
+        Self {
+            requested_work,
+            quote_amount,
+            turnaround_days,
+        }
+    }
+
+    /// Promise date implied by the longest turnaround (or the store's rush
+    /// turnaround), counted in days the store is open.
+    pub fn promise_date(
+        &self,
+        calendar: &BusinessCalendar,
+        today: NaiveDate,
+        is_rush: bool,
+    ) -> Option<NaiveDate> {
+        calendar
+            .turnaround_days(self.turnaround_days, is_rush)
+            .map(|days| calendar.promise_date(today, days).date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service(name: &str, default_price: Option<i64>, turnaround_days: Option<i32>) -> Service {
+        Service {
+            service_id: Uuid::new_v4(),
+            name: name.to_string(),
+            default_price: default_price.map(|price| Decimal::new(price, 0)),
+            turnaround_days,
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_service_defaults() {
+        let defaults = ServiceDefaults::from_services(&[
+            service("Ring sizing", Some(40), Some(3)),
+            service("Prong re-tip", Some(25), Some(7)),
+            service("Cleaning", None, None),
+        ]);
+        assert_eq!(
+            defaults.requested_work,
+            "Ring sizing, Prong re-tip, Cleaning"
+        );
+        assert_eq!(defaults.quote_amount, Some(Decimal::new(65, 0)));
+        assert_eq!(defaults.turnaround_days, Some(7));
+
+        let calendar = BusinessCalendar::new(&[], &[], None);
+        let today = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+        assert_eq!(
+            defaults.promise_date(&calendar, today, false),
+            NaiveDate::from_ymd_opt(2024, 3, 8)
+        );
+    }
+
+    #[test]
+    fn test_service_defaults_unpriced() {
+        let defaults = ServiceDefaults::from_services(&[service("Cleaning", None, None)]);
+        assert_eq!(defaults.quote_amount, None);
+        let calendar = BusinessCalendar::new(&[], &[], None);
+        assert_eq!(
+            defaults.promise_date(&calendar, Utc::now().date_naive(), false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_update_service_nullable_fields() {
+        let input: UpdateService =
+            serde_json::from_str(r#"{"default_price": null, "is_active": false}"#).unwrap();
+        assert_eq!(input.default_price, Some(None));
+        assert!(input.turnaround_days.is_none());
+        assert_eq!(input.is_active, Some(false));
+    }
+}
@@ -0,0 +1,158 @@
+//! Settings change history model.
+//!
+//! Each settings update records the fields it changed so it can be
+//! reviewed and rolled back.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use super::store_settings::{SettingsSection, StoreSettingsPublic};
+
+/// A recorded settings change.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SettingsChange {
+    pub change_id: Uuid,
+    /// Store whose settings changed
+    pub store_id: Uuid,
+    /// Settings version produced by this change
+    pub version: i32,
+    /// Changed fields and their values before the change
+    pub old_values: Value,
+    /// Changed fields and their values after the change
+    pub new_values: Value,
+    pub changed_by: Option<Uuid>,
+    /// The change this one rolled back, if it was a rollback
+    pub rolled_back_change_id: Option<Uuid>,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// A settings change with the employee's name, for the history view.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct SettingsChangeEntry {
+    pub change_id: Uuid,
+    pub store_id: Uuid,
+    pub version: i32,
+    pub old_values: Value,
+    pub new_values: Value,
+    pub changed_by: Option<Uuid>,
+    pub changed_by_name: Option<String>,
+    pub rolled_back_change_id: Option<Uuid>,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Input for recording a settings change.
+#[derive(Debug, Clone)]
+pub struct CreateSettingsChange {
+    pub store_id: Uuid,
+    pub version: i32,
+    pub old_values: Value,
+    pub new_values: Value,
+    pub changed_by: Option<Uuid>,
+    pub rolled_back_change_id: Option<Uuid>,
+}
+
+/// The editable settings that differ between two snapshots.
+///
+/// Returns `(old_values, new_values)` keyed by field name, or None when
+/// nothing editable changed.
+pub fn settings_diff(
+    before: &StoreSettingsPublic,
+    after: &StoreSettingsPublic,
+) -> Option<(Value, Value)> {
+    let mut old_values = Map::new();
+    let mut new_values = Map::new();
+
+    for section in SettingsSection::ALL {
+        let (Value::Object(old), Value::Object(new)) = (section.view(before), section.view(after))
+        else {
+            continue;
+        };
+        for (field, old_value) in old {
+            let new_value = new.get(&field).cloned().unwrap_or(Value::Null);
+            if old_value != new_value {
+                old_values.insert(field.clone(), old_value);
+                new_values.insert(field, new_value);
+            }
+        }
+    }
+
+    if old_values.is_empty() {
+        None
+    } else {
+        Some((Value::Object(old_values), Value::Object(new_values)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> StoreSettingsPublic {
+        StoreSettingsPublic {
+            setting_id: Uuid::new_v4(),
+            store_name: "Test Store".to_string(),
+            store_phone: None,
+            store_address: None,
+            ticket_prefix: "JR".to_string(),
+            next_ticket_number: 1,
+            currency: "USD".to_string(),
+            max_photos_per_ticket: 10,
+            setup_complete: true,
+            setup_required: false,
+            min_pin_length: 6,
+            qc_checklist: vec![],
+            scope_ticket_visibility: false,
+            custody_value_threshold: None,
+            quote_approval_threshold: None,
+            notifications_enabled: true,
+            auto_archive_after_days: None,
+            restrict_item_types: false,
+            overdue_digest_email: None,
+            overdue_digest_sms: false,
+            overdue_digest_hour: 8,
+            receipt_logo_key: None,
+            receipt_footer_text: None,
+            receipt_show_prices: true,
+            label_width_mm: rust_decimal::Decimal::new(508, 1),
+            label_height_mm: rust_decimal::Decimal::new(254, 1),
+            receipt_format: Default::default(),
+            tax_rate: rust_decimal::Decimal::ZERO,
+            next_invoice_number: 1,
+            tax_inclusive: true,
+            ticket_number_padding: 4,
+            ticket_number_yearly_reset: false,
+            business_hours: vec![],
+            holidays: vec![],
+            rush_turnaround_days: None,
+            timezone: "UTC".to_string(),
+            calendar_feed_enabled: false,
+            version: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_settings_diff_only_changed_fields() {
+        let before = settings();
+        let mut after = settings();
+        after.ticket_prefix = "RJ".to_string();
+        after.auto_archive_after_days = Some(30);
+        after.next_ticket_number = 42;
+        after.version = 2;
+
+        let (old_values, new_values) = settings_diff(&before, &after).unwrap();
+        assert_eq!(
+            old_values,
+            serde_json::json!({"ticket_prefix": "JR", "auto_archive_after_days": null})
+        );
+        assert_eq!(
+            new_values,
+            serde_json::json!({"ticket_prefix": "RJ", "auto_archive_after_days": 30})
+        );
+
+        assert!(settings_diff(&before, &before).is_none());
+    }
+}
@@ -4,6 +4,7 @@
 //! during the repair process (e.g., safe drawers, workbenches, display cases).
 
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -11,9 +12,13 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct StorageLocation {
     pub location_id: Uuid,
+    /// Store the location is in; tickets stored there belong to it
+    pub store_id: Uuid,
     pub name: String,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
+    /// Open tickets the location holds before it is full (None = no limit)
+    pub capacity: Option<i32>,
 }
 
 /// Summary view of a storage location.
@@ -22,12 +27,26 @@ pub struct StorageLocationSummary {
     pub location_id: Uuid,
     pub name: String,
     pub is_active: bool,
+    /// Open tickets the location holds before it is full (None = no limit)
+    pub capacity: Option<i32>,
+    /// Open tickets stored there or in transit to it
+    pub open_tickets: i64,
+}
+
+impl StorageLocationSummary {
+    /// Returns true if the location has a capacity and has reached it.
+    pub fn is_full(&self) -> bool {
+        self.capacity
+            .is_some_and(|capacity| self.open_tickets >= i64::from(capacity))
+    }
 }
 
 /// Input for creating a new storage location.
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateStorageLocation {
     pub name: String,
+    #[serde(default)]
+    pub capacity: Option<i32>,
 }
 
 /// Input for updating a storage location.
@@ -35,12 +54,246 @@ pub struct CreateStorageLocation {
 pub struct UpdateStorageLocation {
     pub name: Option<String>,
     pub is_active: Option<bool>,
+    /// Explicit null removes the limit
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub capacity: Option<Option<i32>>,
+}
+
+/// Rule for suggesting a storage location during intake.
+///
+/// Matches when `item_type` equals the ticket's item type (case-insensitive)
+/// and the quote is at least `min_quote_amount`. Unset conditions always match.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct StorageLocationRule {
+    pub rule_id: Uuid,
+    pub item_type: Option<String>,
+    pub min_quote_amount: Option<Decimal>,
+    pub location_id: Uuid,
+    /// Higher priority rules are suggested first.
+    pub priority: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl StorageLocationRule {
+    /// Check whether this rule applies to an item.
+    pub fn matches(&self, item_type: Option<&str>, quote_amount: Option<Decimal>) -> bool {
+        let type_matches = match (&self.item_type, item_type) {
+            (None, _) => true,
+            (Some(rule_type), Some(item_type)) => rule_type.eq_ignore_ascii_case(item_type.trim()),
+            (Some(_), None) => false,
+        };
+        let quote_matches = match (self.min_quote_amount, quote_amount) {
+            (None, _) => true,
+            (Some(min), Some(quote)) => quote >= min,
+            (Some(_), None) => false,
+        };
+        type_matches && quote_matches
+    }
+}
+
+/// Input for a storage location rule (rules are replaced as a set).
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateStorageLocationRule {
+    pub item_type: Option<String>,
+    pub min_quote_amount: Option<Decimal>,
+    pub location_id: Uuid,
+    #[serde(default)]
+    pub priority: i32,
+}
+
+/// Active storage location with its current number of open tickets.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LocationOccupancy {
+    pub location_id: Uuid,
+    pub name: String,
+    pub open_tickets: i64,
+}
+
+/// Why a location was suggested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestionReason {
+    /// A storage rule matched the item.
+    Rule,
+    /// No rule matched; the location has the fewest open tickets.
+    LeastOccupied,
+}
+
+/// A suggested storage location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationSuggestion {
+    pub location_id: Uuid,
+    pub name: String,
+    pub open_tickets: i64,
+    pub reason: SuggestionReason,
+    /// The rule that produced this suggestion (if any).
+    pub rule_id: Option<Uuid>,
+}
+
+/// Rank active locations for an item, best suggestion first.
+///
+/// Locations from matching rules come first, by rule priority and then by
+/// fewest open tickets. Every other active location follows, least occupied
+/// first. Rules pointing at inactive locations are ignored.
+pub fn rank_locations(
+    item_type: Option<&str>,
+    quote_amount: Option<Decimal>,
+    rules: &[StorageLocationRule],
+    locations: &[LocationOccupancy],
+) -> Vec<LocationSuggestion> {
+    let mut matched: Vec<(&StorageLocationRule, &LocationOccupancy)> = rules
+        .iter()
+        .filter(|rule| rule.matches(item_type, quote_amount))
+        .filter_map(|rule| {
+            locations
+                .iter()
+                .find(|loc| loc.location_id == rule.location_id)
+                .map(|loc| (rule, loc))
+        })
+        .collect();
+    matched.sort_by(|(a_rule, a_loc), (b_rule, b_loc)| {
+        b_rule
+            .priority
+            .cmp(&a_rule.priority)
+            .then(a_loc.open_tickets.cmp(&b_loc.open_tickets))
+    });
+
+    let mut suggestions: Vec<LocationSuggestion> = Vec::new();
+    for (rule, loc) in matched {
+        if suggestions.iter().any(|s| s.location_id == loc.location_id) {
+            continue;
+        }
+        suggestions.push(LocationSuggestion {
+            location_id: loc.location_id,
+            name: loc.name.clone(),
+            open_tickets: loc.open_tickets,
+            reason: SuggestionReason::Rule,
+            rule_id: Some(rule.rule_id),
+        });
+    }
+
+    let mut remaining: Vec<&LocationOccupancy> = locations
+        .iter()
+        .filter(|loc| !suggestions.iter().any(|s| s.location_id == loc.location_id))
+        .collect();
+    remaining.sort_by(|a, b| {
+        a.open_tickets
+            .cmp(&b.open_tickets)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    suggestions.extend(remaining.into_iter().map(|loc| LocationSuggestion {
+        location_id: loc.location_id,
+        name: loc.name.clone(),
+        open_tickets: loc.open_tickets,
+        reason: SuggestionReason::LeastOccupied,
+        rule_id: None,
+    }));
+
+    suggestions
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn location(n: u128, name: &str, open_tickets: i64) -> LocationOccupancy {
+        LocationOccupancy {
+            location_id: Uuid::from_u128(n),
+            name: name.to_string(),
+            open_tickets,
+        }
+    }
+
+    fn rule(
+        location: u128,
+        item_type: Option<&str>,
+        min_quote_amount: Option<Decimal>,
+        priority: i32,
+    ) -> StorageLocationRule {
+        StorageLocationRule {
+            rule_id: Uuid::new_v4(),
+            item_type: item_type.map(String::from),
+            min_quote_amount,
+            location_id: Uuid::from_u128(location),
+            priority,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_rule_matches() {
+        let watch = rule(1, Some("Watch"), None, 0);
+        assert!(watch.matches(Some("watch"), None));
+        assert!(!watch.matches(Some("ring"), None));
+        assert!(!watch.matches(None, None));
+
+        let high_value = rule(2, None, Some(Decimal::new(1000, 0)), 0);
+        assert!(high_value.matches(Some("ring"), Some(Decimal::new(1500, 0))));
+        assert!(high_value.matches(None, Some(Decimal::new(1000, 0))));
+        assert!(!high_value.matches(Some("ring"), Some(Decimal::new(999, 0))));
+        assert!(!high_value.matches(Some("ring"), None));
+    }
+
+    #[test]
+    fn test_rank_locations_prefers_priority_then_occupancy() {
+        let locations = vec![
+            location(1, "Drawer W", 4),
+            location(2, "Safe", 9),
+            location(3, "Bench A", 1),
+            location(4, "Bench B", 0),
+        ];
+        let rules = vec![
+            rule(1, Some("watch"), None, 0),
+            rule(2, None, Some(Decimal::new(1000, 0)), 10),
+        ];
+
+        let ranked = rank_locations(
+            Some("watch"),
+            Some(Decimal::new(2500, 0)),
+            &rules,
+            &locations,
+        );
+        let names: Vec<_> = ranked.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Safe", "Drawer W", "Bench B", "Bench A"]);
+        assert_eq!(ranked[0].reason, SuggestionReason::Rule);
+        assert_eq!(ranked[0].rule_id, Some(rules[1].rule_id));
+        assert_eq!(ranked[2].reason, SuggestionReason::LeastOccupied);
+        assert!(ranked[2].rule_id.is_none());
+    }
+
+    #[test]
+    fn test_rank_locations_without_rules_uses_occupancy() {
+        let locations = vec![
+            location(1, "B", 2),
+            location(2, "A", 2),
+            location(3, "C", 0),
+        ];
+        let ranked = rank_locations(Some("ring"), None, &[], &locations);
+        let names: Vec<_> = ranked.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["C", "A", "B"]);
+    }
+
+    #[test]
+    fn test_rank_locations_skips_inactive_rule_locations() {
+        let locations = vec![location(1, "Bench", 0)];
+        let rules = vec![rule(99, Some("watch"), None, 5)];
+        let ranked = rank_locations(Some("watch"), None, &rules, &locations);
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].reason, SuggestionReason::LeastOccupied);
+    }
+
+    #[test]
+    fn test_create_rule_priority_defaults_to_zero() {
+        let json =
+            r#"{"item_type": "watch", "location_id": "550e8400-e29b-41d4-a716-446655440000"}"#;
+        let input: CreateStorageLocationRule = serde_json::from_str(json).unwrap();
+        assert_eq!(input.priority, 0);
+        assert!(input.min_quote_amount.is_none());
+    }
+
     #[test]
     fn test_create_storage_location_deserialize() {
         let json = r#"{"name": "Safe Drawer 1"}"#;
@@ -0,0 +1,29 @@
+//! Storage reconciliation model.
+//!
+//! Result of comparing stored photo objects against `ticket_photos` and
+//! deleting the ones no record points at.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// An orphaned object that could not be deleted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconcileFailure {
+    pub storage_key: String,
+    pub error: String,
+}
+
+/// Result of a storage reconciliation run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageReconcileReport {
+    pub ran_at: DateTime<Utc>,
+    /// Only objects last modified before this are deleted
+    pub cutoff: DateTime<Utc>,
+    /// Objects found under the photo prefix
+    pub scanned: usize,
+    /// Orphans newer than the cutoff, left for a later run
+    pub skipped_recent: usize,
+    /// Keys of the orphaned objects that were deleted
+    pub deleted: Vec<String>,
+    pub failed: Vec<ReconcileFailure>,
+}
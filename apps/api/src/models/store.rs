@@ -0,0 +1,41 @@
+//! Store model.
+//!
+//! One deployment can run every branch of a small chain. Each store has its
+//! own settings row, employees, storage locations, and tickets; customers and
+//! the catalogs are shared. The primary store is the one that existed before
+//! there were several, and its settings hold the deployment-wide admin PIN
+//! and setup state.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Header a terminal sends to pick the store it works for.
+pub const STORE_HEADER: &str = "X-Store-ID";
+
+/// A store, with the name and ticket prefix from its settings.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Store {
+    pub store_id: Uuid,
+    pub name: String,
+    pub ticket_prefix: String,
+    /// The original store; can't be deactivated
+    pub is_primary: bool,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for opening a store.
+///
+/// Its settings start as a copy of the primary store's, with its own name,
+/// contact details, and ticket prefix, and numbering from 1.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateStore {
+    pub name: String,
+    pub ticket_prefix: String,
+    #[serde(default)]
+    pub store_phone: Option<String>,
+    #[serde(default)]
+    pub store_address: Option<String>,
+}
@@ -2,11 +2,76 @@
 //!
 //! Store settings contain configuration for the jewelry store,
 //! including store info, ticket numbering, and admin PIN.
+//!
+//! Each store has its own row. A few settings apply to the whole deployment
+//! and are only kept on the primary store's row: the admin PIN and setup
+//! state, invoice numbering, and the settings in [`DEPLOYMENT_SETTINGS`].
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
+use sqlx::Type;
 use uuid::Uuid;
 
+use super::business_hours::{BusinessCalendar, OpeningHours};
+use super::tax::TaxRate;
+
+/// How receipts are printed by default, matching the database type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "receipt_format", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiptFormat {
+    /// Letter-size PDF
+    #[default]
+    Pdf,
+    /// ESC/POS bytes for 80mm thermal printers
+    Escpos,
+    /// Plain text, 48 columns
+    Text,
+}
+
+impl ReceiptFormat {
+    /// File extension of the receipt endpoint for this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ReceiptFormat::Pdf => "pdf",
+            ReceiptFormat::Escpos => "escpos",
+            ReceiptFormat::Text => "txt",
+        }
+    }
+}
+
+/// Named label stocks that set both label dimensions at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LabelStock {
+    /// Standard 2" x 1" jewelry tag
+    #[serde(rename = "tag_2x1")]
+    Tag2x1,
+    /// 2.2" x 0.5" barbell tag, printed on the two flaps either side of the tail
+    #[serde(rename = "barbell")]
+    Barbell,
+    /// 2.25" x 1.25" tag
+    #[serde(rename = "tag_2.25x1.25")]
+    Tag225x125,
+    /// Dymo 30334 multipurpose label (2.25" x 1.25")
+    #[serde(rename = "dymo_30334")]
+    Dymo30334,
+}
+
+impl LabelStock {
+    /// Width and height in mm, rounded to the 0.1mm the settings store.
+    pub fn size_mm(self) -> (Decimal, Decimal) {
+        match self {
+            LabelStock::Tag2x1 => (Decimal::new(508, 1), Decimal::new(254, 1)),
+            LabelStock::Barbell => (Decimal::new(559, 1), Decimal::new(127, 1)),
+            LabelStock::Tag225x125 | LabelStock::Dymo30334 => {
+                (Decimal::new(572, 1), Decimal::new(318, 1))
+            }
+        }
+    }
+}
+
 /// Full store settings entity (internal use only).
 ///
 /// The admin_pin_hash is excluded from serialization for security.
@@ -24,10 +89,88 @@ pub struct StoreSettings {
     pub setup_complete: bool,
     pub setup_deadline: DateTime<Utc>,
     pub min_pin_length: i32,
+    pub qc_checklist: Vec<String>,
+    pub scope_ticket_visibility: bool,
+    pub custody_value_threshold: Option<Decimal>,
+    pub quote_approval_threshold: Option<Decimal>,
+    pub notifications_enabled: bool,
+    pub auto_archive_after_days: Option<i32>,
+    pub restrict_item_types: bool,
+    pub overdue_digest_email: Option<String>,
+    pub overdue_digest_sms: bool,
+    pub overdue_digest_hour: i32,
+    pub receipt_logo_key: Option<String>,
+    pub receipt_footer_text: Option<String>,
+    pub receipt_show_prices: bool,
+    pub label_width_mm: Decimal,
+    pub label_height_mm: Decimal,
+    pub receipt_format: ReceiptFormat,
+    pub tax_rate: Decimal,
+    pub next_invoice_number: i32,
+    pub tax_inclusive: bool,
+    pub ticket_number_padding: i32,
+    pub ticket_number_yearly_reset: bool,
+    pub business_hours: Json<Vec<OpeningHours>>,
+    pub holidays: Vec<NaiveDate>,
+    pub rush_turnaround_days: Option<i32>,
+    /// IANA time zone the store's days are counted in
+    pub timezone: String,
+    /// SHA-256 of the promise date calendar feed token (None = no feed)
+    pub calendar_feed_token_hash: Option<String>,
+    pub version: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Settings shared by every store, changed through the primary store.
+pub const DEPLOYMENT_SETTINGS: &[&str] = &[
+    "min_pin_length",
+    "scope_ticket_visibility",
+    "auto_archive_after_days",
+    "notifications_enabled",
+    "overdue_digest_email",
+    "overdue_digest_sms",
+    "overdue_digest_hour",
+];
+
+impl StoreSettings {
+    /// This store's settings with the deployment-wide ones taken from the
+    /// primary store's.
+    pub fn with_deployment_settings(self, primary: &StoreSettings) -> Self {
+        Self {
+            admin_pin_hash: primary.admin_pin_hash.clone(),
+            setup_complete: primary.setup_complete,
+            setup_deadline: primary.setup_deadline,
+            next_invoice_number: primary.next_invoice_number,
+            min_pin_length: primary.min_pin_length,
+            scope_ticket_visibility: primary.scope_ticket_visibility,
+            auto_archive_after_days: primary.auto_archive_after_days,
+            notifications_enabled: primary.notifications_enabled,
+            overdue_digest_email: primary.overdue_digest_email.clone(),
+            overdue_digest_sms: primary.overdue_digest_sms,
+            overdue_digest_hour: primary.overdue_digest_hour,
+            ..self
+        }
+    }
+
+    /// The store's tax settings.
+    pub fn tax(&self) -> TaxRate {
+        TaxRate {
+            rate: self.tax_rate,
+            inclusive: self.tax_inclusive,
+        }
+    }
+
+    /// The days the store is open, for counting promise dates.
+    pub fn business_calendar(&self) -> BusinessCalendar {
+        BusinessCalendar::new(
+            &self.business_hours,
+            &self.holidays,
+            self.rush_turnaround_days,
+        )
+    }
+}
+
 /// Full public view of store settings (without admin PIN hash).
 /// Used for authenticated admin responses.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,6 +187,61 @@ pub struct StoreSettingsPublic {
     /// Whether initial setup is still required (setup incomplete and deadline not passed).
     pub setup_required: bool,
     pub min_pin_length: i32,
+    /// QC checklist items required before ready for pickup (empty = disabled).
+    pub qc_checklist: Vec<String>,
+    /// Staff only see tickets they took in or are assigned in lists/search/queue.
+    pub scope_ticket_visibility: bool,
+    /// Tickets quoted at or above this amount need witnessed custody events (null = disabled).
+    pub custody_value_threshold: Option<Decimal>,
+    /// Quotes above this amount need customer approval before work starts (null = disabled).
+    pub quote_approval_threshold: Option<Decimal>,
+    /// Send customer SMS/email notifications.
+    pub notifications_enabled: bool,
+    /// Closed tickets older than this many days are archived automatically (null = disabled).
+    pub auto_archive_after_days: Option<i32>,
+    /// Tickets must use a configured item type or "Other".
+    pub restrict_item_types: bool,
+    /// Where the daily due/overdue digest is emailed (null = no email).
+    pub overdue_digest_email: Option<String>,
+    /// Text assigned employees their due and overdue tickets each morning.
+    pub overdue_digest_sms: bool,
+    /// Hour of the day (UTC) the reminder job runs.
+    pub overdue_digest_hour: i32,
+    /// Storage key of the logo printed on receipts (null = no logo).
+    pub receipt_logo_key: Option<String>,
+    /// Disclaimer printed at the bottom of receipts (null = none).
+    pub receipt_footer_text: Option<String>,
+    /// Print quotes, payments, and the balance on receipts.
+    pub receipt_show_prices: bool,
+    /// Label stock width in mm.
+    pub label_width_mm: Decimal,
+    /// Label stock height in mm.
+    pub label_height_mm: Decimal,
+    /// Format served by `GET /tickets/:id/receipt`.
+    pub receipt_format: ReceiptFormat,
+    /// Sales tax percentage.
+    pub tax_rate: Decimal,
+    /// Number the next invoice will be issued with.
+    pub next_invoice_number: i32,
+    /// Ticket amounts include tax; otherwise tax is added on top.
+    pub tax_inclusive: bool,
+    /// Digits ticket numbers are zero-padded to.
+    pub ticket_number_padding: i32,
+    /// Ticket numbers start over each year, with the year in the code.
+    pub ticket_number_yearly_reset: bool,
+    /// Opening hours by ISO weekday; days without hours are closed (empty =
+    /// open every day).
+    pub business_hours: Vec<OpeningHours>,
+    /// Dates the store is closed.
+    pub holidays: Vec<NaiveDate>,
+    /// Open days a rush job takes, when sooner than its usual turnaround.
+    pub rush_turnaround_days: Option<i32>,
+    /// IANA time zone the store's days are counted in (e.g. `America/Chicago`).
+    pub timezone: String,
+    /// Whether the promise date calendar feed is published.
+    pub calendar_feed_enabled: bool,
+    /// Incremented on every update; served as the ETag.
+    pub version: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -82,6 +280,33 @@ impl From<StoreSettings> for StoreSettingsPublic {
             setup_complete: settings.setup_complete,
             setup_required,
             min_pin_length: settings.min_pin_length,
+            qc_checklist: settings.qc_checklist,
+            scope_ticket_visibility: settings.scope_ticket_visibility,
+            custody_value_threshold: settings.custody_value_threshold,
+            quote_approval_threshold: settings.quote_approval_threshold,
+            notifications_enabled: settings.notifications_enabled,
+            auto_archive_after_days: settings.auto_archive_after_days,
+            restrict_item_types: settings.restrict_item_types,
+            overdue_digest_email: settings.overdue_digest_email,
+            overdue_digest_sms: settings.overdue_digest_sms,
+            overdue_digest_hour: settings.overdue_digest_hour,
+            receipt_logo_key: settings.receipt_logo_key,
+            receipt_footer_text: settings.receipt_footer_text,
+            receipt_show_prices: settings.receipt_show_prices,
+            label_width_mm: settings.label_width_mm,
+            label_height_mm: settings.label_height_mm,
+            receipt_format: settings.receipt_format,
+            tax_rate: settings.tax_rate,
+            next_invoice_number: settings.next_invoice_number,
+            tax_inclusive: settings.tax_inclusive,
+            ticket_number_padding: settings.ticket_number_padding,
+            ticket_number_yearly_reset: settings.ticket_number_yearly_reset,
+            business_hours: settings.business_hours.0,
+            holidays: settings.holidays,
+            rush_turnaround_days: settings.rush_turnaround_days,
+            timezone: settings.timezone,
+            calendar_feed_enabled: settings.calendar_feed_token_hash.is_some(),
+            version: settings.version,
             created_at: settings.created_at,
             updated_at: settings.updated_at,
         }
@@ -106,7 +331,7 @@ impl From<StoreSettings> for StoreSettingsMinimalPublic {
 }
 
 /// Input for updating store settings.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 pub struct UpdateStoreSettings {
     pub store_name: Option<String>,
     pub store_phone: Option<String>,
@@ -114,13 +339,392 @@ pub struct UpdateStoreSettings {
     pub ticket_prefix: Option<String>,
     pub currency: Option<String>,
     pub max_photos_per_ticket: Option<i32>,
+    pub qc_checklist: Option<Vec<String>>,
+    pub scope_ticket_visibility: Option<bool>,
+    /// Custody threshold (null to disable)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub custody_value_threshold: Option<Option<Decimal>>,
+    /// Quote approval threshold (null to disable)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub quote_approval_threshold: Option<Option<Decimal>>,
+    pub notifications_enabled: Option<bool>,
+    pub min_pin_length: Option<i32>,
+    /// Auto-archive age in days (null to disable)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub auto_archive_after_days: Option<Option<i32>>,
+    pub restrict_item_types: Option<bool>,
+    /// Digest email address (null to stop emailing the digest)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub overdue_digest_email: Option<Option<String>>,
+    pub overdue_digest_sms: Option<bool>,
+    pub overdue_digest_hour: Option<i32>,
+    /// Receipt logo storage key (null to remove the logo)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub receipt_logo_key: Option<Option<String>>,
+    /// Receipt disclaimer (null to remove)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub receipt_footer_text: Option<Option<String>>,
+    pub receipt_show_prices: Option<bool>,
+    pub label_width_mm: Option<Decimal>,
+    pub label_height_mm: Option<Decimal>,
+    /// Named stock; sets `label_width_mm` and `label_height_mm` when validated
+    pub label_stock: Option<LabelStock>,
+    pub receipt_format: Option<ReceiptFormat>,
+    pub tax_rate: Option<Decimal>,
+    pub tax_inclusive: Option<bool>,
+    pub ticket_number_padding: Option<i32>,
+    pub ticket_number_yearly_reset: Option<bool>,
+    pub business_hours: Option<Vec<OpeningHours>>,
+    pub holidays: Option<Vec<NaiveDate>>,
+    /// Rush turnaround in open days (null to treat rush jobs like any other)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub rush_turnaround_days: Option<Option<i32>>,
+    pub timezone: Option<String>,
+}
+
+impl UpdateStoreSettings {
+    /// The deployment-wide settings this update changes.
+    pub fn deployment_settings(&self) -> Vec<&'static str> {
+        let set = [
+            self.min_pin_length.is_some(),
+            self.scope_ticket_visibility.is_some(),
+            self.auto_archive_after_days.is_some(),
+            self.notifications_enabled.is_some(),
+            self.overdue_digest_email.is_some(),
+            self.overdue_digest_sms.is_some(),
+            self.overdue_digest_hour.is_some(),
+        ];
+        DEPLOYMENT_SETTINGS
+            .iter()
+            .zip(set)
+            .filter_map(|(name, set)| set.then_some(*name))
+            .collect()
+    }
+}
+
+/// A group of related settings, edited on its own via `PATCH /settings/:section`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsSection {
+    /// Store name, contact details, and currency
+    Store,
+    /// Ticket numbering and the receipt and label templates
+    Printing,
+    /// Photo limits, QC, custody rules, and archiving
+    Workflow,
+    /// Business hours, holidays, and rush turnaround
+    Hours,
+    /// Customer notifications and the overdue digest
+    Notifications,
+    /// PIN policy and ticket visibility
+    Security,
+}
+
+impl SettingsSection {
+    /// Every section, in display order.
+    pub const ALL: &'static [SettingsSection] = &[
+        SettingsSection::Store,
+        SettingsSection::Printing,
+        SettingsSection::Workflow,
+        SettingsSection::Hours,
+        SettingsSection::Notifications,
+        SettingsSection::Security,
+    ];
+
+    /// Path segment for the section.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SettingsSection::Store => "store",
+            SettingsSection::Printing => "printing",
+            SettingsSection::Workflow => "workflow",
+            SettingsSection::Hours => "hours",
+            SettingsSection::Notifications => "notifications",
+            SettingsSection::Security => "security",
+        }
+    }
+
+    /// Parse a path segment.
+    pub fn parse(value: &str) -> Option<Self> {
+        SettingsSection::ALL
+            .iter()
+            .copied()
+            .find(|section| section.as_str() == value)
+    }
+
+    /// The section's current values.
+    pub fn view(self, settings: &StoreSettingsPublic) -> serde_json::Value {
+        match self {
+            SettingsSection::Store => serde_json::json!({
+                "store_name": settings.store_name,
+                "store_phone": settings.store_phone,
+                "store_address": settings.store_address,
+                "currency": settings.currency,
+                "tax_rate": settings.tax_rate,
+                "tax_inclusive": settings.tax_inclusive,
+            }),
+            SettingsSection::Printing => serde_json::json!({
+                "ticket_prefix": settings.ticket_prefix,
+                "ticket_number_padding": settings.ticket_number_padding,
+                "ticket_number_yearly_reset": settings.ticket_number_yearly_reset,
+                "receipt_logo_key": settings.receipt_logo_key,
+                "receipt_footer_text": settings.receipt_footer_text,
+                "receipt_show_prices": settings.receipt_show_prices,
+                "label_width_mm": settings.label_width_mm,
+                "label_height_mm": settings.label_height_mm,
+                "receipt_format": settings.receipt_format,
+            }),
+            SettingsSection::Workflow => serde_json::json!({
+                "max_photos_per_ticket": settings.max_photos_per_ticket,
+                "qc_checklist": settings.qc_checklist,
+                "custody_value_threshold": settings.custody_value_threshold,
+                "quote_approval_threshold": settings.quote_approval_threshold,
+                "auto_archive_after_days": settings.auto_archive_after_days,
+                "restrict_item_types": settings.restrict_item_types,
+            }),
+            SettingsSection::Hours => serde_json::json!({
+                "business_hours": settings.business_hours,
+                "holidays": settings.holidays,
+                "rush_turnaround_days": settings.rush_turnaround_days,
+                "timezone": settings.timezone,
+            }),
+            SettingsSection::Notifications => serde_json::json!({
+                "notifications_enabled": settings.notifications_enabled,
+                "overdue_digest_email": settings.overdue_digest_email,
+                "overdue_digest_sms": settings.overdue_digest_sms,
+                "overdue_digest_hour": settings.overdue_digest_hour,
+            }),
+            SettingsSection::Security => serde_json::json!({
+                "min_pin_length": settings.min_pin_length,
+                "scope_ticket_visibility": settings.scope_ticket_visibility,
+            }),
+        }
+    }
+
+    /// Parse a PATCH body for this section.
+    ///
+    /// Fields that belong to another section are rejected.
+    pub fn parse_patch(self, body: serde_json::Value) -> Result<UpdateStoreSettings, String> {
+        let input = match self {
+            SettingsSection::Store => {
+                let patch: StoreInfoPatch = serde_json::from_value(body).map_err(patch_error)?;
+                UpdateStoreSettings {
+                    store_name: patch.store_name,
+                    store_phone: patch.store_phone,
+                    store_address: patch.store_address,
+                    currency: patch.currency,
+                    tax_rate: patch.tax_rate,
+                    tax_inclusive: patch.tax_inclusive,
+                    ..Default::default()
+                }
+            }
+            SettingsSection::Printing => {
+                let patch: PrintingPatch = serde_json::from_value(body).map_err(patch_error)?;
+                UpdateStoreSettings {
+                    ticket_prefix: patch.ticket_prefix,
+                    ticket_number_padding: patch.ticket_number_padding,
+                    ticket_number_yearly_reset: patch.ticket_number_yearly_reset,
+                    receipt_logo_key: patch.receipt_logo_key,
+                    receipt_footer_text: patch.receipt_footer_text,
+                    receipt_show_prices: patch.receipt_show_prices,
+                    label_width_mm: patch.label_width_mm,
+                    label_height_mm: patch.label_height_mm,
+                    label_stock: patch.label_stock,
+                    receipt_format: patch.receipt_format,
+                    ..Default::default()
+                }
+            }
+            SettingsSection::Workflow => {
+                let patch: WorkflowPatch = serde_json::from_value(body).map_err(patch_error)?;
+                UpdateStoreSettings {
+                    max_photos_per_ticket: patch.max_photos_per_ticket,
+                    qc_checklist: patch.qc_checklist,
+                    custody_value_threshold: patch.custody_value_threshold,
+                    quote_approval_threshold: patch.quote_approval_threshold,
+                    auto_archive_after_days: patch.auto_archive_after_days,
+                    restrict_item_types: patch.restrict_item_types,
+                    ..Default::default()
+                }
+            }
+            SettingsSection::Hours => {
+                let patch: HoursPatch = serde_json::from_value(body).map_err(patch_error)?;
+                UpdateStoreSettings {
+                    business_hours: patch.business_hours,
+                    holidays: patch.holidays,
+                    rush_turnaround_days: patch.rush_turnaround_days,
+                    timezone: patch.timezone,
+                    ..Default::default()
+                }
+            }
+            SettingsSection::Notifications => {
+                let patch: NotificationsPatch =
+                    serde_json::from_value(body).map_err(patch_error)?;
+                UpdateStoreSettings {
+                    notifications_enabled: patch.notifications_enabled,
+                    overdue_digest_email: patch.overdue_digest_email,
+                    overdue_digest_sms: patch.overdue_digest_sms,
+                    overdue_digest_hour: patch.overdue_digest_hour,
+                    ..Default::default()
+                }
+            }
+            SettingsSection::Security => {
+                let patch: SecurityPatch = serde_json::from_value(body).map_err(patch_error)?;
+                UpdateStoreSettings {
+                    min_pin_length: patch.min_pin_length,
+                    scope_ticket_visibility: patch.scope_ticket_visibility,
+                    ..Default::default()
+                }
+            }
+        };
+        Ok(input)
+    }
+}
+
+fn patch_error(err: serde_json::Error) -> String {
+    format!("Invalid settings: {}", err)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StoreInfoPatch {
+    store_name: Option<String>,
+    store_phone: Option<String>,
+    store_address: Option<String>,
+    currency: Option<String>,
+    tax_rate: Option<Decimal>,
+    tax_inclusive: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PrintingPatch {
+    ticket_prefix: Option<String>,
+    ticket_number_padding: Option<i32>,
+    ticket_number_yearly_reset: Option<bool>,
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    receipt_logo_key: Option<Option<String>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    receipt_footer_text: Option<Option<String>>,
+    receipt_show_prices: Option<bool>,
+    label_width_mm: Option<Decimal>,
+    label_height_mm: Option<Decimal>,
+    label_stock: Option<LabelStock>,
+    receipt_format: Option<ReceiptFormat>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WorkflowPatch {
+    max_photos_per_ticket: Option<i32>,
+    qc_checklist: Option<Vec<String>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    custody_value_threshold: Option<Option<Decimal>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    quote_approval_threshold: Option<Option<Decimal>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    auto_archive_after_days: Option<Option<i32>>,
+    restrict_item_types: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct HoursPatch {
+    business_hours: Option<Vec<OpeningHours>>,
+    holidays: Option<Vec<NaiveDate>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    rush_turnaround_days: Option<Option<i32>>,
+    timezone: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NotificationsPatch {
+    notifications_enabled: Option<bool>,
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    overdue_digest_email: Option<Option<String>>,
+    overdue_digest_sms: Option<bool>,
+    overdue_digest_hour: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SecurityPatch {
+    min_pin_length: Option<i32>,
+    scope_ticket_visibility: Option<bool>,
+}
+
+/// A store's ticket numbering, read with its sequence row locked.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TicketNumbering {
+    pub ticket_prefix: String,
+    pub ticket_number_padding: i32,
+    pub ticket_number_yearly_reset: bool,
+    /// Year the sequence last numbered a ticket in (None before the first)
+    pub ticket_number_year: Option<i32>,
+    pub next_ticket_number: i32,
 }
 
-/// Result of ticket number increment operation.
-#[derive(Debug, Clone)]
-pub struct TicketNumberResult {
-    pub prefix: String,
-    pub number: i32,
+impl TicketNumbering {
+    /// The number the next ticket taken in during `year` gets, before
+    /// skipping codes already in use. Yearly numbering starts over at 1.
+    pub fn next_number(&self, year: i32) -> i32 {
+        if self.ticket_number_yearly_reset && self.ticket_number_year != Some(year) {
+            1
+        } else {
+            self.next_ticket_number
+        }
+    }
+
+    /// The ticket code for a number, e.g. `JR-0042`, or `JR-2025-0042` with
+    /// yearly numbering.
+    pub fn code(&self, year: i32, number: i32) -> String {
+        let width = self.ticket_number_padding.max(1) as usize;
+        if self.ticket_number_yearly_reset {
+            format!("{}-{}-{:0width$}", self.ticket_prefix, year, number)
+        } else {
+            format!("{}-{:0width$}", self.ticket_prefix, number)
+        }
+    }
 }
 
 #[cfg(test)]
@@ -137,6 +741,17 @@ mod tests {
         assert!(input.ticket_prefix.is_none());
         assert!(input.currency.is_none());
         assert!(input.max_photos_per_ticket.is_none());
+        assert!(input.qc_checklist.is_none());
+    }
+
+    #[test]
+    fn test_update_store_settings_qc_checklist() {
+        let json = r#"{"qc_checklist": ["Stones secure", "Polished"]}"#;
+        let input: UpdateStoreSettings = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            input.qc_checklist,
+            Some(vec!["Stones secure".to_string(), "Polished".to_string()])
+        );
     }
 
     #[test]
@@ -158,6 +773,80 @@ mod tests {
         assert_eq!(input.max_photos_per_ticket, Some(5));
     }
 
+    #[test]
+    fn test_settings_section_parse_round_trips() {
+        for section in SettingsSection::ALL {
+            assert_eq!(SettingsSection::parse(section.as_str()), Some(*section));
+        }
+        assert_eq!(SettingsSection::parse("billing"), None);
+    }
+
+    #[test]
+    fn test_settings_section_patch_only_touches_its_fields() {
+        let input = SettingsSection::Workflow
+            .parse_patch(serde_json::json!({
+                "max_photos_per_ticket": 5,
+                "custody_value_threshold": null
+            }))
+            .unwrap();
+        assert_eq!(input.max_photos_per_ticket, Some(5));
+        assert_eq!(input.custody_value_threshold, Some(None));
+        assert!(input.store_name.is_none());
+        assert!(input.qc_checklist.is_none());
+
+        let input = SettingsSection::Security
+            .parse_patch(serde_json::json!({ "min_pin_length": 8 }))
+            .unwrap();
+        assert_eq!(input.min_pin_length, Some(8));
+        assert!(input.scope_ticket_visibility.is_none());
+    }
+
+    #[test]
+    fn test_printing_patch_accepts_template_fields() {
+        let input = SettingsSection::Printing
+            .parse_patch(serde_json::json!({
+                "receipt_logo_key": null,
+                "receipt_show_prices": false,
+                "label_width_mm": "76.2"
+            }))
+            .unwrap();
+        assert_eq!(input.receipt_logo_key, Some(None));
+        assert!(input.receipt_footer_text.is_none());
+        assert_eq!(input.receipt_show_prices, Some(false));
+        assert_eq!(input.label_width_mm, Some(Decimal::new(762, 1)));
+        assert!(input.label_height_mm.is_none());
+    }
+
+    #[test]
+    fn test_hours_patch() {
+        let input = SettingsSection::Hours
+            .parse_patch(serde_json::json!({
+                "business_hours": [
+                    { "weekday": 1, "opens_at": "09:00", "closes_at": "17:30" }
+                ],
+                "holidays": ["2026-12-25"],
+                "rush_turnaround_days": null
+            }))
+            .unwrap();
+        assert_eq!(input.business_hours.map(|hours| hours.len()), Some(1));
+        assert_eq!(
+            input.holidays,
+            Some(vec![NaiveDate::from_ymd_opt(2026, 12, 25).unwrap()])
+        );
+        assert_eq!(input.rush_turnaround_days, Some(None));
+    }
+
+    #[test]
+    fn test_settings_section_patch_rejects_other_sections_fields() {
+        let err = SettingsSection::Store
+            .parse_patch(serde_json::json!({ "ticket_prefix": "XX" }))
+            .unwrap_err();
+        assert!(err.contains("ticket_prefix"), "{}", err);
+        assert!(SettingsSection::Notifications
+            .parse_patch(serde_json::json!({ "notifications_enabled": "yes" }))
+            .is_err());
+    }
+
     #[test]
     fn test_store_settings_public_serialization() {
         let public = StoreSettingsPublic {
@@ -172,6 +861,33 @@ mod tests {
             setup_complete: false,
             setup_required: true,
             min_pin_length: 6,
+            qc_checklist: vec![],
+            scope_ticket_visibility: false,
+            custody_value_threshold: None,
+            quote_approval_threshold: None,
+            notifications_enabled: true,
+            auto_archive_after_days: None,
+            restrict_item_types: false,
+            overdue_digest_email: None,
+            overdue_digest_sms: false,
+            overdue_digest_hour: 8,
+            receipt_logo_key: None,
+            receipt_footer_text: None,
+            receipt_show_prices: true,
+            label_width_mm: Decimal::new(508, 1),
+            label_height_mm: Decimal::new(254, 1),
+            receipt_format: Default::default(),
+            tax_rate: Decimal::ZERO,
+            next_invoice_number: 1,
+            tax_inclusive: true,
+            ticket_number_padding: 4,
+            ticket_number_yearly_reset: false,
+            business_hours: vec![],
+            holidays: vec![],
+            rush_turnaround_days: None,
+            timezone: "UTC".to_string(),
+            calendar_feed_enabled: false,
+            version: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -201,6 +917,33 @@ mod tests {
             setup_complete: false,
             setup_deadline: Utc::now() + chrono::Duration::hours(24),
             min_pin_length: 6,
+            qc_checklist: vec![],
+            scope_ticket_visibility: false,
+            custody_value_threshold: None,
+            quote_approval_threshold: None,
+            notifications_enabled: true,
+            auto_archive_after_days: None,
+            restrict_item_types: false,
+            overdue_digest_email: None,
+            overdue_digest_sms: false,
+            overdue_digest_hour: 8,
+            receipt_logo_key: None,
+            receipt_footer_text: None,
+            receipt_show_prices: true,
+            label_width_mm: Decimal::new(508, 1),
+            label_height_mm: Decimal::new(254, 1),
+            receipt_format: Default::default(),
+            tax_rate: Decimal::ZERO,
+            next_invoice_number: 1,
+            tax_inclusive: true,
+            ticket_number_padding: 4,
+            ticket_number_yearly_reset: false,
+            business_hours: Json(vec![]),
+            holidays: vec![],
+            rush_turnaround_days: None,
+            timezone: "UTC".to_string(),
+            calendar_feed_token_hash: None,
+            version: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -227,6 +970,33 @@ mod tests {
             setup_complete: false,
             setup_deadline: Utc::now() - chrono::Duration::hours(1),
             min_pin_length: 6,
+            qc_checklist: vec![],
+            scope_ticket_visibility: false,
+            custody_value_threshold: None,
+            quote_approval_threshold: None,
+            notifications_enabled: true,
+            auto_archive_after_days: None,
+            restrict_item_types: false,
+            overdue_digest_email: None,
+            overdue_digest_sms: false,
+            overdue_digest_hour: 8,
+            receipt_logo_key: None,
+            receipt_footer_text: None,
+            receipt_show_prices: true,
+            label_width_mm: Decimal::new(508, 1),
+            label_height_mm: Decimal::new(254, 1),
+            receipt_format: Default::default(),
+            tax_rate: Decimal::ZERO,
+            next_invoice_number: 1,
+            tax_inclusive: true,
+            ticket_number_padding: 4,
+            ticket_number_yearly_reset: false,
+            business_hours: Json(vec![]),
+            holidays: vec![],
+            rush_turnaround_days: None,
+            timezone: "UTC".to_string(),
+            calendar_feed_token_hash: None,
+            version: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -253,6 +1023,33 @@ mod tests {
             setup_complete: true,
             setup_deadline: Utc::now() + chrono::Duration::hours(24),
             min_pin_length: 6,
+            qc_checklist: vec![],
+            scope_ticket_visibility: false,
+            custody_value_threshold: None,
+            quote_approval_threshold: None,
+            notifications_enabled: true,
+            auto_archive_after_days: None,
+            restrict_item_types: false,
+            overdue_digest_email: None,
+            overdue_digest_sms: false,
+            overdue_digest_hour: 8,
+            receipt_logo_key: None,
+            receipt_footer_text: None,
+            receipt_show_prices: true,
+            label_width_mm: Decimal::new(508, 1),
+            label_height_mm: Decimal::new(254, 1),
+            receipt_format: Default::default(),
+            tax_rate: Decimal::ZERO,
+            next_invoice_number: 1,
+            tax_inclusive: true,
+            ticket_number_padding: 4,
+            ticket_number_yearly_reset: false,
+            business_hours: Json(vec![]),
+            holidays: vec![],
+            rush_turnaround_days: None,
+            timezone: "UTC".to_string(),
+            calendar_feed_token_hash: None,
+            version: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -261,4 +1058,120 @@ mod tests {
         assert!(public.setup_complete);
         assert!(!public.setup_required);
     }
+
+    #[test]
+    fn test_with_deployment_settings() {
+        let primary = StoreSettings {
+            setting_id: Uuid::nil(),
+            store_name: "Main".to_string(),
+            store_phone: None,
+            store_address: None,
+            ticket_prefix: "JR".to_string(),
+            next_ticket_number: 40,
+            currency: "USD".to_string(),
+            max_photos_per_ticket: 10,
+            admin_pin_hash: "hash".to_string(),
+            setup_complete: true,
+            setup_deadline: Utc::now(),
+            min_pin_length: 8,
+            qc_checklist: vec![],
+            scope_ticket_visibility: true,
+            custody_value_threshold: None,
+            quote_approval_threshold: None,
+            notifications_enabled: false,
+            auto_archive_after_days: Some(30),
+            restrict_item_types: false,
+            overdue_digest_email: Some("owner@example.com".to_string()),
+            overdue_digest_sms: true,
+            overdue_digest_hour: 7,
+            receipt_logo_key: None,
+            receipt_footer_text: None,
+            receipt_show_prices: true,
+            label_width_mm: Decimal::new(508, 1),
+            label_height_mm: Decimal::new(254, 1),
+            receipt_format: Default::default(),
+            tax_rate: Decimal::ZERO,
+            next_invoice_number: 12,
+            tax_inclusive: true,
+            ticket_number_padding: 4,
+            ticket_number_yearly_reset: false,
+            business_hours: Json(vec![]),
+            holidays: vec![],
+            rush_turnaround_days: None,
+            timezone: "UTC".to_string(),
+            calendar_feed_token_hash: None,
+            version: 3,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let branch = StoreSettings {
+            store_name: "Uptown".to_string(),
+            ticket_prefix: "UP".to_string(),
+            next_ticket_number: 2,
+            admin_pin_hash: "stale".to_string(),
+            min_pin_length: 6,
+            scope_ticket_visibility: false,
+            tax_rate: Decimal::new(825, 4),
+            version: 1,
+            ..primary.clone()
+        };
+
+        let settings = branch.with_deployment_settings(&primary);
+        assert_eq!(settings.store_name, "Uptown");
+        assert_eq!(settings.ticket_prefix, "UP");
+        assert_eq!(settings.next_ticket_number, 2);
+        assert_eq!(settings.tax_rate, Decimal::new(825, 4));
+        assert_eq!(settings.version, 1);
+        assert_eq!(settings.admin_pin_hash, "hash");
+        assert_eq!(settings.min_pin_length, 8);
+        assert!(settings.scope_ticket_visibility);
+    }
+
+    #[test]
+    fn test_update_deployment_settings() {
+        let input: UpdateStoreSettings =
+            serde_json::from_str(r#"{"store_name": "Uptown", "tax_rate": "0.0825"}"#).unwrap();
+        assert!(input.deployment_settings().is_empty());
+
+        let input: UpdateStoreSettings = serde_json::from_str(
+            r#"{"store_name": "Uptown", "min_pin_length": 8, "overdue_digest_email": null}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            input.deployment_settings(),
+            vec!["min_pin_length", "overdue_digest_email"]
+        );
+    }
+
+    fn numbering(yearly_reset: bool, year: Option<i32>) -> TicketNumbering {
+        TicketNumbering {
+            ticket_prefix: "JR".to_string(),
+            ticket_number_padding: 4,
+            ticket_number_yearly_reset: yearly_reset,
+            ticket_number_year: year,
+            next_ticket_number: 42,
+        }
+    }
+
+    #[test]
+    fn test_ticket_code_format() {
+        assert_eq!(numbering(false, None).code(2025, 42), "JR-0042");
+        assert_eq!(numbering(true, None).code(2025, 42), "JR-2025-0042");
+
+        let wide = TicketNumbering {
+            ticket_number_padding: 6,
+            ..numbering(false, None)
+        };
+        assert_eq!(wide.code(2025, 7), "JR-000007");
+        // Numbers past the padding keep every digit
+        assert_eq!(numbering(false, None).code(2025, 123456), "JR-123456");
+    }
+
+    #[test]
+    fn test_ticket_number_yearly_reset() {
+        assert_eq!(numbering(false, Some(2024)).next_number(2025), 42);
+        assert_eq!(numbering(true, Some(2025)).next_number(2025), 42);
+        assert_eq!(numbering(true, Some(2024)).next_number(2025), 1);
+        assert_eq!(numbering(true, None).next_number(2025), 1);
+    }
 }
@@ -0,0 +1,110 @@
+//! Sales tax.
+//!
+//! A store either prices work with tax included or adds tax on top. Either
+//! way a ticket's amount is split into the subtotal, the tax, and what the
+//! customer pays.
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// The store's tax settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaxRate {
+    /// Percentage, e.g. 8.25
+    pub rate: Decimal,
+    /// Whether ticket amounts already include the tax
+    pub inclusive: bool,
+}
+
+/// A ticket amount split into subtotal, tax, and total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaxBreakdown {
+    /// Amount before tax
+    pub subtotal: Decimal,
+    pub tax_rate: Decimal,
+    pub tax_inclusive: bool,
+    pub tax_amount: Decimal,
+    /// What the customer pays
+    pub total: Decimal,
+}
+
+impl TaxRate {
+    /// Split a ticket amount at this rate.
+    pub fn apply(self, amount: Decimal) -> TaxBreakdown {
+        let (subtotal, tax_amount, total) = if self.inclusive {
+            let tax = included_tax(amount, self.rate);
+            (amount - tax, tax, amount)
+        } else {
+            let tax = added_tax(amount, self.rate);
+            (amount, tax, amount + tax)
+        };
+        TaxBreakdown {
+            subtotal,
+            tax_rate: self.rate,
+            tax_inclusive: self.inclusive,
+            tax_amount,
+            total,
+        }
+    }
+}
+
+/// Tax included in a tax-inclusive `amount` at `rate` percent, to the cent.
+pub fn included_tax(amount: Decimal, rate: Decimal) -> Decimal {
+    if rate <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    (amount * rate / (Decimal::ONE_HUNDRED + rate)).round_dp(2)
+}
+
+/// Tax added on top of `amount` at `rate` percent, to the cent.
+pub fn added_tax(amount: Decimal, rate: Decimal) -> Decimal {
+    if rate <= Decimal::ZERO {
+        return Decimal::ZERO;
+    }
+    (amount * rate / Decimal::ONE_HUNDRED).round_dp(2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_included_tax() {
+        assert_eq!(
+            included_tax(Decimal::new(10825, 2), Decimal::new(825, 2)),
+            Decimal::new(825, 2)
+        );
+        assert_eq!(
+            included_tax(Decimal::new(5000, 2), Decimal::new(7, 0)),
+            Decimal::new(327, 2)
+        );
+        assert_eq!(
+            included_tax(Decimal::new(5000, 2), Decimal::ZERO),
+            Decimal::ZERO
+        );
+    }
+
+    #[test]
+    fn test_apply_inclusive_rate() {
+        let tax = TaxRate {
+            rate: Decimal::new(825, 2),
+            inclusive: true,
+        }
+        .apply(Decimal::new(10825, 2));
+        assert_eq!(tax.subtotal, Decimal::new(10000, 2));
+        assert_eq!(tax.tax_amount, Decimal::new(825, 2));
+        assert_eq!(tax.total, Decimal::new(10825, 2));
+    }
+
+    #[test]
+    fn test_apply_exclusive_rate() {
+        let tax = TaxRate {
+            rate: Decimal::new(8875, 3),
+            inclusive: false,
+        }
+        .apply(Decimal::new(4500, 2));
+        assert_eq!(tax.subtotal, Decimal::new(4500, 2));
+        assert_eq!(tax.tax_amount, Decimal::new(399, 2));
+        assert_eq!(tax.total, Decimal::new(4899, 2));
+    }
+}
@@ -2,12 +2,16 @@
 //!
 //! Tickets represent repair jobs in the system.
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::Type;
 use uuid::Uuid;
 
+use super::quote::QuoteStatus;
+use super::ticket_item::CreateTicketItem;
+
 /// Ticket status enum matching the database type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[sqlx(type_name = "ticket_status", rename_all = "snake_case")]
@@ -37,6 +41,13 @@ impl TicketStatus {
         !matches!(self, TicketStatus::Closed | TicketStatus::Archived)
     }
 
+    /// Returns true if entering this status requires a passing QC check.
+    ///
+    /// The QC gate only applies when the store has a checklist configured.
+    pub fn requires_qc(&self) -> bool {
+        matches!(self, TicketStatus::ReadyForPickup)
+    }
+
     /// Check if a status transition is valid.
     ///
     /// Allowed transitions:
@@ -103,10 +114,20 @@ pub struct Ticket {
     pub is_rush: bool,
     pub promise_date: Option<NaiveDate>,
     pub storage_location_id: Uuid,
+    /// Store holding the ticket (its storage location's store)
+    pub store_id: Uuid,
+
+    // Weight and metal (for melt-value estimates)
+    pub weight_grams: Option<Decimal>,
+    pub metal_type: Option<String>,
 
     // Pricing
     pub quote_amount: Option<Decimal>,
     pub actual_amount: Option<Decimal>,
+    /// Part of quote_amount charged for a rush job
+    pub rush_surcharge: Option<Decimal>,
+    /// Where the quote stands with the customer
+    pub quote_status: QuoteStatus,
 
     // Employee attribution
     pub taken_in_by: Uuid,
@@ -125,6 +146,13 @@ pub struct Ticket {
     // Soft-delete fields
     pub deleted_at: Option<DateTime<Utc>>,
     pub deleted_by: Option<Uuid>,
+
+    // Created in training mode
+    pub is_training: bool,
+
+    // Public status lookup (printed on the receipt, never returned by the API)
+    #[serde(skip_serializing, default)]
+    pub lookup_token: String,
 }
 
 impl Ticket {
@@ -132,6 +160,29 @@ impl Ticket {
     pub fn is_deleted(&self) -> bool {
         self.deleted_at.is_some()
     }
+
+    /// Returns true if movements of this item need a witnessed custody event.
+    ///
+    /// Applies when the store has a custody threshold and the ticket is
+    /// quoted at or above it.
+    pub fn requires_custody(&self, threshold: Option<Decimal>) -> bool {
+        super::custody::custody_required(self.quote_amount, threshold)
+    }
+}
+
+/// A ticket with the customer, storage location, and employee names shown on
+/// its detail view, loaded in one query.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct TicketDetail {
+    #[sqlx(flatten)]
+    pub ticket: Ticket,
+    pub customer_name: String,
+    pub customer_phone: Option<String>,
+    pub customer_email: Option<String>,
+    pub storage_location_name: String,
+    pub taken_in_by_name: String,
+    pub worked_by_name: Option<String>,
+    pub closed_by_name: Option<String>,
 }
 
 /// Summary view of a ticket for list views.
@@ -150,6 +201,10 @@ pub struct TicketSummary {
     pub promise_date: Option<NaiveDate>,
     pub quote_amount: Option<Decimal>,
     pub created_at: DateTime<Utc>,
+    /// Set only for soft-deleted tickets (listed with `include_deleted`).
+    #[sqlx(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// Input for creating a new ticket.
@@ -164,7 +219,17 @@ pub struct CreateTicket {
     pub promise_date: Option<NaiveDate>,
     pub storage_location_id: Uuid,
     pub quote_amount: Option<Decimal>,
+    pub rush_surcharge: Option<Decimal>,
+    pub weight_grams: Option<Decimal>,
+    pub metal_type: Option<String>,
     pub taken_in_by: Uuid,
+    /// Created from a training-mode session
+    #[serde(default)]
+    pub is_training: bool,
+    /// Items on the ticket, item 1 matching the fields above. Empty for a
+    /// single-item ticket, whose item is taken from those fields.
+    #[serde(default)]
+    pub items: Vec<CreateTicketItem>,
 }
 
 /// Input for updating an existing ticket.
@@ -178,11 +243,116 @@ pub struct UpdateTicket {
     pub promise_date: Option<Option<NaiveDate>>,
     pub storage_location_id: Option<Uuid>,
     pub quote_amount: Option<Option<Decimal>>,
+    pub rush_surcharge: Option<Option<Decimal>>,
     pub actual_amount: Option<Option<Decimal>>,
     pub worked_by: Option<Option<Uuid>>,
+    pub weight_grams: Option<Option<Decimal>>,
+    pub metal_type: Option<Option<String>>,
     pub last_modified_by: Option<Uuid>,
 }
 
+/// Order of a ticket listing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TicketOrder {
+    /// Rush tickets first, then oldest first; searches put exact ticket
+    /// codes and the best matches ahead of that
+    #[default]
+    Priority,
+    /// Oldest first by creation time, then ticket ID, for cursor pagination
+    Created,
+    /// By a requested field, then oldest first
+    Sorted(TicketSort),
+}
+
+/// Field a ticket listing can be sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TicketSortField {
+    PromiseDate,
+    CreatedAt,
+    QuoteAmount,
+    CustomerName,
+}
+
+impl TicketSortField {
+    /// Every sortable field, in the order listed to API clients.
+    pub const ALL: [TicketSortField; 4] = [
+        Self::PromiseDate,
+        Self::CreatedAt,
+        Self::QuoteAmount,
+        Self::CustomerName,
+    ];
+
+    /// Field name as used in the API (e.g., `promise_date`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PromiseDate => "promise_date",
+            Self::CreatedAt => "created_at",
+            Self::QuoteAmount => "quote_amount",
+            Self::CustomerName => "customer_name",
+        }
+    }
+}
+
+/// A requested sort: a field, ascending unless `descending`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TicketSort {
+    pub field: TicketSortField,
+    pub descending: bool,
+}
+
+impl TicketSort {
+    /// Parse a sort like `promise_date` or `-created_at` (descending).
+    ///
+    /// Returns None for fields that can't be sorted by.
+    pub fn parse(sort: &str) -> Option<Self> {
+        let (name, descending) = match sort.strip_prefix('-') {
+            Some(name) => (name, true),
+            None => (sort, false),
+        };
+        let field = TicketSortField::ALL
+            .into_iter()
+            .find(|field| field.as_str() == name)?;
+        Some(Self { field, descending })
+    }
+}
+
+/// Position in a ticket listing ordered by [`TicketOrder::Created`]: the
+/// last ticket of the previous page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TicketCursor {
+    pub created_at: DateTime<Utc>,
+    pub ticket_id: Uuid,
+}
+
+impl TicketCursor {
+    /// Cursor pointing just past `ticket`.
+    pub fn after(ticket: &QueueTicket) -> Self {
+        Self {
+            created_at: ticket.created_at,
+            ticket_id: ticket.ticket_id,
+        }
+    }
+
+    /// Opaque, URL-safe form of the cursor.
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!(
+            "{}_{}",
+            self.created_at.timestamp_micros(),
+            self.ticket_id
+        ))
+    }
+
+    /// Parse an encoded cursor. Returns None if it is malformed.
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let decoded = String::from_utf8(URL_SAFE_NO_PAD.decode(cursor).ok()?).ok()?;
+        let (micros, ticket_id) = decoded.split_once('_')?;
+        Some(Self {
+            created_at: DateTime::from_timestamp_micros(micros.parse().ok()?)?,
+            ticket_id: Uuid::parse_str(ticket_id).ok()?,
+        })
+    }
+}
+
 /// Filters for listing tickets.
 #[derive(Debug, Clone, Default)]
 pub struct TicketFilters {
@@ -200,6 +370,21 @@ pub struct TicketFilters {
     pub limit: Option<i64>,
     /// Offset for pagination
     pub offset: Option<i64>,
+    pub order: TicketOrder,
+    /// With [`TicketOrder::Created`], only tickets after this position
+    pub after: Option<TicketCursor>,
+    /// Only tickets this employee took in or is assigned to (None = all)
+    pub visible_to: Option<Uuid>,
+    /// Only tickets held at this store (None = every store)
+    pub store_id: Option<Uuid>,
+    /// Only tickets assigned to this employee
+    pub worked_by: Option<Uuid>,
+    /// Only tickets this employee took in
+    pub taken_in_by: Option<Uuid>,
+    /// Include soft-deleted tickets
+    pub include_deleted: bool,
+    /// List training tickets instead of real ones
+    pub training: bool,
 }
 
 /// Extended ticket summary for queue/workboard views.
@@ -218,14 +403,22 @@ pub struct QueueTicket {
     pub promise_date: Option<NaiveDate>,
     pub quote_amount: Option<Decimal>,
     pub created_at: DateTime<Utc>,
+    /// Manual position within the lane (None = after positioned tickets, oldest first).
+    #[sqlx(default)]
+    pub queue_position: Option<i32>,
     /// True if promise_date is in the past and ticket is still open.
     pub is_overdue: bool,
+    /// Set only for soft-deleted tickets (listed with `include_deleted`).
+    #[sqlx(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 /// Search parameters for full-text ticket search.
 #[derive(Debug, Clone)]
 pub struct TicketSearchParams {
-    /// Search query string (searches across ticket, customer, and notes)
+    /// Search query string (searches across ticket, customer, notes, and
+    /// contact notes)
     pub query: String,
     /// Filter by statuses (empty = all statuses including archived)
     pub statuses: Option<Vec<TicketStatus>>,
@@ -233,6 +426,17 @@ pub struct TicketSearchParams {
     pub limit: Option<i64>,
     /// Offset for pagination
     pub offset: Option<i64>,
+    pub order: TicketOrder,
+    /// With [`TicketOrder::Created`], only tickets after this position
+    pub after: Option<TicketCursor>,
+    /// Only tickets this employee took in or is assigned to (None = all)
+    pub visible_to: Option<Uuid>,
+    /// Only tickets held at this store (None = every store)
+    pub store_id: Option<Uuid>,
+    /// Include soft-deleted tickets
+    pub include_deleted: bool,
+    /// List training tickets instead of real ones
+    pub training: bool,
 }
 
 /// Workboard queue response grouped by status lanes.
@@ -266,6 +470,16 @@ mod tests {
         assert!(!active.contains(&TicketStatus::Archived));
     }
 
+    #[test]
+    fn test_ticket_status_requires_qc() {
+        assert!(TicketStatus::ReadyForPickup.requires_qc());
+        assert!(!TicketStatus::Intake.requires_qc());
+        assert!(!TicketStatus::InProgress.requires_qc());
+        assert!(!TicketStatus::WaitingOnParts.requires_qc());
+        assert!(!TicketStatus::Closed.requires_qc());
+        assert!(!TicketStatus::Archived.requires_qc());
+    }
+
     #[test]
     fn test_ticket_status_serialization() {
         let status = TicketStatus::WaitingOnParts;
@@ -386,6 +600,7 @@ mod tests {
             is_rush: false,
             promise_date: None,
             storage_location_id: Uuid::new_v4(),
+            store_id: Uuid::nil(),
             quote_amount: Some(Decimal::new(100, 2)),
             actual_amount: None,
             taken_in_by: Uuid::new_v4(),
@@ -398,6 +613,12 @@ mod tests {
             queue_position: None,
             deleted_at: Some(Utc::now()),
             deleted_by: Some(Uuid::new_v4()),
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "tok".to_string(),
+            weight_grams: None,
+            metal_type: None,
         };
 
         assert!(ticket.is_deleted());
@@ -420,6 +641,7 @@ mod tests {
             is_rush: false,
             promise_date: None,
             storage_location_id: Uuid::new_v4(),
+            store_id: Uuid::nil(),
             quote_amount: Some(Decimal::new(100, 2)),
             actual_amount: None,
             taken_in_by: Uuid::new_v4(),
@@ -432,8 +654,108 @@ mod tests {
             queue_position: None,
             deleted_at: None,
             deleted_by: None,
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "tok".to_string(),
+            weight_grams: None,
+            metal_type: None,
         };
 
         assert!(!ticket.is_deleted());
     }
+
+    #[test]
+    fn test_ticket_requires_custody_at_threshold() {
+        use chrono::Utc;
+        use rust_decimal::Decimal;
+
+        let mut ticket = Ticket {
+            ticket_id: Uuid::new_v4(),
+            friendly_code: "JR-0001".to_string(),
+            customer_id: Uuid::new_v4(),
+            item_type: None,
+            item_description: "Test".to_string(),
+            condition_notes: "Test".to_string(),
+            requested_work: "Test".to_string(),
+            status: TicketStatus::Intake,
+            is_rush: false,
+            promise_date: None,
+            storage_location_id: Uuid::new_v4(),
+            store_id: Uuid::nil(),
+            quote_amount: Some(Decimal::new(5000, 0)),
+            actual_amount: None,
+            taken_in_by: Uuid::new_v4(),
+            worked_by: None,
+            closed_by: None,
+            last_modified_by: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            closed_at: None,
+            queue_position: None,
+            deleted_at: None,
+            deleted_by: None,
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "tok".to_string(),
+            weight_grams: None,
+            metal_type: None,
+        };
+
+        assert!(!ticket.requires_custody(None));
+        assert!(ticket.requires_custody(Some(Decimal::new(5000, 0))));
+        assert!(!ticket.requires_custody(Some(Decimal::new(5001, 0))));
+
+        ticket.quote_amount = None;
+        assert!(!ticket.requires_custody(Some(Decimal::ZERO)));
+    }
+
+    #[test]
+    fn test_ticket_sort_parse() {
+        assert_eq!(
+            TicketSort::parse("promise_date"),
+            Some(TicketSort {
+                field: TicketSortField::PromiseDate,
+                descending: false,
+            })
+        );
+        assert_eq!(
+            TicketSort::parse("-customer_name"),
+            Some(TicketSort {
+                field: TicketSortField::CustomerName,
+                descending: true,
+            })
+        );
+        assert_eq!(TicketSort::parse("--created_at"), None);
+        assert_eq!(TicketSort::parse("item_description"), None);
+        assert_eq!(TicketSort::parse("t.created_at; DROP TABLE tickets"), None);
+    }
+
+    #[test]
+    fn test_ticket_cursor_round_trip() {
+        let cursor = TicketCursor {
+            created_at: DateTime::from_timestamp_micros(1_768_820_400_123_456).unwrap(),
+            ticket_id: Uuid::new_v4(),
+        };
+        let encoded = cursor.encode();
+        assert!(encoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(TicketCursor::decode(&encoded), Some(cursor));
+    }
+
+    #[test]
+    fn test_ticket_cursor_rejects_garbage() {
+        assert_eq!(TicketCursor::decode(""), None);
+        assert_eq!(TicketCursor::decode("not a cursor!"), None);
+        assert_eq!(
+            TicketCursor::decode(&URL_SAFE_NO_PAD.encode("12_nope")),
+            None
+        );
+        assert_eq!(
+            TicketCursor::decode(&URL_SAFE_NO_PAD.encode(Uuid::nil().to_string())),
+            None
+        );
+    }
 }
@@ -0,0 +1,38 @@
+//! Ticket item model.
+//!
+//! The pieces brought in on one ticket. Every ticket has at least one item;
+//! item 1 mirrors the ticket's own item fields.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Maximum number of items on one ticket.
+pub const MAX_TICKET_ITEMS: usize = 20;
+
+/// One item on a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketItem {
+    pub item_id: Uuid,
+    pub ticket_id: Uuid,
+    /// 1-based position on the ticket
+    pub position: i32,
+    pub item_type: Option<String>,
+    pub item_description: String,
+    pub condition_notes: String,
+    pub requested_work: String,
+    pub quote_amount: Option<Decimal>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for one item of a new ticket.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTicketItem {
+    pub item_type: Option<String>,
+    pub item_description: String,
+    pub condition_notes: String,
+    pub requested_work: String,
+    pub quote_amount: Option<Decimal>,
+}
@@ -0,0 +1,76 @@
+//! Bench time entry model.
+//!
+//! An employee clocks in on a ticket while working on it and clocks out when
+//! they stop. Entries with no stop time are still running, and an employee
+//! has at most one running entry.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A stretch of bench time on a ticket.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TimeEntry {
+    pub entry_id: Uuid,
+    pub ticket_id: Uuid,
+    pub employee_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    /// None while the employee is still clocked in
+    pub stopped_at: Option<DateTime<Utc>>,
+}
+
+/// Time entry with ticket and employee names, for display.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TimeEntryEntry {
+    pub entry_id: Uuid,
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    pub employee_id: Uuid,
+    pub employee_name: String,
+    pub started_at: DateTime<Utc>,
+    pub stopped_at: Option<DateTime<Utc>>,
+}
+
+impl TimeEntryEntry {
+    /// Seconds worked, counting a running entry up to `now`.
+    pub fn seconds_at(&self, now: DateTime<Utc>) -> i64 {
+        let end = self.stopped_at.unwrap_or(now);
+        (end - self.started_at).num_seconds().max(0)
+    }
+}
+
+/// Total bench seconds across entries, counting running ones up to `now`.
+pub fn total_bench_seconds(entries: &[TimeEntryEntry], now: DateTime<Utc>) -> i64 {
+    entries.iter().map(|entry| entry.seconds_at(now)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn entry(started_at: DateTime<Utc>, stopped_at: Option<DateTime<Utc>>) -> TimeEntryEntry {
+        TimeEntryEntry {
+            entry_id: Uuid::new_v4(),
+            ticket_id: Uuid::new_v4(),
+            friendly_code: "JR-0001".to_string(),
+            employee_id: Uuid::new_v4(),
+            employee_name: "Sam".to_string(),
+            started_at,
+            stopped_at,
+        }
+    }
+
+    #[test]
+    fn test_total_bench_seconds() {
+        let now = Utc::now();
+        let entries = vec![
+            entry(now - Duration::hours(3), Some(now - Duration::hours(2))),
+            entry(now - Duration::minutes(15), None),
+        ];
+        assert_eq!(entries[0].seconds_at(now), 3600);
+        assert_eq!(entries[1].seconds_at(now), 900);
+        assert_eq!(total_bench_seconds(&entries, now), 4500);
+        assert_eq!(total_bench_seconds(&[], now), 0);
+    }
+}
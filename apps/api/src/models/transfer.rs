@@ -0,0 +1,84 @@
+//! Ticket transfer model.
+//!
+//! A transfer sends a ticket's item to another storage location. It stays in
+//! transit until an employee at the receiving location accepts it, which is
+//! when the ticket's storage location changes. Every transfer is kept as
+//! history, whether it was accepted or cancelled.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// State of a transfer, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "transfer_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TransferStatus {
+    /// Sent and in transit, waiting for the receiving location
+    Pending,
+    /// Received at the destination
+    Accepted,
+    /// Called off before it was received
+    Cancelled,
+}
+
+/// A transfer of a ticket between storage locations.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketTransfer {
+    pub transfer_id: Uuid,
+    pub ticket_id: Uuid,
+    pub from_location_id: Uuid,
+    pub to_location_id: Uuid,
+    pub status: TransferStatus,
+    pub requested_by: Uuid,
+    pub notes: Option<String>,
+    pub requested_at: DateTime<Utc>,
+    /// Employee who accepted or cancelled the transfer
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// Transfer with ticket, location, and employee names, for display.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TicketTransferEntry {
+    pub transfer_id: Uuid,
+    pub ticket_id: Uuid,
+    pub friendly_code: String,
+    pub from_location_id: Uuid,
+    pub from_location_name: String,
+    pub to_location_id: Uuid,
+    pub to_location_name: String,
+    pub status: TransferStatus,
+    pub requested_by: Uuid,
+    pub requested_by_name: String,
+    pub notes: Option<String>,
+    pub requested_at: DateTime<Utc>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_by_name: Option<String>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+/// Input for starting a transfer.
+#[derive(Debug, Clone)]
+pub struct CreateTicketTransfer {
+    pub ticket_id: Uuid,
+    pub from_location_id: Uuid,
+    pub to_location_id: Uuid,
+    pub requested_by: Uuid,
+    pub notes: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_status_serialization() {
+        let json = serde_json::to_string(&TransferStatus::Pending).unwrap();
+        assert_eq!(json, "\"pending\"");
+
+        let parsed: TransferStatus = serde_json::from_str("\"cancelled\"").unwrap();
+        assert_eq!(parsed, TransferStatus::Cancelled);
+    }
+}
@@ -0,0 +1,153 @@
+//! Completion estimate model.
+//!
+//! Estimated completion window shown on the public status page, derived
+//! from the ticket's place in the queue, how quickly the shop has been
+//! finishing work, and the item type's configured turnaround.
+
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+
+use super::ticket::TicketStatus;
+
+/// Turnaround assumed when the item type has none configured.
+pub const DEFAULT_TURNAROUND_DAYS: i64 = 7;
+
+/// Days of completed work used to measure the shop's pace.
+pub const THROUGHPUT_WINDOW_DAYS: i64 = 28;
+
+/// Width of the window as a fraction of the days remaining.
+const WINDOW_SPREAD: f64 = 0.25;
+
+/// What an estimate is computed from.
+#[derive(Debug, Clone)]
+pub struct WaitEstimateInput {
+    pub status: TicketStatus,
+    pub today: NaiveDate,
+    /// Date the ticket was taken in
+    pub intake_date: NaiveDate,
+    /// Item type's default turnaround, if configured
+    pub turnaround_days: Option<i32>,
+    /// Open tickets that will be worked before this one
+    pub tickets_ahead: i64,
+    /// Tickets made ready for pickup over the throughput window
+    pub recently_completed: i64,
+}
+
+/// Dates the work is expected to be finished between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct CompletionEstimate {
+    pub earliest: NaiveDate,
+    pub latest: NaiveDate,
+}
+
+/// Estimate when a ticket's work will be finished.
+///
+/// The work takes as long as the longer of what's left of the configured
+/// turnaround and the time to clear the tickets ahead at the recent pace.
+/// Returns None when there is nothing useful to say: the ticket is waiting
+/// on parts (arrival isn't tracked), or is already ready or closed.
+pub fn estimate_completion(input: &WaitEstimateInput) -> Option<CompletionEstimate> {
+    if !matches!(
+        input.status,
+        TicketStatus::Intake | TicketStatus::InProgress
+    ) {
+        return None;
+    }
+
+    let turnaround = input
+        .turnaround_days
+        .map(i64::from)
+        .unwrap_or(DEFAULT_TURNAROUND_DAYS);
+    let elapsed = (input.today - input.intake_date).num_days();
+    let turnaround_left = (turnaround - elapsed).max(0) as f64;
+
+    let daily_pace = input.recently_completed as f64 / THROUGHPUT_WINDOW_DAYS as f64;
+    let backlog_days = if daily_pace > 0.0 {
+        input.tickets_ahead.max(0) as f64 / daily_pace
+    } else {
+        0.0
+    };
+
+    let days = turnaround_left.max(backlog_days).ceil();
+    let spread = (days * WINDOW_SPREAD).ceil().max(1.0);
+    let earliest = input.today + Duration::days(days as i64);
+
+    Some(CompletionEstimate {
+        earliest,
+        latest: earliest + Duration::days(spread as i64),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    fn input() -> WaitEstimateInput {
+        WaitEstimateInput {
+            status: TicketStatus::Intake,
+            today: date(10),
+            intake_date: date(8),
+            turnaround_days: Some(7),
+            tickets_ahead: 0,
+            recently_completed: 28,
+        }
+    }
+
+    #[test]
+    fn test_estimate_uses_remaining_turnaround() {
+        // Taken in 2 days ago with a 7 day turnaround: 5 days left
+        let estimate = estimate_completion(&input()).unwrap();
+        assert_eq!(estimate.earliest, date(15));
+        assert_eq!(estimate.latest, date(17));
+    }
+
+    #[test]
+    fn test_estimate_uses_backlog_when_longer() {
+        // 12 tickets ahead at one per day outlasts the turnaround
+        let estimate = estimate_completion(&WaitEstimateInput {
+            tickets_ahead: 12,
+            ..input()
+        })
+        .unwrap();
+        assert_eq!(estimate.earliest, date(22));
+        assert_eq!(estimate.latest, date(25));
+    }
+
+    #[test]
+    fn test_estimate_defaults_turnaround_and_ignores_idle_shop() {
+        let estimate = estimate_completion(&WaitEstimateInput {
+            turnaround_days: None,
+            tickets_ahead: 40,
+            recently_completed: 0,
+            ..input()
+        })
+        .unwrap();
+        assert_eq!(estimate.earliest, date(15));
+    }
+
+    #[test]
+    fn test_overdue_ticket_estimates_today() {
+        let estimate = estimate_completion(&WaitEstimateInput {
+            intake_date: date(1),
+            ..input()
+        })
+        .unwrap();
+        assert_eq!(estimate.earliest, date(10));
+        assert_eq!(estimate.latest, date(11));
+    }
+
+    #[test]
+    fn test_no_estimate_outside_work_lanes() {
+        for status in [
+            TicketStatus::WaitingOnParts,
+            TicketStatus::ReadyForPickup,
+            TicketStatus::Closed,
+        ] {
+            assert!(estimate_completion(&WaitEstimateInput { status, ..input() }).is_none());
+        }
+    }
+}
@@ -0,0 +1,200 @@
+//! Webhook model.
+//!
+//! Admins register URLs that receive ticket events. Each event becomes a
+//! delivery in the outbox, one per subscribed webhook, which a background
+//! worker sends and retries with backoff.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+/// Version of the event envelope sent to receivers.
+pub const WEBHOOK_SCHEMA_VERSION: i32 = 1;
+
+/// Attempts made on a delivery before it is marked failed.
+pub const MAX_WEBHOOK_ATTEMPTS: i32 = 8;
+
+/// Wait before the first retry; doubled after each further failure.
+const WEBHOOK_RETRY_BASE_SECS: i64 = 30;
+
+/// Longest wait between retries.
+const WEBHOOK_RETRY_MAX_SECS: i64 = 60 * 60;
+
+/// An event a webhook can subscribe to, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "webhook_event")]
+pub enum WebhookEvent {
+    /// A ticket was taken in
+    #[serde(rename = "ticket.created")]
+    #[sqlx(rename = "ticket.created")]
+    TicketCreated,
+    /// A ticket moved to another status (including closing)
+    #[serde(rename = "ticket.status_changed")]
+    #[sqlx(rename = "ticket.status_changed")]
+    TicketStatusChanged,
+    /// A ticket was closed out
+    #[serde(rename = "ticket.closed")]
+    #[sqlx(rename = "ticket.closed")]
+    TicketClosed,
+    /// A photo was added to a ticket
+    #[serde(rename = "photo.uploaded")]
+    #[sqlx(rename = "photo.uploaded")]
+    PhotoUploaded,
+    /// A ticket was assigned to an employee or unassigned
+    #[serde(rename = "ticket.assigned")]
+    #[sqlx(rename = "ticket.assigned")]
+    TicketAssigned,
+}
+
+impl WebhookEvent {
+    /// Event name as sent to receivers (e.g., `ticket.created`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::TicketCreated => "ticket.created",
+            Self::TicketStatusChanged => "ticket.status_changed",
+            Self::TicketClosed => "ticket.closed",
+            Self::PhotoUploaded => "photo.uploaded",
+            Self::TicketAssigned => "ticket.assigned",
+        }
+    }
+}
+
+/// Where a delivery stands, matching the database type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[sqlx(type_name = "webhook_delivery_status", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its next attempt
+    Pending,
+    /// Accepted by the receiver (2xx response)
+    Delivered,
+    /// Gave up after the last attempt
+    Failed,
+}
+
+/// A registered webhook.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Webhook {
+    pub webhook_id: Uuid,
+    pub url: String,
+    pub description: Option<String>,
+    pub events: Vec<WebhookEvent>,
+    /// Signing secret (only returned when created or rotated)
+    #[serde(skip_serializing, default)]
+    pub secret: String,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Input for registering a webhook.
+#[derive(Debug, Clone)]
+pub struct CreateWebhook {
+    pub url: String,
+    pub description: Option<String>,
+    pub events: Vec<WebhookEvent>,
+    pub secret: String,
+}
+
+/// Input for updating a webhook.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateWebhook {
+    pub url: Option<String>,
+    /// Description (null to clear)
+    #[serde(
+        default,
+        deserialize_with = "crate::handlers::tickets::deserialize_optional_nullable"
+    )]
+    pub description: Option<Option<String>>,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub is_active: Option<bool>,
+}
+
+/// One event queued for one webhook.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct WebhookDelivery {
+    pub delivery_id: Uuid,
+    pub webhook_id: Uuid,
+    pub event: WebhookEvent,
+    /// The event's `data`
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    /// HTTP status of the last response, if one came back
+    pub response_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    /// Body of the last response (truncated)
+    pub response_body: Option<String>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+}
+
+/// A claimed delivery with where to send it and how to sign it.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct WebhookDispatch {
+    #[sqlx(flatten)]
+    pub delivery: WebhookDelivery,
+    pub url: String,
+    pub secret: String,
+}
+
+/// How long to wait after a delivery's `attempts`-th failed attempt.
+///
+/// 30 seconds after the first, doubling up to an hour.
+pub fn webhook_retry_delay(attempts: i32) -> Duration {
+    let doublings = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    Duration::seconds((WEBHOOK_RETRY_BASE_SECS << doublings).min(WEBHOOK_RETRY_MAX_SECS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_webhook_event_names() {
+        for event in [
+            WebhookEvent::TicketCreated,
+            WebhookEvent::TicketStatusChanged,
+            WebhookEvent::TicketClosed,
+            WebhookEvent::PhotoUploaded,
+            WebhookEvent::TicketAssigned,
+        ] {
+            let json = serde_json::to_string(&event).unwrap();
+            assert_eq!(json, format!("\"{}\"", event.as_str()));
+            assert_eq!(serde_json::from_str::<WebhookEvent>(&json).unwrap(), event);
+        }
+        assert!(serde_json::from_str::<WebhookEvent>("\"ticket_created\"").is_err());
+    }
+
+    #[test]
+    fn test_webhook_retry_delay_backs_off() {
+        assert_eq!(webhook_retry_delay(1), Duration::seconds(30));
+        assert_eq!(webhook_retry_delay(2), Duration::seconds(60));
+        assert_eq!(webhook_retry_delay(4), Duration::seconds(240));
+        assert_eq!(
+            webhook_retry_delay(MAX_WEBHOOK_ATTEMPTS),
+            Duration::hours(1)
+        );
+        assert_eq!(webhook_retry_delay(40), Duration::hours(1));
+    }
+
+    #[test]
+    fn test_webhook_never_serializes_secret() {
+        let webhook = Webhook {
+            webhook_id: Uuid::new_v4(),
+            url: "https://example.com/hooks".to_string(),
+            description: None,
+            events: vec![WebhookEvent::TicketCreated],
+            secret: "whsec_abc".to_string(),
+            is_active: true,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let json = serde_json::to_string(&webhook).unwrap();
+        assert!(json.contains("\"events\":[\"ticket.created\"]"));
+        assert!(!json.contains("whsec_abc"));
+    }
+}
@@ -0,0 +1,1652 @@
+//! OpenAPI 3 description of the API.
+//!
+//! [`OPERATIONS`] lists every route the router serves with how it
+//! authenticates, what it accepts, and what it returns. The document served
+//! at `GET /api/v1/openapi.json` is built from it. Add an entry here when
+//! adding a route; the tests fail for any handler in `routes/mod.rs` that
+//! isn't listed, and for any listed path the router doesn't serve.
+//!
+//! Request and response fields are described in `docs/API.md`; every JSON
+//! response uses the envelope in `components.schemas.Envelope`.
+
+use serde_json::{json, Map, Value};
+
+use crate::error::ERROR_CATALOG;
+
+/// HTTP method of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl Method {
+    /// Lowercase name, as used for OpenAPI path item keys.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "get",
+            Method::Post => "post",
+            Method::Put => "put",
+            Method::Patch => "patch",
+            Method::Delete => "delete",
+        }
+    }
+}
+
+/// How an operation authenticates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Auth {
+    /// No authentication
+    None,
+    /// An employee session
+    Employee,
+    /// Admin session or PIN
+    Admin,
+    /// Admin session only; the deprecated PIN header isn't accepted
+    AdminSession,
+    /// Admin, or an employee session holding the named permission
+    Permission(&'static str),
+    /// X-Partner-Key
+    PartnerKey,
+    /// X-Facet-Signature over the request body
+    Signature,
+    /// `expires` and `signature` query parameters from a signed URL
+    SignedUrl,
+}
+
+/// What an operation accepts as its request body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Body {
+    None,
+    Json,
+    /// multipart/form-data with a `file` field
+    Multipart,
+}
+
+/// What an operation returns on success.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reply {
+    /// 200 with a JSON envelope
+    Json,
+    /// 201 with a JSON envelope
+    Created,
+    /// 200 with a PDF
+    Pdf,
+    /// 200 with a PNG image
+    Png,
+    /// 200 with ESC/POS printer bytes
+    EscPos,
+    /// 200 with plain text
+    Text,
+    /// 200 with a receipt in the store's default format
+    Receipt,
+    /// 200 with the stored file's bytes
+    File,
+    /// 200 with a JSON envelope, or CSV when the path ends in `.csv`
+    JsonOrCsv,
+    /// 200 with an HTML page
+    Html,
+    /// 200 with a bare JSON document (no envelope)
+    Document,
+    /// 200 with Prometheus text metrics
+    Metrics,
+    /// 200 with an iCalendar feed
+    Calendar,
+    /// 307 to a signed URL for a stored file
+    Redirect,
+}
+
+/// One documented route and method.
+#[derive(Debug, Clone, Copy)]
+pub struct ApiOperation {
+    pub method: Method,
+    /// Full path with `{param}` placeholders
+    pub path: &'static str,
+    /// Name of the handler function
+    pub operation_id: &'static str,
+    pub summary: &'static str,
+    pub auth: Auth,
+    pub body: Body,
+    pub reply: Reply,
+}
+
+impl ApiOperation {
+    const fn new(
+        method: Method,
+        path: &'static str,
+        operation_id: &'static str,
+        summary: &'static str,
+        body: Body,
+    ) -> Self {
+        Self {
+            method,
+            path,
+            operation_id,
+            summary,
+            auth: Auth::None,
+            body,
+            reply: Reply::Json,
+        }
+    }
+
+    const fn get(path: &'static str, operation_id: &'static str, summary: &'static str) -> Self {
+        Self::new(Method::Get, path, operation_id, summary, Body::None)
+    }
+
+    const fn post(path: &'static str, operation_id: &'static str, summary: &'static str) -> Self {
+        Self::new(Method::Post, path, operation_id, summary, Body::Json)
+    }
+
+    const fn put(path: &'static str, operation_id: &'static str, summary: &'static str) -> Self {
+        Self::new(Method::Put, path, operation_id, summary, Body::Json)
+    }
+
+    const fn patch(path: &'static str, operation_id: &'static str, summary: &'static str) -> Self {
+        Self::new(Method::Patch, path, operation_id, summary, Body::Json)
+    }
+
+    const fn delete(path: &'static str, operation_id: &'static str, summary: &'static str) -> Self {
+        Self::new(Method::Delete, path, operation_id, summary, Body::None)
+    }
+
+    const fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    const fn body(mut self, body: Body) -> Self {
+        self.body = body;
+        self
+    }
+
+    const fn reply(mut self, reply: Reply) -> Self {
+        self.reply = reply;
+        self
+    }
+}
+
+/// Tag for each top-level path segment under /api/v1, in display order.
+const TAGS: &[(&str, &str)] = &[
+    ("tickets", "Tickets"),
+    ("queue", "Queue"),
+    ("transfers", "Transfers"),
+    ("customers", "Customers"),
+    ("employees", "Employees"),
+    ("locations", "Storage Locations"),
+    ("stores", "Stores"),
+    ("materials", "Materials"),
+    ("settings", "Store Settings"),
+    ("admin", "Admin"),
+    ("reports", "Reports"),
+    ("public", "Public Status Lookup"),
+    ("calendar", "Calendar Feeds"),
+    ("partner", "Partner API"),
+    ("intake-drafts", "Intake Drafts"),
+    ("integrations", "Integrations"),
+    ("storage", "Storage"),
+];
+
+/// Tag for routes outside the domain groups (health, errors, docs).
+const SERVICE_TAG: &str = "Service";
+
+/// Every route the API serves.
+pub const OPERATIONS: &[ApiOperation] = &[
+    ApiOperation::get(
+        "/health",
+        "health_check",
+        "Check the server and provider breakers",
+    ),
+    ApiOperation::get(
+        "/health/ready",
+        "readiness_check",
+        "Check the database, storage, and migrations (503 until ready)",
+    ),
+    ApiOperation::get(
+        "/health/live",
+        "liveness_check",
+        "Whether the process answers, without checking dependencies",
+    ),
+    ApiOperation::get(
+        "/metrics",
+        "get_metrics",
+        "Prometheus metrics, when METRICS_ENABLED is set",
+    )
+    .reply(Reply::Metrics),
+    ApiOperation::get(
+        "/api/v1/openapi.json",
+        "get_openapi_spec",
+        "This OpenAPI document",
+    )
+    .reply(Reply::Document),
+    ApiOperation::get("/docs", "get_api_docs", "Swagger UI for this API").reply(Reply::Html),
+    ApiOperation::get(
+        "/api/v1/tickets",
+        "list_tickets",
+        "List tickets with filters",
+    ),
+    ApiOperation::post("/api/v1/tickets", "create_ticket", "Create a new ticket")
+        .auth(Auth::Employee)
+        .reply(Reply::Created),
+    ApiOperation::post(
+        "/api/v1/tickets/quote",
+        "quote_ticket",
+        "Calculate a quote with any rush surcharge",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::get(
+        "/api/v1/tickets/promise-date-suggestion",
+        "suggest_promise_date",
+        "Suggest a promise date on a day the store is open",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}",
+        "get_ticket",
+        "Get full ticket details",
+    ),
+    ApiOperation::put(
+        "/api/v1/tickets/{ticket_id}",
+        "update_ticket",
+        "Update a ticket",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::delete(
+        "/api/v1/tickets/{ticket_id}",
+        "delete_ticket",
+        "Soft-delete a ticket",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/restore",
+        "restore_ticket",
+        "Restore a soft-deleted ticket",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/history",
+        "get_ticket_history",
+        "Get the ticket's audit trail",
+    ),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/receipt",
+        "get_receipt",
+        "Generate a receipt in the store's default format",
+    )
+    .reply(Reply::Receipt),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/receipt.pdf",
+        "get_receipt_pdf",
+        "Generate receipt PDF for a ticket",
+    )
+    .reply(Reply::Pdf),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/receipt.escpos",
+        "get_receipt_escpos",
+        "Generate an ESC/POS receipt for a thermal printer",
+    )
+    .reply(Reply::EscPos),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/receipt.txt",
+        "get_receipt_text",
+        "Generate a plain-text receipt",
+    )
+    .reply(Reply::Text),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/invoice.pdf",
+        "get_invoice_pdf",
+        "Generate an invoice PDF for a closed ticket",
+    )
+    .reply(Reply::Pdf),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/label.pdf",
+        "get_label_pdf",
+        "Generate label PDF for a physical tag",
+    )
+    .reply(Reply::Pdf),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/appraisal",
+        "get_appraisal",
+        "Get a ticket's appraisal",
+    ),
+    ApiOperation::put(
+        "/api/v1/tickets/{ticket_id}/appraisal",
+        "save_appraisal",
+        "Record or revise a ticket's appraisal",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::delete(
+        "/api/v1/tickets/{ticket_id}/appraisal",
+        "delete_appraisal",
+        "Remove a ticket's appraisal",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/appraisal.pdf",
+        "get_appraisal_pdf",
+        "Generate the appraisal PDF",
+    )
+    .reply(Reply::Pdf),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/work-order.pdf",
+        "get_work_order_pdf",
+        "Generate work order PDF for the bench",
+    )
+    .reply(Reply::Pdf),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/status",
+        "change_status",
+        "Change ticket status",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/bulk/status",
+        "bulk_change_status",
+        "Change the status of several tickets at once",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/close",
+        "close_ticket",
+        "Close a ticket",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/reopen",
+        "reopen_ticket",
+        "Reopen a closed ticket",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/archive",
+        "archive_ticket",
+        "Archive a closed ticket",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/rush",
+        "toggle_rush",
+        "Toggle rush flag on a ticket",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/assign",
+        "assign_ticket",
+        "Assign a ticket to an employee",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/notes",
+        "add_note",
+        "Add an internal note to a ticket",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/payments",
+        "list_payments",
+        "List a ticket's payments",
+    ),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/payments",
+        "record_payment",
+        "Record a payment on a ticket",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/materials",
+        "list_ticket_materials",
+        "List the materials used on a ticket",
+    ),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/materials",
+        "record_ticket_material",
+        "Record a material used on a ticket, taking it out of stock",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/contacts",
+        "list_contacts",
+        "List a ticket's customer contacts",
+    ),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/contacts",
+        "log_contact",
+        "Log a call or message with the customer",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/authorized-pickups",
+        "list_authorized_pickups",
+        "List authorizations and releases",
+    ),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/authorized-pickups",
+        "create_authorized_pickup",
+        "Authorize someone to collect the item",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::delete(
+        "/api/v1/tickets/{ticket_id}/authorized-pickups/{authorized_pickup_id}",
+        "revoke_authorized_pickup",
+        "Revoke an authorization",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/pickups/{pickup_id}/signature",
+        "get_pickup_signature",
+        "Signature captured at a release",
+    )
+    .reply(Reply::Png),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/quote/send",
+        "send_quote",
+        "Record that the quote was given to the customer",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/quote/approve",
+        "approve_quote",
+        "Record the customer's approval of the quote",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/quote/decline",
+        "decline_quote",
+        "Record that the customer declined the quote",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/qc",
+        "record_qc_check",
+        "Record a QC checklist result",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/defects",
+        "record_defect",
+        "Record a defect for a customer return",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/custody",
+        "get_custody_chain",
+        "Get the chain of custody",
+    ),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/custody",
+        "record_custody_handoff",
+        "Record a custody handoff",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/custody.pdf",
+        "get_custody_report_pdf",
+        "Generate chain-of-custody report PDF",
+    )
+    .reply(Reply::Pdf),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/transfer",
+        "create_transfer",
+        "Send a ticket to another location",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/transfer/accept",
+        "accept_transfer",
+        "Confirm receipt of a ticket",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/transfer/cancel",
+        "cancel_transfer",
+        "Call off a transfer",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/transfers",
+        "list_ticket_transfers",
+        "List a ticket's transfers",
+    ),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/movements",
+        "list_ticket_movements",
+        "List a ticket's movements and where the item is now",
+    ),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/movements",
+        "record_movement",
+        "Check an item out to a bench or back into storage",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/time",
+        "list_time_entries",
+        "List a ticket's bench time entries",
+    ),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/time/start",
+        "start_time_entry",
+        "Clock in on a ticket",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/time/stop",
+        "stop_time_entry",
+        "Clock out of a ticket",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/tickets/{ticket_id}/photos",
+        "upload_photo",
+        "Upload a photo to a ticket",
+    )
+    .auth(Auth::Employee)
+    .body(Body::Multipart)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/tickets/{ticket_id}/photos/{photo_id}",
+        "get_photo",
+        "Redirect to a signed URL for a photo",
+    )
+    .reply(Reply::Redirect),
+    ApiOperation::delete(
+        "/api/v1/tickets/{ticket_id}/photos/{photo_id}",
+        "delete_photo",
+        "Delete a photo",
+    )
+    .auth(Auth::Permission("delete_photos")),
+    ApiOperation::get(
+        "/api/v1/queue",
+        "get_queue",
+        "Get workboard queue with tickets grouped by status lane",
+    ),
+    ApiOperation::post(
+        "/api/v1/queue/{lane}/reorder",
+        "reorder_queue",
+        "Set the manual order of a workboard lane",
+    )
+    .auth(Auth::Permission("modify_any_ticket")),
+    ApiOperation::get(
+        "/api/v1/transfers",
+        "list_transfers",
+        "List transfers across tickets",
+    ),
+    ApiOperation::get("/api/v1/employees", "list_employees", "List all employees")
+        .auth(Auth::Permission("manage_employees")),
+    ApiOperation::get(
+        "/api/v1/employees/me",
+        "get_my_profile",
+        "Get the logged-in employee's profile",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::get(
+        "/api/v1/employees/active",
+        "list_active_employees",
+        "List active employees' IDs and names",
+    ),
+    ApiOperation::post(
+        "/api/v1/employees",
+        "create_employee",
+        "Create a new employee",
+    )
+    .auth(Auth::Permission("manage_employees"))
+    .reply(Reply::Created),
+    ApiOperation::put(
+        "/api/v1/employees/{employee_id}",
+        "update_employee",
+        "Update an employee",
+    )
+    .auth(Auth::Permission("manage_employees")),
+    ApiOperation::delete(
+        "/api/v1/employees/{employee_id}",
+        "delete_employee",
+        "Delete an employee",
+    )
+    .auth(Auth::Permission("manage_employees")),
+    ApiOperation::get(
+        "/api/v1/employees/{employee_id}/permissions",
+        "get_employee_permissions",
+        "Get an employee's permissions",
+    )
+    .auth(Auth::Permission("manage_employees")),
+    ApiOperation::put(
+        "/api/v1/employees/{employee_id}/permissions",
+        "set_employee_permissions",
+        "Replace an employee's permissions",
+    )
+    .auth(Auth::Permission("manage_employees")),
+    ApiOperation::post(
+        "/api/v1/employees/{employee_id}/unlock",
+        "unlock_employee",
+        "Lift an employee's PIN lockout",
+    )
+    .auth(Auth::Permission("manage_employees")),
+    ApiOperation::post(
+        "/api/v1/employees/verify",
+        "verify_employee_pin",
+        "Verify an employee PIN and create a session",
+    ),
+    ApiOperation::post(
+        "/api/v1/employees/verify/challenge",
+        "create_pin_challenge",
+        "Issue a nonce for challenge-response PIN entry",
+    )
+    .reply(Reply::Created),
+    ApiOperation::post(
+        "/api/v1/employees/verify/response",
+        "verify_employee_pin_challenge",
+        "Verify a PIN challenge response and create a session",
+    ),
+    ApiOperation::post(
+        "/api/v1/employees/logout",
+        "employee_logout",
+        "End the employee session",
+    )
+    .auth(Auth::Employee)
+    .body(Body::None),
+    ApiOperation::put(
+        "/api/v1/employees/training",
+        "set_training_mode",
+        "Switch the session into or out of training mode",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::get(
+        "/api/v1/customers",
+        "search_customers",
+        "Search customers for autocomplete",
+    ),
+    ApiOperation::post(
+        "/api/v1/customers",
+        "create_customer",
+        "Create a customer outside of ticket intake",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/customers/{customer_id}",
+        "get_customer",
+        "Get customer with ticket history",
+    ),
+    ApiOperation::put(
+        "/api/v1/customers/{customer_id}",
+        "update_customer",
+        "Update a customer",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::delete(
+        "/api/v1/customers/{customer_id}",
+        "delete_customer",
+        "Soft-delete a customer",
+    )
+    .auth(Auth::Permission("delete_customers")),
+    ApiOperation::post(
+        "/api/v1/customers/{customer_id}/merge",
+        "merge_customer",
+        "Merge a duplicate into this customer",
+    )
+    .auth(Auth::Permission("delete_customers")),
+    ApiOperation::post(
+        "/api/v1/admin/setup",
+        "admin_setup",
+        "Initial admin setup (force password change)",
+    ),
+    ApiOperation::post(
+        "/api/v1/admin/verify",
+        "verify_admin",
+        "Verify the admin PIN and get a session token",
+    ),
+    ApiOperation::post(
+        "/api/v1/admin/change-pin",
+        "change_pin",
+        "Change the admin PIN",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/logout",
+        "admin_logout",
+        "End the admin session",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::post(
+        "/api/v1/admin/recover",
+        "recover_admin",
+        "Reset a lost admin PIN with the recovery code",
+    ),
+    ApiOperation::post(
+        "/api/v1/admin/recovery/code",
+        "regenerate_recovery_code",
+        "Issue a new recovery code",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::get(
+        "/api/v1/admin/recovery/attempts",
+        "list_recovery_attempts",
+        "List recovery attempts, newest first",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/admin/audit-log",
+        "list_audit_log",
+        "List admin actions, newest first",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/admin/debug-capture",
+        "get_debug_capture",
+        "Get the debug capture toggle",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::put(
+        "/api/v1/admin/debug-capture",
+        "update_debug_capture",
+        "Enable or disable debug capture",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/admin/request-logs",
+        "list_request_logs",
+        "List captured requests, most recent first",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/admin/config/export",
+        "export_config",
+        "Export the store configuration",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/config/import",
+        "import_config",
+        "Import a store configuration",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/import/customers",
+        "import_customers",
+        "Import customers from CSV",
+    )
+    .auth(Auth::Admin)
+    .body(Body::Multipart),
+    ApiOperation::post(
+        "/api/v1/admin/import/tickets",
+        "import_tickets",
+        "Import tickets from CSV",
+    )
+    .auth(Auth::Admin)
+    .body(Body::Multipart),
+    ApiOperation::get(
+        "/api/v1/admin/integrity",
+        "get_integrity_report",
+        "Run the consistency checks and report problems",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/admin/jobs",
+        "list_jobs",
+        "List background jobs with their last and next runs",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/storage/reconcile",
+        "reconcile_storage",
+        "Delete orphaned photo objects now",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::get(
+        "/api/v1/admin/partners",
+        "list_partners",
+        "List partner accounts",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/partners",
+        "create_partner",
+        "Create a partner account",
+    )
+    .auth(Auth::Admin)
+    .reply(Reply::Created),
+    ApiOperation::put(
+        "/api/v1/admin/partners/{partner_id}",
+        "update_partner",
+        "Update a partner account",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/partners/{partner_id}/rotate-key",
+        "rotate_partner_key",
+        "Issue a new API key",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::get("/api/v1/admin/webhooks", "list_webhooks", "List webhooks").auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/webhooks",
+        "create_webhook",
+        "Register a webhook for ticket events",
+    )
+    .auth(Auth::Admin)
+    .reply(Reply::Created),
+    ApiOperation::put(
+        "/api/v1/admin/webhooks/{webhook_id}",
+        "update_webhook",
+        "Update a webhook",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::delete(
+        "/api/v1/admin/webhooks/{webhook_id}",
+        "delete_webhook",
+        "Delete a webhook",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/webhooks/{webhook_id}/rotate-secret",
+        "rotate_webhook_secret",
+        "Issue a new signing secret",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::get(
+        "/api/v1/admin/webhooks/{webhook_id}/deliveries",
+        "list_webhook_deliveries",
+        "List a webhook's deliveries with the last response",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/webhooks/{webhook_id}/deliveries/{delivery_id}/redeliver",
+        "redeliver_webhook_delivery",
+        "Send a delivered or failed event again",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::get(
+        "/api/v1/admin/campaigns",
+        "list_campaigns",
+        "List campaigns with progress, newest first",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/campaigns",
+        "create_campaign",
+        "Create a campaign and queue it for sending",
+    )
+    .auth(Auth::Admin)
+    .reply(Reply::Created),
+    ApiOperation::post(
+        "/api/v1/admin/campaigns/preview",
+        "preview_campaign",
+        "Count recipients and render the message",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/admin/campaigns/{campaign_id}",
+        "get_campaign",
+        "Get a campaign with progress",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/admin/campaigns/{campaign_id}/recipients",
+        "list_campaign_recipients",
+        "List delivery status per customer",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/admin/campaigns/{campaign_id}/abort",
+        "abort_campaign",
+        "Stop sending a campaign",
+    )
+    .auth(Auth::Admin)
+    .body(Body::None),
+    ApiOperation::get("/api/v1/settings", "get_settings", "Get store settings"),
+    ApiOperation::put(
+        "/api/v1/settings",
+        "update_settings",
+        "Update store settings",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/settings/location-rules",
+        "get_location_rules",
+        "List storage suggestion rules",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::put(
+        "/api/v1/settings/location-rules",
+        "update_location_rules",
+        "Replace storage suggestion rules",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/settings/metal-prices",
+        "get_metal_prices",
+        "List metal prices",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::put(
+        "/api/v1/settings/metal-prices",
+        "update_metal_prices",
+        "Replace metal prices",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/settings/item-types",
+        "get_item_types",
+        "List item types",
+    ),
+    ApiOperation::put(
+        "/api/v1/settings/item-types",
+        "update_item_types",
+        "Replace item types",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/settings/promise-date-reasons",
+        "get_promise_date_reasons",
+        "List promise date reasons",
+    ),
+    ApiOperation::put(
+        "/api/v1/settings/promise-date-reasons",
+        "update_promise_date_reasons",
+        "Replace promise date reasons",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/settings/rush-pricing",
+        "get_rush_pricing",
+        "List rush surcharge tiers",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::put(
+        "/api/v1/settings/rush-pricing",
+        "update_rush_pricing",
+        "Replace rush surcharge tiers",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/settings/notification-templates",
+        "list_notification_templates",
+        "List email templates",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::put(
+        "/api/v1/settings/notification-templates/{event}",
+        "update_notification_template",
+        "Update an email template",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::post(
+        "/api/v1/settings/templates/validate",
+        "validate_template",
+        "Preview a template",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/settings/history",
+        "get_settings_history",
+        "List settings changes, newest first",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::post(
+        "/api/v1/settings/rollback/{change_id}",
+        "rollback_settings",
+        "Revert a settings change",
+    )
+    .auth(Auth::AdminSession)
+    .body(Body::None),
+    ApiOperation::put(
+        "/api/v1/settings/receipt-logo",
+        "upload_receipt_logo",
+        "Upload the receipt logo",
+    )
+    .auth(Auth::Permission("manage_settings"))
+    .body(Body::Multipart),
+    ApiOperation::delete(
+        "/api/v1/settings/receipt-logo",
+        "delete_receipt_logo",
+        "Remove the receipt logo",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::post(
+        "/api/v1/settings/calendar-feed",
+        "rotate_calendar_feed_token",
+        "Issue a new promise date feed token, revoking the old one",
+    )
+    .auth(Auth::Permission("manage_settings"))
+    .body(Body::None),
+    ApiOperation::delete(
+        "/api/v1/settings/calendar-feed",
+        "revoke_calendar_feed_token",
+        "Turn off the promise date feed",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/settings/{section}",
+        "get_settings_section",
+        "Get one settings section",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::patch(
+        "/api/v1/settings/{section}",
+        "patch_settings_section",
+        "Update one settings section",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/locations",
+        "list_locations",
+        "List all storage locations",
+    ),
+    ApiOperation::post(
+        "/api/v1/locations",
+        "create_location",
+        "Create a new storage location",
+    )
+    .auth(Auth::Permission("manage_locations"))
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/locations/suggest",
+        "suggest_location",
+        "Suggest where to store an item",
+    ),
+    ApiOperation::put(
+        "/api/v1/locations/{location_id}",
+        "update_location",
+        "Update a storage location",
+    )
+    .auth(Auth::Permission("manage_locations")),
+    ApiOperation::delete(
+        "/api/v1/locations/{location_id}",
+        "delete_location",
+        "Deactivate an empty storage location",
+    )
+    .auth(Auth::Permission("manage_locations")),
+    ApiOperation::get(
+        "/api/v1/locations/{location_id}/tickets",
+        "list_location_tickets",
+        "List open tickets stored at a location",
+    ),
+    ApiOperation::get("/api/v1/stores", "list_stores", "List the chain's stores"),
+    ApiOperation::post("/api/v1/stores", "create_store", "Open a store")
+        .auth(Auth::Admin)
+        .reply(Reply::Created),
+    ApiOperation::delete("/api/v1/stores/{store_id}", "delete_store", "Close a store")
+        .auth(Auth::Admin),
+    ApiOperation::get("/api/v1/services", "list_services", "List catalog services"),
+    ApiOperation::post(
+        "/api/v1/services",
+        "create_service",
+        "Add a service to the catalog",
+    )
+    .auth(Auth::Permission("manage_settings"))
+    .reply(Reply::Created),
+    ApiOperation::put(
+        "/api/v1/services/{service_id}",
+        "update_service",
+        "Update a catalog service",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::delete(
+        "/api/v1/services/{service_id}",
+        "delete_service",
+        "Deactivate a catalog service",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/materials",
+        "list_materials",
+        "List the materials inventory",
+    ),
+    ApiOperation::post(
+        "/api/v1/materials",
+        "create_material",
+        "Add a material to the inventory",
+    )
+    .auth(Auth::Permission("manage_settings"))
+    .reply(Reply::Created),
+    ApiOperation::put(
+        "/api/v1/materials/{material_id}",
+        "update_material",
+        "Update a material or its stock",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::delete(
+        "/api/v1/materials/{material_id}",
+        "delete_material",
+        "Deactivate a material",
+    )
+    .auth(Auth::Permission("manage_settings")),
+    ApiOperation::get(
+        "/api/v1/reports/quality",
+        "quality_report",
+        "Rework rates by employee, item type, and reason",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/partners",
+        "partner_report",
+        "Trade work and API usage by partner",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/employees",
+        "employee_report",
+        "Tickets taken in, worked, and closed per employee",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/services",
+        "service_report",
+        "Tickets, prices, and turnaround by service",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/revenue",
+        "revenue_report",
+        "Revenue from closed tickets",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/throughput",
+        "throughput_report",
+        "Tickets received and closed, with turnaround",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/queue-trends",
+        "queue_trends_report",
+        "How the open ticket backlog changes over time",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/overdue",
+        "overdue_report",
+        "Open tickets due today and overdue",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/location-audit",
+        "location_audit_report",
+        "Items out of storage and their movements",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/daily-closeout",
+        "daily_closeout_report",
+        "The store's end-of-day summary for a date",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/reports/daily-closeout.pdf",
+        "daily_closeout_pdf",
+        "Generate the daily closeout PDF",
+    )
+    .auth(Auth::Admin)
+    .reply(Reply::Pdf),
+    ApiOperation::get(
+        "/api/v1/reports/custom",
+        "list_custom_reports",
+        "List saved custom reports",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::post(
+        "/api/v1/reports/custom",
+        "create_custom_report",
+        "Save a custom report",
+    )
+    .auth(Auth::Admin)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/reports/custom/{report_id}",
+        "run_custom_report",
+        "Run a custom report",
+    )
+    .auth(Auth::Admin)
+    .reply(Reply::JsonOrCsv),
+    ApiOperation::put(
+        "/api/v1/reports/custom/{report_id}",
+        "update_custom_report",
+        "Replace a custom report",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::delete(
+        "/api/v1/reports/custom/{report_id}",
+        "delete_custom_report",
+        "Delete a custom report",
+    )
+    .auth(Auth::Admin),
+    ApiOperation::get(
+        "/api/v1/public/tickets/{friendly_code}/status",
+        "get_public_ticket_status",
+        "Look up a ticket's status",
+    ),
+    ApiOperation::get(
+        "/api/v1/calendar/promise-dates.ics",
+        "get_promise_date_feed",
+        "Promise dates as an iCalendar feed (?token=)",
+    )
+    .reply(Reply::Calendar),
+    ApiOperation::get(
+        "/api/v1/partner/tickets",
+        "partner_list_tickets",
+        "List the partner's tickets, newest first",
+    )
+    .auth(Auth::PartnerKey),
+    ApiOperation::post(
+        "/api/v1/partner/tickets",
+        "partner_create_ticket",
+        "Submit trade work",
+    )
+    .auth(Auth::PartnerKey)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/partner/tickets/{friendly_code}",
+        "partner_get_ticket",
+        "Get one of the partner's tickets",
+    )
+    .auth(Auth::PartnerKey),
+    ApiOperation::get(
+        "/api/v1/intake-drafts",
+        "list_intake_drafts",
+        "List intake drafts, oldest first",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::get(
+        "/api/v1/intake-drafts/{draft_id}",
+        "get_intake_draft",
+        "Get one intake draft",
+    )
+    .auth(Auth::Employee),
+    ApiOperation::post(
+        "/api/v1/intake-drafts/{draft_id}/convert",
+        "convert_intake_draft",
+        "Create a ticket from a draft",
+    )
+    .auth(Auth::Employee)
+    .reply(Reply::Created),
+    ApiOperation::post(
+        "/api/v1/intake-drafts/{draft_id}/dismiss",
+        "dismiss_intake_draft",
+        "Dismiss a draft without a ticket",
+    )
+    .auth(Auth::Employee)
+    .body(Body::None),
+    ApiOperation::post(
+        "/api/v1/integrations/phone-intake",
+        "receive_phone_intake",
+        "Record a transcribed call as a draft",
+    )
+    .auth(Auth::Signature)
+    .reply(Reply::Created),
+    ApiOperation::get(
+        "/api/v1/errors",
+        "get_error_catalog",
+        "List every error code the API can return",
+    ),
+    ApiOperation::get(
+        "/api/v1/storage/{key}",
+        "get_stored_object",
+        "Download an object through a signed URL",
+    )
+    .auth(Auth::SignedUrl)
+    .reply(Reply::File),
+];
+
+/// Tag for an operation, from the first path segment under /api/v1.
+fn tag_for(path: &str) -> &'static str {
+    let segment = path
+        .strip_prefix("/api/v1/")
+        .and_then(|rest| rest.split('/').next())
+        .unwrap_or_default();
+    TAGS.iter()
+        .find(|(prefix, _)| *prefix == segment)
+        .map(|(_, tag)| *tag)
+        .unwrap_or(SERVICE_TAG)
+}
+
+/// Names of the `{param}` placeholders in a path.
+fn path_params(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/')
+        .filter_map(|segment| segment.strip_prefix('{')?.split('}').next())
+}
+
+/// Security requirement alternatives for an operation.
+fn security(auth: Auth) -> Value {
+    match auth {
+        Auth::None | Auth::SignedUrl => json!([]),
+        Auth::Employee => json!([{ "EmployeeSession": [] }]),
+        Auth::Admin => json!([{ "AdminSession": [] }, { "AdminPin": [] }]),
+        Auth::AdminSession => json!([{ "AdminSession": [] }]),
+        Auth::Permission(_) => json!([
+            { "AdminSession": [] },
+            { "AdminPin": [] },
+            { "EmployeeSession": [] }
+        ]),
+        Auth::PartnerKey => json!([{ "PartnerKey": [] }]),
+        Auth::Signature => json!([{ "FacetSignature": [] }]),
+    }
+}
+
+/// Success responses for an operation.
+fn success_responses(reply: Reply) -> Value {
+    let envelope = json!({
+        "application/json": { "schema": { "$ref": "#/components/schemas/Envelope" } }
+    });
+    let binary = |content_type: &str| json!({ content_type: { "schema": { "type": "string", "format": "binary" } } });
+    match reply {
+        Reply::Json => json!({ "200": { "description": "Success", "content": envelope } }),
+        Reply::Created => json!({ "201": { "description": "Created", "content": envelope } }),
+        Reply::Pdf => {
+            json!({ "200": { "description": "PDF document", "content": binary("application/pdf") } })
+        }
+        Reply::Png => {
+            json!({ "200": { "description": "PNG image", "content": binary("image/png") } })
+        }
+        Reply::EscPos => {
+            json!({ "200": { "description": "ESC/POS printer commands", "content": binary("application/octet-stream") } })
+        }
+        Reply::Text => {
+            json!({ "200": { "description": "Plain text", "content": { "text/plain": { "schema": { "type": "string" } } } } })
+        }
+        Reply::Receipt => {
+            let mut content = binary("application/pdf");
+            content["application/octet-stream"] = content["application/pdf"].clone();
+            content["text/plain"] = json!({ "schema": { "type": "string" } });
+            json!({ "200": { "description": "Receipt as PDF, ESC/POS, or text, per the receipt_format setting", "content": content } })
+        }
+        Reply::File => {
+            json!({ "200": { "description": "Stored file", "content": binary("application/octet-stream") } })
+        }
+        Reply::JsonOrCsv => {
+            let mut content = envelope;
+            content["text/csv"] = json!({ "schema": { "type": "string" } });
+            json!({ "200": { "description": "Report; CSV when the path ends in .csv", "content": content } })
+        }
+        Reply::Html => {
+            json!({ "200": { "description": "HTML page", "content": { "text/html": { "schema": { "type": "string" } } } } })
+        }
+        Reply::Document => {
+            json!({ "200": { "description": "OpenAPI document", "content": { "application/json": { "schema": { "type": "object" } } } } })
+        }
+        Reply::Metrics => {
+            json!({ "200": { "description": "Prometheus text exposition", "content": { "text/plain": { "schema": { "type": "string" } } } } })
+        }
+        Reply::Calendar => {
+            json!({ "200": { "description": "iCalendar feed", "content": { "text/calendar": { "schema": { "type": "string" } } } } })
+        }
+        Reply::Redirect => {
+            json!({ "307": { "description": "Redirect to a signed URL", "headers": { "Location": { "schema": { "type": "string" } } } } })
+        }
+    }
+}
+
+/// The OpenAPI object for one operation.
+fn operation_object(op: &ApiOperation) -> Value {
+    let mut object = json!({
+        "operationId": op.operation_id,
+        "summary": op.summary,
+        "tags": [tag_for(op.path)],
+        "security": security(op.auth),
+        "responses": success_responses(op.reply),
+    });
+    object["responses"]["default"] = json!({ "$ref": "#/components/responses/Error" });
+
+    let parameters: Vec<Value> = path_params(op.path)
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" }
+            })
+        })
+        .collect();
+    if !parameters.is_empty() {
+        object["parameters"] = Value::Array(parameters);
+    }
+
+    match op.body {
+        Body::None => {}
+        Body::Json => {
+            object["requestBody"] = json!({
+                "required": true,
+                "content": { "application/json": { "schema": { "type": "object" } } }
+            });
+        }
+        Body::Multipart => {
+            object["requestBody"] = json!({
+                "required": true,
+                "content": {
+                    "multipart/form-data": {
+                        "schema": {
+                            "type": "object",
+                            "properties": { "file": { "type": "string", "format": "binary" } },
+                            "required": ["file"]
+                        }
+                    }
+                }
+            });
+        }
+    }
+
+    match op.auth {
+        Auth::Permission(permission) => {
+            object["description"] = Value::String(format!(
+                "Employee sessions need the `{}` permission.",
+                permission
+            ));
+        }
+        Auth::SignedUrl => {
+            object["description"] = Value::String(
+                "Authorized by the `expires` and `signature` query parameters of a signed URL."
+                    .into(),
+            );
+        }
+        _ => {}
+    }
+
+    object
+}
+
+/// Shared schemas, responses, and security schemes.
+fn components() -> Value {
+    let error_codes: Vec<&str> = ERROR_CATALOG.iter().map(|entry| entry.code).collect();
+    let api_key = |header: &str, description: &str| json!({ "type": "apiKey", "in": "header", "name": header, "description": description });
+
+    json!({
+        "securitySchemes": {
+            "AdminSession": api_key("X-Admin-Session", "Token from POST /api/v1/admin/verify"),
+            "AdminPin": api_key("X-Admin-PIN", "Admin PIN (deprecated; use AdminSession)"),
+            "EmployeeSession": api_key("X-Employee-Session", "Token from POST /api/v1/employees/verify"),
+            "PartnerKey": api_key("X-Partner-Key", "Partner API key"),
+            "FacetSignature": api_key("X-Facet-Signature", "HMAC-SHA256 of the body with the integration secret"),
+        },
+        "schemas": {
+            "ErrorCode": { "type": "string", "enum": error_codes },
+            "ErrorDetail": {
+                "type": "object",
+                "properties": {
+                    "code": { "$ref": "#/components/schemas/ErrorCode" },
+                    "message": { "type": "string" }
+                },
+                "required": ["code", "message"]
+            },
+            "Warning": {
+                "type": "object",
+                "properties": {
+                    "code": { "type": "string" },
+                    "message": { "type": "string" }
+                }
+            },
+            "Envelope": {
+                "type": "object",
+                "properties": {
+                    "data": { "nullable": true },
+                    "error": {
+                        "allOf": [{ "$ref": "#/components/schemas/ErrorDetail" }],
+                        "nullable": true
+                    },
+                    "warnings": {
+                        "type": "array",
+                        "items": { "$ref": "#/components/schemas/Warning" }
+                    },
+                    "meta": { "type": "object" }
+                },
+                "required": ["data", "error"]
+            }
+        },
+        "responses": {
+            "Error": {
+                "description": "Error envelope; see GET /api/v1/errors for every code",
+                "content": {
+                    "application/json": { "schema": { "$ref": "#/components/schemas/Envelope" } }
+                }
+            }
+        }
+    })
+}
+
+/// Build the OpenAPI document for every operation in [`OPERATIONS`].
+pub fn openapi_document() -> Value {
+    let mut paths = Map::new();
+    for op in OPERATIONS {
+        let item = paths
+            .entry(op.path)
+            .or_insert_with(|| Value::Object(Map::new()));
+        item[op.method.as_str()] = operation_object(op);
+    }
+
+    let mut tags: Vec<Value> = TAGS.iter().map(|(_, tag)| json!({ "name": tag })).collect();
+    tags.push(json!({ "name": SERVICE_TAG }));
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Facet API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Jewelry repair intake and ticketing. Field-level details are in docs/API.md."
+        },
+        "tags": tags,
+        "paths": paths,
+        "components": components(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_every_routed_handler_is_documented() {
+        let routes = include_str!("routes/mod.rs");
+        let documented: HashSet<&str> = OPERATIONS.iter().map(|op| op.operation_id).collect();
+        for chunk in routes.split("handlers::").skip(1) {
+            let handler: String = chunk
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect();
+            assert!(
+                documented.contains(handler.as_str()),
+                "{} is routed but missing from OPERATIONS",
+                handler
+            );
+        }
+    }
+
+    #[test]
+    fn test_operations_are_unique() {
+        let mut seen = HashSet::new();
+        for op in OPERATIONS {
+            assert!(
+                seen.insert((op.method, op.path)),
+                "{} {} is listed twice",
+                op.method.as_str(),
+                op.path
+            );
+        }
+    }
+
+    #[test]
+    fn test_openapi_document_shape() {
+        let doc = openapi_document();
+        let ticket = &doc["paths"]["/api/v1/tickets/{ticket_id}"];
+        assert_eq!(ticket["get"]["operationId"], "get_ticket");
+        assert_eq!(ticket["get"]["parameters"][0]["name"], "ticket_id");
+        assert_eq!(ticket["delete"]["security"][1]["AdminPin"], json!([]));
+        assert_eq!(ticket["put"]["tags"][0], "Tickets");
+
+        let upload = &doc["paths"]["/api/v1/tickets/{ticket_id}/photos"]["post"];
+        assert!(upload["requestBody"]["content"]["multipart/form-data"].is_object());
+        assert!(upload["responses"]["201"].is_object());
+
+        assert_eq!(doc["paths"]["/health"]["get"]["tags"][0], SERVICE_TAG);
+        assert!(doc["components"]["schemas"]["ErrorCode"]["enum"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("NOT_FOUND")));
+    }
+
+    #[tokio::test]
+    async fn test_documented_paths_are_routed() {
+        use axum::body::{to_bytes, Body as HttpBody};
+        use axum::http::{Request, StatusCode};
+        use sqlx::postgres::PgPoolOptions;
+        use std::time::Duration;
+        use tower::ServiceExt;
+
+        // Nothing listens here; handlers fail fast, but only after routing
+        let pool = PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(50))
+            .connect_lazy("postgres://facet@127.0.0.1:1/facet")
+            .unwrap();
+        let app = crate::routes::api_router(crate::routes::AppState::new(pool));
+
+        for op in OPERATIONS {
+            let uri: Vec<String> = op
+                .path
+                .split('/')
+                .map(|segment| match segment.split_once('}') {
+                    Some((_, rest)) if segment.starts_with('{') => {
+                        format!("{}{}", uuid::Uuid::nil(), rest)
+                    }
+                    _ => segment.to_string(),
+                })
+                .collect();
+            let request = Request::builder()
+                .method(op.method.as_str().to_uppercase().as_str())
+                .uri(uri.join("/"))
+                .body(HttpBody::empty())
+                .unwrap();
+
+            let response = app.clone().oneshot(request).await.unwrap();
+            let status = response.status();
+            let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            // The router's own 404 has no body; handlers always explain theirs
+            assert!(
+                status != StatusCode::METHOD_NOT_ALLOWED
+                    && !(status == StatusCode::NOT_FOUND && body.is_empty()),
+                "{} {} is documented but not routed ({})",
+                op.method.as_str(),
+                op.path,
+                status
+            );
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Admin recovery repository for database operations.
+
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool};
+
+use crate::auth::{hash_pin, verify_pin};
+use crate::error::AppError;
+use crate::models::admin_recovery::{
+    generate_recovery_code, normalize_recovery_code, AdminRecoveryAttempt, AdminRecoveryCode,
+    CreateAdminRecoveryAttempt,
+};
+
+/// Repository for admin recovery codes and the attempt log.
+pub struct AdminRecoveryRepository;
+
+impl AdminRecoveryRepository {
+    /// Issue a new recovery code, revoking any live one.
+    ///
+    /// Run it in a transaction so the old code isn't revoked without a new
+    /// one. Returns the plain code. It is not stored and can't be shown again.
+    pub async fn issue_code(conn: &mut PgConnection) -> Result<String, AppError> {
+        let code = generate_recovery_code();
+        let code_hash = hash_pin(&normalize_recovery_code(&code))?;
+
+        sqlx::query(
+            r#"
+            UPDATE admin_recovery_codes
+            SET revoked_at = NOW()
+            WHERE used_at IS NULL AND revoked_at IS NULL
+            "#,
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query("INSERT INTO admin_recovery_codes (code_hash) VALUES ($1)")
+            .bind(&code_hash)
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(code)
+    }
+
+    /// Find the live (unused, unrevoked) recovery code, if any.
+    pub async fn find_live(pool: &PgPool) -> Result<Option<AdminRecoveryCode>, AppError> {
+        let code = sqlx::query_as::<_, AdminRecoveryCode>(
+            "SELECT * FROM admin_recovery_codes WHERE used_at IS NULL AND revoked_at IS NULL",
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(code)
+    }
+
+    /// Check a recovery code against the live code and use it up.
+    ///
+    /// Run it in a transaction with whatever the code unlocks, so the code
+    /// is only used up if that goes through too. The live code stays locked
+    /// until then.
+    ///
+    /// Returns false if there is no live code, the code doesn't match, or it
+    /// was used concurrently.
+    pub async fn redeem(conn: &mut PgConnection, code: &str) -> Result<bool, AppError> {
+        let live = sqlx::query_as::<_, AdminRecoveryCode>(
+            r#"
+            SELECT * FROM admin_recovery_codes
+            WHERE used_at IS NULL AND revoked_at IS NULL
+            FOR UPDATE
+            "#,
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+        let Some(live) = live else {
+            return Ok(false);
+        };
+        if !verify_pin(&normalize_recovery_code(code), &live.code_hash)? {
+            return Ok(false);
+        }
+
+        let result = sqlx::query(
+            r#"
+            UPDATE admin_recovery_codes
+            SET used_at = NOW()
+            WHERE code_id = $1 AND used_at IS NULL AND revoked_at IS NULL
+            "#,
+        )
+        .bind(live.code_id)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Record a recovery attempt.
+    pub async fn record_attempt(
+        pool: &PgPool,
+        input: CreateAdminRecoveryAttempt,
+    ) -> Result<AdminRecoveryAttempt, AppError> {
+        let attempt = sqlx::query_as::<_, AdminRecoveryAttempt>(
+            r#"
+            INSERT INTO admin_recovery_attempts (client_ip, user_agent, succeeded, failure_reason)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(&input.client_ip)
+        .bind(&input.user_agent)
+        .bind(input.succeeded)
+        .bind(&input.failure_reason)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(attempt)
+    }
+
+    /// Attempts with a wrong recovery code since `since`, with the time of
+    /// the oldest one.
+    ///
+    /// Attempts turned away by the rate limits don't count, so they can't be
+    /// used to keep recovery locked.
+    pub async fn failures_since(
+        pool: &PgPool,
+        since: DateTime<Utc>,
+    ) -> Result<(i64, Option<DateTime<Utc>>), AppError> {
+        let row = sqlx::query_as::<_, (i64, Option<DateTime<Utc>>)>(
+            r#"
+            SELECT COUNT(*), MIN(attempted_at)
+            FROM admin_recovery_attempts
+            WHERE NOT succeeded
+              AND failure_reason = 'invalid_recovery_code'
+              AND attempted_at >= $1
+            "#,
+        )
+        .bind(since)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(row)
+    }
+
+    /// List recovery attempts, newest first.
+    pub async fn list_attempts(
+        pool: &PgPool,
+        limit: i64,
+    ) -> Result<Vec<AdminRecoveryAttempt>, AppError> {
+        let attempts = sqlx::query_as::<_, AdminRecoveryAttempt>(
+            "SELECT * FROM admin_recovery_attempts ORDER BY attempted_at DESC LIMIT $1",
+        )
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(attempts)
+    }
+}
@@ -3,7 +3,7 @@
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use chrono::{Duration, Utc};
 use rand::RngCore;
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 use crate::error::AppError;
@@ -152,6 +152,17 @@ impl AdminSessionRepository {
         Ok(result.rows_affected() > 0)
     }
 
+    /// Delete every session, signing out all admins.
+    ///
+    /// Returns the number of sessions deleted.
+    pub async fn delete_all(executor: impl PgExecutor<'_>) -> Result<u64, AppError> {
+        let result = sqlx::query("DELETE FROM admin_sessions")
+            .execute(executor)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
     /// Delete all expired sessions (cleanup).
     ///
     /// Returns the number of sessions deleted.
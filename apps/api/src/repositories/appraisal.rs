@@ -0,0 +1,90 @@
+//! Appraisal repository for database operations.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::appraisal::{Appraisal, AppraisalEntry, SaveAppraisal};
+
+/// Columns selected for [`AppraisalEntry`], joined with the appraiser's name.
+const ENTRY_SELECT: &str = r#"
+    SELECT
+        a.appraisal_id,
+        a.ticket_id,
+        a.appraised_value,
+        a.metal,
+        a.stones,
+        a.certification_numbers,
+        a.notes,
+        a.appraiser_id,
+        e.name AS appraiser_name,
+        a.appraised_on,
+        a.created_at,
+        a.updated_at
+    FROM appraisals a
+    JOIN employees e ON e.employee_id = a.appraiser_id
+"#;
+
+/// Repository for appraisal operations.
+pub struct AppraisalRepository;
+
+impl AppraisalRepository {
+    /// Record a ticket's appraisal, replacing the one it has.
+    pub async fn save(pool: &PgPool, input: SaveAppraisal) -> Result<Appraisal, AppError> {
+        let appraisal = sqlx::query_as::<_, Appraisal>(
+            r#"
+            INSERT INTO appraisals (
+                ticket_id, appraised_value, metal, stones, certification_numbers,
+                notes, appraiser_id, appraised_on
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (ticket_id) DO UPDATE SET
+                appraised_value = EXCLUDED.appraised_value,
+                metal = EXCLUDED.metal,
+                stones = EXCLUDED.stones,
+                certification_numbers = EXCLUDED.certification_numbers,
+                notes = EXCLUDED.notes,
+                appraiser_id = EXCLUDED.appraiser_id,
+                appraised_on = EXCLUDED.appraised_on,
+                updated_at = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.appraised_value)
+        .bind(&input.metal)
+        .bind(&input.stones)
+        .bind(&input.certification_numbers)
+        .bind(&input.notes)
+        .bind(input.appraiser_id)
+        .bind(input.appraised_on)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(appraisal)
+    }
+
+    /// Find a ticket's appraisal with the appraiser's name.
+    pub async fn find_entry_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Option<AppraisalEntry>, AppError> {
+        let query = format!("{} WHERE a.ticket_id = $1", ENTRY_SELECT);
+        let entry = sqlx::query_as::<_, AppraisalEntry>(&query)
+            .bind(ticket_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(entry)
+    }
+
+    /// Delete a ticket's appraisal. Returns whether it had one.
+    pub async fn delete(pool: &PgPool, ticket_id: Uuid) -> Result<bool, AppError> {
+        let result = sqlx::query("DELETE FROM appraisals WHERE ticket_id = $1")
+            .bind(ticket_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,72 @@
+//! Audit log repository for database operations.
+
+use crate::error::AppError;
+use crate::models::audit_log::{AuditLogEntry, AuditLogFilter, CreateAuditLogEntry};
+use sqlx::PgPool;
+
+/// Repository for the admin audit log.
+pub struct AuditLogRepository;
+
+impl AuditLogRepository {
+    /// Record an admin action.
+    pub async fn record(
+        pool: &PgPool,
+        input: CreateAuditLogEntry,
+    ) -> Result<AuditLogEntry, AppError> {
+        let entry = sqlx::query_as::<_, AuditLogEntry>(
+            r#"
+            INSERT INTO audit_log (
+                action, auth_method, actor_employee_id, actor_name, client_ip,
+                target_type, target_id, summary
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(input.action)
+        .bind(input.auth_method)
+        .bind(input.actor_employee_id)
+        .bind(&input.actor_name)
+        .bind(&input.client_ip)
+        .bind(&input.target_type)
+        .bind(input.target_id)
+        .bind(&input.summary)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    /// List entries matching the filter, newest first.
+    pub async fn list(
+        pool: &PgPool,
+        filter: &AuditLogFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<AuditLogEntry>, AppError> {
+        let entries = sqlx::query_as::<_, AuditLogEntry>(
+            r#"
+            SELECT *
+            FROM audit_log
+            WHERE ($1::audit_action IS NULL OR action = $1)
+              AND ($2::UUID IS NULL OR actor_employee_id = $2)
+              AND ($3::UUID IS NULL OR target_id = $3)
+              AND ($4::TIMESTAMPTZ IS NULL OR created_at >= $4)
+              AND ($5::TIMESTAMPTZ IS NULL OR created_at < $5)
+            ORDER BY created_at DESC, audit_id
+            LIMIT $6 OFFSET $7
+            "#,
+        )
+        .bind(filter.action)
+        .bind(filter.actor_employee_id)
+        .bind(filter.target_id)
+        .bind(filter.from)
+        .bind(filter.to)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(entries)
+    }
+}
@@ -0,0 +1,454 @@
+//! Campaign repository for database operations.
+
+use chrono::{DateTime, Days, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::campaign::{
+    Campaign, CampaignRecipient, CampaignRecipientStatus, CampaignSegment, CampaignSummary,
+    CreateCampaign,
+};
+use crate::models::notification::NotificationChannel;
+
+/// Customers in a segment, with their phone or email for the channel.
+///
+/// Binds: $1 channel, $2 closed on/after, $3 closed before, $4 item type,
+/// $5 has open tickets.
+const SEGMENT_QUERY: &str = r#"
+    SELECT c.customer_id,
+           CASE WHEN $1 = 'sms'::notification_channel THEN c.phone ELSE c.email END AS recipient
+    FROM customers c
+    WHERE c.deleted_at IS NULL
+      AND NOT c.is_training
+      AND NOT c.notifications_opt_out
+      AND NULLIF(TRIM(CASE WHEN $1 = 'sms'::notification_channel THEN c.phone ELSE c.email END), '') IS NOT NULL
+      AND (($2::TIMESTAMPTZ IS NULL AND $3::TIMESTAMPTZ IS NULL) OR EXISTS (
+          SELECT 1 FROM tickets t
+          WHERE t.customer_id = c.customer_id
+            AND t.deleted_at IS NULL
+            AND NOT t.is_training
+            AND t.closed_at IS NOT NULL
+            AND ($2::TIMESTAMPTZ IS NULL OR t.closed_at >= $2)
+            AND ($3::TIMESTAMPTZ IS NULL OR t.closed_at < $3)
+      ))
+      AND ($4::TEXT IS NULL OR EXISTS (
+          SELECT 1 FROM tickets t
+          WHERE t.customer_id = c.customer_id
+            AND t.deleted_at IS NULL
+            AND NOT t.is_training
+            AND LOWER(t.item_type) = LOWER($4)
+      ))
+      AND ($5::BOOLEAN IS NULL OR $5 = EXISTS (
+          SELECT 1 FROM tickets t
+          WHERE t.customer_id = c.customer_id
+            AND t.deleted_at IS NULL
+            AND NOT t.is_training
+            AND t.status NOT IN ('closed', 'archived')
+      ))
+"#;
+
+/// Campaign columns with recipient counts by status.
+const SUMMARY_QUERY: &str = r#"
+    SELECT c.*,
+           COUNT(r.recipient_id) AS total,
+           COUNT(r.recipient_id) FILTER (WHERE r.status = 'pending') AS pending,
+           COUNT(r.recipient_id) FILTER (WHERE r.status = 'sending') AS sending,
+           COUNT(r.recipient_id) FILTER (WHERE r.status = 'sent') AS sent,
+           COUNT(r.recipient_id) FILTER (WHERE r.status = 'failed') AS failed,
+           COUNT(r.recipient_id) FILTER (WHERE r.status = 'skipped') AS skipped,
+           COUNT(r.recipient_id) FILTER (WHERE r.status = 'cancelled') AS cancelled
+    FROM campaigns c
+    LEFT JOIN campaign_recipients r ON r.campaign_id = c.campaign_id
+"#;
+
+/// Recipient columns with the customer's name.
+const RECIPIENT_COLUMNS: &str = r#"
+    r.recipient_id,
+    r.campaign_id,
+    r.customer_id,
+    cu.name AS customer_name,
+    r.recipient,
+    r.status,
+    r.provider_message_id,
+    r.error,
+    r.attempted_at,
+    r.sent_at
+"#;
+
+/// Start of a day, for "closed on or after".
+fn day_start(date: NaiveDate) -> DateTime<Utc> {
+    date.and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time")
+        .and_utc()
+}
+
+/// Start of the next day, for "closed on or before".
+fn day_end(date: NaiveDate) -> DateTime<Utc> {
+    day_start(date.checked_add_days(Days::new(1)).unwrap_or(date))
+}
+
+/// Bind a segment's filters to a query built on [`SEGMENT_QUERY`].
+macro_rules! bind_segment {
+    ($query:expr, $channel:expr, $segment:expr) => {
+        $query
+            .bind($channel)
+            .bind($segment.closed_from.map(day_start))
+            .bind($segment.closed_to.map(day_end))
+            .bind(&$segment.item_type)
+            .bind($segment.has_open_tickets)
+    };
+}
+
+/// Repository for campaign database operations.
+pub struct CampaignRepository;
+
+impl CampaignRepository {
+    /// Count the customers a campaign on `channel` to `segment` would reach.
+    pub async fn count_segment(
+        pool: &PgPool,
+        channel: NotificationChannel,
+        segment: &CampaignSegment,
+    ) -> Result<i64, AppError> {
+        let sql = format!("SELECT COUNT(*) FROM ({}) segment", SEGMENT_QUERY);
+        let count = bind_segment!(sqlx::query_scalar::<_, i64>(&sql), channel, segment)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(count)
+    }
+
+    /// Create a campaign and queue a recipient for every customer in its segment.
+    pub async fn create(pool: &PgPool, input: CreateCampaign) -> Result<Campaign, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let campaign = sqlx::query_as::<_, Campaign>(
+            r#"
+            INSERT INTO campaigns (
+                name, channel, subject, body, closed_from, closed_to,
+                item_type, has_open_tickets, send_rate_per_minute, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(&input.name)
+        .bind(input.channel)
+        .bind(&input.subject)
+        .bind(&input.body)
+        .bind(input.segment.closed_from)
+        .bind(input.segment.closed_to)
+        .bind(&input.segment.item_type)
+        .bind(input.segment.has_open_tickets)
+        .bind(input.send_rate_per_minute)
+        .bind(input.created_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let sql = format!(
+            r#"
+            INSERT INTO campaign_recipients (campaign_id, customer_id, recipient)
+            SELECT $6, segment.customer_id, segment.recipient
+            FROM ({}) segment
+            "#,
+            SEGMENT_QUERY
+        );
+        bind_segment!(sqlx::query(&sql), input.channel, input.segment)
+            .bind(campaign.campaign_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(campaign)
+    }
+
+    /// List campaigns with their progress, newest first.
+    pub async fn list(pool: &PgPool) -> Result<Vec<CampaignSummary>, AppError> {
+        let sql = format!(
+            "{} GROUP BY c.campaign_id ORDER BY c.created_at DESC",
+            SUMMARY_QUERY
+        );
+        let campaigns = sqlx::query_as::<_, CampaignSummary>(&sql)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(campaigns)
+    }
+
+    /// Find a campaign with its progress.
+    pub async fn find_summary(
+        pool: &PgPool,
+        campaign_id: Uuid,
+    ) -> Result<Option<CampaignSummary>, AppError> {
+        let sql = format!(
+            "{} WHERE c.campaign_id = $1 GROUP BY c.campaign_id",
+            SUMMARY_QUERY
+        );
+        let campaign = sqlx::query_as::<_, CampaignSummary>(&sql)
+            .bind(campaign_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(campaign)
+    }
+
+    /// List a campaign's recipients, optionally by status, by customer name.
+    pub async fn list_recipients(
+        pool: &PgPool,
+        campaign_id: Uuid,
+        status: Option<CampaignRecipientStatus>,
+    ) -> Result<Vec<CampaignRecipient>, AppError> {
+        let recipients = sqlx::query_as::<_, CampaignRecipient>(&format!(
+            r#"
+            SELECT {}
+            FROM campaign_recipients r
+            JOIN customers cu ON cu.customer_id = r.customer_id
+            WHERE r.campaign_id = $1
+              AND ($2::campaign_recipient_status IS NULL OR r.status = $2)
+            ORDER BY cu.name ASC, r.recipient_id ASC
+            "#,
+            RECIPIENT_COLUMNS
+        ))
+        .bind(campaign_id)
+        .bind(status)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(recipients)
+    }
+
+    /// Stop a queued or running campaign and cancel its unsent recipients.
+    ///
+    /// Returns None if the campaign doesn't exist or already finished.
+    pub async fn abort(
+        pool: &PgPool,
+        campaign_id: Uuid,
+        aborted_by: Uuid,
+    ) -> Result<Option<Campaign>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let campaign = sqlx::query_as::<_, Campaign>(
+            r#"
+            UPDATE campaigns
+            SET status = 'aborted', finished_at = NOW(), aborted_by = $2
+            WHERE campaign_id = $1 AND status IN ('queued', 'running')
+            RETURNING *
+            "#,
+        )
+        .bind(campaign_id)
+        .bind(aborted_by)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        // Claimed recipients are left to the worker, which may be sending them
+        if campaign.is_some() {
+            sqlx::query(
+                r#"
+                UPDATE campaign_recipients
+                SET status = 'cancelled'
+                WHERE campaign_id = $1 AND status = 'pending'
+                "#,
+            )
+            .bind(campaign_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(campaign)
+    }
+
+    /// Campaigns that may still send messages, oldest first.
+    pub async fn find_active(pool: &PgPool) -> Result<Vec<Campaign>, AppError> {
+        let campaigns = sqlx::query_as::<_, Campaign>(
+            r#"
+            SELECT * FROM campaigns
+            WHERE status IN ('queued', 'running')
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(campaigns)
+    }
+
+    /// Whether a campaign may still send messages (false once aborted).
+    pub async fn is_active(pool: &PgPool, campaign_id: Uuid) -> Result<bool, AppError> {
+        let active = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM campaigns
+                WHERE campaign_id = $1 AND status IN ('queued', 'running')
+            )
+            "#,
+        )
+        .bind(campaign_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(active)
+    }
+
+    /// Move a queued campaign to running.
+    pub async fn mark_running(pool: &PgPool, campaign_id: Uuid) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE campaigns
+            SET status = 'running', started_at = COALESCE(started_at, NOW())
+            WHERE campaign_id = $1 AND status = 'queued'
+            "#,
+        )
+        .bind(campaign_id)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Complete a running campaign once no recipient is waiting or in flight.
+    ///
+    /// Returns true if the campaign was completed.
+    pub async fn complete_if_done(pool: &PgPool, campaign_id: Uuid) -> Result<bool, AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE campaigns
+            SET status = 'completed', finished_at = NOW()
+            WHERE campaign_id = $1
+              AND status = 'running'
+              AND NOT EXISTS (
+                  SELECT 1 FROM campaign_recipients
+                  WHERE campaign_id = $1 AND status IN ('pending', 'sending')
+              )
+            "#,
+        )
+        .bind(campaign_id)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Count recipients attempted since `since`, for throttling.
+    pub async fn count_attempted_since(
+        pool: &PgPool,
+        campaign_id: Uuid,
+        since: DateTime<Utc>,
+    ) -> Result<i64, AppError> {
+        let count = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM campaign_recipients
+            WHERE campaign_id = $1 AND attempted_at >= $2
+            "#,
+        )
+        .bind(campaign_id)
+        .bind(since)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// Claim up to `limit` pending recipients of an active campaign.
+    ///
+    /// Claimed recipients move to `sending`; rows locked by another worker
+    /// are skipped, so a recipient is only sent once.
+    pub async fn claim_pending(
+        pool: &PgPool,
+        campaign_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<CampaignRecipient>, AppError> {
+        let recipients = sqlx::query_as::<_, CampaignRecipient>(&format!(
+            r#"
+            WITH claimed AS (
+                UPDATE campaign_recipients
+                SET status = 'sending', attempted_at = NOW()
+                WHERE recipient_id IN (
+                    SELECT recipient_id FROM campaign_recipients
+                    WHERE campaign_id = $1 AND status = 'pending'
+                    ORDER BY recipient_id
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                AND EXISTS (
+                    SELECT 1 FROM campaigns
+                    WHERE campaign_id = $1 AND status IN ('queued', 'running')
+                )
+                RETURNING *
+            )
+            SELECT {}
+            FROM claimed r
+            JOIN customers cu ON cu.customer_id = r.customer_id
+            "#,
+            RECIPIENT_COLUMNS
+        ))
+        .bind(campaign_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(recipients)
+    }
+
+    /// Return a claimed recipient to `pending` without an attempt, so a
+    /// later poll sends it.
+    pub async fn release_recipient(pool: &PgPool, recipient_id: Uuid) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE campaign_recipients
+            SET status = 'pending', attempted_at = NULL
+            WHERE recipient_id = $1 AND status = 'sending'
+            "#,
+        )
+        .bind(recipient_id)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Record the outcome of a claimed recipient.
+    pub async fn finish_recipient(
+        pool: &PgPool,
+        recipient_id: Uuid,
+        status: CampaignRecipientStatus,
+        recipient: Option<&str>,
+        provider_message_id: Option<&str>,
+        error: Option<&str>,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE campaign_recipients
+            SET status = $2,
+                recipient = COALESCE($3, recipient),
+                provider_message_id = $4,
+                error = $5,
+                sent_at = CASE WHEN $2 = 'sent'::campaign_recipient_status THEN NOW() END
+            WHERE recipient_id = $1 AND status = 'sending'
+            "#,
+        )
+        .bind(recipient_id)
+        .bind(status)
+        .bind(recipient)
+        .bind(provider_message_id)
+        .bind(error)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_dates_cover_whole_days() {
+        let date = NaiveDate::from_ymd_opt(2026, 3, 31).unwrap();
+        assert_eq!(day_start(date).to_rfc3339(), "2026-03-31T00:00:00+00:00");
+        assert_eq!(day_end(date).to_rfc3339(), "2026-04-01T00:00:00+00:00");
+    }
+}
@@ -0,0 +1,65 @@
+//! Customer contact log repository for database operations.
+
+use crate::error::AppError;
+use crate::models::contact::{CreateTicketContact, TicketContact};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Repository for customer contact log database operations.
+pub struct ContactRepository;
+
+impl ContactRepository {
+    /// Log a contact.
+    pub async fn create(
+        pool: &PgPool,
+        input: CreateTicketContact,
+    ) -> Result<TicketContact, AppError> {
+        let contact = sqlx::query_as::<_, TicketContact>(
+            r#"
+            WITH inserted AS (
+                INSERT INTO ticket_contacts (
+                    ticket_id, channel, direction, outcome, notes, contacted_by
+                )
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING *
+            )
+            SELECT i.contact_id, i.ticket_id, i.channel, i.direction, i.outcome,
+                   i.notes, i.contacted_by, e.name AS contacted_by_name, i.contacted_at
+            FROM inserted i
+            JOIN employees e ON e.employee_id = i.contacted_by
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.channel)
+        .bind(input.direction)
+        .bind(input.outcome)
+        .bind(&input.notes)
+        .bind(input.contacted_by)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(contact)
+    }
+
+    /// Find a ticket's contacts, oldest first.
+    pub async fn find_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketContact>, AppError> {
+        let contacts = sqlx::query_as::<_, TicketContact>(
+            r#"
+            SELECT c.contact_id, c.ticket_id, c.channel, c.direction, c.outcome,
+                   c.notes, c.contacted_by, e.name AS contacted_by_name, c.contacted_at
+            FROM ticket_contacts c
+            JOIN employees e ON e.employee_id = c.contacted_by
+            WHERE c.ticket_id = $1
+            ORDER BY c.contacted_at ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(contacts)
+    }
+}
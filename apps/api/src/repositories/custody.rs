@@ -0,0 +1,76 @@
+//! Custody event repository for database operations.
+
+use crate::error::AppError;
+use crate::models::custody::{CreateCustodyEvent, CustodyEvent, CustodyEventEntry};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+/// Repository for chain-of-custody operations.
+pub struct CustodyRepository;
+
+impl CustodyRepository {
+    /// Record a custody event as the next entry in the ticket's chain.
+    pub async fn create(
+        executor: impl PgExecutor<'_>,
+        input: CreateCustodyEvent,
+    ) -> Result<CustodyEvent, AppError> {
+        let event = sqlx::query_as::<_, CustodyEvent>(
+            r#"
+            INSERT INTO custody_events (
+                ticket_id, sequence, event_type, from_location_id, to_location_id,
+                handled_by, witnessed_by, notes
+            )
+            SELECT $1, COALESCE(MAX(sequence), 0) + 1, $2, $3, $4, $5, $6, $7
+            FROM custody_events
+            WHERE ticket_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.event_type)
+        .bind(input.from_location_id)
+        .bind(input.to_location_id)
+        .bind(input.handled_by)
+        .bind(input.witnessed_by)
+        .bind(&input.notes)
+        .fetch_one(executor)
+        .await?;
+
+        Ok(event)
+    }
+
+    /// Find a ticket's custody chain with names, in sequence order.
+    pub async fn find_entries_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<CustodyEventEntry>, AppError> {
+        let entries = sqlx::query_as::<_, CustodyEventEntry>(
+            r#"
+            SELECT
+                c.event_id,
+                c.sequence,
+                c.event_type,
+                fl.name AS from_location_name,
+                tl.name AS to_location_name,
+                c.handled_by,
+                h.name AS handled_by_name,
+                c.witnessed_by,
+                w.name AS witnessed_by_name,
+                c.notes,
+                c.occurred_at
+            FROM custody_events c
+            JOIN employees h ON h.employee_id = c.handled_by
+            LEFT JOIN employees w ON w.employee_id = c.witnessed_by
+            LEFT JOIN storage_locations fl ON fl.location_id = c.from_location_id
+            LEFT JOIN storage_locations tl ON tl.location_id = c.to_location_id
+            WHERE c.ticket_id = $1
+            ORDER BY c.sequence ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(entries)
+    }
+}
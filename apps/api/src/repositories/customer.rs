@@ -2,10 +2,12 @@
 
 use crate::error::AppError;
 use crate::models::customer::{
-    CreateCustomer, Customer, CustomerSearchParams, CustomerWithTicketCount, CustomerWithTickets,
+    CreateCustomer, Customer, CustomerMerge, CustomerSearchParams, CustomerWithTicketCount,
+    CustomerWithTickets, UpdateCustomer,
 };
 use crate::models::ticket::TicketSummary;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// Repository for customer database operations.
@@ -13,24 +15,31 @@ pub struct CustomerRepository;
 
 impl CustomerRepository {
     /// Create a new customer.
-    pub async fn create(pool: &PgPool, input: CreateCustomer) -> Result<Customer, AppError> {
+    pub async fn create(
+        executor: impl PgExecutor<'_>,
+        input: CreateCustomer,
+    ) -> Result<Customer, AppError> {
         let customer = sqlx::query_as::<_, Customer>(
             r#"
-            INSERT INTO customers (name, phone, email)
-            VALUES ($1, $2, $3)
+            INSERT INTO customers (name, phone, email, is_training)
+            VALUES ($1, $2, $3, $4)
             RETURNING *
             "#,
         )
         .bind(&input.name)
         .bind(&input.phone)
         .bind(&input.email)
-        .fetch_one(pool)
+        .bind(input.is_training)
+        .fetch_one(executor)
         .await?;
 
         Ok(customer)
     }
 
     /// Find a customer by ID.
+    ///
+    /// Includes soft-deleted customers so existing tickets still resolve
+    /// their customer.
     pub async fn find_by_id(
         pool: &PgPool,
         customer_id: Uuid,
@@ -47,12 +56,60 @@ impl CustomerRepository {
         Ok(customer)
     }
 
+    /// Find a customer by ID, excluding soft-deleted customers.
+    pub async fn find_active_by_id(
+        pool: &PgPool,
+        customer_id: Uuid,
+    ) -> Result<Option<Customer>, AppError> {
+        let customer = sqlx::query_as::<_, Customer>(
+            r#"
+            SELECT * FROM customers WHERE customer_id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(customer_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(customer)
+    }
+
+    /// Find a real (non-training) customer with the same phone number or
+    /// email, excluding soft-deleted customers.
+    ///
+    /// `phone_digits` is compared with the digits of stored phone numbers;
+    /// emails are compared case-insensitively.
+    pub async fn find_active_by_contact(
+        pool: &PgPool,
+        phone_digits: Option<&str>,
+        email: Option<&str>,
+    ) -> Result<Option<Customer>, AppError> {
+        let customer = sqlx::query_as::<_, Customer>(
+            r#"
+            SELECT * FROM customers
+            WHERE deleted_at IS NULL
+              AND NOT is_training
+              AND (
+                  ($1::TEXT IS NOT NULL AND regexp_replace(phone, '[^0-9]', '', 'g') = $1)
+                  OR ($2::TEXT IS NOT NULL AND LOWER(email) = LOWER($2))
+              )
+            ORDER BY created_at ASC
+            LIMIT 1
+            "#,
+        )
+        .bind(phone_digits)
+        .bind(email)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(customer)
+    }
+
     /// Check if a customer exists.
     ///
-    /// Returns true if the customer exists.
+    /// Returns true if the customer exists and has not been deleted.
     pub async fn exists(pool: &PgPool, customer_id: Uuid) -> Result<bool, AppError> {
         let result = sqlx::query_scalar::<_, bool>(
-            "SELECT EXISTS(SELECT 1 FROM customers WHERE customer_id = $1)",
+            "SELECT EXISTS(SELECT 1 FROM customers WHERE customer_id = $1 AND deleted_at IS NULL)",
         )
         .bind(customer_id)
         .fetch_one(pool)
@@ -75,9 +132,9 @@ impl CustomerRepository {
             r#"
             SELECT *
             FROM customers
-            WHERE name ILIKE $1
-               OR phone ILIKE $1
-               OR email ILIKE $1
+            WHERE deleted_at IS NULL
+              AND ($4 OR NOT is_training)
+              AND (name ILIKE $1 OR phone ILIKE $1 OR email ILIKE $1)
             ORDER BY name ASC
             LIMIT $2
             OFFSET $3
@@ -86,6 +143,7 @@ impl CustomerRepository {
         .bind(&search_pattern)
         .bind(params.limit.unwrap_or(50))
         .bind(params.offset.unwrap_or(0))
+        .bind(params.include_training)
         .fetch_all(pool)
         .await?;
 
@@ -115,9 +173,10 @@ impl CustomerRepository {
                 COUNT(t.ticket_id) as ticket_count
             FROM customers c
             LEFT JOIN tickets t ON c.customer_id = t.customer_id
-            WHERE c.name ILIKE $1
-               OR c.phone ILIKE $1
-               OR c.email ILIKE $1
+                AND ($4 OR NOT t.is_training)
+            WHERE c.deleted_at IS NULL
+              AND ($4 OR NOT c.is_training)
+              AND (c.name ILIKE $1 OR c.phone ILIKE $1 OR c.email ILIKE $1)
             GROUP BY c.customer_id, c.name, c.phone, c.email, c.created_at, c.updated_at
             ORDER BY c.name ASC
             LIMIT $2
@@ -127,6 +186,7 @@ impl CustomerRepository {
         .bind(&search_pattern)
         .bind(params.limit.unwrap_or(50))
         .bind(params.offset.unwrap_or(0))
+        .bind(params.include_training)
         .fetch_all(pool)
         .await?;
 
@@ -136,13 +196,15 @@ impl CustomerRepository {
     /// Get a customer with their associated tickets.
     ///
     /// Returns the customer and a list of their ticket summaries, sorted by
-    /// created_at descending (most recent first).
+    /// created_at descending (most recent first). `training` selects training
+    /// tickets instead of real ones.
     pub async fn get_with_tickets(
         pool: &PgPool,
         customer_id: Uuid,
+        training: bool,
     ) -> Result<Option<CustomerWithTickets>, AppError> {
         // First fetch the customer
-        let customer = Self::find_by_id(pool, customer_id).await?;
+        let customer = Self::find_active_by_id(pool, customer_id).await?;
 
         let customer = match customer {
             Some(c) => c,
@@ -167,15 +229,215 @@ impl CustomerRepository {
             FROM tickets t
             JOIN customers c ON t.customer_id = c.customer_id
             WHERE t.customer_id = $1
+              AND t.is_training = $2
             ORDER BY t.created_at DESC
             "#,
         )
         .bind(customer_id)
+        .bind(training)
         .fetch_all(pool)
         .await?;
 
         Ok(Some(CustomerWithTickets { customer, tickets }))
     }
+
+    /// Update a customer.
+    ///
+    /// Only provided fields are changed. Soft-deleted customers can't be updated.
+    pub async fn update(
+        pool: &PgPool,
+        customer_id: Uuid,
+        input: UpdateCustomer,
+    ) -> Result<Customer, AppError> {
+        let customer = sqlx::query_as::<_, Customer>(
+            r#"
+            UPDATE customers SET
+                name = COALESCE($2, name),
+                phone = CASE WHEN $3::boolean THEN $4 ELSE phone END,
+                email = CASE WHEN $5::boolean THEN $6 ELSE email END,
+                notifications_opt_out = COALESCE($7, notifications_opt_out),
+                updated_at = NOW()
+            WHERE customer_id = $1 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(customer_id)
+        .bind(&input.name)
+        // For Option<Option<T>> fields, we need to signal when to update vs skip
+        .bind(input.phone.is_some()) // $3: flag
+        .bind(input.phone.flatten()) // $4: actual value
+        .bind(input.email.is_some()) // $5: flag
+        .bind(input.email.flatten()) // $6: actual value
+        .bind(input.notifications_opt_out)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(customer)
+    }
+
+    /// Soft-delete a customer.
+    ///
+    /// Returns the deletion timestamp. Tickets keep their reference to the customer.
+    pub async fn soft_delete(
+        pool: &PgPool,
+        customer_id: Uuid,
+        deleted_by: Uuid,
+    ) -> Result<DateTime<Utc>, AppError> {
+        let deleted_at = sqlx::query_scalar::<_, DateTime<Utc>>(
+            r#"
+            UPDATE customers SET
+                deleted_at = NOW(),
+                deleted_by = $2,
+                updated_at = NOW()
+            WHERE customer_id = $1 AND deleted_at IS NULL
+            RETURNING deleted_at
+            "#,
+        )
+        .bind(customer_id)
+        .bind(deleted_by)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(deleted_at)
+    }
+
+    /// Merge a duplicate customer into a surviving record.
+    ///
+    /// Runs in a single transaction:
+    /// 1. Locks both customers (both must be active)
+    /// 2. Records a `customer_id` field history entry on each moved ticket
+    /// 3. Re-points the source's tickets (and with them their notes, photos,
+    ///    and history) to the target
+    /// 4. Fills the target's missing phone/email from the source
+    /// 5. Soft-deletes the source and writes the merge audit record
+    ///
+    /// Returns `None` if either customer does not exist or was deleted.
+    pub async fn merge(
+        pool: &PgPool,
+        target_id: Uuid,
+        source_id: Uuid,
+        merged_by: Uuid,
+    ) -> Result<Option<(Customer, CustomerMerge)>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        // Lock in a consistent order so concurrent merges can't deadlock
+        let locked = sqlx::query_as::<_, Customer>(
+            r#"
+            SELECT * FROM customers
+            WHERE customer_id IN ($1, $2) AND deleted_at IS NULL
+            ORDER BY customer_id
+            FOR UPDATE
+            "#,
+        )
+        .bind(target_id)
+        .bind(source_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let Some(source) = locked.iter().find(|c| c.customer_id == source_id).cloned() else {
+            return Ok(None);
+        };
+        if !locked.iter().any(|c| c.customer_id == target_id) {
+            return Ok(None);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO ticket_field_history (ticket_id, field_name, old_value, new_value, changed_by)
+            SELECT ticket_id, 'customer_id', $2::TEXT, $1::TEXT, $3
+            FROM tickets
+            WHERE customer_id = $2
+            "#,
+        )
+        .bind(target_id)
+        .bind(source_id)
+        .bind(merged_by)
+        .execute(&mut *tx)
+        .await?;
+
+        let moved = sqlx::query(
+            r#"
+            UPDATE tickets SET customer_id = $1, updated_at = NOW()
+            WHERE customer_id = $2
+            "#,
+        )
+        .bind(target_id)
+        .bind(source_id)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        let customer = sqlx::query_as::<_, Customer>(
+            r#"
+            UPDATE customers SET
+                phone = COALESCE(phone, $2),
+                email = COALESCE(email, $3),
+                updated_at = NOW()
+            WHERE customer_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(target_id)
+        .bind(&source.phone)
+        .bind(&source.email)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE customers SET
+                deleted_at = NOW(),
+                deleted_by = $2,
+                updated_at = NOW()
+            WHERE customer_id = $1
+            "#,
+        )
+        .bind(source_id)
+        .bind(merged_by)
+        .execute(&mut *tx)
+        .await?;
+
+        let merge = sqlx::query_as::<_, CustomerMerge>(
+            r#"
+            INSERT INTO customer_merges (
+                target_customer_id, source_customer_id, source_name,
+                source_phone, source_email, tickets_moved, merged_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(target_id)
+        .bind(source_id)
+        .bind(&source.name)
+        .bind(&source.phone)
+        .bind(&source.email)
+        .bind(moved.min(i32::MAX as u64) as i32)
+        .bind(merged_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some((customer, merge)))
+    }
+
+    /// Count a customer's tickets that are still open (not closed or archived).
+    pub async fn count_open_tickets(pool: &PgPool, customer_id: Uuid) -> Result<i64, AppError> {
+        let count = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM tickets
+            WHERE customer_id = $1
+              AND deleted_at IS NULL
+              AND status NOT IN ('closed', 'archived')
+            "#,
+        )
+        .bind(customer_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,54 @@
+//! Defect repository for database operations.
+
+use crate::error::AppError;
+use crate::models::defect::{CreateTicketDefect, TicketDefect};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Repository for ticket defect database operations.
+pub struct DefectRepository;
+
+impl DefectRepository {
+    /// Record a new defect.
+    pub async fn create(
+        pool: &PgPool,
+        input: CreateTicketDefect,
+    ) -> Result<TicketDefect, AppError> {
+        let defect = sqlx::query_as::<_, TicketDefect>(
+            r#"
+            INSERT INTO ticket_defects (ticket_id, reason, source, qc_check_id, notes, recorded_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.reason)
+        .bind(input.source)
+        .bind(input.qc_check_id)
+        .bind(&input.notes)
+        .bind(input.recorded_by)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(defect)
+    }
+
+    /// Find all defects for a ticket, oldest first.
+    pub async fn find_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketDefect>, AppError> {
+        let defects = sqlx::query_as::<_, TicketDefect>(
+            r#"
+            SELECT * FROM ticket_defects
+            WHERE ticket_id = $1
+            ORDER BY recorded_at ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(defects)
+    }
+}
@@ -1,10 +1,12 @@
 //! Employee repository for database operations.
 
-use crate::auth::hash_pin;
+use crate::auth::{derive_pin_challenge_key, generate_pin_challenge_salt, hash_pin};
 use crate::error::AppError;
 use crate::models::employee::{
-    CreateEmployee, Employee, EmployeeRole, EmployeeSummary, UpdateEmployee,
+    CreateEmployee, Employee, EmployeeName, EmployeeRole, EmployeeSummary, OpenTicketCounts,
+    Permission, UpdateEmployee,
 };
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -12,23 +14,39 @@ use uuid::Uuid;
 pub struct EmployeeRepository;
 
 impl EmployeeRepository {
-    /// Create a new employee.
+    /// Create a new employee at a store.
     ///
-    /// The PIN is hashed before storage using argon2.
-    pub async fn create(pool: &PgPool, input: CreateEmployee) -> Result<Employee, AppError> {
+    /// The PIN is hashed before storage using argon2, alongside its
+    /// challenge key and a fresh salt for it. The employee starts with their role's default
+    /// permissions.
+    pub async fn create(
+        pool: &PgPool,
+        store_id: Uuid,
+        input: CreateEmployee,
+    ) -> Result<Employee, AppError> {
         let pin_hash = hash_pin(&input.pin)?;
+        let pin_challenge_salt = generate_pin_challenge_salt();
+        let pin_challenge_key = derive_pin_challenge_key(&input.pin, &pin_challenge_salt);
         let role = input.role.unwrap_or(EmployeeRole::Staff);
 
         let employee = sqlx::query_as::<_, Employee>(
             r#"
-            INSERT INTO employees (name, pin_hash, role)
-            VALUES ($1, $2, $3)
+            INSERT INTO employees (
+                name, pin_hash, role, pin_challenge_key, pin_challenge_salt, permissions,
+                phone, store_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
             RETURNING *
             "#,
         )
         .bind(&input.name)
         .bind(&pin_hash)
         .bind(role)
+        .bind(&pin_challenge_key)
+        .bind(&pin_challenge_salt)
+        .bind(role.default_permissions())
+        .bind(&input.phone)
+        .bind(store_id)
         .fetch_one(pool)
         .await?;
 
@@ -105,32 +123,128 @@ impl EmployeeRepository {
         Ok(employees)
     }
 
-    /// List employees with optional filtering.
+    /// Count a wrong PIN against an employee, locking them out when the
+    /// count inside the window reaches `max_attempts`.
+    ///
+    /// Failures before `window_start` no longer count; the window restarts
+    /// at this failure. Returns the failures in the current window and when
+    /// the employee was locked out, or None if the employee doesn't exist.
+    pub async fn record_pin_failure(
+        pool: &PgPool,
+        employee_id: Uuid,
+        window_start: DateTime<Utc>,
+        max_attempts: u32,
+    ) -> Result<Option<(i32, Option<DateTime<Utc>>)>, AppError> {
+        let failure = sqlx::query_as::<_, (i32, Option<DateTime<Utc>>)>(
+            r#"
+            WITH current AS (
+                SELECT employee_id,
+                       CASE
+                           WHEN failed_pin_window_started_at IS NULL
+                             OR failed_pin_window_started_at < $2 THEN 1
+                           ELSE failed_pin_attempts + 1
+                       END AS attempts
+                FROM employees
+                WHERE employee_id = $1
+                FOR UPDATE
+            )
+            UPDATE employees e
+            SET failed_pin_attempts = c.attempts,
+                failed_pin_window_started_at = CASE
+                    WHEN c.attempts = 1 THEN NOW()
+                    ELSE e.failed_pin_window_started_at
+                END,
+                pin_locked_at = CASE
+                    WHEN e.pin_locked_at IS NULL AND c.attempts >= $3 THEN NOW()
+                    ELSE e.pin_locked_at
+                END
+            FROM current c
+            WHERE e.employee_id = c.employee_id
+            RETURNING e.failed_pin_attempts, e.pin_locked_at
+            "#,
+        )
+        .bind(employee_id)
+        .bind(window_start)
+        .bind(max_attempts as i32)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(failure)
+    }
+
+    /// Forget an employee's wrong PINs after they log in.
+    pub async fn clear_pin_failures(pool: &PgPool, employee_id: Uuid) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE employees
+            SET failed_pin_attempts = 0, failed_pin_window_started_at = NULL
+            WHERE employee_id = $1 AND failed_pin_attempts > 0
+            "#,
+        )
+        .bind(employee_id)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Lift an employee's PIN lockout and forget their wrong PINs.
+    ///
+    /// Returns the updated employee, or None if not found.
+    pub async fn unlock_pin(
+        pool: &PgPool,
+        employee_id: Uuid,
+    ) -> Result<Option<Employee>, AppError> {
+        let employee = sqlx::query_as::<_, Employee>(
+            r#"
+            UPDATE employees
+            SET pin_locked_at = NULL,
+                failed_pin_attempts = 0,
+                failed_pin_window_started_at = NULL,
+                updated_at = NOW()
+            WHERE employee_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(employee_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(employee)
+    }
+
+    /// List a store's employees with optional filtering.
     ///
     /// If include_inactive is false (default), only active employees are returned.
     pub async fn list(
         pool: &PgPool,
+        store_id: Uuid,
         include_inactive: bool,
     ) -> Result<Vec<EmployeeSummary>, AppError> {
         let employees = if include_inactive {
             sqlx::query_as::<_, EmployeeSummary>(
                 r#"
-                SELECT employee_id, name, role, is_active
+                SELECT employee_id, store_id, name, role, phone, permissions, is_active,
+                       pin_locked_at
                 FROM employees
+                WHERE store_id = $1
                 ORDER BY name ASC
                 "#,
             )
+            .bind(store_id)
             .fetch_all(pool)
             .await?
         } else {
             sqlx::query_as::<_, EmployeeSummary>(
                 r#"
-                SELECT employee_id, name, role, is_active
+                SELECT employee_id, store_id, name, role, phone, permissions, is_active,
+                       pin_locked_at
                 FROM employees
-                WHERE is_active = TRUE
+                WHERE store_id = $1 AND is_active = TRUE
                 ORDER BY name ASC
                 "#,
             )
+            .bind(store_id)
             .fetch_all(pool)
             .await?
         };
@@ -138,10 +252,61 @@ impl EmployeeRepository {
         Ok(employees)
     }
 
+    /// List a store's active employees' IDs and names, sorted by name.
+    pub async fn list_active_names(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<Vec<EmployeeName>, AppError> {
+        let employees = sqlx::query_as::<_, EmployeeName>(
+            r#"
+            SELECT employee_id, name
+            FROM employees
+            WHERE store_id = $1 AND is_active = TRUE
+            ORDER BY name ASC
+            "#,
+        )
+        .bind(store_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(employees)
+    }
+
+    /// Count open tickets an employee took in or is working on.
+    ///
+    /// Only training tickets are counted when `is_training` is set, and only
+    /// real ones otherwise.
+    pub async fn count_open_tickets(
+        pool: &PgPool,
+        employee_id: Uuid,
+        is_training: bool,
+    ) -> Result<OpenTicketCounts, AppError> {
+        let counts = sqlx::query_as::<_, OpenTicketCounts>(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE taken_in_by = $1) AS taken_in,
+                COUNT(*) FILTER (WHERE worked_by = $1) AS working_on
+            FROM tickets
+            WHERE (taken_in_by = $1 OR worked_by = $1)
+              AND is_training = $2
+              AND deleted_at IS NULL
+              AND status NOT IN ('closed', 'archived')
+            "#,
+        )
+        .bind(employee_id)
+        .bind(is_training)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(counts)
+    }
+
     /// Update an employee.
     ///
     /// Only the provided fields are updated.
-    /// If PIN is provided, it's hashed before storage.
+    /// If PIN is provided, it's hashed before storage and its challenge key
+    /// replaced. Changing the role resets permissions to the new role's
+    /// defaults, so a demoted admin doesn't keep admin permissions.
     pub async fn update(
         pool: &PgPool,
         employee_id: Uuid,
@@ -156,18 +321,35 @@ impl EmployeeRepository {
 
         // Build update with provided fields, keeping existing values for unspecified fields
         let name = input.name.unwrap_or(existing.name);
-        let pin_hash = match input.pin {
-            Some(pin) => hash_pin(&pin)?,
-            None => existing.pin_hash,
+        let (pin_hash, pin_challenge_key, pin_challenge_salt) = match input.pin {
+            Some(pin) => {
+                let salt = generate_pin_challenge_salt();
+                let key = derive_pin_challenge_key(&pin, &salt);
+                (hash_pin(&pin)?, Some(key), Some(salt))
+            }
+            None => (
+                existing.pin_hash,
+                existing.pin_challenge_key,
+                existing.pin_challenge_salt,
+            ),
         };
         let role = input.role.unwrap_or(existing.role);
+        let permissions = if role == existing.role {
+            existing.permissions
+        } else {
+            role.default_permissions()
+        };
         let is_active = input.is_active.unwrap_or(existing.is_active);
+        let phone = input.phone.unwrap_or(existing.phone);
+        let store_id = input.store_id.unwrap_or(existing.store_id);
 
         let employee = sqlx::query_as::<_, Employee>(
             r#"
             UPDATE employees
-            SET name = $1, pin_hash = $2, role = $3, is_active = $4, updated_at = NOW()
-            WHERE employee_id = $5
+            SET name = $1, pin_hash = $2, role = $3, is_active = $4, pin_challenge_key = $5,
+                permissions = $7, phone = $8, store_id = $9, pin_challenge_salt = $10,
+                updated_at = NOW()
+            WHERE employee_id = $6
             RETURNING *
             "#,
         )
@@ -175,13 +357,42 @@ impl EmployeeRepository {
         .bind(&pin_hash)
         .bind(role)
         .bind(is_active)
+        .bind(&pin_challenge_key)
         .bind(employee_id)
+        .bind(&permissions)
+        .bind(&phone)
+        .bind(store_id)
+        .bind(&pin_challenge_salt)
         .fetch_one(pool)
         .await?;
 
         Ok(Some(employee))
     }
 
+    /// Replace an employee's permissions.
+    ///
+    /// Returns the updated employee, or None if not found.
+    pub async fn set_permissions(
+        pool: &PgPool,
+        employee_id: Uuid,
+        permissions: &[Permission],
+    ) -> Result<Option<Employee>, AppError> {
+        let employee = sqlx::query_as::<_, Employee>(
+            r#"
+            UPDATE employees
+            SET permissions = $2, updated_at = NOW()
+            WHERE employee_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(employee_id)
+        .bind(permissions)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(employee)
+    }
+
     /// Soft-delete an employee by setting is_active to false.
     ///
     /// Returns the updated employee, or None if not found.
@@ -215,6 +426,7 @@ impl EmployeeRepository {
                     (SELECT COUNT(*) FROM tickets WHERE last_modified_by = $1) +
                     (SELECT COUNT(*) FROM ticket_photos WHERE uploaded_by = $1) +
                     (SELECT COUNT(*) FROM ticket_notes WHERE created_by = $1) +
+                    (SELECT COUNT(*) FROM ticket_contacts WHERE contacted_by = $1) +
                     (SELECT COUNT(*) FROM ticket_status_history WHERE changed_by = $1) +
                     (SELECT COUNT(*) FROM ticket_field_history WHERE changed_by = $1),
                     0
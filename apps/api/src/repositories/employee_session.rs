@@ -31,18 +31,26 @@ impl EmployeeSessionRepository {
     /// Create a new employee session.
     ///
     /// Generates a secure token and stores the session in the database,
-    /// bound to the specified employee.
+    /// bound to the specified employee and the store they signed in at.
     pub async fn create(
         pool: &PgPool,
         employee_id: Uuid,
+        store_id: Uuid,
     ) -> Result<EmployeeSessionResponse, AppError> {
-        Self::create_with_duration(pool, employee_id, DEFAULT_SESSION_DURATION_MINUTES).await
+        Self::create_with_duration(
+            pool,
+            employee_id,
+            store_id,
+            DEFAULT_SESSION_DURATION_MINUTES,
+        )
+        .await
     }
 
     /// Create a new employee session with a custom duration.
     pub async fn create_with_duration(
         pool: &PgPool,
         employee_id: Uuid,
+        store_id: Uuid,
         duration_minutes: i64,
     ) -> Result<EmployeeSessionResponse, AppError> {
         let token = Self::generate_token();
@@ -51,14 +59,16 @@ impl EmployeeSessionRepository {
 
         let session = sqlx::query_as::<_, EmployeeSession>(
             r#"
-            INSERT INTO employee_sessions (employee_id, session_token, expires_at)
-            VALUES ($1, $2, $3)
-            RETURNING session_id, employee_id, session_token, created_at, expires_at, last_activity_at
+            INSERT INTO employee_sessions (employee_id, session_token, expires_at, store_id)
+            VALUES ($1, $2, $3, $4)
+            RETURNING session_id, employee_id, session_token, created_at, expires_at, last_activity_at,
+                training_mode
             "#,
         )
         .bind(employee_id)
         .bind(&token)
         .bind(expires_at)
+        .bind(store_id)
         .fetch_one(pool)
         .await?;
 
@@ -78,7 +88,8 @@ impl EmployeeSessionRepository {
     ) -> Result<Option<EmployeeSession>, AppError> {
         let session = sqlx::query_as::<_, EmployeeSession>(
             r#"
-            SELECT session_id, employee_id, session_token, created_at, expires_at, last_activity_at
+            SELECT session_id, employee_id, session_token, created_at, expires_at, last_activity_at,
+                training_mode
             FROM employee_sessions
             WHERE session_token = $1
             "#,
@@ -132,6 +143,39 @@ impl EmployeeSessionRepository {
         }
     }
 
+    /// Check whether an unexpired session is in training mode.
+    ///
+    /// Returns false for unknown or expired tokens.
+    pub async fn is_training(pool: &PgPool, token: &str) -> Result<bool, AppError> {
+        let training = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT training_mode
+            FROM employee_sessions
+            WHERE session_token = $1 AND expires_at > NOW()
+            "#,
+        )
+        .bind(token)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(training.unwrap_or(false))
+    }
+
+    /// Switch a session into or out of training mode.
+    pub async fn set_training_mode(
+        pool: &PgPool,
+        session_id: Uuid,
+        enabled: bool,
+    ) -> Result<(), AppError> {
+        sqlx::query("UPDATE employee_sessions SET training_mode = $1 WHERE session_id = $2")
+            .bind(enabled)
+            .bind(session_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
     /// Delete a session by its token (logout).
     pub async fn delete_by_token(pool: &PgPool, token: &str) -> Result<bool, AppError> {
         let result = sqlx::query(
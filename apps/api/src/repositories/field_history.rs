@@ -1,8 +1,11 @@
 //! Field history repository for database operations.
 
 use crate::error::AppError;
-use crate::models::field_history::{CreateFieldHistory, FieldHistoryEntry};
-use sqlx::PgPool;
+use crate::models::field_history::{
+    CreateFieldHistory, FieldHistoryEntry, TicketHistoryEvent, PHOTO_FIELD,
+};
+use sqlx::{PgConnection, PgExecutor, PgPool};
+use uuid::Uuid;
 
 /// Repository for field history database operations.
 pub struct FieldHistoryRepository;
@@ -10,7 +13,7 @@ pub struct FieldHistoryRepository;
 impl FieldHistoryRepository {
     /// Create a new field history entry.
     pub async fn create(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         input: CreateFieldHistory,
     ) -> Result<FieldHistoryEntry, AppError> {
         let entry = sqlx::query_as::<_, FieldHistoryEntry>(
@@ -25,7 +28,7 @@ impl FieldHistoryRepository {
         .bind(&input.old_value)
         .bind(&input.new_value)
         .bind(input.changed_by)
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
 
         Ok(entry)
@@ -33,7 +36,7 @@ impl FieldHistoryRepository {
 
     /// Create multiple field history entries in a batch.
     pub async fn create_batch(
-        pool: &PgPool,
+        conn: &mut PgConnection,
         entries: Vec<CreateFieldHistory>,
     ) -> Result<(), AppError> {
         if entries.is_empty() {
@@ -41,11 +44,88 @@ impl FieldHistoryRepository {
         }
 
         for entry in entries {
-            Self::create(pool, entry).await?;
+            Self::create(&mut *conn, entry).await?;
         }
 
         Ok(())
     }
+
+    /// List a ticket's merged history feed, oldest first.
+    ///
+    /// Combines field changes, status changes, notes, and photo uploads with
+    /// the attributed employee's name. Photo removals are recorded as field
+    /// changes on the `photo` field and reported as their own event type; the
+    /// upload of a removed photo is no longer listed.
+    pub async fn list_feed(
+        pool: &PgPool,
+        ticket_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<TicketHistoryEvent>, AppError> {
+        let events = sqlx::query_as::<_, TicketHistoryEvent>(
+            r#"
+            SELECT
+                ev.event_id,
+                ev.event_type,
+                ev.field_name,
+                ev.old_value,
+                ev.new_value,
+                ev.employee_id,
+                e.name AS employee_name,
+                ev.occurred_at
+            FROM (
+                SELECT
+                    h.history_id AS event_id,
+                    CASE WHEN h.field_name = $4 THEN 'photo_removed' ELSE 'field_change' END::text
+                        AS event_type,
+                    h.field_name::text AS field_name,
+                    h.old_value,
+                    h.new_value,
+                    h.changed_by AS employee_id,
+                    h.changed_at AS occurred_at
+                FROM ticket_field_history h
+                WHERE h.ticket_id = $1
+                UNION ALL
+                SELECT
+                    s.history_id,
+                    'status_change'::text,
+                    'status'::text,
+                    s.from_status::text,
+                    s.to_status::text,
+                    s.changed_by,
+                    s.changed_at
+                FROM ticket_status_history s
+                WHERE s.ticket_id = $1
+                UNION ALL
+                SELECT n.note_id, 'note'::text, NULL, NULL, n.content, n.created_by, n.created_at
+                FROM ticket_notes n
+                WHERE n.ticket_id = $1
+                UNION ALL
+                SELECT
+                    p.photo_id,
+                    'photo_added'::text,
+                    $4,
+                    NULL,
+                    p.photo_id::text,
+                    p.uploaded_by,
+                    p.uploaded_at
+                FROM ticket_photos p
+                WHERE p.ticket_id = $1
+            ) ev
+            JOIN employees e ON e.employee_id = ev.employee_id
+            ORDER BY ev.occurred_at ASC, ev.event_id ASC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(limit)
+        .bind(offset)
+        .bind(PHOTO_FIELD)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(events)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,138 @@
+//! Idempotency key repository for database operations.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::idempotency::{IdempotencyRecord, StoredResponse};
+
+/// Repository for idempotency key database operations.
+pub struct IdempotencyRepository;
+
+impl IdempotencyRepository {
+    /// Claim a key for a new request.
+    ///
+    /// Succeeds when the key is unused, expired (created before
+    /// `expired_before`), or abandoned mid-request (still running since
+    /// before `stale_before`). Returns false when another request holds it;
+    /// look it up with [`find`](Self::find).
+    pub async fn claim(
+        pool: &PgPool,
+        principal: &str,
+        key: &str,
+        endpoint: &str,
+        request_hash: &str,
+        expired_before: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<bool, AppError> {
+        let claimed = sqlx::query_scalar::<_, bool>(
+            r#"
+            INSERT INTO idempotency_keys (principal, idempotency_key, endpoint, request_hash)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (principal, idempotency_key, endpoint) DO UPDATE
+            SET request_hash = EXCLUDED.request_hash,
+                response_status = NULL,
+                response_content_type = NULL,
+                response_body = NULL,
+                created_at = NOW()
+            WHERE idempotency_keys.created_at < $5
+               OR (idempotency_keys.response_status IS NULL AND idempotency_keys.created_at < $6)
+            RETURNING TRUE
+            "#,
+        )
+        .bind(principal)
+        .bind(key)
+        .bind(endpoint)
+        .bind(request_hash)
+        .bind(expired_before)
+        .bind(stale_before)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(claimed.is_some())
+    }
+
+    /// Find a key's record.
+    pub async fn find(
+        pool: &PgPool,
+        principal: &str,
+        key: &str,
+        endpoint: &str,
+    ) -> Result<Option<IdempotencyRecord>, AppError> {
+        let record = sqlx::query_as::<_, IdempotencyRecord>(
+            r#"
+            SELECT * FROM idempotency_keys
+            WHERE principal = $1 AND idempotency_key = $2 AND endpoint = $3
+            "#,
+        )
+        .bind(principal)
+        .bind(key)
+        .bind(endpoint)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Store the response of the request holding a key.
+    pub async fn complete(
+        pool: &PgPool,
+        principal: &str,
+        key: &str,
+        endpoint: &str,
+        response: &StoredResponse,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE idempotency_keys
+            SET response_status = $4, response_content_type = $5, response_body = $6
+            WHERE principal = $1 AND idempotency_key = $2 AND endpoint = $3
+            "#,
+        )
+        .bind(principal)
+        .bind(key)
+        .bind(endpoint)
+        .bind(response.status)
+        .bind(&response.content_type)
+        .bind(&response.body)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Release a key whose request didn't finish, so a retry can run it.
+    pub async fn release(
+        pool: &PgPool,
+        principal: &str,
+        key: &str,
+        endpoint: &str,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            DELETE FROM idempotency_keys
+            WHERE principal = $1 AND idempotency_key = $2 AND endpoint = $3
+              AND response_status IS NULL
+            "#,
+        )
+        .bind(principal)
+        .bind(key)
+        .bind(endpoint)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Delete keys created before the given time.
+    ///
+    /// Returns the number of rows deleted.
+    pub async fn delete_older_than(pool: &PgPool, cutoff: DateTime<Utc>) -> Result<u64, AppError> {
+        let result = sqlx::query("DELETE FROM idempotency_keys WHERE created_at < $1")
+            .bind(cutoff)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,159 @@
+//! Intake draft repository for database operations.
+
+use crate::error::AppError;
+use crate::models::intake_draft::{
+    CreateIntakeDraft, IntakeDraft, IntakeDraftSource, IntakeDraftStatus,
+};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Repository for intake draft database operations.
+pub struct IntakeDraftRepository;
+
+impl IntakeDraftRepository {
+    /// Record a draft.
+    ///
+    /// A draft already recorded with the same source and external ID is
+    /// returned instead, with `false` to say nothing was created.
+    pub async fn create(
+        pool: &PgPool,
+        input: CreateIntakeDraft,
+    ) -> Result<(IntakeDraft, bool), AppError> {
+        let created = sqlx::query_as::<_, IntakeDraft>(
+            r#"
+            INSERT INTO intake_drafts (
+                source, external_id, customer_name, customer_phone,
+                requested_work, transcript, recording_url, received_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, COALESCE($8, NOW()))
+            ON CONFLICT (source, external_id) WHERE external_id IS NOT NULL DO NOTHING
+            RETURNING *
+            "#,
+        )
+        .bind(input.source)
+        .bind(&input.external_id)
+        .bind(&input.customer_name)
+        .bind(&input.customer_phone)
+        .bind(&input.requested_work)
+        .bind(&input.transcript)
+        .bind(&input.recording_url)
+        .bind(input.received_at)
+        .fetch_optional(pool)
+        .await?;
+
+        if let Some(draft) = created {
+            return Ok((draft, true));
+        }
+
+        // Only a conflicting external ID skips the insert
+        let existing = Self::find_by_external_id(
+            pool,
+            input.source,
+            input.external_id.as_deref().unwrap_or_default(),
+        )
+        .await?
+        .ok_or_else(|| AppError::server_error("Intake draft conflict without a match"))?;
+
+        Ok((existing, false))
+    }
+
+    /// Find a draft by the sending service's ID.
+    pub async fn find_by_external_id(
+        pool: &PgPool,
+        source: IntakeDraftSource,
+        external_id: &str,
+    ) -> Result<Option<IntakeDraft>, AppError> {
+        let draft = sqlx::query_as::<_, IntakeDraft>(
+            "SELECT * FROM intake_drafts WHERE source = $1 AND external_id = $2",
+        )
+        .bind(source)
+        .bind(external_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(draft)
+    }
+
+    /// Find a draft by ID.
+    pub async fn find_by_id(
+        pool: &PgPool,
+        draft_id: Uuid,
+    ) -> Result<Option<IntakeDraft>, AppError> {
+        let draft =
+            sqlx::query_as::<_, IntakeDraft>("SELECT * FROM intake_drafts WHERE draft_id = $1")
+                .bind(draft_id)
+                .fetch_optional(pool)
+                .await?;
+
+        Ok(draft)
+    }
+
+    /// List drafts, optionally by status, oldest first.
+    pub async fn list(
+        pool: &PgPool,
+        status: Option<IntakeDraftStatus>,
+    ) -> Result<Vec<IntakeDraft>, AppError> {
+        let drafts = sqlx::query_as::<_, IntakeDraft>(
+            r#"
+            SELECT * FROM intake_drafts
+            WHERE ($1::intake_draft_status IS NULL OR status = $1)
+            ORDER BY received_at ASC
+            "#,
+        )
+        .bind(status)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(drafts)
+    }
+
+    /// Mark a pending draft as converted into a ticket.
+    ///
+    /// Returns None if the draft doesn't exist or was already resolved.
+    pub async fn mark_converted(
+        pool: &PgPool,
+        draft_id: Uuid,
+        ticket_id: Uuid,
+        resolved_by: Uuid,
+    ) -> Result<Option<IntakeDraft>, AppError> {
+        let draft = sqlx::query_as::<_, IntakeDraft>(
+            r#"
+            UPDATE intake_drafts
+            SET status = 'converted', ticket_id = $2, resolved_by = $3, resolved_at = NOW()
+            WHERE draft_id = $1 AND status = 'pending'
+            RETURNING *
+            "#,
+        )
+        .bind(draft_id)
+        .bind(ticket_id)
+        .bind(resolved_by)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(draft)
+    }
+
+    /// Dismiss a pending draft.
+    ///
+    /// Returns None if the draft doesn't exist or was already resolved.
+    pub async fn dismiss(
+        pool: &PgPool,
+        draft_id: Uuid,
+        resolved_by: Uuid,
+    ) -> Result<Option<IntakeDraft>, AppError> {
+        let draft = sqlx::query_as::<_, IntakeDraft>(
+            r#"
+            UPDATE intake_drafts
+            SET status = 'dismissed', resolved_by = $2, resolved_at = NOW()
+            WHERE draft_id = $1 AND status = 'pending'
+            RETURNING *
+            "#,
+        )
+        .bind(draft_id)
+        .bind(resolved_by)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(draft)
+    }
+}
@@ -0,0 +1,119 @@
+//! Integrity repository for consistency check queries.
+
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::integrity::IntegrityRecord;
+
+/// Tickets still being handled (not closed, archived, or deleted).
+const OPEN_TICKET_FILTER: &str = "t.deleted_at IS NULL AND t.status NOT IN ('closed', 'archived')";
+
+/// Repository for integrity check queries.
+pub struct IntegrityRepository;
+
+impl IntegrityRepository {
+    /// Every photo record, with its storage key as the detail.
+    pub async fn photos(pool: &PgPool) -> Result<Vec<IntegrityRecord>, AppError> {
+        let records = sqlx::query_as::<_, IntegrityRecord>(
+            r#"
+            SELECT p.photo_id AS record_id, p.ticket_id, t.friendly_code, p.storage_key AS detail
+            FROM ticket_photos p
+            JOIN tickets t ON t.ticket_id = p.ticket_id
+            ORDER BY p.uploaded_at ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Open tickets stored at an inactive location, with the location name.
+    pub async fn tickets_at_inactive_locations(
+        pool: &PgPool,
+    ) -> Result<Vec<IntegrityRecord>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT t.ticket_id AS record_id, t.ticket_id, t.friendly_code, l.name AS detail
+            FROM tickets t
+            JOIN storage_locations l ON l.location_id = t.storage_location_id
+            WHERE {} AND NOT l.is_active
+            ORDER BY t.created_at ASC
+            "#,
+            OPEN_TICKET_FILTER
+        );
+
+        let records = sqlx::query_as::<_, IntegrityRecord>(&sql)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(records)
+    }
+
+    /// Open tickets worked by an inactive employee, with the employee name.
+    pub async fn tickets_with_inactive_assignees(
+        pool: &PgPool,
+    ) -> Result<Vec<IntegrityRecord>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT t.ticket_id AS record_id, t.ticket_id, t.friendly_code, e.name AS detail
+            FROM tickets t
+            JOIN employees e ON e.employee_id = t.worked_by
+            WHERE {} AND NOT e.is_active
+            ORDER BY t.created_at ASC
+            "#,
+            OPEN_TICKET_FILTER
+        );
+
+        let records = sqlx::query_as::<_, IntegrityRecord>(&sql)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(records)
+    }
+
+    /// worked_by history entries whose old or new value names no employee.
+    ///
+    /// Field history stores values as text, so these references aren't
+    /// covered by a foreign key. The detail is the unknown value.
+    pub async fn unknown_employee_references(
+        pool: &PgPool,
+    ) -> Result<Vec<IntegrityRecord>, AppError> {
+        let records = sqlx::query_as::<_, IntegrityRecord>(
+            r#"
+            SELECT h.history_id AS record_id, h.ticket_id, t.friendly_code, v.value AS detail
+            FROM ticket_field_history h
+            JOIN tickets t ON t.ticket_id = h.ticket_id
+            CROSS JOIN LATERAL (VALUES (h.old_value), (h.new_value)) AS v(value)
+            WHERE h.field_name = 'worked_by'
+              AND v.value IS NOT NULL
+              AND NOT EXISTS (
+                  SELECT 1 FROM employees e WHERE e.employee_id::TEXT = v.value
+              )
+            ORDER BY h.changed_at ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Notes on deleted tickets, with the note's author as the detail.
+    pub async fn notes_on_deleted_tickets(pool: &PgPool) -> Result<Vec<IntegrityRecord>, AppError> {
+        let records = sqlx::query_as::<_, IntegrityRecord>(
+            r#"
+            SELECT n.note_id AS record_id, n.ticket_id, t.friendly_code, e.name AS detail
+            FROM ticket_notes n
+            JOIN tickets t ON t.ticket_id = n.ticket_id
+            JOIN employees e ON e.employee_id = n.created_by
+            WHERE t.deleted_at IS NOT NULL
+            ORDER BY n.created_at ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+}
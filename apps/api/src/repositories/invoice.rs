@@ -0,0 +1,87 @@
+//! Ticket invoice repository for database operations.
+
+use crate::error::AppError;
+use crate::models::invoice::{CreateTicketInvoice, TicketInvoice};
+use crate::models::tax::TaxBreakdown;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+/// Repository for ticket invoice database operations.
+pub struct InvoiceRepository;
+
+impl InvoiceRepository {
+    /// Record an invoice.
+    ///
+    /// Returns None if the ticket was invoiced meanwhile; a ticket never has
+    /// more than one invoice.
+    pub async fn create(
+        executor: impl PgExecutor<'_>,
+        input: CreateTicketInvoice,
+    ) -> Result<Option<TicketInvoice>, AppError> {
+        let invoice = sqlx::query_as::<_, TicketInvoice>(
+            r#"
+            INSERT INTO ticket_invoices (
+                ticket_id, invoice_number, tax_rate, tax_inclusive, tax_amount, total_amount
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (ticket_id) DO NOTHING
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.invoice_number)
+        .bind(input.tax.tax_rate)
+        .bind(input.tax.tax_inclusive)
+        .bind(input.tax.tax_amount)
+        .bind(input.tax.total)
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(invoice)
+    }
+
+    /// Replace the tax recorded on a ticket's invoice, keeping its number.
+    ///
+    /// Used when a reopened ticket is closed again. Returns None if the
+    /// ticket has no invoice.
+    pub async fn update_tax(
+        executor: impl PgExecutor<'_>,
+        ticket_id: Uuid,
+        tax: TaxBreakdown,
+    ) -> Result<Option<TicketInvoice>, AppError> {
+        let invoice = sqlx::query_as::<_, TicketInvoice>(
+            r#"
+            UPDATE ticket_invoices
+            SET tax_rate = $2, tax_inclusive = $3, tax_amount = $4, total_amount = $5
+            WHERE ticket_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(tax.tax_rate)
+        .bind(tax.tax_inclusive)
+        .bind(tax.tax_amount)
+        .bind(tax.total)
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(invoice)
+    }
+
+    /// Find a ticket's invoice, if it has been invoiced.
+    pub async fn find_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Option<TicketInvoice>, AppError> {
+        let invoice = sqlx::query_as::<_, TicketInvoice>(
+            r#"
+            SELECT * FROM ticket_invoices WHERE ticket_id = $1
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(invoice)
+    }
+}
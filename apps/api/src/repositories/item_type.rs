@@ -0,0 +1,94 @@
+//! Item type repository for database operations.
+
+use crate::error::AppError;
+use crate::models::item_type::{CreateItemType, ItemType};
+use sqlx::PgPool;
+
+/// Repository for item type operations.
+pub struct ItemTypeRepository;
+
+impl ItemTypeRepository {
+    /// List all item types in their configured order.
+    pub async fn list(pool: &PgPool) -> Result<Vec<ItemType>, AppError> {
+        let item_types = sqlx::query_as::<_, ItemType>(
+            r#"
+            SELECT * FROM item_types
+            ORDER BY sort_order ASC, name ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(item_types)
+    }
+
+    /// Item types whose name contains `query` (case-insensitive), names
+    /// starting with it first, for typeahead.
+    pub async fn search(pool: &PgPool, query: &str, limit: i64) -> Result<Vec<ItemType>, AppError> {
+        let item_types = sqlx::query_as::<_, ItemType>(
+            r#"
+            SELECT * FROM item_types
+            WHERE POSITION(LOWER($1) IN LOWER(name)) > 0
+            ORDER BY POSITION(LOWER($1) IN LOWER(name)) = 1 DESC, sort_order ASC, name ASC
+            LIMIT $2
+            "#,
+        )
+        .bind(query)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(item_types)
+    }
+
+    /// Find the item type matching a ticket's item type (case-insensitive).
+    pub async fn find_by_name(pool: &PgPool, name: &str) -> Result<Option<ItemType>, AppError> {
+        let item_type = sqlx::query_as::<_, ItemType>(
+            r#"
+            SELECT * FROM item_types WHERE LOWER(name) = LOWER($1)
+            "#,
+        )
+        .bind(name)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(item_type)
+    }
+
+    /// Replace all item types in a single transaction, keeping their order.
+    pub async fn replace_all(
+        pool: &PgPool,
+        item_types: Vec<CreateItemType>,
+    ) -> Result<Vec<ItemType>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM item_types")
+            .execute(&mut *tx)
+            .await?;
+
+        for (sort_order, item_type) in item_types.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO item_types (
+                    name, default_turnaround_days, required_photos,
+                    condition_checklist, suggested_services, base_price, sort_order
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+            )
+            .bind(&item_type.name)
+            .bind(item_type.default_turnaround_days)
+            .bind(item_type.required_photos)
+            .bind(&item_type.condition_checklist)
+            .bind(&item_type.suggested_services)
+            .bind(item_type.base_price)
+            .bind(sort_order as i32)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Self::list(pool).await
+    }
+}
@@ -0,0 +1,295 @@
+//! Materials inventory repository for database operations.
+
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::material::{
+    CreateMaterial, CreateTicketMaterial, Material, TicketMaterialEntry, UpdateMaterial,
+};
+
+/// Columns for [`TicketMaterialEntry`], from ticket materials `tm` joined to
+/// their material `m` and recording employee `e`.
+const TICKET_MATERIAL_COLUMNS: &str = r#"
+    tm.ticket_material_id,
+    tm.material_id,
+    m.name AS material_name,
+    m.unit,
+    tm.quantity,
+    tm.unit_cost,
+    ROUND(tm.quantity * tm.unit_cost, 2) AS cost,
+    tm.recorded_by,
+    e.name AS recorded_by_name,
+    tm.recorded_at
+"#;
+
+/// Columns for [`Material`], from materials `m` joined to their stock `s` at
+/// one store. A store without a stock row has none on hand.
+const MATERIAL_COLUMNS: &str = r#"
+    m.material_id,
+    m.name,
+    m.unit,
+    COALESCE(s.quantity_on_hand, 0.000) AS quantity_on_hand,
+    m.unit_cost,
+    m.is_active,
+    m.created_at,
+    GREATEST(m.updated_at, s.updated_at) AS updated_at
+"#;
+
+/// Repository for materials inventory operations.
+pub struct MaterialRepository;
+
+impl MaterialRepository {
+    /// List materials by name with a store's stock, optionally including
+    /// inactive ones.
+    pub async fn list(
+        pool: &PgPool,
+        store_id: Uuid,
+        include_inactive: bool,
+    ) -> Result<Vec<Material>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT {}
+            FROM materials m
+            LEFT JOIN material_stock s ON s.material_id = m.material_id AND s.store_id = $1
+            WHERE $2 OR m.is_active
+            ORDER BY m.name ASC
+            "#,
+            MATERIAL_COLUMNS
+        );
+        let materials = sqlx::query_as::<_, Material>(&sql)
+            .bind(store_id)
+            .bind(include_inactive)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(materials)
+    }
+
+    /// Find a material by ID, with a store's stock.
+    pub async fn find_by_id(
+        pool: &PgPool,
+        material_id: Uuid,
+        store_id: Uuid,
+    ) -> Result<Option<Material>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT {}
+            FROM materials m
+            LEFT JOIN material_stock s ON s.material_id = m.material_id AND s.store_id = $2
+            WHERE m.material_id = $1
+            "#,
+            MATERIAL_COLUMNS
+        );
+        let material = sqlx::query_as::<_, Material>(&sql)
+            .bind(material_id)
+            .bind(store_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(material)
+    }
+
+    /// Find the ID of the material with a name (case-insensitive).
+    pub async fn find_id_by_name(pool: &PgPool, name: &str) -> Result<Option<Uuid>, AppError> {
+        let material_id = sqlx::query_scalar::<_, Uuid>(
+            "SELECT material_id FROM materials WHERE LOWER(name) = LOWER($1)",
+        )
+        .bind(name)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(material_id)
+    }
+
+    /// Create a material, with its starting stock at a store.
+    pub async fn create(
+        pool: &PgPool,
+        store_id: Uuid,
+        input: CreateMaterial,
+    ) -> Result<Material, AppError> {
+        let material = sqlx::query_as::<_, Material>(
+            r#"
+            WITH created AS (
+                INSERT INTO materials (name, unit, unit_cost)
+                VALUES ($1, $2, $3)
+                RETURNING *
+            ),
+            stocked AS (
+                INSERT INTO material_stock (material_id, store_id, quantity_on_hand)
+                SELECT material_id, $4, $5 FROM created
+                RETURNING quantity_on_hand
+            )
+            SELECT m.material_id, m.name, m.unit, s.quantity_on_hand, m.unit_cost,
+                   m.is_active, m.created_at, m.updated_at
+            FROM created m, stocked s
+            "#,
+        )
+        .bind(&input.name)
+        .bind(&input.unit)
+        .bind(input.unit_cost)
+        .bind(store_id)
+        .bind(input.quantity_on_hand)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(material)
+    }
+
+    /// Update a material, keeping existing values for fields not given.
+    /// `quantity_on_hand` sets the stock at the given store.
+    ///
+    /// One statement, so it can't overwrite stock taken out by a concurrent
+    /// [`consume`](Self::consume) with a stale value. Returns None if the
+    /// material doesn't exist.
+    pub async fn update(
+        pool: &PgPool,
+        material_id: Uuid,
+        store_id: Uuid,
+        input: UpdateMaterial,
+    ) -> Result<Option<Material>, AppError> {
+        let material = sqlx::query_as::<_, Material>(
+            r#"
+            WITH updated AS (
+                UPDATE materials
+                SET name = COALESCE($3, name),
+                    unit = COALESCE($4, unit),
+                    unit_cost = COALESCE($5, unit_cost),
+                    is_active = COALESCE($6, is_active),
+                    updated_at = NOW()
+                WHERE material_id = $1
+                RETURNING *
+            ),
+            counted AS (
+                INSERT INTO material_stock (material_id, store_id, quantity_on_hand)
+                SELECT material_id, $2, $7 FROM updated
+                WHERE $7::NUMERIC IS NOT NULL
+                ON CONFLICT (material_id, store_id) DO UPDATE
+                SET quantity_on_hand = EXCLUDED.quantity_on_hand, updated_at = NOW()
+                RETURNING quantity_on_hand
+            )
+            SELECT m.material_id, m.name, m.unit,
+                   COALESCE((SELECT quantity_on_hand FROM counted), s.quantity_on_hand, 0.000)
+                       AS quantity_on_hand,
+                   m.unit_cost, m.is_active, m.created_at, m.updated_at
+            FROM updated m
+            LEFT JOIN material_stock s ON s.material_id = m.material_id AND s.store_id = $2
+            "#,
+        )
+        .bind(material_id)
+        .bind(store_id)
+        .bind(input.name)
+        .bind(input.unit)
+        .bind(input.unit_cost)
+        .bind(input.is_active)
+        .bind(input.quantity_on_hand)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(material)
+    }
+
+    /// Record a material used on a ticket at its current cost.
+    ///
+    /// Takes the quantity out of the given store's stock unless
+    /// `take_from_stock` is false (training tickets). Returns None, recording
+    /// nothing, if the material is inactive or less than the quantity is on
+    /// hand there.
+    pub async fn consume(
+        pool: &PgPool,
+        store_id: Uuid,
+        input: CreateTicketMaterial,
+        take_from_stock: bool,
+    ) -> Result<Option<TicketMaterialEntry>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let unit_cost = sqlx::query_scalar::<_, Decimal>(
+            "SELECT unit_cost FROM materials WHERE material_id = $1 AND is_active FOR SHARE",
+        )
+        .bind(input.material_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(unit_cost) = unit_cost else {
+            return Ok(None);
+        };
+
+        if take_from_stock {
+            let taken = sqlx::query(
+                r#"
+                UPDATE material_stock
+                SET quantity_on_hand = quantity_on_hand - $3, updated_at = NOW()
+                WHERE material_id = $1
+                  AND store_id = $2
+                  AND quantity_on_hand >= $3
+                "#,
+            )
+            .bind(input.material_id)
+            .bind(store_id)
+            .bind(input.quantity)
+            .execute(&mut *tx)
+            .await?;
+            if taken.rows_affected() == 0 {
+                return Ok(None);
+            }
+        }
+
+        let ticket_material_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO ticket_materials (ticket_id, material_id, quantity, unit_cost, recorded_by)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING ticket_material_id
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.material_id)
+        .bind(input.quantity)
+        .bind(unit_cost)
+        .bind(input.recorded_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let sql = format!(
+            r#"
+            SELECT {}
+            FROM ticket_materials tm
+            JOIN materials m ON m.material_id = tm.material_id
+            JOIN employees e ON e.employee_id = tm.recorded_by
+            WHERE tm.ticket_material_id = $1
+            "#,
+            TICKET_MATERIAL_COLUMNS
+        );
+        let entry = sqlx::query_as::<_, TicketMaterialEntry>(&sql)
+            .bind(ticket_material_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(entry))
+    }
+
+    /// Materials used on a ticket, oldest first.
+    pub async fn find_entries_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketMaterialEntry>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT {}
+            FROM ticket_materials tm
+            JOIN materials m ON m.material_id = tm.material_id
+            JOIN employees e ON e.employee_id = tm.recorded_by
+            WHERE tm.ticket_id = $1
+            ORDER BY tm.recorded_at ASC
+            "#,
+            TICKET_MATERIAL_COLUMNS
+        );
+        let entries = sqlx::query_as::<_, TicketMaterialEntry>(&sql)
+            .bind(ticket_id)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(entries)
+    }
+}
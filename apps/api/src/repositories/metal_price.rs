@@ -0,0 +1,71 @@
+//! Metal price repository for database operations.
+
+use crate::error::AppError;
+use crate::models::metal_price::{CreateMetalPrice, MetalPrice};
+use sqlx::PgPool;
+
+/// Repository for metal price operations.
+pub struct MetalPriceRepository;
+
+impl MetalPriceRepository {
+    /// List all metal prices.
+    pub async fn list(pool: &PgPool) -> Result<Vec<MetalPrice>, AppError> {
+        let prices = sqlx::query_as::<_, MetalPrice>(
+            r#"
+            SELECT * FROM metal_prices
+            ORDER BY metal_type ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(prices)
+    }
+
+    /// Find the price for a metal type.
+    pub async fn find_by_metal_type(
+        pool: &PgPool,
+        metal_type: &str,
+    ) -> Result<Option<MetalPrice>, AppError> {
+        let price = sqlx::query_as::<_, MetalPrice>(
+            r#"
+            SELECT * FROM metal_prices WHERE metal_type = $1
+            "#,
+        )
+        .bind(metal_type)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(price)
+    }
+
+    /// Replace all metal prices in a single transaction.
+    pub async fn replace_all(
+        pool: &PgPool,
+        prices: Vec<CreateMetalPrice>,
+    ) -> Result<Vec<MetalPrice>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM metal_prices")
+            .execute(&mut *tx)
+            .await?;
+
+        for price in &prices {
+            sqlx::query(
+                r#"
+                INSERT INTO metal_prices (metal_type, purity, price_per_gram)
+                VALUES ($1, $2, $3)
+                "#,
+            )
+            .bind(&price.metal_type)
+            .bind(price.purity)
+            .bind(price.price_per_gram)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Self::list(pool).await
+    }
+}
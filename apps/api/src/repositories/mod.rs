@@ -2,27 +2,107 @@
 //!
 //! Repositories handle database operations and provide a clean interface
 //! for data access. Each repository is responsible for a specific domain entity.
+//!
+//! Writes that handlers combine into one unit of work take an executor (or a
+//! `&mut PgConnection` when they issue several statements), so they can run on
+//! the pool or inside a caller's transaction.
 
+pub mod admin_recovery;
 pub mod admin_session;
+pub mod appraisal;
+pub mod audit_log;
+pub mod campaign;
+pub mod contact;
+pub mod custody;
 pub mod customer;
+pub mod defect;
 pub mod employee;
 pub mod employee_session;
 pub mod field_history;
+pub mod idempotency;
+pub mod intake_draft;
+pub mod integrity;
+pub mod invoice;
+pub mod item_type;
+pub mod material;
+pub mod metal_price;
+pub mod movement;
+pub mod notification;
+pub mod notification_template;
+pub mod partner;
+pub mod payment;
+pub mod pickup;
+pub mod pin_challenge;
+pub mod promise_date_reason;
+pub mod qc_check;
+pub mod queue_snapshot;
+pub mod quote;
+pub mod rate_limit;
+pub mod reminder;
+pub mod report;
+pub mod report_definition;
+pub mod request_log;
+pub mod rush_pricing;
+pub mod service;
+pub mod settings_change;
 pub mod status_history;
 pub mod storage_location;
+pub mod store;
 pub mod store_settings;
 pub mod ticket;
+pub mod ticket_item;
 pub mod ticket_note;
 pub mod ticket_photo;
+pub mod time_entry;
+pub mod transfer;
+pub mod webhook;
 
+pub use admin_recovery::AdminRecoveryRepository;
 pub use admin_session::AdminSessionRepository;
+pub use appraisal::AppraisalRepository;
+pub use audit_log::AuditLogRepository;
+pub use campaign::CampaignRepository;
+pub use contact::ContactRepository;
+pub use custody::CustodyRepository;
 pub use customer::CustomerRepository;
+pub use defect::DefectRepository;
 pub use employee::EmployeeRepository;
 pub use employee_session::EmployeeSessionRepository;
 pub use field_history::FieldHistoryRepository;
+pub use idempotency::IdempotencyRepository;
+pub use intake_draft::IntakeDraftRepository;
+pub use integrity::IntegrityRepository;
+pub use invoice::InvoiceRepository;
+pub use item_type::ItemTypeRepository;
+pub use material::MaterialRepository;
+pub use metal_price::MetalPriceRepository;
+pub use movement::MovementRepository;
+pub use notification::NotificationRepository;
+pub use notification_template::NotificationTemplateRepository;
+pub use partner::PartnerRepository;
+pub use payment::PaymentRepository;
+pub use pickup::PickupRepository;
+pub use pin_challenge::PinChallengeRepository;
+pub use promise_date_reason::PromiseDateReasonRepository;
+pub use qc_check::QcCheckRepository;
+pub use queue_snapshot::QueueSnapshotRepository;
+pub use quote::QuoteRepository;
+pub use rate_limit::RateLimitRepository;
+pub use reminder::ReminderRepository;
+pub use report::ReportRepository;
+pub use report_definition::ReportDefinitionRepository;
+pub use request_log::RequestLogRepository;
+pub use rush_pricing::RushPricingRepository;
+pub use service::ServiceRepository;
+pub use settings_change::SettingsChangeRepository;
 pub use status_history::StatusHistoryRepository;
 pub use storage_location::StorageLocationRepository;
+pub use store::StoreRepository;
 pub use store_settings::StoreSettingsRepository;
 pub use ticket::TicketRepository;
+pub use ticket_item::TicketItemRepository;
 pub use ticket_note::TicketNoteRepository;
 pub use ticket_photo::TicketPhotoRepository;
+pub use time_entry::TimeEntryRepository;
+pub use transfer::TransferRepository;
+pub use webhook::WebhookRepository;
@@ -0,0 +1,158 @@
+//! Ticket movement repository for database operations.
+
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::movement::{CreateTicketMovement, TicketMovement, TicketMovementEntry};
+
+/// Columns selected for [`TicketMovementEntry`], joined with names.
+const ENTRY_SELECT: &str = r#"
+    SELECT
+        m.movement_id,
+        m.ticket_id,
+        t.friendly_code,
+        m.from_location_id,
+        fl.name AS from_location_name,
+        m.from_employee_id,
+        fe.name AS from_employee_name,
+        m.to_location_id,
+        tl.name AS to_location_name,
+        m.to_employee_id,
+        te.name AS to_employee_name,
+        m.moved_by,
+        b.name AS moved_by_name,
+        m.notes,
+        m.moved_at
+    FROM ticket_movements m
+    JOIN tickets t ON t.ticket_id = m.ticket_id
+    LEFT JOIN storage_locations fl ON fl.location_id = m.from_location_id
+    LEFT JOIN employees fe ON fe.employee_id = m.from_employee_id
+    LEFT JOIN storage_locations tl ON tl.location_id = m.to_location_id
+    LEFT JOIN employees te ON te.employee_id = m.to_employee_id
+    JOIN employees b ON b.employee_id = m.moved_by
+"#;
+
+/// Repository for ticket movement operations.
+pub struct MovementRepository;
+
+impl MovementRepository {
+    /// Record a movement.
+    pub async fn create(
+        executor: impl PgExecutor<'_>,
+        input: CreateTicketMovement,
+    ) -> Result<TicketMovement, AppError> {
+        let movement = sqlx::query_as::<_, TicketMovement>(
+            r#"
+            INSERT INTO ticket_movements (
+                ticket_id, from_location_id, from_employee_id,
+                to_location_id, to_employee_id, moved_by, notes
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.from_location_id)
+        .bind(input.from_employee_id)
+        .bind(input.to_location_id)
+        .bind(input.to_employee_id)
+        .bind(input.moved_by)
+        .bind(&input.notes)
+        .fetch_one(executor)
+        .await?;
+
+        Ok(movement)
+    }
+
+    /// Find a ticket's movements with names, oldest first.
+    pub async fn find_entries_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketMovementEntry>, AppError> {
+        let query = format!(
+            "{} WHERE m.ticket_id = $1 ORDER BY m.moved_at ASC",
+            ENTRY_SELECT
+        );
+        let entries = sqlx::query_as::<_, TicketMovementEntry>(&query)
+            .bind(ticket_id)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(entries)
+    }
+
+    /// Find a ticket's most recent movement with names, if it has moved.
+    pub async fn find_latest_entry_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Option<TicketMovementEntry>, AppError> {
+        let query = format!(
+            "{} WHERE m.ticket_id = $1 ORDER BY m.moved_at DESC LIMIT 1",
+            ENTRY_SELECT
+        );
+        let entry = sqlx::query_as::<_, TicketMovementEntry>(&query)
+            .bind(ticket_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(entry)
+    }
+
+    /// List movements into or out of a location (or anywhere) in a time
+    /// range, oldest first. Deleted tickets are excluded.
+    pub async fn list_entries(
+        pool: &PgPool,
+        location_id: Option<Uuid>,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<TicketMovementEntry>, AppError> {
+        let query = format!(
+            r#"{}
+            WHERE t.deleted_at IS NULL
+              AND m.moved_at >= $2 AND m.moved_at < $3
+              AND ($1::UUID IS NULL OR m.from_location_id = $1 OR m.to_location_id = $1)
+            ORDER BY m.moved_at ASC
+            "#,
+            ENTRY_SELECT
+        );
+        let entries = sqlx::query_as::<_, TicketMovementEntry>(&query)
+            .bind(location_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(entries)
+    }
+
+    /// List the latest movement of each open ticket that is out at a bench,
+    /// optionally only tickets stored at one location. Longest out first.
+    pub async fn list_checked_out(
+        pool: &PgPool,
+        location_id: Option<Uuid>,
+    ) -> Result<Vec<TicketMovementEntry>, AppError> {
+        let query = format!(
+            r#"{}
+            WHERE m.movement_id IN (
+                SELECT DISTINCT ON (ticket_id) movement_id
+                FROM ticket_movements
+                ORDER BY ticket_id, moved_at DESC
+            )
+              AND m.to_employee_id IS NOT NULL
+              AND t.deleted_at IS NULL
+              AND t.status NOT IN ('closed', 'archived')
+              AND ($1::UUID IS NULL OR t.storage_location_id = $1)
+            ORDER BY m.moved_at ASC
+            "#,
+            ENTRY_SELECT
+        );
+        let entries = sqlx::query_as::<_, TicketMovementEntry>(&query)
+            .bind(location_id)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(entries)
+    }
+}
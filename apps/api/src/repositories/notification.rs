@@ -0,0 +1,160 @@
+//! Notification log repository for database operations.
+
+use crate::error::AppError;
+use crate::models::notification::{CreateNotificationLog, NotificationLog, NotificationStatus};
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Repository for customer notification log operations.
+pub struct NotificationRepository;
+
+impl NotificationRepository {
+    /// Log a new notification.
+    pub async fn create(
+        pool: &PgPool,
+        input: CreateNotificationLog,
+    ) -> Result<NotificationLog, AppError> {
+        let notification = sqlx::query_as::<_, NotificationLog>(
+            r#"
+            INSERT INTO notification_log (ticket_id, channel, recipient, subject, message, status, provider, error)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.channel)
+        .bind(&input.recipient)
+        .bind(&input.subject)
+        .bind(&input.message)
+        .bind(input.status)
+        .bind(&input.provider)
+        .bind(&input.error)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(notification)
+    }
+
+    /// Mark a notification as accepted by the provider.
+    pub async fn mark_sent(
+        pool: &PgPool,
+        notification_id: Uuid,
+        provider_message_id: Option<&str>,
+    ) -> Result<NotificationLog, AppError> {
+        let notification = sqlx::query_as::<_, NotificationLog>(
+            r#"
+            UPDATE notification_log
+            SET status = $2, provider_message_id = $3, sent_at = NOW()
+            WHERE notification_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(notification_id)
+        .bind(NotificationStatus::Sent)
+        .bind(provider_message_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(notification)
+    }
+
+    /// Mark a notification as failed with the provider's error.
+    pub async fn mark_failed(
+        pool: &PgPool,
+        notification_id: Uuid,
+        error: &str,
+    ) -> Result<NotificationLog, AppError> {
+        let notification = sqlx::query_as::<_, NotificationLog>(
+            r#"
+            UPDATE notification_log
+            SET status = $2, error = $3
+            WHERE notification_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(notification_id)
+        .bind(NotificationStatus::Failed)
+        .bind(error)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(notification)
+    }
+
+    /// Claim up to `limit` pending notifications logged before `before`.
+    ///
+    /// A claimed message isn't claimed again for a minute, and rows locked by
+    /// another worker are skipped, so each retry is sent once.
+    pub async fn claim_pending(
+        pool: &PgPool,
+        before: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<Vec<NotificationLog>, AppError> {
+        let notifications = sqlx::query_as::<_, NotificationLog>(
+            r#"
+            UPDATE notification_log
+            SET last_attempt_at = NOW()
+            WHERE notification_id IN (
+                SELECT notification_id FROM notification_log
+                WHERE status = 'pending'
+                  AND created_at < $1
+                  AND (last_attempt_at IS NULL OR last_attempt_at < NOW() - INTERVAL '1 minute')
+                ORDER BY created_at
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING *
+            "#,
+        )
+        .bind(before)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(notifications)
+    }
+
+    /// Mark pending notifications logged before `before` as failed.
+    ///
+    /// Returns the number of notifications marked.
+    pub async fn expire_pending(
+        pool: &PgPool,
+        before: DateTime<Utc>,
+        error: &str,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE notification_log
+            SET status = $2, error = $3
+            WHERE status = 'pending' AND created_at < $1
+            "#,
+        )
+        .bind(before)
+        .bind(NotificationStatus::Failed)
+        .bind(error)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Find all notifications for a ticket, oldest first.
+    pub async fn find_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<NotificationLog>, AppError> {
+        let notifications = sqlx::query_as::<_, NotificationLog>(
+            r#"
+            SELECT * FROM notification_log
+            WHERE ticket_id = $1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(notifications)
+    }
+}
@@ -0,0 +1,70 @@
+//! Notification template repository for database operations.
+
+use crate::error::AppError;
+use crate::models::notification::{
+    NotificationEvent, NotificationTemplate, UpdateNotificationTemplate,
+};
+use sqlx::PgPool;
+
+/// Repository for notification template operations.
+pub struct NotificationTemplateRepository;
+
+impl NotificationTemplateRepository {
+    /// List all templates.
+    pub async fn list(pool: &PgPool) -> Result<Vec<NotificationTemplate>, AppError> {
+        let templates = sqlx::query_as::<_, NotificationTemplate>(
+            r#"
+            SELECT * FROM notification_templates
+            ORDER BY event ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(templates)
+    }
+
+    /// Find the template for an event.
+    pub async fn find_by_event(
+        pool: &PgPool,
+        event: NotificationEvent,
+    ) -> Result<Option<NotificationTemplate>, AppError> {
+        let template = sqlx::query_as::<_, NotificationTemplate>(
+            r#"
+            SELECT * FROM notification_templates WHERE event = $1
+            "#,
+        )
+        .bind(event)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(template)
+    }
+
+    /// Update a template. Only the provided fields are changed.
+    pub async fn update(
+        pool: &PgPool,
+        event: NotificationEvent,
+        input: UpdateNotificationTemplate,
+    ) -> Result<Option<NotificationTemplate>, AppError> {
+        let template = sqlx::query_as::<_, NotificationTemplate>(
+            r#"
+            UPDATE notification_templates SET
+                subject = COALESCE($2, subject),
+                body = COALESCE($3, body),
+                is_enabled = COALESCE($4, is_enabled),
+                updated_at = NOW()
+            WHERE event = $1
+            RETURNING *
+            "#,
+        )
+        .bind(event)
+        .bind(&input.subject)
+        .bind(&input.body)
+        .bind(input.is_enabled)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(template)
+    }
+}
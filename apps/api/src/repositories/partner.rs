@@ -0,0 +1,325 @@
+//! Partner repository for database operations.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::partner::{CreatePartner, Partner, PartnerTicket, UpdatePartner};
+use crate::models::ticket::TicketStatus;
+
+/// Prefix marking a string as a partner API key.
+const API_KEY_PREFIX: &str = "fpk_";
+
+/// Characters of the key kept in the clear for identifying it.
+const API_KEY_DISPLAY_LENGTH: usize = 8;
+
+/// Repository for partner database operations.
+pub struct PartnerRepository;
+
+impl PartnerRepository {
+    /// Generate a partner API key.
+    ///
+    /// Creates a 256-bit random key encoded as base64url (no padding),
+    /// prefixed with `fpk_` so it's recognizable in config files.
+    pub fn generate_api_key() -> String {
+        let mut key_bytes = [0u8; 32]; // 256 bits
+        rand::thread_rng().fill_bytes(&mut key_bytes);
+        format!("{}{}", API_KEY_PREFIX, URL_SAFE_NO_PAD.encode(key_bytes))
+    }
+
+    /// Hash an API key for storage and lookup.
+    ///
+    /// Keys are long random strings, so a fast hash is enough; only the
+    /// hash is stored.
+    pub fn hash_api_key(key: &str) -> String {
+        hex::encode(Sha256::digest(key.as_bytes()))
+    }
+
+    /// The leading characters of a key shown in partner lists.
+    pub fn display_prefix(key: &str) -> String {
+        key.chars().take(API_KEY_DISPLAY_LENGTH).collect()
+    }
+
+    /// Create a new partner.
+    pub async fn create(pool: &PgPool, input: CreatePartner) -> Result<Partner, AppError> {
+        let partner = sqlx::query_as::<_, Partner>(
+            r#"
+            INSERT INTO partners (
+                name, customer_id, api_key_prefix, api_key_hash, rate_limit_per_minute, taken_in_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(&input.name)
+        .bind(input.customer_id)
+        .bind(&input.api_key_prefix)
+        .bind(&input.api_key_hash)
+        .bind(input.rate_limit_per_minute)
+        .bind(input.taken_in_by)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(partner)
+    }
+
+    /// Find a partner by ID.
+    pub async fn find_by_id(pool: &PgPool, partner_id: Uuid) -> Result<Option<Partner>, AppError> {
+        let partner = sqlx::query_as::<_, Partner>("SELECT * FROM partners WHERE partner_id = $1")
+            .bind(partner_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(partner)
+    }
+
+    /// Find the partner linked to a customer.
+    pub async fn find_by_customer_id(
+        pool: &PgPool,
+        customer_id: Uuid,
+    ) -> Result<Option<Partner>, AppError> {
+        let partner = sqlx::query_as::<_, Partner>("SELECT * FROM partners WHERE customer_id = $1")
+            .bind(customer_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(partner)
+    }
+
+    /// Find an active partner by API key.
+    pub async fn find_active_by_api_key(
+        pool: &PgPool,
+        key: &str,
+    ) -> Result<Option<Partner>, AppError> {
+        let partner = sqlx::query_as::<_, Partner>(
+            r#"
+            SELECT * FROM partners
+            WHERE api_key_hash = $1 AND is_active = TRUE
+            "#,
+        )
+        .bind(Self::hash_api_key(key))
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(partner)
+    }
+
+    /// List all partners, ordered by name.
+    pub async fn list(pool: &PgPool) -> Result<Vec<Partner>, AppError> {
+        let partners = sqlx::query_as::<_, Partner>("SELECT * FROM partners ORDER BY name ASC")
+            .fetch_all(pool)
+            .await?;
+
+        Ok(partners)
+    }
+
+    /// Update a partner.
+    ///
+    /// Returns None if the partner doesn't exist.
+    pub async fn update(
+        pool: &PgPool,
+        partner_id: Uuid,
+        input: UpdatePartner,
+    ) -> Result<Option<Partner>, AppError> {
+        let Some(existing) = Self::find_by_id(pool, partner_id).await? else {
+            return Ok(None);
+        };
+
+        let partner = sqlx::query_as::<_, Partner>(
+            r#"
+            UPDATE partners
+            SET name = $1,
+                rate_limit_per_minute = $2,
+                taken_in_by = $3,
+                is_active = $4,
+                updated_at = NOW()
+            WHERE partner_id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(input.name.unwrap_or(existing.name))
+        .bind(
+            input
+                .rate_limit_per_minute
+                .unwrap_or(existing.rate_limit_per_minute),
+        )
+        .bind(input.taken_in_by.unwrap_or(existing.taken_in_by))
+        .bind(input.is_active.unwrap_or(existing.is_active))
+        .bind(partner_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(Some(partner))
+    }
+
+    /// Replace a partner's API key.
+    pub async fn set_api_key(
+        pool: &PgPool,
+        partner_id: Uuid,
+        api_key_prefix: &str,
+        api_key_hash: &str,
+    ) -> Result<Partner, AppError> {
+        let partner = sqlx::query_as::<_, Partner>(
+            r#"
+            UPDATE partners
+            SET api_key_prefix = $1, api_key_hash = $2, updated_at = NOW()
+            WHERE partner_id = $3
+            RETURNING *
+            "#,
+        )
+        .bind(api_key_prefix)
+        .bind(api_key_hash)
+        .bind(partner_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(partner)
+    }
+
+    /// Count a partner API request toward today's usage.
+    pub async fn record_usage(
+        pool: &PgPool,
+        partner_id: Uuid,
+        rate_limited: bool,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            INSERT INTO partner_api_usage (partner_id, day, request_count, rate_limited_count)
+            VALUES ($1, CURRENT_DATE, 1, $2)
+            ON CONFLICT (partner_id, day) DO UPDATE
+            SET request_count = partner_api_usage.request_count + 1,
+                rate_limited_count = partner_api_usage.rate_limited_count + EXCLUDED.rate_limited_count
+            "#,
+        )
+        .bind(partner_id)
+        .bind(i32::from(rate_limited))
+        .execute(pool)
+        .await?;
+
+        sqlx::query("UPDATE partners SET last_used_at = NOW() WHERE partner_id = $1")
+            .bind(partner_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Record that a ticket was submitted through the partner API.
+    pub async fn link_ticket(
+        executor: impl PgExecutor<'_>,
+        ticket_id: Uuid,
+        partner_id: Uuid,
+        partner_reference: Option<&str>,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            INSERT INTO partner_tickets (ticket_id, partner_id, partner_reference)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(partner_id)
+        .bind(partner_reference)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// List a partner's tickets, newest first.
+    ///
+    /// Covers every ticket for the partner's customer record, including work
+    /// dropped off in person. Deleted and training tickets are excluded.
+    pub async fn list_tickets(
+        pool: &PgPool,
+        customer_id: Uuid,
+        status: Option<TicketStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<PartnerTicket>, AppError> {
+        let tickets = sqlx::query_as::<_, PartnerTicket>(
+            r#"
+            SELECT t.friendly_code, pt.partner_reference, t.item_type, t.item_description,
+                t.requested_work, t.status, t.is_rush, t.promise_date, t.quote_amount,
+                t.actual_amount, t.created_at, t.closed_at
+            FROM tickets t
+            LEFT JOIN partner_tickets pt ON pt.ticket_id = t.ticket_id
+            WHERE t.customer_id = $1
+              AND t.deleted_at IS NULL
+              AND NOT t.is_training
+              AND ($2::ticket_status IS NULL OR t.status = $2)
+            ORDER BY t.created_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(customer_id)
+        .bind(status)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(tickets)
+    }
+
+    /// Find one of a partner's tickets by friendly code (case-insensitive).
+    pub async fn find_ticket(
+        pool: &PgPool,
+        customer_id: Uuid,
+        friendly_code: &str,
+    ) -> Result<Option<PartnerTicket>, AppError> {
+        let ticket = sqlx::query_as::<_, PartnerTicket>(
+            r#"
+            SELECT t.friendly_code, pt.partner_reference, t.item_type, t.item_description,
+                t.requested_work, t.status, t.is_rush, t.promise_date, t.quote_amount,
+                t.actual_amount, t.created_at, t.closed_at
+            FROM tickets t
+            LEFT JOIN partner_tickets pt ON pt.ticket_id = t.ticket_id
+            WHERE t.customer_id = $1
+              AND UPPER(t.friendly_code) = UPPER($2)
+              AND t.deleted_at IS NULL
+              AND NOT t.is_training
+            "#,
+        )
+        .bind(customer_id)
+        .bind(friendly_code)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(ticket)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_api_key_format() {
+        let key = PartnerRepository::generate_api_key();
+        assert!(key.starts_with("fpk_"));
+        // 32 bytes -> 43 chars in base64url without padding
+        assert_eq!(key.len(), 4 + 43);
+        assert_ne!(key, PartnerRepository::generate_api_key());
+    }
+
+    #[test]
+    fn test_hash_api_key_is_stable_hex() {
+        let hash = PartnerRepository::hash_api_key("fpk_example");
+        assert_eq!(hash.len(), 64);
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_eq!(hash, PartnerRepository::hash_api_key("fpk_example"));
+        assert_ne!(hash, PartnerRepository::hash_api_key("fpk_other"));
+    }
+
+    #[test]
+    fn test_display_prefix() {
+        assert_eq!(
+            PartnerRepository::display_prefix("fpk_Ab3dEfGh"),
+            "fpk_Ab3d"
+        );
+    }
+}
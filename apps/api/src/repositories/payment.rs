@@ -0,0 +1,83 @@
+//! Payment repository for database operations.
+
+use crate::error::AppError;
+use crate::models::payment::{CreateTicketPayment, TicketPayment, TicketPaymentEntry};
+use rust_decimal::Decimal;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+/// Repository for ticket payment database operations.
+pub struct PaymentRepository;
+
+impl PaymentRepository {
+    /// Record a payment.
+    pub async fn create(
+        executor: impl PgExecutor<'_>,
+        input: CreateTicketPayment,
+    ) -> Result<TicketPayment, AppError> {
+        let payment = sqlx::query_as::<_, TicketPayment>(
+            r#"
+            INSERT INTO ticket_payments (ticket_id, kind, method, amount, notes, received_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.kind)
+        .bind(input.method)
+        .bind(input.amount)
+        .bind(&input.notes)
+        .bind(input.received_by)
+        .fetch_one(executor)
+        .await?;
+
+        Ok(payment)
+    }
+
+    /// List a ticket's payments with employee names, oldest first.
+    pub async fn find_entries_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketPaymentEntry>, AppError> {
+        let payments = sqlx::query_as::<_, TicketPaymentEntry>(
+            r#"
+            SELECT
+                p.payment_id,
+                p.kind,
+                p.method,
+                p.amount,
+                p.notes,
+                p.received_by,
+                e.name AS received_by_name,
+                p.received_at
+            FROM ticket_payments p
+            JOIN employees e ON e.employee_id = p.received_by
+            WHERE p.ticket_id = $1
+            ORDER BY p.received_at ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(payments)
+    }
+
+    /// Total paid against a ticket, and the part of it paid as deposits.
+    pub async fn totals(pool: &PgPool, ticket_id: Uuid) -> Result<(Decimal, Decimal), AppError> {
+        let totals = sqlx::query_as::<_, (Decimal, Decimal)>(
+            r#"
+            SELECT
+                COALESCE(SUM(amount), 0),
+                COALESCE(SUM(amount) FILTER (WHERE kind = 'deposit'), 0)
+            FROM ticket_payments
+            WHERE ticket_id = $1
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(totals)
+    }
+}
@@ -0,0 +1,203 @@
+//! Authorized pickup repository for database operations.
+
+use crate::error::AppError;
+use crate::models::pickup::{
+    CreateAuthorizedPickup, CreateTicketPickup, TicketAuthorizedPickup, TicketPickupEntry,
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+/// Columns for a pickup entry, with the authorization and releasing employee.
+const PICKUP_ENTRY_COLUMNS: &str = r#"
+    p.pickup_id,
+    p.authorized_pickup_id,
+    p.picked_up_by_name,
+    a.relationship,
+    p.id_type,
+    p.id_last_four,
+    p.signature IS NOT NULL AS has_signature,
+    p.released_by,
+    e.name AS released_by_name,
+    p.picked_up_at
+"#;
+
+/// Repository for authorized pickup and release records.
+pub struct PickupRepository;
+
+impl PickupRepository {
+    /// Authorize someone to collect a ticket's item.
+    pub async fn create_authorized(
+        pool: &PgPool,
+        input: CreateAuthorizedPickup,
+    ) -> Result<TicketAuthorizedPickup, AppError> {
+        let authorized = sqlx::query_as::<_, TicketAuthorizedPickup>(
+            r#"
+            INSERT INTO ticket_authorized_pickups (ticket_id, name, relationship, phone, notes, added_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(&input.name)
+        .bind(&input.relationship)
+        .bind(&input.phone)
+        .bind(&input.notes)
+        .bind(input.added_by)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(authorized)
+    }
+
+    /// List a ticket's authorizations, including revoked ones, oldest first.
+    pub async fn list_authorized(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketAuthorizedPickup>, AppError> {
+        let authorized = sqlx::query_as::<_, TicketAuthorizedPickup>(
+            r#"
+            SELECT * FROM ticket_authorized_pickups
+            WHERE ticket_id = $1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(authorized)
+    }
+
+    /// Find one of a ticket's authorizations.
+    pub async fn find_authorized(
+        pool: &PgPool,
+        ticket_id: Uuid,
+        authorized_pickup_id: Uuid,
+    ) -> Result<Option<TicketAuthorizedPickup>, AppError> {
+        let authorized = sqlx::query_as::<_, TicketAuthorizedPickup>(
+            r#"
+            SELECT * FROM ticket_authorized_pickups
+            WHERE ticket_id = $1 AND authorized_pickup_id = $2
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(authorized_pickup_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(authorized)
+    }
+
+    /// Revoke an active authorization.
+    ///
+    /// Returns None if it doesn't exist on the ticket or is already revoked.
+    pub async fn revoke_authorized(
+        pool: &PgPool,
+        ticket_id: Uuid,
+        authorized_pickup_id: Uuid,
+        revoked_by: Uuid,
+    ) -> Result<Option<TicketAuthorizedPickup>, AppError> {
+        let authorized = sqlx::query_as::<_, TicketAuthorizedPickup>(
+            r#"
+            UPDATE ticket_authorized_pickups
+            SET revoked_at = NOW(), revoked_by = $3
+            WHERE ticket_id = $1 AND authorized_pickup_id = $2 AND revoked_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(authorized_pickup_id)
+        .bind(revoked_by)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(authorized)
+    }
+
+    /// Record a release and return it as an entry.
+    pub async fn create_pickup(
+        conn: &mut PgConnection,
+        input: CreateTicketPickup,
+    ) -> Result<TicketPickupEntry, AppError> {
+        let pickup_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO ticket_pickups (
+                ticket_id, authorized_pickup_id, picked_up_by_name,
+                id_type, id_last_four, signature, released_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING pickup_id
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.authorized_pickup_id)
+        .bind(&input.picked_up_by_name)
+        .bind(&input.id_type)
+        .bind(&input.id_last_four)
+        .bind(&input.signature)
+        .bind(input.released_by)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        let entry = sqlx::query_as::<_, TicketPickupEntry>(&format!(
+            r#"
+            SELECT {}
+            FROM ticket_pickups p
+            JOIN employees e ON e.employee_id = p.released_by
+            LEFT JOIN ticket_authorized_pickups a ON a.authorized_pickup_id = p.authorized_pickup_id
+            WHERE p.pickup_id = $1
+            "#,
+            PICKUP_ENTRY_COLUMNS
+        ))
+        .bind(pickup_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        Ok(entry)
+    }
+
+    /// Find every release of a ticket's item, oldest first.
+    pub async fn find_entries_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketPickupEntry>, AppError> {
+        let entries = sqlx::query_as::<_, TicketPickupEntry>(&format!(
+            r#"
+            SELECT {}
+            FROM ticket_pickups p
+            JOIN employees e ON e.employee_id = p.released_by
+            LEFT JOIN ticket_authorized_pickups a ON a.authorized_pickup_id = p.authorized_pickup_id
+            WHERE p.ticket_id = $1
+            ORDER BY p.picked_up_at ASC
+            "#,
+            PICKUP_ENTRY_COLUMNS
+        ))
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    /// Find the signature captured at a release.
+    ///
+    /// Returns None if the release doesn't exist on the ticket or has no signature.
+    pub async fn find_signature(
+        pool: &PgPool,
+        ticket_id: Uuid,
+        pickup_id: Uuid,
+    ) -> Result<Option<Vec<u8>>, AppError> {
+        let signature = sqlx::query_scalar::<_, Option<Vec<u8>>>(
+            r#"
+            SELECT signature FROM ticket_pickups
+            WHERE ticket_id = $1 AND pickup_id = $2
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(pickup_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(signature.flatten())
+    }
+}
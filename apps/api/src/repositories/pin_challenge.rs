@@ -0,0 +1,57 @@
+//! PIN challenge repository for database operations.
+
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::pin_challenge::PinChallenge;
+use crate::repositories::EmployeeSessionRepository;
+
+/// How long a challenge can be answered, in seconds.
+const PIN_CHALLENGE_TTL_SECONDS: i64 = 120;
+
+/// Repository for PIN challenge operations.
+pub struct PinChallengeRepository;
+
+impl PinChallengeRepository {
+    /// Issue a new challenge to an employee, clearing out expired ones.
+    pub async fn create(pool: &PgPool, employee_id: Uuid) -> Result<PinChallenge, AppError> {
+        sqlx::query("DELETE FROM pin_challenges WHERE expires_at < NOW()")
+            .execute(pool)
+            .await?;
+
+        let challenge = sqlx::query_as::<_, PinChallenge>(
+            r#"
+            INSERT INTO pin_challenges (nonce, employee_id, expires_at)
+            VALUES ($1, $2, $3)
+            RETURNING *
+            "#,
+        )
+        .bind(EmployeeSessionRepository::generate_token())
+        .bind(employee_id)
+        .bind(Utc::now() + Duration::seconds(PIN_CHALLENGE_TTL_SECONDS))
+        .fetch_one(pool)
+        .await?;
+
+        Ok(challenge)
+    }
+
+    /// Use up a challenge, returning the employee it was issued for.
+    ///
+    /// Returns None if it doesn't exist, was already answered, or has expired.
+    pub async fn consume(pool: &PgPool, nonce: &str) -> Result<Option<Uuid>, AppError> {
+        let employee_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            DELETE FROM pin_challenges
+            WHERE nonce = $1 AND expires_at > NOW()
+            RETURNING employee_id
+            "#,
+        )
+        .bind(nonce)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(employee_id)
+    }
+}
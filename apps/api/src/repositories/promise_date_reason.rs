@@ -0,0 +1,71 @@
+//! Promise date reason repository for database operations.
+
+use crate::error::AppError;
+use crate::models::promise_date_reason::{CreatePromiseDateReason, PromiseDateReason};
+use sqlx::PgPool;
+
+/// Repository for promise date reason operations.
+pub struct PromiseDateReasonRepository;
+
+impl PromiseDateReasonRepository {
+    /// List all reasons in their configured order.
+    pub async fn list(pool: &PgPool) -> Result<Vec<PromiseDateReason>, AppError> {
+        let reasons = sqlx::query_as::<_, PromiseDateReason>(
+            r#"
+            SELECT * FROM promise_date_reasons
+            ORDER BY sort_order ASC, code ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(reasons)
+    }
+
+    /// Find a reason by code (case-insensitive).
+    pub async fn find_by_code(
+        pool: &PgPool,
+        code: &str,
+    ) -> Result<Option<PromiseDateReason>, AppError> {
+        let reason = sqlx::query_as::<_, PromiseDateReason>(
+            r#"
+            SELECT * FROM promise_date_reasons WHERE LOWER(code) = LOWER($1)
+            "#,
+        )
+        .bind(code)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(reason)
+    }
+
+    /// Replace all reasons in a single transaction, keeping their order.
+    pub async fn replace_all(
+        pool: &PgPool,
+        reasons: Vec<CreatePromiseDateReason>,
+    ) -> Result<Vec<PromiseDateReason>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM promise_date_reasons")
+            .execute(&mut *tx)
+            .await?;
+
+        for (sort_order, reason) in reasons.iter().enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO promise_date_reasons (code, label, sort_order)
+                VALUES ($1, $2, $3)
+                "#,
+            )
+            .bind(&reason.code)
+            .bind(&reason.label)
+            .bind(sort_order as i32)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Self::list(pool).await
+    }
+}
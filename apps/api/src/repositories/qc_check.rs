@@ -0,0 +1,78 @@
+//! QC check repository for database operations.
+
+use crate::error::AppError;
+use crate::models::qc_check::{CreateTicketQcCheck, TicketQcCheck};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Repository for ticket QC check database operations.
+pub struct QcCheckRepository;
+
+impl QcCheckRepository {
+    /// Record a new QC check.
+    ///
+    /// The check is marked as passed only when no items failed.
+    pub async fn create(
+        pool: &PgPool,
+        input: CreateTicketQcCheck,
+    ) -> Result<TicketQcCheck, AppError> {
+        let passed = input.failed_items.is_empty();
+
+        let check = sqlx::query_as::<_, TicketQcCheck>(
+            r#"
+            INSERT INTO ticket_qc_checks (ticket_id, passed, passed_items, failed_items, notes, checked_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(passed)
+        .bind(&input.passed_items)
+        .bind(&input.failed_items)
+        .bind(&input.notes)
+        .bind(input.checked_by)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(check)
+    }
+
+    /// Find all QC checks for a ticket, oldest first.
+    pub async fn find_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketQcCheck>, AppError> {
+        let checks = sqlx::query_as::<_, TicketQcCheck>(
+            r#"
+            SELECT * FROM ticket_qc_checks
+            WHERE ticket_id = $1
+            ORDER BY checked_at ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(checks)
+    }
+
+    /// Find the most recent QC check for a ticket.
+    pub async fn find_latest(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Option<TicketQcCheck>, AppError> {
+        let check = sqlx::query_as::<_, TicketQcCheck>(
+            r#"
+            SELECT * FROM ticket_qc_checks
+            WHERE ticket_id = $1
+            ORDER BY checked_at DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(check)
+    }
+}
@@ -0,0 +1,51 @@
+//! Queue snapshot repository for database operations.
+
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::queue_snapshot::QueueSnapshot;
+
+/// Repository for queue snapshot operations.
+pub struct QueueSnapshotRepository;
+
+impl QueueSnapshotRepository {
+    /// Record the current open ticket counts per lane.
+    ///
+    /// Training and deleted tickets are excluded, as on the workboard.
+    pub async fn capture(pool: &PgPool) -> Result<QueueSnapshot, AppError> {
+        let snapshot = sqlx::query_as::<_, QueueSnapshot>(
+            r#"
+            INSERT INTO queue_snapshots (
+                intake, in_progress, waiting_on_parts, ready_for_pickup, overdue, rush
+            )
+            SELECT
+                COUNT(*) FILTER (WHERE t.status = 'intake'),
+                COUNT(*) FILTER (WHERE t.status = 'in_progress'),
+                COUNT(*) FILTER (WHERE t.status = 'waiting_on_parts'),
+                COUNT(*) FILTER (WHERE t.status = 'ready_for_pickup'),
+                COUNT(*) FILTER (WHERE t.promise_date < CURRENT_DATE),
+                COUNT(*) FILTER (WHERE t.is_rush)
+            FROM tickets t
+            WHERE t.deleted_at IS NULL
+              AND NOT t.is_training
+              AND t.status NOT IN ('closed', 'archived')
+            RETURNING *
+            "#,
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(snapshot)
+    }
+
+    /// Find the most recent snapshot, if any has been taken.
+    pub async fn latest(pool: &PgPool) -> Result<Option<QueueSnapshot>, AppError> {
+        let snapshot = sqlx::query_as::<_, QueueSnapshot>(
+            "SELECT * FROM queue_snapshots ORDER BY taken_at DESC LIMIT 1",
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(snapshot)
+    }
+}
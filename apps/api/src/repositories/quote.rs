@@ -0,0 +1,93 @@
+//! Quote approval repository for database operations.
+
+use crate::error::AppError;
+use crate::models::quote::{CreateQuoteEvent, QuoteEvent, QuoteStatus};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Repository for quote approval database operations.
+pub struct QuoteRepository;
+
+impl QuoteRepository {
+    /// Move a ticket's quote from `from` to the event's status and log the step.
+    ///
+    /// Returns None without changing anything if the quote is no longer at
+    /// `from` for the same amount, e.g. it was edited or decided meanwhile.
+    pub async fn record(
+        pool: &PgPool,
+        from: QuoteStatus,
+        input: CreateQuoteEvent,
+    ) -> Result<Option<QuoteEvent>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let updated = sqlx::query(
+            r#"
+            UPDATE tickets
+            SET quote_status = $2, updated_at = NOW()
+            WHERE ticket_id = $1 AND quote_status = $3 AND quote_amount = $4
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.status)
+        .bind(from)
+        .bind(input.quote_amount)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if updated == 0 {
+            return Ok(None);
+        }
+
+        let event = sqlx::query_as::<_, QuoteEvent>(
+            r#"
+            WITH inserted AS (
+                INSERT INTO ticket_quote_events (
+                    ticket_id, status, quote_amount, channel, decided_by, notes, recorded_by
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                RETURNING *
+            )
+            SELECT i.event_id, i.ticket_id, i.status, i.quote_amount, i.channel,
+                   i.decided_by, i.notes, i.recorded_by, e.name AS recorded_by_name,
+                   i.created_at
+            FROM inserted i
+            JOIN employees e ON e.employee_id = i.recorded_by
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.status)
+        .bind(input.quote_amount)
+        .bind(input.channel)
+        .bind(&input.decided_by)
+        .bind(&input.notes)
+        .bind(input.recorded_by)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(event))
+    }
+
+    /// Find a ticket's quote steps, oldest first.
+    pub async fn find_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<QuoteEvent>, AppError> {
+        let events = sqlx::query_as::<_, QuoteEvent>(
+            r#"
+            SELECT q.event_id, q.ticket_id, q.status, q.quote_amount, q.channel,
+                   q.decided_by, q.notes, q.recorded_by, e.name AS recorded_by_name,
+                   q.created_at
+            FROM ticket_quote_events q
+            JOIN employees e ON e.employee_id = q.recorded_by
+            WHERE q.ticket_id = $1
+            ORDER BY q.created_at ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(events)
+    }
+}
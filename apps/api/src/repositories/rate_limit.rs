@@ -0,0 +1,93 @@
+//! Rate limit failure counter repository for database operations.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::rate_limit::FailureRecord;
+
+/// Repository for rate limit failure counters.
+pub struct RateLimitRepository;
+
+impl RateLimitRepository {
+    /// Find a client's failure record for a limiter.
+    pub async fn find(
+        pool: &PgPool,
+        scope: &str,
+        client_ip: &str,
+    ) -> Result<Option<FailureRecord>, AppError> {
+        let record = sqlx::query_as::<_, FailureRecord>(
+            r#"
+            SELECT failure_count, last_failure_at
+            FROM rate_limit_failures
+            WHERE scope = $1 AND client_ip = $2
+            "#,
+        )
+        .bind(scope)
+        .bind(client_ip)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Count a failure for a client, returning the updated record.
+    ///
+    /// The increment happens in one statement, so failures reported by
+    /// different replicas at once are all counted.
+    pub async fn record_failure(
+        pool: &PgPool,
+        scope: &str,
+        client_ip: &str,
+    ) -> Result<FailureRecord, AppError> {
+        let record = sqlx::query_as::<_, FailureRecord>(
+            r#"
+            INSERT INTO rate_limit_failures (scope, client_ip, failure_count)
+            VALUES ($1, $2, 1)
+            ON CONFLICT (scope, client_ip) DO UPDATE
+            SET failure_count = rate_limit_failures.failure_count + 1,
+                last_failure_at = NOW()
+            RETURNING failure_count, last_failure_at
+            "#,
+        )
+        .bind(scope)
+        .bind(client_ip)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(record)
+    }
+
+    /// Clear a client's failures for a limiter.
+    pub async fn reset(pool: &PgPool, scope: &str, client_ip: &str) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM rate_limit_failures WHERE scope = $1 AND client_ip = $2")
+            .bind(scope)
+            .bind(client_ip)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Every failure record for a limiter.
+    pub async fn list(pool: &PgPool, scope: &str) -> Result<Vec<FailureRecord>, AppError> {
+        let records = sqlx::query_as::<_, FailureRecord>(
+            "SELECT failure_count, last_failure_at FROM rate_limit_failures WHERE scope = $1",
+        )
+        .bind(scope)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(records)
+    }
+
+    /// Delete records whose last failure was before `idle_before`.
+    pub async fn purge(pool: &PgPool, idle_before: DateTime<Utc>) -> Result<u64, AppError> {
+        let result = sqlx::query("DELETE FROM rate_limit_failures WHERE last_failure_at < $1")
+            .bind(idle_before)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,122 @@
+//! Promise-date reminder repository for database operations.
+
+use crate::error::AppError;
+use crate::models::reminder::{DueTicket, OverdueDigest, OverdueReport};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+/// Repository for promise-date reminder database operations.
+pub struct ReminderRepository;
+
+impl ReminderRepository {
+    /// Find open tickets due on or before `as_of`, most overdue first.
+    ///
+    /// Deleted and training tickets are left out.
+    pub async fn find_due(pool: &PgPool, as_of: NaiveDate) -> Result<Vec<DueTicket>, AppError> {
+        let tickets = sqlx::query_as::<_, DueTicket>(
+            r#"
+            SELECT
+                t.ticket_id,
+                t.friendly_code,
+                t.customer_id,
+                c.name as customer_name,
+                t.item_description,
+                t.status,
+                t.is_rush,
+                t.promise_date,
+                ($1::date - t.promise_date) as days_overdue,
+                t.worked_by,
+                e.name as worked_by_name,
+                e.phone as worked_by_phone
+            FROM tickets t
+            JOIN customers c ON t.customer_id = c.customer_id
+            LEFT JOIN employees e ON t.worked_by = e.employee_id
+            WHERE t.promise_date <= $1
+              AND t.deleted_at IS NULL
+              AND NOT t.is_training
+              AND t.status NOT IN ('closed', 'archived')
+            ORDER BY t.promise_date ASC, t.is_rush DESC, t.friendly_code ASC
+            "#,
+        )
+        .bind(as_of)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(tickets)
+    }
+
+    /// Claim the day's reminder run and write a reminder for each due ticket.
+    ///
+    /// Returns None without writing anything if the day was already claimed,
+    /// so the digest goes out once even with several servers running.
+    pub async fn claim_day(
+        pool: &PgPool,
+        report: &OverdueReport,
+    ) -> Result<Option<OverdueDigest>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let Some(digest) = sqlx::query_as::<_, OverdueDigest>(
+            r#"
+            INSERT INTO overdue_digests (digest_date, due_today, overdue)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (digest_date) DO NOTHING
+            RETURNING *
+            "#,
+        )
+        .bind(report.as_of)
+        .bind(report.due_today.len() as i32)
+        .bind(report.overdue.len() as i32)
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let (mut ticket_ids, mut kinds, mut promise_dates, mut worked_by) =
+            (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        for ticket in report.tickets() {
+            ticket_ids.push(ticket.ticket_id);
+            kinds.push(ticket.kind());
+            promise_dates.push(ticket.promise_date);
+            worked_by.push(ticket.worked_by);
+        }
+        sqlx::query(
+            r#"
+            INSERT INTO ticket_reminders (ticket_id, reminder_date, kind, promise_date, worked_by)
+            SELECT ticket_id, $1, kind, promise_date, worked_by
+            FROM UNNEST($2::uuid[], $3::reminder_kind[], $4::date[], $5::uuid[])
+                AS r(ticket_id, kind, promise_date, worked_by)
+            ON CONFLICT (ticket_id, reminder_date) DO NOTHING
+            "#,
+        )
+        .bind(report.as_of)
+        .bind(&ticket_ids)
+        .bind(&kinds)
+        .bind(&promise_dates)
+        .bind(&worked_by)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(Some(digest))
+    }
+
+    /// Record how the day's digest was delivered.
+    pub async fn record_delivery(
+        pool: &PgPool,
+        digest_date: NaiveDate,
+        email_sent: bool,
+        sms_sent: i32,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE overdue_digests SET email_sent = $2, sms_sent = $3 WHERE digest_date = $1",
+        )
+        .bind(digest_date)
+        .bind(email_sent)
+        .bind(sms_sent)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,808 @@
+//! Report repository for aggregate queries.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::report::{
+    CloseoutTicketEntry, EmployeeProductivity, EmployeeQuality, ItemTypeQuality, PartnerActivity,
+    PaymentMethodTotal, PeriodQuality, PeriodQueueTrend, PeriodRevenue, PeriodThroughput,
+    QualityCounts, ReasonQuality, ReportInterval, RevenueCounts, ServiceUsage, ThroughputCounts,
+    WeekdayQueueTrend,
+};
+
+/// Tickets created in the window ($1 inclusive, $2 exclusive) with their defect count.
+const QUALITY_TICKETS_CTE: &str = r#"
+    WITH report_tickets AS (
+        SELECT
+            t.ticket_id,
+            t.worked_by,
+            t.item_type,
+            t.created_at,
+            (SELECT COUNT(*) FROM ticket_defects d WHERE d.ticket_id = t.ticket_id) AS defect_count
+        FROM tickets t
+        WHERE t.deleted_at IS NULL
+          AND NOT t.is_training
+          AND t.created_at >= $1
+          AND t.created_at < $2
+    )
+"#;
+
+/// Aggregate columns shared by every quality grouping.
+const QUALITY_COUNT_COLUMNS: &str = r#"
+    COUNT(*) AS tickets,
+    COUNT(*) FILTER (WHERE rt.defect_count > 0) AS reworked_tickets,
+    COALESCE(SUM(rt.defect_count), 0)::BIGINT AS defects,
+    COALESCE(COUNT(*) FILTER (WHERE rt.defect_count > 0)::FLOAT8 / NULLIF(COUNT(*), 0), 0) AS rework_rate
+"#;
+
+/// Tickets closed in the window ($1 inclusive, $2 exclusive).
+///
+/// Reopening a ticket clears closed_at, so only the latest close counts.
+/// Each ticket carries the cost of the materials used on it.
+const CLOSED_TICKETS_CTE: &str = r#"
+    WITH closed_tickets AS (
+        SELECT t.ticket_id, t.is_rush, t.actual_amount, t.rush_surcharge, t.created_at, t.closed_at,
+            (
+                SELECT COALESCE(SUM(ROUND(tm.quantity * tm.unit_cost, 2)), 0)
+                FROM ticket_materials tm
+                WHERE tm.ticket_id = t.ticket_id
+            ) AS material_cost
+        FROM tickets t
+        WHERE t.deleted_at IS NULL
+          AND NOT t.is_training
+          AND t.closed_at >= $1
+          AND t.closed_at < $2
+    )
+"#;
+
+/// Aggregate columns shared by every revenue grouping.
+const REVENUE_COLUMNS: &str = r#"
+    COUNT(*) AS closed_tickets,
+    COALESCE(SUM(ct.actual_amount), 0) AS revenue,
+    COALESCE(ROUND(AVG(ct.actual_amount), 2), 0) AS average_ticket,
+    COUNT(*) FILTER (WHERE ct.is_rush) AS rush_tickets,
+    COALESCE(SUM(ct.actual_amount) FILTER (WHERE ct.is_rush), 0) AS rush_revenue,
+    COALESCE(SUM(ct.rush_surcharge), 0) AS rush_surcharges,
+    COALESCE(SUM(ct.material_cost), 0) AS material_cost,
+    COALESCE(SUM(ct.actual_amount), 0) - COALESCE(SUM(ct.material_cost), 0) AS gross_profit
+"#;
+
+/// Tickets received in the window, as `received` with a `period_start`
+/// bucket ($3) alongside closed tickets as `closed`.
+const THROUGHPUT_CTE: &str = r#"
+    WITH received AS (
+        SELECT
+            date_trunc($3, t.created_at) AS period_start,
+            COUNT(*) AS tickets_received,
+            COUNT(*) FILTER (WHERE t.is_rush) AS rush_received
+        FROM tickets t
+        WHERE t.deleted_at IS NULL
+          AND NOT t.is_training
+          AND t.created_at >= $1
+          AND t.created_at < $2
+        GROUP BY period_start
+    ),
+    closed AS (
+        SELECT
+            date_trunc($3, t.closed_at) AS period_start,
+            COUNT(*) AS tickets_closed,
+            COUNT(*) FILTER (WHERE t.is_rush) AS rush_closed,
+            SUM(EXTRACT(EPOCH FROM t.closed_at - t.created_at))::FLOAT8 AS turnaround_seconds,
+            (SUM(EXTRACT(EPOCH FROM t.closed_at - t.created_at)) FILTER (WHERE t.is_rush))::FLOAT8
+                AS rush_turnaround_seconds
+        FROM tickets t
+        WHERE t.deleted_at IS NULL
+          AND NOT t.is_training
+          AND t.closed_at >= $1
+          AND t.closed_at < $2
+        GROUP BY period_start
+    ),
+    buckets AS (
+        SELECT
+            COALESCE(r.period_start, c.period_start) AS period_start,
+            COALESCE(r.tickets_received, 0) AS tickets_received,
+            COALESCE(r.rush_received, 0) AS rush_received,
+            COALESCE(c.tickets_closed, 0) AS tickets_closed,
+            COALESCE(c.rush_closed, 0) AS rush_closed,
+            COALESCE(c.turnaround_seconds, 0) AS turnaround_seconds,
+            COALESCE(c.rush_turnaround_seconds, 0) AS rush_turnaround_seconds
+        FROM received r
+        FULL JOIN closed c ON c.period_start = r.period_start
+    )
+"#;
+
+/// Aggregate columns over `buckets`, averaging turnaround per closed ticket.
+const THROUGHPUT_COLUMNS: &str = r#"
+    COALESCE(SUM(b.tickets_received), 0)::BIGINT AS tickets_received,
+    COALESCE(SUM(b.rush_received), 0)::BIGINT AS rush_received,
+    COALESCE(SUM(b.tickets_closed), 0)::BIGINT AS tickets_closed,
+    COALESCE(SUM(b.rush_closed), 0)::BIGINT AS rush_closed,
+    SUM(b.turnaround_seconds) / NULLIF(SUM(b.tickets_closed), 0) / 86400.0
+        AS average_turnaround_days,
+    SUM(b.rush_turnaround_seconds) / NULLIF(SUM(b.rush_closed), 0) / 86400.0
+        AS rush_average_turnaround_days
+"#;
+
+/// Aggregate columns over queue snapshots `qs`.
+const QUEUE_TREND_COLUMNS: &str = r#"
+    COUNT(*) AS snapshots,
+    AVG(qs.intake)::FLOAT8 AS intake,
+    AVG(qs.in_progress)::FLOAT8 AS in_progress,
+    AVG(qs.waiting_on_parts)::FLOAT8 AS waiting_on_parts,
+    AVG(qs.ready_for_pickup)::FLOAT8 AS ready_for_pickup,
+    AVG(qs.overdue)::FLOAT8 AS overdue,
+    AVG(qs.rush)::FLOAT8 AS rush,
+    AVG(qs.intake + qs.in_progress + qs.waiting_on_parts + qs.ready_for_pickup)::FLOAT8 AS total,
+    MAX(qs.intake + qs.in_progress + qs.waiting_on_parts + qs.ready_for_pickup) AS peak_total
+"#;
+
+/// Columns for [`CloseoutTicketEntry`], from tickets `t` joined to their
+/// customer `c`.
+const CLOSEOUT_TICKET_COLUMNS: &str = r#"
+    t.ticket_id,
+    t.friendly_code,
+    c.name AS customer_name,
+    t.item_description,
+    t.is_rush,
+    t.quote_amount,
+    t.actual_amount,
+    (
+        SELECT COALESCE(SUM(p.amount), 0)
+        FROM ticket_payments p
+        WHERE p.ticket_id = t.ticket_id
+    ) AS total_paid
+"#;
+
+/// Repository for report queries.
+pub struct ReportRepository;
+
+impl ReportRepository {
+    /// Overall rework counts for tickets created in the window.
+    pub async fn quality_overall(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<QualityCounts, AppError> {
+        let sql = format!(
+            "{} SELECT {} FROM report_tickets rt",
+            QUALITY_TICKETS_CTE, QUALITY_COUNT_COLUMNS
+        );
+
+        let counts = sqlx::query_as::<_, QualityCounts>(&sql)
+            .bind(from)
+            .bind(to)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(counts)
+    }
+
+    /// Rework counts grouped by the employee who worked the ticket.
+    pub async fn quality_by_employee(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<EmployeeQuality>, AppError> {
+        let sql = format!(
+            r#"{}
+            SELECT rt.worked_by AS employee_id, e.name AS employee_name, {}
+            FROM report_tickets rt
+            LEFT JOIN employees e ON e.employee_id = rt.worked_by
+            GROUP BY rt.worked_by, e.name
+            ORDER BY rework_rate DESC, tickets DESC
+            "#,
+            QUALITY_TICKETS_CTE, QUALITY_COUNT_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, EmployeeQuality>(&sql)
+            .bind(from)
+            .bind(to)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Rework counts grouped by item type.
+    pub async fn quality_by_item_type(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ItemTypeQuality>, AppError> {
+        let sql = format!(
+            r#"{}
+            SELECT rt.item_type, {}
+            FROM report_tickets rt
+            GROUP BY rt.item_type
+            ORDER BY rework_rate DESC, tickets DESC
+            "#,
+            QUALITY_TICKETS_CTE, QUALITY_COUNT_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, ItemTypeQuality>(&sql)
+            .bind(from)
+            .bind(to)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Defect counts grouped by reason code.
+    pub async fn quality_by_reason(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ReasonQuality>, AppError> {
+        let sql = format!(
+            r#"{}
+            SELECT d.reason, COUNT(*) AS defects
+            FROM ticket_defects d
+            JOIN report_tickets rt ON rt.ticket_id = d.ticket_id
+            GROUP BY d.reason
+            ORDER BY defects DESC
+            "#,
+            QUALITY_TICKETS_CTE
+        );
+
+        let rows = sqlx::query_as::<_, ReasonQuality>(&sql)
+            .bind(from)
+            .bind(to)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Rework counts bucketed by ticket creation time.
+    pub async fn quality_over_time(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval: ReportInterval,
+    ) -> Result<Vec<PeriodQuality>, AppError> {
+        let sql = format!(
+            r#"{}
+            SELECT date_trunc($3, rt.created_at) AS period_start, {}
+            FROM report_tickets rt
+            GROUP BY period_start
+            ORDER BY period_start ASC
+            "#,
+            QUALITY_TICKETS_CTE, QUALITY_COUNT_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, PeriodQuality>(&sql)
+            .bind(from)
+            .bind(to)
+            .bind(interval.as_date_trunc())
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Ticket, revenue, and API usage totals per partner.
+    ///
+    /// Tickets count by creation time, closed tickets and revenue by close
+    /// time, and API requests by UTC day, all within the window.
+    pub async fn partner_activity(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<PartnerActivity>, AppError> {
+        let rows = sqlx::query_as::<_, PartnerActivity>(
+            r#"
+            WITH partner_ticket_rows AS (
+                SELECT p.partner_id, t.ticket_id, t.created_at, t.closed_at, t.actual_amount,
+                    pt.ticket_id IS NOT NULL AS via_api
+                FROM partners p
+                JOIN tickets t ON t.customer_id = p.customer_id
+                LEFT JOIN partner_tickets pt ON pt.ticket_id = t.ticket_id
+                WHERE t.deleted_at IS NULL
+                  AND NOT t.is_training
+            ),
+            usage AS (
+                SELECT u.partner_id,
+                    SUM(u.request_count)::BIGINT AS api_requests,
+                    SUM(u.rate_limited_count)::BIGINT AS rate_limited_requests
+                FROM partner_api_usage u
+                WHERE u.day >= ($1 AT TIME ZONE 'UTC')::date
+                  AND u.day < ($2 AT TIME ZONE 'UTC')::date
+                GROUP BY u.partner_id
+            )
+            SELECT
+                p.partner_id,
+                p.name AS partner_name,
+                p.is_active,
+                COUNT(r.ticket_id) FILTER (WHERE r.created_at >= $1 AND r.created_at < $2) AS tickets,
+                COUNT(r.ticket_id) FILTER (
+                    WHERE r.via_api AND r.created_at >= $1 AND r.created_at < $2
+                ) AS submitted_via_api,
+                COUNT(r.ticket_id) FILTER (WHERE r.closed_at >= $1 AND r.closed_at < $2) AS closed_tickets,
+                COALESCE(
+                    SUM(r.actual_amount) FILTER (WHERE r.closed_at >= $1 AND r.closed_at < $2),
+                    0
+                ) AS revenue,
+                COALESCE(MAX(u.api_requests), 0) AS api_requests,
+                COALESCE(MAX(u.rate_limited_requests), 0) AS rate_limited_requests
+            FROM partners p
+            LEFT JOIN partner_ticket_rows r ON r.partner_id = p.partner_id
+            LEFT JOIN usage u ON u.partner_id = p.partner_id
+            GROUP BY p.partner_id, p.name, p.is_active
+            ORDER BY revenue DESC, tickets DESC, p.name ASC
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// Ticket, price, and turnaround totals per catalog service, for tickets
+    /// taken in within the window. Every service is listed, busiest first.
+    pub async fn service_usage(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<ServiceUsage>, AppError> {
+        let rows = sqlx::query_as::<_, ServiceUsage>(
+            r#"
+            WITH service_ticket_rows AS (
+                SELECT ts.service_id, ts.price, t.ticket_id, t.created_at, t.closed_at,
+                    t.actual_amount
+                FROM ticket_services ts
+                JOIN tickets t ON t.ticket_id = ts.ticket_id
+                WHERE t.deleted_at IS NULL
+                  AND NOT t.is_training
+                  AND t.created_at >= $1
+                  AND t.created_at < $2
+            )
+            SELECT
+                s.service_id,
+                s.name AS service_name,
+                s.is_active,
+                COUNT(r.ticket_id) AS tickets,
+                COALESCE(SUM(r.price), 0) AS quoted_total,
+                ROUND(AVG(r.price), 2) AS average_price,
+                COUNT(r.closed_at) AS closed_tickets,
+                ROUND(AVG(r.actual_amount) FILTER (WHERE r.closed_at IS NOT NULL), 2)
+                    AS average_actual_amount,
+                (AVG(EXTRACT(EPOCH FROM r.closed_at - r.created_at)) / 86400.0)::FLOAT8
+                    AS average_turnaround_days
+            FROM services s
+            LEFT JOIN service_ticket_rows r ON r.service_id = s.service_id
+            GROUP BY s.service_id, s.name, s.is_active
+            ORDER BY tickets DESC, s.name ASC
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// Revenue totals for tickets closed in the window.
+    pub async fn revenue_overall(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<RevenueCounts, AppError> {
+        let sql = format!(
+            "{} SELECT {} FROM closed_tickets ct",
+            CLOSED_TICKETS_CTE, REVENUE_COLUMNS
+        );
+
+        let counts = sqlx::query_as::<_, RevenueCounts>(&sql)
+            .bind(from)
+            .bind(to)
+            .fetch_one(pool)
+            .await?;
+
+        Ok(counts)
+    }
+
+    /// Revenue totals bucketed by close time.
+    pub async fn revenue_over_time(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval: ReportInterval,
+    ) -> Result<Vec<PeriodRevenue>, AppError> {
+        let sql = format!(
+            r#"{}
+            SELECT date_trunc($3, ct.closed_at) AS period_start, {}
+            FROM closed_tickets ct
+            GROUP BY period_start
+            ORDER BY period_start ASC
+            "#,
+            CLOSED_TICKETS_CTE, REVENUE_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, PeriodRevenue>(&sql)
+            .bind(from)
+            .bind(to)
+            .bind(interval.as_date_trunc())
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Intake and completion totals for the window.
+    pub async fn throughput_overall(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<ThroughputCounts, AppError> {
+        let sql = format!(
+            "{} SELECT {} FROM buckets b",
+            THROUGHPUT_CTE, THROUGHPUT_COLUMNS
+        );
+
+        // Bucketing by day is enough to sum over the whole window
+        let counts = sqlx::query_as::<_, ThroughputCounts>(&sql)
+            .bind(from)
+            .bind(to)
+            .bind(ReportInterval::Day.as_date_trunc())
+            .fetch_one(pool)
+            .await?;
+
+        Ok(counts)
+    }
+
+    /// Intake and completion totals per time bucket. Received tickets count
+    /// by creation time and closed tickets by close time.
+    pub async fn throughput_over_time(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval: ReportInterval,
+    ) -> Result<Vec<PeriodThroughput>, AppError> {
+        let sql = format!(
+            r#"{}
+            SELECT b.period_start, {}
+            FROM buckets b
+            GROUP BY b.period_start
+            ORDER BY b.period_start ASC
+            "#,
+            THROUGHPUT_CTE, THROUGHPUT_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, PeriodThroughput>(&sql)
+            .bind(from)
+            .bind(to)
+            .bind(interval.as_date_trunc())
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Average lane counts per time bucket, from queue snapshots.
+    pub async fn queue_trends_over_time(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        interval: ReportInterval,
+    ) -> Result<Vec<PeriodQueueTrend>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT date_trunc($3, qs.taken_at) AS period_start, {}
+            FROM queue_snapshots qs
+            WHERE qs.taken_at >= $1 AND qs.taken_at < $2
+            GROUP BY period_start
+            ORDER BY period_start ASC
+            "#,
+            QUEUE_TREND_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, PeriodQueueTrend>(&sql)
+            .bind(from)
+            .bind(to)
+            .bind(interval.as_date_trunc())
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Average lane counts per ISO day of the week (UTC), from queue snapshots.
+    pub async fn queue_trends_by_weekday(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<WeekdayQueueTrend>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT EXTRACT(ISODOW FROM qs.taken_at AT TIME ZONE 'UTC')::INT4 AS weekday, {}
+            FROM queue_snapshots qs
+            WHERE qs.taken_at >= $1 AND qs.taken_at < $2
+            GROUP BY weekday
+            ORDER BY weekday ASC
+            "#,
+            QUEUE_TREND_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, WeekdayQueueTrend>(&sql)
+            .bind(from)
+            .bind(to)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Attribution and status activity per employee.
+    ///
+    /// Intake counts by creation time, worked tickets by when they first
+    /// became ready for pickup, closes by close time, and status changes by
+    /// when they were made. Inactive employees are listed only when they have
+    /// activity in the window.
+    pub async fn employee_productivity(
+        pool: &PgPool,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<EmployeeProductivity>, AppError> {
+        let rows = sqlx::query_as::<_, EmployeeProductivity>(
+            r#"
+            WITH live_tickets AS (
+                SELECT t.ticket_id, t.taken_in_by, t.worked_by, t.closed_by, t.created_at, t.closed_at
+                FROM tickets t
+                WHERE t.deleted_at IS NULL
+                  AND NOT t.is_training
+            ),
+            taken_in AS (
+                SELECT lt.taken_in_by AS employee_id, COUNT(*) AS tickets_taken_in
+                FROM live_tickets lt
+                WHERE lt.created_at >= $1 AND lt.created_at < $2
+                GROUP BY lt.taken_in_by
+            ),
+            first_ready AS (
+                SELECT h.ticket_id, MIN(h.changed_at) AS ready_at
+                FROM ticket_status_history h
+                WHERE h.to_status = 'ready_for_pickup'
+                GROUP BY h.ticket_id
+            ),
+            worked AS (
+                SELECT lt.worked_by AS employee_id,
+                    COUNT(*) AS tickets_worked,
+                    (AVG(EXTRACT(EPOCH FROM fr.ready_at - lt.created_at)) / 86400)::FLOAT8
+                        AS average_days_to_ready
+                FROM live_tickets lt
+                JOIN first_ready fr ON fr.ticket_id = lt.ticket_id
+                WHERE lt.worked_by IS NOT NULL
+                  AND fr.ready_at >= $1 AND fr.ready_at < $2
+                GROUP BY lt.worked_by
+            ),
+            closed AS (
+                SELECT lt.closed_by AS employee_id, COUNT(*) AS tickets_closed
+                FROM live_tickets lt
+                WHERE lt.closed_by IS NOT NULL
+                  AND lt.closed_at >= $1 AND lt.closed_at < $2
+                GROUP BY lt.closed_by
+            ),
+            changes AS (
+                SELECT h.changed_by AS employee_id, COUNT(*) AS status_changes
+                FROM ticket_status_history h
+                JOIN live_tickets lt ON lt.ticket_id = h.ticket_id
+                WHERE h.changed_at >= $1 AND h.changed_at < $2
+                GROUP BY h.changed_by
+            ),
+            bench AS (
+                SELECT x.employee_id,
+                    (SUM(EXTRACT(EPOCH FROM COALESCE(x.stopped_at, NOW()) - x.started_at))
+                        / 3600)::FLOAT8 AS bench_hours,
+                    COUNT(DISTINCT x.ticket_id) AS tickets_timed
+                FROM time_entries x
+                JOIN live_tickets lt ON lt.ticket_id = x.ticket_id
+                WHERE x.started_at >= $1 AND x.started_at < $2
+                GROUP BY x.employee_id
+            )
+            SELECT
+                e.employee_id,
+                e.name AS employee_name,
+                e.is_active,
+                COALESCE(ti.tickets_taken_in, 0) AS tickets_taken_in,
+                COALESCE(w.tickets_worked, 0) AS tickets_worked,
+                COALESCE(c.tickets_closed, 0) AS tickets_closed,
+                COALESCE(sc.status_changes, 0) AS status_changes,
+                w.average_days_to_ready,
+                COALESCE(b.bench_hours, 0)::FLOAT8 AS bench_hours,
+                COALESCE(b.tickets_timed, 0) AS tickets_timed,
+                (b.bench_hours / b.tickets_timed)::FLOAT8 AS average_bench_hours
+            FROM employees e
+            LEFT JOIN taken_in ti ON ti.employee_id = e.employee_id
+            LEFT JOIN worked w ON w.employee_id = e.employee_id
+            LEFT JOIN closed c ON c.employee_id = e.employee_id
+            LEFT JOIN changes sc ON sc.employee_id = e.employee_id
+            LEFT JOIN bench b ON b.employee_id = e.employee_id
+            WHERE e.is_active
+               OR ti.employee_id IS NOT NULL
+               OR w.employee_id IS NOT NULL
+               OR c.employee_id IS NOT NULL
+               OR sc.employee_id IS NOT NULL
+               OR b.employee_id IS NOT NULL
+            ORDER BY tickets_worked DESC, tickets_taken_in DESC, e.name ASC
+            "#,
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// A calendar day in a time zone, as the UTC instants it starts and
+    /// ends at (23 or 25 hours apart on daylight saving changes).
+    ///
+    /// Without a date, it's today in that zone.
+    pub async fn local_day(
+        pool: &PgPool,
+        timezone: &str,
+        date: Option<NaiveDate>,
+    ) -> Result<(NaiveDate, DateTime<Utc>, DateTime<Utc>), AppError> {
+        let day = sqlx::query_as::<_, (NaiveDate, DateTime<Utc>, DateTime<Utc>)>(
+            r#"
+            SELECT
+                d.day,
+                d.day::timestamp AT TIME ZONE $1,
+                (d.day + 1)::timestamp AT TIME ZONE $1
+            FROM (SELECT COALESCE($2::date, (NOW() AT TIME ZONE $1)::date) AS day) d
+            "#,
+        )
+        .bind(timezone)
+        .bind(date)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(day)
+    }
+
+    /// A store's tickets received in the window, in intake order.
+    pub async fn closeout_taken_in(
+        pool: &PgPool,
+        store_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CloseoutTicketEntry>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT {}
+            FROM tickets t
+            JOIN customers c ON c.customer_id = t.customer_id
+            WHERE t.store_id = $1
+              AND t.deleted_at IS NULL
+              AND NOT t.is_training
+              AND t.created_at >= $2
+              AND t.created_at < $3
+            ORDER BY t.created_at ASC
+            "#,
+            CLOSEOUT_TICKET_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, CloseoutTicketEntry>(&sql)
+            .bind(store_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// A store's tickets closed in the window, in close order.
+    ///
+    /// Reopening a ticket clears closed_at, so only the latest close counts.
+    pub async fn closeout_closed(
+        pool: &PgPool,
+        store_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<CloseoutTicketEntry>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT {}
+            FROM tickets t
+            JOIN customers c ON c.customer_id = t.customer_id
+            WHERE t.store_id = $1
+              AND t.deleted_at IS NULL
+              AND NOT t.is_training
+              AND t.closed_at >= $2
+              AND t.closed_at < $3
+            ORDER BY t.closed_at ASC
+            "#,
+            CLOSEOUT_TICKET_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, CloseoutTicketEntry>(&sql)
+            .bind(store_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+
+    /// Payments received in the window against a store's tickets, one row
+    /// per payment method, including methods with no payments.
+    pub async fn closeout_payments_by_method(
+        pool: &PgPool,
+        store_id: Uuid,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<PaymentMethodTotal>, AppError> {
+        let rows = sqlx::query_as::<_, PaymentMethodTotal>(
+            r#"
+            SELECT
+                m.method,
+                COUNT(p.payment_id) AS payments,
+                COALESCE(SUM(p.amount), 0) AS total
+            FROM unnest(enum_range(NULL::payment_method)) AS m(method)
+            LEFT JOIN (
+                SELECT p.payment_id, p.method, p.amount
+                FROM ticket_payments p
+                JOIN tickets t ON t.ticket_id = p.ticket_id
+                WHERE t.store_id = $1
+                  AND t.deleted_at IS NULL
+                  AND NOT t.is_training
+                  AND p.received_at >= $2
+                  AND p.received_at < $3
+            ) p ON p.method = m.method
+            GROUP BY m.method
+            ORDER BY m.method
+            "#,
+        )
+        .bind(store_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    /// A store's tickets that were ready for pickup at `as_of`: their
+    /// latest status change before then was to ready_for_pickup. Oldest
+    /// promise date first.
+    pub async fn closeout_ready_for_pickup(
+        pool: &PgPool,
+        store_id: Uuid,
+        as_of: DateTime<Utc>,
+    ) -> Result<Vec<CloseoutTicketEntry>, AppError> {
+        let sql = format!(
+            r#"
+            SELECT {}
+            FROM tickets t
+            JOIN customers c ON c.customer_id = t.customer_id
+            WHERE t.store_id = $1
+              AND t.deleted_at IS NULL
+              AND NOT t.is_training
+              AND (
+                  SELECT h.to_status
+                  FROM ticket_status_history h
+                  WHERE h.ticket_id = t.ticket_id AND h.changed_at < $2
+                  ORDER BY h.changed_at DESC
+                  LIMIT 1
+              ) = 'ready_for_pickup'
+            ORDER BY t.promise_date ASC NULLS LAST, t.friendly_code ASC
+            "#,
+            CLOSEOUT_TICKET_COLUMNS
+        );
+
+        let rows = sqlx::query_as::<_, CloseoutTicketEntry>(&sql)
+            .bind(store_id)
+            .bind(as_of)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(rows)
+    }
+}
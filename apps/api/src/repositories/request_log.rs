@@ -0,0 +1,115 @@
+//! Request log repository for database operations.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::request_log::{CreateRequestLog, DebugCaptureConfig, RequestLog};
+
+/// Repository for debug request log database operations.
+pub struct RequestLogRepository;
+
+impl RequestLogRepository {
+    /// Record a captured request.
+    pub async fn create(pool: &PgPool, input: CreateRequestLog) -> Result<RequestLog, AppError> {
+        let log = sqlx::query_as::<_, RequestLog>(
+            r#"
+            INSERT INTO request_logs (
+                method, path, query, status, duration_ms, client_ip,
+                request_headers, request_body, response_body
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(&input.method)
+        .bind(&input.path)
+        .bind(&input.query)
+        .bind(input.status)
+        .bind(input.duration_ms)
+        .bind(&input.client_ip)
+        .bind(&input.request_headers)
+        .bind(&input.request_body)
+        .bind(&input.response_body)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(log)
+    }
+
+    /// List captured requests, most recent first.
+    ///
+    /// Optionally filters to paths starting with `path_prefix`.
+    pub async fn list(
+        pool: &PgPool,
+        path_prefix: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<RequestLog>, AppError> {
+        let logs = sqlx::query_as::<_, RequestLog>(
+            r#"
+            SELECT * FROM request_logs
+            WHERE ($1::TEXT IS NULL OR path LIKE $1 || '%')
+            ORDER BY created_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(path_prefix)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(logs)
+    }
+
+    /// Delete captured requests older than the given time.
+    ///
+    /// Returns the number of rows deleted.
+    pub async fn delete_older_than(pool: &PgPool, cutoff: DateTime<Utc>) -> Result<u64, AppError> {
+        let result = sqlx::query("DELETE FROM request_logs WHERE created_at < $1")
+            .bind(cutoff)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Get the debug capture toggle.
+    pub async fn get_capture_config(pool: &PgPool) -> Result<DebugCaptureConfig, AppError> {
+        let config = sqlx::query_as::<_, DebugCaptureConfig>(
+            r#"
+            SELECT debug_capture_until, debug_capture_routes
+            FROM store_settings
+            WHERE store_id = primary_store_id()
+            "#,
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(config.unwrap_or_default())
+    }
+
+    /// Set the debug capture toggle.
+    pub async fn set_capture_config(
+        pool: &PgPool,
+        config: &DebugCaptureConfig,
+    ) -> Result<DebugCaptureConfig, AppError> {
+        let config = sqlx::query_as::<_, DebugCaptureConfig>(
+            r#"
+            UPDATE store_settings
+            SET debug_capture_until = $1,
+                debug_capture_routes = $2,
+                updated_at = NOW()
+            WHERE store_id = primary_store_id()
+            RETURNING debug_capture_until, debug_capture_routes
+            "#,
+        )
+        .bind(config.capture_until)
+        .bind(&config.routes)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(config)
+    }
+}
@@ -0,0 +1,54 @@
+//! Rush pricing repository for database operations.
+
+use crate::error::AppError;
+use crate::models::rush_pricing::{CreateRushSurchargeTier, RushSurchargeTier};
+use sqlx::PgPool;
+
+/// Repository for rush surcharge tier operations.
+pub struct RushPricingRepository;
+
+impl RushPricingRepository {
+    /// List all surcharge tiers, shortest lead time first.
+    pub async fn list(pool: &PgPool) -> Result<Vec<RushSurchargeTier>, AppError> {
+        let tiers = sqlx::query_as::<_, RushSurchargeTier>(
+            r#"
+            SELECT * FROM rush_surcharge_tiers
+            ORDER BY max_lead_days ASC NULLS LAST
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(tiers)
+    }
+
+    /// Replace all surcharge tiers in a single transaction.
+    pub async fn replace_all(
+        pool: &PgPool,
+        tiers: Vec<CreateRushSurchargeTier>,
+    ) -> Result<Vec<RushSurchargeTier>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM rush_surcharge_tiers")
+            .execute(&mut *tx)
+            .await?;
+
+        for tier in &tiers {
+            sqlx::query(
+                r#"
+                INSERT INTO rush_surcharge_tiers (max_lead_days, kind, amount)
+                VALUES ($1, $2, $3)
+                "#,
+            )
+            .bind(tier.max_lead_days)
+            .bind(tier.kind)
+            .bind(tier.amount)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Self::list(pool).await
+    }
+}
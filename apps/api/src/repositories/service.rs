@@ -0,0 +1,167 @@
+//! Service catalog repository for database operations.
+
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::service::{CreateService, Service, TicketService, UpdateService};
+
+/// Repository for service catalog operations.
+pub struct ServiceRepository;
+
+impl ServiceRepository {
+    /// List services by name, optionally including inactive ones.
+    pub async fn list(pool: &PgPool, include_inactive: bool) -> Result<Vec<Service>, AppError> {
+        let services = sqlx::query_as::<_, Service>(
+            r#"
+            SELECT * FROM services
+            WHERE $1 OR is_active
+            ORDER BY name ASC
+            "#,
+        )
+        .bind(include_inactive)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(services)
+    }
+
+    /// Find a service by ID.
+    pub async fn find_by_id(pool: &PgPool, service_id: Uuid) -> Result<Option<Service>, AppError> {
+        let service = sqlx::query_as::<_, Service>("SELECT * FROM services WHERE service_id = $1")
+            .bind(service_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(service)
+    }
+
+    /// Find a service by name (case-insensitive).
+    pub async fn find_by_name(pool: &PgPool, name: &str) -> Result<Option<Service>, AppError> {
+        let service =
+            sqlx::query_as::<_, Service>("SELECT * FROM services WHERE LOWER(name) = LOWER($1)")
+                .bind(name)
+                .fetch_optional(pool)
+                .await?;
+
+        Ok(service)
+    }
+
+    /// Find the active services among `service_ids`, in the order given.
+    pub async fn find_active_by_ids(
+        pool: &PgPool,
+        service_ids: &[Uuid],
+    ) -> Result<Vec<Service>, AppError> {
+        let services = sqlx::query_as::<_, Service>(
+            r#"
+            SELECT s.*
+            FROM UNNEST($1::uuid[]) WITH ORDINALITY AS ids(service_id, position)
+            JOIN services s ON s.service_id = ids.service_id
+            WHERE s.is_active
+            ORDER BY ids.position ASC
+            "#,
+        )
+        .bind(service_ids)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(services)
+    }
+
+    /// Create a service.
+    pub async fn create(pool: &PgPool, input: CreateService) -> Result<Service, AppError> {
+        let service = sqlx::query_as::<_, Service>(
+            r#"
+            INSERT INTO services (name, default_price, turnaround_days)
+            VALUES ($1, $2, $3)
+            RETURNING *
+            "#,
+        )
+        .bind(&input.name)
+        .bind(input.default_price)
+        .bind(input.turnaround_days)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(service)
+    }
+
+    /// Update a service, keeping existing values for fields not given.
+    ///
+    /// Returns None if the service doesn't exist.
+    pub async fn update(
+        pool: &PgPool,
+        service_id: Uuid,
+        input: UpdateService,
+    ) -> Result<Option<Service>, AppError> {
+        let Some(existing) = Self::find_by_id(pool, service_id).await? else {
+            return Ok(None);
+        };
+
+        let service = sqlx::query_as::<_, Service>(
+            r#"
+            UPDATE services
+            SET name = $1, default_price = $2, turnaround_days = $3, is_active = $4,
+                updated_at = NOW()
+            WHERE service_id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(input.name.unwrap_or(existing.name))
+        .bind(input.default_price.unwrap_or(existing.default_price))
+        .bind(input.turnaround_days.unwrap_or(existing.turnaround_days))
+        .bind(input.is_active.unwrap_or(existing.is_active))
+        .bind(service_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(Some(service))
+    }
+
+    /// Record the services picked for a new ticket, at their current prices.
+    pub async fn add_to_ticket(
+        executor: impl PgExecutor<'_>,
+        ticket_id: Uuid,
+        services: &[Service],
+    ) -> Result<(), AppError> {
+        let service_ids: Vec<Uuid> = services.iter().map(|s| s.service_id).collect();
+        let prices: Vec<Option<rust_decimal::Decimal>> =
+            services.iter().map(|s| s.default_price).collect();
+
+        sqlx::query(
+            r#"
+            INSERT INTO ticket_services (ticket_id, service_id, price)
+            SELECT $1, service_id, price
+            FROM UNNEST($2::uuid[], $3::numeric[]) AS s(service_id, price)
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(&service_ids)
+        .bind(&prices)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Services on a ticket, by name.
+    pub async fn find_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketService>, AppError> {
+        let services = sqlx::query_as::<_, TicketService>(
+            r#"
+            SELECT ts.service_id, s.name, ts.price
+            FROM ticket_services ts
+            JOIN services s ON s.service_id = ts.service_id
+            WHERE ts.ticket_id = $1
+            ORDER BY s.name ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(services)
+    }
+}
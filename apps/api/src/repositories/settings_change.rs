@@ -0,0 +1,87 @@
+//! Settings change history repository for database operations.
+
+use crate::error::AppError;
+use crate::models::settings_change::{CreateSettingsChange, SettingsChange, SettingsChangeEntry};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Repository for settings change history.
+pub struct SettingsChangeRepository;
+
+impl SettingsChangeRepository {
+    /// Record a settings change.
+    pub async fn create(
+        pool: &PgPool,
+        input: CreateSettingsChange,
+    ) -> Result<SettingsChange, AppError> {
+        let change = sqlx::query_as::<_, SettingsChange>(
+            r#"
+            INSERT INTO settings_changes (
+                version, old_values, new_values, changed_by, rolled_back_change_id, store_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING *
+            "#,
+        )
+        .bind(input.version)
+        .bind(&input.old_values)
+        .bind(&input.new_values)
+        .bind(input.changed_by)
+        .bind(input.rolled_back_change_id)
+        .bind(input.store_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(change)
+    }
+
+    /// Find a settings change by ID.
+    pub async fn find_by_id(
+        pool: &PgPool,
+        change_id: Uuid,
+    ) -> Result<Option<SettingsChange>, AppError> {
+        let change = sqlx::query_as::<_, SettingsChange>(
+            r#"
+            SELECT * FROM settings_changes WHERE change_id = $1
+            "#,
+        )
+        .bind(change_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(change)
+    }
+
+    /// List a store's recent settings changes with employee names, newest first.
+    pub async fn list_recent(
+        pool: &PgPool,
+        store_id: Uuid,
+        limit: i64,
+    ) -> Result<Vec<SettingsChangeEntry>, AppError> {
+        let entries = sqlx::query_as::<_, SettingsChangeEntry>(
+            r#"
+            SELECT
+                c.change_id,
+                c.store_id,
+                c.version,
+                c.old_values,
+                c.new_values,
+                c.changed_by,
+                e.name AS changed_by_name,
+                c.rolled_back_change_id,
+                c.changed_at
+            FROM settings_changes c
+            LEFT JOIN employees e ON c.changed_by = e.employee_id
+            WHERE c.store_id = $1
+            ORDER BY c.changed_at DESC
+            LIMIT $2
+            "#,
+        )
+        .bind(store_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(entries)
+    }
+}
@@ -2,7 +2,9 @@
 
 use crate::error::AppError;
 use crate::models::status_history::{CreateStatusHistory, StatusHistoryEntry};
-use sqlx::PgPool;
+use crate::models::ticket::TicketStatus;
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// Repository for status history database operations.
@@ -11,7 +13,7 @@ pub struct StatusHistoryRepository;
 impl StatusHistoryRepository {
     /// Create a new status history entry.
     pub async fn create(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         input: CreateStatusHistory,
     ) -> Result<StatusHistoryEntry, AppError> {
         let entry = sqlx::query_as::<_, StatusHistoryEntry>(
@@ -25,7 +27,7 @@ impl StatusHistoryRepository {
         .bind(input.from_status)
         .bind(input.to_status)
         .bind(input.changed_by)
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
 
         Ok(entry)
@@ -52,6 +54,35 @@ impl StatusHistoryRepository {
 
         Ok(entries)
     }
+
+    /// Count tickets moved into `status` since the given time.
+    ///
+    /// A ticket moved in more than once counts once. Training tickets are
+    /// counted only when `training` is set.
+    pub async fn count_entered_since(
+        pool: &PgPool,
+        status: TicketStatus,
+        since: DateTime<Utc>,
+        training: bool,
+    ) -> Result<i64, AppError> {
+        let count = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(DISTINCT h.ticket_id)
+            FROM ticket_status_history h
+            JOIN tickets t ON t.ticket_id = h.ticket_id
+            WHERE h.to_status = $1
+              AND h.changed_at >= $2
+              AND t.is_training = $3
+            "#,
+        )
+        .bind(status)
+        .bind(since)
+        .bind(training)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
 }
 
 #[cfg(test)]
@@ -2,45 +2,77 @@
 
 use crate::error::AppError;
 use crate::models::storage_location::{
-    CreateStorageLocation, StorageLocation, StorageLocationSummary, UpdateStorageLocation,
+    CreateStorageLocation, CreateStorageLocationRule, LocationOccupancy, StorageLocation,
+    StorageLocationRule, StorageLocationSummary, UpdateStorageLocation,
 };
+use crate::models::ticket::TicketSummary;
 use sqlx::PgPool;
 use uuid::Uuid;
 
+/// Open tickets with the location they count toward; tickets in transit
+/// count toward the location expecting them rather than the one they left.
+const OPEN_TICKET_LOCATIONS: &str = r#"
+    SELECT t.ticket_id, COALESCE(x.to_location_id, t.storage_location_id) AS location_id
+    FROM tickets t
+    LEFT JOIN ticket_transfers x
+      ON x.ticket_id = t.ticket_id AND x.status = 'pending'
+    WHERE t.deleted_at IS NULL
+      AND t.status NOT IN ('closed', 'archived')
+"#;
+
+/// Storage location summaries with their open ticket counts; callers add a
+/// WHERE clause, then `GROUP BY l.location_id`.
+fn summary_select() -> String {
+    format!(
+        r#"
+        SELECT l.location_id, l.name, l.is_active, l.capacity,
+               COUNT(t.ticket_id) AS open_tickets
+        FROM storage_locations l
+        LEFT JOIN ({}) t ON t.location_id = l.location_id
+        "#,
+        OPEN_TICKET_LOCATIONS
+    )
+}
+
 /// Repository for storage location database operations.
 pub struct StorageLocationRepository;
 
 impl StorageLocationRepository {
-    /// Create a new storage location.
+    /// Create a new storage location in a store.
     pub async fn create(
         pool: &PgPool,
+        store_id: Uuid,
         input: CreateStorageLocation,
     ) -> Result<StorageLocation, AppError> {
         let location = sqlx::query_as::<_, StorageLocation>(
             r#"
-            INSERT INTO storage_locations (name)
-            VALUES ($1)
+            INSERT INTO storage_locations (name, capacity, store_id)
+            VALUES ($1, $2, $3)
             RETURNING *
             "#,
         )
         .bind(&input.name)
+        .bind(input.capacity)
+        .bind(store_id)
         .fetch_one(pool)
         .await?;
 
         Ok(location)
     }
 
-    /// Find a storage location by name.
+    /// Find a store's storage location by name.
     pub async fn find_by_name(
         pool: &PgPool,
+        store_id: Uuid,
         name: &str,
     ) -> Result<Option<StorageLocation>, AppError> {
         let location = sqlx::query_as::<_, StorageLocation>(
             r#"
-            SELECT * FROM storage_locations WHERE LOWER(name) = LOWER($1)
+            SELECT * FROM storage_locations WHERE store_id = $2 AND LOWER(name) = LOWER($1)
             "#,
         )
         .bind(name)
+        .bind(store_id)
         .fetch_optional(pool)
         .await?;
 
@@ -98,35 +130,39 @@ impl StorageLocationRepository {
         Ok(result)
     }
 
-    /// List storage locations with optional filtering.
+    /// Find a storage location's summary with its open ticket count.
+    pub async fn find_summary(
+        pool: &PgPool,
+        location_id: Uuid,
+    ) -> Result<Option<StorageLocationSummary>, AppError> {
+        let location = sqlx::query_as::<_, StorageLocationSummary>(&format!(
+            "{} WHERE l.location_id = $1 GROUP BY l.location_id",
+            summary_select()
+        ))
+        .bind(location_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(location)
+    }
+
+    /// List a store's storage locations with their open ticket counts.
     ///
     /// If include_inactive is false (default), only active locations are returned.
     pub async fn list(
         pool: &PgPool,
+        store_id: Uuid,
         include_inactive: bool,
     ) -> Result<Vec<StorageLocationSummary>, AppError> {
-        let locations = if include_inactive {
-            sqlx::query_as::<_, StorageLocationSummary>(
-                r#"
-                SELECT location_id, name, is_active
-                FROM storage_locations
-                ORDER BY name ASC
-                "#,
-            )
-            .fetch_all(pool)
-            .await?
-        } else {
-            sqlx::query_as::<_, StorageLocationSummary>(
-                r#"
-                SELECT location_id, name, is_active
-                FROM storage_locations
-                WHERE is_active = TRUE
-                ORDER BY name ASC
-                "#,
-            )
-            .fetch_all(pool)
-            .await?
-        };
+        let locations = sqlx::query_as::<_, StorageLocationSummary>(&format!(
+            "{} WHERE l.store_id = $2 AND ($1 OR l.is_active = TRUE) \
+             GROUP BY l.location_id ORDER BY l.name ASC",
+            summary_select()
+        ))
+        .bind(include_inactive)
+        .bind(store_id)
+        .fetch_all(pool)
+        .await?;
 
         Ok(locations)
     }
@@ -149,23 +185,163 @@ impl StorageLocationRepository {
         // Build update with provided fields, keeping existing values for unspecified fields
         let name = input.name.unwrap_or(existing.name);
         let is_active = input.is_active.unwrap_or(existing.is_active);
+        let capacity = input.capacity.unwrap_or(existing.capacity);
 
         let location = sqlx::query_as::<_, StorageLocation>(
             r#"
             UPDATE storage_locations
-            SET name = $1, is_active = $2
-            WHERE location_id = $3
+            SET name = $1, is_active = $2, capacity = $3
+            WHERE location_id = $4
             RETURNING *
             "#,
         )
         .bind(&name)
         .bind(is_active)
+        .bind(capacity)
         .bind(location_id)
         .fetch_one(pool)
         .await?;
 
         Ok(Some(location))
     }
+
+    /// Count open tickets stored at a location or in transit to it.
+    pub async fn count_open_tickets(pool: &PgPool, location_id: Uuid) -> Result<i64, AppError> {
+        let count = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*)
+            FROM tickets t
+            WHERE t.deleted_at IS NULL
+              AND t.status NOT IN ('closed', 'archived')
+              AND (
+                  t.storage_location_id = $1
+                  OR EXISTS (
+                      SELECT 1 FROM ticket_transfers x
+                      WHERE x.ticket_id = t.ticket_id
+                        AND x.status = 'pending'
+                        AND x.to_location_id = $1
+                  )
+              )
+            "#,
+        )
+        .bind(location_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    /// List the open tickets stored at a location, rush first, then oldest
+    /// first.
+    ///
+    /// With `training`, lists training tickets instead of real ones.
+    pub async fn list_open_tickets(
+        pool: &PgPool,
+        location_id: Uuid,
+        training: bool,
+    ) -> Result<Vec<TicketSummary>, AppError> {
+        let tickets = sqlx::query_as::<_, TicketSummary>(
+            r#"
+            SELECT
+                t.ticket_id,
+                t.friendly_code,
+                t.customer_id,
+                c.name as customer_name,
+                t.item_type,
+                t.item_description,
+                t.status,
+                t.is_rush,
+                t.promise_date,
+                t.quote_amount,
+                t.created_at
+            FROM tickets t
+            JOIN customers c ON t.customer_id = c.customer_id
+            WHERE t.storage_location_id = $1
+              AND t.deleted_at IS NULL
+              AND t.status NOT IN ('closed', 'archived')
+              AND t.is_training = $2
+            ORDER BY t.is_rush DESC, t.created_at ASC
+            "#,
+        )
+        .bind(location_id)
+        .bind(training)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(tickets)
+    }
+
+    /// List a store's active locations with the number of open tickets
+    /// stored in each.
+    ///
+    /// Tickets in transit count toward the location expecting them rather
+    /// than the one they left.
+    pub async fn occupancy(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<Vec<LocationOccupancy>, AppError> {
+        let locations = sqlx::query_as::<_, LocationOccupancy>(&format!(
+            r#"
+            SELECT l.location_id, l.name, COUNT(t.ticket_id) AS open_tickets
+            FROM storage_locations l
+            LEFT JOIN ({}) t ON t.location_id = l.location_id
+            WHERE l.is_active = TRUE AND l.store_id = $1
+            GROUP BY l.location_id, l.name
+            ORDER BY l.name ASC
+            "#,
+            OPEN_TICKET_LOCATIONS
+        ))
+        .bind(store_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(locations)
+    }
+
+    /// List storage suggestion rules, highest priority first.
+    pub async fn list_rules(pool: &PgPool) -> Result<Vec<StorageLocationRule>, AppError> {
+        let rules = sqlx::query_as::<_, StorageLocationRule>(
+            r#"
+            SELECT * FROM storage_location_rules
+            ORDER BY priority DESC, created_at ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rules)
+    }
+
+    /// Replace all storage suggestion rules in a single transaction.
+    pub async fn replace_rules(
+        pool: &PgPool,
+        rules: Vec<CreateStorageLocationRule>,
+    ) -> Result<Vec<StorageLocationRule>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM storage_location_rules")
+            .execute(&mut *tx)
+            .await?;
+
+        for rule in &rules {
+            sqlx::query(
+                r#"
+                INSERT INTO storage_location_rules (item_type, min_quote_amount, location_id, priority)
+                VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .bind(&rule.item_type)
+            .bind(rule.min_quote_amount)
+            .bind(rule.location_id)
+            .bind(rule.priority)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Self::list_rules(pool).await
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,177 @@
+//! Store repository for database operations.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::store::{CreateStore, Store};
+
+/// Columns selected for [`Store`], with the name and prefix from its settings.
+const STORE_SELECT: &str = r#"
+    SELECT
+        s.store_id,
+        ss.store_name AS name,
+        ss.ticket_prefix,
+        s.is_primary,
+        s.is_active,
+        s.created_at,
+        s.updated_at
+    FROM stores s
+    JOIN store_settings ss ON ss.store_id = s.store_id
+"#;
+
+/// Repository for store operations.
+pub struct StoreRepository;
+
+impl StoreRepository {
+    /// List stores, primary first then by name.
+    pub async fn list(pool: &PgPool, include_inactive: bool) -> Result<Vec<Store>, AppError> {
+        let query = format!(
+            "{} WHERE s.is_active OR $1 ORDER BY s.is_primary DESC, ss.store_name",
+            STORE_SELECT
+        );
+        let stores = sqlx::query_as::<_, Store>(&query)
+            .bind(include_inactive)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(stores)
+    }
+
+    /// Find a store by ID.
+    pub async fn find_by_id(pool: &PgPool, store_id: Uuid) -> Result<Option<Store>, AppError> {
+        let query = format!("{} WHERE s.store_id = $1", STORE_SELECT);
+        let store = sqlx::query_as::<_, Store>(&query)
+            .bind(store_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(store)
+    }
+
+    /// Check whether a store exists and is open.
+    pub async fn is_active(pool: &PgPool, store_id: Uuid) -> Result<bool, AppError> {
+        let active = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM stores WHERE store_id = $1 AND is_active)",
+        )
+        .bind(store_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(active)
+    }
+
+    /// The store a request without `X-Store-ID` works for.
+    ///
+    /// That's the store the session was started at, else the employee's
+    /// home store, else the primary store. Closed stores are skipped.
+    pub async fn resolve_default(
+        pool: &PgPool,
+        session_token: Option<&str>,
+        employee_id: Option<Uuid>,
+    ) -> Result<Uuid, AppError> {
+        let store_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT COALESCE(
+                (
+                    SELECT s.store_id
+                    FROM employee_sessions es
+                    JOIN stores s ON s.store_id = es.store_id
+                    WHERE es.session_token = $1 AND es.expires_at > NOW() AND s.is_active
+                ),
+                (
+                    SELECT s.store_id
+                    FROM employees e
+                    JOIN stores s ON s.store_id = e.store_id
+                    WHERE e.employee_id = $2 AND s.is_active
+                ),
+                primary_store_id()
+            )
+            "#,
+        )
+        .bind(session_token)
+        .bind(employee_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(store_id)
+    }
+
+    /// Open a store.
+    ///
+    /// Its settings are copied from the primary store's, with the given
+    /// name, contact details, and prefix, and ticket numbers from 1.
+    pub async fn create(pool: &PgPool, input: &CreateStore) -> Result<Store, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let store_id =
+            sqlx::query_scalar::<_, Uuid>("INSERT INTO stores DEFAULT VALUES RETURNING store_id")
+                .fetch_one(&mut *tx)
+                .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO store_settings
+            SELECT (jsonb_populate_record(
+                NULL::store_settings,
+                to_jsonb(ss) || jsonb_build_object(
+                    'setting_id', gen_random_uuid(),
+                    'store_id', $1::uuid,
+                    'store_name', $2::text,
+                    'ticket_prefix', $3::text,
+                    'store_phone', $4::text,
+                    'store_address', $5::text,
+                    'next_ticket_number', 1,
+                    'ticket_number_year', NULL,
+                    'receipt_logo_key', NULL,
+                    'calendar_feed_token_hash', NULL,
+                    'version', 1,
+                    'created_at', NOW(),
+                    'updated_at', NOW()
+                )
+            )).*
+            FROM store_settings ss
+            WHERE ss.store_id = primary_store_id()
+            "#,
+        )
+        .bind(store_id)
+        .bind(&input.name)
+        .bind(&input.ticket_prefix)
+        .bind(&input.store_phone)
+        .bind(&input.store_address)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Self::find_by_id(pool, store_id)
+            .await?
+            .ok_or_else(|| AppError::server_error("Store missing after insert"))
+    }
+
+    /// Open or close a store. The primary store is never closed.
+    ///
+    /// Returns the store, or None if it doesn't exist.
+    pub async fn set_active(
+        pool: &PgPool,
+        store_id: Uuid,
+        is_active: bool,
+    ) -> Result<Option<Store>, AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE stores
+            SET is_active = $2, updated_at = NOW()
+            WHERE store_id = $1 AND NOT is_primary
+            "#,
+        )
+        .bind(store_id)
+        .bind(is_active)
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+        Self::find_by_id(pool, store_id).await
+    }
+}
@@ -1,25 +1,38 @@
 //! Store settings repository for database operations.
 
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
 use crate::auth::{hash_pin, verify_pin};
 use crate::error::AppError;
+use crate::models::business_hours::BusinessCalendar;
 use crate::models::store_settings::{
-    StoreSettings, StoreSettingsMinimalPublic, StoreSettingsPublic, TicketNumberResult,
+    ReceiptFormat, StoreSettings, StoreSettingsMinimalPublic, StoreSettingsPublic,
     UpdateStoreSettings,
 };
-use sqlx::PgPool;
+use crate::models::tax::TaxRate;
+use rust_decimal::Decimal;
+use sqlx::types::Json;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+/// Prefix marking a string as a calendar feed token.
+const CALENDAR_FEED_TOKEN_PREFIX: &str = "fcal_";
 
 /// Repository for store settings database operations.
 pub struct StoreSettingsRepository;
 
 impl StoreSettingsRepository {
-    /// Get the store settings.
+    /// Get the primary store's settings.
     ///
-    /// Returns the single store settings row.
+    /// These hold the deployment-wide settings (admin PIN, setup state, PIN
+    /// length, ...) and are what chain-wide jobs and reports use.
     /// Returns a server error if settings don't exist (should be seeded).
     pub async fn get_settings(pool: &PgPool) -> Result<StoreSettings, AppError> {
         let settings = sqlx::query_as::<_, StoreSettings>(
             r#"
-            SELECT * FROM store_settings LIMIT 1
+            SELECT * FROM store_settings WHERE store_id = primary_store_id()
             "#,
         )
         .fetch_optional(pool)
@@ -28,30 +41,65 @@ impl StoreSettingsRepository {
         settings.ok_or_else(|| AppError::server_error("Store settings not initialized"))
     }
 
-    /// Get the public store settings (without admin PIN hash).
+    /// Get a store's settings.
+    ///
+    /// The deployment-wide settings always come from the primary store.
+    pub async fn get_store_settings(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<StoreSettings, AppError> {
+        let primary = Self::get_settings(pool).await?;
+        let settings = sqlx::query_as::<_, StoreSettings>(
+            r#"
+            SELECT * FROM store_settings WHERE store_id = $1
+            "#,
+        )
+        .bind(store_id)
+        .fetch_optional(pool)
+        .await?;
+
+        match settings {
+            Some(settings) => Ok(settings.with_deployment_settings(&primary)),
+            None => Err(AppError::server_error("Store settings not initialized")),
+        }
+    }
+
+    /// Get a store's public settings (without admin PIN hash).
     /// For authenticated admin responses.
-    pub async fn get_settings_public(pool: &PgPool) -> Result<StoreSettingsPublic, AppError> {
-        let settings = Self::get_settings(pool).await?;
+    pub async fn get_settings_public(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<StoreSettingsPublic, AppError> {
+        let settings = Self::get_store_settings(pool, store_id).await?;
         Ok(StoreSettingsPublic::from(settings))
     }
 
-    /// Get minimal public store settings for unauthenticated access.
+    /// Get a store's minimal public settings for unauthenticated access.
     /// Excludes security-sensitive fields like setup_complete and next_ticket_number.
     pub async fn get_settings_minimal_public(
         pool: &PgPool,
+        store_id: Uuid,
     ) -> Result<StoreSettingsMinimalPublic, AppError> {
-        let settings = Self::get_settings(pool).await?;
+        let settings = Self::get_store_settings(pool, store_id).await?;
         Ok(StoreSettingsMinimalPublic::from(settings))
     }
 
-    /// Update store settings.
+    /// Update a store's settings.
     ///
-    /// Only the provided fields are updated.
+    /// Only the provided fields are updated. If `expected_version` is given
+    /// (from an If-Match header) it must equal the current version. Either
+    /// way the write only succeeds if no other update landed since the
+    /// current values were read, and it bumps the version.
     pub async fn update_settings(
         pool: &PgPool,
+        store_id: Uuid,
         input: UpdateStoreSettings,
+        expected_version: Option<i32>,
     ) -> Result<StoreSettingsPublic, AppError> {
-        let existing = Self::get_settings(pool).await?;
+        let existing = Self::get_store_settings(pool, store_id).await?;
+        if expected_version.is_some_and(|version| version != existing.version) {
+            return Err(stale_settings());
+        }
 
         let store_name = input.store_name.unwrap_or(existing.store_name);
         let store_phone = input.store_phone.or(existing.store_phone);
@@ -61,6 +109,59 @@ impl StoreSettingsRepository {
         let max_photos_per_ticket = input
             .max_photos_per_ticket
             .unwrap_or(existing.max_photos_per_ticket);
+        let qc_checklist = input.qc_checklist.unwrap_or(existing.qc_checklist);
+        let scope_ticket_visibility = input
+            .scope_ticket_visibility
+            .unwrap_or(existing.scope_ticket_visibility);
+        let custody_value_threshold = input
+            .custody_value_threshold
+            .unwrap_or(existing.custody_value_threshold);
+        let quote_approval_threshold = input
+            .quote_approval_threshold
+            .unwrap_or(existing.quote_approval_threshold);
+        let notifications_enabled = input
+            .notifications_enabled
+            .unwrap_or(existing.notifications_enabled);
+        let min_pin_length = input.min_pin_length.unwrap_or(existing.min_pin_length);
+        let auto_archive_after_days = input
+            .auto_archive_after_days
+            .unwrap_or(existing.auto_archive_after_days);
+        let restrict_item_types = input
+            .restrict_item_types
+            .unwrap_or(existing.restrict_item_types);
+        let overdue_digest_email = input
+            .overdue_digest_email
+            .unwrap_or(existing.overdue_digest_email);
+        let overdue_digest_sms = input
+            .overdue_digest_sms
+            .unwrap_or(existing.overdue_digest_sms);
+        let overdue_digest_hour = input
+            .overdue_digest_hour
+            .unwrap_or(existing.overdue_digest_hour);
+        let receipt_logo_key = input.receipt_logo_key.unwrap_or(existing.receipt_logo_key);
+        let receipt_footer_text = input
+            .receipt_footer_text
+            .unwrap_or(existing.receipt_footer_text);
+        let receipt_show_prices = input
+            .receipt_show_prices
+            .unwrap_or(existing.receipt_show_prices);
+        let label_width_mm = input.label_width_mm.unwrap_or(existing.label_width_mm);
+        let label_height_mm = input.label_height_mm.unwrap_or(existing.label_height_mm);
+        let receipt_format = input.receipt_format.unwrap_or(existing.receipt_format);
+        let tax_rate = input.tax_rate.unwrap_or(existing.tax_rate);
+        let tax_inclusive = input.tax_inclusive.unwrap_or(existing.tax_inclusive);
+        let ticket_number_padding = input
+            .ticket_number_padding
+            .unwrap_or(existing.ticket_number_padding);
+        let ticket_number_yearly_reset = input
+            .ticket_number_yearly_reset
+            .unwrap_or(existing.ticket_number_yearly_reset);
+        let business_hours = input.business_hours.unwrap_or(existing.business_hours.0);
+        let holidays = input.holidays.unwrap_or(existing.holidays);
+        let rush_turnaround_days = input
+            .rush_turnaround_days
+            .unwrap_or(existing.rush_turnaround_days);
+        let timezone = input.timezone.unwrap_or(existing.timezone);
 
         let settings = sqlx::query_as::<_, StoreSettings>(
             r#"
@@ -71,7 +172,34 @@ impl StoreSettingsRepository {
                 ticket_prefix = $4,
                 currency = $5,
                 max_photos_per_ticket = $6,
+                qc_checklist = $7,
+                scope_ticket_visibility = $8,
+                custody_value_threshold = $9,
+                notifications_enabled = $10,
+                min_pin_length = $11,
+                auto_archive_after_days = $12,
+                quote_approval_threshold = $13,
+                overdue_digest_email = $14,
+                overdue_digest_sms = $15,
+                overdue_digest_hour = $16,
+                receipt_logo_key = $17,
+                receipt_footer_text = $18,
+                receipt_show_prices = $19,
+                label_width_mm = $20,
+                label_height_mm = $21,
+                receipt_format = $22,
+                tax_rate = $23,
+                tax_inclusive = $24,
+                restrict_item_types = $26,
+                ticket_number_padding = $28,
+                ticket_number_yearly_reset = $29,
+                business_hours = $30,
+                holidays = $31,
+                rush_turnaround_days = $32,
+                timezone = $33,
+                version = version + 1,
                 updated_at = NOW()
+            WHERE store_id = $27 AND version = $25
             RETURNING *
             "#,
         )
@@ -81,10 +209,52 @@ impl StoreSettingsRepository {
         .bind(&ticket_prefix)
         .bind(&currency)
         .bind(max_photos_per_ticket)
+        .bind(&qc_checklist)
+        .bind(scope_ticket_visibility)
+        .bind(custody_value_threshold)
+        .bind(notifications_enabled)
+        .bind(min_pin_length)
+        .bind(auto_archive_after_days)
+        .bind(quote_approval_threshold)
+        .bind(&overdue_digest_email)
+        .bind(overdue_digest_sms)
+        .bind(overdue_digest_hour)
+        .bind(&receipt_logo_key)
+        .bind(&receipt_footer_text)
+        .bind(receipt_show_prices)
+        .bind(label_width_mm)
+        .bind(label_height_mm)
+        .bind(receipt_format)
+        .bind(tax_rate)
+        .bind(tax_inclusive)
+        .bind(existing.version)
+        .bind(restrict_item_types)
+        .bind(store_id)
+        .bind(ticket_number_padding)
+        .bind(ticket_number_yearly_reset)
+        .bind(Json(&business_hours))
+        .bind(&holidays)
+        .bind(rush_turnaround_days)
+        .bind(&timezone)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(stale_settings)?;
+
+        Ok(StoreSettingsPublic::from(
+            settings.with_deployment_settings(&Self::get_settings(pool).await?),
+        ))
+    }
+
+    /// Check that Postgres knows an IANA time zone name.
+    pub async fn is_known_timezone(pool: &PgPool, timezone: &str) -> Result<bool, AppError> {
+        let known = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM pg_timezone_names WHERE name = $1)",
+        )
+        .bind(timezone)
         .fetch_one(pool)
         .await?;
 
-        Ok(StoreSettingsPublic::from(settings))
+        Ok(known)
     }
 
     /// Verify the admin PIN.
@@ -100,7 +270,7 @@ impl StoreSettingsRepository {
     ///
     /// Returns the updated settings (public view).
     pub async fn change_admin_pin(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         new_pin: &str,
     ) -> Result<StoreSettingsPublic, AppError> {
         let pin_hash = hash_pin(new_pin)?;
@@ -109,37 +279,36 @@ impl StoreSettingsRepository {
             r#"
             UPDATE store_settings
             SET admin_pin_hash = $1, updated_at = NOW()
+            WHERE store_id = primary_store_id()
             RETURNING *
             "#,
         )
         .bind(&pin_hash)
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
 
         Ok(StoreSettingsPublic::from(settings))
     }
 
-    /// Get the next ticket number and increment the counter atomically.
+    /// Get the next invoice number and increment the counter atomically.
     ///
-    /// Returns the prefix and number to use for the new ticket.
-    pub async fn get_and_increment_ticket_number(
-        pool: &PgPool,
-    ) -> Result<TicketNumberResult, AppError> {
-        let result = sqlx::query_as::<_, (String, i32)>(
+    /// Invoice numbers are shared by every store.
+    pub async fn get_and_increment_invoice_number(
+        executor: impl PgExecutor<'_>,
+    ) -> Result<i32, AppError> {
+        let number = sqlx::query_scalar::<_, i32>(
             r#"
             UPDATE store_settings
-            SET next_ticket_number = next_ticket_number + 1,
+            SET next_invoice_number = next_invoice_number + 1,
                 updated_at = NOW()
-            RETURNING ticket_prefix, next_ticket_number - 1
+            WHERE store_id = primary_store_id()
+            RETURNING next_invoice_number - 1
             "#,
         )
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
 
-        Ok(TicketNumberResult {
-            prefix: result.0,
-            number: result.1,
-        })
+        Ok(number)
     }
 
     /// Mark setup as complete.
@@ -150,6 +319,7 @@ impl StoreSettingsRepository {
             r#"
             UPDATE store_settings
             SET setup_complete = TRUE, updated_at = NOW()
+            WHERE store_id = primary_store_id()
             RETURNING *
             "#,
         )
@@ -170,7 +340,7 @@ impl StoreSettingsRepository {
         let result = sqlx::query_scalar::<_, bool>(
             r#"
             SELECT NOT setup_complete AND setup_deadline > NOW()
-            FROM store_settings LIMIT 1
+            FROM store_settings WHERE store_id = primary_store_id()
             "#,
         )
         .fetch_one(pool)
@@ -186,7 +356,7 @@ impl StoreSettingsRepository {
         let result = sqlx::query_scalar::<_, bool>(
             r#"
             SELECT NOT setup_complete AND setup_deadline <= NOW()
-            FROM store_settings LIMIT 1
+            FROM store_settings WHERE store_id = primary_store_id()
             "#,
         )
         .fetch_one(pool)
@@ -195,6 +365,135 @@ impl StoreSettingsRepository {
         Ok(result)
     }
 
+    /// Get a store's QC checklist items.
+    ///
+    /// An empty list means the QC gate is disabled.
+    pub async fn get_qc_checklist(pool: &PgPool, store_id: Uuid) -> Result<Vec<String>, AppError> {
+        let settings = Self::get_store_settings(pool, store_id).await?;
+        Ok(settings.qc_checklist)
+    }
+
+    /// Get the store's tax rate and whether ticket amounts include it.
+    pub async fn get_tax_rate(pool: &PgPool, store_id: Uuid) -> Result<TaxRate, AppError> {
+        let settings = Self::get_store_settings(pool, store_id).await?;
+        Ok(settings.tax())
+    }
+
+    /// Get the format a store's receipts are printed in by default.
+    pub async fn get_receipt_format(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<ReceiptFormat, AppError> {
+        let settings = Self::get_store_settings(pool, store_id).await?;
+        Ok(settings.receipt_format)
+    }
+
+    /// Check whether staff ticket visibility is limited to their own tickets.
+    pub async fn is_ticket_visibility_scoped(pool: &PgPool) -> Result<bool, AppError> {
+        let settings = Self::get_settings(pool).await?;
+        Ok(settings.scope_ticket_visibility)
+    }
+
+    /// Get a store's quote amount at which custody events are required, if any.
+    pub async fn get_custody_value_threshold(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<Option<Decimal>, AppError> {
+        let settings = Self::get_store_settings(pool, store_id).await?;
+        Ok(settings.custody_value_threshold)
+    }
+
+    /// Get a store's quote amount above which work needs customer approval, if any.
+    pub async fn get_quote_approval_threshold(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<Option<Decimal>, AppError> {
+        let settings = Self::get_store_settings(pool, store_id).await?;
+        Ok(settings.quote_approval_threshold)
+    }
+
+    /// Get the days a store is open, for counting promise dates.
+    pub async fn get_business_calendar(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<BusinessCalendar, AppError> {
+        let settings = Self::get_store_settings(pool, store_id).await?;
+        Ok(settings.business_calendar())
+    }
+
+    /// Generate a calendar feed token.
+    ///
+    /// Creates a 256-bit random token encoded as base64url (no padding),
+    /// prefixed with `fcal_` so it's recognizable in a feed URL.
+    pub fn generate_calendar_feed_token() -> String {
+        let mut token_bytes = [0u8; 32]; // 256 bits
+        rand::thread_rng().fill_bytes(&mut token_bytes);
+        format!(
+            "{}{}",
+            CALENDAR_FEED_TOKEN_PREFIX,
+            URL_SAFE_NO_PAD.encode(token_bytes)
+        )
+    }
+
+    /// Hash a calendar feed token for storage and lookup.
+    pub fn hash_calendar_feed_token(token: &str) -> String {
+        hex::encode(Sha256::digest(token.as_bytes()))
+    }
+
+    /// Publish a store's calendar feed under a new token hash, or revoke it
+    /// with None. Any previous token stops working.
+    pub async fn set_calendar_feed_token_hash(
+        pool: &PgPool,
+        store_id: Uuid,
+        token_hash: Option<&str>,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE store_settings
+            SET calendar_feed_token_hash = $2, updated_at = NOW()
+            WHERE store_id = $1
+            "#,
+        )
+        .bind(store_id)
+        .bind(token_hash)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Find the open store whose calendar feed a token unlocks.
+    pub async fn find_store_by_calendar_feed_token(
+        pool: &PgPool,
+        token: &str,
+    ) -> Result<Option<(Uuid, String)>, AppError> {
+        let store = sqlx::query_as::<_, (Uuid, String)>(
+            r#"
+            SELECT ss.store_id, ss.store_name
+            FROM store_settings ss
+            JOIN stores s ON s.store_id = ss.store_id
+            WHERE ss.calendar_feed_token_hash = $1 AND s.is_active
+            "#,
+        )
+        .bind(Self::hash_calendar_feed_token(token))
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(store)
+    }
+
+    /// Get whether a store's tickets must use a configured item type.
+    pub async fn get_restrict_item_types(pool: &PgPool, store_id: Uuid) -> Result<bool, AppError> {
+        let settings = Self::get_store_settings(pool, store_id).await?;
+        Ok(settings.restrict_item_types)
+    }
+
+    /// Get the age in days after which closed tickets are auto-archived, if enabled.
+    pub async fn get_auto_archive_after_days(pool: &PgPool) -> Result<Option<i32>, AppError> {
+        let settings = Self::get_settings(pool).await?;
+        Ok(settings.auto_archive_after_days)
+    }
+
     /// Get the minimum PIN length requirement.
     pub async fn get_min_pin_length(pool: &PgPool) -> Result<i32, AppError> {
         let settings = Self::get_settings(pool).await?;
@@ -202,8 +501,29 @@ impl StoreSettingsRepository {
     }
 }
 
+/// Error for an update that lost a race with another settings change.
+fn stale_settings() -> AppError {
+    AppError::precondition_failed("Settings were changed by someone else; reload and try again")
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calendar_feed_token_format() {
+        let token = StoreSettingsRepository::generate_calendar_feed_token();
+        assert!(token.starts_with(CALENDAR_FEED_TOKEN_PREFIX));
+        assert_ne!(
+            token,
+            StoreSettingsRepository::generate_calendar_feed_token()
+        );
+        assert_eq!(
+            StoreSettingsRepository::hash_calendar_feed_token(&token).len(),
+            64
+        );
+    }
+
     #[test]
     fn test_store_settings_repository_exists() {
         // Basic sanity test
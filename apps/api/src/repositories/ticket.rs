@@ -1,21 +1,160 @@
 //! Ticket repository for database operations.
 
 use crate::error::AppError;
+use crate::models::calendar_feed::PromiseDateEntry;
+use crate::models::store_settings::TicketNumbering;
 use crate::models::ticket::{
-    CreateTicket, QueueTicket, Ticket, TicketFilters, TicketSearchParams, TicketStatus,
-    TicketSummary, UpdateTicket, WorkboardQueue,
+    CreateTicket, QueueTicket, Ticket, TicketDetail, TicketFilters, TicketOrder,
+    TicketSearchParams, TicketSortField, TicketStatus, TicketSummary, UpdateTicket, WorkboardQueue,
 };
-use sqlx::PgPool;
+use crate::models::ticket_item::CreateTicketItem;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Datelike, Utc};
+use rand::RngCore;
+use sqlx::{Connection, PgConnection, PgExecutor, PgPool};
+use std::collections::HashSet;
 use uuid::Uuid;
 
+/// ORDER BY clause for a ticket listing; `priority` leads the default order.
+///
+/// Every order ends oldest first, then by ticket ID, so it is total. Sort
+/// fields map to fixed column expressions; nothing from the request reaches
+/// the SQL text.
+fn order_by_sql(order: TicketOrder, priority: &str) -> String {
+    const OLDEST_FIRST: &str = "t.created_at ASC, t.ticket_id ASC";
+    match order {
+        TicketOrder::Priority => format!("{}, {}", priority, OLDEST_FIRST),
+        TicketOrder::Created => OLDEST_FIRST.to_string(),
+        TicketOrder::Sorted(sort) => {
+            let column = match sort.field {
+                TicketSortField::PromiseDate => "t.promise_date",
+                TicketSortField::CreatedAt => "t.created_at",
+                TicketSortField::QuoteAmount => "t.quote_amount",
+                TicketSortField::CustomerName => "LOWER(c.name)",
+            };
+            let direction = if sort.descending { "DESC" } else { "ASC" };
+            format!("{} {} NULLS LAST, {}", column, direction, OLDEST_FIRST)
+        }
+    }
+}
+
+/// ILIKE pattern matching `text` anywhere, with its wildcards escaped.
+fn contains_pattern(text: &str) -> String {
+    let mut pattern = String::with_capacity(text.len() + 2);
+    pattern.push('%');
+    for c in text.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
 /// Repository for ticket database operations.
 pub struct TicketRepository;
 
 impl TicketRepository {
+    /// Generate a token for the public status lookup.
+    ///
+    /// Creates a 96-bit random token encoded as base64url (16 characters),
+    /// short enough to print on a receipt and type in by hand.
+    pub fn generate_lookup_token() -> String {
+        let mut token_bytes = [0u8; 12]; // 96 bits
+        rand::thread_rng().fill_bytes(&mut token_bytes);
+        URL_SAFE_NO_PAD.encode(token_bytes)
+    }
+
+    /// Reserve the next `count` ticket codes of the store holding a storage
+    /// location.
+    ///
+    /// The store's numbering row is locked until the caller's transaction
+    /// ends, so concurrent intakes get consecutive numbers. Codes already
+    /// used by a ticket (for example after the format changed back) are
+    /// skipped.
+    pub async fn reserve_friendly_codes(
+        conn: &mut PgConnection,
+        storage_location_id: Uuid,
+        count: usize,
+    ) -> Result<Vec<String>, AppError> {
+        let numbering = sqlx::query_as::<_, TicketNumbering>(
+            r#"
+            SELECT ticket_prefix, ticket_number_padding, ticket_number_yearly_reset,
+                   ticket_number_year, next_ticket_number
+            FROM store_settings
+            WHERE store_id = COALESCE(
+                (SELECT store_id FROM storage_locations WHERE location_id = $1),
+                primary_store_id()
+            )
+            FOR UPDATE
+            "#,
+        )
+        .bind(storage_location_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        let year = Utc::now().year();
+        let mut number = numbering.next_number(year);
+        let mut codes = Vec::with_capacity(count);
+        while codes.len() < count {
+            let candidates: Vec<String> = (number..)
+                .take(count - codes.len())
+                .map(|n| numbering.code(year, n))
+                .collect();
+            let taken: HashSet<String> = sqlx::query_scalar::<_, String>(
+                "SELECT UPPER(friendly_code) FROM tickets WHERE UPPER(friendly_code) = ANY($1)",
+            )
+            .bind(
+                candidates
+                    .iter()
+                    .map(|code| code.to_uppercase())
+                    .collect::<Vec<_>>(),
+            )
+            .fetch_all(&mut *conn)
+            .await?
+            .into_iter()
+            .collect();
+            number += candidates.len() as i32;
+            codes.extend(
+                candidates
+                    .into_iter()
+                    .filter(|code| !taken.contains(&code.to_uppercase())),
+            );
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE store_settings
+            SET next_ticket_number = $2, ticket_number_year = $3, updated_at = NOW()
+            WHERE store_id = COALESCE(
+                (SELECT store_id FROM storage_locations WHERE location_id = $1),
+                primary_store_id()
+            )
+            "#,
+        )
+        .bind(storage_location_id)
+        .bind(number)
+        .bind(year)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(codes)
+    }
+
     /// Create a new ticket.
     ///
-    /// The friendly_code is generated atomically using the database function.
-    pub async fn create(pool: &PgPool, input: CreateTicket) -> Result<Ticket, AppError> {
+    /// The friendly_code is the next code of the storage location's store,
+    /// which the ticket belongs to, in the store's numbering format.
+    /// The ticket's items are inserted in the same transaction; with no
+    /// `items`, the ticket gets one item from its own item fields. Inside a
+    /// caller's transaction this runs as a savepoint.
+    pub async fn create(conn: &mut PgConnection, input: CreateTicket) -> Result<Ticket, AppError> {
+        let mut tx = conn.begin().await?;
+
+        let friendly_code = Self::reserve_friendly_codes(&mut tx, input.storage_location_id, 1)
+            .await?
+            .remove(0);
         let ticket = sqlx::query_as::<_, Ticket>(
             r#"
             INSERT INTO tickets (
@@ -29,12 +168,14 @@ impl TicketRepository {
                 promise_date,
                 storage_location_id,
                 quote_amount,
-                taken_in_by
-            )
-            VALUES (
-                generate_friendly_code(),
-                $1, $2, $3, $4, $5, $6, $7, $8, $9, $10
+                taken_in_by,
+                weight_grams,
+                metal_type,
+                lookup_token,
+                is_training,
+                rush_surcharge
             )
+            VALUES ($16, $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
             RETURNING *
             "#,
         )
@@ -48,12 +189,154 @@ impl TicketRepository {
         .bind(input.storage_location_id)
         .bind(input.quote_amount)
         .bind(input.taken_in_by)
-        .fetch_one(pool)
+        .bind(input.weight_grams)
+        .bind(&input.metal_type)
+        .bind(Self::generate_lookup_token())
+        .bind(input.is_training)
+        .bind(input.rush_surcharge)
+        .bind(&friendly_code)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let items = if input.items.is_empty() {
+            vec![CreateTicketItem {
+                item_type: input.item_type,
+                item_description: input.item_description,
+                condition_notes: input.condition_notes,
+                requested_work: input.requested_work,
+                quote_amount: input.quote_amount,
+            }]
+        } else {
+            input.items
+        };
+        let (mut item_types, mut descriptions, mut conditions, mut works, mut quotes) =
+            (Vec::new(), Vec::new(), Vec::new(), Vec::new(), Vec::new());
+        for item in items {
+            item_types.push(item.item_type);
+            descriptions.push(item.item_description);
+            conditions.push(item.condition_notes);
+            works.push(item.requested_work);
+            quotes.push(item.quote_amount);
+        }
+        sqlx::query(
+            r#"
+            INSERT INTO ticket_items (
+                ticket_id, position, item_type, item_description,
+                condition_notes, requested_work, quote_amount
+            )
+            SELECT $1, i.position, i.item_type, i.item_description,
+                   i.condition_notes, i.requested_work, i.quote_amount
+            FROM UNNEST($2::VARCHAR[], $3::TEXT[], $4::TEXT[], $5::TEXT[], $6::NUMERIC[])
+                WITH ORDINALITY AS i(item_type, item_description, condition_notes,
+                                     requested_work, quote_amount, position)
+            "#,
+        )
+        .bind(ticket.ticket_id)
+        .bind(&item_types)
+        .bind(&descriptions)
+        .bind(&conditions)
+        .bind(&works)
+        .bind(&quotes)
+        .execute(&mut *tx)
         .await?;
 
+        tx.commit().await?;
         Ok(ticket)
     }
 
+    /// Find a ticket by friendly code and lookup token (excludes soft-deleted tickets).
+    ///
+    /// The friendly code is matched case-insensitively; the token exactly.
+    pub async fn find_by_lookup_token(
+        pool: &PgPool,
+        friendly_code: &str,
+        lookup_token: &str,
+    ) -> Result<Option<Ticket>, AppError> {
+        let ticket = sqlx::query_as::<_, Ticket>(
+            r#"
+            SELECT * FROM tickets
+            WHERE UPPER(friendly_code) = UPPER($1)
+              AND lookup_token = $2
+              AND deleted_at IS NULL
+            "#,
+        )
+        .bind(friendly_code)
+        .bind(lookup_token)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(ticket)
+    }
+
+    /// List a store's open tickets that have a promise date, soonest first.
+    ///
+    /// Training and deleted tickets are left out.
+    pub async fn list_promise_dates(
+        pool: &PgPool,
+        store_id: Uuid,
+    ) -> Result<Vec<PromiseDateEntry>, AppError> {
+        let entries = sqlx::query_as::<_, PromiseDateEntry>(
+            r#"
+            SELECT
+                t.ticket_id,
+                t.friendly_code,
+                c.name AS customer_name,
+                t.item_description,
+                t.status,
+                t.is_rush,
+                t.promise_date,
+                t.updated_at
+            FROM tickets t
+            JOIN customers c ON t.customer_id = c.customer_id
+            WHERE t.store_id = $1
+              AND t.promise_date IS NOT NULL
+              AND t.deleted_at IS NULL
+              AND NOT t.is_training
+              AND t.status NOT IN ('closed', 'archived')
+            ORDER BY t.promise_date ASC, t.friendly_code ASC
+            "#,
+        )
+        .bind(store_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    /// Count open tickets that will be worked before `ticket`.
+    ///
+    /// Within a lane the queue runs rush first, then by manual queue
+    /// position, then oldest first. A ticket still in intake also waits
+    /// behind everything already in progress.
+    pub async fn count_ahead_in_queue(pool: &PgPool, ticket: &Ticket) -> Result<i64, AppError> {
+        let count = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM tickets t
+            WHERE t.deleted_at IS NULL
+              AND t.is_training = $1
+              AND t.ticket_id <> $2
+              AND (
+                (t.status = $3 AND (
+                    (t.is_rush AND NOT $4)
+                    OR (t.is_rush = $4 AND (COALESCE(t.queue_position, 2147483647), t.created_at)
+                        < (COALESCE($6, 2147483647), $5))
+                ))
+                OR ($3 = 'intake' AND t.status = 'in_progress')
+              )
+            "#,
+        )
+        .bind(ticket.is_training)
+        .bind(ticket.ticket_id)
+        .bind(ticket.status)
+        .bind(ticket.is_rush)
+        .bind(ticket.created_at)
+        .bind(ticket.queue_position)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(count)
+    }
+
     /// Find a ticket by ID (excludes soft-deleted tickets).
     pub async fn find_by_id(pool: &PgPool, ticket_id: Uuid) -> Result<Option<Ticket>, AppError> {
         let ticket = sqlx::query_as::<_, Ticket>(
@@ -85,6 +368,42 @@ impl TicketRepository {
         Ok(ticket)
     }
 
+    /// Find a ticket with its customer, storage location, and employee names.
+    ///
+    /// Soft-deleted tickets are found only with `include_deleted`.
+    pub async fn find_detail(
+        pool: &PgPool,
+        ticket_id: Uuid,
+        include_deleted: bool,
+    ) -> Result<Option<TicketDetail>, AppError> {
+        let detail = sqlx::query_as::<_, TicketDetail>(
+            r#"
+            SELECT
+                t.*,
+                c.name AS customer_name,
+                c.phone AS customer_phone,
+                c.email AS customer_email,
+                sl.name AS storage_location_name,
+                taken.name AS taken_in_by_name,
+                worked.name AS worked_by_name,
+                closed.name AS closed_by_name
+            FROM tickets t
+            JOIN customers c ON c.customer_id = t.customer_id
+            JOIN storage_locations sl ON sl.location_id = t.storage_location_id
+            JOIN employees taken ON taken.employee_id = t.taken_in_by
+            LEFT JOIN employees worked ON worked.employee_id = t.worked_by
+            LEFT JOIN employees closed ON closed.employee_id = t.closed_by
+            WHERE t.ticket_id = $1 AND ($2 OR t.deleted_at IS NULL)
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(include_deleted)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(detail)
+    }
+
     /// Find a ticket by friendly code (excludes soft-deleted tickets).
     pub async fn find_by_code(
         pool: &PgPool,
@@ -105,9 +424,10 @@ impl TicketRepository {
     /// Update just the status of a ticket.
     ///
     /// This is a focused update for status changes, separate from general ticket updates.
-    /// Also updates the last_modified_by and updated_at fields.
+    /// Also updates the last_modified_by and updated_at fields. A ticket that
+    /// changes lanes loses its manual queue position.
     pub async fn update_status(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         ticket_id: Uuid,
         new_status: TicketStatus,
         modified_by: Uuid,
@@ -115,6 +435,7 @@ impl TicketRepository {
         let ticket = sqlx::query_as::<_, Ticket>(
             r#"
             UPDATE tickets SET
+                queue_position = CASE WHEN status = $2 THEN queue_position END,
                 status = $2,
                 last_modified_by = $3,
                 updated_at = NOW()
@@ -125,17 +446,105 @@ impl TicketRepository {
         .bind(ticket_id)
         .bind(new_status)
         .bind(modified_by)
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
 
         Ok(ticket)
     }
 
+    /// Change the status of several tickets in one transaction.
+    ///
+    /// Each `(ticket_id, from_status)` is updated only if the ticket is still
+    /// in `from_status`, and gets a status history entry. Returns the tickets
+    /// that were updated; the others changed status in the meantime.
+    pub async fn update_status_bulk(
+        pool: &PgPool,
+        changes: &[(Uuid, TicketStatus)],
+        new_status: TicketStatus,
+        modified_by: Uuid,
+    ) -> Result<Vec<Ticket>, AppError> {
+        let mut tx = pool.begin().await?;
+        let mut updated = Vec::with_capacity(changes.len());
+
+        for (ticket_id, from_status) in changes {
+            let ticket = sqlx::query_as::<_, Ticket>(
+                r#"
+                UPDATE tickets SET
+                    queue_position = NULL,
+                    status = $3,
+                    last_modified_by = $4,
+                    updated_at = NOW()
+                WHERE ticket_id = $1 AND status = $2 AND deleted_at IS NULL
+                RETURNING *
+                "#,
+            )
+            .bind(ticket_id)
+            .bind(from_status)
+            .bind(new_status)
+            .bind(modified_by)
+            .fetch_optional(&mut *tx)
+            .await?;
+
+            let Some(ticket) = ticket else {
+                continue;
+            };
+            sqlx::query(
+                r#"
+                INSERT INTO ticket_status_history (ticket_id, from_status, to_status, changed_by)
+                VALUES ($1, $2, $3, $4)
+                "#,
+            )
+            .bind(ticket_id)
+            .bind(from_status)
+            .bind(new_status)
+            .bind(modified_by)
+            .execute(&mut *tx)
+            .await?;
+            updated.push(ticket);
+        }
+
+        tx.commit().await?;
+        Ok(updated)
+    }
+
+    /// Archive every closed ticket that was closed before `cutoff`.
+    ///
+    /// Each archived ticket gets a status history entry attributed to the
+    /// employee who closed it. Deleted tickets are left alone.
+    pub async fn archive_closed_before(
+        pool: &PgPool,
+        cutoff: DateTime<Utc>,
+    ) -> Result<Vec<Ticket>, AppError> {
+        let tickets = sqlx::query_as::<_, Ticket>(
+            r#"
+            WITH archived AS (
+                UPDATE tickets SET
+                    status = 'archived',
+                    updated_at = NOW()
+                WHERE status = 'closed'
+                  AND closed_at < $1
+                  AND closed_by IS NOT NULL
+                  AND deleted_at IS NULL
+                RETURNING *
+            ), history AS (
+                INSERT INTO ticket_status_history (ticket_id, from_status, to_status, changed_by)
+                SELECT ticket_id, 'closed', 'archived', closed_by FROM archived
+            )
+            SELECT * FROM archived
+            "#,
+        )
+        .bind(cutoff)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(tickets)
+    }
+
     /// Close a ticket.
     ///
     /// Sets the status to Closed, records the actual amount, and sets closed_at/closed_by.
     pub async fn close(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         ticket_id: Uuid,
         actual_amount: rust_decimal::Decimal,
         closed_by: Uuid,
@@ -144,6 +553,7 @@ impl TicketRepository {
             r#"
             UPDATE tickets SET
                 status = 'closed',
+                queue_position = NULL,
                 actual_amount = $2,
                 closed_by = $3,
                 closed_at = NOW(),
@@ -156,7 +566,36 @@ impl TicketRepository {
         .bind(ticket_id)
         .bind(actual_amount)
         .bind(closed_by)
-        .fetch_one(pool)
+        .fetch_one(executor)
+        .await?;
+
+        Ok(ticket)
+    }
+
+    /// Reopen a closed ticket.
+    ///
+    /// Sets the status back to InProgress and clears closed_at/closed_by.
+    pub async fn reopen(
+        executor: impl PgExecutor<'_>,
+        ticket_id: Uuid,
+        reopened_by: Uuid,
+    ) -> Result<Ticket, AppError> {
+        let ticket = sqlx::query_as::<_, Ticket>(
+            r#"
+            UPDATE tickets SET
+                status = 'in_progress',
+                queue_position = NULL,
+                closed_by = NULL,
+                closed_at = NULL,
+                last_modified_by = $2,
+                updated_at = NOW()
+            WHERE ticket_id = $1 AND status = 'closed'
+            RETURNING *
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(reopened_by)
+        .fetch_one(executor)
         .await?;
 
         Ok(ticket)
@@ -166,29 +605,60 @@ impl TicketRepository {
     ///
     /// Only non-None fields in the input will be updated.
     /// Uses COALESCE for simple optional fields and CASE WHEN for nullable fields
-    /// that can be explicitly set to NULL.
+    /// that can be explicitly set to NULL. Changing the quote amount returns
+    /// the quote to draft, and item 1 is kept in step with the item fields.
     pub async fn update(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         ticket_id: Uuid,
         input: UpdateTicket,
     ) -> Result<Ticket, AppError> {
         let ticket = sqlx::query_as::<_, Ticket>(
             r#"
-            UPDATE tickets SET
-                item_type = COALESCE($2, item_type),
-                item_description = COALESCE($3, item_description),
-                condition_notes = COALESCE($4, condition_notes),
-                requested_work = COALESCE($5, requested_work),
-                is_rush = COALESCE($6, is_rush),
-                promise_date = CASE WHEN $7::boolean THEN $8 ELSE promise_date END,
-                storage_location_id = COALESCE($9, storage_location_id),
-                quote_amount = CASE WHEN $10::boolean THEN $11 ELSE quote_amount END,
-                actual_amount = CASE WHEN $12::boolean THEN $13 ELSE actual_amount END,
-                worked_by = CASE WHEN $14::boolean THEN $15 ELSE worked_by END,
-                last_modified_by = COALESCE($16, last_modified_by),
-                updated_at = NOW()
-            WHERE ticket_id = $1
-            RETURNING *
+            WITH updated AS (
+                UPDATE tickets SET
+                    item_type = COALESCE($2, item_type),
+                    item_description = COALESCE($3, item_description),
+                    condition_notes = COALESCE($4, condition_notes),
+                    requested_work = COALESCE($5, requested_work),
+                    is_rush = COALESCE($6, is_rush),
+                    promise_date = CASE WHEN $7::boolean THEN $8 ELSE promise_date END,
+                    storage_location_id = COALESCE($9, storage_location_id),
+                    quote_amount = CASE WHEN $10::boolean THEN $11 ELSE quote_amount END,
+                    -- A changed quote has to go back to the customer
+                    quote_status = CASE
+                        WHEN $10::boolean AND $11 IS DISTINCT FROM quote_amount THEN 'draft'
+                        ELSE quote_status
+                    END,
+                    actual_amount = CASE WHEN $12::boolean THEN $13 ELSE actual_amount END,
+                    worked_by = CASE WHEN $14::boolean THEN $15 ELSE worked_by END,
+                    last_modified_by = COALESCE($16, last_modified_by),
+                    weight_grams = CASE WHEN $17::boolean THEN $18 ELSE weight_grams END,
+                    metal_type = CASE WHEN $19::boolean THEN $20 ELSE metal_type END,
+                    rush_surcharge = CASE WHEN $21::boolean THEN $22 ELSE rush_surcharge END,
+                    updated_at = NOW()
+                WHERE ticket_id = $1
+                RETURNING *
+            ),
+            -- Item 1 mirrors the ticket's item fields; a lone item also
+            -- carries the ticket's quote
+            synced AS (
+                UPDATE ticket_items i SET
+                    item_type = u.item_type,
+                    item_description = u.item_description,
+                    condition_notes = u.condition_notes,
+                    requested_work = u.requested_work,
+                    quote_amount = CASE
+                        WHEN EXISTS (
+                            SELECT 1 FROM ticket_items o
+                            WHERE o.ticket_id = u.ticket_id AND o.position > 1
+                        ) THEN i.quote_amount
+                        ELSE u.quote_amount
+                    END,
+                    updated_at = NOW()
+                FROM updated u
+                WHERE i.ticket_id = u.ticket_id AND i.position = 1
+            )
+            SELECT * FROM updated
             "#,
         )
         .bind(ticket_id)
@@ -208,7 +678,13 @@ impl TicketRepository {
         .bind(input.worked_by.is_some()) // $14: flag
         .bind(input.worked_by.flatten()) // $15: actual value
         .bind(input.last_modified_by)
-        .fetch_one(pool)
+        .bind(input.weight_grams.is_some()) // $17: flag
+        .bind(input.weight_grams.flatten()) // $18: actual value
+        .bind(input.metal_type.is_some()) // $19: flag
+        .bind(input.metal_type.flatten()) // $20: actual value
+        .bind(input.rush_surcharge.is_some()) // $21: flag
+        .bind(input.rush_surcharge.flatten()) // $22: actual value
+        .fetch_one(executor)
         .await?;
 
         Ok(ticket)
@@ -216,13 +692,20 @@ impl TicketRepository {
 
     /// List tickets with optional filters.
     ///
-    /// Returns summaries joined with customer name for display.
-    /// Default ordering: rush tickets first, then by created_at ascending (FIFO).
+    /// Returns summaries joined with customer name for display, in
+    /// `filters.order`: by default rush tickets first, then by created_at
+    /// ascending (FIFO).
     pub async fn list(
         pool: &PgPool,
         filters: TicketFilters,
     ) -> Result<Vec<TicketSummary>, AppError> {
-        let tickets = sqlx::query_as::<_, TicketSummary>(
+        // An empty status list means no status filter
+        let status_strings: Option<Vec<String>> = filters
+            .statuses
+            .filter(|statuses| !statuses.is_empty())
+            .map(|statuses| statuses.iter().map(Self::status_to_string).collect());
+
+        let sql = format!(
             r#"
             SELECT
                 t.ticket_id,
@@ -235,41 +718,46 @@ impl TicketRepository {
                 t.is_rush,
                 t.promise_date,
                 t.quote_amount,
-                t.created_at
+                t.created_at,
+                t.deleted_at
             FROM tickets t
             JOIN customers c ON t.customer_id = c.customer_id
-            WHERE t.deleted_at IS NULL
+            WHERE ($8 OR t.deleted_at IS NULL)
+              AND t.is_training = $9
               AND ($1::boolean IS NULL OR t.is_rush = $1)
               AND ($2::uuid IS NULL OR t.customer_id = $2)
               AND ($3::timestamptz IS NULL OR t.created_at >= $3)
               AND ($4::timestamptz IS NULL OR t.created_at <= $4)
-            ORDER BY t.is_rush DESC, t.created_at ASC
+              AND ($7::uuid IS NULL OR t.taken_in_by = $7 OR t.worked_by = $7)
+              AND ($10::text[] IS NULL OR t.status::text = ANY($10))
+              AND ($11::timestamptz IS NULL OR (t.created_at, t.ticket_id) > ($11, $12))
+              AND ($13::uuid IS NULL OR t.worked_by = $13)
+              AND ($14::uuid IS NULL OR t.taken_in_by = $14)
+              AND ($15::uuid IS NULL OR t.store_id = $15)
+            ORDER BY {}
             LIMIT $5
             OFFSET $6
             "#,
-        )
-        .bind(filters.is_rush)
-        .bind(filters.customer_id)
-        .bind(filters.created_after)
-        .bind(filters.created_before)
-        .bind(filters.limit.unwrap_or(100))
-        .bind(filters.offset.unwrap_or(0))
-        .fetch_all(pool)
-        .await?;
-
-        // Filter by status in application code if needed
-        let tickets = if let Some(ref statuses) = filters.statuses {
-            if !statuses.is_empty() {
-                tickets
-                    .into_iter()
-                    .filter(|t| statuses.contains(&t.status))
-                    .collect()
-            } else {
-                tickets
-            }
-        } else {
-            tickets
-        };
+            order_by_sql(filters.order, "t.is_rush DESC")
+        );
+        let tickets = sqlx::query_as::<_, TicketSummary>(&sql)
+            .bind(filters.is_rush)
+            .bind(filters.customer_id)
+            .bind(filters.created_after)
+            .bind(filters.created_before)
+            .bind(filters.limit.unwrap_or(100))
+            .bind(filters.offset.unwrap_or(0))
+            .bind(filters.visible_to)
+            .bind(filters.include_deleted)
+            .bind(filters.training)
+            .bind(&status_strings)
+            .bind(filters.after.map(|cursor| cursor.created_at))
+            .bind(filters.after.map(|cursor| cursor.ticket_id))
+            .bind(filters.worked_by)
+            .bind(filters.taken_in_by)
+            .bind(filters.store_id)
+            .fetch_all(pool)
+            .await?;
 
         Ok(tickets)
     }
@@ -333,23 +821,36 @@ impl TicketRepository {
         Ok(tickets)
     }
 
-    /// Count tickets matching the given filters (excludes soft-deleted tickets).
+    /// Count tickets matching the given filters.
+    ///
+    /// Soft-deleted tickets are excluded unless `include_deleted` is set.
     pub async fn count(pool: &PgPool, filters: TicketFilters) -> Result<i64, AppError> {
         let count = sqlx::query_scalar::<_, i64>(
             r#"
             SELECT COUNT(*)
             FROM tickets t
-            WHERE t.deleted_at IS NULL
+            WHERE ($6 OR t.deleted_at IS NULL)
+              AND t.is_training = $7
               AND ($1::boolean IS NULL OR t.is_rush = $1)
               AND ($2::uuid IS NULL OR t.customer_id = $2)
               AND ($3::timestamptz IS NULL OR t.created_at >= $3)
               AND ($4::timestamptz IS NULL OR t.created_at <= $4)
+              AND ($5::uuid IS NULL OR t.taken_in_by = $5 OR t.worked_by = $5)
+              AND ($8::uuid IS NULL OR t.worked_by = $8)
+              AND ($9::uuid IS NULL OR t.taken_in_by = $9)
+              AND ($10::uuid IS NULL OR t.store_id = $10)
             "#,
         )
         .bind(filters.is_rush)
         .bind(filters.customer_id)
         .bind(filters.created_after)
         .bind(filters.created_before)
+        .bind(filters.visible_to)
+        .bind(filters.include_deleted)
+        .bind(filters.training)
+        .bind(filters.worked_by)
+        .bind(filters.taken_in_by)
+        .bind(filters.store_id)
         .fetch_one(pool)
         .await?;
 
@@ -358,12 +859,19 @@ impl TicketRepository {
 
     /// Search tickets with full-text search across ticket, customer, and notes.
     ///
-    /// Searches across:
+    /// Matches the query against each ticket's `search_vector`, which covers:
     /// - Ticket: friendly_code, item_type, item_description, condition_notes, requested_work
-    /// - Customer: name, phone, email
+    /// - Customer: name, email
     /// - Notes: content
+    /// - Customer contacts: notes
     ///
-    /// Returns tickets matching the search query, sorted by relevance then date.
+    /// The query uses web search syntax (`"quoted phrase"`, `or`, `-exclude`).
+    /// Partial ticket codes and phone numbers are matched by substring.
+    /// Each kind of match is found separately so it can use its own index
+    /// (the search vector, or the code or phone trigrams).
+    ///
+    /// With the default order, returns exact ticket code matches first, then
+    /// by rank, rush status, and creation date.
     pub async fn search(
         pool: &PgPool,
         params: TicketSearchParams,
@@ -373,30 +881,40 @@ impl TicketRepository {
             .statuses
             .map(|statuses| statuses.iter().map(Self::status_to_string).collect());
 
-        // Use ILIKE for case-insensitive partial matching
-        // The % wildcards allow matching anywhere in the text
-        let search_pattern = format!("%{}%", params.query);
+        // Codes and phone numbers are matched anywhere in the text
+        let partial_pattern = contains_pattern(&params.query);
 
-        let tickets = sqlx::query_as::<_, QueueTicket>(
+        let sql = format!(
             r#"
-            WITH matching_tickets AS (
-                SELECT DISTINCT t.ticket_id
+            WITH search AS (
+                SELECT websearch_to_tsquery('english', $1)
+                    || websearch_to_tsquery('simple', $1) AS query
+            ),
+            candidates AS (
+                SELECT t.ticket_id
+                FROM tickets t, search s
+                WHERE t.search_vector @@ s.query
+                UNION
+                SELECT t.ticket_id
                 FROM tickets t
-                JOIN customers c ON t.customer_id = c.customer_id
-                LEFT JOIN ticket_notes n ON t.ticket_id = n.ticket_id
-                WHERE t.deleted_at IS NULL
-                AND (
-                    t.friendly_code ILIKE $1
-                    OR t.item_type ILIKE $1
-                    OR t.item_description ILIKE $1
-                    OR t.condition_notes ILIKE $1
-                    OR t.requested_work ILIKE $1
-                    OR c.name ILIKE $1
-                    OR c.phone ILIKE $1
-                    OR c.email ILIKE $1
-                    OR n.content ILIKE $1
-                )
+                WHERE t.friendly_code ILIKE $8
+                UNION
+                SELECT t.ticket_id
+                FROM customers c
+                JOIN tickets t ON t.customer_id = c.customer_id
+                WHERE c.phone ILIKE $8
+            ),
+            matching_tickets AS (
+                SELECT t.ticket_id, ts_rank(t.search_vector, s.query) AS rank
+                FROM candidates m
+                JOIN tickets t ON t.ticket_id = m.ticket_id
+                CROSS JOIN search s
+                WHERE ($6 OR t.deleted_at IS NULL)
+                AND t.is_training = $7
                 AND ($2::text[] IS NULL OR t.status::text = ANY($2))
+                AND ($5::uuid IS NULL OR t.taken_in_by = $5 OR t.worked_by = $5)
+                AND ($9::timestamptz IS NULL OR (t.created_at, t.ticket_id) > ($9, $10))
+                AND ($11::uuid IS NULL OR t.store_id = $11)
             )
             SELECT
                 t.ticket_id,
@@ -410,33 +928,43 @@ impl TicketRepository {
                 t.promise_date,
                 t.quote_amount,
                 t.created_at,
+                t.queue_position,
                 CASE
                     WHEN t.promise_date IS NOT NULL
                      AND t.promise_date < CURRENT_DATE
                      AND t.status NOT IN ('closed', 'archived')
                     THEN TRUE
                     ELSE FALSE
-                END as is_overdue
-            FROM tickets t
+                END as is_overdue,
+                t.deleted_at
+            FROM matching_tickets m
+            JOIN tickets t ON t.ticket_id = m.ticket_id
             JOIN customers c ON t.customer_id = c.customer_id
-            WHERE t.ticket_id IN (SELECT ticket_id FROM matching_tickets)
-            ORDER BY
-                -- Prioritize exact friendly_code matches
-                CASE WHEN t.friendly_code ILIKE $1 THEN 0 ELSE 1 END,
-                -- Then by rush status
-                t.is_rush DESC,
-                -- Then by creation date (FIFO)
-                t.created_at ASC
+            ORDER BY {}
             LIMIT $3
             OFFSET $4
             "#,
-        )
-        .bind(&search_pattern)
-        .bind(&status_strings)
-        .bind(params.limit.unwrap_or(100))
-        .bind(params.offset.unwrap_or(0))
-        .fetch_all(pool)
-        .await?;
+            // By default exact friendly_code matches come first, then the
+            // best text matches, then rush tickets
+            order_by_sql(
+                params.order,
+                "CASE WHEN LOWER(t.friendly_code) = LOWER($1) THEN 0 ELSE 1 END, m.rank DESC, t.is_rush DESC",
+            )
+        );
+        let tickets = sqlx::query_as::<_, QueueTicket>(&sql)
+            .bind(&params.query)
+            .bind(&status_strings)
+            .bind(params.limit.unwrap_or(100))
+            .bind(params.offset.unwrap_or(0))
+            .bind(params.visible_to)
+            .bind(params.include_deleted)
+            .bind(params.training)
+            .bind(&partial_pattern)
+            .bind(params.after.map(|cursor| cursor.created_at))
+            .bind(params.after.map(|cursor| cursor.ticket_id))
+            .bind(params.store_id)
+            .fetch_all(pool)
+            .await?;
 
         Ok(tickets)
     }
@@ -445,9 +973,21 @@ impl TicketRepository {
     ///
     /// Returns only active tickets (not closed/archived), sorted within each lane
     /// by rush first, then FIFO (created_at ascending).
+    ///
+    /// If `store_id` is set, only tickets held at that store are included. If
+    /// `visible_to` is set, only tickets that employee took in or is
+    /// assigned to are included; if `worked_by` is set, only tickets assigned
+    /// to that employee. Soft-deleted tickets are excluded unless
+    /// `include_deleted` is set. `training` selects training tickets instead
+    /// of real ones.
     pub async fn get_queue(
         pool: &PgPool,
         limit_per_lane: Option<i64>,
+        store_id: Option<Uuid>,
+        visible_to: Option<Uuid>,
+        worked_by: Option<Uuid>,
+        include_deleted: bool,
+        training: bool,
     ) -> Result<WorkboardQueue, AppError> {
         // Fetch all active tickets in a single query, then group in memory
         // This is efficient for typical workloads (~30 tickets/day)
@@ -465,20 +1005,31 @@ impl TicketRepository {
                 t.promise_date,
                 t.quote_amount,
                 t.created_at,
+                t.queue_position,
                 CASE
                     WHEN t.promise_date IS NOT NULL
                      AND t.promise_date < CURRENT_DATE
                      AND t.status NOT IN ('closed', 'archived')
                     THEN TRUE
                     ELSE FALSE
-                END as is_overdue
+                END as is_overdue,
+                t.deleted_at
             FROM tickets t
             JOIN customers c ON t.customer_id = c.customer_id
-            WHERE t.deleted_at IS NULL
+            WHERE ($2 OR t.deleted_at IS NULL)
+              AND t.is_training = $3
               AND t.status NOT IN ('closed', 'archived')
-            ORDER BY t.is_rush DESC, t.created_at ASC
+              AND ($1::uuid IS NULL OR t.taken_in_by = $1 OR t.worked_by = $1)
+              AND ($4::uuid IS NULL OR t.worked_by = $4)
+              AND ($5::uuid IS NULL OR t.store_id = $5)
+            ORDER BY t.is_rush DESC, t.queue_position ASC NULLS LAST, t.created_at ASC
             "#,
         )
+        .bind(visible_to)
+        .bind(include_deleted)
+        .bind(training)
+        .bind(worked_by)
+        .bind(store_id)
         .fetch_all(pool)
         .await?;
 
@@ -527,8 +1078,8 @@ impl TicketRepository {
 
     /// Get tickets by status for a single lane.
     ///
-    /// Returns tickets for the specified status, sorted by rush first, then FIFO.
-    /// Includes overdue calculation.
+    /// Returns tickets for the specified status, sorted by rush first, then
+    /// manual queue position, then FIFO. Includes overdue calculation.
     pub async fn get_lane(
         pool: &PgPool,
         status: TicketStatus,
@@ -550,6 +1101,7 @@ impl TicketRepository {
                 t.promise_date,
                 t.quote_amount,
                 t.created_at,
+                t.queue_position,
                 CASE
                     WHEN t.promise_date IS NOT NULL
                      AND t.promise_date < CURRENT_DATE
@@ -561,7 +1113,7 @@ impl TicketRepository {
             JOIN customers c ON t.customer_id = c.customer_id
             WHERE t.deleted_at IS NULL
               AND t.status::text = $1
-            ORDER BY t.is_rush DESC, t.created_at ASC
+            ORDER BY t.is_rush DESC, t.queue_position ASC NULLS LAST, t.created_at ASC
             LIMIT $2
             "#,
         )
@@ -573,6 +1125,56 @@ impl TicketRepository {
         Ok(tickets)
     }
 
+    /// IDs of a lane's tickets at a store in queue order: rush first, then
+    /// manual queue position, then oldest first.
+    pub async fn lane_order(
+        pool: &PgPool,
+        store_id: Uuid,
+        status: TicketStatus,
+        training: bool,
+    ) -> Result<Vec<Uuid>, AppError> {
+        let ids = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT ticket_id FROM tickets
+            WHERE deleted_at IS NULL
+              AND status = $1
+              AND is_training = $2
+              AND store_id = $3
+            ORDER BY is_rush DESC, queue_position ASC NULLS LAST, created_at ASC
+            "#,
+        )
+        .bind(status)
+        .bind(training)
+        .bind(store_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(ids)
+    }
+
+    /// Number a lane's tickets 1, 2, 3... in the given order.
+    ///
+    /// Tickets that have since left the lane are skipped.
+    pub async fn set_queue_positions(
+        pool: &PgPool,
+        status: TicketStatus,
+        ticket_ids: &[Uuid],
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE tickets t SET queue_position = o.position
+            FROM UNNEST($2::uuid[]) WITH ORDINALITY AS o(ticket_id, position)
+            WHERE t.ticket_id = o.ticket_id AND t.status = $1
+            "#,
+        )
+        .bind(status)
+        .bind(ticket_ids)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
     /// Toggle the rush flag on a ticket.
     ///
     /// Updates the is_rush field and the last_modified_by attribution.
@@ -601,6 +1203,32 @@ impl TicketRepository {
         Ok(ticket)
     }
 
+    /// Set or clear who is working on a ticket.
+    pub async fn set_worked_by(
+        pool: &PgPool,
+        ticket_id: Uuid,
+        worked_by: Option<Uuid>,
+        modified_by: Uuid,
+    ) -> Result<Ticket, AppError> {
+        let ticket = sqlx::query_as::<_, Ticket>(
+            r#"
+            UPDATE tickets SET
+                worked_by = $2,
+                last_modified_by = $3,
+                updated_at = NOW()
+            WHERE ticket_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(worked_by)
+        .bind(modified_by)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(ticket)
+    }
+
     /// Soft-delete a ticket.
     ///
     /// Sets deleted_at and deleted_by fields. The ticket remains in the database
@@ -665,11 +1293,18 @@ impl TicketRepository {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::ticket::TicketSort;
 
     // Note: Integration tests requiring a database connection should be run
     // with the test database available. Unit tests here focus on logic that
     // doesn't require database access.
 
+    #[test]
+    fn test_contains_pattern_escapes_wildcards() {
+        assert_eq!(contains_pattern("JR-0042"), "%JR-0042%");
+        assert_eq!(contains_pattern("50%_off\\"), "%50\\%\\_off\\\\%");
+    }
+
     #[test]
     fn test_ticket_filters_default() {
         let filters = TicketFilters::default();
@@ -718,6 +1353,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_order_by_sql() {
+        assert_eq!(
+            order_by_sql(TicketOrder::Priority, "t.is_rush DESC"),
+            "t.is_rush DESC, t.created_at ASC, t.ticket_id ASC"
+        );
+        assert_eq!(
+            order_by_sql(TicketOrder::Created, "t.is_rush DESC"),
+            "t.created_at ASC, t.ticket_id ASC"
+        );
+        let sort = TicketSort::parse("-customer_name").unwrap();
+        assert_eq!(
+            order_by_sql(TicketOrder::Sorted(sort), "t.is_rush DESC"),
+            "LOWER(c.name) DESC NULLS LAST, t.created_at ASC, t.ticket_id ASC"
+        );
+    }
+
     #[test]
     fn test_ticket_search_params() {
         let params = TicketSearchParams {
@@ -725,6 +1377,12 @@ mod tests {
             statuses: Some(vec![TicketStatus::Intake, TicketStatus::InProgress]),
             limit: Some(50),
             offset: Some(10),
+            order: TicketOrder::Priority,
+            after: None,
+            visible_to: None,
+            store_id: None,
+            include_deleted: false,
+            training: false,
         };
         assert_eq!(params.query, "test");
         assert_eq!(params.statuses.as_ref().unwrap().len(), 2);
@@ -739,6 +1397,12 @@ mod tests {
             statuses: None,
             limit: None,
             offset: None,
+            order: TicketOrder::Priority,
+            after: None,
+            visible_to: None,
+            store_id: None,
+            include_deleted: false,
+            training: false,
         };
         assert_eq!(params.query, "ring");
         assert!(params.statuses.is_none());
@@ -0,0 +1,38 @@
+//! Ticket item repository for database operations.
+//!
+//! Items are written with their ticket by [`TicketRepository::create`], and
+//! item 1 is kept in step with ticket edits by [`TicketRepository::update`].
+//!
+//! [`TicketRepository::create`]: crate::repositories::TicketRepository::create
+//! [`TicketRepository::update`]: crate::repositories::TicketRepository::update
+
+use crate::error::AppError;
+use crate::models::ticket_item::TicketItem;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Repository for ticket item database operations.
+pub struct TicketItemRepository;
+
+impl TicketItemRepository {
+    /// Find all items on a ticket, in position order.
+    pub async fn find_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketItem>, AppError> {
+        let items = sqlx::query_as::<_, TicketItem>(
+            r#"
+            SELECT item_id, ticket_id, position, item_type, item_description,
+                   condition_notes, requested_work, quote_amount, created_at, updated_at
+            FROM ticket_items
+            WHERE ticket_id = $1
+            ORDER BY position ASC
+            "#,
+        )
+        .bind(ticket_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(items)
+    }
+}
@@ -4,7 +4,7 @@
 
 use crate::error::AppError;
 use crate::models::ticket_note::{CreateTicketNote, TicketNote};
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 /// Repository for ticket note database operations.
@@ -14,7 +14,10 @@ impl TicketNoteRepository {
     /// Create a new ticket note.
     ///
     /// Notes are append-only, so there is no update or delete.
-    pub async fn create(pool: &PgPool, input: CreateTicketNote) -> Result<TicketNote, AppError> {
+    pub async fn create(
+        executor: impl PgExecutor<'_>,
+        input: CreateTicketNote,
+    ) -> Result<TicketNote, AppError> {
         let note = sqlx::query_as::<_, TicketNote>(
             r#"
             INSERT INTO ticket_notes (ticket_id, content, created_by)
@@ -25,7 +28,7 @@ impl TicketNoteRepository {
         .bind(input.ticket_id)
         .bind(input.content)
         .bind(input.created_by)
-        .fetch_one(pool)
+        .fetch_one(executor)
         .await?;
 
         Ok(note)
@@ -87,6 +87,27 @@ impl TicketPhotoRepository {
         Ok(count.0)
     }
 
+    /// Of the given storage keys, those no photo record points at.
+    pub async fn find_untracked_keys(
+        pool: &PgPool,
+        storage_keys: &[String],
+    ) -> Result<Vec<String>, AppError> {
+        let keys = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT k.storage_key
+            FROM UNNEST($1::text[]) AS k(storage_key)
+            WHERE NOT EXISTS (
+                SELECT 1 FROM ticket_photos p WHERE p.storage_key = k.storage_key
+            )
+            "#,
+        )
+        .bind(storage_keys)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(keys)
+    }
+
     /// Delete a photo by ID.
     ///
     /// Note: The caller should also delete the file from S3 storage.
@@ -0,0 +1,122 @@
+//! Bench time entry repository for database operations.
+
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::time_entry::{TimeEntry, TimeEntryEntry};
+
+/// Columns selected for [`TimeEntryEntry`], joined with names.
+const ENTRY_SELECT: &str = r#"
+    SELECT
+        x.entry_id,
+        x.ticket_id,
+        t.friendly_code,
+        x.employee_id,
+        e.name AS employee_name,
+        x.started_at,
+        x.stopped_at
+    FROM time_entries x
+    JOIN tickets t ON t.ticket_id = x.ticket_id
+    JOIN employees e ON e.employee_id = x.employee_id
+"#;
+
+/// Repository for bench time entry operations.
+pub struct TimeEntryRepository;
+
+impl TimeEntryRepository {
+    /// Clock an employee in on a ticket.
+    ///
+    /// Fails with a conflict if the employee already has a running entry.
+    pub async fn start(
+        pool: &PgPool,
+        ticket_id: Uuid,
+        employee_id: Uuid,
+    ) -> Result<TimeEntry, AppError> {
+        let entry = sqlx::query_as::<_, TimeEntry>(
+            r#"
+            INSERT INTO time_entries (ticket_id, employee_id)
+            VALUES ($1, $2)
+            RETURNING *
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(employee_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    /// Clock an employee out of a ticket. Returns None if they weren't
+    /// clocked in on it.
+    pub async fn stop(
+        pool: &PgPool,
+        ticket_id: Uuid,
+        employee_id: Uuid,
+    ) -> Result<Option<TimeEntry>, AppError> {
+        let entry = sqlx::query_as::<_, TimeEntry>(
+            r#"
+            UPDATE time_entries
+            SET stopped_at = NOW()
+            WHERE ticket_id = $1 AND employee_id = $2 AND stopped_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(ticket_id)
+        .bind(employee_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    /// Clock everyone still working on a ticket out of it.
+    pub async fn stop_all_for_ticket(
+        executor: impl PgExecutor<'_>,
+        ticket_id: Uuid,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            "UPDATE time_entries SET stopped_at = NOW() WHERE ticket_id = $1 AND stopped_at IS NULL",
+        )
+        .bind(ticket_id)
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Find an employee's running entry with names, on any ticket.
+    pub async fn find_running_by_employee_id(
+        pool: &PgPool,
+        employee_id: Uuid,
+    ) -> Result<Option<TimeEntryEntry>, AppError> {
+        let query = format!(
+            "{} WHERE x.employee_id = $1 AND x.stopped_at IS NULL",
+            ENTRY_SELECT
+        );
+        let entry = sqlx::query_as::<_, TimeEntryEntry>(&query)
+            .bind(employee_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(entry)
+    }
+
+    /// Find a ticket's time entries with names, oldest first.
+    pub async fn find_entries_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TimeEntryEntry>, AppError> {
+        let query = format!(
+            "{} WHERE x.ticket_id = $1 ORDER BY x.started_at ASC",
+            ENTRY_SELECT
+        );
+        let entries = sqlx::query_as::<_, TimeEntryEntry>(&query)
+            .bind(ticket_id)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(entries)
+    }
+}
@@ -0,0 +1,230 @@
+//! Ticket transfer repository for database operations.
+
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::transfer::{
+    CreateTicketTransfer, TicketTransfer, TicketTransferEntry, TransferStatus,
+};
+
+/// Columns selected for [`TicketTransferEntry`], joined with names.
+const ENTRY_SELECT: &str = r#"
+    SELECT
+        x.transfer_id,
+        x.ticket_id,
+        t.friendly_code,
+        x.from_location_id,
+        fl.name AS from_location_name,
+        x.to_location_id,
+        tl.name AS to_location_name,
+        x.status,
+        x.requested_by,
+        r.name AS requested_by_name,
+        x.notes,
+        x.requested_at,
+        x.resolved_by,
+        v.name AS resolved_by_name,
+        x.resolved_at
+    FROM ticket_transfers x
+    JOIN tickets t ON t.ticket_id = x.ticket_id
+    JOIN storage_locations fl ON fl.location_id = x.from_location_id
+    JOIN storage_locations tl ON tl.location_id = x.to_location_id
+    JOIN employees r ON r.employee_id = x.requested_by
+    LEFT JOIN employees v ON v.employee_id = x.resolved_by
+"#;
+
+/// Repository for ticket transfer operations.
+pub struct TransferRepository;
+
+impl TransferRepository {
+    /// Start a transfer. The ticket stays at its current location until the
+    /// transfer is accepted.
+    pub async fn create(
+        pool: &PgPool,
+        input: CreateTicketTransfer,
+    ) -> Result<TicketTransfer, AppError> {
+        let transfer = sqlx::query_as::<_, TicketTransfer>(
+            r#"
+            INSERT INTO ticket_transfers (
+                ticket_id, from_location_id, to_location_id, requested_by, notes
+            )
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(input.ticket_id)
+        .bind(input.from_location_id)
+        .bind(input.to_location_id)
+        .bind(input.requested_by)
+        .bind(&input.notes)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(transfer)
+    }
+
+    /// Find the transfer a ticket is currently in transit on, if any.
+    pub async fn find_pending_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Option<TicketTransfer>, AppError> {
+        let transfer = sqlx::query_as::<_, TicketTransfer>(
+            "SELECT * FROM ticket_transfers WHERE ticket_id = $1 AND status = 'pending'",
+        )
+        .bind(ticket_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(transfer)
+    }
+
+    /// Find a ticket's transfer history with names, oldest first.
+    pub async fn find_entries_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Vec<TicketTransferEntry>, AppError> {
+        let query = format!(
+            "{} WHERE x.ticket_id = $1 ORDER BY x.requested_at ASC",
+            ENTRY_SELECT
+        );
+        let entries = sqlx::query_as::<_, TicketTransferEntry>(&query)
+            .bind(ticket_id)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(entries)
+    }
+
+    /// Find the pending transfer for a ticket with names, if any.
+    pub async fn find_pending_entry_by_ticket_id(
+        pool: &PgPool,
+        ticket_id: Uuid,
+    ) -> Result<Option<TicketTransferEntry>, AppError> {
+        let query = format!(
+            "{} WHERE x.ticket_id = $1 AND x.status = 'pending'",
+            ENTRY_SELECT
+        );
+        let entry = sqlx::query_as::<_, TicketTransferEntry>(&query)
+            .bind(ticket_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(entry)
+    }
+
+    /// List transfers, optionally filtered by status and destination.
+    ///
+    /// Oldest first, so the receiving location works through the longest
+    /// waiting items first. Deleted tickets are excluded.
+    pub async fn list_entries(
+        pool: &PgPool,
+        status: Option<TransferStatus>,
+        to_location_id: Option<Uuid>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<TicketTransferEntry>, AppError> {
+        let query = format!(
+            r#"{}
+            WHERE t.deleted_at IS NULL
+              AND ($1::transfer_status IS NULL OR x.status = $1)
+              AND ($2::UUID IS NULL OR x.to_location_id = $2)
+            ORDER BY x.requested_at ASC
+            LIMIT $3 OFFSET $4
+            "#,
+            ENTRY_SELECT
+        );
+        let entries = sqlx::query_as::<_, TicketTransferEntry>(&query)
+            .bind(status)
+            .bind(to_location_id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(pool)
+            .await?;
+
+        Ok(entries)
+    }
+
+    /// Accept a pending transfer, moving the ticket to the destination.
+    ///
+    /// Updates the transfer, the ticket's storage location, and its field
+    /// history in a single transaction. Returns None if the transfer is no
+    /// longer pending.
+    pub async fn accept(
+        pool: &PgPool,
+        transfer_id: Uuid,
+        accepted_by: Uuid,
+    ) -> Result<Option<TicketTransfer>, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let Some(transfer) = sqlx::query_as::<_, TicketTransfer>(
+            r#"
+            UPDATE ticket_transfers
+            SET status = 'accepted', resolved_by = $2, resolved_at = NOW()
+            WHERE transfer_id = $1 AND status = 'pending'
+            RETURNING *
+            "#,
+        )
+        .bind(transfer_id)
+        .bind(accepted_by)
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE tickets
+            SET storage_location_id = $2, last_modified_by = $3, updated_at = NOW()
+            WHERE ticket_id = $1
+            "#,
+        )
+        .bind(transfer.ticket_id)
+        .bind(transfer.to_location_id)
+        .bind(accepted_by)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO ticket_field_history (ticket_id, field_name, old_value, new_value, changed_by)
+            VALUES ($1, 'storage_location_id', $2::TEXT, $3::TEXT, $4)
+            "#,
+        )
+        .bind(transfer.ticket_id)
+        .bind(transfer.from_location_id)
+        .bind(transfer.to_location_id)
+        .bind(accepted_by)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(transfer))
+    }
+
+    /// Cancel a pending transfer. The ticket stays at its origin.
+    ///
+    /// Returns None if the transfer is no longer pending.
+    pub async fn cancel(
+        pool: &PgPool,
+        transfer_id: Uuid,
+        cancelled_by: Uuid,
+    ) -> Result<Option<TicketTransfer>, AppError> {
+        let transfer = sqlx::query_as::<_, TicketTransfer>(
+            r#"
+            UPDATE ticket_transfers
+            SET status = 'cancelled', resolved_by = $2, resolved_at = NOW()
+            WHERE transfer_id = $1 AND status = 'pending'
+            RETURNING *
+            "#,
+        )
+        .bind(transfer_id)
+        .bind(cancelled_by)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(transfer)
+    }
+}
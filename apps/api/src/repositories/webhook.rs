@@ -0,0 +1,328 @@
+//! Webhook repository for database operations.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::Utc;
+use rand::RngCore;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::webhook::{
+    webhook_retry_delay, CreateWebhook, UpdateWebhook, Webhook, WebhookDelivery,
+    WebhookDeliveryStatus, WebhookDispatch, WebhookEvent, MAX_WEBHOOK_ATTEMPTS,
+};
+
+/// Prefix marking a string as a webhook signing secret.
+const SECRET_PREFIX: &str = "whsec_";
+
+/// How long a claimed delivery is held before another worker may retry it,
+/// in case the worker sending it dies mid-request.
+const CLAIM_LEASE_SECS: i64 = 5 * 60;
+
+/// Repository for webhook database operations.
+pub struct WebhookRepository;
+
+impl WebhookRepository {
+    /// Generate a webhook signing secret.
+    ///
+    /// Creates a 256-bit random secret encoded as base64url (no padding),
+    /// prefixed with `whsec_`.
+    pub fn generate_secret() -> String {
+        let mut secret_bytes = [0u8; 32]; // 256 bits
+        rand::thread_rng().fill_bytes(&mut secret_bytes);
+        format!("{}{}", SECRET_PREFIX, URL_SAFE_NO_PAD.encode(secret_bytes))
+    }
+
+    /// Register a webhook.
+    pub async fn create(pool: &PgPool, input: CreateWebhook) -> Result<Webhook, AppError> {
+        let webhook = sqlx::query_as::<_, Webhook>(
+            r#"
+            INSERT INTO webhooks (url, description, events, secret)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(&input.url)
+        .bind(&input.description)
+        .bind(&input.events)
+        .bind(&input.secret)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(webhook)
+    }
+
+    /// Find a webhook by ID.
+    pub async fn find_by_id(pool: &PgPool, webhook_id: Uuid) -> Result<Option<Webhook>, AppError> {
+        let webhook = sqlx::query_as::<_, Webhook>("SELECT * FROM webhooks WHERE webhook_id = $1")
+            .bind(webhook_id)
+            .fetch_optional(pool)
+            .await?;
+
+        Ok(webhook)
+    }
+
+    /// List all webhooks, oldest first.
+    pub async fn list(pool: &PgPool) -> Result<Vec<Webhook>, AppError> {
+        let webhooks =
+            sqlx::query_as::<_, Webhook>("SELECT * FROM webhooks ORDER BY created_at ASC")
+                .fetch_all(pool)
+                .await?;
+
+        Ok(webhooks)
+    }
+
+    /// Update a webhook.
+    ///
+    /// Returns None if the webhook doesn't exist.
+    pub async fn update(
+        pool: &PgPool,
+        webhook_id: Uuid,
+        input: UpdateWebhook,
+    ) -> Result<Option<Webhook>, AppError> {
+        let Some(existing) = Self::find_by_id(pool, webhook_id).await? else {
+            return Ok(None);
+        };
+
+        let webhook = sqlx::query_as::<_, Webhook>(
+            r#"
+            UPDATE webhooks
+            SET url = $1,
+                description = $2,
+                events = $3,
+                is_active = $4,
+                updated_at = NOW()
+            WHERE webhook_id = $5
+            RETURNING *
+            "#,
+        )
+        .bind(input.url.unwrap_or(existing.url))
+        .bind(input.description.unwrap_or(existing.description))
+        .bind(input.events.unwrap_or(existing.events))
+        .bind(input.is_active.unwrap_or(existing.is_active))
+        .bind(webhook_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(Some(webhook))
+    }
+
+    /// Replace a webhook's signing secret.
+    pub async fn set_secret(
+        pool: &PgPool,
+        webhook_id: Uuid,
+        secret: &str,
+    ) -> Result<Webhook, AppError> {
+        let webhook = sqlx::query_as::<_, Webhook>(
+            r#"
+            UPDATE webhooks
+            SET secret = $1, updated_at = NOW()
+            WHERE webhook_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(secret)
+        .bind(webhook_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(webhook)
+    }
+
+    /// Delete a webhook and its deliveries.
+    ///
+    /// Returns false if the webhook doesn't exist.
+    pub async fn delete(pool: &PgPool, webhook_id: Uuid) -> Result<bool, AppError> {
+        let result = sqlx::query("DELETE FROM webhooks WHERE webhook_id = $1")
+            .bind(webhook_id)
+            .execute(pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Queue an event for every active webhook subscribed to it.
+    ///
+    /// Returns how many deliveries were queued.
+    pub async fn enqueue(
+        pool: &PgPool,
+        event: WebhookEvent,
+        data: &serde_json::Value,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries (webhook_id, event, payload)
+            SELECT webhook_id, $1, $2
+            FROM webhooks
+            WHERE is_active = TRUE AND $1 = ANY(events)
+            "#,
+        )
+        .bind(event)
+        .bind(data)
+        .execute(pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Claim up to `limit` pending deliveries that are due.
+    ///
+    /// Each claimed delivery has its attempt counted and is held for a lease
+    /// period, so concurrent workers don't send it twice.
+    pub async fn claim_due(pool: &PgPool, limit: i64) -> Result<Vec<WebhookDispatch>, AppError> {
+        let dispatches = sqlx::query_as::<_, WebhookDispatch>(
+            r#"
+            WITH due AS (
+                SELECT d.delivery_id
+                FROM webhook_deliveries d
+                JOIN webhooks w ON w.webhook_id = d.webhook_id
+                WHERE d.status = 'pending'
+                  AND d.next_attempt_at <= NOW()
+                  AND w.is_active = TRUE
+                ORDER BY d.next_attempt_at ASC
+                LIMIT $1
+                FOR UPDATE OF d SKIP LOCKED
+            )
+            UPDATE webhook_deliveries d
+            SET attempts = d.attempts + 1,
+                next_attempt_at = NOW() + make_interval(secs => $2),
+                last_attempt_at = NOW()
+            FROM due, webhooks w
+            WHERE d.delivery_id = due.delivery_id AND w.webhook_id = d.webhook_id
+            RETURNING d.*, w.url, w.secret
+            "#,
+        )
+        .bind(limit)
+        .bind(CLAIM_LEASE_SECS as f64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(dispatches)
+    }
+
+    /// Record that the receiver accepted a delivery.
+    pub async fn mark_delivered(
+        pool: &PgPool,
+        delivery_id: Uuid,
+        response_status: i32,
+        response_body: Option<&str>,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $1, response_status = $2, response_body = $3, last_error = NULL,
+                delivered_at = NOW()
+            WHERE delivery_id = $4
+            "#,
+        )
+        .bind(WebhookDeliveryStatus::Delivered)
+        .bind(response_status)
+        .bind(response_body)
+        .bind(delivery_id)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Record a failed attempt, scheduling a retry or giving up after the
+    /// last attempt.
+    pub async fn mark_attempt_failed(
+        pool: &PgPool,
+        delivery: &WebhookDelivery,
+        response_status: Option<i32>,
+        response_body: Option<&str>,
+        error: &str,
+    ) -> Result<(), AppError> {
+        let status = if delivery.attempts >= MAX_WEBHOOK_ATTEMPTS {
+            WebhookDeliveryStatus::Failed
+        } else {
+            WebhookDeliveryStatus::Pending
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $1, response_status = $2, response_body = $3, last_error = $4,
+                next_attempt_at = $5
+            WHERE delivery_id = $6
+            "#,
+        )
+        .bind(status)
+        .bind(response_status)
+        .bind(response_body)
+        .bind(error)
+        .bind(Utc::now() + webhook_retry_delay(delivery.attempts))
+        .bind(delivery.delivery_id)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// List a webhook's deliveries, newest first.
+    pub async fn list_deliveries(
+        pool: &PgPool,
+        webhook_id: Uuid,
+        status: Option<WebhookDeliveryStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<WebhookDelivery>, AppError> {
+        let deliveries = sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE webhook_id = $1
+              AND ($2::webhook_delivery_status IS NULL OR status = $2)
+            ORDER BY created_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(status)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(deliveries)
+    }
+
+    /// Find one of a webhook's deliveries.
+    pub async fn find_delivery(
+        pool: &PgPool,
+        webhook_id: Uuid,
+        delivery_id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, AppError> {
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(
+            "SELECT * FROM webhook_deliveries WHERE webhook_id = $1 AND delivery_id = $2",
+        )
+        .bind(webhook_id)
+        .bind(delivery_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(delivery)
+    }
+
+    /// Queue a finished delivery to be sent again, with a fresh set of attempts.
+    ///
+    /// Returns None if the delivery is already pending.
+    pub async fn redeliver(
+        pool: &PgPool,
+        delivery_id: Uuid,
+    ) -> Result<Option<WebhookDelivery>, AppError> {
+        let delivery = sqlx::query_as::<_, WebhookDelivery>(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = 'pending', attempts = 0, next_attempt_at = NOW(), delivered_at = NULL
+            WHERE delivery_id = $1 AND status <> 'pending'
+            RETURNING *
+            "#,
+        )
+        .bind(delivery_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(delivery)
+    }
+}
@@ -5,11 +5,68 @@
 //!
 //! Success: `{ "data": { ... }, "error": null }`
 //! Error: `{ "data": null, "error": { "code": "...", "message": "..." } }`
+//!
+//! Successful responses may also carry a `warnings` array for conditions
+//! that should be confirmed by the user but don't block the request.
+//! A `meta` block (server time, duration, request ID, deprecations, training
+//! mode) is added
+//! to every envelope by the `response_meta` middleware.
 
 use axum::{http::StatusCode, response::IntoResponse, Json};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::Serialize;
 
 use crate::error::{AppError, ErrorDetail};
+use crate::i18n::localize;
+use crate::middleware::RequestId;
+
+/// A non-blocking validation warning.
+///
+/// Warnings describe conditions the UI should confirm with the user
+/// (e.g., a promise date on a Sunday) without failing the request.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ApiWarning {
+    pub code: &'static str,
+    pub message: String,
+    /// The request field the warning relates to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<&'static str>,
+}
+
+impl ApiWarning {
+    /// Create a warning for a specific request field.
+    pub fn for_field(code: &'static str, field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: localize(message.into()),
+            field: Some(field),
+        }
+    }
+}
+
+/// A notice that the endpoint (or something the request used) is deprecated.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeprecationNotice {
+    pub message: String,
+    /// Planned removal date, if scheduled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sunset: Option<NaiveDate>,
+}
+
+/// Request metadata attached to every response envelope.
+#[derive(Debug, Clone, Serialize)]
+pub struct ResponseMeta {
+    pub server_time: DateTime<Utc>,
+    /// Time spent processing the request, in milliseconds.
+    pub duration_ms: u64,
+    pub request_id: String,
+    /// Deprecation notices for this request (omitted when empty).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deprecations: Vec<DeprecationNotice>,
+    /// Set when the request ran against training data (omitted otherwise).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub training_mode: bool,
+}
 
 /// Standard API response wrapper.
 ///
@@ -19,6 +76,12 @@ use crate::error::{AppError, ErrorDetail};
 pub struct ApiResponse<T: Serialize> {
     pub data: Option<T>,
     pub error: Option<ErrorDetail>,
+    /// Non-blocking warnings (omitted when empty).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ApiWarning>,
+    /// Request metadata, filled in by the `response_meta` middleware.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<ResponseMeta>,
 }
 
 impl<T: Serialize> ApiResponse<T> {
@@ -27,8 +90,16 @@ impl<T: Serialize> ApiResponse<T> {
         ApiResponse {
             data: Some(data),
             error: None,
+            warnings: Vec::new(),
+            meta: None,
         }
     }
+
+    /// Attach non-blocking warnings to the response.
+    pub fn with_warnings(mut self, warnings: Vec<ApiWarning>) -> Self {
+        self.warnings.extend(warnings);
+        self
+    }
 }
 
 impl ApiResponse<()> {
@@ -42,7 +113,10 @@ impl ApiResponse<()> {
             error: Some(ErrorDetail {
                 code,
                 message: message.into(),
+                request_id: RequestId::current().map(|id| id.0),
             }),
+            warnings: Vec::new(),
+            meta: None,
         }
     }
 }
@@ -137,6 +211,28 @@ mod tests {
         assert!(json.contains(r#""message":"Invalid input""#));
     }
 
+    #[test]
+    fn test_warnings_omitted_when_empty() {
+        let response = ApiResponse::success("ok");
+        let json = serde_json::to_string(&response).unwrap();
+        assert!(!json.contains("warnings"));
+    }
+
+    #[test]
+    fn test_warnings_serialization() {
+        let response = ApiResponse::success("ok").with_warnings(vec![ApiWarning::for_field(
+            "PROMISE_DATE_SUNDAY",
+            "promise_date",
+            "Promise date falls on a Sunday",
+        )]);
+        let json = serde_json::to_string(&response).unwrap();
+
+        assert!(json.contains(r#""warnings":[{"#));
+        assert!(json.contains(r#""code":"PROMISE_DATE_SUNDAY""#));
+        assert!(json.contains(r#""field":"promise_date""#));
+        assert!(json.contains(r#""error":null"#));
+    }
+
     #[test]
     fn test_empty_success() {
         let result = empty();
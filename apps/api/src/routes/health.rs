@@ -1,32 +1,231 @@
-//! Health check endpoint.
+//! Health check endpoints.
+//!
+//! - `/health/live` only shows the process answers; for liveness probes
+//! - `/health` adds provider breakers and schema versions
+//! - `/health/ready` checks the database, photo storage, and migrations;
+//!   for readiness probes
 
-use axum::Json;
+use axum::{extract::State, http::StatusCode, Json};
 use serde::Serialize;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use super::AppState;
+use crate::db::{applied_migration_version, latest_migration_version};
+use crate::utils::circuit_breaker::{BreakerState, BreakerStatus};
+
+/// Longest a readiness check waits on one dependency.
+pub const DEPENDENCY_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Liveness check response.
+#[derive(Serialize)]
+pub struct LivenessResponse {
+    pub status: &'static str,
+}
+
+/// Liveness check handler.
+///
+/// Always answers 200 without touching any dependency, so a slow database
+/// never gets a healthy process restarted.
+pub async fn liveness_check() -> Json<LivenessResponse> {
+    Json(LivenessResponse { status: "ok" })
+}
 
 /// Health check response.
 #[derive(Serialize)]
 pub struct HealthResponse {
+    /// "ok", or "degraded" while any provider's breaker is not closed
     pub status: &'static str,
     pub version: &'static str,
+    /// Circuit breakers around photo storage and notification providers
+    pub breakers: Vec<BreakerStatus>,
+    /// Newest migration the database had applied at startup
+    pub schema_version: Option<i64>,
+    /// Newest migration embedded in this build
+    pub latest_schema_version: i64,
 }
 
 /// Health check handler.
 ///
-/// Returns OK status and API version. Used for load balancer health checks
-/// and deployment verification.
-pub async fn health_check() -> Json<HealthResponse> {
+/// Returns status, API version, schema versions, and circuit breaker states.
+/// Used for load balancer health checks and deployment verification. An open breaker
+/// reports "degraded" but still answers 200: the API keeps serving tickets
+/// while a provider is down, so the instance shouldn't be taken out.
+pub async fn health_check(State(state): State<AppState>) -> Json<HealthResponse> {
+    let mut breakers: Vec<BreakerStatus> = state.storage.breaker_status().into_iter().collect();
+    breakers.extend(state.notifications.breakers());
+
+    let degraded = breakers
+        .iter()
+        .any(|breaker| breaker.state != BreakerState::Closed);
     Json(HealthResponse {
-        status: "ok",
+        status: if degraded { "degraded" } else { "ok" },
         version: env!("CARGO_PKG_VERSION"),
+        breakers,
+        schema_version: state.schema_version,
+        latest_schema_version: latest_migration_version(),
     })
 }
 
+/// Outcome of one dependency check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Ok,
+    /// The dependency failed or didn't answer in time
+    Error,
+    /// The database is behind the migrations built into this server
+    Pending,
+    /// Migrations were never run through sqlx, so the version is unknown
+    Untracked,
+}
+
+/// A dependency checked by `/health/ready`.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyCheck {
+    /// "database", "storage", or "migrations"
+    pub name: &'static str,
+    pub status: CheckStatus,
+    /// Whether a failure makes the instance not ready
+    pub required: bool,
+    pub latency_ms: u64,
+    /// Error message or version details
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl DependencyCheck {
+    /// Build a check from its outcome; a failure becomes an error status.
+    fn new(
+        name: &'static str,
+        required: bool,
+        elapsed: Duration,
+        result: Result<(CheckStatus, Option<String>), String>,
+    ) -> Self {
+        let (status, detail) = result.unwrap_or_else(|err| (CheckStatus::Error, Some(err)));
+        Self {
+            name,
+            status,
+            required,
+            latency_ms: elapsed.as_millis() as u64,
+            detail,
+        }
+    }
+
+    /// Whether this check keeps the instance from taking traffic.
+    fn blocks_readiness(&self) -> bool {
+        self.required && matches!(self.status, CheckStatus::Error | CheckStatus::Pending)
+    }
+}
+
+/// Readiness check response.
+#[derive(Serialize)]
+pub struct ReadinessResponse {
+    /// "ready", "warming_up" until startup warm-up finishes, "draining"
+    /// once shutdown starts, or "unavailable" while a required dependency
+    /// check fails
+    pub status: &'static str,
+    pub checks: Vec<DependencyCheck>,
+}
+
+/// Readiness check handler.
+///
+/// Checks the database, photo storage, and migration state, each with its
+/// latency, and answers 200 only once startup warm-up has finished, the
+/// database answers, and no migrations are pending; and 503 again once
+/// shutdown starts draining. Storage is reported but
+/// doesn't fail the check: every instance shares it, and the API keeps
+/// serving tickets while it's down, so taking instances out would only
+/// turn a degraded service into an outage. Point load balancer and
+/// Kubernetes readiness probes here.
+pub async fn readiness_check(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<ReadinessResponse>) {
+    let (database, storage, migrations) = tokio::join!(
+        // Not test_connection, which logs every call
+        timed(sqlx::query("SELECT 1").execute(&state.db)),
+        timed(state.storage.check()),
+        timed(applied_migration_version(&state.db)),
+    );
+
+    let latest = latest_migration_version();
+    let checks = vec![
+        DependencyCheck::new("database", true, database.0, database.1.map(|_| ok())),
+        DependencyCheck::new("storage", false, storage.0, storage.1.map(|_| ok())),
+        DependencyCheck::new(
+            "migrations",
+            true,
+            migrations.0,
+            migrations
+                .1
+                .map(|applied| migration_status(applied, latest)),
+        ),
+    ];
+
+    let status = if state.shutdown.is_draining() {
+        "draining"
+    } else if !state.readiness.is_ready() {
+        "warming_up"
+    } else if checks.iter().any(DependencyCheck::blocks_readiness) {
+        "unavailable"
+    } else {
+        "ready"
+    };
+    let code = if status == "ready" {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (code, Json(ReadinessResponse { status, checks }))
+}
+
+/// Run a check with the dependency timeout, returning its latency.
+async fn timed<T, E: std::fmt::Display>(
+    check: impl Future<Output = Result<T, E>>,
+) -> (Duration, Result<T, String>) {
+    let started = Instant::now();
+    let result = match tokio::time::timeout(DEPENDENCY_CHECK_TIMEOUT, check).await {
+        Ok(result) => result.map_err(|err| err.to_string()),
+        Err(_) => Err(format!(
+            "No answer within {}s",
+            DEPENDENCY_CHECK_TIMEOUT.as_secs()
+        )),
+    };
+    (started.elapsed(), result)
+}
+
+/// Status and detail of a check that passed.
+fn ok() -> (CheckStatus, Option<String>) {
+    (CheckStatus::Ok, None)
+}
+
+/// Migration status from the applied and built-in versions.
+fn migration_status(applied: Option<i64>, latest: i64) -> (CheckStatus, Option<String>) {
+    match applied {
+        Some(version) if version >= latest => {
+            (CheckStatus::Ok, Some(format!("At migration {}", version)))
+        }
+        Some(version) => (
+            CheckStatus::Pending,
+            Some(format!(
+                "At migration {}; this build expects {}",
+                version, latest
+            )),
+        ),
+        None => (
+            CheckStatus::Untracked,
+            Some("Migrations haven't been run through sqlx".to_string()),
+        ),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use axum::{body::Body, http::Request, routing::get, Router};
     use http_body_util::BodyExt;
     use serde::Deserialize;
+    use sqlx::postgres::PgPoolOptions;
     use tower::ServiceExt;
 
     /// Test-only deserializable version of HealthResponse.
@@ -34,11 +233,19 @@ mod tests {
     struct TestHealthResponse {
         status: String,
         version: String,
+        breakers: Vec<serde_json::Value>,
+        schema_version: Option<i64>,
+        latest_schema_version: i64,
     }
 
     #[tokio::test]
     async fn test_health_check() {
-        let app = Router::new().route("/health", get(health_check));
+        let pool = PgPoolOptions::new()
+            .connect_lazy("postgres://facet@127.0.0.1:1/facet")
+            .unwrap();
+        let app = Router::new()
+            .route("/health", get(health_check))
+            .with_state(AppState::new(pool));
 
         let response = app
             .oneshot(
@@ -57,5 +264,76 @@ mod tests {
 
         assert_eq!(health.status, "ok");
         assert!(!health.version.is_empty());
+        // Local storage and unconfigured providers have no breakers
+        assert!(health.breakers.is_empty());
+        assert!(health.schema_version.is_none());
+        assert!(health.latest_schema_version > 0);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_check_waits_for_warm_up() {
+        let pool = PgPoolOptions::new()
+            .connect_lazy("postgres://facet@127.0.0.1:1/facet")
+            .unwrap();
+        let state = AppState::new(pool);
+        let readiness = state.readiness.clone();
+        let app = Router::new()
+            .route("/health/ready", get(readiness_check))
+            .with_state(state);
+
+        let request = || {
+            Request::builder()
+                .uri("/health/ready")
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = app.clone().oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), 503);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let ready: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(ready["status"], "warming_up");
+
+        // Warmed up, but the database is unreachable
+        readiness.mark_ready();
+        let response = app.oneshot(request()).await.unwrap();
+        assert_eq!(response.status(), 503);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let ready: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(ready["status"], "unavailable");
+        assert_eq!(ready["checks"][0]["name"], "database");
+        assert_eq!(ready["checks"][0]["status"], "error");
+    }
+
+    #[tokio::test]
+    async fn test_liveness_check() {
+        let app = Router::new().route("/health/live", get(liveness_check));
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/health/live")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), 200);
+    }
+
+    #[test]
+    fn test_migration_status() {
+        assert_eq!(migration_status(Some(54), 54).0, CheckStatus::Ok);
+        assert_eq!(migration_status(Some(53), 54).0, CheckStatus::Pending);
+        assert_eq!(migration_status(None, 54).0, CheckStatus::Untracked);
+    }
+
+    #[test]
+    fn test_only_required_failures_block_readiness() {
+        let elapsed = Duration::from_millis(5);
+        let failed = || Err("connection refused".to_string());
+        assert!(DependencyCheck::new("database", true, elapsed, failed()).blocks_readiness());
+        assert!(!DependencyCheck::new("storage", false, elapsed, failed()).blocks_readiness());
+        assert!(!DependencyCheck::new("database", true, elapsed, Ok(ok())).blocks_readiness());
+        let untracked = Ok(migration_status(None, 54));
+        assert!(!DependencyCheck::new("migrations", true, elapsed, untracked).blocks_readiness());
     }
 }
@@ -1,33 +1,63 @@
 //! API route modules.
 //!
 //! Routes are organized by domain:
-//! - `/health` - Health check endpoint
+//! - `/health`, `/health/live`, `/health/ready` - Health and readiness checks
+//! - `/metrics` - Prometheus metrics (when enabled)
 //! - `/api/v1/tickets` - Ticket management
 //! - `/api/v1/customers` - Customer management
 //! - `/api/v1/employees` - Employee management
 //! - `/api/v1/locations` - Storage location management
+//! - `/api/v1/stores` - Stores in the chain
+//! - `/api/v1/services` - Service catalog
+//! - `/api/v1/materials` - Materials inventory
 //! - `/api/v1/queue` - Workboard queue
+//! - `/api/v1/transfers` - Tickets in transit between locations
 //! - `/api/v1/settings` - Store settings
 //! - `/api/v1/admin` - Admin operations
+//! - `/api/v1/reports` - Reporting
+//! - `/api/v1/public` - Unauthenticated customer-facing lookups
+//! - `/api/v1/calendar` - Calendar feeds (feed token in the URL)
+//! - `/api/v1/partner` - Partner jeweler API (X-Partner-Key)
+//! - `/api/v1/intake-drafts` - Review of requests from integrations
+//! - `/api/v1/integrations` - Inbound integration webhooks
+//! - `/api/v1/errors` - Error code catalog
+//! - `/api/v1/openapi.json` - OpenAPI specification (Swagger UI at `/docs`)
 
 mod health;
 
 use axum::{
+    extract::FromRef,
+    handler::Handler,
     middleware,
     routing::{delete, get, post, put},
     Router,
 };
 use sqlx::postgres::PgPool;
 use tower_http::limit::RequestBodyLimitLayer;
-use tower_http::services::ServeDir;
 
-use crate::config::{DEFAULT_MAX_BODY_SIZE, DEFAULT_MAX_PHOTO_SIZE};
+use crate::config::{
+    DEFAULT_LOCAL_STORAGE_DIR, DEFAULT_MAX_BODY_SIZE, DEFAULT_MAX_PHOTO_SIZE,
+    DEFAULT_PHOTO_URL_TTL_SECS,
+};
 use crate::handlers;
-use crate::middleware::{json_payload_error, RateLimitState};
+use crate::middleware::{
+    current_store, debug_capture, enforce_rate_limit, idempotency, json_payload_error,
+    negotiate_locale, refuse_while_draining, request_id, request_timeout, response_meta,
+    track_metrics, training_mode, DebugCaptureState, Metrics, PartnerRateLimits, ProbePolicy,
+    RateLimitBackend, RateLimitPolicies, RateLimitState, RequestTimeouts, RouteRateLimits,
+    TrustedProxies,
+};
 
-pub use health::health_check;
+pub use health::{health_check, liveness_check, readiness_check};
 
-use crate::storage::StorageClient;
+use crate::services::notifications::NotificationService;
+use crate::services::pin_lockout::PinLockoutPolicy;
+use crate::services::scheduler::JobStatuses;
+use crate::services::shutdown::Shutdown;
+use crate::services::warmup::Readiness;
+use crate::storage::{LocalStorage, LocalStorageConfig, StorageBackend};
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Application state shared across all handlers.
 ///
@@ -36,29 +66,163 @@ use crate::storage::StorageClient;
 pub struct AppState {
     /// PostgreSQL connection pool
     pub db: PgPool,
-    /// S3-compatible storage client for photo uploads (optional for dev environments)
-    pub storage: Option<StorageClient>,
+    /// Where photos are stored (S3, Cloud Storage, or local disk)
+    pub storage: Arc<dyn StorageBackend>,
     /// Rate limiter state for PIN verification endpoints
     pub rate_limit: RateLimitState,
+    /// Stricter rate limiter for admin recovery
+    pub recovery_rate_limit: RateLimitState,
+    /// Per-partner rate limiters for the partner API
+    pub partner_rate_limits: PartnerRateLimits,
+    /// Cached debug capture toggle for request logging
+    pub debug_capture: DebugCaptureState,
+    /// How requests for missing resources are answered
+    pub probe_policy: ProbePolicy,
+    /// Customer notification providers (SMS)
+    pub notifications: NotificationService,
+    /// Whether uploaded photos are stripped of metadata and oriented
+    pub process_photos: bool,
+    /// How long signed photo URLs handed to clients stay valid
+    pub photo_url_ttl: Duration,
+    /// Secret phone intake webhooks are signed with (None = endpoint off)
+    pub phone_intake_secret: Option<String>,
+    /// Time budgets after which requests are cancelled
+    pub request_timeouts: RequestTimeouts,
+    /// Request metrics for `/metrics` (None = metrics off)
+    pub metrics: Option<Arc<Metrics>>,
+    /// Set once startup warm-up finishes; reported by `/health/ready`
+    pub readiness: Readiness,
+    /// Status of scheduled background jobs, for `/admin/jobs`
+    pub jobs: JobStatuses,
+    /// Newest migration the database had applied at startup (None = unknown)
+    pub schema_version: Option<i64>,
+    /// Draining state on shutdown, and background tasks it waits for
+    pub shutdown: Shutdown,
+    /// Proxies whose forwarding headers identify the client IP
+    pub trusted_proxies: TrustedProxies,
+    /// Per-IP and per-employee limits for busy route groups
+    pub route_rate_limits: RouteRateLimits,
+    /// When wrong PINs lock an employee out
+    pub pin_lockout: PinLockoutPolicy,
 }
 
 impl AppState {
     /// Create a new AppState with the given database pool.
+    ///
+    /// Photos go to local storage under `uploads/` until a backend is set
+    /// with [`AppState::with_storage`].
     pub fn new(db: PgPool) -> Self {
+        let storage = LocalStorage::new(LocalStorageConfig {
+            root: DEFAULT_LOCAL_STORAGE_DIR.into(),
+            signing_key: None,
+        });
         Self {
             db,
-            storage: None,
+            storage: Arc::new(storage),
             rate_limit: RateLimitState::new(),
+            recovery_rate_limit: RateLimitState::recovery(),
+            partner_rate_limits: PartnerRateLimits::new(),
+            debug_capture: DebugCaptureState::new(),
+            probe_policy: ProbePolicy::default(),
+            notifications: NotificationService::new(),
+            process_photos: true,
+            photo_url_ttl: Duration::from_secs(DEFAULT_PHOTO_URL_TTL_SECS),
+            phone_intake_secret: None,
+            request_timeouts: RequestTimeouts::default(),
+            metrics: None,
+            readiness: Readiness::new(),
+            jobs: JobStatuses::new(),
+            schema_version: None,
+            shutdown: Shutdown::new(),
+            trusted_proxies: TrustedProxies::default(),
+            route_rate_limits: RouteRateLimits::default(),
+            pin_lockout: PinLockoutPolicy::default(),
         }
     }
 
-    /// Create a new AppState with database pool and storage client.
-    pub fn with_storage(db: PgPool, storage: StorageClient) -> Self {
-        Self {
-            db,
-            storage: Some(storage),
-            rate_limit: RateLimitState::new(),
-        }
+    /// Set the backend photos are stored in.
+    pub fn with_storage(mut self, storage: Arc<dyn StorageBackend>) -> Self {
+        self.storage = storage;
+        self
+    }
+
+    /// Set the policy for answering requests for missing resources.
+    pub fn with_probe_policy(mut self, probe_policy: ProbePolicy) -> Self {
+        self.probe_policy = probe_policy;
+        self
+    }
+
+    /// Set the service used to send customer notifications.
+    pub fn with_notifications(mut self, notifications: NotificationService) -> Self {
+        self.notifications = notifications;
+        self
+    }
+
+    /// Set whether uploaded photos are stripped of metadata and oriented.
+    pub fn with_photo_processing(mut self, process_photos: bool) -> Self {
+        self.process_photos = process_photos;
+        self
+    }
+
+    /// Set how long signed photo URLs stay valid.
+    pub fn with_photo_url_ttl(mut self, photo_url_ttl: Duration) -> Self {
+        self.photo_url_ttl = photo_url_ttl;
+        self
+    }
+
+    /// Set the time budgets after which requests are cancelled.
+    pub fn with_request_timeouts(mut self, request_timeouts: RequestTimeouts) -> Self {
+        self.request_timeouts = request_timeouts;
+        self
+    }
+
+    /// Turn request metrics and the `/metrics` endpoint on or off.
+    pub fn with_metrics(mut self, enabled: bool) -> Self {
+        self.metrics = enabled.then(|| Arc::new(Metrics::new()));
+        self
+    }
+
+    /// Set the secret phone intake webhooks are signed with.
+    pub fn with_phone_intake_secret(mut self, secret: Option<String>) -> Self {
+        self.phone_intake_secret = secret;
+        self
+    }
+
+    /// Set the migration version the database was found at on startup.
+    pub fn with_schema_version(mut self, schema_version: Option<i64>) -> Self {
+        self.schema_version = schema_version;
+        self
+    }
+
+    /// Set the proxies trusted to report client IPs.
+    pub fn with_trusted_proxies(mut self, trusted_proxies: TrustedProxies) -> Self {
+        self.trusted_proxies = trusted_proxies;
+        self
+    }
+
+    /// Set where PIN and recovery failure counters are kept.
+    pub fn with_rate_limit_backend(mut self, backend: Arc<dyn RateLimitBackend>) -> Self {
+        self.rate_limit = self.rate_limit.with_backend(backend.clone());
+        self.recovery_rate_limit = self.recovery_rate_limit.with_backend(backend);
+        self
+    }
+
+    /// Set the rate limits for route groups.
+    pub fn with_rate_limit_policies(mut self, policies: &RateLimitPolicies) -> Self {
+        self.route_rate_limits = RouteRateLimits::new(policies);
+        self
+    }
+
+    /// Set when wrong PINs lock an employee out.
+    pub fn with_pin_lockout(mut self, policy: PinLockoutPolicy) -> Self {
+        self.pin_lockout = policy;
+        self
+    }
+}
+
+impl FromRef<AppState> for TrustedProxies {
+    fn from_ref(state: &AppState) -> Self {
+        state.trusted_proxies.clone()
     }
 }
 
@@ -93,17 +257,44 @@ pub fn api_router(state: AppState) -> Router {
 /// The router is configured with shared application state and custom
 /// request body size limits.
 pub fn api_router_with_limits(state: AppState, limits: BodyLimitConfig) -> Router {
+    // Replays the stored response when a client retries with the same Idempotency-Key
+    let idempotent = middleware::from_fn_with_state(state.clone(), idempotency);
+
+    // Per-IP and per-employee limits for busy route groups (RATE_LIMITS).
+    // They sit outside idempotency so a rejected request doesn't claim its key.
+    let rate_limited = |group| {
+        middleware::from_fn_with_state(
+            state.route_rate_limits.group(group, &state.trusted_proxies),
+            enforce_rate_limit,
+        )
+    };
+
     // Photo upload route with larger limit
     let photo_upload_route = Router::new()
-        .route("/", post(handlers::upload_photo))
+        .route(
+            "/",
+            post(handlers::upload_photo)
+                .layer(idempotent.clone())
+                .layer(rate_limited("photo_uploads")),
+        )
         .layer(RequestBodyLimitLayer::new(limits.max_photo_size));
 
     // Ticket routes (without photo upload, which has its own limit)
     let tickets_routes = Router::new()
         .route(
             "/",
-            get(handlers::list_tickets).post(handlers::create_ticket),
+            get(handlers::list_tickets).post(
+                handlers::create_ticket
+                    .layer(idempotent.clone())
+                    .layer(rate_limited("ticket_creation")),
+            ),
+        )
+        .route("/quote", post(handlers::quote_ticket))
+        .route(
+            "/promise-date-suggestion",
+            get(handlers::suggest_promise_date),
         )
+        .route("/bulk/status", post(handlers::bulk_change_status))
         .route(
             "/:ticket_id",
             get(handlers::get_ticket)
@@ -111,20 +302,110 @@ pub fn api_router_with_limits(state: AppState, limits: BodyLimitConfig) -> Route
                 .delete(handlers::delete_ticket),
         )
         .route("/:ticket_id/restore", post(handlers::restore_ticket))
+        .route("/:ticket_id/history", get(handlers::get_ticket_history))
+        .route("/:ticket_id/receipt", get(handlers::get_receipt))
         .route("/:ticket_id/receipt.pdf", get(handlers::get_receipt_pdf))
+        .route(
+            "/:ticket_id/receipt.escpos",
+            get(handlers::get_receipt_escpos),
+        )
+        .route("/:ticket_id/receipt.txt", get(handlers::get_receipt_text))
+        .route("/:ticket_id/invoice.pdf", get(handlers::get_invoice_pdf))
         .route("/:ticket_id/label.pdf", get(handlers::get_label_pdf))
+        .route(
+            "/:ticket_id/appraisal",
+            get(handlers::get_appraisal)
+                .put(handlers::save_appraisal)
+                .delete(handlers::delete_appraisal),
+        )
+        .route(
+            "/:ticket_id/appraisal.pdf",
+            get(handlers::get_appraisal_pdf),
+        )
+        .route(
+            "/:ticket_id/work-order.pdf",
+            get(handlers::get_work_order_pdf),
+        )
         .route("/:ticket_id/status", post(handlers::change_status))
-        .route("/:ticket_id/close", post(handlers::close_ticket))
+        .route(
+            "/:ticket_id/close",
+            post(handlers::close_ticket).layer(idempotent.clone()),
+        )
+        .route("/:ticket_id/reopen", post(handlers::reopen_ticket))
+        .route("/:ticket_id/archive", post(handlers::archive_ticket))
         .route("/:ticket_id/rush", post(handlers::toggle_rush))
+        .route("/:ticket_id/assign", post(handlers::assign_ticket))
         .route("/:ticket_id/notes", post(handlers::add_note))
+        .route(
+            "/:ticket_id/payments",
+            get(handlers::list_payments).post(handlers::record_payment.layer(idempotent)),
+        )
+        .route(
+            "/:ticket_id/contacts",
+            get(handlers::list_contacts).post(handlers::log_contact),
+        )
+        .route(
+            "/:ticket_id/materials",
+            get(handlers::list_ticket_materials).post(handlers::record_ticket_material),
+        )
+        .route(
+            "/:ticket_id/authorized-pickups",
+            get(handlers::list_authorized_pickups).post(handlers::create_authorized_pickup),
+        )
+        .route(
+            "/:ticket_id/authorized-pickups/:authorized_pickup_id",
+            delete(handlers::revoke_authorized_pickup),
+        )
+        .route(
+            "/:ticket_id/pickups/:pickup_id/signature",
+            get(handlers::get_pickup_signature),
+        )
+        .route("/:ticket_id/quote/send", post(handlers::send_quote))
+        .route("/:ticket_id/quote/approve", post(handlers::approve_quote))
+        .route("/:ticket_id/quote/decline", post(handlers::decline_quote))
+        .route("/:ticket_id/qc", post(handlers::record_qc_check))
+        .route("/:ticket_id/defects", post(handlers::record_defect))
+        .route(
+            "/:ticket_id/custody",
+            get(handlers::get_custody_chain).post(handlers::record_custody_handoff),
+        )
+        .route(
+            "/:ticket_id/custody.pdf",
+            get(handlers::get_custody_report_pdf),
+        )
+        .route("/:ticket_id/transfer", post(handlers::create_transfer))
+        .route(
+            "/:ticket_id/transfer/accept",
+            post(handlers::accept_transfer),
+        )
+        .route(
+            "/:ticket_id/transfer/cancel",
+            post(handlers::cancel_transfer),
+        )
+        .route(
+            "/:ticket_id/transfers",
+            get(handlers::list_ticket_transfers),
+        )
+        .route(
+            "/:ticket_id/movements",
+            get(handlers::list_ticket_movements).post(handlers::record_movement),
+        )
+        .route("/:ticket_id/time", get(handlers::list_time_entries))
+        .route("/:ticket_id/time/start", post(handlers::start_time_entry))
+        .route("/:ticket_id/time/stop", post(handlers::stop_time_entry))
         .nest("/:ticket_id/photos", photo_upload_route)
         .route(
             "/:ticket_id/photos/:photo_id",
-            delete(handlers::delete_photo),
+            get(handlers::get_photo).delete(handlers::delete_photo),
         );
 
     // Queue route
-    let queue_route = Router::new().route("/", get(handlers::get_queue));
+    let queue_route = Router::new()
+        .route("/", get(handlers::get_queue))
+        .route("/:lane/reorder", post(handlers::reorder_queue));
+
+    // Transfer route (across tickets)
+    let transfers_route = Router::new().route("/", get(handlers::list_transfers));
 
     // Employee routes
     let employees_routes = Router::new()
@@ -132,30 +413,155 @@ pub fn api_router_with_limits(state: AppState, limits: BodyLimitConfig) -> Route
             "/",
             get(handlers::list_employees).post(handlers::create_employee),
         )
+        .route("/me", get(handlers::get_my_profile))
+        .route("/active", get(handlers::list_active_employees))
         .route(
             "/:employee_id",
             put(handlers::update_employee).delete(handlers::delete_employee),
         )
+        .route(
+            "/:employee_id/permissions",
+            get(handlers::get_employee_permissions).put(handlers::set_employee_permissions),
+        )
+        .route("/:employee_id/unlock", post(handlers::unlock_employee))
         .route("/verify", post(handlers::verify_employee_pin))
-        .route("/logout", post(handlers::employee_logout));
+        .route("/verify/challenge", post(handlers::create_pin_challenge))
+        .route(
+            "/verify/response",
+            post(handlers::verify_employee_pin_challenge),
+        )
+        .route("/logout", post(handlers::employee_logout))
+        .route("/training", put(handlers::set_training_mode));
 
     // Customer routes
     let customers_routes = Router::new()
-        .route("/", get(handlers::search_customers))
-        .route("/:customer_id", get(handlers::get_customer));
+        .route(
+            "/",
+            get(handlers::search_customers).post(handlers::create_customer),
+        )
+        .route(
+            "/:customer_id",
+            get(handlers::get_customer)
+                .put(handlers::update_customer)
+                .delete(handlers::delete_customer),
+        )
+        .route("/:customer_id/merge", post(handlers::merge_customer));
 
     // Admin routes
     let admin_routes = Router::new()
         .route("/setup", post(handlers::admin_setup))
         .route("/verify", post(handlers::verify_admin))
         .route("/change-pin", post(handlers::change_pin))
-        .route("/logout", post(handlers::admin_logout));
+        .route("/logout", post(handlers::admin_logout))
+        .route("/recover", post(handlers::recover_admin))
+        .route("/recovery/code", post(handlers::regenerate_recovery_code))
+        .route("/recovery/attempts", get(handlers::list_recovery_attempts))
+        .route("/audit-log", get(handlers::list_audit_log))
+        .route(
+            "/debug-capture",
+            get(handlers::get_debug_capture).put(handlers::update_debug_capture),
+        )
+        .route("/request-logs", get(handlers::list_request_logs))
+        .route("/config/export", get(handlers::export_config))
+        .route("/config/import", post(handlers::import_config))
+        .route("/import/customers", post(handlers::import_customers))
+        .route("/import/tickets", post(handlers::import_tickets))
+        .route("/integrity", get(handlers::get_integrity_report))
+        .route("/jobs", get(handlers::list_jobs))
+        .route("/storage/reconcile", post(handlers::reconcile_storage))
+        .route(
+            "/partners",
+            get(handlers::list_partners).post(handlers::create_partner),
+        )
+        .route("/partners/:partner_id", put(handlers::update_partner))
+        .route(
+            "/partners/:partner_id/rotate-key",
+            post(handlers::rotate_partner_key),
+        )
+        .route(
+            "/webhooks",
+            get(handlers::list_webhooks).post(handlers::create_webhook),
+        )
+        .route(
+            "/webhooks/:webhook_id",
+            put(handlers::update_webhook).delete(handlers::delete_webhook),
+        )
+        .route(
+            "/webhooks/:webhook_id/rotate-secret",
+            post(handlers::rotate_webhook_secret),
+        )
+        .route(
+            "/webhooks/:webhook_id/deliveries",
+            get(handlers::list_webhook_deliveries),
+        )
+        .route(
+            "/webhooks/:webhook_id/deliveries/:delivery_id/redeliver",
+            post(handlers::redeliver_webhook_delivery),
+        )
+        .route(
+            "/campaigns",
+            get(handlers::list_campaigns).post(handlers::create_campaign),
+        )
+        .route("/campaigns/preview", post(handlers::preview_campaign))
+        .route("/campaigns/:campaign_id", get(handlers::get_campaign))
+        .route(
+            "/campaigns/:campaign_id/recipients",
+            get(handlers::list_campaign_recipients),
+        )
+        .route(
+            "/campaigns/:campaign_id/abort",
+            post(handlers::abort_campaign),
+        );
 
     // Settings routes
-    let settings_routes = Router::new().route(
-        "/",
-        get(handlers::get_settings).put(handlers::update_settings),
-    );
+    let settings_routes = Router::new()
+        .route(
+            "/",
+            get(handlers::get_settings).put(handlers::update_settings),
+        )
+        .route(
+            "/location-rules",
+            get(handlers::get_location_rules).put(handlers::update_location_rules),
+        )
+        .route(
+            "/metal-prices",
+            get(handlers::get_metal_prices).put(handlers::update_metal_prices),
+        )
+        .route(
+            "/item-types",
+            get(handlers::get_item_types).put(handlers::update_item_types),
+        )
+        .route(
+            "/promise-date-reasons",
+            get(handlers::get_promise_date_reasons).put(handlers::update_promise_date_reasons),
+        )
+        .route(
+            "/rush-pricing",
+            get(handlers::get_rush_pricing).put(handlers::update_rush_pricing),
+        )
+        .route(
+            "/notification-templates",
+            get(handlers::list_notification_templates),
+        )
+        .route(
+            "/notification-templates/:event",
+            put(handlers::update_notification_template),
+        )
+        .route("/templates/validate", post(handlers::validate_template))
+        .route("/history", get(handlers::get_settings_history))
+        .route("/rollback/:change_id", post(handlers::rollback_settings))
+        .route(
+            "/receipt-logo",
+            put(handlers::upload_receipt_logo).delete(handlers::delete_receipt_logo),
+        )
+        .route(
+            "/calendar-feed",
+            post(handlers::rotate_calendar_feed_token).delete(handlers::revoke_calendar_feed_token),
+        )
+        .route(
+            "/:section",
+            get(handlers::get_settings_section).patch(handlers::patch_settings_section),
+        );
 
     // Storage location routes
     let locations_routes = Router::new()
@@ -163,26 +569,157 @@ pub fn api_router_with_limits(state: AppState, limits: BodyLimitConfig) -> Route
             "/",
             get(handlers::list_locations).post(handlers::create_location),
         )
-        .route("/:location_id", put(handlers::update_location));
+        .route("/suggest", get(handlers::suggest_location))
+        .route(
+            "/:location_id",
+            put(handlers::update_location).delete(handlers::delete_location),
+        )
+        .route(
+            "/:location_id/tickets",
+            get(handlers::list_location_tickets),
+        );
+
+    // Store routes
+    let stores_routes = Router::new()
+        .route("/", get(handlers::list_stores).post(handlers::create_store))
+        .route("/:store_id", delete(handlers::delete_store));
+
+    // Service catalog routes
+    let services_routes = Router::new()
+        .route(
+            "/",
+            get(handlers::list_services).post(handlers::create_service),
+        )
+        .route(
+            "/:service_id",
+            put(handlers::update_service).delete(handlers::delete_service),
+        );
+
+    // Materials inventory routes
+    let materials_routes = Router::new()
+        .route(
+            "/",
+            get(handlers::list_materials).post(handlers::create_material),
+        )
+        .route(
+            "/:material_id",
+            put(handlers::update_material).delete(handlers::delete_material),
+        );
+
+    // Report routes
+    let reports_routes = Router::new()
+        .route("/quality", get(handlers::quality_report))
+        .route("/partners", get(handlers::partner_report))
+        .route("/services", get(handlers::service_report))
+        .route("/employees", get(handlers::employee_report))
+        .route("/revenue", get(handlers::revenue_report))
+        .route("/throughput", get(handlers::throughput_report))
+        .route("/queue-trends", get(handlers::queue_trends_report))
+        .route("/overdue", get(handlers::overdue_report))
+        .route("/location-audit", get(handlers::location_audit_report))
+        .route("/daily-closeout", get(handlers::daily_closeout_report))
+        .route("/daily-closeout.pdf", get(handlers::daily_closeout_pdf))
+        .route(
+            "/custom",
+            get(handlers::list_custom_reports).post(handlers::create_custom_report),
+        )
+        .route(
+            "/custom/:report_id",
+            get(handlers::run_custom_report)
+                .put(handlers::update_custom_report)
+                .delete(handlers::delete_custom_report),
+        );
+
+    // Public routes (no authentication; customer-facing)
+    let public_routes = Router::new().route(
+        "/tickets/:friendly_code/status",
+        get(handlers::get_public_ticket_status),
+    );
+
+    // Calendar feed routes (feed token in the URL, for calendar apps)
+    let calendar_routes =
+        Router::new().route("/promise-dates.ics", get(handlers::get_promise_date_feed));
+
+    // Partner routes (X-Partner-Key; per-partner rate limits)
+    let partner_routes = Router::new()
+        .route(
+            "/tickets",
+            get(handlers::partner_list_tickets).post(handlers::partner_create_ticket),
+        )
+        .route("/tickets/:friendly_code", get(handlers::partner_get_ticket));
+
+    // Intake draft routes (staff review of calls from the phone integration)
+    let intake_drafts_routes = Router::new()
+        .route("/", get(handlers::list_intake_drafts))
+        .route("/:draft_id", get(handlers::get_intake_draft))
+        .route("/:draft_id/convert", post(handlers::convert_intake_draft))
+        .route("/:draft_id/dismiss", post(handlers::dismiss_intake_draft));
+
+    // Inbound integration routes (signed webhooks)
+    let integrations_routes =
+        Router::new().route("/phone-intake", post(handlers::receive_phone_intake));
 
     // API v1 routes with default body limit
     let api_v1 = Router::new()
         .nest("/tickets", tickets_routes)
         .nest("/queue", queue_route)
+        .nest("/transfers", transfers_route)
         .nest("/employees", employees_routes)
         .nest("/customers", customers_routes)
         .nest("/admin", admin_routes)
         .nest("/settings", settings_routes)
         .nest("/locations", locations_routes)
+        .nest("/stores", stores_routes)
+        .nest("/services", services_routes)
+        .nest("/materials", materials_routes)
+        .nest("/reports", reports_routes)
+        .nest("/public", public_routes)
+        .nest("/calendar", calendar_routes)
+        .nest("/partner", partner_routes)
+        .nest("/intake-drafts", intake_drafts_routes)
+        .nest("/integrations", integrations_routes)
+        .route("/errors", get(handlers::get_error_catalog))
+        .route("/openapi.json", get(handlers::get_openapi_spec))
+        .route("/storage/*key", get(handlers::get_stored_object))
+        // Resolve the store the request works for (X-Store-ID or the session's)
+        .layer(middleware::from_fn_with_state(state.clone(), current_store))
         // Apply default body size limit to all API routes (except photo upload which has its own)
         .layer(RequestBodyLimitLayer::new(limits.max_body_size))
         // Convert 413 responses to JSON format
-        .layer(middleware::from_fn(json_payload_error));
+        .layer(middleware::from_fn(json_payload_error))
+        // Refuse new requests once shutdown starts draining
+        .layer(middleware::from_fn_with_state(
+            state.shutdown.clone(),
+            refuse_while_draining,
+        ));
 
-    Router::new()
+    let router = Router::new()
         .route("/health", axum::routing::get(health::health_check))
+        .route("/health/ready", get(health::readiness_check))
+        .route("/health/live", get(health::liveness_check))
+        .route("/metrics", get(handlers::get_metrics))
+        .route("/docs", get(handlers::get_api_docs))
         .nest("/api/v1", api_v1)
-        // Serve uploaded files from local storage (dev only)
-        .nest_service("/uploads", ServeDir::new("uploads"))
-        .with_state(state)
+        // Cancel requests that run past their time budget
+        .layer(middleware::from_fn_with_state(
+            state.request_timeouts,
+            request_timeout,
+        ))
+        // Resolve the session's training mode for handlers and the meta block
+        .layer(middleware::from_fn_with_state(state.clone(), training_mode))
+        // Add the meta block (timing, request ID, deprecations) to JSON envelopes
+        .layer(middleware::from_fn(response_meta))
+        // Record redacted request/response pairs while debug capture is enabled
+        .layer(middleware::from_fn_with_state(state.clone(), debug_capture))
+        // Translate error and warning messages per Accept-Language
+        .layer(middleware::from_fn(negotiate_locale))
+        // Assign the request ID before anything logs, and return it in X-Request-ID
+        .layer(middleware::from_fn(request_id));
+
+    // Count requests and time them by route, outermost so the whole stack is measured
+    let router = match state.metrics.clone() {
+        Some(metrics) => router.layer(middleware::from_fn_with_state(metrics, track_metrics)),
+        None => router,
+    };
+    router.with_state(state)
 }
@@ -0,0 +1,52 @@
+//! Automatic archiving of closed tickets.
+//!
+//! Tickets closed more than `auto_archive_after_days` days ago (a store
+//! setting) are moved to Archived so they drop out of day-to-day views.
+
+use crate::error::AppError;
+use crate::models::ticket::TicketStatus;
+use crate::repositories::{StoreSettingsRepository, TicketRepository};
+use crate::services::webhooks;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+
+/// How often the background task checks for tickets to archive.
+pub const AUTO_ARCHIVE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// Tickets closed before this instant are due for archiving.
+pub fn archive_cutoff(now: DateTime<Utc>, after_days: i32) -> DateTime<Utc> {
+    now - Duration::days(i64::from(after_days))
+}
+
+/// Archive closed tickets older than the configured age.
+///
+/// Returns the number of tickets archived; zero when auto-archiving is disabled.
+pub async fn auto_archive(pool: &PgPool) -> Result<usize, AppError> {
+    let Some(after_days) = StoreSettingsRepository::get_auto_archive_after_days(pool).await? else {
+        return Ok(0);
+    };
+
+    let archived =
+        TicketRepository::archive_closed_before(pool, archive_cutoff(Utc::now(), after_days))
+            .await?;
+    for ticket in &archived {
+        webhooks::ticket_status_changed(pool, ticket, TicketStatus::Closed).await;
+    }
+
+    Ok(archived.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_archive_cutoff() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+        assert_eq!(
+            archive_cutoff(now, 30),
+            Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap()
+        );
+    }
+}
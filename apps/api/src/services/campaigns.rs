@@ -0,0 +1,242 @@
+//! Campaign sending.
+//!
+//! Campaign recipients are the send queue. A background worker polls for
+//! queued and running campaigns, claims pending recipients no faster than
+//! each campaign's send rate, and records every outcome on the recipient.
+//! An aborted campaign stops before its next message.
+
+use chrono::{Duration, Utc};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::campaign::{
+    Campaign, CampaignRecipient, CampaignRecipientStatus, CAMPAIGN_PLACEHOLDERS,
+};
+use crate::repositories::{CampaignRepository, CustomerRepository, StoreSettingsRepository};
+use crate::services::notifications::{
+    unknown_placeholders, DeliveryOutcome, NotificationService, TemplateContext, PLACEHOLDERS,
+};
+
+/// How often the background worker looks for messages to send.
+pub const CAMPAIGN_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Window the send rate applies over.
+const RATE_WINDOW_SECS: i64 = 60;
+
+/// How many recipients a campaign may claim on this poll.
+///
+/// Spreads the per-minute rate across polls instead of sending a minute's
+/// worth at once, and never exceeds the rate over the last minute.
+pub fn batch_size(send_rate_per_minute: i32, attempted_last_minute: i64) -> i64 {
+    let rate = i64::from(send_rate_per_minute.max(1));
+    let polls_per_window = (RATE_WINDOW_SECS / CAMPAIGN_POLL_INTERVAL.as_secs() as i64).max(1);
+    let per_poll = (rate + polls_per_window - 1) / polls_per_window;
+    per_poll.min(rate - attempted_last_minute).max(0)
+}
+
+/// Placeholders in a campaign message that campaigns can't fill in, in
+/// order of first appearance: ticket placeholders and unknown ones.
+pub fn unsupported_placeholders(template: &str) -> Vec<String> {
+    let mut unsupported: Vec<String> = PLACEHOLDERS
+        .iter()
+        .filter(|p| !CAMPAIGN_PLACEHOLDERS.contains(p))
+        .filter_map(|p| {
+            let written = format!("{{{}}}", p);
+            template.find(&written).map(|at| (at, p.to_string()))
+        })
+        .collect::<std::collections::BTreeMap<_, _>>()
+        .into_values()
+        .collect();
+    unsupported.extend(unknown_placeholders(template));
+    unsupported
+}
+
+/// Send the next batch for every active campaign.
+///
+/// Returns the number of recipients attempted.
+pub async fn run_campaigns(
+    pool: &PgPool,
+    notifications: &NotificationService,
+) -> Result<usize, AppError> {
+    let mut attempted = 0;
+    for campaign in CampaignRepository::find_active(pool).await? {
+        attempted += send_batch(pool, notifications, &campaign).await?;
+        if CampaignRepository::complete_if_done(pool, campaign.campaign_id).await? {
+            tracing::info!(campaign_id = %campaign.campaign_id, "Campaign completed");
+        }
+    }
+    Ok(attempted)
+}
+
+/// Claim and send one batch of a campaign's recipients.
+async fn send_batch(
+    pool: &PgPool,
+    notifications: &NotificationService,
+    campaign: &Campaign,
+) -> Result<usize, AppError> {
+    // Don't claim recipients while the provider's breaker is open
+    if !notifications.channel_available(campaign.channel) {
+        return Ok(0);
+    }
+    CampaignRepository::mark_running(pool, campaign.campaign_id).await?;
+
+    let since = Utc::now() - Duration::seconds(RATE_WINDOW_SECS);
+    let attempted_last_minute =
+        CampaignRepository::count_attempted_since(pool, campaign.campaign_id, since).await?;
+    let limit = batch_size(campaign.send_rate_per_minute, attempted_last_minute);
+    if limit == 0 {
+        return Ok(0);
+    }
+
+    let recipients = CampaignRepository::claim_pending(pool, campaign.campaign_id, limit).await?;
+    if recipients.is_empty() {
+        return Ok(0);
+    }
+
+    let settings = StoreSettingsRepository::get_settings(pool).await?;
+    let mut aborted = false;
+    for recipient in &recipients {
+        // The abort switch takes effect before the next message
+        aborted = aborted || !CampaignRepository::is_active(pool, campaign.campaign_id).await?;
+        if aborted {
+            CampaignRepository::finish_recipient(
+                pool,
+                recipient.recipient_id,
+                CampaignRecipientStatus::Cancelled,
+                None,
+                None,
+                None,
+            )
+            .await?;
+            continue;
+        }
+
+        send_one(
+            pool,
+            notifications,
+            campaign,
+            recipient,
+            &settings.store_name,
+            settings.store_phone.as_deref(),
+        )
+        .await?;
+    }
+
+    Ok(recipients.len())
+}
+
+/// Render and send the campaign message to one recipient.
+async fn send_one(
+    pool: &PgPool,
+    notifications: &NotificationService,
+    campaign: &Campaign,
+    recipient: &CampaignRecipient,
+    store_name: &str,
+    store_phone: Option<&str>,
+) -> Result<(), AppError> {
+    // Contact details and opt-out are read again, since they may have changed
+    let Some(customer) = CustomerRepository::find_active_by_id(pool, recipient.customer_id).await?
+    else {
+        return CampaignRepository::finish_recipient(
+            pool,
+            recipient.recipient_id,
+            CampaignRecipientStatus::Skipped,
+            None,
+            None,
+            Some("Customer was deleted"),
+        )
+        .await;
+    };
+
+    let context = TemplateContext::for_customer(
+        customer.name.clone(),
+        store_name.to_string(),
+        store_phone.map(String::from),
+    );
+    let subject = campaign.subject.as_deref().map(|s| context.render(s));
+    let message = context.render(&campaign.body);
+
+    match notifications
+        .send_to_customer(&customer, campaign.channel, subject.as_deref(), &message)
+        .await
+    {
+        DeliveryOutcome::Sent {
+            recipient: to,
+            message_id,
+        } => {
+            CampaignRepository::finish_recipient(
+                pool,
+                recipient.recipient_id,
+                CampaignRecipientStatus::Sent,
+                Some(&to),
+                message_id.as_deref(),
+                None,
+            )
+            .await
+        }
+        DeliveryOutcome::Failed {
+            recipient: to,
+            error,
+        } => {
+            tracing::warn!(
+                campaign_id = %campaign.campaign_id,
+                "Campaign message to customer {} failed: {}",
+                customer.customer_id,
+                error
+            );
+            CampaignRepository::finish_recipient(
+                pool,
+                recipient.recipient_id,
+                CampaignRecipientStatus::Failed,
+                Some(&to),
+                None,
+                Some(&error),
+            )
+            .await
+        }
+        DeliveryOutcome::Skipped { reason } => {
+            CampaignRepository::finish_recipient(
+                pool,
+                recipient.recipient_id,
+                CampaignRecipientStatus::Skipped,
+                None,
+                None,
+                Some(reason),
+            )
+            .await
+        }
+        DeliveryOutcome::Deferred => {
+            CampaignRepository::release_recipient(pool, recipient.recipient_id).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsupported_placeholders() {
+        assert!(unsupported_placeholders("Hi {customer_name}, from {store_name}").is_empty());
+        assert_eq!(
+            unsupported_placeholders("{promise_date} {customer_name} {friendly_code} {nme}"),
+            vec!["promise_date", "friendly_code", "nme"]
+        );
+    }
+
+    #[test]
+    fn test_batch_size_spreads_rate_across_polls() {
+        // 5-second polls: 12 per minute
+        assert_eq!(batch_size(60, 0), 5);
+        assert_eq!(batch_size(30, 0), 3);
+        assert_eq!(batch_size(1, 0), 1);
+    }
+
+    #[test]
+    fn test_batch_size_respects_last_minute() {
+        assert_eq!(batch_size(30, 28), 2);
+        assert_eq!(batch_size(30, 30), 0);
+        assert_eq!(batch_size(30, 45), 0);
+        assert_eq!(batch_size(1, 1), 0);
+    }
+}
@@ -0,0 +1,185 @@
+//! iCalendar (RFC 5545) output.
+//!
+//! Renders the promise date feed calendar apps subscribe to: one all-day
+//! event per open ticket, summarized by its code and customer name.
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::models::calendar_feed::PromiseDateEntry;
+use crate::models::ticket::TicketStatus;
+
+/// Longest content line in octets before it's folded.
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Format of DATE-TIME values in UTC.
+const UTC_DATE_TIME: &str = "%Y%m%dT%H%M%SZ";
+
+/// Render a store's promise dates as an iCalendar feed.
+pub fn promise_date_feed(
+    store_name: &str,
+    entries: &[PromiseDateEntry],
+    now: DateTime<Utc>,
+) -> String {
+    let mut calendar = String::new();
+    push_line(&mut calendar, "BEGIN:VCALENDAR");
+    push_line(&mut calendar, "VERSION:2.0");
+    push_line(&mut calendar, "PRODID:-//Facet//Promise Dates//EN");
+    push_line(&mut calendar, "CALSCALE:GREGORIAN");
+    push_line(&mut calendar, "METHOD:PUBLISH");
+    push_line(
+        &mut calendar,
+        &format!(
+            "X-WR-CALNAME:{}",
+            escape_text(&format!("{} promise dates", store_name))
+        ),
+    );
+
+    for entry in entries {
+        let summary = format!(
+            "{}{} {}",
+            if entry.is_rush { "RUSH " } else { "" },
+            entry.friendly_code,
+            entry.customer_name
+        );
+        let description = format!(
+            "{}\nStatus: {}",
+            entry.item_description,
+            status_label(entry.status)
+        );
+
+        push_line(&mut calendar, "BEGIN:VEVENT");
+        push_line(&mut calendar, &format!("UID:{}@facet", entry.ticket_id));
+        push_line(
+            &mut calendar,
+            &format!("DTSTAMP:{}", now.format(UTC_DATE_TIME)),
+        );
+        push_line(
+            &mut calendar,
+            &format!("LAST-MODIFIED:{}", entry.updated_at.format(UTC_DATE_TIME)),
+        );
+        push_line(
+            &mut calendar,
+            &format!("DTSTART;VALUE=DATE:{}", entry.promise_date.format("%Y%m%d")),
+        );
+        push_line(
+            &mut calendar,
+            &format!(
+                "DTEND;VALUE=DATE:{}",
+                (entry.promise_date + Duration::days(1)).format("%Y%m%d")
+            ),
+        );
+        push_line(&mut calendar, &format!("SUMMARY:{}", escape_text(&summary)));
+        push_line(
+            &mut calendar,
+            &format!("DESCRIPTION:{}", escape_text(&description)),
+        );
+        push_line(&mut calendar, "TRANSP:TRANSPARENT");
+        push_line(&mut calendar, "END:VEVENT");
+    }
+
+    push_line(&mut calendar, "END:VCALENDAR");
+    calendar
+}
+
+/// Status as shown in an event's description.
+fn status_label(status: TicketStatus) -> &'static str {
+    match status {
+        TicketStatus::Intake => "Intake",
+        TicketStatus::InProgress => "In progress",
+        TicketStatus::WaitingOnParts => "Waiting on parts",
+        TicketStatus::ReadyForPickup => "Ready for pickup",
+        TicketStatus::Closed => "Closed",
+        TicketStatus::Archived => "Archived",
+    }
+}
+
+/// Escape a TEXT value: backslashes, semicolons, commas, and line breaks.
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append a content line, folded at 75 octets without splitting a
+/// character, and ended with CRLF.
+fn push_line(calendar: &mut String, line: &str) {
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            calendar.push_str("\r\n ");
+            // The leading space counts toward the continuation line
+            octets = 1;
+        }
+        calendar.push(c);
+        octets += c.len_utf8();
+    }
+    calendar.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+    use uuid::Uuid;
+
+    fn entry() -> PromiseDateEntry {
+        PromiseDateEntry {
+            ticket_id: Uuid::nil(),
+            friendly_code: "JR-0042".to_string(),
+            customer_name: "Smith, Ann".to_string(),
+            item_description: "Gold ring; sizing".to_string(),
+            status: TicketStatus::InProgress,
+            is_rush: true,
+            promise_date: NaiveDate::from_ymd_opt(2026, 10, 20).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2026, 10, 15, 9, 30, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_promise_date_feed_event() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap();
+        let feed = promise_date_feed("Example Jewelers", &[entry()], now);
+
+        assert!(feed.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+        assert!(feed.ends_with("END:VCALENDAR\r\n"));
+        assert!(feed.contains("X-WR-CALNAME:Example Jewelers promise dates\r\n"));
+        assert!(feed.contains("DTSTAMP:20261016T120000Z\r\n"));
+        assert!(feed.contains("DTSTART;VALUE=DATE:20261020\r\n"));
+        assert!(feed.contains("DTEND;VALUE=DATE:20261021\r\n"));
+        assert!(feed.contains("SUMMARY:RUSH JR-0042 Smith\\, Ann\r\n"));
+        assert!(feed.contains("DESCRIPTION:Gold ring\\; sizing\\nStatus: In progress\r\n"));
+    }
+
+    #[test]
+    fn test_promise_date_feed_empty() {
+        let feed = promise_date_feed("Store", &[], Utc::now());
+        assert!(!feed.contains("BEGIN:VEVENT"));
+    }
+
+    #[test]
+    fn test_push_line_folds_long_lines() {
+        let mut calendar = String::new();
+        let line = format!("SUMMARY:{}", "é".repeat(60));
+        push_line(&mut calendar, &line);
+
+        let lines: Vec<&str> = calendar.trim_end_matches("\r\n").split("\r\n").collect();
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|line| line.len() <= MAX_LINE_OCTETS));
+        assert!(lines[1..].iter().all(|line| line.starts_with(' ')));
+        let unfolded: String = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| if i == 0 { *line } else { &line[1..] })
+            .collect();
+        assert_eq!(unfolded, line);
+    }
+}
@@ -0,0 +1,133 @@
+//! Referential integrity checks.
+//!
+//! Finds records that are still in the database but point at something that
+//! is gone or deactivated: photos whose stored file is missing, open tickets
+//! at inactive locations or assigned to inactive employees, field history
+//! naming unknown employees, and notes on deleted tickets. Nothing is
+//! changed; each problem comes with a suggested fix for an admin.
+
+use crate::error::AppError;
+use crate::models::integrity::{IntegrityCheck, IntegrityIssue, IntegrityRecord, IntegrityReport};
+use crate::repositories::IntegrityRepository;
+use crate::storage::StorageBackend;
+use chrono::Utc;
+use sqlx::PgPool;
+
+/// How often the background task runs the integrity checks.
+pub const INTEGRITY_CHECK_INTERVAL: std::time::Duration =
+    std::time::Duration::from_secs(24 * 60 * 60);
+
+/// Describe a flagged record for the report.
+fn describe(check: IntegrityCheck, record: &IntegrityRecord) -> String {
+    let code = &record.friendly_code;
+    let detail = &record.detail;
+    match check {
+        IntegrityCheck::MissingPhotoObject => {
+            format!(
+                "Photo on ticket {} is missing from storage ({})",
+                code, detail
+            )
+        }
+        IntegrityCheck::InactiveLocation => {
+            format!("Ticket {} is stored at inactive location {}", code, detail)
+        }
+        IntegrityCheck::InactiveAssignee => {
+            format!(
+                "Ticket {} is assigned to inactive employee {}",
+                code, detail
+            )
+        }
+        IntegrityCheck::UnknownEmployeeReference => format!(
+            "History on ticket {} references unknown employee {}",
+            code, detail
+        ),
+        IntegrityCheck::OrphanedNote => {
+            format!("Note by {} is attached to deleted ticket {}", detail, code)
+        }
+    }
+}
+
+fn to_issue(check: IntegrityCheck, record: IntegrityRecord) -> IntegrityIssue {
+    IntegrityIssue {
+        check,
+        message: describe(check, &record),
+        suggested_fix: check.suggested_fix().to_string(),
+        record_id: record.record_id,
+        ticket_id: record.ticket_id,
+        friendly_code: record.friendly_code,
+    }
+}
+
+/// Whether a photo's stored file is present.
+async fn photo_exists(storage: &dyn StorageBackend, storage_key: &str) -> Result<bool, AppError> {
+    storage
+        .exists(storage_key)
+        .await
+        .map_err(|e| AppError::server_error(format!("Failed to check photo storage: {}", e)))
+}
+
+/// Run every integrity check.
+///
+/// Photos are looked up in `storage`, the configured storage backend.
+pub async fn run_integrity_checks(
+    pool: &PgPool,
+    storage: &dyn StorageBackend,
+) -> Result<IntegrityReport, AppError> {
+    let mut issues = Vec::new();
+
+    for photo in IntegrityRepository::photos(pool).await? {
+        if !photo_exists(storage, &photo.detail).await? {
+            issues.push(to_issue(IntegrityCheck::MissingPhotoObject, photo));
+        }
+    }
+
+    let flagged = [
+        (
+            IntegrityCheck::InactiveLocation,
+            IntegrityRepository::tickets_at_inactive_locations(pool).await?,
+        ),
+        (
+            IntegrityCheck::InactiveAssignee,
+            IntegrityRepository::tickets_with_inactive_assignees(pool).await?,
+        ),
+        (
+            IntegrityCheck::UnknownEmployeeReference,
+            IntegrityRepository::unknown_employee_references(pool).await?,
+        ),
+        (
+            IntegrityCheck::OrphanedNote,
+            IntegrityRepository::notes_on_deleted_tickets(pool).await?,
+        ),
+    ];
+    for (check, records) in flagged {
+        issues.extend(records.into_iter().map(|record| to_issue(check, record)));
+    }
+
+    Ok(IntegrityReport::new(Utc::now(), issues))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_to_issue_describes_record() {
+        let record = IntegrityRecord {
+            record_id: Uuid::new_v4(),
+            ticket_id: Uuid::new_v4(),
+            friendly_code: "JR-0042".to_string(),
+            detail: "Back Safe".to_string(),
+        };
+        let issue = to_issue(IntegrityCheck::InactiveLocation, record);
+
+        assert_eq!(
+            issue.message,
+            "Ticket JR-0042 is stored at inactive location Back Safe"
+        );
+        assert_eq!(
+            issue.suggested_fix,
+            IntegrityCheck::InactiveLocation.suggested_fix()
+        );
+    }
+}
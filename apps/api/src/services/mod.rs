@@ -3,10 +3,23 @@
 //! Services contain the core business logic and orchestrate operations
 //! between handlers, repositories, and external integrations.
 
+pub mod archive;
+pub mod campaigns;
+pub mod ical;
+pub mod integrity;
+pub mod notifications;
 pub mod pdf;
+pub mod photos;
+pub mod pin_lockout;
+pub mod print;
+pub mod reminders;
+pub mod scheduler;
+pub mod shutdown;
+pub mod storage_reconcile;
+pub mod warmup;
+pub mod webhooks;
 
 // Future service modules:
 // pub mod ticket_service;
 // pub mod customer_service;
 // pub mod employee_service;
-// pub mod photo_service;
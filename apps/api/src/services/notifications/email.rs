@@ -0,0 +1,164 @@
+//! Email senders.
+//!
+//! [`EmailSender`] is the extension point for sending email. The SMTP
+//! implementation is used when `SMTP_*` variables are configured.
+
+use async_trait::async_trait;
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use thiserror::Error;
+
+/// Errors from an email sender.
+#[derive(Debug, Error)]
+pub enum EmailError {
+    /// A sender or recipient address could not be parsed.
+    #[error("Invalid email address: {0}")]
+    InvalidAddress(String),
+
+    /// The message could not be built or delivered.
+    #[error("Email delivery failed: {0}")]
+    Transport(String),
+}
+
+/// A service that can send plain-text email.
+#[async_trait]
+pub trait EmailSender: Send + Sync {
+    /// Short sender name recorded in the notification log (e.g., "smtp").
+    fn name(&self) -> &'static str;
+
+    /// Send a plain-text message.
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), EmailError>;
+}
+
+/// How the SMTP connection is secured.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SmtpTls {
+    /// Upgrade a plain connection with STARTTLS (port 587)
+    #[default]
+    StartTls,
+    /// Implicit TLS (port 465)
+    Tls,
+    /// No encryption (local development relays only)
+    None,
+}
+
+impl SmtpTls {
+    /// Parse an `SMTP_TLS` value (`starttls`, `tls`, or `none`).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "starttls" => Some(Self::StartTls),
+            "tls" => Some(Self::Tls),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// SMTP relay settings.
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    pub port: u16,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    /// From address, optionally with a display name ("Store <shop@example.com>")
+    pub from_address: String,
+    pub tls: SmtpTls,
+}
+
+/// Email sender backed by an SMTP relay.
+pub struct SmtpEmailSender {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpEmailSender {
+    /// Create an SMTP sender. Fails if the host or from address is invalid.
+    pub fn new(config: SmtpConfig) -> Result<Self, EmailError> {
+        let from = parse_mailbox(&config.from_address)?;
+
+        let mut builder = match config.tls {
+            SmtpTls::StartTls => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.host)
+                .map_err(|e| EmailError::Transport(e.to_string()))?,
+            SmtpTls::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&config.host)
+                .map_err(|e| EmailError::Transport(e.to_string()))?,
+            SmtpTls::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&config.host),
+        }
+        .port(config.port);
+
+        if let (Some(username), Some(password)) = (config.username, config.password) {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+            from,
+        })
+    }
+}
+
+#[async_trait]
+impl EmailSender for SmtpEmailSender {
+    fn name(&self) -> &'static str {
+        "smtp"
+    }
+
+    async fn send(&self, to: &str, subject: &str, body: &str) -> Result<(), EmailError> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(parse_mailbox(to)?)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body.to_string())
+            .map_err(|e| EmailError::Transport(e.to_string()))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| EmailError::Transport(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+fn parse_mailbox(address: &str) -> Result<Mailbox, EmailError> {
+    address
+        .parse()
+        .map_err(|_| EmailError::InvalidAddress(address.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_smtp_tls_parse() {
+        assert_eq!(SmtpTls::parse("STARTTLS"), Some(SmtpTls::StartTls));
+        assert_eq!(SmtpTls::parse("tls"), Some(SmtpTls::Tls));
+        assert_eq!(SmtpTls::parse(" none "), Some(SmtpTls::None));
+        assert_eq!(SmtpTls::parse("ssl"), None);
+    }
+
+    #[test]
+    fn test_parse_mailbox() {
+        assert!(parse_mailbox("Example Jewelers <shop@example.com>").is_ok());
+        assert!(matches!(
+            parse_mailbox("not an address"),
+            Err(EmailError::InvalidAddress(_))
+        ));
+    }
+
+    #[test]
+    fn test_smtp_sender_rejects_bad_from_address() {
+        let result = SmtpEmailSender::new(SmtpConfig {
+            host: "localhost".to_string(),
+            port: 1025,
+            username: None,
+            password: None,
+            from_address: "nope".to_string(),
+            tls: SmtpTls::None,
+        });
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,708 @@
+//! Customer notifications.
+//!
+//! Sends customer-facing messages for ticket events and records each one in
+//! the notification log. Delivery never blocks or fails the request that
+//! triggered it; handlers spawn the send and failures are logged instead.
+//!
+//! Each provider sits behind a circuit breaker. While a breaker is open,
+//! ticket messages stay `pending` in the log and
+//! [`NotificationService::retry_pending`] sends them once the provider
+//! recovers.
+
+pub mod email;
+pub mod sms;
+pub mod templates;
+
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::error::AppError;
+use crate::models::notification::{
+    CreateNotificationLog, NotificationChannel, NotificationEvent, NotificationLog,
+    NotificationStatus,
+};
+use crate::models::ticket::Ticket;
+use crate::models::Customer;
+use crate::repositories::{
+    CustomerRepository, NotificationRepository, NotificationTemplateRepository,
+    StoreSettingsRepository,
+};
+use crate::utils::circuit_breaker::{BreakerStatus, CircuitBreaker};
+
+pub use email::{EmailError, EmailSender, SmtpConfig, SmtpEmailSender, SmtpTls};
+pub use sms::{SmsError, SmsProvider, SmsReceipt, TwilioConfig, TwilioSmsProvider};
+pub use templates::{unknown_placeholders, TemplateContext, PLACEHOLDERS};
+
+/// How often the background worker resends queued ticket messages.
+pub const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Pending ticket messages older than this are given up on rather than sent
+/// late.
+pub const PENDING_MAX_AGE_HOURS: i64 = 24;
+
+/// How many pending messages one retry pass sends.
+const RETRY_BATCH_SIZE: i64 = 50;
+
+/// Sends customer notifications through the configured providers.
+#[derive(Clone)]
+pub struct NotificationService {
+    sms: Option<Arc<dyn SmsProvider>>,
+    email: Option<Arc<dyn EmailSender>>,
+    sms_breaker: Arc<CircuitBreaker>,
+    email_breaker: Arc<CircuitBreaker>,
+}
+
+impl Default for NotificationService {
+    fn default() -> Self {
+        Self {
+            sms: None,
+            email: None,
+            sms_breaker: Arc::new(CircuitBreaker::new("sms")),
+            email_breaker: Arc::new(CircuitBreaker::new("email")),
+        }
+    }
+}
+
+/// A configured provider chosen for one message, with its breaker.
+enum Sender<'a> {
+    Sms(&'a dyn SmsProvider, &'a CircuitBreaker),
+    Email(&'a dyn EmailSender, &'a CircuitBreaker),
+}
+
+/// Why a message was not sent.
+#[derive(Debug)]
+enum SendError {
+    /// The provider's breaker is open; the message can be sent later
+    Unavailable,
+    /// The provider rejected the message or could not be reached
+    Failed(String),
+}
+
+impl Sender<'_> {
+    /// Send the message, returning the provider's message ID if it gave one.
+    ///
+    /// Only transport failures count against the breaker; a rejected message
+    /// means the provider is up.
+    async fn send(
+        &self,
+        to: &str,
+        subject: Option<&str>,
+        body: &str,
+    ) -> Result<Option<String>, SendError> {
+        let breaker = match self {
+            Sender::Sms(_, breaker) | Sender::Email(_, breaker) => *breaker,
+        };
+        breaker.try_acquire().map_err(|_| SendError::Unavailable)?;
+
+        let (result, outage) = match self {
+            Sender::Sms(provider, _) => match provider.send(to, body).await {
+                Ok(receipt) => (Ok(receipt.message_id), false),
+                Err(e) => (Err(e.to_string()), matches!(e, SmsError::Transport(_))),
+            },
+            Sender::Email(sender, _) => {
+                match sender.send(to, subject.unwrap_or_default(), body).await {
+                    Ok(()) => (Ok(None), false),
+                    Err(e) => (Err(e.to_string()), matches!(e, EmailError::Transport(_))),
+                }
+            }
+        };
+
+        if outage {
+            breaker.record_failure();
+        } else {
+            breaker.record_success();
+        }
+        result.map_err(SendError::Failed)
+    }
+}
+
+/// Outcome of a message sent with [`NotificationService::send_to_customer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryOutcome {
+    /// Accepted by the provider
+    Sent {
+        recipient: String,
+        message_id: Option<String>,
+    },
+    /// The provider rejected the message or could not be reached
+    Failed { recipient: String, error: String },
+    /// Not sent (opted out, no usable contact details, or no provider)
+    Skipped { reason: &'static str },
+    /// Not attempted because the provider's breaker is open; send it later
+    Deferred,
+}
+
+/// A message ready to be logged and sent.
+struct Outgoing<'a> {
+    channel: NotificationChannel,
+    provider_name: Option<&'static str>,
+    /// Who to send to, or why the message can't be sent
+    target: Result<(Sender<'a>, String), &'static str>,
+    /// Contact details as stored, logged when the message is skipped
+    raw_recipient: Option<String>,
+    subject: Option<String>,
+    message: String,
+}
+
+impl NotificationService {
+    /// Create a service with no providers (notifications are logged as skipped).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the given provider for text messages.
+    pub fn with_sms(mut self, provider: Arc<dyn SmsProvider>) -> Self {
+        self.sms = Some(provider);
+        self
+    }
+
+    /// Use the given sender for email.
+    pub fn with_email(mut self, sender: Arc<dyn EmailSender>) -> Self {
+        self.email = Some(sender);
+        self
+    }
+
+    /// Whether a message on `channel` would be attempted right now.
+    ///
+    /// False while the channel's breaker is open, so batch senders can wait
+    /// instead of claiming work they can't send.
+    pub fn channel_available(&self, channel: NotificationChannel) -> bool {
+        match channel {
+            NotificationChannel::Sms => self.sms_breaker.is_available(),
+            NotificationChannel::Email => self.email_breaker.is_available(),
+        }
+    }
+
+    /// Breaker state for each configured provider.
+    pub fn breakers(&self) -> Vec<BreakerStatus> {
+        let mut breakers = Vec::new();
+        if self.sms.is_some() {
+            breakers.push(self.sms_breaker.status());
+        }
+        if self.email.is_some() {
+            breakers.push(self.email_breaker.status());
+        }
+        breakers
+    }
+
+    /// Send ticket messages left pending while a provider was down.
+    ///
+    /// Messages older than [`PENDING_MAX_AGE_HOURS`] are marked failed
+    /// instead, since a days-late "ready for pickup" text does more harm than
+    /// good. Returns the number of messages attempted.
+    pub async fn retry_pending(&self, pool: &sqlx::PgPool) -> Result<usize, AppError> {
+        let cutoff = Utc::now() - Duration::hours(PENDING_MAX_AGE_HOURS);
+        let expired = NotificationRepository::expire_pending(
+            pool,
+            cutoff,
+            "Provider was unavailable and the message went stale",
+        )
+        .await?;
+        if expired > 0 {
+            tracing::warn!("Gave up on {} stale pending notifications", expired);
+        }
+
+        // Leave messages from the last minute to the request that logged them
+        let settled = Utc::now() - Duration::minutes(1);
+        let pending =
+            NotificationRepository::claim_pending(pool, settled, RETRY_BATCH_SIZE).await?;
+
+        let mut attempted = 0;
+        for log in pending {
+            let sender = match log.channel {
+                NotificationChannel::Sms => self
+                    .sms
+                    .as_ref()
+                    .map(|p| Sender::Sms(p.as_ref(), &self.sms_breaker)),
+                NotificationChannel::Email => self
+                    .email
+                    .as_ref()
+                    .map(|s| Sender::Email(s.as_ref(), &self.email_breaker)),
+            };
+            let (Some(sender), Some(to)) = (sender, log.recipient.as_deref()) else {
+                continue;
+            };
+
+            match sender.send(to, log.subject.as_deref(), &log.message).await {
+                Ok(message_id) => {
+                    attempted += 1;
+                    NotificationRepository::mark_sent(
+                        pool,
+                        log.notification_id,
+                        message_id.as_deref(),
+                    )
+                    .await?;
+                }
+                Err(SendError::Unavailable) => {}
+                Err(SendError::Failed(err)) => {
+                    attempted += 1;
+                    NotificationRepository::mark_failed(pool, log.notification_id, &err).await?;
+                }
+            }
+        }
+        Ok(attempted)
+    }
+
+    /// Email the customer a confirmation that their item was taken in.
+    pub async fn notify_intake(
+        &self,
+        pool: &sqlx::PgPool,
+        ticket: &Ticket,
+    ) -> Result<Vec<NotificationLog>, AppError> {
+        let Some((customer, context)) = Self::load_context(pool, ticket).await? else {
+            return Ok(Vec::new());
+        };
+
+        let mut logs = Vec::new();
+        if let Some(outgoing) = self
+            .email_for(
+                pool,
+                &customer,
+                &context,
+                NotificationEvent::IntakeConfirmation,
+            )
+            .await?
+        {
+            logs.push(Self::deliver(pool, ticket, outgoing).await?);
+        }
+        Ok(logs)
+    }
+
+    /// Text and email the customer that their item is ready for pickup.
+    pub async fn notify_ready_for_pickup(
+        &self,
+        pool: &sqlx::PgPool,
+        ticket: &Ticket,
+    ) -> Result<Vec<NotificationLog>, AppError> {
+        let Some((customer, context)) = Self::load_context(pool, ticket).await? else {
+            return Ok(Vec::new());
+        };
+
+        let sms = self.sms_for(
+            &customer,
+            ready_for_pickup_message(&context.store_name, &customer.name, &ticket.friendly_code),
+        );
+        let mut logs = vec![Self::deliver(pool, ticket, sms).await?];
+
+        if let Some(outgoing) = self
+            .email_for(pool, &customer, &context, NotificationEvent::ReadyForPickup)
+            .await?
+        {
+            logs.push(Self::deliver(pool, ticket, outgoing).await?);
+        }
+        Ok(logs)
+    }
+
+    /// Text and email the customer that their promise date moved later.
+    ///
+    /// Only sent when the store has enabled the promise date template; the
+    /// ticket carries the new date and `reason` is the chosen reason's label.
+    pub async fn notify_promise_date_changed(
+        &self,
+        pool: &sqlx::PgPool,
+        ticket: &Ticket,
+        reason: &str,
+    ) -> Result<Vec<NotificationLog>, AppError> {
+        let enabled = NotificationTemplateRepository::find_by_event(
+            pool,
+            NotificationEvent::PromiseDateChanged,
+        )
+        .await?
+        .is_some_and(|template| template.is_enabled);
+        if !enabled {
+            return Ok(Vec::new());
+        }
+        let Some((customer, mut context)) = Self::load_context(pool, ticket).await? else {
+            return Ok(Vec::new());
+        };
+        context.promise_date_reason = Some(reason.to_string());
+
+        let sms = self.sms_for(
+            &customer,
+            promise_date_changed_message(&context, &customer.name),
+        );
+        let mut logs = vec![Self::deliver(pool, ticket, sms).await?];
+
+        if let Some(outgoing) = self
+            .email_for(
+                pool,
+                &customer,
+                &context,
+                NotificationEvent::PromiseDateChanged,
+            )
+            .await?
+        {
+            logs.push(Self::deliver(pool, ticket, outgoing).await?);
+        }
+        Ok(logs)
+    }
+
+    /// Send a message to a customer outside of a ticket event (e.g., a campaign).
+    ///
+    /// Respects the customer's opt-out. Nothing is written to the ticket
+    /// notification log; the caller records the outcome.
+    pub async fn send_to_customer(
+        &self,
+        customer: &Customer,
+        channel: NotificationChannel,
+        subject: Option<&str>,
+        message: &str,
+    ) -> DeliveryOutcome {
+        let target = match channel {
+            NotificationChannel::Sms => self.sms_target(customer),
+            NotificationChannel::Email => self.email_target(customer),
+        };
+        let (sender, to) = match target {
+            Ok(target) => target,
+            Err(reason) => return DeliveryOutcome::Skipped { reason },
+        };
+
+        match sender.send(&to, subject, message).await {
+            Ok(message_id) => DeliveryOutcome::Sent {
+                recipient: to,
+                message_id,
+            },
+            Err(SendError::Unavailable) => DeliveryOutcome::Deferred,
+            Err(SendError::Failed(error)) => DeliveryOutcome::Failed {
+                recipient: to,
+                error,
+            },
+        }
+    }
+
+    /// Send a message to the store or an employee (e.g., the overdue digest).
+    ///
+    /// `to` is an email address or a phone number, depending on `channel`.
+    /// Nothing is written to the ticket notification log.
+    pub async fn send_to_staff(
+        &self,
+        channel: NotificationChannel,
+        to: &str,
+        subject: Option<&str>,
+        message: &str,
+    ) -> DeliveryOutcome {
+        let target = match channel {
+            NotificationChannel::Sms => match (&self.sms, sms::to_e164(to)) {
+                (None, _) => Err("SMS is not configured"),
+                (_, None) => Err("Phone number cannot receive texts"),
+                (Some(provider), Some(to)) => {
+                    Ok((Sender::Sms(provider.as_ref(), &self.sms_breaker), to))
+                }
+            },
+            NotificationChannel::Email => match &self.email {
+                None => Err("Email is not configured"),
+                Some(sender) => Ok((
+                    Sender::Email(sender.as_ref(), &self.email_breaker),
+                    to.to_string(),
+                )),
+            },
+        };
+        let (sender, to) = match target {
+            Ok(target) => target,
+            Err(reason) => return DeliveryOutcome::Skipped { reason },
+        };
+
+        match sender.send(&to, subject, message).await {
+            Ok(message_id) => DeliveryOutcome::Sent {
+                recipient: to,
+                message_id,
+            },
+            Err(SendError::Unavailable) => DeliveryOutcome::Deferred,
+            Err(SendError::Failed(error)) => DeliveryOutcome::Failed {
+                recipient: to,
+                error,
+            },
+        }
+    }
+
+    /// Load the customer and template values for a ticket, with the name and
+    /// phone of the store holding it.
+    ///
+    /// Returns None if the store has turned notifications off or the ticket
+    /// was created in training mode.
+    async fn load_context(
+        pool: &sqlx::PgPool,
+        ticket: &Ticket,
+    ) -> Result<Option<(Customer, TemplateContext)>, AppError> {
+        if ticket.is_training {
+            return Ok(None);
+        }
+        let settings = StoreSettingsRepository::get_store_settings(pool, ticket.store_id).await?;
+        if !settings.notifications_enabled {
+            return Ok(None);
+        }
+        let customer = CustomerRepository::find_by_id(pool, ticket.customer_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("Customer not found"))?;
+
+        let context = TemplateContext {
+            customer_name: customer.name.clone(),
+            friendly_code: ticket.friendly_code.clone(),
+            item_description: ticket.item_description.clone(),
+            promise_date: ticket.promise_date,
+            promise_date_reason: None,
+            store_name: settings.store_name,
+            store_phone: settings.store_phone,
+        };
+        Ok(Some((customer, context)))
+    }
+
+    /// Where to text the customer, or why they can't be texted.
+    fn sms_target(&self, customer: &Customer) -> Result<(Sender<'_>, String), &'static str> {
+        let phone = customer.phone.as_deref();
+        match (&self.sms, phone, phone.and_then(sms::to_e164)) {
+            _ if customer.notifications_opt_out => Err("Customer opted out of notifications"),
+            (None, _, _) => Err("SMS is not configured"),
+            (_, None, _) => Err("Customer has no phone number"),
+            (_, Some(_), None) => Err("Customer phone number cannot receive texts"),
+            (Some(provider), Some(_), Some(to)) => {
+                Ok((Sender::Sms(provider.as_ref(), &self.sms_breaker), to))
+            }
+        }
+    }
+
+    /// Where to email the customer, or why they can't be emailed.
+    fn email_target(&self, customer: &Customer) -> Result<(Sender<'_>, String), &'static str> {
+        match (&self.email, &customer.email) {
+            _ if customer.notifications_opt_out => Err("Customer opted out of notifications"),
+            (None, _) => Err("Email is not configured"),
+            (_, None) => Err("Customer has no email address"),
+            (Some(sender), Some(to)) => Ok((
+                Sender::Email(sender.as_ref(), &self.email_breaker),
+                to.clone(),
+            )),
+        }
+    }
+
+    /// Prepare a text message to the customer.
+    fn sms_for(&self, customer: &Customer, message: String) -> Outgoing<'_> {
+        Outgoing {
+            channel: NotificationChannel::Sms,
+            provider_name: self.sms.as_ref().map(|p| p.name()),
+            target: self.sms_target(customer),
+            raw_recipient: customer.phone.clone(),
+            subject: None,
+            message,
+        }
+    }
+
+    /// Prepare an email to the customer from the event's template.
+    ///
+    /// Returns None if the store has disabled the template.
+    async fn email_for(
+        &self,
+        pool: &sqlx::PgPool,
+        customer: &Customer,
+        context: &TemplateContext,
+        event: NotificationEvent,
+    ) -> Result<Option<Outgoing<'_>>, AppError> {
+        let Some(template) = NotificationTemplateRepository::find_by_event(pool, event).await?
+        else {
+            return Ok(None);
+        };
+        if !template.is_enabled {
+            return Ok(None);
+        }
+
+        Ok(Some(Outgoing {
+            channel: NotificationChannel::Email,
+            provider_name: self.email.as_ref().map(|s| s.name()),
+            target: self.email_target(customer),
+            raw_recipient: customer.email.clone(),
+            subject: Some(context.render(&template.subject)),
+            message: context.render(&template.body),
+        }))
+    }
+
+    /// Log a message, send it if possible, and record the outcome.
+    async fn deliver(
+        pool: &sqlx::PgPool,
+        ticket: &Ticket,
+        outgoing: Outgoing<'_>,
+    ) -> Result<NotificationLog, AppError> {
+        let log = NotificationRepository::create(
+            pool,
+            CreateNotificationLog {
+                ticket_id: ticket.ticket_id,
+                channel: outgoing.channel,
+                recipient: match &outgoing.target {
+                    Ok((_, to)) => Some(to.clone()),
+                    Err(_) => outgoing.raw_recipient.clone(),
+                },
+                subject: outgoing.subject.clone(),
+                message: outgoing.message.clone(),
+                status: if outgoing.target.is_ok() {
+                    NotificationStatus::Pending
+                } else {
+                    NotificationStatus::Skipped
+                },
+                provider: outgoing.provider_name.map(String::from),
+                error: outgoing
+                    .target
+                    .as_ref()
+                    .err()
+                    .map(|reason| reason.to_string()),
+            },
+        )
+        .await?;
+
+        let Ok((sender, to)) = outgoing.target else {
+            return Ok(log);
+        };
+
+        match sender
+            .send(&to, outgoing.subject.as_deref(), &outgoing.message)
+            .await
+        {
+            Ok(message_id) => {
+                NotificationRepository::mark_sent(pool, log.notification_id, message_id.as_deref())
+                    .await
+            }
+            Err(SendError::Unavailable) => {
+                tracing::info!(
+                    "{:?} provider unavailable; notification for ticket {} queued for retry",
+                    outgoing.channel,
+                    ticket.friendly_code
+                );
+                Ok(log)
+            }
+            Err(SendError::Failed(err)) => {
+                tracing::warn!(
+                    "{:?} notification for ticket {} failed: {}",
+                    outgoing.channel,
+                    ticket.friendly_code,
+                    err
+                );
+                NotificationRepository::mark_failed(pool, log.notification_id, &err).await
+            }
+        }
+    }
+}
+
+/// Build the ready-for-pickup text.
+///
+/// Greets the customer by first name and includes the ticket code so the
+/// counter can find the item quickly.
+pub fn ready_for_pickup_message(
+    store_name: &str,
+    customer_name: &str,
+    friendly_code: &str,
+) -> String {
+    let first_name = customer_name.split_whitespace().next().unwrap_or("there");
+    format!(
+        "Hi {}, your item (ticket {}) is ready for pickup at {}.",
+        first_name, friendly_code, store_name
+    )
+}
+
+/// Build the promise date change text.
+///
+/// Gives the new date and the reason so the customer doesn't need to call.
+pub fn promise_date_changed_message(context: &TemplateContext, customer_name: &str) -> String {
+    let first_name = customer_name.split_whitespace().next().unwrap_or("there");
+    let reason = context
+        .promise_date_reason
+        .as_deref()
+        .map(|reason| format!(" {}.", reason.trim_end_matches('.')))
+        .unwrap_or_default();
+    format!(
+        "Hi {}, your item (ticket {}) at {} will now be ready by {}.{}",
+        first_name,
+        context.friendly_code,
+        context.store_name,
+        context.value("promise_date").unwrap_or_default(),
+        reason
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ready_for_pickup_message() {
+        assert_eq!(
+            ready_for_pickup_message("Example Jewelers", "Jane Doe", "JR-0042"),
+            "Hi Jane, your item (ticket JR-0042) is ready for pickup at Example Jewelers."
+        );
+    }
+
+    #[test]
+    fn test_promise_date_changed_message() {
+        let mut context = TemplateContext::sample("Example Jewelers".to_string(), None);
+        assert_eq!(
+            promise_date_changed_message(&context, "Jane Doe"),
+            "Hi Jane, your item (ticket JR-0042) at Example Jewelers will now be ready by \
+             October 20, 2026. We are waiting on parts."
+        );
+
+        context.promise_date_reason = None;
+        assert!(promise_date_changed_message(&context, "Jane Doe").ends_with("October 20, 2026."));
+    }
+
+    #[tokio::test]
+    async fn test_send_to_customer_skips_without_target() {
+        let mut customer = Customer {
+            customer_id: uuid::Uuid::new_v4(),
+            name: "Jane Doe".to_string(),
+            phone: Some("555-0100".to_string()),
+            email: None,
+            notifications_opt_out: false,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+        let service = NotificationService::new();
+
+        assert_eq!(
+            service
+                .send_to_customer(&customer, NotificationChannel::Sms, None, "Hi")
+                .await,
+            DeliveryOutcome::Skipped {
+                reason: "SMS is not configured"
+            }
+        );
+
+        customer.notifications_opt_out = true;
+        assert_eq!(
+            service
+                .send_to_customer(&customer, NotificationChannel::Email, Some("Hi"), "Hi")
+                .await,
+            DeliveryOutcome::Skipped {
+                reason: "Customer opted out of notifications"
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_to_staff_skips_without_provider() {
+        let service = NotificationService::new();
+        assert_eq!(
+            service
+                .send_to_staff(
+                    NotificationChannel::Email,
+                    "shop@example.com",
+                    Some("Hi"),
+                    "Hi"
+                )
+                .await,
+            DeliveryOutcome::Skipped {
+                reason: "Email is not configured"
+            }
+        );
+        assert_eq!(
+            service
+                .send_to_staff(NotificationChannel::Sms, "555-0100", None, "Hi")
+                .await,
+            DeliveryOutcome::Skipped {
+                reason: "SMS is not configured"
+            }
+        );
+    }
+
+    #[test]
+    fn test_ready_for_pickup_message_blank_name() {
+        let message = ready_for_pickup_message("Shop", "  ", "JR-1");
+        assert!(message.starts_with("Hi there,"));
+    }
+}
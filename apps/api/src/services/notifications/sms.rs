@@ -0,0 +1,181 @@
+//! SMS providers.
+//!
+//! [`SmsProvider`] is the extension point for sending text messages. The
+//! Twilio implementation is used when `TWILIO_*` variables are configured.
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use thiserror::Error;
+
+/// Twilio REST API base URL.
+const TWILIO_API_BASE: &str = "https://api.twilio.com/2010-04-01";
+
+/// Errors from an SMS provider.
+#[derive(Debug, Error)]
+pub enum SmsError {
+    /// The provider could not be reached.
+    #[error("SMS provider request failed: {0}")]
+    Transport(String),
+
+    /// The provider refused the message (bad number, auth failure, etc.).
+    #[error("SMS provider rejected message: {0}")]
+    Rejected(String),
+}
+
+/// Result of a message accepted by the provider.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmsReceipt {
+    /// Provider's identifier for the message, if returned
+    pub message_id: Option<String>,
+}
+
+/// A service that can send text messages.
+#[async_trait]
+pub trait SmsProvider: Send + Sync {
+    /// Short provider name recorded in the notification log (e.g., "twilio").
+    fn name(&self) -> &'static str;
+
+    /// Send `body` to `to` (an E.164 phone number).
+    async fn send(&self, to: &str, body: &str) -> Result<SmsReceipt, SmsError>;
+}
+
+/// Twilio account settings.
+#[derive(Debug, Clone)]
+pub struct TwilioConfig {
+    pub account_sid: String,
+    pub auth_token: String,
+    /// Sending number in E.164 format
+    pub from_number: String,
+}
+
+/// SMS provider backed by the Twilio Messages API.
+pub struct TwilioSmsProvider {
+    config: TwilioConfig,
+    client: reqwest::Client,
+}
+
+impl TwilioSmsProvider {
+    /// Create a Twilio provider with the given account settings.
+    pub fn new(config: TwilioConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TwilioMessage {
+    sid: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TwilioError {
+    message: Option<String>,
+}
+
+#[async_trait]
+impl SmsProvider for TwilioSmsProvider {
+    fn name(&self) -> &'static str {
+        "twilio"
+    }
+
+    async fn send(&self, to: &str, body: &str) -> Result<SmsReceipt, SmsError> {
+        let url = format!(
+            "{}/Accounts/{}/Messages.json",
+            TWILIO_API_BASE, self.config.account_sid
+        );
+
+        let response = self
+            .client
+            .post(url)
+            .basic_auth(&self.config.account_sid, Some(&self.config.auth_token))
+            .form(&[
+                ("To", to),
+                ("From", &self.config.from_number),
+                ("Body", body),
+            ])
+            .send()
+            .await
+            .map_err(|e| SmsError::Transport(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            let message: TwilioMessage = response
+                .json()
+                .await
+                .map_err(|e| SmsError::Transport(e.to_string()))?;
+            return Ok(SmsReceipt {
+                message_id: message.sid,
+            });
+        }
+
+        let detail = response
+            .json::<TwilioError>()
+            .await
+            .ok()
+            .and_then(|e| e.message)
+            .unwrap_or_else(|| status.to_string());
+        // An overloaded or failing provider is an outage, not a refusal
+        if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(SmsError::Transport(detail));
+        }
+        Err(SmsError::Rejected(detail))
+    }
+}
+
+/// Normalize a stored phone number to E.164 for sending.
+///
+/// Numbers with a leading `+` keep their country code. Bare 10-digit
+/// numbers (and 11-digit numbers starting with 1) are treated as North
+/// American. Anything else can't be texted reliably and returns None.
+pub fn to_e164(phone: &str) -> Option<String> {
+    let digits: String = phone.chars().filter(|c| c.is_ascii_digit()).collect();
+
+    if phone.trim_start().starts_with('+') {
+        return (8..=15)
+            .contains(&digits.len())
+            .then(|| format!("+{}", digits));
+    }
+
+    match digits.len() {
+        10 => Some(format!("+1{}", digits)),
+        11 if digits.starts_with('1') => Some(format!("+{}", digits)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_e164_north_american() {
+        assert_eq!(to_e164("(555) 123-4567"), Some("+15551234567".to_string()));
+        assert_eq!(to_e164("1-555-123-4567"), Some("+15551234567".to_string()));
+    }
+
+    #[test]
+    fn test_to_e164_international() {
+        assert_eq!(
+            to_e164("+44 20 7946 0958"),
+            Some("+442079460958".to_string())
+        );
+        assert_eq!(to_e164("+1"), None);
+    }
+
+    #[test]
+    fn test_to_e164_rejects_short_numbers() {
+        assert_eq!(to_e164("555-1234"), None);
+        assert_eq!(to_e164(""), None);
+    }
+
+    #[test]
+    fn test_sms_error_display() {
+        let err = SmsError::Rejected("invalid number".to_string());
+        assert_eq!(
+            err.to_string(),
+            "SMS provider rejected message: invalid number"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! Notification template rendering.
+//!
+//! Templates use `{placeholder}` variables filled in from the ticket,
+//! customer, and store. Unknown placeholders are left as written.
+
+use chrono::NaiveDate;
+
+/// Placeholders available in notification templates.
+pub const PLACEHOLDERS: &[&str] = &[
+    "customer_name",
+    "friendly_code",
+    "item_description",
+    "promise_date",
+    "promise_date_reason",
+    "store_name",
+    "store_phone",
+];
+
+/// Values substituted into a notification template.
+#[derive(Debug, Clone)]
+pub struct TemplateContext {
+    pub customer_name: String,
+    pub friendly_code: String,
+    pub item_description: String,
+    pub promise_date: Option<NaiveDate>,
+    /// Why the promise date moved (promise date change notifications only)
+    pub promise_date_reason: Option<String>,
+    pub store_name: String,
+    pub store_phone: Option<String>,
+}
+
+/// Placeholders in a template that are not in [`PLACEHOLDERS`], in order of
+/// first appearance. These are sent to customers as written.
+pub fn unknown_placeholders(template: &str) -> Vec<String> {
+    let mut unknown: Vec<String> = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else { break };
+        let name = &after[..end];
+        if name.contains('{') {
+            // Nested brace: the inner `{` starts the real placeholder
+            rest = after;
+            continue;
+        }
+        if !PLACEHOLDERS.contains(&name) && !unknown.iter().any(|u| u == name) {
+            unknown.push(name.to_string());
+        }
+        rest = &after[end + 1..];
+    }
+
+    unknown
+}
+
+impl TemplateContext {
+    /// Sample values used to preview a template for the given store.
+    pub fn sample(store_name: String, store_phone: Option<String>) -> Self {
+        Self {
+            customer_name: "Jane Doe".to_string(),
+            friendly_code: "JR-0042".to_string(),
+            item_description: "14k gold ring, resize to 7".to_string(),
+            promise_date: NaiveDate::from_ymd_opt(2026, 10, 20),
+            promise_date_reason: Some("We are waiting on parts".to_string()),
+            store_name,
+            store_phone,
+        }
+    }
+
+    /// Values for a message about the customer rather than a ticket, such
+    /// as a campaign. Only customer and store placeholders are meaningful.
+    pub fn for_customer(
+        customer_name: String,
+        store_name: String,
+        store_phone: Option<String>,
+    ) -> Self {
+        Self {
+            customer_name,
+            friendly_code: String::new(),
+            item_description: String::new(),
+            promise_date: None,
+            promise_date_reason: None,
+            store_name,
+            store_phone,
+        }
+    }
+
+    /// Value for a placeholder, or None if the placeholder is unknown.
+    pub fn value(&self, placeholder: &str) -> Option<String> {
+        let value = match placeholder {
+            "customer_name" => self.customer_name.clone(),
+            "friendly_code" => self.friendly_code.clone(),
+            "item_description" => self.item_description.clone(),
+            "promise_date" => self
+                .promise_date
+                .map(|d| d.format("%B %-d, %Y").to_string())
+                .unwrap_or_else(|| "to be confirmed".to_string()),
+            "promise_date_reason" => self.promise_date_reason.clone().unwrap_or_default(),
+            "store_name" => self.store_name.clone(),
+            "store_phone" => self.store_phone.clone().unwrap_or_default(),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Fill in every known `{placeholder}` in a template.
+    pub fn render(&self, template: &str) -> String {
+        let mut output = String::with_capacity(template.len());
+        let mut rest = template;
+
+        while let Some(start) = rest.find('{') {
+            output.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            match after.find('}') {
+                Some(end) => match self.value(&after[..end]) {
+                    Some(value) => {
+                        output.push_str(&value);
+                        rest = &after[end + 1..];
+                    }
+                    None => {
+                        output.push('{');
+                        rest = after;
+                    }
+                },
+                None => {
+                    output.push_str(&rest[start..]);
+                    rest = "";
+                }
+            }
+        }
+        output.push_str(rest);
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> TemplateContext {
+        TemplateContext {
+            customer_name: "Jane Doe".to_string(),
+            friendly_code: "JR-0042".to_string(),
+            item_description: "gold ring".to_string(),
+            promise_date: NaiveDate::from_ymd_opt(2026, 10, 20),
+            promise_date_reason: None,
+            store_name: "Example Jewelers".to_string(),
+            store_phone: None,
+        }
+    }
+
+    #[test]
+    fn test_render_fills_placeholders() {
+        let rendered =
+            context().render("Hi {customer_name}, {friendly_code} is due {promise_date}.");
+        assert_eq!(rendered, "Hi Jane Doe, JR-0042 is due October 20, 2026.");
+    }
+
+    #[test]
+    fn test_render_leaves_unknown_and_unclosed_placeholders() {
+        assert_eq!(
+            context().render("{nope} {store_name}"),
+            "{nope} Example Jewelers"
+        );
+        assert_eq!(context().render("Call {store_phone"), "Call {store_phone");
+        assert_eq!(context().render("{{store_name}}"), "{Example Jewelers}");
+    }
+
+    #[test]
+    fn test_render_missing_optional_values() {
+        let mut ctx = context();
+        ctx.promise_date = None;
+        assert_eq!(
+            ctx.render("{promise_date}/{store_phone}"),
+            "to be confirmed/"
+        );
+    }
+
+    #[test]
+    fn test_unknown_placeholders() {
+        assert!(unknown_placeholders("Hi {customer_name}, {store_name}").is_empty());
+        assert_eq!(
+            unknown_placeholders("{name} {friendly_code} {name} {ticket}"),
+            vec!["name", "ticket"]
+        );
+        assert_eq!(
+            unknown_placeholders("{{store_name}} {x"),
+            Vec::<String>::new()
+        );
+        assert_eq!(unknown_placeholders("{}"), vec![""]);
+    }
+
+    #[test]
+    fn test_every_placeholder_has_a_value() {
+        let ctx = context();
+        for placeholder in PLACEHOLDERS {
+            assert!(ctx.value(placeholder).is_some(), "{}", placeholder);
+        }
+    }
+}
@@ -1,42 +1,171 @@
-//! PDF generation service for receipts and labels.
+//! PDF generation service for receipts, invoices, labels, work orders,
+//! custody reports, appraisals, and daily closeouts.
 //!
-//! Generates PDF documents for customer receipts and physical labels.
+//! Generates PDF documents for customer receipts and invoices, physical
+//! labels, bench work orders, chain-of-custody reports, appraisals, and the
+//! end-of-day closeout.
 
 use crate::error::AppError;
+use crate::models::report::{CloseoutTicketEntry, DailyCloseoutReport};
 use crate::models::ticket::Ticket;
-use crate::models::Customer;
+use crate::models::{
+    balance_due, AppraisalEntry, CustodyEventEntry, Customer, PaymentKind, PaymentMethod,
+    TaxBreakdown, TicketInvoice, TicketItem, TicketPaymentEntry, TicketPickupEntry, TicketQcCheck,
+};
 use printpdf::*;
+use rust_decimal::Decimal;
 use std::io::BufWriter;
 
 /// Label data for PDF generation.
 pub struct LabelData {
     pub ticket: Ticket,
     pub customer_name: String,
+    /// Label stock size in mm
+    pub width_mm: f32,
+    pub height_mm: f32,
 }
 
+/// Size of the standard 2" x 1" jewelry tag, in mm.
+pub const DEFAULT_LABEL_WIDTH_MM: f32 = 50.8;
+pub const DEFAULT_LABEL_HEIGHT_MM: f32 = 25.4;
+
 /// Receipt data for PDF generation.
 pub struct ReceiptData {
     pub ticket: Ticket,
     pub customer: Customer,
+    /// Items on the ticket, in order
+    pub items: Vec<TicketItem>,
+    pub store_name: String,
+    pub store_phone: Option<String>,
+    pub store_address: Option<String>,
+    /// Total of deposits taken so far
+    pub deposit_total: Decimal,
+    /// Total of all payments taken so far
+    pub total_paid: Decimal,
+    /// Tax on the ticket's amount, when it has one
+    pub tax: Option<TaxBreakdown>,
+    pub template: ReceiptTemplate,
+}
+
+/// Store-configured parts of the receipt layout.
+pub struct ReceiptTemplate {
+    /// PNG or JPEG logo printed above the store name
+    pub logo: Option<Vec<u8>>,
+    /// Disclaimer printed below the standard footer
+    pub footer_text: Option<String>,
+    /// Print quotes, payments, and the balance due
+    pub show_prices: bool,
+}
+
+impl Default for ReceiptTemplate {
+    fn default() -> Self {
+        Self {
+            logo: None,
+            footer_text: None,
+            show_prices: true,
+        }
+    }
+}
+
+/// Invoice data for PDF generation.
+pub struct InvoiceData {
+    pub ticket: Ticket,
+    pub customer: Customer,
+    pub invoice: TicketInvoice,
+    /// Items on the ticket, in order
+    pub items: Vec<TicketItem>,
+    /// Payments on the ticket, oldest first
+    pub payments: Vec<TicketPaymentEntry>,
+    pub store_name: String,
+    pub store_phone: Option<String>,
+    pub store_address: Option<String>,
+}
+
+/// Work order data for PDF generation.
+pub struct WorkOrderData {
+    pub ticket: Ticket,
+    pub customer_name: String,
+    pub storage_location_name: String,
+    pub worked_by_name: Option<String>,
+    /// Configured QC checklist items (empty = QC not required).
+    pub qc_checklist: Vec<String>,
+    /// Most recent QC check, if any.
+    pub qc_check: Option<TicketQcCheck>,
+    /// Name of the employee who performed the most recent QC check.
+    pub qc_checked_by_name: Option<String>,
+}
+
+/// Chain-of-custody report data for PDF generation.
+pub struct CustodyReportData {
+    pub ticket: Ticket,
+    pub customer_name: String,
+    pub store_name: String,
+    /// Events in sequence order.
+    pub events: Vec<CustodyEventEntry>,
+    /// Releases of the item, oldest first.
+    pub pickups: Vec<PickupReportEntry>,
+}
+
+/// A release of the item with its signature image, for the custody report.
+pub struct PickupReportEntry {
+    pub entry: TicketPickupEntry,
+    /// PNG signature captured at pickup.
+    pub signature: Option<Vec<u8>>,
+}
+
+/// Appraisal data for PDF generation.
+pub struct AppraisalData {
+    pub ticket: Ticket,
+    pub customer_name: String,
+    pub appraisal: AppraisalEntry,
     pub store_name: String,
     pub store_phone: Option<String>,
     pub store_address: Option<String>,
 }
 
+/// Largest size a signature is drawn at on the custody report, in mm.
+const SIGNATURE_MAX_WIDTH_MM: f32 = 60.0;
+const SIGNATURE_MAX_HEIGHT_MM: f32 = 20.0;
+
+/// Largest size the store logo is drawn at on receipts, in mm.
+const LOGO_MAX_WIDTH_MM: f32 = 60.0;
+const LOGO_MAX_HEIGHT_MM: f32 = 25.0;
+
+/// Check that the fonts every document uses load and render.
+///
+/// Builds and saves a one-line document, which also loads the PDF writer,
+/// so a broken build fails at startup instead of on the first receipt.
+pub fn verify_fonts() -> Result<(), AppError> {
+    let (doc, page1, layer1) = PdfDocument::new("Font check", Mm(50.0), Mm(20.0), "Layer 1");
+    let layer = doc.get_page(page1).get_layer(layer1);
+    for font in [BuiltinFont::Helvetica, BuiltinFont::HelveticaBold] {
+        let font = doc
+            .add_builtin_font(font)
+            .map_err(|e| AppError::server_error(format!("Failed to load font: {:?}", e)))?;
+        layer.use_text("Facet", 10.0, Mm(5.0), Mm(5.0), &font);
+    }
+
+    let mut buffer = BufWriter::new(Vec::new());
+    doc.save(&mut buffer)
+        .map_err(|e| AppError::server_error(format!("Failed to save PDF: {:?}", e)))?;
+    Ok(())
+}
+
 /// Generate a receipt PDF for a ticket.
 ///
 /// The receipt includes:
 /// - Ticket friendly code
 /// - Customer name and contact info
-/// - Item description and condition
-/// - Requested work
+/// - Each item's description, condition, and requested work
 /// - Quote amount and promise date
 /// - Store information
 pub fn generate_receipt_pdf(data: &ReceiptData) -> Result<Vec<u8>, AppError> {
     // Create PDF document
     // Letter size: 8.5" x 11" = 215.9mm x 279.4mm
-    let (doc, page1, layer1) = PdfDocument::new("Repair Receipt", Mm(215.9), Mm(279.4), "Layer 1");
-    let current_layer = doc.get_page(page1).get_layer(layer1);
+    let (page_width, page_height) = (Mm(215.9), Mm(279.4));
+    let (doc, page1, layer1) =
+        PdfDocument::new("Repair Receipt", page_width, page_height, "Layer 1");
+    let mut current_layer = doc.get_page(page1).get_layer(layer1);
 
     // Load built-in font
     let font = doc
@@ -46,12 +175,29 @@ pub fn generate_receipt_pdf(data: &ReceiptData) -> Result<Vec<u8>, AppError> {
         .add_builtin_font(BuiltinFont::HelveticaBold)
         .map_err(|e| AppError::server_error(format!("Failed to load bold font: {:?}", e)))?;
 
-    let mut y_pos = 260.0; // Start from top (with margin)
+    let top = 260.0; // Start from top (with margin)
+    let bottom_margin = 25.0;
+    let mut y_pos = top;
     let left_margin = 20.0;
     let line_height = 6.0;
     let section_gap = 10.0;
+    let footer_line_height = 4.5;
+    let footer_lines = receipt_footer_lines(data.template.footer_text.as_deref(), 110);
 
     // === Store Header ===
+    if let Some((image, dpi, height)) = data.template.logo.as_deref().and_then(logo_image) {
+        Image::from(image).add_to_layer(
+            current_layer.clone(),
+            ImageTransform {
+                translate_x: Some(Mm(left_margin)),
+                translate_y: Some(Mm(y_pos + line_height - height)),
+                dpi: Some(dpi),
+                ..Default::default()
+            },
+        );
+        y_pos -= height + line_height / 2.0;
+    }
+
     current_layer.use_text(
         &data.store_name,
         18.0,
@@ -124,68 +270,56 @@ pub fn generate_receipt_pdf(data: &ReceiptData) -> Result<Vec<u8>, AppError> {
 
     y_pos -= section_gap;
 
-    // === Item Details ===
-    current_layer.use_text("ITEM DETAILS", 10.0, Mm(left_margin), Mm(y_pos), &font_bold);
+    // === Items ===
+    let heading = if data.items.len() > 1 {
+        format!("ITEMS ({})", data.items.len())
+    } else {
+        "ITEM DETAILS".to_string()
+    };
+    current_layer.use_text(heading, 10.0, Mm(left_margin), Mm(y_pos), &font_bold);
     y_pos -= line_height;
 
-    if let Some(ref item_type) = data.ticket.item_type {
-        current_layer.use_text(
-            format!("Type: {}", item_type),
-            10.0,
-            Mm(left_margin),
-            Mm(y_pos),
-            &font,
-        );
-        y_pos -= line_height;
-    }
+    for item in &data.items {
+        let lines = receipt_item_lines(item, data.items.len(), data.template.show_prices);
 
-    // Word wrap long descriptions
-    let desc_lines = wrap_text(&data.ticket.item_description, 80);
-    current_layer.use_text("Description:", 10.0, Mm(left_margin), Mm(y_pos), &font);
-    y_pos -= line_height;
-    for line in desc_lines {
-        current_layer.use_text(&line, 10.0, Mm(left_margin + 5.0), Mm(y_pos), &font);
-        y_pos -= line_height;
-    }
+        // Start a new page if the whole item won't fit
+        if y_pos - line_height * (lines.len() as f32 + 1.0) < bottom_margin {
+            let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+            current_layer = doc.get_page(page).get_layer(layer);
+            y_pos = top;
+        }
 
-    let condition_lines = wrap_text(&data.ticket.condition_notes, 80);
-    current_layer.use_text("Condition:", 10.0, Mm(left_margin), Mm(y_pos), &font);
-    y_pos -= line_height;
-    for line in condition_lines {
-        current_layer.use_text(&line, 10.0, Mm(left_margin + 5.0), Mm(y_pos), &font);
-        y_pos -= line_height;
+        for (i, line) in lines.iter().enumerate() {
+            let (indent, line_font) = if i == 0 {
+                (0.0, &font_bold)
+            } else {
+                (5.0, &font)
+            };
+            current_layer.use_text(line, 10.0, Mm(left_margin + indent), Mm(y_pos), line_font);
+            y_pos -= line_height;
+        }
+        y_pos -= line_height / 2.0;
     }
 
     y_pos -= section_gap;
 
-    // === Requested Work ===
-    current_layer.use_text(
-        "REQUESTED WORK",
-        10.0,
-        Mm(left_margin),
-        Mm(y_pos),
-        &font_bold,
-    );
-    y_pos -= line_height;
-
-    let work_lines = wrap_text(&data.ticket.requested_work, 80);
-    for line in work_lines {
-        current_layer.use_text(&line, 10.0, Mm(left_margin), Mm(y_pos), &font);
-        y_pos -= line_height;
+    // Keep pricing, dates, the signature line, and the footer together
+    let closing_height = RECEIPT_CLOSING_HEIGHT_MM + footer_line_height * footer_lines.len() as f32;
+    if y_pos - closing_height < bottom_margin {
+        let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+        current_layer = doc.get_page(page).get_layer(layer);
+        y_pos = top;
     }
 
-    y_pos -= section_gap;
-
     // === Pricing & Dates ===
-    if let Some(quote) = data.ticket.quote_amount {
-        current_layer.use_text(
-            format!("Estimated Price: ${:.2}", quote),
-            12.0,
-            Mm(left_margin),
-            Mm(y_pos),
-            &font_bold,
-        );
-        y_pos -= line_height * 1.5;
+    for (line, is_total) in receipt_price_lines(data) {
+        if is_total {
+            current_layer.use_text(line, 12.0, Mm(left_margin), Mm(y_pos), &font_bold);
+            y_pos -= line_height * 1.5;
+        } else {
+            current_layer.use_text(line, 10.0, Mm(left_margin), Mm(y_pos), &font);
+            y_pos -= line_height;
+        }
     }
 
     if let Some(promise_date) = data.ticket.promise_date {
@@ -234,24 +368,15 @@ pub fn generate_receipt_pdf(data: &ReceiptData) -> Result<Vec<u8>, AppError> {
     y_pos -= line_height * 3.0;
 
     // === Footer ===
-    current_layer.use_text(
-        "Please retain this receipt for pickup.",
-        9.0,
-        Mm(left_margin),
-        Mm(y_pos),
-        &font,
-    );
-    y_pos -= line_height;
-    current_layer.use_text(
-        format!(
-            "Ticket ID required for all inquiries: {}",
-            data.ticket.friendly_code
-        ),
-        9.0,
-        Mm(left_margin),
-        Mm(y_pos),
-        &font,
-    );
+    for line in receipt_notice_lines(&data.ticket) {
+        current_layer.use_text(line, 9.0, Mm(left_margin), Mm(y_pos), &font);
+        y_pos -= line_height;
+    }
+
+    for line in &footer_lines {
+        current_layer.use_text(line, 8.0, Mm(left_margin), Mm(y_pos), &font);
+        y_pos -= footer_line_height;
+    }
 
     // Suppress unused variable warning for final y_pos
     let _ = y_pos;
@@ -266,122 +391,138 @@ pub fn generate_receipt_pdf(data: &ReceiptData) -> Result<Vec<u8>, AppError> {
         .map_err(|e| AppError::server_error(format!("Failed to get PDF buffer: {:?}", e)))
 }
 
-/// Simple text wrapper for PDF output.
-fn wrap_text(text: &str, max_chars: usize) -> Vec<String> {
-    let mut lines = Vec::new();
-    let mut current_line = String::new();
-
-    for word in text.split_whitespace() {
-        if current_line.is_empty() {
-            current_line = word.to_string();
-        } else if current_line.len() + 1 + word.len() <= max_chars {
-            current_line.push(' ');
-            current_line.push_str(word);
-        } else {
-            lines.push(current_line);
-            current_line = word.to_string();
-        }
-    }
-
-    if !current_line.is_empty() {
-        lines.push(current_line);
-    }
-
-    if lines.is_empty() {
-        lines.push(String::new());
-    }
-
-    lines
-}
-
-/// Generate a label PDF for a physical tag.
-///
-/// The label is 2x1 inches (50.8mm x 25.4mm) and includes:
-/// - Ticket friendly code (large, prominent)
-/// - Short item descriptor
+/// Generate an invoice PDF for a closed ticket.
 ///
-/// This is designed to print on standard jewelry tag stock.
-pub fn generate_label_pdf(data: &LabelData) -> Result<Vec<u8>, AppError> {
-    // Create PDF document
-    // Label size: 2" x 1" = 50.8mm x 25.4mm
-    let (doc, page1, layer1) = PdfDocument::new("Repair Label", Mm(50.8), Mm(25.4), "Layer 1");
-    let current_layer = doc.get_page(page1).get_layer(layer1);
+/// The invoice includes:
+/// - Store information, invoice number, and invoice date
+/// - Customer name and contact info
+/// - Each item's description and the work done
+/// - The amount charged, with the included tax broken out
+/// - Payments received and the balance due
+pub fn generate_invoice_pdf(data: &InvoiceData) -> Result<Vec<u8>, AppError> {
+    // Letter size: 8.5" x 11" = 215.9mm x 279.4mm
+    let (page_width, page_height) = (Mm(215.9), Mm(279.4));
+    let (doc, page1, layer1) = PdfDocument::new("Invoice", page_width, page_height, "Layer 1");
+    let mut current_layer = doc.get_page(page1).get_layer(layer1);
 
-    // Load built-in fonts
-    let font_bold = doc
-        .add_builtin_font(BuiltinFont::HelveticaBold)
-        .map_err(|e| AppError::server_error(format!("Failed to load bold font: {:?}", e)))?;
     let font = doc
         .add_builtin_font(BuiltinFont::Helvetica)
         .map_err(|e| AppError::server_error(format!("Failed to load font: {:?}", e)))?;
+    let font_bold = doc
+        .add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|e| AppError::server_error(format!("Failed to load bold font: {:?}", e)))?;
 
-    // Label layout - centered content with small margins
-    // Y starts from top (25.4mm height)
-    let center_x = 25.4; // Center of the label width
-    let margin = 2.0;
-
-    // === Ticket Code (large, prominent, centered) ===
-    // Place near top of label
-    let code_y = 20.0;
-    let code_text = &data.ticket.friendly_code;
-
-    // Calculate approximate text width for centering (rough estimate: 3.5mm per char at size 14)
-    let code_width_estimate = code_text.len() as f32 * 3.5;
-    let code_x = center_x - (code_width_estimate / 2.0);
+    let top = 260.0;
+    let bottom_margin = 25.0;
+    let mut y_pos = top;
+    let left_margin = 20.0;
+    let amount_x = 165.0;
+    let line_height = 6.0;
+    let section_gap = 10.0;
 
+    // === Store Header ===
     current_layer.use_text(
-        code_text,
-        14.0,
-        Mm(code_x.max(margin)),
-        Mm(code_y),
+        &data.store_name,
+        18.0,
+        Mm(left_margin),
+        Mm(y_pos),
         &font_bold,
     );
+    current_layer.use_text("INVOICE", 18.0, Mm(amount_x - 15.0), Mm(y_pos), &font_bold);
+    y_pos -= line_height * 1.5;
 
-    // === Customer Name (below ticket code) ===
-    // Truncate long names to fit on small label
-    let customer_name = truncate_text(&data.customer_name, 20);
-    let name_y = 14.5;
-    let name_width_estimate = customer_name.len() as f32 * 2.0; // Approx 2mm per char at size 9
-    let name_x = center_x - (name_width_estimate / 2.0);
+    let details = [
+        format!("Invoice #: {}", data.invoice.code()),
+        format!("Date: {}", data.invoice.issued_at.format("%B %d, %Y")),
+        format!("Ticket #: {}", data.ticket.friendly_code),
+    ];
+    let contact: Vec<&String> = [&data.store_phone, &data.store_address]
+        .into_iter()
+        .flatten()
+        .collect();
+    for i in 0..details.len().max(contact.len()) {
+        if let Some(line) = contact.get(i) {
+            current_layer.use_text(*line, 10.0, Mm(left_margin), Mm(y_pos), &font);
+        }
+        if let Some(line) = details.get(i) {
+            current_layer.use_text(line, 10.0, Mm(amount_x - 15.0), Mm(y_pos), &font);
+        }
+        y_pos -= line_height;
+    }
 
-    current_layer.use_text(
-        &customer_name,
-        9.0,
-        Mm(name_x.max(margin)),
-        Mm(name_y),
-        &font,
-    );
+    y_pos -= section_gap;
 
-    // === Item Descriptor (smaller, below customer name) ===
-    // Create a short descriptor from item_type and truncated description
-    let descriptor = create_short_descriptor(
-        data.ticket.item_type.as_deref(),
-        &data.ticket.item_description,
-    );
+    // === Bill To ===
+    current_layer.use_text("BILL TO", 10.0, Mm(left_margin), Mm(y_pos), &font_bold);
+    y_pos -= line_height;
+    current_layer.use_text(&data.customer.name, 12.0, Mm(left_margin), Mm(y_pos), &font);
+    y_pos -= line_height;
+    for line in [&data.customer.phone, &data.customer.email]
+        .into_iter()
+        .flatten()
+    {
+        current_layer.use_text(line, 10.0, Mm(left_margin), Mm(y_pos), &font);
+        y_pos -= line_height;
+    }
 
-    // Smaller font for descriptor
-    let desc_y = 9.0;
-    let desc_width_estimate = descriptor.len() as f32 * 1.8; // Approx 1.8mm per char at size 8
-    let desc_x = center_x - (desc_width_estimate / 2.0);
+    y_pos -= section_gap;
 
-    current_layer.use_text(&descriptor, 8.0, Mm(desc_x.max(margin)), Mm(desc_y), &font);
+    // === Items ===
+    current_layer.use_text("DESCRIPTION", 10.0, Mm(left_margin), Mm(y_pos), &font_bold);
+    y_pos -= line_height;
 
-    // === Rush indicator (if applicable) ===
-    if data.ticket.is_rush {
-        let rush_y = 3.5;
-        let rush_text = "RUSH";
-        let rush_width_estimate = rush_text.len() as f32 * 2.5;
-        let rush_x = center_x - (rush_width_estimate / 2.0);
-        current_layer.use_text(
-            rush_text,
-            10.0,
-            Mm(rush_x.max(margin)),
-            Mm(rush_y),
-            &font_bold,
-        );
+    for item in &data.items {
+        let lines = invoice_item_lines(item, data.items.len());
+
+        // Start a new page if the whole item won't fit
+        if y_pos - line_height * (lines.len() as f32 + 1.0) < bottom_margin {
+            let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+            current_layer = doc.get_page(page).get_layer(layer);
+            y_pos = top;
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            let (indent, line_font) = if i == 0 {
+                (0.0, &font_bold)
+            } else {
+                (5.0, &font)
+            };
+            current_layer.use_text(line, 10.0, Mm(left_margin + indent), Mm(y_pos), line_font);
+            y_pos -= line_height;
+        }
+    }
+
+    y_pos -= section_gap;
+
+    // === Totals and Payments ===
+    let payment_lines = invoice_payment_lines(&data.payments);
+    let total_lines = invoice_total_lines(data);
+    let closing_lines = payment_lines.len() + total_lines.len() + 4;
+    if y_pos - line_height * (closing_lines as f32) < bottom_margin {
+        let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+        current_layer = doc.get_page(page).get_layer(layer);
+        y_pos = top;
+    }
+
+    let mut draw_row = |layer: &PdfLayerReference, label: &str, amount: &str, bold: bool| {
+        let row_font = if bold { &font_bold } else { &font };
+        layer.use_text(label, 10.0, Mm(left_margin), Mm(y_pos), row_font);
+        layer.use_text(amount, 10.0, Mm(amount_x), Mm(y_pos), row_font);
+        y_pos -= line_height;
+    };
+
+    if !payment_lines.is_empty() {
+        draw_row(&current_layer, "PAYMENTS", "", true);
+        for (label, amount) in &payment_lines {
+            draw_row(&current_layer, label, amount, false);
+        }
+        draw_row(&current_layer, "", "", false);
+    }
+
+    for (label, amount, is_total) in &total_lines {
+        draw_row(&current_layer, label, amount, *is_total);
     }
 
-    // Save PDF to bytes
     let mut buffer = BufWriter::new(Vec::new());
     doc.save(&mut buffer)
         .map_err(|e| AppError::server_error(format!("Failed to save PDF: {:?}", e)))?;
@@ -391,48 +532,1380 @@ pub fn generate_label_pdf(data: &LabelData) -> Result<Vec<u8>, AppError> {
         .map_err(|e| AppError::server_error(format!("Failed to get PDF buffer: {:?}", e)))
 }
 
-/// Create a short descriptor for the label from item type and description.
+/// Generate a work order PDF for a ticket.
 ///
-/// Combines item_type (if present) with a truncated description,
-/// keeping the total length reasonable for a small label.
-fn create_short_descriptor(item_type: Option<&str>, description: &str) -> String {
-    let max_len: usize = 25; // Maximum characters for the descriptor
-
-    match item_type {
-        Some(t) if !t.is_empty() => {
-            // "Ring - Gold band with..."
-            let prefix = format!("{} - ", t);
-            let remaining = max_len.saturating_sub(prefix.len());
+/// The work order is the bench copy and includes:
+/// - Ticket friendly code, rush flag, and promise date
+/// - Customer name and storage location
+/// - Item description, condition, and requested work
+/// - Assigned employee
+/// - QC checklist with the most recent result and checker
+pub fn generate_work_order_pdf(data: &WorkOrderData) -> Result<Vec<u8>, AppError> {
+    // Letter size: 8.5" x 11" = 215.9mm x 279.4mm
+    let (doc, page1, layer1) = PdfDocument::new("Work Order", Mm(215.9), Mm(279.4), "Layer 1");
+    let current_layer = doc.get_page(page1).get_layer(layer1);
 
-            if remaining > 3 {
-                let truncated_desc = truncate_text(description, remaining);
-                format!("{}{}", prefix, truncated_desc)
-            } else {
-                truncate_text(t, max_len)
-            }
-        }
-        _ => truncate_text(description, max_len),
-    }
-}
+    let font = doc
+        .add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| AppError::server_error(format!("Failed to load font: {:?}", e)))?;
+    let font_bold = doc
+        .add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|e| AppError::server_error(format!("Failed to load bold font: {:?}", e)))?;
 
-/// Truncate text to a maximum length, adding "..." if truncated.
-fn truncate_text(text: &str, max_len: usize) -> String {
-    if text.len() <= max_len {
-        text.to_string()
-    } else if max_len <= 3 {
-        text.chars().take(max_len).collect()
-    } else {
-        let truncated: String = text.chars().take(max_len - 3).collect();
-        format!("{}...", truncated.trim_end())
-    }
-}
+    let mut y_pos = 260.0;
+    let left_margin = 20.0;
+    let line_height = 6.0;
+    let section_gap = 10.0;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    // === Title and Ticket ID ===
+    current_layer.use_text("WORK ORDER", 14.0, Mm(left_margin), Mm(y_pos), &font_bold);
+    y_pos -= section_gap;
 
-    #[test]
-    fn test_wrap_text_short() {
+    current_layer.use_text(
+        format!("Ticket #: {}", data.ticket.friendly_code),
+        16.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    y_pos -= line_height * 1.5;
+
+    if data.ticket.is_rush {
+        current_layer.use_text(
+            "*** RUSH ORDER ***",
+            12.0,
+            Mm(left_margin),
+            Mm(y_pos),
+            &font_bold,
+        );
+        y_pos -= line_height * 1.5;
+    }
+
+    if let Some(promise_date) = data.ticket.promise_date {
+        current_layer.use_text(
+            format!("Promise Date: {}", promise_date.format("%B %d, %Y")),
+            10.0,
+            Mm(left_margin),
+            Mm(y_pos),
+            &font,
+        );
+        y_pos -= line_height;
+    }
+
+    current_layer.use_text(
+        format!("Customer: {}", data.customer_name),
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font,
+    );
+    y_pos -= line_height;
+
+    current_layer.use_text(
+        format!("Location: {}", data.storage_location_name),
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font,
+    );
+    y_pos -= line_height;
+
+    current_layer.use_text(
+        format!(
+            "Worked By: {}",
+            data.worked_by_name.as_deref().unwrap_or("Unassigned")
+        ),
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font,
+    );
+    y_pos -= section_gap;
+
+    // === Item Details ===
+    current_layer.use_text("ITEM DETAILS", 10.0, Mm(left_margin), Mm(y_pos), &font_bold);
+    y_pos -= line_height;
+
+    if let Some(ref item_type) = data.ticket.item_type {
+        current_layer.use_text(
+            format!("Type: {}", item_type),
+            10.0,
+            Mm(left_margin),
+            Mm(y_pos),
+            &font,
+        );
+        y_pos -= line_height;
+    }
+
+    current_layer.use_text("Description:", 10.0, Mm(left_margin), Mm(y_pos), &font);
+    y_pos -= line_height;
+    for line in wrap_text(&data.ticket.item_description, 80) {
+        current_layer.use_text(&line, 10.0, Mm(left_margin + 5.0), Mm(y_pos), &font);
+        y_pos -= line_height;
+    }
+
+    current_layer.use_text("Condition:", 10.0, Mm(left_margin), Mm(y_pos), &font);
+    y_pos -= line_height;
+    for line in wrap_text(&data.ticket.condition_notes, 80) {
+        current_layer.use_text(&line, 10.0, Mm(left_margin + 5.0), Mm(y_pos), &font);
+        y_pos -= line_height;
+    }
+
+    y_pos -= section_gap;
+
+    // === Requested Work ===
+    current_layer.use_text(
+        "REQUESTED WORK",
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    y_pos -= line_height;
+
+    for line in wrap_text(&data.ticket.requested_work, 80) {
+        current_layer.use_text(&line, 10.0, Mm(left_margin), Mm(y_pos), &font);
+        y_pos -= line_height;
+    }
+
+    y_pos -= section_gap;
+
+    // === QC Checklist ===
+    if !data.qc_checklist.is_empty() {
+        current_layer.use_text(
+            "QUALITY CONTROL",
+            10.0,
+            Mm(left_margin),
+            Mm(y_pos),
+            &font_bold,
+        );
+        y_pos -= line_height;
+
+        for item in &data.qc_checklist {
+            current_layer.use_text(
+                qc_checklist_line(item, data.qc_check.as_ref()),
+                10.0,
+                Mm(left_margin),
+                Mm(y_pos),
+                &font,
+            );
+            y_pos -= line_height;
+        }
+
+        if let Some(ref check) = data.qc_check {
+            current_layer.use_text(
+                format!(
+                    "Checked By: {} on {}",
+                    data.qc_checked_by_name.as_deref().unwrap_or("Unknown"),
+                    check.checked_at.format("%B %d, %Y at %I:%M %p")
+                ),
+                10.0,
+                Mm(left_margin),
+                Mm(y_pos),
+                &font,
+            );
+            y_pos -= line_height;
+
+            if let Some(ref notes) = check.notes {
+                for line in wrap_text(notes, 80) {
+                    current_layer.use_text(&line, 10.0, Mm(left_margin + 5.0), Mm(y_pos), &font);
+                    y_pos -= line_height;
+                }
+            }
+        } else {
+            current_layer.use_text(
+                "Checked By: ____________________________",
+                10.0,
+                Mm(left_margin),
+                Mm(y_pos),
+                &font,
+            );
+        }
+    }
+
+    // Suppress unused variable warning for final y_pos
+    let _ = y_pos;
+
+    // Save PDF to bytes
+    let mut buffer = BufWriter::new(Vec::new());
+    doc.save(&mut buffer)
+        .map_err(|e| AppError::server_error(format!("Failed to save PDF: {:?}", e)))?;
+
+    buffer
+        .into_inner()
+        .map_err(|e| AppError::server_error(format!("Failed to get PDF buffer: {:?}", e)))
+}
+
+/// Generate a chain-of-custody report PDF for a ticket.
+///
+/// The report is an internal document and includes:
+/// - Store name, ticket friendly code, customer, and item
+/// - Every custody event in sequence with handler, witness, and locations
+/// - Every release with who collected the item, their ID, and signature
+/// - A reviewer signature line
+pub fn generate_custody_report_pdf(data: &CustodyReportData) -> Result<Vec<u8>, AppError> {
+    // Letter size: 8.5" x 11" = 215.9mm x 279.4mm
+    let (page_width, page_height) = (Mm(215.9), Mm(279.4));
+    let (doc, page1, layer1) =
+        PdfDocument::new("Chain of Custody", page_width, page_height, "Layer 1");
+    let mut current_layer = doc.get_page(page1).get_layer(layer1);
+
+    let font = doc
+        .add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| AppError::server_error(format!("Failed to load font: {:?}", e)))?;
+    let font_bold = doc
+        .add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|e| AppError::server_error(format!("Failed to load bold font: {:?}", e)))?;
+
+    let top = 260.0;
+    let bottom_margin = 25.0;
+    let mut y_pos = top;
+    let left_margin = 20.0;
+    let line_height = 6.0;
+    let section_gap = 10.0;
+
+    // === Header ===
+    current_layer.use_text(
+        &data.store_name,
+        14.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    y_pos -= line_height * 1.5;
+
+    current_layer.use_text(
+        "CHAIN OF CUSTODY",
+        14.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    y_pos -= section_gap;
+
+    current_layer.use_text(
+        format!("Ticket #: {}", data.ticket.friendly_code),
+        16.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    y_pos -= line_height * 1.5;
+
+    current_layer.use_text(
+        format!("Customer: {}", data.customer_name),
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font,
+    );
+    y_pos -= line_height;
+
+    for line in wrap_text(&format!("Item: {}", data.ticket.item_description), 80) {
+        current_layer.use_text(&line, 10.0, Mm(left_margin), Mm(y_pos), &font);
+        y_pos -= line_height;
+    }
+
+    if let Some(quote) = data.ticket.quote_amount {
+        current_layer.use_text(
+            format!("Quoted Value: ${:.2}", quote),
+            10.0,
+            Mm(left_margin),
+            Mm(y_pos),
+            &font,
+        );
+        y_pos -= line_height;
+    }
+
+    y_pos -= section_gap;
+
+    // === Events ===
+    current_layer.use_text("EVENTS", 10.0, Mm(left_margin), Mm(y_pos), &font_bold);
+    y_pos -= line_height;
+
+    if data.events.is_empty() {
+        current_layer.use_text(
+            "No custody events recorded.",
+            10.0,
+            Mm(left_margin),
+            Mm(y_pos),
+            &font,
+        );
+        y_pos -= line_height;
+    }
+
+    for event in &data.events {
+        let lines = custody_event_lines(event);
+
+        // Start a new page if the whole event won't fit
+        if y_pos - line_height * (lines.len() as f32 + 1.0) < bottom_margin {
+            let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+            current_layer = doc.get_page(page).get_layer(layer);
+            y_pos = top;
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            let (indent, line_font) = if i == 0 {
+                (0.0, &font_bold)
+            } else {
+                (5.0, &font)
+            };
+            current_layer.use_text(line, 10.0, Mm(left_margin + indent), Mm(y_pos), line_font);
+            y_pos -= line_height;
+        }
+        y_pos -= line_height / 2.0;
+    }
+
+    // === Pickups ===
+    if !data.pickups.is_empty() {
+        y_pos -= section_gap / 2.0;
+        current_layer.use_text("PICKUP", 10.0, Mm(left_margin), Mm(y_pos), &font_bold);
+        y_pos -= line_height;
+    }
+
+    for pickup in &data.pickups {
+        let lines = pickup_lines(&pickup.entry);
+        let signature = pickup.signature.as_deref().and_then(signature_image);
+        let signature_height = signature
+            .as_ref()
+            .map_or(0.0, |(_, _, height)| *height + line_height);
+
+        // Start a new page if the whole release won't fit
+        if y_pos - line_height * (lines.len() as f32 + 1.0) - signature_height < bottom_margin {
+            let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+            current_layer = doc.get_page(page).get_layer(layer);
+            y_pos = top;
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            let (indent, line_font) = if i == 0 {
+                (0.0, &font_bold)
+            } else {
+                (5.0, &font)
+            };
+            current_layer.use_text(line, 10.0, Mm(left_margin + indent), Mm(y_pos), line_font);
+            y_pos -= line_height;
+        }
+
+        if let Some((image, dpi, height)) = signature {
+            current_layer.use_text("Signature:", 10.0, Mm(left_margin + 5.0), Mm(y_pos), &font);
+            Image::from(image).add_to_layer(
+                current_layer.clone(),
+                ImageTransform {
+                    translate_x: Some(Mm(left_margin + 25.0)),
+                    translate_y: Some(Mm(y_pos - height + line_height / 2.0)),
+                    dpi: Some(dpi),
+                    ..Default::default()
+                },
+            );
+            y_pos -= signature_height;
+        }
+        y_pos -= line_height / 2.0;
+    }
+
+    // === Reviewer Signature ===
+    if y_pos - section_gap < bottom_margin {
+        let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+        current_layer = doc.get_page(page).get_layer(layer);
+        y_pos = top;
+    }
+    y_pos -= section_gap;
+    current_layer.use_text(
+        "Reviewed By: ____________________________   Date: ____________",
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font,
+    );
+
+    // Save PDF to bytes
+    let mut buffer = BufWriter::new(Vec::new());
+    doc.save(&mut buffer)
+        .map_err(|e| AppError::server_error(format!("Failed to save PDF: {:?}", e)))?;
+
+    buffer
+        .into_inner()
+        .map_err(|e| AppError::server_error(format!("Failed to get PDF buffer: {:?}", e)))
+}
+
+/// Generate an appraisal PDF for a ticket.
+///
+/// The appraisal is given to the customer and includes:
+/// - Store information, ticket friendly code, and appraisal date
+/// - Customer name
+/// - Item description, metal, stones, and certification numbers
+/// - The appraised value
+/// - The appraiser's name and a signature line
+pub fn generate_appraisal_pdf(data: &AppraisalData) -> Result<Vec<u8>, AppError> {
+    // Letter size: 8.5" x 11" = 215.9mm x 279.4mm
+    let (page_width, page_height) = (Mm(215.9), Mm(279.4));
+    let (doc, page1, layer1) = PdfDocument::new("Appraisal", page_width, page_height, "Layer 1");
+    let mut current_layer = doc.get_page(page1).get_layer(layer1);
+
+    let font = doc
+        .add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| AppError::server_error(format!("Failed to load font: {:?}", e)))?;
+    let font_bold = doc
+        .add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|e| AppError::server_error(format!("Failed to load bold font: {:?}", e)))?;
+
+    let top = 260.0;
+    let bottom_margin = 25.0;
+    let mut y_pos = top;
+    let left_margin = 20.0;
+    let right_x = 150.0;
+    let line_height = 6.0;
+    let section_gap = 10.0;
+
+    // === Store Header ===
+    current_layer.use_text(
+        &data.store_name,
+        18.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    current_layer.use_text("APPRAISAL", 18.0, Mm(right_x), Mm(y_pos), &font_bold);
+    y_pos -= line_height * 1.5;
+
+    let details = [
+        format!("Ticket #: {}", data.ticket.friendly_code),
+        format!("Date: {}", data.appraisal.appraised_on.format("%B %d, %Y")),
+    ];
+    let contact: Vec<&String> = [&data.store_phone, &data.store_address]
+        .into_iter()
+        .flatten()
+        .collect();
+    for i in 0..details.len().max(contact.len()) {
+        if let Some(line) = contact.get(i) {
+            current_layer.use_text(*line, 10.0, Mm(left_margin), Mm(y_pos), &font);
+        }
+        if let Some(line) = details.get(i) {
+            current_layer.use_text(line, 10.0, Mm(right_x), Mm(y_pos), &font);
+        }
+        y_pos -= line_height;
+    }
+
+    y_pos -= section_gap;
+
+    // === Prepared For ===
+    current_layer.use_text("PREPARED FOR", 10.0, Mm(left_margin), Mm(y_pos), &font_bold);
+    y_pos -= line_height;
+    current_layer.use_text(&data.customer_name, 12.0, Mm(left_margin), Mm(y_pos), &font);
+    y_pos -= section_gap;
+
+    // === Item and Appraisal Details ===
+    for (line, bold) in appraisal_lines(data) {
+        if y_pos - line_height < bottom_margin {
+            let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+            current_layer = doc.get_page(page).get_layer(layer);
+            y_pos = top;
+        }
+        if line.is_empty() {
+            y_pos -= section_gap - line_height;
+            continue;
+        }
+        let line_font = if bold { &font_bold } else { &font };
+        current_layer.use_text(&line, 10.0, Mm(left_margin), Mm(y_pos), line_font);
+        y_pos -= line_height;
+    }
+
+    // === Value and Signature ===
+    if y_pos - (section_gap * 3.0 + line_height * 3.0) < bottom_margin {
+        let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+        current_layer = doc.get_page(page).get_layer(layer);
+        y_pos = top;
+    }
+    y_pos -= section_gap;
+    current_layer.use_text(
+        format!("APPRAISED VALUE: ${:.2}", data.appraisal.appraised_value),
+        14.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    y_pos -= section_gap * 2.0;
+
+    current_layer.use_text(
+        format!("Appraised By: {}", data.appraisal.appraiser_name),
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font,
+    );
+    y_pos -= section_gap;
+    current_layer.use_text(
+        "Signature: ____________________________   Date: ____________",
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font,
+    );
+
+    // Save PDF to bytes
+    let mut buffer = BufWriter::new(Vec::new());
+    doc.save(&mut buffer)
+        .map_err(|e| AppError::server_error(format!("Failed to save PDF: {:?}", e)))?;
+
+    buffer
+        .into_inner()
+        .map_err(|e| AppError::server_error(format!("Failed to get PDF buffer: {:?}", e)))
+}
+
+/// The appraisal's item and detail lines, each flagged when it is a heading.
+/// An empty line separates sections.
+fn appraisal_lines(data: &AppraisalData) -> Vec<(String, bool)> {
+    let appraisal = &data.appraisal;
+    let mut lines = vec![("ITEM".to_string(), true)];
+    if let Some(ref item_type) = data.ticket.item_type {
+        lines.push((format!("Type: {}", item_type), false));
+    }
+    lines.extend(
+        wrap_text(&data.ticket.item_description, 85)
+            .into_iter()
+            .map(|line| (line, false)),
+    );
+
+    lines.push((String::new(), false));
+    lines.push(("DETAILS".to_string(), true));
+    lines.push((
+        format!(
+            "Metal: {}",
+            appraisal.metal.as_deref().unwrap_or("Not stated")
+        ),
+        false,
+    ));
+    let stones = appraisal.stones.as_deref().unwrap_or("None");
+    lines.extend(
+        wrap_text(&format!("Stones: {}", stones), 85)
+            .into_iter()
+            .map(|line| (line, false)),
+    );
+    if !appraisal.certification_numbers.is_empty() {
+        lines.push((
+            format!(
+                "Certification #: {}",
+                appraisal.certification_numbers.join(", ")
+            ),
+            false,
+        ));
+    }
+
+    if let Some(ref notes) = appraisal.notes {
+        lines.push((String::new(), false));
+        lines.push(("NOTES".to_string(), true));
+        lines.extend(wrap_text(notes, 85).into_iter().map(|line| (line, false)));
+    }
+    lines
+}
+
+/// Generate the daily closeout PDF for a store.
+///
+/// The closeout is the end-of-day paper trail and includes:
+/// - Store name and date
+/// - Payments received, by method, with the total to reconcile the drawer
+/// - Tickets taken in and closed, with their quotes and final amounts
+/// - Tickets waiting for pickup and their balances
+/// - A counted-by signature line
+pub fn generate_daily_closeout_pdf(report: &DailyCloseoutReport) -> Result<Vec<u8>, AppError> {
+    // Letter size: 8.5" x 11" = 215.9mm x 279.4mm
+    let (page_width, page_height) = (Mm(215.9), Mm(279.4));
+    let (doc, page1, layer1) =
+        PdfDocument::new("Daily Closeout", page_width, page_height, "Layer 1");
+    let mut current_layer = doc.get_page(page1).get_layer(layer1);
+
+    let font = doc
+        .add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| AppError::server_error(format!("Failed to load font: {:?}", e)))?;
+    let font_bold = doc
+        .add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|e| AppError::server_error(format!("Failed to load bold font: {:?}", e)))?;
+
+    let top = 260.0;
+    let bottom_margin = 25.0;
+    let mut y_pos = top;
+    let left_margin = 20.0;
+    let amount_x = 165.0;
+    let line_height = 6.0;
+    let section_gap = 10.0;
+
+    // === Header ===
+    current_layer.use_text(
+        &report.store_name,
+        14.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    y_pos -= line_height * 1.5;
+
+    current_layer.use_text(
+        "DAILY CLOSEOUT",
+        14.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font_bold,
+    );
+    current_layer.use_text(
+        report.date.format("%B %d, %Y").to_string(),
+        10.0,
+        Mm(amount_x - 15.0),
+        Mm(y_pos),
+        &font,
+    );
+    y_pos -= section_gap;
+
+    // === Sections ===
+    for (label, amount, bold) in daily_closeout_lines(report) {
+        if y_pos < bottom_margin {
+            let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+            current_layer = doc.get_page(page).get_layer(layer);
+            y_pos = top;
+        }
+
+        let row_font = if bold { &font_bold } else { &font };
+        current_layer.use_text(&label, 10.0, Mm(left_margin), Mm(y_pos), row_font);
+        current_layer.use_text(&amount, 10.0, Mm(amount_x), Mm(y_pos), row_font);
+        y_pos -= line_height;
+    }
+
+    // === Counted By Signature ===
+    if y_pos - section_gap < bottom_margin {
+        let (page, layer) = doc.add_page(page_width, page_height, "Layer 1");
+        current_layer = doc.get_page(page).get_layer(layer);
+        y_pos = top;
+    }
+    y_pos -= section_gap;
+    current_layer.use_text(
+        "Counted By: ____________________________   Date: ____________",
+        10.0,
+        Mm(left_margin),
+        Mm(y_pos),
+        &font,
+    );
+
+    let mut buffer = BufWriter::new(Vec::new());
+    doc.save(&mut buffer)
+        .map_err(|e| AppError::server_error(format!("Failed to save PDF: {:?}", e)))?;
+
+    buffer
+        .into_inner()
+        .map_err(|e| AppError::server_error(format!("Failed to get PDF buffer: {:?}", e)))
+}
+
+/// The closeout's `(label, amount, is_heading)` rows, with a blank row
+/// between sections.
+fn daily_closeout_lines(report: &DailyCloseoutReport) -> Vec<(String, String, bool)> {
+    let mut lines = vec![("PAYMENTS RECEIVED".to_string(), String::new(), true)];
+    for total in &report.payments_by_method {
+        let method = match total.method {
+            PaymentMethod::Cash => "Cash",
+            PaymentMethod::Card => "Card",
+            PaymentMethod::Check => "Check",
+            PaymentMethod::Other => "Other",
+        };
+        lines.push((
+            format!("{} ({})", method, total.payments),
+            format!("${:.2}", total.total),
+            false,
+        ));
+    }
+    lines.push((
+        "Total".to_string(),
+        format!("${:.2}", report.payments_total),
+        true,
+    ));
+
+    // Tickets waiting for pickup show what's still owed; the rest show
+    // their final amount, or the quote before then
+    let sections = [
+        ("TAKEN IN", &report.taken_in, false),
+        ("CLOSED", &report.closed, false),
+        ("READY FOR PICKUP", &report.ready_for_pickup, true),
+    ];
+    for (heading, tickets, show_balance) in sections {
+        lines.push((String::new(), String::new(), false));
+        lines.push((
+            format!("{} ({})", heading, tickets.len()),
+            String::new(),
+            true,
+        ));
+        if tickets.is_empty() {
+            lines.push(("None".to_string(), String::new(), false));
+        }
+        for ticket in tickets {
+            let amount = if show_balance {
+                balance_due(
+                    ticket.actual_amount.or(ticket.quote_amount),
+                    ticket.total_paid,
+                )
+                .map(|due| format!("Due ${:.2}", due))
+            } else {
+                ticket
+                    .actual_amount
+                    .or(ticket.quote_amount)
+                    .map(|amount| format!("${:.2}", amount))
+            };
+            lines.push((
+                closeout_ticket_line(ticket),
+                amount.unwrap_or_default(),
+                false,
+            ));
+        }
+    }
+
+    lines.push((String::new(), String::new(), false));
+    lines.push((
+        "Closed Revenue".to_string(),
+        format!("${:.2}", report.closed_revenue),
+        true,
+    ));
+
+    lines
+}
+
+/// One ticket on the closeout: code, rush flag, customer, and item, cut to
+/// fit before the amount column.
+fn closeout_ticket_line(ticket: &CloseoutTicketEntry) -> String {
+    truncate_text(
+        &format!(
+            "{}{}  {} - {}",
+            ticket.friendly_code,
+            if ticket.is_rush { " RUSH" } else { "" },
+            ticket.customer_name,
+            ticket.item_description
+        ),
+        75,
+    )
+}
+
+/// Height reserved below the items for pricing, dates, signature, and footer.
+const RECEIPT_CLOSING_HEIGHT_MM: f32 = 110.0;
+
+/// Format the invoice lines for one item of `count`: a heading, the item's
+/// description, and the work done.
+fn invoice_item_lines(item: &TicketItem, count: usize) -> Vec<String> {
+    let mut heading = if count > 1 {
+        format!("Item {} of {}", item.position, count)
+    } else {
+        "Item".to_string()
+    };
+    if let Some(ref item_type) = item.item_type {
+        heading = format!("{}: {}", heading, item_type);
+    }
+    let mut lines = vec![heading];
+    lines.extend(wrap_text(&item.item_description, 85));
+    lines.extend(wrap_text(&format!("Work: {}", item.requested_work), 85));
+    lines
+}
+
+/// One `(description, amount)` row per payment received.
+fn invoice_payment_lines(payments: &[TicketPaymentEntry]) -> Vec<(String, String)> {
+    payments
+        .iter()
+        .map(|payment| {
+            let kind = match payment.kind {
+                PaymentKind::Deposit => "Deposit",
+                PaymentKind::Final => "Payment",
+            };
+            let method = match payment.method {
+                PaymentMethod::Cash => "cash",
+                PaymentMethod::Card => "card",
+                PaymentMethod::Check => "check",
+                PaymentMethod::Other => "other",
+            };
+            (
+                format!(
+                    "{} {} ({})",
+                    payment.received_at.format("%b %d, %Y"),
+                    kind,
+                    method
+                ),
+                format!("${:.2}", payment.amount),
+            )
+        })
+        .collect()
+}
+
+/// The invoice's `(label, amount, is_total)` rows.
+///
+/// With a tax rate the subtotal and tax recorded at close are listed above
+/// the total. Payments and the balance due follow.
+fn invoice_total_lines(data: &InvoiceData) -> Vec<(String, String, bool)> {
+    let tax = data.invoice.tax();
+    let mut lines = Vec::new();
+
+    if tax.tax_rate > Decimal::ZERO {
+        lines.push((
+            "Subtotal".to_string(),
+            format!("${:.2}", tax.subtotal),
+            false,
+        ));
+        lines.push((
+            format!("Tax ({}%)", tax.tax_rate.normalize()),
+            format!("${:.2}", tax.tax_amount),
+            false,
+        ));
+    }
+    lines.push(("Total".to_string(), format!("${:.2}", tax.total), true));
+
+    let paid: Decimal = data.payments.iter().map(|payment| payment.amount).sum();
+    lines.push(("Paid".to_string(), format!("${:.2}", paid), false));
+    let balance = balance_due(Some(tax.total), paid).unwrap_or_default();
+    lines.push(("Balance Due".to_string(), format!("${:.2}", balance), true));
+
+    lines
+}
+
+/// Quote, payment, and balance lines for a receipt, each flagged when it is
+/// a total to emphasise. Empty when the store leaves prices off receipts.
+pub(crate) fn receipt_price_lines(data: &ReceiptData) -> Vec<(String, bool)> {
+    let mut lines = Vec::new();
+    if !data.template.show_prices {
+        return lines;
+    }
+
+    if let (Some(quote), Some(surcharge)) = (data.ticket.quote_amount, data.ticket.rush_surcharge) {
+        if surcharge > Decimal::ZERO {
+            lines.push((format!("Work: ${:.2}", quote - surcharge), false));
+            lines.push((format!("Rush Surcharge: ${:.2}", surcharge), false));
+        }
+    }
+
+    if let Some(quote) = data.ticket.quote_amount {
+        lines.push((format!("Estimated Price: ${:.2}", quote), true));
+    }
+
+    if let Some(tax) = data.tax.filter(|tax| tax.tax_rate > Decimal::ZERO) {
+        let rate = tax.tax_rate.normalize();
+        if tax.tax_inclusive {
+            lines.push((
+                format!("Includes Tax ({}%): ${:.2}", rate, tax.tax_amount),
+                false,
+            ));
+        } else {
+            lines.push((format!("Tax ({}%): ${:.2}", rate, tax.tax_amount), false));
+            lines.push((format!("Total: ${:.2}", tax.total), true));
+        }
+    }
+
+    if data.deposit_total > Decimal::ZERO {
+        lines.push((format!("Deposit Paid: ${:.2}", data.deposit_total), false));
+    }
+
+    if data.total_paid > Decimal::ZERO {
+        let owed = match data.tax {
+            Some(tax) => Some(tax.total),
+            None => data.ticket.actual_amount.or(data.ticket.quote_amount),
+        };
+        if let Some(balance) = balance_due(owed, data.total_paid) {
+            lines.push((format!("Balance Due: ${:.2}", balance), true));
+        }
+    }
+
+    lines
+}
+
+/// The standard pickup and status lookup notice at the bottom of a receipt.
+pub(crate) fn receipt_notice_lines(ticket: &Ticket) -> Vec<String> {
+    vec![
+        "Please retain this receipt for pickup.".to_string(),
+        format!(
+            "Ticket ID required for all inquiries: {}",
+            ticket.friendly_code
+        ),
+        format!(
+            "Check repair status online with status code: {}",
+            ticket.lookup_token
+        ),
+    ]
+}
+
+/// Format the receipt lines for one item of `count`.
+///
+/// The first line is the heading; the rest are indented details. An item's
+/// own quote is listed only when the ticket has several items and the
+/// receipt shows prices.
+pub(crate) fn receipt_item_lines(
+    item: &TicketItem,
+    count: usize,
+    show_prices: bool,
+) -> Vec<String> {
+    let mut heading = if count > 1 {
+        format!("Item {} of {}", item.position, count)
+    } else {
+        "Item".to_string()
+    };
+    if let Some(ref item_type) = item.item_type {
+        heading = format!("{}: {}", heading, item_type);
+    }
+    let mut lines = vec![heading];
+
+    lines.extend(wrap_text(
+        &format!("Description: {}", item.item_description),
+        75,
+    ));
+    lines.extend(wrap_text(
+        &format!("Condition: {}", item.condition_notes),
+        75,
+    ));
+    lines.extend(wrap_text(
+        &format!("Requested Work: {}", item.requested_work),
+        75,
+    ));
+    if let (true, Some(quote)) = (count > 1 && show_prices, item.quote_amount) {
+        lines.push(format!("Quote: ${:.2}", quote));
+    }
+
+    lines
+}
+
+/// Most lines of store disclaimer printed on a receipt.
+const RECEIPT_FOOTER_MAX_LINES: usize = 20;
+
+/// Wrap the store's receipt disclaimer to `width` characters, keeping its
+/// own line breaks.
+pub(crate) fn receipt_footer_lines(text: Option<&str>, width: usize) -> Vec<String> {
+    let Some(text) = text.filter(|text| !text.trim().is_empty()) else {
+        return Vec::new();
+    };
+    text.lines()
+        .flat_map(|line| wrap_text(line, width))
+        .take(RECEIPT_FOOTER_MAX_LINES)
+        .collect()
+}
+
+/// Format the report lines for one custody event.
+///
+/// The first line is the heading; the rest are indented details.
+fn custody_event_lines(event: &CustodyEventEntry) -> Vec<String> {
+    let mut lines = vec![format!(
+        "#{}  {}  {}",
+        event.sequence,
+        event.event_type.label(),
+        event.occurred_at.format("%B %d, %Y at %I:%M %p UTC")
+    )];
+
+    lines.push(format!(
+        "Handled By: {}   Witness: {}",
+        event.handled_by_name,
+        event.witnessed_by_name.as_deref().unwrap_or("None")
+    ));
+
+    match (&event.from_location_name, &event.to_location_name) {
+        (Some(from), Some(to)) => lines.push(format!("Moved: {} -> {}", from, to)),
+        (Some(from), None) => lines.push(format!("From: {}", from)),
+        (None, Some(to)) => lines.push(format!("To: {}", to)),
+        (None, None) => {}
+    }
+
+    if let Some(ref notes) = event.notes {
+        lines.extend(wrap_text(&format!("Notes: {}", notes), 75));
+    }
+
+    lines
+}
+
+/// Format the report lines for one release.
+///
+/// The first line is the heading; the rest are indented details.
+fn pickup_lines(pickup: &TicketPickupEntry) -> Vec<String> {
+    let collector = match (&pickup.authorized_pickup_id, &pickup.relationship) {
+        (None, _) => format!("{} (customer)", pickup.picked_up_by_name),
+        (Some(_), Some(relationship)) => format!(
+            "{} ({}, authorized by customer)",
+            pickup.picked_up_by_name, relationship
+        ),
+        (Some(_), None) => format!("{} (authorized by customer)", pickup.picked_up_by_name),
+    };
+    let mut lines = vec![format!(
+        "{}  Picked up by {}",
+        pickup.picked_up_at.format("%B %d, %Y at %I:%M %p UTC"),
+        collector
+    )];
+
+    match (&pickup.id_type, &pickup.id_last_four) {
+        (Some(id_type), Some(last_four)) => {
+            lines.push(format!("ID: {} ending {}", id_type, last_four))
+        }
+        (Some(id_type), None) => lines.push(format!("ID: {}", id_type)),
+        (None, Some(last_four)) => lines.push(format!("ID ending {}", last_four)),
+        (None, None) => lines.push("ID: Not recorded".to_string()),
+    }
+    lines.push(format!("Released By: {}", pickup.released_by_name));
+
+    lines
+}
+
+/// Decode a PNG signature into a greyscale PDF image, flattened onto white.
+///
+/// Returns the image with the DPI that draws it within the signature box,
+/// and its drawn height in mm. Returns None if the PNG can't be decoded.
+fn signature_image(png: &[u8]) -> Option<(ImageXObject, f32, f32)> {
+    let image = ::image::load_from_memory_with_format(png, ::image::ImageFormat::Png).ok()?;
+    greyscale_image(&image, SIGNATURE_MAX_WIDTH_MM, SIGNATURE_MAX_HEIGHT_MM)
+}
+
+/// Decode a PNG or JPEG store logo like `signature_image`, sized for the
+/// receipt header.
+fn logo_image(data: &[u8]) -> Option<(ImageXObject, f32, f32)> {
+    let image = ::image::load_from_memory(data).ok()?;
+    greyscale_image(&image, LOGO_MAX_WIDTH_MM, LOGO_MAX_HEIGHT_MM)
+}
+
+/// Convert an image to a greyscale PDF image, flattened onto white.
+///
+/// Returns the image with the DPI that draws it within a `max_width_mm` by
+/// `max_height_mm` box, and its drawn height in mm.
+fn greyscale_image(
+    image: &::image::DynamicImage,
+    max_width_mm: f32,
+    max_height_mm: f32,
+) -> Option<(ImageXObject, f32, f32)> {
+    let rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let image_data = rgba.pixels().map(|pixel| grey_on_white(*pixel)).collect();
+
+    // Never upscale past 72 DPI; shrink until it fits the box
+    let dpi = (width as f32 * 25.4 / max_width_mm)
+        .max(height as f32 * 25.4 / max_height_mm)
+        .max(72.0);
+    let height_mm = height as f32 * 25.4 / dpi;
+
+    let image = ImageXObject {
+        width: Px(width as usize),
+        height: Px(height as usize),
+        color_space: ColorSpace::Greyscale,
+        bits_per_component: ColorBits::Bit8,
+        interpolate: true,
+        image_data,
+        image_filter: None,
+        smask: None,
+        clipping_bbox: None,
+    };
+    Some((image, dpi, height_mm))
+}
+
+/// Grey level of a pixel, with any transparency flattened onto white.
+pub(crate) fn grey_on_white(pixel: ::image::Rgba<u8>) -> u8 {
+    let [r, g, b, a] = pixel.0.map(f32::from);
+    let luma = 0.299 * r + 0.587 * g + 0.114 * b;
+    let alpha = a / 255.0;
+    (luma * alpha + 255.0 * (1.0 - alpha)).round() as u8
+}
+
+/// Format a QC checklist line with the result from the most recent check.
+fn qc_checklist_line(item: &str, check: Option<&TicketQcCheck>) -> String {
+    let mark = match check {
+        Some(c) if c.passed_items.iter().any(|i| i == item) => "PASS",
+        Some(c) if c.failed_items.iter().any(|i| i == item) => "FAIL",
+        _ => "    ",
+    };
+    format!("[{}] {}", mark, item)
+}
+
+/// Simple text wrapper for PDF output.
+pub(crate) fn wrap_text(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current_line = String::new();
+
+    for word in text.split_whitespace() {
+        if current_line.is_empty() {
+            current_line = word.to_string();
+        } else if current_line.len() + 1 + word.len() <= max_chars {
+            current_line.push(' ');
+            current_line.push_str(word);
+        } else {
+            lines.push(current_line);
+            current_line = word.to_string();
+        }
+    }
+
+    if !current_line.is_empty() {
+        lines.push(current_line);
+    }
+
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    lines
+}
+
+/// Generate a label PDF for a physical tag.
+///
+/// The label is sized to the store's label stock (2x1 inches, 50.8mm x
+/// 25.4mm, by default) and includes:
+/// - Ticket friendly code (large, prominent)
+/// - Customer name and a short item descriptor
+/// - RUSH, for rush tickets
+///
+/// See `label_layout` for how the text is arranged on each stock shape.
+pub fn generate_label_pdf(data: &LabelData) -> Result<Vec<u8>, AppError> {
+    let (doc, page1, layer1) = PdfDocument::new(
+        "Repair Label",
+        Mm(data.width_mm),
+        Mm(data.height_mm),
+        "Layer 1",
+    );
+    let current_layer = doc.get_page(page1).get_layer(layer1);
+
+    // Load built-in fonts
+    let font_bold = doc
+        .add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|e| AppError::server_error(format!("Failed to load bold font: {:?}", e)))?;
+    let font = doc
+        .add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| AppError::server_error(format!("Failed to load font: {:?}", e)))?;
+
+    for text in label_layout(data) {
+        let font = if text.bold { &font_bold } else { &font };
+        current_layer.use_text(&text.text, text.size, Mm(text.x), Mm(text.y), font);
+    }
+
+    // Save PDF to bytes
+    let mut buffer = BufWriter::new(Vec::new());
+    doc.save(&mut buffer)
+        .map_err(|e| AppError::server_error(format!("Failed to save PDF: {:?}", e)))?;
+
+    buffer
+        .into_inner()
+        .map_err(|e| AppError::server_error(format!("Failed to get PDF buffer: {:?}", e)))
+}
+
+/// Stock at least this many times as wide as it is tall is a barbell tag.
+const BARBELL_MIN_ASPECT: f32 = 3.5;
+
+/// Share of a barbell tag's width taken by each printable flap; the tail
+/// between them wraps around the item and is left blank.
+const BARBELL_FLAP_SHARE: f32 = 0.4;
+
+/// Height of a standard barbell tag, in mm.
+const BARBELL_HEIGHT_MM: f32 = 12.7;
+
+/// Space kept clear at the edges of a label, in mm.
+const LABEL_MARGIN_MM: f32 = 2.0;
+
+/// One line of text placed on a label.
+#[derive(Debug, Clone, PartialEq)]
+struct LabelText {
+    text: String,
+    /// Font size in points
+    size: f32,
+    bold: bool,
+    /// Left edge and baseline, in mm from the bottom left corner
+    x: f32,
+    y: f32,
+}
+
+impl LabelText {
+    /// Place `text` centered on `center_x`, kept inside the left margin.
+    fn centered(text: String, size: f32, bold: bool, center_x: f32, y: f32) -> Self {
+        let x = center_x - label_text_width(&text, size, bold) / 2.0;
+        Self {
+            text,
+            size,
+            bold,
+            x: x.max(LABEL_MARGIN_MM),
+            y,
+        }
+    }
+}
+
+/// Approximate printed width of label text in mm (Helvetica averages about
+/// 0.22mm per point per character, and 0.25mm in bold).
+fn label_text_width(text: &str, size: f32, bold: bool) -> f32 {
+    let per_point = if bold { 0.25 } else { 0.22 };
+    text.chars().count() as f32 * size * per_point
+}
+
+/// Largest font size up to `size` at which `text` fits in `width` mm.
+fn fit_label_text(text: &str, size: f32, bold: bool, width: f32) -> f32 {
+    let natural = label_text_width(text, size, bold);
+    if natural > width {
+        size * width / natural
+    } else {
+        size
+    }
+}
+
+/// Characters of regular text at `size` that fit in `width` mm.
+fn label_chars(size: f32, width: f32) -> usize {
+    (width / (size * 0.22)) as usize
+}
+
+/// Arrange a label's text for its stock.
+///
+/// Barbell tags (see `BARBELL_MIN_ASPECT`) carry the ticket code and RUSH
+/// on the left flap and the customer and item on the right. Other stock
+/// stacks centered rows, as on the standard 2x1 tag: rows keep their
+/// relative height, text scales with the smaller dimension, and wider
+/// stock fits more characters per row. The ticket code shrinks if it
+/// would run off the label.
+fn label_layout(data: &LabelData) -> Vec<LabelText> {
+    let (width, height) = (data.width_mm, data.height_mm);
+    let mut texts = Vec::new();
+
+    if width / height >= BARBELL_MIN_ASPECT {
+        let scale = height / BARBELL_HEIGHT_MM;
+        let flap_width = width * BARBELL_FLAP_SHARE;
+        let usable = flap_width - 2.0 * LABEL_MARGIN_MM;
+
+        // === Left flap: ticket code and rush ===
+        let left_center = flap_width / 2.0;
+        let code = &data.ticket.friendly_code;
+        let code_size = fit_label_text(code, 10.0 * scale, true, usable);
+        let code_y = if data.ticket.is_rush {
+            height * 0.5
+        } else {
+            height * 0.35
+        };
+        texts.push(LabelText::centered(
+            code.clone(),
+            code_size,
+            true,
+            left_center,
+            code_y,
+        ));
+        if data.ticket.is_rush {
+            texts.push(LabelText::centered(
+                "RUSH".to_string(),
+                6.0 * scale,
+                true,
+                left_center,
+                height * 0.15,
+            ));
+        }
+
+        // === Right flap: customer and item ===
+        let right_center = width - flap_width / 2.0;
+        let name_size = 6.5 * scale;
+        let desc_size = 5.5 * scale;
+        texts.push(LabelText::centered(
+            truncate_text(&data.customer_name, label_chars(name_size, usable)),
+            name_size,
+            false,
+            right_center,
+            height * 0.58,
+        ));
+        texts.push(LabelText::centered(
+            create_short_descriptor(
+                data.ticket.item_type.as_deref(),
+                &data.ticket.item_description,
+                label_chars(desc_size, usable),
+            ),
+            desc_size,
+            false,
+            right_center,
+            height * 0.22,
+        ));
+        return texts;
+    }
+
+    let scale = (width / DEFAULT_LABEL_WIDTH_MM).min(height / DEFAULT_LABEL_HEIGHT_MM);
+    let row_y = |default_y: f32| default_y * height / DEFAULT_LABEL_HEIGHT_MM;
+    let row_chars = |default_chars: usize| {
+        (default_chars as f32 * width / (DEFAULT_LABEL_WIDTH_MM * scale)) as usize
+    };
+    let center_x = width / 2.0;
+
+    // === Ticket Code (large, prominent, centered) ===
+    let code = &data.ticket.friendly_code;
+    let code_size = fit_label_text(code, 14.0 * scale, true, width - 2.0 * LABEL_MARGIN_MM);
+    texts.push(LabelText::centered(
+        code.clone(),
+        code_size,
+        true,
+        center_x,
+        row_y(20.0),
+    ));
+
+    // === Customer Name (below ticket code) ===
+    texts.push(LabelText::centered(
+        truncate_text(&data.customer_name, row_chars(20)),
+        9.0 * scale,
+        false,
+        center_x,
+        row_y(14.5),
+    ));
+
+    // === Item Descriptor (smaller, below customer name) ===
+    texts.push(LabelText::centered(
+        create_short_descriptor(
+            data.ticket.item_type.as_deref(),
+            &data.ticket.item_description,
+            row_chars(25),
+        ),
+        8.0 * scale,
+        false,
+        center_x,
+        row_y(9.0),
+    ));
+
+    // === Rush indicator (if applicable) ===
+    if data.ticket.is_rush {
+        texts.push(LabelText::centered(
+            "RUSH".to_string(),
+            10.0 * scale,
+            true,
+            center_x,
+            row_y(3.5),
+        ));
+    }
+
+    texts
+}
+
+/// Create a short descriptor for the label from item type and description.
+///
+/// Combines item_type (if present) with a truncated description,
+/// keeping the total at most `max_len` characters.
+fn create_short_descriptor(item_type: Option<&str>, description: &str, max_len: usize) -> String {
+    match item_type {
+        Some(t) if !t.is_empty() => {
+            // "Ring - Gold band with..."
+            let prefix = format!("{} - ", t);
+            let remaining = max_len.saturating_sub(prefix.len());
+
+            if remaining > 3 {
+                let truncated_desc = truncate_text(description, remaining);
+                format!("{}{}", prefix, truncated_desc)
+            } else {
+                truncate_text(t, max_len)
+            }
+        }
+        _ => truncate_text(description, max_len),
+    }
+}
+
+/// Truncate text to a maximum length, adding "..." if truncated.
+fn truncate_text(text: &str, max_len: usize) -> String {
+    if text.len() <= max_len {
+        text.to_string()
+    } else if max_len <= 3 {
+        text.chars().take(max_len).collect()
+    } else {
+        let truncated: String = text.chars().take(max_len - 3).collect();
+        format!("{}...", truncated.trim_end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TaxRate;
+
+    #[test]
+    fn test_verify_fonts() {
+        assert!(verify_fonts().is_ok());
+    }
+
+    #[test]
+    fn test_wrap_text_short() {
         let lines = wrap_text("Hello world", 80);
         assert_eq!(lines.len(), 1);
         assert_eq!(lines[0], "Hello world");
@@ -481,25 +1954,25 @@ mod tests {
 
     #[test]
     fn test_create_short_descriptor_with_type() {
-        let result = create_short_descriptor(Some("Ring"), "Gold band with diamonds");
+        let result = create_short_descriptor(Some("Ring"), "Gold band with diamonds", 25);
         assert_eq!(result, "Ring - Gold band with...");
     }
 
     #[test]
     fn test_create_short_descriptor_without_type() {
-        let result = create_short_descriptor(None, "Gold band with diamonds");
+        let result = create_short_descriptor(None, "Gold band with diamonds", 25);
         assert_eq!(result, "Gold band with diamonds");
     }
 
     #[test]
     fn test_create_short_descriptor_empty_type() {
-        let result = create_short_descriptor(Some(""), "Gold band");
+        let result = create_short_descriptor(Some(""), "Gold band", 25);
         assert_eq!(result, "Gold band");
     }
 
     #[test]
     fn test_create_short_descriptor_short_description() {
-        let result = create_short_descriptor(Some("Ring"), "Gold");
+        let result = create_short_descriptor(Some("Ring"), "Gold", 25);
         assert_eq!(result, "Ring - Gold");
     }
 
@@ -508,8 +1981,554 @@ mod tests {
         let result = create_short_descriptor(
             Some("Necklace"),
             "Beautiful platinum chain with sapphire pendant",
+            25,
         );
         assert!(result.len() <= 25);
         assert!(result.ends_with("..."));
     }
+
+    #[test]
+    fn test_create_short_descriptor_wider_label() {
+        let result = create_short_descriptor(
+            Some("Necklace"),
+            "Beautiful platinum chain with sapphire pendant",
+            60,
+        );
+        assert_eq!(
+            result,
+            "Necklace - Beautiful platinum chain with sapphire pendant"
+        );
+    }
+
+    fn test_ticket() -> Ticket {
+        Ticket {
+            ticket_id: uuid::Uuid::nil(),
+            friendly_code: "JR-0042".to_string(),
+            customer_id: uuid::Uuid::nil(),
+            status: crate::models::ticket::TicketStatus::Intake,
+            item_type: Some("Ring".to_string()),
+            item_description: "Gold band with three small diamonds".to_string(),
+            condition_notes: String::new(),
+            requested_work: String::new(),
+            is_rush: false,
+            promise_date: None,
+            storage_location_id: uuid::Uuid::nil(),
+            store_id: uuid::Uuid::nil(),
+            quote_amount: None,
+            actual_amount: None,
+            taken_in_by: uuid::Uuid::nil(),
+            worked_by: None,
+            closed_by: None,
+            last_modified_by: None,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+            closed_at: None,
+            queue_position: None,
+            deleted_at: None,
+            deleted_by: None,
+            rush_surcharge: None,
+            is_training: false,
+            quote_status: Default::default(),
+            lookup_token: "tok".to_string(),
+            weight_grams: None,
+            metal_type: None,
+        }
+    }
+
+    fn label_data(width_mm: f32, height_mm: f32, is_rush: bool) -> LabelData {
+        LabelData {
+            ticket: Ticket {
+                is_rush,
+                ..test_ticket()
+            },
+            customer_name: "Margaret Elizabeth Thompson-Whitfield".to_string(),
+            width_mm,
+            height_mm,
+        }
+    }
+
+    fn assert_fits(texts: &[LabelText], width: f32, height: f32) {
+        for text in texts {
+            let right = text.x + label_text_width(&text.text, text.size, text.bold);
+            assert!(text.x >= LABEL_MARGIN_MM - 0.01, "{:?}", text);
+            assert!(right <= width - LABEL_MARGIN_MM + 0.01, "{:?}", text);
+            assert!(text.y > 0.0 && text.y < height, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn test_label_layout_stacks_rows_on_tags() {
+        for (width, height) in [(50.8, 25.4), (57.2, 31.8)] {
+            let texts = label_layout(&label_data(width, height, true));
+            assert_eq!(texts.len(), 4);
+            assert_eq!(texts[0].text, "JR-0042");
+            assert_eq!(texts[3].text, "RUSH");
+            assert!(texts.windows(2).all(|pair| pair[0].y > pair[1].y));
+            assert_fits(&texts, width, height);
+        }
+    }
+
+    #[test]
+    fn test_label_layout_splits_barbell_flaps() {
+        let (width, height) = (55.9, 12.7);
+        let texts = label_layout(&label_data(width, height, true));
+        let flap = width * BARBELL_FLAP_SHARE;
+
+        let (left, right): (Vec<_>, Vec<_>) = texts.iter().partition(|text| text.x < flap);
+        let left_text: Vec<&str> = left.iter().map(|text| text.text.as_str()).collect();
+        assert_eq!(left_text, ["JR-0042", "RUSH"]);
+        assert_eq!(right.len(), 2);
+        assert!(right.iter().all(|text| text.x >= width - flap));
+        assert_fits(&texts, width, height);
+    }
+
+    #[test]
+    fn test_label_layout_shrinks_long_codes() {
+        let mut data = label_data(25.0, 25.4, false);
+        data.ticket.friendly_code = "STORE-2026-00042".to_string();
+        let texts = label_layout(&data);
+        assert!(texts[0].size < 14.0);
+        assert_fits(&texts, 25.0, 25.4);
+    }
+
+    fn invoice_data(amount: Decimal, tax: TaxRate, paid: &[Decimal]) -> InvoiceData {
+        let tax = tax.apply(amount);
+        InvoiceData {
+            ticket: Ticket {
+                status: crate::models::ticket::TicketStatus::Closed,
+                actual_amount: Some(amount),
+                ..test_ticket()
+            },
+            customer: Customer {
+                customer_id: uuid::Uuid::nil(),
+                name: "Jane Doe".to_string(),
+                phone: None,
+                email: None,
+                notifications_opt_out: false,
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+            },
+            invoice: TicketInvoice {
+                ticket_id: uuid::Uuid::nil(),
+                invoice_number: 7,
+                tax_rate: tax.tax_rate,
+                tax_inclusive: tax.tax_inclusive,
+                tax_amount: tax.tax_amount,
+                total_amount: tax.total,
+                issued_at: chrono::Utc::now(),
+            },
+            items: vec![ticket_item(1)],
+            payments: paid
+                .iter()
+                .map(|amount| TicketPaymentEntry {
+                    payment_id: uuid::Uuid::nil(),
+                    kind: PaymentKind::Deposit,
+                    method: PaymentMethod::Card,
+                    amount: *amount,
+                    notes: None,
+                    received_by: uuid::Uuid::nil(),
+                    received_by_name: "Sam".to_string(),
+                    received_at: chrono::Utc::now(),
+                })
+                .collect(),
+            store_name: "Test Store".to_string(),
+            store_phone: None,
+            store_address: None,
+        }
+    }
+
+    #[test]
+    fn test_invoice_total_lines_break_out_tax() {
+        let data = invoice_data(
+            Decimal::new(10825, 2),
+            TaxRate {
+                rate: Decimal::new(8250, 3),
+                inclusive: true,
+            },
+            &[Decimal::new(5000, 2)],
+        );
+        let lines = invoice_total_lines(&data);
+        let lines: Vec<(&str, &str, bool)> = lines
+            .iter()
+            .map(|(label, amount, total)| (label.as_str(), amount.as_str(), *total))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                ("Subtotal", "$100.00", false),
+                ("Tax (8.25%)", "$8.25", false),
+                ("Total", "$108.25", true),
+                ("Paid", "$50.00", false),
+                ("Balance Due", "$58.25", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invoice_total_lines_add_exclusive_tax() {
+        let data = invoice_data(
+            Decimal::new(10000, 2),
+            TaxRate {
+                rate: Decimal::new(8250, 3),
+                inclusive: false,
+            },
+            &[Decimal::new(10000, 2)],
+        );
+        let lines = invoice_total_lines(&data);
+        let amounts: Vec<&str> = lines.iter().map(|(_, amount, _)| amount.as_str()).collect();
+        assert_eq!(amounts, ["$100.00", "$8.25", "$108.25", "$100.00", "$8.25"]);
+    }
+
+    #[test]
+    fn test_invoice_total_lines_without_tax() {
+        let no_tax = TaxRate {
+            rate: Decimal::ZERO,
+            inclusive: true,
+        };
+        let data = invoice_data(Decimal::new(4500, 2), no_tax, &[]);
+        let labels: Vec<String> = invoice_total_lines(&data)
+            .into_iter()
+            .map(|(label, _, _)| label)
+            .collect();
+        assert_eq!(labels, ["Total", "Paid", "Balance Due"]);
+    }
+
+    #[test]
+    fn test_generate_invoice_pdf() {
+        let data = invoice_data(
+            Decimal::new(10825, 2),
+            TaxRate {
+                rate: Decimal::new(825, 2),
+                inclusive: true,
+            },
+            &[Decimal::new(10825, 2)],
+        );
+        let pdf = generate_invoice_pdf(&data).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    fn ticket_item(position: i32) -> TicketItem {
+        TicketItem {
+            item_id: uuid::Uuid::nil(),
+            ticket_id: uuid::Uuid::nil(),
+            position,
+            item_type: Some("Ring".to_string()),
+            item_description: "Gold band".to_string(),
+            condition_notes: "Worn shank".to_string(),
+            requested_work: "Resize to 7".to_string(),
+            quote_amount: Some(Decimal::new(4500, 2)),
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_receipt_item_lines_single_item() {
+        let lines = receipt_item_lines(&ticket_item(1), 1, true);
+        assert_eq!(
+            lines,
+            vec![
+                "Item: Ring",
+                "Description: Gold band",
+                "Condition: Worn shank",
+                "Requested Work: Resize to 7",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_receipt_item_lines_numbers_items_and_lists_quotes() {
+        let mut item = ticket_item(2);
+        item.item_type = None;
+        let lines = receipt_item_lines(&item, 3, true);
+        assert_eq!(lines[0], "Item 2 of 3");
+        assert_eq!(lines.last().unwrap(), "Quote: $45.00");
+
+        item.quote_amount = None;
+        let lines = receipt_item_lines(&item, 3, true);
+        assert_eq!(lines.last().unwrap(), "Requested Work: Resize to 7");
+    }
+
+    #[test]
+    fn test_receipt_item_lines_hides_quotes_without_prices() {
+        let lines = receipt_item_lines(&ticket_item(2), 3, false);
+        assert_eq!(lines.last().unwrap(), "Requested Work: Resize to 7");
+    }
+
+    #[test]
+    fn test_receipt_footer_lines() {
+        assert!(receipt_footer_lines(None, 110).is_empty());
+        assert!(receipt_footer_lines(Some("  "), 110).is_empty());
+
+        let lines =
+            receipt_footer_lines(Some(&format!("{}\nNo refunds.", "word ".repeat(30))), 110);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].len() <= 110);
+        assert_eq!(lines[2], "No refunds.");
+
+        let lines = receipt_footer_lines(Some(&"line\n".repeat(50)), 110);
+        assert_eq!(lines.len(), RECEIPT_FOOTER_MAX_LINES);
+    }
+
+    fn custody_event() -> CustodyEventEntry {
+        CustodyEventEntry {
+            event_id: uuid::Uuid::nil(),
+            sequence: 2,
+            event_type: crate::models::CustodyEventType::LocationChange,
+            from_location_name: Some("Safe".to_string()),
+            to_location_name: Some("Bench 1".to_string()),
+            handled_by: uuid::Uuid::nil(),
+            handled_by_name: "Alice".to_string(),
+            witnessed_by: Some(uuid::Uuid::nil()),
+            witnessed_by_name: Some("Bob".to_string()),
+            notes: None,
+            occurred_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_custody_event_lines_location_change() {
+        let lines = custody_event_lines(&custody_event());
+        assert!(lines[0].starts_with("#2  Location change"));
+        assert_eq!(lines[1], "Handled By: Alice   Witness: Bob");
+        assert_eq!(lines[2], "Moved: Safe -> Bench 1");
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_custody_event_lines_without_witness_or_locations() {
+        let mut event = custody_event();
+        event.event_type = crate::models::CustodyEventType::Handoff;
+        event.from_location_name = None;
+        event.to_location_name = None;
+        event.witnessed_by_name = None;
+        event.notes = Some("Given to setter".to_string());
+
+        let lines = custody_event_lines(&event);
+        assert_eq!(lines[1], "Handled By: Alice   Witness: None");
+        assert_eq!(lines[2], "Notes: Given to setter");
+    }
+
+    fn qc_check() -> TicketQcCheck {
+        TicketQcCheck {
+            check_id: uuid::Uuid::nil(),
+            ticket_id: uuid::Uuid::nil(),
+            passed: false,
+            passed_items: vec!["Polished".to_string()],
+            failed_items: vec!["Stones secure".to_string()],
+            notes: None,
+            checked_by: uuid::Uuid::nil(),
+            checked_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_qc_checklist_line_pass_and_fail() {
+        let check = qc_check();
+        assert_eq!(
+            qc_checklist_line("Polished", Some(&check)),
+            "[PASS] Polished"
+        );
+        assert_eq!(
+            qc_checklist_line("Stones secure", Some(&check)),
+            "[FAIL] Stones secure"
+        );
+    }
+
+    #[test]
+    fn test_qc_checklist_line_unchecked() {
+        let check = qc_check();
+        assert_eq!(
+            qc_checklist_line("Clasp tested", Some(&check)),
+            "[    ] Clasp tested"
+        );
+        assert_eq!(qc_checklist_line("Polished", None), "[    ] Polished");
+    }
+
+    fn pickup() -> TicketPickupEntry {
+        TicketPickupEntry {
+            pickup_id: uuid::Uuid::nil(),
+            authorized_pickup_id: Some(uuid::Uuid::nil()),
+            picked_up_by_name: "Sam Doe".to_string(),
+            relationship: Some("Spouse".to_string()),
+            id_type: Some("Driver's license".to_string()),
+            id_last_four: Some("6789".to_string()),
+            has_signature: true,
+            released_by: uuid::Uuid::nil(),
+            released_by_name: "Alice".to_string(),
+            picked_up_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_pickup_lines_authorized() {
+        let lines = pickup_lines(&pickup());
+        assert!(lines[0].ends_with("Picked up by Sam Doe (Spouse, authorized by customer)"));
+        assert_eq!(lines[1], "ID: Driver's license ending 6789");
+        assert_eq!(lines[2], "Released By: Alice");
+    }
+
+    #[test]
+    fn test_pickup_lines_customer_without_id() {
+        let entry = TicketPickupEntry {
+            authorized_pickup_id: None,
+            relationship: None,
+            id_type: None,
+            id_last_four: None,
+            ..pickup()
+        };
+        let lines = pickup_lines(&entry);
+        assert!(lines[0].ends_with("Picked up by Sam Doe (customer)"));
+        assert_eq!(lines[1], "ID: Not recorded");
+    }
+
+    #[test]
+    fn test_signature_image_flattens_onto_white() {
+        // 2x1: opaque black, fully transparent
+        let png = {
+            let image = ::image::RgbaImage::from_fn(2, 1, |x, _| {
+                if x == 0 {
+                    ::image::Rgba([0, 0, 0, 255])
+                } else {
+                    ::image::Rgba([0, 0, 0, 0])
+                }
+            });
+            let mut output = std::io::Cursor::new(Vec::new());
+            image
+                .write_to(&mut output, ::image::ImageFormat::Png)
+                .unwrap();
+            output.into_inner()
+        };
+
+        let (image, dpi, height) = signature_image(&png).unwrap();
+        assert_eq!(image.image_data, vec![0, 255]);
+        assert_eq!(dpi, 72.0);
+        assert!(height <= SIGNATURE_MAX_HEIGHT_MM);
+        assert!(signature_image(b"not a png").is_none());
+    }
+
+    #[test]
+    fn test_logo_image_fits_header_box() {
+        let jpeg = {
+            let image = ::image::RgbImage::from_pixel(1200, 300, ::image::Rgb([255, 255, 255]));
+            let mut output = std::io::Cursor::new(Vec::new());
+            image
+                .write_to(&mut output, ::image::ImageFormat::Jpeg)
+                .unwrap();
+            output.into_inner()
+        };
+
+        let (image, dpi, height) = logo_image(&jpeg).unwrap();
+        assert_eq!(image.image_data.len(), 1200 * 300);
+        assert!((1200.0 * 25.4 / dpi - LOGO_MAX_WIDTH_MM).abs() < 0.01);
+        assert!(height <= LOGO_MAX_HEIGHT_MM);
+        assert!(logo_image(b"not an image").is_none());
+    }
+
+    fn appraisal_data(notes: Option<&str>) -> AppraisalData {
+        AppraisalData {
+            ticket: test_ticket(),
+            customer_name: "Jane Doe".to_string(),
+            appraisal: AppraisalEntry {
+                appraisal_id: uuid::Uuid::nil(),
+                ticket_id: uuid::Uuid::nil(),
+                appraised_value: Decimal::new(425000, 2),
+                metal: Some("14k yellow gold".to_string()),
+                stones: None,
+                certification_numbers: vec!["GIA 2141438171".to_string(), "AGS 104".to_string()],
+                notes: notes.map(str::to_string),
+                appraiser_id: uuid::Uuid::nil(),
+                appraiser_name: "Alice".to_string(),
+                appraised_on: chrono::NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(),
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+            },
+            store_name: "Facet Jewelers".to_string(),
+            store_phone: Some("555-0100".to_string()),
+            store_address: None,
+        }
+    }
+
+    #[test]
+    fn test_appraisal_lines() {
+        let lines: Vec<String> = appraisal_lines(&appraisal_data(None))
+            .into_iter()
+            .map(|(line, _)| line)
+            .collect();
+        assert_eq!(lines[0], "ITEM");
+        assert_eq!(lines[1], "Type: Ring");
+        assert!(lines.contains(&"Metal: 14k yellow gold".to_string()));
+        assert!(lines.contains(&"Stones: None".to_string()));
+        assert!(lines.contains(&"Certification #: GIA 2141438171, AGS 104".to_string()));
+        assert!(!lines.contains(&"NOTES".to_string()));
+
+        let lines = appraisal_lines(&appraisal_data(Some("Light wear on shank")));
+        assert_eq!(
+            lines.last().unwrap(),
+            &("Light wear on shank".to_string(), false)
+        );
+    }
+
+    #[test]
+    fn test_generate_appraisal_pdf() {
+        let pdf = generate_appraisal_pdf(&appraisal_data(Some("Light wear"))).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+
+    fn closeout_report() -> DailyCloseoutReport {
+        let ticket = |code: &str, actual: Option<Decimal>, paid: Decimal| CloseoutTicketEntry {
+            ticket_id: uuid::Uuid::new_v4(),
+            friendly_code: code.to_string(),
+            customer_name: "Jane Doe".to_string(),
+            item_description: "Gold ring".to_string(),
+            is_rush: false,
+            quote_amount: Some(Decimal::new(8000, 2)),
+            actual_amount: actual,
+            total_paid: paid,
+        };
+        DailyCloseoutReport {
+            date: chrono::NaiveDate::from_ymd_opt(2026, 10, 16).unwrap(),
+            timezone: "America/Chicago".to_string(),
+            store_id: uuid::Uuid::new_v4(),
+            store_name: "Example Jewelers".to_string(),
+            taken_in: vec![ticket("JR-0002", None, Decimal::ZERO)],
+            closed: vec![],
+            closed_revenue: Decimal::ZERO,
+            payments_by_method: vec![crate::models::report::PaymentMethodTotal {
+                method: PaymentMethod::Cash,
+                payments: 1,
+                total: Decimal::new(2000, 2),
+            }],
+            payments_total: Decimal::new(2000, 2),
+            ready_for_pickup: vec![ticket(
+                "JR-0001",
+                Some(Decimal::new(9500, 2)),
+                Decimal::new(2000, 2),
+            )],
+        }
+    }
+
+    #[test]
+    fn test_daily_closeout_lines() {
+        let lines = daily_closeout_lines(&closeout_report());
+        let row = |label: &str| {
+            lines
+                .iter()
+                .find(|(line, _, _)| line.starts_with(label))
+                .map(|(_, amount, _)| amount.as_str())
+        };
+
+        assert_eq!(row("Cash (1)"), Some("$20.00"));
+        assert_eq!(row("JR-0002"), Some("$80.00"));
+        assert_eq!(row("CLOSED (0)"), Some(""));
+        // Ready for pickup shows the balance on the final amount
+        assert_eq!(row("JR-0001"), Some("Due $75.00"));
+        assert_eq!(row("Closed Revenue"), Some("$0.00"));
+    }
+
+    #[test]
+    fn test_generate_daily_closeout_pdf() {
+        let pdf = generate_daily_closeout_pdf(&closeout_report()).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
 }
@@ -0,0 +1,141 @@
+//! Photo processing for uploads.
+//!
+//! Phone photos carry EXIF metadata (including GPS coordinates) and often
+//! store the image sideways with an orientation tag. Before a photo is
+//! stored, it is decoded, rotated upright according to that tag, and
+//! re-encoded in its original format. The encoders write pixel data only, so
+//! the metadata is dropped.
+
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::webp::WebPEncoder;
+use image::{DynamicImage, ImageDecoder, ImageReader, ImageResult};
+
+use crate::utils::file_validation::ImageFormat;
+
+/// JPEG quality used when re-encoding photos.
+pub const JPEG_QUALITY: u8 = 90;
+
+/// Strip metadata from a photo and apply its orientation.
+///
+/// Returns the re-encoded image in the same format. Fails if the data
+/// cannot be decoded as `format`.
+pub fn process_photo(data: &[u8], format: ImageFormat) -> ImageResult<Vec<u8>> {
+    let reader = ImageReader::with_format(Cursor::new(data), codec_format(format));
+    let mut decoder = reader.into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+
+    encode(&image, format)
+}
+
+/// Encode an image without metadata.
+fn encode(image: &DynamicImage, format: ImageFormat) -> ImageResult<Vec<u8>> {
+    let mut output = Cursor::new(Vec::new());
+    match format {
+        // JPEG has no alpha channel
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut output, JPEG_QUALITY))?,
+        ImageFormat::Png => image.write_to(&mut output, image::ImageFormat::Png)?,
+        // The built-in WebP encoder is lossless and takes 8-bit RGB(A) only
+        ImageFormat::WebP => DynamicImage::ImageRgba8(image.to_rgba8())
+            .write_with_encoder(WebPEncoder::new_lossless(&mut output))?,
+    }
+
+    Ok(output.into_inner())
+}
+
+/// The image crate's format for a detected upload format.
+fn codec_format(format: ImageFormat) -> image::ImageFormat {
+    match format {
+        ImageFormat::Jpeg => image::ImageFormat::Jpeg,
+        ImageFormat::Png => image::ImageFormat::Png,
+        ImageFormat::WebP => image::ImageFormat::WebP,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::file_validation::detect_image_format;
+    use image::{Rgb, RgbImage};
+
+    /// A 4x2 JPEG with a red left half and a blue right half.
+    fn landscape_jpeg() -> Vec<u8> {
+        let image = RgbImage::from_fn(4, 2, |x, _| {
+            if x < 2 {
+                Rgb([255, 0, 0])
+            } else {
+                Rgb([0, 0, 255])
+            }
+        });
+        let mut output = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(image)
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut output, 100))
+            .unwrap();
+        output.into_inner()
+    }
+
+    /// Insert an APP1 EXIF segment with an orientation tag after the SOI marker.
+    fn with_exif_orientation(jpeg: &[u8], orientation: u16) -> Vec<u8> {
+        let mut tiff = Vec::new();
+        tiff.extend_from_slice(b"MM\x00\x2a\x00\x00\x00\x08"); // big-endian header, IFD at 8
+        tiff.extend_from_slice(&1u16.to_be_bytes()); // one entry
+        tiff.extend_from_slice(&0x0112u16.to_be_bytes()); // Orientation
+        tiff.extend_from_slice(&3u16.to_be_bytes()); // SHORT
+        tiff.extend_from_slice(&1u32.to_be_bytes()); // count
+        tiff.extend_from_slice(&orientation.to_be_bytes());
+        tiff.extend_from_slice(&[0, 0]); // value padding
+        tiff.extend_from_slice(&0u32.to_be_bytes()); // no next IFD
+
+        let mut segment = b"Exif\x00\x00".to_vec();
+        segment.extend_from_slice(&tiff);
+
+        let mut output = jpeg[..2].to_vec();
+        output.extend_from_slice(&[0xFF, 0xE1]);
+        output.extend_from_slice(&((segment.len() + 2) as u16).to_be_bytes());
+        output.extend_from_slice(&segment);
+        output.extend_from_slice(&jpeg[2..]);
+        output
+    }
+
+    #[test]
+    fn test_process_photo_applies_orientation_and_strips_exif() {
+        // Orientation 6: the stored image must be rotated 90° clockwise
+        let input = with_exif_orientation(&landscape_jpeg(), 6);
+        assert!(input.windows(4).any(|w| w == b"Exif"));
+
+        let output = process_photo(&input, ImageFormat::Jpeg).unwrap();
+        assert_eq!(detect_image_format(&output), Some(ImageFormat::Jpeg));
+        assert!(!output.windows(4).any(|w| w == b"Exif"));
+
+        let image = image::load_from_memory(&output).unwrap();
+        assert_eq!((image.width(), image.height()), (2, 4));
+        // Rotated clockwise, the red left half ends up on top
+        let top = image.to_rgb8().get_pixel(1, 0).0;
+        assert!(
+            top[0] > 200 && top[2] < 60,
+            "unexpected top pixel {:?}",
+            top
+        );
+    }
+
+    #[test]
+    fn test_process_photo_keeps_format() {
+        let image = DynamicImage::ImageRgb8(RgbImage::new(3, 3));
+        for format in [ImageFormat::Png, ImageFormat::WebP] {
+            let input = encode(&image, format).unwrap();
+            let output = process_photo(&input, format).unwrap();
+            assert_eq!(detect_image_format(&output), Some(format));
+        }
+    }
+
+    #[test]
+    fn test_process_photo_rejects_corrupt_data() {
+        let mut data = landscape_jpeg();
+        data.truncate(20);
+        assert!(process_photo(&data, ImageFormat::Jpeg).is_err());
+    }
+}
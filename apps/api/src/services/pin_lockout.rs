@@ -0,0 +1,152 @@
+//! Per-employee PIN lockout.
+//!
+//! Per-IP rate limits slow down guessing from one tablet, but not someone
+//! working through one employee's PIN from several. When a PIN login names
+//! the employee, wrong PINs are counted against them; reaching the limit
+//! inside the window locks them out until an admin unlocks them. Lockouts go
+//! to the audit log and, when `SECURITY_ALERT_EMAIL` is set, to that address.
+
+use chrono::{DateTime, Utc};
+use std::net::IpAddr;
+use std::time::Duration;
+
+use crate::models::notification::NotificationChannel;
+use crate::services::notifications::{DeliveryOutcome, NotificationService};
+
+/// Default wrong PINs allowed inside the window.
+pub const DEFAULT_PIN_LOCKOUT_ATTEMPTS: u32 = 5;
+
+/// Default window wrong PINs are counted over (minutes).
+pub const DEFAULT_PIN_LOCKOUT_WINDOW_MINUTES: u64 = 15;
+
+/// When wrong PINs lock an employee out, and who hears about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PinLockoutPolicy {
+    /// Wrong PINs inside the window that lock the employee out (0 = never)
+    pub max_attempts: u32,
+    /// How long wrong PINs count for, from the first one
+    pub window: Duration,
+    /// Where lockout alerts are emailed (None = no email)
+    pub alert_email: Option<String>,
+}
+
+impl Default for PinLockoutPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_PIN_LOCKOUT_ATTEMPTS,
+            window: Duration::from_secs(DEFAULT_PIN_LOCKOUT_WINDOW_MINUTES * 60),
+            alert_email: None,
+        }
+    }
+}
+
+impl PinLockoutPolicy {
+    /// Whether wrong PINs are counted at all.
+    pub fn enabled(&self) -> bool {
+        self.max_attempts > 0
+    }
+
+    /// Failures before this time belong to an expired window.
+    pub fn window_start(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - chrono::Duration::from_std(self.window).unwrap_or(chrono::Duration::zero())
+    }
+
+    /// Whether the failure that brought the count to `failed_attempts` is
+    /// the one that locked the employee out.
+    pub fn locks_out(&self, failed_attempts: i32) -> bool {
+        self.enabled() && failed_attempts == self.max_attempts as i32
+    }
+}
+
+/// Subject and body of the lockout alert email.
+pub fn lockout_alert(
+    employee_name: &str,
+    failed_attempts: i32,
+    window: Duration,
+    client_ip: IpAddr,
+    locked_at: DateTime<Utc>,
+) -> (String, String) {
+    let subject = format!("{} locked out after wrong PINs", employee_name);
+    let body = format!(
+        "{} was locked out at {} after {} wrong PINs within {} minutes.\n\
+         The last attempt came from {}.\n\n\
+         If this wasn't them, someone may be guessing their PIN. An admin can \
+         unlock them from the employee list; consider changing their PIN.",
+        employee_name,
+        locked_at.format("%Y-%m-%d %H:%M UTC"),
+        failed_attempts,
+        window.as_secs() / 60,
+        client_ip,
+    );
+    (subject, body)
+}
+
+/// Email a lockout alert, logging the outcome.
+pub async fn send_lockout_alert(
+    notifications: &NotificationService,
+    to: &str,
+    subject: &str,
+    body: &str,
+) {
+    let outcome = notifications
+        .send_to_staff(NotificationChannel::Email, to, Some(subject), body)
+        .await;
+    match outcome {
+        DeliveryOutcome::Sent { .. } => {}
+        DeliveryOutcome::Failed { recipient, error } => {
+            tracing::warn!("Lockout alert to {} failed: {}", recipient, error)
+        }
+        DeliveryOutcome::Skipped { reason } => {
+            tracing::info!("Lockout alert not sent: {}", reason)
+        }
+        DeliveryOutcome::Deferred => {
+            tracing::warn!("Lockout alert not sent: provider unavailable")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locks_out_on_the_limit_only() {
+        let policy = PinLockoutPolicy::default();
+        assert!(!policy.locks_out(4));
+        assert!(policy.locks_out(5));
+        // Concurrent failures past the limit don't lock out (or alert) again
+        assert!(!policy.locks_out(6));
+
+        let off = PinLockoutPolicy {
+            max_attempts: 0,
+            ..Default::default()
+        };
+        assert!(!off.enabled());
+        assert!(!off.locks_out(0));
+    }
+
+    #[test]
+    fn test_window_start() {
+        let policy = PinLockoutPolicy::default();
+        let now = Utc::now();
+        assert_eq!(
+            policy.window_start(now),
+            now - chrono::Duration::minutes(15)
+        );
+    }
+
+    #[test]
+    fn test_lockout_alert() {
+        let locked_at = "2026-03-02T14:05:00Z".parse().unwrap();
+        let (subject, body) = lockout_alert(
+            "Sam",
+            5,
+            Duration::from_secs(15 * 60),
+            "192.168.1.20".parse().unwrap(),
+            locked_at,
+        );
+        assert_eq!(subject, "Sam locked out after wrong PINs");
+        assert!(body.contains("2026-03-02 14:05 UTC after 5 wrong PINs within 15 minutes"));
+        assert!(body.contains("192.168.1.20"));
+    }
+}
@@ -0,0 +1,372 @@
+//! ESC/POS and plain-text receipts for 80mm thermal printers.
+//!
+//! Both formats are laid out in 48 columns (Font A on 80mm paper) from the
+//! same lines. The ESC/POS output selects code page WPC1252, prints the store
+//! logo as a raster image, and ends with a partial cut.
+
+use crate::models::ticket::Ticket;
+use crate::services::pdf::{
+    grey_on_white, receipt_footer_lines, receipt_item_lines, receipt_notice_lines,
+    receipt_price_lines, wrap_text, ReceiptData,
+};
+
+/// Characters per line in the normal font.
+pub const RECEIPT_COLUMNS: usize = 48;
+
+/// Characters per line at double width.
+const TITLE_COLUMNS: usize = RECEIPT_COLUMNS / 2;
+
+/// Widest the logo is printed, in dots (80mm paper prints 576).
+const LOGO_MAX_WIDTH_DOTS: u32 = 384;
+
+/// Tallest the logo is printed, in dots.
+const LOGO_MAX_HEIGHT_DOTS: u32 = 160;
+
+const ESC: u8 = 0x1B;
+const GS: u8 = 0x1D;
+const LF: u8 = b'\n';
+
+/// One line of a thermal receipt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Line {
+    /// Centered, double width and height
+    Title(String),
+    /// Centered and bold
+    Heading(String),
+    /// Centered
+    Centered(String),
+    Bold(String),
+    Text(String),
+    /// A dashed rule across the paper
+    Rule,
+    Blank,
+}
+
+/// Render a receipt as plain text, 48 columns wide.
+pub fn receipt_text(data: &ReceiptData) -> String {
+    render_text(&receipt_lines(data))
+}
+
+/// Render a receipt as ESC/POS commands for an 80mm thermal printer.
+pub fn receipt_escpos(data: &ReceiptData) -> Vec<u8> {
+    let logo = data.template.logo.as_deref().and_then(raster_image);
+    render_escpos(&receipt_lines(data), logo.as_deref())
+}
+
+/// Lay out the receipt's content.
+///
+/// Mirrors the PDF receipt: store header, ticket code, customer, items,
+/// prices, dates, signature line, and the footer.
+fn receipt_lines(data: &ReceiptData) -> Vec<Line> {
+    let mut lines = Vec::new();
+
+    // === Store Header ===
+    lines.extend(
+        wrap_text(&data.store_name, TITLE_COLUMNS)
+            .into_iter()
+            .map(Line::Title),
+    );
+    for detail in [&data.store_phone, &data.store_address]
+        .into_iter()
+        .flatten()
+    {
+        lines.extend(
+            wrap_text(detail, RECEIPT_COLUMNS)
+                .into_iter()
+                .map(Line::Centered),
+        );
+    }
+    lines.push(Line::Blank);
+    lines.push(Line::Heading("REPAIR RECEIPT".to_string()));
+    lines.push(Line::Title(data.ticket.friendly_code.clone()));
+    lines.push(Line::Rule);
+
+    // === Customer Info ===
+    lines.push(Line::Bold("CUSTOMER".to_string()));
+    lines.extend(text_lines(&data.customer.name, ""));
+    if let Some(ref phone) = data.customer.phone {
+        lines.extend(text_lines(&format!("Phone: {}", phone), ""));
+    }
+    if let Some(ref email) = data.customer.email {
+        lines.extend(text_lines(&format!("Email: {}", email), ""));
+    }
+    lines.push(Line::Rule);
+
+    // === Items ===
+    let count = data.items.len();
+    lines.push(Line::Bold(if count > 1 {
+        format!("ITEMS ({})", count)
+    } else {
+        "ITEM DETAILS".to_string()
+    }));
+    for item in &data.items {
+        let item_lines = receipt_item_lines(item, count, data.template.show_prices);
+        for (i, line) in item_lines.iter().enumerate() {
+            if i == 0 {
+                lines.push(Line::Bold(line.clone()));
+            } else {
+                lines.extend(text_lines(line, "  "));
+            }
+        }
+    }
+    lines.push(Line::Rule);
+
+    // === Pricing & Dates ===
+    for (line, is_total) in receipt_price_lines(data) {
+        lines.push(if is_total {
+            Line::Bold(line)
+        } else {
+            Line::Text(line)
+        });
+    }
+    if let Some(promise_date) = data.ticket.promise_date {
+        lines.push(Line::Text(format!(
+            "Promise Date: {}",
+            promise_date.format("%B %d, %Y")
+        )));
+    }
+    if data.ticket.is_rush {
+        lines.push(Line::Heading("*** RUSH ORDER ***".to_string()));
+    }
+    lines.push(Line::Text(format!(
+        "Date Received: {}",
+        data.ticket.created_at.format("%b %d, %Y %I:%M %p")
+    )));
+
+    // === Signature & Footer ===
+    lines.push(Line::Blank);
+    lines.push(Line::Blank);
+    lines.push(Line::Text(format!(
+        "Signature: {}",
+        "_".repeat(RECEIPT_COLUMNS - "Signature: ".len())
+    )));
+    lines.push(Line::Blank);
+    lines.extend(notice_lines(&data.ticket));
+    let footer = receipt_footer_lines(data.template.footer_text.as_deref(), RECEIPT_COLUMNS);
+    if !footer.is_empty() {
+        lines.push(Line::Blank);
+        lines.extend(footer.into_iter().map(Line::Text));
+    }
+
+    lines
+}
+
+/// Wrap text to the receipt width, indenting every line by `indent`.
+fn text_lines(text: &str, indent: &str) -> Vec<Line> {
+    wrap_text(text, RECEIPT_COLUMNS - indent.len())
+        .into_iter()
+        .map(|line| Line::Text(format!("{}{}", indent, line)))
+        .collect()
+}
+
+/// The pickup and status lookup notice, wrapped to the receipt width.
+fn notice_lines(ticket: &Ticket) -> Vec<Line> {
+    receipt_notice_lines(ticket)
+        .iter()
+        .flat_map(|line| text_lines(line, ""))
+        .collect()
+}
+
+/// Center `text` within `width` columns.
+fn center(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.chars().count()) / 2;
+    format!("{}{}", " ".repeat(padding), text)
+}
+
+/// Render receipt lines as plain text.
+fn render_text(lines: &[Line]) -> String {
+    let mut output = String::new();
+    for line in lines {
+        let text = match line {
+            Line::Title(text) | Line::Heading(text) | Line::Centered(text) => {
+                center(text, RECEIPT_COLUMNS)
+            }
+            Line::Bold(text) | Line::Text(text) => text.clone(),
+            Line::Rule => "-".repeat(RECEIPT_COLUMNS),
+            Line::Blank => String::new(),
+        };
+        output.push_str(text.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// Render receipt lines as ESC/POS commands, with an optional raster logo
+/// (a complete `GS v 0` command) printed first.
+fn render_escpos(lines: &[Line], logo: Option<&[u8]>) -> Vec<u8> {
+    // Initialize, then select code page 16 (WPC1252)
+    let mut output = vec![ESC, b'@', ESC, b't', 16];
+
+    if let Some(logo) = logo {
+        output.extend([ESC, b'a', 1]);
+        output.extend_from_slice(logo);
+        output.extend([LF, ESC, b'a', 0]);
+    }
+
+    for line in lines {
+        match line {
+            Line::Title(text) => {
+                output.extend([ESC, b'a', 1, GS, b'!', 0x11]);
+                output.extend(encode_text(text));
+                output.extend([LF, GS, b'!', 0, ESC, b'a', 0]);
+            }
+            Line::Heading(text) => {
+                output.extend([ESC, b'a', 1, ESC, b'E', 1]);
+                output.extend(encode_text(text));
+                output.extend([LF, ESC, b'E', 0, ESC, b'a', 0]);
+            }
+            Line::Centered(text) => {
+                output.extend([ESC, b'a', 1]);
+                output.extend(encode_text(text));
+                output.extend([LF, ESC, b'a', 0]);
+            }
+            Line::Bold(text) => {
+                output.extend([ESC, b'E', 1]);
+                output.extend(encode_text(text));
+                output.extend([LF, ESC, b'E', 0]);
+            }
+            Line::Text(text) => {
+                output.extend(encode_text(text));
+                output.push(LF);
+            }
+            Line::Rule => {
+                output.extend(std::iter::repeat_n(b'-', RECEIPT_COLUMNS));
+                output.push(LF);
+            }
+            Line::Blank => output.push(LF),
+        }
+    }
+
+    // Feed past the cutter, then partial cut
+    output.extend([ESC, b'd', 4, GS, b'V', 1]);
+    output
+}
+
+/// Encode text for code page WPC1252.
+///
+/// Latin-1 characters map to the same byte; anything else prints as `?`.
+/// Control characters become spaces so text entered by staff or customers
+/// can never be read by the printer as a command.
+fn encode_text(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match u32::from(c) {
+            0x00..=0x1F | 0x7F..=0x9F => b' ',
+            code @ 0x20..=0xFF => code as u8,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Convert a PNG or JPEG logo into a `GS v 0` raster image command.
+///
+/// The logo is scaled down to fit the logo box and thresholded to black and
+/// white. Returns None if the image can't be decoded.
+fn raster_image(data: &[u8]) -> Option<Vec<u8>> {
+    let image = ::image::load_from_memory(data).ok()?;
+    let image = if image.width() > LOGO_MAX_WIDTH_DOTS || image.height() > LOGO_MAX_HEIGHT_DOTS {
+        image.resize(
+            LOGO_MAX_WIDTH_DOTS,
+            LOGO_MAX_HEIGHT_DOTS,
+            ::image::imageops::FilterType::Triangle,
+        )
+    } else {
+        image
+    };
+    let rgba = image.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let bytes_per_row = width.div_ceil(8) as usize;
+    let mut bits = vec![0u8; bytes_per_row * height as usize];
+    for (x, y, pixel) in rgba.enumerate_pixels() {
+        if grey_on_white(*pixel) < 128 {
+            bits[y as usize * bytes_per_row + x as usize / 8] |= 0x80 >> (x % 8);
+        }
+    }
+
+    let [x_low, x_high] = (bytes_per_row as u16).to_le_bytes();
+    let [y_low, y_high] = (height as u16).to_le_bytes();
+    let mut command = vec![GS, b'v', b'0', 0, x_low, x_high, y_low, y_high];
+    command.extend(bits);
+    Some(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_text_centers_and_rules() {
+        let text = render_text(&[
+            Line::Title("Example".to_string()),
+            Line::Rule,
+            Line::Bold("CUSTOMER".to_string()),
+            Line::Blank,
+        ]);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], format!("{}Example", " ".repeat(20)));
+        assert_eq!(lines[1].len(), RECEIPT_COLUMNS);
+        assert_eq!(lines[2], "CUSTOMER");
+        assert_eq!(lines[3], "");
+    }
+
+    #[test]
+    fn test_render_escpos_commands() {
+        let output = render_escpos(
+            &[Line::Bold("Hi".to_string()), Line::Text("é".to_string())],
+            None,
+        );
+        assert!(output.starts_with(&[ESC, b'@', ESC, b't', 16]));
+        assert!(output
+            .windows(7)
+            .any(|w| w == [ESC, b'E', 1, b'H', b'i', LF, ESC]));
+        assert!(output.windows(2).any(|w| w == [0xE9, LF]));
+        assert!(output.ends_with(&[GS, b'V', 1]));
+    }
+
+    #[test]
+    fn test_encode_text_strips_control_characters() {
+        assert_eq!(encode_text("A\u{1b}@B"), b"A @B");
+        assert_eq!(encode_text("Café"), b"Caf\xE9");
+        assert_eq!(encode_text("💍 ring"), b"? ring");
+    }
+
+    #[test]
+    fn test_text_lines_wrap_with_indent() {
+        let lines = text_lines(&"word ".repeat(20), "  ");
+        assert!(lines.len() > 1);
+        for line in lines {
+            let Line::Text(text) = line else {
+                panic!("expected text line");
+            };
+            assert!(text.starts_with("  "));
+            assert!(text.len() <= RECEIPT_COLUMNS);
+        }
+    }
+
+    #[test]
+    fn test_raster_image_packs_dark_pixels() {
+        // 10x2: left column black, rest white
+        let png = {
+            let image = ::image::RgbImage::from_fn(10, 2, |x, _| {
+                if x == 0 {
+                    ::image::Rgb([0, 0, 0])
+                } else {
+                    ::image::Rgb([255, 255, 255])
+                }
+            });
+            let mut output = std::io::Cursor::new(Vec::new());
+            image
+                .write_to(&mut output, ::image::ImageFormat::Png)
+                .unwrap();
+            output.into_inner()
+        };
+
+        let command = raster_image(&png).unwrap();
+        assert_eq!(&command[..8], &[GS, b'v', b'0', 0, 2, 0, 2, 0]);
+        assert_eq!(&command[8..], &[0x80, 0x00, 0x80, 0x00]);
+        assert!(raster_image(b"not an image").is_none());
+    }
+}
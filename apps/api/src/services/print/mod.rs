@@ -0,0 +1,6 @@
+//! Receipt output for printers that don't take PDF.
+//!
+//! PDF documents live in `services::pdf`; the formats here reuse its
+//! `ReceiptData` so every receipt carries the same content.
+
+pub mod escpos;
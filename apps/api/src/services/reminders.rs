@@ -0,0 +1,283 @@
+//! Promise-date reminders.
+//!
+//! A background task checks through the day and, once the store's
+//! `overdue_digest_hour` (UTC) has passed, claims the day's run: every open
+//! ticket due today or overdue gets a reminder row, the digest is emailed to
+//! `overdue_digest_email`, and with `overdue_digest_sms` on each assigned
+//! employee with a phone number is texted their own tickets. A day is only
+//! claimed once, so restarts and extra servers don't resend it.
+
+use chrono::{DateTime, NaiveDate, Timelike, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::notification::NotificationChannel;
+use crate::models::reminder::{DueTicket, OverdueDigest, OverdueReport};
+use crate::repositories::{ReminderRepository, StoreSettingsRepository};
+use crate::services::notifications::{DeliveryOutcome, NotificationService};
+
+/// How often the background task checks whether the day's reminders are due.
+pub const REMINDER_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+/// Ticket codes listed in an employee's text before the rest are counted.
+const MAX_TEXT_TICKETS: usize = 10;
+
+/// Whether the day's reminders should go out at `now`.
+pub fn digest_due(now: DateTime<Utc>, digest_hour: i32) -> bool {
+    now.hour() as i32 >= digest_hour
+}
+
+/// Open tickets due today and overdue as of `as_of`.
+pub async fn overdue_report(pool: &PgPool, as_of: NaiveDate) -> Result<OverdueReport, AppError> {
+    let tickets = ReminderRepository::find_due(pool, as_of).await?;
+    Ok(OverdueReport::new(as_of, tickets))
+}
+
+/// One line describing a due ticket.
+fn ticket_line(ticket: &DueTicket) -> String {
+    let due = match ticket.days_overdue {
+        0 => "due today".to_string(),
+        1 => format!("due {}, 1 day late", ticket.promise_date),
+        days => format!("due {}, {} days late", ticket.promise_date, days),
+    };
+    format!(
+        "- {}{} {}: {} ({}; {})",
+        ticket.friendly_code,
+        if ticket.is_rush { " RUSH" } else { "" },
+        ticket.customer_name,
+        ticket.item_description,
+        due,
+        ticket.worked_by_name.as_deref().unwrap_or("unassigned")
+    )
+}
+
+/// Subject and body of the digest email.
+pub fn digest_email(report: &OverdueReport, store_name: &str) -> (String, String) {
+    let subject = format!(
+        "{}: {} overdue, {} due today",
+        store_name,
+        report.overdue.len(),
+        report.due_today.len()
+    );
+
+    let mut body = format!("Promise dates for {} as of {}\n", store_name, report.as_of);
+    for (heading, tickets) in [
+        ("Overdue", &report.overdue),
+        ("Due today", &report.due_today),
+    ] {
+        if tickets.is_empty() {
+            continue;
+        }
+        body.push_str(&format!("\n{} ({}):\n", heading, tickets.len()));
+        for ticket in tickets {
+            body.push_str(&ticket_line(ticket));
+            body.push('\n');
+        }
+    }
+    (subject, body)
+}
+
+/// Reminder texts for assigned employees with a phone number, as
+/// `(phone, message)` in the order their first ticket appears.
+pub fn employee_texts(report: &OverdueReport, store_name: &str) -> Vec<(String, String)> {
+    let mut employees: Vec<(Uuid, &str, Vec<&DueTicket>)> = Vec::new();
+    for ticket in report.tickets() {
+        let (Some(employee_id), Some(phone)) = (ticket.worked_by, &ticket.worked_by_phone) else {
+            continue;
+        };
+        match employees.iter_mut().find(|(id, _, _)| *id == employee_id) {
+            Some((_, _, tickets)) => tickets.push(ticket),
+            None => employees.push((employee_id, phone, vec![ticket])),
+        }
+    }
+
+    employees
+        .into_iter()
+        .map(|(_, phone, tickets)| {
+            let overdue = tickets.iter().filter(|t| t.days_overdue > 0).count();
+            let mut codes = tickets
+                .iter()
+                .take(MAX_TEXT_TICKETS)
+                .map(|t| t.friendly_code.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            if tickets.len() > MAX_TEXT_TICKETS {
+                codes.push_str(&format!(" and {} more", tickets.len() - MAX_TEXT_TICKETS));
+            }
+            let message = format!(
+                "{}: you have {} overdue and {} due today: {}",
+                store_name,
+                overdue,
+                tickets.len() - overdue,
+                codes
+            );
+            (phone.to_string(), message)
+        })
+        .collect()
+}
+
+/// Whether a message was accepted, logging why if not.
+fn delivered(outcome: DeliveryOutcome, what: &str) -> bool {
+    match outcome {
+        DeliveryOutcome::Sent { .. } => true,
+        DeliveryOutcome::Failed { recipient, error } => {
+            tracing::warn!("Overdue {} to {} failed: {}", what, recipient, error);
+            false
+        }
+        DeliveryOutcome::Skipped { reason } => {
+            tracing::info!("Overdue {} not sent: {}", what, reason);
+            false
+        }
+        DeliveryOutcome::Deferred => {
+            tracing::warn!("Overdue {} not sent: provider unavailable", what);
+            false
+        }
+    }
+}
+
+/// Write today's reminders and send the digest, once the digest hour has passed.
+///
+/// Returns the day's run when this call claimed it; None when it isn't time
+/// yet or the day was already handled.
+pub async fn run_daily_reminders(
+    pool: &PgPool,
+    notifications: &NotificationService,
+) -> Result<Option<OverdueDigest>, AppError> {
+    let settings = StoreSettingsRepository::get_settings(pool).await?;
+    let now = Utc::now();
+    if !digest_due(now, settings.overdue_digest_hour) {
+        return Ok(None);
+    }
+
+    let report = overdue_report(pool, now.date_naive()).await?;
+    let Some(mut digest) = ReminderRepository::claim_day(pool, &report).await? else {
+        return Ok(None);
+    };
+    if report.is_empty() {
+        return Ok(Some(digest));
+    }
+
+    if let Some(to) = settings.overdue_digest_email.as_deref() {
+        let (subject, body) = digest_email(&report, &settings.store_name);
+        let outcome = notifications
+            .send_to_staff(NotificationChannel::Email, to, Some(&subject), &body)
+            .await;
+        digest.email_sent = delivered(outcome, "digest email");
+    }
+
+    if settings.overdue_digest_sms {
+        for (phone, message) in employee_texts(&report, &settings.store_name) {
+            let outcome = notifications
+                .send_to_staff(NotificationChannel::Sms, &phone, None, &message)
+                .await;
+            if delivered(outcome, "reminder text") {
+                digest.sms_sent += 1;
+            }
+        }
+    }
+
+    ReminderRepository::record_delivery(
+        pool,
+        digest.digest_date,
+        digest.email_sent,
+        digest.sms_sent,
+    )
+    .await?;
+    Ok(Some(digest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ticket::TicketStatus;
+    use chrono::TimeZone;
+
+    fn due(code: &str, days_overdue: i32, worked_by: Option<(Uuid, &str)>) -> DueTicket {
+        let as_of = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        DueTicket {
+            ticket_id: Uuid::new_v4(),
+            friendly_code: code.to_string(),
+            customer_id: Uuid::new_v4(),
+            customer_name: "Jane Doe".to_string(),
+            item_description: "Gold ring".to_string(),
+            status: TicketStatus::InProgress,
+            is_rush: false,
+            promise_date: as_of - chrono::Duration::days(i64::from(days_overdue)),
+            days_overdue,
+            worked_by: worked_by.map(|(id, _)| id),
+            worked_by_name: worked_by.map(|_| "Sam".to_string()),
+            worked_by_phone: worked_by.map(|(_, phone)| phone.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_digest_due() {
+        let morning = Utc.with_ymd_and_hms(2024, 3, 10, 7, 59, 0).unwrap();
+        assert!(!digest_due(morning, 8));
+        assert!(digest_due(morning + chrono::Duration::minutes(1), 8));
+        assert!(digest_due(morning, 0));
+    }
+
+    #[test]
+    fn test_digest_email() {
+        let as_of = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let mut rush = due("JR-0002", 0, None);
+        rush.is_rush = true;
+        let report = OverdueReport::new(as_of, vec![due("JR-0001", 3, None), rush]);
+
+        let (subject, body) = digest_email(&report, "Example Jewelers");
+        assert_eq!(subject, "Example Jewelers: 1 overdue, 1 due today");
+        assert!(body.contains(
+            "Overdue (1):\n- JR-0001 Jane Doe: Gold ring (due 2024-03-07, 3 days late; unassigned)"
+        ));
+        assert!(body.contains(
+            "Due today (1):\n- JR-0002 RUSH Jane Doe: Gold ring (due today; unassigned)"
+        ));
+    }
+
+    #[test]
+    fn test_employee_texts_group_by_employee() {
+        let as_of = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let sam = (Uuid::new_v4(), "555-0100");
+        let alex = (Uuid::new_v4(), "555-0101");
+        let mut no_phone = due("JR-0004", 1, Some((Uuid::new_v4(), "")));
+        no_phone.worked_by_phone = None;
+        let report = OverdueReport::new(
+            as_of,
+            vec![
+                due("JR-0001", 2, Some(sam)),
+                due("JR-0002", 1, Some(alex)),
+                no_phone,
+                due("JR-0005", 0, Some(sam)),
+                due("JR-0006", 0, None),
+            ],
+        );
+
+        let texts = employee_texts(&report, "Shop");
+        assert_eq!(
+            texts,
+            vec![
+                (
+                    "555-0100".to_string(),
+                    "Shop: you have 1 overdue and 1 due today: JR-0001, JR-0005".to_string()
+                ),
+                (
+                    "555-0101".to_string(),
+                    "Shop: you have 1 overdue and 0 due today: JR-0002".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_employee_texts_cap_listed_codes() {
+        let as_of = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let sam = (Uuid::new_v4(), "555-0100");
+        let tickets = (1..=12)
+            .map(|n| due(&format!("JR-{:04}", n), 1, Some(sam)))
+            .collect();
+        let texts = employee_texts(&OverdueReport::new(as_of, tickets), "Shop");
+        assert!(texts[0].1.ends_with("JR-0010 and 2 more"));
+    }
+}
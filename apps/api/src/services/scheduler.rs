@@ -0,0 +1,303 @@
+//! Scheduled background jobs.
+//!
+//! Periodic work (archiving, reminders, webhook delivery, cleanup) is
+//! registered here as named jobs instead of hand-rolled loops. Each job runs
+//! on its own interval, which `JOB_INTERVALS` can override or turn off. First
+//! runs are spread over a short random delay so a restart doesn't fire every
+//! job at once. Once the server drains, jobs stop waiting for their next
+//! run; one that is running gets until the drain timeout to finish. The outcome of each job's last run is kept for
+//! `GET /api/v1/admin/jobs`.
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+use crate::error::AppError;
+use crate::services::shutdown::Shutdown;
+
+/// Longest delay before a job's first run.
+pub const MAX_START_JITTER: Duration = Duration::from_secs(30);
+
+/// How often expired admin and employee sessions are deleted.
+pub const SESSION_CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+type JobFuture = Pin<Box<dyn Future<Output = Result<(), AppError>> + Send>>;
+type JobFn = Arc<dyn Fn() -> JobFuture + Send + Sync>;
+
+/// Status of a registered job, as reported by `GET /admin/jobs`.
+#[derive(Debug, Clone, Serialize)]
+pub struct JobStatus {
+    pub name: String,
+    /// Seconds between runs; None when the job is turned off
+    pub interval_secs: Option<u64>,
+    /// Whether a run is in progress
+    pub running: bool,
+    /// When the last run started
+    pub last_run_at: Option<DateTime<Utc>>,
+    /// How long the last run took
+    pub last_duration_ms: Option<u64>,
+    /// Error from the last run, cleared when a run succeeds
+    pub last_error: Option<String>,
+    /// When the last failed run started
+    pub last_error_at: Option<DateTime<Utc>>,
+    /// When the job runs next; None when it is turned off or stopped
+    pub next_run_at: Option<DateTime<Utc>>,
+    /// Runs since the server started
+    pub runs: u64,
+    /// Failed runs since the server started
+    pub failures: u64,
+}
+
+impl JobStatus {
+    fn new(name: &str, interval: Option<Duration>) -> Self {
+        Self {
+            name: name.to_string(),
+            interval_secs: interval.map(|interval| interval.as_secs()),
+            running: false,
+            last_run_at: None,
+            last_duration_ms: None,
+            last_error: None,
+            last_error_at: None,
+            next_run_at: None,
+            runs: 0,
+            failures: 0,
+        }
+    }
+
+    /// Record the outcome of a run that started at `started_at`.
+    fn record_run(
+        &mut self,
+        started_at: DateTime<Utc>,
+        elapsed: Duration,
+        result: Result<(), AppError>,
+    ) {
+        self.running = false;
+        self.last_run_at = Some(started_at);
+        self.last_duration_ms = Some(elapsed.as_millis() as u64);
+        self.runs += 1;
+        match result {
+            Ok(()) => self.last_error = None,
+            Err(err) => {
+                self.failures += 1;
+                self.last_error = Some(err.to_string());
+                self.last_error_at = Some(started_at);
+            }
+        }
+    }
+}
+
+/// Shared status of every registered job, in registration order.
+#[derive(Debug, Clone, Default)]
+pub struct JobStatuses(Arc<RwLock<Vec<JobStatus>>>);
+
+impl JobStatuses {
+    /// Create an empty status list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current status of every job.
+    pub fn snapshot(&self) -> Vec<JobStatus> {
+        self.0.read().expect("job status lock poisoned").clone()
+    }
+
+    fn add(&self, status: JobStatus) {
+        self.0
+            .write()
+            .expect("job status lock poisoned")
+            .push(status);
+    }
+
+    fn update(&self, name: &str, f: impl FnOnce(&mut JobStatus)) {
+        let mut statuses = self.0.write().expect("job status lock poisoned");
+        if let Some(status) = statuses.iter_mut().find(|status| status.name == name) {
+            f(status);
+        }
+    }
+}
+
+struct Job {
+    name: &'static str,
+    interval: Duration,
+    run: JobFn,
+}
+
+/// Runs registered jobs until shutdown.
+pub struct Scheduler {
+    jobs: Vec<Job>,
+    statuses: JobStatuses,
+    overrides: HashMap<String, u64>,
+}
+
+impl Scheduler {
+    /// Create a scheduler reporting into `statuses`.
+    ///
+    /// `overrides` maps job names to seconds between runs; 0 turns a job off.
+    pub fn new(statuses: JobStatuses, overrides: HashMap<String, u64>) -> Self {
+        Self {
+            jobs: Vec::new(),
+            statuses,
+            overrides,
+        }
+    }
+
+    /// Register a job to run every `interval`, unless overridden.
+    pub fn register<F, Fut>(&mut self, name: &'static str, interval: Duration, job: F)
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), AppError>> + Send + 'static,
+    {
+        let interval = job_interval(&self.overrides, name, interval);
+        self.statuses.add(JobStatus::new(name, interval));
+        match interval {
+            Some(interval) => self.jobs.push(Job {
+                name,
+                interval,
+                run: Arc::new(move || Box::pin(job())),
+            }),
+            None => tracing::info!("Job {} disabled by JOB_INTERVALS", name),
+        }
+    }
+
+    /// Start every job as a task `shutdown` drains. Jobs stop once
+    /// draining starts.
+    pub fn start(self, shutdown: &Shutdown) {
+        for job in self.jobs {
+            let delay = start_jitter(job.interval);
+            shutdown.spawn(run_job(job, delay, self.statuses.clone(), shutdown.token()));
+        }
+    }
+}
+
+/// Run a job on its interval until shutdown.
+async fn run_job(
+    job: Job,
+    mut delay: Duration,
+    statuses: JobStatuses,
+    shutdown: CancellationToken,
+) {
+    loop {
+        statuses.update(job.name, |status| {
+            status.next_run_at = Some(Utc::now() + delay)
+        });
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = shutdown.cancelled() => break,
+        }
+
+        statuses.update(job.name, |status| {
+            status.running = true;
+            status.next_run_at = None;
+        });
+        let started_at = Utc::now();
+        let started = Instant::now();
+        let result = (job.run)().await;
+        if let Err(err) = &result {
+            tracing::warn!("Job {} failed: {:?}", job.name, err);
+        }
+        statuses.update(job.name, |status| {
+            status.record_run(started_at, started.elapsed(), result)
+        });
+
+        delay = job.interval;
+    }
+
+    statuses.update(job.name, |status| status.next_run_at = None);
+}
+
+/// A job's interval after applying `JOB_INTERVALS`, or None when it's off.
+fn job_interval(
+    overrides: &HashMap<String, u64>,
+    name: &str,
+    default: Duration,
+) -> Option<Duration> {
+    match overrides.get(name) {
+        Some(0) => None,
+        Some(&secs) => Some(Duration::from_secs(secs)),
+        None => Some(default),
+    }
+}
+
+/// Random delay before a job's first run, never longer than its interval.
+fn start_jitter(interval: Duration) -> Duration {
+    let bound = interval.min(MAX_START_JITTER).as_millis() as u64;
+    Duration::from_millis(rand::thread_rng().gen_range(0..=bound))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_job_interval_overrides() {
+        let overrides = HashMap::from([("webhooks".to_string(), 60), ("archive".to_string(), 0)]);
+        let default = Duration::from_secs(5);
+        assert_eq!(
+            job_interval(&overrides, "webhooks", default),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(job_interval(&overrides, "archive", default), None);
+        assert_eq!(job_interval(&overrides, "other", default), Some(default));
+    }
+
+    #[test]
+    fn test_start_jitter_is_bounded() {
+        for _ in 0..100 {
+            assert!(start_jitter(Duration::from_secs(5)) <= Duration::from_secs(5));
+            assert!(start_jitter(Duration::from_secs(3600)) <= MAX_START_JITTER);
+        }
+    }
+
+    #[test]
+    fn test_record_run() {
+        let mut status = JobStatus::new("webhooks", Some(Duration::from_secs(5)));
+        let started_at = Utc::now();
+        status.running = true;
+
+        status.record_run(
+            started_at,
+            Duration::from_millis(12),
+            Err(AppError::server_error("boom")),
+        );
+        assert!(!status.running);
+        assert_eq!(status.runs, 1);
+        assert_eq!(status.failures, 1);
+        assert!(status.last_error.as_deref().unwrap().contains("boom"));
+        assert_eq!(status.last_error_at, Some(started_at));
+
+        status.record_run(started_at, Duration::from_millis(3), Ok(()));
+        assert_eq!(status.runs, 2);
+        assert_eq!(status.failures, 1);
+        assert!(status.last_error.is_none());
+        assert_eq!(status.last_error_at, Some(started_at));
+        assert_eq!(status.last_duration_ms, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_scheduler_runs_until_shutdown() {
+        let statuses = JobStatuses::new();
+        let mut scheduler =
+            Scheduler::new(statuses.clone(), HashMap::from([("off".to_string(), 0)]));
+        scheduler.register("tick", Duration::from_millis(10), || async { Ok(()) });
+        scheduler.register("off", Duration::from_millis(10), || async { Ok(()) });
+
+        let shutdown = Shutdown::new();
+        scheduler.start(&shutdown);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        shutdown.begin();
+        assert!(shutdown.drain(Duration::from_secs(5)).await);
+
+        let snapshot = statuses.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert!(snapshot[0].runs >= 2);
+        assert!(snapshot[0].next_run_at.is_none());
+        assert_eq!(snapshot[1].interval_secs, None);
+        assert_eq!(snapshot[1].runs, 0);
+    }
+}
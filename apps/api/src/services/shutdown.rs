@@ -0,0 +1,156 @@
+//! Graceful shutdown coordination.
+//!
+//! On SIGTERM the server starts draining: new API requests are refused with
+//! 503, `/health/ready` reports `draining` so load balancers stop sending
+//! traffic, and scheduled jobs stop waiting for their next run. Requests
+//! already running (such as large photo uploads), jobs mid-run, and
+//! background notifications get until the drain timeout to finish before
+//! the process exits.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use tokio::sync::Notify;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+/// Seconds to retry a request refused while the server drains.
+pub const DRAIN_RETRY_AFTER_SECS: u64 = 5;
+
+/// Shared shutdown state: the cancellation token and in-flight task count.
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown(Arc<Inner>);
+
+#[derive(Debug, Default)]
+struct Inner {
+    token: CancellationToken,
+    began_at: OnceLock<Instant>,
+    tasks: AtomicUsize,
+    idle: Notify,
+}
+
+impl Shutdown {
+    /// Create shutdown state that isn't draining yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start draining. Calling it again has no effect.
+    pub fn begin(&self) {
+        self.0.began_at.get_or_init(Instant::now);
+        self.0.token.cancel();
+    }
+
+    /// Whether draining has started.
+    pub fn is_draining(&self) -> bool {
+        self.0.token.is_cancelled()
+    }
+
+    /// Token cancelled when draining starts, for background tasks to watch.
+    pub fn token(&self) -> CancellationToken {
+        self.0.token.clone()
+    }
+
+    /// Spawn a background task that draining waits for.
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let guard = TaskGuard::new(self.clone());
+        tokio::spawn(async move {
+            task.await;
+            drop(guard);
+        });
+    }
+
+    /// Tracked tasks still running.
+    pub fn running_tasks(&self) -> usize {
+        self.0.tasks.load(Ordering::Acquire)
+    }
+
+    /// Wait until draining started `timeout` ago.
+    pub async fn deadline(&self, timeout: Duration) {
+        self.0.token.cancelled().await;
+        let began_at = *self.0.began_at.get_or_init(Instant::now);
+        tokio::time::sleep_until(began_at + timeout).await;
+    }
+
+    /// Wait for tracked tasks to finish, at most until the drain deadline.
+    ///
+    /// Returns false if tasks were still running at the deadline.
+    pub async fn drain(&self, timeout: Duration) -> bool {
+        tokio::select! {
+            _ = self.idle() => true,
+            _ = self.deadline(timeout) => self.running_tasks() == 0,
+        }
+    }
+
+    /// Wait until no tracked task is running.
+    async fn idle(&self) {
+        loop {
+            let notified = self.0.idle.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if self.running_tasks() == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Counts a tracked task as running until dropped.
+struct TaskGuard(Shutdown);
+
+impl TaskGuard {
+    fn new(shutdown: Shutdown) -> Self {
+        shutdown.0.tasks.fetch_add(1, Ordering::AcqRel);
+        Self(shutdown)
+    }
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        if self.0 .0.tasks.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.0 .0.idle.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_begin_cancels_token() {
+        let shutdown = Shutdown::new();
+        let token = shutdown.token();
+        assert!(!shutdown.is_draining());
+
+        shutdown.begin();
+        assert!(shutdown.is_draining());
+        assert!(token.is_cancelled());
+    }
+
+    #[tokio::test]
+    async fn test_drain_waits_for_tasks() {
+        let shutdown = Shutdown::new();
+        shutdown.spawn(tokio::time::sleep(Duration::from_millis(50)));
+        assert_eq!(shutdown.running_tasks(), 1);
+
+        shutdown.begin();
+        assert!(shutdown.drain(Duration::from_secs(5)).await);
+        assert_eq!(shutdown.running_tasks(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drain_gives_up_at_deadline() {
+        let shutdown = Shutdown::new();
+        shutdown.spawn(tokio::time::sleep(Duration::from_secs(60)));
+
+        shutdown.begin();
+        assert!(!shutdown.drain(Duration::from_millis(20)).await);
+        assert_eq!(shutdown.running_tasks(), 1);
+    }
+}
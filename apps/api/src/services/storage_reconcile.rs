@@ -0,0 +1,117 @@
+//! Orphaned photo object cleanup.
+//!
+//! An upload whose database insert fails, or a ticket delete that stops
+//! partway, leaves objects in storage that no `ticket_photos` row points at.
+//! Reconciliation lists everything under the photo prefix and deletes those
+//! orphans once they are old enough that no upload can still be in flight.
+
+use crate::error::AppError;
+use crate::models::{ReconcileFailure, StorageReconcileReport};
+use crate::repositories::TicketPhotoRepository;
+use crate::storage::{StorageBackend, StoredObject};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::PgPool;
+use std::collections::HashSet;
+
+/// How often the background task reconciles storage.
+pub const RECONCILE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(24 * 60 * 60);
+
+/// Orphans must be at least this old before they are deleted.
+pub const ORPHAN_GRACE_HOURS: i64 = 24;
+
+/// Key prefix photos are stored under.
+const PHOTO_PREFIX: &str = "tickets/";
+
+/// Objects last modified before this instant may be deleted.
+pub fn orphan_cutoff(now: DateTime<Utc>) -> DateTime<Utc> {
+    now - Duration::hours(ORPHAN_GRACE_HOURS)
+}
+
+/// Split objects into those old enough to delete and the count too recent.
+fn partition_by_age(objects: Vec<StoredObject>, cutoff: DateTime<Utc>) -> (Vec<String>, usize) {
+    let (old, recent): (Vec<_>, Vec<_>) = objects
+        .into_iter()
+        .partition(|object| object.last_modified < cutoff);
+    (
+        old.into_iter().map(|object| object.key).collect(),
+        recent.len(),
+    )
+}
+
+/// Delete photo objects no record points at.
+///
+/// Failed deletes are reported rather than aborting the run, so one bad
+/// object doesn't keep the rest from being cleaned up.
+pub async fn reconcile_orphans(
+    pool: &PgPool,
+    storage: &dyn StorageBackend,
+) -> Result<StorageReconcileReport, AppError> {
+    let ran_at = Utc::now();
+    let cutoff = orphan_cutoff(ran_at);
+
+    let objects = storage
+        .list(PHOTO_PREFIX)
+        .await
+        .map_err(|e| AppError::server_error(format!("Failed to list photo storage: {}", e)))?;
+    let scanned = objects.len();
+
+    let keys: Vec<String> = objects.iter().map(|object| object.key.clone()).collect();
+    let untracked: HashSet<String> = TicketPhotoRepository::find_untracked_keys(pool, &keys)
+        .await?
+        .into_iter()
+        .collect();
+    let orphans = objects
+        .into_iter()
+        .filter(|object| untracked.contains(&object.key))
+        .collect();
+    let (expired, skipped_recent) = partition_by_age(orphans, cutoff);
+
+    let mut deleted = Vec::new();
+    let mut failed = Vec::new();
+    for storage_key in expired {
+        match storage.delete(&storage_key).await {
+            Ok(()) => deleted.push(storage_key),
+            Err(e) => failed.push(ReconcileFailure {
+                storage_key,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(StorageReconcileReport {
+        ran_at,
+        cutoff,
+        scanned,
+        skipped_recent,
+        deleted,
+        failed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_partition_by_age() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap();
+        let cutoff = orphan_cutoff(now);
+        assert_eq!(cutoff, Utc.with_ymd_and_hms(2026, 3, 9, 12, 0, 0).unwrap());
+
+        let objects = vec![
+            StoredObject {
+                key: "tickets/a/old.jpg".to_string(),
+                last_modified: cutoff - Duration::minutes(1),
+            },
+            StoredObject {
+                key: "tickets/a/new.jpg".to_string(),
+                last_modified: now - Duration::hours(2),
+            },
+        ];
+        let (expired, skipped_recent) = partition_by_age(objects, cutoff);
+
+        assert_eq!(expired, vec!["tickets/a/old.jpg"]);
+        assert_eq!(skipped_recent, 1);
+    }
+}
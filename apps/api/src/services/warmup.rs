@@ -0,0 +1,141 @@
+//! Startup warm-up.
+//!
+//! Runs once the listener is bound. Loads store settings into the caches
+//! that read them, checks that the PDF fonts load, and runs the hot queries
+//! on several pooled connections so their prepared statements are cached.
+//! Until it finishes, `/health/ready` answers 503 so a load balancer keeps
+//! sending traffic to the old instance instead of a cold one.
+
+use sqlx::PgPool;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::middleware::DebugCaptureState;
+use crate::models::ticket::TicketFilters;
+use crate::repositories::{
+    EmployeeRepository, EmployeeSessionRepository, StoreSettingsRepository, TicketRepository,
+};
+use crate::services::pdf;
+
+/// How long to wait before retrying a failed warm-up.
+pub const WARMUP_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How many pooled connections get their statements prepared.
+const WARMUP_CONNECTIONS: u32 = 4;
+
+/// Whether the server has finished warming up.
+#[derive(Debug, Clone, Default)]
+pub struct Readiness(Arc<AtomicBool>);
+
+impl Readiness {
+    /// Create a flag that is not ready yet.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether warm-up has finished.
+    pub fn is_ready(&self) -> bool {
+        self.0.load(Ordering::Acquire)
+    }
+
+    /// Report ready.
+    pub fn mark_ready(&self) {
+        self.0.store(true, Ordering::Release);
+    }
+}
+
+/// Warm up, retrying until it succeeds, then mark the server ready.
+///
+/// Stops retrying once `shutdown` is cancelled.
+pub async fn run(
+    pool: PgPool,
+    debug_capture: DebugCaptureState,
+    readiness: Readiness,
+    shutdown: CancellationToken,
+) {
+    loop {
+        let started = Instant::now();
+        match warm_up(&pool, &debug_capture).await {
+            Ok(()) => {
+                readiness.mark_ready();
+                tracing::info!(
+                    "Warm-up finished in {}ms; ready for traffic",
+                    started.elapsed().as_millis()
+                );
+                return;
+            }
+            Err(err) => {
+                tracing::warn!("Warm-up failed, retrying: {:?}", err);
+                tokio::select! {
+                    _ = tokio::time::sleep(WARMUP_RETRY_INTERVAL) => {}
+                    _ = shutdown.cancelled() => return,
+                }
+            }
+        }
+    }
+}
+
+/// Prime caches, check the PDF fonts, and prepare hot queries.
+pub async fn warm_up(pool: &PgPool, debug_capture: &DebugCaptureState) -> Result<(), AppError> {
+    // Settings, and the debug capture toggle every request checks
+    StoreSettingsRepository::get_settings(pool).await?;
+    debug_capture.current(pool).await;
+
+    tokio::task::spawn_blocking(pdf::verify_fonts)
+        .await
+        .map_err(|e| AppError::server_error(format!("Font check panicked: {}", e)))??;
+
+    // Concurrent runs land on different connections, each of which caches
+    // its own prepared statements
+    let connections = pool.options().get_max_connections().min(WARMUP_CONNECTIONS);
+    let mut runs = JoinSet::new();
+    for _ in 0..connections {
+        let pool = pool.clone();
+        runs.spawn(async move { prepare_hot_queries(&pool).await });
+    }
+    while let Some(run) = runs.join_next().await {
+        run.map_err(|e| AppError::server_error(format!("Warm-up task panicked: {}", e)))??;
+    }
+    Ok(())
+}
+
+/// Run the queries behind the busiest endpoints with arguments that match
+/// nothing: session checks, the ticket list and queue, and ticket and
+/// employee lookups.
+async fn prepare_hot_queries(pool: &PgPool) -> Result<(), AppError> {
+    EmployeeSessionRepository::find_by_token(pool, "").await?;
+    EmployeeSessionRepository::is_training(pool, "").await?;
+    EmployeeRepository::find_active_by_id(pool, Uuid::nil()).await?;
+    TicketRepository::find_by_id(pool, Uuid::nil()).await?;
+    TicketRepository::list(
+        pool,
+        TicketFilters {
+            limit: Some(1),
+            ..Default::default()
+        },
+    )
+    .await?;
+    TicketRepository::get_queue(pool, Some(1), None, None, None, false, false).await?;
+    StoreSettingsRepository::get_settings(pool).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readiness_is_shared_between_clones() {
+        let readiness = Readiness::new();
+        let seen_by_router = readiness.clone();
+        assert!(!seen_by_router.is_ready());
+
+        readiness.mark_ready();
+        assert!(seen_by_router.is_ready());
+    }
+}
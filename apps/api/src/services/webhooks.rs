@@ -0,0 +1,296 @@
+//! Webhook delivery.
+//!
+//! Ticket events are queued in the `webhook_deliveries` outbox for every
+//! active webhook subscribed to them. A background task claims due
+//! deliveries and POSTs each as a JSON envelope:
+//!
+//! ```json
+//! {
+//!   "id": "<delivery_id>",
+//!   "version": 1,
+//!   "event": "ticket.created",
+//!   "created_at": "...",
+//!   "data": { ... }
+//! }
+//! ```
+//!
+//! Requests carry `X-Facet-Event`, `X-Facet-Delivery`, and
+//! `X-Facet-Signature: t=<unix seconds>,v1=<hex HMAC-SHA256>`, where the HMAC
+//! is keyed with the webhook's secret over `"<t>.<body>"`. Any 2xx response
+//! counts as delivered; anything else is retried with backoff until
+//! `MAX_WEBHOOK_ATTEMPTS` is reached. The status and body of the last
+//! response are kept on the delivery for debugging.
+
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::ticket::{Ticket, TicketStatus};
+use crate::models::ticket_photo::TicketPhoto;
+use crate::models::webhook::{
+    WebhookDelivery, WebhookDispatch, WebhookEvent, WEBHOOK_SCHEMA_VERSION,
+};
+use crate::repositories::WebhookRepository;
+
+/// How often the background task looks for deliveries to send.
+pub const WEBHOOK_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// How long to wait for a receiver to respond.
+pub const WEBHOOK_REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Deliveries claimed per poll.
+const DELIVERY_BATCH_SIZE: i64 = 50;
+
+/// Longest error text kept on a delivery.
+const MAX_ERROR_LENGTH: usize = 500;
+
+/// Longest response body kept on a delivery.
+const MAX_RESPONSE_BODY_LENGTH: usize = 4096;
+
+/// The `X-Facet-Signature` header value for a request body sent at `timestamp`.
+pub fn signature_header(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    format!(
+        "t={},v1={}",
+        timestamp,
+        hex::encode(mac.finalize().into_bytes())
+    )
+}
+
+/// The JSON body sent for a delivery.
+pub fn envelope(delivery: &WebhookDelivery) -> serde_json::Value {
+    json!({
+        "id": delivery.delivery_id,
+        "version": WEBHOOK_SCHEMA_VERSION,
+        "event": delivery.event,
+        "created_at": delivery.created_at,
+        "data": delivery.payload,
+    })
+}
+
+/// Queue an event, logging rather than failing the request that raised it.
+async fn emit(pool: &PgPool, event: WebhookEvent, data: serde_json::Value) {
+    if let Err(err) = WebhookRepository::enqueue(pool, event, &data).await {
+        tracing::error!(
+            event = event.as_str(),
+            "Failed to queue webhook event: {:?}",
+            err
+        );
+    }
+}
+
+/// Queue `ticket.created` for a new ticket.
+///
+/// Training tickets never raise events.
+pub async fn ticket_created(pool: &PgPool, ticket: &Ticket) {
+    if ticket.is_training {
+        return;
+    }
+    emit(
+        pool,
+        WebhookEvent::TicketCreated,
+        json!({ "ticket": ticket }),
+    )
+    .await;
+}
+
+/// Queue `ticket.status_changed` for a ticket that moved from
+/// `previous_status`, plus `ticket.closed` when it was closed.
+///
+/// Training tickets and no-op changes never raise events.
+pub async fn ticket_status_changed(pool: &PgPool, ticket: &Ticket, previous_status: TicketStatus) {
+    if ticket.is_training || ticket.status == previous_status {
+        return;
+    }
+    let data = json!({ "ticket": ticket, "previous_status": previous_status });
+    emit(pool, WebhookEvent::TicketStatusChanged, data.clone()).await;
+    if ticket.status == TicketStatus::Closed {
+        emit(pool, WebhookEvent::TicketClosed, data).await;
+    }
+}
+
+/// Queue `photo.uploaded` for a photo added to a ticket.
+pub async fn photo_uploaded(pool: &PgPool, ticket: &Ticket, photo: &TicketPhoto) {
+    if ticket.is_training {
+        return;
+    }
+    let data = json!({
+        "ticket_id": ticket.ticket_id,
+        "friendly_code": ticket.friendly_code,
+        "photo": photo,
+    });
+    emit(pool, WebhookEvent::PhotoUploaded, data).await;
+}
+
+/// Queue `ticket.assigned` for a ticket whose assignee changed from
+/// `previous_worked_by`.
+pub async fn ticket_assigned(pool: &PgPool, ticket: &Ticket, previous_worked_by: Option<Uuid>) {
+    if ticket.is_training || ticket.worked_by == previous_worked_by {
+        return;
+    }
+    let data = json!({ "ticket": ticket, "previous_worked_by": previous_worked_by });
+    emit(pool, WebhookEvent::TicketAssigned, data).await;
+}
+
+/// Cut `text` to at most `max_chars` characters.
+fn truncate(mut text: String, max_chars: usize) -> String {
+    if let Some((end, _)) = text.char_indices().nth(max_chars) {
+        text.truncate(end);
+    }
+    text
+}
+
+/// Send one claimed delivery and record the outcome. Returns whether it was delivered.
+async fn send(
+    pool: &PgPool,
+    client: &reqwest::Client,
+    dispatch: &WebhookDispatch,
+) -> Result<bool, AppError> {
+    let delivery = &dispatch.delivery;
+    let body = serde_json::to_vec(&envelope(delivery))
+        .map_err(|e| AppError::server_error(format!("Failed to encode webhook body: {}", e)))?;
+    let signature = signature_header(&dispatch.secret, chrono::Utc::now().timestamp(), &body);
+
+    let result = client
+        .post(&dispatch.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header("X-Facet-Event", delivery.event.as_str())
+        .header("X-Facet-Delivery", delivery.delivery_id.to_string())
+        .header("X-Facet-Signature", signature)
+        .body(body)
+        .send()
+        .await;
+
+    let response = match result {
+        Ok(response) => response,
+        Err(err) => {
+            let error = truncate(err.to_string(), MAX_ERROR_LENGTH);
+            WebhookRepository::mark_attempt_failed(pool, delivery, None, None, &error).await?;
+            return Ok(false);
+        }
+    };
+
+    let status = response.status();
+    let response_status = i32::from(status.as_u16());
+    let response_body = response
+        .text()
+        .await
+        .ok()
+        .map(|body| truncate(body, MAX_RESPONSE_BODY_LENGTH));
+    if status.is_success() {
+        WebhookRepository::mark_delivered(
+            pool,
+            delivery.delivery_id,
+            response_status,
+            response_body.as_deref(),
+        )
+        .await?;
+        Ok(true)
+    } else {
+        let error = format!("Receiver responded with {}", status);
+        WebhookRepository::mark_attempt_failed(
+            pool,
+            delivery,
+            Some(response_status),
+            response_body.as_deref(),
+            &error,
+        )
+        .await?;
+        Ok(false)
+    }
+}
+
+/// Send every delivery that is due.
+///
+/// Returns `(delivered, failed)` attempt counts for this run.
+pub async fn deliver_pending(
+    pool: &PgPool,
+    client: &reqwest::Client,
+) -> Result<(usize, usize), AppError> {
+    let (mut delivered, mut failed) = (0, 0);
+    loop {
+        let batch = WebhookRepository::claim_due(pool, DELIVERY_BATCH_SIZE).await?;
+        let claimed = batch.len();
+        for dispatch in &batch {
+            if send(pool, client, dispatch).await? {
+                delivered += 1;
+            } else {
+                tracing::warn!(
+                    delivery_id = %dispatch.delivery.delivery_id,
+                    attempts = dispatch.delivery.attempts,
+                    "Webhook delivery to {} failed",
+                    dispatch.url
+                );
+                failed += 1;
+            }
+        }
+        if (claimed as i64) < DELIVERY_BATCH_SIZE {
+            return Ok((delivered, failed));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::webhook::WebhookDeliveryStatus;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_signature_header() {
+        let header = signature_header("whsec_test", 1_700_000_000, b"{\"a\":1}");
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"whsec_test").unwrap();
+        mac.update(b"1700000000.{\"a\":1}");
+        let expected = hex::encode(mac.finalize().into_bytes());
+        assert_eq!(header, format!("t=1700000000,v1={}", expected));
+        assert_ne!(
+            header,
+            signature_header("whsec_other", 1_700_000_000, b"{\"a\":1}")
+        );
+    }
+
+    #[test]
+    fn test_envelope() {
+        let delivery = WebhookDelivery {
+            delivery_id: Uuid::new_v4(),
+            webhook_id: Uuid::new_v4(),
+            event: WebhookEvent::TicketClosed,
+            payload: json!({ "ticket": { "friendly_code": "JR-0001" } }),
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 1,
+            next_attempt_at: Utc::now(),
+            response_status: None,
+            last_error: None,
+            created_at: Utc::now(),
+            delivered_at: None,
+            response_body: None,
+            last_attempt_at: None,
+        };
+        let body = envelope(&delivery);
+        assert_eq!(body["id"], json!(delivery.delivery_id));
+        assert_eq!(body["version"], WEBHOOK_SCHEMA_VERSION);
+        assert_eq!(body["event"], "ticket.closed");
+        assert_eq!(body["data"]["ticket"]["friendly_code"], "JR-0001");
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(
+            truncate("timed out".to_string(), MAX_ERROR_LENGTH),
+            "timed out"
+        );
+        assert_eq!(
+            truncate("x".repeat(600), MAX_ERROR_LENGTH).len(),
+            MAX_ERROR_LENGTH
+        );
+        assert_eq!(truncate("ééé".to_string(), 2), "éé");
+    }
+}
@@ -0,0 +1,187 @@
+//! Circuit breaker around a remote storage backend.
+
+use async_trait::async_trait;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::{StorageBackend, StorageError, StorageResult, StoredObject};
+use crate::utils::circuit_breaker::{BreakerStatus, CircuitBreaker};
+
+/// A [`StorageBackend`] that stops calling the inner backend after repeated
+/// failures.
+///
+/// While the breaker is open, calls fail fast with
+/// [`StorageError::Unavailable`] instead of waiting on the object store.
+/// Missing objects and invalid keys are answers, not outages, so they don't
+/// count as failures. Signed URLs are built locally and always pass through.
+pub struct CircuitBreakerStorage {
+    inner: Arc<dyn StorageBackend>,
+    breaker: CircuitBreaker,
+}
+
+impl CircuitBreakerStorage {
+    /// Wrap `inner` with a breaker using the default limits.
+    pub fn new(inner: Arc<dyn StorageBackend>) -> Self {
+        let breaker = CircuitBreaker::new(inner.name());
+        Self { inner, breaker }
+    }
+
+    /// Wrap `inner` with a custom breaker.
+    pub fn with_breaker(inner: Arc<dyn StorageBackend>, breaker: CircuitBreaker) -> Self {
+        Self { inner, breaker }
+    }
+
+    async fn call<T>(&self, op: impl Future<Output = StorageResult<T>>) -> StorageResult<T> {
+        self.breaker
+            .try_acquire()
+            .map_err(|open| StorageError::Unavailable {
+                retry_after: open.retry_after,
+            })?;
+
+        let result = op.await;
+        match &result {
+            Ok(_) | Err(StorageError::NotFound(_)) | Err(StorageError::InvalidKey(_)) => {
+                self.breaker.record_success()
+            }
+            Err(_) => self.breaker.record_failure(),
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl StorageBackend for CircuitBreakerStorage {
+    fn name(&self) -> &'static str {
+        self.inner.name()
+    }
+
+    async fn upload(&self, key: &str, data: Vec<u8>, content_type: &str) -> StorageResult<()> {
+        self.call(self.inner.upload(key, data, content_type)).await
+    }
+
+    async fn download(&self, key: &str) -> StorageResult<Vec<u8>> {
+        self.call(self.inner.download(key)).await
+    }
+
+    async fn delete(&self, key: &str) -> StorageResult<()> {
+        self.call(self.inner.delete(key)).await
+    }
+
+    async fn exists(&self, key: &str) -> StorageResult<bool> {
+        self.call(self.inner.exists(key)).await
+    }
+
+    async fn list(&self, prefix: &str) -> StorageResult<Vec<StoredObject>> {
+        self.call(self.inner.list(prefix)).await
+    }
+
+    /// Bypasses the breaker so probes see the provider's real state.
+    async fn check(&self) -> StorageResult<()> {
+        self.inner.check().await
+    }
+
+    async fn get_signed_url(
+        &self,
+        key: &str,
+        expires_in: Option<Duration>,
+    ) -> StorageResult<String> {
+        self.inner.get_signed_url(key, expires_in).await
+    }
+
+    fn verify_signed_url(&self, key: &str, expires: i64, signature: &str) -> bool {
+        self.inner.verify_signed_url(key, expires, signature)
+    }
+
+    fn breaker_status(&self) -> Option<BreakerStatus> {
+        Some(self.breaker.status())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::circuit_breaker::BreakerState;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Backend whose uploads always fail and downloads always miss.
+    #[derive(Default)]
+    struct FailingStorage {
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl StorageBackend for FailingStorage {
+        fn name(&self) -> &'static str {
+            "failing"
+        }
+
+        async fn upload(&self, _key: &str, _data: Vec<u8>, _ct: &str) -> StorageResult<()> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(StorageError::UploadError("connection reset".to_string()))
+        }
+
+        async fn download(&self, key: &str) -> StorageResult<Vec<u8>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Err(StorageError::NotFound(key.to_string()))
+        }
+
+        async fn delete(&self, _key: &str) -> StorageResult<()> {
+            Ok(())
+        }
+
+        async fn exists(&self, _key: &str) -> StorageResult<bool> {
+            Ok(false)
+        }
+
+        async fn list(&self, _prefix: &str) -> StorageResult<Vec<StoredObject>> {
+            Ok(Vec::new())
+        }
+
+        async fn check(&self) -> StorageResult<()> {
+            Ok(())
+        }
+
+        async fn get_signed_url(&self, key: &str, _: Option<Duration>) -> StorageResult<String> {
+            Ok(format!("https://example.test/{}", key))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_breaker_fails_fast() {
+        let inner = Arc::new(FailingStorage::default());
+        let storage = CircuitBreakerStorage::with_breaker(
+            inner.clone(),
+            CircuitBreaker::with_limits("failing", 2, Duration::from_secs(60)),
+        );
+
+        for _ in 0..2 {
+            let err = storage.upload("k", vec![1], "image/jpeg").await;
+            assert!(matches!(err, Err(StorageError::UploadError(_))));
+        }
+        assert_eq!(storage.breaker_status().unwrap().state, BreakerState::Open);
+
+        let err = storage.upload("k", vec![1], "image/jpeg").await;
+        assert!(matches!(err, Err(StorageError::Unavailable { .. })));
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+
+        // Signed URLs don't touch the object store
+        assert!(storage.get_signed_url("k", None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_not_found_is_not_a_failure() {
+        let storage = CircuitBreakerStorage::with_breaker(
+            Arc::new(FailingStorage::default()),
+            CircuitBreaker::with_limits("failing", 1, Duration::from_secs(60)),
+        );
+        for _ in 0..3 {
+            let err = storage.download("missing").await;
+            assert!(matches!(err, Err(StorageError::NotFound(_))));
+        }
+        assert_eq!(
+            storage.breaker_status().unwrap().state,
+            BreakerState::Closed
+        );
+    }
+}
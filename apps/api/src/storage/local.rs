@@ -0,0 +1,320 @@
+//! Local-disk storage for development.
+//!
+//! Objects are files under a root directory. Signed URLs point at
+//! `GET /api/v1/storage/*key` and carry an expiry and an HMAC-SHA256
+//! signature, so links behave like object-store presigned URLs: they stop
+//! working once expired and can't be forged or pointed at another file.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+use super::{
+    StorageBackend, StorageError, StorageResult, StoredObject, DEFAULT_URL_EXPIRATION_SECS,
+};
+
+/// Path that serves signed local storage URLs.
+pub const LOCAL_URL_PREFIX: &str = "/api/v1/storage";
+
+/// Local storage settings.
+#[derive(Debug, Clone)]
+pub struct LocalStorageConfig {
+    /// Directory objects are written under
+    pub root: PathBuf,
+    /// Key for signing URLs; a random key is generated when unset, so
+    /// links stop working when the server restarts
+    pub signing_key: Option<String>,
+}
+
+/// Storage backed by a directory on the API server.
+pub struct LocalStorage {
+    root: PathBuf,
+    signing_key: Vec<u8>,
+}
+
+impl LocalStorage {
+    /// Create a local storage backend.
+    pub fn new(config: LocalStorageConfig) -> Self {
+        let signing_key = match config.signing_key {
+            Some(key) => key.into_bytes(),
+            None => {
+                let mut key = vec![0u8; 32];
+                rand::thread_rng().fill_bytes(&mut key);
+                key
+            }
+        };
+        Self {
+            root: config.root,
+            signing_key,
+        }
+    }
+
+    /// File path for `key`, rejecting keys that would leave the root.
+    pub fn path_for(&self, key: &str) -> StorageResult<PathBuf> {
+        let relative = Path::new(key);
+        let is_safe = !key.is_empty()
+            && relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)));
+        if !is_safe {
+            return Err(StorageError::InvalidKey(key.to_string()));
+        }
+        Ok(self.root.join(relative))
+    }
+
+    /// Storage key of a file under the root, with `/` separators.
+    fn key_for(&self, path: &Path) -> Option<String> {
+        let relative = path.strip_prefix(&self.root).ok()?;
+        let parts: Option<Vec<&str>> = relative
+            .components()
+            .map(|c| c.as_os_str().to_str())
+            .collect();
+        Some(parts?.join("/"))
+    }
+
+    fn mac(&self, key: &str, expires: i64) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.signing_key)
+            .expect("HMAC accepts keys of any length");
+        mac.update(key.as_bytes());
+        mac.update(b"\n");
+        mac.update(expires.to_string().as_bytes());
+        mac
+    }
+
+    /// Signature over a key and expiry (Unix seconds), hex-encoded.
+    fn sign(&self, key: &str, expires: i64) -> String {
+        hex::encode(self.mac(key, expires).finalize().into_bytes())
+    }
+}
+
+#[async_trait]
+impl StorageBackend for LocalStorage {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn upload(&self, key: &str, data: Vec<u8>, _content_type: &str) -> StorageResult<()> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| StorageError::UploadError(e.to_string()))?;
+        }
+        tokio::fs::write(&path, data)
+            .await
+            .map_err(|e| StorageError::UploadError(e.to_string()))
+    }
+
+    async fn download(&self, key: &str) -> StorageResult<Vec<u8>> {
+        let path = self.path_for(key)?;
+        tokio::fs::read(&path).await.map_err(|e| match e.kind() {
+            ErrorKind::NotFound => StorageError::NotFound(key.to_string()),
+            _ => StorageError::DownloadError(e.to_string()),
+        })
+    }
+
+    async fn delete(&self, key: &str) -> StorageResult<()> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(StorageError::DeleteError(e.to_string())),
+        }
+    }
+
+    async fn exists(&self, key: &str) -> StorageResult<bool> {
+        let path = self.path_for(key)?;
+        tokio::fs::try_exists(&path)
+            .await
+            .map_err(|e| StorageError::DownloadError(e.to_string()))
+    }
+
+    async fn list(&self, prefix: &str) -> StorageResult<Vec<StoredObject>> {
+        // Walk the deepest directory the prefix names, then filter by the rest
+        let directory = match prefix.rsplit_once('/') {
+            Some((directory, _)) if !directory.is_empty() => self.path_for(directory)?,
+            _ => self.root.clone(),
+        };
+
+        let mut objects = Vec::new();
+        let mut pending = vec![directory];
+        while let Some(directory) = pending.pop() {
+            let mut entries = match tokio::fs::read_dir(&directory).await {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == ErrorKind::NotFound => continue,
+                Err(e) => return Err(StorageError::ListError(e.to_string())),
+            };
+            while let Some(entry) = entries
+                .next_entry()
+                .await
+                .map_err(|e| StorageError::ListError(e.to_string()))?
+            {
+                let metadata = entry
+                    .metadata()
+                    .await
+                    .map_err(|e| StorageError::ListError(e.to_string()))?;
+                let path = entry.path();
+                if metadata.is_dir() {
+                    pending.push(path);
+                    continue;
+                }
+                let Some(key) = self.key_for(&path).filter(|key| key.starts_with(prefix)) else {
+                    continue;
+                };
+                let last_modified = metadata
+                    .modified()
+                    .map(DateTime::<Utc>::from)
+                    .unwrap_or_else(|_| Utc::now());
+                objects.push(StoredObject { key, last_modified });
+            }
+        }
+
+        Ok(objects)
+    }
+
+    /// The root is created on first upload, so creating it here is harmless
+    /// and proves the directory is usable.
+    async fn check(&self) -> StorageResult<()> {
+        tokio::fs::create_dir_all(&self.root)
+            .await
+            .map_err(|e| StorageError::Unreachable(e.to_string()))
+    }
+
+    async fn get_signed_url(
+        &self,
+        key: &str,
+        expires_in: Option<Duration>,
+    ) -> StorageResult<String> {
+        self.path_for(key)?;
+        let expiration = expires_in.unwrap_or(Duration::from_secs(DEFAULT_URL_EXPIRATION_SECS));
+        let expires = Utc::now().timestamp() + expiration.as_secs() as i64;
+        Ok(format!(
+            "{}/{}?expires={}&signature={}",
+            LOCAL_URL_PREFIX,
+            key,
+            expires,
+            self.sign(key, expires)
+        ))
+    }
+
+    fn verify_signed_url(&self, key: &str, expires: i64, signature: &str) -> bool {
+        if expires < Utc::now().timestamp() {
+            return false;
+        }
+        let Ok(signature) = hex::decode(signature) else {
+            return false;
+        };
+        self.mac(key, expires).verify_slice(&signature).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage() -> LocalStorage {
+        LocalStorage::new(LocalStorageConfig {
+            root: PathBuf::from("uploads"),
+            signing_key: Some("test-key".to_string()),
+        })
+    }
+
+    #[test]
+    fn test_path_for() {
+        let storage = storage();
+        assert_eq!(
+            storage.path_for("tickets/abc/def.jpg").unwrap(),
+            PathBuf::from("uploads/tickets/abc/def.jpg")
+        );
+        assert!(storage.path_for("../etc/passwd").is_err());
+        assert!(storage.path_for("tickets/../../secret").is_err());
+        assert!(storage.path_for("/etc/passwd").is_err());
+        assert!(storage.path_for("").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_signed_url_round_trip() {
+        let storage = storage();
+        let url = storage
+            .get_signed_url("tickets/abc/def.jpg", None)
+            .await
+            .unwrap();
+        assert!(url.starts_with("/api/v1/storage/tickets/abc/def.jpg?expires="));
+
+        let query = url.split_once('?').unwrap().1;
+        let (expires, signature) = query.split_once("&signature=").unwrap();
+        let expires: i64 = expires.trim_start_matches("expires=").parse().unwrap();
+
+        assert!(storage.verify_signed_url("tickets/abc/def.jpg", expires, signature));
+        assert!(!storage.verify_signed_url("tickets/abc/other.jpg", expires, signature));
+        assert!(!storage.verify_signed_url("tickets/abc/def.jpg", expires + 1, signature));
+        assert!(!storage.verify_signed_url("tickets/abc/def.jpg", expires, "zz"));
+    }
+
+    #[tokio::test]
+    async fn test_list_by_prefix() {
+        let root = std::env::temp_dir().join(format!("facet-storage-{}", uuid::Uuid::new_v4()));
+        let storage = LocalStorage::new(LocalStorageConfig {
+            root: root.clone(),
+            signing_key: None,
+        });
+        storage
+            .upload("tickets/a/1.jpg", vec![1], "image/jpeg")
+            .await
+            .unwrap();
+        storage
+            .upload("tickets/b/2.png", vec![2], "image/png")
+            .await
+            .unwrap();
+        storage
+            .upload("exports/3.csv", vec![3], "text/csv")
+            .await
+            .unwrap();
+
+        let mut keys: Vec<String> = storage
+            .list("tickets/")
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|object| object.key)
+            .collect();
+        keys.sort();
+        assert_eq!(keys, vec!["tickets/a/1.jpg", "tickets/b/2.png"]);
+        assert!(storage.list("missing/").await.unwrap().is_empty());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_check_root_directory() {
+        let root = std::env::temp_dir().join(format!("facet-storage-{}", uuid::Uuid::new_v4()));
+        let storage = LocalStorage::new(LocalStorageConfig {
+            root: root.clone(),
+            signing_key: None,
+        });
+        storage.check().await.unwrap();
+        assert!(root.is_dir());
+        std::fs::remove_dir_all(&root).unwrap();
+
+        // A file where the root should be
+        std::fs::write(&root, b"not a directory").unwrap();
+        assert!(matches!(
+            storage.check().await,
+            Err(StorageError::Unreachable(_))
+        ));
+        std::fs::remove_file(root).unwrap();
+    }
+
+    #[test]
+    fn test_expired_signature_rejected() {
+        let storage = storage();
+        let expires = Utc::now().timestamp() - 1;
+        let signature = storage.sign("tickets/abc/def.jpg", expires);
+        assert!(!storage.verify_signed_url("tickets/abc/def.jpg", expires, &signature));
+    }
+}
@@ -0,0 +1,195 @@
+//! Photo storage backends.
+//!
+//! [`StorageBackend`] is the extension point handlers use to store and serve
+//! photos. Three implementations are available, selected by `STORAGE_BACKEND`:
+//! S3-compatible object storage ([`StorageClient`]), Google Cloud Storage
+//! ([`GcsStorage`]), and a local directory for development ([`LocalStorage`]).
+//! The remote backends are wrapped in a [`CircuitBreakerStorage`] so an
+//! outage fails fast instead of piling up requests.
+
+mod breaker;
+mod gcs;
+mod local;
+mod s3;
+
+pub use breaker::CircuitBreakerStorage;
+pub use gcs::{GcsConfig, GcsStorage};
+pub use local::{LocalStorage, LocalStorageConfig, LOCAL_URL_PREFIX};
+pub use s3::{StorageClient, StorageConfig};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+use crate::utils::circuit_breaker::BreakerStatus;
+
+/// Default signed URL expiration time (1 hour).
+pub const DEFAULT_URL_EXPIRATION_SECS: u64 = 3600;
+
+/// Storage-specific errors.
+#[derive(Debug, Error)]
+pub enum StorageError {
+    #[error("Failed to upload file: {0}")]
+    UploadError(String),
+
+    #[error("Failed to download file: {0}")]
+    DownloadError(String),
+
+    #[error("Failed to delete file: {0}")]
+    DeleteError(String),
+
+    #[error("Failed to list files: {0}")]
+    ListError(String),
+
+    #[error("Failed to generate signed URL: {0}")]
+    SignedUrlError(String),
+
+    #[error("File not found: {0}")]
+    NotFound(String),
+
+    #[error("Invalid storage key: {0}")]
+    InvalidKey(String),
+
+    #[error("Invalid configuration: {0}")]
+    ConfigError(String),
+
+    #[error("Storage is unreachable: {0}")]
+    Unreachable(String),
+
+    #[error("Storage is unavailable; retry in {retry_after}s")]
+    Unavailable { retry_after: u64 },
+}
+
+/// Result type for storage operations.
+pub type StorageResult<T> = Result<T, StorageError>;
+
+/// An object found by [`StorageBackend::list`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredObject {
+    pub key: String,
+    pub last_modified: DateTime<Utc>,
+}
+
+/// A place photos can be stored.
+///
+/// Keys are relative paths such as `tickets/<ticket_id>/<photo_id>.jpg`.
+#[async_trait]
+pub trait StorageBackend: Send + Sync {
+    /// Short backend name for logs (e.g., "s3").
+    fn name(&self) -> &'static str;
+
+    /// Store `data` under `key`, replacing any existing object.
+    async fn upload(&self, key: &str, data: Vec<u8>, content_type: &str) -> StorageResult<()>;
+
+    /// Read the object stored under `key`.
+    async fn download(&self, key: &str) -> StorageResult<Vec<u8>>;
+
+    /// Delete the object stored under `key`. Deleting a missing object succeeds.
+    async fn delete(&self, key: &str) -> StorageResult<()>;
+
+    /// Whether an object is stored under `key`.
+    async fn exists(&self, key: &str) -> StorageResult<bool>;
+
+    /// Every object whose key starts with `prefix`.
+    async fn list(&self, prefix: &str) -> StorageResult<Vec<StoredObject>>;
+
+    /// Check that the backend can be reached, for readiness probes.
+    async fn check(&self) -> StorageResult<()>;
+
+    /// A URL granting temporary read access to `key` (defaults to 1 hour).
+    async fn get_signed_url(
+        &self,
+        key: &str,
+        expires_in: Option<Duration>,
+    ) -> StorageResult<String>;
+
+    /// Check a signature from a URL this backend issued.
+    ///
+    /// Only backends whose URLs point back at this API (local disk) accept
+    /// anything; object stores verify their own URLs.
+    fn verify_signed_url(&self, _key: &str, _expires: i64, _signature: &str) -> bool {
+        false
+    }
+
+    /// Circuit breaker state, for backends that have one.
+    fn breaker_status(&self) -> Option<BreakerStatus> {
+        None
+    }
+}
+
+/// Which storage backend to use (`STORAGE_BACKEND`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageBackendKind {
+    /// S3 or an S3-compatible service such as DigitalOcean Spaces
+    S3,
+    /// Google Cloud Storage
+    Gcs,
+    /// A directory on the API server's disk
+    #[default]
+    Local,
+}
+
+impl StorageBackendKind {
+    /// Parse a backend name: `s3`, `gcs`, or `local`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "s3" => Some(Self::S3),
+            "gcs" => Some(Self::Gcs),
+            "local" => Some(Self::Local),
+            _ => None,
+        }
+    }
+}
+
+/// Settings for the selected storage backend.
+#[derive(Debug, Clone)]
+pub enum BackendConfig {
+    S3(StorageConfig),
+    Gcs(GcsConfig),
+    Local(LocalStorageConfig),
+}
+
+/// Create the storage backend described by `config`.
+///
+/// Remote backends come wrapped in a circuit breaker; local disk does not.
+pub async fn connect(config: BackendConfig) -> StorageResult<Arc<dyn StorageBackend>> {
+    let remote: Arc<dyn StorageBackend> = match config {
+        BackendConfig::S3(config) => Arc::new(StorageClient::new(config).await?),
+        BackendConfig::Gcs(config) => Arc::new(GcsStorage::new(config).await?),
+        BackendConfig::Local(config) => return Ok(Arc::new(LocalStorage::new(config))),
+    };
+    Ok(Arc::new(CircuitBreakerStorage::new(remote)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_storage_error_display() {
+        let err = StorageError::UploadError("connection failed".to_string());
+        assert_eq!(err.to_string(), "Failed to upload file: connection failed");
+
+        let err = StorageError::NotFound("photos/123.jpg".to_string());
+        assert_eq!(err.to_string(), "File not found: photos/123.jpg");
+    }
+
+    #[test]
+    fn test_parse_backend_kind() {
+        assert_eq!(
+            StorageBackendKind::parse("s3"),
+            Some(StorageBackendKind::S3)
+        );
+        assert_eq!(
+            StorageBackendKind::parse(" GCS "),
+            Some(StorageBackendKind::Gcs)
+        );
+        assert_eq!(
+            StorageBackendKind::parse("local"),
+            Some(StorageBackendKind::Local)
+        );
+        assert_eq!(StorageBackendKind::parse("azure"), None);
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! This module provides functionality for uploading, downloading, and managing
 //! photos in an S3-compatible object storage service (like DigitalOcean Spaces).
+//! It is also the transport for [`super::GcsStorage`], which talks to Google
+//! Cloud Storage's S3-compatible XML API.
 
+use async_trait::async_trait;
 use aws_config::BehaviorVersion;
 use aws_credential_types::Credentials;
 use aws_sdk_s3::{
@@ -11,36 +14,12 @@ use aws_sdk_s3::{
     primitives::ByteStream,
     Client,
 };
+use chrono::{DateTime, Utc};
 use std::time::Duration;
-use thiserror::Error;
 
-/// Default presigned URL expiration time (1 hour).
-const DEFAULT_URL_EXPIRATION_SECS: u64 = 3600;
-
-/// Storage-specific errors.
-#[derive(Debug, Error)]
-pub enum StorageError {
-    #[error("Failed to upload file: {0}")]
-    UploadError(String),
-
-    #[error("Failed to download file: {0}")]
-    DownloadError(String),
-
-    #[error("Failed to delete file: {0}")]
-    DeleteError(String),
-
-    #[error("Failed to generate signed URL: {0}")]
-    SignedUrlError(String),
-
-    #[error("File not found: {0}")]
-    NotFound(String),
-
-    #[error("Invalid configuration: {0}")]
-    ConfigError(String),
-}
-
-/// Result type for storage operations.
-pub type StorageResult<T> = Result<T, StorageError>;
+use super::{
+    StorageBackend, StorageError, StorageResult, StoredObject, DEFAULT_URL_EXPIRATION_SECS,
+};
 
 /// Configuration for the storage client.
 #[derive(Debug, Clone)]
@@ -103,9 +82,23 @@ pub struct StorageClient {
 impl StorageClient {
     /// Create a new storage client from configuration.
     pub async fn new(config: StorageConfig) -> StorageResult<Self> {
+        let s3_config = Self::client_builder(&config).build();
+        Ok(Self::from_conf(s3_config, config.bucket))
+    }
+
+    /// Create a client from a finished SDK configuration.
+    pub(super) fn from_conf(s3_config: aws_sdk_s3::Config, bucket: String) -> Self {
+        Self {
+            client: Client::from_conf(s3_config),
+            bucket,
+        }
+    }
+
+    /// SDK configuration builder for the given endpoint, region, and credentials.
+    pub(super) fn client_builder(config: &StorageConfig) -> Builder {
         let mut builder = Builder::new()
             .behavior_version(BehaviorVersion::latest())
-            .region(Region::new(config.region));
+            .region(Region::new(config.region.clone()));
 
         // Set endpoint for S3-compatible services
         if let Some(endpoint) = &config.endpoint {
@@ -126,13 +119,7 @@ impl StorageClient {
             builder = builder.credentials_provider(credentials);
         }
 
-        let s3_config = builder.build();
-        let client = Client::from_conf(s3_config);
-
-        Ok(Self {
-            client,
-            bucket: config.bucket,
-        })
+        builder
     }
 
     /// Upload a file to storage.
@@ -307,12 +294,99 @@ impl StorageClient {
         }
     }
 
+    /// Check that the bucket exists and these credentials can reach it.
+    pub async fn check(&self) -> StorageResult<()> {
+        self.client
+            .head_bucket()
+            .bucket(&self.bucket)
+            .send()
+            .await
+            .map_err(|e| StorageError::Unreachable(e.to_string()))?;
+        Ok(())
+    }
+
+    /// List every object whose key starts with `prefix`.
+    ///
+    /// Follows continuation tokens, so buckets with more than 1,000 matching
+    /// objects are listed in full.
+    pub async fn list(&self, prefix: &str) -> StorageResult<Vec<StoredObject>> {
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .prefix(prefix)
+            .into_paginator()
+            .send();
+
+        let mut objects = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|e| StorageError::ListError(e.to_string()))?;
+            for object in page.contents() {
+                let Some(key) = object.key() else {
+                    continue;
+                };
+                // Objects without a timestamp count as new so they are never
+                // mistaken for old orphans
+                let last_modified = object
+                    .last_modified()
+                    .and_then(|t| DateTime::from_timestamp(t.secs(), t.subsec_nanos()))
+                    .unwrap_or_else(Utc::now);
+                objects.push(StoredObject {
+                    key: key.to_string(),
+                    last_modified,
+                });
+            }
+        }
+
+        Ok(objects)
+    }
+
     /// Get the bucket name.
     pub fn bucket(&self) -> &str {
         &self.bucket
     }
 }
 
+#[async_trait]
+impl StorageBackend for StorageClient {
+    fn name(&self) -> &'static str {
+        "s3"
+    }
+
+    async fn upload(&self, key: &str, data: Vec<u8>, content_type: &str) -> StorageResult<()> {
+        StorageClient::upload(self, key, data, content_type).await?;
+        Ok(())
+    }
+
+    async fn download(&self, key: &str) -> StorageResult<Vec<u8>> {
+        StorageClient::download(self, key).await
+    }
+
+    async fn delete(&self, key: &str) -> StorageResult<()> {
+        StorageClient::delete(self, key).await
+    }
+
+    async fn exists(&self, key: &str) -> StorageResult<bool> {
+        StorageClient::exists(self, key).await
+    }
+
+    async fn list(&self, prefix: &str) -> StorageResult<Vec<StoredObject>> {
+        StorageClient::list(self, prefix).await
+    }
+
+    async fn check(&self) -> StorageResult<()> {
+        StorageClient::check(self).await
+    }
+
+    async fn get_signed_url(
+        &self,
+        key: &str,
+        expires_in: Option<Duration>,
+    ) -> StorageResult<String> {
+        StorageClient::get_signed_url(self, key, expires_in).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -344,13 +418,4 @@ mod tests {
         assert!(config.access_key.is_none());
         assert!(config.secret_key.is_none());
     }
-
-    #[test]
-    fn test_storage_error_display() {
-        let err = StorageError::UploadError("connection failed".to_string());
-        assert_eq!(err.to_string(), "Failed to upload file: connection failed");
-
-        let err = StorageError::NotFound("photos/123.jpg".to_string());
-        assert_eq!(err.to_string(), "File not found: photos/123.jpg");
-    }
 }
@@ -0,0 +1,246 @@
+//! Circuit breaker for calls to external providers.
+//!
+//! After [`FAILURE_THRESHOLD`] consecutive failures the breaker opens and
+//! calls fail fast for [`OPEN_DURATION`] instead of waiting on a provider
+//! that is down. Then it goes half-open: one probe call is let through, and
+//! its result closes the breaker again or reopens it.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Consecutive failures that open the breaker.
+pub const FAILURE_THRESHOLD: u32 = 5;
+
+/// How long the breaker stays open before probing the provider.
+pub const OPEN_DURATION: Duration = Duration::from_secs(30);
+
+/// Where a breaker is in its cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BreakerState {
+    /// Calls go through
+    Closed,
+    /// Calls fail fast
+    Open,
+    /// One probe call is allowed through
+    HalfOpen,
+}
+
+/// A breaker's state for health reporting.
+#[derive(Debug, Clone, Serialize)]
+pub struct BreakerStatus {
+    pub name: &'static str,
+    pub state: BreakerState,
+    pub consecutive_failures: u32,
+    /// When the breaker last opened
+    pub opened_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    consecutive_failures: u32,
+    /// Set while open or half-open
+    opened: Option<(Instant, DateTime<Utc>)>,
+    /// When the half-open probe was let through
+    probe_started: Option<Instant>,
+}
+
+/// A circuit breaker around one provider.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    name: &'static str,
+    failure_threshold: u32,
+    open_duration: Duration,
+    inner: Mutex<Inner>,
+}
+
+/// Why a call was not attempted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BreakerOpen {
+    /// Seconds until the breaker lets a probe through
+    pub retry_after: u64,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker with the default threshold and open duration.
+    pub fn new(name: &'static str) -> Self {
+        Self::with_limits(name, FAILURE_THRESHOLD, OPEN_DURATION)
+    }
+
+    /// Create a closed breaker with a custom threshold and open duration.
+    pub fn with_limits(
+        name: &'static str,
+        failure_threshold: u32,
+        open_duration: Duration,
+    ) -> Self {
+        Self {
+            name,
+            failure_threshold: failure_threshold.max(1),
+            open_duration,
+            inner: Mutex::new(Inner {
+                consecutive_failures: 0,
+                opened: None,
+                probe_started: None,
+            }),
+        }
+    }
+
+    /// Short provider name, e.g. "s3" or "sms".
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn state_of(&self, inner: &Inner, now: Instant) -> BreakerState {
+        match inner.opened {
+            None => BreakerState::Closed,
+            Some((at, _)) if now.duration_since(at) < self.open_duration => BreakerState::Open,
+            Some(_) => BreakerState::HalfOpen,
+        }
+    }
+
+    /// Whether a half-open probe is still out. A probe that never reported
+    /// back (its request was cancelled) stops counting after the open
+    /// duration, so the breaker can't stay stuck.
+    fn probe_in_flight(&self, inner: &Inner, now: Instant) -> bool {
+        inner
+            .probe_started
+            .is_some_and(|at| now.duration_since(at) < self.open_duration.max(OPEN_DURATION))
+    }
+
+    /// Check whether a call may go ahead.
+    ///
+    /// Every successful check must be followed by [`record_success`] or
+    /// [`record_failure`] once the call finishes.
+    ///
+    /// [`record_success`]: CircuitBreaker::record_success
+    /// [`record_failure`]: CircuitBreaker::record_failure
+    pub fn try_acquire(&self) -> Result<(), BreakerOpen> {
+        let now = Instant::now();
+        let mut inner = self.lock();
+        match self.state_of(&inner, now) {
+            BreakerState::Closed => Ok(()),
+            BreakerState::HalfOpen if !self.probe_in_flight(&inner, now) => {
+                inner.probe_started = Some(now);
+                Ok(())
+            }
+            BreakerState::HalfOpen => Err(BreakerOpen { retry_after: 1 }),
+            BreakerState::Open => {
+                let (at, _) = inner.opened.expect("open breaker has an open time");
+                let remaining = self.open_duration.saturating_sub(now.duration_since(at));
+                Err(BreakerOpen {
+                    retry_after: remaining.as_secs().max(1),
+                })
+            }
+        }
+    }
+
+    /// Whether a call would be attempted right now, without claiming the
+    /// half-open probe.
+    pub fn is_available(&self) -> bool {
+        let now = Instant::now();
+        let inner = self.lock();
+        match self.state_of(&inner, now) {
+            BreakerState::Closed => true,
+            BreakerState::HalfOpen => !self.probe_in_flight(&inner, now),
+            BreakerState::Open => false,
+        }
+    }
+
+    /// Record a call that succeeded, closing the breaker.
+    pub fn record_success(&self) {
+        let mut inner = self.lock();
+        if inner.opened.is_some() {
+            tracing::info!(provider = self.name, "Circuit breaker closed");
+        }
+        inner.consecutive_failures = 0;
+        inner.opened = None;
+        inner.probe_started = None;
+    }
+
+    /// Record a call that failed, opening the breaker at the threshold or
+    /// when the half-open probe fails.
+    pub fn record_failure(&self) {
+        let now = Instant::now();
+        let mut inner = self.lock();
+        inner.consecutive_failures = inner.consecutive_failures.saturating_add(1);
+        let probe_failed = inner.probe_started.take().is_some();
+        if probe_failed || inner.consecutive_failures >= self.failure_threshold {
+            if inner.opened.is_none() || probe_failed {
+                tracing::warn!(
+                    provider = self.name,
+                    failures = inner.consecutive_failures,
+                    "Circuit breaker opened"
+                );
+            }
+            inner.opened = Some((now, Utc::now()));
+        }
+    }
+
+    /// Current state for health reporting.
+    pub fn status(&self) -> BreakerStatus {
+        let inner = self.lock();
+        BreakerStatus {
+            name: self.name,
+            state: self.state_of(&inner, Instant::now()),
+            consecutive_failures: inner.consecutive_failures,
+            opened_at: inner.opened.map(|(_, at)| at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_breaker_opens_after_threshold() {
+        let breaker = CircuitBreaker::with_limits("sms", 3, Duration::from_secs(60));
+        for _ in 0..2 {
+            assert!(breaker.try_acquire().is_ok());
+            breaker.record_failure();
+        }
+        assert_eq!(breaker.status().state, BreakerState::Closed);
+
+        assert!(breaker.try_acquire().is_ok());
+        breaker.record_failure();
+        assert_eq!(breaker.status().state, BreakerState::Open);
+        assert!(breaker.status().opened_at.is_some());
+        assert!(!breaker.is_available());
+        let open = breaker.try_acquire().unwrap_err();
+        assert!(open.retry_after > 0 && open.retry_after <= 60);
+    }
+
+    #[test]
+    fn test_success_resets_failures() {
+        let breaker = CircuitBreaker::with_limits("s3", 2, Duration::from_secs(60));
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        assert_eq!(breaker.status().state, BreakerState::Closed);
+        assert_eq!(breaker.status().consecutive_failures, 1);
+    }
+
+    #[test]
+    fn test_half_open_allows_one_probe() {
+        let breaker = CircuitBreaker::with_limits("email", 1, Duration::ZERO);
+        breaker.record_failure();
+        assert_eq!(breaker.status().state, BreakerState::HalfOpen);
+
+        assert!(breaker.try_acquire().is_ok());
+        assert!(breaker.try_acquire().is_err());
+        assert!(!breaker.is_available());
+
+        // A failed probe reopens; a successful one closes
+        breaker.record_failure();
+        assert!(breaker.try_acquire().is_ok());
+        breaker.record_success();
+        assert_eq!(breaker.status().state, BreakerState::Closed);
+        assert_eq!(breaker.status().consecutive_failures, 0);
+    }
+}
@@ -1,3 +1,4 @@
 //! Utility modules for the Facet API.
 
+pub mod circuit_breaker;
 pub mod file_validation;
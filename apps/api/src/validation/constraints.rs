@@ -15,6 +15,12 @@ pub const MAX_EMAIL_LENGTH: usize = 255;
 /// Maximum length for item type field.
 pub const MAX_ITEM_TYPE_LENGTH: usize = 100;
 
+/// Maximum length for an authorized pickup's relationship to the customer.
+pub const MAX_RELATIONSHIP_LENGTH: usize = 100;
+
+/// Maximum length for the kind of ID shown at pickup.
+pub const MAX_ID_TYPE_LENGTH: usize = 50;
+
 /// Maximum length for description fields (item_description, condition_notes, requested_work).
 pub const MAX_DESCRIPTION_LENGTH: usize = 2000;
 
@@ -30,6 +36,118 @@ pub const MAX_TICKET_PREFIX_LENGTH: usize = 10;
 /// Maximum length for currency code (e.g., "USD").
 pub const MAX_CURRENCY_LENGTH: usize = 10;
 
+/// Maximum length for an IANA time zone name (e.g., "America/Chicago").
+pub const MAX_TIMEZONE_LENGTH: usize = 64;
+
+/// Maximum length for a single QC checklist item.
+pub const MAX_QC_ITEM_LENGTH: usize = 255;
+
+/// Maximum number of items on the QC checklist.
+pub const MAX_QC_ITEMS: usize = 50;
+
+/// Maximum length for a metal type (e.g., "14k_gold").
+pub const MAX_METAL_TYPE_LENGTH: usize = 50;
+
+/// Maximum number of entries in the metal price table.
+pub const MAX_METAL_PRICES: usize = 100;
+
+/// Maximum number of configured item types.
+pub const MAX_ITEM_TYPES: usize = 100;
+
+/// Longest default turnaround an item type or service may set, in days.
+pub const MAX_TURNAROUND_DAYS: i32 = 365;
+
+/// Maximum number of holidays a store may list.
+pub const MAX_HOLIDAYS: usize = 100;
+
+/// Maximum number of condition checklist items or suggested services on an
+/// item type.
+pub const MAX_ITEM_TYPE_LIST_ITEMS: usize = 50;
+
+/// Maximum length for a service catalog name.
+pub const MAX_SERVICE_NAME_LENGTH: usize = 100;
+
+/// Maximum length for a material's name.
+pub const MAX_MATERIAL_NAME_LENGTH: usize = 100;
+
+/// Maximum length for the unit a material is counted in (e.g., "dwt").
+pub const MAX_MATERIAL_UNIT_LENGTH: usize = 20;
+
+/// Largest quantity of a material that may be stocked or used at once.
+pub const MAX_MATERIAL_QUANTITY: i64 = 1_000_000;
+
+/// Maximum length for an appraisal's metal (e.g., "18k yellow gold").
+pub const MAX_APPRAISAL_METAL_LENGTH: usize = 100;
+
+/// Maximum length for one grading report or certificate number.
+pub const MAX_CERTIFICATION_NUMBER_LENGTH: usize = 100;
+
+/// Maximum number of configured promise date reasons.
+pub const MAX_PROMISE_DATE_REASONS: usize = 50;
+
+/// Maximum length of a promise date reason code.
+pub const MAX_REASON_CODE_LENGTH: usize = 50;
+
+/// Maximum number of storage location suggestion rules.
+pub const MAX_LOCATION_RULES: usize = 100;
+
+/// Maximum number of rush surcharge tiers.
+pub const MAX_RUSH_SURCHARGE_TIERS: usize = 20;
+
+/// Largest percentage a rush surcharge tier may add.
+pub const MAX_RUSH_SURCHARGE_PERCENT: i64 = 500;
+
+/// Maximum length for a notification email template body.
+pub const MAX_TEMPLATE_BODY_LENGTH: usize = 5000;
+
+/// Upper bound for the store's max_photos_per_ticket setting.
+pub const MAX_PHOTOS_PER_TICKET_LIMIT: i32 = 100;
+
+/// Allowed range for the store's min_pin_length setting.
+pub const MIN_PIN_LENGTH_RANGE: std::ops::RangeInclusive<i32> = 4..=32;
+
+/// Allowed range for the store's ticket_number_padding setting.
+pub const TICKET_NUMBER_PADDING_RANGE: std::ops::RangeInclusive<i32> = 1..=8;
+
+/// Allowed range for the store's auto_archive_after_days setting.
+pub const AUTO_ARCHIVE_DAYS_RANGE: std::ops::RangeInclusive<i32> = 1..=3650;
+
+/// Maximum length for the disclaimer printed at the bottom of receipts.
+pub const MAX_RECEIPT_FOOTER_LENGTH: usize = 1000;
+
+/// Allowed label stock widths, in whole mm.
+pub const LABEL_WIDTH_MM_RANGE: std::ops::RangeInclusive<i64> = 25..=150;
+
+/// Allowed label stock heights, in whole mm.
+pub const LABEL_HEIGHT_MM_RANGE: std::ops::RangeInclusive<i64> = 12..=100;
+
+/// Highest sales tax percentage a store may set.
+pub const MAX_TAX_RATE_PERCENT: i64 = 100;
+
+/// Largest receipt logo accepted for upload (512 KB), well under the JSON body limit.
+pub const MAX_RECEIPT_LOGO_SIZE: usize = 512 * 1024;
+
+/// Maximum length for a partner's own job number.
+pub const MAX_PARTNER_REFERENCE_LENGTH: usize = 100;
+
+/// Allowed range for a partner's requests-per-minute limit.
+pub const PARTNER_RATE_LIMIT_RANGE: std::ops::RangeInclusive<i32> = 1..=600;
+
+/// Allowed campaign send rates (messages per minute).
+pub const CAMPAIGN_SEND_RATE_RANGE: std::ops::RangeInclusive<i32> = 1..=600;
+
+/// Maximum number of data rows in one CSV import.
+pub const MAX_IMPORT_ROWS: usize = 5000;
+
+/// Maximum length for a call transcript on an intake draft.
+pub const MAX_TRANSCRIPT_LENGTH: usize = 20000;
+
+/// Maximum length for an object storage key.
+pub const MAX_STORAGE_KEY_LENGTH: usize = 500;
+
+/// Maximum length for a URL sent by an integration (e.g., a call recording).
+pub const MAX_URL_LENGTH: usize = 2000;
+
 #[cfg(test)]
 mod tests {
     use super::*;
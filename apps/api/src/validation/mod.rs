@@ -7,10 +7,12 @@
 //! - Email format validation
 //! - Log-safe sanitization
 //! - Reference validation for foreign key relationships
+//! - Soft warnings for allowed-but-suspicious values
 
 pub mod constraints;
 pub mod references;
 pub mod sanitize;
+pub mod warnings;
 
 pub use constraints::*;
 pub use references::*;
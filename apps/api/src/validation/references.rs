@@ -1,11 +1,13 @@
 //! Reference validation for foreign key relationships.
 //!
 //! Provides consistent validation for referenced entities (storage locations, employees,
-//! customers) before database operations. This ensures clear 404 errors instead of
+//! customers, metal prices) before database operations. This ensures clear 404 errors instead of
 //! cryptic foreign key constraint violations.
 
 use crate::error::AppError;
-use crate::repositories::{CustomerRepository, EmployeeRepository, StorageLocationRepository};
+use crate::repositories::{
+    CustomerRepository, EmployeeRepository, MetalPriceRepository, StorageLocationRepository,
+};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -41,6 +43,19 @@ pub async fn validate_customer(pool: &PgPool, customer_id: Uuid) -> Result<(), A
     Ok(())
 }
 
+/// Validate that a metal type has an entry in the metal price table.
+///
+/// Returns an error if the metal type has not been configured.
+pub async fn validate_metal_type(pool: &PgPool, metal_type: &str) -> Result<(), AppError> {
+    if MetalPriceRepository::find_by_metal_type(pool, metal_type)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::not_found("Metal type not found in price table"));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     // Unit tests would require mocking the database connection
@@ -0,0 +1,208 @@
+//! Soft validation warnings.
+//!
+//! These checks flag values that are allowed but likely mistakes, such as a
+//! promise date on a Sunday or an unusually low quote. They never fail a
+//! request; handlers attach the results to the response via
+//! [`ApiResponse::with_warnings`](crate::response::ApiResponse::with_warnings)
+//! so the UI can ask the user to confirm.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+use rust_decimal::Decimal;
+
+use crate::models::storage_location::StorageLocationSummary;
+use crate::response::ApiWarning;
+
+/// Warning codes returned in the `warnings` array.
+pub mod codes {
+    pub const PROMISE_DATE_SUNDAY: &str = "PROMISE_DATE_SUNDAY";
+    pub const PROMISE_DATE_PAST: &str = "PROMISE_DATE_PAST";
+    pub const QUOTE_UNUSUALLY_LOW: &str = "QUOTE_UNUSUALLY_LOW";
+    pub const QUOTE_UNUSUALLY_HIGH: &str = "QUOTE_UNUSUALLY_HIGH";
+    pub const LOCATION_AT_CAPACITY: &str = "LOCATION_AT_CAPACITY";
+}
+
+/// Quotes below this amount are flagged as unusually low.
+pub const LOW_QUOTE_THRESHOLD: Decimal = Decimal::from_parts(500, 0, 0, false, 2);
+
+/// Quotes above this amount are flagged as unusually high.
+pub const HIGH_QUOTE_THRESHOLD: Decimal = Decimal::from_parts(1_000_000, 0, 0, false, 2);
+
+/// Check a promise date for values worth confirming.
+///
+/// Flags dates in the past and dates that fall on a Sunday.
+pub fn promise_date_warnings(promise_date: NaiveDate, today: NaiveDate) -> Vec<ApiWarning> {
+    let mut warnings = Vec::new();
+
+    if promise_date < today {
+        warnings.push(ApiWarning::for_field(
+            codes::PROMISE_DATE_PAST,
+            "promise_date",
+            format!("Promise date {} is in the past", promise_date),
+        ));
+    }
+
+    if promise_date.weekday() == Weekday::Sun {
+        warnings.push(ApiWarning::for_field(
+            codes::PROMISE_DATE_SUNDAY,
+            "promise_date",
+            format!("Promise date {} falls on a Sunday", promise_date),
+        ));
+    }
+
+    warnings
+}
+
+/// Check a quote amount for values worth confirming.
+pub fn quote_warnings(quote_amount: Decimal) -> Vec<ApiWarning> {
+    if quote_amount < LOW_QUOTE_THRESHOLD {
+        vec![ApiWarning::for_field(
+            codes::QUOTE_UNUSUALLY_LOW,
+            "quote_amount",
+            format!(
+                "Quote of {:.2} is below {:.2}; confirm this is correct",
+                quote_amount, LOW_QUOTE_THRESHOLD
+            ),
+        )]
+    } else if quote_amount > HIGH_QUOTE_THRESHOLD {
+        vec![ApiWarning::for_field(
+            codes::QUOTE_UNUSUALLY_HIGH,
+            "quote_amount",
+            format!(
+                "Quote of {:.2} is above {:.2}; confirm this is correct",
+                quote_amount, HIGH_QUOTE_THRESHOLD
+            ),
+        )]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Check a storage location a ticket is being put in for room.
+///
+/// `location.open_tickets` is the count before the ticket arrives, so a full
+/// location means the ticket would put it over capacity.
+pub fn location_capacity_warnings(location: &StorageLocationSummary) -> Vec<ApiWarning> {
+    match location.capacity {
+        Some(capacity) if location.is_full() => vec![ApiWarning::for_field(
+            codes::LOCATION_AT_CAPACITY,
+            "storage_location_id",
+            format!(
+                "{} already holds {} of {} tickets; consider another location",
+                location.name, location.open_tickets, capacity
+            ),
+        )],
+        _ => Vec::new(),
+    }
+}
+
+/// Collect warnings for the schedulable/priced fields of a ticket.
+///
+/// Pass only the values being set by the request so unchanged fields don't
+/// re-warn on every update.
+pub fn ticket_warnings(
+    promise_date: Option<NaiveDate>,
+    quote_amount: Option<Decimal>,
+    today: NaiveDate,
+) -> Vec<ApiWarning> {
+    let mut warnings = Vec::new();
+    if let Some(date) = promise_date {
+        warnings.extend(promise_date_warnings(date, today));
+    }
+    if let Some(amount) = quote_amount {
+        warnings.extend(quote_warnings(amount));
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_thresholds() {
+        assert_eq!(LOW_QUOTE_THRESHOLD, Decimal::new(500, 2));
+        assert_eq!(HIGH_QUOTE_THRESHOLD, Decimal::new(1_000_000, 2));
+    }
+
+    #[test]
+    fn test_promise_date_sunday() {
+        // 2026-10-18 is a Sunday
+        let warnings = promise_date_warnings(date(2026, 10, 18), date(2026, 10, 15));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, codes::PROMISE_DATE_SUNDAY);
+        assert_eq!(warnings[0].field, Some("promise_date"));
+    }
+
+    #[test]
+    fn test_promise_date_past() {
+        let warnings = promise_date_warnings(date(2026, 10, 14), date(2026, 10, 15));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, codes::PROMISE_DATE_PAST);
+    }
+
+    #[test]
+    fn test_promise_date_ok() {
+        assert!(promise_date_warnings(date(2026, 10, 15), date(2026, 10, 15)).is_empty());
+        assert!(promise_date_warnings(date(2026, 10, 20), date(2026, 10, 15)).is_empty());
+    }
+
+    #[test]
+    fn test_quote_warnings() {
+        assert_eq!(
+            quote_warnings(Decimal::new(100, 2))[0].code,
+            codes::QUOTE_UNUSUALLY_LOW
+        );
+        assert_eq!(
+            quote_warnings(Decimal::ZERO)[0].code,
+            codes::QUOTE_UNUSUALLY_LOW
+        );
+        assert_eq!(
+            quote_warnings(Decimal::new(2_500_000, 2))[0].code,
+            codes::QUOTE_UNUSUALLY_HIGH
+        );
+        assert!(quote_warnings(Decimal::new(500, 2)).is_empty());
+        assert!(quote_warnings(Decimal::new(17500, 2)).is_empty());
+    }
+
+    #[test]
+    fn test_ticket_warnings_combines_and_skips_unset() {
+        let today = date(2026, 10, 15);
+        assert!(ticket_warnings(None, None, today).is_empty());
+
+        let warnings = ticket_warnings(Some(date(2026, 10, 11)), Some(Decimal::new(100, 2)), today);
+        let found: Vec<_> = warnings.iter().map(|w| w.code).collect();
+        assert_eq!(
+            found,
+            vec![
+                codes::PROMISE_DATE_PAST,
+                codes::PROMISE_DATE_SUNDAY,
+                codes::QUOTE_UNUSUALLY_LOW
+            ]
+        );
+    }
+
+    #[test]
+    fn test_location_capacity_warnings() {
+        let mut location = StorageLocationSummary {
+            location_id: uuid::Uuid::new_v4(),
+            name: "Safe A".to_string(),
+            is_active: true,
+            capacity: None,
+            open_tickets: 40,
+        };
+        assert!(location_capacity_warnings(&location).is_empty());
+
+        location.capacity = Some(41);
+        assert!(location_capacity_warnings(&location).is_empty());
+
+        location.capacity = Some(40);
+        let warnings = location_capacity_warnings(&location);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, codes::LOCATION_AT_CAPACITY);
+        assert_eq!(warnings[0].field, Some("storage_location_id"));
+    }
+}
@@ -16,27 +16,180 @@ import type {
 	TicketDetailResponse,
 	CreateTicketRequest,
 	CreateTicketResponse,
+	IntakeDraft,
+	IntakeDraftStatus,
+	ConvertIntakeDraftRequest,
+	ConvertIntakeDraftResponse,
 	UpdateTicketRequest,
 	GetQueueResponse,
+	QueueLane,
+	QueueLanes,
+	ReorderQueueRequest,
+	PublicTicketStatus,
 	ChangeStatusRequest,
 	ChangeStatusResponse,
+	BulkStatusRequest,
+	BulkStatusResponse,
 	CloseTicketRequest,
 	CloseTicketResponse,
+	ReopenTicketRequest,
+	ReopenTicketResponse,
+	DeleteTicketResponse,
+	RestoreTicketResponse,
+	CustodyChainResponse,
+	TicketHistoryResponse,
+	PaymentKind,
+	PaymentMethod,
+	PaymentInput,
+	RecordPaymentRequest,
+	RecordPaymentResponse,
+	TicketPayment,
+	TicketPaymentEntry,
+	TicketPaymentsResponse,
+	TicketContact,
+	LogContactRequest,
+	TicketAuthorizedPickup,
+	CreateAuthorizedPickupRequest,
+	TicketPickupEntry,
+	PickupInput,
+	TicketPickupsResponse,
+	CustodyEvent,
 	TicketStatus,
 	Customer,
 	CreateCustomerRequest,
 	EmployeeSummary,
 	VerifyPinResponse,
+	PinChallenge,
+	TrainingModeResponse,
 	StorageLocationSummary,
 	ListLocationsResponse,
+	LocationTicketsResponse,
+	SuggestLocationResponse,
 	CreateStorageLocationRequest,
 	UpdateStorageLocationRequest,
 	StoreSettings,
 	UpdateStoreSettingsRequest,
+	SettingsSection,
+	SettingsSectionResponse,
+	PrintingSettings,
+	HoursSettings,
+	OpeningHours,
+	CalendarFeedResponse,
+	SettingsChange,
+	SettingsHistoryResponse,
+	SettingsRollbackResponse,
+	ConfigBundle,
+	ConfigImportResponse,
+	ImportOptions,
+	ImportSummary,
+	IntegrityReport,
+	JobStatus,
+	StorageReconcileReport,
+	RecoverAdminResponse,
+	RecoveryAttemptsResponse,
+	Partner,
+	PartnerKeyResponse,
+	ListPartnersResponse,
+	CreatePartnerRequest,
+	UpdatePartnerRequest,
+	PartnerActivity,
+	PartnerReport,
+	Store,
+	CreateStoreRequest,
+	ListStoresResponse,
+	Service,
+	CreateServiceRequest,
+	UpdateServiceRequest,
+	TicketService,
+	ServiceUsage,
+	Material,
+	CreateMaterialRequest,
+	UpdateMaterialRequest,
+	TicketMaterialEntry,
+	TicketMaterialsResponse,
+	RecordMaterialRequest,
+	RecordMaterialResponse,
+	ServiceReport,
+	WebhookEvent,
+	Webhook,
+	WebhookSecretResponse,
+	ListWebhooksResponse,
+	CreateWebhookRequest,
+	UpdateWebhookRequest,
+	WebhookDeliveryStatus,
+	WebhookDelivery,
+	WebhookDeliveriesResponse,
+	CampaignStatus,
+	CampaignRecipientStatus,
+	CampaignSegment,
+	CreateCampaignRequest,
+	CampaignPreview,
+	CampaignProgress,
+	Campaign,
+	ListCampaignsResponse,
+	CampaignRecipient,
+	CampaignRecipientsResponse,
+	AuditAction,
+	AuditAuthMethod,
+	AuditLogEntry,
+	AuditLogParams,
+	AuditLogResponse,
+	EmployeeReport,
+	ReportRangeParams,
+	RevenueReport,
+	ThroughputReport,
+	QueueTrendReport,
+	DueTicket,
+	OverdueReport,
+	LocationAuditParams,
+	LocationAuditReport,
+	DailyCloseoutReport,
+	ReportDimension,
+	ReportMeasure,
+	ReportDefinition,
+	ReportDefinitionRequest,
+	ListReportDefinitionsResponse,
+	CustomReport,
+	TicketTransfer,
+	CreateTransferRequest,
+	ResolveTransferRequest,
+	TicketTransfersResponse,
+	ListTransfersParams,
+	ListTransfersResponse,
+	TimeEntry,
+	TimeEntryEntry,
+	TicketTimeResponse,
+	Appraisal,
+	SaveAppraisalRequest,
+	DeleteAppraisalResponse,
+	TicketMovement,
+	TicketMovementEntry,
+	CurrentLocation,
+	RecordMovementRequest,
+	TicketMovementsResponse,
+	ItemType,
+	ItemTypeInput,
+	PromiseDateReason,
+	PromiseDateReasonInput,
+	RushSurchargeTier,
+	RushSurchargeTierInput,
+	QuoteRequest,
+	QuoteBreakdown,
+	PromiseDateSuggestion,
+	QuoteChannel,
+	QuoteDecisionRequest,
+	QuoteEvent,
+	QuoteStatus,
+	SendQuoteRequest,
+	TicketQuoteResponse,
 	CreateEmployeeRequest,
 	UpdateEmployeeRequest,
 	ListEmployeesResponse,
-	DeleteEmployeeResponse
+	ActiveEmployeesResponse,
+	EmployeeProfile,
+	DeleteEmployeeResponse,
+	Permission,
+	EmployeePermissions
 } from '$lib/types/api';
 
 // =============================================================================
@@ -172,6 +325,30 @@ export function clearEmployeeSession(): void {
 	employeeSessionExpiresAt = null;
 }
 
+// =============================================================================
+// Store Context
+// =============================================================================
+
+/**
+ * The store this terminal works for, sent as X-Store-ID.
+ * When null the server uses the session's store or the primary store.
+ */
+let currentStoreId: string | null = null;
+
+/**
+ * Set the store this terminal works for (null for the default).
+ */
+export function setCurrentStore(storeId: string | null): void {
+	currentStoreId = storeId;
+}
+
+/**
+ * Get the store this terminal works for, if one was picked.
+ */
+export function getCurrentStore(): string | null {
+	return currentStoreId;
+}
+
 // =============================================================================
 // Admin Session Context
 // =============================================================================
@@ -298,6 +475,10 @@ function buildHeaders(useAdminSession?: boolean): HeadersInit {
 		headers['X-Employee-ID'] = currentEmployeeId;
 	}
 
+	if (currentStoreId) {
+		headers['X-Store-ID'] = currentStoreId;
+	}
+
 	if (useAdminSession && adminSessionToken) {
 		headers['X-Admin-Session'] = adminSessionToken;
 	}
@@ -393,6 +574,30 @@ async function put<T>(path: string, body?: unknown, useAdminSession?: boolean):
 	return parseResponse<T>(response);
 }
 
+/**
+ * Make a PATCH request to the API.
+ * @param useAdminSession - If true, includes admin session authentication
+ * @param ifMatch - ETag or version the update is based on (rejected if stale)
+ */
+async function patch<T>(
+	path: string,
+	body?: unknown,
+	useAdminSession?: boolean,
+	ifMatch?: string | number
+): Promise<T> {
+	const url = buildUrl(path);
+	const headers = buildHeaders(useAdminSession) as Record<string, string>;
+	if (ifMatch !== undefined) {
+		headers['If-Match'] = typeof ifMatch === 'number' ? `"${ifMatch}"` : ifMatch;
+	}
+	const response = await fetch(url, {
+		method: 'PATCH',
+		headers,
+		body: body ? JSON.stringify(body) : undefined
+	});
+	return parseResponse<T>(response);
+}
+
 /**
  * Make a DELETE request to the API.
  * @param useAdminSession - If true, includes admin session authentication
@@ -426,7 +631,15 @@ async function getWithAdmin<T>(path: string, params?: Record<string, unknown>):
  * Health check response.
  */
 export interface HealthResponse {
+	/** "ok", or "degraded" while a provider's circuit breaker is not closed */
 	status: string;
+	version: string;
+	breakers: {
+		name: string;
+		state: 'closed' | 'open' | 'half_open';
+		consecutive_failures: number;
+		opened_at: string | null;
+	}[];
 }
 
 /**
@@ -463,6 +676,38 @@ export async function createTicket(request: CreateTicketRequest): Promise<Create
 	return post<CreateTicketResponse>('/tickets', request);
 }
 
+/**
+ * List intake drafts (e.g., from phone calls), oldest first.
+ */
+export async function listIntakeDrafts(status?: IntakeDraftStatus): Promise<IntakeDraft[]> {
+	return get<IntakeDraft[]>('/intake-drafts', status ? { status } : undefined);
+}
+
+/**
+ * Get one intake draft.
+ */
+export async function getIntakeDraft(draftId: string): Promise<IntakeDraft> {
+	return get<IntakeDraft>(`/intake-drafts/${draftId}`);
+}
+
+/**
+ * Create a ticket from an intake draft. The customer and requested work
+ * default to the draft's.
+ */
+export async function convertIntakeDraft(
+	draftId: string,
+	request: ConvertIntakeDraftRequest
+): Promise<ConvertIntakeDraftResponse> {
+	return post<ConvertIntakeDraftResponse>(`/intake-drafts/${draftId}/convert`, request);
+}
+
+/**
+ * Dismiss an intake draft without creating a ticket.
+ */
+export async function dismissIntakeDraft(draftId: string): Promise<IntakeDraft> {
+	return post<IntakeDraft>(`/intake-drafts/${draftId}/dismiss`);
+}
+
 /**
  * Update an existing ticket.
  * Requires X-Employee-ID header (set via setCurrentEmployee).
@@ -488,6 +733,18 @@ export async function changeTicketStatus(
 	return post<ChangeStatusResponse>(`/tickets/${ticketId}/status`, request);
 }
 
+/**
+ * Change the status of several tickets at once (up to 100).
+ * Each ticket succeeds or fails on its own; see `results`.
+ */
+export async function bulkChangeTicketStatus(
+	ticketIds: string[],
+	status: TicketStatus
+): Promise<BulkStatusResponse> {
+	const request: BulkStatusRequest = { ticket_ids: ticketIds, status };
+	return post<BulkStatusResponse>('/tickets/bulk/status', request);
+}
+
 /**
  * Close a ticket.
  * Requires X-Employee-ID header (set via setCurrentEmployee).
@@ -495,12 +752,49 @@ export async function changeTicketStatus(
  */
 export async function closeTicket(
 	ticketId: string,
-	actualAmount: string
+	actualAmount: string,
+	options?: { payment?: PaymentInput; allow_balance_due?: boolean; pickup?: PickupInput }
 ): Promise<CloseTicketResponse> {
-	const request: CloseTicketRequest = { actual_amount: actualAmount };
+	const request: CloseTicketRequest = { actual_amount: actualAmount, ...options };
 	return post<CloseTicketResponse>(`/tickets/${ticketId}/close`, request);
 }
 
+/**
+ * Reopen a closed ticket (admin only), moving it back to in_progress.
+ * Requires an active admin session; the reason is saved as a note.
+ */
+export async function reopenTicket(
+	ticketId: string,
+	reason: string
+): Promise<ReopenTicketResponse> {
+	const request: ReopenTicketRequest = { reason };
+	return post<ReopenTicketResponse>(`/tickets/${ticketId}/reopen`, request, true);
+}
+
+/**
+ * Archive a closed ticket (admin only).
+ * Requires an active admin session.
+ */
+export async function archiveTicket(ticketId: string): Promise<ChangeStatusResponse> {
+	return post<ChangeStatusResponse>(`/tickets/${ticketId}/archive`, undefined, true);
+}
+
+/**
+ * Soft-delete a ticket (admin only).
+ * Requires an active admin session; the employee session is used for attribution.
+ */
+export async function deleteTicket(ticketId: string): Promise<DeleteTicketResponse> {
+	return del<DeleteTicketResponse>(`/tickets/${ticketId}`, true);
+}
+
+/**
+ * Restore a soft-deleted ticket (admin only).
+ * Requires an active admin session; the employee session is used for attribution.
+ */
+export async function restoreTicket(ticketId: string): Promise<RestoreTicketResponse> {
+	return post<RestoreTicketResponse>(`/tickets/${ticketId}/restore`, undefined, true);
+}
+
 /**
  * Request body for toggling rush flag.
  */
@@ -524,6 +818,92 @@ export async function toggleRush(ticketId: string, isRush: boolean): Promise<Tog
 	return post<ToggleRushResponse>(`/tickets/${ticketId}/rush`, request);
 }
 
+/**
+ * Request body for assigning a ticket.
+ */
+export interface AssignTicketRequest {
+	/** Null unassigns the ticket */
+	employee_id: string | null;
+	/** Text the assignee about it */
+	notify?: boolean;
+}
+
+/**
+ * Response for a ticket assignment.
+ */
+export interface AssignTicketResponse extends Ticket {
+	previous_worked_by: string | null;
+}
+
+/**
+ * Assign a ticket to an employee, or unassign it with null.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function assignTicket(
+	ticketId: string,
+	employeeId: string | null,
+	notify = false
+): Promise<AssignTicketResponse> {
+	const request: AssignTicketRequest = { employee_id: employeeId, notify };
+	return post<AssignTicketResponse>(`/tickets/${ticketId}/assign`, request);
+}
+
+/**
+ * Calculate a quote with the store's rush surcharge (nothing is saved).
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function quoteTicket(request: QuoteRequest): Promise<QuoteBreakdown> {
+	return post<QuoteBreakdown>('/tickets/quote', request);
+}
+
+/**
+ * Suggest a promise date from the item type's turnaround, skipping days the
+ * store is closed.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function getPromiseDateSuggestion(
+	itemType?: string,
+	isRush = false
+): Promise<PromiseDateSuggestion> {
+	return get<PromiseDateSuggestion>('/tickets/promise-date-suggestion', {
+		item_type: itemType,
+		is_rush: isRush
+	});
+}
+
+/**
+ * Record that a ticket's quote was given to the customer.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function sendQuote(
+	ticketId: string,
+	request: SendQuoteRequest
+): Promise<TicketQuoteResponse> {
+	return post<TicketQuoteResponse>(`/tickets/${ticketId}/quote/send`, request);
+}
+
+/**
+ * Record that the customer approved a ticket's quote.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function approveQuote(
+	ticketId: string,
+	request: QuoteDecisionRequest
+): Promise<TicketQuoteResponse> {
+	return post<TicketQuoteResponse>(`/tickets/${ticketId}/quote/approve`, request);
+}
+
+/**
+ * Record that the customer declined a ticket's quote.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function declineQuote(
+	ticketId: string,
+	request: QuoteDecisionRequest
+): Promise<TicketQuoteResponse> {
+	return post<TicketQuoteResponse>(`/tickets/${ticketId}/quote/decline`, request);
+}
+
 /**
  * Request body for adding a note.
  */
@@ -559,178 +939,573 @@ export function getReceiptPdfUrl(ticketId: string): string {
 }
 
 /**
- * Get the label PDF URL for a ticket.
+ * Get a ticket's merged history of field, status, note, and photo events.
  */
-export function getLabelPdfUrl(ticketId: string): string {
-	return `${config.baseUrl}/tickets/${ticketId}/label.pdf`;
+export async function getTicketHistory(
+	ticketId: string,
+	params?: { limit?: number; offset?: number }
+): Promise<TicketHistoryResponse> {
+	return get<TicketHistoryResponse>(`/tickets/${ticketId}/history`, params);
 }
 
 /**
- * Fetch the receipt PDF as a blob.
+ * Get a ticket's payments and balance.
  */
-export async function fetchReceiptPdf(ticketId: string): Promise<Blob> {
-	const url = getReceiptPdfUrl(ticketId);
-	const response = await fetch(url, {
-		method: 'GET',
-		headers: buildHeaders()
-	});
-
-	if (!response.ok) {
-		throw new ApiClientError(
-			'SERVER_ERROR',
-			`Failed to fetch receipt PDF: ${response.status}`,
-			response.status
-		);
-	}
+export async function getPayments(ticketId: string): Promise<TicketPaymentsResponse> {
+	return get<TicketPaymentsResponse>(`/tickets/${ticketId}/payments`);
+}
 
-	return response.blob();
+/**
+ * Record a payment on a ticket.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function recordPayment(
+	ticketId: string,
+	request: RecordPaymentRequest
+): Promise<RecordPaymentResponse> {
+	return post<RecordPaymentResponse>(`/tickets/${ticketId}/payments`, request);
 }
 
 /**
- * Fetch the label PDF as a blob.
+ * Get the materials used on a ticket and their cost.
  */
-export async function fetchLabelPdf(ticketId: string): Promise<Blob> {
-	const url = getLabelPdfUrl(ticketId);
-	const response = await fetch(url, {
-		method: 'GET',
-		headers: buildHeaders()
-	});
+export async function getTicketMaterials(ticketId: string): Promise<TicketMaterialsResponse> {
+	return get<TicketMaterialsResponse>(`/tickets/${ticketId}/materials`);
+}
 
-	if (!response.ok) {
-		throw new ApiClientError(
-			'SERVER_ERROR',
-			`Failed to fetch label PDF: ${response.status}`,
-			response.status
-		);
-	}
+/**
+ * Record a material used on a ticket, taking it out of stock.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function recordTicketMaterial(
+	ticketId: string,
+	request: RecordMaterialRequest
+): Promise<RecordMaterialResponse> {
+	return post<RecordMaterialResponse>(`/tickets/${ticketId}/materials`, request);
+}
 
-	return response.blob();
+/**
+ * Get a ticket's customer contact log, oldest first.
+ */
+export async function getContacts(ticketId: string): Promise<TicketContact[]> {
+	return get<TicketContact[]>(`/tickets/${ticketId}/contacts`);
 }
 
-// =============================================================================
-// Queue Endpoints
-// =============================================================================
+/**
+ * Log a call or message with the customer.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function logContact(
+	ticketId: string,
+	request: LogContactRequest
+): Promise<TicketContact> {
+	return post<TicketContact>(`/tickets/${ticketId}/contacts`, request);
+}
 
 /**
- * Get the workboard queue with tickets grouped by status lane.
+ * Get a ticket's authorized pickups and release records.
  */
-export async function getQueue(): Promise<GetQueueResponse> {
-	return get<GetQueueResponse>('/queue');
+export async function getAuthorizedPickups(ticketId: string): Promise<TicketPickupsResponse> {
+	return get<TicketPickupsResponse>(`/tickets/${ticketId}/authorized-pickups`);
 }
 
-// =============================================================================
-// Customer Endpoints (Placeholder - not yet implemented in backend)
-// =============================================================================
+/**
+ * Authorize someone to collect a ticket's item.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function createAuthorizedPickup(
+	ticketId: string,
+	request: CreateAuthorizedPickupRequest
+): Promise<TicketAuthorizedPickup> {
+	return post<TicketAuthorizedPickup>(`/tickets/${ticketId}/authorized-pickups`, request);
+}
 
 /**
- * List customers with optional search.
- * Note: This endpoint is not yet implemented in the backend.
+ * Revoke an authorized pickup. The record is kept with revoked_at set.
  */
-export async function listCustomers(search?: string): Promise<Customer[]> {
-	const params = search ? { search } : undefined;
-	return get<Customer[]>('/customers', params);
+export async function revokeAuthorizedPickup(
+	ticketId: string,
+	authorizedPickupId: string
+): Promise<TicketAuthorizedPickup> {
+	return del<TicketAuthorizedPickup>(
+		`/tickets/${ticketId}/authorized-pickups/${authorizedPickupId}`
+	);
 }
 
 /**
- * Get customer by ID.
- * Note: This endpoint is not yet implemented in the backend.
+ * Get the URL of the signature captured at a pickup.
  */
-export async function getCustomer(customerId: string): Promise<Customer> {
-	return get<Customer>(`/customers/${customerId}`);
+export function getPickupSignatureUrl(ticketId: string, pickupId: string): string {
+	return `${config.baseUrl}/tickets/${ticketId}/pickups/${pickupId}/signature`;
 }
 
 /**
- * Create a new customer.
- * Note: This endpoint is not yet implemented in the backend.
+ * Get a ticket's chain of custody.
  */
-export async function createCustomer(request: CreateCustomerRequest): Promise<Customer> {
-	return post<Customer>('/customers', request);
+export async function getCustodyChain(ticketId: string): Promise<CustodyChainResponse> {
+	return get<CustodyChainResponse>(`/tickets/${ticketId}/custody`);
 }
 
-// =============================================================================
-// Employee Endpoints
-// =============================================================================
+/**
+ * Record a custody handoff to the current employee.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function recordCustodyHandoff(
+	ticketId: string,
+	witnessedBy: string | null,
+	notes?: string
+): Promise<CustodyEvent> {
+	return post<CustodyEvent>(`/tickets/${ticketId}/custody`, {
+		witnessed_by: witnessedBy,
+		notes: notes ?? null
+	});
+}
 
 /**
- * List employees (admin only).
- * Requires active admin session.
- * By default returns only active employees.
- * Use includeInactive=true to include inactive employees.
+ * Send a ticket to another storage location.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
  */
-export async function listEmployees(includeInactive?: boolean): Promise<ListEmployeesResponse> {
-	const params = includeInactive ? { include_inactive: true } : undefined;
-	return getWithAdmin<ListEmployeesResponse>('/employees', params);
+export async function createTransfer(
+	ticketId: string,
+	request: CreateTransferRequest
+): Promise<TicketTransfer> {
+	return post<TicketTransfer>(`/tickets/${ticketId}/transfer`, request);
 }
 
 /**
- * Create a new employee (admin only).
- * Requires active admin session.
- * Returns the created employee summary.
+ * Accept a ticket's pending transfer at the receiving location.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
  */
-export async function createEmployee(request: CreateEmployeeRequest): Promise<EmployeeSummary> {
-	return post<EmployeeSummary>('/employees', request, true);
+export async function acceptTransfer(
+	ticketId: string,
+	request: ResolveTransferRequest = {}
+): Promise<TicketTransfer> {
+	return post<TicketTransfer>(`/tickets/${ticketId}/transfer/accept`, request);
 }
 
 /**
- * Update an employee (admin only).
- * Requires active admin session.
- * Returns the updated employee summary.
+ * Cancel a ticket's pending transfer.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
  */
-export async function updateEmployee(
-	employeeId: string,
-	request: UpdateEmployeeRequest
-): Promise<EmployeeSummary> {
-	return put<EmployeeSummary>(`/employees/${employeeId}`, request, true);
+export async function cancelTransfer(
+	ticketId: string,
+	request: ResolveTransferRequest = {}
+): Promise<TicketTransfer> {
+	return post<TicketTransfer>(`/tickets/${ticketId}/transfer/cancel`, request);
 }
 
 /**
- * Delete an employee (admin only).
- * Requires active admin session.
- * Returns deletion status with optional warning about history loss.
+ * Get a ticket's transfer history.
  */
-export async function deleteEmployee(employeeId: string): Promise<DeleteEmployeeResponse> {
-	return del<DeleteEmployeeResponse>(`/employees/${employeeId}`, true);
+export async function getTicketTransfers(ticketId: string): Promise<TicketTransfersResponse> {
+	return get<TicketTransfersResponse>(`/tickets/${ticketId}/transfers`);
 }
 
 /**
- * Verify employee PIN and create an employee session.
- * On success, stores the session token for subsequent employee requests.
- * Returns employee_id, name, role, session_token, and expires_at if the PIN is valid.
- * Throws ApiClientError with code 'INVALID_PIN' if the PIN is invalid.
+ * List transfers across tickets (pending by default).
  */
-export async function verifyEmployeePin(pin: string): Promise<VerifyPinResponse> {
-	const response = await post<VerifyPinResponse>('/employees/verify', { pin });
-	// Store the session token for subsequent requests
-	if (response.session_token) {
-		setEmployeeSession(response.employee_id, response.session_token, response.expires_at);
-	}
-	return response;
+export async function listTransfers(params?: ListTransfersParams): Promise<ListTransfersResponse> {
+	return get<ListTransfersResponse>('/transfers', params as Record<string, unknown>);
 }
 
 /**
- * Log out the current employee session.
- * Invalidates the session on the server and clears local session state.
+ * Check an item out to an employee's bench or back into storage.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
  */
-export async function employeeLogout(): Promise<void> {
-	if (employeeSessionToken) {
-		try {
-			// Tell the server to invalidate the session
-			await post<{ success: boolean }>('/employees/logout', undefined, false);
-		} catch {
-			// Ignore errors - we'll clear the local session anyway
-		}
-	}
-	clearEmployeeSession();
+export async function recordMovement(
+	ticketId: string,
+	request: RecordMovementRequest
+): Promise<TicketMovement> {
+	return post<TicketMovement>(`/tickets/${ticketId}/movements`, request);
 }
 
 /**
- * Response from admin PIN verification.
- * Includes a session token for subsequent authenticated requests.
+ * Get a ticket's movements and where the item is now.
  */
-export interface AdminVerifyResponse {
-	valid: boolean;
-	session_token: string;
-	expires_at: string;
+export async function getTicketMovements(ticketId: string): Promise<TicketMovementsResponse> {
+	return get<TicketMovementsResponse>(`/tickets/${ticketId}/movements`);
+}
+
+/**
+ * Clock the current employee in on a ticket.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function startTimeEntry(ticketId: string): Promise<TimeEntry> {
+	return post<TimeEntry>(`/tickets/${ticketId}/time/start`);
+}
+
+/**
+ * Clock the current employee out of a ticket.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function stopTimeEntry(ticketId: string): Promise<TimeEntry> {
+	return post<TimeEntry>(`/tickets/${ticketId}/time/stop`);
+}
+
+/**
+ * Get a ticket's bench time entries and total.
+ */
+export async function getTicketTime(ticketId: string): Promise<TicketTimeResponse> {
+	return get<TicketTimeResponse>(`/tickets/${ticketId}/time`);
+}
+
+/**
+ * Get a ticket's appraisal.
+ */
+export async function getAppraisal(ticketId: string): Promise<Appraisal> {
+	return get<Appraisal>(`/tickets/${ticketId}/appraisal`);
+}
+
+/**
+ * Record or revise a ticket's appraisal.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function saveAppraisal(
+	ticketId: string,
+	request: SaveAppraisalRequest
+): Promise<Appraisal> {
+	return put<Appraisal>(`/tickets/${ticketId}/appraisal`, request);
+}
+
+/**
+ * Remove a ticket's appraisal.
+ * Requires X-Employee-ID header (set via setCurrentEmployee).
+ */
+export async function deleteAppraisal(ticketId: string): Promise<DeleteAppraisalResponse> {
+	return del<DeleteAppraisalResponse>(`/tickets/${ticketId}/appraisal`);
+}
+
+/**
+ * Get the appraisal PDF URL for a ticket.
+ */
+export function getAppraisalPdfUrl(ticketId: string): string {
+	return `${config.baseUrl}/tickets/${ticketId}/appraisal.pdf`;
+}
+
+/**
+ * Get the chain-of-custody report PDF URL for a ticket.
+ */
+export function getCustodyReportPdfUrl(ticketId: string): string {
+	return `${config.baseUrl}/tickets/${ticketId}/custody.pdf`;
+}
+
+/**
+ * Get the invoice PDF URL for a closed ticket.
+ */
+export function getInvoicePdfUrl(ticketId: string): string {
+	return `${config.baseUrl}/tickets/${ticketId}/invoice.pdf`;
+}
+
+/**
+ * Get the label PDF URL for a ticket.
+ */
+export function getLabelPdfUrl(ticketId: string): string {
+	return `${config.baseUrl}/tickets/${ticketId}/label.pdf`;
+}
+
+/**
+ * Fetch the receipt PDF as a blob.
+ */
+export async function fetchReceiptPdf(ticketId: string): Promise<Blob> {
+	const url = getReceiptPdfUrl(ticketId);
+	const response = await fetch(url, {
+		method: 'GET',
+		headers: buildHeaders()
+	});
+
+	if (!response.ok) {
+		throw new ApiClientError(
+			'SERVER_ERROR',
+			`Failed to fetch receipt PDF: ${response.status}`,
+			response.status
+		);
+	}
+
+	return response.blob();
+}
+
+/**
+ * Fetch the label PDF as a blob.
+ */
+export async function fetchLabelPdf(ticketId: string): Promise<Blob> {
+	const url = getLabelPdfUrl(ticketId);
+	const response = await fetch(url, {
+		method: 'GET',
+		headers: buildHeaders()
+	});
+
+	if (!response.ok) {
+		throw new ApiClientError(
+			'SERVER_ERROR',
+			`Failed to fetch label PDF: ${response.status}`,
+			response.status
+		);
+	}
+
+	return response.blob();
+}
+
+// =============================================================================
+// Queue Endpoints
+// =============================================================================
+
+/**
+ * Get the workboard queue with tickets grouped by status lane, optionally
+ * only the tickets assigned to one employee.
+ */
+export async function getQueue(employeeId?: string): Promise<GetQueueResponse> {
+	return get<GetQueueResponse>('/queue', employeeId ? { employee_id: employeeId } : undefined);
+}
+
+/**
+ * Set the manual order of a workboard lane.
+ * Requires the modify_any_ticket permission.
+ */
+export async function reorderQueue(
+	lane: keyof QueueLanes,
+	request: ReorderQueueRequest
+): Promise<QueueLane> {
+	return post<QueueLane>(`/queue/${lane}/reorder`, request);
+}
+
+/**
+ * Look up a ticket's status with the token printed on the receipt (no authentication).
+ */
+export async function getPublicTicketStatus(
+	friendlyCode: string,
+	token: string
+): Promise<PublicTicketStatus> {
+	return get<PublicTicketStatus>(
+		`/public/tickets/${encodeURIComponent(friendlyCode)}/status`,
+		{ token }
+	);
+}
+
+// =============================================================================
+// Customer Endpoints (Placeholder - not yet implemented in backend)
+// =============================================================================
+
+/**
+ * List customers with optional search.
+ * Note: This endpoint is not yet implemented in the backend.
+ */
+export async function listCustomers(search?: string): Promise<Customer[]> {
+	const params = search ? { search } : undefined;
+	return get<Customer[]>('/customers', params);
+}
+
+/**
+ * Get customer by ID.
+ * Note: This endpoint is not yet implemented in the backend.
+ */
+export async function getCustomer(customerId: string): Promise<Customer> {
+	return get<Customer>(`/customers/${customerId}`);
+}
+
+/**
+ * Create a new customer.
+ * Note: This endpoint is not yet implemented in the backend.
+ */
+export async function createCustomer(request: CreateCustomerRequest): Promise<Customer> {
+	return post<Customer>('/customers', request);
+}
+
+// =============================================================================
+// Employee Endpoints
+// =============================================================================
+
+/**
+ * List employees (admin only).
+ * Requires active admin session.
+ * By default returns only active employees.
+ * Use includeInactive=true to include inactive employees.
+ */
+export async function listEmployees(includeInactive?: boolean): Promise<ListEmployeesResponse> {
+	const params = includeInactive ? { include_inactive: true } : undefined;
+	return getWithAdmin<ListEmployeesResponse>('/employees', params);
+}
+
+/**
+ * List active employees' IDs and names, for "worked by" pickers.
+ * Needs no authentication.
+ */
+export async function listActiveEmployees(): Promise<ActiveEmployeesResponse> {
+	return get<ActiveEmployeesResponse>('/employees/active');
+}
+
+/**
+ * Get the logged-in employee's profile, with open-ticket counts.
+ */
+export async function getMyProfile(): Promise<EmployeeProfile> {
+	return get<EmployeeProfile>('/employees/me');
+}
+
+/**
+ * Create a new employee (admin only).
+ * Requires active admin session.
+ * Returns the created employee summary.
+ */
+export async function createEmployee(request: CreateEmployeeRequest): Promise<EmployeeSummary> {
+	return post<EmployeeSummary>('/employees', request, true);
+}
+
+/**
+ * Update an employee (admin only).
+ * Requires active admin session.
+ * Returns the updated employee summary.
+ */
+export async function updateEmployee(
+	employeeId: string,
+	request: UpdateEmployeeRequest
+): Promise<EmployeeSummary> {
+	return put<EmployeeSummary>(`/employees/${employeeId}`, request, true);
+}
+
+/**
+ * Delete an employee (admin only).
+ * Requires active admin session.
+ * Returns deletion status with optional warning about history loss.
+ */
+export async function deleteEmployee(employeeId: string): Promise<DeleteEmployeeResponse> {
+	return del<DeleteEmployeeResponse>(`/employees/${employeeId}`, true);
+}
+
+/**
+ * Get an employee's permissions (admin or manage_employees).
+ */
+export async function getEmployeePermissions(employeeId: string): Promise<EmployeePermissions> {
+	return getWithAdmin<EmployeePermissions>(`/employees/${employeeId}/permissions`);
+}
+
+/**
+ * Replace a staff employee's permissions (admin or manage_employees).
+ */
+export async function setEmployeePermissions(
+	employeeId: string,
+	permissions: Permission[]
+): Promise<EmployeePermissions> {
+	return put<EmployeePermissions>(`/employees/${employeeId}/permissions`, { permissions }, true);
+}
+
+/**
+ * Lift an employee's PIN lockout (admin or manage_employees).
+ * Returns the updated employee summary.
+ */
+export async function unlockEmployee(employeeId: string): Promise<EmployeeSummary> {
+	return post<EmployeeSummary>(`/employees/${employeeId}/unlock`, {}, true);
+}
+
+/**
+ * Verify employee PIN and create an employee session.
+ * On success, stores the session token for subsequent employee requests.
+ * Returns employee_id, name, role, session_token, and expires_at if the PIN is valid.
+ * Throws ApiClientError with code 'INVALID_PIN' if the PIN is invalid.
+ * Pass employeeId when the employee is known so wrong PINs count toward their
+ * lockout; throws 'ACCOUNT_LOCKED' once they are locked out.
+ */
+export async function verifyEmployeePin(
+	pin: string,
+	employeeId?: string
+): Promise<VerifyPinResponse> {
+	const response = await post<VerifyPinResponse>('/employees/verify', {
+		pin,
+		employee_id: employeeId
+	});
+	// Store the session token for subsequent requests
+	if (response.session_token) {
+		setEmployeeSession(response.employee_id, response.session_token, response.expires_at);
+	}
+	return response;
+}
+
+/**
+ * Answer a PIN challenge: HMAC-SHA256 of the nonce, keyed with the
+ * PBKDF2-SHA256 key derived from the PIN, hex encoded.
+ */
+async function answerPinChallenge(pin: string, challenge: PinChallenge): Promise<string> {
+	const encoder = new TextEncoder();
+	const pinKey = await crypto.subtle.importKey('raw', encoder.encode(pin), 'PBKDF2', false, [
+		'deriveBits'
+	]);
+	const keyBits = await crypto.subtle.deriveBits(
+		{
+			name: 'PBKDF2',
+			hash: 'SHA-256',
+			salt: encoder.encode(challenge.kdf.salt),
+			iterations: challenge.kdf.iterations
+		},
+		pinKey,
+		256
+	);
+	const hmacKey = await crypto.subtle.importKey(
+		'raw',
+		keyBits,
+		{ name: 'HMAC', hash: 'SHA-256' },
+		false,
+		['sign']
+	);
+	const signature = await crypto.subtle.sign('HMAC', hmacKey, encoder.encode(challenge.nonce));
+	return Array.from(new Uint8Array(signature))
+		.map((b) => b.toString(16).padStart(2, '0'))
+		.join('');
+}
+
+/**
+ * Verify an employee's PIN without sending it, using challenge-response.
+ * On success, stores the session token like verifyEmployeePin.
+ * Throws ApiClientError with code 'INVALID_PIN' if the PIN is invalid, or
+ * 'VALIDATION_ERROR' if the employee's PIN predates challenge mode and hasn't
+ * been set since (use verifyEmployeePin instead).
+ */
+export async function verifyEmployeePinChallenge(
+	employeeId: string,
+	pin: string
+): Promise<VerifyPinResponse> {
+	const challenge = await post<PinChallenge>('/employees/verify/challenge', {
+		employee_id: employeeId
+	});
+	const response = await post<VerifyPinResponse>('/employees/verify/response', {
+		nonce: challenge.nonce,
+		response: await answerPinChallenge(pin, challenge)
+	});
+	if (response.session_token) {
+		setEmployeeSession(response.employee_id, response.session_token, response.expires_at);
+	}
+	return response;
+}
+
+/**
+ * Switch the current employee session into or out of training mode.
+ * While on, the terminal works against training data only.
+ */
+export async function setTrainingMode(enabled: boolean): Promise<TrainingModeResponse> {
+	return put<TrainingModeResponse>('/employees/training', { enabled });
+}
+
+/**
+ * Log out the current employee session.
+ * Invalidates the session on the server and clears local session state.
+ */
+export async function employeeLogout(): Promise<void> {
+	if (employeeSessionToken) {
+		try {
+			// Tell the server to invalidate the session
+			await post<{ success: boolean }>('/employees/logout', undefined, false);
+		} catch {
+			// Ignore errors - we'll clear the local session anyway
+		}
+	}
+	clearEmployeeSession();
+}
+
+/**
+ * Response from admin PIN verification.
+ * Includes a session token for subsequent authenticated requests.
+ */
+export interface AdminVerifyResponse {
+	valid: boolean;
+	session_token: string;
+	expires_at: string;
 }
 
 /**
@@ -739,94 +1514,792 @@ export interface AdminVerifyResponse {
  * Returns { valid: true, session_token: "...", expires_at: "..." } if the PIN is correct.
  * Throws ApiClientError with code 'INVALID_PIN' if incorrect.
  */
-export async function verifyAdminPin(pin: string): Promise<AdminVerifyResponse> {
-	const response = await post<AdminVerifyResponse>('/admin/verify', { pin });
-	// Store the session token for subsequent requests
-	if (response.valid && response.session_token) {
-		setAdminSession(response.session_token, response.expires_at);
-	}
-	return response;
+export async function verifyAdminPin(pin: string): Promise<AdminVerifyResponse> {
+	const response = await post<AdminVerifyResponse>('/admin/verify', { pin });
+	// Store the session token for subsequent requests
+	if (response.valid && response.session_token) {
+		setAdminSession(response.session_token, response.expires_at);
+	}
+	return response;
+}
+
+/**
+ * Log out the current admin session.
+ * Invalidates the session on the server and clears local session state.
+ */
+export async function adminLogout(): Promise<void> {
+	if (adminSessionToken) {
+		try {
+			// Tell the server to invalidate the session
+			await post<{ success: boolean }>('/admin/logout', undefined, true);
+		} catch {
+			// Ignore errors - we'll clear the local session anyway
+		}
+	}
+	clearAdminSession();
+}
+
+/**
+ * Reset a lost admin PIN with the recovery code.
+ * Signs out every admin session and returns a replacement recovery code.
+ * Throws ApiClientError with code 'INVALID_PIN' if the code is wrong or used,
+ * or 'RATE_LIMITED' after too many attempts.
+ */
+export async function recoverAdmin(
+	recoveryCode: string,
+	newPin: string
+): Promise<RecoverAdminResponse> {
+	const response = await post<RecoverAdminResponse>('/admin/recover', {
+		recovery_code: recoveryCode,
+		new_pin: newPin
+	});
+	clearAdminSession();
+	return response;
+}
+
+/**
+ * Issue a new admin recovery code, replacing the current one (admin only).
+ */
+export async function regenerateRecoveryCode(): Promise<{ recovery_code: string }> {
+	return post<{ recovery_code: string }>('/admin/recovery/code', undefined, true);
+}
+
+/**
+ * List admin recovery attempts, newest first (admin only).
+ */
+export async function getRecoveryAttempts(limit?: number): Promise<RecoveryAttemptsResponse> {
+	return getWithAdmin<RecoveryAttemptsResponse>(
+		'/admin/recovery/attempts',
+		limit ? { limit } : undefined
+	);
+}
+
+// =============================================================================
+// Storage Location Endpoints
+// =============================================================================
+
+/**
+ * List all storage locations.
+ * Public endpoint - does not require authentication.
+ * By default returns only active locations.
+ * Use includeInactive=true to include inactive locations.
+ */
+export async function listStorageLocations(
+	includeInactive?: boolean
+): Promise<ListLocationsResponse> {
+	const params = includeInactive ? { include_inactive: true } : undefined;
+	return get<ListLocationsResponse>('/locations', params);
+}
+
+/**
+ * Suggest a storage location for a new item.
+ * Public endpoint - does not require authentication.
+ * Uses the store's location rules, then current occupancy.
+ */
+export async function suggestStorageLocation(
+	itemType?: string,
+	quoteAmount?: string
+): Promise<SuggestLocationResponse> {
+	return get<SuggestLocationResponse>('/locations/suggest', {
+		item_type: itemType || undefined,
+		quote_amount: quoteAmount || undefined
+	});
+}
+
+/**
+ * Create a new storage location (admin only).
+ * Requires active admin session.
+ * Returns the created location summary.
+ */
+export async function createStorageLocation(
+	request: CreateStorageLocationRequest
+): Promise<StorageLocationSummary> {
+	return post<StorageLocationSummary>('/locations', request, true);
+}
+
+/**
+ * Update a storage location (admin only).
+ * Requires active admin session.
+ * Returns the updated location summary.
+ */
+export async function updateStorageLocation(
+	locationId: string,
+	request: UpdateStorageLocationRequest
+): Promise<StorageLocationSummary> {
+	return put<StorageLocationSummary>(`/locations/${locationId}`, request, true);
+}
+
+/**
+ * Deactivate a storage location (admin only).
+ * Fails with CONFLICT while open tickets are stored at or headed to it.
+ * Returns the deactivated location summary.
+ */
+export async function deleteStorageLocation(locationId: string): Promise<StorageLocationSummary> {
+	return del<StorageLocationSummary>(`/locations/${locationId}`, true);
+}
+
+/**
+ * List the open tickets stored at a location.
+ * Public endpoint - does not require authentication.
+ */
+export async function listLocationTickets(locationId: string): Promise<LocationTicketsResponse> {
+	return get<LocationTicketsResponse>(`/locations/${locationId}/tickets`);
+}
+
+// =============================================================================
+// Store Endpoints
+// =============================================================================
+
+/**
+ * List the chain's stores, primary first.
+ * Public endpoint - does not require authentication.
+ */
+export async function listStores(includeInactive?: boolean): Promise<ListStoresResponse> {
+	const params = includeInactive ? { include_inactive: true } : undefined;
+	return get<ListStoresResponse>('/stores', params);
+}
+
+/**
+ * Open a store (admin only).
+ */
+export async function createStore(request: CreateStoreRequest): Promise<Store> {
+	return post<Store>('/stores', request, true);
+}
+
+/**
+ * Close a store (admin only). The primary store can't be closed.
+ */
+export async function deleteStore(storeId: string): Promise<Store> {
+	return del<Store>(`/stores/${storeId}`, true);
+}
+
+// =============================================================================
+// Service Catalog Endpoints
+// =============================================================================
+
+/**
+ * List catalog services by name.
+ * Public endpoint - does not require authentication.
+ */
+export async function listServices(includeInactive?: boolean): Promise<{ services: Service[] }> {
+	const params = includeInactive ? { include_inactive: true } : undefined;
+	return get<{ services: Service[] }>('/services', params);
+}
+
+/**
+ * Add a service to the catalog (admin or manage_settings).
+ */
+export async function createService(request: CreateServiceRequest): Promise<Service> {
+	return post<Service>('/services', request, true);
+}
+
+/**
+ * Update a catalog service (admin or manage_settings).
+ */
+export async function updateService(
+	serviceId: string,
+	request: UpdateServiceRequest
+): Promise<Service> {
+	return put<Service>(`/services/${serviceId}`, request, true);
+}
+
+/**
+ * Deactivate a catalog service (admin or manage_settings).
+ */
+export async function deleteService(serviceId: string): Promise<Service> {
+	return del<Service>(`/services/${serviceId}`, true);
+}
+
+// =============================================================================
+// Materials Endpoints
+// =============================================================================
+
+/**
+ * List materials by name.
+ * Public endpoint - does not require authentication.
+ */
+export async function listMaterials(includeInactive?: boolean): Promise<{ materials: Material[] }> {
+	const params = includeInactive ? { include_inactive: true } : undefined;
+	return get<{ materials: Material[] }>('/materials', params);
+}
+
+/**
+ * Add a material to the inventory (admin or manage_settings).
+ */
+export async function createMaterial(request: CreateMaterialRequest): Promise<Material> {
+	return post<Material>('/materials', request, true);
+}
+
+/**
+ * Update a material, e.g. to restock it (admin or manage_settings).
+ */
+export async function updateMaterial(
+	materialId: string,
+	request: UpdateMaterialRequest
+): Promise<Material> {
+	return put<Material>(`/materials/${materialId}`, request, true);
+}
+
+/**
+ * Deactivate a material (admin or manage_settings).
+ */
+export async function deleteMaterial(materialId: string): Promise<Material> {
+	return del<Material>(`/materials/${materialId}`, true);
+}
+
+// =============================================================================
+// Store Settings Endpoints
+// =============================================================================
+
+/**
+ * Get store settings.
+ * Public endpoint - does not require authentication.
+ */
+export async function getStoreSettings(): Promise<StoreSettings> {
+	return get<StoreSettings>('/settings');
+}
+
+/**
+ * Update store settings.
+ * Requires active admin session.
+ *
+ * @param updates - Partial store settings to update
+ * @returns Updated store settings
+ */
+export async function updateStoreSettings(
+	updates: UpdateStoreSettingsRequest
+): Promise<StoreSettings> {
+	return put<StoreSettings>('/settings', updates, true);
+}
+
+/**
+ * Get one settings section.
+ * Requires active admin session.
+ */
+export async function getSettingsSection(
+	section: SettingsSection
+): Promise<SettingsSectionResponse> {
+	return getWithAdmin<SettingsSectionResponse>(`/settings/${section}`);
+}
+
+/**
+ * Update one settings section.
+ * Requires active admin session.
+ *
+ * @param version - Version from getSettingsSection; a PRECONDITION_FAILED
+ *   error means another admin changed settings in the meantime
+ */
+export async function updateSettingsSection(
+	section: SettingsSection,
+	updates: Record<string, unknown>,
+	version: number
+): Promise<SettingsSectionResponse> {
+	return patch<SettingsSectionResponse>(`/settings/${section}`, updates, true, version);
+}
+
+/**
+ * Get recent settings changes, newest first (admin only).
+ */
+export async function getSettingsHistory(limit?: number): Promise<SettingsHistoryResponse> {
+	return getWithAdmin<SettingsHistoryResponse>('/settings/history', { limit });
+}
+
+/**
+ * Revert a settings change to its previous values (admin only).
+ * The rollback is recorded as a new change.
+ */
+export async function rollbackSettingsChange(changeId: string): Promise<SettingsRollbackResponse> {
+	return post<SettingsRollbackResponse>(`/settings/rollback/${changeId}`, undefined, true);
+}
+
+/**
+ * Upload a PNG or JPEG logo to print on receipts (admin only).
+ * Responds with the updated `printing` section.
+ */
+export async function uploadReceiptLogo(file: File): Promise<SettingsSectionResponse> {
+	// Let the browser set the multipart Content-Type
+	const headers = buildHeaders(true) as Record<string, string>;
+	delete headers['Content-Type'];
+
+	const formData = new FormData();
+	formData.append('logo', file);
+
+	const response = await fetch(buildUrl('/settings/receipt-logo'), {
+		method: 'PUT',
+		headers,
+		body: formData
+	});
+	return parseResponse<SettingsSectionResponse>(response);
+}
+
+/**
+ * Stop printing a logo on receipts (admin only).
+ */
+export async function deleteReceiptLogo(): Promise<SettingsSectionResponse> {
+	return del<SettingsSectionResponse>('/settings/receipt-logo', true);
+}
+
+/**
+ * Issue a new promise date feed token, revoking the old one (admin only).
+ */
+export async function rotateCalendarFeed(): Promise<CalendarFeedResponse> {
+	return post<CalendarFeedResponse>('/settings/calendar-feed', undefined, true);
+}
+
+/**
+ * Turn off the promise date feed (admin only).
+ */
+export async function revokeCalendarFeed(): Promise<StoreSettings> {
+	return del<StoreSettings>('/settings/calendar-feed', true);
+}
+
+/**
+ * Get the configured item types and their intake defaults. With a query,
+ * returns the best matches for typeahead.
+ */
+export async function getItemTypes(
+	query?: string,
+	limit?: number
+): Promise<{ item_types: ItemType[] }> {
+	return get<{ item_types: ItemType[] }>(
+		'/settings/item-types',
+		query ? { q: query, limit } : undefined
+	);
 }
 
 /**
- * Log out the current admin session.
- * Invalidates the session on the server and clears local session state.
+ * Replace the item types, keeping their order (admin only).
  */
-export async function adminLogout(): Promise<void> {
-	if (adminSessionToken) {
-		try {
-			// Tell the server to invalidate the session
-			await post<{ success: boolean }>('/admin/logout', undefined, true);
-		} catch {
-			// Ignore errors - we'll clear the local session anyway
-		}
-	}
-	clearAdminSession();
+export async function updateItemTypes(
+	itemTypes: ItemTypeInput[]
+): Promise<{ item_types: ItemType[] }> {
+	return put<{ item_types: ItemType[] }>('/settings/item-types', { item_types: itemTypes }, true);
 }
 
-// =============================================================================
-// Storage Location Endpoints
-// =============================================================================
+/**
+ * Get the reasons staff can give for moving a promise date later.
+ */
+export async function getPromiseDateReasons(): Promise<{ reasons: PromiseDateReason[] }> {
+	return get<{ reasons: PromiseDateReason[] }>('/settings/promise-date-reasons');
+}
 
 /**
- * List all storage locations.
- * Public endpoint - does not require authentication.
- * By default returns only active locations.
- * Use includeInactive=true to include inactive locations.
+ * Replace the promise date reasons, keeping their order (admin only).
  */
-export async function listStorageLocations(
-	includeInactive?: boolean
-): Promise<ListLocationsResponse> {
-	const params = includeInactive ? { include_inactive: true } : undefined;
-	return get<ListLocationsResponse>('/locations', params);
+export async function updatePromiseDateReasons(
+	reasons: PromiseDateReasonInput[]
+): Promise<{ reasons: PromiseDateReason[] }> {
+	return put<{ reasons: PromiseDateReason[] }>(
+		'/settings/promise-date-reasons',
+		{ reasons },
+		true
+	);
 }
 
 /**
- * Create a new storage location (admin only).
- * Requires active admin session.
- * Returns the created location summary.
+ * Get the rush surcharge tiers (admin only).
  */
-export async function createStorageLocation(
-	request: CreateStorageLocationRequest
-): Promise<StorageLocationSummary> {
-	return post<StorageLocationSummary>('/locations', request, true);
+export async function getRushPricing(): Promise<{ tiers: RushSurchargeTier[] }> {
+	return getWithAdmin<{ tiers: RushSurchargeTier[] }>('/settings/rush-pricing');
 }
 
 /**
- * Update a storage location (admin only).
- * Requires active admin session.
- * Returns the updated location summary.
+ * Replace the rush surcharge tiers (admin only).
  */
-export async function updateStorageLocation(
-	locationId: string,
-	request: UpdateStorageLocationRequest
-): Promise<StorageLocationSummary> {
-	return put<StorageLocationSummary>(`/locations/${locationId}`, request, true);
+export async function updateRushPricing(
+	tiers: RushSurchargeTierInput[]
+): Promise<{ tiers: RushSurchargeTier[] }> {
+	return put<{ tiers: RushSurchargeTier[] }>('/settings/rush-pricing', { tiers }, true);
 }
 
-// =============================================================================
-// Store Settings Endpoints
-// =============================================================================
+/**
+ * Export the store configuration as a bundle (admin only).
+ */
+export async function exportConfig(): Promise<ConfigBundle> {
+	return getWithAdmin<ConfigBundle>('/admin/config/export');
+}
 
 /**
- * Get store settings.
- * Public endpoint - does not require authentication.
+ * Import a configuration bundle (admin only). Parts left out are unchanged.
  */
-export async function getStoreSettings(): Promise<StoreSettings> {
-	return get<StoreSettings>('/settings');
+export async function importConfig(bundle: ConfigBundle): Promise<ConfigImportResponse> {
+	return post<ConfigImportResponse>('/admin/config/import', bundle, true);
 }
 
 /**
- * Update store settings.
- * Requires active admin session.
- *
- * @param updates - Partial store settings to update
- * @returns Updated store settings
+ * Upload a CSV file to an import endpoint with admin authentication.
  */
-export async function updateStoreSettings(
-	updates: UpdateStoreSettingsRequest
-): Promise<StoreSettings> {
-	return put<StoreSettings>('/settings', updates, true);
+async function postCsvImport(path: string, file: File, options: ImportOptions): Promise<ImportSummary> {
+	const url = buildUrl(path, options as Record<string, unknown>);
+	// Let the browser set the multipart Content-Type
+	const headers = buildHeaders(true) as Record<string, string>;
+	delete headers['Content-Type'];
+
+	const formData = new FormData();
+	formData.append('file', file);
+
+	const response = await fetch(url, { method: 'POST', headers, body: formData });
+	return parseResponse<ImportSummary>(response);
+}
+
+/**
+ * Import customers from a CSV file (admin only).
+ */
+export async function importCustomers(
+	file: File,
+	options: ImportOptions = {}
+): Promise<ImportSummary> {
+	return postCsvImport('/admin/import/customers', file, options);
+}
+
+/**
+ * Import tickets from a CSV file (admin only). Requires `taken_in_by`.
+ */
+export async function importTickets(file: File, options: ImportOptions): Promise<ImportSummary> {
+	return postCsvImport('/admin/import/tickets', file, options);
+}
+
+/**
+ * Run the integrity checks and list problems found (admin only).
+ */
+export async function getIntegrityReport(): Promise<IntegrityReport> {
+	return getWithAdmin<IntegrityReport>('/admin/integrity');
+}
+
+/**
+ * List background jobs with their last and next runs (admin only).
+ */
+export async function getJobStatuses(): Promise<JobStatus[]> {
+	return getWithAdmin<JobStatus[]>('/admin/jobs');
+}
+
+/**
+ * Delete photo objects no photo record points at (admin only).
+ */
+export async function reconcileStorage(): Promise<StorageReconcileReport> {
+	return post<StorageReconcileReport>('/admin/storage/reconcile', undefined, true);
+}
+
+/**
+ * List partner jeweler accounts (admin only).
+ */
+export async function listPartners(): Promise<ListPartnersResponse> {
+	return getWithAdmin<ListPartnersResponse>('/admin/partners');
+}
+
+/**
+ * Create a partner account (admin only). The API key is only returned here.
+ */
+export async function createPartner(request: CreatePartnerRequest): Promise<PartnerKeyResponse> {
+	return post<PartnerKeyResponse>('/admin/partners', request, true);
+}
+
+/**
+ * Update a partner account (admin only). Set is_active false to revoke access.
+ */
+export async function updatePartner(
+	partnerId: string,
+	request: UpdatePartnerRequest
+): Promise<Partner> {
+	return put<Partner>(`/admin/partners/${partnerId}`, request, true);
+}
+
+/**
+ * Issue a new API key for a partner (admin only). The old key stops working.
+ */
+export async function rotatePartnerKey(partnerId: string): Promise<PartnerKeyResponse> {
+	return post<PartnerKeyResponse>(`/admin/partners/${partnerId}/rotate-key`, undefined, true);
+}
+
+/**
+ * List webhooks (admin only).
+ */
+export async function listWebhooks(): Promise<ListWebhooksResponse> {
+	return getWithAdmin<ListWebhooksResponse>('/admin/webhooks');
+}
+
+/**
+ * Register a webhook (admin only). The signing secret is only returned here.
+ */
+export async function createWebhook(
+	request: CreateWebhookRequest
+): Promise<WebhookSecretResponse> {
+	return post<WebhookSecretResponse>('/admin/webhooks', request, true);
+}
+
+/**
+ * Update a webhook (admin only). Set is_active false to pause deliveries.
+ */
+export async function updateWebhook(
+	webhookId: string,
+	request: UpdateWebhookRequest
+): Promise<Webhook> {
+	return put<Webhook>(`/admin/webhooks/${webhookId}`, request, true);
+}
+
+/**
+ * Delete a webhook and its queued deliveries (admin only).
+ */
+export async function deleteWebhook(webhookId: string): Promise<{ deleted: boolean }> {
+	return del<{ deleted: boolean }>(`/admin/webhooks/${webhookId}`, true);
+}
+
+/**
+ * Issue a new signing secret for a webhook (admin only).
+ */
+export async function rotateWebhookSecret(webhookId: string): Promise<WebhookSecretResponse> {
+	return post<WebhookSecretResponse>(
+		`/admin/webhooks/${webhookId}/rotate-secret`,
+		undefined,
+		true
+	);
+}
+
+/**
+ * List a webhook's deliveries, newest first (admin only).
+ */
+export async function listWebhookDeliveries(
+	webhookId: string,
+	params?: { status?: WebhookDeliveryStatus; limit?: number; offset?: number }
+): Promise<WebhookDeliveriesResponse> {
+	return getWithAdmin<WebhookDeliveriesResponse>(
+		`/admin/webhooks/${webhookId}/deliveries`,
+		params
+	);
+}
+
+/**
+ * Send a delivered or failed webhook event again (admin only).
+ */
+export async function redeliverWebhookDelivery(
+	webhookId: string,
+	deliveryId: string
+): Promise<WebhookDelivery> {
+	return post<WebhookDelivery>(
+		`/admin/webhooks/${webhookId}/deliveries/${deliveryId}/redeliver`,
+		undefined,
+		true
+	);
+}
+
+/**
+ * Count a campaign's recipients and render its message for a sample customer (admin only).
+ */
+export async function previewCampaign(request: CreateCampaignRequest): Promise<CampaignPreview> {
+	return post<CampaignPreview>('/admin/campaigns/preview', request, true);
+}
+
+/**
+ * Create a campaign and queue it for sending (admin only).
+ */
+export async function createCampaign(request: CreateCampaignRequest): Promise<Campaign> {
+	return post<Campaign>('/admin/campaigns', request, true);
+}
+
+/**
+ * List campaigns with their progress, newest first (admin only).
+ */
+export async function listCampaigns(): Promise<ListCampaignsResponse> {
+	return getWithAdmin<ListCampaignsResponse>('/admin/campaigns');
+}
+
+/**
+ * Get a campaign with its progress (admin only).
+ */
+export async function getCampaign(campaignId: string): Promise<Campaign> {
+	return getWithAdmin<Campaign>(`/admin/campaigns/${campaignId}`);
+}
+
+/**
+ * List a campaign's recipients and their delivery status (admin only).
+ */
+export async function getCampaignRecipients(
+	campaignId: string,
+	params?: { status?: CampaignRecipientStatus }
+): Promise<CampaignRecipientsResponse> {
+	return getWithAdmin<CampaignRecipientsResponse>(
+		`/admin/campaigns/${campaignId}/recipients`,
+		params
+	);
+}
+
+/**
+ * Stop sending a campaign; unsent recipients are cancelled (admin only).
+ */
+export async function abortCampaign(campaignId: string): Promise<Campaign> {
+	return post<Campaign>(`/admin/campaigns/${campaignId}/abort`, undefined, true);
+}
+
+/**
+ * List admin actions, newest first (admin only).
+ */
+export async function getAuditLog(params?: AuditLogParams): Promise<AuditLogResponse> {
+	return getWithAdmin<AuditLogResponse>('/admin/audit-log', params as Record<string, unknown>);
+}
+
+/**
+ * Trade work and API usage by partner (admin only).
+ */
+export async function getPartnerReport(params?: {
+	from_date?: string;
+	to_date?: string;
+}): Promise<PartnerReport> {
+	return getWithAdmin<PartnerReport>('/reports/partners', params);
+}
+
+/**
+ * Tickets, prices, and turnaround by catalog service (admin only).
+ */
+export async function getServiceReport(params?: {
+	from_date?: string;
+	to_date?: string;
+}): Promise<ServiceReport> {
+	return getWithAdmin<ServiceReport>('/reports/services', params);
+}
+
+/**
+ * Revenue from closed tickets (admin only).
+ */
+export async function getRevenueReport(params?: ReportRangeParams): Promise<RevenueReport> {
+	return getWithAdmin<RevenueReport>('/reports/revenue', params as Record<string, unknown>);
+}
+
+/**
+ * Tickets taken in, worked, and closed per employee (admin only).
+ */
+export async function getEmployeeReport(params?: {
+	from_date?: string;
+	to_date?: string;
+}): Promise<EmployeeReport> {
+	return getWithAdmin<EmployeeReport>('/reports/employees', params);
+}
+
+/**
+ * Tickets received and closed, with turnaround (admin only).
+ */
+export async function getThroughputReport(params?: ReportRangeParams): Promise<ThroughputReport> {
+	return getWithAdmin<ThroughputReport>('/reports/throughput', params as Record<string, unknown>);
+}
+
+/**
+ * Average lane, overdue, and rush counts from queue snapshots (admin only).
+ */
+export async function getQueueTrendsReport(params?: ReportRangeParams): Promise<QueueTrendReport> {
+	return getWithAdmin<QueueTrendReport>('/reports/queue-trends', params as Record<string, unknown>);
+}
+
+/**
+ * Open tickets due today and overdue, as in the morning digest (admin only).
+ */
+export async function getOverdueReport(): Promise<OverdueReport> {
+	return getWithAdmin<OverdueReport>('/reports/overdue');
+}
+
+/**
+ * Items out at benches and movements in and out of storage (admin only).
+ */
+export async function getLocationAuditReport(
+	params?: LocationAuditParams
+): Promise<LocationAuditReport> {
+	return getWithAdmin<LocationAuditReport>(
+		'/reports/location-audit',
+		params as Record<string, unknown>
+	);
+}
+
+/**
+ * The current store's end-of-day summary; defaults to today (admin only).
+ */
+export async function getDailyCloseoutReport(date?: string): Promise<DailyCloseoutReport> {
+	return getWithAdmin<DailyCloseoutReport>('/reports/daily-closeout', date ? { date } : undefined);
+}
+
+/**
+ * Fetch the daily closeout as a PDF blob (admin only).
+ */
+export async function fetchDailyCloseoutPdf(date?: string): Promise<Blob> {
+	const url = buildUrl('/reports/daily-closeout.pdf', date ? { date } : undefined);
+	const response = await fetch(url, {
+		method: 'GET',
+		headers: buildHeaders(true)
+	});
+
+	if (!response.ok) {
+		throw new ApiClientError(
+			'SERVER_ERROR',
+			`Failed to fetch closeout PDF: ${response.status}`,
+			response.status
+		);
+	}
+
+	return response.blob();
+}
+
+/**
+ * List saved custom reports (admin only).
+ */
+export async function listCustomReports(): Promise<ListReportDefinitionsResponse> {
+	return getWithAdmin<ListReportDefinitionsResponse>('/reports/custom');
+}
+
+/**
+ * Save a custom report (admin only).
+ */
+export async function createCustomReport(
+	request: ReportDefinitionRequest
+): Promise<ReportDefinition> {
+	return post<ReportDefinition>('/reports/custom', request, true);
+}
+
+/**
+ * Replace a custom report (admin only).
+ */
+export async function updateCustomReport(
+	reportId: string,
+	request: ReportDefinitionRequest
+): Promise<ReportDefinition> {
+	return put<ReportDefinition>(`/reports/custom/${reportId}`, request, true);
+}
+
+/**
+ * Delete a custom report (admin only).
+ */
+export async function deleteCustomReport(reportId: string): Promise<{ deleted: boolean }> {
+	return del<{ deleted: boolean }>(`/reports/custom/${reportId}`, true);
+}
+
+/**
+ * Run a custom report over a date range (admin only).
+ */
+export async function runCustomReport(
+	reportId: string,
+	params?: { from_date?: string; to_date?: string }
+): Promise<CustomReport> {
+	return getWithAdmin<CustomReport>(`/reports/custom/${reportId}`, params);
+}
+
+/**
+ * Fetch a custom report as a CSV blob (admin only).
+ */
+export async function fetchCustomReportCsv(
+	reportId: string,
+	params?: { from_date?: string; to_date?: string }
+): Promise<Blob> {
+	const url = buildUrl(`/reports/custom/${reportId}.csv`, params);
+	const response = await fetch(url, {
+		method: 'GET',
+		headers: buildHeaders(true)
+	});
+
+	if (!response.ok) {
+		throw new ApiClientError(
+			'SERVER_ERROR',
+			`Failed to fetch report CSV: ${response.status}`,
+			response.status
+		);
+	}
+
+	return response.blob();
 }
 
 // =============================================================================
@@ -923,6 +2396,9 @@ export async function uploadTicketPhoto(
 			// Deprecated: X-Employee-ID header for backward compatibility
 			xhr.setRequestHeader('X-Employee-ID', currentEmployeeId);
 		}
+		if (currentStoreId) {
+			xhr.setRequestHeader('X-Store-ID', currentStoreId);
+		}
 
 		xhr.send(formData);
 	});
@@ -942,38 +2418,214 @@ export type {
 	TicketDetailResponse,
 	CreateTicketRequest,
 	CreateTicketResponse,
+	IntakeDraft,
+	IntakeDraftStatus,
+	ConvertIntakeDraftRequest,
+	ConvertIntakeDraftResponse,
 	UpdateTicketRequest,
 	GetQueueResponse,
+	PublicTicketStatus,
 	ChangeStatusRequest,
 	ChangeStatusResponse,
+	BulkStatusRequest,
+	BulkStatusResponse,
+	BulkStatusResult,
 	CloseTicketRequest,
 	CloseTicketResponse,
+	ReopenTicketRequest,
+	ReopenTicketResponse,
+	DeleteTicketResponse,
+	RestoreTicketResponse,
+	CustodyChainResponse,
+	TicketHistoryResponse,
+	PaymentKind,
+	PaymentMethod,
+	PaymentInput,
+	RecordPaymentRequest,
+	RecordPaymentResponse,
+	TicketPayment,
+	TicketPaymentEntry,
+	TicketPaymentsResponse,
+	TicketContact,
+	ContactChannel,
+	ContactDirection,
+	ContactOutcome,
+	LogContactRequest,
+	TicketAuthorizedPickup,
+	CreateAuthorizedPickupRequest,
+	TicketPickupEntry,
+	PickupInput,
+	TicketPickupsResponse,
+	CustodyEvent,
+	CustodyEventEntry,
+	CustodyEventType,
+	CustodyWitness,
+	TicketTransfer,
+	TicketTransferEntry,
+	TransferStatus,
+	CreateTransferRequest,
+	ResolveTransferRequest,
+	TicketTransfersResponse,
+	ListTransfersParams,
+	ListTransfersResponse,
+	TimeEntry,
+	TimeEntryEntry,
+	TicketTimeResponse,
+	Appraisal,
+	SaveAppraisalRequest,
+	DeleteAppraisalResponse,
+	TicketMovement,
+	TicketMovementEntry,
+	CurrentLocation,
+	RecordMovementRequest,
+	TicketMovementsResponse,
+	ItemType,
+	ItemTypeInput,
+	PromiseDateReason,
+	PromiseDateReasonInput,
+	RushSurchargeKind,
+	RushSurchargeTier,
+	RushSurchargeTierInput,
+	QuoteRequest,
+	QuoteLine,
+	QuoteBreakdown,
+	PromiseDateSuggestion,
+	QuoteChannel,
+	QuoteDecisionRequest,
+	QuoteEvent,
+	QuoteStatus,
+	SendQuoteRequest,
+	TicketQuoteResponse,
 	TicketStatus,
 	Customer,
 	CreateCustomerRequest,
 	EmployeeSummary,
 	EmployeeInfo,
 	VerifyPinResponse,
+	PinChallenge,
+	TrainingModeResponse,
 	StorageLocation,
 	StorageLocationSummary,
 	ListLocationsResponse,
+	LocationTicketsResponse,
+	TicketSummary,
+	SuggestLocationResponse,
 	CreateStorageLocationRequest,
 	UpdateStorageLocationRequest,
 	StoreSettings,
 	UpdateStoreSettingsRequest,
+	SettingsSection,
+	SettingsSectionResponse,
+	PrintingSettings,
+	HoursSettings,
+	OpeningHours,
+	CalendarFeedResponse,
+	SettingsChange,
+	SettingsHistoryResponse,
+	SettingsRollbackResponse,
+	ConfigBundle,
+	ConfigImportResponse,
+	ImportedRow,
+	ImportOptions,
+	ImportSummary,
+	RejectedRow,
+	IntegrityCheck,
+	IntegrityIssue,
+	IntegrityReport,
+	JobStatus,
+	StorageReconcileReport,
+	RecoverAdminResponse,
+	AdminRecoveryAttempt,
+	RecoveryAttemptsResponse,
+	Partner,
+	PartnerKeyResponse,
+	ListPartnersResponse,
+	CreatePartnerRequest,
+	UpdatePartnerRequest,
+	PartnerActivity,
+	PartnerReport,
+	Store,
+	CreateStoreRequest,
+	ListStoresResponse,
+	Service,
+	CreateServiceRequest,
+	UpdateServiceRequest,
+	TicketService,
+	ServiceUsage,
+	Material,
+	CreateMaterialRequest,
+	UpdateMaterialRequest,
+	TicketMaterialEntry,
+	TicketMaterialsResponse,
+	RecordMaterialRequest,
+	RecordMaterialResponse,
+	ServiceReport,
+	WebhookEvent,
+	Webhook,
+	WebhookSecretResponse,
+	ListWebhooksResponse,
+	CreateWebhookRequest,
+	UpdateWebhookRequest,
+	WebhookDeliveryStatus,
+	WebhookDelivery,
+	WebhookDeliveriesResponse,
+	CampaignStatus,
+	CampaignRecipientStatus,
+	CampaignSegment,
+	CreateCampaignRequest,
+	CampaignPreview,
+	CampaignProgress,
+	Campaign,
+	ListCampaignsResponse,
+	CampaignRecipient,
+	CampaignRecipientsResponse,
+	AuditAction,
+	AuditAuthMethod,
+	AuditLogEntry,
+	AuditLogParams,
+	AuditLogResponse,
+	EmployeeProductivity,
+	EmployeeReport,
+	ReportInterval,
+	ReportRangeParams,
+	RevenueCounts,
+	RevenueReport,
+	ThroughputCounts,
+	ThroughputReport,
+	QueueSnapshot,
+	QueueTrendCounts,
+	QueueTrendReport,
+	DueTicket,
+	OverdueReport,
+	LocationAuditParams,
+	LocationAuditReport,
+	DailyCloseoutReport,
+	ReportDimension,
+	ReportMeasure,
+	ReportDefinition,
+	ReportDefinitionRequest,
+	ListReportDefinitionsResponse,
+	CustomReport,
 	EmployeeAttribution,
 	TicketCustomer,
 	TicketStorageLocation,
 	TicketPhoto,
+	TicketItem,
+	CreateTicketItem,
 	TicketNote,
 	TicketStatusHistoryEntry,
 	QueueLane,
 	QueueLanes,
+	ReorderQueueRequest,
 	PaginationInfo,
 	InlineCustomer,
 	EmployeeRole,
 	CreateEmployeeRequest,
 	UpdateEmployeeRequest,
 	ListEmployeesResponse,
-	DeleteEmployeeResponse
+	ActiveEmployeesResponse,
+	EmployeeProfile,
+	DeleteEmployeeResponse,
+	Permission,
+	EmployeePermissions
 } from '$lib/types/api';
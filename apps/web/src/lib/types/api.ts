@@ -23,6 +23,8 @@ export interface ApiResponse<T> {
 export interface ApiError {
 	code: string;
 	message: string;
+	/** Server request ID, also returned in the X-Request-ID header */
+	request_id?: string;
 }
 
 /**
@@ -34,9 +36,12 @@ export const ErrorCodes = {
 	FORBIDDEN: 'FORBIDDEN',
 	NOT_FOUND: 'NOT_FOUND',
 	CONFLICT: 'CONFLICT',
+	PRECONDITION_FAILED: 'PRECONDITION_FAILED',
 	PHOTO_LIMIT: 'PHOTO_LIMIT',
 	PRINT_REQUIRED: 'PRINT_REQUIRED',
-	SERVER_ERROR: 'SERVER_ERROR'
+	SERVER_ERROR: 'SERVER_ERROR',
+	SERVICE_UNAVAILABLE: 'SERVICE_UNAVAILABLE',
+	TIMEOUT: 'TIMEOUT'
 } as const;
 
 export type ErrorCode = (typeof ErrorCodes)[keyof typeof ErrorCodes];
@@ -71,8 +76,15 @@ export interface Ticket {
 	is_rush: boolean;
 	promise_date: string | null; // ISO date string (YYYY-MM-DD)
 	storage_location_id: string;
+	/** Store holding the ticket (its storage location's store) */
+	store_id: string;
 	quote_amount: string | null; // Decimal as string for precision
 	actual_amount: string | null;
+	/** Part of quote_amount charged for a rush job */
+	rush_surcharge: string | null;
+	quote_status: QuoteStatus;
+	weight_grams: string | null; // Decimal as string
+	metal_type: string | null;
 	taken_in_by: string;
 	worked_by: string | null;
 	closed_by: string | null;
@@ -81,6 +93,7 @@ export interface Ticket {
 	updated_at: string;
 	closed_at: string | null;
 	queue_position: number | null;
+	is_training: boolean; // Created in training mode
 }
 
 /**
@@ -98,7 +111,9 @@ export interface QueueTicket {
 	promise_date: string | null;
 	quote_amount: string | null;
 	created_at: string;
+	queue_position: number | null; // Manual order within the lane
 	is_overdue: boolean;
+	deleted_at?: string | null; // Only present when include_deleted is set
 }
 
 /**
@@ -109,6 +124,8 @@ export interface PaginationInfo {
 	limit: number;
 	offset: number;
 	has_more: boolean;
+	/** Present with cursor pagination while more results remain */
+	next_cursor?: string;
 }
 
 /**
@@ -119,6 +136,16 @@ export interface ListTicketsResponse {
 	pagination: PaginationInfo;
 }
 
+/**
+ * Fields tickets can be sorted by.
+ */
+export type TicketSortField = 'promise_date' | 'created_at' | 'quote_amount' | 'customer_name';
+
+/**
+ * A ticket sort: a field, ascending, or "-" prefixed for descending.
+ */
+export type TicketSort = TicketSortField | `-${TicketSortField}`;
+
 /**
  * Query parameters for listing tickets.
  */
@@ -127,11 +154,24 @@ export interface ListTicketsParams {
 	is_rush?: boolean;
 	search?: string;
 	customer_id?: string;
+	/** Only tickets assigned to this employee */
+	worked_by?: string;
+	/** Only tickets this employee took in */
+	taken_in_by?: string;
 	from_date?: string; // ISO datetime
 	to_date?: string;
 	include_archived?: boolean;
+	/** Include tickets held at every store, not just the current one */
+	all_stores?: boolean;
+	include_deleted?: boolean; // Admin only
+	/** Sort field, "-" prefixed for descending (e.g. "-promise_date") */
+	sort?: TicketSort;
 	limit?: number;
 	offset?: number;
+	/** "cursor" lists oldest first and returns next_cursor */
+	pagination?: 'offset' | 'cursor';
+	/** next_cursor from the previous page */
+	cursor?: string;
 }
 
 // =============================================================================
@@ -174,6 +214,33 @@ export interface TicketPhoto {
 	uploaded_by: EmployeeAttribution;
 }
 
+/**
+ * One item on a ticket. Item 1 matches the ticket's own item fields.
+ */
+export interface TicketItem {
+	item_id: string;
+	ticket_id: string;
+	position: number;
+	item_type: string | null;
+	item_description: string;
+	condition_notes: string;
+	requested_work: string;
+	quote_amount: string | null;
+	created_at: string;
+	updated_at: string;
+}
+
+/**
+ * One item of a new multi-item ticket.
+ */
+export interface CreateTicketItem {
+	item_type?: string | null;
+	item_description: string;
+	condition_notes: string;
+	requested_work: string;
+	quote_amount?: string | null;
+}
+
 /**
  * Note info in ticket detail response.
  */
@@ -184,6 +251,46 @@ export interface TicketNote {
 	created_by: EmployeeAttribution;
 }
 
+/**
+ * How the customer was contacted.
+ */
+export type ContactChannel = 'phone' | 'sms' | 'email' | 'in_person';
+
+/**
+ * Who started a contact: staff (outbound) or the customer (inbound).
+ */
+export type ContactDirection = 'outbound' | 'inbound';
+
+/**
+ * How a contact turned out.
+ */
+export type ContactOutcome = 'reached' | 'left_message' | 'no_answer' | 'wrong_number';
+
+/**
+ * A logged call or message with the customer.
+ */
+export interface TicketContact {
+	contact_id: string;
+	ticket_id: string;
+	channel: ContactChannel;
+	direction: ContactDirection;
+	outcome: ContactOutcome;
+	notes: string | null;
+	contacted_by: string;
+	contacted_by_name: string;
+	contacted_at: string;
+}
+
+/**
+ * Request body for POST /tickets/:ticket_id/contacts.
+ */
+export interface LogContactRequest {
+	channel: ContactChannel;
+	direction: ContactDirection;
+	outcome: ContactOutcome;
+	notes?: string;
+}
+
 /**
  * Status history entry in ticket detail response.
  */
@@ -194,6 +301,27 @@ export interface TicketStatusHistoryEntry {
 	changed_by: EmployeeAttribution;
 }
 
+/**
+ * Customer notification delivery status.
+ */
+export type NotificationStatus = 'pending' | 'sent' | 'failed' | 'skipped';
+
+/**
+ * Customer notification in ticket detail response.
+ */
+export interface TicketNotification {
+	notification_id: string;
+	channel: 'sms' | 'email';
+	recipient: string | null;
+	subject: string | null;
+	message: string;
+	status: NotificationStatus;
+	provider: string | null;
+	error: string | null;
+	created_at: string;
+	sent_at: string | null;
+}
+
 /**
  * Full ticket detail response.
  */
@@ -204,22 +332,53 @@ export interface TicketDetailResponse {
 	is_rush: boolean;
 	customer: TicketCustomer;
 	item_type: string | null;
+	/** Configured defaults when item_type matches an item type */
+	item_type_config: ItemType | null;
 	item_description: string;
 	condition_notes: string;
 	requested_work: string;
+	/** Items on the ticket, in order */
+	items: TicketItem[];
+	/** Catalog services picked at intake, with their prices then */
+	services: TicketService[];
 	promise_date: string | null;
 	storage_location: TicketStorageLocation;
+	/** Where the item is now: its storage location or an employee's bench */
+	current_location: CurrentLocation;
+	/** Transfer the item is in transit on, if any */
+	pending_transfer: TicketTransferEntry | null;
 	quote_amount: string | null;
 	actual_amount: string | null;
+	rush_surcharge: string | null;
+	quote_status: QuoteStatus;
+	/** Quote approval steps, oldest first */
+	quote_events: QuoteEvent[];
+	weight_grams: string | null;
+	metal_type: string | null;
+	/** Internal estimate from the metal price table; never shown to customers */
+	melt_value_estimate: string | null;
 	photos: TicketPhoto[];
 	notes: TicketNote[];
+	/** Calls and messages with the customer, oldest first */
+	contacts: TicketContact[];
 	status_history: TicketStatusHistoryEntry[];
+	notifications: TicketNotification[];
+	/** People the customer has authorized to collect the item */
+	authorized_pickups: TicketAuthorizedPickup[];
+	/** Releases of the item, oldest first */
+	pickups: TicketPickupEntry[];
+	/** Total clocked-in time, counting running entries up to now */
+	bench_seconds: number;
+	/** Oldest first */
+	time_entries: TimeEntryEntry[];
 	taken_in_by: EmployeeAttribution;
 	worked_by: EmployeeAttribution | null;
 	closed_by: EmployeeAttribution | null;
 	created_at: string;
 	updated_at: string;
 	closed_at: string | null;
+	deleted_at?: string | null; // Only present when include_deleted is set
+	is_training: boolean;
 }
 
 // =============================================================================
@@ -242,13 +401,27 @@ export interface CreateTicketRequest {
 	customer_id?: string;
 	customer?: InlineCustomer;
 	item_type?: string | null;
-	item_description: string;
-	condition_notes: string;
-	requested_work: string;
+	/** Item fields of a single-item ticket; omit when sending items */
+	item_description?: string;
+	condition_notes?: string;
+	requested_work?: string;
+	/** Items of a multi-item ticket, in place of the item fields above */
+	items?: CreateTicketItem[];
 	is_rush?: boolean;
 	promise_date?: string | null;
 	storage_location_id: string;
+	/** Defaults to the sum of the item quotes */
 	quote_amount?: string | null;
+	/** Part of quote_amount; see quoteTicket */
+	rush_surcharge?: string | null;
+	weight_grams?: string | null;
+	metal_type?: string | null;
+	/** Required when the quote meets the store's custody threshold */
+	custody?: CustodyWitness;
+	/** Deposit taken at intake */
+	deposit?: PaymentInput;
+	/** Catalog services; fill in requested work, quote, and promise date when not sent */
+	service_ids?: string[];
 }
 
 /**
@@ -257,6 +430,52 @@ export interface CreateTicketRequest {
 export interface CreateTicketResponse extends Ticket {
 	receipt_url: string;
 	label_url: string;
+	/** Present when a deposit was taken at intake */
+	deposit?: TicketPayment;
+}
+
+/**
+ * Review state of an intake draft.
+ */
+export type IntakeDraftStatus = 'pending' | 'converted' | 'dismissed';
+
+/**
+ * A repair request from an integration (e.g., a transcribed phone call)
+ * waiting to be confirmed into a ticket.
+ */
+export interface IntakeDraft {
+	draft_id: string;
+	source: 'phone';
+	/** The sending service's ID for the call */
+	external_id: string | null;
+	customer_name: string | null;
+	customer_phone: string | null;
+	requested_work: string | null;
+	transcript: string | null;
+	recording_url: string | null;
+	received_at: string;
+	status: IntakeDraftStatus;
+	/** The ticket created from the draft, once converted */
+	ticket_id: string | null;
+	resolved_by: string | null;
+	resolved_at: string | null;
+	created_at: string;
+}
+
+/**
+ * Request body for converting a draft. Same as CreateTicketRequest; the
+ * customer and requested_work default to the draft's.
+ */
+export type ConvertIntakeDraftRequest = Omit<CreateTicketRequest, 'requested_work'> & {
+	requested_work?: string;
+};
+
+/**
+ * Response for POST /intake-drafts/:draft_id/convert.
+ */
+export interface ConvertIntakeDraftResponse {
+	draft: IntakeDraft;
+	ticket: CreateTicketResponse;
 }
 
 /**
@@ -269,10 +488,17 @@ export interface UpdateTicketRequest {
 	requested_work?: string;
 	is_rush?: boolean;
 	promise_date?: string | null;
+	/** Reason code for moving promise_date later (required when notifications are on) */
+	promise_date_reason?: string;
 	storage_location_id?: string;
 	quote_amount?: string | null;
 	actual_amount?: string | null;
+	rush_surcharge?: string | null;
 	worked_by_employee_id?: string | null;
+	weight_grams?: string | null;
+	metal_type?: string | null;
+	/** Required when moving a high-value item to another location */
+	custody?: CustodyWitness;
 }
 
 // =============================================================================
@@ -287,6 +513,13 @@ export interface QueueLane {
 	tickets: QueueTicket[];
 }
 
+/**
+ * Request to reorder a workboard lane: the new order, or a single move.
+ */
+export type ReorderQueueRequest =
+	| { ticket_ids: string[] }
+	| { move: { ticket_id: string; after: string | null } };
+
 /**
  * All lanes in the workboard queue.
  */
@@ -304,6 +537,19 @@ export interface GetQueueResponse {
 	lanes: QueueLanes;
 }
 
+/**
+ * Redacted ticket status from the public lookup (no authentication).
+ */
+export interface PublicTicketStatus {
+	friendly_code: string;
+	status: TicketStatus;
+	promise_date: string | null;
+	/** Expected finish window; null once ready or while waiting on parts */
+	estimated_completion: { earliest: string; latest: string } | null;
+	store_name: string;
+	store_phone: string | null;
+}
+
 // =============================================================================
 // Status Change Types
 // =============================================================================
@@ -322,6 +568,35 @@ export interface ChangeStatusResponse extends Ticket {
 	previous_status: TicketStatus;
 }
 
+/**
+ * Request body for changing the status of several tickets.
+ */
+export interface BulkStatusRequest {
+	ticket_ids: string[];
+	status: TicketStatus;
+}
+
+/**
+ * Outcome for one ticket in a bulk status change.
+ */
+export interface BulkStatusResult {
+	ticket_id: string;
+	success: boolean;
+	friendly_code?: string;
+	previous_status?: TicketStatus;
+	error?: ApiError;
+}
+
+/**
+ * Response for a bulk status change.
+ */
+export interface BulkStatusResponse {
+	status: TicketStatus;
+	succeeded: number;
+	failed: number;
+	results: BulkStatusResult[];
+}
+
 // =============================================================================
 // Close Ticket Types
 // =============================================================================
@@ -331,193 +606,2124 @@ export interface ChangeStatusResponse extends Ticket {
  */
 export interface CloseTicketRequest {
 	actual_amount: string; // Decimal as string
-}
-
-/**
- * Response for a closed ticket.
- */
-export interface CloseTicketResponse extends Ticket {
-	previous_status: TicketStatus;
+	/** Required when releasing a high-value item */
+	custody?: CustodyWitness;
+	/** Final payment taken at pickup */
+	payment?: PaymentInput;
+	/** Close even though payments don't cover actual_amount */
+	allow_balance_due?: boolean;
+	/** Who collected the item (defaults to the customer) */
+	pickup?: PickupInput;
 }
 
 // =============================================================================
-// Customer Types
+// Authorized Pickup Types
 // =============================================================================
 
 /**
- * Full customer entity.
+ * A person the customer has authorized to collect a ticket's item.
  */
-export interface Customer {
-	customer_id: string;
+export interface TicketAuthorizedPickup {
+	authorized_pickup_id: string;
+	ticket_id: string;
 	name: string;
+	relationship: string | null;
 	phone: string | null;
-	email: string | null;
+	notes: string | null;
+	added_by: string;
 	created_at: string;
-	updated_at: string;
+	/** Set when the authorization was withdrawn */
+	revoked_at: string | null;
+	revoked_by: string | null;
 }
 
 /**
- * Request for creating a customer.
+ * Request body for POST /tickets/:ticket_id/authorized-pickups.
  */
-export interface CreateCustomerRequest {
+export interface CreateAuthorizedPickupRequest {
 	name: string;
-	phone?: string | null;
-	email?: string | null;
+	relationship?: string;
+	phone?: string;
+	notes?: string;
 }
 
-// =============================================================================
-// Employee Types
-// =============================================================================
+/**
+ * A recorded release of a ticket's item.
+ */
+export interface TicketPickupEntry {
+	pickup_id: string;
+	/** null when the customer collected the item */
+	authorized_pickup_id: string | null;
+	picked_up_by_name: string;
+	relationship: string | null;
+	id_type: string | null;
+	/** Last four characters of the ID number */
+	id_last_four: string | null;
+	has_signature: boolean;
+	released_by: string;
+	released_by_name: string;
+	picked_up_at: string;
+}
 
 /**
- * Employee role enum values.
+ * Pickup details sent when closing a ticket. ID type, number, and signature
+ * are required when authorized_pickup_id is set.
  */
-export type EmployeeRole = 'staff' | 'admin';
+export interface PickupInput {
+	authorized_pickup_id?: string;
+	id_type?: string;
+	/** Only the last four characters are stored */
+	id_number?: string;
+	/** Base64 PNG, optionally as a data: URL */
+	signature?: string;
+}
 
 /**
- * Summary view of an employee (without PIN hash).
+ * Response for GET /tickets/:ticket_id/authorized-pickups.
  */
-export interface EmployeeSummary {
-	employee_id: string;
-	name: string;
-	role: EmployeeRole;
-	is_active: boolean;
+export interface TicketPickupsResponse {
+	ticket_id: string;
+	authorized_pickups: TicketAuthorizedPickup[];
+	pickups: TicketPickupEntry[];
 }
 
+// =============================================================================
+// Payment Types
+// =============================================================================
+
+export type PaymentKind = 'deposit' | 'final';
+
+export type PaymentMethod = 'cash' | 'card' | 'check' | 'other';
+
 /**
- * Basic employee info returned from verification.
- * Used for offline verification where a session token is not available.
+ * A payment taken with intake (deposit) or close (final payment).
  */
-export interface EmployeeInfo {
-	employee_id: string;
-	name: string;
-	role: EmployeeRole;
+export interface PaymentInput {
+	amount: string; // Decimal as string
+	method: PaymentMethod;
+	notes?: string | null;
 }
 
 /**
- * Response from online PIN verification.
- * Includes a session token that should be used for subsequent requests
- * via the X-Employee-Session header.
+ * Request body for recording a payment on a ticket.
  */
-export interface VerifyPinResponse extends EmployeeInfo {
-	/** Session token for subsequent requests (use in X-Employee-Session header) */
-	session_token: string;
-	/** When the session expires (ISO 8601 format) */
-	expires_at: string;
+export interface RecordPaymentRequest extends PaymentInput {
+	/** Defaults to final on closed tickets, deposit otherwise */
+	kind?: PaymentKind;
 }
 
 /**
- * Request body for creating an employee.
+ * A payment recorded against a ticket.
  */
-export interface CreateEmployeeRequest {
-	name: string;
-	pin: string;
-	role?: EmployeeRole;
+export interface TicketPayment {
+	payment_id: string;
+	ticket_id: string;
+	kind: PaymentKind;
+	method: PaymentMethod;
+	amount: string; // Decimal as string
+	notes: string | null;
+	received_by: string;
+	received_at: string;
 }
 
 /**
- * Request body for updating an employee.
+ * A payment with the receiving employee's name.
  */
-export interface UpdateEmployeeRequest {
-	name?: string;
-	pin?: string;
-	role?: EmployeeRole;
-	is_active?: boolean;
+export interface TicketPaymentEntry {
+	payment_id: string;
+	kind: PaymentKind;
+	method: PaymentMethod;
+	amount: string; // Decimal as string
+	notes: string | null;
+	received_by: string;
+	received_by_name: string;
+	received_at: string;
 }
 
 /**
- * Response for listing employees.
+ * Response for recording a payment, with the updated balance.
  */
-export interface ListEmployeesResponse {
-	employees: EmployeeSummary[];
-	count: number;
+export interface RecordPaymentResponse extends TicketPayment {
+	total_paid: string;
+	/** Null when the ticket has no price yet */
+	balance_due: string | null;
 }
 
 /**
- * Response for deleting an employee.
+ * A ticket's payments and balance.
  */
-export interface DeleteEmployeeResponse {
-	deleted: boolean;
-	warning?: string;
+export interface TicketPaymentsResponse {
+	ticket_id: string;
+	friendly_code: string;
+	payments: TicketPaymentEntry[];
+	total_paid: string;
+	deposit_total: string;
+	/** actual_amount once set, otherwise quote_amount */
+	amount_due: string | null;
+	/** Null when the ticket has no price yet */
+	balance_due: string | null;
 }
 
 // =============================================================================
-// Storage Location Types
+// Chain of Custody Types
 // =============================================================================
 
 /**
- * Storage location entity.
+ * Witness details sent with a movement of a high-value item.
  */
-export interface StorageLocation {
-	location_id: string;
-	name: string;
-	is_active: boolean;
-	created_at: string;
-	updated_at: string;
+export interface CustodyWitness {
+	witnessed_by: string;
+	notes?: string | null;
 }
 
+export type CustodyEventType =
+	| 'intake'
+	| 'location_change'
+	| 'handoff'
+	| 'release'
+	| 'transfer_out'
+	| 'transfer_in';
+
 /**
- * Summary view of a storage location.
+ * Custody event as recorded.
  */
-export interface StorageLocationSummary {
-	location_id: string;
-	name: string;
-	is_active: boolean;
+export interface CustodyEvent {
+	event_id: string;
+	ticket_id: string;
+	sequence: number;
+	event_type: CustodyEventType;
+	from_location_id: string | null;
+	to_location_id: string | null;
+	handled_by: string;
+	witnessed_by: string | null;
+	notes: string | null;
+	occurred_at: string;
 }
 
 /**
- * Response for listing storage locations.
+ * Kind of event in a ticket's history feed.
  */
-export interface ListLocationsResponse {
-	locations: StorageLocationSummary[];
-	count: number;
+export type TicketHistoryEventType =
+	| 'field_change'
+	| 'status_change'
+	| 'note'
+	| 'photo_added'
+	| 'photo_removed';
+
+/**
+ * Event in a ticket's history feed.
+ */
+export interface TicketHistoryEvent {
+	event_id: string;
+	event_type: TicketHistoryEventType;
+	field_name: string | null;
+	old_value: string | null;
+	new_value: string | null;
+	employee_id: string;
+	employee_name: string;
+	occurred_at: string;
 }
 
 /**
- * Request body for creating a storage location.
+ * Response for GET /tickets/:id/history.
  */
-export interface CreateStorageLocationRequest {
-	name: string;
+export interface TicketHistoryResponse {
+	ticket_id: string;
+	friendly_code: string;
+	events: TicketHistoryEvent[];
+	pagination: PaginationInfo;
 }
 
 /**
- * Request body for updating a storage location.
+ * Custody event with employee and location names.
  */
-export interface UpdateStorageLocationRequest {
-	name?: string;
-	is_active?: boolean;
+export interface CustodyEventEntry {
+	event_id: string;
+	sequence: number;
+	event_type: CustodyEventType;
+	from_location_name: string | null;
+	to_location_name: string | null;
+	handled_by: string;
+	handled_by_name: string;
+	witnessed_by: string | null;
+	witnessed_by_name: string | null;
+	notes: string | null;
+	occurred_at: string;
+}
+
+/**
+ * Response for GET /tickets/:id/custody.
+ */
+export interface CustodyChainResponse {
+	ticket_id: string;
+	friendly_code: string;
+	requires_custody: boolean;
+	events: CustodyEventEntry[];
 }
 
 // =============================================================================
-// Store Settings Types
+// Transfer Types
 // =============================================================================
 
+export type TransferStatus = 'pending' | 'accepted' | 'cancelled';
+
 /**
- * Minimal store settings returned from GET /api/v1/settings (public endpoint).
- * Excludes security-sensitive fields for unauthenticated access.
+ * Transfer of a ticket between storage locations.
  */
-export interface StoreSettings {
-	setting_id: string;
-	store_name: string;
-	store_phone: string | null;
-	store_address: string | null;
-	ticket_prefix: string;
-	currency: string;
-	max_photos_per_ticket: number;
-	min_pin_length: number;
-	created_at: string;
-	updated_at: string;
+export interface TicketTransfer {
+	transfer_id: string;
+	ticket_id: string;
+	from_location_id: string;
+	to_location_id: string;
+	status: TransferStatus;
+	requested_by: string;
+	notes: string | null;
+	requested_at: string;
+	resolved_by: string | null;
+	resolved_at: string | null;
 }
 
 /**
- * Request to update store settings.
- * All fields are optional - only provided fields will be updated.
+ * Transfer with ticket, location, and employee names.
  */
-export interface UpdateStoreSettingsRequest {
-	store_name?: string;
-	store_phone?: string;
-	store_address?: string;
-	ticket_prefix?: string;
-	currency?: string;
-	max_photos_per_ticket?: number;
+export interface TicketTransferEntry extends TicketTransfer {
+	friendly_code: string;
+	from_location_name: string;
+	to_location_name: string;
+	requested_by_name: string;
+	resolved_by_name: string | null;
+}
+
+/**
+ * Request body for sending a ticket to another location.
+ */
+export interface CreateTransferRequest {
+	to_location_id: string;
+	notes?: string | null;
+	/** Required for high-value tickets */
+	custody?: CustodyWitness;
+}
+
+/**
+ * Request body for accepting or cancelling a transfer.
+ */
+export interface ResolveTransferRequest {
+	notes?: string | null;
+	/** Required for high-value tickets */
+	custody?: CustodyWitness;
+}
+
+/**
+ * Response for GET /tickets/:id/transfers.
+ */
+export interface TicketTransfersResponse {
+	ticket_id: string;
+	friendly_code: string;
+	in_transit: boolean;
+	transfers: TicketTransferEntry[];
+}
+
+/**
+ * Query parameters for GET /transfers.
+ */
+export interface ListTransfersParams {
+	/** Default: pending */
+	status?: TransferStatus;
+	to_location_id?: string;
+	limit?: number;
+	offset?: number;
+}
+
+/**
+ * Response for GET /transfers.
+ */
+export interface ListTransfersResponse {
+	transfers: TicketTransferEntry[];
+	pagination: PaginationInfo;
+}
+
+// =============================================================================
+// Bench Time Types
+// =============================================================================
+
+/**
+ * A stretch of bench time an employee spent on a ticket.
+ */
+export interface TimeEntry {
+	entry_id: string;
+	ticket_id: string;
+	employee_id: string;
+	started_at: string;
+	/** Null while the employee is still clocked in */
+	stopped_at: string | null;
+}
+
+/**
+ * Time entry with ticket and employee names.
+ */
+export interface TimeEntryEntry extends TimeEntry {
+	friendly_code: string;
+	employee_name: string;
+}
+
+/**
+ * Response for GET /tickets/:id/time.
+ */
+export interface TicketTimeResponse {
+	ticket_id: string;
+	friendly_code: string;
+	bench_seconds: number;
+	entries: TimeEntryEntry[];
+}
+
+// =============================================================================
+// Appraisal Types
+// =============================================================================
+
+/**
+ * A ticket's appraisal, with the appraiser's name.
+ */
+export interface Appraisal {
+	appraisal_id: string;
+	ticket_id: string;
+	appraised_value: string;
+	metal: string | null;
+	stones: string | null;
+	/** Grading report or certificate numbers */
+	certification_numbers: string[];
+	notes: string | null;
+	appraiser_id: string;
+	appraiser_name: string;
+	appraised_on: string;
+	created_at: string;
+	updated_at: string;
+}
+
+/**
+ * Request body for PUT /tickets/:id/appraisal. Replaces the whole appraisal.
+ */
+export interface SaveAppraisalRequest {
+	appraised_value: number;
+	metal?: string;
+	stones?: string;
+	certification_numbers?: string[];
+	notes?: string;
+	/** Defaults to the current employee */
+	appraiser_id?: string;
+	/** Defaults to today */
+	appraised_on?: string;
+}
+
+/**
+ * Response for DELETE /tickets/:id/appraisal.
+ */
+export interface DeleteAppraisalResponse {
+	appraisal_id: string;
+	ticket_id: string;
+}
+
+// =============================================================================
+// Movement Types
+// =============================================================================
+
+/**
+ * An item moving between its storage location and employees' benches.
+ * Each end is either a location or an employee.
+ */
+export interface TicketMovement {
+	movement_id: string;
+	ticket_id: string;
+	from_location_id: string | null;
+	from_employee_id: string | null;
+	to_location_id: string | null;
+	to_employee_id: string | null;
+	moved_by: string;
+	notes: string | null;
+	moved_at: string;
+}
+
+/**
+ * Movement with ticket, location, and employee names.
+ */
+export interface TicketMovementEntry extends TicketMovement {
+	friendly_code: string;
+	from_location_name: string | null;
+	from_employee_name: string | null;
+	to_location_name: string | null;
+	to_employee_name: string | null;
+	moved_by_name: string;
+}
+
+/**
+ * Where a ticket's item is now.
+ */
+export interface CurrentLocation {
+	/** Storage location the item belongs in */
+	location_id: string;
+	location_name: string;
+	/** Set while the item is out at an employee's bench */
+	employee_id: string | null;
+	employee_name: string | null;
+	/** When the item last moved */
+	since: string | null;
+}
+
+/**
+ * Request body for recording a movement. Give exactly one destination.
+ */
+export interface RecordMovementRequest {
+	/** Check the item back into the ticket's storage location */
+	to_location_id?: string;
+	/** Check the item out to an employee's bench */
+	to_employee_id?: string;
+	notes?: string | null;
+	/** Required for high-value tickets */
+	custody?: CustodyWitness;
+}
+
+/**
+ * Response for GET /tickets/:id/movements.
+ */
+export interface TicketMovementsResponse {
+	ticket_id: string;
+	friendly_code: string;
+	current_location: CurrentLocation;
+	movements: TicketMovementEntry[];
+}
+
+// =============================================================================
+// Item Type Types
+// =============================================================================
+
+/**
+ * Configured item type with intake defaults.
+ */
+export interface ItemType {
+	item_type_id: string;
+	name: string;
+	/** Days from intake to the default promise date */
+	default_turnaround_days: number | null;
+	/** Photos required before work starts */
+	required_photos: number;
+	condition_checklist: string[];
+	suggested_services: string[];
+	/** Starting quote offered at intake (decimal string), not applied automatically */
+	base_price: string | null;
+	sort_order: number;
+	updated_at: string;
+}
+
+/**
+ * Item type input for PUT /settings/item-types (replaces all, in order).
+ */
+export interface ItemTypeInput {
+	name: string;
+	default_turnaround_days?: number | null;
+	required_photos?: number;
+	condition_checklist?: string[];
+	suggested_services?: string[];
+	base_price?: number | null;
+}
+
+// =============================================================================
+// Promise Date Reason Types
+// =============================================================================
+
+/**
+ * Configured reason for moving a promise date later.
+ */
+export interface PromiseDateReason {
+	reason_id: string;
+	code: string;
+	/** Customer-facing wording */
+	label: string;
+	sort_order: number;
+	updated_at: string;
+}
+
+/**
+ * Reason input for PUT /settings/promise-date-reasons (replaces all, in order).
+ */
+export interface PromiseDateReasonInput {
+	code: string;
+	label: string;
+}
+
+// =============================================================================
+// Rush Pricing Types
+// =============================================================================
+
+export type RushSurchargeKind = 'flat' | 'percent';
+
+/**
+ * Surcharge tier for rush jobs, chosen by lead time.
+ */
+export interface RushSurchargeTier {
+	tier_id: string;
+	/** Promise dates at most this many days out; null covers any lead time */
+	max_lead_days: number | null;
+	kind: RushSurchargeKind;
+	/** Flat amount, or percentage for percent tiers */
+	amount: string;
+	created_at: string;
+}
+
+/**
+ * Tier input for PUT /settings/rush-pricing (replaces all tiers).
+ */
+export interface RushSurchargeTierInput {
+	max_lead_days: number | null;
+	kind: RushSurchargeKind;
+	amount: string;
+}
+
+/**
+ * Request body for POST /tickets/quote.
+ */
+export interface QuoteRequest {
+	base_amount: string;
+	is_rush?: boolean;
+	promise_date?: string | null;
+}
+
+/**
+ * One line of a quote breakdown.
+ */
+export interface QuoteLine {
+	label: string;
+	amount: string;
+}
+
+/**
+ * An amount split into subtotal, tax, and what the customer pays.
+ */
+export interface TaxBreakdown {
+	subtotal: string;
+	tax_rate: string;
+	tax_inclusive: boolean;
+	tax_amount: string;
+	total: string;
+}
+
+/**
+ * Response for POST /tickets/quote.
+ */
+export interface QuoteBreakdown {
+	base_amount: string;
+	is_rush: boolean;
+	lead_time_days: number | null;
+	tier: RushSurchargeTier | null;
+	rush_surcharge: string;
+	lines: QuoteLine[];
+	/** Send as the ticket's quote_amount */
+	total: string;
+	/** Tax on `total` at the store's rate */
+	tax: TaxBreakdown;
+}
+
+/**
+ * Response for GET /tickets/promise-date-suggestion.
+ */
+export interface PromiseDateSuggestion {
+	/** Null when no turnaround applies */
+	promise_date: string | null;
+	/** The configured item type matched */
+	item_type: string | null;
+	/** Open days counted from today */
+	turnaround_days: number | null;
+	is_rush: boolean;
+	/** Closed days and holidays passed over */
+	closed_days_skipped: number;
+}
+
+/**
+ * Where a ticket's quote is in customer approval.
+ */
+export type QuoteStatus = 'draft' | 'sent' | 'approved' | 'declined';
+
+/**
+ * How a quote was given to, or answered by, the customer.
+ */
+export type QuoteChannel = 'in_person' | 'phone' | 'sms' | 'email';
+
+/**
+ * A recorded quote approval step.
+ */
+export interface QuoteEvent {
+	event_id: string;
+	ticket_id: string;
+	status: QuoteStatus;
+	/** Quote amount at the time */
+	quote_amount: string;
+	channel: QuoteChannel;
+	/** Person who approved or declined (null for sends) */
+	decided_by: string | null;
+	notes: string | null;
+	recorded_by: string;
+	recorded_by_name: string;
+	created_at: string;
+}
+
+/**
+ * Request to record that a quote was given to the customer.
+ */
+export interface SendQuoteRequest {
+	channel: QuoteChannel;
+	notes?: string;
+}
+
+/**
+ * Request to record the customer's decision on a quote.
+ */
+export interface QuoteDecisionRequest {
+	channel: QuoteChannel;
+	/** Defaults to the customer's name */
+	decided_by?: string;
+	notes?: string;
+}
+
+/**
+ * Response from the quote send/approve/decline endpoints.
+ */
+export interface TicketQuoteResponse {
+	ticket_id: string;
+	quote_amount: string | null;
+	quote_status: QuoteStatus;
+	/** Whether work can't start until the quote is approved */
+	approval_required: boolean;
+	events: QuoteEvent[];
+}
+
+/**
+ * Response for a closed ticket.
+ */
+export interface CloseTicketResponse extends Ticket {
+	previous_status: TicketStatus;
+	/** Present when a final payment was taken */
+	payment?: TicketPayment;
+	/** Tax on actual_amount, as recorded on the invoice */
+	tax: TaxBreakdown;
+	total_paid: string;
+	balance_due: string;
+	/** Present when pickup details were sent */
+	pickup?: TicketPickupEntry;
+	/** e.g. "INV-000042" */
+	invoice_number: string;
+	invoice_url: string;
+}
+
+/**
+ * Request body for reopening a closed ticket.
+ */
+export interface ReopenTicketRequest {
+	reason: string;
+}
+
+/**
+ * Response for a reopened ticket, including the note recording the reason.
+ */
+export interface ReopenTicketResponse extends Ticket {
+	previous_status: TicketStatus;
+	note: TicketNote;
+}
+
+/**
+ * Response from soft-deleting a ticket.
+ */
+export interface DeleteTicketResponse {
+	ticket_id: string;
+	friendly_code: string;
+	deleted_at: string;
+}
+
+/**
+ * Response from restoring a soft-deleted ticket.
+ */
+export interface RestoreTicketResponse {
+	ticket_id: string;
+	friendly_code: string;
+}
+
+// =============================================================================
+// Customer Types
+// =============================================================================
+
+/**
+ * Full customer entity.
+ */
+export interface Customer {
+	customer_id: string;
+	name: string;
+	phone: string | null;
+	email: string | null;
+	notifications_opt_out: boolean;
+	created_at: string;
+	updated_at: string;
+}
+
+/**
+ * Request for creating a customer.
+ */
+export interface CreateCustomerRequest {
+	name: string;
+	phone?: string | null;
+	email?: string | null;
+}
+
+// =============================================================================
+// Employee Types
+// =============================================================================
+
+/**
+ * Employee role enum values.
+ */
+export type EmployeeRole = 'staff' | 'admin';
+
+/**
+ * Permission an employee can hold. Admins hold every permission.
+ */
+export type Permission =
+	| 'create_ticket'
+	| 'modify_own_ticket'
+	| 'modify_any_ticket'
+	| 'add_notes'
+	| 'upload_photos'
+	| 'delete_photos'
+	| 'close_any_ticket'
+	| 'edit_prices'
+	| 'manage_employees'
+	| 'manage_settings'
+	| 'manage_locations'
+	| 'manage_customers'
+	| 'delete_customers';
+
+/**
+ * Summary view of an employee (without PIN hash).
+ */
+export interface EmployeeSummary {
+	employee_id: string;
+	/** Home store; the employee can sign in at any store */
+	store_id: string;
+	name: string;
+	role: EmployeeRole;
+	/** Where promise-date reminder texts go */
+	phone: string | null;
+	permissions: Permission[];
+	is_active: boolean;
+	/** When too many wrong PINs locked the employee out; null if not locked */
+	pin_locked_at: string | null;
+}
+
+/**
+ * Basic employee info returned from verification.
+ * Used for offline verification where a session token is not available.
+ */
+export interface EmployeeInfo {
+	employee_id: string;
+	name: string;
+	role: EmployeeRole;
+}
+
+/**
+ * Response from online PIN verification.
+ * Includes a session token that should be used for subsequent requests
+ * via the X-Employee-Session header.
+ */
+export interface VerifyPinResponse extends EmployeeInfo {
+	/** Store the session works for */
+	store_id: string;
+	/** Session token for subsequent requests (use in X-Employee-Session header) */
+	session_token: string;
+	/** When the session expires (ISO 8601 format) */
+	expires_at: string;
+}
+
+/**
+ * Nonce issued for challenge-response PIN entry, with the parameters for
+ * deriving the key from the PIN.
+ */
+export interface PinChallenge {
+	nonce: string;
+	expires_at: string;
+	kdf: {
+		/** Always 'pbkdf2-sha256' */
+		algorithm: string;
+		/** The employee's own salt */
+		salt: string;
+		iterations: number;
+	};
+}
+
+/**
+ * Response after switching the employee session's training mode.
+ */
+export interface TrainingModeResponse {
+	training_mode: boolean;
+}
+
+/**
+ * Request body for creating an employee.
+ */
+export interface CreateEmployeeRequest {
+	name: string;
+	pin: string;
+	role?: EmployeeRole;
+	phone?: string;
+}
+
+/**
+ * Request body for updating an employee.
+ */
+export interface UpdateEmployeeRequest {
+	name?: string;
+	pin?: string;
+	role?: EmployeeRole;
+	is_active?: boolean;
+	/** null removes the phone number */
+	phone?: string | null;
+	/** Move the employee to another store */
+	store_id?: string;
+}
+
+/**
+ * Response for listing employees.
+ */
+export interface ListEmployeesResponse {
+	employees: EmployeeSummary[];
+	count: number;
+}
+
+/**
+ * An active employee's ID and name, for "worked by" pickers.
+ */
+export interface EmployeeName {
+	employee_id: string;
+	name: string;
+}
+
+/**
+ * Response for listing active employee names.
+ */
+export interface ActiveEmployeesResponse {
+	employees: EmployeeName[];
+}
+
+/**
+ * The logged-in employee's own profile.
+ */
+export interface EmployeeProfile {
+	employee_id: string;
+	name: string;
+	role: EmployeeRole;
+	permissions: Permission[];
+	/** Open tickets the employee took in or is working on */
+	open_tickets: {
+		taken_in: number;
+		working_on: number;
+	};
+}
+
+/**
+ * Response for deleting an employee.
+ */
+export interface DeleteEmployeeResponse {
+	deleted: boolean;
+	warning?: string;
+}
+
+/**
+ * An employee's permissions, and the permissions that can be granted.
+ */
+export interface EmployeePermissions {
+	employee_id: string;
+	role: EmployeeRole;
+	permissions: Permission[];
+	assignable: Permission[];
+}
+
+// =============================================================================
+// Storage Location Types
+// =============================================================================
+
+/**
+ * Storage location entity.
+ */
+export interface StorageLocation {
+	location_id: string;
+	store_id: string;
+	name: string;
+	is_active: boolean;
+	capacity: number | null;
+	created_at: string;
+	updated_at: string;
+}
+
+/**
+ * Summary view of a storage location.
+ */
+export interface StorageLocationSummary {
+	location_id: string;
+	name: string;
+	is_active: boolean;
+	/** Open tickets the location is meant to hold; null means no limit */
+	capacity: number | null;
+	/** Open tickets stored there or in transit to it */
+	open_tickets: number;
+}
+
+/**
+ * Response for listing storage locations.
+ */
+export interface ListLocationsResponse {
+	locations: StorageLocationSummary[];
+	count: number;
+}
+
+/**
+ * Basic ticket view for lists.
+ */
+export type TicketSummary = Omit<QueueTicket, 'queue_position' | 'is_overdue'>;
+
+/**
+ * Response for the open tickets stored at a location.
+ */
+export interface LocationTicketsResponse {
+	location: StorageLocationSummary;
+	tickets: TicketSummary[];
+	count: number;
+}
+
+/**
+ * A suggested storage location for a new item.
+ */
+export interface LocationSuggestion {
+	location_id: string;
+	name: string;
+	open_tickets: number;
+	reason: 'rule' | 'least_occupied';
+	rule_id: string | null;
+}
+
+/**
+ * Response for a storage location suggestion.
+ */
+export interface SuggestLocationResponse {
+	suggested: LocationSuggestion | null;
+	alternatives: LocationSuggestion[];
+}
+
+/**
+ * Request body for creating a storage location.
+ */
+export interface CreateStorageLocationRequest {
+	name: string;
+	capacity?: number;
+}
+
+/**
+ * Request body for updating a storage location.
+ */
+export interface UpdateStorageLocationRequest {
+	name?: string;
+	is_active?: boolean;
+	capacity?: number | null;
+}
+
+// =============================================================================
+// Store Settings Types
+// =============================================================================
+
+/**
+ * Minimal store settings returned from GET /api/v1/settings (public endpoint).
+ * Excludes security-sensitive fields for unauthenticated access.
+ */
+export interface StoreSettings {
+	setting_id: string;
+	store_name: string;
+	store_phone: string | null;
+	store_address: string | null;
+	ticket_prefix: string;
+	currency: string;
+	/** Sales tax percentage, e.g. "8.250" */
+	tax_rate: string;
+	/** Ticket amounts include tax; otherwise tax is added on top */
+	tax_inclusive: boolean;
+	max_photos_per_ticket: number;
+	min_pin_length: number;
+	/** A promise date feed token is live */
+	calendar_feed_enabled: boolean;
+	created_at: string;
+	updated_at: string;
+}
+
+/**
+ * Request to update store settings.
+ * All fields are optional - only provided fields will be updated.
+ */
+export interface UpdateStoreSettingsRequest {
+	store_name?: string;
+	store_phone?: string;
+	store_address?: string;
+	ticket_prefix?: string;
+	/** Digits ticket numbers are zero-padded to (1-8) */
+	ticket_number_padding?: number;
+	/** Start numbering over each year, with the year in the code (JR-2025-0042) */
+	ticket_number_yearly_reset?: boolean;
+	currency?: string;
+	tax_rate?: number;
+	tax_inclusive?: boolean;
+	max_photos_per_ticket?: number;
+	/** Tickets must use a configured item type or "Other" */
+	restrict_item_types?: boolean;
+}
+
+/**
+ * A group of related settings edited with PATCH /settings/:section.
+ */
+export type SettingsSection =
+	| 'store'
+	| 'printing'
+	| 'workflow'
+	| 'hours'
+	| 'notifications'
+	| 'security';
+
+/**
+ * One settings section. `version` is also sent as the ETag header.
+ */
+export interface SettingsSectionResponse {
+	section: SettingsSection;
+	version: number;
+	settings: Record<string, unknown>;
+}
+
+/**
+ * Default receipt format: letter PDF, ESC/POS for 80mm thermal printers, or plain text.
+ */
+export type ReceiptFormat = 'pdf' | 'escpos' | 'text';
+
+/**
+ * Named label stock. Send as `label_stock` in a printing update to set
+ * label_width_mm and label_height_mm together; it is not read back.
+ */
+export type LabelStock = 'tag_2x1' | 'barbell' | 'tag_2.25x1.25' | 'dymo_30334';
+
+/**
+ * Fields of the `printing` settings section: ticket numbering and the
+ * receipt and label templates.
+ */
+export interface PrintingSettings {
+	ticket_prefix: string;
+	/** Digits ticket numbers are zero-padded to */
+	ticket_number_padding: number;
+	/** Numbering starts over each year, with the year in the code */
+	ticket_number_yearly_reset: boolean;
+	/** Set by uploadReceiptLogo; null when no logo is printed */
+	receipt_logo_key: string | null;
+	receipt_footer_text: string | null;
+	receipt_show_prices: boolean;
+	/** Label stock size in mm (decimal string, e.g. "50.8") */
+	label_width_mm: string;
+	label_height_mm: string;
+	/** Format served by GET /tickets/:id/receipt and linked as receipt_url */
+	receipt_format: ReceiptFormat;
+}
+
+/**
+ * Opening hours for one day of the week; days without an entry are closed.
+ */
+export interface OpeningHours {
+	/** ISO weekday: 1 = Monday, 7 = Sunday */
+	weekday: number;
+	/** "HH:MM" or "HH:MM:SS" */
+	opens_at: string;
+	closes_at: string;
+}
+
+/**
+ * Fields of the `hours` settings section. Promise dates are counted in the
+ * days the store is open.
+ */
+export interface HoursSettings {
+	/** Empty means open every day */
+	business_hours: OpeningHours[];
+	/** Dates the store is closed (YYYY-MM-DD) */
+	holidays: string[];
+	/** Open days a rush job takes, when sooner than its usual turnaround */
+	rush_turnaround_days: number | null;
+	/** IANA time zone the store's days are counted in, e.g. 'America/Chicago' */
+	timezone: string;
+}
+
+/**
+ * A new promise date feed token. It's only shown once.
+ */
+export interface CalendarFeedResponse {
+	token: string;
+	/** Feed path with the token, to subscribe to from a calendar app */
+	feed_path: string;
+}
+
+/**
+ * A recorded settings change. Only the fields that changed are listed.
+ */
+export interface SettingsChange {
+	change_id: string;
+	store_id: string;
+	version: number;
+	old_values: Record<string, unknown>;
+	new_values: Record<string, unknown>;
+	changed_by: string | null;
+	changed_by_name?: string | null;
+	rolled_back_change_id: string | null;
+	changed_at: string;
+}
+
+/**
+ * Response for GET /settings/history.
+ */
+export interface SettingsHistoryResponse {
+	changes: SettingsChange[];
+}
+
+/**
+ * Store configuration bundle from GET /admin/config/export.
+ * Every part except format_version is optional on import.
+ */
+export interface ConfigBundle {
+	format_version: number;
+	exported_at?: string | null;
+	settings?: Record<string, unknown>;
+	storage_locations?: { name: string; is_active: boolean; capacity?: number | null }[];
+	location_rules?: {
+		item_type: string | null;
+		min_quote_amount: string | null;
+		location: string;
+		priority: number;
+	}[];
+	metal_prices?: { metal_type: string; purity: string; price_per_gram: string }[];
+	rush_surcharge_tiers?: RushSurchargeTierInput[];
+	item_types?: ItemTypeInput[];
+	promise_date_reasons?: PromiseDateReasonInput[];
+	notification_templates?: {
+		event: string;
+		subject: string;
+		body: string;
+		is_enabled: boolean;
+	}[];
+}
+
+/**
+ * Summary of what POST /admin/config/import changed.
+ */
+export interface ConfigImportResponse {
+	settings_changed: boolean;
+	locations_created: number;
+	locations_updated: number;
+	location_rules: number | null;
+	metal_prices: number | null;
+	rush_surcharge_tiers: number | null;
+	item_types: number | null;
+	promise_date_reasons: number | null;
+	notification_templates_updated: number;
+}
+
+/**
+ * Row imported (or, on a dry run, that would be imported) by a CSV import.
+ */
+export interface ImportedRow {
+	/** Line in the file; the header is row 1 */
+	row: number;
+	/** Created customer or ticket (null on a dry run) */
+	record_id: string | null;
+	friendly_code: string | null;
+}
+
+/**
+ * Row rejected by a CSV import.
+ */
+export interface RejectedRow {
+	row: number;
+	reason: string;
+}
+
+/**
+ * Response for POST /admin/import/customers and /admin/import/tickets.
+ */
+export interface ImportSummary {
+	dry_run: boolean;
+	total_rows: number;
+	accepted_count: number;
+	rejected_count: number;
+	customers_created: number;
+	accepted: ImportedRow[];
+	rejected: RejectedRow[];
+}
+
+/**
+ * Options for a CSV import.
+ */
+export interface ImportOptions {
+	/** Validate without writing anything */
+	dry_run?: boolean;
+	/** Employee recorded as having taken in imported tickets (tickets only) */
+	taken_in_by?: string;
+}
+
+/**
+ * Consistency check run by GET /admin/integrity.
+ */
+export type IntegrityCheck =
+	| 'missing_photo_object'
+	| 'inactive_location'
+	| 'inactive_assignee'
+	| 'unknown_employee_reference'
+	| 'orphaned_note';
+
+/**
+ * A problem found by the integrity checks.
+ */
+export interface IntegrityIssue {
+	check: IntegrityCheck;
+	/** The flagged photo, ticket, history entry, or note */
+	record_id: string;
+	ticket_id: string;
+	friendly_code: string;
+	message: string;
+	suggested_fix: string;
+}
+
+/**
+ * Response for GET /admin/integrity.
+ */
+export interface IntegrityReport {
+	checked_at: string;
+	checks: { check: IntegrityCheck; issues: number }[];
+	issues: IntegrityIssue[];
+}
+
+/**
+ * Status of a scheduled background job, from GET /admin/jobs.
+ */
+export interface JobStatus {
+	name: string;
+	/** Seconds between runs; null when the job is turned off */
+	interval_secs: number | null;
+	running: boolean;
+	last_run_at: string | null;
+	last_duration_ms: number | null;
+	/** Error from the last run, cleared when a run succeeds */
+	last_error: string | null;
+	last_error_at: string | null;
+	next_run_at: string | null;
+	runs: number;
+	failures: number;
+}
+
+/**
+ * Response for POST /admin/storage/reconcile.
+ */
+export interface StorageReconcileReport {
+	ran_at: string;
+	/** Only orphans last modified before this are deleted */
+	cutoff: string;
+	scanned: number;
+	/** Orphans newer than the cutoff, left for a later run */
+	skipped_recent: number;
+	/** Storage keys of the deleted orphans */
+	deleted: string[];
+	failed: { storage_key: string; error: string }[];
+}
+
+/**
+ * Response for POST /admin/recover.
+ */
+export interface RecoverAdminResponse {
+	settings: StoreSettings;
+	/** Replacement recovery code; show it once and have the owner store it offline */
+	recovery_code: string;
+	sessions_revoked: number;
+}
+
+/**
+ * A recorded admin recovery attempt.
+ */
+export interface AdminRecoveryAttempt {
+	attempt_id: string;
+	attempted_at: string;
+	client_ip: string;
+	user_agent: string | null;
+	succeeded: boolean;
+	/** 'invalid_recovery_code', 'rate_limited', or 'locked_out' */
+	failure_reason: string | null;
+}
+
+/**
+ * Response for GET /admin/recovery/attempts.
+ */
+export interface RecoveryAttemptsResponse {
+	recovery_code_set: boolean;
+	attempts: AdminRecoveryAttempt[];
+}
+
+/**
+ * Response for POST /settings/rollback/:change_id.
+ */
+export interface SettingsRollbackResponse {
+	settings: StoreSettings;
+	change: SettingsChange | null;
+}
+
+// =============================================================================
+// Partner Types
+// =============================================================================
+
+/**
+ * Partner jeweler account for the partner API.
+ */
+export interface Partner {
+	partner_id: string;
+	name: string;
+	customer_id: string;
+	/** Leading characters of the API key */
+	api_key_prefix: string;
+	rate_limit_per_minute: number;
+	/** Employee recorded as taking in submitted tickets */
+	taken_in_by: string;
+	is_active: boolean;
+	created_at: string;
+	updated_at: string;
+	last_used_at: string | null;
+}
+
+/**
+ * A partner with its newly issued API key (shown once).
+ */
+export interface PartnerKeyResponse extends Partner {
+	api_key: string;
+}
+
+export interface ListPartnersResponse {
+	partners: Partner[];
+}
+
+/**
+ * Request body for creating a partner. Give customer_id to link an
+ * existing customer, or phone/email to create one for the shop.
+ */
+export interface CreatePartnerRequest {
+	name: string;
+	customer_id?: string | null;
+	phone?: string | null;
+	email?: string | null;
+	rate_limit_per_minute?: number;
+	taken_in_by?: string;
+}
+
+export interface UpdatePartnerRequest {
+	name?: string;
+	rate_limit_per_minute?: number;
+	taken_in_by?: string;
+	is_active?: boolean;
+}
+
+/**
+ * Trade work and API usage for one partner.
+ */
+export interface PartnerActivity {
+	partner_id: string;
+	partner_name: string;
+	is_active: boolean;
+	tickets: number;
+	submitted_via_api: number;
+	closed_tickets: number;
+	revenue: string; // Decimal as string
+	api_requests: number;
+	rate_limited_requests: number;
+}
+
+export interface PartnerReport {
+	from_date: string;
+	to_date: string;
+	partners: PartnerActivity[];
+}
+
+// =============================================================================
+// Service Catalog Types
+// =============================================================================
+
+/**
+ * A store in the chain, with the name and ticket prefix from its settings.
+ */
+export interface Store {
+	store_id: string;
+	name: string;
+	ticket_prefix: string;
+	/** The original store; can't be closed */
+	is_primary: boolean;
+	is_active: boolean;
+	created_at: string;
+	updated_at: string;
+}
+
+export interface CreateStoreRequest {
+	name: string;
+	ticket_prefix: string;
+	store_phone?: string | null;
+	store_address?: string | null;
+}
+
+export interface ListStoresResponse {
+	stores: Store[];
+}
+
+/**
+ * Catalog service with its default price and turnaround.
+ */
+export interface Service {
+	service_id: string;
+	name: string;
+	default_price: string | null; // Decimal as string
+	turnaround_days: number | null;
+	is_active: boolean;
+	created_at: string;
+	updated_at: string;
+}
+
+export interface CreateServiceRequest {
+	name: string;
+	default_price?: number | null;
+	turnaround_days?: number | null;
+}
+
+/**
+ * Fields to change; null clears default_price or turnaround_days.
+ */
+export interface UpdateServiceRequest {
+	name?: string;
+	default_price?: number | null;
+	turnaround_days?: number | null;
+	is_active?: boolean;
+}
+
+/**
+ * A service on a ticket, with its price at intake.
+ */
+export interface TicketService {
+	service_id: string;
+	name: string;
+	price: string | null;
+}
+
+/**
+ * Stock the bench uses up, with the quantity on hand at the current store
+ * and unit cost.
+ */
+export interface Material {
+	material_id: string;
+	name: string;
+	/** What quantities are counted in, e.g. "g", "dwt", "each" */
+	unit: string;
+	quantity_on_hand: string; // Decimal as string
+	unit_cost: string; // Decimal as string
+	is_active: boolean;
+	created_at: string;
+	updated_at: string;
+}
+
+export interface CreateMaterialRequest {
+	name: string;
+	unit: string;
+	quantity_on_hand?: number;
+	unit_cost: number;
+}
+
+/**
+ * Fields to change; setting quantity_on_hand records a restock or count at
+ * the current store.
+ */
+export interface UpdateMaterialRequest {
+	name?: string;
+	unit?: string;
+	quantity_on_hand?: number;
+	unit_cost?: number;
+	is_active?: boolean;
+}
+
+/**
+ * A material used on a ticket, at its cost when used.
+ */
+export interface TicketMaterialEntry {
+	ticket_material_id: string;
+	material_id: string;
+	material_name: string;
+	unit: string;
+	quantity: string;
+	unit_cost: string;
+	/** quantity × unit_cost */
+	cost: string;
+	recorded_by: string;
+	recorded_by_name: string;
+	recorded_at: string;
+}
+
+export interface TicketMaterialsResponse {
+	ticket_id: string;
+	friendly_code: string;
+	materials: TicketMaterialEntry[];
+	material_cost: string;
+}
+
+export interface RecordMaterialRequest {
+	material_id: string;
+	quantity: number;
+}
+
+export interface RecordMaterialResponse {
+	material: TicketMaterialEntry;
+	/** The material's stock left after this use */
+	quantity_on_hand: string;
+	material_cost: string;
+}
+
+/**
+ * How often a service was picked and what those tickets came to.
+ */
+export interface ServiceUsage {
+	service_id: string;
+	service_name: string;
+	is_active: boolean;
+	tickets: number;
+	quoted_total: string;
+	average_price: string | null;
+	closed_tickets: number;
+	average_actual_amount: string | null;
+	average_turnaround_days: number | null;
+}
+
+export interface ServiceReport {
+	from_date: string;
+	to_date: string;
+	services: ServiceUsage[];
+}
+
+// =============================================================================
+// Webhook Types
+// =============================================================================
+
+export type WebhookEvent =
+	| 'ticket.created'
+	| 'ticket.status_changed'
+	| 'ticket.closed'
+	| 'photo.uploaded'
+	| 'ticket.assigned';
+
+/**
+ * A URL registered to receive ticket events.
+ */
+export interface Webhook {
+	webhook_id: string;
+	url: string;
+	description: string | null;
+	events: WebhookEvent[];
+	is_active: boolean;
+	created_at: string;
+	updated_at: string;
+}
+
+/**
+ * A webhook with its newly issued signing secret (shown once).
+ */
+export interface WebhookSecretResponse extends Webhook {
+	secret: string;
+}
+
+export interface ListWebhooksResponse {
+	webhooks: Webhook[];
+}
+
+export interface CreateWebhookRequest {
+	url: string;
+	description?: string | null;
+	events: WebhookEvent[];
+}
+
+export interface UpdateWebhookRequest {
+	url?: string;
+	description?: string | null;
+	events?: WebhookEvent[];
+	is_active?: boolean;
+}
+
+export type WebhookDeliveryStatus = 'pending' | 'delivered' | 'failed';
+
+/**
+ * One event sent (or queued) to one webhook, with the last response.
+ */
+export interface WebhookDelivery {
+	delivery_id: string;
+	webhook_id: string;
+	event: WebhookEvent;
+	/** The event's data */
+	payload: Record<string, unknown>;
+	status: WebhookDeliveryStatus;
+	attempts: number;
+	next_attempt_at: string;
+	last_attempt_at: string | null;
+	response_status: number | null;
+	/** First 4096 characters of the last response */
+	response_body: string | null;
+	last_error: string | null;
+	created_at: string;
+	delivered_at: string | null;
+}
+
+export interface WebhookDeliveriesResponse {
+	deliveries: WebhookDelivery[];
+	pagination: PaginationInfo;
+}
+
+// =============================================================================
+// Campaign Types
+// =============================================================================
+
+export type CampaignStatus = 'queued' | 'running' | 'completed' | 'aborted';
+
+export type CampaignRecipientStatus =
+	| 'pending'
+	| 'sending'
+	| 'sent'
+	| 'failed'
+	| 'skipped'
+	| 'cancelled';
+
+/**
+ * Which customers a campaign goes to. Customers must match every filter set.
+ */
+export interface CampaignSegment {
+	/** A ticket closed on or after this date */
+	closed_from?: string | null;
+	/** A ticket closed on or before this date */
+	closed_to?: string | null;
+	item_type?: string | null;
+	has_open_tickets?: boolean | null;
+}
+
+/**
+ * Request body for previewing or creating a campaign.
+ */
+export interface CreateCampaignRequest {
+	name: string;
+	channel: 'sms' | 'email';
+	/** Required for email */
+	subject?: string | null;
+	/** May use {customer_name}, {store_name}, {store_phone} */
+	body: string;
+	segment?: CampaignSegment;
+	send_rate_per_minute?: number;
+}
+
+export interface CampaignPreview {
+	/** Customers the campaign would reach */
+	recipients: number;
+	subject: string | null;
+	/** Message rendered for a sample customer */
+	message: string;
+}
+
+/**
+ * Recipient counts by delivery status.
+ */
+export interface CampaignProgress {
+	total: number;
+	pending: number;
+	sending: number;
+	sent: number;
+	failed: number;
+	skipped: number;
+	cancelled: number;
+}
+
+export interface Campaign {
+	campaign_id: string;
+	name: string;
+	channel: 'sms' | 'email';
+	subject: string | null;
+	body: string;
+	segment: CampaignSegment;
+	send_rate_per_minute: number;
+	status: CampaignStatus;
+	created_by: string;
+	created_at: string;
+	started_at: string | null;
+	finished_at: string | null;
+	aborted_by: string | null;
+	progress: CampaignProgress;
+}
+
+export interface ListCampaignsResponse {
+	campaigns: Campaign[];
+}
+
+export interface CampaignRecipient {
+	recipient_id: string;
+	campaign_id: string;
+	customer_id: string;
+	customer_name: string;
+	/** Phone or email the message was addressed to */
+	recipient: string;
+	status: CampaignRecipientStatus;
+	provider_message_id: string | null;
+	/** Why the message failed or was skipped */
+	error: string | null;
+	attempted_at: string | null;
+	sent_at: string | null;
+}
+
+export interface CampaignRecipientsResponse {
+	recipients: CampaignRecipient[];
+}
+
+export type AuditAction =
+	| 'admin_setup'
+	| 'admin_pin_changed'
+	| 'admin_recovered'
+	| 'recovery_code_regenerated'
+	| 'employee_created'
+	| 'employee_updated'
+	| 'employee_deleted'
+	| 'employee_permissions_changed'
+	| 'photo_deleted'
+	| 'settings_updated'
+	| 'settings_rolled_back'
+	| 'calendar_feed_token_rotated'
+	| 'calendar_feed_token_revoked'
+	| 'config_imported'
+	| 'partner_created'
+	| 'partner_updated'
+	| 'partner_key_rotated'
+	| 'campaign_created'
+	| 'campaign_aborted'
+	| 'webhook_created'
+	| 'webhook_updated'
+	| 'webhook_deleted'
+	| 'webhook_secret_rotated'
+	| 'webhook_redelivered';
+
+export type AuditAuthMethod = 'admin_session' | 'admin_pin' | 'employee_session' | 'recovery_code';
+
+/**
+ * A recorded admin action.
+ */
+export interface AuditLogEntry {
+	audit_id: string;
+	action: AuditAction;
+	auth_method: AuditAuthMethod;
+	/** Employee signed in when the action was taken */
+	actor_employee_id: string | null;
+	actor_name: string | null;
+	client_ip: string;
+	/** Kind of record acted on, e.g. 'employee' or 'photo' */
+	target_type: string | null;
+	target_id: string | null;
+	/** What changed (never PINs, codes, or keys) */
+	summary: Record<string, unknown>;
+	created_at: string;
+}
+
+/**
+ * Query parameters for GET /admin/audit-log.
+ */
+export interface AuditLogParams {
+	action?: AuditAction;
+	actor_id?: string;
+	target_id?: string;
+	from_date?: string;
+	to_date?: string;
+	limit?: number;
+	offset?: number;
+}
+
+/**
+ * Response for GET /admin/audit-log.
+ */
+export interface AuditLogResponse {
+	entries: AuditLogEntry[];
+	pagination: PaginationInfo;
+}
+
+export type ReportInterval = 'day' | 'week' | 'month';
+
+/**
+ * Query parameters shared by date-ranged reports.
+ */
+export interface ReportRangeParams {
+	from_date?: string;
+	to_date?: string;
+	interval?: ReportInterval;
+}
+
+/**
+ * Closed-ticket revenue totals.
+ */
+export interface RevenueCounts {
+	closed_tickets: number;
+	revenue: string;
+	average_ticket: string;
+	rush_tickets: number;
+	rush_revenue: string;
+	rush_surcharges: string;
+	/** Cost of the materials used on closed tickets */
+	material_cost: string;
+	/** Revenue less material cost */
+	gross_profit: string;
+}
+
+/**
+ * Response for GET /reports/revenue.
+ */
+export interface RevenueReport {
+	from_date: string;
+	to_date: string;
+	interval: ReportInterval;
+	overall: RevenueCounts;
+	over_time: (RevenueCounts & { period_start: string })[];
+}
+
+/**
+ * Ticket attribution and status activity for one employee.
+ */
+export interface EmployeeProductivity {
+	employee_id: string;
+	employee_name: string;
+	is_active: boolean;
+	tickets_taken_in: number;
+	tickets_worked: number;
+	tickets_closed: number;
+	status_changes: number;
+	/** Null when no worked ticket reached ready_for_pickup */
+	average_days_to_ready: number | null;
+	/** Hours clocked in on tickets in the range */
+	bench_hours: number;
+	tickets_timed: number;
+	/** Null when no ticket was timed */
+	average_bench_hours: number | null;
+}
+
+/**
+ * Response for GET /reports/employees.
+ */
+export interface EmployeeReport {
+	from_date: string;
+	to_date: string;
+	employees: EmployeeProductivity[];
+}
+
+/**
+ * Intake and completion totals.
+ */
+export interface ThroughputCounts {
+	tickets_received: number;
+	rush_received: number;
+	tickets_closed: number;
+	rush_closed: number;
+	/** Null when nothing closed */
+	average_turnaround_days: number | null;
+	rush_average_turnaround_days: number | null;
+}
+
+/**
+ * Response for GET /reports/throughput.
+ */
+export interface ThroughputReport {
+	from_date: string;
+	to_date: string;
+	interval: ReportInterval;
+	overall: ThroughputCounts;
+	over_time: (ThroughputCounts & { period_start: string })[];
+}
+
+/**
+ * Open ticket counts recorded at a point in time.
+ */
+export interface QueueSnapshot {
+	snapshot_id: string;
+	taken_at: string;
+	intake: number;
+	in_progress: number;
+	waiting_on_parts: number;
+	ready_for_pickup: number;
+	overdue: number;
+	rush: number;
+}
+
+/**
+ * Average open ticket counts over the snapshots in a grouping.
+ */
+export interface QueueTrendCounts {
+	snapshots: number;
+	intake: number;
+	in_progress: number;
+	waiting_on_parts: number;
+	ready_for_pickup: number;
+	overdue: number;
+	rush: number;
+	total: number;
+	peak_total: number;
+}
+
+/**
+ * Response for GET /reports/queue-trends.
+ */
+export interface QueueTrendReport {
+	from_date: string;
+	to_date: string;
+	interval: ReportInterval;
+	/** Null before the first snapshot */
+	latest: QueueSnapshot | null;
+	over_time: (QueueTrendCounts & { period_start: string })[];
+	/** ISO day of the week (1 = Monday, UTC) */
+	by_weekday: (QueueTrendCounts & { weekday: number })[];
+}
+
+/**
+ * An open ticket due today or overdue.
+ */
+export interface DueTicket {
+	ticket_id: string;
+	friendly_code: string;
+	customer_id: string;
+	customer_name: string;
+	item_description: string;
+	status: TicketStatus;
+	is_rush: boolean;
+	promise_date: string;
+	/** 0 when due today */
+	days_overdue: number;
+	worked_by: string | null;
+	worked_by_name: string | null;
+}
+
+/**
+ * Response for GET /reports/overdue.
+ */
+export interface OverdueReport {
+	as_of: string;
+	/** Most overdue first */
+	overdue: DueTicket[];
+	due_today: DueTicket[];
+}
+
+/**
+ * Query parameters for GET /reports/location-audit.
+ */
+export interface LocationAuditParams {
+	location_id?: string;
+	from_date?: string;
+	to_date?: string;
+}
+
+/**
+ * Response for GET /reports/location-audit.
+ */
+export interface LocationAuditReport {
+	from_date: string;
+	to_date: string;
+	location_id: string | null;
+	/** Open tickets out at a bench, longest out first */
+	checked_out: TicketMovementEntry[];
+	/** Movements in the range, oldest first */
+	movements: TicketMovementEntry[];
+}
+
+/**
+ * A ticket listed on the daily closeout.
+ */
+export interface CloseoutTicketEntry {
+	ticket_id: string;
+	friendly_code: string;
+	customer_name: string;
+	item_description: string;
+	is_rush: boolean;
+	quote_amount: string | null;
+	actual_amount: string | null;
+	/** Payments taken against the ticket so far */
+	total_paid: string;
+}
+
+/**
+ * Payments taken by one method on the closeout date.
+ */
+export interface PaymentMethodTotal {
+	method: PaymentMethod;
+	payments: number;
+	total: string;
+}
+
+/**
+ * Response for GET /reports/daily-closeout.
+ */
+export interface DailyCloseoutReport {
+	date: string;
+	/** The store's time zone; the day runs midnight to midnight there */
+	timezone: string;
+	store_id: string;
+	store_name: string;
+	/** Tickets received that day, in intake order */
+	taken_in: CloseoutTicketEntry[];
+	/** Tickets closed that day, in close order */
+	closed: CloseoutTicketEntry[];
+	closed_revenue: string;
+	/** One row per payment method */
+	payments_by_method: PaymentMethodTotal[];
+	payments_total: string;
+	/** Tickets waiting for pickup at the end of the day */
+	ready_for_pickup: CloseoutTicketEntry[];
+}
+
+export type ReportDimension = 'status' | 'employee' | 'item_type' | 'month';
+
+export type ReportMeasure = 'count' | 'revenue' | 'avg_turnaround_days';
+
+/**
+ * A saved custom report.
+ */
+export interface ReportDefinition {
+	report_id: string;
+	name: string;
+	dimensions: ReportDimension[];
+	measures: ReportMeasure[];
+	created_at: string;
+	updated_at: string;
+}
+
+/**
+ * Request body for creating or replacing a custom report.
+ */
+export interface ReportDefinitionRequest {
+	name: string;
+	/** At most 3 */
+	dimensions: ReportDimension[];
+	/** At least 1 */
+	measures: ReportMeasure[];
+}
+
+/**
+ * Response for GET /reports/custom.
+ */
+export interface ListReportDefinitionsResponse {
+	reports: ReportDefinition[];
+}
+
+/**
+ * Response for GET /reports/custom/:report_id.
+ */
+export interface CustomReport {
+	report: ReportDefinition;
+	from_date: string;
+	to_date: string;
+	/** Dimensions, then measures */
+	columns: string[];
+	/** Values in `columns` order; revenue is a decimal string */
+	rows: (string | number | null)[][];
 }